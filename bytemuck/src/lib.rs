@@ -2,6 +2,8 @@
 
 pub mod types;
 
+use std::mem::size_of;
+
 use bytemuck::Pod;
 pub use jito_account_traits_derive::AccountDeserialize;
 use solana_program::{msg, program_error::ProgramError};
@@ -13,7 +15,8 @@ pub trait Discriminator {
 pub trait AccountDeserialize: Sized + Pod + Discriminator {
     /// Deserialize the account data into a struct.
     /// It assumes the first byte is the discriminator and the next seven bytes are reserved.
-    /// The rest of the data is deserialized into the struct.
+    /// The rest of the data is deserialized into the struct and must be exactly its size. Accounts
+    /// at an older, smaller layout are grown by migrating them to the current version first.
     ///
     /// # Arguments
     /// * `data` - The account data to deserialize
@@ -34,7 +37,8 @@ pub trait AccountDeserialize: Sized + Pod + Discriminator {
 
     /// Deserialize the account data into a mutable struct.
     /// It assumes the first byte is the discriminator and the next seven bytes are reserved.
-    /// The rest of the data is deserialized into the struct.
+    /// The rest of the data is deserialized into the struct and must be exactly its size. Accounts
+    /// at an older, smaller layout are grown by migrating them to the current version first.
     ///
     /// # Arguments
     /// * `data` - The account data to deserialize
@@ -53,3 +57,51 @@ pub trait AccountDeserialize: Sized + Pod + Discriminator {
         bytemuck::try_from_bytes_mut(&mut data[8..]).map_err(|_| ProgramError::InvalidAccountData)
     }
 }
+
+/// An account whose layout is versioned. The version is stored in the second byte of the account
+/// data, the first of the seven reserved header bytes, so it can be read without knowing the
+/// layout. Accounts created before their layout was versioned have a zero there and are at
+/// version 0.
+pub trait AccountVersion: AccountDeserialize {
+    /// The version of the layout the program reads and writes
+    const VERSION: u8;
+
+    /// Reads the layout version from the account data's header
+    ///
+    /// # Arguments
+    /// * `data` - The account data
+    ///
+    /// # Returns
+    /// * `Result<u8, ProgramError>` - The layout version of the account data
+    fn version(data: &[u8]) -> Result<u8, ProgramError> {
+        data.get(1).copied().ok_or(ProgramError::InvalidAccountData)
+    }
+
+    /// The size of the account data, header included, at a layout version. Accounts can only be
+    /// migrated from versions whose size is known. The default only knows the current layout, so
+    /// a layout that changes size shall override this with the size of each older version.
+    ///
+    /// # Arguments
+    /// * `version` - The layout version
+    ///
+    /// # Returns
+    /// * `Option<usize>` - The size of the account data at `version`, or `None` if it's unknown
+    fn size_of_version(version: u8) -> Option<usize> {
+        (version == Self::VERSION).then(|| 8 + size_of::<Self>())
+    }
+
+    /// Upgrades account data from `from_version` to the current layout in place. The data has
+    /// already been grown to fit the current layout, with the new space zeroed, and the version is
+    /// written once this returns. The default keeps the zeroed space, which is enough when new
+    /// fields are carved out of the reserved space or default to zero.
+    ///
+    /// # Arguments
+    /// * `data` - The account data, header included
+    /// * `from_version` - The layout version the account data was at
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    fn migrate(_data: &mut [u8], _from_version: u8) -> Result<(), ProgramError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,371 @@
+//! Composes the restaking handshake between a vault, an NCN and an operator.
+//!
+//! Before a vault can delegate to an operator securing an NCN, both programs need a ticket for
+//! every pair: the NCN and vault opt in to each other, the NCN and operator opt in to each other,
+//! and the operator and vault opt in to each other. Each opt-in is created by a different admin,
+//! and tickets can only be warmed up in a later slot than the one they were created in.
+//! [`HandshakeBuilder`] reads the accounts involved and produces the instructions still missing,
+//! in the order they need to land.
+use anyhow::{anyhow, Result};
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::slot_toggle::{SlotToggle, SlotToggleState};
+use jito_restaking_core::{
+    ncn::Ncn, ncn_operator_state::NcnOperatorState, ncn_vault_ticket::NcnVaultTicket,
+    operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_core::{
+    vault::Vault, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::transaction::Transaction;
+
+/// The transactions making up a handshake, unsigned.
+///
+/// Every transaction in [`Handshake::initialize`] must land before any transaction in
+/// [`Handshake::warmup`] is sent, in an earlier slot. Once warmed up, each ticket becomes active
+/// after a full epoch of warm-up, after which the vault can delegate to the operator.
+#[derive(Debug, Default)]
+pub struct Handshake {
+    /// Creates the tickets on both programs
+    pub initialize: Vec<Transaction>,
+
+    /// Warms up the tickets
+    pub warmup: Vec<Transaction>,
+}
+
+impl Handshake {
+    /// Whether the vault, NCN and operator have already completed the handshake
+    pub fn is_empty(&self) -> bool {
+        self.initialize.is_empty() && self.warmup.is_empty()
+    }
+}
+
+/// Builds the restaking and vault instructions connecting a vault, an NCN and an operator.
+///
+/// The builder validates the handshake against on-chain state first: the vault, NCN and operator
+/// must exist, the vault must be up-to-date, and no ticket may be cooling down. Tickets that
+/// already exist aren't created again and tickets that are warming up or active aren't warmed up
+/// again, so an interrupted handshake can be resumed by building it again.
+///
+/// There's one transaction per pair in each stage, signed by the admins the accounts name for it
+/// and paid for by `payer`.
+pub struct HandshakeBuilder {
+    restaking_program_id: Pubkey,
+    vault_program_id: Pubkey,
+    vault: Pubkey,
+    ncn: Pubkey,
+    operator: Pubkey,
+    payer: Pubkey,
+}
+
+impl HandshakeBuilder {
+    pub const fn new(
+        restaking_program_id: Pubkey,
+        vault_program_id: Pubkey,
+        vault: Pubkey,
+        ncn: Pubkey,
+        operator: Pubkey,
+        payer: Pubkey,
+    ) -> Self {
+        Self {
+            restaking_program_id,
+            vault_program_id,
+            vault,
+            ncn,
+            operator,
+            payer,
+        }
+    }
+
+    /// Reads the accounts involved and builds the transactions still needed to complete the
+    /// handshake
+    pub async fn build(&self, rpc_client: &RpcClient) -> Result<Handshake> {
+        let restaking_config_address =
+            jito_restaking_core::config::Config::find_program_address(&self.restaking_program_id).0;
+        let vault_config_address =
+            jito_vault_core::config::Config::find_program_address(&self.vault_program_id).0;
+        let ncn_vault_ticket_address = NcnVaultTicket::find_program_address(
+            &self.restaking_program_id,
+            &self.ncn,
+            &self.vault,
+        )
+        .0;
+        let ncn_operator_state_address = NcnOperatorState::find_program_address(
+            &self.restaking_program_id,
+            &self.ncn,
+            &self.operator,
+        )
+        .0;
+        let operator_vault_ticket_address = OperatorVaultTicket::find_program_address(
+            &self.restaking_program_id,
+            &self.operator,
+            &self.vault,
+        )
+        .0;
+        let vault_ncn_ticket_address =
+            VaultNcnTicket::find_program_address(&self.vault_program_id, &self.vault, &self.ncn).0;
+        let vault_operator_delegation_address = VaultOperatorDelegation::find_program_address(
+            &self.vault_program_id,
+            &self.vault,
+            &self.operator,
+        )
+        .0;
+
+        let restaking_config = get_account::<jito_restaking_core::config::Config>(
+            rpc_client,
+            &restaking_config_address,
+        )
+        .await?
+        .ok_or_else(|| anyhow!("Restaking config {restaking_config_address} not found"))?;
+        let vault_config =
+            get_account::<jito_vault_core::config::Config>(rpc_client, &vault_config_address)
+                .await?
+                .ok_or_else(|| anyhow!("Vault config {vault_config_address} not found"))?;
+        let vault = get_account::<Vault>(rpc_client, &self.vault)
+            .await?
+            .ok_or_else(|| anyhow!("Vault {} not found", self.vault))?;
+        let ncn = get_account::<Ncn>(rpc_client, &self.ncn)
+            .await?
+            .ok_or_else(|| anyhow!("NCN {} not found", self.ncn))?;
+        let operator = get_account::<Operator>(rpc_client, &self.operator)
+            .await?
+            .ok_or_else(|| anyhow!("Operator {} not found", self.operator))?;
+        let ncn_vault_ticket =
+            get_account::<NcnVaultTicket>(rpc_client, &ncn_vault_ticket_address).await?;
+        let ncn_operator_state =
+            get_account::<NcnOperatorState>(rpc_client, &ncn_operator_state_address).await?;
+        let operator_vault_ticket =
+            get_account::<OperatorVaultTicket>(rpc_client, &operator_vault_ticket_address).await?;
+        let vault_ncn_ticket =
+            get_account::<VaultNcnTicket>(rpc_client, &vault_ncn_ticket_address).await?;
+        let vault_operator_delegation =
+            get_account::<VaultOperatorDelegation>(rpc_client, &vault_operator_delegation_address)
+                .await?;
+
+        let slot = rpc_client.get_slot().await?;
+        let restaking_epoch_length = restaking_config.epoch_length();
+        let vault_epoch_length = vault_config.epoch_length();
+
+        // The vault program rejects new tickets until the vault is updated for the current epoch
+        if vault.is_update_needed(slot, vault_epoch_length) {
+            return Err(anyhow!(
+                "Vault {} must be updated for the current epoch before the handshake",
+                self.vault
+            ));
+        }
+
+        let ncn_vault_ticket_warmup = needs_warmup(
+            "NCN vault ticket",
+            ncn_vault_ticket.as_ref().map(|ticket| &ticket.state),
+            slot,
+            restaking_epoch_length,
+        )?;
+        let vault_ncn_ticket_warmup = needs_warmup(
+            "Vault NCN ticket",
+            vault_ncn_ticket.as_ref().map(|ticket| &ticket.state),
+            slot,
+            vault_epoch_length,
+        )?;
+        let ncn_opt_in_warmup = needs_warmup(
+            "NCN opt-in to the operator",
+            ncn_operator_state
+                .as_ref()
+                .map(|state| &state.ncn_opt_in_state),
+            slot,
+            restaking_epoch_length,
+        )?;
+        let operator_opt_in_warmup = needs_warmup(
+            "Operator opt-in to the NCN",
+            ncn_operator_state
+                .as_ref()
+                .map(|state| &state.operator_opt_in_state),
+            slot,
+            restaking_epoch_length,
+        )?;
+        let operator_vault_ticket_warmup = needs_warmup(
+            "Operator vault ticket",
+            operator_vault_ticket.as_ref().map(|ticket| &ticket.state),
+            slot,
+            restaking_epoch_length,
+        )?;
+
+        let mut ncn_vault_initialize = Vec::new();
+        let mut ncn_vault_warmup = Vec::new();
+        if ncn_vault_ticket.is_none() {
+            ncn_vault_initialize.push(jito_restaking_sdk::sdk::initialize_ncn_vault_ticket(
+                &self.restaking_program_id,
+                &restaking_config_address,
+                &self.ncn,
+                &self.vault,
+                &ncn_vault_ticket_address,
+                &ncn.vault_admin,
+                &self.payer,
+            ));
+        }
+        if vault_ncn_ticket.is_none() {
+            ncn_vault_initialize.push(jito_vault_sdk::sdk::initialize_vault_ncn_ticket(
+                &self.vault_program_id,
+                &vault_config_address,
+                &self.vault,
+                &self.ncn,
+                &ncn_vault_ticket_address,
+                &vault_ncn_ticket_address,
+                &vault.ncn_admin,
+                &self.payer,
+            ));
+        }
+        if ncn_vault_ticket_warmup {
+            ncn_vault_warmup.push(jito_restaking_sdk::sdk::warmup_ncn_vault_ticket(
+                &self.restaking_program_id,
+                &restaking_config_address,
+                &self.ncn,
+                &self.vault,
+                &ncn_vault_ticket_address,
+                &ncn.vault_admin,
+            ));
+        }
+        if vault_ncn_ticket_warmup {
+            ncn_vault_warmup.push(jito_vault_sdk::sdk::warmup_vault_ncn_ticket(
+                &self.vault_program_id,
+                &vault_config_address,
+                &self.vault,
+                &self.ncn,
+                &vault_ncn_ticket_address,
+                &vault.ncn_admin,
+            ));
+        }
+
+        let mut ncn_operator_initialize = Vec::new();
+        let mut ncn_operator_warmup = Vec::new();
+        if ncn_operator_state.is_none() {
+            ncn_operator_initialize.push(jito_restaking_sdk::sdk::initialize_ncn_operator_state(
+                &self.restaking_program_id,
+                &restaking_config_address,
+                &self.ncn,
+                &self.operator,
+                &ncn_operator_state_address,
+                &ncn.operator_admin,
+                &self.payer,
+            ));
+        }
+        if ncn_opt_in_warmup {
+            ncn_operator_warmup.push(jito_restaking_sdk::sdk::ncn_warmup_operator(
+                &self.restaking_program_id,
+                &restaking_config_address,
+                &self.ncn,
+                &self.operator,
+                &ncn_operator_state_address,
+                &ncn.operator_admin,
+            ));
+        }
+        if operator_opt_in_warmup {
+            ncn_operator_warmup.push(jito_restaking_sdk::sdk::operator_warmup_ncn(
+                &self.restaking_program_id,
+                &restaking_config_address,
+                &self.ncn,
+                &self.operator,
+                &ncn_operator_state_address,
+                &operator.ncn_admin,
+            ));
+        }
+
+        let mut operator_vault_initialize = Vec::new();
+        let mut operator_vault_warmup = Vec::new();
+        if operator_vault_ticket.is_none() {
+            operator_vault_initialize.push(
+                jito_restaking_sdk::sdk::initialize_operator_vault_ticket(
+                    &self.restaking_program_id,
+                    &restaking_config_address,
+                    &self.operator,
+                    &self.vault,
+                    &operator_vault_ticket_address,
+                    &operator.vault_admin,
+                    &self.payer,
+                ),
+            );
+        }
+        if vault_operator_delegation.is_none() {
+            operator_vault_initialize.push(
+                jito_vault_sdk::sdk::initialize_vault_operator_delegation(
+                    &self.vault_program_id,
+                    &vault_config_address,
+                    &self.vault,
+                    &self.operator,
+                    &operator_vault_ticket_address,
+                    &vault_operator_delegation_address,
+                    &vault.operator_admin,
+                    &self.payer,
+                ),
+            );
+        }
+        if operator_vault_ticket_warmup {
+            operator_vault_warmup.push(jito_restaking_sdk::sdk::warmup_operator_vault_ticket(
+                &self.restaking_program_id,
+                &restaking_config_address,
+                &self.operator,
+                &self.vault,
+                &operator_vault_ticket_address,
+                &operator.vault_admin,
+            ));
+        }
+
+        Ok(Handshake {
+            initialize: self.transactions([
+                ncn_vault_initialize,
+                ncn_operator_initialize,
+                operator_vault_initialize,
+            ]),
+            warmup: self.transactions([
+                ncn_vault_warmup,
+                ncn_operator_warmup,
+                operator_vault_warmup,
+            ]),
+        })
+    }
+
+    /// Builds an unsigned transaction paid for by the payer for each non-empty set of instructions
+    fn transactions<const N: usize>(
+        &self,
+        instructions: [Vec<Instruction>; N],
+    ) -> Vec<Transaction> {
+        instructions
+            .iter()
+            .filter(|instructions| !instructions.is_empty())
+            .map(|instructions| Transaction::new_with_payer(instructions, Some(&self.payer)))
+            .collect()
+    }
+}
+
+/// Reads and deserializes an account, returning `None` if it doesn't exist
+async fn get_account<T: AccountDeserialize + Copy>(
+    rpc_client: &RpcClient,
+    address: &Pubkey,
+) -> Result<Option<T>> {
+    let account = rpc_client
+        .get_account_with_commitment(address, rpc_client.commitment())
+        .await?
+        .value;
+    match account {
+        Some(account) => Ok(Some(*T::try_from_slice_unchecked(&account.data)?)),
+        None => Ok(None),
+    }
+}
+
+/// Whether a ticket still needs warming up. A ticket that doesn't exist yet will be created
+/// inactive, and a ticket that's cooling down can't be warmed up until its cooldown completes.
+fn needs_warmup(
+    name: &str,
+    toggle: Option<&SlotToggle>,
+    slot: u64,
+    epoch_length: u64,
+) -> Result<bool> {
+    match toggle.map(|toggle| toggle.state(slot, epoch_length)) {
+        None | Some(SlotToggleState::Inactive) => Ok(true),
+        Some(SlotToggleState::WarmUp) | Some(SlotToggleState::Active) => Ok(false),
+        Some(SlotToggleState::Cooldown) => Err(anyhow!(
+            "{name} is cooling down, wait for it to become inactive before the handshake"
+        )),
+    }
+}
@@ -1,9 +1,11 @@
 use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
 
 pub mod cli_args;
+pub mod handshake;
 pub mod log;
 pub mod restaking;
 pub mod restaking_handler;
+pub mod security_txt;
 pub mod vault;
 pub mod vault_handler;
 
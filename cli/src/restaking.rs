@@ -16,6 +16,8 @@ pub enum RestakingCommands {
         #[command(subcommand)]
         action: OperatorActions,
     },
+    /// Displays the security.txt embedded in the restaking program
+    SecurityTxt,
 }
 
 /// The actions that can be performed on the restaking config
@@ -45,4 +47,15 @@ pub enum OperatorActions {
     Get { pubkey: String },
     /// List all operators
     List,
+    /// Set the operator's name and website
+    SetMetadata {
+        /// The operator
+        operator: String,
+        /// The display name, at most 32 bytes
+        #[arg(long, default_value = "")]
+        name: String,
+        /// The website, at most 64 bytes
+        #[arg(long, default_value = "")]
+        website: String,
+    },
 }
@@ -6,6 +6,7 @@ use jito_restaking_client::instructions::{
     InitializeConfigBuilder, InitializeNcnBuilder, InitializeOperatorBuilder,
 };
 use jito_restaking_core::{config::Config, ncn::Ncn, operator::Operator};
+use jito_restaking_sdk::sdk::operator_set_metadata;
 use log::{debug, info};
 use solana_account_decoder::UiAccountEncoding;
 use solana_program::pubkey::Pubkey;
@@ -21,6 +22,7 @@ use solana_sdk::{
 
 use crate::{
     restaking::{ConfigActions, NcnActions, OperatorActions, RestakingCommands},
+    security_txt::get_security_txt,
     CliConfig,
 };
 
@@ -69,9 +71,28 @@ impl RestakingCliHandler {
             RestakingCommands::Operator {
                 action: OperatorActions::List,
             } => self.operator_list().await,
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::SetMetadata {
+                        operator,
+                        name,
+                        website,
+                    },
+            } => self.operator_set_metadata(operator, name, website).await,
+            RestakingCommands::SecurityTxt => self.get_security_txt().await,
         }
     }
 
+    pub async fn get_security_txt(&self) -> Result<()> {
+        let rpc_client = self.get_rpc_client();
+        let security_txt = get_security_txt(&rpc_client, &self.restaking_program_id).await?;
+        info!(
+            "Restaking program {} security.txt:\n{}",
+            self.restaking_program_id, security_txt
+        );
+        Ok(())
+    }
+
     pub async fn get_config(&self) -> Result<()> {
         let rpc_client = self.get_rpc_client();
 
@@ -254,6 +275,11 @@ impl RestakingCliHandler {
         let account = self.get_rpc_client().get_account(&pubkey).await?;
         let operator = Operator::try_from_slice_unchecked(&account.data)?;
         info!("Operator at address {}: {:?}", pubkey, operator);
+        info!(
+            "Name: {:?}, website: {:?}",
+            operator.name(),
+            operator.website()
+        );
 
         Ok(())
     }
@@ -281,10 +307,54 @@ impl RestakingCliHandler {
         for (operator_pubkey, operator) in accounts {
             let operator = Operator::try_from_slice_unchecked(&operator.data)?;
             info!("Operator at address {}: {:?}", operator_pubkey, operator);
+            info!(
+                "Name: {:?}, website: {:?}",
+                operator.name(),
+                operator.website()
+            );
         }
         Ok(())
     }
 
+    pub async fn operator_set_metadata(
+        &self,
+        operator: String,
+        name: String,
+        website: String,
+    ) -> Result<()> {
+        let keypair = self
+            .cli_config
+            .keypair
+            .as_ref()
+            .ok_or_else(|| anyhow!("No keypair"))?;
+        let rpc_client = self.get_rpc_client();
+
+        let operator = Pubkey::from_str(&operator)?;
+        let ix = operator_set_metadata(
+            &self.restaking_program_id,
+            &operator,
+            &keypair.pubkey(),
+            name,
+            website,
+        );
+
+        let blockhash = rpc_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&keypair.pubkey()),
+            &[keypair],
+            blockhash,
+        );
+        info!(
+            "Setting operator metadata transaction: {:?}",
+            tx.get_signature()
+        );
+        rpc_client.send_and_confirm_transaction(&tx).await?;
+        info!("Transaction confirmed");
+
+        Ok(())
+    }
+
     fn get_rpc_client(&self) -> RpcClient {
         RpcClient::new_with_commitment(self.cli_config.rpc_url.clone(), self.cli_config.commitment)
     }
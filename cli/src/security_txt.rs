@@ -0,0 +1,35 @@
+//! Reads the security.txt embedded in a deployed program with the `security_txt!` macro.
+//!
+//! Upgradeable programs keep their ELF in a separate program data account, behind the loader's
+//! metadata, so the program data account is read instead of the program account.
+use anyhow::{anyhow, Result};
+use solana_program::{bpf_loader_upgradeable::UpgradeableLoaderState, pubkey::Pubkey};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_security_txt::{find_and_parse, SecurityTxt};
+
+/// Fetches and parses the security.txt of the program at `program_id`
+pub async fn get_security_txt(rpc_client: &RpcClient, program_id: &Pubkey) -> Result<SecurityTxt> {
+    let program_account = rpc_client.get_account(program_id).await?;
+
+    let elf = if program_account
+        .owner
+        .eq(&solana_program::bpf_loader_upgradeable::id())
+    {
+        let UpgradeableLoaderState::Program {
+            programdata_address,
+        } = program_account.deserialize_data()?
+        else {
+            return Err(anyhow!("Account {} is not a program", program_id));
+        };
+        let program_data_account = rpc_client.get_account(&programdata_address).await?;
+        program_data_account
+            .data
+            .get(UpgradeableLoaderState::size_of_programdata_metadata()..)
+            .ok_or_else(|| anyhow!("Program data account {} is empty", programdata_address))?
+            .to_vec()
+    } else {
+        program_account.data
+    };
+
+    find_and_parse(&elf).map_err(|e| anyhow!("No security.txt in program {}: {}", program_id, e))
+}
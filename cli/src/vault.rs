@@ -10,6 +10,18 @@ pub enum VaultCommands {
         #[command(subcommand)]
         action: VaultActions,
     },
+    /// Withdrawal ticket commands
+    Ticket {
+        #[command(subcommand)]
+        action: TicketActions,
+    },
+    /// Vault NCN slasher commands
+    Slasher {
+        #[command(subcommand)]
+        action: SlasherActions,
+    },
+    /// Displays the security.txt embedded in the vault program
+    SecurityTxt,
 }
 
 #[derive(Subcommand)]
@@ -52,4 +64,61 @@ pub enum VaultActions {
     },
     /// List all vaults
     List,
+    /// Computes the realized APY of a vault from the exchange rates of its deposits and
+    /// withdrawals over a window
+    Apy {
+        /// The vault pubkey
+        vault: String,
+        /// The window to compute the APY over, in days (`30d`), hours (`12h`) or years (`1y`)
+        #[arg(long, default_value = "30d")]
+        window: String,
+    },
+}
+
+/// Withdrawal ticket commands
+#[derive(Subcommand)]
+pub enum TicketActions {
+    /// List all withdrawal tickets for a vault
+    List {
+        /// The vault pubkey
+        vault: String,
+    },
+    /// List the withdrawal tickets a staker can claim now
+    ListClaimable {
+        /// The staker pubkey
+        staker: String,
+    },
+    /// Sets the fee paid to whoever claims the withdrawal ticket, opting it into auto-claiming
+    SetAutoClaimFee {
+        /// The withdrawal ticket pubkey
+        ticket: String,
+        /// The auto-claim fee in bips, zero opts out of auto-claiming
+        fee_bps: u16,
+    },
+    /// Runs a keeper that claims matured withdrawal tickets which opted into auto-claiming,
+    /// collecting their auto-claim fee
+    AutoClaim {
+        /// Seconds to wait between passes over the withdrawal tickets
+        #[arg(long, default_value_t = 60)]
+        interval_secs: u64,
+    },
+}
+
+/// Vault NCN slasher commands
+#[derive(Subcommand)]
+pub enum SlasherActions {
+    /// List the slashers of a vault along with their current state
+    List {
+        /// The vault pubkey
+        vault: String,
+    },
+    /// Removes a slasher from a vault, the slasher must be cooled down and inactive
+    Remove {
+        /// The vault pubkey
+        vault: String,
+        /// The NCN pubkey
+        ncn: String,
+        /// The slasher pubkey
+        slasher: String,
+    },
 }
@@ -1,27 +1,47 @@
-use std::str::FromStr;
+use std::{mem::offset_of, str::FromStr, time::Duration};
 
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_vault_client::instructions::{
     CreateTokenMetadataBuilder, InitializeConfigBuilder, InitializeVaultBuilder,
 };
-use jito_vault_core::{config::Config, vault::Vault};
-use jito_vault_sdk::inline_mpl_token_metadata;
-use log::{debug, info};
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_sdk::{
+    apy::{ExchangeRateCheckpoint, RealizedApy},
+    events::{parse_vault_events, DepositEvent, SlashedEvent, VaultEvent, WithdrawEvent},
+    inline_mpl_token_metadata,
+    sdk::{
+        burn_withdrawal_ticket, initialize_vault_token_account, remove_slasher,
+        set_withdrawal_ticket_auto_claim_fee,
+    },
+};
+use log::{debug, error, info};
 use solana_account_decoder::UiAccountEncoding;
 use solana_program::pubkey::Pubkey;
-use solana_rpc_client::{nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction};
+use solana_rpc_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_client::{GetConfirmedSignaturesForAddress2Config, SerializableTransaction},
+};
 use solana_rpc_client_api::{
-    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionConfig},
     filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
 use solana_sdk::{
-    signature::{Keypair, Signer},
+    signature::{Keypair, Signature, Signer},
     transaction::Transaction,
 };
+use solana_transaction_status::{option_serializer::OptionSerializer, UiTransactionEncoding};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
 
 use crate::{
-    vault::{ConfigActions, VaultActions, VaultCommands},
+    security_txt::get_security_txt,
+    vault::{ConfigActions, SlasherActions, TicketActions, VaultActions, VaultCommands},
     CliConfig,
 };
 
@@ -77,6 +97,9 @@ impl VaultCliHandler {
             VaultCommands::Vault {
                 action: VaultActions::List,
             } => self.list_vaults().await,
+            VaultCommands::Vault {
+                action: VaultActions::Apy { vault, window },
+            } => self.realized_apy(vault, window).await,
             VaultCommands::Vault {
                 action:
                     VaultActions::CreateTokenMetadata {
@@ -86,9 +109,61 @@ impl VaultCliHandler {
                         uri,
                     },
             } => self.create_token_metadata(vault, name, symbol, uri).await,
+            VaultCommands::Ticket {
+                action: TicketActions::List { vault },
+            } => {
+                let vault = Pubkey::from_str(&vault)?;
+                for (ticket_pubkey, ticket) in self.list_withdrawal_tickets(&vault).await? {
+                    info!(
+                        "withdrawal ticket at address {}: {:?}",
+                        ticket_pubkey, ticket
+                    );
+                }
+                Ok(())
+            }
+            VaultCommands::Ticket {
+                action: TicketActions::ListClaimable { staker },
+            } => {
+                let staker = Pubkey::from_str(&staker)?;
+                for (ticket_pubkey, ticket) in self.list_claimable_tickets(&staker).await? {
+                    info!(
+                        "withdrawal ticket at address {}: {:?}",
+                        ticket_pubkey, ticket
+                    );
+                }
+                Ok(())
+            }
+            VaultCommands::Ticket {
+                action: TicketActions::SetAutoClaimFee { ticket, fee_bps },
+            } => self.set_auto_claim_fee(ticket, fee_bps).await,
+            VaultCommands::Ticket {
+                action: TicketActions::AutoClaim { interval_secs },
+            } => self.auto_claim(interval_secs).await,
+            VaultCommands::Slasher {
+                action: SlasherActions::List { vault },
+            } => self.list_slashers(vault).await,
+            VaultCommands::Slasher {
+                action:
+                    SlasherActions::Remove {
+                        vault,
+                        ncn,
+                        slasher,
+                    },
+            } => self.remove_slasher(vault, ncn, slasher).await,
+            VaultCommands::SecurityTxt => self.get_security_txt().await,
         }
     }
 
+    pub async fn get_security_txt(&self) -> Result<()> {
+        let rpc_client = self.get_rpc_client();
+        let security_txt = get_security_txt(&rpc_client, &self.vault_program_id).await?;
+        info!(
+            "Vault program {} security.txt:\n{}",
+            self.vault_program_id, security_txt
+        );
+        Ok(())
+    }
+
     pub async fn initialize_config(&self) -> Result<()> {
         let keypair = self
             .cli_config
@@ -172,7 +247,16 @@ impl VaultCliHandler {
 
         let blockhash = rpc_client.get_latest_blockhash().await?;
         let tx = Transaction::new_signed_with_payer(
-            &[ix_builder.instruction()],
+            &[
+                ix_builder.instruction(),
+                initialize_vault_token_account(
+                    &self.vault_program_id,
+                    &vault,
+                    &get_associated_token_address(&vault, &token_mint),
+                    &token_mint,
+                    &keypair.pubkey(),
+                ),
+            ],
             Some(&keypair.pubkey()),
             &[keypair, &base, &vrt_mint],
             blockhash,
@@ -220,6 +304,435 @@ impl VaultCliHandler {
         Ok(())
     }
 
+    /// Computes the realized APY of a vault over a window ending now. The exchange rate at the
+    /// start of the window is the rate of the earliest deposit or withdrawal in it, read from the
+    /// vault events in the vault's transaction logs.
+    pub async fn realized_apy(&self, vault: String, window: String) -> Result<()> {
+        let vault_pubkey = Pubkey::from_str(&vault)?;
+        let window_seconds = parse_window(&window)?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault_account = rpc_client.get_account(&vault_pubkey).await?;
+        let vault = Vault::try_from_slice_unchecked(&vault_account.data)?;
+        let end = ExchangeRateCheckpoint::from_vault(
+            Utc::now().timestamp(),
+            vault.tokens_deposited(),
+            vault.vrt_supply(),
+        );
+        let window_start = end.unix_timestamp - window_seconds;
+
+        let mut checkpoints = Vec::new();
+        let mut slashes = Vec::new();
+        let mut before = None;
+        'pages: loop {
+            let signatures = rpc_client
+                .get_signatures_for_address_with_config(
+                    &vault_pubkey,
+                    GetConfirmedSignaturesForAddress2Config {
+                        before,
+                        until: None,
+                        limit: None,
+                        commitment: None,
+                    },
+                )
+                .await?;
+            let Some(last) = signatures.last() else {
+                break;
+            };
+            before = Some(Signature::from_str(&last.signature)?);
+
+            for signature in signatures {
+                let Some(block_time) = signature.block_time else {
+                    continue;
+                };
+                if block_time < window_start {
+                    break 'pages;
+                }
+                if signature.err.is_some() {
+                    continue;
+                }
+
+                let tx = rpc_client
+                    .get_transaction_with_config(
+                        &Signature::from_str(&signature.signature)?,
+                        RpcTransactionConfig {
+                            encoding: Some(UiTransactionEncoding::Base64),
+                            commitment: None,
+                            max_supported_transaction_version: Some(0),
+                        },
+                    )
+                    .await?;
+                let Some(OptionSerializer::Some(logs)) =
+                    tx.transaction.meta.map(|meta| meta.log_messages)
+                else {
+                    continue;
+                };
+                for event in parse_vault_events(&self.vault_program_id, &logs) {
+                    match &event {
+                        VaultEvent::Deposit(DepositEvent { vault, .. })
+                        | VaultEvent::Withdraw(WithdrawEvent { vault, .. })
+                            if vault.eq(&vault_pubkey) =>
+                        {
+                            checkpoints
+                                .extend(ExchangeRateCheckpoint::from_event(block_time, &event));
+                        }
+                        VaultEvent::Slashed(SlashedEvent { vault, amount, .. })
+                            if vault.eq(&vault_pubkey) =>
+                        {
+                            slashes.push((block_time, *amount));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        debug!(
+            "Found {} exchange rate checkpoints and {} slashes for vault {}",
+            checkpoints.len(),
+            slashes.len(),
+            vault_pubkey
+        );
+
+        let Some((start, apy)) = RealizedApy::over_window(
+            &checkpoints,
+            &end,
+            window_seconds,
+            &slashes,
+            vault.deposit_fee_bps(),
+            vault.withdrawal_fee_bps(),
+        ) else {
+            return Err(anyhow!(
+                "No deposits or withdrawals of vault {} in the last {}",
+                vault_pubkey,
+                window
+            ));
+        };
+
+        info!(
+            "vault {} realized APY from {} to {}: {:.4}%",
+            vault_pubkey,
+            DateTime::from_timestamp(start.unix_timestamp, 0).unwrap_or_default(),
+            DateTime::from_timestamp(end.unix_timestamp, 0).unwrap_or_default(),
+            apy.apy * 100.0
+        );
+        info!("APY before slashes: {:.4}%", apy.apy_before_slashes * 100.0);
+        info!(
+            "APY net of deposit and withdrawal fees: {:.4}%",
+            apy.fee_adjusted_apy * 100.0
+        );
+        Ok(())
+    }
+
+    /// Lists the withdrawal tickets of a vault
+    pub async fn list_withdrawal_tickets(
+        &self,
+        vault: &Pubkey,
+    ) -> Result<Vec<(Pubkey, VaultStakerWithdrawalTicket)>> {
+        self.get_withdrawal_tickets(Some(Memcmp::new(
+            8 + offset_of!(VaultStakerWithdrawalTicket, vault),
+            MemcmpEncodedBytes::Bytes(vault.to_bytes().to_vec()),
+        )))
+        .await
+    }
+
+    /// Lists the withdrawal tickets owned by a staker that can be burned at the current slot
+    pub async fn list_claimable_tickets(
+        &self,
+        staker: &Pubkey,
+    ) -> Result<Vec<(Pubkey, VaultStakerWithdrawalTicket)>> {
+        let rpc_client = self.get_rpc_client();
+        let config_account = rpc_client
+            .get_account(&Config::find_program_address(&self.vault_program_id).0)
+            .await?;
+        let config = Config::try_from_slice_unchecked(&config_account.data)?;
+        let config_epoch_length = config.epoch_length();
+        let cooldown_epochs = config.delegation_cooldown_epochs();
+        let slot = rpc_client.get_slot().await?;
+
+        let tickets = self
+            .get_withdrawal_tickets(Some(Memcmp::new(
+                8 + offset_of!(VaultStakerWithdrawalTicket, staker),
+                MemcmpEncodedBytes::Bytes(staker.to_bytes().to_vec()),
+            )))
+            .await?;
+        let mut claimable_tickets = Vec::with_capacity(tickets.len());
+        for (ticket_pubkey, ticket) in tickets {
+            let vault_account = rpc_client.get_account(&ticket.vault).await?;
+            let epoch_length = Vault::try_from_slice_unchecked(&vault_account.data)?
+                .epoch_length(config_epoch_length);
+            if ticket.is_withdrawable(slot, epoch_length, cooldown_epochs)? {
+                claimable_tickets.push((ticket_pubkey, ticket));
+            }
+        }
+        Ok(claimable_tickets)
+    }
+
+    /// Fetches the withdrawal tickets, optionally narrowed down by a filter on the ticket's fields
+    async fn get_withdrawal_tickets(
+        &self,
+        filter: Option<Memcmp>,
+    ) -> Result<Vec<(Pubkey, VaultStakerWithdrawalTicket)>> {
+        let rpc_client = self.get_rpc_client();
+        let mut filters = vec![RpcFilterType::Memcmp(Memcmp::new(
+            0,
+            MemcmpEncodedBytes::Bytes(vec![VaultStakerWithdrawalTicket::DISCRIMINATOR]),
+        ))];
+        if let Some(filter) = filter {
+            filters.push(RpcFilterType::Memcmp(filter));
+        }
+        let accounts = rpc_client
+            .get_program_accounts_with_config(
+                &self.vault_program_id,
+                RpcProgramAccountsConfig {
+                    filters: Some(filters),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        data_slice: None,
+                        commitment: None,
+                        min_context_slot: None,
+                    },
+                    with_context: None,
+                },
+            )
+            .await?;
+        let mut tickets = Vec::with_capacity(accounts.len());
+        for (ticket_pubkey, account) in accounts {
+            let ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked(&account.data)?;
+            tickets.push((ticket_pubkey, *ticket));
+        }
+        Ok(tickets)
+    }
+
+    async fn set_auto_claim_fee(&self, ticket: String, fee_bps: u16) -> Result<()> {
+        let keypair = self
+            .cli_config
+            .keypair
+            .as_ref()
+            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let ticket_pubkey = Pubkey::from_str(&ticket)?;
+
+        let rpc_client = self.get_rpc_client();
+        let ticket_account = rpc_client.get_account(&ticket_pubkey).await?;
+        let ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked(&ticket_account.data)?;
+
+        let ix = set_withdrawal_ticket_auto_claim_fee(
+            &self.vault_program_id,
+            &Config::find_program_address(&self.vault_program_id).0,
+            &ticket.vault,
+            &ticket_pubkey,
+            &keypair.pubkey(),
+            fee_bps,
+        );
+
+        let recent_blockhash = rpc_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&keypair.pubkey()),
+            &[keypair],
+            recent_blockhash,
+        );
+        info!(
+            "Setting withdrawal ticket auto-claim fee transaction: {:?}",
+            tx.get_signature()
+        );
+        rpc_client.send_and_confirm_transaction(&tx).await?;
+        info!("Transaction confirmed: {:?}", tx.get_signature());
+
+        Ok(())
+    }
+
+    /// Lists the slashers of a vault with the state of their ticket at the current slot. Removed
+    /// slashers have no ticket and aren't listed.
+    async fn list_slashers(&self, vault: String) -> Result<()> {
+        let vault = Pubkey::from_str(&vault)?;
+        let rpc_client = self.get_rpc_client();
+        let config_account = rpc_client
+            .get_account(&Config::find_program_address(&self.vault_program_id).0)
+            .await?;
+        let epoch_length = Config::try_from_slice_unchecked(&config_account.data)?.epoch_length();
+        let slot = rpc_client.get_slot().await?;
+
+        let accounts = rpc_client
+            .get_program_accounts_with_config(
+                &self.vault_program_id,
+                RpcProgramAccountsConfig {
+                    filters: Some(vec![
+                        RpcFilterType::Memcmp(Memcmp::new(
+                            0,
+                            MemcmpEncodedBytes::Bytes(vec![VaultNcnSlasherTicket::DISCRIMINATOR]),
+                        )),
+                        RpcFilterType::Memcmp(Memcmp::new(
+                            8 + offset_of!(VaultNcnSlasherTicket, vault),
+                            MemcmpEncodedBytes::Bytes(vault.to_bytes().to_vec()),
+                        )),
+                    ]),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        data_slice: None,
+                        commitment: None,
+                        min_context_slot: None,
+                    },
+                    with_context: None,
+                },
+            )
+            .await?;
+        for (ticket_pubkey, account) in accounts {
+            let ticket = VaultNcnSlasherTicket::try_from_slice_unchecked(&account.data)?;
+            info!(
+                "slasher {} for NCN {} at address {}: index {}, max slashable per epoch {}, state {:?}",
+                ticket.slasher,
+                ticket.ncn,
+                ticket_pubkey,
+                ticket.index(),
+                ticket.max_slashable_per_epoch(),
+                ticket.state.state(slot, epoch_length)
+            );
+        }
+        Ok(())
+    }
+
+    async fn remove_slasher(&self, vault: String, ncn: String, slasher: String) -> Result<()> {
+        let keypair = self
+            .cli_config
+            .keypair
+            .as_ref()
+            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let vault = Pubkey::from_str(&vault)?;
+        let ncn = Pubkey::from_str(&ncn)?;
+        let slasher = Pubkey::from_str(&slasher)?;
+
+        let ix = remove_slasher(
+            &self.vault_program_id,
+            &Config::find_program_address(&self.vault_program_id).0,
+            &vault,
+            &ncn,
+            &slasher,
+            &VaultNcnSlasherTicket::find_program_address(
+                &self.vault_program_id,
+                &vault,
+                &ncn,
+                &slasher,
+            )
+            .0,
+            &keypair.pubkey(),
+        );
+
+        let rpc_client = self.get_rpc_client();
+        let recent_blockhash = rpc_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&keypair.pubkey()),
+            &[keypair],
+            recent_blockhash,
+        );
+        info!("Removing slasher transaction: {:?}", tx.get_signature());
+        rpc_client.send_and_confirm_transaction(&tx).await?;
+        info!("Transaction confirmed: {:?}", tx.get_signature());
+
+        Ok(())
+    }
+
+    /// Periodically burns every matured withdrawal ticket that opted into auto-claiming,
+    /// collecting the auto-claim fee into the keeper's associated token account.
+    async fn auto_claim(&self, interval_secs: u64) -> Result<()> {
+        let keypair = self
+            .cli_config
+            .keypair
+            .as_ref()
+            .ok_or_else(|| anyhow!("Keypair not provided"))?;
+        let rpc_client = self.get_rpc_client();
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+
+        loop {
+            let config_account = rpc_client.get_account(&config_address).await?;
+            let config = Config::try_from_slice_unchecked(&config_account.data)?;
+            let config_epoch_length = config.epoch_length();
+            let cooldown_epochs = config.delegation_cooldown_epochs();
+            let slot = rpc_client.get_slot().await?;
+
+            for (ticket_pubkey, ticket) in self.get_withdrawal_tickets(None).await? {
+                if ticket.auto_claim_fee_bps() == 0 {
+                    continue;
+                }
+
+                let vault_account = rpc_client.get_account(&ticket.vault).await?;
+                let vault = Vault::try_from_slice_unchecked(&vault_account.data)?;
+                let epoch_length = vault.epoch_length(config_epoch_length);
+                if !ticket.is_withdrawable(slot, epoch_length, cooldown_epochs)? {
+                    continue;
+                }
+                // the keeper can't sign for a mint burn admin, and stale or paused vaults
+                // reject the burn until someone else updates or unpauses them
+                if vault.mint_burn_admin.ne(&Pubkey::default())
+                    || vault.is_paused()
+                    || vault.is_update_needed(slot, epoch_length)
+                {
+                    debug!(
+                        "Skipping withdrawal ticket {}, vault {} can't be claimed from",
+                        ticket_pubkey, ticket.vault
+                    );
+                    continue;
+                }
+
+                let claimer_token_account =
+                    get_associated_token_address(&keypair.pubkey(), &vault.supported_mint);
+                let instructions = [
+                    create_associated_token_account_idempotent(
+                        &keypair.pubkey(),
+                        &keypair.pubkey(),
+                        &vault.supported_mint,
+                        &spl_token::id(),
+                    ),
+                    create_associated_token_account_idempotent(
+                        &keypair.pubkey(),
+                        &ticket.staker,
+                        &vault.supported_mint,
+                        &spl_token::id(),
+                    ),
+                    burn_withdrawal_ticket(
+                        &self.vault_program_id,
+                        &config_address,
+                        &ticket.vault,
+                        &get_associated_token_address(&ticket.vault, &vault.supported_mint),
+                        &vault.vrt_mint,
+                        &ticket.staker,
+                        &get_associated_token_address(&ticket.staker, &vault.supported_mint),
+                        &ticket_pubkey,
+                        &get_associated_token_address(&ticket_pubkey, &vault.vrt_mint),
+                        &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+                        None,
+                        Some(&claimer_token_account),
+                        // the staker opted in to being claimed at the vault's exchange rate
+                        0,
+                    ),
+                ];
+
+                let recent_blockhash = rpc_client.get_latest_blockhash().await?;
+                let tx = Transaction::new_signed_with_payer(
+                    &instructions,
+                    Some(&keypair.pubkey()),
+                    &[keypair],
+                    recent_blockhash,
+                );
+                info!(
+                    "Auto-claiming withdrawal ticket {} transaction: {:?}",
+                    ticket_pubkey,
+                    tx.get_signature()
+                );
+                // keep going on failure, another keeper may have claimed the ticket first
+                match rpc_client.send_and_confirm_transaction(&tx).await {
+                    Ok(_) => info!("Transaction confirmed: {:?}", tx.get_signature()),
+                    Err(e) => error!(
+                        "Failed to auto-claim withdrawal ticket {}: {}",
+                        ticket_pubkey, e
+                    ),
+                }
+            }
+
+            tokio::time::sleep(Duration::from_secs(interval_secs)).await;
+        }
+    }
+
     fn get_rpc_client(&self) -> RpcClient {
         RpcClient::new_with_commitment(self.cli_config.rpc_url.clone(), self.cli_config.commitment)
     }
@@ -284,3 +797,23 @@ impl VaultCliHandler {
         Ok(())
     }
 }
+
+/// Parses a window like `30d`, `12h` or `1y` into seconds
+fn parse_window(window: &str) -> Result<i64> {
+    let (amount, unit) = window.split_at(window.len().saturating_sub(1));
+    let seconds_per_unit = match unit {
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "y" => 365 * 24 * 60 * 60,
+        _ => return Err(anyhow!("Window {} must end in h, d or y", window)),
+    };
+    let amount: i64 = amount
+        .parse()
+        .map_err(|_| anyhow!("Invalid window {}", window))?;
+    if amount <= 0 {
+        return Err(anyhow!("Window {} must be positive", window));
+    }
+    amount
+        .checked_mul(seconds_per_unit)
+        .ok_or_else(|| anyhow!("Window {} is too long", window))
+}
@@ -0,0 +1,51 @@
+/**
+ * Helpers for the SlotToggle type, kept outside the generated clients so regenerating them
+ * doesn't remove them.
+ */
+
+import { SlotToggleState as RestakingSlotToggleState } from '../restaking_client';
+import { SlotToggleState as VaultSlotToggleState } from '../vault_client';
+
+/** The slots a toggle was added and removed at, as held by either client's SlotToggle */
+export type SlotToggleSlots = { slotAdded: bigint; slotRemoved: bigint };
+
+/**
+ * Gets the index of the state of a toggle at the given slot, mirroring the program's slot math.
+ * Both clients' SlotToggleState enums list the states in the same order.
+ */
+function getSlotToggleStateIndex(
+  toggle: SlotToggleSlots,
+  slot: bigint,
+  epochLength: bigint
+): number {
+  const currentEpoch = slot / epochLength;
+  if (toggle.slotAdded === toggle.slotRemoved) {
+    return RestakingSlotToggleState.Inactive;
+  }
+  if (toggle.slotAdded < toggle.slotRemoved) {
+    return currentEpoch > toggle.slotRemoved / epochLength + 1n
+      ? RestakingSlotToggleState.Inactive
+      : RestakingSlotToggleState.Cooldown;
+  }
+  return currentEpoch > toggle.slotAdded / epochLength + 1n
+    ? RestakingSlotToggleState.Active
+    : RestakingSlotToggleState.WarmUp;
+}
+
+/** Gets the state of a restaking program toggle at the given slot */
+export function getRestakingSlotToggleState(
+  toggle: SlotToggleSlots,
+  slot: bigint,
+  epochLength: bigint
+): RestakingSlotToggleState {
+  return getSlotToggleStateIndex(toggle, slot, epochLength);
+}
+
+/** Gets the state of a vault program toggle at the given slot */
+export function getVaultSlotToggleState(
+  toggle: SlotToggleSlots,
+  slot: bigint,
+  epochLength: bigint
+): VaultSlotToggleState {
+  return getSlotToggleStateIndex(toggle, slot, epochLength);
+}
@@ -34,6 +34,7 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 
 export type Config = {
   discriminator: bigint;
@@ -43,6 +44,7 @@ export type Config = {
   operatorCount: bigint;
   epochLength: bigint;
   bump: number;
+  pendingAdmin: Address;
   reserved1: Array<number>;
 };
 
@@ -54,6 +56,7 @@ export type ConfigArgs = {
   operatorCount: number | bigint;
   epochLength: number | bigint;
   bump: number;
+  pendingAdmin: Address;
   reserved1: Array<number>;
 };
 
@@ -66,7 +69,8 @@ export function getConfigEncoder(): Encoder<ConfigArgs> {
     ['operatorCount', getU64Encoder()],
     ['epochLength', getU64Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved1', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['pendingAdmin', getAddressEncoder()],
+    ['reserved1', getArrayEncoder(getU8Encoder(), { size: 231 })],
   ]);
 }
 
@@ -79,7 +83,8 @@ export function getConfigDecoder(): Decoder<Config> {
     ['operatorCount', getU64Decoder()],
     ['epochLength', getU64Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved1', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['pendingAdmin', getAddressDecoder()],
+    ['reserved1', getArrayDecoder(getU8Decoder(), { size: 231 })],
   ]);
 }
 
@@ -139,3 +144,21 @@ export async function fetchAllMaybeConfig(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeConfig(maybeAccount));
 }
+
+export async function fetchConfigFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<Config>> {
+  const maybeAccount = await fetchMaybeConfigFromSeeds(rpc, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeConfigFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<Config>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findConfigPda({ programAddress });
+  return await fetchMaybeConfig(rpc, address, fetchConfig);
+}
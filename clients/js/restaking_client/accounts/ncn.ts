@@ -34,6 +34,7 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import { findNcnPda, type NcnSeeds } from '../pdas';
 
 export type Ncn = {
   discriminator: bigint;
@@ -49,6 +50,7 @@ export type Ncn = {
   vaultCount: bigint;
   slasherCount: bigint;
   bump: number;
+  ticketExpiryEpochs: bigint;
   reserved: Array<number>;
 };
 
@@ -66,6 +68,7 @@ export type NcnArgs = {
   vaultCount: number | bigint;
   slasherCount: number | bigint;
   bump: number;
+  ticketExpiryEpochs: number | bigint;
   reserved: Array<number>;
 };
 
@@ -84,7 +87,8 @@ export function getNcnEncoder(): Encoder<NcnArgs> {
     ['vaultCount', getU64Encoder()],
     ['slasherCount', getU64Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['ticketExpiryEpochs', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 255 })],
   ]);
 }
 
@@ -103,7 +107,8 @@ export function getNcnDecoder(): Decoder<Ncn> {
     ['vaultCount', getU64Decoder()],
     ['slasherCount', getU64Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['ticketExpiryEpochs', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 255 })],
   ]);
 }
 
@@ -163,3 +168,23 @@ export async function fetchAllMaybeNcn(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeNcn(maybeAccount));
 }
+
+export async function fetchNcnFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: NcnSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<Ncn>> {
+  const maybeAccount = await fetchMaybeNcnFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeNcnFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: NcnSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<Ncn>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findNcnPda(seeds, { programAddress });
+  return await fetchMaybeNcn(rpc, address, fetchConfig);
+}
@@ -34,6 +34,7 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import { findNcnOperatorStatePda, type NcnOperatorStateSeeds } from '../pdas';
 import {
   getSlotToggleDecoder,
   getSlotToggleEncoder,
@@ -49,6 +50,11 @@ export type NcnOperatorState = {
   ncnOptInState: SlotToggle;
   operatorOptInState: SlotToggle;
   bump: number;
+  taskWindowEndSlot: bigint;
+  frozenUntilSlot: bigint;
+  expirySlot: bigint;
+  taskWindowStartSlot: bigint;
+  taskWindowExtensions: number;
   reserved: Array<number>;
 };
 
@@ -60,6 +66,11 @@ export type NcnOperatorStateArgs = {
   ncnOptInState: SlotToggleArgs;
   operatorOptInState: SlotToggleArgs;
   bump: number;
+  taskWindowEndSlot: number | bigint;
+  frozenUntilSlot: number | bigint;
+  expirySlot: number | bigint;
+  taskWindowStartSlot: number | bigint;
+  taskWindowExtensions: number;
   reserved: Array<number>;
 };
 
@@ -72,7 +83,12 @@ export function getNcnOperatorStateEncoder(): Encoder<NcnOperatorStateArgs> {
     ['ncnOptInState', getSlotToggleEncoder()],
     ['operatorOptInState', getSlotToggleEncoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['taskWindowEndSlot', getU64Encoder()],
+    ['frozenUntilSlot', getU64Encoder()],
+    ['expirySlot', getU64Encoder()],
+    ['taskWindowStartSlot', getU64Encoder()],
+    ['taskWindowExtensions', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 230 })],
   ]);
 }
 
@@ -85,7 +101,12 @@ export function getNcnOperatorStateDecoder(): Decoder<NcnOperatorState> {
     ['ncnOptInState', getSlotToggleDecoder()],
     ['operatorOptInState', getSlotToggleDecoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['taskWindowEndSlot', getU64Decoder()],
+    ['frozenUntilSlot', getU64Decoder()],
+    ['expirySlot', getU64Decoder()],
+    ['taskWindowStartSlot', getU64Decoder()],
+    ['taskWindowExtensions', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 230 })],
   ]);
 }
 
@@ -161,3 +182,27 @@ export async function fetchAllMaybeNcnOperatorState(
     decodeNcnOperatorState(maybeAccount)
   );
 }
+
+export async function fetchNcnOperatorStateFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: NcnOperatorStateSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<NcnOperatorState>> {
+  const maybeAccount = await fetchMaybeNcnOperatorStateFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeNcnOperatorStateFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: NcnOperatorStateSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<NcnOperatorState>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findNcnOperatorStatePda(seeds, { programAddress });
+  return await fetchMaybeNcnOperatorState(rpc, address, fetchConfig);
+}
@@ -34,6 +34,10 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import {
+  findNcnVaultSlasherTicketPda,
+  type NcnVaultSlasherTicketSeeds,
+} from '../pdas';
 import {
   getSlotToggleDecoder,
   getSlotToggleEncoder,
@@ -50,6 +54,10 @@ export type NcnVaultSlasherTicket = {
   index: bigint;
   state: SlotToggle;
   bump: number;
+  slashDestination: number;
+  insuranceWallet: Address;
+  pendingMaxSlashablePerEpoch: bigint;
+  pendingMaxSlashableEpoch: bigint;
   reserved: Array<number>;
 };
 
@@ -62,6 +70,10 @@ export type NcnVaultSlasherTicketArgs = {
   index: number | bigint;
   state: SlotToggleArgs;
   bump: number;
+  slashDestination: number;
+  insuranceWallet: Address;
+  pendingMaxSlashablePerEpoch: number | bigint;
+  pendingMaxSlashableEpoch: number | bigint;
   reserved: Array<number>;
 };
 
@@ -75,7 +87,11 @@ export function getNcnVaultSlasherTicketEncoder(): Encoder<NcnVaultSlasherTicket
     ['index', getU64Encoder()],
     ['state', getSlotToggleEncoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['slashDestination', getU8Encoder()],
+    ['insuranceWallet', getAddressEncoder()],
+    ['pendingMaxSlashablePerEpoch', getU64Encoder()],
+    ['pendingMaxSlashableEpoch', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 214 })],
   ]);
 }
 
@@ -89,7 +105,11 @@ export function getNcnVaultSlasherTicketDecoder(): Decoder<NcnVaultSlasherTicket
     ['index', getU64Decoder()],
     ['state', getSlotToggleDecoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['slashDestination', getU8Decoder()],
+    ['insuranceWallet', getAddressDecoder()],
+    ['pendingMaxSlashablePerEpoch', getU64Decoder()],
+    ['pendingMaxSlashableEpoch', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 214 })],
   ]);
 }
 
@@ -171,3 +191,29 @@ export async function fetchAllMaybeNcnVaultSlasherTicket(
     decodeNcnVaultSlasherTicket(maybeAccount)
   );
 }
+
+export async function fetchNcnVaultSlasherTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: NcnVaultSlasherTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<NcnVaultSlasherTicket>> {
+  const maybeAccount = await fetchMaybeNcnVaultSlasherTicketFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeNcnVaultSlasherTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: NcnVaultSlasherTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<NcnVaultSlasherTicket>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findNcnVaultSlasherTicketPda(seeds, {
+    programAddress,
+  });
+  return await fetchMaybeNcnVaultSlasherTicket(rpc, address, fetchConfig);
+}
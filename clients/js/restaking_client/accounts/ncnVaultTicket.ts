@@ -34,6 +34,7 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import { findNcnVaultTicketPda, type NcnVaultTicketSeeds } from '../pdas';
 import {
   getSlotToggleDecoder,
   getSlotToggleEncoder,
@@ -48,6 +49,7 @@ export type NcnVaultTicket = {
   index: bigint;
   state: SlotToggle;
   bump: number;
+  expirySlot: bigint;
   reserved: Array<number>;
 };
 
@@ -58,6 +60,7 @@ export type NcnVaultTicketArgs = {
   index: number | bigint;
   state: SlotToggleArgs;
   bump: number;
+  expirySlot: number | bigint;
   reserved: Array<number>;
 };
 
@@ -69,7 +72,8 @@ export function getNcnVaultTicketEncoder(): Encoder<NcnVaultTicketArgs> {
     ['index', getU64Encoder()],
     ['state', getSlotToggleEncoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['expirySlot', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 255 })],
   ]);
 }
 
@@ -81,7 +85,8 @@ export function getNcnVaultTicketDecoder(): Decoder<NcnVaultTicket> {
     ['index', getU64Decoder()],
     ['state', getSlotToggleDecoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['expirySlot', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 255 })],
   ]);
 }
 
@@ -152,3 +157,27 @@ export async function fetchAllMaybeNcnVaultTicket(
     decodeNcnVaultTicket(maybeAccount)
   );
 }
+
+export async function fetchNcnVaultTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: NcnVaultTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<NcnVaultTicket>> {
+  const maybeAccount = await fetchMaybeNcnVaultTicketFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeNcnVaultTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: NcnVaultTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<NcnVaultTicket>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findNcnVaultTicketPda(seeds, { programAddress });
+  return await fetchMaybeNcnVaultTicket(rpc, address, fetchConfig);
+}
@@ -13,12 +13,18 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -33,7 +39,9 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
 } from '@solana/web3.js';
+import { findOperatorPda, type OperatorSeeds } from '../pdas';
 
 export type Operator = {
   discriminator: bigint;
@@ -48,6 +56,16 @@ export type Operator = {
   ncnCount: bigint;
   vaultCount: bigint;
   bump: number;
+  taskWindowCount: bigint;
+  taskWindowEndSlot: bigint;
+  freezeCount: bigint;
+  frozenUntilSlot: bigint;
+  commissionBps: number;
+  commissionBpsEpochStart: number;
+  commissionEpoch: bigint;
+  name: ReadonlyUint8Array;
+  website: ReadonlyUint8Array;
+  delegationApprovalRequired: number;
   reservedSpace: Array<number>;
 };
 
@@ -64,6 +82,16 @@ export type OperatorArgs = {
   ncnCount: number | bigint;
   vaultCount: number | bigint;
   bump: number;
+  taskWindowCount: number | bigint;
+  taskWindowEndSlot: number | bigint;
+  freezeCount: number | bigint;
+  frozenUntilSlot: number | bigint;
+  commissionBps: number;
+  commissionBpsEpochStart: number;
+  commissionEpoch: number | bigint;
+  name: ReadonlyUint8Array;
+  website: ReadonlyUint8Array;
+  delegationApprovalRequired: number;
   reservedSpace: Array<number>;
 };
 
@@ -81,7 +109,17 @@ export function getOperatorEncoder(): Encoder<OperatorArgs> {
     ['ncnCount', getU64Encoder()],
     ['vaultCount', getU64Encoder()],
     ['bump', getU8Encoder()],
-    ['reservedSpace', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['taskWindowCount', getU64Encoder()],
+    ['taskWindowEndSlot', getU64Encoder()],
+    ['freezeCount', getU64Encoder()],
+    ['frozenUntilSlot', getU64Encoder()],
+    ['commissionBps', getU16Encoder()],
+    ['commissionBpsEpochStart', getU16Encoder()],
+    ['commissionEpoch', getU64Encoder()],
+    ['name', fixEncoderSize(getBytesEncoder(), 32)],
+    ['website', fixEncoderSize(getBytesEncoder(), 64)],
+    ['delegationApprovalRequired', getU8Encoder()],
+    ['reservedSpace', getArrayEncoder(getU8Encoder(), { size: 122 })],
   ]);
 }
 
@@ -99,7 +137,17 @@ export function getOperatorDecoder(): Decoder<Operator> {
     ['ncnCount', getU64Decoder()],
     ['vaultCount', getU64Decoder()],
     ['bump', getU8Decoder()],
-    ['reservedSpace', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['taskWindowCount', getU64Decoder()],
+    ['taskWindowEndSlot', getU64Decoder()],
+    ['freezeCount', getU64Decoder()],
+    ['frozenUntilSlot', getU64Decoder()],
+    ['commissionBps', getU16Decoder()],
+    ['commissionBpsEpochStart', getU16Decoder()],
+    ['commissionEpoch', getU64Decoder()],
+    ['name', fixDecoderSize(getBytesDecoder(), 32)],
+    ['website', fixDecoderSize(getBytesDecoder(), 64)],
+    ['delegationApprovalRequired', getU8Decoder()],
+    ['reservedSpace', getArrayDecoder(getU8Decoder(), { size: 122 })],
   ]);
 }
 
@@ -159,3 +207,23 @@ export async function fetchAllMaybeOperator(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeOperator(maybeAccount));
 }
+
+export async function fetchOperatorFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: OperatorSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<Operator>> {
+  const maybeAccount = await fetchMaybeOperatorFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeOperatorFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: OperatorSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<Operator>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findOperatorPda(seeds, { programAddress });
+  return await fetchMaybeOperator(rpc, address, fetchConfig);
+}
@@ -34,6 +34,10 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import {
+  findOperatorVaultTicketPda,
+  type OperatorVaultTicketSeeds,
+} from '../pdas';
 import {
   getSlotToggleDecoder,
   getSlotToggleEncoder,
@@ -48,6 +52,7 @@ export type OperatorVaultTicket = {
   index: bigint;
   state: SlotToggle;
   bump: number;
+  approvedDelegationAmount: bigint;
   reserved: Array<number>;
 };
 
@@ -58,6 +63,7 @@ export type OperatorVaultTicketArgs = {
   index: number | bigint;
   state: SlotToggleArgs;
   bump: number;
+  approvedDelegationAmount: number | bigint;
   reserved: Array<number>;
 };
 
@@ -69,7 +75,8 @@ export function getOperatorVaultTicketEncoder(): Encoder<OperatorVaultTicketArgs
     ['index', getU64Encoder()],
     ['state', getSlotToggleEncoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['approvedDelegationAmount', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 255 })],
   ]);
 }
 
@@ -81,7 +88,8 @@ export function getOperatorVaultTicketDecoder(): Decoder<OperatorVaultTicket> {
     ['index', getU64Decoder()],
     ['state', getSlotToggleDecoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['approvedDelegationAmount', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 255 })],
   ]);
 }
 
@@ -163,3 +171,27 @@ export async function fetchAllMaybeOperatorVaultTicket(
     decodeOperatorVaultTicket(maybeAccount)
   );
 }
+
+export async function fetchOperatorVaultTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: OperatorVaultTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<OperatorVaultTicket>> {
+  const maybeAccount = await fetchMaybeOperatorVaultTicketFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeOperatorVaultTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: OperatorVaultTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<OperatorVaultTicket>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findOperatorVaultTicketPda(seeds, { programAddress });
+  return await fetchMaybeOperatorVaultTicket(rpc, address, fetchConfig);
+}
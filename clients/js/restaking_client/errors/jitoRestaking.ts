@@ -36,6 +36,26 @@ export const JITO_RESTAKING_ERROR__NCN_WARMUP_OPERATOR_FAILED = 0x3f0; // 1008
 export const JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_WARMUP = 0x3f1; // 1009
 /** NcnVaultTicketFailedWarmup: NcnVaultTicketFailedWarmup */
 export const JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_WARMUP = 0x3f2; // 1010
+/** NcnTaskWindowInvalid: NcnTaskWindowInvalid */
+export const JITO_RESTAKING_ERROR__NCN_TASK_WINDOW_INVALID = 0x3f3; // 1011
+/** NcnTaskWindowNotOpen: NcnTaskWindowNotOpen */
+export const JITO_RESTAKING_ERROR__NCN_TASK_WINDOW_NOT_OPEN = 0x3f4; // 1012
+/** NcnOperatorNotFrozen: NcnOperatorNotFrozen */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_NOT_FROZEN = 0x3f5; // 1013
+/** NcnTicketExpiryInvalid: NcnTicketExpiryInvalid */
+export const JITO_RESTAKING_ERROR__NCN_TICKET_EXPIRY_INVALID = 0x3f6; // 1014
+/** NcnInsuranceWalletMissing: NcnInsuranceWalletMissing */
+export const JITO_RESTAKING_ERROR__NCN_INSURANCE_WALLET_MISSING = 0x3f7; // 1015
+/** NcnOperatorStateUnslashable: NcnOperatorStateUnslashable */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_STATE_UNSLASHABLE = 0x3f8; // 1016
+/** NcnVaultTicketUnslashable: NcnVaultTicketUnslashable */
+export const JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_UNSLASHABLE = 0x3f9; // 1017
+/** NcnVaultSlasherTicketUnslashable: NcnVaultSlasherTicketUnslashable */
+export const JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_UNSLASHABLE = 0x3fa; // 1018
+/** NcnOperatorBatchInvalid: NcnOperatorBatchInvalid */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_BATCH_INVALID = 0x3fb; // 1019
+/** NcnTaskWindowExtensionsExceeded: NcnTaskWindowExtensionsExceeded */
+export const JITO_RESTAKING_ERROR__NCN_TASK_WINDOW_EXTENSIONS_EXCEEDED = 0x3fc; // 1020
 /** OperatorNcnAdminInvalid: OperatorNcnAdminInvalid */
 export const JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID = 0x7d0; // 2000
 /** OperatorVaultAdminInvalid: OperatorVaultAdminInvalid */
@@ -60,22 +80,60 @@ export const JITO_RESTAKING_ERROR__OPERATOR_OVERFLOW = 0x7d9; // 2009
 export const JITO_RESTAKING_ERROR__VAULT_OVERFLOW = 0x7da; // 2010
 /** SlasherOverflow: SlasherOverflow */
 export const JITO_RESTAKING_ERROR__SLASHER_OVERFLOW = 0x7db; // 2011
+/** OperatorCommissionInvalid: OperatorCommissionInvalid */
+export const JITO_RESTAKING_ERROR__OPERATOR_COMMISSION_INVALID = 0x7dc; // 2012
+/** OperatorCommissionChangeTooLarge: OperatorCommissionChangeTooLarge */
+export const JITO_RESTAKING_ERROR__OPERATOR_COMMISSION_CHANGE_TOO_LARGE = 0x7dd; // 2013
+/** OperatorMetadataInvalid: OperatorMetadataInvalid */
+export const JITO_RESTAKING_ERROR__OPERATOR_METADATA_INVALID = 0x7de; // 2014
+/** RestakingAccountDataEmpty: RestakingAccountDataEmpty */
+export const JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_DATA_EMPTY = 0xbb8; // 3000
+/** RestakingAccountNotWritable: RestakingAccountNotWritable */
+export const JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_NOT_WRITABLE = 0xbb9; // 3001
+/** RestakingAccountDiscriminatorInvalid: RestakingAccountDiscriminatorInvalid */
+export const JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_DISCRIMINATOR_INVALID = 0xbba; // 3002
+/** RestakingAccountNotCanonicalPda: RestakingAccountNotCanonicalPda */
+export const JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_NOT_CANONICAL_PDA = 0xbbb; // 3003
+/** RestakingAccountVersionInvalid: RestakingAccountVersionInvalid */
+export const JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_VERSION_INVALID = 0xbbc; // 3004
+/** ConfigAdminInvalid: ConfigAdminInvalid */
+export const JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID = 0xfa0; // 4000
+/** ConfigPendingAdminInvalid: ConfigPendingAdminInvalid */
+export const JITO_RESTAKING_ERROR__CONFIG_PENDING_ADMIN_INVALID = 0xfa1; // 4001
+/** ConfigEpochLengthInvalid: ConfigEpochLengthInvalid */
+export const JITO_RESTAKING_ERROR__CONFIG_EPOCH_LENGTH_INVALID = 0xfa2; // 4002
 
 export type JitoRestakingError =
+  | typeof JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__CONFIG_EPOCH_LENGTH_INVALID
+  | typeof JITO_RESTAKING_ERROR__CONFIG_PENDING_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_COOLDOWN_OPERATOR_FAILED
+  | typeof JITO_RESTAKING_ERROR__NCN_INSURANCE_WALLET_MISSING
   | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_BATCH_INVALID
+  | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_NOT_FROZEN
+  | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_STATE_UNSLASHABLE
   | typeof JITO_RESTAKING_ERROR__NCN_OVERFLOW
   | typeof JITO_RESTAKING_ERROR__NCN_SLASHER_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__NCN_TASK_WINDOW_EXTENSIONS_EXCEEDED
+  | typeof JITO_RESTAKING_ERROR__NCN_TASK_WINDOW_INVALID
+  | typeof JITO_RESTAKING_ERROR__NCN_TASK_WINDOW_NOT_OPEN
+  | typeof JITO_RESTAKING_ERROR__NCN_TICKET_EXPIRY_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_COOLDOWN
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_WARMUP
+  | typeof JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_UNSLASHABLE
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_COOLDOWN
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_WARMUP
+  | typeof JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_UNSLASHABLE
   | typeof JITO_RESTAKING_ERROR__NCN_WARMUP_OPERATOR_FAILED
   | typeof JITO_RESTAKING_ERROR__NCN_WITHDRAW_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__OPERATOR_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__OPERATOR_COMMISSION_CHANGE_TOO_LARGE
+  | typeof JITO_RESTAKING_ERROR__OPERATOR_COMMISSION_INVALID
   | typeof JITO_RESTAKING_ERROR__OPERATOR_COOLDOWN_NCN_FAILED
+  | typeof JITO_RESTAKING_ERROR__OPERATOR_METADATA_INVALID
   | typeof JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__OPERATOR_OVERFLOW
   | typeof JITO_RESTAKING_ERROR__OPERATOR_VAULT_ADMIN_INVALID
@@ -83,26 +141,47 @@ export type JitoRestakingError =
   | typeof JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_WARMUP
   | typeof JITO_RESTAKING_ERROR__OPERATOR_WARMUP_NCN_FAILED
   | typeof JITO_RESTAKING_ERROR__OPERATOR_WITHDRAW_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_DATA_EMPTY
+  | typeof JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_DISCRIMINATOR_INVALID
+  | typeof JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_NOT_CANONICAL_PDA
+  | typeof JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_NOT_WRITABLE
+  | typeof JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_VERSION_INVALID
   | typeof JITO_RESTAKING_ERROR__SLASHER_OVERFLOW
   | typeof JITO_RESTAKING_ERROR__VAULT_OVERFLOW;
 
 let jitoRestakingErrorMessages: Record<JitoRestakingError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   jitoRestakingErrorMessages = {
+    [JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID]: `ConfigAdminInvalid`,
+    [JITO_RESTAKING_ERROR__CONFIG_EPOCH_LENGTH_INVALID]: `ConfigEpochLengthInvalid`,
+    [JITO_RESTAKING_ERROR__CONFIG_PENDING_ADMIN_INVALID]: `ConfigPendingAdminInvalid`,
     [JITO_RESTAKING_ERROR__NCN_ADMIN_INVALID]: `NcnAdminInvalid`,
     [JITO_RESTAKING_ERROR__NCN_COOLDOWN_OPERATOR_FAILED]: `NcnCooldownOperatorFailed`,
+    [JITO_RESTAKING_ERROR__NCN_INSURANCE_WALLET_MISSING]: `NcnInsuranceWalletMissing`,
     [JITO_RESTAKING_ERROR__NCN_OPERATOR_ADMIN_INVALID]: `NcnOperatorAdminInvalid`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_BATCH_INVALID]: `NcnOperatorBatchInvalid`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_NOT_FROZEN]: `NcnOperatorNotFrozen`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_STATE_UNSLASHABLE]: `NcnOperatorStateUnslashable`,
     [JITO_RESTAKING_ERROR__NCN_OVERFLOW]: `NcnOverflow`,
     [JITO_RESTAKING_ERROR__NCN_SLASHER_ADMIN_INVALID]: `NcnSlasherAdminInvalid`,
+    [JITO_RESTAKING_ERROR__NCN_TASK_WINDOW_EXTENSIONS_EXCEEDED]: `NcnTaskWindowExtensionsExceeded`,
+    [JITO_RESTAKING_ERROR__NCN_TASK_WINDOW_INVALID]: `NcnTaskWindowInvalid`,
+    [JITO_RESTAKING_ERROR__NCN_TASK_WINDOW_NOT_OPEN]: `NcnTaskWindowNotOpen`,
+    [JITO_RESTAKING_ERROR__NCN_TICKET_EXPIRY_INVALID]: `NcnTicketExpiryInvalid`,
     [JITO_RESTAKING_ERROR__NCN_VAULT_ADMIN_INVALID]: `NcnVaultAdminInvalid`,
     [JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_COOLDOWN]: `NcnVaultSlasherTicketFailedCooldown`,
     [JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_WARMUP]: `NcnVaultSlasherTicketFailedWarmup`,
+    [JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_UNSLASHABLE]: `NcnVaultSlasherTicketUnslashable`,
     [JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_COOLDOWN]: `NcnVaultTicketFailedCooldown`,
     [JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_WARMUP]: `NcnVaultTicketFailedWarmup`,
+    [JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_UNSLASHABLE]: `NcnVaultTicketUnslashable`,
     [JITO_RESTAKING_ERROR__NCN_WARMUP_OPERATOR_FAILED]: `NcnWarmupOperatorFailed`,
     [JITO_RESTAKING_ERROR__NCN_WITHDRAW_ADMIN_INVALID]: `NcnWithdrawAdminInvalid`,
     [JITO_RESTAKING_ERROR__OPERATOR_ADMIN_INVALID]: `OperatorAdminInvalid`,
+    [JITO_RESTAKING_ERROR__OPERATOR_COMMISSION_CHANGE_TOO_LARGE]: `OperatorCommissionChangeTooLarge`,
+    [JITO_RESTAKING_ERROR__OPERATOR_COMMISSION_INVALID]: `OperatorCommissionInvalid`,
     [JITO_RESTAKING_ERROR__OPERATOR_COOLDOWN_NCN_FAILED]: `OperatorCooldownNcnFailed`,
+    [JITO_RESTAKING_ERROR__OPERATOR_METADATA_INVALID]: `OperatorMetadataInvalid`,
     [JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID]: `OperatorNcnAdminInvalid`,
     [JITO_RESTAKING_ERROR__OPERATOR_OVERFLOW]: `OperatorOverflow`,
     [JITO_RESTAKING_ERROR__OPERATOR_VAULT_ADMIN_INVALID]: `OperatorVaultAdminInvalid`,
@@ -110,6 +189,11 @@ if (process.env.NODE_ENV !== 'production') {
     [JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_WARMUP]: `OperatorVaultTicketFailedWarmup`,
     [JITO_RESTAKING_ERROR__OPERATOR_WARMUP_NCN_FAILED]: `OperatorWarmupNcnFailed`,
     [JITO_RESTAKING_ERROR__OPERATOR_WITHDRAW_ADMIN_INVALID]: `OperatorWithdrawAdminInvalid`,
+    [JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_DATA_EMPTY]: `RestakingAccountDataEmpty`,
+    [JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_DISCRIMINATOR_INVALID]: `RestakingAccountDiscriminatorInvalid`,
+    [JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_NOT_CANONICAL_PDA]: `RestakingAccountNotCanonicalPda`,
+    [JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_NOT_WRITABLE]: `RestakingAccountNotWritable`,
+    [JITO_RESTAKING_ERROR__RESTAKING_ACCOUNT_VERSION_INVALID]: `RestakingAccountVersionInvalid`,
     [JITO_RESTAKING_ERROR__SLASHER_OVERFLOW]: `SlasherOverflow`,
     [JITO_RESTAKING_ERROR__VAULT_OVERFLOW]: `VaultOverflow`,
   };
@@ -0,0 +1,310 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const BATCH_ADD_OPERATORS_DISCRIMINATOR = 41;
+
+export function getBatchAddOperatorsDiscriminatorBytes() {
+  return getU8Encoder().encode(BATCH_ADD_OPERATORS_DISCRIMINATOR);
+}
+
+export type BatchAddOperatorsInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? WritableAccount<TAccountNcn> : TAccountNcn,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type BatchAddOperatorsInstructionData = { discriminator: number };
+
+export type BatchAddOperatorsInstructionDataArgs = {};
+
+export function getBatchAddOperatorsInstructionDataEncoder(): Encoder<BatchAddOperatorsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: BATCH_ADD_OPERATORS_DISCRIMINATOR })
+  );
+}
+
+export function getBatchAddOperatorsInstructionDataDecoder(): Decoder<BatchAddOperatorsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getBatchAddOperatorsInstructionDataCodec(): Codec<
+  BatchAddOperatorsInstructionDataArgs,
+  BatchAddOperatorsInstructionData
+> {
+  return combineCodec(
+    getBatchAddOperatorsInstructionDataEncoder(),
+    getBatchAddOperatorsInstructionDataDecoder()
+  );
+}
+
+export type BatchAddOperatorsAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getBatchAddOperatorsInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: BatchAddOperatorsAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  BatchAddOperatorsInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getBatchAddOperatorsInstructionDataEncoder().encode({}),
+  } as BatchAddOperatorsInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type BatchAddOperatorsInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getBatchAddOperatorsInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: BatchAddOperatorsInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): BatchAddOperatorsInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountAdmin,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getBatchAddOperatorsInstructionDataEncoder().encode({}),
+  } as BatchAddOperatorsInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedBatchAddOperatorsInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    admin: TAccountMetas[2];
+    payer: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
+  };
+  data: BatchAddOperatorsInstructionData;
+};
+
+export function parseBatchAddOperatorsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedBatchAddOperatorsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      admin: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getBatchAddOperatorsInstructionDataDecoder().decode(instruction.data),
+  };
+}
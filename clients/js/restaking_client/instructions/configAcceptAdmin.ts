@@ -0,0 +1,225 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CONFIG_ACCEPT_ADMIN_DISCRIMINATOR = 37;
+
+export function getConfigAcceptAdminDiscriminatorBytes() {
+  return getU8Encoder().encode(CONFIG_ACCEPT_ADMIN_DISCRIMINATOR);
+}
+
+export type ConfigAcceptAdminInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNewAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNewAdmin extends string
+        ? ReadonlySignerAccount<TAccountNewAdmin> &
+            IAccountSignerMeta<TAccountNewAdmin>
+        : TAccountNewAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ConfigAcceptAdminInstructionData = { discriminator: number };
+
+export type ConfigAcceptAdminInstructionDataArgs = {};
+
+export function getConfigAcceptAdminInstructionDataEncoder(): Encoder<ConfigAcceptAdminInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: CONFIG_ACCEPT_ADMIN_DISCRIMINATOR })
+  );
+}
+
+export function getConfigAcceptAdminInstructionDataDecoder(): Decoder<ConfigAcceptAdminInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getConfigAcceptAdminInstructionDataCodec(): Codec<
+  ConfigAcceptAdminInstructionDataArgs,
+  ConfigAcceptAdminInstructionData
+> {
+  return combineCodec(
+    getConfigAcceptAdminInstructionDataEncoder(),
+    getConfigAcceptAdminInstructionDataDecoder()
+  );
+}
+
+export type ConfigAcceptAdminAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNewAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  newAdmin: TransactionSigner<TAccountNewAdmin>;
+};
+
+export async function getConfigAcceptAdminInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNewAdmin extends string,
+>(
+  input: ConfigAcceptAdminAsyncInput<TAccountConfig, TAccountNewAdmin>
+): Promise<
+  ConfigAcceptAdminInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNewAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    newAdmin: { value: input.newAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.newAdmin),
+    ],
+    programAddress,
+    data: getConfigAcceptAdminInstructionDataEncoder().encode({}),
+  } as ConfigAcceptAdminInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNewAdmin
+  >;
+
+  return instruction;
+}
+
+export type ConfigAcceptAdminInput<
+  TAccountConfig extends string = string,
+  TAccountNewAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  newAdmin: TransactionSigner<TAccountNewAdmin>;
+};
+
+export function getConfigAcceptAdminInstruction<
+  TAccountConfig extends string,
+  TAccountNewAdmin extends string,
+>(
+  input: ConfigAcceptAdminInput<TAccountConfig, TAccountNewAdmin>
+): ConfigAcceptAdminInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountNewAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    newAdmin: { value: input.newAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.newAdmin),
+    ],
+    programAddress,
+    data: getConfigAcceptAdminInstructionDataEncoder().encode({}),
+  } as ConfigAcceptAdminInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNewAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedConfigAcceptAdminInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    newAdmin: TAccountMetas[1];
+  };
+  data: ConfigAcceptAdminInstructionData;
+};
+
+export function parseConfigAcceptAdminInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedConfigAcceptAdminInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      newAdmin: getNextAccount(),
+    },
+    data: getConfigAcceptAdminInstructionDataDecoder().decode(instruction.data),
+  };
+}
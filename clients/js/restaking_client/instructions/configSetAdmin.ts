@@ -0,0 +1,250 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CONFIG_SET_ADMIN_DISCRIMINATOR = 36;
+
+export function getConfigSetAdminDiscriminatorBytes() {
+  return getU8Encoder().encode(CONFIG_SET_ADMIN_DISCRIMINATOR);
+}
+
+export type ConfigSetAdminInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountNewAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountNewAdmin extends string
+        ? ReadonlyAccount<TAccountNewAdmin>
+        : TAccountNewAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ConfigSetAdminInstructionData = { discriminator: number };
+
+export type ConfigSetAdminInstructionDataArgs = {};
+
+export function getConfigSetAdminInstructionDataEncoder(): Encoder<ConfigSetAdminInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: CONFIG_SET_ADMIN_DISCRIMINATOR })
+  );
+}
+
+export function getConfigSetAdminInstructionDataDecoder(): Decoder<ConfigSetAdminInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getConfigSetAdminInstructionDataCodec(): Codec<
+  ConfigSetAdminInstructionDataArgs,
+  ConfigSetAdminInstructionData
+> {
+  return combineCodec(
+    getConfigSetAdminInstructionDataEncoder(),
+    getConfigSetAdminInstructionDataDecoder()
+  );
+}
+
+export type ConfigSetAdminAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountNewAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  newAdmin: Address<TAccountNewAdmin>;
+};
+
+export async function getConfigSetAdminInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+  TAccountNewAdmin extends string,
+>(
+  input: ConfigSetAdminAsyncInput<
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewAdmin
+  >
+): Promise<
+  ConfigSetAdminInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    newAdmin: { value: input.newAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.newAdmin),
+    ],
+    programAddress,
+    data: getConfigSetAdminInstructionDataEncoder().encode({}),
+  } as ConfigSetAdminInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewAdmin
+  >;
+
+  return instruction;
+}
+
+export type ConfigSetAdminInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountNewAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  newAdmin: Address<TAccountNewAdmin>;
+};
+
+export function getConfigSetAdminInstruction<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+  TAccountNewAdmin extends string,
+>(
+  input: ConfigSetAdminInput<TAccountConfig, TAccountAdmin, TAccountNewAdmin>
+): ConfigSetAdminInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountAdmin,
+  TAccountNewAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    newAdmin: { value: input.newAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.newAdmin),
+    ],
+    programAddress,
+    data: getConfigSetAdminInstructionDataEncoder().encode({}),
+  } as ConfigSetAdminInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedConfigSetAdminInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    admin: TAccountMetas[1];
+    newAdmin: TAccountMetas[2];
+  };
+  data: ConfigSetAdminInstructionData;
+};
+
+export function parseConfigSetAdminInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedConfigSetAdminInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+      newAdmin: getNextAccount(),
+    },
+    data: getConfigSetAdminInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,249 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CONFIG_SET_EPOCH_LENGTH_DISCRIMINATOR = 38;
+
+export function getConfigSetEpochLengthDiscriminatorBytes() {
+  return getU8Encoder().encode(CONFIG_SET_EPOCH_LENGTH_DISCRIMINATOR);
+}
+
+export type ConfigSetEpochLengthInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ConfigSetEpochLengthInstructionData = {
+  discriminator: number;
+  epochLength: bigint;
+};
+
+export type ConfigSetEpochLengthInstructionDataArgs = {
+  epochLength: number | bigint;
+};
+
+export function getConfigSetEpochLengthInstructionDataEncoder(): Encoder<ConfigSetEpochLengthInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epochLength', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: CONFIG_SET_EPOCH_LENGTH_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getConfigSetEpochLengthInstructionDataDecoder(): Decoder<ConfigSetEpochLengthInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epochLength', getU64Decoder()],
+  ]);
+}
+
+export function getConfigSetEpochLengthInstructionDataCodec(): Codec<
+  ConfigSetEpochLengthInstructionDataArgs,
+  ConfigSetEpochLengthInstructionData
+> {
+  return combineCodec(
+    getConfigSetEpochLengthInstructionDataEncoder(),
+    getConfigSetEpochLengthInstructionDataDecoder()
+  );
+}
+
+export type ConfigSetEpochLengthAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  epochLength: ConfigSetEpochLengthInstructionDataArgs['epochLength'];
+};
+
+export async function getConfigSetEpochLengthInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: ConfigSetEpochLengthAsyncInput<TAccountConfig, TAccountAdmin>
+): Promise<
+  ConfigSetEpochLengthInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getConfigSetEpochLengthInstructionDataEncoder().encode(
+      args as ConfigSetEpochLengthInstructionDataArgs
+    ),
+  } as ConfigSetEpochLengthInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ConfigSetEpochLengthInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  epochLength: ConfigSetEpochLengthInstructionDataArgs['epochLength'];
+};
+
+export function getConfigSetEpochLengthInstruction<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: ConfigSetEpochLengthInput<TAccountConfig, TAccountAdmin>
+): ConfigSetEpochLengthInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getConfigSetEpochLengthInstructionDataEncoder().encode(
+      args as ConfigSetEpochLengthInstructionDataArgs
+    ),
+  } as ConfigSetEpochLengthInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedConfigSetEpochLengthInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    admin: TAccountMetas[1];
+  };
+  data: ConfigSetEpochLengthInstructionData;
+};
+
+export function parseConfigSetEpochLengthInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedConfigSetEpochLengthInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getConfigSetEpochLengthInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,253 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CONFIG_SET_PARAMETERS_DISCRIMINATOR = 39;
+
+export function getConfigSetParametersDiscriminatorBytes() {
+  return getU8Encoder().encode(CONFIG_SET_PARAMETERS_DISCRIMINATOR);
+}
+
+export type ConfigSetParametersInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ConfigSetParametersInstructionData = {
+  discriminator: number;
+  vaultProgram: Option<Address>;
+};
+
+export type ConfigSetParametersInstructionDataArgs = {
+  vaultProgram: OptionOrNullable<Address>;
+};
+
+export function getConfigSetParametersInstructionDataEncoder(): Encoder<ConfigSetParametersInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['vaultProgram', getOptionEncoder(getAddressEncoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: CONFIG_SET_PARAMETERS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getConfigSetParametersInstructionDataDecoder(): Decoder<ConfigSetParametersInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['vaultProgram', getOptionDecoder(getAddressDecoder())],
+  ]);
+}
+
+export function getConfigSetParametersInstructionDataCodec(): Codec<
+  ConfigSetParametersInstructionDataArgs,
+  ConfigSetParametersInstructionData
+> {
+  return combineCodec(
+    getConfigSetParametersInstructionDataEncoder(),
+    getConfigSetParametersInstructionDataDecoder()
+  );
+}
+
+export type ConfigSetParametersAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  vaultProgram: ConfigSetParametersInstructionDataArgs['vaultProgram'];
+};
+
+export async function getConfigSetParametersInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: ConfigSetParametersAsyncInput<TAccountConfig, TAccountAdmin>
+): Promise<
+  ConfigSetParametersInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getConfigSetParametersInstructionDataEncoder().encode(
+      args as ConfigSetParametersInstructionDataArgs
+    ),
+  } as ConfigSetParametersInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ConfigSetParametersInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  vaultProgram: ConfigSetParametersInstructionDataArgs['vaultProgram'];
+};
+
+export function getConfigSetParametersInstruction<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: ConfigSetParametersInput<TAccountConfig, TAccountAdmin>
+): ConfigSetParametersInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getConfigSetParametersInstructionDataEncoder().encode(
+      args as ConfigSetParametersInstructionDataArgs
+    ),
+  } as ConfigSetParametersInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedConfigSetParametersInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    admin: TAccountMetas[1];
+  };
+  data: ConfigSetParametersInstructionData;
+};
+
+export function parseConfigSetParametersInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedConfigSetParametersInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getConfigSetParametersInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -27,8 +27,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findNcnVaultSlasherTicketPda } from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const COOLDOWN_NCN_VAULT_SLASHER_TICKET_DISCRIMINATOR = 14;
 
@@ -100,6 +105,106 @@ export function getCooldownNcnVaultSlasherTicketInstructionDataCodec(): Codec<
   );
 }
 
+export type CooldownNcnVaultSlasherTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVault extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vault: Address<TAccountVault>;
+  slasher: Address<TAccountSlasher>;
+  ncnVaultSlasherTicket?: Address<TAccountNcnVaultSlasherTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getCooldownNcnVaultSlasherTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVault extends string,
+  TAccountSlasher extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: CooldownNcnVaultSlasherTicketAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin
+  >
+): Promise<
+  CooldownNcnVaultSlasherTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnVaultSlasherTicket.value) {
+    accounts.ncnVaultSlasherTicket.value = await findNcnVaultSlasherTicketPda({
+      ncn: expectAddress(accounts.ncn.value),
+      vault: expectAddress(accounts.vault.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getCooldownNcnVaultSlasherTicketInstructionDataEncoder().encode({}),
+  } as CooldownNcnVaultSlasherTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
 export type CooldownNcnVaultSlasherTicketInput<
   TAccountConfig extends string = string,
   TAccountNcn extends string = string,
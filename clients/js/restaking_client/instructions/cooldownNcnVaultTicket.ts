@@ -27,8 +27,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findNcnVaultTicketPda } from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const COOLDOWN_NCN_VAULT_TICKET_DISCRIMINATOR = 8;
 
@@ -94,6 +99,94 @@ export function getCooldownNcnVaultTicketInstructionDataCodec(): Codec<
   );
 }
 
+export type CooldownNcnVaultTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcnVaultTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vault: Address<TAccountVault>;
+  ncnVaultTicket?: Address<TAccountNcnVaultTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getCooldownNcnVaultTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVault extends string,
+  TAccountNcnVaultTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: CooldownNcnVaultTicketAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountAdmin
+  >
+): Promise<
+  CooldownNcnVaultTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnVaultTicket.value) {
+    accounts.ncnVaultTicket.value = await findNcnVaultTicketPda({
+      ncn: expectAddress(accounts.ncn.value),
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getCooldownNcnVaultTicketInstructionDataEncoder().encode({}),
+  } as CooldownNcnVaultTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
 export type CooldownNcnVaultTicketInput<
   TAccountConfig extends string = string,
   TAccountNcn extends string = string,
@@ -6,6 +6,11 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './batchAddOperators';
+export * from './configAcceptAdmin';
+export * from './configSetAdmin';
+export * from './configSetEpochLength';
+export * from './configSetParameters';
 export * from './cooldownNcnVaultSlasherTicket';
 export * from './cooldownNcnVaultTicket';
 export * from './cooldownOperatorVaultTicket';
@@ -16,16 +21,30 @@ export * from './initializeNcnVaultSlasherTicket';
 export * from './initializeNcnVaultTicket';
 export * from './initializeOperator';
 export * from './initializeOperatorVaultTicket';
+export * from './migrateAccount';
+export * from './ncnCloseTaskWindow';
 export * from './ncnCooldownOperator';
+export * from './ncnFreezeOperator';
+export * from './ncnOpenTaskWindow';
+export * from './ncnRequestSlash';
 export * from './ncnSetAdmin';
+export * from './ncnSetMaxSlashablePerEpoch';
 export * from './ncnSetSecondaryAdmin';
+export * from './ncnSetSlashDestination';
+export * from './ncnSetTicketExpiry';
+export * from './ncnUnfreezeOperator';
 export * from './ncnWarmupOperator';
 export * from './ncnWithdrawalAsset';
+export * from './operatorApproveDelegation';
 export * from './operatorCooldownNcn';
 export * from './operatorSetAdmin';
+export * from './operatorSetCommission';
+export * from './operatorSetDelegationApprovalRequired';
+export * from './operatorSetMetadata';
 export * from './operatorSetSecondaryAdmin';
 export * from './operatorWarmupNcn';
 export * from './operatorWithdrawalAsset';
+export * from './renewTicket';
 export * from './warmupNcnVaultSlasherTicket';
 export * from './warmupNcnVaultTicket';
 export * from './warmupOperatorVaultTicket';
@@ -27,6 +27,7 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
@@ -91,6 +92,84 @@ export function getInitializeConfigInstructionDataCodec(): Codec<
   );
 }
 
+export type InitializeConfigAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountVaultProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  vaultProgram: Address<TAccountVaultProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getInitializeConfigInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+  TAccountVaultProgram extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeConfigAsyncInput<
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountVaultProgram,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeConfigInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountVaultProgram,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: true },
+    vaultProgram: { value: input.vaultProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.vaultProgram),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeConfigInstructionDataEncoder().encode({}),
+  } as InitializeConfigInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountVaultProgram,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeConfigInput<
   TAccountConfig extends string = string,
   TAccountAdmin extends string = string,
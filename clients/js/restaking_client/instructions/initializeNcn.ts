@@ -28,8 +28,13 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findNcnPda } from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_NCN_DISCRIMINATOR = 1;
 
@@ -94,6 +99,97 @@ export function getInitializeNcnInstructionDataCodec(): Codec<
   );
 }
 
+export type InitializeNcnAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountBase extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn?: Address<TAccountNcn>;
+  admin: TransactionSigner<TAccountAdmin>;
+  base: TransactionSigner<TAccountBase>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getInitializeNcnInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountAdmin extends string,
+  TAccountBase extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeNcnAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin,
+    TAccountBase,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeNcnInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin,
+    TAccountBase,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: true },
+    base: { value: input.base ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncn.value) {
+    accounts.ncn.value = await findNcnPda({
+      base: expectAddress(accounts.base.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.base),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeNcnInstructionDataEncoder().encode({}),
+  } as InitializeNcnInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin,
+    TAccountBase,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeNcnInput<
   TAccountConfig extends string = string,
   TAccountNcn extends string = string,
@@ -28,8 +28,13 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findNcnOperatorStatePda } from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_NCN_OPERATOR_STATE_DISCRIMINATOR = 6;
 
@@ -108,6 +113,117 @@ export function getInitializeNcnOperatorStateInstructionDataCodec(): Codec<
   );
 }
 
+export type InitializeNcnOperatorStateAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  ncnOperatorState?: Address<TAccountNcnOperatorState>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getInitializeNcnOperatorStateInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeNcnOperatorStateAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeNcnOperatorStateInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: true },
+    operator: { value: input.operator ?? null, isWritable: true },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnOperatorState.value) {
+    accounts.ncnOperatorState.value = await findNcnOperatorStatePda({
+      ncn: expectAddress(accounts.ncn.value),
+      operator: expectAddress(accounts.operator.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeNcnOperatorStateInstructionDataEncoder().encode({}),
+  } as InitializeNcnOperatorStateInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeNcnOperatorStateInput<
   TAccountConfig extends string = string,
   TAccountNcn extends string = string,
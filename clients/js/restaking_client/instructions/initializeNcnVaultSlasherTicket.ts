@@ -30,8 +30,17 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import {
+  findConfigPda,
+  findNcnVaultSlasherTicketPda,
+  findNcnVaultTicketPda,
+} from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_NCN_VAULT_SLASHER_TICKET_DISCRIMINATOR = 3;
 
@@ -129,6 +138,146 @@ export function getInitializeNcnVaultSlasherTicketInstructionDataCodec(): Codec<
   );
 }
 
+export type InitializeNcnVaultSlasherTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVault extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnVaultTicket extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vault: Address<TAccountVault>;
+  slasher: Address<TAccountSlasher>;
+  ncnVaultTicket?: Address<TAccountNcnVaultTicket>;
+  ncnVaultSlasherTicket?: Address<TAccountNcnVaultSlasherTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  args: InitializeNcnVaultSlasherTicketInstructionDataArgs['args'];
+};
+
+export async function getInitializeNcnVaultSlasherTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVault extends string,
+  TAccountSlasher extends string,
+  TAccountNcnVaultTicket extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeNcnVaultSlasherTicketAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultTicket,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeNcnVaultSlasherTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultTicket,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: false },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnVaultTicket.value) {
+    accounts.ncnVaultTicket.value = await findNcnVaultTicketPda({
+      ncn: expectAddress(accounts.ncn.value),
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+  if (!accounts.ncnVaultSlasherTicket.value) {
+    accounts.ncnVaultSlasherTicket.value = await findNcnVaultSlasherTicketPda({
+      ncn: expectAddress(accounts.ncn.value),
+      vault: expectAddress(accounts.vault.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeNcnVaultSlasherTicketInstructionDataEncoder().encode(
+      args as InitializeNcnVaultSlasherTicketInstructionDataArgs
+    ),
+  } as InitializeNcnVaultSlasherTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultTicket,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeNcnVaultSlasherTicketInput<
   TAccountConfig extends string = string,
   TAccountNcn extends string = string,
@@ -28,8 +28,13 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findNcnVaultTicketPda } from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_NCN_VAULT_TICKET_DISCRIMINATOR = 4;
 
@@ -106,6 +111,114 @@ export function getInitializeNcnVaultTicketInstructionDataCodec(): Codec<
   );
 }
 
+export type InitializeNcnVaultTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcnVaultTicket extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vault: Address<TAccountVault>;
+  ncnVaultTicket?: Address<TAccountNcnVaultTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getInitializeNcnVaultTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVault extends string,
+  TAccountNcnVaultTicket extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeNcnVaultTicketAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeNcnVaultTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnVaultTicket.value) {
+    accounts.ncnVaultTicket.value = await findNcnVaultTicketPda({
+      ncn: expectAddress(accounts.ncn.value),
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeNcnVaultTicketInstructionDataEncoder().encode({}),
+  } as InitializeNcnVaultTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeNcnVaultTicketInput<
   TAccountConfig extends string = string,
   TAccountNcn extends string = string,
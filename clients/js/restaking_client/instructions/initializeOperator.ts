@@ -28,8 +28,13 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findOperatorPda } from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_OPERATOR_DISCRIMINATOR = 2;
 
@@ -96,6 +101,97 @@ export function getInitializeOperatorInstructionDataCodec(): Codec<
   );
 }
 
+export type InitializeOperatorAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountOperator extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountBase extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  operator?: Address<TAccountOperator>;
+  admin: TransactionSigner<TAccountAdmin>;
+  base: TransactionSigner<TAccountBase>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getInitializeOperatorInstructionAsync<
+  TAccountConfig extends string,
+  TAccountOperator extends string,
+  TAccountAdmin extends string,
+  TAccountBase extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeOperatorAsyncInput<
+    TAccountConfig,
+    TAccountOperator,
+    TAccountAdmin,
+    TAccountBase,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeOperatorInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountAdmin,
+    TAccountBase,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    operator: { value: input.operator ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: true },
+    base: { value: input.base ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.operator.value) {
+    accounts.operator.value = await findOperatorPda({
+      base: expectAddress(accounts.base.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.base),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeOperatorInstructionDataEncoder().encode({}),
+  } as InitializeOperatorInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountAdmin,
+    TAccountBase,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeOperatorInput<
   TAccountConfig extends string = string,
   TAccountOperator extends string = string,
@@ -28,8 +28,13 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findOperatorVaultTicketPda } from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_OPERATOR_VAULT_TICKET_DISCRIMINATOR = 5;
 
@@ -110,6 +115,117 @@ export function getInitializeOperatorVaultTicketInstructionDataCodec(): Codec<
   );
 }
 
+export type InitializeOperatorVaultTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  operator: Address<TAccountOperator>;
+  vault: Address<TAccountVault>;
+  operatorVaultTicket?: Address<TAccountOperatorVaultTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getInitializeOperatorVaultTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountOperator extends string,
+  TAccountVault extends string,
+  TAccountOperatorVaultTicket extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeOperatorVaultTicketAsyncInput<
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeOperatorVaultTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.operatorVaultTicket.value) {
+    accounts.operatorVaultTicket.value = await findOperatorVaultTicketPda({
+      operator: expectAddress(accounts.operator.value),
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operatorVaultTicket),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeOperatorVaultTicketInstructionDataEncoder().encode({}),
+  } as InitializeOperatorVaultTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeOperatorVaultTicketInput<
   TAccountConfig extends string = string,
   TAccountOperator extends string = string,
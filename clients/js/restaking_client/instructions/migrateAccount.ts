@@ -0,0 +1,195 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const MIGRATE_ACCOUNT_DISCRIMINATOR = 31;
+
+export function getMigrateAccountDiscriminatorBytes() {
+  return getU8Encoder().encode(MIGRATE_ACCOUNT_DISCRIMINATOR);
+}
+
+export type MigrateAccountInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountAccount extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountAccount extends string
+        ? WritableAccount<TAccountAccount>
+        : TAccountAccount,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type MigrateAccountInstructionData = { discriminator: number };
+
+export type MigrateAccountInstructionDataArgs = {};
+
+export function getMigrateAccountInstructionDataEncoder(): Encoder<MigrateAccountInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: MIGRATE_ACCOUNT_DISCRIMINATOR })
+  );
+}
+
+export function getMigrateAccountInstructionDataDecoder(): Decoder<MigrateAccountInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getMigrateAccountInstructionDataCodec(): Codec<
+  MigrateAccountInstructionDataArgs,
+  MigrateAccountInstructionData
+> {
+  return combineCodec(
+    getMigrateAccountInstructionDataEncoder(),
+    getMigrateAccountInstructionDataDecoder()
+  );
+}
+
+export type MigrateAccountInput<
+  TAccountAccount extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  account: Address<TAccountAccount>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getMigrateAccountInstruction<
+  TAccountAccount extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: MigrateAccountInput<
+    TAccountAccount,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): MigrateAccountInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountAccount,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    account: { value: input.account ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.account),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getMigrateAccountInstructionDataEncoder().encode({}),
+  } as MigrateAccountInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountAccount,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedMigrateAccountInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    account: TAccountMetas[0];
+    payer: TAccountMetas[1];
+    systemProgram: TAccountMetas[2];
+  };
+  data: MigrateAccountInstructionData;
+};
+
+export function parseMigrateAccountInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMigrateAccountInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      account: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getMigrateAccountInstructionDataDecoder().decode(instruction.data),
+  };
+}
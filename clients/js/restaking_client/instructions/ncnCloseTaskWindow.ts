@@ -0,0 +1,292 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findNcnOperatorStatePda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const NCN_CLOSE_TASK_WINDOW_DISCRIMINATOR = 24;
+
+export function getNcnCloseTaskWindowDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_CLOSE_TASK_WINDOW_DISCRIMINATOR);
+}
+
+export type NcnCloseTaskWindowInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountNcnOperatorState extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? WritableAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountNcnOperatorState extends string
+        ? WritableAccount<TAccountNcnOperatorState>
+        : TAccountNcnOperatorState,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnCloseTaskWindowInstructionData = { discriminator: number };
+
+export type NcnCloseTaskWindowInstructionDataArgs = {};
+
+export function getNcnCloseTaskWindowInstructionDataEncoder(): Encoder<NcnCloseTaskWindowInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: NCN_CLOSE_TASK_WINDOW_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getNcnCloseTaskWindowInstructionDataDecoder(): Decoder<NcnCloseTaskWindowInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getNcnCloseTaskWindowInstructionDataCodec(): Codec<
+  NcnCloseTaskWindowInstructionDataArgs,
+  NcnCloseTaskWindowInstructionData
+> {
+  return combineCodec(
+    getNcnCloseTaskWindowInstructionDataEncoder(),
+    getNcnCloseTaskWindowInstructionDataDecoder()
+  );
+}
+
+export type NcnCloseTaskWindowAsyncInput<
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  ncnOperatorState?: Address<TAccountNcnOperatorState>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getNcnCloseTaskWindowInstructionAsync<
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountAdmin extends string,
+>(
+  input: NcnCloseTaskWindowAsyncInput<
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+): Promise<
+  NcnCloseTaskWindowInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: true },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.ncnOperatorState.value) {
+    accounts.ncnOperatorState.value = await findNcnOperatorStatePda({
+      ncn: expectAddress(accounts.ncn.value),
+      operator: expectAddress(accounts.operator.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnCloseTaskWindowInstructionDataEncoder().encode({}),
+  } as NcnCloseTaskWindowInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type NcnCloseTaskWindowInput<
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  ncnOperatorState: Address<TAccountNcnOperatorState>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export function getNcnCloseTaskWindowInstruction<
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountAdmin extends string,
+>(
+  input: NcnCloseTaskWindowInput<
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+): NcnCloseTaskWindowInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountNcnOperatorState,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: true },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnCloseTaskWindowInstructionDataEncoder().encode({}),
+  } as NcnCloseTaskWindowInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnCloseTaskWindowInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncn: TAccountMetas[0];
+    operator: TAccountMetas[1];
+    ncnOperatorState: TAccountMetas[2];
+    admin: TAccountMetas[3];
+  };
+  data: NcnCloseTaskWindowInstructionData;
+};
+
+export function parseNcnCloseTaskWindowInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnCloseTaskWindowInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      ncnOperatorState: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getNcnCloseTaskWindowInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -27,8 +27,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findNcnOperatorStatePda } from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const NCN_COOLDOWN_OPERATOR_DISCRIMINATOR = 10;
 
@@ -94,6 +99,97 @@ export function getNcnCooldownOperatorInstructionDataCodec(): Codec<
   );
 }
 
+export type NcnCooldownOperatorAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  ncnOperatorState?: Address<TAccountNcnOperatorState>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getNcnCooldownOperatorInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountAdmin extends string,
+>(
+  input: NcnCooldownOperatorAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+): Promise<
+  NcnCooldownOperatorInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnOperatorState.value) {
+    accounts.ncnOperatorState.value = await findNcnOperatorStatePda({
+      ncn: expectAddress(accounts.ncn.value),
+      operator: expectAddress(accounts.operator.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnCooldownOperatorInstructionDataEncoder().encode({}),
+  } as NcnCooldownOperatorInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
 export type NcnCooldownOperatorInput<
   TAccountConfig extends string = string,
   TAccountNcn extends string = string,
@@ -0,0 +1,312 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findNcnOperatorStatePda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const NCN_FREEZE_OPERATOR_DISCRIMINATOR = 25;
+
+export function getNcnFreezeOperatorDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_FREEZE_OPERATOR_DISCRIMINATOR);
+}
+
+export type NcnFreezeOperatorInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountNcnOperatorState extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? WritableAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountNcnOperatorState extends string
+        ? WritableAccount<TAccountNcnOperatorState>
+        : TAccountNcnOperatorState,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnFreezeOperatorInstructionData = { discriminator: number };
+
+export type NcnFreezeOperatorInstructionDataArgs = {};
+
+export function getNcnFreezeOperatorInstructionDataEncoder(): Encoder<NcnFreezeOperatorInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: NCN_FREEZE_OPERATOR_DISCRIMINATOR })
+  );
+}
+
+export function getNcnFreezeOperatorInstructionDataDecoder(): Decoder<NcnFreezeOperatorInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getNcnFreezeOperatorInstructionDataCodec(): Codec<
+  NcnFreezeOperatorInstructionDataArgs,
+  NcnFreezeOperatorInstructionData
+> {
+  return combineCodec(
+    getNcnFreezeOperatorInstructionDataEncoder(),
+    getNcnFreezeOperatorInstructionDataDecoder()
+  );
+}
+
+export type NcnFreezeOperatorAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  ncnOperatorState?: Address<TAccountNcnOperatorState>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getNcnFreezeOperatorInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountAdmin extends string,
+>(
+  input: NcnFreezeOperatorAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+): Promise<
+  NcnFreezeOperatorInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: true },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnOperatorState.value) {
+    accounts.ncnOperatorState.value = await findNcnOperatorStatePda({
+      ncn: expectAddress(accounts.ncn.value),
+      operator: expectAddress(accounts.operator.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnFreezeOperatorInstructionDataEncoder().encode({}),
+  } as NcnFreezeOperatorInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type NcnFreezeOperatorInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  ncnOperatorState: Address<TAccountNcnOperatorState>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export function getNcnFreezeOperatorInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountAdmin extends string,
+>(
+  input: NcnFreezeOperatorInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+): NcnFreezeOperatorInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountNcnOperatorState,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: true },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnFreezeOperatorInstructionDataEncoder().encode({}),
+  } as NcnFreezeOperatorInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnFreezeOperatorInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    ncnOperatorState: TAccountMetas[3];
+    admin: TAccountMetas[4];
+  };
+  data: NcnFreezeOperatorInstructionData;
+};
+
+export function parseNcnFreezeOperatorInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnFreezeOperatorInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      ncnOperatorState: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getNcnFreezeOperatorInstructionDataDecoder().decode(instruction.data),
+  };
+}
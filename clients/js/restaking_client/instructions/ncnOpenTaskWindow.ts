@@ -0,0 +1,335 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findNcnOperatorStatePda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const NCN_OPEN_TASK_WINDOW_DISCRIMINATOR = 23;
+
+export function getNcnOpenTaskWindowDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_OPEN_TASK_WINDOW_DISCRIMINATOR);
+}
+
+export type NcnOpenTaskWindowInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountNcnOperatorState extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? WritableAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountNcnOperatorState extends string
+        ? WritableAccount<TAccountNcnOperatorState>
+        : TAccountNcnOperatorState,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnOpenTaskWindowInstructionData = {
+  discriminator: number;
+  endSlot: bigint;
+};
+
+export type NcnOpenTaskWindowInstructionDataArgs = { endSlot: number | bigint };
+
+export function getNcnOpenTaskWindowInstructionDataEncoder(): Encoder<NcnOpenTaskWindowInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['endSlot', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: NCN_OPEN_TASK_WINDOW_DISCRIMINATOR })
+  );
+}
+
+export function getNcnOpenTaskWindowInstructionDataDecoder(): Decoder<NcnOpenTaskWindowInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['endSlot', getU64Decoder()],
+  ]);
+}
+
+export function getNcnOpenTaskWindowInstructionDataCodec(): Codec<
+  NcnOpenTaskWindowInstructionDataArgs,
+  NcnOpenTaskWindowInstructionData
+> {
+  return combineCodec(
+    getNcnOpenTaskWindowInstructionDataEncoder(),
+    getNcnOpenTaskWindowInstructionDataDecoder()
+  );
+}
+
+export type NcnOpenTaskWindowAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  ncnOperatorState?: Address<TAccountNcnOperatorState>;
+  admin: TransactionSigner<TAccountAdmin>;
+  endSlot: NcnOpenTaskWindowInstructionDataArgs['endSlot'];
+};
+
+export async function getNcnOpenTaskWindowInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountAdmin extends string,
+>(
+  input: NcnOpenTaskWindowAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+): Promise<
+  NcnOpenTaskWindowInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: true },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnOperatorState.value) {
+    accounts.ncnOperatorState.value = await findNcnOperatorStatePda({
+      ncn: expectAddress(accounts.ncn.value),
+      operator: expectAddress(accounts.operator.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnOpenTaskWindowInstructionDataEncoder().encode(
+      args as NcnOpenTaskWindowInstructionDataArgs
+    ),
+  } as NcnOpenTaskWindowInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type NcnOpenTaskWindowInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  ncnOperatorState: Address<TAccountNcnOperatorState>;
+  admin: TransactionSigner<TAccountAdmin>;
+  endSlot: NcnOpenTaskWindowInstructionDataArgs['endSlot'];
+};
+
+export function getNcnOpenTaskWindowInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountAdmin extends string,
+>(
+  input: NcnOpenTaskWindowInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >
+): NcnOpenTaskWindowInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountNcnOperatorState,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: true },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnOpenTaskWindowInstructionDataEncoder().encode(
+      args as NcnOpenTaskWindowInstructionDataArgs
+    ),
+  } as NcnOpenTaskWindowInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnOpenTaskWindowInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    ncnOperatorState: TAccountMetas[3];
+    admin: TAccountMetas[4];
+  };
+  data: NcnOpenTaskWindowInstructionData;
+};
+
+export function parseNcnOpenTaskWindowInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnOpenTaskWindowInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      ncnOperatorState: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getNcnOpenTaskWindowInstructionDataDecoder().decode(instruction.data),
+  };
+}
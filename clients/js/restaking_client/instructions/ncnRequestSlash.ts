@@ -0,0 +1,704 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import {
+  findConfigPda,
+  findNcnOperatorStatePda,
+  findNcnVaultSlasherTicketPda,
+  findNcnVaultTicketPda,
+  findOperatorVaultTicketPda,
+} from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const NCN_REQUEST_SLASH_DISCRIMINATOR = 40;
+
+export function getNcnRequestSlashDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_REQUEST_SLASH_DISCRIMINATOR);
+}
+
+export type NcnRequestSlashInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVaultProgram extends string | IAccountMeta<string> = string,
+  TAccountVaultConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountSlasher extends string | IAccountMeta<string> = string,
+  TAccountNcnOperatorState extends string | IAccountMeta<string> = string,
+  TAccountNcnVaultTicket extends string | IAccountMeta<string> = string,
+  TAccountOperatorVaultTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultNcnTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultOperatorDelegation extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountNcnVaultSlasherTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultNcnSlasherTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultNcnSlasherOperatorTicket extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountSlashDestination extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVaultProgram extends string
+        ? ReadonlyAccount<TAccountVaultProgram>
+        : TAccountVaultProgram,
+      TAccountVaultConfig extends string
+        ? ReadonlyAccount<TAccountVaultConfig>
+        : TAccountVaultConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountSlasher extends string
+        ? ReadonlySignerAccount<TAccountSlasher> &
+            IAccountSignerMeta<TAccountSlasher>
+        : TAccountSlasher,
+      TAccountNcnOperatorState extends string
+        ? ReadonlyAccount<TAccountNcnOperatorState>
+        : TAccountNcnOperatorState,
+      TAccountNcnVaultTicket extends string
+        ? ReadonlyAccount<TAccountNcnVaultTicket>
+        : TAccountNcnVaultTicket,
+      TAccountOperatorVaultTicket extends string
+        ? ReadonlyAccount<TAccountOperatorVaultTicket>
+        : TAccountOperatorVaultTicket,
+      TAccountVaultNcnTicket extends string
+        ? ReadonlyAccount<TAccountVaultNcnTicket>
+        : TAccountVaultNcnTicket,
+      TAccountVaultOperatorDelegation extends string
+        ? WritableAccount<TAccountVaultOperatorDelegation>
+        : TAccountVaultOperatorDelegation,
+      TAccountNcnVaultSlasherTicket extends string
+        ? ReadonlyAccount<TAccountNcnVaultSlasherTicket>
+        : TAccountNcnVaultSlasherTicket,
+      TAccountVaultNcnSlasherTicket extends string
+        ? ReadonlyAccount<TAccountVaultNcnSlasherTicket>
+        : TAccountVaultNcnSlasherTicket,
+      TAccountVaultNcnSlasherOperatorTicket extends string
+        ? WritableAccount<TAccountVaultNcnSlasherOperatorTicket>
+        : TAccountVaultNcnSlasherOperatorTicket,
+      TAccountVaultTokenAccount extends string
+        ? WritableAccount<TAccountVaultTokenAccount>
+        : TAccountVaultTokenAccount,
+      TAccountSlashDestination extends string
+        ? WritableAccount<TAccountSlashDestination>
+        : TAccountSlashDestination,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnRequestSlashInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type NcnRequestSlashInstructionDataArgs = { amount: number | bigint };
+
+export function getNcnRequestSlashInstructionDataEncoder(): Encoder<NcnRequestSlashInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: NCN_REQUEST_SLASH_DISCRIMINATOR })
+  );
+}
+
+export function getNcnRequestSlashInstructionDataDecoder(): Decoder<NcnRequestSlashInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getNcnRequestSlashInstructionDataCodec(): Codec<
+  NcnRequestSlashInstructionDataArgs,
+  NcnRequestSlashInstructionData
+> {
+  return combineCodec(
+    getNcnRequestSlashInstructionDataEncoder(),
+    getNcnRequestSlashInstructionDataDecoder()
+  );
+}
+
+export type NcnRequestSlashAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVaultProgram extends string = string,
+  TAccountVaultConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountNcnVaultTicket extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+  TAccountVaultNcnTicket extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherOperatorTicket extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountSlashDestination extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vaultProgram: Address<TAccountVaultProgram>;
+  vaultConfig: Address<TAccountVaultConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  slasher: TransactionSigner<TAccountSlasher>;
+  ncnOperatorState?: Address<TAccountNcnOperatorState>;
+  ncnVaultTicket?: Address<TAccountNcnVaultTicket>;
+  operatorVaultTicket?: Address<TAccountOperatorVaultTicket>;
+  vaultNcnTicket: Address<TAccountVaultNcnTicket>;
+  vaultOperatorDelegation: Address<TAccountVaultOperatorDelegation>;
+  ncnVaultSlasherTicket?: Address<TAccountNcnVaultSlasherTicket>;
+  vaultNcnSlasherTicket: Address<TAccountVaultNcnSlasherTicket>;
+  vaultNcnSlasherOperatorTicket: Address<TAccountVaultNcnSlasherOperatorTicket>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  slashDestination: Address<TAccountSlashDestination>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  amount: NcnRequestSlashInstructionDataArgs['amount'];
+};
+
+export async function getNcnRequestSlashInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVaultProgram extends string,
+  TAccountVaultConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSlasher extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountNcnVaultTicket extends string,
+  TAccountOperatorVaultTicket extends string,
+  TAccountVaultNcnTicket extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountVaultNcnSlasherTicket extends string,
+  TAccountVaultNcnSlasherOperatorTicket extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountSlashDestination extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: NcnRequestSlashAsyncInput<
+    TAccountConfig,
+    TAccountVaultProgram,
+    TAccountVaultConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >
+): Promise<
+  NcnRequestSlashInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVaultProgram,
+    TAccountVaultConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vaultProgram: { value: input.vaultProgram ?? null, isWritable: false },
+    vaultConfig: { value: input.vaultConfig ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: false,
+    },
+    ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnTicket: { value: input.vaultNcnTicket ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherTicket: {
+      value: input.vaultNcnSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherOperatorTicket: {
+      value: input.vaultNcnSlasherOperatorTicket ?? null,
+      isWritable: true,
+    },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    slashDestination: {
+      value: input.slashDestination ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnOperatorState.value) {
+    accounts.ncnOperatorState.value = await findNcnOperatorStatePda({
+      ncn: expectAddress(accounts.ncn.value),
+      operator: expectAddress(accounts.operator.value),
+    });
+  }
+  if (!accounts.ncnVaultTicket.value) {
+    accounts.ncnVaultTicket.value = await findNcnVaultTicketPda({
+      ncn: expectAddress(accounts.ncn.value),
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+  if (!accounts.operatorVaultTicket.value) {
+    accounts.operatorVaultTicket.value = await findOperatorVaultTicketPda({
+      operator: expectAddress(accounts.operator.value),
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+  if (!accounts.ncnVaultSlasherTicket.value) {
+    accounts.ncnVaultSlasherTicket.value = await findNcnVaultSlasherTicketPda({
+      ncn: expectAddress(accounts.ncn.value),
+      vault: expectAddress(accounts.vault.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vaultProgram),
+      getAccountMeta(accounts.vaultConfig),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.operatorVaultTicket),
+      getAccountMeta(accounts.vaultNcnTicket),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherOperatorTicket),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.slashDestination),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getNcnRequestSlashInstructionDataEncoder().encode(
+      args as NcnRequestSlashInstructionDataArgs
+    ),
+  } as NcnRequestSlashInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVaultProgram,
+    TAccountVaultConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type NcnRequestSlashInput<
+  TAccountConfig extends string = string,
+  TAccountVaultProgram extends string = string,
+  TAccountVaultConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountNcnVaultTicket extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+  TAccountVaultNcnTicket extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherOperatorTicket extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountSlashDestination extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vaultProgram: Address<TAccountVaultProgram>;
+  vaultConfig: Address<TAccountVaultConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  slasher: TransactionSigner<TAccountSlasher>;
+  ncnOperatorState: Address<TAccountNcnOperatorState>;
+  ncnVaultTicket: Address<TAccountNcnVaultTicket>;
+  operatorVaultTicket: Address<TAccountOperatorVaultTicket>;
+  vaultNcnTicket: Address<TAccountVaultNcnTicket>;
+  vaultOperatorDelegation: Address<TAccountVaultOperatorDelegation>;
+  ncnVaultSlasherTicket: Address<TAccountNcnVaultSlasherTicket>;
+  vaultNcnSlasherTicket: Address<TAccountVaultNcnSlasherTicket>;
+  vaultNcnSlasherOperatorTicket: Address<TAccountVaultNcnSlasherOperatorTicket>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  slashDestination: Address<TAccountSlashDestination>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  amount: NcnRequestSlashInstructionDataArgs['amount'];
+};
+
+export function getNcnRequestSlashInstruction<
+  TAccountConfig extends string,
+  TAccountVaultProgram extends string,
+  TAccountVaultConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSlasher extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountNcnVaultTicket extends string,
+  TAccountOperatorVaultTicket extends string,
+  TAccountVaultNcnTicket extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountVaultNcnSlasherTicket extends string,
+  TAccountVaultNcnSlasherOperatorTicket extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountSlashDestination extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: NcnRequestSlashInput<
+    TAccountConfig,
+    TAccountVaultProgram,
+    TAccountVaultConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >
+): NcnRequestSlashInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVaultProgram,
+  TAccountVaultConfig,
+  TAccountVault,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountSlasher,
+  TAccountNcnOperatorState,
+  TAccountNcnVaultTicket,
+  TAccountOperatorVaultTicket,
+  TAccountVaultNcnTicket,
+  TAccountVaultOperatorDelegation,
+  TAccountNcnVaultSlasherTicket,
+  TAccountVaultNcnSlasherTicket,
+  TAccountVaultNcnSlasherOperatorTicket,
+  TAccountVaultTokenAccount,
+  TAccountSlashDestination,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vaultProgram: { value: input.vaultProgram ?? null, isWritable: false },
+    vaultConfig: { value: input.vaultConfig ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: false,
+    },
+    ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnTicket: { value: input.vaultNcnTicket ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherTicket: {
+      value: input.vaultNcnSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherOperatorTicket: {
+      value: input.vaultNcnSlasherOperatorTicket ?? null,
+      isWritable: true,
+    },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    slashDestination: {
+      value: input.slashDestination ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vaultProgram),
+      getAccountMeta(accounts.vaultConfig),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.operatorVaultTicket),
+      getAccountMeta(accounts.vaultNcnTicket),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherOperatorTicket),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.slashDestination),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getNcnRequestSlashInstructionDataEncoder().encode(
+      args as NcnRequestSlashInstructionDataArgs
+    ),
+  } as NcnRequestSlashInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVaultProgram,
+    TAccountVaultConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnRequestSlashInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vaultProgram: TAccountMetas[1];
+    vaultConfig: TAccountMetas[2];
+    vault: TAccountMetas[3];
+    ncn: TAccountMetas[4];
+    operator: TAccountMetas[5];
+    slasher: TAccountMetas[6];
+    ncnOperatorState: TAccountMetas[7];
+    ncnVaultTicket: TAccountMetas[8];
+    operatorVaultTicket: TAccountMetas[9];
+    vaultNcnTicket: TAccountMetas[10];
+    vaultOperatorDelegation: TAccountMetas[11];
+    ncnVaultSlasherTicket: TAccountMetas[12];
+    vaultNcnSlasherTicket: TAccountMetas[13];
+    vaultNcnSlasherOperatorTicket: TAccountMetas[14];
+    vaultTokenAccount: TAccountMetas[15];
+    slashDestination: TAccountMetas[16];
+    tokenProgram: TAccountMetas[17];
+  };
+  data: NcnRequestSlashInstructionData;
+};
+
+export function parseNcnRequestSlashInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnRequestSlashInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 18) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vaultProgram: getNextAccount(),
+      vaultConfig: getNextAccount(),
+      vault: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      slasher: getNextAccount(),
+      ncnOperatorState: getNextAccount(),
+      ncnVaultTicket: getNextAccount(),
+      operatorVaultTicket: getNextAccount(),
+      vaultNcnTicket: getNextAccount(),
+      vaultOperatorDelegation: getNextAccount(),
+      ncnVaultSlasherTicket: getNextAccount(),
+      vaultNcnSlasherTicket: getNextAccount(),
+      vaultNcnSlasherOperatorTicket: getNextAccount(),
+      vaultTokenAccount: getNextAccount(),
+      slashDestination: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getNcnRequestSlashInstructionDataDecoder().decode(instruction.data),
+  };
+}
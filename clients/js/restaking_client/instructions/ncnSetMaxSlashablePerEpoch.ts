@@ -0,0 +1,365 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findNcnVaultSlasherTicketPda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const NCN_SET_MAX_SLASHABLE_PER_EPOCH_DISCRIMINATOR = 33;
+
+export function getNcnSetMaxSlashablePerEpochDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_SET_MAX_SLASHABLE_PER_EPOCH_DISCRIMINATOR);
+}
+
+export type NcnSetMaxSlashablePerEpochInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountSlasher extends string | IAccountMeta<string> = string,
+  TAccountNcnVaultSlasherTicket extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountSlasher extends string
+        ? ReadonlyAccount<TAccountSlasher>
+        : TAccountSlasher,
+      TAccountNcnVaultSlasherTicket extends string
+        ? WritableAccount<TAccountNcnVaultSlasherTicket>
+        : TAccountNcnVaultSlasherTicket,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnSetMaxSlashablePerEpochInstructionData = {
+  discriminator: number;
+  maxSlashablePerEpoch: bigint;
+};
+
+export type NcnSetMaxSlashablePerEpochInstructionDataArgs = {
+  maxSlashablePerEpoch: number | bigint;
+};
+
+export function getNcnSetMaxSlashablePerEpochInstructionDataEncoder(): Encoder<NcnSetMaxSlashablePerEpochInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['maxSlashablePerEpoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: NCN_SET_MAX_SLASHABLE_PER_EPOCH_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getNcnSetMaxSlashablePerEpochInstructionDataDecoder(): Decoder<NcnSetMaxSlashablePerEpochInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['maxSlashablePerEpoch', getU64Decoder()],
+  ]);
+}
+
+export function getNcnSetMaxSlashablePerEpochInstructionDataCodec(): Codec<
+  NcnSetMaxSlashablePerEpochInstructionDataArgs,
+  NcnSetMaxSlashablePerEpochInstructionData
+> {
+  return combineCodec(
+    getNcnSetMaxSlashablePerEpochInstructionDataEncoder(),
+    getNcnSetMaxSlashablePerEpochInstructionDataDecoder()
+  );
+}
+
+export type NcnSetMaxSlashablePerEpochAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVault extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vault: Address<TAccountVault>;
+  slasher: Address<TAccountSlasher>;
+  ncnVaultSlasherTicket?: Address<TAccountNcnVaultSlasherTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+  maxSlashablePerEpoch: NcnSetMaxSlashablePerEpochInstructionDataArgs['maxSlashablePerEpoch'];
+};
+
+export async function getNcnSetMaxSlashablePerEpochInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVault extends string,
+  TAccountSlasher extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: NcnSetMaxSlashablePerEpochAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin
+  >
+): Promise<
+  NcnSetMaxSlashablePerEpochInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnVaultSlasherTicket.value) {
+    accounts.ncnVaultSlasherTicket.value = await findNcnVaultSlasherTicketPda({
+      ncn: expectAddress(accounts.ncn.value),
+      vault: expectAddress(accounts.vault.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnSetMaxSlashablePerEpochInstructionDataEncoder().encode(
+      args as NcnSetMaxSlashablePerEpochInstructionDataArgs
+    ),
+  } as NcnSetMaxSlashablePerEpochInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type NcnSetMaxSlashablePerEpochInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVault extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vault: Address<TAccountVault>;
+  slasher: Address<TAccountSlasher>;
+  ncnVaultSlasherTicket: Address<TAccountNcnVaultSlasherTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+  maxSlashablePerEpoch: NcnSetMaxSlashablePerEpochInstructionDataArgs['maxSlashablePerEpoch'];
+};
+
+export function getNcnSetMaxSlashablePerEpochInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVault extends string,
+  TAccountSlasher extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: NcnSetMaxSlashablePerEpochInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin
+  >
+): NcnSetMaxSlashablePerEpochInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountVault,
+  TAccountSlasher,
+  TAccountNcnVaultSlasherTicket,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnSetMaxSlashablePerEpochInstructionDataEncoder().encode(
+      args as NcnSetMaxSlashablePerEpochInstructionDataArgs
+    ),
+  } as NcnSetMaxSlashablePerEpochInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnSetMaxSlashablePerEpochInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    vault: TAccountMetas[2];
+    slasher: TAccountMetas[3];
+    ncnVaultSlasherTicket: TAccountMetas[4];
+    admin: TAccountMetas[5];
+  };
+  data: NcnSetMaxSlashablePerEpochInstructionData;
+};
+
+export function parseNcnSetMaxSlashablePerEpochInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnSetMaxSlashablePerEpochInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      vault: getNextAccount(),
+      slasher: getNextAccount(),
+      ncnVaultSlasherTicket: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getNcnSetMaxSlashablePerEpochInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,403 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findNcnVaultSlasherTicketPda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+import {
+  getSlashDestinationDecoder,
+  getSlashDestinationEncoder,
+  type SlashDestination,
+  type SlashDestinationArgs,
+} from '../types';
+
+export const NCN_SET_SLASH_DESTINATION_DISCRIMINATOR = 30;
+
+export function getNcnSetSlashDestinationDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_SET_SLASH_DESTINATION_DISCRIMINATOR);
+}
+
+export type NcnSetSlashDestinationInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountSlasher extends string | IAccountMeta<string> = string,
+  TAccountNcnVaultSlasherTicket extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountInsuranceWallet extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountSlasher extends string
+        ? ReadonlyAccount<TAccountSlasher>
+        : TAccountSlasher,
+      TAccountNcnVaultSlasherTicket extends string
+        ? WritableAccount<TAccountNcnVaultSlasherTicket>
+        : TAccountNcnVaultSlasherTicket,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountInsuranceWallet extends string
+        ? ReadonlyAccount<TAccountInsuranceWallet>
+        : TAccountInsuranceWallet,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnSetSlashDestinationInstructionData = {
+  discriminator: number;
+  slashDestination: SlashDestination;
+};
+
+export type NcnSetSlashDestinationInstructionDataArgs = {
+  slashDestination: SlashDestinationArgs;
+};
+
+export function getNcnSetSlashDestinationInstructionDataEncoder(): Encoder<NcnSetSlashDestinationInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['slashDestination', getSlashDestinationEncoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: NCN_SET_SLASH_DESTINATION_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getNcnSetSlashDestinationInstructionDataDecoder(): Decoder<NcnSetSlashDestinationInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['slashDestination', getSlashDestinationDecoder()],
+  ]);
+}
+
+export function getNcnSetSlashDestinationInstructionDataCodec(): Codec<
+  NcnSetSlashDestinationInstructionDataArgs,
+  NcnSetSlashDestinationInstructionData
+> {
+  return combineCodec(
+    getNcnSetSlashDestinationInstructionDataEncoder(),
+    getNcnSetSlashDestinationInstructionDataDecoder()
+  );
+}
+
+export type NcnSetSlashDestinationAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVault extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountInsuranceWallet extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vault: Address<TAccountVault>;
+  slasher: Address<TAccountSlasher>;
+  ncnVaultSlasherTicket?: Address<TAccountNcnVaultSlasherTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+  insuranceWallet?: Address<TAccountInsuranceWallet>;
+  slashDestination: NcnSetSlashDestinationInstructionDataArgs['slashDestination'];
+};
+
+export async function getNcnSetSlashDestinationInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVault extends string,
+  TAccountSlasher extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountAdmin extends string,
+  TAccountInsuranceWallet extends string,
+>(
+  input: NcnSetSlashDestinationAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountInsuranceWallet
+  >
+): Promise<
+  NcnSetSlashDestinationInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountInsuranceWallet
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    insuranceWallet: {
+      value: input.insuranceWallet ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.ncnVaultSlasherTicket.value) {
+    accounts.ncnVaultSlasherTicket.value = await findNcnVaultSlasherTicketPda({
+      ncn: expectAddress(accounts.ncn.value),
+      vault: expectAddress(accounts.vault.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.insuranceWallet),
+    ],
+    programAddress,
+    data: getNcnSetSlashDestinationInstructionDataEncoder().encode(
+      args as NcnSetSlashDestinationInstructionDataArgs
+    ),
+  } as NcnSetSlashDestinationInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountInsuranceWallet
+  >;
+
+  return instruction;
+}
+
+export type NcnSetSlashDestinationInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVault extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountInsuranceWallet extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  vault: Address<TAccountVault>;
+  slasher: Address<TAccountSlasher>;
+  ncnVaultSlasherTicket: Address<TAccountNcnVaultSlasherTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+  insuranceWallet?: Address<TAccountInsuranceWallet>;
+  slashDestination: NcnSetSlashDestinationInstructionDataArgs['slashDestination'];
+};
+
+export function getNcnSetSlashDestinationInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountVault extends string,
+  TAccountSlasher extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountAdmin extends string,
+  TAccountInsuranceWallet extends string,
+>(
+  input: NcnSetSlashDestinationInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountInsuranceWallet
+  >
+): NcnSetSlashDestinationInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountVault,
+  TAccountSlasher,
+  TAccountNcnVaultSlasherTicket,
+  TAccountAdmin,
+  TAccountInsuranceWallet
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    insuranceWallet: {
+      value: input.insuranceWallet ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.insuranceWallet),
+    ],
+    programAddress,
+    data: getNcnSetSlashDestinationInstructionDataEncoder().encode(
+      args as NcnSetSlashDestinationInstructionDataArgs
+    ),
+  } as NcnSetSlashDestinationInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountVault,
+    TAccountSlasher,
+    TAccountNcnVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountInsuranceWallet
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnSetSlashDestinationInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    vault: TAccountMetas[2];
+    slasher: TAccountMetas[3];
+    ncnVaultSlasherTicket: TAccountMetas[4];
+    admin: TAccountMetas[5];
+    insuranceWallet?: TAccountMetas[6] | undefined;
+  };
+  data: NcnSetSlashDestinationInstructionData;
+};
+
+export function parseNcnSetSlashDestinationInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnSetSlashDestinationInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_RESTAKING_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      vault: getNextAccount(),
+      slasher: getNextAccount(),
+      ncnVaultSlasherTicket: getNextAccount(),
+      admin: getNextAccount(),
+      insuranceWallet: getNextOptionalAccount(),
+    },
+    data: getNcnSetSlashDestinationInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
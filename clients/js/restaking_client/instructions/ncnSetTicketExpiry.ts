@@ -0,0 +1,188 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const NCN_SET_TICKET_EXPIRY_DISCRIMINATOR = 27;
+
+export function getNcnSetTicketExpiryDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_SET_TICKET_EXPIRY_DISCRIMINATOR);
+}
+
+export type NcnSetTicketExpiryInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountNcn extends string ? WritableAccount<TAccountNcn> : TAccountNcn,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnSetTicketExpiryInstructionData = {
+  discriminator: number;
+  ticketExpiryEpochs: bigint;
+};
+
+export type NcnSetTicketExpiryInstructionDataArgs = {
+  ticketExpiryEpochs: number | bigint;
+};
+
+export function getNcnSetTicketExpiryInstructionDataEncoder(): Encoder<NcnSetTicketExpiryInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['ticketExpiryEpochs', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: NCN_SET_TICKET_EXPIRY_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getNcnSetTicketExpiryInstructionDataDecoder(): Decoder<NcnSetTicketExpiryInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['ticketExpiryEpochs', getU64Decoder()],
+  ]);
+}
+
+export function getNcnSetTicketExpiryInstructionDataCodec(): Codec<
+  NcnSetTicketExpiryInstructionDataArgs,
+  NcnSetTicketExpiryInstructionData
+> {
+  return combineCodec(
+    getNcnSetTicketExpiryInstructionDataEncoder(),
+    getNcnSetTicketExpiryInstructionDataDecoder()
+  );
+}
+
+export type NcnSetTicketExpiryInput<
+  TAccountNcn extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  ncn: Address<TAccountNcn>;
+  admin: TransactionSigner<TAccountAdmin>;
+  ticketExpiryEpochs: NcnSetTicketExpiryInstructionDataArgs['ticketExpiryEpochs'];
+};
+
+export function getNcnSetTicketExpiryInstruction<
+  TAccountNcn extends string,
+  TAccountAdmin extends string,
+>(
+  input: NcnSetTicketExpiryInput<TAccountNcn, TAccountAdmin>
+): NcnSetTicketExpiryInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountNcn,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    ncn: { value: input.ncn ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.ncn), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getNcnSetTicketExpiryInstructionDataEncoder().encode(
+      args as NcnSetTicketExpiryInstructionDataArgs
+    ),
+  } as NcnSetTicketExpiryInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountNcn,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnSetTicketExpiryInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    ncn: TAccountMetas[0];
+    admin: TAccountMetas[1];
+  };
+  data: NcnSetTicketExpiryInstructionData;
+};
+
+export function parseNcnSetTicketExpiryInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnSetTicketExpiryInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      ncn: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getNcnSetTicketExpiryInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
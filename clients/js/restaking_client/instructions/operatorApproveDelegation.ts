@@ -0,0 +1,344 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findOperatorVaultTicketPda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const OPERATOR_APPROVE_DELEGATION_DISCRIMINATOR = 35;
+
+export function getOperatorApproveDelegationDiscriminatorBytes() {
+  return getU8Encoder().encode(OPERATOR_APPROVE_DELEGATION_DISCRIMINATOR);
+}
+
+export type OperatorApproveDelegationInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountOperatorVaultTicket extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountOperatorVaultTicket extends string
+        ? WritableAccount<TAccountOperatorVaultTicket>
+        : TAccountOperatorVaultTicket,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type OperatorApproveDelegationInstructionData = {
+  discriminator: number;
+  approvedDelegationAmount: bigint;
+};
+
+export type OperatorApproveDelegationInstructionDataArgs = {
+  approvedDelegationAmount: number | bigint;
+};
+
+export function getOperatorApproveDelegationInstructionDataEncoder(): Encoder<OperatorApproveDelegationInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['approvedDelegationAmount', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: OPERATOR_APPROVE_DELEGATION_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getOperatorApproveDelegationInstructionDataDecoder(): Decoder<OperatorApproveDelegationInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['approvedDelegationAmount', getU64Decoder()],
+  ]);
+}
+
+export function getOperatorApproveDelegationInstructionDataCodec(): Codec<
+  OperatorApproveDelegationInstructionDataArgs,
+  OperatorApproveDelegationInstructionData
+> {
+  return combineCodec(
+    getOperatorApproveDelegationInstructionDataEncoder(),
+    getOperatorApproveDelegationInstructionDataDecoder()
+  );
+}
+
+export type OperatorApproveDelegationAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  operator: Address<TAccountOperator>;
+  vault: Address<TAccountVault>;
+  operatorVaultTicket?: Address<TAccountOperatorVaultTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+  approvedDelegationAmount: OperatorApproveDelegationInstructionDataArgs['approvedDelegationAmount'];
+};
+
+export async function getOperatorApproveDelegationInstructionAsync<
+  TAccountConfig extends string,
+  TAccountOperator extends string,
+  TAccountVault extends string,
+  TAccountOperatorVaultTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: OperatorApproveDelegationAsyncInput<
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin
+  >
+): Promise<
+  OperatorApproveDelegationInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.operatorVaultTicket.value) {
+    accounts.operatorVaultTicket.value = await findOperatorVaultTicketPda({
+      operator: expectAddress(accounts.operator.value),
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operatorVaultTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getOperatorApproveDelegationInstructionDataEncoder().encode(
+      args as OperatorApproveDelegationInstructionDataArgs
+    ),
+  } as OperatorApproveDelegationInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type OperatorApproveDelegationInput<
+  TAccountConfig extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  operator: Address<TAccountOperator>;
+  vault: Address<TAccountVault>;
+  operatorVaultTicket: Address<TAccountOperatorVaultTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+  approvedDelegationAmount: OperatorApproveDelegationInstructionDataArgs['approvedDelegationAmount'];
+};
+
+export function getOperatorApproveDelegationInstruction<
+  TAccountConfig extends string,
+  TAccountOperator extends string,
+  TAccountVault extends string,
+  TAccountOperatorVaultTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: OperatorApproveDelegationInput<
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin
+  >
+): OperatorApproveDelegationInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountOperator,
+  TAccountVault,
+  TAccountOperatorVaultTicket,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operatorVaultTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getOperatorApproveDelegationInstructionDataEncoder().encode(
+      args as OperatorApproveDelegationInstructionDataArgs
+    ),
+  } as OperatorApproveDelegationInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedOperatorApproveDelegationInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    operator: TAccountMetas[1];
+    vault: TAccountMetas[2];
+    operatorVaultTicket: TAccountMetas[3];
+    admin: TAccountMetas[4];
+  };
+  data: OperatorApproveDelegationInstructionData;
+};
+
+export function parseOperatorApproveDelegationInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedOperatorApproveDelegationInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      operator: getNextAccount(),
+      vault: getNextAccount(),
+      operatorVaultTicket: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getOperatorApproveDelegationInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
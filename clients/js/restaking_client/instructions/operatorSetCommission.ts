@@ -0,0 +1,284 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const OPERATOR_SET_COMMISSION_DISCRIMINATOR = 29;
+
+export function getOperatorSetCommissionDiscriminatorBytes() {
+  return getU8Encoder().encode(OPERATOR_SET_COMMISSION_DISCRIMINATOR);
+}
+
+export type OperatorSetCommissionInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountOperator extends string
+        ? WritableAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type OperatorSetCommissionInstructionData = {
+  discriminator: number;
+  commissionBps: number;
+};
+
+export type OperatorSetCommissionInstructionDataArgs = {
+  commissionBps: number;
+};
+
+export function getOperatorSetCommissionInstructionDataEncoder(): Encoder<OperatorSetCommissionInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['commissionBps', getU16Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: OPERATOR_SET_COMMISSION_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getOperatorSetCommissionInstructionDataDecoder(): Decoder<OperatorSetCommissionInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['commissionBps', getU16Decoder()],
+  ]);
+}
+
+export function getOperatorSetCommissionInstructionDataCodec(): Codec<
+  OperatorSetCommissionInstructionDataArgs,
+  OperatorSetCommissionInstructionData
+> {
+  return combineCodec(
+    getOperatorSetCommissionInstructionDataEncoder(),
+    getOperatorSetCommissionInstructionDataDecoder()
+  );
+}
+
+export type OperatorSetCommissionAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountOperator extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  operator: Address<TAccountOperator>;
+  admin: TransactionSigner<TAccountAdmin>;
+  commissionBps: OperatorSetCommissionInstructionDataArgs['commissionBps'];
+};
+
+export async function getOperatorSetCommissionInstructionAsync<
+  TAccountConfig extends string,
+  TAccountOperator extends string,
+  TAccountAdmin extends string,
+>(
+  input: OperatorSetCommissionAsyncInput<
+    TAccountConfig,
+    TAccountOperator,
+    TAccountAdmin
+  >
+): Promise<
+  OperatorSetCommissionInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getOperatorSetCommissionInstructionDataEncoder().encode(
+      args as OperatorSetCommissionInstructionDataArgs
+    ),
+  } as OperatorSetCommissionInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type OperatorSetCommissionInput<
+  TAccountConfig extends string = string,
+  TAccountOperator extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  operator: Address<TAccountOperator>;
+  admin: TransactionSigner<TAccountAdmin>;
+  commissionBps: OperatorSetCommissionInstructionDataArgs['commissionBps'];
+};
+
+export function getOperatorSetCommissionInstruction<
+  TAccountConfig extends string,
+  TAccountOperator extends string,
+  TAccountAdmin extends string,
+>(
+  input: OperatorSetCommissionInput<
+    TAccountConfig,
+    TAccountOperator,
+    TAccountAdmin
+  >
+): OperatorSetCommissionInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountOperator,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getOperatorSetCommissionInstructionDataEncoder().encode(
+      args as OperatorSetCommissionInstructionDataArgs
+    ),
+  } as OperatorSetCommissionInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedOperatorSetCommissionInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    operator: TAccountMetas[1];
+    admin: TAccountMetas[2];
+  };
+  data: OperatorSetCommissionInstructionData;
+};
+
+export function parseOperatorSetCommissionInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedOperatorSetCommissionInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      operator: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getOperatorSetCommissionInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,202 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const OPERATOR_SET_METADATA_DISCRIMINATOR = 32;
+
+export function getOperatorSetMetadataDiscriminatorBytes() {
+  return getU8Encoder().encode(OPERATOR_SET_METADATA_DISCRIMINATOR);
+}
+
+export type OperatorSetMetadataInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountOperator extends string
+        ? WritableAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type OperatorSetMetadataInstructionData = {
+  discriminator: number;
+  name: string;
+  website: string;
+};
+
+export type OperatorSetMetadataInstructionDataArgs = {
+  name: string;
+  website: string;
+};
+
+export function getOperatorSetMetadataInstructionDataEncoder(): Encoder<OperatorSetMetadataInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['website', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: OPERATOR_SET_METADATA_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getOperatorSetMetadataInstructionDataDecoder(): Decoder<OperatorSetMetadataInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['website', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+  ]);
+}
+
+export function getOperatorSetMetadataInstructionDataCodec(): Codec<
+  OperatorSetMetadataInstructionDataArgs,
+  OperatorSetMetadataInstructionData
+> {
+  return combineCodec(
+    getOperatorSetMetadataInstructionDataEncoder(),
+    getOperatorSetMetadataInstructionDataDecoder()
+  );
+}
+
+export type OperatorSetMetadataInput<
+  TAccountOperator extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  operator: Address<TAccountOperator>;
+  admin: TransactionSigner<TAccountAdmin>;
+  name: OperatorSetMetadataInstructionDataArgs['name'];
+  website: OperatorSetMetadataInstructionDataArgs['website'];
+};
+
+export function getOperatorSetMetadataInstruction<
+  TAccountOperator extends string,
+  TAccountAdmin extends string,
+>(
+  input: OperatorSetMetadataInput<TAccountOperator, TAccountAdmin>
+): OperatorSetMetadataInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountOperator,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    operator: { value: input.operator ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getOperatorSetMetadataInstructionDataEncoder().encode(
+      args as OperatorSetMetadataInstructionDataArgs
+    ),
+  } as OperatorSetMetadataInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountOperator,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedOperatorSetMetadataInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    operator: TAccountMetas[0];
+    admin: TAccountMetas[1];
+  };
+  data: OperatorSetMetadataInstructionData;
+};
+
+export function parseOperatorSetMetadataInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedOperatorSetMetadataInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      operator: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getOperatorSetMetadataInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
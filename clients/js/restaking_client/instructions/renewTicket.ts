@@ -0,0 +1,302 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const RENEW_TICKET_DISCRIMINATOR = 28;
+
+export function getRenewTicketDiscriminatorBytes() {
+  return getU8Encoder().encode(RENEW_TICKET_DISCRIMINATOR);
+}
+
+export type RenewTicketInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperatorOrVault extends string | IAccountMeta<string> = string,
+  TAccountTicket extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperatorOrVault extends string
+        ? ReadonlyAccount<TAccountOperatorOrVault>
+        : TAccountOperatorOrVault,
+      TAccountTicket extends string
+        ? WritableAccount<TAccountTicket>
+        : TAccountTicket,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type RenewTicketInstructionData = { discriminator: number };
+
+export type RenewTicketInstructionDataArgs = {};
+
+export function getRenewTicketInstructionDataEncoder(): Encoder<RenewTicketInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: RENEW_TICKET_DISCRIMINATOR })
+  );
+}
+
+export function getRenewTicketInstructionDataDecoder(): Decoder<RenewTicketInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getRenewTicketInstructionDataCodec(): Codec<
+  RenewTicketInstructionDataArgs,
+  RenewTicketInstructionData
+> {
+  return combineCodec(
+    getRenewTicketInstructionDataEncoder(),
+    getRenewTicketInstructionDataDecoder()
+  );
+}
+
+export type RenewTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperatorOrVault extends string = string,
+  TAccountTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operatorOrVault: Address<TAccountOperatorOrVault>;
+  ticket: Address<TAccountTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getRenewTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperatorOrVault extends string,
+  TAccountTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: RenewTicketAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperatorOrVault,
+    TAccountTicket,
+    TAccountAdmin
+  >
+): Promise<
+  RenewTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperatorOrVault,
+    TAccountTicket,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operatorOrVault: {
+      value: input.operatorOrVault ?? null,
+      isWritable: false,
+    },
+    ticket: { value: input.ticket ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operatorOrVault),
+      getAccountMeta(accounts.ticket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getRenewTicketInstructionDataEncoder().encode({}),
+  } as RenewTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperatorOrVault,
+    TAccountTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type RenewTicketInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperatorOrVault extends string = string,
+  TAccountTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operatorOrVault: Address<TAccountOperatorOrVault>;
+  ticket: Address<TAccountTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export function getRenewTicketInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperatorOrVault extends string,
+  TAccountTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: RenewTicketInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperatorOrVault,
+    TAccountTicket,
+    TAccountAdmin
+  >
+): RenewTicketInstruction<
+  typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountOperatorOrVault,
+  TAccountTicket,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operatorOrVault: {
+      value: input.operatorOrVault ?? null,
+      isWritable: false,
+    },
+    ticket: { value: input.ticket ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operatorOrVault),
+      getAccountMeta(accounts.ticket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getRenewTicketInstructionDataEncoder().encode({}),
+  } as RenewTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperatorOrVault,
+    TAccountTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedRenewTicketInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    operatorOrVault: TAccountMetas[2];
+    ticket: TAccountMetas[3];
+    admin: TAccountMetas[4];
+  };
+  data: RenewTicketInstructionData;
+};
+
+export function parseRenewTicketInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedRenewTicketInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      operatorOrVault: getNextAccount(),
+      ticket: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getRenewTicketInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -27,8 +27,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findOperatorVaultTicketPda } from '../pdas';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const WARMUP_OPERATOR_VAULT_TICKET_DISCRIMINATOR = 15;
 
@@ -98,6 +103,97 @@ export function getWarmupOperatorVaultTicketInstructionDataCodec(): Codec<
   );
 }
 
+export type WarmupOperatorVaultTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  operator: Address<TAccountOperator>;
+  vault: Address<TAccountVault>;
+  operatorVaultTicket?: Address<TAccountOperatorVaultTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getWarmupOperatorVaultTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountOperator extends string,
+  TAccountVault extends string,
+  TAccountOperatorVaultTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: WarmupOperatorVaultTicketAsyncInput<
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin
+  >
+): Promise<
+  WarmupOperatorVaultTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.operatorVaultTicket.value) {
+    accounts.operatorVaultTicket.value = await findOperatorVaultTicketPda({
+      operator: expectAddress(accounts.operator.value),
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operatorVaultTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getWarmupOperatorVaultTicketInstructionDataEncoder().encode({}),
+  } as WarmupOperatorVaultTicketInstruction<
+    typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountVault,
+    TAccountOperatorVaultTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
 export type WarmupOperatorVaultTicketInput<
   TAccountConfig extends string = string,
   TAccountOperator extends string = string,
@@ -0,0 +1,15 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+export * from './config';
+export * from './ncn';
+export * from './ncnOperatorState';
+export * from './ncnVaultSlasherTicket';
+export * from './ncnVaultTicket';
+export * from './operator';
+export * from './operatorVaultTicket';
@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  getUtf8Encoder,
+  type Address,
+  type ProgramDerivedAddress,
+} from '@solana/web3.js';
+
+export type NcnVaultSlasherTicketSeeds = {
+  ncn: Address;
+  vault: Address;
+  slasher: Address;
+};
+
+export async function findNcnVaultSlasherTicketPda(
+  seeds: NcnVaultSlasherTicketSeeds,
+  config: { programAddress?: Address | undefined } = {}
+): Promise<ProgramDerivedAddress> {
+  const {
+    programAddress = '78J8YzXGGNynLRpn85MH77PVLBZsWyLCHZAXRvKaB6Ng' as Address<'78J8YzXGGNynLRpn85MH77PVLBZsWyLCHZAXRvKaB6Ng'>,
+  } = config;
+  return await getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      getUtf8Encoder().encode('ncn_slasher_ticket'),
+      getAddressEncoder().encode(seeds.ncn),
+      getAddressEncoder().encode(seeds.vault),
+      getAddressEncoder().encode(seeds.slasher),
+    ],
+  });
+}
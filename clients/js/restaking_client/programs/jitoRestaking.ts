@@ -13,6 +13,11 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/web3.js';
 import {
+  type ParsedBatchAddOperatorsInstruction,
+  type ParsedConfigAcceptAdminInstruction,
+  type ParsedConfigSetAdminInstruction,
+  type ParsedConfigSetEpochLengthInstruction,
+  type ParsedConfigSetParametersInstruction,
   type ParsedCooldownNcnVaultSlasherTicketInstruction,
   type ParsedCooldownNcnVaultTicketInstruction,
   type ParsedCooldownOperatorVaultTicketInstruction,
@@ -23,16 +28,30 @@ import {
   type ParsedInitializeNcnVaultTicketInstruction,
   type ParsedInitializeOperatorInstruction,
   type ParsedInitializeOperatorVaultTicketInstruction,
+  type ParsedMigrateAccountInstruction,
+  type ParsedNcnCloseTaskWindowInstruction,
   type ParsedNcnCooldownOperatorInstruction,
+  type ParsedNcnFreezeOperatorInstruction,
+  type ParsedNcnOpenTaskWindowInstruction,
+  type ParsedNcnRequestSlashInstruction,
   type ParsedNcnSetAdminInstruction,
+  type ParsedNcnSetMaxSlashablePerEpochInstruction,
   type ParsedNcnSetSecondaryAdminInstruction,
+  type ParsedNcnSetSlashDestinationInstruction,
+  type ParsedNcnSetTicketExpiryInstruction,
+  type ParsedNcnUnfreezeOperatorInstruction,
   type ParsedNcnWarmupOperatorInstruction,
   type ParsedNcnWithdrawalAssetInstruction,
+  type ParsedOperatorApproveDelegationInstruction,
   type ParsedOperatorCooldownNcnInstruction,
   type ParsedOperatorSetAdminInstruction,
+  type ParsedOperatorSetCommissionInstruction,
+  type ParsedOperatorSetDelegationApprovalRequiredInstruction,
+  type ParsedOperatorSetMetadataInstruction,
   type ParsedOperatorSetSecondaryAdminInstruction,
   type ParsedOperatorWarmupNcnInstruction,
   type ParsedOperatorWithdrawalAssetInstruction,
+  type ParsedRenewTicketInstruction,
   type ParsedWarmupNcnVaultSlasherTicketInstruction,
   type ParsedWarmupNcnVaultTicketInstruction,
   type ParsedWarmupOperatorVaultTicketInstruction,
@@ -75,6 +94,25 @@ export enum JitoRestakingInstruction {
   OperatorSetSecondaryAdmin,
   NcnWithdrawalAsset,
   OperatorWithdrawalAsset,
+  NcnOpenTaskWindow,
+  NcnCloseTaskWindow,
+  NcnFreezeOperator,
+  NcnUnfreezeOperator,
+  NcnSetTicketExpiry,
+  RenewTicket,
+  OperatorSetCommission,
+  NcnSetSlashDestination,
+  MigrateAccount,
+  OperatorSetMetadata,
+  NcnSetMaxSlashablePerEpoch,
+  OperatorSetDelegationApprovalRequired,
+  OperatorApproveDelegation,
+  ConfigSetAdmin,
+  ConfigAcceptAdmin,
+  ConfigSetEpochLength,
+  ConfigSetParameters,
+  NcnRequestSlash,
+  BatchAddOperators,
 }
 
 export function identifyJitoRestakingInstruction(
@@ -150,6 +188,63 @@ export function identifyJitoRestakingInstruction(
   if (containsBytes(data, getU8Encoder().encode(22), 0)) {
     return JitoRestakingInstruction.OperatorWithdrawalAsset;
   }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return JitoRestakingInstruction.NcnOpenTaskWindow;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return JitoRestakingInstruction.NcnCloseTaskWindow;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return JitoRestakingInstruction.NcnFreezeOperator;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return JitoRestakingInstruction.NcnUnfreezeOperator;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return JitoRestakingInstruction.NcnSetTicketExpiry;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return JitoRestakingInstruction.RenewTicket;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return JitoRestakingInstruction.OperatorSetCommission;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return JitoRestakingInstruction.NcnSetSlashDestination;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return JitoRestakingInstruction.MigrateAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return JitoRestakingInstruction.OperatorSetMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return JitoRestakingInstruction.NcnSetMaxSlashablePerEpoch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return JitoRestakingInstruction.OperatorSetDelegationApprovalRequired;
+  }
+  if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+    return JitoRestakingInstruction.OperatorApproveDelegation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(36), 0)) {
+    return JitoRestakingInstruction.ConfigSetAdmin;
+  }
+  if (containsBytes(data, getU8Encoder().encode(37), 0)) {
+    return JitoRestakingInstruction.ConfigAcceptAdmin;
+  }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return JitoRestakingInstruction.ConfigSetEpochLength;
+  }
+  if (containsBytes(data, getU8Encoder().encode(39), 0)) {
+    return JitoRestakingInstruction.ConfigSetParameters;
+  }
+  if (containsBytes(data, getU8Encoder().encode(40), 0)) {
+    return JitoRestakingInstruction.NcnRequestSlash;
+  }
+  if (containsBytes(data, getU8Encoder().encode(41), 0)) {
+    return JitoRestakingInstruction.BatchAddOperators;
+  }
   throw new Error(
     'The provided instruction could not be identified as a jitoRestaking instruction.'
   );
@@ -226,4 +321,61 @@ export type ParsedJitoRestakingInstruction<
     } & ParsedNcnWithdrawalAssetInstruction<TProgram>)
   | ({
       instructionType: JitoRestakingInstruction.OperatorWithdrawalAsset;
-    } & ParsedOperatorWithdrawalAssetInstruction<TProgram>);
+    } & ParsedOperatorWithdrawalAssetInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnOpenTaskWindow;
+    } & ParsedNcnOpenTaskWindowInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnCloseTaskWindow;
+    } & ParsedNcnCloseTaskWindowInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnFreezeOperator;
+    } & ParsedNcnFreezeOperatorInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnUnfreezeOperator;
+    } & ParsedNcnUnfreezeOperatorInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnSetTicketExpiry;
+    } & ParsedNcnSetTicketExpiryInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.RenewTicket;
+    } & ParsedRenewTicketInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.OperatorSetCommission;
+    } & ParsedOperatorSetCommissionInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnSetSlashDestination;
+    } & ParsedNcnSetSlashDestinationInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.OperatorSetMetadata;
+    } & ParsedOperatorSetMetadataInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnSetMaxSlashablePerEpoch;
+    } & ParsedNcnSetMaxSlashablePerEpochInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.OperatorSetDelegationApprovalRequired;
+    } & ParsedOperatorSetDelegationApprovalRequiredInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.OperatorApproveDelegation;
+    } & ParsedOperatorApproveDelegationInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.ConfigSetAdmin;
+    } & ParsedConfigSetAdminInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.ConfigAcceptAdmin;
+    } & ParsedConfigAcceptAdminInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.ConfigSetEpochLength;
+    } & ParsedConfigSetEpochLengthInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.ConfigSetParameters;
+    } & ParsedConfigSetParametersInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnRequestSlash;
+    } & ParsedNcnRequestSlashInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.BatchAddOperators;
+    } & ParsedBatchAddOperatorsInstruction<TProgram>);
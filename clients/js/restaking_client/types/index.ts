@@ -8,4 +8,6 @@
 
 export * from './ncnAdminRole';
 export * from './operatorAdminRole';
+export * from './slashDestination';
 export * from './slotToggle';
+export * from './slotToggleState';
@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getEnumDecoder,
+  getEnumEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export enum SlashDestination {
+  Slasher,
+  Burn,
+  Insurance,
+  Treasury,
+}
+
+export type SlashDestinationArgs = SlashDestination;
+
+export function getSlashDestinationEncoder(): Encoder<SlashDestinationArgs> {
+  return getEnumEncoder(SlashDestination);
+}
+
+export function getSlashDestinationDecoder(): Decoder<SlashDestination> {
+  return getEnumDecoder(SlashDestination);
+}
+
+export function getSlashDestinationCodec(): Codec<
+  SlashDestinationArgs,
+  SlashDestination
+> {
+  return combineCodec(
+    getSlashDestinationEncoder(),
+    getSlashDestinationDecoder()
+  );
+}
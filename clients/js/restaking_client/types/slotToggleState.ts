@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getEnumDecoder,
+  getEnumEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export enum SlotToggleState {
+  Inactive,
+  WarmUp,
+  Active,
+  Cooldown,
+}
+
+export type SlotToggleStateArgs = SlotToggleState;
+
+export function getSlotToggleStateEncoder(): Encoder<SlotToggleStateArgs> {
+  return getEnumEncoder(SlotToggleState);
+}
+
+export function getSlotToggleStateDecoder(): Decoder<SlotToggleState> {
+  return getEnumDecoder(SlotToggleState);
+}
+
+export function getSlotToggleStateCodec(): Codec<
+  SlotToggleStateArgs,
+  SlotToggleState
+> {
+  return combineCodec(getSlotToggleStateEncoder(), getSlotToggleStateDecoder());
+}
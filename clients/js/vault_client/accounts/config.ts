@@ -36,6 +36,7 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 
 export type Config = {
   discriminator: bigint;
@@ -47,6 +48,16 @@ export type Config = {
   feeRateOfChangeBps: number;
   feeBumpBps: number;
   bump: number;
+  protocolFeeBps: number;
+  protocolFeeWallet: Address;
+  delegationCooldownEpochs: bigint;
+  slashVetoAdmin: Address;
+  slashVetoWindowSlots: bigint;
+  isPaused: number;
+  crankRewardLamports: bigint;
+  crankRewardBps: number;
+  minVaultEpochLength: bigint;
+  maxVaultEpochLength: bigint;
   reserved: Array<number>;
 };
 
@@ -60,6 +71,16 @@ export type ConfigArgs = {
   feeRateOfChangeBps: number;
   feeBumpBps: number;
   bump: number;
+  protocolFeeBps: number;
+  protocolFeeWallet: Address;
+  delegationCooldownEpochs: number | bigint;
+  slashVetoAdmin: Address;
+  slashVetoWindowSlots: number | bigint;
+  isPaused: number;
+  crankRewardLamports: number | bigint;
+  crankRewardBps: number;
+  minVaultEpochLength: number | bigint;
+  maxVaultEpochLength: number | bigint;
   reserved: Array<number>;
 };
 
@@ -74,7 +95,17 @@ export function getConfigEncoder(): Encoder<ConfigArgs> {
     ['feeRateOfChangeBps', getU16Encoder()],
     ['feeBumpBps', getU16Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['protocolFeeBps', getU16Encoder()],
+    ['protocolFeeWallet', getAddressEncoder()],
+    ['delegationCooldownEpochs', getU64Encoder()],
+    ['slashVetoAdmin', getAddressEncoder()],
+    ['slashVetoWindowSlots', getU64Encoder()],
+    ['isPaused', getU8Encoder()],
+    ['crankRewardLamports', getU64Encoder()],
+    ['crankRewardBps', getU16Encoder()],
+    ['minVaultEpochLength', getU64Encoder()],
+    ['maxVaultEpochLength', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 154 })],
   ]);
 }
 
@@ -89,7 +120,17 @@ export function getConfigDecoder(): Decoder<Config> {
     ['feeRateOfChangeBps', getU16Decoder()],
     ['feeBumpBps', getU16Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['protocolFeeBps', getU16Decoder()],
+    ['protocolFeeWallet', getAddressDecoder()],
+    ['delegationCooldownEpochs', getU64Decoder()],
+    ['slashVetoAdmin', getAddressDecoder()],
+    ['slashVetoWindowSlots', getU64Decoder()],
+    ['isPaused', getU8Decoder()],
+    ['crankRewardLamports', getU64Decoder()],
+    ['crankRewardBps', getU16Decoder()],
+    ['minVaultEpochLength', getU64Decoder()],
+    ['maxVaultEpochLength', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 154 })],
   ]);
 }
 
@@ -149,3 +190,21 @@ export async function fetchAllMaybeConfig(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeConfig(maybeAccount));
 }
+
+export async function fetchConfigFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<Config>> {
+  const maybeAccount = await fetchMaybeConfigFromSeeds(rpc, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeConfigFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<Config>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findConfigPda({ programAddress });
+  return await fetchMaybeConfig(rpc, address, fetchConfig);
+}
@@ -0,0 +1,185 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+import {
+  findDelegationStrategyPda,
+  type DelegationStrategySeeds,
+} from '../pdas';
+
+export type DelegationStrategy = {
+  discriminator: bigint;
+  vault: Address;
+  totalWeight: bigint;
+  maxMovementPerEpoch: bigint;
+  movementEpoch: bigint;
+  movedAmount: bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type DelegationStrategyArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  totalWeight: number | bigint;
+  maxMovementPerEpoch: number | bigint;
+  movementEpoch: number | bigint;
+  movedAmount: number | bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getDelegationStrategyEncoder(): Encoder<DelegationStrategyArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['vault', getAddressEncoder()],
+    ['totalWeight', getU64Encoder()],
+    ['maxMovementPerEpoch', getU64Encoder()],
+    ['movementEpoch', getU64Encoder()],
+    ['movedAmount', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getDelegationStrategyDecoder(): Decoder<DelegationStrategy> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['vault', getAddressDecoder()],
+    ['totalWeight', getU64Decoder()],
+    ['maxMovementPerEpoch', getU64Decoder()],
+    ['movementEpoch', getU64Decoder()],
+    ['movedAmount', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getDelegationStrategyCodec(): Codec<
+  DelegationStrategyArgs,
+  DelegationStrategy
+> {
+  return combineCodec(
+    getDelegationStrategyEncoder(),
+    getDelegationStrategyDecoder()
+  );
+}
+
+export function decodeDelegationStrategy<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<DelegationStrategy, TAddress>;
+export function decodeDelegationStrategy<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<DelegationStrategy, TAddress>;
+export function decodeDelegationStrategy<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<DelegationStrategy, TAddress>
+  | MaybeAccount<DelegationStrategy, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getDelegationStrategyDecoder()
+  );
+}
+
+export async function fetchDelegationStrategy<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<DelegationStrategy, TAddress>> {
+  const maybeAccount = await fetchMaybeDelegationStrategy(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeDelegationStrategy<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<DelegationStrategy, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeDelegationStrategy(maybeAccount);
+}
+
+export async function fetchAllDelegationStrategy(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<DelegationStrategy>[]> {
+  const maybeAccounts = await fetchAllMaybeDelegationStrategy(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeDelegationStrategy(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<DelegationStrategy>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeDelegationStrategy(maybeAccount)
+  );
+}
+
+export async function fetchDelegationStrategyFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: DelegationStrategySeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<DelegationStrategy>> {
+  const maybeAccount = await fetchMaybeDelegationStrategyFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeDelegationStrategyFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: DelegationStrategySeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<DelegationStrategy>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findDelegationStrategyPda(seeds, { programAddress });
+  return await fetchMaybeDelegationStrategy(rpc, address, fetchConfig);
+}
@@ -0,0 +1,156 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type DepositReceipt = {
+  discriminator: bigint;
+  vault: Address;
+  depositor: Address;
+  base: Address;
+  amountIn: bigint;
+  vrtMinted: bigint;
+  slot: bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type DepositReceiptArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  depositor: Address;
+  base: Address;
+  amountIn: number | bigint;
+  vrtMinted: number | bigint;
+  slot: number | bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getDepositReceiptEncoder(): Encoder<DepositReceiptArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['vault', getAddressEncoder()],
+    ['depositor', getAddressEncoder()],
+    ['base', getAddressEncoder()],
+    ['amountIn', getU64Encoder()],
+    ['vrtMinted', getU64Encoder()],
+    ['slot', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getDepositReceiptDecoder(): Decoder<DepositReceipt> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['vault', getAddressDecoder()],
+    ['depositor', getAddressDecoder()],
+    ['base', getAddressDecoder()],
+    ['amountIn', getU64Decoder()],
+    ['vrtMinted', getU64Decoder()],
+    ['slot', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getDepositReceiptCodec(): Codec<
+  DepositReceiptArgs,
+  DepositReceipt
+> {
+  return combineCodec(getDepositReceiptEncoder(), getDepositReceiptDecoder());
+}
+
+export function decodeDepositReceipt<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<DepositReceipt, TAddress>;
+export function decodeDepositReceipt<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<DepositReceipt, TAddress>;
+export function decodeDepositReceipt<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<DepositReceipt, TAddress> | MaybeAccount<DepositReceipt, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getDepositReceiptDecoder()
+  );
+}
+
+export async function fetchDepositReceipt<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<DepositReceipt, TAddress>> {
+  const maybeAccount = await fetchMaybeDepositReceipt(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeDepositReceipt<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<DepositReceipt, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeDepositReceipt(maybeAccount);
+}
+
+export async function fetchAllDepositReceipt(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<DepositReceipt>[]> {
+  const maybeAccounts = await fetchAllMaybeDepositReceipt(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeDepositReceipt(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<DepositReceipt>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeDepositReceipt(maybeAccount)
+  );
+}
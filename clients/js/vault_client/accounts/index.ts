@@ -7,10 +7,22 @@
  */
 
 export * from './config';
+export * from './delegationStrategy';
+export * from './depositReceipt';
+export * from './ncnRewardRouter';
+export * from './operatorStakeWeight';
+export * from './rewardClaim';
+export * from './rewardDistribution';
+export * from './slashProposal';
 export * from './vault';
+export * from './vaultDepositAllowlistEntry';
+export * from './vaultDepositor';
+export * from './vaultEpochStats';
+export * from './vaultInsurance';
 export * from './vaultNcnSlasherOperatorTicket';
 export * from './vaultNcnSlasherTicket';
 export * from './vaultNcnTicket';
 export * from './vaultOperatorDelegation';
+export * from './vaultOperatorDelegationSnapshot';
 export * from './vaultStakerWithdrawalTicket';
 export * from './vaultUpdateStateTracker';
@@ -0,0 +1,175 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type OperatorStakeWeight = {
+  discriminator: bigint;
+  ncn: Address;
+  operator: Address;
+  supportedMint: Address;
+  ncnEpoch: bigint;
+  stakeWeight: bigint;
+  vaultCount: bigint;
+  slotCreated: bigint;
+  bump: number;
+  countedVaults: Array<number>;
+  reserved: Array<number>;
+};
+
+export type OperatorStakeWeightArgs = {
+  discriminator: number | bigint;
+  ncn: Address;
+  operator: Address;
+  supportedMint: Address;
+  ncnEpoch: number | bigint;
+  stakeWeight: number | bigint;
+  vaultCount: number | bigint;
+  slotCreated: number | bigint;
+  bump: number;
+  countedVaults: Array<number>;
+  reserved: Array<number>;
+};
+
+export function getOperatorStakeWeightEncoder(): Encoder<OperatorStakeWeightArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['ncn', getAddressEncoder()],
+    ['operator', getAddressEncoder()],
+    ['supportedMint', getAddressEncoder()],
+    ['ncnEpoch', getU64Encoder()],
+    ['stakeWeight', getU64Encoder()],
+    ['vaultCount', getU64Encoder()],
+    ['slotCreated', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['countedVaults', getArrayEncoder(getU8Encoder(), { size: 256 })],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getOperatorStakeWeightDecoder(): Decoder<OperatorStakeWeight> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['ncn', getAddressDecoder()],
+    ['operator', getAddressDecoder()],
+    ['supportedMint', getAddressDecoder()],
+    ['ncnEpoch', getU64Decoder()],
+    ['stakeWeight', getU64Decoder()],
+    ['vaultCount', getU64Decoder()],
+    ['slotCreated', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['countedVaults', getArrayDecoder(getU8Decoder(), { size: 256 })],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getOperatorStakeWeightCodec(): Codec<
+  OperatorStakeWeightArgs,
+  OperatorStakeWeight
+> {
+  return combineCodec(
+    getOperatorStakeWeightEncoder(),
+    getOperatorStakeWeightDecoder()
+  );
+}
+
+export function decodeOperatorStakeWeight<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<OperatorStakeWeight, TAddress>;
+export function decodeOperatorStakeWeight<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<OperatorStakeWeight, TAddress>;
+export function decodeOperatorStakeWeight<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<OperatorStakeWeight, TAddress>
+  | MaybeAccount<OperatorStakeWeight, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getOperatorStakeWeightDecoder()
+  );
+}
+
+export async function fetchOperatorStakeWeight<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<OperatorStakeWeight, TAddress>> {
+  const maybeAccount = await fetchMaybeOperatorStakeWeight(
+    rpc,
+    address,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeOperatorStakeWeight<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<OperatorStakeWeight, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeOperatorStakeWeight(maybeAccount);
+}
+
+export async function fetchAllOperatorStakeWeight(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<OperatorStakeWeight>[]> {
+  const maybeAccounts = await fetchAllMaybeOperatorStakeWeight(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeOperatorStakeWeight(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<OperatorStakeWeight>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeOperatorStakeWeight(maybeAccount)
+  );
+}
@@ -0,0 +1,137 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type RewardClaim = {
+  discriminator: bigint;
+  rewardDistribution: Address;
+  staker: Address;
+  amount: bigint;
+  slotClaimed: bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type RewardClaimArgs = {
+  discriminator: number | bigint;
+  rewardDistribution: Address;
+  staker: Address;
+  amount: number | bigint;
+  slotClaimed: number | bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getRewardClaimEncoder(): Encoder<RewardClaimArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['rewardDistribution', getAddressEncoder()],
+    ['staker', getAddressEncoder()],
+    ['amount', getU64Encoder()],
+    ['slotClaimed', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getRewardClaimDecoder(): Decoder<RewardClaim> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['rewardDistribution', getAddressDecoder()],
+    ['staker', getAddressDecoder()],
+    ['amount', getU64Decoder()],
+    ['slotClaimed', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getRewardClaimCodec(): Codec<RewardClaimArgs, RewardClaim> {
+  return combineCodec(getRewardClaimEncoder(), getRewardClaimDecoder());
+}
+
+export function decodeRewardClaim<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<RewardClaim, TAddress>;
+export function decodeRewardClaim<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<RewardClaim, TAddress>;
+export function decodeRewardClaim<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<RewardClaim, TAddress> | MaybeAccount<RewardClaim, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getRewardClaimDecoder()
+  );
+}
+
+export async function fetchRewardClaim<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<RewardClaim, TAddress>> {
+  const maybeAccount = await fetchMaybeRewardClaim(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeRewardClaim<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<RewardClaim, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeRewardClaim(maybeAccount);
+}
+
+export async function fetchAllRewardClaim(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<RewardClaim>[]> {
+  const maybeAccounts = await fetchAllMaybeRewardClaim(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeRewardClaim(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<RewardClaim>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeRewardClaim(maybeAccount));
+}
@@ -0,0 +1,178 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+
+export type RewardDistribution = {
+  discriminator: bigint;
+  vault: Address;
+  mint: Address;
+  epoch: bigint;
+  merkleRoot: ReadonlyUint8Array;
+  totalRewards: bigint;
+  totalClaimed: bigint;
+  numClaims: bigint;
+  bump: number;
+  operator: Address;
+  operatorCommission: bigint;
+  reserved: Array<number>;
+};
+
+export type RewardDistributionArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  mint: Address;
+  epoch: number | bigint;
+  merkleRoot: ReadonlyUint8Array;
+  totalRewards: number | bigint;
+  totalClaimed: number | bigint;
+  numClaims: number | bigint;
+  bump: number;
+  operator: Address;
+  operatorCommission: number | bigint;
+  reserved: Array<number>;
+};
+
+export function getRewardDistributionEncoder(): Encoder<RewardDistributionArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['vault', getAddressEncoder()],
+    ['mint', getAddressEncoder()],
+    ['epoch', getU64Encoder()],
+    ['merkleRoot', fixEncoderSize(getBytesEncoder(), 32)],
+    ['totalRewards', getU64Encoder()],
+    ['totalClaimed', getU64Encoder()],
+    ['numClaims', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['operator', getAddressEncoder()],
+    ['operatorCommission', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 223 })],
+  ]);
+}
+
+export function getRewardDistributionDecoder(): Decoder<RewardDistribution> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['vault', getAddressDecoder()],
+    ['mint', getAddressDecoder()],
+    ['epoch', getU64Decoder()],
+    ['merkleRoot', fixDecoderSize(getBytesDecoder(), 32)],
+    ['totalRewards', getU64Decoder()],
+    ['totalClaimed', getU64Decoder()],
+    ['numClaims', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['operator', getAddressDecoder()],
+    ['operatorCommission', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 223 })],
+  ]);
+}
+
+export function getRewardDistributionCodec(): Codec<
+  RewardDistributionArgs,
+  RewardDistribution
+> {
+  return combineCodec(
+    getRewardDistributionEncoder(),
+    getRewardDistributionDecoder()
+  );
+}
+
+export function decodeRewardDistribution<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<RewardDistribution, TAddress>;
+export function decodeRewardDistribution<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<RewardDistribution, TAddress>;
+export function decodeRewardDistribution<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<RewardDistribution, TAddress>
+  | MaybeAccount<RewardDistribution, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getRewardDistributionDecoder()
+  );
+}
+
+export async function fetchRewardDistribution<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<RewardDistribution, TAddress>> {
+  const maybeAccount = await fetchMaybeRewardDistribution(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeRewardDistribution<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<RewardDistribution, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeRewardDistribution(maybeAccount);
+}
+
+export async function fetchAllRewardDistribution(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<RewardDistribution>[]> {
+  const maybeAccounts = await fetchAllMaybeRewardDistribution(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeRewardDistribution(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<RewardDistribution>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeRewardDistribution(maybeAccount)
+  );
+}
@@ -0,0 +1,194 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+import { findSlashProposalPda, type SlashProposalSeeds } from '../pdas';
+
+export type SlashProposal = {
+  discriminator: bigint;
+  vault: Address;
+  ncn: Address;
+  operator: Address;
+  slasher: Address;
+  base: Address;
+  evidenceHash: ReadonlyUint8Array;
+  amount: bigint;
+  epoch: bigint;
+  executableSlot: bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type SlashProposalArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  ncn: Address;
+  operator: Address;
+  slasher: Address;
+  base: Address;
+  evidenceHash: ReadonlyUint8Array;
+  amount: number | bigint;
+  epoch: number | bigint;
+  executableSlot: number | bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getSlashProposalEncoder(): Encoder<SlashProposalArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['vault', getAddressEncoder()],
+    ['ncn', getAddressEncoder()],
+    ['operator', getAddressEncoder()],
+    ['slasher', getAddressEncoder()],
+    ['base', getAddressEncoder()],
+    ['evidenceHash', fixEncoderSize(getBytesEncoder(), 32)],
+    ['amount', getU64Encoder()],
+    ['epoch', getU64Encoder()],
+    ['executableSlot', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getSlashProposalDecoder(): Decoder<SlashProposal> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['vault', getAddressDecoder()],
+    ['ncn', getAddressDecoder()],
+    ['operator', getAddressDecoder()],
+    ['slasher', getAddressDecoder()],
+    ['base', getAddressDecoder()],
+    ['evidenceHash', fixDecoderSize(getBytesDecoder(), 32)],
+    ['amount', getU64Decoder()],
+    ['epoch', getU64Decoder()],
+    ['executableSlot', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getSlashProposalCodec(): Codec<
+  SlashProposalArgs,
+  SlashProposal
+> {
+  return combineCodec(getSlashProposalEncoder(), getSlashProposalDecoder());
+}
+
+export function decodeSlashProposal<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<SlashProposal, TAddress>;
+export function decodeSlashProposal<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<SlashProposal, TAddress>;
+export function decodeSlashProposal<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<SlashProposal, TAddress> | MaybeAccount<SlashProposal, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getSlashProposalDecoder()
+  );
+}
+
+export async function fetchSlashProposal<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<SlashProposal, TAddress>> {
+  const maybeAccount = await fetchMaybeSlashProposal(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeSlashProposal<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<SlashProposal, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeSlashProposal(maybeAccount);
+}
+
+export async function fetchAllSlashProposal(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<SlashProposal>[]> {
+  const maybeAccounts = await fetchAllMaybeSlashProposal(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeSlashProposal(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<SlashProposal>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeSlashProposal(maybeAccount));
+}
+
+export async function fetchSlashProposalFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: SlashProposalSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<SlashProposal>> {
+  const maybeAccount = await fetchMaybeSlashProposalFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeSlashProposalFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: SlashProposalSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<SlashProposal>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findSlashProposalPda(seeds, { programAddress });
+  return await fetchMaybeSlashProposal(rpc, address, fetchConfig);
+}
@@ -13,10 +13,14 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
@@ -35,10 +39,16 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
 } from '@solana/web3.js';
+import { findVaultPda, type VaultSeeds } from '../pdas';
 import {
+  getCooldownCohortsDecoder,
+  getCooldownCohortsEncoder,
   getDelegationStateDecoder,
   getDelegationStateEncoder,
+  type CooldownCohorts,
+  type CooldownCohortsArgs,
   type DelegationState,
   type DelegationStateArgs,
 } from '../types';
@@ -75,6 +85,40 @@ export type Vault = {
   withdrawalFeeBps: number;
   rewardFeeBps: number;
   bump: number;
+  isPaused: number;
+  pauseAdmin: Address;
+  nextDepositFeeBps: number;
+  nextWithdrawalFeeBps: number;
+  isFeeChangePending: number;
+  vrtFeesAccrued: bigint;
+  lastFeeMaterializationSlot: bigint;
+  metadataAdmin: Address;
+  minDelegationAmount: bigint;
+  depositMode: number;
+  maxDepositPerTransaction: bigint;
+  maxDepositPerWallet: bigint;
+  delegationSnapshotRoot: ReadonlyUint8Array;
+  delegationSnapshotEpoch: bigint;
+  mintAuthoritySuccessor: Address;
+  mintAuthorityHandoffSlot: bigint;
+  epochStatsEnabled: number;
+  flashDepositProtection: number;
+  burnAdmin: Address;
+  isShutDown: number;
+  shutdownSlot: bigint;
+  insuranceEnabled: number;
+  hookProgram: Address;
+  lastCrankRewardSlot: bigint;
+  maxTotalSlashableBpsPerEpoch: number;
+  slashEpoch: bigint;
+  slashEpochTokensDeposited: bigint;
+  slashedThisEpoch: bigint;
+  migrationPartner: Address;
+  withdrawalDelayEpochs: bigint;
+  epochLengthSlots: bigint;
+  slashDisputeHaircut: number;
+  pendingSlashAmount: bigint;
+  vrtCoolingDownCohorts: CooldownCohorts;
   reserved: Array<number>;
 };
 
@@ -110,6 +154,40 @@ export type VaultArgs = {
   withdrawalFeeBps: number;
   rewardFeeBps: number;
   bump: number;
+  isPaused: number;
+  pauseAdmin: Address;
+  nextDepositFeeBps: number;
+  nextWithdrawalFeeBps: number;
+  isFeeChangePending: number;
+  vrtFeesAccrued: number | bigint;
+  lastFeeMaterializationSlot: number | bigint;
+  metadataAdmin: Address;
+  minDelegationAmount: number | bigint;
+  depositMode: number;
+  maxDepositPerTransaction: number | bigint;
+  maxDepositPerWallet: number | bigint;
+  delegationSnapshotRoot: ReadonlyUint8Array;
+  delegationSnapshotEpoch: number | bigint;
+  mintAuthoritySuccessor: Address;
+  mintAuthorityHandoffSlot: number | bigint;
+  epochStatsEnabled: number;
+  flashDepositProtection: number;
+  burnAdmin: Address;
+  isShutDown: number;
+  shutdownSlot: number | bigint;
+  insuranceEnabled: number;
+  hookProgram: Address;
+  lastCrankRewardSlot: number | bigint;
+  maxTotalSlashableBpsPerEpoch: number;
+  slashEpoch: number | bigint;
+  slashEpochTokensDeposited: number | bigint;
+  slashedThisEpoch: number | bigint;
+  migrationPartner: Address;
+  withdrawalDelayEpochs: number | bigint;
+  epochLengthSlots: number | bigint;
+  slashDisputeHaircut: number;
+  pendingSlashAmount: number | bigint;
+  vrtCoolingDownCohorts: CooldownCohortsArgs;
   reserved: Array<number>;
 };
 
@@ -146,7 +224,41 @@ export function getVaultEncoder(): Encoder<VaultArgs> {
     ['withdrawalFeeBps', getU16Encoder()],
     ['rewardFeeBps', getU16Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['isPaused', getU8Encoder()],
+    ['pauseAdmin', getAddressEncoder()],
+    ['nextDepositFeeBps', getU16Encoder()],
+    ['nextWithdrawalFeeBps', getU16Encoder()],
+    ['isFeeChangePending', getU8Encoder()],
+    ['vrtFeesAccrued', getU64Encoder()],
+    ['lastFeeMaterializationSlot', getU64Encoder()],
+    ['metadataAdmin', getAddressEncoder()],
+    ['minDelegationAmount', getU64Encoder()],
+    ['depositMode', getU8Encoder()],
+    ['maxDepositPerTransaction', getU64Encoder()],
+    ['maxDepositPerWallet', getU64Encoder()],
+    ['delegationSnapshotRoot', fixEncoderSize(getBytesEncoder(), 32)],
+    ['delegationSnapshotEpoch', getU64Encoder()],
+    ['mintAuthoritySuccessor', getAddressEncoder()],
+    ['mintAuthorityHandoffSlot', getU64Encoder()],
+    ['epochStatsEnabled', getU8Encoder()],
+    ['flashDepositProtection', getU8Encoder()],
+    ['burnAdmin', getAddressEncoder()],
+    ['isShutDown', getU8Encoder()],
+    ['shutdownSlot', getU64Encoder()],
+    ['insuranceEnabled', getU8Encoder()],
+    ['hookProgram', getAddressEncoder()],
+    ['lastCrankRewardSlot', getU64Encoder()],
+    ['maxTotalSlashableBpsPerEpoch', getU16Encoder()],
+    ['slashEpoch', getU64Encoder()],
+    ['slashEpochTokensDeposited', getU64Encoder()],
+    ['slashedThisEpoch', getU64Encoder()],
+    ['migrationPartner', getAddressEncoder()],
+    ['withdrawalDelayEpochs', getU64Encoder()],
+    ['epochLengthSlots', getU64Encoder()],
+    ['slashDisputeHaircut', getU8Encoder()],
+    ['pendingSlashAmount', getU64Encoder()],
+    ['vrtCoolingDownCohorts', getCooldownCohortsEncoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 161 })],
   ]);
 }
 
@@ -183,7 +295,41 @@ export function getVaultDecoder(): Decoder<Vault> {
     ['withdrawalFeeBps', getU16Decoder()],
     ['rewardFeeBps', getU16Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['isPaused', getU8Decoder()],
+    ['pauseAdmin', getAddressDecoder()],
+    ['nextDepositFeeBps', getU16Decoder()],
+    ['nextWithdrawalFeeBps', getU16Decoder()],
+    ['isFeeChangePending', getU8Decoder()],
+    ['vrtFeesAccrued', getU64Decoder()],
+    ['lastFeeMaterializationSlot', getU64Decoder()],
+    ['metadataAdmin', getAddressDecoder()],
+    ['minDelegationAmount', getU64Decoder()],
+    ['depositMode', getU8Decoder()],
+    ['maxDepositPerTransaction', getU64Decoder()],
+    ['maxDepositPerWallet', getU64Decoder()],
+    ['delegationSnapshotRoot', fixDecoderSize(getBytesDecoder(), 32)],
+    ['delegationSnapshotEpoch', getU64Decoder()],
+    ['mintAuthoritySuccessor', getAddressDecoder()],
+    ['mintAuthorityHandoffSlot', getU64Decoder()],
+    ['epochStatsEnabled', getU8Decoder()],
+    ['flashDepositProtection', getU8Decoder()],
+    ['burnAdmin', getAddressDecoder()],
+    ['isShutDown', getU8Decoder()],
+    ['shutdownSlot', getU64Decoder()],
+    ['insuranceEnabled', getU8Decoder()],
+    ['hookProgram', getAddressDecoder()],
+    ['lastCrankRewardSlot', getU64Decoder()],
+    ['maxTotalSlashableBpsPerEpoch', getU16Decoder()],
+    ['slashEpoch', getU64Decoder()],
+    ['slashEpochTokensDeposited', getU64Decoder()],
+    ['slashedThisEpoch', getU64Decoder()],
+    ['migrationPartner', getAddressDecoder()],
+    ['withdrawalDelayEpochs', getU64Decoder()],
+    ['epochLengthSlots', getU64Decoder()],
+    ['slashDisputeHaircut', getU8Decoder()],
+    ['pendingSlashAmount', getU64Decoder()],
+    ['vrtCoolingDownCohorts', getCooldownCohortsDecoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 161 })],
   ]);
 }
 
@@ -243,3 +389,23 @@ export async function fetchAllMaybeVault(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeVault(maybeAccount));
 }
+
+export async function fetchVaultFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<Vault>> {
+  const maybeAccount = await fetchMaybeVaultFromSeeds(rpc, seeds, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<Vault>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVaultPda(seeds, { programAddress });
+  return await fetchMaybeVault(rpc, address, fetchConfig);
+}
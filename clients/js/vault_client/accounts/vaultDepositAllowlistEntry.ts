@@ -0,0 +1,191 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+import {
+  findVaultDepositAllowlistEntryPda,
+  type VaultDepositAllowlistEntrySeeds,
+} from '../pdas';
+
+export type VaultDepositAllowlistEntry = {
+  discriminator: bigint;
+  vault: Address;
+  depositor: Address;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type VaultDepositAllowlistEntryArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  depositor: Address;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getVaultDepositAllowlistEntryEncoder(): Encoder<VaultDepositAllowlistEntryArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['vault', getAddressEncoder()],
+    ['depositor', getAddressEncoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getVaultDepositAllowlistEntryDecoder(): Decoder<VaultDepositAllowlistEntry> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['vault', getAddressDecoder()],
+    ['depositor', getAddressDecoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getVaultDepositAllowlistEntryCodec(): Codec<
+  VaultDepositAllowlistEntryArgs,
+  VaultDepositAllowlistEntry
+> {
+  return combineCodec(
+    getVaultDepositAllowlistEntryEncoder(),
+    getVaultDepositAllowlistEntryDecoder()
+  );
+}
+
+export function decodeVaultDepositAllowlistEntry<
+  TAddress extends string = string,
+>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<VaultDepositAllowlistEntry, TAddress>;
+export function decodeVaultDepositAllowlistEntry<
+  TAddress extends string = string,
+>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<VaultDepositAllowlistEntry, TAddress>;
+export function decodeVaultDepositAllowlistEntry<
+  TAddress extends string = string,
+>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<VaultDepositAllowlistEntry, TAddress>
+  | MaybeAccount<VaultDepositAllowlistEntry, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getVaultDepositAllowlistEntryDecoder()
+  );
+}
+
+export async function fetchVaultDepositAllowlistEntry<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<VaultDepositAllowlistEntry, TAddress>> {
+  const maybeAccount = await fetchMaybeVaultDepositAllowlistEntry(
+    rpc,
+    address,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultDepositAllowlistEntry<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<VaultDepositAllowlistEntry, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeVaultDepositAllowlistEntry(maybeAccount);
+}
+
+export async function fetchAllVaultDepositAllowlistEntry(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<VaultDepositAllowlistEntry>[]> {
+  const maybeAccounts = await fetchAllMaybeVaultDepositAllowlistEntry(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeVaultDepositAllowlistEntry(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<VaultDepositAllowlistEntry>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeVaultDepositAllowlistEntry(maybeAccount)
+  );
+}
+
+export function getVaultDepositAllowlistEntrySize(): number {
+  return 328;
+}
+
+export async function fetchVaultDepositAllowlistEntryFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultDepositAllowlistEntrySeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<VaultDepositAllowlistEntry>> {
+  const maybeAccount = await fetchMaybeVaultDepositAllowlistEntryFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultDepositAllowlistEntryFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultDepositAllowlistEntrySeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<VaultDepositAllowlistEntry>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVaultDepositAllowlistEntryPda(seeds, {
+    programAddress,
+  });
+  return await fetchMaybeVaultDepositAllowlistEntry(rpc, address, fetchConfig);
+}
@@ -0,0 +1,173 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+import { findVaultDepositorPda, type VaultDepositorSeeds } from '../pdas';
+
+export type VaultDepositor = {
+  discriminator: bigint;
+  vault: Address;
+  depositor: Address;
+  tokensDeposited: bigint;
+  bump: number;
+  lastDepositSlot: bigint;
+  reserved: Array<number>;
+};
+
+export type VaultDepositorArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  depositor: Address;
+  tokensDeposited: number | bigint;
+  bump: number;
+  lastDepositSlot: number | bigint;
+  reserved: Array<number>;
+};
+
+export function getVaultDepositorEncoder(): Encoder<VaultDepositorArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['vault', getAddressEncoder()],
+    ['depositor', getAddressEncoder()],
+    ['tokensDeposited', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['lastDepositSlot', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 247 })],
+  ]);
+}
+
+export function getVaultDepositorDecoder(): Decoder<VaultDepositor> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['vault', getAddressDecoder()],
+    ['depositor', getAddressDecoder()],
+    ['tokensDeposited', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['lastDepositSlot', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 247 })],
+  ]);
+}
+
+export function getVaultDepositorCodec(): Codec<
+  VaultDepositorArgs,
+  VaultDepositor
+> {
+  return combineCodec(getVaultDepositorEncoder(), getVaultDepositorDecoder());
+}
+
+export function decodeVaultDepositor<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<VaultDepositor, TAddress>;
+export function decodeVaultDepositor<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<VaultDepositor, TAddress>;
+export function decodeVaultDepositor<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<VaultDepositor, TAddress> | MaybeAccount<VaultDepositor, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getVaultDepositorDecoder()
+  );
+}
+
+export async function fetchVaultDepositor<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<VaultDepositor, TAddress>> {
+  const maybeAccount = await fetchMaybeVaultDepositor(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultDepositor<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<VaultDepositor, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeVaultDepositor(maybeAccount);
+}
+
+export async function fetchAllVaultDepositor(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<VaultDepositor>[]> {
+  const maybeAccounts = await fetchAllMaybeVaultDepositor(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeVaultDepositor(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<VaultDepositor>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeVaultDepositor(maybeAccount)
+  );
+}
+
+export async function fetchVaultDepositorFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultDepositorSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<VaultDepositor>> {
+  const maybeAccount = await fetchMaybeVaultDepositorFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultDepositorFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultDepositorSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<VaultDepositor>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVaultDepositorPda(seeds, { programAddress });
+  return await fetchMaybeVaultDepositor(rpc, address, fetchConfig);
+}
@@ -0,0 +1,173 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+import { findVaultEpochStatsPda, type VaultEpochStatsSeeds } from '../pdas';
+import {
+  getEpochStatsDecoder,
+  getEpochStatsEncoder,
+  type EpochStats,
+  type EpochStatsArgs,
+} from '../types';
+
+export type VaultEpochStats = {
+  discriminator: bigint;
+  vault: Address;
+  epochs: Array<EpochStats>;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type VaultEpochStatsArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  epochs: Array<EpochStatsArgs>;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getVaultEpochStatsEncoder(): Encoder<VaultEpochStatsArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['vault', getAddressEncoder()],
+    ['epochs', getArrayEncoder(getEpochStatsEncoder(), { size: 16 })],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getVaultEpochStatsDecoder(): Decoder<VaultEpochStats> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['vault', getAddressDecoder()],
+    ['epochs', getArrayDecoder(getEpochStatsDecoder(), { size: 16 })],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getVaultEpochStatsCodec(): Codec<
+  VaultEpochStatsArgs,
+  VaultEpochStats
+> {
+  return combineCodec(getVaultEpochStatsEncoder(), getVaultEpochStatsDecoder());
+}
+
+export function decodeVaultEpochStats<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<VaultEpochStats, TAddress>;
+export function decodeVaultEpochStats<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<VaultEpochStats, TAddress>;
+export function decodeVaultEpochStats<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<VaultEpochStats, TAddress>
+  | MaybeAccount<VaultEpochStats, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getVaultEpochStatsDecoder()
+  );
+}
+
+export async function fetchVaultEpochStats<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<VaultEpochStats, TAddress>> {
+  const maybeAccount = await fetchMaybeVaultEpochStats(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultEpochStats<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<VaultEpochStats, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeVaultEpochStats(maybeAccount);
+}
+
+export async function fetchAllVaultEpochStats(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<VaultEpochStats>[]> {
+  const maybeAccounts = await fetchAllMaybeVaultEpochStats(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeVaultEpochStats(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<VaultEpochStats>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeVaultEpochStats(maybeAccount)
+  );
+}
+
+export async function fetchVaultEpochStatsFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultEpochStatsSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<VaultEpochStats>> {
+  const maybeAccount = await fetchMaybeVaultEpochStatsFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultEpochStatsFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultEpochStatsSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<VaultEpochStats>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVaultEpochStatsPda(seeds, { programAddress });
+  return await fetchMaybeVaultEpochStats(rpc, address, fetchConfig);
+}
@@ -0,0 +1,150 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type VaultInsurance = {
+  discriminator: bigint;
+  vault: Address;
+  feeBps: number;
+  vrtFunded: bigint;
+  vrtAbsorbed: bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type VaultInsuranceArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  feeBps: number;
+  vrtFunded: number | bigint;
+  vrtAbsorbed: number | bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getVaultInsuranceEncoder(): Encoder<VaultInsuranceArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['vault', getAddressEncoder()],
+    ['feeBps', getU16Encoder()],
+    ['vrtFunded', getU64Encoder()],
+    ['vrtAbsorbed', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getVaultInsuranceDecoder(): Decoder<VaultInsurance> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['vault', getAddressDecoder()],
+    ['feeBps', getU16Decoder()],
+    ['vrtFunded', getU64Decoder()],
+    ['vrtAbsorbed', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getVaultInsuranceCodec(): Codec<
+  VaultInsuranceArgs,
+  VaultInsurance
+> {
+  return combineCodec(getVaultInsuranceEncoder(), getVaultInsuranceDecoder());
+}
+
+export function decodeVaultInsurance<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<VaultInsurance, TAddress>;
+export function decodeVaultInsurance<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<VaultInsurance, TAddress>;
+export function decodeVaultInsurance<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<VaultInsurance, TAddress> | MaybeAccount<VaultInsurance, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getVaultInsuranceDecoder()
+  );
+}
+
+export async function fetchVaultInsurance<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<VaultInsurance, TAddress>> {
+  const maybeAccount = await fetchMaybeVaultInsurance(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultInsurance<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<VaultInsurance, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeVaultInsurance(maybeAccount);
+}
+
+export async function fetchAllVaultInsurance(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<VaultInsurance>[]> {
+  const maybeAccounts = await fetchAllMaybeVaultInsurance(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeVaultInsurance(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<VaultInsurance>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeVaultInsurance(maybeAccount)
+  );
+}
@@ -34,6 +34,10 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import {
+  findVaultNcnSlasherOperatorTicketPda,
+  type VaultNcnSlasherOperatorTicketSeeds,
+} from '../pdas';
 
 export type VaultNcnSlasherOperatorTicket = {
   discriminator: bigint;
@@ -171,3 +175,33 @@ export async function fetchAllMaybeVaultNcnSlasherOperatorTicket(
     decodeVaultNcnSlasherOperatorTicket(maybeAccount)
   );
 }
+
+export async function fetchVaultNcnSlasherOperatorTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultNcnSlasherOperatorTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<VaultNcnSlasherOperatorTicket>> {
+  const maybeAccount = await fetchMaybeVaultNcnSlasherOperatorTicketFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultNcnSlasherOperatorTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultNcnSlasherOperatorTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<VaultNcnSlasherOperatorTicket>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVaultNcnSlasherOperatorTicketPda(seeds, {
+    programAddress,
+  });
+  return await fetchMaybeVaultNcnSlasherOperatorTicket(
+    rpc,
+    address,
+    fetchConfig
+  );
+}
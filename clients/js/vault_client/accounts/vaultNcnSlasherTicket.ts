@@ -34,6 +34,10 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import {
+  findVaultNcnSlasherTicketPda,
+  type VaultNcnSlasherTicketSeeds,
+} from '../pdas';
 import {
   getSlotToggleDecoder,
   getSlotToggleEncoder,
@@ -171,3 +175,29 @@ export async function fetchAllMaybeVaultNcnSlasherTicket(
     decodeVaultNcnSlasherTicket(maybeAccount)
   );
 }
+
+export async function fetchVaultNcnSlasherTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultNcnSlasherTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<VaultNcnSlasherTicket>> {
+  const maybeAccount = await fetchMaybeVaultNcnSlasherTicketFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultNcnSlasherTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultNcnSlasherTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<VaultNcnSlasherTicket>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVaultNcnSlasherTicketPda(seeds, {
+    programAddress,
+  });
+  return await fetchMaybeVaultNcnSlasherTicket(rpc, address, fetchConfig);
+}
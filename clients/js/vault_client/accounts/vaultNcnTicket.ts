@@ -34,6 +34,7 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import { findVaultNcnTicketPda, type VaultNcnTicketSeeds } from '../pdas';
 import {
   getSlotToggleDecoder,
   getSlotToggleEncoder,
@@ -152,3 +153,27 @@ export async function fetchAllMaybeVaultNcnTicket(
     decodeVaultNcnTicket(maybeAccount)
   );
 }
+
+export async function fetchVaultNcnTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultNcnTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<VaultNcnTicket>> {
+  const maybeAccount = await fetchMaybeVaultNcnTicketFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultNcnTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultNcnTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<VaultNcnTicket>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVaultNcnTicketPda(seeds, { programAddress });
+  return await fetchMaybeVaultNcnTicket(rpc, address, fetchConfig);
+}
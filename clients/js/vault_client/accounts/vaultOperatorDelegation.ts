@@ -34,6 +34,10 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import {
+  findVaultOperatorDelegationPda,
+  type VaultOperatorDelegationSeeds,
+} from '../pdas';
 import {
   getDelegationStateDecoder,
   getDelegationStateEncoder,
@@ -49,6 +53,12 @@ export type VaultOperatorDelegation = {
   lastUpdateSlot: bigint;
   index: bigint;
   bump: number;
+  reservedCooldownStartEpoch: Array<number>;
+  maxDelegationAmount: bigint;
+  delegationPaused: number;
+  targetWeight: bigint;
+  banned: number;
+  banReason: number;
   reserved: Array<number>;
 };
 
@@ -60,6 +70,12 @@ export type VaultOperatorDelegationArgs = {
   lastUpdateSlot: number | bigint;
   index: number | bigint;
   bump: number;
+  reservedCooldownStartEpoch: Array<number>;
+  maxDelegationAmount: number | bigint;
+  delegationPaused: number;
+  targetWeight: number | bigint;
+  banned: number;
+  banReason: number;
   reserved: Array<number>;
 };
 
@@ -72,7 +88,16 @@ export function getVaultOperatorDelegationEncoder(): Encoder<VaultOperatorDelega
     ['lastUpdateSlot', getU64Encoder()],
     ['index', getU64Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    [
+      'reservedCooldownStartEpoch',
+      getArrayEncoder(getU8Encoder(), { size: 8 }),
+    ],
+    ['maxDelegationAmount', getU64Encoder()],
+    ['delegationPaused', getU8Encoder()],
+    ['targetWeight', getU64Encoder()],
+    ['banned', getU8Encoder()],
+    ['banReason', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 236 })],
   ]);
 }
 
@@ -85,7 +110,16 @@ export function getVaultOperatorDelegationDecoder(): Decoder<VaultOperatorDelega
     ['lastUpdateSlot', getU64Decoder()],
     ['index', getU64Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    [
+      'reservedCooldownStartEpoch',
+      getArrayDecoder(getU8Decoder(), { size: 8 }),
+    ],
+    ['maxDelegationAmount', getU64Decoder()],
+    ['delegationPaused', getU8Decoder()],
+    ['targetWeight', getU64Decoder()],
+    ['banned', getU8Decoder()],
+    ['banReason', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 236 })],
   ]);
 }
 
@@ -167,3 +201,29 @@ export async function fetchAllMaybeVaultOperatorDelegation(
     decodeVaultOperatorDelegation(maybeAccount)
   );
 }
+
+export async function fetchVaultOperatorDelegationFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultOperatorDelegationSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<VaultOperatorDelegation>> {
+  const maybeAccount = await fetchMaybeVaultOperatorDelegationFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultOperatorDelegationFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultOperatorDelegationSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<VaultOperatorDelegation>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVaultOperatorDelegationPda(seeds, {
+    programAddress,
+  });
+  return await fetchMaybeVaultOperatorDelegation(rpc, address, fetchConfig);
+}
@@ -0,0 +1,179 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+import {
+  getDelegationStateDecoder,
+  getDelegationStateEncoder,
+  type DelegationState,
+  type DelegationStateArgs,
+} from '../types';
+
+export type VaultOperatorDelegationSnapshot = {
+  discriminator: bigint;
+  vault: Address;
+  operator: Address;
+  ncnEpoch: bigint;
+  index: bigint;
+  delegationState: DelegationState;
+  slotCreated: bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type VaultOperatorDelegationSnapshotArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  operator: Address;
+  ncnEpoch: number | bigint;
+  index: number | bigint;
+  delegationState: DelegationStateArgs;
+  slotCreated: number | bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getVaultOperatorDelegationSnapshotEncoder(): Encoder<VaultOperatorDelegationSnapshotArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['vault', getAddressEncoder()],
+    ['operator', getAddressEncoder()],
+    ['ncnEpoch', getU64Encoder()],
+    ['index', getU64Encoder()],
+    ['delegationState', getDelegationStateEncoder()],
+    ['slotCreated', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getVaultOperatorDelegationSnapshotDecoder(): Decoder<VaultOperatorDelegationSnapshot> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['vault', getAddressDecoder()],
+    ['operator', getAddressDecoder()],
+    ['ncnEpoch', getU64Decoder()],
+    ['index', getU64Decoder()],
+    ['delegationState', getDelegationStateDecoder()],
+    ['slotCreated', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getVaultOperatorDelegationSnapshotCodec(): Codec<
+  VaultOperatorDelegationSnapshotArgs,
+  VaultOperatorDelegationSnapshot
+> {
+  return combineCodec(
+    getVaultOperatorDelegationSnapshotEncoder(),
+    getVaultOperatorDelegationSnapshotDecoder()
+  );
+}
+
+export function decodeVaultOperatorDelegationSnapshot<
+  TAddress extends string = string,
+>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<VaultOperatorDelegationSnapshot, TAddress>;
+export function decodeVaultOperatorDelegationSnapshot<
+  TAddress extends string = string,
+>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<VaultOperatorDelegationSnapshot, TAddress>;
+export function decodeVaultOperatorDelegationSnapshot<
+  TAddress extends string = string,
+>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<VaultOperatorDelegationSnapshot, TAddress>
+  | MaybeAccount<VaultOperatorDelegationSnapshot, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getVaultOperatorDelegationSnapshotDecoder()
+  );
+}
+
+export async function fetchVaultOperatorDelegationSnapshot<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<VaultOperatorDelegationSnapshot, TAddress>> {
+  const maybeAccount = await fetchMaybeVaultOperatorDelegationSnapshot(
+    rpc,
+    address,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultOperatorDelegationSnapshot<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<VaultOperatorDelegationSnapshot, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeVaultOperatorDelegationSnapshot(maybeAccount);
+}
+
+export async function fetchAllVaultOperatorDelegationSnapshot(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<VaultOperatorDelegationSnapshot>[]> {
+  const maybeAccounts = await fetchAllMaybeVaultOperatorDelegationSnapshot(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeVaultOperatorDelegationSnapshot(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<VaultOperatorDelegationSnapshot>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeVaultOperatorDelegationSnapshot(maybeAccount)
+  );
+}
@@ -19,6 +19,8 @@ import {
   getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -34,6 +36,10 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import {
+  findVaultStakerWithdrawalTicketPda,
+  type VaultStakerWithdrawalTicketSeeds,
+} from '../pdas';
 
 export type VaultStakerWithdrawalTicket = {
   discriminator: bigint;
@@ -43,6 +49,10 @@ export type VaultStakerWithdrawalTicket = {
   vrtAmount: bigint;
   slotUnstaked: bigint;
   bump: number;
+  autoClaimFeeBps: number;
+  adminBurnRecovery: Address;
+  adminBurnSlot: bigint;
+  withdrawalDelayEpochs: bigint;
   reserved: Array<number>;
 };
 
@@ -54,6 +64,10 @@ export type VaultStakerWithdrawalTicketArgs = {
   vrtAmount: number | bigint;
   slotUnstaked: number | bigint;
   bump: number;
+  autoClaimFeeBps: number;
+  adminBurnRecovery: Address;
+  adminBurnSlot: number | bigint;
+  withdrawalDelayEpochs: number | bigint;
   reserved: Array<number>;
 };
 
@@ -66,7 +80,11 @@ export function getVaultStakerWithdrawalTicketEncoder(): Encoder<VaultStakerWith
     ['vrtAmount', getU64Encoder()],
     ['slotUnstaked', getU64Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['autoClaimFeeBps', getU16Encoder()],
+    ['adminBurnRecovery', getAddressEncoder()],
+    ['adminBurnSlot', getU64Encoder()],
+    ['withdrawalDelayEpochs', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 213 })],
   ]);
 }
 
@@ -79,7 +97,11 @@ export function getVaultStakerWithdrawalTicketDecoder(): Decoder<VaultStakerWith
     ['vrtAmount', getU64Decoder()],
     ['slotUnstaked', getU64Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['autoClaimFeeBps', getU16Decoder()],
+    ['adminBurnRecovery', getAddressDecoder()],
+    ['adminBurnSlot', getU64Decoder()],
+    ['withdrawalDelayEpochs', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 213 })],
   ]);
 }
 
@@ -167,3 +189,29 @@ export async function fetchAllMaybeVaultStakerWithdrawalTicket(
     decodeVaultStakerWithdrawalTicket(maybeAccount)
   );
 }
+
+export async function fetchVaultStakerWithdrawalTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultStakerWithdrawalTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<VaultStakerWithdrawalTicket>> {
+  const maybeAccount = await fetchMaybeVaultStakerWithdrawalTicketFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultStakerWithdrawalTicketFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultStakerWithdrawalTicketSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<VaultStakerWithdrawalTicket>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVaultStakerWithdrawalTicketPda(seeds, {
+    programAddress,
+  });
+  return await fetchMaybeVaultStakerWithdrawalTicket(rpc, address, fetchConfig);
+}
@@ -35,8 +35,16 @@ import {
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
 import {
+  findVaultUpdateStateTrackerPda,
+  type VaultUpdateStateTrackerSeeds,
+} from '../pdas';
+import {
+  getDelegationSnapshotDecoder,
+  getDelegationSnapshotEncoder,
   getDelegationStateDecoder,
   getDelegationStateEncoder,
+  type DelegationSnapshot,
+  type DelegationSnapshotArgs,
   type DelegationState,
   type DelegationStateArgs,
 } from '../types';
@@ -49,6 +57,8 @@ export type VaultUpdateStateTracker = {
   additionalAssetsNeedUnstaking: bigint;
   delegationState: DelegationState;
   withdrawalAllocationMethod: number;
+  delegationSnapshot: DelegationSnapshot;
+  deferredUpdateCount: bigint;
   reserved: Array<number>;
 };
 
@@ -60,6 +70,8 @@ export type VaultUpdateStateTrackerArgs = {
   additionalAssetsNeedUnstaking: number | bigint;
   delegationState: DelegationStateArgs;
   withdrawalAllocationMethod: number;
+  delegationSnapshot: DelegationSnapshotArgs;
+  deferredUpdateCount: number | bigint;
   reserved: Array<number>;
 };
 
@@ -72,7 +84,9 @@ export function getVaultUpdateStateTrackerEncoder(): Encoder<VaultUpdateStateTra
     ['additionalAssetsNeedUnstaking', getU64Encoder()],
     ['delegationState', getDelegationStateEncoder()],
     ['withdrawalAllocationMethod', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['delegationSnapshot', getDelegationSnapshotEncoder()],
+    ['deferredUpdateCount', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 255 })],
   ]);
 }
 
@@ -85,7 +99,9 @@ export function getVaultUpdateStateTrackerDecoder(): Decoder<VaultUpdateStateTra
     ['additionalAssetsNeedUnstaking', getU64Decoder()],
     ['delegationState', getDelegationStateDecoder()],
     ['withdrawalAllocationMethod', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['delegationSnapshot', getDelegationSnapshotDecoder()],
+    ['deferredUpdateCount', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 255 })],
   ]);
 }
 
@@ -167,3 +183,29 @@ export async function fetchAllMaybeVaultUpdateStateTracker(
     decodeVaultUpdateStateTracker(maybeAccount)
   );
 }
+
+export async function fetchVaultUpdateStateTrackerFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultUpdateStateTrackerSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<Account<VaultUpdateStateTracker>> {
+  const maybeAccount = await fetchMaybeVaultUpdateStateTrackerFromSeeds(
+    rpc,
+    seeds,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeVaultUpdateStateTrackerFromSeeds(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  seeds: VaultUpdateStateTrackerSeeds,
+  config: FetchAccountConfig & { programAddress?: Address } = {}
+): Promise<MaybeAccount<VaultUpdateStateTracker>> {
+  const { programAddress, ...fetchConfig } = config;
+  const [address] = await findVaultUpdateStateTrackerPda(seeds, {
+    programAddress,
+  });
+  return await fetchMaybeVaultUpdateStateTracker(rpc, address, fetchConfig);
+}
@@ -104,102 +104,382 @@ export const JITO_VAULT_ERROR__VAULT_BURN_ZERO = 0x412; // 1042
 export const JITO_VAULT_ERROR__VAULT_ENQUEUE_WITHDRAWAL_AMOUNT_ZERO = 0x413; // 1043
 /** VaultMintZero: VaultMintZero */
 export const JITO_VAULT_ERROR__VAULT_MINT_ZERO = 0x414; // 1044
+/** VaultPauseAdminInvalid: VaultPauseAdminInvalid */
+export const JITO_VAULT_ERROR__VAULT_PAUSE_ADMIN_INVALID = 0x415; // 1045
+/** VaultIsPaused: VaultIsPaused */
+export const JITO_VAULT_ERROR__VAULT_IS_PAUSED = 0x416; // 1046
+/** ConfigAdminInvalid: ConfigAdminInvalid */
+export const JITO_VAULT_ERROR__CONFIG_ADMIN_INVALID = 0x417; // 1047
+/** ConfigProtocolFeeCapExceeded: ConfigProtocolFeeCapExceeded */
+export const JITO_VAULT_ERROR__CONFIG_PROTOCOL_FEE_CAP_EXCEEDED = 0x418; // 1048
+/** VaultMetadataAdminInvalid: VaultMetadataAdminInvalid */
+export const JITO_VAULT_ERROR__VAULT_METADATA_ADMIN_INVALID = 0x419; // 1049
+/** VaultDelegationBelowMinimum: VaultDelegationBelowMinimum */
+export const JITO_VAULT_ERROR__VAULT_DELEGATION_BELOW_MINIMUM = 0x41a; // 1050
+/** VaultDepositNotAllowed: VaultDepositNotAllowed */
+export const JITO_VAULT_ERROR__VAULT_DEPOSIT_NOT_ALLOWED = 0x41b; // 1051
+/** VaultDepositPerTransactionLimitExceeded: VaultDepositPerTransactionLimitExceeded */
+export const JITO_VAULT_ERROR__VAULT_DEPOSIT_PER_TRANSACTION_LIMIT_EXCEEDED = 0x41c; // 1052
+/** VaultDepositPerWalletLimitExceeded: VaultDepositPerWalletLimitExceeded */
+export const JITO_VAULT_ERROR__VAULT_DEPOSIT_PER_WALLET_LIMIT_EXCEEDED = 0x41d; // 1053
+/** VaultMintAuthorityHandoffPending: VaultMintAuthorityHandoffPending */
+export const JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_HANDOFF_PENDING = 0x41e; // 1054
+/** VaultMintAuthorityHandoffNotPending: VaultMintAuthorityHandoffNotPending */
+export const JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_HANDOFF_NOT_PENDING = 0x41f; // 1055
+/** VaultMintAuthorityHandoffChallengeWindowActive: VaultMintAuthorityHandoffChallengeWindowActive */
+export const JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_HANDOFF_CHALLENGE_WINDOW_ACTIVE = 0x420; // 1056
+/** VaultMintAuthoritySuccessorInvalid: VaultMintAuthoritySuccessorInvalid */
+export const JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_SUCCESSOR_INVALID = 0x421; // 1057
+/** ConfigDelegationCooldownEpochsInvalid: ConfigDelegationCooldownEpochsInvalid */
+export const JITO_VAULT_ERROR__CONFIG_DELEGATION_COOLDOWN_EPOCHS_INVALID = 0x422; // 1058
+/** ConfigSlashVetoWindowInvalid: ConfigSlashVetoWindowInvalid */
+export const JITO_VAULT_ERROR__CONFIG_SLASH_VETO_WINDOW_INVALID = 0x423; // 1059
+/** ConfigSlashVetoAdminInvalid: ConfigSlashVetoAdminInvalid */
+export const JITO_VAULT_ERROR__CONFIG_SLASH_VETO_ADMIN_INVALID = 0x424; // 1060
+/** VaultSlashProposalRequired: VaultSlashProposalRequired */
+export const JITO_VAULT_ERROR__VAULT_SLASH_PROPOSAL_REQUIRED = 0x425; // 1061
+/** VaultSlashProposalVetoWindowActive: VaultSlashProposalVetoWindowActive */
+export const JITO_VAULT_ERROR__VAULT_SLASH_PROPOSAL_VETO_WINDOW_ACTIVE = 0x426; // 1062
+/** VaultSlashProposalInvalid: VaultSlashProposalInvalid */
+export const JITO_VAULT_ERROR__VAULT_SLASH_PROPOSAL_INVALID = 0x427; // 1063
+/** VaultOperatorFrozen: VaultOperatorFrozen */
+export const JITO_VAULT_ERROR__VAULT_OPERATOR_FROZEN = 0x428; // 1064
+/** VaultNotEmpty: VaultNotEmpty */
+export const JITO_VAULT_ERROR__VAULT_NOT_EMPTY = 0x429; // 1065
+/** VaultDelegationAboveMaximum: VaultDelegationAboveMaximum */
+export const JITO_VAULT_ERROR__VAULT_DELEGATION_ABOVE_MAXIMUM = 0x42a; // 1066
+/** VaultOperatorDelegationPaused: VaultOperatorDelegationPaused */
+export const JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_PAUSED = 0x42b; // 1067
+/** VaultDelegationStrategyMovementExceeded: VaultDelegationStrategyMovementExceeded */
+export const JITO_VAULT_ERROR__VAULT_DELEGATION_STRATEGY_MOVEMENT_EXCEEDED = 0x42c; // 1068
+/** VaultBurnInDepositSlot: VaultBurnInDepositSlot */
+export const JITO_VAULT_ERROR__VAULT_BURN_IN_DEPOSIT_SLOT = 0x42d; // 1069
+/** VaultAdminBurnAlreadyEnabled: VaultAdminBurnAlreadyEnabled */
+export const JITO_VAULT_ERROR__VAULT_ADMIN_BURN_ALREADY_ENABLED = 0x42e; // 1070
+/** VaultAdminBurnEnableAfterDeposit: VaultAdminBurnEnableAfterDeposit */
+export const JITO_VAULT_ERROR__VAULT_ADMIN_BURN_ENABLE_AFTER_DEPOSIT = 0x42f; // 1071
+/** VaultAdminBurnNotEnabled: VaultAdminBurnNotEnabled */
+export const JITO_VAULT_ERROR__VAULT_ADMIN_BURN_NOT_ENABLED = 0x430; // 1072
+/** VaultBurnAdminInvalid: VaultBurnAdminInvalid */
+export const JITO_VAULT_ERROR__VAULT_BURN_ADMIN_INVALID = 0x431; // 1073
+/** VaultAdminBurnPending: VaultAdminBurnPending */
+export const JITO_VAULT_ERROR__VAULT_ADMIN_BURN_PENDING = 0x432; // 1074
+/** VaultAdminBurnNotPending: VaultAdminBurnNotPending */
+export const JITO_VAULT_ERROR__VAULT_ADMIN_BURN_NOT_PENDING = 0x433; // 1075
+/** VaultAdminBurnTimelockActive: VaultAdminBurnTimelockActive */
+export const JITO_VAULT_ERROR__VAULT_ADMIN_BURN_TIMELOCK_ACTIVE = 0x434; // 1076
+/** VaultAccountDataEmpty: VaultAccountDataEmpty */
+export const JITO_VAULT_ERROR__VAULT_ACCOUNT_DATA_EMPTY = 0x435; // 1077
+/** VaultAccountNotWritable: VaultAccountNotWritable */
+export const JITO_VAULT_ERROR__VAULT_ACCOUNT_NOT_WRITABLE = 0x436; // 1078
+/** VaultAccountDiscriminatorInvalid: VaultAccountDiscriminatorInvalid */
+export const JITO_VAULT_ERROR__VAULT_ACCOUNT_DISCRIMINATOR_INVALID = 0x437; // 1079
+/** VaultAccountNotCanonicalPda: VaultAccountNotCanonicalPda */
+export const JITO_VAULT_ERROR__VAULT_ACCOUNT_NOT_CANONICAL_PDA = 0x438; // 1080
+/** VaultVrtMintInvalid: VaultVrtMintInvalid */
+export const JITO_VAULT_ERROR__VAULT_VRT_MINT_INVALID = 0x439; // 1081
+/** VaultSupportedMintInvalid: VaultSupportedMintInvalid */
+export const JITO_VAULT_ERROR__VAULT_SUPPORTED_MINT_INVALID = 0x43a; // 1082
+/** VaultMetadataAccountInvalid: VaultMetadataAccountInvalid */
+export const JITO_VAULT_ERROR__VAULT_METADATA_ACCOUNT_INVALID = 0x43b; // 1083
+/** ConfigPaused: ConfigPaused */
+export const JITO_VAULT_ERROR__CONFIG_PAUSED = 0x43c; // 1084
+/** VaultTokenAccountClosed: VaultTokenAccountClosed */
+export const JITO_VAULT_ERROR__VAULT_TOKEN_ACCOUNT_CLOSED = 0x43d; // 1085
+/** VaultBalanceDiverged: VaultBalanceDiverged */
+export const JITO_VAULT_ERROR__VAULT_BALANCE_DIVERGED = 0x43e; // 1086
+/** VaultBalanceNotDiverged: VaultBalanceNotDiverged */
+export const JITO_VAULT_ERROR__VAULT_BALANCE_NOT_DIVERGED = 0x43f; // 1087
+/** VaultReconcileAdjustmentExceeded: VaultReconcileAdjustmentExceeded */
+export const JITO_VAULT_ERROR__VAULT_RECONCILE_ADJUSTMENT_EXCEEDED = 0x440; // 1088
+/** VaultOperatorDelegationNotCranked: VaultOperatorDelegationNotCranked */
+export const JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_NOT_CRANKED = 0x441; // 1089
+/** VaultRewardMintInvalid: VaultRewardMintInvalid */
+export const JITO_VAULT_ERROR__VAULT_REWARD_MINT_INVALID = 0x442; // 1090
+/** VaultRewardProofInvalid: VaultRewardProofInvalid */
+export const JITO_VAULT_ERROR__VAULT_REWARD_PROOF_INVALID = 0x443; // 1091
+/** VaultRewardsExceeded: VaultRewardsExceeded */
+export const JITO_VAULT_ERROR__VAULT_REWARDS_EXCEEDED = 0x444; // 1092
+/** VaultIsShutDown: VaultIsShutDown */
+export const JITO_VAULT_ERROR__VAULT_IS_SHUT_DOWN = 0x445; // 1093
+/** VaultOperatorDelegationsIncomplete: VaultOperatorDelegationsIncomplete */
+export const JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATIONS_INCOMPLETE = 0x446; // 1094
+/** VaultSlashDestinationInvalid: VaultSlashDestinationInvalid */
+export const JITO_VAULT_ERROR__VAULT_SLASH_DESTINATION_INVALID = 0x447; // 1095
+/** VaultHookProgramInvalid: VaultHookProgramInvalid */
+export const JITO_VAULT_ERROR__VAULT_HOOK_PROGRAM_INVALID = 0x448; // 1096
+/** VaultBurnDelegateInvalid: VaultBurnDelegateInvalid */
+export const JITO_VAULT_ERROR__VAULT_BURN_DELEGATE_INVALID = 0x449; // 1097
+/** ConfigCrankRewardInvalid: ConfigCrankRewardInvalid */
+export const JITO_VAULT_ERROR__CONFIG_CRANK_REWARD_INVALID = 0x44a; // 1098
+/** VaultMaxTotalSlashableBpsInvalid: VaultMaxTotalSlashableBpsInvalid */
+export const JITO_VAULT_ERROR__VAULT_MAX_TOTAL_SLASHABLE_BPS_INVALID = 0x44b; // 1099
+/** VaultMaxTotalSlashedPerEpochExceeded: VaultMaxTotalSlashedPerEpochExceeded */
+export const JITO_VAULT_ERROR__VAULT_MAX_TOTAL_SLASHED_PER_EPOCH_EXCEEDED = 0x44c; // 1100
+/** VaultMigrationInvalid: VaultMigrationInvalid */
+export const JITO_VAULT_ERROR__VAULT_MIGRATION_INVALID = 0x44d; // 1101
+/** VaultNcnSlasherTicketNotInactive: VaultNcnSlasherTicketNotInactive */
+export const JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_NOT_INACTIVE = 0x44e; // 1102
+/** VaultWithdrawalDelayInvalid: VaultWithdrawalDelayInvalid */
+export const JITO_VAULT_ERROR__VAULT_WITHDRAWAL_DELAY_INVALID = 0x44f; // 1103
+/** VaultWithdrawalDelayActive: VaultWithdrawalDelayActive */
+export const JITO_VAULT_ERROR__VAULT_WITHDRAWAL_DELAY_ACTIVE = 0x450; // 1104
+/** VaultAccountVersionInvalid: VaultAccountVersionInvalid */
+export const JITO_VAULT_ERROR__VAULT_ACCOUNT_VERSION_INVALID = 0x451; // 1105
+/** OperatorStakeWeightVaultIndexInvalid: OperatorStakeWeightVaultIndexInvalid */
+export const JITO_VAULT_ERROR__OPERATOR_STAKE_WEIGHT_VAULT_INDEX_INVALID = 0x452; // 1106
+/** OperatorStakeWeightVaultAlreadyCounted: OperatorStakeWeightVaultAlreadyCounted */
+export const JITO_VAULT_ERROR__OPERATOR_STAKE_WEIGHT_VAULT_ALREADY_COUNTED = 0x453; // 1107
+/** OperatorStakeWeightVaultNcnInactive: OperatorStakeWeightVaultNcnInactive */
+export const JITO_VAULT_ERROR__OPERATOR_STAKE_WEIGHT_VAULT_NCN_INACTIVE = 0x454; // 1108
+/** VaultDelegationNotApproved: VaultDelegationNotApproved */
+export const JITO_VAULT_ERROR__VAULT_DELEGATION_NOT_APPROVED = 0x455; // 1109
+/** ConfigVaultEpochLengthBoundsInvalid: ConfigVaultEpochLengthBoundsInvalid */
+export const JITO_VAULT_ERROR__CONFIG_VAULT_EPOCH_LENGTH_BOUNDS_INVALID = 0x456; // 1110
+/** VaultEpochLengthInvalid: VaultEpochLengthInvalid */
+export const JITO_VAULT_ERROR__VAULT_EPOCH_LENGTH_INVALID = 0x457; // 1111
+/** VaultEpochLengthCooldownInProgress: VaultEpochLengthCooldownInProgress */
+export const JITO_VAULT_ERROR__VAULT_EPOCH_LENGTH_COOLDOWN_IN_PROGRESS = 0x458; // 1112
+/** VaultOperatorBanned: VaultOperatorBanned */
+export const JITO_VAULT_ERROR__VAULT_OPERATOR_BANNED = 0x459; // 1113
+/** VaultOperatorNotBanned: VaultOperatorNotBanned */
+export const JITO_VAULT_ERROR__VAULT_OPERATOR_NOT_BANNED = 0x45a; // 1114
 
 export type JitoVaultError =
+  | typeof JITO_VAULT_ERROR__CONFIG_ADMIN_INVALID
+  | typeof JITO_VAULT_ERROR__CONFIG_CRANK_REWARD_INVALID
+  | typeof JITO_VAULT_ERROR__CONFIG_DELEGATION_COOLDOWN_EPOCHS_INVALID
+  | typeof JITO_VAULT_ERROR__CONFIG_PAUSED
+  | typeof JITO_VAULT_ERROR__CONFIG_PROTOCOL_FEE_CAP_EXCEEDED
+  | typeof JITO_VAULT_ERROR__CONFIG_SLASH_VETO_ADMIN_INVALID
+  | typeof JITO_VAULT_ERROR__CONFIG_SLASH_VETO_WINDOW_INVALID
+  | typeof JITO_VAULT_ERROR__CONFIG_VAULT_EPOCH_LENGTH_BOUNDS_INVALID
   | typeof JITO_VAULT_ERROR__NCN_OPERATOR_STATE_UNSLASHABLE
   | typeof JITO_VAULT_ERROR__NCN_OVERFLOW
   | typeof JITO_VAULT_ERROR__NCN_VAULT_SLASHER_TICKET_UNSLASHABLE
   | typeof JITO_VAULT_ERROR__NCN_VAULT_TICKET_UNSLASHABLE
   | typeof JITO_VAULT_ERROR__OPERATOR_OVERFLOW
+  | typeof JITO_VAULT_ERROR__OPERATOR_STAKE_WEIGHT_VAULT_ALREADY_COUNTED
+  | typeof JITO_VAULT_ERROR__OPERATOR_STAKE_WEIGHT_VAULT_INDEX_INVALID
+  | typeof JITO_VAULT_ERROR__OPERATOR_STAKE_WEIGHT_VAULT_NCN_INACTIVE
   | typeof JITO_VAULT_ERROR__OPERATOR_VAULT_TICKET_UNSLASHABLE
   | typeof JITO_VAULT_ERROR__SLASHER_OVERFLOW
   | typeof JITO_VAULT_ERROR__SLIPPAGE_ERROR
+  | typeof JITO_VAULT_ERROR__VAULT_ACCOUNT_DATA_EMPTY
+  | typeof JITO_VAULT_ERROR__VAULT_ACCOUNT_DISCRIMINATOR_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_ACCOUNT_NOT_CANONICAL_PDA
+  | typeof JITO_VAULT_ERROR__VAULT_ACCOUNT_NOT_WRITABLE
+  | typeof JITO_VAULT_ERROR__VAULT_ACCOUNT_VERSION_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_ADMIN_BURN_ALREADY_ENABLED
+  | typeof JITO_VAULT_ERROR__VAULT_ADMIN_BURN_ENABLE_AFTER_DEPOSIT
+  | typeof JITO_VAULT_ERROR__VAULT_ADMIN_BURN_NOT_ENABLED
+  | typeof JITO_VAULT_ERROR__VAULT_ADMIN_BURN_NOT_PENDING
+  | typeof JITO_VAULT_ERROR__VAULT_ADMIN_BURN_PENDING
+  | typeof JITO_VAULT_ERROR__VAULT_ADMIN_BURN_TIMELOCK_ACTIVE
   | typeof JITO_VAULT_ERROR__VAULT_ADMIN_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_BALANCE_DIVERGED
+  | typeof JITO_VAULT_ERROR__VAULT_BALANCE_NOT_DIVERGED
+  | typeof JITO_VAULT_ERROR__VAULT_BURN_ADMIN_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_BURN_DELEGATE_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_BURN_IN_DEPOSIT_SLOT
   | typeof JITO_VAULT_ERROR__VAULT_BURN_ZERO
   | typeof JITO_VAULT_ERROR__VAULT_CAPACITY_ADMIN_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_CAPACITY_EXCEEDED
   | typeof JITO_VAULT_ERROR__VAULT_COOLDOWN_ZERO
+  | typeof JITO_VAULT_ERROR__VAULT_DELEGATION_ABOVE_MAXIMUM
   | typeof JITO_VAULT_ERROR__VAULT_DELEGATION_ADMIN_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_DELEGATION_BELOW_MINIMUM
+  | typeof JITO_VAULT_ERROR__VAULT_DELEGATION_NOT_APPROVED
+  | typeof JITO_VAULT_ERROR__VAULT_DELEGATION_STRATEGY_MOVEMENT_EXCEEDED
   | typeof JITO_VAULT_ERROR__VAULT_DELEGATION_ZERO
+  | typeof JITO_VAULT_ERROR__VAULT_DEPOSIT_NOT_ALLOWED
+  | typeof JITO_VAULT_ERROR__VAULT_DEPOSIT_PER_TRANSACTION_LIMIT_EXCEEDED
+  | typeof JITO_VAULT_ERROR__VAULT_DEPOSIT_PER_WALLET_LIMIT_EXCEEDED
   | typeof JITO_VAULT_ERROR__VAULT_ENQUEUE_WITHDRAWAL_AMOUNT_ZERO
+  | typeof JITO_VAULT_ERROR__VAULT_EPOCH_LENGTH_COOLDOWN_IN_PROGRESS
+  | typeof JITO_VAULT_ERROR__VAULT_EPOCH_LENGTH_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_FEE_ADMIN_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_FEE_BUMP_TOO_LARGE
   | typeof JITO_VAULT_ERROR__VAULT_FEE_CAP_EXCEEDED
   | typeof JITO_VAULT_ERROR__VAULT_FEE_CHANGE_TOO_SOON
+  | typeof JITO_VAULT_ERROR__VAULT_HOOK_PROGRAM_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_INSUFFICIENT_FUNDS
+  | typeof JITO_VAULT_ERROR__VAULT_IS_PAUSED
+  | typeof JITO_VAULT_ERROR__VAULT_IS_SHUT_DOWN
   | typeof JITO_VAULT_ERROR__VAULT_IS_UPDATED
   | typeof JITO_VAULT_ERROR__VAULT_MAX_SLASHED_PER_OPERATOR_EXCEEDED
+  | typeof JITO_VAULT_ERROR__VAULT_MAX_TOTAL_SLASHABLE_BPS_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_MAX_TOTAL_SLASHED_PER_EPOCH_EXCEEDED
+  | typeof JITO_VAULT_ERROR__VAULT_METADATA_ACCOUNT_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_METADATA_ADMIN_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_MIGRATION_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_HANDOFF_CHALLENGE_WINDOW_ACTIVE
+  | typeof JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_HANDOFF_NOT_PENDING
+  | typeof JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_HANDOFF_PENDING
+  | typeof JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_SUCCESSOR_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_MINT_BURN_ADMIN_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_MINT_ZERO
   | typeof JITO_VAULT_ERROR__VAULT_NCN_ADMIN_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_FAILED_COOLDOWN
   | typeof JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_FAILED_WARMUP
+  | typeof JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_NOT_INACTIVE
   | typeof JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_UNSLASHABLE
   | typeof JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_COOLDOWN
   | typeof JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_WARMUP
   | typeof JITO_VAULT_ERROR__VAULT_NCN_TICKET_UNSLASHABLE
+  | typeof JITO_VAULT_ERROR__VAULT_NOT_EMPTY
   | typeof JITO_VAULT_ERROR__VAULT_OPERATOR_ADMIN_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_OPERATOR_BANNED
+  | typeof JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATIONS_INCOMPLETE
+  | typeof JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_NOT_CRANKED
+  | typeof JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_PAUSED
+  | typeof JITO_VAULT_ERROR__VAULT_OPERATOR_FROZEN
+  | typeof JITO_VAULT_ERROR__VAULT_OPERATOR_NOT_BANNED
   | typeof JITO_VAULT_ERROR__VAULT_OVERFLOW
+  | typeof JITO_VAULT_ERROR__VAULT_PAUSE_ADMIN_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_RECONCILE_ADJUSTMENT_EXCEEDED
+  | typeof JITO_VAULT_ERROR__VAULT_REWARDS_EXCEEDED
+  | typeof JITO_VAULT_ERROR__VAULT_REWARD_MINT_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_REWARD_PROOF_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_SECURITY_OVERFLOW
   | typeof JITO_VAULT_ERROR__VAULT_SECURITY_UNDERFLOW
   | typeof JITO_VAULT_ERROR__VAULT_SLASHER_ADMIN_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_SLASH_DESTINATION_INVALID
   | typeof JITO_VAULT_ERROR__VAULT_SLASH_INCOMPLETE
+  | typeof JITO_VAULT_ERROR__VAULT_SLASH_PROPOSAL_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_SLASH_PROPOSAL_REQUIRED
+  | typeof JITO_VAULT_ERROR__VAULT_SLASH_PROPOSAL_VETO_WINDOW_ACTIVE
   | typeof JITO_VAULT_ERROR__VAULT_SLASH_UNDERFLOW
   | typeof JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_STAKER
   | typeof JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_NOT_WITHDRAWABLE
+  | typeof JITO_VAULT_ERROR__VAULT_SUPPORTED_MINT_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_TOKEN_ACCOUNT_CLOSED
   | typeof JITO_VAULT_ERROR__VAULT_UNDERFLOW
   | typeof JITO_VAULT_ERROR__VAULT_UPDATE_INCORRECT_INDEX
   | typeof JITO_VAULT_ERROR__VAULT_UPDATE_NEEDED
-  | typeof JITO_VAULT_ERROR__VAULT_UPDATE_STATE_NOT_FINISHED_UPDATING;
+  | typeof JITO_VAULT_ERROR__VAULT_UPDATE_STATE_NOT_FINISHED_UPDATING
+  | typeof JITO_VAULT_ERROR__VAULT_VRT_MINT_INVALID
+  | typeof JITO_VAULT_ERROR__VAULT_WITHDRAWAL_DELAY_ACTIVE
+  | typeof JITO_VAULT_ERROR__VAULT_WITHDRAWAL_DELAY_INVALID;
 
 let jitoVaultErrorMessages: Record<JitoVaultError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   jitoVaultErrorMessages = {
+    [JITO_VAULT_ERROR__CONFIG_ADMIN_INVALID]: `ConfigAdminInvalid`,
+    [JITO_VAULT_ERROR__CONFIG_CRANK_REWARD_INVALID]: `ConfigCrankRewardInvalid`,
+    [JITO_VAULT_ERROR__CONFIG_DELEGATION_COOLDOWN_EPOCHS_INVALID]: `ConfigDelegationCooldownEpochsInvalid`,
+    [JITO_VAULT_ERROR__CONFIG_PAUSED]: `ConfigPaused`,
+    [JITO_VAULT_ERROR__CONFIG_PROTOCOL_FEE_CAP_EXCEEDED]: `ConfigProtocolFeeCapExceeded`,
+    [JITO_VAULT_ERROR__CONFIG_SLASH_VETO_ADMIN_INVALID]: `ConfigSlashVetoAdminInvalid`,
+    [JITO_VAULT_ERROR__CONFIG_SLASH_VETO_WINDOW_INVALID]: `ConfigSlashVetoWindowInvalid`,
+    [JITO_VAULT_ERROR__CONFIG_VAULT_EPOCH_LENGTH_BOUNDS_INVALID]: `ConfigVaultEpochLengthBoundsInvalid`,
     [JITO_VAULT_ERROR__NCN_OPERATOR_STATE_UNSLASHABLE]: `NcnOperatorStateUnslashable`,
     [JITO_VAULT_ERROR__NCN_OVERFLOW]: `NcnOverflow`,
     [JITO_VAULT_ERROR__NCN_VAULT_SLASHER_TICKET_UNSLASHABLE]: `NcnVaultSlasherTicketUnslashable`,
     [JITO_VAULT_ERROR__NCN_VAULT_TICKET_UNSLASHABLE]: `NcnVaultTicketUnslashable`,
     [JITO_VAULT_ERROR__OPERATOR_OVERFLOW]: `OperatorOverflow`,
+    [JITO_VAULT_ERROR__OPERATOR_STAKE_WEIGHT_VAULT_ALREADY_COUNTED]: `OperatorStakeWeightVaultAlreadyCounted`,
+    [JITO_VAULT_ERROR__OPERATOR_STAKE_WEIGHT_VAULT_INDEX_INVALID]: `OperatorStakeWeightVaultIndexInvalid`,
+    [JITO_VAULT_ERROR__OPERATOR_STAKE_WEIGHT_VAULT_NCN_INACTIVE]: `OperatorStakeWeightVaultNcnInactive`,
     [JITO_VAULT_ERROR__OPERATOR_VAULT_TICKET_UNSLASHABLE]: `OperatorVaultTicketUnslashable`,
     [JITO_VAULT_ERROR__SLASHER_OVERFLOW]: `SlasherOverflow`,
     [JITO_VAULT_ERROR__SLIPPAGE_ERROR]: `SlippageError`,
+    [JITO_VAULT_ERROR__VAULT_ACCOUNT_DATA_EMPTY]: `VaultAccountDataEmpty`,
+    [JITO_VAULT_ERROR__VAULT_ACCOUNT_DISCRIMINATOR_INVALID]: `VaultAccountDiscriminatorInvalid`,
+    [JITO_VAULT_ERROR__VAULT_ACCOUNT_NOT_CANONICAL_PDA]: `VaultAccountNotCanonicalPda`,
+    [JITO_VAULT_ERROR__VAULT_ACCOUNT_NOT_WRITABLE]: `VaultAccountNotWritable`,
+    [JITO_VAULT_ERROR__VAULT_ACCOUNT_VERSION_INVALID]: `VaultAccountVersionInvalid`,
+    [JITO_VAULT_ERROR__VAULT_ADMIN_BURN_ALREADY_ENABLED]: `VaultAdminBurnAlreadyEnabled`,
+    [JITO_VAULT_ERROR__VAULT_ADMIN_BURN_ENABLE_AFTER_DEPOSIT]: `VaultAdminBurnEnableAfterDeposit`,
+    [JITO_VAULT_ERROR__VAULT_ADMIN_BURN_NOT_ENABLED]: `VaultAdminBurnNotEnabled`,
+    [JITO_VAULT_ERROR__VAULT_ADMIN_BURN_NOT_PENDING]: `VaultAdminBurnNotPending`,
+    [JITO_VAULT_ERROR__VAULT_ADMIN_BURN_PENDING]: `VaultAdminBurnPending`,
+    [JITO_VAULT_ERROR__VAULT_ADMIN_BURN_TIMELOCK_ACTIVE]: `VaultAdminBurnTimelockActive`,
     [JITO_VAULT_ERROR__VAULT_ADMIN_INVALID]: `VaultAdminInvalid`,
+    [JITO_VAULT_ERROR__VAULT_BALANCE_DIVERGED]: `VaultBalanceDiverged`,
+    [JITO_VAULT_ERROR__VAULT_BALANCE_NOT_DIVERGED]: `VaultBalanceNotDiverged`,
+    [JITO_VAULT_ERROR__VAULT_BURN_ADMIN_INVALID]: `VaultBurnAdminInvalid`,
+    [JITO_VAULT_ERROR__VAULT_BURN_DELEGATE_INVALID]: `VaultBurnDelegateInvalid`,
+    [JITO_VAULT_ERROR__VAULT_BURN_IN_DEPOSIT_SLOT]: `VaultBurnInDepositSlot`,
     [JITO_VAULT_ERROR__VAULT_BURN_ZERO]: `VaultBurnZero`,
     [JITO_VAULT_ERROR__VAULT_CAPACITY_ADMIN_INVALID]: `VaultCapacityAdminInvalid`,
     [JITO_VAULT_ERROR__VAULT_CAPACITY_EXCEEDED]: `VaultCapacityExceeded`,
     [JITO_VAULT_ERROR__VAULT_COOLDOWN_ZERO]: `VaultCooldownZero`,
+    [JITO_VAULT_ERROR__VAULT_DELEGATION_ABOVE_MAXIMUM]: `VaultDelegationAboveMaximum`,
     [JITO_VAULT_ERROR__VAULT_DELEGATION_ADMIN_INVALID]: `VaultDelegationAdminInvalid`,
+    [JITO_VAULT_ERROR__VAULT_DELEGATION_BELOW_MINIMUM]: `VaultDelegationBelowMinimum`,
+    [JITO_VAULT_ERROR__VAULT_DELEGATION_NOT_APPROVED]: `VaultDelegationNotApproved`,
+    [JITO_VAULT_ERROR__VAULT_DELEGATION_STRATEGY_MOVEMENT_EXCEEDED]: `VaultDelegationStrategyMovementExceeded`,
     [JITO_VAULT_ERROR__VAULT_DELEGATION_ZERO]: `VaultDelegationZero`,
+    [JITO_VAULT_ERROR__VAULT_DEPOSIT_NOT_ALLOWED]: `VaultDepositNotAllowed`,
+    [JITO_VAULT_ERROR__VAULT_DEPOSIT_PER_TRANSACTION_LIMIT_EXCEEDED]: `VaultDepositPerTransactionLimitExceeded`,
+    [JITO_VAULT_ERROR__VAULT_DEPOSIT_PER_WALLET_LIMIT_EXCEEDED]: `VaultDepositPerWalletLimitExceeded`,
     [JITO_VAULT_ERROR__VAULT_ENQUEUE_WITHDRAWAL_AMOUNT_ZERO]: `VaultEnqueueWithdrawalAmountZero`,
+    [JITO_VAULT_ERROR__VAULT_EPOCH_LENGTH_COOLDOWN_IN_PROGRESS]: `VaultEpochLengthCooldownInProgress`,
+    [JITO_VAULT_ERROR__VAULT_EPOCH_LENGTH_INVALID]: `VaultEpochLengthInvalid`,
     [JITO_VAULT_ERROR__VAULT_FEE_ADMIN_INVALID]: `VaultFeeAdminInvalid`,
     [JITO_VAULT_ERROR__VAULT_FEE_BUMP_TOO_LARGE]: `VaultFeeBumpTooLarge`,
     [JITO_VAULT_ERROR__VAULT_FEE_CAP_EXCEEDED]: `VaultFeeCapExceeded`,
     [JITO_VAULT_ERROR__VAULT_FEE_CHANGE_TOO_SOON]: `VaultFeeChangeTooSoon`,
+    [JITO_VAULT_ERROR__VAULT_HOOK_PROGRAM_INVALID]: `VaultHookProgramInvalid`,
     [JITO_VAULT_ERROR__VAULT_INSUFFICIENT_FUNDS]: `VaultInsufficientFunds`,
+    [JITO_VAULT_ERROR__VAULT_IS_PAUSED]: `VaultIsPaused`,
+    [JITO_VAULT_ERROR__VAULT_IS_SHUT_DOWN]: `VaultIsShutDown`,
     [JITO_VAULT_ERROR__VAULT_IS_UPDATED]: `VaultIsUpdated`,
     [JITO_VAULT_ERROR__VAULT_MAX_SLASHED_PER_OPERATOR_EXCEEDED]: `VaultMaxSlashedPerOperatorExceeded`,
+    [JITO_VAULT_ERROR__VAULT_MAX_TOTAL_SLASHABLE_BPS_INVALID]: `VaultMaxTotalSlashableBpsInvalid`,
+    [JITO_VAULT_ERROR__VAULT_MAX_TOTAL_SLASHED_PER_EPOCH_EXCEEDED]: `VaultMaxTotalSlashedPerEpochExceeded`,
+    [JITO_VAULT_ERROR__VAULT_METADATA_ACCOUNT_INVALID]: `VaultMetadataAccountInvalid`,
+    [JITO_VAULT_ERROR__VAULT_METADATA_ADMIN_INVALID]: `VaultMetadataAdminInvalid`,
+    [JITO_VAULT_ERROR__VAULT_MIGRATION_INVALID]: `VaultMigrationInvalid`,
+    [JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_HANDOFF_CHALLENGE_WINDOW_ACTIVE]: `VaultMintAuthorityHandoffChallengeWindowActive`,
+    [JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_HANDOFF_NOT_PENDING]: `VaultMintAuthorityHandoffNotPending`,
+    [JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_HANDOFF_PENDING]: `VaultMintAuthorityHandoffPending`,
+    [JITO_VAULT_ERROR__VAULT_MINT_AUTHORITY_SUCCESSOR_INVALID]: `VaultMintAuthoritySuccessorInvalid`,
     [JITO_VAULT_ERROR__VAULT_MINT_BURN_ADMIN_INVALID]: `VaultMintBurnAdminInvalid`,
     [JITO_VAULT_ERROR__VAULT_MINT_ZERO]: `VaultMintZero`,
     [JITO_VAULT_ERROR__VAULT_NCN_ADMIN_INVALID]: `VaultNcnAdminInvalid`,
     [JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_FAILED_COOLDOWN]: `VaultNcnSlasherTicketFailedCooldown`,
     [JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_FAILED_WARMUP]: `VaultNcnSlasherTicketFailedWarmup`,
+    [JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_NOT_INACTIVE]: `VaultNcnSlasherTicketNotInactive`,
     [JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_UNSLASHABLE]: `VaultNcnSlasherTicketUnslashable`,
     [JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_COOLDOWN]: `VaultNcnTicketFailedCooldown`,
     [JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_WARMUP]: `VaultNcnTicketFailedWarmup`,
     [JITO_VAULT_ERROR__VAULT_NCN_TICKET_UNSLASHABLE]: `VaultNcnTicketUnslashable`,
+    [JITO_VAULT_ERROR__VAULT_NOT_EMPTY]: `VaultNotEmpty`,
     [JITO_VAULT_ERROR__VAULT_OPERATOR_ADMIN_INVALID]: `VaultOperatorAdminInvalid`,
+    [JITO_VAULT_ERROR__VAULT_OPERATOR_BANNED]: `VaultOperatorBanned`,
+    [JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATIONS_INCOMPLETE]: `VaultOperatorDelegationsIncomplete`,
+    [JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_NOT_CRANKED]: `VaultOperatorDelegationNotCranked`,
+    [JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_PAUSED]: `VaultOperatorDelegationPaused`,
+    [JITO_VAULT_ERROR__VAULT_OPERATOR_FROZEN]: `VaultOperatorFrozen`,
+    [JITO_VAULT_ERROR__VAULT_OPERATOR_NOT_BANNED]: `VaultOperatorNotBanned`,
     [JITO_VAULT_ERROR__VAULT_OVERFLOW]: `VaultOverflow`,
+    [JITO_VAULT_ERROR__VAULT_PAUSE_ADMIN_INVALID]: `VaultPauseAdminInvalid`,
+    [JITO_VAULT_ERROR__VAULT_RECONCILE_ADJUSTMENT_EXCEEDED]: `VaultReconcileAdjustmentExceeded`,
+    [JITO_VAULT_ERROR__VAULT_REWARDS_EXCEEDED]: `VaultRewardsExceeded`,
+    [JITO_VAULT_ERROR__VAULT_REWARD_MINT_INVALID]: `VaultRewardMintInvalid`,
+    [JITO_VAULT_ERROR__VAULT_REWARD_PROOF_INVALID]: `VaultRewardProofInvalid`,
     [JITO_VAULT_ERROR__VAULT_SECURITY_OVERFLOW]: `VaultSecurityOverflow`,
     [JITO_VAULT_ERROR__VAULT_SECURITY_UNDERFLOW]: `VaultSecurityUnderflow`,
     [JITO_VAULT_ERROR__VAULT_SLASHER_ADMIN_INVALID]: `VaultSlasherAdminInvalid`,
+    [JITO_VAULT_ERROR__VAULT_SLASH_DESTINATION_INVALID]: `VaultSlashDestinationInvalid`,
     [JITO_VAULT_ERROR__VAULT_SLASH_INCOMPLETE]: `VaultSlashIncomplete`,
+    [JITO_VAULT_ERROR__VAULT_SLASH_PROPOSAL_INVALID]: `VaultSlashProposalInvalid`,
+    [JITO_VAULT_ERROR__VAULT_SLASH_PROPOSAL_REQUIRED]: `VaultSlashProposalRequired`,
+    [JITO_VAULT_ERROR__VAULT_SLASH_PROPOSAL_VETO_WINDOW_ACTIVE]: `VaultSlashProposalVetoWindowActive`,
     [JITO_VAULT_ERROR__VAULT_SLASH_UNDERFLOW]: `VaultSlashUnderflow`,
     [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_STAKER]: `VaultStakerWithdrawalTicketInvalidStaker`,
     [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_NOT_WITHDRAWABLE]: `VaultStakerWithdrawalTicketNotWithdrawable`,
+    [JITO_VAULT_ERROR__VAULT_SUPPORTED_MINT_INVALID]: `VaultSupportedMintInvalid`,
+    [JITO_VAULT_ERROR__VAULT_TOKEN_ACCOUNT_CLOSED]: `VaultTokenAccountClosed`,
     [JITO_VAULT_ERROR__VAULT_UNDERFLOW]: `VaultUnderflow`,
     [JITO_VAULT_ERROR__VAULT_UPDATE_INCORRECT_INDEX]: `VaultUpdateIncorrectIndex`,
     [JITO_VAULT_ERROR__VAULT_UPDATE_NEEDED]: `VaultUpdateNeeded`,
     [JITO_VAULT_ERROR__VAULT_UPDATE_STATE_NOT_FINISHED_UPDATING]: `VaultUpdateStateNotFinishedUpdating`,
+    [JITO_VAULT_ERROR__VAULT_VRT_MINT_INVALID]: `VaultVrtMintInvalid`,
+    [JITO_VAULT_ERROR__VAULT_WITHDRAWAL_DELAY_ACTIVE]: `VaultWithdrawalDelayActive`,
+    [JITO_VAULT_ERROR__VAULT_WITHDRAWAL_DELAY_INVALID]: `VaultWithdrawalDelayInvalid`,
   };
 }
 
@@ -29,8 +29,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultOperatorDelegationPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const ADD_DELEGATION_DISCRIMINATOR = 21;
 
@@ -47,6 +52,7 @@ export type AddDelegationInstruction<
     | string
     | IAccountMeta<string> = string,
   TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountOperatorVaultTicket extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -68,6 +74,9 @@ export type AddDelegationInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             IAccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountOperatorVaultTicket extends string
+        ? ReadonlyAccount<TAccountOperatorVaultTicket>
+        : TAccountOperatorVaultTicket,
       ...TRemainingAccounts,
     ]
   >;
@@ -106,18 +115,131 @@ export function getAddDelegationInstructionDataCodec(): Codec<
   );
 }
 
+export type AddDelegationAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  admin: TransactionSigner<TAccountAdmin>;
+  /** Required when the operator requires approval of delegations */
+  operatorVaultTicket?: Address<TAccountOperatorVaultTicket>;
+  amount: AddDelegationInstructionDataArgs['amount'];
+};
+
+export async function getAddDelegationInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountAdmin extends string,
+  TAccountOperatorVaultTicket extends string,
+>(
+  input: AddDelegationAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin,
+    TAccountOperatorVaultTicket
+  >
+): Promise<
+  AddDelegationInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin,
+    TAccountOperatorVaultTicket
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.operatorVaultTicket),
+    ],
+    programAddress,
+    data: getAddDelegationInstructionDataEncoder().encode(
+      args as AddDelegationInstructionDataArgs
+    ),
+  } as AddDelegationInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin,
+    TAccountOperatorVaultTicket
+  >;
+
+  return instruction;
+}
+
 export type AddDelegationInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
   TAccountOperator extends string = string,
   TAccountVaultOperatorDelegation extends string = string,
   TAccountAdmin extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
 > = {
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
   operator: Address<TAccountOperator>;
   vaultOperatorDelegation: Address<TAccountVaultOperatorDelegation>;
   admin: TransactionSigner<TAccountAdmin>;
+  /** Required when the operator requires approval of delegations */
+  operatorVaultTicket?: Address<TAccountOperatorVaultTicket>;
   amount: AddDelegationInstructionDataArgs['amount'];
 };
 
@@ -127,13 +249,15 @@ export function getAddDelegationInstruction<
   TAccountOperator extends string,
   TAccountVaultOperatorDelegation extends string,
   TAccountAdmin extends string,
+  TAccountOperatorVaultTicket extends string,
 >(
   input: AddDelegationInput<
     TAccountConfig,
     TAccountVault,
     TAccountOperator,
     TAccountVaultOperatorDelegation,
-    TAccountAdmin
+    TAccountAdmin,
+    TAccountOperatorVaultTicket
   >
 ): AddDelegationInstruction<
   typeof JITO_VAULT_PROGRAM_ADDRESS,
@@ -141,7 +265,8 @@ export function getAddDelegationInstruction<
   TAccountVault,
   TAccountOperator,
   TAccountVaultOperatorDelegation,
-  TAccountAdmin
+  TAccountAdmin,
+  TAccountOperatorVaultTicket
 > {
   // Program address.
   const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
@@ -156,6 +281,10 @@ export function getAddDelegationInstruction<
       isWritable: true,
     },
     admin: { value: input.admin ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -173,6 +302,7 @@ export function getAddDelegationInstruction<
       getAccountMeta(accounts.operator),
       getAccountMeta(accounts.vaultOperatorDelegation),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.operatorVaultTicket),
     ],
     programAddress,
     data: getAddDelegationInstructionDataEncoder().encode(
@@ -184,7 +314,8 @@ export function getAddDelegationInstruction<
     TAccountVault,
     TAccountOperator,
     TAccountVaultOperatorDelegation,
-    TAccountAdmin
+    TAccountAdmin,
+    TAccountOperatorVaultTicket
   >;
 
   return instruction;
@@ -201,6 +332,8 @@ export type ParsedAddDelegationInstruction<
     operator: TAccountMetas[2];
     vaultOperatorDelegation: TAccountMetas[3];
     admin: TAccountMetas[4];
+    /** Required when the operator requires approval of delegations */
+    operatorVaultTicket?: TAccountMetas[5] | undefined;
   };
   data: AddDelegationInstructionData;
 };
@@ -213,7 +346,7 @@ export function parseAddDelegationInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedAddDelegationInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -223,6 +356,12 @@ export function parseAddDelegationInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_VAULT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -231,6 +370,7 @@ export function parseAddDelegationInstruction<
       operator: getNextAccount(),
       vaultOperatorDelegation: getNextAccount(),
       admin: getNextAccount(),
+      operatorVaultTicket: getNextOptionalAccount(),
     },
     data: getAddDelegationInstructionDataDecoder().decode(instruction.data),
   };
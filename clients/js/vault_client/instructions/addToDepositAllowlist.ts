@@ -0,0 +1,380 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findVaultDepositAllowlistEntryPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const ADD_TO_DEPOSIT_ALLOWLIST_DISCRIMINATOR = 35;
+
+export function getAddToDepositAllowlistDiscriminatorBytes() {
+  return getU8Encoder().encode(ADD_TO_DEPOSIT_ALLOWLIST_DISCRIMINATOR);
+}
+
+export type AddToDepositAllowlistInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountDepositor extends string | IAccountMeta<string> = string,
+  TAccountVaultDepositAllowlistEntry extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountDepositor extends string
+        ? ReadonlyAccount<TAccountDepositor>
+        : TAccountDepositor,
+      TAccountVaultDepositAllowlistEntry extends string
+        ? WritableAccount<TAccountVaultDepositAllowlistEntry>
+        : TAccountVaultDepositAllowlistEntry,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AddToDepositAllowlistInstructionData = { discriminator: number };
+
+export type AddToDepositAllowlistInstructionDataArgs = {};
+
+export function getAddToDepositAllowlistInstructionDataEncoder(): Encoder<AddToDepositAllowlistInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: ADD_TO_DEPOSIT_ALLOWLIST_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAddToDepositAllowlistInstructionDataDecoder(): Decoder<AddToDepositAllowlistInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getAddToDepositAllowlistInstructionDataCodec(): Codec<
+  AddToDepositAllowlistInstructionDataArgs,
+  AddToDepositAllowlistInstructionData
+> {
+  return combineCodec(
+    getAddToDepositAllowlistInstructionDataEncoder(),
+    getAddToDepositAllowlistInstructionDataDecoder()
+  );
+}
+
+export type AddToDepositAllowlistAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountDepositor extends string = string,
+  TAccountVaultDepositAllowlistEntry extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  depositor: Address<TAccountDepositor>;
+  vaultDepositAllowlistEntry?: Address<TAccountVaultDepositAllowlistEntry>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getAddToDepositAllowlistInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountDepositor extends string,
+  TAccountVaultDepositAllowlistEntry extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: AddToDepositAllowlistAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountDepositor,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  AddToDepositAllowlistInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountDepositor,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    depositor: { value: input.depositor ?? null, isWritable: false },
+    vaultDepositAllowlistEntry: {
+      value: input.vaultDepositAllowlistEntry ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultDepositAllowlistEntry.value) {
+    accounts.vaultDepositAllowlistEntry.value =
+      await findVaultDepositAllowlistEntryPda({
+        vault: expectAddress(accounts.vault.value),
+        depositor: expectAddress(accounts.depositor.value),
+      });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.depositor),
+      getAccountMeta(accounts.vaultDepositAllowlistEntry),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getAddToDepositAllowlistInstructionDataEncoder().encode({}),
+  } as AddToDepositAllowlistInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountDepositor,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type AddToDepositAllowlistInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountDepositor extends string = string,
+  TAccountVaultDepositAllowlistEntry extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  depositor: Address<TAccountDepositor>;
+  vaultDepositAllowlistEntry: Address<TAccountVaultDepositAllowlistEntry>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getAddToDepositAllowlistInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountDepositor extends string,
+  TAccountVaultDepositAllowlistEntry extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: AddToDepositAllowlistInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountDepositor,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): AddToDepositAllowlistInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountDepositor,
+  TAccountVaultDepositAllowlistEntry,
+  TAccountAdmin,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    depositor: { value: input.depositor ?? null, isWritable: false },
+    vaultDepositAllowlistEntry: {
+      value: input.vaultDepositAllowlistEntry ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.depositor),
+      getAccountMeta(accounts.vaultDepositAllowlistEntry),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getAddToDepositAllowlistInstructionDataEncoder().encode({}),
+  } as AddToDepositAllowlistInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountDepositor,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedAddToDepositAllowlistInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    depositor: TAccountMetas[2];
+    vaultDepositAllowlistEntry: TAccountMetas[3];
+    admin: TAccountMetas[4];
+    payer: TAccountMetas[5];
+    systemProgram: TAccountMetas[6];
+  };
+  data: AddToDepositAllowlistInstructionData;
+};
+
+export function parseAddToDepositAllowlistInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAddToDepositAllowlistInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      depositor: getNextAccount(),
+      vaultDepositAllowlistEntry: getNextAccount(),
+      admin: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getAddToDepositAllowlistInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
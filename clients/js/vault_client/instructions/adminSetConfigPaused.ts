@@ -0,0 +1,247 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_CONFIG_PAUSED_DISCRIMINATOR = 61;
+
+export function getAdminSetConfigPausedDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_CONFIG_PAUSED_DISCRIMINATOR);
+}
+
+export type AdminSetConfigPausedInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetConfigPausedInstructionData = {
+  discriminator: number;
+  isPaused: boolean;
+};
+
+export type AdminSetConfigPausedInstructionDataArgs = { isPaused: boolean };
+
+export function getAdminSetConfigPausedInstructionDataEncoder(): Encoder<AdminSetConfigPausedInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['isPaused', getBooleanEncoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_CONFIG_PAUSED_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetConfigPausedInstructionDataDecoder(): Decoder<AdminSetConfigPausedInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['isPaused', getBooleanDecoder()],
+  ]);
+}
+
+export function getAdminSetConfigPausedInstructionDataCodec(): Codec<
+  AdminSetConfigPausedInstructionDataArgs,
+  AdminSetConfigPausedInstructionData
+> {
+  return combineCodec(
+    getAdminSetConfigPausedInstructionDataEncoder(),
+    getAdminSetConfigPausedInstructionDataDecoder()
+  );
+}
+
+export type AdminSetConfigPausedAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  isPaused: AdminSetConfigPausedInstructionDataArgs['isPaused'];
+};
+
+export async function getAdminSetConfigPausedInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: AdminSetConfigPausedAsyncInput<TAccountConfig, TAccountAdmin>
+): Promise<
+  AdminSetConfigPausedInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getAdminSetConfigPausedInstructionDataEncoder().encode(
+      args as AdminSetConfigPausedInstructionDataArgs
+    ),
+  } as AdminSetConfigPausedInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type AdminSetConfigPausedInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  isPaused: AdminSetConfigPausedInstructionDataArgs['isPaused'];
+};
+
+export function getAdminSetConfigPausedInstruction<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: AdminSetConfigPausedInput<TAccountConfig, TAccountAdmin>
+): AdminSetConfigPausedInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getAdminSetConfigPausedInstructionDataEncoder().encode(
+      args as AdminSetConfigPausedInstructionDataArgs
+    ),
+  } as AdminSetConfigPausedInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetConfigPausedInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    admin: TAccountMetas[1];
+  };
+  data: AdminSetConfigPausedInstructionData;
+};
+
+export function parseAdminSetConfigPausedInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetConfigPausedInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getAdminSetConfigPausedInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
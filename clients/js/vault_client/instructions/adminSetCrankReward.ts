@@ -0,0 +1,257 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_CRANK_REWARD_DISCRIMINATOR = 73;
+
+export function getAdminSetCrankRewardDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_CRANK_REWARD_DISCRIMINATOR);
+}
+
+export type AdminSetCrankRewardInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetCrankRewardInstructionData = {
+  discriminator: number;
+  crankRewardLamports: bigint;
+  crankRewardBps: number;
+};
+
+export type AdminSetCrankRewardInstructionDataArgs = {
+  crankRewardLamports: number | bigint;
+  crankRewardBps: number;
+};
+
+export function getAdminSetCrankRewardInstructionDataEncoder(): Encoder<AdminSetCrankRewardInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['crankRewardLamports', getU64Encoder()],
+      ['crankRewardBps', getU16Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_CRANK_REWARD_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetCrankRewardInstructionDataDecoder(): Decoder<AdminSetCrankRewardInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['crankRewardLamports', getU64Decoder()],
+    ['crankRewardBps', getU16Decoder()],
+  ]);
+}
+
+export function getAdminSetCrankRewardInstructionDataCodec(): Codec<
+  AdminSetCrankRewardInstructionDataArgs,
+  AdminSetCrankRewardInstructionData
+> {
+  return combineCodec(
+    getAdminSetCrankRewardInstructionDataEncoder(),
+    getAdminSetCrankRewardInstructionDataDecoder()
+  );
+}
+
+export type AdminSetCrankRewardAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  crankRewardLamports: AdminSetCrankRewardInstructionDataArgs['crankRewardLamports'];
+  crankRewardBps: AdminSetCrankRewardInstructionDataArgs['crankRewardBps'];
+};
+
+export async function getAdminSetCrankRewardInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: AdminSetCrankRewardAsyncInput<TAccountConfig, TAccountAdmin>
+): Promise<
+  AdminSetCrankRewardInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getAdminSetCrankRewardInstructionDataEncoder().encode(
+      args as AdminSetCrankRewardInstructionDataArgs
+    ),
+  } as AdminSetCrankRewardInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type AdminSetCrankRewardInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  crankRewardLamports: AdminSetCrankRewardInstructionDataArgs['crankRewardLamports'];
+  crankRewardBps: AdminSetCrankRewardInstructionDataArgs['crankRewardBps'];
+};
+
+export function getAdminSetCrankRewardInstruction<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: AdminSetCrankRewardInput<TAccountConfig, TAccountAdmin>
+): AdminSetCrankRewardInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getAdminSetCrankRewardInstructionDataEncoder().encode(
+      args as AdminSetCrankRewardInstructionDataArgs
+    ),
+  } as AdminSetCrankRewardInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetCrankRewardInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    admin: TAccountMetas[1];
+  };
+  data: AdminSetCrankRewardInstructionData;
+};
+
+export function parseAdminSetCrankRewardInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetCrankRewardInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getAdminSetCrankRewardInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,249 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_DELEGATION_COOLDOWN_DISCRIMINATOR = 42;
+
+export function getAdminSetDelegationCooldownDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_DELEGATION_COOLDOWN_DISCRIMINATOR);
+}
+
+export type AdminSetDelegationCooldownInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetDelegationCooldownInstructionData = {
+  discriminator: number;
+  delegationCooldownEpochs: bigint;
+};
+
+export type AdminSetDelegationCooldownInstructionDataArgs = {
+  delegationCooldownEpochs: number | bigint;
+};
+
+export function getAdminSetDelegationCooldownInstructionDataEncoder(): Encoder<AdminSetDelegationCooldownInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['delegationCooldownEpochs', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_DELEGATION_COOLDOWN_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetDelegationCooldownInstructionDataDecoder(): Decoder<AdminSetDelegationCooldownInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['delegationCooldownEpochs', getU64Decoder()],
+  ]);
+}
+
+export function getAdminSetDelegationCooldownInstructionDataCodec(): Codec<
+  AdminSetDelegationCooldownInstructionDataArgs,
+  AdminSetDelegationCooldownInstructionData
+> {
+  return combineCodec(
+    getAdminSetDelegationCooldownInstructionDataEncoder(),
+    getAdminSetDelegationCooldownInstructionDataDecoder()
+  );
+}
+
+export type AdminSetDelegationCooldownAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  delegationCooldownEpochs: AdminSetDelegationCooldownInstructionDataArgs['delegationCooldownEpochs'];
+};
+
+export async function getAdminSetDelegationCooldownInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: AdminSetDelegationCooldownAsyncInput<TAccountConfig, TAccountAdmin>
+): Promise<
+  AdminSetDelegationCooldownInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getAdminSetDelegationCooldownInstructionDataEncoder().encode(
+      args as AdminSetDelegationCooldownInstructionDataArgs
+    ),
+  } as AdminSetDelegationCooldownInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type AdminSetDelegationCooldownInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  delegationCooldownEpochs: AdminSetDelegationCooldownInstructionDataArgs['delegationCooldownEpochs'];
+};
+
+export function getAdminSetDelegationCooldownInstruction<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: AdminSetDelegationCooldownInput<TAccountConfig, TAccountAdmin>
+): AdminSetDelegationCooldownInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getAdminSetDelegationCooldownInstructionDataEncoder().encode(
+      args as AdminSetDelegationCooldownInstructionDataArgs
+    ),
+  } as AdminSetDelegationCooldownInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetDelegationCooldownInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    admin: TAccountMetas[1];
+  };
+  data: AdminSetDelegationCooldownInstructionData;
+};
+
+export function parseAdminSetDelegationCooldownInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetDelegationCooldownInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getAdminSetDelegationCooldownInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,288 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_PROTOCOL_FEE_DISCRIMINATOR = 32;
+
+export function getAdminSetProtocolFeeDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_PROTOCOL_FEE_DISCRIMINATOR);
+}
+
+export type AdminSetProtocolFeeInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountNewProtocolFeeWallet extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountNewProtocolFeeWallet extends string
+        ? ReadonlyAccount<TAccountNewProtocolFeeWallet>
+        : TAccountNewProtocolFeeWallet,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetProtocolFeeInstructionData = {
+  discriminator: number;
+  protocolFeeBps: number;
+};
+
+export type AdminSetProtocolFeeInstructionDataArgs = { protocolFeeBps: number };
+
+export function getAdminSetProtocolFeeInstructionDataEncoder(): Encoder<AdminSetProtocolFeeInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['protocolFeeBps', getU16Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_PROTOCOL_FEE_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetProtocolFeeInstructionDataDecoder(): Decoder<AdminSetProtocolFeeInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['protocolFeeBps', getU16Decoder()],
+  ]);
+}
+
+export function getAdminSetProtocolFeeInstructionDataCodec(): Codec<
+  AdminSetProtocolFeeInstructionDataArgs,
+  AdminSetProtocolFeeInstructionData
+> {
+  return combineCodec(
+    getAdminSetProtocolFeeInstructionDataEncoder(),
+    getAdminSetProtocolFeeInstructionDataDecoder()
+  );
+}
+
+export type AdminSetProtocolFeeAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountNewProtocolFeeWallet extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  newProtocolFeeWallet: Address<TAccountNewProtocolFeeWallet>;
+  protocolFeeBps: AdminSetProtocolFeeInstructionDataArgs['protocolFeeBps'];
+};
+
+export async function getAdminSetProtocolFeeInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+  TAccountNewProtocolFeeWallet extends string,
+>(
+  input: AdminSetProtocolFeeAsyncInput<
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewProtocolFeeWallet
+  >
+): Promise<
+  AdminSetProtocolFeeInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewProtocolFeeWallet
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    newProtocolFeeWallet: {
+      value: input.newProtocolFeeWallet ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.newProtocolFeeWallet),
+    ],
+    programAddress,
+    data: getAdminSetProtocolFeeInstructionDataEncoder().encode(
+      args as AdminSetProtocolFeeInstructionDataArgs
+    ),
+  } as AdminSetProtocolFeeInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewProtocolFeeWallet
+  >;
+
+  return instruction;
+}
+
+export type AdminSetProtocolFeeInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountNewProtocolFeeWallet extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  newProtocolFeeWallet: Address<TAccountNewProtocolFeeWallet>;
+  protocolFeeBps: AdminSetProtocolFeeInstructionDataArgs['protocolFeeBps'];
+};
+
+export function getAdminSetProtocolFeeInstruction<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+  TAccountNewProtocolFeeWallet extends string,
+>(
+  input: AdminSetProtocolFeeInput<
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewProtocolFeeWallet
+  >
+): AdminSetProtocolFeeInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountAdmin,
+  TAccountNewProtocolFeeWallet
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    newProtocolFeeWallet: {
+      value: input.newProtocolFeeWallet ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.newProtocolFeeWallet),
+    ],
+    programAddress,
+    data: getAdminSetProtocolFeeInstructionDataEncoder().encode(
+      args as AdminSetProtocolFeeInstructionDataArgs
+    ),
+  } as AdminSetProtocolFeeInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewProtocolFeeWallet
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetProtocolFeeInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    admin: TAccountMetas[1];
+    newProtocolFeeWallet: TAccountMetas[2];
+  };
+  data: AdminSetProtocolFeeInstructionData;
+};
+
+export function parseAdminSetProtocolFeeInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetProtocolFeeInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+      newProtocolFeeWallet: getNextAccount(),
+    },
+    data: getAdminSetProtocolFeeInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,285 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_SLASH_VETO_DISCRIMINATOR = 43;
+
+export function getAdminSetSlashVetoDiscriminatorBytes() {
+  return getU8Encoder().encode(ADMIN_SET_SLASH_VETO_DISCRIMINATOR);
+}
+
+export type AdminSetSlashVetoInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountNewSlashVetoAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountNewSlashVetoAdmin extends string
+        ? ReadonlyAccount<TAccountNewSlashVetoAdmin>
+        : TAccountNewSlashVetoAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetSlashVetoInstructionData = {
+  discriminator: number;
+  slashVetoWindowSlots: bigint;
+};
+
+export type AdminSetSlashVetoInstructionDataArgs = {
+  slashVetoWindowSlots: number | bigint;
+};
+
+export function getAdminSetSlashVetoInstructionDataEncoder(): Encoder<AdminSetSlashVetoInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['slashVetoWindowSlots', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: ADMIN_SET_SLASH_VETO_DISCRIMINATOR })
+  );
+}
+
+export function getAdminSetSlashVetoInstructionDataDecoder(): Decoder<AdminSetSlashVetoInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['slashVetoWindowSlots', getU64Decoder()],
+  ]);
+}
+
+export function getAdminSetSlashVetoInstructionDataCodec(): Codec<
+  AdminSetSlashVetoInstructionDataArgs,
+  AdminSetSlashVetoInstructionData
+> {
+  return combineCodec(
+    getAdminSetSlashVetoInstructionDataEncoder(),
+    getAdminSetSlashVetoInstructionDataDecoder()
+  );
+}
+
+export type AdminSetSlashVetoAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountNewSlashVetoAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  newSlashVetoAdmin: Address<TAccountNewSlashVetoAdmin>;
+  slashVetoWindowSlots: AdminSetSlashVetoInstructionDataArgs['slashVetoWindowSlots'];
+};
+
+export async function getAdminSetSlashVetoInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+  TAccountNewSlashVetoAdmin extends string,
+>(
+  input: AdminSetSlashVetoAsyncInput<
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewSlashVetoAdmin
+  >
+): Promise<
+  AdminSetSlashVetoInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewSlashVetoAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    newSlashVetoAdmin: {
+      value: input.newSlashVetoAdmin ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.newSlashVetoAdmin),
+    ],
+    programAddress,
+    data: getAdminSetSlashVetoInstructionDataEncoder().encode(
+      args as AdminSetSlashVetoInstructionDataArgs
+    ),
+  } as AdminSetSlashVetoInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewSlashVetoAdmin
+  >;
+
+  return instruction;
+}
+
+export type AdminSetSlashVetoInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountNewSlashVetoAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  newSlashVetoAdmin: Address<TAccountNewSlashVetoAdmin>;
+  slashVetoWindowSlots: AdminSetSlashVetoInstructionDataArgs['slashVetoWindowSlots'];
+};
+
+export function getAdminSetSlashVetoInstruction<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+  TAccountNewSlashVetoAdmin extends string,
+>(
+  input: AdminSetSlashVetoInput<
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewSlashVetoAdmin
+  >
+): AdminSetSlashVetoInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountAdmin,
+  TAccountNewSlashVetoAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    newSlashVetoAdmin: {
+      value: input.newSlashVetoAdmin ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.newSlashVetoAdmin),
+    ],
+    programAddress,
+    data: getAdminSetSlashVetoInstructionDataEncoder().encode(
+      args as AdminSetSlashVetoInstructionDataArgs
+    ),
+  } as AdminSetSlashVetoInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin,
+    TAccountNewSlashVetoAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetSlashVetoInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    admin: TAccountMetas[1];
+    newSlashVetoAdmin: TAccountMetas[2];
+  };
+  data: AdminSetSlashVetoInstructionData;
+};
+
+export function parseAdminSetSlashVetoInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetSlashVetoInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+      newSlashVetoAdmin: getNextAccount(),
+    },
+    data: getAdminSetSlashVetoInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,257 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ADMIN_SET_VAULT_EPOCH_LENGTH_BOUNDS_DISCRIMINATOR = 84;
+
+export function getAdminSetVaultEpochLengthBoundsDiscriminatorBytes() {
+  return getU8Encoder().encode(
+    ADMIN_SET_VAULT_EPOCH_LENGTH_BOUNDS_DISCRIMINATOR
+  );
+}
+
+export type AdminSetVaultEpochLengthBoundsInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AdminSetVaultEpochLengthBoundsInstructionData = {
+  discriminator: number;
+  minVaultEpochLength: bigint;
+  maxVaultEpochLength: bigint;
+};
+
+export type AdminSetVaultEpochLengthBoundsInstructionDataArgs = {
+  minVaultEpochLength: number | bigint;
+  maxVaultEpochLength: number | bigint;
+};
+
+export function getAdminSetVaultEpochLengthBoundsInstructionDataEncoder(): Encoder<AdminSetVaultEpochLengthBoundsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['minVaultEpochLength', getU64Encoder()],
+      ['maxVaultEpochLength', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADMIN_SET_VAULT_EPOCH_LENGTH_BOUNDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getAdminSetVaultEpochLengthBoundsInstructionDataDecoder(): Decoder<AdminSetVaultEpochLengthBoundsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['minVaultEpochLength', getU64Decoder()],
+    ['maxVaultEpochLength', getU64Decoder()],
+  ]);
+}
+
+export function getAdminSetVaultEpochLengthBoundsInstructionDataCodec(): Codec<
+  AdminSetVaultEpochLengthBoundsInstructionDataArgs,
+  AdminSetVaultEpochLengthBoundsInstructionData
+> {
+  return combineCodec(
+    getAdminSetVaultEpochLengthBoundsInstructionDataEncoder(),
+    getAdminSetVaultEpochLengthBoundsInstructionDataDecoder()
+  );
+}
+
+export type AdminSetVaultEpochLengthBoundsAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  minVaultEpochLength: AdminSetVaultEpochLengthBoundsInstructionDataArgs['minVaultEpochLength'];
+  maxVaultEpochLength: AdminSetVaultEpochLengthBoundsInstructionDataArgs['maxVaultEpochLength'];
+};
+
+export async function getAdminSetVaultEpochLengthBoundsInstructionAsync<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: AdminSetVaultEpochLengthBoundsAsyncInput<TAccountConfig, TAccountAdmin>
+): Promise<
+  AdminSetVaultEpochLengthBoundsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getAdminSetVaultEpochLengthBoundsInstructionDataEncoder().encode(
+      args as AdminSetVaultEpochLengthBoundsInstructionDataArgs
+    ),
+  } as AdminSetVaultEpochLengthBoundsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type AdminSetVaultEpochLengthBoundsInput<
+  TAccountConfig extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  admin: TransactionSigner<TAccountAdmin>;
+  minVaultEpochLength: AdminSetVaultEpochLengthBoundsInstructionDataArgs['minVaultEpochLength'];
+  maxVaultEpochLength: AdminSetVaultEpochLengthBoundsInstructionDataArgs['maxVaultEpochLength'];
+};
+
+export function getAdminSetVaultEpochLengthBoundsInstruction<
+  TAccountConfig extends string,
+  TAccountAdmin extends string,
+>(
+  input: AdminSetVaultEpochLengthBoundsInput<TAccountConfig, TAccountAdmin>
+): AdminSetVaultEpochLengthBoundsInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [getAccountMeta(accounts.config), getAccountMeta(accounts.admin)],
+    programAddress,
+    data: getAdminSetVaultEpochLengthBoundsInstructionDataEncoder().encode(
+      args as AdminSetVaultEpochLengthBoundsInstructionDataArgs
+    ),
+  } as AdminSetVaultEpochLengthBoundsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedAdminSetVaultEpochLengthBoundsInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    admin: TAccountMetas[1];
+  };
+  data: AdminSetVaultEpochLengthBoundsInstructionData;
+};
+
+export function parseAdminSetVaultEpochLengthBoundsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAdminSetVaultEpochLengthBoundsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getAdminSetVaultEpochLengthBoundsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,338 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findVaultOperatorDelegationPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const BAN_OPERATOR_DISCRIMINATOR = 86;
+
+export function getBanOperatorDiscriminatorBytes() {
+  return getU8Encoder().encode(BAN_OPERATOR_DISCRIMINATOR);
+}
+
+export type BanOperatorInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountVaultOperatorDelegation extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountVaultOperatorDelegation extends string
+        ? WritableAccount<TAccountVaultOperatorDelegation>
+        : TAccountVaultOperatorDelegation,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type BanOperatorInstructionData = {
+  discriminator: number;
+  reason: number;
+};
+
+export type BanOperatorInstructionDataArgs = { reason: number };
+
+export function getBanOperatorInstructionDataEncoder(): Encoder<BanOperatorInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['reason', getU8Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: BAN_OPERATOR_DISCRIMINATOR })
+  );
+}
+
+export function getBanOperatorInstructionDataDecoder(): Decoder<BanOperatorInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['reason', getU8Decoder()],
+  ]);
+}
+
+export function getBanOperatorInstructionDataCodec(): Codec<
+  BanOperatorInstructionDataArgs,
+  BanOperatorInstructionData
+> {
+  return combineCodec(
+    getBanOperatorInstructionDataEncoder(),
+    getBanOperatorInstructionDataDecoder()
+  );
+}
+
+export type BanOperatorAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  admin: TransactionSigner<TAccountAdmin>;
+  reason: BanOperatorInstructionDataArgs['reason'];
+};
+
+export async function getBanOperatorInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountAdmin extends string,
+>(
+  input: BanOperatorAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin
+  >
+): Promise<
+  BanOperatorInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getBanOperatorInstructionDataEncoder().encode(
+      args as BanOperatorInstructionDataArgs
+    ),
+  } as BanOperatorInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type BanOperatorInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation: Address<TAccountVaultOperatorDelegation>;
+  admin: TransactionSigner<TAccountAdmin>;
+  reason: BanOperatorInstructionDataArgs['reason'];
+};
+
+export function getBanOperatorInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountAdmin extends string,
+>(
+  input: BanOperatorInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin
+  >
+): BanOperatorInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountOperator,
+  TAccountVaultOperatorDelegation,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getBanOperatorInstructionDataEncoder().encode(
+      args as BanOperatorInstructionDataArgs
+    ),
+  } as BanOperatorInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedBanOperatorInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    vaultOperatorDelegation: TAccountMetas[3];
+    admin: TAccountMetas[4];
+  };
+  data: BanOperatorInstructionData;
+};
+
+export function parseBanOperatorInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedBanOperatorInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      operator: getNextAccount(),
+      vaultOperatorDelegation: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getBanOperatorInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -29,6 +29,7 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
@@ -55,6 +56,7 @@ export type BurnInstruction<
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
   TAccountBurnSigner extends string | IAccountMeta<string> = string,
+  TAccountVaultDepositor extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -96,6 +98,9 @@ export type BurnInstruction<
         ? ReadonlySignerAccount<TAccountBurnSigner> &
             IAccountSignerMeta<TAccountBurnSigner>
         : TAccountBurnSigner,
+      TAccountVaultDepositor extends string
+        ? ReadonlyAccount<TAccountVaultDepositor>
+        : TAccountVaultDepositor,
       ...TRemainingAccounts,
     ]
   >;
@@ -140,6 +145,173 @@ export function getBurnInstructionDataCodec(): Codec<
   );
 }
 
+export type BurnAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountStaker extends string = string,
+  TAccountStakerTokenAccount extends string = string,
+  TAccountStakerVrtTokenAccount extends string = string,
+  TAccountVaultFeeTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountBurnSigner extends string = string,
+  TAccountVaultDepositor extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  vrtMint: Address<TAccountVrtMint>;
+  staker: TransactionSigner<TAccountStaker>;
+  stakerTokenAccount: Address<TAccountStakerTokenAccount>;
+  stakerVrtTokenAccount: TransactionSigner<TAccountStakerVrtTokenAccount>;
+  vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Signer for burning */
+  burnSigner?: TransactionSigner<TAccountBurnSigner>;
+  /** The staker's VaultDepositor, required when the vault has flash deposit protection */
+  vaultDepositor?: Address<TAccountVaultDepositor>;
+  amountIn: BurnInstructionDataArgs['amountIn'];
+  minAmountOut: BurnInstructionDataArgs['minAmountOut'];
+};
+
+export async function getBurnInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountVrtMint extends string,
+  TAccountStaker extends string,
+  TAccountStakerTokenAccount extends string,
+  TAccountStakerVrtTokenAccount extends string,
+  TAccountVaultFeeTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountBurnSigner extends string,
+  TAccountVaultDepositor extends string,
+>(
+  input: BurnAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >
+): Promise<
+  BurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    staker: { value: input.staker ?? null, isWritable: false },
+    stakerTokenAccount: {
+      value: input.stakerTokenAccount ?? null,
+      isWritable: true,
+    },
+    stakerVrtTokenAccount: {
+      value: input.stakerVrtTokenAccount ?? null,
+      isWritable: false,
+    },
+    vaultFeeTokenAccount: {
+      value: input.vaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    burnSigner: { value: input.burnSigner ?? null, isWritable: false },
+    vaultDepositor: { value: input.vaultDepositor ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.stakerTokenAccount),
+      getAccountMeta(accounts.stakerVrtTokenAccount),
+      getAccountMeta(accounts.vaultFeeTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.burnSigner),
+      getAccountMeta(accounts.vaultDepositor),
+    ],
+    programAddress,
+    data: getBurnInstructionDataEncoder().encode(
+      args as BurnInstructionDataArgs
+    ),
+  } as BurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >;
+
+  return instruction;
+}
+
 export type BurnInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -152,6 +324,7 @@ export type BurnInput<
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountBurnSigner extends string = string,
+  TAccountVaultDepositor extends string = string,
 > = {
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
@@ -165,6 +338,8 @@ export type BurnInput<
   systemProgram?: Address<TAccountSystemProgram>;
   /** Signer for burning */
   burnSigner?: TransactionSigner<TAccountBurnSigner>;
+  /** The staker's VaultDepositor, required when the vault has flash deposit protection */
+  vaultDepositor?: Address<TAccountVaultDepositor>;
   amountIn: BurnInstructionDataArgs['amountIn'];
   minAmountOut: BurnInstructionDataArgs['minAmountOut'];
 };
@@ -181,6 +356,7 @@ export function getBurnInstruction<
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountBurnSigner extends string,
+  TAccountVaultDepositor extends string,
 >(
   input: BurnInput<
     TAccountConfig,
@@ -193,7 +369,8 @@ export function getBurnInstruction<
     TAccountVaultFeeTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram,
-    TAccountBurnSigner
+    TAccountBurnSigner,
+    TAccountVaultDepositor
   >
 ): BurnInstruction<
   typeof JITO_VAULT_PROGRAM_ADDRESS,
@@ -207,7 +384,8 @@ export function getBurnInstruction<
   TAccountVaultFeeTokenAccount,
   TAccountTokenProgram,
   TAccountSystemProgram,
-  TAccountBurnSigner
+  TAccountBurnSigner,
+  TAccountVaultDepositor
 > {
   // Program address.
   const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
@@ -237,6 +415,7 @@ export function getBurnInstruction<
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     burnSigner: { value: input.burnSigner ?? null, isWritable: false },
+    vaultDepositor: { value: input.vaultDepositor ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -270,6 +449,7 @@ export function getBurnInstruction<
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.burnSigner),
+      getAccountMeta(accounts.vaultDepositor),
     ],
     programAddress,
     data: getBurnInstructionDataEncoder().encode(
@@ -287,7 +467,8 @@ export function getBurnInstruction<
     TAccountVaultFeeTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram,
-    TAccountBurnSigner
+    TAccountBurnSigner,
+    TAccountVaultDepositor
   >;
 
   return instruction;
@@ -311,6 +492,8 @@ export type ParsedBurnInstruction<
     systemProgram: TAccountMetas[9];
     /** Signer for burning */
     burnSigner?: TAccountMetas[10] | undefined;
+    /** The staker's VaultDepositor, required when the vault has flash deposit protection */
+    vaultDepositor?: TAccountMetas[11] | undefined;
   };
   data: BurnInstructionData;
 };
@@ -323,7 +506,7 @@ export function parseBurnInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedBurnInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -353,6 +536,7 @@ export function parseBurnInstruction<
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
       burnSigner: getNextOptionalAccount(),
+      vaultDepositor: getNextOptionalAccount(),
     },
     data: getBurnInstructionDataDecoder().decode(instruction.data),
   };
@@ -0,0 +1,538 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const BURN_DELEGATED_DISCRIMINATOR = 72;
+
+export function getBurnDelegatedDiscriminatorBytes() {
+  return getU8Encoder().encode(BURN_DELEGATED_DISCRIMINATOR);
+}
+
+export type BurnDelegatedInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountVrtMint extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountBurner extends string | IAccountMeta<string> = string,
+  TAccountOwnerTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountOwnerVrtTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountVaultFeeTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountBurnSigner extends string | IAccountMeta<string> = string,
+  TAccountVaultDepositor extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultTokenAccount extends string
+        ? WritableAccount<TAccountVaultTokenAccount>
+        : TAccountVaultTokenAccount,
+      TAccountVrtMint extends string
+        ? WritableAccount<TAccountVrtMint>
+        : TAccountVrtMint,
+      TAccountOwner extends string
+        ? ReadonlyAccount<TAccountOwner>
+        : TAccountOwner,
+      TAccountBurner extends string
+        ? ReadonlySignerAccount<TAccountBurner> &
+            IAccountSignerMeta<TAccountBurner>
+        : TAccountBurner,
+      TAccountOwnerTokenAccount extends string
+        ? WritableAccount<TAccountOwnerTokenAccount>
+        : TAccountOwnerTokenAccount,
+      TAccountOwnerVrtTokenAccount extends string
+        ? WritableAccount<TAccountOwnerVrtTokenAccount>
+        : TAccountOwnerVrtTokenAccount,
+      TAccountVaultFeeTokenAccount extends string
+        ? WritableAccount<TAccountVaultFeeTokenAccount>
+        : TAccountVaultFeeTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountBurnSigner extends string
+        ? ReadonlySignerAccount<TAccountBurnSigner> &
+            IAccountSignerMeta<TAccountBurnSigner>
+        : TAccountBurnSigner,
+      TAccountVaultDepositor extends string
+        ? ReadonlyAccount<TAccountVaultDepositor>
+        : TAccountVaultDepositor,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type BurnDelegatedInstructionData = {
+  discriminator: number;
+  amountIn: bigint;
+  minAmountOut: bigint;
+};
+
+export type BurnDelegatedInstructionDataArgs = {
+  amountIn: number | bigint;
+  minAmountOut: number | bigint;
+};
+
+export function getBurnDelegatedInstructionDataEncoder(): Encoder<BurnDelegatedInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amountIn', getU64Encoder()],
+      ['minAmountOut', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: BURN_DELEGATED_DISCRIMINATOR })
+  );
+}
+
+export function getBurnDelegatedInstructionDataDecoder(): Decoder<BurnDelegatedInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amountIn', getU64Decoder()],
+    ['minAmountOut', getU64Decoder()],
+  ]);
+}
+
+export function getBurnDelegatedInstructionDataCodec(): Codec<
+  BurnDelegatedInstructionDataArgs,
+  BurnDelegatedInstructionData
+> {
+  return combineCodec(
+    getBurnDelegatedInstructionDataEncoder(),
+    getBurnDelegatedInstructionDataDecoder()
+  );
+}
+
+export type BurnDelegatedAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountOwner extends string = string,
+  TAccountBurner extends string = string,
+  TAccountOwnerTokenAccount extends string = string,
+  TAccountOwnerVrtTokenAccount extends string = string,
+  TAccountVaultFeeTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountBurnSigner extends string = string,
+  TAccountVaultDepositor extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  vrtMint: Address<TAccountVrtMint>;
+  /** Owner of the VRT token account to burn from */
+  owner: Address<TAccountOwner>;
+  /** Delegate the owner approved on the VRT token account */
+  burner: TransactionSigner<TAccountBurner>;
+  ownerTokenAccount: Address<TAccountOwnerTokenAccount>;
+  ownerVrtTokenAccount: Address<TAccountOwnerVrtTokenAccount>;
+  vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** Signer for burning */
+  burnSigner?: TransactionSigner<TAccountBurnSigner>;
+  /** The owner's VaultDepositor, required when the vault has flash deposit protection */
+  vaultDepositor?: Address<TAccountVaultDepositor>;
+  amountIn: BurnDelegatedInstructionDataArgs['amountIn'];
+  minAmountOut: BurnDelegatedInstructionDataArgs['minAmountOut'];
+};
+
+export async function getBurnDelegatedInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountVrtMint extends string,
+  TAccountOwner extends string,
+  TAccountBurner extends string,
+  TAccountOwnerTokenAccount extends string,
+  TAccountOwnerVrtTokenAccount extends string,
+  TAccountVaultFeeTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountBurnSigner extends string,
+  TAccountVaultDepositor extends string,
+>(
+  input: BurnDelegatedAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountOwner,
+    TAccountBurner,
+    TAccountOwnerTokenAccount,
+    TAccountOwnerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >
+): Promise<
+  BurnDelegatedInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountOwner,
+    TAccountBurner,
+    TAccountOwnerTokenAccount,
+    TAccountOwnerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    owner: { value: input.owner ?? null, isWritable: false },
+    burner: { value: input.burner ?? null, isWritable: false },
+    ownerTokenAccount: {
+      value: input.ownerTokenAccount ?? null,
+      isWritable: true,
+    },
+    ownerVrtTokenAccount: {
+      value: input.ownerVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultFeeTokenAccount: {
+      value: input.vaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    burnSigner: { value: input.burnSigner ?? null, isWritable: false },
+    vaultDepositor: { value: input.vaultDepositor ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.burner),
+      getAccountMeta(accounts.ownerTokenAccount),
+      getAccountMeta(accounts.ownerVrtTokenAccount),
+      getAccountMeta(accounts.vaultFeeTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.burnSigner),
+      getAccountMeta(accounts.vaultDepositor),
+    ],
+    programAddress,
+    data: getBurnDelegatedInstructionDataEncoder().encode(
+      args as BurnDelegatedInstructionDataArgs
+    ),
+  } as BurnDelegatedInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountOwner,
+    TAccountBurner,
+    TAccountOwnerTokenAccount,
+    TAccountOwnerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >;
+
+  return instruction;
+}
+
+export type BurnDelegatedInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountOwner extends string = string,
+  TAccountBurner extends string = string,
+  TAccountOwnerTokenAccount extends string = string,
+  TAccountOwnerVrtTokenAccount extends string = string,
+  TAccountVaultFeeTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountBurnSigner extends string = string,
+  TAccountVaultDepositor extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  vrtMint: Address<TAccountVrtMint>;
+  /** Owner of the VRT token account to burn from */
+  owner: Address<TAccountOwner>;
+  /** Delegate the owner approved on the VRT token account */
+  burner: TransactionSigner<TAccountBurner>;
+  ownerTokenAccount: Address<TAccountOwnerTokenAccount>;
+  ownerVrtTokenAccount: Address<TAccountOwnerVrtTokenAccount>;
+  vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** Signer for burning */
+  burnSigner?: TransactionSigner<TAccountBurnSigner>;
+  /** The owner's VaultDepositor, required when the vault has flash deposit protection */
+  vaultDepositor?: Address<TAccountVaultDepositor>;
+  amountIn: BurnDelegatedInstructionDataArgs['amountIn'];
+  minAmountOut: BurnDelegatedInstructionDataArgs['minAmountOut'];
+};
+
+export function getBurnDelegatedInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountVrtMint extends string,
+  TAccountOwner extends string,
+  TAccountBurner extends string,
+  TAccountOwnerTokenAccount extends string,
+  TAccountOwnerVrtTokenAccount extends string,
+  TAccountVaultFeeTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountBurnSigner extends string,
+  TAccountVaultDepositor extends string,
+>(
+  input: BurnDelegatedInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountOwner,
+    TAccountBurner,
+    TAccountOwnerTokenAccount,
+    TAccountOwnerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >
+): BurnDelegatedInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultTokenAccount,
+  TAccountVrtMint,
+  TAccountOwner,
+  TAccountBurner,
+  TAccountOwnerTokenAccount,
+  TAccountOwnerVrtTokenAccount,
+  TAccountVaultFeeTokenAccount,
+  TAccountTokenProgram,
+  TAccountBurnSigner,
+  TAccountVaultDepositor
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    owner: { value: input.owner ?? null, isWritable: false },
+    burner: { value: input.burner ?? null, isWritable: false },
+    ownerTokenAccount: {
+      value: input.ownerTokenAccount ?? null,
+      isWritable: true,
+    },
+    ownerVrtTokenAccount: {
+      value: input.ownerVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultFeeTokenAccount: {
+      value: input.vaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    burnSigner: { value: input.burnSigner ?? null, isWritable: false },
+    vaultDepositor: { value: input.vaultDepositor ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.burner),
+      getAccountMeta(accounts.ownerTokenAccount),
+      getAccountMeta(accounts.ownerVrtTokenAccount),
+      getAccountMeta(accounts.vaultFeeTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.burnSigner),
+      getAccountMeta(accounts.vaultDepositor),
+    ],
+    programAddress,
+    data: getBurnDelegatedInstructionDataEncoder().encode(
+      args as BurnDelegatedInstructionDataArgs
+    ),
+  } as BurnDelegatedInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountOwner,
+    TAccountBurner,
+    TAccountOwnerTokenAccount,
+    TAccountOwnerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >;
+
+  return instruction;
+}
+
+export type ParsedBurnDelegatedInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vaultTokenAccount: TAccountMetas[2];
+    vrtMint: TAccountMetas[3];
+    /** Owner of the VRT token account to burn from */
+    owner: TAccountMetas[4];
+    /** Delegate the owner approved on the VRT token account */
+    burner: TAccountMetas[5];
+    ownerTokenAccount: TAccountMetas[6];
+    ownerVrtTokenAccount: TAccountMetas[7];
+    vaultFeeTokenAccount: TAccountMetas[8];
+    tokenProgram: TAccountMetas[9];
+    /** Signer for burning */
+    burnSigner?: TAccountMetas[10] | undefined;
+    /** The owner's VaultDepositor, required when the vault has flash deposit protection */
+    vaultDepositor?: TAccountMetas[11] | undefined;
+  };
+  data: BurnDelegatedInstructionData;
+};
+
+export function parseBurnDelegatedInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedBurnDelegatedInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 12) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_VAULT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultTokenAccount: getNextAccount(),
+      vrtMint: getNextAccount(),
+      owner: getNextAccount(),
+      burner: getNextAccount(),
+      ownerTokenAccount: getNextAccount(),
+      ownerVrtTokenAccount: getNextAccount(),
+      vaultFeeTokenAccount: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      burnSigner: getNextOptionalAccount(),
+      vaultDepositor: getNextOptionalAccount(),
+    },
+    data: getBurnDelegatedInstructionDataDecoder().decode(instruction.data),
+  };
+}
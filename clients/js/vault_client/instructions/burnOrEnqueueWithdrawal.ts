@@ -0,0 +1,641 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findVaultStakerWithdrawalTicketPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const BURN_OR_ENQUEUE_WITHDRAWAL_DISCRIMINATOR = 54;
+
+export function getBurnOrEnqueueWithdrawalDiscriminatorBytes() {
+  return getU8Encoder().encode(BURN_OR_ENQUEUE_WITHDRAWAL_DISCRIMINATOR);
+}
+
+export type BurnOrEnqueueWithdrawalInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountVrtMint extends string | IAccountMeta<string> = string,
+  TAccountStaker extends string | IAccountMeta<string> = string,
+  TAccountStakerTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountStakerVrtTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountVaultFeeTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountVaultStakerWithdrawalTicket extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountBase extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountBurnSigner extends string | IAccountMeta<string> = string,
+  TAccountVaultDepositor extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultTokenAccount extends string
+        ? WritableAccount<TAccountVaultTokenAccount>
+        : TAccountVaultTokenAccount,
+      TAccountVrtMint extends string
+        ? WritableAccount<TAccountVrtMint>
+        : TAccountVrtMint,
+      TAccountStaker extends string
+        ? WritableSignerAccount<TAccountStaker> &
+            IAccountSignerMeta<TAccountStaker>
+        : TAccountStaker,
+      TAccountStakerTokenAccount extends string
+        ? WritableAccount<TAccountStakerTokenAccount>
+        : TAccountStakerTokenAccount,
+      TAccountStakerVrtTokenAccount extends string
+        ? WritableAccount<TAccountStakerVrtTokenAccount>
+        : TAccountStakerVrtTokenAccount,
+      TAccountVaultFeeTokenAccount extends string
+        ? WritableAccount<TAccountVaultFeeTokenAccount>
+        : TAccountVaultFeeTokenAccount,
+      TAccountVaultStakerWithdrawalTicket extends string
+        ? WritableAccount<TAccountVaultStakerWithdrawalTicket>
+        : TAccountVaultStakerWithdrawalTicket,
+      TAccountVaultStakerWithdrawalTicketTokenAccount extends string
+        ? WritableAccount<TAccountVaultStakerWithdrawalTicketTokenAccount>
+        : TAccountVaultStakerWithdrawalTicketTokenAccount,
+      TAccountBase extends string
+        ? ReadonlySignerAccount<TAccountBase> & IAccountSignerMeta<TAccountBase>
+        : TAccountBase,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountBurnSigner extends string
+        ? ReadonlySignerAccount<TAccountBurnSigner> &
+            IAccountSignerMeta<TAccountBurnSigner>
+        : TAccountBurnSigner,
+      TAccountVaultDepositor extends string
+        ? ReadonlyAccount<TAccountVaultDepositor>
+        : TAccountVaultDepositor,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type BurnOrEnqueueWithdrawalInstructionData = {
+  discriminator: number;
+  amountIn: bigint;
+  minAmountOut: bigint;
+};
+
+export type BurnOrEnqueueWithdrawalInstructionDataArgs = {
+  amountIn: number | bigint;
+  minAmountOut: number | bigint;
+};
+
+export function getBurnOrEnqueueWithdrawalInstructionDataEncoder(): Encoder<BurnOrEnqueueWithdrawalInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amountIn', getU64Encoder()],
+      ['minAmountOut', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: BURN_OR_ENQUEUE_WITHDRAWAL_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getBurnOrEnqueueWithdrawalInstructionDataDecoder(): Decoder<BurnOrEnqueueWithdrawalInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amountIn', getU64Decoder()],
+    ['minAmountOut', getU64Decoder()],
+  ]);
+}
+
+export function getBurnOrEnqueueWithdrawalInstructionDataCodec(): Codec<
+  BurnOrEnqueueWithdrawalInstructionDataArgs,
+  BurnOrEnqueueWithdrawalInstructionData
+> {
+  return combineCodec(
+    getBurnOrEnqueueWithdrawalInstructionDataEncoder(),
+    getBurnOrEnqueueWithdrawalInstructionDataDecoder()
+  );
+}
+
+export type BurnOrEnqueueWithdrawalAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountStaker extends string = string,
+  TAccountStakerTokenAccount extends string = string,
+  TAccountStakerVrtTokenAccount extends string = string,
+  TAccountVaultFeeTokenAccount extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string = string,
+  TAccountBase extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountBurnSigner extends string = string,
+  TAccountVaultDepositor extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  vrtMint: Address<TAccountVrtMint>;
+  staker: TransactionSigner<TAccountStaker>;
+  stakerTokenAccount: Address<TAccountStakerTokenAccount>;
+  stakerVrtTokenAccount: Address<TAccountStakerVrtTokenAccount>;
+  vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
+  vaultStakerWithdrawalTicket?: Address<TAccountVaultStakerWithdrawalTicket>;
+  vaultStakerWithdrawalTicketTokenAccount: Address<TAccountVaultStakerWithdrawalTicketTokenAccount>;
+  base: TransactionSigner<TAccountBase>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Signer for burning */
+  burnSigner?: TransactionSigner<TAccountBurnSigner>;
+  /** The staker's VaultDepositor, required when the vault has flash deposit protection */
+  vaultDepositor?: Address<TAccountVaultDepositor>;
+  amountIn: BurnOrEnqueueWithdrawalInstructionDataArgs['amountIn'];
+  minAmountOut: BurnOrEnqueueWithdrawalInstructionDataArgs['minAmountOut'];
+};
+
+export async function getBurnOrEnqueueWithdrawalInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountVrtMint extends string,
+  TAccountStaker extends string,
+  TAccountStakerTokenAccount extends string,
+  TAccountStakerVrtTokenAccount extends string,
+  TAccountVaultFeeTokenAccount extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string,
+  TAccountBase extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountBurnSigner extends string,
+  TAccountVaultDepositor extends string,
+>(
+  input: BurnOrEnqueueWithdrawalAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountBase,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >
+): Promise<
+  BurnOrEnqueueWithdrawalInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountBase,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    staker: { value: input.staker ?? null, isWritable: true },
+    stakerTokenAccount: {
+      value: input.stakerTokenAccount ?? null,
+      isWritable: true,
+    },
+    stakerVrtTokenAccount: {
+      value: input.stakerVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultFeeTokenAccount: {
+      value: input.vaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    vaultStakerWithdrawalTicketTokenAccount: {
+      value: input.vaultStakerWithdrawalTicketTokenAccount ?? null,
+      isWritable: true,
+    },
+    base: { value: input.base ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    burnSigner: { value: input.burnSigner ?? null, isWritable: false },
+    vaultDepositor: { value: input.vaultDepositor ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultStakerWithdrawalTicket.value) {
+    accounts.vaultStakerWithdrawalTicket.value =
+      await findVaultStakerWithdrawalTicketPda({
+        vault: expectAddress(accounts.vault.value),
+        base: expectAddress(accounts.base.value),
+      });
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.stakerTokenAccount),
+      getAccountMeta(accounts.stakerVrtTokenAccount),
+      getAccountMeta(accounts.vaultFeeTokenAccount),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicketTokenAccount),
+      getAccountMeta(accounts.base),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.burnSigner),
+      getAccountMeta(accounts.vaultDepositor),
+    ],
+    programAddress,
+    data: getBurnOrEnqueueWithdrawalInstructionDataEncoder().encode(
+      args as BurnOrEnqueueWithdrawalInstructionDataArgs
+    ),
+  } as BurnOrEnqueueWithdrawalInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountBase,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >;
+
+  return instruction;
+}
+
+export type BurnOrEnqueueWithdrawalInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountStaker extends string = string,
+  TAccountStakerTokenAccount extends string = string,
+  TAccountStakerVrtTokenAccount extends string = string,
+  TAccountVaultFeeTokenAccount extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string = string,
+  TAccountBase extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountBurnSigner extends string = string,
+  TAccountVaultDepositor extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  vrtMint: Address<TAccountVrtMint>;
+  staker: TransactionSigner<TAccountStaker>;
+  stakerTokenAccount: Address<TAccountStakerTokenAccount>;
+  stakerVrtTokenAccount: Address<TAccountStakerVrtTokenAccount>;
+  vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  vaultStakerWithdrawalTicketTokenAccount: Address<TAccountVaultStakerWithdrawalTicketTokenAccount>;
+  base: TransactionSigner<TAccountBase>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Signer for burning */
+  burnSigner?: TransactionSigner<TAccountBurnSigner>;
+  /** The staker's VaultDepositor, required when the vault has flash deposit protection */
+  vaultDepositor?: Address<TAccountVaultDepositor>;
+  amountIn: BurnOrEnqueueWithdrawalInstructionDataArgs['amountIn'];
+  minAmountOut: BurnOrEnqueueWithdrawalInstructionDataArgs['minAmountOut'];
+};
+
+export function getBurnOrEnqueueWithdrawalInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountVrtMint extends string,
+  TAccountStaker extends string,
+  TAccountStakerTokenAccount extends string,
+  TAccountStakerVrtTokenAccount extends string,
+  TAccountVaultFeeTokenAccount extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string,
+  TAccountBase extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountBurnSigner extends string,
+  TAccountVaultDepositor extends string,
+>(
+  input: BurnOrEnqueueWithdrawalInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountBase,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >
+): BurnOrEnqueueWithdrawalInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultTokenAccount,
+  TAccountVrtMint,
+  TAccountStaker,
+  TAccountStakerTokenAccount,
+  TAccountStakerVrtTokenAccount,
+  TAccountVaultFeeTokenAccount,
+  TAccountVaultStakerWithdrawalTicket,
+  TAccountVaultStakerWithdrawalTicketTokenAccount,
+  TAccountBase,
+  TAccountTokenProgram,
+  TAccountSystemProgram,
+  TAccountBurnSigner,
+  TAccountVaultDepositor
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    staker: { value: input.staker ?? null, isWritable: true },
+    stakerTokenAccount: {
+      value: input.stakerTokenAccount ?? null,
+      isWritable: true,
+    },
+    stakerVrtTokenAccount: {
+      value: input.stakerVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultFeeTokenAccount: {
+      value: input.vaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    vaultStakerWithdrawalTicketTokenAccount: {
+      value: input.vaultStakerWithdrawalTicketTokenAccount ?? null,
+      isWritable: true,
+    },
+    base: { value: input.base ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    burnSigner: { value: input.burnSigner ?? null, isWritable: false },
+    vaultDepositor: { value: input.vaultDepositor ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.stakerTokenAccount),
+      getAccountMeta(accounts.stakerVrtTokenAccount),
+      getAccountMeta(accounts.vaultFeeTokenAccount),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicketTokenAccount),
+      getAccountMeta(accounts.base),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.burnSigner),
+      getAccountMeta(accounts.vaultDepositor),
+    ],
+    programAddress,
+    data: getBurnOrEnqueueWithdrawalInstructionDataEncoder().encode(
+      args as BurnOrEnqueueWithdrawalInstructionDataArgs
+    ),
+  } as BurnOrEnqueueWithdrawalInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerVrtTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountBase,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountVaultDepositor
+  >;
+
+  return instruction;
+}
+
+export type ParsedBurnOrEnqueueWithdrawalInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vaultTokenAccount: TAccountMetas[2];
+    vrtMint: TAccountMetas[3];
+    staker: TAccountMetas[4];
+    stakerTokenAccount: TAccountMetas[5];
+    stakerVrtTokenAccount: TAccountMetas[6];
+    vaultFeeTokenAccount: TAccountMetas[7];
+    vaultStakerWithdrawalTicket: TAccountMetas[8];
+    vaultStakerWithdrawalTicketTokenAccount: TAccountMetas[9];
+    base: TAccountMetas[10];
+    tokenProgram: TAccountMetas[11];
+    systemProgram: TAccountMetas[12];
+    /** Signer for burning */
+    burnSigner?: TAccountMetas[13] | undefined;
+    /** The staker's VaultDepositor, required when the vault has flash deposit protection */
+    vaultDepositor?: TAccountMetas[14] | undefined;
+  };
+  data: BurnOrEnqueueWithdrawalInstructionData;
+};
+
+export function parseBurnOrEnqueueWithdrawalInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedBurnOrEnqueueWithdrawalInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 15) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_VAULT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultTokenAccount: getNextAccount(),
+      vrtMint: getNextAccount(),
+      staker: getNextAccount(),
+      stakerTokenAccount: getNextAccount(),
+      stakerVrtTokenAccount: getNextAccount(),
+      vaultFeeTokenAccount: getNextAccount(),
+      vaultStakerWithdrawalTicket: getNextAccount(),
+      vaultStakerWithdrawalTicketTokenAccount: getNextAccount(),
+      base: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      systemProgram: getNextAccount(),
+      burnSigner: getNextOptionalAccount(),
+      vaultDepositor: getNextOptionalAccount(),
+    },
+    data: getBurnOrEnqueueWithdrawalInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -29,6 +29,7 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
@@ -60,6 +61,7 @@ export type BurnWithdrawTicketInstruction<
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
   TAccountBurnSigner extends string | IAccountMeta<string> = string,
+  TAccountClaimerTokenAccount extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -102,6 +104,9 @@ export type BurnWithdrawTicketInstruction<
         ? ReadonlySignerAccount<TAccountBurnSigner> &
             IAccountSignerMeta<TAccountBurnSigner>
         : TAccountBurnSigner,
+      TAccountClaimerTokenAccount extends string
+        ? WritableAccount<TAccountClaimerTokenAccount>
+        : TAccountClaimerTokenAccount,
       ...TRemainingAccounts,
     ]
   >;
@@ -142,6 +147,186 @@ export function getBurnWithdrawTicketInstructionDataCodec(): Codec<
   );
 }
 
+export type BurnWithdrawTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountStaker extends string = string,
+  TAccountStakerTokenAccount extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string = string,
+  TAccountVaultFeeTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountBurnSigner extends string = string,
+  TAccountClaimerTokenAccount extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  vrtMint: Address<TAccountVrtMint>;
+  staker: Address<TAccountStaker>;
+  stakerTokenAccount: Address<TAccountStakerTokenAccount>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  vaultStakerWithdrawalTicketTokenAccount: Address<TAccountVaultStakerWithdrawalTicketTokenAccount>;
+  vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Signer for burning */
+  burnSigner?: TransactionSigner<TAccountBurnSigner>;
+  /** Receives the auto-claim fee when the ticket opted into auto-claiming */
+  claimerTokenAccount?: Address<TAccountClaimerTokenAccount>;
+  minAmountOut: BurnWithdrawTicketInstructionDataArgs['minAmountOut'];
+};
+
+export async function getBurnWithdrawTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountVrtMint extends string,
+  TAccountStaker extends string,
+  TAccountStakerTokenAccount extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string,
+  TAccountVaultFeeTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountBurnSigner extends string,
+  TAccountClaimerTokenAccount extends string,
+>(
+  input: BurnWithdrawTicketAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountClaimerTokenAccount
+  >
+): Promise<
+  BurnWithdrawTicketInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountClaimerTokenAccount
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    staker: { value: input.staker ?? null, isWritable: true },
+    stakerTokenAccount: {
+      value: input.stakerTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    vaultStakerWithdrawalTicketTokenAccount: {
+      value: input.vaultStakerWithdrawalTicketTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultFeeTokenAccount: {
+      value: input.vaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    burnSigner: { value: input.burnSigner ?? null, isWritable: false },
+    claimerTokenAccount: {
+      value: input.claimerTokenAccount ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.stakerTokenAccount),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicketTokenAccount),
+      getAccountMeta(accounts.vaultFeeTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.burnSigner),
+      getAccountMeta(accounts.claimerTokenAccount),
+    ],
+    programAddress,
+    data: getBurnWithdrawTicketInstructionDataEncoder().encode(
+      args as BurnWithdrawTicketInstructionDataArgs
+    ),
+  } as BurnWithdrawTicketInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner,
+    TAccountClaimerTokenAccount
+  >;
+
+  return instruction;
+}
+
 export type BurnWithdrawTicketInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -155,6 +340,7 @@ export type BurnWithdrawTicketInput<
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountBurnSigner extends string = string,
+  TAccountClaimerTokenAccount extends string = string,
 > = {
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
@@ -169,6 +355,8 @@ export type BurnWithdrawTicketInput<
   systemProgram?: Address<TAccountSystemProgram>;
   /** Signer for burning */
   burnSigner?: TransactionSigner<TAccountBurnSigner>;
+  /** Receives the auto-claim fee when the ticket opted into auto-claiming */
+  claimerTokenAccount?: Address<TAccountClaimerTokenAccount>;
   minAmountOut: BurnWithdrawTicketInstructionDataArgs['minAmountOut'];
 };
 
@@ -185,6 +373,7 @@ export function getBurnWithdrawTicketInstruction<
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountBurnSigner extends string,
+  TAccountClaimerTokenAccount extends string,
 >(
   input: BurnWithdrawTicketInput<
     TAccountConfig,
@@ -198,7 +387,8 @@ export function getBurnWithdrawTicketInstruction<
     TAccountVaultFeeTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram,
-    TAccountBurnSigner
+    TAccountBurnSigner,
+    TAccountClaimerTokenAccount
   >
 ): BurnWithdrawTicketInstruction<
   typeof JITO_VAULT_PROGRAM_ADDRESS,
@@ -213,7 +403,8 @@ export function getBurnWithdrawTicketInstruction<
   TAccountVaultFeeTokenAccount,
   TAccountTokenProgram,
   TAccountSystemProgram,
-  TAccountBurnSigner
+  TAccountBurnSigner,
+  TAccountClaimerTokenAccount
 > {
   // Program address.
   const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
@@ -247,6 +438,10 @@ export function getBurnWithdrawTicketInstruction<
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     burnSigner: { value: input.burnSigner ?? null, isWritable: false },
+    claimerTokenAccount: {
+      value: input.claimerTokenAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -281,6 +476,7 @@ export function getBurnWithdrawTicketInstruction<
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.burnSigner),
+      getAccountMeta(accounts.claimerTokenAccount),
     ],
     programAddress,
     data: getBurnWithdrawTicketInstructionDataEncoder().encode(
@@ -299,7 +495,8 @@ export function getBurnWithdrawTicketInstruction<
     TAccountVaultFeeTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram,
-    TAccountBurnSigner
+    TAccountBurnSigner,
+    TAccountClaimerTokenAccount
   >;
 
   return instruction;
@@ -324,6 +521,8 @@ export type ParsedBurnWithdrawTicketInstruction<
     systemProgram: TAccountMetas[10];
     /** Signer for burning */
     burnSigner?: TAccountMetas[11] | undefined;
+    /** Receives the auto-claim fee when the ticket opted into auto-claiming */
+    claimerTokenAccount?: TAccountMetas[12] | undefined;
   };
   data: BurnWithdrawTicketInstructionData;
 };
@@ -336,7 +535,7 @@ export function parseBurnWithdrawTicketInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedBurnWithdrawTicketInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -367,6 +566,7 @@ export function parseBurnWithdrawTicketInstruction<
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
       burnSigner: getNextOptionalAccount(),
+      claimerTokenAccount: getNextOptionalAccount(),
     },
     data: getBurnWithdrawTicketInstructionDataDecoder().decode(
       instruction.data
@@ -0,0 +1,287 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CANCEL_ADMIN_BURN_DISCRIMINATOR = 59;
+
+export function getCancelAdminBurnDiscriminatorBytes() {
+  return getU8Encoder().encode(CANCEL_ADMIN_BURN_DISCRIMINATOR);
+}
+
+export type CancelAdminBurnInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultStakerWithdrawalTicket extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultStakerWithdrawalTicket extends string
+        ? WritableAccount<TAccountVaultStakerWithdrawalTicket>
+        : TAccountVaultStakerWithdrawalTicket,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CancelAdminBurnInstructionData = { discriminator: number };
+
+export type CancelAdminBurnInstructionDataArgs = {};
+
+export function getCancelAdminBurnInstructionDataEncoder(): Encoder<CancelAdminBurnInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: CANCEL_ADMIN_BURN_DISCRIMINATOR })
+  );
+}
+
+export function getCancelAdminBurnInstructionDataDecoder(): Decoder<CancelAdminBurnInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getCancelAdminBurnInstructionDataCodec(): Codec<
+  CancelAdminBurnInstructionDataArgs,
+  CancelAdminBurnInstructionData
+> {
+  return combineCodec(
+    getCancelAdminBurnInstructionDataEncoder(),
+    getCancelAdminBurnInstructionDataDecoder()
+  );
+}
+
+export type CancelAdminBurnAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  /** The vault admin or the burn admin */
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getCancelAdminBurnInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: CancelAdminBurnAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountAdmin
+  >
+): Promise<
+  CancelAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getCancelAdminBurnInstructionDataEncoder().encode({}),
+  } as CancelAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type CancelAdminBurnInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  /** The vault admin or the burn admin */
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export function getCancelAdminBurnInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: CancelAdminBurnInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountAdmin
+  >
+): CancelAdminBurnInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultStakerWithdrawalTicket,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getCancelAdminBurnInstructionDataEncoder().encode({}),
+  } as CancelAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedCancelAdminBurnInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vaultStakerWithdrawalTicket: TAccountMetas[2];
+    /** The vault admin or the burn admin */
+    admin: TAccountMetas[3];
+  };
+  data: CancelAdminBurnInstructionData;
+};
+
+export function parseCancelAdminBurnInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCancelAdminBurnInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultStakerWithdrawalTicket: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getCancelAdminBurnInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,264 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CANCEL_MINT_AUTHORITY_HANDOFF_DISCRIMINATOR = 40;
+
+export function getCancelMintAuthorityHandoffDiscriminatorBytes() {
+  return getU8Encoder().encode(CANCEL_MINT_AUTHORITY_HANDOFF_DISCRIMINATOR);
+}
+
+export type CancelMintAuthorityHandoffInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CancelMintAuthorityHandoffInstructionData = {
+  discriminator: number;
+};
+
+export type CancelMintAuthorityHandoffInstructionDataArgs = {};
+
+export function getCancelMintAuthorityHandoffInstructionDataEncoder(): Encoder<CancelMintAuthorityHandoffInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: CANCEL_MINT_AUTHORITY_HANDOFF_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getCancelMintAuthorityHandoffInstructionDataDecoder(): Decoder<CancelMintAuthorityHandoffInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getCancelMintAuthorityHandoffInstructionDataCodec(): Codec<
+  CancelMintAuthorityHandoffInstructionDataArgs,
+  CancelMintAuthorityHandoffInstructionData
+> {
+  return combineCodec(
+    getCancelMintAuthorityHandoffInstructionDataEncoder(),
+    getCancelMintAuthorityHandoffInstructionDataDecoder()
+  );
+}
+
+export type CancelMintAuthorityHandoffAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  /** The vault admin or the config admin */
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getCancelMintAuthorityHandoffInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+>(
+  input: CancelMintAuthorityHandoffAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+): Promise<
+  CancelMintAuthorityHandoffInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getCancelMintAuthorityHandoffInstructionDataEncoder().encode({}),
+  } as CancelMintAuthorityHandoffInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type CancelMintAuthorityHandoffInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  /** The vault admin or the config admin */
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export function getCancelMintAuthorityHandoffInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+>(
+  input: CancelMintAuthorityHandoffInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+): CancelMintAuthorityHandoffInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getCancelMintAuthorityHandoffInstructionDataEncoder().encode({}),
+  } as CancelMintAuthorityHandoffInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedCancelMintAuthorityHandoffInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    /** The vault admin or the config admin */
+    admin: TAccountMetas[2];
+  };
+  data: CancelMintAuthorityHandoffInstructionData;
+};
+
+export function parseCancelMintAuthorityHandoffInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCancelMintAuthorityHandoffInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getCancelMintAuthorityHandoffInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -27,6 +27,7 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
@@ -100,6 +101,91 @@ export function getChangeWithdrawalTicketOwnerInstructionDataCodec(): Codec<
   );
 }
 
+export type ChangeWithdrawalTicketOwnerAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountOldOwner extends string = string,
+  TAccountNewOwner extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  oldOwner: TransactionSigner<TAccountOldOwner>;
+  newOwner: Address<TAccountNewOwner>;
+};
+
+export async function getChangeWithdrawalTicketOwnerInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountOldOwner extends string,
+  TAccountNewOwner extends string,
+>(
+  input: ChangeWithdrawalTicketOwnerAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountOldOwner,
+    TAccountNewOwner
+  >
+): Promise<
+  ChangeWithdrawalTicketOwnerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountOldOwner,
+    TAccountNewOwner
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    oldOwner: { value: input.oldOwner ?? null, isWritable: false },
+    newOwner: { value: input.newOwner ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.oldOwner),
+      getAccountMeta(accounts.newOwner),
+    ],
+    programAddress,
+    data: getChangeWithdrawalTicketOwnerInstructionDataEncoder().encode({}),
+  } as ChangeWithdrawalTicketOwnerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountOldOwner,
+    TAccountNewOwner
+  >;
+
+  return instruction;
+}
+
 export type ChangeWithdrawalTicketOwnerInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -0,0 +1,466 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CLAIM_REWARDS_DISCRIMINATOR = 65;
+
+export function getClaimRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(CLAIM_REWARDS_DISCRIMINATOR);
+}
+
+export type ClaimRewardsInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountRewardDistribution extends string | IAccountMeta<string> = string,
+  TAccountRewardClaim extends string | IAccountMeta<string> = string,
+  TAccountVaultRewardsTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountStakerTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountStaker extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountRewardDistribution extends string
+        ? WritableAccount<TAccountRewardDistribution>
+        : TAccountRewardDistribution,
+      TAccountRewardClaim extends string
+        ? WritableAccount<TAccountRewardClaim>
+        : TAccountRewardClaim,
+      TAccountVaultRewardsTokenAccount extends string
+        ? WritableAccount<TAccountVaultRewardsTokenAccount>
+        : TAccountVaultRewardsTokenAccount,
+      TAccountStakerTokenAccount extends string
+        ? WritableAccount<TAccountStakerTokenAccount>
+        : TAccountStakerTokenAccount,
+      TAccountStaker extends string
+        ? WritableSignerAccount<TAccountStaker> &
+            IAccountSignerMeta<TAccountStaker>
+        : TAccountStaker,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ClaimRewardsInstructionData = {
+  discriminator: number;
+  amount: bigint;
+  proof: Array<ReadonlyUint8Array>;
+};
+
+export type ClaimRewardsInstructionDataArgs = {
+  amount: number | bigint;
+  proof: Array<ReadonlyUint8Array>;
+};
+
+export function getClaimRewardsInstructionDataEncoder(): Encoder<ClaimRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+      ['proof', getArrayEncoder(fixEncoderSize(getBytesEncoder(), 32))],
+    ]),
+    (value) => ({ ...value, discriminator: CLAIM_REWARDS_DISCRIMINATOR })
+  );
+}
+
+export function getClaimRewardsInstructionDataDecoder(): Decoder<ClaimRewardsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+    ['proof', getArrayDecoder(fixDecoderSize(getBytesDecoder(), 32))],
+  ]);
+}
+
+export function getClaimRewardsInstructionDataCodec(): Codec<
+  ClaimRewardsInstructionDataArgs,
+  ClaimRewardsInstructionData
+> {
+  return combineCodec(
+    getClaimRewardsInstructionDataEncoder(),
+    getClaimRewardsInstructionDataDecoder()
+  );
+}
+
+export type ClaimRewardsAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountRewardDistribution extends string = string,
+  TAccountRewardClaim extends string = string,
+  TAccountVaultRewardsTokenAccount extends string = string,
+  TAccountStakerTokenAccount extends string = string,
+  TAccountStaker extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  rewardDistribution: Address<TAccountRewardDistribution>;
+  rewardClaim: Address<TAccountRewardClaim>;
+  vaultRewardsTokenAccount: Address<TAccountVaultRewardsTokenAccount>;
+  stakerTokenAccount: Address<TAccountStakerTokenAccount>;
+  staker: TransactionSigner<TAccountStaker>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  amount: ClaimRewardsInstructionDataArgs['amount'];
+  proof: ClaimRewardsInstructionDataArgs['proof'];
+};
+
+export async function getClaimRewardsInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountRewardDistribution extends string,
+  TAccountRewardClaim extends string,
+  TAccountVaultRewardsTokenAccount extends string,
+  TAccountStakerTokenAccount extends string,
+  TAccountStaker extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: ClaimRewardsAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountRewardDistribution,
+    TAccountRewardClaim,
+    TAccountVaultRewardsTokenAccount,
+    TAccountStakerTokenAccount,
+    TAccountStaker,
+    TAccountTokenProgram,
+    TAccountSystemProgram
+  >
+): Promise<
+  ClaimRewardsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountRewardDistribution,
+    TAccountRewardClaim,
+    TAccountVaultRewardsTokenAccount,
+    TAccountStakerTokenAccount,
+    TAccountStaker,
+    TAccountTokenProgram,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    rewardDistribution: {
+      value: input.rewardDistribution ?? null,
+      isWritable: true,
+    },
+    rewardClaim: { value: input.rewardClaim ?? null, isWritable: true },
+    vaultRewardsTokenAccount: {
+      value: input.vaultRewardsTokenAccount ?? null,
+      isWritable: true,
+    },
+    stakerTokenAccount: {
+      value: input.stakerTokenAccount ?? null,
+      isWritable: true,
+    },
+    staker: { value: input.staker ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.rewardDistribution),
+      getAccountMeta(accounts.rewardClaim),
+      getAccountMeta(accounts.vaultRewardsTokenAccount),
+      getAccountMeta(accounts.stakerTokenAccount),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getClaimRewardsInstructionDataEncoder().encode(
+      args as ClaimRewardsInstructionDataArgs
+    ),
+  } as ClaimRewardsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountRewardDistribution,
+    TAccountRewardClaim,
+    TAccountVaultRewardsTokenAccount,
+    TAccountStakerTokenAccount,
+    TAccountStaker,
+    TAccountTokenProgram,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ClaimRewardsInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountRewardDistribution extends string = string,
+  TAccountRewardClaim extends string = string,
+  TAccountVaultRewardsTokenAccount extends string = string,
+  TAccountStakerTokenAccount extends string = string,
+  TAccountStaker extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  rewardDistribution: Address<TAccountRewardDistribution>;
+  rewardClaim: Address<TAccountRewardClaim>;
+  vaultRewardsTokenAccount: Address<TAccountVaultRewardsTokenAccount>;
+  stakerTokenAccount: Address<TAccountStakerTokenAccount>;
+  staker: TransactionSigner<TAccountStaker>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  amount: ClaimRewardsInstructionDataArgs['amount'];
+  proof: ClaimRewardsInstructionDataArgs['proof'];
+};
+
+export function getClaimRewardsInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountRewardDistribution extends string,
+  TAccountRewardClaim extends string,
+  TAccountVaultRewardsTokenAccount extends string,
+  TAccountStakerTokenAccount extends string,
+  TAccountStaker extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: ClaimRewardsInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountRewardDistribution,
+    TAccountRewardClaim,
+    TAccountVaultRewardsTokenAccount,
+    TAccountStakerTokenAccount,
+    TAccountStaker,
+    TAccountTokenProgram,
+    TAccountSystemProgram
+  >
+): ClaimRewardsInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountRewardDistribution,
+  TAccountRewardClaim,
+  TAccountVaultRewardsTokenAccount,
+  TAccountStakerTokenAccount,
+  TAccountStaker,
+  TAccountTokenProgram,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    rewardDistribution: {
+      value: input.rewardDistribution ?? null,
+      isWritable: true,
+    },
+    rewardClaim: { value: input.rewardClaim ?? null, isWritable: true },
+    vaultRewardsTokenAccount: {
+      value: input.vaultRewardsTokenAccount ?? null,
+      isWritable: true,
+    },
+    stakerTokenAccount: {
+      value: input.stakerTokenAccount ?? null,
+      isWritable: true,
+    },
+    staker: { value: input.staker ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.rewardDistribution),
+      getAccountMeta(accounts.rewardClaim),
+      getAccountMeta(accounts.vaultRewardsTokenAccount),
+      getAccountMeta(accounts.stakerTokenAccount),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getClaimRewardsInstructionDataEncoder().encode(
+      args as ClaimRewardsInstructionDataArgs
+    ),
+  } as ClaimRewardsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountRewardDistribution,
+    TAccountRewardClaim,
+    TAccountVaultRewardsTokenAccount,
+    TAccountStakerTokenAccount,
+    TAccountStaker,
+    TAccountTokenProgram,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedClaimRewardsInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    rewardDistribution: TAccountMetas[2];
+    rewardClaim: TAccountMetas[3];
+    vaultRewardsTokenAccount: TAccountMetas[4];
+    stakerTokenAccount: TAccountMetas[5];
+    staker: TAccountMetas[6];
+    tokenProgram: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
+  };
+  data: ClaimRewardsInstructionData;
+};
+
+export function parseClaimRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedClaimRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 9) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      rewardDistribution: getNextAccount(),
+      rewardClaim: getNextAccount(),
+      vaultRewardsTokenAccount: getNextAccount(),
+      stakerTokenAccount: getNextAccount(),
+      staker: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getClaimRewardsInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,259 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const CLOSE_DEPOSIT_RECEIPT_DISCRIMINATOR = 75;
+
+export function getCloseDepositReceiptDiscriminatorBytes() {
+  return getU8Encoder().encode(CLOSE_DEPOSIT_RECEIPT_DISCRIMINATOR);
+}
+
+export type CloseDepositReceiptInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountDepositReceipt extends string | IAccountMeta<string> = string,
+  TAccountDepositor extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountDepositReceipt extends string
+        ? WritableAccount<TAccountDepositReceipt>
+        : TAccountDepositReceipt,
+      TAccountDepositor extends string
+        ? WritableSignerAccount<TAccountDepositor> &
+            IAccountSignerMeta<TAccountDepositor>
+        : TAccountDepositor,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CloseDepositReceiptInstructionData = { discriminator: number };
+
+export type CloseDepositReceiptInstructionDataArgs = {};
+
+export function getCloseDepositReceiptInstructionDataEncoder(): Encoder<CloseDepositReceiptInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: CLOSE_DEPOSIT_RECEIPT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getCloseDepositReceiptInstructionDataDecoder(): Decoder<CloseDepositReceiptInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getCloseDepositReceiptInstructionDataCodec(): Codec<
+  CloseDepositReceiptInstructionDataArgs,
+  CloseDepositReceiptInstructionData
+> {
+  return combineCodec(
+    getCloseDepositReceiptInstructionDataEncoder(),
+    getCloseDepositReceiptInstructionDataDecoder()
+  );
+}
+
+export type CloseDepositReceiptAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountDepositReceipt extends string = string,
+  TAccountDepositor extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  depositReceipt: Address<TAccountDepositReceipt>;
+  depositor: TransactionSigner<TAccountDepositor>;
+};
+
+export async function getCloseDepositReceiptInstructionAsync<
+  TAccountConfig extends string,
+  TAccountDepositReceipt extends string,
+  TAccountDepositor extends string,
+>(
+  input: CloseDepositReceiptAsyncInput<
+    TAccountConfig,
+    TAccountDepositReceipt,
+    TAccountDepositor
+  >
+): Promise<
+  CloseDepositReceiptInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountDepositReceipt,
+    TAccountDepositor
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    depositReceipt: { value: input.depositReceipt ?? null, isWritable: true },
+    depositor: { value: input.depositor ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.depositReceipt),
+      getAccountMeta(accounts.depositor),
+    ],
+    programAddress,
+    data: getCloseDepositReceiptInstructionDataEncoder().encode({}),
+  } as CloseDepositReceiptInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountDepositReceipt,
+    TAccountDepositor
+  >;
+
+  return instruction;
+}
+
+export type CloseDepositReceiptInput<
+  TAccountConfig extends string = string,
+  TAccountDepositReceipt extends string = string,
+  TAccountDepositor extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  depositReceipt: Address<TAccountDepositReceipt>;
+  depositor: TransactionSigner<TAccountDepositor>;
+};
+
+export function getCloseDepositReceiptInstruction<
+  TAccountConfig extends string,
+  TAccountDepositReceipt extends string,
+  TAccountDepositor extends string,
+>(
+  input: CloseDepositReceiptInput<
+    TAccountConfig,
+    TAccountDepositReceipt,
+    TAccountDepositor
+  >
+): CloseDepositReceiptInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountDepositReceipt,
+  TAccountDepositor
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    depositReceipt: { value: input.depositReceipt ?? null, isWritable: true },
+    depositor: { value: input.depositor ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.depositReceipt),
+      getAccountMeta(accounts.depositor),
+    ],
+    programAddress,
+    data: getCloseDepositReceiptInstructionDataEncoder().encode({}),
+  } as CloseDepositReceiptInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountDepositReceipt,
+    TAccountDepositor
+  >;
+
+  return instruction;
+}
+
+export type ParsedCloseDepositReceiptInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    depositReceipt: TAccountMetas[1];
+    depositor: TAccountMetas[2];
+  };
+  data: CloseDepositReceiptInstructionData;
+};
+
+export function parseCloseDepositReceiptInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCloseDepositReceiptInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      depositReceipt: getNextAccount(),
+      depositor: getNextAccount(),
+    },
+    data: getCloseDepositReceiptInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
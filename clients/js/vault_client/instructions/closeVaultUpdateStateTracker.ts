@@ -29,8 +29,14 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultUpdateStateTrackerPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  expectSome,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const CLOSE_VAULT_UPDATE_STATE_TRACKER_DISCRIMINATOR = 26;
 
@@ -46,6 +52,11 @@ export type CloseVaultUpdateStateTrackerInstruction<
     | string
     | IAccountMeta<string> = string,
   TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountPayerTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -64,6 +75,15 @@ export type CloseVaultUpdateStateTrackerInstruction<
         ? WritableSignerAccount<TAccountPayer> &
             IAccountSignerMeta<TAccountPayer>
         : TAccountPayer,
+      TAccountVaultTokenAccount extends string
+        ? WritableAccount<TAccountVaultTokenAccount>
+        : TAccountVaultTokenAccount,
+      TAccountPayerTokenAccount extends string
+        ? WritableAccount<TAccountPayerTokenAccount>
+        : TAccountPayerTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -107,16 +127,152 @@ export function getCloseVaultUpdateStateTrackerInstructionDataCodec(): Codec<
   );
 }
 
+export type CloseVaultUpdateStateTrackerAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultUpdateStateTracker extends string = string,
+  TAccountPayer extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountPayerTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultUpdateStateTracker?: Address<TAccountVaultUpdateStateTracker>;
+  payer: TransactionSigner<TAccountPayer>;
+  /** Required when the config has a crank reward in basis points */
+  vaultTokenAccount?: Address<TAccountVaultTokenAccount>;
+  /** The payer's associated token account for the supported mint, required when the config has a crank reward in basis points */
+  payerTokenAccount?: Address<TAccountPayerTokenAccount>;
+  /** Required when the config has a crank reward in basis points */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  ncnEpoch: CloseVaultUpdateStateTrackerInstructionDataArgs['ncnEpoch'];
+};
+
+export async function getCloseVaultUpdateStateTrackerInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultUpdateStateTracker extends string,
+  TAccountPayer extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountPayerTokenAccount extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: CloseVaultUpdateStateTrackerAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultUpdateStateTracker,
+    TAccountPayer,
+    TAccountVaultTokenAccount,
+    TAccountPayerTokenAccount,
+    TAccountTokenProgram
+  >
+): Promise<
+  CloseVaultUpdateStateTrackerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultUpdateStateTracker,
+    TAccountPayer,
+    TAccountVaultTokenAccount,
+    TAccountPayerTokenAccount,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultUpdateStateTracker: {
+      value: input.vaultUpdateStateTracker ?? null,
+      isWritable: true,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    payerTokenAccount: {
+      value: input.payerTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultUpdateStateTracker.value) {
+    accounts.vaultUpdateStateTracker.value =
+      await findVaultUpdateStateTrackerPda({
+        vault: expectAddress(accounts.vault.value),
+        ncnEpoch: expectSome(args.ncnEpoch),
+      });
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultUpdateStateTracker),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.payerTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getCloseVaultUpdateStateTrackerInstructionDataEncoder().encode(
+      args as CloseVaultUpdateStateTrackerInstructionDataArgs
+    ),
+  } as CloseVaultUpdateStateTrackerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultUpdateStateTracker,
+    TAccountPayer,
+    TAccountVaultTokenAccount,
+    TAccountPayerTokenAccount,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
 export type CloseVaultUpdateStateTrackerInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
   TAccountVaultUpdateStateTracker extends string = string,
   TAccountPayer extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountPayerTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
 > = {
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
   vaultUpdateStateTracker: Address<TAccountVaultUpdateStateTracker>;
   payer: TransactionSigner<TAccountPayer>;
+  /** Required when the config has a crank reward in basis points */
+  vaultTokenAccount?: Address<TAccountVaultTokenAccount>;
+  /** The payer's associated token account for the supported mint, required when the config has a crank reward in basis points */
+  payerTokenAccount?: Address<TAccountPayerTokenAccount>;
+  /** Required when the config has a crank reward in basis points */
+  tokenProgram?: Address<TAccountTokenProgram>;
   ncnEpoch: CloseVaultUpdateStateTrackerInstructionDataArgs['ncnEpoch'];
 };
 
@@ -125,19 +281,28 @@ export function getCloseVaultUpdateStateTrackerInstruction<
   TAccountVault extends string,
   TAccountVaultUpdateStateTracker extends string,
   TAccountPayer extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountPayerTokenAccount extends string,
+  TAccountTokenProgram extends string,
 >(
   input: CloseVaultUpdateStateTrackerInput<
     TAccountConfig,
     TAccountVault,
     TAccountVaultUpdateStateTracker,
-    TAccountPayer
+    TAccountPayer,
+    TAccountVaultTokenAccount,
+    TAccountPayerTokenAccount,
+    TAccountTokenProgram
   >
 ): CloseVaultUpdateStateTrackerInstruction<
   typeof JITO_VAULT_PROGRAM_ADDRESS,
   TAccountConfig,
   TAccountVault,
   TAccountVaultUpdateStateTracker,
-  TAccountPayer
+  TAccountPayer,
+  TAccountVaultTokenAccount,
+  TAccountPayerTokenAccount,
+  TAccountTokenProgram
 > {
   // Program address.
   const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
@@ -151,6 +316,15 @@ export function getCloseVaultUpdateStateTrackerInstruction<
       isWritable: true,
     },
     payer: { value: input.payer ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    payerTokenAccount: {
+      value: input.payerTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -160,6 +334,12 @@ export function getCloseVaultUpdateStateTrackerInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
     accounts: [
@@ -167,6 +347,9 @@ export function getCloseVaultUpdateStateTrackerInstruction<
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.vaultUpdateStateTracker),
       getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.payerTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
     ],
     programAddress,
     data: getCloseVaultUpdateStateTrackerInstructionDataEncoder().encode(
@@ -177,7 +360,10 @@ export function getCloseVaultUpdateStateTrackerInstruction<
     TAccountConfig,
     TAccountVault,
     TAccountVaultUpdateStateTracker,
-    TAccountPayer
+    TAccountPayer,
+    TAccountVaultTokenAccount,
+    TAccountPayerTokenAccount,
+    TAccountTokenProgram
   >;
 
   return instruction;
@@ -193,6 +379,12 @@ export type ParsedCloseVaultUpdateStateTrackerInstruction<
     vault: TAccountMetas[1];
     vaultUpdateStateTracker: TAccountMetas[2];
     payer: TAccountMetas[3];
+    /** Required when the config has a crank reward in basis points */
+    vaultTokenAccount?: TAccountMetas[4] | undefined;
+    /** The payer's associated token account for the supported mint, required when the config has a crank reward in basis points */
+    payerTokenAccount?: TAccountMetas[5] | undefined;
+    /** Required when the config has a crank reward in basis points */
+    tokenProgram?: TAccountMetas[6] | undefined;
   };
   data: CloseVaultUpdateStateTrackerInstructionData;
 };
@@ -205,7 +397,7 @@ export function parseCloseVaultUpdateStateTrackerInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedCloseVaultUpdateStateTrackerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -215,6 +407,12 @@ export function parseCloseVaultUpdateStateTrackerInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_VAULT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -222,6 +420,9 @@ export function parseCloseVaultUpdateStateTrackerInstruction<
       vault: getNextAccount(),
       vaultUpdateStateTracker: getNextAccount(),
       payer: getNextAccount(),
+      vaultTokenAccount: getNextOptionalAccount(),
+      payerTokenAccount: getNextOptionalAccount(),
+      tokenProgram: getNextOptionalAccount(),
     },
     data: getCloseVaultUpdateStateTrackerInstructionDataDecoder().decode(
       instruction.data
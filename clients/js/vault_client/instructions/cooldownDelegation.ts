@@ -29,8 +29,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultOperatorDelegationPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const COOLDOWN_DELEGATION_DISCRIMINATOR = 22;
 
@@ -106,6 +111,104 @@ export function getCooldownDelegationInstructionDataCodec(): Codec<
   );
 }
 
+export type CooldownDelegationAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  admin: TransactionSigner<TAccountAdmin>;
+  amount: CooldownDelegationInstructionDataArgs['amount'];
+};
+
+export async function getCooldownDelegationInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountAdmin extends string,
+>(
+  input: CooldownDelegationAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin
+  >
+): Promise<
+  CooldownDelegationInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getCooldownDelegationInstructionDataEncoder().encode(
+      args as CooldownDelegationInstructionDataArgs
+    ),
+  } as CooldownDelegationInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
 export type CooldownDelegationInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
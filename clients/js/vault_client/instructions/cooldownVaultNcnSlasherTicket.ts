@@ -27,8 +27,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultNcnSlasherTicketPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const COOLDOWN_VAULT_NCN_SLASHER_TICKET_DISCRIMINATOR = 10;
 
@@ -100,6 +105,106 @@ export function getCooldownVaultNcnSlasherTicketInstructionDataCodec(): Codec<
   );
 }
 
+export type CooldownVaultNcnSlasherTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountVaultNcnSlasherTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  slasher: Address<TAccountSlasher>;
+  vaultNcnSlasherTicket?: Address<TAccountVaultNcnSlasherTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getCooldownVaultNcnSlasherTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountSlasher extends string,
+  TAccountVaultNcnSlasherTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: CooldownVaultNcnSlasherTicketAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherTicket,
+    TAccountAdmin
+  >
+): Promise<
+  CooldownVaultNcnSlasherTicketInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherTicket,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    vaultNcnSlasherTicket: {
+      value: input.vaultNcnSlasherTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultNcnSlasherTicket.value) {
+    accounts.vaultNcnSlasherTicket.value = await findVaultNcnSlasherTicketPda({
+      vault: expectAddress(accounts.vault.value),
+      ncn: expectAddress(accounts.ncn.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.vaultNcnSlasherTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getCooldownVaultNcnSlasherTicketInstructionDataEncoder().encode({}),
+  } as CooldownVaultNcnSlasherTicketInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
 export type CooldownVaultNcnSlasherTicketInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
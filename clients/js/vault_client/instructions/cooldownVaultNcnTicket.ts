@@ -27,8 +27,13 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultNcnTicketPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const COOLDOWN_VAULT_NCN_TICKET_DISCRIMINATOR = 8;
 
@@ -94,6 +99,94 @@ export function getCooldownVaultNcnTicketInstructionDataCodec(): Codec<
   );
 }
 
+export type CooldownVaultNcnTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountVaultNcnTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  vaultNcnTicket?: Address<TAccountVaultNcnTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getCooldownVaultNcnTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountVaultNcnTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: CooldownVaultNcnTicketAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountVaultNcnTicket,
+    TAccountAdmin
+  >
+): Promise<
+  CooldownVaultNcnTicketInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountVaultNcnTicket,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    vaultNcnTicket: { value: input.vaultNcnTicket ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultNcnTicket.value) {
+    accounts.vaultNcnTicket.value = await findVaultNcnTicketPda({
+      vault: expectAddress(accounts.vault.value),
+      ncn: expectAddress(accounts.ncn.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.vaultNcnTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getCooldownVaultNcnTicketInstructionDataEncoder().encode({}),
+  } as CooldownVaultNcnTicketInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountVaultNcnTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
 export type CooldownVaultNcnTicketInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
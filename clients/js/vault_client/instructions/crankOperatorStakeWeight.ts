@@ -0,0 +1,387 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findVaultNcnTicketPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const CRANK_OPERATOR_STAKE_WEIGHT_DISCRIMINATOR = 83;
+
+export function getCrankOperatorStakeWeightDiscriminatorBytes() {
+  return getU8Encoder().encode(CRANK_OPERATOR_STAKE_WEIGHT_DISCRIMINATOR);
+}
+
+export type CrankOperatorStakeWeightInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountNcnVaultTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultNcnTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultOperatorDelegationSnapshot extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountOperatorStakeWeight extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountNcnVaultTicket extends string
+        ? ReadonlyAccount<TAccountNcnVaultTicket>
+        : TAccountNcnVaultTicket,
+      TAccountVaultNcnTicket extends string
+        ? ReadonlyAccount<TAccountVaultNcnTicket>
+        : TAccountVaultNcnTicket,
+      TAccountVaultOperatorDelegationSnapshot extends string
+        ? ReadonlyAccount<TAccountVaultOperatorDelegationSnapshot>
+        : TAccountVaultOperatorDelegationSnapshot,
+      TAccountOperatorStakeWeight extends string
+        ? WritableAccount<TAccountOperatorStakeWeight>
+        : TAccountOperatorStakeWeight,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CrankOperatorStakeWeightInstructionData = { discriminator: number };
+
+export type CrankOperatorStakeWeightInstructionDataArgs = {};
+
+export function getCrankOperatorStakeWeightInstructionDataEncoder(): Encoder<CrankOperatorStakeWeightInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: CRANK_OPERATOR_STAKE_WEIGHT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getCrankOperatorStakeWeightInstructionDataDecoder(): Decoder<CrankOperatorStakeWeightInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getCrankOperatorStakeWeightInstructionDataCodec(): Codec<
+  CrankOperatorStakeWeightInstructionDataArgs,
+  CrankOperatorStakeWeightInstructionData
+> {
+  return combineCodec(
+    getCrankOperatorStakeWeightInstructionDataEncoder(),
+    getCrankOperatorStakeWeightInstructionDataDecoder()
+  );
+}
+
+export type CrankOperatorStakeWeightAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcnVaultTicket extends string = string,
+  TAccountVaultNcnTicket extends string = string,
+  TAccountVaultOperatorDelegationSnapshot extends string = string,
+  TAccountOperatorStakeWeight extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  vault: Address<TAccountVault>;
+  ncnVaultTicket: Address<TAccountNcnVaultTicket>;
+  vaultNcnTicket?: Address<TAccountVaultNcnTicket>;
+  vaultOperatorDelegationSnapshot: Address<TAccountVaultOperatorDelegationSnapshot>;
+  operatorStakeWeight: Address<TAccountOperatorStakeWeight>;
+};
+
+export async function getCrankOperatorStakeWeightInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountVault extends string,
+  TAccountNcnVaultTicket extends string,
+  TAccountVaultNcnTicket extends string,
+  TAccountVaultOperatorDelegationSnapshot extends string,
+  TAccountOperatorStakeWeight extends string,
+>(
+  input: CrankOperatorStakeWeightAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegationSnapshot,
+    TAccountOperatorStakeWeight
+  >
+): Promise<
+  CrankOperatorStakeWeightInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegationSnapshot,
+    TAccountOperatorStakeWeight
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: false },
+    vaultNcnTicket: { value: input.vaultNcnTicket ?? null, isWritable: false },
+    vaultOperatorDelegationSnapshot: {
+      value: input.vaultOperatorDelegationSnapshot ?? null,
+      isWritable: false,
+    },
+    operatorStakeWeight: {
+      value: input.operatorStakeWeight ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultNcnTicket.value) {
+    accounts.vaultNcnTicket.value = await findVaultNcnTicketPda({
+      vault: expectAddress(accounts.vault.value),
+      ncn: expectAddress(accounts.ncn.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.vaultNcnTicket),
+      getAccountMeta(accounts.vaultOperatorDelegationSnapshot),
+      getAccountMeta(accounts.operatorStakeWeight),
+    ],
+    programAddress,
+    data: getCrankOperatorStakeWeightInstructionDataEncoder().encode({}),
+  } as CrankOperatorStakeWeightInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegationSnapshot,
+    TAccountOperatorStakeWeight
+  >;
+
+  return instruction;
+}
+
+export type CrankOperatorStakeWeightInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcnVaultTicket extends string = string,
+  TAccountVaultNcnTicket extends string = string,
+  TAccountVaultOperatorDelegationSnapshot extends string = string,
+  TAccountOperatorStakeWeight extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  vault: Address<TAccountVault>;
+  ncnVaultTicket: Address<TAccountNcnVaultTicket>;
+  vaultNcnTicket: Address<TAccountVaultNcnTicket>;
+  vaultOperatorDelegationSnapshot: Address<TAccountVaultOperatorDelegationSnapshot>;
+  operatorStakeWeight: Address<TAccountOperatorStakeWeight>;
+};
+
+export function getCrankOperatorStakeWeightInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountVault extends string,
+  TAccountNcnVaultTicket extends string,
+  TAccountVaultNcnTicket extends string,
+  TAccountVaultOperatorDelegationSnapshot extends string,
+  TAccountOperatorStakeWeight extends string,
+>(
+  input: CrankOperatorStakeWeightInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegationSnapshot,
+    TAccountOperatorStakeWeight
+  >
+): CrankOperatorStakeWeightInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountVault,
+  TAccountNcnVaultTicket,
+  TAccountVaultNcnTicket,
+  TAccountVaultOperatorDelegationSnapshot,
+  TAccountOperatorStakeWeight
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: false },
+    vaultNcnTicket: { value: input.vaultNcnTicket ?? null, isWritable: false },
+    vaultOperatorDelegationSnapshot: {
+      value: input.vaultOperatorDelegationSnapshot ?? null,
+      isWritable: false,
+    },
+    operatorStakeWeight: {
+      value: input.operatorStakeWeight ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.vaultNcnTicket),
+      getAccountMeta(accounts.vaultOperatorDelegationSnapshot),
+      getAccountMeta(accounts.operatorStakeWeight),
+    ],
+    programAddress,
+    data: getCrankOperatorStakeWeightInstructionDataEncoder().encode({}),
+  } as CrankOperatorStakeWeightInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountVault,
+    TAccountNcnVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegationSnapshot,
+    TAccountOperatorStakeWeight
+  >;
+
+  return instruction;
+}
+
+export type ParsedCrankOperatorStakeWeightInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    vault: TAccountMetas[3];
+    ncnVaultTicket: TAccountMetas[4];
+    vaultNcnTicket: TAccountMetas[5];
+    vaultOperatorDelegationSnapshot: TAccountMetas[6];
+    operatorStakeWeight: TAccountMetas[7];
+  };
+  data: CrankOperatorStakeWeightInstructionData;
+};
+
+export function parseCrankOperatorStakeWeightInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCrankOperatorStakeWeightInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      vault: getNextAccount(),
+      ncnVaultTicket: getNextAccount(),
+      vaultNcnTicket: getNextAccount(),
+      vaultOperatorDelegationSnapshot: getNextAccount(),
+      operatorStakeWeight: getNextAccount(),
+    },
+    data: getCrankOperatorStakeWeightInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
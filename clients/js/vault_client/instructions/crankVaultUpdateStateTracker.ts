@@ -24,8 +24,13 @@ import {
   type ReadonlyAccount,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultOperatorDelegationPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const CRANK_VAULT_UPDATE_STATE_TRACKER_DISCRIMINATOR = 25;
 
@@ -98,6 +103,101 @@ export function getCrankVaultUpdateStateTrackerInstructionDataCodec(): Codec<
   );
 }
 
+export type CrankVaultUpdateStateTrackerAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountVaultUpdateStateTracker extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  vaultUpdateStateTracker: Address<TAccountVaultUpdateStateTracker>;
+};
+
+export async function getCrankVaultUpdateStateTrackerInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountVaultUpdateStateTracker extends string,
+>(
+  input: CrankVaultUpdateStateTrackerAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountVaultUpdateStateTracker
+  >
+): Promise<
+  CrankVaultUpdateStateTrackerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountVaultUpdateStateTracker
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    vaultUpdateStateTracker: {
+      value: input.vaultUpdateStateTracker ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.vaultUpdateStateTracker),
+    ],
+    programAddress,
+    data: getCrankVaultUpdateStateTrackerInstructionDataEncoder().encode({}),
+  } as CrankVaultUpdateStateTrackerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountVaultUpdateStateTracker
+  >;
+
+  return instruction;
+}
+
 export type CrankVaultUpdateStateTrackerInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -0,0 +1,397 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const DISTRIBUTE_NCN_REWARDS_DISCRIMINATOR = 68;
+
+export function getDistributeNcnRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(DISTRIBUTE_NCN_REWARDS_DISCRIMINATOR);
+}
+
+export type DistributeNcnRewardsInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardRouterTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountProtocolFeeTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnRewardRouter extends string
+        ? WritableAccount<TAccountNcnRewardRouter>
+        : TAccountNcnRewardRouter,
+      TAccountNcnRewardRouterTokenAccount extends string
+        ? WritableAccount<TAccountNcnRewardRouterTokenAccount>
+        : TAccountNcnRewardRouterTokenAccount,
+      TAccountVaultTokenAccount extends string
+        ? WritableAccount<TAccountVaultTokenAccount>
+        : TAccountVaultTokenAccount,
+      TAccountProtocolFeeTokenAccount extends string
+        ? WritableAccount<TAccountProtocolFeeTokenAccount>
+        : TAccountProtocolFeeTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type DistributeNcnRewardsInstructionData = { discriminator: number };
+
+export type DistributeNcnRewardsInstructionDataArgs = {};
+
+export function getDistributeNcnRewardsInstructionDataEncoder(): Encoder<DistributeNcnRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: DISTRIBUTE_NCN_REWARDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getDistributeNcnRewardsInstructionDataDecoder(): Decoder<DistributeNcnRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getDistributeNcnRewardsInstructionDataCodec(): Codec<
+  DistributeNcnRewardsInstructionDataArgs,
+  DistributeNcnRewardsInstructionData
+> {
+  return combineCodec(
+    getDistributeNcnRewardsInstructionDataEncoder(),
+    getDistributeNcnRewardsInstructionDataDecoder()
+  );
+}
+
+export type DistributeNcnRewardsAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnRewardRouter extends string = string,
+  TAccountNcnRewardRouterTokenAccount extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountProtocolFeeTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  ncnRewardRouter: Address<TAccountNcnRewardRouter>;
+  ncnRewardRouterTokenAccount: Address<TAccountNcnRewardRouterTokenAccount>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  protocolFeeTokenAccount: Address<TAccountProtocolFeeTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export async function getDistributeNcnRewardsInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountNcnRewardRouter extends string,
+  TAccountNcnRewardRouterTokenAccount extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountProtocolFeeTokenAccount extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: DistributeNcnRewardsAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardRouterTokenAccount,
+    TAccountVaultTokenAccount,
+    TAccountProtocolFeeTokenAccount,
+    TAccountTokenProgram
+  >
+): Promise<
+  DistributeNcnRewardsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardRouterTokenAccount,
+    TAccountVaultTokenAccount,
+    TAccountProtocolFeeTokenAccount,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnRewardRouter: { value: input.ncnRewardRouter ?? null, isWritable: true },
+    ncnRewardRouterTokenAccount: {
+      value: input.ncnRewardRouterTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    protocolFeeTokenAccount: {
+      value: input.protocolFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnRewardRouter),
+      getAccountMeta(accounts.ncnRewardRouterTokenAccount),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.protocolFeeTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getDistributeNcnRewardsInstructionDataEncoder().encode({}),
+  } as DistributeNcnRewardsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardRouterTokenAccount,
+    TAccountVaultTokenAccount,
+    TAccountProtocolFeeTokenAccount,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type DistributeNcnRewardsInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnRewardRouter extends string = string,
+  TAccountNcnRewardRouterTokenAccount extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountProtocolFeeTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  ncnRewardRouter: Address<TAccountNcnRewardRouter>;
+  ncnRewardRouterTokenAccount: Address<TAccountNcnRewardRouterTokenAccount>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  protocolFeeTokenAccount: Address<TAccountProtocolFeeTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export function getDistributeNcnRewardsInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountNcnRewardRouter extends string,
+  TAccountNcnRewardRouterTokenAccount extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountProtocolFeeTokenAccount extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: DistributeNcnRewardsInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardRouterTokenAccount,
+    TAccountVaultTokenAccount,
+    TAccountProtocolFeeTokenAccount,
+    TAccountTokenProgram
+  >
+): DistributeNcnRewardsInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountNcn,
+  TAccountNcnRewardRouter,
+  TAccountNcnRewardRouterTokenAccount,
+  TAccountVaultTokenAccount,
+  TAccountProtocolFeeTokenAccount,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnRewardRouter: { value: input.ncnRewardRouter ?? null, isWritable: true },
+    ncnRewardRouterTokenAccount: {
+      value: input.ncnRewardRouterTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    protocolFeeTokenAccount: {
+      value: input.protocolFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnRewardRouter),
+      getAccountMeta(accounts.ncnRewardRouterTokenAccount),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.protocolFeeTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getDistributeNcnRewardsInstructionDataEncoder().encode({}),
+  } as DistributeNcnRewardsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountNcnRewardRouterTokenAccount,
+    TAccountVaultTokenAccount,
+    TAccountProtocolFeeTokenAccount,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedDistributeNcnRewardsInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    ncnRewardRouter: TAccountMetas[3];
+    ncnRewardRouterTokenAccount: TAccountMetas[4];
+    vaultTokenAccount: TAccountMetas[5];
+    protocolFeeTokenAccount: TAccountMetas[6];
+    tokenProgram: TAccountMetas[7];
+  };
+  data: DistributeNcnRewardsInstructionData;
+};
+
+export function parseDistributeNcnRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedDistributeNcnRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      ncn: getNextAccount(),
+      ncnRewardRouter: getNextAccount(),
+      ncnRewardRouterTokenAccount: getNextAccount(),
+      vaultTokenAccount: getNextAccount(),
+      protocolFeeTokenAccount: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getDistributeNcnRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,276 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const ENABLE_ADMIN_BURN_DISCRIMINATOR = 57;
+
+export function getEnableAdminBurnDiscriminatorBytes() {
+  return getU8Encoder().encode(ENABLE_ADMIN_BURN_DISCRIMINATOR);
+}
+
+export type EnableAdminBurnInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountBurnAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountBurnAdmin extends string
+        ? ReadonlyAccount<TAccountBurnAdmin>
+        : TAccountBurnAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type EnableAdminBurnInstructionData = { discriminator: number };
+
+export type EnableAdminBurnInstructionDataArgs = {};
+
+export function getEnableAdminBurnInstructionDataEncoder(): Encoder<EnableAdminBurnInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: ENABLE_ADMIN_BURN_DISCRIMINATOR })
+  );
+}
+
+export function getEnableAdminBurnInstructionDataDecoder(): Decoder<EnableAdminBurnInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getEnableAdminBurnInstructionDataCodec(): Codec<
+  EnableAdminBurnInstructionDataArgs,
+  EnableAdminBurnInstructionData
+> {
+  return combineCodec(
+    getEnableAdminBurnInstructionDataEncoder(),
+    getEnableAdminBurnInstructionDataDecoder()
+  );
+}
+
+export type EnableAdminBurnAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountBurnAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  burnAdmin: Address<TAccountBurnAdmin>;
+};
+
+export async function getEnableAdminBurnInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+  TAccountBurnAdmin extends string,
+>(
+  input: EnableAdminBurnAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountBurnAdmin
+  >
+): Promise<
+  EnableAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountBurnAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    burnAdmin: { value: input.burnAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.burnAdmin),
+    ],
+    programAddress,
+    data: getEnableAdminBurnInstructionDataEncoder().encode({}),
+  } as EnableAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountBurnAdmin
+  >;
+
+  return instruction;
+}
+
+export type EnableAdminBurnInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountBurnAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  burnAdmin: Address<TAccountBurnAdmin>;
+};
+
+export function getEnableAdminBurnInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+  TAccountBurnAdmin extends string,
+>(
+  input: EnableAdminBurnInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountBurnAdmin
+  >
+): EnableAdminBurnInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountAdmin,
+  TAccountBurnAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    burnAdmin: { value: input.burnAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.burnAdmin),
+    ],
+    programAddress,
+    data: getEnableAdminBurnInstructionDataEncoder().encode({}),
+  } as EnableAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountBurnAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedEnableAdminBurnInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    admin: TAccountMetas[2];
+    burnAdmin: TAccountMetas[3];
+  };
+  data: EnableAdminBurnInstructionData;
+};
+
+export function parseEnableAdminBurnInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedEnableAdminBurnInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      admin: getNextAccount(),
+      burnAdmin: getNextAccount(),
+    },
+    data: getEnableAdminBurnInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -30,8 +30,13 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultStakerWithdrawalTicketPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const ENQUEUE_WITHDRAWAL_DISCRIMINATOR = 13;
 
@@ -134,6 +139,159 @@ export function getEnqueueWithdrawalInstructionDataCodec(): Codec<
   );
 }
 
+export type EnqueueWithdrawalAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string = string,
+  TAccountStaker extends string = string,
+  TAccountStakerVrtTokenAccount extends string = string,
+  TAccountBase extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountBurnSigner extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultStakerWithdrawalTicket?: Address<TAccountVaultStakerWithdrawalTicket>;
+  vaultStakerWithdrawalTicketTokenAccount: Address<TAccountVaultStakerWithdrawalTicketTokenAccount>;
+  staker: TransactionSigner<TAccountStaker>;
+  stakerVrtTokenAccount: Address<TAccountStakerVrtTokenAccount>;
+  base: TransactionSigner<TAccountBase>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Signer for burning */
+  burnSigner?: TransactionSigner<TAccountBurnSigner>;
+  amount: EnqueueWithdrawalInstructionDataArgs['amount'];
+};
+
+export async function getEnqueueWithdrawalInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string,
+  TAccountStaker extends string,
+  TAccountStakerVrtTokenAccount extends string,
+  TAccountBase extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountBurnSigner extends string,
+>(
+  input: EnqueueWithdrawalAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountStaker,
+    TAccountStakerVrtTokenAccount,
+    TAccountBase,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner
+  >
+): Promise<
+  EnqueueWithdrawalInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountStaker,
+    TAccountStakerVrtTokenAccount,
+    TAccountBase,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    vaultStakerWithdrawalTicketTokenAccount: {
+      value: input.vaultStakerWithdrawalTicketTokenAccount ?? null,
+      isWritable: true,
+    },
+    staker: { value: input.staker ?? null, isWritable: true },
+    stakerVrtTokenAccount: {
+      value: input.stakerVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    base: { value: input.base ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    burnSigner: { value: input.burnSigner ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultStakerWithdrawalTicket.value) {
+    accounts.vaultStakerWithdrawalTicket.value =
+      await findVaultStakerWithdrawalTicketPda({
+        vault: expectAddress(accounts.vault.value),
+        base: expectAddress(accounts.base.value),
+      });
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicketTokenAccount),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.stakerVrtTokenAccount),
+      getAccountMeta(accounts.base),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.burnSigner),
+    ],
+    programAddress,
+    data: getEnqueueWithdrawalInstructionDataEncoder().encode(
+      args as EnqueueWithdrawalInstructionDataArgs
+    ),
+  } as EnqueueWithdrawalInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountStaker,
+    TAccountStakerVrtTokenAccount,
+    TAccountBase,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountBurnSigner
+  >;
+
+  return instruction;
+}
+
 export type EnqueueWithdrawalInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
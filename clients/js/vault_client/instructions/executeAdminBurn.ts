@@ -0,0 +1,508 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const EXECUTE_ADMIN_BURN_DISCRIMINATOR = 60;
+
+export function getExecuteAdminBurnDiscriminatorBytes() {
+  return getU8Encoder().encode(EXECUTE_ADMIN_BURN_DISCRIMINATOR);
+}
+
+export type ExecuteAdminBurnInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountVrtMint extends string | IAccountMeta<string> = string,
+  TAccountStaker extends string | IAccountMeta<string> = string,
+  TAccountVaultStakerWithdrawalTicket extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountVaultFeeTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountRecoveryTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountBurnAdmin extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultTokenAccount extends string
+        ? WritableAccount<TAccountVaultTokenAccount>
+        : TAccountVaultTokenAccount,
+      TAccountVrtMint extends string
+        ? WritableAccount<TAccountVrtMint>
+        : TAccountVrtMint,
+      TAccountStaker extends string
+        ? WritableAccount<TAccountStaker>
+        : TAccountStaker,
+      TAccountVaultStakerWithdrawalTicket extends string
+        ? WritableAccount<TAccountVaultStakerWithdrawalTicket>
+        : TAccountVaultStakerWithdrawalTicket,
+      TAccountVaultStakerWithdrawalTicketTokenAccount extends string
+        ? WritableAccount<TAccountVaultStakerWithdrawalTicketTokenAccount>
+        : TAccountVaultStakerWithdrawalTicketTokenAccount,
+      TAccountVaultFeeTokenAccount extends string
+        ? WritableAccount<TAccountVaultFeeTokenAccount>
+        : TAccountVaultFeeTokenAccount,
+      TAccountRecoveryTokenAccount extends string
+        ? WritableAccount<TAccountRecoveryTokenAccount>
+        : TAccountRecoveryTokenAccount,
+      TAccountBurnAdmin extends string
+        ? ReadonlySignerAccount<TAccountBurnAdmin> &
+            IAccountSignerMeta<TAccountBurnAdmin>
+        : TAccountBurnAdmin,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ExecuteAdminBurnInstructionData = { discriminator: number };
+
+export type ExecuteAdminBurnInstructionDataArgs = {};
+
+export function getExecuteAdminBurnInstructionDataEncoder(): Encoder<ExecuteAdminBurnInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: EXECUTE_ADMIN_BURN_DISCRIMINATOR })
+  );
+}
+
+export function getExecuteAdminBurnInstructionDataDecoder(): Decoder<ExecuteAdminBurnInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getExecuteAdminBurnInstructionDataCodec(): Codec<
+  ExecuteAdminBurnInstructionDataArgs,
+  ExecuteAdminBurnInstructionData
+> {
+  return combineCodec(
+    getExecuteAdminBurnInstructionDataEncoder(),
+    getExecuteAdminBurnInstructionDataDecoder()
+  );
+}
+
+export type ExecuteAdminBurnAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountStaker extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string = string,
+  TAccountVaultFeeTokenAccount extends string = string,
+  TAccountRecoveryTokenAccount extends string = string,
+  TAccountBurnAdmin extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  vrtMint: Address<TAccountVrtMint>;
+  staker: Address<TAccountStaker>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  vaultStakerWithdrawalTicketTokenAccount: Address<TAccountVaultStakerWithdrawalTicketTokenAccount>;
+  vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
+  recoveryTokenAccount: Address<TAccountRecoveryTokenAccount>;
+  burnAdmin: TransactionSigner<TAccountBurnAdmin>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getExecuteAdminBurnInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountVrtMint extends string,
+  TAccountStaker extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string,
+  TAccountVaultFeeTokenAccount extends string,
+  TAccountRecoveryTokenAccount extends string,
+  TAccountBurnAdmin extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: ExecuteAdminBurnAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountRecoveryTokenAccount,
+    TAccountBurnAdmin,
+    TAccountTokenProgram,
+    TAccountSystemProgram
+  >
+): Promise<
+  ExecuteAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountRecoveryTokenAccount,
+    TAccountBurnAdmin,
+    TAccountTokenProgram,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    staker: { value: input.staker ?? null, isWritable: true },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    vaultStakerWithdrawalTicketTokenAccount: {
+      value: input.vaultStakerWithdrawalTicketTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultFeeTokenAccount: {
+      value: input.vaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    recoveryTokenAccount: {
+      value: input.recoveryTokenAccount ?? null,
+      isWritable: true,
+    },
+    burnAdmin: { value: input.burnAdmin ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicketTokenAccount),
+      getAccountMeta(accounts.vaultFeeTokenAccount),
+      getAccountMeta(accounts.recoveryTokenAccount),
+      getAccountMeta(accounts.burnAdmin),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getExecuteAdminBurnInstructionDataEncoder().encode({}),
+  } as ExecuteAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountRecoveryTokenAccount,
+    TAccountBurnAdmin,
+    TAccountTokenProgram,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ExecuteAdminBurnInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountStaker extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string = string,
+  TAccountVaultFeeTokenAccount extends string = string,
+  TAccountRecoveryTokenAccount extends string = string,
+  TAccountBurnAdmin extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  vrtMint: Address<TAccountVrtMint>;
+  staker: Address<TAccountStaker>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  vaultStakerWithdrawalTicketTokenAccount: Address<TAccountVaultStakerWithdrawalTicketTokenAccount>;
+  vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
+  recoveryTokenAccount: Address<TAccountRecoveryTokenAccount>;
+  burnAdmin: TransactionSigner<TAccountBurnAdmin>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getExecuteAdminBurnInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountVrtMint extends string,
+  TAccountStaker extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountVaultStakerWithdrawalTicketTokenAccount extends string,
+  TAccountVaultFeeTokenAccount extends string,
+  TAccountRecoveryTokenAccount extends string,
+  TAccountBurnAdmin extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: ExecuteAdminBurnInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountRecoveryTokenAccount,
+    TAccountBurnAdmin,
+    TAccountTokenProgram,
+    TAccountSystemProgram
+  >
+): ExecuteAdminBurnInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultTokenAccount,
+  TAccountVrtMint,
+  TAccountStaker,
+  TAccountVaultStakerWithdrawalTicket,
+  TAccountVaultStakerWithdrawalTicketTokenAccount,
+  TAccountVaultFeeTokenAccount,
+  TAccountRecoveryTokenAccount,
+  TAccountBurnAdmin,
+  TAccountTokenProgram,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    staker: { value: input.staker ?? null, isWritable: true },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    vaultStakerWithdrawalTicketTokenAccount: {
+      value: input.vaultStakerWithdrawalTicketTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultFeeTokenAccount: {
+      value: input.vaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    recoveryTokenAccount: {
+      value: input.recoveryTokenAccount ?? null,
+      isWritable: true,
+    },
+    burnAdmin: { value: input.burnAdmin ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicketTokenAccount),
+      getAccountMeta(accounts.vaultFeeTokenAccount),
+      getAccountMeta(accounts.recoveryTokenAccount),
+      getAccountMeta(accounts.burnAdmin),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getExecuteAdminBurnInstructionDataEncoder().encode({}),
+  } as ExecuteAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountStaker,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountVaultStakerWithdrawalTicketTokenAccount,
+    TAccountVaultFeeTokenAccount,
+    TAccountRecoveryTokenAccount,
+    TAccountBurnAdmin,
+    TAccountTokenProgram,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedExecuteAdminBurnInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vaultTokenAccount: TAccountMetas[2];
+    vrtMint: TAccountMetas[3];
+    staker: TAccountMetas[4];
+    vaultStakerWithdrawalTicket: TAccountMetas[5];
+    vaultStakerWithdrawalTicketTokenAccount: TAccountMetas[6];
+    vaultFeeTokenAccount: TAccountMetas[7];
+    recoveryTokenAccount: TAccountMetas[8];
+    burnAdmin: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
+  };
+  data: ExecuteAdminBurnInstructionData;
+};
+
+export function parseExecuteAdminBurnInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedExecuteAdminBurnInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 12) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultTokenAccount: getNextAccount(),
+      vrtMint: getNextAccount(),
+      staker: getNextAccount(),
+      vaultStakerWithdrawalTicket: getNextAccount(),
+      vaultStakerWithdrawalTicketTokenAccount: getNextAccount(),
+      vaultFeeTokenAccount: getNextAccount(),
+      recoveryTokenAccount: getNextAccount(),
+      burnAdmin: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getExecuteAdminBurnInstructionDataDecoder().decode(instruction.data),
+  };
+}
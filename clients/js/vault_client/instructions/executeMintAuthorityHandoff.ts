@@ -0,0 +1,291 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const EXECUTE_MINT_AUTHORITY_HANDOFF_DISCRIMINATOR = 41;
+
+export function getExecuteMintAuthorityHandoffDiscriminatorBytes() {
+  return getU8Encoder().encode(EXECUTE_MINT_AUTHORITY_HANDOFF_DISCRIMINATOR);
+}
+
+export type ExecuteMintAuthorityHandoffInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVrtMint extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVrtMint extends string
+        ? WritableAccount<TAccountVrtMint>
+        : TAccountVrtMint,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ExecuteMintAuthorityHandoffInstructionData = {
+  discriminator: number;
+};
+
+export type ExecuteMintAuthorityHandoffInstructionDataArgs = {};
+
+export function getExecuteMintAuthorityHandoffInstructionDataEncoder(): Encoder<ExecuteMintAuthorityHandoffInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: EXECUTE_MINT_AUTHORITY_HANDOFF_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getExecuteMintAuthorityHandoffInstructionDataDecoder(): Decoder<ExecuteMintAuthorityHandoffInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getExecuteMintAuthorityHandoffInstructionDataCodec(): Codec<
+  ExecuteMintAuthorityHandoffInstructionDataArgs,
+  ExecuteMintAuthorityHandoffInstructionData
+> {
+  return combineCodec(
+    getExecuteMintAuthorityHandoffInstructionDataEncoder(),
+    getExecuteMintAuthorityHandoffInstructionDataDecoder()
+  );
+}
+
+export type ExecuteMintAuthorityHandoffAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vrtMint: Address<TAccountVrtMint>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export async function getExecuteMintAuthorityHandoffInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVrtMint extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: ExecuteMintAuthorityHandoffAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountTokenProgram
+  >
+): Promise<
+  ExecuteMintAuthorityHandoffInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getExecuteMintAuthorityHandoffInstructionDataEncoder().encode({}),
+  } as ExecuteMintAuthorityHandoffInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ExecuteMintAuthorityHandoffInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vrtMint: Address<TAccountVrtMint>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export function getExecuteMintAuthorityHandoffInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVrtMint extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: ExecuteMintAuthorityHandoffInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountTokenProgram
+  >
+): ExecuteMintAuthorityHandoffInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVrtMint,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getExecuteMintAuthorityHandoffInstructionDataEncoder().encode({}),
+  } as ExecuteMintAuthorityHandoffInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedExecuteMintAuthorityHandoffInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vrtMint: TAccountMetas[2];
+    tokenProgram: TAccountMetas[3];
+  };
+  data: ExecuteMintAuthorityHandoffInstructionData;
+};
+
+export function parseExecuteMintAuthorityHandoffInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedExecuteMintAuthorityHandoffInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vrtMint: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getExecuteMintAuthorityHandoffInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
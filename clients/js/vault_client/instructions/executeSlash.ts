@@ -0,0 +1,477 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findVaultOperatorDelegationPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const EXECUTE_SLASH_DISCRIMINATOR = 46;
+
+export function getExecuteSlashDiscriminatorBytes() {
+  return getU8Encoder().encode(EXECUTE_SLASH_DISCRIMINATOR);
+}
+
+export type ExecuteSlashInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountSlasher extends string | IAccountMeta<string> = string,
+  TAccountVaultOperatorDelegation extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountSlashProposal extends string | IAccountMeta<string> = string,
+  TAccountNcnVaultSlasherTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountSlashDestination extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountSlasher extends string
+        ? WritableSignerAccount<TAccountSlasher> &
+            IAccountSignerMeta<TAccountSlasher>
+        : TAccountSlasher,
+      TAccountVaultOperatorDelegation extends string
+        ? WritableAccount<TAccountVaultOperatorDelegation>
+        : TAccountVaultOperatorDelegation,
+      TAccountSlashProposal extends string
+        ? WritableAccount<TAccountSlashProposal>
+        : TAccountSlashProposal,
+      TAccountNcnVaultSlasherTicket extends string
+        ? ReadonlyAccount<TAccountNcnVaultSlasherTicket>
+        : TAccountNcnVaultSlasherTicket,
+      TAccountVaultTokenAccount extends string
+        ? WritableAccount<TAccountVaultTokenAccount>
+        : TAccountVaultTokenAccount,
+      TAccountSlashDestination extends string
+        ? WritableAccount<TAccountSlashDestination>
+        : TAccountSlashDestination,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ExecuteSlashInstructionData = { discriminator: number };
+
+export type ExecuteSlashInstructionDataArgs = {};
+
+export function getExecuteSlashInstructionDataEncoder(): Encoder<ExecuteSlashInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: EXECUTE_SLASH_DISCRIMINATOR })
+  );
+}
+
+export function getExecuteSlashInstructionDataDecoder(): Decoder<ExecuteSlashInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getExecuteSlashInstructionDataCodec(): Codec<
+  ExecuteSlashInstructionDataArgs,
+  ExecuteSlashInstructionData
+> {
+  return combineCodec(
+    getExecuteSlashInstructionDataEncoder(),
+    getExecuteSlashInstructionDataDecoder()
+  );
+}
+
+export type ExecuteSlashAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountSlashProposal extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountSlashDestination extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  slasher: TransactionSigner<TAccountSlasher>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  slashProposal: Address<TAccountSlashProposal>;
+  ncnVaultSlasherTicket: Address<TAccountNcnVaultSlasherTicket>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  slashDestination: Address<TAccountSlashDestination>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export async function getExecuteSlashInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSlasher extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountSlashProposal extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountSlashDestination extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: ExecuteSlashAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountVaultOperatorDelegation,
+    TAccountSlashProposal,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >
+): Promise<
+  ExecuteSlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountVaultOperatorDelegation,
+    TAccountSlashProposal,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: true },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    slashProposal: { value: input.slashProposal ?? null, isWritable: true },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    slashDestination: {
+      value: input.slashDestination ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.slashProposal),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.slashDestination),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getExecuteSlashInstructionDataEncoder().encode({}),
+  } as ExecuteSlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountVaultOperatorDelegation,
+    TAccountSlashProposal,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ExecuteSlashInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountSlashProposal extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountSlashDestination extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  slasher: TransactionSigner<TAccountSlasher>;
+  vaultOperatorDelegation: Address<TAccountVaultOperatorDelegation>;
+  slashProposal: Address<TAccountSlashProposal>;
+  ncnVaultSlasherTicket: Address<TAccountNcnVaultSlasherTicket>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  slashDestination: Address<TAccountSlashDestination>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export function getExecuteSlashInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSlasher extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountSlashProposal extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountSlashDestination extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: ExecuteSlashInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountVaultOperatorDelegation,
+    TAccountSlashProposal,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >
+): ExecuteSlashInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountSlasher,
+  TAccountVaultOperatorDelegation,
+  TAccountSlashProposal,
+  TAccountNcnVaultSlasherTicket,
+  TAccountVaultTokenAccount,
+  TAccountSlashDestination,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: true },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    slashProposal: { value: input.slashProposal ?? null, isWritable: true },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    slashDestination: {
+      value: input.slashDestination ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.slashProposal),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.slashDestination),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getExecuteSlashInstructionDataEncoder().encode({}),
+  } as ExecuteSlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountVaultOperatorDelegation,
+    TAccountSlashProposal,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedExecuteSlashInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    operator: TAccountMetas[3];
+    slasher: TAccountMetas[4];
+    vaultOperatorDelegation: TAccountMetas[5];
+    slashProposal: TAccountMetas[6];
+    ncnVaultSlasherTicket: TAccountMetas[7];
+    vaultTokenAccount: TAccountMetas[8];
+    slashDestination: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
+  };
+  data: ExecuteSlashInstructionData;
+};
+
+export function parseExecuteSlashInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedExecuteSlashInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 11) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      slasher: getNextAccount(),
+      vaultOperatorDelegation: getNextAccount(),
+      slashProposal: getNextAccount(),
+      ncnVaultSlasherTicket: getNextAccount(),
+      vaultTokenAccount: getNextAccount(),
+      slashDestination: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getExecuteSlashInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,413 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const FUND_INSURANCE_DISCRIMINATOR = 70;
+
+export function getFundInsuranceDiscriminatorBytes() {
+  return getU8Encoder().encode(FUND_INSURANCE_DISCRIMINATOR);
+}
+
+export type FundInsuranceInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultInsurance extends string | IAccountMeta<string> = string,
+  TAccountVrtMint extends string | IAccountMeta<string> = string,
+  TAccountAdminVrtTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountInsuranceVrtTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultInsurance extends string
+        ? WritableAccount<TAccountVaultInsurance>
+        : TAccountVaultInsurance,
+      TAccountVrtMint extends string
+        ? ReadonlyAccount<TAccountVrtMint>
+        : TAccountVrtMint,
+      TAccountAdminVrtTokenAccount extends string
+        ? WritableAccount<TAccountAdminVrtTokenAccount>
+        : TAccountAdminVrtTokenAccount,
+      TAccountInsuranceVrtTokenAccount extends string
+        ? WritableAccount<TAccountInsuranceVrtTokenAccount>
+        : TAccountInsuranceVrtTokenAccount,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type FundInsuranceInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type FundInsuranceInstructionDataArgs = { amount: number | bigint };
+
+export function getFundInsuranceInstructionDataEncoder(): Encoder<FundInsuranceInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: FUND_INSURANCE_DISCRIMINATOR })
+  );
+}
+
+export function getFundInsuranceInstructionDataDecoder(): Decoder<FundInsuranceInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getFundInsuranceInstructionDataCodec(): Codec<
+  FundInsuranceInstructionDataArgs,
+  FundInsuranceInstructionData
+> {
+  return combineCodec(
+    getFundInsuranceInstructionDataEncoder(),
+    getFundInsuranceInstructionDataDecoder()
+  );
+}
+
+export type FundInsuranceAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultInsurance extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountAdminVrtTokenAccount extends string = string,
+  TAccountInsuranceVrtTokenAccount extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultInsurance: Address<TAccountVaultInsurance>;
+  vrtMint: Address<TAccountVrtMint>;
+  adminVrtTokenAccount: Address<TAccountAdminVrtTokenAccount>;
+  insuranceVrtTokenAccount: Address<TAccountInsuranceVrtTokenAccount>;
+  admin: TransactionSigner<TAccountAdmin>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  amount: FundInsuranceInstructionDataArgs['amount'];
+};
+
+export async function getFundInsuranceInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultInsurance extends string,
+  TAccountVrtMint extends string,
+  TAccountAdminVrtTokenAccount extends string,
+  TAccountInsuranceVrtTokenAccount extends string,
+  TAccountAdmin extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: FundInsuranceAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultInsurance,
+    TAccountVrtMint,
+    TAccountAdminVrtTokenAccount,
+    TAccountInsuranceVrtTokenAccount,
+    TAccountAdmin,
+    TAccountTokenProgram
+  >
+): Promise<
+  FundInsuranceInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultInsurance,
+    TAccountVrtMint,
+    TAccountAdminVrtTokenAccount,
+    TAccountInsuranceVrtTokenAccount,
+    TAccountAdmin,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultInsurance: { value: input.vaultInsurance ?? null, isWritable: true },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: false },
+    adminVrtTokenAccount: {
+      value: input.adminVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    insuranceVrtTokenAccount: {
+      value: input.insuranceVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultInsurance),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.adminVrtTokenAccount),
+      getAccountMeta(accounts.insuranceVrtTokenAccount),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getFundInsuranceInstructionDataEncoder().encode(
+      args as FundInsuranceInstructionDataArgs
+    ),
+  } as FundInsuranceInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultInsurance,
+    TAccountVrtMint,
+    TAccountAdminVrtTokenAccount,
+    TAccountInsuranceVrtTokenAccount,
+    TAccountAdmin,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type FundInsuranceInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultInsurance extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountAdminVrtTokenAccount extends string = string,
+  TAccountInsuranceVrtTokenAccount extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultInsurance: Address<TAccountVaultInsurance>;
+  vrtMint: Address<TAccountVrtMint>;
+  adminVrtTokenAccount: Address<TAccountAdminVrtTokenAccount>;
+  insuranceVrtTokenAccount: Address<TAccountInsuranceVrtTokenAccount>;
+  admin: TransactionSigner<TAccountAdmin>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  amount: FundInsuranceInstructionDataArgs['amount'];
+};
+
+export function getFundInsuranceInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultInsurance extends string,
+  TAccountVrtMint extends string,
+  TAccountAdminVrtTokenAccount extends string,
+  TAccountInsuranceVrtTokenAccount extends string,
+  TAccountAdmin extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: FundInsuranceInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultInsurance,
+    TAccountVrtMint,
+    TAccountAdminVrtTokenAccount,
+    TAccountInsuranceVrtTokenAccount,
+    TAccountAdmin,
+    TAccountTokenProgram
+  >
+): FundInsuranceInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultInsurance,
+  TAccountVrtMint,
+  TAccountAdminVrtTokenAccount,
+  TAccountInsuranceVrtTokenAccount,
+  TAccountAdmin,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultInsurance: { value: input.vaultInsurance ?? null, isWritable: true },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: false },
+    adminVrtTokenAccount: {
+      value: input.adminVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    insuranceVrtTokenAccount: {
+      value: input.insuranceVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultInsurance),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.adminVrtTokenAccount),
+      getAccountMeta(accounts.insuranceVrtTokenAccount),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getFundInsuranceInstructionDataEncoder().encode(
+      args as FundInsuranceInstructionDataArgs
+    ),
+  } as FundInsuranceInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultInsurance,
+    TAccountVrtMint,
+    TAccountAdminVrtTokenAccount,
+    TAccountInsuranceVrtTokenAccount,
+    TAccountAdmin,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedFundInsuranceInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vaultInsurance: TAccountMetas[2];
+    vrtMint: TAccountMetas[3];
+    adminVrtTokenAccount: TAccountMetas[4];
+    insuranceVrtTokenAccount: TAccountMetas[5];
+    admin: TAccountMetas[6];
+    tokenProgram: TAccountMetas[7];
+  };
+  data: FundInsuranceInstructionData;
+};
+
+export function parseFundInsuranceInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedFundInsuranceInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultInsurance: getNextAccount(),
+      vrtMint: getNextAccount(),
+      adminVrtTokenAccount: getNextAccount(),
+      insuranceVrtTokenAccount: getNextAccount(),
+      admin: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getFundInsuranceInstructionDataDecoder().decode(instruction.data),
+  };
+}
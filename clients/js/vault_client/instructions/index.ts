@@ -7,32 +7,91 @@
  */
 
 export * from './addDelegation';
+export * from './addToDepositAllowlist';
+export * from './adminSetConfigPaused';
+export * from './adminSetCrankReward';
+export * from './adminSetDelegationCooldown';
+export * from './adminSetProtocolFee';
+export * from './adminSetSlashVeto';
+export * from './adminSetVaultEpochLengthBounds';
 export * from './adminWithdraw';
+export * from './banOperator';
 export * from './burn';
+export * from './burnDelegated';
+export * from './burnOrEnqueueWithdrawal';
 export * from './burnWithdrawTicket';
+export * from './cancelAdminBurn';
+export * from './cancelMintAuthorityHandoff';
 export * from './changeWithdrawalTicketOwner';
+export * from './claimRewards';
+export * from './closeDepositReceipt';
+export * from './closeVault';
 export * from './closeVaultUpdateStateTracker';
 export * from './cooldownDelegation';
 export * from './cooldownVaultNcnSlasherTicket';
 export * from './cooldownVaultNcnTicket';
+export * from './crankOperatorStakeWeight';
 export * from './crankVaultUpdateStateTracker';
 export * from './createTokenMetadata';
+export * from './distributeNcnRewards';
+export * from './enableAdminBurn';
 export * from './enqueueWithdrawal';
+export * from './executeAdminBurn';
+export * from './executeMintAuthorityHandoff';
+export * from './executeSlash';
+export * from './fundInsurance';
 export * from './initializeConfig';
+export * from './initializeDelegationStrategy';
+export * from './initializeNcnRewardRouter';
+export * from './initializeOperatorStakeWeight';
+export * from './initializeRewardDistribution';
 export * from './initializeVault';
+export * from './initializeVaultEpochStats';
 export * from './initializeVaultNcnSlasherOperatorTicket';
 export * from './initializeVaultNcnSlasherTicket';
 export * from './initializeVaultNcnTicket';
 export * from './initializeVaultOperatorDelegation';
+export * from './initializeVaultTokenAccount';
 export * from './initializeVaultUpdateStateTracker';
 export * from './initializeVaultWithMint';
+export * from './initiateVaultShutdown';
+export * from './migrateAccount';
+export * from './migrateToVault';
 export * from './mintTo';
+export * from './mintToWithSol';
+export * from './proposeAdminBurn';
+export * from './proposeMintAuthorityHandoff';
+export * from './proposeSlash';
+export * from './rebalanceDelegations';
+export * from './reconcileVaultBalance';
+export * from './removeFromDepositAllowlist';
+export * from './removeSlasher';
 export * from './setAdmin';
+export * from './setDelegationStrategyWeight';
 export * from './setDepositCapacity';
+export * from './setDepositLimits';
+export * from './setDepositMode';
+export * from './setFeeWallet';
 export * from './setFees';
+export * from './setFlashDepositProtection';
+export * from './setHookProgram';
+export * from './setInsuranceParams';
+export * from './setMaxTotalSlashablePerEpoch';
+export * from './setMigrationPartner';
+export * from './setMinDelegationAmount';
+export * from './setOperatorDelegationPaused';
+export * from './setOperatorMaxDelegation';
 export * from './setSecondaryAdmin';
+export * from './setSlashDisputeHaircut';
+export * from './setVaultEpochLength';
+export * from './setVaultPaused';
+export * from './setWithdrawalDelay';
+export * from './setWithdrawalTicketAutoClaimFee';
 export * from './slash';
+export * from './snapshotVaultOperatorDelegation';
+export * from './unbanOperator';
 export * from './updateTokenMetadata';
 export * from './updateVaultBalance';
+export * from './vetoSlash';
 export * from './warmupVaultNcnSlasherTicket';
 export * from './warmupVaultNcnTicket';
@@ -0,0 +1,379 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findDelegationStrategyPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const INITIALIZE_DELEGATION_STRATEGY_DISCRIMINATOR = 51;
+
+export function getInitializeDelegationStrategyDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_DELEGATION_STRATEGY_DISCRIMINATOR);
+}
+
+export type InitializeDelegationStrategyInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountDelegationStrategy extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountDelegationStrategy extends string
+        ? WritableAccount<TAccountDelegationStrategy>
+        : TAccountDelegationStrategy,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeDelegationStrategyInstructionData = {
+  discriminator: number;
+  maxMovementPerEpoch: bigint;
+};
+
+export type InitializeDelegationStrategyInstructionDataArgs = {
+  maxMovementPerEpoch: number | bigint;
+};
+
+export function getInitializeDelegationStrategyInstructionDataEncoder(): Encoder<InitializeDelegationStrategyInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['maxMovementPerEpoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_DELEGATION_STRATEGY_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeDelegationStrategyInstructionDataDecoder(): Decoder<InitializeDelegationStrategyInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['maxMovementPerEpoch', getU64Decoder()],
+  ]);
+}
+
+export function getInitializeDelegationStrategyInstructionDataCodec(): Codec<
+  InitializeDelegationStrategyInstructionDataArgs,
+  InitializeDelegationStrategyInstructionData
+> {
+  return combineCodec(
+    getInitializeDelegationStrategyInstructionDataEncoder(),
+    getInitializeDelegationStrategyInstructionDataDecoder()
+  );
+}
+
+export type InitializeDelegationStrategyAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountDelegationStrategy extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  delegationStrategy?: Address<TAccountDelegationStrategy>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  maxMovementPerEpoch: InitializeDelegationStrategyInstructionDataArgs['maxMovementPerEpoch'];
+};
+
+export async function getInitializeDelegationStrategyInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountDelegationStrategy extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeDelegationStrategyAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountDelegationStrategy,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeDelegationStrategyInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountDelegationStrategy,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    delegationStrategy: {
+      value: input.delegationStrategy ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.delegationStrategy.value) {
+    accounts.delegationStrategy.value = await findDelegationStrategyPda({
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.delegationStrategy),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeDelegationStrategyInstructionDataEncoder().encode(
+      args as InitializeDelegationStrategyInstructionDataArgs
+    ),
+  } as InitializeDelegationStrategyInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountDelegationStrategy,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type InitializeDelegationStrategyInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountDelegationStrategy extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  delegationStrategy: Address<TAccountDelegationStrategy>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  maxMovementPerEpoch: InitializeDelegationStrategyInstructionDataArgs['maxMovementPerEpoch'];
+};
+
+export function getInitializeDelegationStrategyInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountDelegationStrategy extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeDelegationStrategyInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountDelegationStrategy,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): InitializeDelegationStrategyInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountDelegationStrategy,
+  TAccountAdmin,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    delegationStrategy: {
+      value: input.delegationStrategy ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.delegationStrategy),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeDelegationStrategyInstructionDataEncoder().encode(
+      args as InitializeDelegationStrategyInstructionDataArgs
+    ),
+  } as InitializeDelegationStrategyInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountDelegationStrategy,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeDelegationStrategyInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    delegationStrategy: TAccountMetas[2];
+    admin: TAccountMetas[3];
+    payer: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
+  };
+  data: InitializeDelegationStrategyInstructionData;
+};
+
+export function parseInitializeDelegationStrategyInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeDelegationStrategyInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      delegationStrategy: getNextAccount(),
+      admin: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeDelegationStrategyInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
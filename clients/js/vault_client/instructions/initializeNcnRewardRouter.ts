@@ -0,0 +1,360 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_NCN_REWARD_ROUTER_DISCRIMINATOR = 67;
+
+export function getInitializeNcnRewardRouterDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_NCN_REWARD_ROUTER_DISCRIMINATOR);
+}
+
+export type InitializeNcnRewardRouterInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnRewardRouter extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnRewardRouter extends string
+        ? WritableAccount<TAccountNcnRewardRouter>
+        : TAccountNcnRewardRouter,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeNcnRewardRouterInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+};
+
+export type InitializeNcnRewardRouterInstructionDataArgs = {
+  epoch: number | bigint;
+};
+
+export function getInitializeNcnRewardRouterInstructionDataEncoder(): Encoder<InitializeNcnRewardRouterInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_NCN_REWARD_ROUTER_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeNcnRewardRouterInstructionDataDecoder(): Decoder<InitializeNcnRewardRouterInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+  ]);
+}
+
+export function getInitializeNcnRewardRouterInstructionDataCodec(): Codec<
+  InitializeNcnRewardRouterInstructionDataArgs,
+  InitializeNcnRewardRouterInstructionData
+> {
+  return combineCodec(
+    getInitializeNcnRewardRouterInstructionDataEncoder(),
+    getInitializeNcnRewardRouterInstructionDataDecoder()
+  );
+}
+
+export type InitializeNcnRewardRouterAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnRewardRouter extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  ncnRewardRouter: Address<TAccountNcnRewardRouter>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  epoch: InitializeNcnRewardRouterInstructionDataArgs['epoch'];
+};
+
+export async function getInitializeNcnRewardRouterInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountNcnRewardRouter extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeNcnRewardRouterAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeNcnRewardRouterInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnRewardRouter: { value: input.ncnRewardRouter ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnRewardRouter),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeNcnRewardRouterInstructionDataEncoder().encode(
+      args as InitializeNcnRewardRouterInstructionDataArgs
+    ),
+  } as InitializeNcnRewardRouterInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type InitializeNcnRewardRouterInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnRewardRouter extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  ncnRewardRouter: Address<TAccountNcnRewardRouter>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  epoch: InitializeNcnRewardRouterInstructionDataArgs['epoch'];
+};
+
+export function getInitializeNcnRewardRouterInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountNcnRewardRouter extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeNcnRewardRouterInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): InitializeNcnRewardRouterInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountNcn,
+  TAccountNcnRewardRouter,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnRewardRouter: { value: input.ncnRewardRouter ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnRewardRouter),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeNcnRewardRouterInstructionDataEncoder().encode(
+      args as InitializeNcnRewardRouterInstructionDataArgs
+    ),
+  } as InitializeNcnRewardRouterInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountNcnRewardRouter,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeNcnRewardRouterInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    ncnRewardRouter: TAccountMetas[3];
+    payer: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
+  };
+  data: InitializeNcnRewardRouterInstructionData;
+};
+
+export function parseInitializeNcnRewardRouterInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeNcnRewardRouterInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      ncn: getNextAccount(),
+      ncnRewardRouter: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeNcnRewardRouterInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
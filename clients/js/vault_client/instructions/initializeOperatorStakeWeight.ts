@@ -0,0 +1,365 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_OPERATOR_STAKE_WEIGHT_DISCRIMINATOR = 82;
+
+export function getInitializeOperatorStakeWeightDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_OPERATOR_STAKE_WEIGHT_DISCRIMINATOR);
+}
+
+export type InitializeOperatorStakeWeightInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountSupportedMint extends string | IAccountMeta<string> = string,
+  TAccountOperatorStakeWeight extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountSupportedMint extends string
+        ? ReadonlyAccount<TAccountSupportedMint>
+        : TAccountSupportedMint,
+      TAccountOperatorStakeWeight extends string
+        ? WritableAccount<TAccountOperatorStakeWeight>
+        : TAccountOperatorStakeWeight,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeOperatorStakeWeightInstructionData = {
+  discriminator: number;
+};
+
+export type InitializeOperatorStakeWeightInstructionDataArgs = {};
+
+export function getInitializeOperatorStakeWeightInstructionDataEncoder(): Encoder<InitializeOperatorStakeWeightInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_OPERATOR_STAKE_WEIGHT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeOperatorStakeWeightInstructionDataDecoder(): Decoder<InitializeOperatorStakeWeightInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeOperatorStakeWeightInstructionDataCodec(): Codec<
+  InitializeOperatorStakeWeightInstructionDataArgs,
+  InitializeOperatorStakeWeightInstructionData
+> {
+  return combineCodec(
+    getInitializeOperatorStakeWeightInstructionDataEncoder(),
+    getInitializeOperatorStakeWeightInstructionDataDecoder()
+  );
+}
+
+export type InitializeOperatorStakeWeightAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSupportedMint extends string = string,
+  TAccountOperatorStakeWeight extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  supportedMint: Address<TAccountSupportedMint>;
+  operatorStakeWeight: Address<TAccountOperatorStakeWeight>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getInitializeOperatorStakeWeightInstructionAsync<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSupportedMint extends string,
+  TAccountOperatorStakeWeight extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeOperatorStakeWeightAsyncInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSupportedMint,
+    TAccountOperatorStakeWeight,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeOperatorStakeWeightInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSupportedMint,
+    TAccountOperatorStakeWeight,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    supportedMint: { value: input.supportedMint ?? null, isWritable: false },
+    operatorStakeWeight: {
+      value: input.operatorStakeWeight ?? null,
+      isWritable: true,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.supportedMint),
+      getAccountMeta(accounts.operatorStakeWeight),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeOperatorStakeWeightInstructionDataEncoder().encode({}),
+  } as InitializeOperatorStakeWeightInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSupportedMint,
+    TAccountOperatorStakeWeight,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type InitializeOperatorStakeWeightInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSupportedMint extends string = string,
+  TAccountOperatorStakeWeight extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  supportedMint: Address<TAccountSupportedMint>;
+  operatorStakeWeight: Address<TAccountOperatorStakeWeight>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getInitializeOperatorStakeWeightInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSupportedMint extends string,
+  TAccountOperatorStakeWeight extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeOperatorStakeWeightInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSupportedMint,
+    TAccountOperatorStakeWeight,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): InitializeOperatorStakeWeightInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountSupportedMint,
+  TAccountOperatorStakeWeight,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    supportedMint: { value: input.supportedMint ?? null, isWritable: false },
+    operatorStakeWeight: {
+      value: input.operatorStakeWeight ?? null,
+      isWritable: true,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.supportedMint),
+      getAccountMeta(accounts.operatorStakeWeight),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeOperatorStakeWeightInstructionDataEncoder().encode({}),
+  } as InitializeOperatorStakeWeightInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSupportedMint,
+    TAccountOperatorStakeWeight,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeOperatorStakeWeightInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    supportedMint: TAccountMetas[3];
+    operatorStakeWeight: TAccountMetas[4];
+    payer: TAccountMetas[5];
+    systemProgram: TAccountMetas[6];
+  };
+  data: InitializeOperatorStakeWeightInstructionData;
+};
+
+export function parseInitializeOperatorStakeWeightInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeOperatorStakeWeightInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      supportedMint: getNextAccount(),
+      operatorStakeWeight: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeOperatorStakeWeightInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,539 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_REWARD_DISTRIBUTION_DISCRIMINATOR = 64;
+
+export function getInitializeRewardDistributionDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_REWARD_DISTRIBUTION_DISCRIMINATOR);
+}
+
+export type InitializeRewardDistributionInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountRewardDistribution extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountVaultRewardsTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountAdminTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountOperatorFeeTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountRewardDistribution extends string
+        ? WritableAccount<TAccountRewardDistribution>
+        : TAccountRewardDistribution,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountVaultRewardsTokenAccount extends string
+        ? WritableAccount<TAccountVaultRewardsTokenAccount>
+        : TAccountVaultRewardsTokenAccount,
+      TAccountAdminTokenAccount extends string
+        ? WritableAccount<TAccountAdminTokenAccount>
+        : TAccountAdminTokenAccount,
+      TAccountAdmin extends string
+        ? WritableSignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountOperatorFeeTokenAccount extends string
+        ? WritableAccount<TAccountOperatorFeeTokenAccount>
+        : TAccountOperatorFeeTokenAccount,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeRewardDistributionInstructionData = {
+  discriminator: number;
+  epoch: bigint;
+  merkleRoot: ReadonlyUint8Array;
+  totalRewards: bigint;
+};
+
+export type InitializeRewardDistributionInstructionDataArgs = {
+  epoch: number | bigint;
+  merkleRoot: ReadonlyUint8Array;
+  totalRewards: number | bigint;
+};
+
+export function getInitializeRewardDistributionInstructionDataEncoder(): Encoder<InitializeRewardDistributionInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['epoch', getU64Encoder()],
+      ['merkleRoot', fixEncoderSize(getBytesEncoder(), 32)],
+      ['totalRewards', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_REWARD_DISTRIBUTION_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeRewardDistributionInstructionDataDecoder(): Decoder<InitializeRewardDistributionInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['epoch', getU64Decoder()],
+    ['merkleRoot', fixDecoderSize(getBytesDecoder(), 32)],
+    ['totalRewards', getU64Decoder()],
+  ]);
+}
+
+export function getInitializeRewardDistributionInstructionDataCodec(): Codec<
+  InitializeRewardDistributionInstructionDataArgs,
+  InitializeRewardDistributionInstructionData
+> {
+  return combineCodec(
+    getInitializeRewardDistributionInstructionDataEncoder(),
+    getInitializeRewardDistributionInstructionDataDecoder()
+  );
+}
+
+export type InitializeRewardDistributionAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountRewardDistribution extends string = string,
+  TAccountMint extends string = string,
+  TAccountVaultRewardsTokenAccount extends string = string,
+  TAccountAdminTokenAccount extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountOperator extends string = string,
+  TAccountOperatorFeeTokenAccount extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  rewardDistribution: Address<TAccountRewardDistribution>;
+  mint: Address<TAccountMint>;
+  vaultRewardsTokenAccount: Address<TAccountVaultRewardsTokenAccount>;
+  adminTokenAccount: Address<TAccountAdminTokenAccount>;
+  admin: TransactionSigner<TAccountAdmin>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** The operator the rewards were earned through, paid its commission */
+  operator?: Address<TAccountOperator>;
+  /** The operator withdrawal fee wallet's token account for the reward mint */
+  operatorFeeTokenAccount?: Address<TAccountOperatorFeeTokenAccount>;
+  epoch: InitializeRewardDistributionInstructionDataArgs['epoch'];
+  merkleRoot: InitializeRewardDistributionInstructionDataArgs['merkleRoot'];
+  totalRewards: InitializeRewardDistributionInstructionDataArgs['totalRewards'];
+};
+
+export async function getInitializeRewardDistributionInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountRewardDistribution extends string,
+  TAccountMint extends string,
+  TAccountVaultRewardsTokenAccount extends string,
+  TAccountAdminTokenAccount extends string,
+  TAccountAdmin extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountOperator extends string,
+  TAccountOperatorFeeTokenAccount extends string,
+>(
+  input: InitializeRewardDistributionAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountRewardDistribution,
+    TAccountMint,
+    TAccountVaultRewardsTokenAccount,
+    TAccountAdminTokenAccount,
+    TAccountAdmin,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountOperator,
+    TAccountOperatorFeeTokenAccount
+  >
+): Promise<
+  InitializeRewardDistributionInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountRewardDistribution,
+    TAccountMint,
+    TAccountVaultRewardsTokenAccount,
+    TAccountAdminTokenAccount,
+    TAccountAdmin,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountOperator,
+    TAccountOperatorFeeTokenAccount
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    rewardDistribution: {
+      value: input.rewardDistribution ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    vaultRewardsTokenAccount: {
+      value: input.vaultRewardsTokenAccount ?? null,
+      isWritable: true,
+    },
+    adminTokenAccount: {
+      value: input.adminTokenAccount ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    operatorFeeTokenAccount: {
+      value: input.operatorFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.rewardDistribution),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.vaultRewardsTokenAccount),
+      getAccountMeta(accounts.adminTokenAccount),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.operatorFeeTokenAccount),
+    ],
+    programAddress,
+    data: getInitializeRewardDistributionInstructionDataEncoder().encode(
+      args as InitializeRewardDistributionInstructionDataArgs
+    ),
+  } as InitializeRewardDistributionInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountRewardDistribution,
+    TAccountMint,
+    TAccountVaultRewardsTokenAccount,
+    TAccountAdminTokenAccount,
+    TAccountAdmin,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountOperator,
+    TAccountOperatorFeeTokenAccount
+  >;
+
+  return instruction;
+}
+
+export type InitializeRewardDistributionInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountRewardDistribution extends string = string,
+  TAccountMint extends string = string,
+  TAccountVaultRewardsTokenAccount extends string = string,
+  TAccountAdminTokenAccount extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountOperator extends string = string,
+  TAccountOperatorFeeTokenAccount extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  rewardDistribution: Address<TAccountRewardDistribution>;
+  mint: Address<TAccountMint>;
+  vaultRewardsTokenAccount: Address<TAccountVaultRewardsTokenAccount>;
+  adminTokenAccount: Address<TAccountAdminTokenAccount>;
+  admin: TransactionSigner<TAccountAdmin>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** The operator the rewards were earned through, paid its commission */
+  operator?: Address<TAccountOperator>;
+  /** The operator withdrawal fee wallet's token account for the reward mint */
+  operatorFeeTokenAccount?: Address<TAccountOperatorFeeTokenAccount>;
+  epoch: InitializeRewardDistributionInstructionDataArgs['epoch'];
+  merkleRoot: InitializeRewardDistributionInstructionDataArgs['merkleRoot'];
+  totalRewards: InitializeRewardDistributionInstructionDataArgs['totalRewards'];
+};
+
+export function getInitializeRewardDistributionInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountRewardDistribution extends string,
+  TAccountMint extends string,
+  TAccountVaultRewardsTokenAccount extends string,
+  TAccountAdminTokenAccount extends string,
+  TAccountAdmin extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountOperator extends string,
+  TAccountOperatorFeeTokenAccount extends string,
+>(
+  input: InitializeRewardDistributionInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountRewardDistribution,
+    TAccountMint,
+    TAccountVaultRewardsTokenAccount,
+    TAccountAdminTokenAccount,
+    TAccountAdmin,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountOperator,
+    TAccountOperatorFeeTokenAccount
+  >
+): InitializeRewardDistributionInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountRewardDistribution,
+  TAccountMint,
+  TAccountVaultRewardsTokenAccount,
+  TAccountAdminTokenAccount,
+  TAccountAdmin,
+  TAccountTokenProgram,
+  TAccountSystemProgram,
+  TAccountOperator,
+  TAccountOperatorFeeTokenAccount
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    rewardDistribution: {
+      value: input.rewardDistribution ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    vaultRewardsTokenAccount: {
+      value: input.vaultRewardsTokenAccount ?? null,
+      isWritable: true,
+    },
+    adminTokenAccount: {
+      value: input.adminTokenAccount ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    operatorFeeTokenAccount: {
+      value: input.operatorFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.rewardDistribution),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.vaultRewardsTokenAccount),
+      getAccountMeta(accounts.adminTokenAccount),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.operatorFeeTokenAccount),
+    ],
+    programAddress,
+    data: getInitializeRewardDistributionInstructionDataEncoder().encode(
+      args as InitializeRewardDistributionInstructionDataArgs
+    ),
+  } as InitializeRewardDistributionInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountRewardDistribution,
+    TAccountMint,
+    TAccountVaultRewardsTokenAccount,
+    TAccountAdminTokenAccount,
+    TAccountAdmin,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountOperator,
+    TAccountOperatorFeeTokenAccount
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeRewardDistributionInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    rewardDistribution: TAccountMetas[2];
+    mint: TAccountMetas[3];
+    vaultRewardsTokenAccount: TAccountMetas[4];
+    adminTokenAccount: TAccountMetas[5];
+    admin: TAccountMetas[6];
+    tokenProgram: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
+    /** The operator the rewards were earned through, paid its commission */
+    operator?: TAccountMetas[9] | undefined;
+    /** The operator withdrawal fee wallet's token account for the reward mint */
+    operatorFeeTokenAccount?: TAccountMetas[10] | undefined;
+  };
+  data: InitializeRewardDistributionInstructionData;
+};
+
+export function parseInitializeRewardDistributionInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeRewardDistributionInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 11) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_VAULT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      rewardDistribution: getNextAccount(),
+      mint: getNextAccount(),
+      vaultRewardsTokenAccount: getNextAccount(),
+      adminTokenAccount: getNextAccount(),
+      admin: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      systemProgram: getNextAccount(),
+      operator: getNextOptionalAccount(),
+      operatorFeeTokenAccount: getNextOptionalAccount(),
+    },
+    data: getInitializeRewardDistributionInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -30,8 +30,13 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_VAULT_DISCRIMINATOR = 1;
 
@@ -136,6 +141,134 @@ export function getInitializeVaultInstructionDataCodec(): Codec<
   );
 }
 
+export type InitializeVaultAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountTokenMint extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountBase extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault?: Address<TAccountVault>;
+  vrtMint: TransactionSigner<TAccountVrtMint>;
+  tokenMint: Address<TAccountTokenMint>;
+  admin: TransactionSigner<TAccountAdmin>;
+  base: TransactionSigner<TAccountBase>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  depositFeeBps: InitializeVaultInstructionDataArgs['depositFeeBps'];
+  withdrawalFeeBps: InitializeVaultInstructionDataArgs['withdrawalFeeBps'];
+  rewardFeeBps: InitializeVaultInstructionDataArgs['rewardFeeBps'];
+  decimals: InitializeVaultInstructionDataArgs['decimals'];
+};
+
+export async function getInitializeVaultInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVrtMint extends string,
+  TAccountTokenMint extends string,
+  TAccountAdmin extends string,
+  TAccountBase extends string,
+  TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: InitializeVaultAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountTokenMint,
+    TAccountAdmin,
+    TAccountBase,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+): Promise<
+  InitializeVaultInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountTokenMint,
+    TAccountAdmin,
+    TAccountBase,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    tokenMint: { value: input.tokenMint ?? null, isWritable: false },
+    admin: { value: input.admin ?? null, isWritable: true },
+    base: { value: input.base ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vault.value) {
+    accounts.vault.value = await findVaultPda({
+      base: expectAddress(accounts.base.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.tokenMint),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.base),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getInitializeVaultInstructionDataEncoder().encode(
+      args as InitializeVaultInstructionDataArgs
+    ),
+  } as InitializeVaultInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountTokenMint,
+    TAccountAdmin,
+    TAccountBase,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeVaultInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -27,8 +27,13 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultNcnSlasherTicketPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_VAULT_NCN_SLASHER_OPERATOR_TICKET_DISCRIMINATOR = 5;
 
@@ -118,6 +123,139 @@ export function getInitializeVaultNcnSlasherOperatorTicketInstructionDataCodec()
   );
 }
 
+export type InitializeVaultNcnSlasherOperatorTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultNcnSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherOperatorTicket extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  slasher: Address<TAccountSlasher>;
+  operator: Address<TAccountOperator>;
+  vaultNcnSlasherTicket?: Address<TAccountVaultNcnSlasherTicket>;
+  vaultNcnSlasherOperatorTicket: Address<TAccountVaultNcnSlasherOperatorTicket>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getInitializeVaultNcnSlasherOperatorTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountSlasher extends string,
+  TAccountOperator extends string,
+  TAccountVaultNcnSlasherTicket extends string,
+  TAccountVaultNcnSlasherOperatorTicket extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeVaultNcnSlasherOperatorTicketAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountOperator,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeVaultNcnSlasherOperatorTicketInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountOperator,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultNcnSlasherTicket: {
+      value: input.vaultNcnSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherOperatorTicket: {
+      value: input.vaultNcnSlasherOperatorTicket ?? null,
+      isWritable: true,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultNcnSlasherTicket.value) {
+    accounts.vaultNcnSlasherTicket.value = await findVaultNcnSlasherTicketPda({
+      vault: expectAddress(accounts.vault.value),
+      ncn: expectAddress(accounts.ncn.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultNcnSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherOperatorTicket),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeVaultNcnSlasherOperatorTicketInstructionDataEncoder().encode(
+      {}
+    ),
+  } as InitializeVaultNcnSlasherOperatorTicketInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountOperator,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeVaultNcnSlasherOperatorTicketInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -28,8 +28,13 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultNcnSlasherTicketPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_VAULT_NCN_SLASHER_TICKET_DISCRIMINATOR = 6;
 
@@ -118,6 +123,137 @@ export function getInitializeVaultNcnSlasherTicketInstructionDataCodec(): Codec<
   );
 }
 
+export type InitializeVaultNcnSlasherTicketAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnSlasherTicket extends string = string,
+  TAccountVaultSlasherTicket extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  slasher: Address<TAccountSlasher>;
+  ncnSlasherTicket: Address<TAccountNcnSlasherTicket>;
+  vaultSlasherTicket?: Address<TAccountVaultSlasherTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getInitializeVaultNcnSlasherTicketInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountSlasher extends string,
+  TAccountNcnSlasherTicket extends string,
+  TAccountVaultSlasherTicket extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeVaultNcnSlasherTicketAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountNcnSlasherTicket,
+    TAccountVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeVaultNcnSlasherTicketInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountNcnSlasherTicket,
+    TAccountVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    ncnSlasherTicket: {
+      value: input.ncnSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultSlasherTicket: {
+      value: input.vaultSlasherTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultSlasherTicket.value) {
+    accounts.vaultSlasherTicket.value = await findVaultNcnSlasherTicketPda({
+      vault: expectAddress(accounts.vault.value),
+      ncn: expectAddress(accounts.ncn.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnSlasherTicket),
+      getAccountMeta(accounts.vaultSlasherTicket),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeVaultNcnSlasherTicketInstructionDataEncoder().encode({}),
+  } as InitializeVaultNcnSlasherTicketInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountNcnSlasherTicket,
+    TAccountVaultSlasherTicket,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeVaultNcnSlasherTicketInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
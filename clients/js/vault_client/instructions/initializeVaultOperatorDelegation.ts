@@ -28,8 +28,13 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda, findVaultOperatorDelegationPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const INITIALIZE_VAULT_OPERATOR_DELEGATION_DISCRIMINATOR = 3;
 
@@ -118,6 +123,131 @@ export function getInitializeVaultOperatorDelegationInstructionDataCodec(): Code
   );
 }
 
+export type InitializeVaultOperatorDelegationAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  operatorVaultTicket: Address<TAccountOperatorVaultTicket>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  admin: TransactionSigner<TAccountAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getInitializeVaultOperatorDelegationInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountOperatorVaultTicket extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeVaultOperatorDelegationAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountOperatorVaultTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeVaultOperatorDelegationInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountOperatorVaultTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    operator: { value: input.operator ?? null, isWritable: true },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: false,
+    },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.operatorVaultTicket),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeVaultOperatorDelegationInstructionDataEncoder().encode(
+      {}
+    ),
+  } as InitializeVaultOperatorDelegationInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountOperatorVaultTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeVaultOperatorDelegationInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
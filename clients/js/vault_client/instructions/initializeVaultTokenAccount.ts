@@ -0,0 +1,270 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_VAULT_TOKEN_ACCOUNT_DISCRIMINATOR = 74;
+
+export function getInitializeVaultTokenAccountDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_VAULT_TOKEN_ACCOUNT_DISCRIMINATOR);
+}
+
+export type InitializeVaultTokenAccountInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountSupportedMint extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountAssociatedTokenProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultTokenAccount extends string
+        ? WritableAccount<TAccountVaultTokenAccount>
+        : TAccountVaultTokenAccount,
+      TAccountSupportedMint extends string
+        ? ReadonlyAccount<TAccountSupportedMint>
+        : TAccountSupportedMint,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+        : TAccountAssociatedTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeVaultTokenAccountInstructionData = {
+  discriminator: number;
+};
+
+export type InitializeVaultTokenAccountInstructionDataArgs = {};
+
+export function getInitializeVaultTokenAccountInstructionDataEncoder(): Encoder<InitializeVaultTokenAccountInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_VAULT_TOKEN_ACCOUNT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeVaultTokenAccountInstructionDataDecoder(): Decoder<InitializeVaultTokenAccountInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeVaultTokenAccountInstructionDataCodec(): Codec<
+  InitializeVaultTokenAccountInstructionDataArgs,
+  InitializeVaultTokenAccountInstructionData
+> {
+  return combineCodec(
+    getInitializeVaultTokenAccountInstructionDataEncoder(),
+    getInitializeVaultTokenAccountInstructionDataDecoder()
+  );
+}
+
+export type InitializeVaultTokenAccountInput<
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountSupportedMint extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
+> = {
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  supportedMint: Address<TAccountSupportedMint>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram: Address<TAccountAssociatedTokenProgram>;
+};
+
+export function getInitializeVaultTokenAccountInstruction<
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountSupportedMint extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
+>(
+  input: InitializeVaultTokenAccountInput<
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountSupportedMint,
+    TAccountPayer,
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram
+  >
+): InitializeVaultTokenAccountInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountVault,
+  TAccountVaultTokenAccount,
+  TAccountSupportedMint,
+  TAccountPayer,
+  TAccountSystemProgram,
+  TAccountTokenProgram,
+  TAccountAssociatedTokenProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    supportedMint: { value: input.supportedMint ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.supportedMint),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
+    ],
+    programAddress,
+    data: getInitializeVaultTokenAccountInstructionDataEncoder().encode({}),
+  } as InitializeVaultTokenAccountInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountSupportedMint,
+    TAccountPayer,
+    TAccountSystemProgram,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeVaultTokenAccountInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    vault: TAccountMetas[0];
+    vaultTokenAccount: TAccountMetas[1];
+    supportedMint: TAccountMetas[2];
+    payer: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
+    tokenProgram: TAccountMetas[5];
+    associatedTokenProgram: TAccountMetas[6];
+  };
+  data: InitializeVaultTokenAccountInstructionData;
+};
+
+export function parseInitializeVaultTokenAccountInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeVaultTokenAccountInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      vault: getNextAccount(),
+      vaultTokenAccount: getNextAccount(),
+      supportedMint: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      associatedTokenProgram: getNextAccount(),
+    },
+    data: getInitializeVaultTokenAccountInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -24,6 +24,7 @@ import {
   type ReadonlyAccount,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
@@ -115,6 +116,101 @@ export function getInitializeVaultUpdateStateTrackerInstructionDataCodec(): Code
   );
 }
 
+export type InitializeVaultUpdateStateTrackerAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultUpdateStateTracker extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultUpdateStateTracker: Address<TAccountVaultUpdateStateTracker>;
+  payer: Address<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  withdrawalAllocationMethod: InitializeVaultUpdateStateTrackerInstructionDataArgs['withdrawalAllocationMethod'];
+};
+
+export async function getInitializeVaultUpdateStateTrackerInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultUpdateStateTracker extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeVaultUpdateStateTrackerAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultUpdateStateTracker,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  InitializeVaultUpdateStateTrackerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultUpdateStateTracker,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultUpdateStateTracker: {
+      value: input.vaultUpdateStateTracker ?? null,
+      isWritable: true,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultUpdateStateTracker),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeVaultUpdateStateTrackerInstructionDataEncoder().encode(
+      args as InitializeVaultUpdateStateTrackerInstructionDataArgs
+    ),
+  } as InitializeVaultUpdateStateTrackerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultUpdateStateTracker,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type InitializeVaultUpdateStateTrackerInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -0,0 +1,259 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIATE_VAULT_SHUTDOWN_DISCRIMINATOR = 66;
+
+export function getInitiateVaultShutdownDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIATE_VAULT_SHUTDOWN_DISCRIMINATOR);
+}
+
+export type InitiateVaultShutdownInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitiateVaultShutdownInstructionData = { discriminator: number };
+
+export type InitiateVaultShutdownInstructionDataArgs = {};
+
+export function getInitiateVaultShutdownInstructionDataEncoder(): Encoder<InitiateVaultShutdownInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: INITIATE_VAULT_SHUTDOWN_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitiateVaultShutdownInstructionDataDecoder(): Decoder<InitiateVaultShutdownInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitiateVaultShutdownInstructionDataCodec(): Codec<
+  InitiateVaultShutdownInstructionDataArgs,
+  InitiateVaultShutdownInstructionData
+> {
+  return combineCodec(
+    getInitiateVaultShutdownInstructionDataEncoder(),
+    getInitiateVaultShutdownInstructionDataDecoder()
+  );
+}
+
+export type InitiateVaultShutdownAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getInitiateVaultShutdownInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+>(
+  input: InitiateVaultShutdownAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+): Promise<
+  InitiateVaultShutdownInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getInitiateVaultShutdownInstructionDataEncoder().encode({}),
+  } as InitiateVaultShutdownInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type InitiateVaultShutdownInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export function getInitiateVaultShutdownInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+>(
+  input: InitiateVaultShutdownInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+): InitiateVaultShutdownInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getInitiateVaultShutdownInstructionDataEncoder().encode({}),
+  } as InitiateVaultShutdownInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitiateVaultShutdownInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    admin: TAccountMetas[2];
+  };
+  data: InitiateVaultShutdownInstructionData;
+};
+
+export function parseInitiateVaultShutdownInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitiateVaultShutdownInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getInitiateVaultShutdownInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
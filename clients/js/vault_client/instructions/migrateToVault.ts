@@ -0,0 +1,608 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const MIGRATE_TO_VAULT_DISCRIMINATOR = 78;
+
+export function getMigrateToVaultDiscriminatorBytes() {
+  return getU8Encoder().encode(MIGRATE_TO_VAULT_DISCRIMINATOR);
+}
+
+export type MigrateToVaultInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountSourceVault extends string | IAccountMeta<string> = string,
+  TAccountSourceVaultTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountSourceVrtMint extends string | IAccountMeta<string> = string,
+  TAccountSourceVaultFeeTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountStaker extends string | IAccountMeta<string> = string,
+  TAccountStakerTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountStakerSourceVrtTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountDestinationVault extends string | IAccountMeta<string> = string,
+  TAccountDestinationVaultTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountDestinationVrtMint extends string | IAccountMeta<string> = string,
+  TAccountStakerDestinationVrtTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountMintBurnSigner extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountSourceVault extends string
+        ? WritableAccount<TAccountSourceVault>
+        : TAccountSourceVault,
+      TAccountSourceVaultTokenAccount extends string
+        ? WritableAccount<TAccountSourceVaultTokenAccount>
+        : TAccountSourceVaultTokenAccount,
+      TAccountSourceVrtMint extends string
+        ? WritableAccount<TAccountSourceVrtMint>
+        : TAccountSourceVrtMint,
+      TAccountSourceVaultFeeTokenAccount extends string
+        ? WritableAccount<TAccountSourceVaultFeeTokenAccount>
+        : TAccountSourceVaultFeeTokenAccount,
+      TAccountStaker extends string
+        ? WritableSignerAccount<TAccountStaker> &
+            IAccountSignerMeta<TAccountStaker>
+        : TAccountStaker,
+      TAccountStakerTokenAccount extends string
+        ? WritableAccount<TAccountStakerTokenAccount>
+        : TAccountStakerTokenAccount,
+      TAccountStakerSourceVrtTokenAccount extends string
+        ? WritableAccount<TAccountStakerSourceVrtTokenAccount>
+        : TAccountStakerSourceVrtTokenAccount,
+      TAccountDestinationVault extends string
+        ? WritableAccount<TAccountDestinationVault>
+        : TAccountDestinationVault,
+      TAccountDestinationVaultTokenAccount extends string
+        ? WritableAccount<TAccountDestinationVaultTokenAccount>
+        : TAccountDestinationVaultTokenAccount,
+      TAccountDestinationVrtMint extends string
+        ? WritableAccount<TAccountDestinationVrtMint>
+        : TAccountDestinationVrtMint,
+      TAccountStakerDestinationVrtTokenAccount extends string
+        ? WritableAccount<TAccountStakerDestinationVrtTokenAccount>
+        : TAccountStakerDestinationVrtTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountMintBurnSigner extends string
+        ? ReadonlySignerAccount<TAccountMintBurnSigner> &
+            IAccountSignerMeta<TAccountMintBurnSigner>
+        : TAccountMintBurnSigner,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type MigrateToVaultInstructionData = {
+  discriminator: number;
+  amountIn: bigint;
+  minAmountOut: bigint;
+};
+
+export type MigrateToVaultInstructionDataArgs = {
+  amountIn: number | bigint;
+  minAmountOut: number | bigint;
+};
+
+export function getMigrateToVaultInstructionDataEncoder(): Encoder<MigrateToVaultInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amountIn', getU64Encoder()],
+      ['minAmountOut', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: MIGRATE_TO_VAULT_DISCRIMINATOR })
+  );
+}
+
+export function getMigrateToVaultInstructionDataDecoder(): Decoder<MigrateToVaultInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amountIn', getU64Decoder()],
+    ['minAmountOut', getU64Decoder()],
+  ]);
+}
+
+export function getMigrateToVaultInstructionDataCodec(): Codec<
+  MigrateToVaultInstructionDataArgs,
+  MigrateToVaultInstructionData
+> {
+  return combineCodec(
+    getMigrateToVaultInstructionDataEncoder(),
+    getMigrateToVaultInstructionDataDecoder()
+  );
+}
+
+export type MigrateToVaultAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountSourceVault extends string = string,
+  TAccountSourceVaultTokenAccount extends string = string,
+  TAccountSourceVrtMint extends string = string,
+  TAccountSourceVaultFeeTokenAccount extends string = string,
+  TAccountStaker extends string = string,
+  TAccountStakerTokenAccount extends string = string,
+  TAccountStakerSourceVrtTokenAccount extends string = string,
+  TAccountDestinationVault extends string = string,
+  TAccountDestinationVaultTokenAccount extends string = string,
+  TAccountDestinationVrtMint extends string = string,
+  TAccountStakerDestinationVrtTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountMintBurnSigner extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  sourceVault: Address<TAccountSourceVault>;
+  sourceVaultTokenAccount: Address<TAccountSourceVaultTokenAccount>;
+  sourceVrtMint: Address<TAccountSourceVrtMint>;
+  sourceVaultFeeTokenAccount: Address<TAccountSourceVaultFeeTokenAccount>;
+  staker: TransactionSigner<TAccountStaker>;
+  stakerTokenAccount: Address<TAccountStakerTokenAccount>;
+  stakerSourceVrtTokenAccount: Address<TAccountStakerSourceVrtTokenAccount>;
+  destinationVault: Address<TAccountDestinationVault>;
+  destinationVaultTokenAccount: Address<TAccountDestinationVaultTokenAccount>;
+  destinationVrtMint: Address<TAccountDestinationVrtMint>;
+  stakerDestinationVrtTokenAccount: Address<TAccountStakerDestinationVrtTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** Signer for burning and minting */
+  mintBurnSigner?: TransactionSigner<TAccountMintBurnSigner>;
+  amountIn: MigrateToVaultInstructionDataArgs['amountIn'];
+  minAmountOut: MigrateToVaultInstructionDataArgs['minAmountOut'];
+};
+
+export async function getMigrateToVaultInstructionAsync<
+  TAccountConfig extends string,
+  TAccountSourceVault extends string,
+  TAccountSourceVaultTokenAccount extends string,
+  TAccountSourceVrtMint extends string,
+  TAccountSourceVaultFeeTokenAccount extends string,
+  TAccountStaker extends string,
+  TAccountStakerTokenAccount extends string,
+  TAccountStakerSourceVrtTokenAccount extends string,
+  TAccountDestinationVault extends string,
+  TAccountDestinationVaultTokenAccount extends string,
+  TAccountDestinationVrtMint extends string,
+  TAccountStakerDestinationVrtTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountMintBurnSigner extends string,
+>(
+  input: MigrateToVaultAsyncInput<
+    TAccountConfig,
+    TAccountSourceVault,
+    TAccountSourceVaultTokenAccount,
+    TAccountSourceVrtMint,
+    TAccountSourceVaultFeeTokenAccount,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerSourceVrtTokenAccount,
+    TAccountDestinationVault,
+    TAccountDestinationVaultTokenAccount,
+    TAccountDestinationVrtMint,
+    TAccountStakerDestinationVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountMintBurnSigner
+  >
+): Promise<
+  MigrateToVaultInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountSourceVault,
+    TAccountSourceVaultTokenAccount,
+    TAccountSourceVrtMint,
+    TAccountSourceVaultFeeTokenAccount,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerSourceVrtTokenAccount,
+    TAccountDestinationVault,
+    TAccountDestinationVaultTokenAccount,
+    TAccountDestinationVrtMint,
+    TAccountStakerDestinationVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountMintBurnSigner
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    sourceVault: { value: input.sourceVault ?? null, isWritable: true },
+    sourceVaultTokenAccount: {
+      value: input.sourceVaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    sourceVrtMint: { value: input.sourceVrtMint ?? null, isWritable: true },
+    sourceVaultFeeTokenAccount: {
+      value: input.sourceVaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    staker: { value: input.staker ?? null, isWritable: true },
+    stakerTokenAccount: {
+      value: input.stakerTokenAccount ?? null,
+      isWritable: true,
+    },
+    stakerSourceVrtTokenAccount: {
+      value: input.stakerSourceVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    destinationVault: {
+      value: input.destinationVault ?? null,
+      isWritable: true,
+    },
+    destinationVaultTokenAccount: {
+      value: input.destinationVaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    destinationVrtMint: {
+      value: input.destinationVrtMint ?? null,
+      isWritable: true,
+    },
+    stakerDestinationVrtTokenAccount: {
+      value: input.stakerDestinationVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    mintBurnSigner: { value: input.mintBurnSigner ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.sourceVault),
+      getAccountMeta(accounts.sourceVaultTokenAccount),
+      getAccountMeta(accounts.sourceVrtMint),
+      getAccountMeta(accounts.sourceVaultFeeTokenAccount),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.stakerTokenAccount),
+      getAccountMeta(accounts.stakerSourceVrtTokenAccount),
+      getAccountMeta(accounts.destinationVault),
+      getAccountMeta(accounts.destinationVaultTokenAccount),
+      getAccountMeta(accounts.destinationVrtMint),
+      getAccountMeta(accounts.stakerDestinationVrtTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.mintBurnSigner),
+    ],
+    programAddress,
+    data: getMigrateToVaultInstructionDataEncoder().encode(
+      args as MigrateToVaultInstructionDataArgs
+    ),
+  } as MigrateToVaultInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountSourceVault,
+    TAccountSourceVaultTokenAccount,
+    TAccountSourceVrtMint,
+    TAccountSourceVaultFeeTokenAccount,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerSourceVrtTokenAccount,
+    TAccountDestinationVault,
+    TAccountDestinationVaultTokenAccount,
+    TAccountDestinationVrtMint,
+    TAccountStakerDestinationVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountMintBurnSigner
+  >;
+
+  return instruction;
+}
+
+export type MigrateToVaultInput<
+  TAccountConfig extends string = string,
+  TAccountSourceVault extends string = string,
+  TAccountSourceVaultTokenAccount extends string = string,
+  TAccountSourceVrtMint extends string = string,
+  TAccountSourceVaultFeeTokenAccount extends string = string,
+  TAccountStaker extends string = string,
+  TAccountStakerTokenAccount extends string = string,
+  TAccountStakerSourceVrtTokenAccount extends string = string,
+  TAccountDestinationVault extends string = string,
+  TAccountDestinationVaultTokenAccount extends string = string,
+  TAccountDestinationVrtMint extends string = string,
+  TAccountStakerDestinationVrtTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountMintBurnSigner extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  sourceVault: Address<TAccountSourceVault>;
+  sourceVaultTokenAccount: Address<TAccountSourceVaultTokenAccount>;
+  sourceVrtMint: Address<TAccountSourceVrtMint>;
+  sourceVaultFeeTokenAccount: Address<TAccountSourceVaultFeeTokenAccount>;
+  staker: TransactionSigner<TAccountStaker>;
+  stakerTokenAccount: Address<TAccountStakerTokenAccount>;
+  stakerSourceVrtTokenAccount: Address<TAccountStakerSourceVrtTokenAccount>;
+  destinationVault: Address<TAccountDestinationVault>;
+  destinationVaultTokenAccount: Address<TAccountDestinationVaultTokenAccount>;
+  destinationVrtMint: Address<TAccountDestinationVrtMint>;
+  stakerDestinationVrtTokenAccount: Address<TAccountStakerDestinationVrtTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** Signer for burning and minting */
+  mintBurnSigner?: TransactionSigner<TAccountMintBurnSigner>;
+  amountIn: MigrateToVaultInstructionDataArgs['amountIn'];
+  minAmountOut: MigrateToVaultInstructionDataArgs['minAmountOut'];
+};
+
+export function getMigrateToVaultInstruction<
+  TAccountConfig extends string,
+  TAccountSourceVault extends string,
+  TAccountSourceVaultTokenAccount extends string,
+  TAccountSourceVrtMint extends string,
+  TAccountSourceVaultFeeTokenAccount extends string,
+  TAccountStaker extends string,
+  TAccountStakerTokenAccount extends string,
+  TAccountStakerSourceVrtTokenAccount extends string,
+  TAccountDestinationVault extends string,
+  TAccountDestinationVaultTokenAccount extends string,
+  TAccountDestinationVrtMint extends string,
+  TAccountStakerDestinationVrtTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountMintBurnSigner extends string,
+>(
+  input: MigrateToVaultInput<
+    TAccountConfig,
+    TAccountSourceVault,
+    TAccountSourceVaultTokenAccount,
+    TAccountSourceVrtMint,
+    TAccountSourceVaultFeeTokenAccount,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerSourceVrtTokenAccount,
+    TAccountDestinationVault,
+    TAccountDestinationVaultTokenAccount,
+    TAccountDestinationVrtMint,
+    TAccountStakerDestinationVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountMintBurnSigner
+  >
+): MigrateToVaultInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountSourceVault,
+  TAccountSourceVaultTokenAccount,
+  TAccountSourceVrtMint,
+  TAccountSourceVaultFeeTokenAccount,
+  TAccountStaker,
+  TAccountStakerTokenAccount,
+  TAccountStakerSourceVrtTokenAccount,
+  TAccountDestinationVault,
+  TAccountDestinationVaultTokenAccount,
+  TAccountDestinationVrtMint,
+  TAccountStakerDestinationVrtTokenAccount,
+  TAccountTokenProgram,
+  TAccountMintBurnSigner
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    sourceVault: { value: input.sourceVault ?? null, isWritable: true },
+    sourceVaultTokenAccount: {
+      value: input.sourceVaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    sourceVrtMint: { value: input.sourceVrtMint ?? null, isWritable: true },
+    sourceVaultFeeTokenAccount: {
+      value: input.sourceVaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    staker: { value: input.staker ?? null, isWritable: true },
+    stakerTokenAccount: {
+      value: input.stakerTokenAccount ?? null,
+      isWritable: true,
+    },
+    stakerSourceVrtTokenAccount: {
+      value: input.stakerSourceVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    destinationVault: {
+      value: input.destinationVault ?? null,
+      isWritable: true,
+    },
+    destinationVaultTokenAccount: {
+      value: input.destinationVaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    destinationVrtMint: {
+      value: input.destinationVrtMint ?? null,
+      isWritable: true,
+    },
+    stakerDestinationVrtTokenAccount: {
+      value: input.stakerDestinationVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    mintBurnSigner: { value: input.mintBurnSigner ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.sourceVault),
+      getAccountMeta(accounts.sourceVaultTokenAccount),
+      getAccountMeta(accounts.sourceVrtMint),
+      getAccountMeta(accounts.sourceVaultFeeTokenAccount),
+      getAccountMeta(accounts.staker),
+      getAccountMeta(accounts.stakerTokenAccount),
+      getAccountMeta(accounts.stakerSourceVrtTokenAccount),
+      getAccountMeta(accounts.destinationVault),
+      getAccountMeta(accounts.destinationVaultTokenAccount),
+      getAccountMeta(accounts.destinationVrtMint),
+      getAccountMeta(accounts.stakerDestinationVrtTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.mintBurnSigner),
+    ],
+    programAddress,
+    data: getMigrateToVaultInstructionDataEncoder().encode(
+      args as MigrateToVaultInstructionDataArgs
+    ),
+  } as MigrateToVaultInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountSourceVault,
+    TAccountSourceVaultTokenAccount,
+    TAccountSourceVrtMint,
+    TAccountSourceVaultFeeTokenAccount,
+    TAccountStaker,
+    TAccountStakerTokenAccount,
+    TAccountStakerSourceVrtTokenAccount,
+    TAccountDestinationVault,
+    TAccountDestinationVaultTokenAccount,
+    TAccountDestinationVrtMint,
+    TAccountStakerDestinationVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountMintBurnSigner
+  >;
+
+  return instruction;
+}
+
+export type ParsedMigrateToVaultInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    sourceVault: TAccountMetas[1];
+    sourceVaultTokenAccount: TAccountMetas[2];
+    sourceVrtMint: TAccountMetas[3];
+    sourceVaultFeeTokenAccount: TAccountMetas[4];
+    staker: TAccountMetas[5];
+    stakerTokenAccount: TAccountMetas[6];
+    stakerSourceVrtTokenAccount: TAccountMetas[7];
+    destinationVault: TAccountMetas[8];
+    destinationVaultTokenAccount: TAccountMetas[9];
+    destinationVrtMint: TAccountMetas[10];
+    stakerDestinationVrtTokenAccount: TAccountMetas[11];
+    tokenProgram: TAccountMetas[12];
+    /** Signer for burning and minting */
+    mintBurnSigner?: TAccountMetas[13] | undefined;
+  };
+  data: MigrateToVaultInstructionData;
+};
+
+export function parseMigrateToVaultInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMigrateToVaultInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 14) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_VAULT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      sourceVault: getNextAccount(),
+      sourceVaultTokenAccount: getNextAccount(),
+      sourceVrtMint: getNextAccount(),
+      sourceVaultFeeTokenAccount: getNextAccount(),
+      staker: getNextAccount(),
+      stakerTokenAccount: getNextAccount(),
+      stakerSourceVrtTokenAccount: getNextAccount(),
+      destinationVault: getNextAccount(),
+      destinationVaultTokenAccount: getNextAccount(),
+      destinationVrtMint: getNextAccount(),
+      stakerDestinationVrtTokenAccount: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      mintBurnSigner: getNextOptionalAccount(),
+    },
+    data: getMigrateToVaultInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -30,8 +30,17 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
+import {
+  findConfigPda,
+  findVaultDepositAllowlistEntryPda,
+  findVaultDepositorPda,
+} from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const MINT_TO_DISCRIMINATOR = 11;
 
@@ -50,11 +59,19 @@ export type MintToInstruction<
   TAccountDepositorVrtTokenAccount extends
     | string
     | IAccountMeta<string> = string,
-  TAccountVaultFeeTokenAccount extends string | IAccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
   TAccountMintSigner extends string | IAccountMeta<string> = string,
+  TAccountVaultDepositAllowlistEntry extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountVaultDepositor extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountDepositReceipt extends string | IAccountMeta<string> = string,
+  TAccountDepositReceiptBase extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -82,9 +99,6 @@ export type MintToInstruction<
       TAccountDepositorVrtTokenAccount extends string
         ? WritableAccount<TAccountDepositorVrtTokenAccount>
         : TAccountDepositorVrtTokenAccount,
-      TAccountVaultFeeTokenAccount extends string
-        ? WritableAccount<TAccountVaultFeeTokenAccount>
-        : TAccountVaultFeeTokenAccount,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -92,6 +106,22 @@ export type MintToInstruction<
         ? ReadonlySignerAccount<TAccountMintSigner> &
             IAccountSignerMeta<TAccountMintSigner>
         : TAccountMintSigner,
+      TAccountVaultDepositAllowlistEntry extends string
+        ? ReadonlyAccount<TAccountVaultDepositAllowlistEntry>
+        : TAccountVaultDepositAllowlistEntry,
+      TAccountVaultDepositor extends string
+        ? WritableAccount<TAccountVaultDepositor>
+        : TAccountVaultDepositor,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountDepositReceipt extends string
+        ? WritableAccount<TAccountDepositReceipt>
+        : TAccountDepositReceipt,
+      TAccountDepositReceiptBase extends string
+        ? ReadonlySignerAccount<TAccountDepositReceiptBase> &
+            IAccountSignerMeta<TAccountDepositReceiptBase>
+        : TAccountDepositReceiptBase,
       ...TRemainingAccounts,
     ]
   >;
@@ -136,6 +166,209 @@ export function getMintToInstructionDataCodec(): Codec<
   );
 }
 
+export type MintToAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountDepositor extends string = string,
+  TAccountDepositorTokenAccount extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountDepositorVrtTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountMintSigner extends string = string,
+  TAccountVaultDepositAllowlistEntry extends string = string,
+  TAccountVaultDepositor extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountDepositReceipt extends string = string,
+  TAccountDepositReceiptBase extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vrtMint: Address<TAccountVrtMint>;
+  depositor: TransactionSigner<TAccountDepositor>;
+  depositorTokenAccount: Address<TAccountDepositorTokenAccount>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  depositorVrtTokenAccount: Address<TAccountDepositorVrtTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** Signer for minting */
+  mintSigner?: TransactionSigner<TAccountMintSigner>;
+  /** Required in the allowlist deposit mode */
+  vaultDepositAllowlistEntry?: Address<TAccountVaultDepositAllowlistEntry>;
+  /** Required when the vault has a max deposit per wallet or flash deposit protection */
+  vaultDepositor?: Address<TAccountVaultDepositor>;
+  /** Required to create the vault depositor or deposit receipt */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Created to record the deposit when passed in with its base */
+  depositReceipt?: Address<TAccountDepositReceipt>;
+  /** The base of the deposit receipt */
+  depositReceiptBase?: TransactionSigner<TAccountDepositReceiptBase>;
+  amountIn: MintToInstructionDataArgs['amountIn'];
+  minAmountOut: MintToInstructionDataArgs['minAmountOut'];
+};
+
+export async function getMintToInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVrtMint extends string,
+  TAccountDepositor extends string,
+  TAccountDepositorTokenAccount extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountDepositorVrtTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountMintSigner extends string,
+  TAccountVaultDepositAllowlistEntry extends string,
+  TAccountVaultDepositor extends string,
+  TAccountSystemProgram extends string,
+  TAccountDepositReceipt extends string,
+  TAccountDepositReceiptBase extends string,
+>(
+  input: MintToAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountDepositor,
+    TAccountDepositorTokenAccount,
+    TAccountVaultTokenAccount,
+    TAccountDepositorVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountMintSigner,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountVaultDepositor,
+    TAccountSystemProgram,
+    TAccountDepositReceipt,
+    TAccountDepositReceiptBase
+  >
+): Promise<
+  MintToInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountDepositor,
+    TAccountDepositorTokenAccount,
+    TAccountVaultTokenAccount,
+    TAccountDepositorVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountMintSigner,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountVaultDepositor,
+    TAccountSystemProgram,
+    TAccountDepositReceipt,
+    TAccountDepositReceiptBase
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    depositor: { value: input.depositor ?? null, isWritable: true },
+    depositorTokenAccount: {
+      value: input.depositorTokenAccount ?? null,
+      isWritable: true,
+    },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    depositorVrtTokenAccount: {
+      value: input.depositorVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    mintSigner: { value: input.mintSigner ?? null, isWritable: false },
+    vaultDepositAllowlistEntry: {
+      value: input.vaultDepositAllowlistEntry ?? null,
+      isWritable: false,
+    },
+    vaultDepositor: { value: input.vaultDepositor ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    depositReceipt: { value: input.depositReceipt ?? null, isWritable: true },
+    depositReceiptBase: {
+      value: input.depositReceiptBase ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.vaultDepositAllowlistEntry.value) {
+    accounts.vaultDepositAllowlistEntry.value =
+      await findVaultDepositAllowlistEntryPda({
+        vault: expectAddress(accounts.vault.value),
+        depositor: expectAddress(accounts.depositor.value),
+      });
+  }
+  if (!accounts.vaultDepositor.value) {
+    accounts.vaultDepositor.value = await findVaultDepositorPda({
+      vault: expectAddress(accounts.vault.value),
+      depositor: expectAddress(accounts.depositor.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.depositor),
+      getAccountMeta(accounts.depositorTokenAccount),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.depositorVrtTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.mintSigner),
+      getAccountMeta(accounts.vaultDepositAllowlistEntry),
+      getAccountMeta(accounts.vaultDepositor),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.depositReceipt),
+      getAccountMeta(accounts.depositReceiptBase),
+    ],
+    programAddress,
+    data: getMintToInstructionDataEncoder().encode(
+      args as MintToInstructionDataArgs
+    ),
+  } as MintToInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountDepositor,
+    TAccountDepositorTokenAccount,
+    TAccountVaultTokenAccount,
+    TAccountDepositorVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountMintSigner,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountVaultDepositor,
+    TAccountSystemProgram,
+    TAccountDepositReceipt,
+    TAccountDepositReceiptBase
+  >;
+
+  return instruction;
+}
+
 export type MintToInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -144,9 +377,13 @@ export type MintToInput<
   TAccountDepositorTokenAccount extends string = string,
   TAccountVaultTokenAccount extends string = string,
   TAccountDepositorVrtTokenAccount extends string = string,
-  TAccountVaultFeeTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountMintSigner extends string = string,
+  TAccountVaultDepositAllowlistEntry extends string = string,
+  TAccountVaultDepositor extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountDepositReceipt extends string = string,
+  TAccountDepositReceiptBase extends string = string,
 > = {
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
@@ -155,10 +392,19 @@ export type MintToInput<
   depositorTokenAccount: Address<TAccountDepositorTokenAccount>;
   vaultTokenAccount: Address<TAccountVaultTokenAccount>;
   depositorVrtTokenAccount: Address<TAccountDepositorVrtTokenAccount>;
-  vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   /** Signer for minting */
   mintSigner?: TransactionSigner<TAccountMintSigner>;
+  /** Required in the allowlist deposit mode */
+  vaultDepositAllowlistEntry?: Address<TAccountVaultDepositAllowlistEntry>;
+  /** Required when the vault has a max deposit per wallet or flash deposit protection */
+  vaultDepositor?: Address<TAccountVaultDepositor>;
+  /** Required to create the vault depositor or deposit receipt */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Created to record the deposit when passed in with its base */
+  depositReceipt?: Address<TAccountDepositReceipt>;
+  /** The base of the deposit receipt */
+  depositReceiptBase?: TransactionSigner<TAccountDepositReceiptBase>;
   amountIn: MintToInstructionDataArgs['amountIn'];
   minAmountOut: MintToInstructionDataArgs['minAmountOut'];
 };
@@ -171,9 +417,13 @@ export function getMintToInstruction<
   TAccountDepositorTokenAccount extends string,
   TAccountVaultTokenAccount extends string,
   TAccountDepositorVrtTokenAccount extends string,
-  TAccountVaultFeeTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountMintSigner extends string,
+  TAccountVaultDepositAllowlistEntry extends string,
+  TAccountVaultDepositor extends string,
+  TAccountSystemProgram extends string,
+  TAccountDepositReceipt extends string,
+  TAccountDepositReceiptBase extends string,
 >(
   input: MintToInput<
     TAccountConfig,
@@ -183,9 +433,13 @@ export function getMintToInstruction<
     TAccountDepositorTokenAccount,
     TAccountVaultTokenAccount,
     TAccountDepositorVrtTokenAccount,
-    TAccountVaultFeeTokenAccount,
     TAccountTokenProgram,
-    TAccountMintSigner
+    TAccountMintSigner,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountVaultDepositor,
+    TAccountSystemProgram,
+    TAccountDepositReceipt,
+    TAccountDepositReceiptBase
   >
 ): MintToInstruction<
   typeof JITO_VAULT_PROGRAM_ADDRESS,
@@ -196,9 +450,13 @@ export function getMintToInstruction<
   TAccountDepositorTokenAccount,
   TAccountVaultTokenAccount,
   TAccountDepositorVrtTokenAccount,
-  TAccountVaultFeeTokenAccount,
   TAccountTokenProgram,
-  TAccountMintSigner
+  TAccountMintSigner,
+  TAccountVaultDepositAllowlistEntry,
+  TAccountVaultDepositor,
+  TAccountSystemProgram,
+  TAccountDepositReceipt,
+  TAccountDepositReceiptBase
 > {
   // Program address.
   const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
@@ -221,12 +479,19 @@ export function getMintToInstruction<
       value: input.depositorVrtTokenAccount ?? null,
       isWritable: true,
     },
-    vaultFeeTokenAccount: {
-      value: input.vaultFeeTokenAccount ?? null,
-      isWritable: true,
-    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     mintSigner: { value: input.mintSigner ?? null, isWritable: false },
+    vaultDepositAllowlistEntry: {
+      value: input.vaultDepositAllowlistEntry ?? null,
+      isWritable: false,
+    },
+    vaultDepositor: { value: input.vaultDepositor ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    depositReceipt: { value: input.depositReceipt ?? null, isWritable: true },
+    depositReceiptBase: {
+      value: input.depositReceiptBase ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -241,6 +506,10 @@ export function getMintToInstruction<
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
   }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -252,9 +521,13 @@ export function getMintToInstruction<
       getAccountMeta(accounts.depositorTokenAccount),
       getAccountMeta(accounts.vaultTokenAccount),
       getAccountMeta(accounts.depositorVrtTokenAccount),
-      getAccountMeta(accounts.vaultFeeTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.mintSigner),
+      getAccountMeta(accounts.vaultDepositAllowlistEntry),
+      getAccountMeta(accounts.vaultDepositor),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.depositReceipt),
+      getAccountMeta(accounts.depositReceiptBase),
     ],
     programAddress,
     data: getMintToInstructionDataEncoder().encode(
@@ -269,9 +542,13 @@ export function getMintToInstruction<
     TAccountDepositorTokenAccount,
     TAccountVaultTokenAccount,
     TAccountDepositorVrtTokenAccount,
-    TAccountVaultFeeTokenAccount,
     TAccountTokenProgram,
-    TAccountMintSigner
+    TAccountMintSigner,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountVaultDepositor,
+    TAccountSystemProgram,
+    TAccountDepositReceipt,
+    TAccountDepositReceiptBase
   >;
 
   return instruction;
@@ -290,10 +567,19 @@ export type ParsedMintToInstruction<
     depositorTokenAccount: TAccountMetas[4];
     vaultTokenAccount: TAccountMetas[5];
     depositorVrtTokenAccount: TAccountMetas[6];
-    vaultFeeTokenAccount: TAccountMetas[7];
-    tokenProgram: TAccountMetas[8];
+    tokenProgram: TAccountMetas[7];
     /** Signer for minting */
-    mintSigner?: TAccountMetas[9] | undefined;
+    mintSigner?: TAccountMetas[8] | undefined;
+    /** Required in the allowlist deposit mode */
+    vaultDepositAllowlistEntry?: TAccountMetas[9] | undefined;
+    /** Required when the vault has a max deposit per wallet or flash deposit protection */
+    vaultDepositor?: TAccountMetas[10] | undefined;
+    /** Required to create the vault depositor or deposit receipt */
+    systemProgram?: TAccountMetas[11] | undefined;
+    /** Created to record the deposit when passed in with its base */
+    depositReceipt?: TAccountMetas[12] | undefined;
+    /** The base of the deposit receipt */
+    depositReceiptBase?: TAccountMetas[13] | undefined;
   };
   data: MintToInstructionData;
 };
@@ -306,7 +592,7 @@ export function parseMintToInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedMintToInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -332,9 +618,13 @@ export function parseMintToInstruction<
       depositorTokenAccount: getNextAccount(),
       vaultTokenAccount: getNextAccount(),
       depositorVrtTokenAccount: getNextAccount(),
-      vaultFeeTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
       mintSigner: getNextOptionalAccount(),
+      vaultDepositAllowlistEntry: getNextOptionalAccount(),
+      vaultDepositor: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
+      depositReceipt: getNextOptionalAccount(),
+      depositReceiptBase: getNextOptionalAccount(),
     },
     data: getMintToInstructionDataDecoder().decode(instruction.data),
   };
@@ -0,0 +1,600 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import {
+  findConfigPda,
+  findVaultDepositAllowlistEntryPda,
+  findVaultDepositorPda,
+} from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const MINT_TO_WITH_SOL_DISCRIMINATOR = 47;
+
+export function getMintToWithSolDiscriminatorBytes() {
+  return getU8Encoder().encode(MINT_TO_WITH_SOL_DISCRIMINATOR);
+}
+
+export type MintToWithSolInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVrtMint extends string | IAccountMeta<string> = string,
+  TAccountDepositor extends string | IAccountMeta<string> = string,
+  TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountDepositorVrtTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMintSigner extends string | IAccountMeta<string> = string,
+  TAccountVaultDepositAllowlistEntry extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountVaultDepositor extends string | IAccountMeta<string> = string,
+  TAccountDepositReceipt extends string | IAccountMeta<string> = string,
+  TAccountDepositReceiptBase extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVrtMint extends string
+        ? WritableAccount<TAccountVrtMint>
+        : TAccountVrtMint,
+      TAccountDepositor extends string
+        ? WritableSignerAccount<TAccountDepositor> &
+            IAccountSignerMeta<TAccountDepositor>
+        : TAccountDepositor,
+      TAccountVaultTokenAccount extends string
+        ? WritableAccount<TAccountVaultTokenAccount>
+        : TAccountVaultTokenAccount,
+      TAccountDepositorVrtTokenAccount extends string
+        ? WritableAccount<TAccountDepositorVrtTokenAccount>
+        : TAccountDepositorVrtTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountMintSigner extends string
+        ? ReadonlySignerAccount<TAccountMintSigner> &
+            IAccountSignerMeta<TAccountMintSigner>
+        : TAccountMintSigner,
+      TAccountVaultDepositAllowlistEntry extends string
+        ? ReadonlyAccount<TAccountVaultDepositAllowlistEntry>
+        : TAccountVaultDepositAllowlistEntry,
+      TAccountVaultDepositor extends string
+        ? WritableAccount<TAccountVaultDepositor>
+        : TAccountVaultDepositor,
+      TAccountDepositReceipt extends string
+        ? WritableAccount<TAccountDepositReceipt>
+        : TAccountDepositReceipt,
+      TAccountDepositReceiptBase extends string
+        ? ReadonlySignerAccount<TAccountDepositReceiptBase> &
+            IAccountSignerMeta<TAccountDepositReceiptBase>
+        : TAccountDepositReceiptBase,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type MintToWithSolInstructionData = {
+  discriminator: number;
+  amountIn: bigint;
+  minAmountOut: bigint;
+};
+
+export type MintToWithSolInstructionDataArgs = {
+  amountIn: number | bigint;
+  minAmountOut: number | bigint;
+};
+
+export function getMintToWithSolInstructionDataEncoder(): Encoder<MintToWithSolInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amountIn', getU64Encoder()],
+      ['minAmountOut', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: MINT_TO_WITH_SOL_DISCRIMINATOR })
+  );
+}
+
+export function getMintToWithSolInstructionDataDecoder(): Decoder<MintToWithSolInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amountIn', getU64Decoder()],
+    ['minAmountOut', getU64Decoder()],
+  ]);
+}
+
+export function getMintToWithSolInstructionDataCodec(): Codec<
+  MintToWithSolInstructionDataArgs,
+  MintToWithSolInstructionData
+> {
+  return combineCodec(
+    getMintToWithSolInstructionDataEncoder(),
+    getMintToWithSolInstructionDataDecoder()
+  );
+}
+
+export type MintToWithSolAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountDepositor extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountDepositorVrtTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountMintSigner extends string = string,
+  TAccountVaultDepositAllowlistEntry extends string = string,
+  TAccountVaultDepositor extends string = string,
+  TAccountDepositReceipt extends string = string,
+  TAccountDepositReceiptBase extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vrtMint: Address<TAccountVrtMint>;
+  depositor: TransactionSigner<TAccountDepositor>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  depositorVrtTokenAccount: Address<TAccountDepositorVrtTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Signer for minting */
+  mintSigner?: TransactionSigner<TAccountMintSigner>;
+  /** Required in the allowlist deposit mode */
+  vaultDepositAllowlistEntry?: Address<TAccountVaultDepositAllowlistEntry>;
+  /** Required when the vault has a max deposit per wallet or flash deposit protection */
+  vaultDepositor?: Address<TAccountVaultDepositor>;
+  /** Created to record the deposit when passed in with its base */
+  depositReceipt?: Address<TAccountDepositReceipt>;
+  /** The base of the deposit receipt */
+  depositReceiptBase?: TransactionSigner<TAccountDepositReceiptBase>;
+  amountIn: MintToWithSolInstructionDataArgs['amountIn'];
+  minAmountOut: MintToWithSolInstructionDataArgs['minAmountOut'];
+};
+
+export async function getMintToWithSolInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVrtMint extends string,
+  TAccountDepositor extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountDepositorVrtTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountMintSigner extends string,
+  TAccountVaultDepositAllowlistEntry extends string,
+  TAccountVaultDepositor extends string,
+  TAccountDepositReceipt extends string,
+  TAccountDepositReceiptBase extends string,
+>(
+  input: MintToWithSolAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountDepositor,
+    TAccountVaultTokenAccount,
+    TAccountDepositorVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountMintSigner,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountVaultDepositor,
+    TAccountDepositReceipt,
+    TAccountDepositReceiptBase
+  >
+): Promise<
+  MintToWithSolInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountDepositor,
+    TAccountVaultTokenAccount,
+    TAccountDepositorVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountMintSigner,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountVaultDepositor,
+    TAccountDepositReceipt,
+    TAccountDepositReceiptBase
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    depositor: { value: input.depositor ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    depositorVrtTokenAccount: {
+      value: input.depositorVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    mintSigner: { value: input.mintSigner ?? null, isWritable: false },
+    vaultDepositAllowlistEntry: {
+      value: input.vaultDepositAllowlistEntry ?? null,
+      isWritable: false,
+    },
+    vaultDepositor: { value: input.vaultDepositor ?? null, isWritable: true },
+    depositReceipt: { value: input.depositReceipt ?? null, isWritable: true },
+    depositReceiptBase: {
+      value: input.depositReceiptBase ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.vaultDepositAllowlistEntry.value) {
+    accounts.vaultDepositAllowlistEntry.value =
+      await findVaultDepositAllowlistEntryPda({
+        vault: expectAddress(accounts.vault.value),
+        depositor: expectAddress(accounts.depositor.value),
+      });
+  }
+  if (!accounts.vaultDepositor.value) {
+    accounts.vaultDepositor.value = await findVaultDepositorPda({
+      vault: expectAddress(accounts.vault.value),
+      depositor: expectAddress(accounts.depositor.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.depositor),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.depositorVrtTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.mintSigner),
+      getAccountMeta(accounts.vaultDepositAllowlistEntry),
+      getAccountMeta(accounts.vaultDepositor),
+      getAccountMeta(accounts.depositReceipt),
+      getAccountMeta(accounts.depositReceiptBase),
+    ],
+    programAddress,
+    data: getMintToWithSolInstructionDataEncoder().encode(
+      args as MintToWithSolInstructionDataArgs
+    ),
+  } as MintToWithSolInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountDepositor,
+    TAccountVaultTokenAccount,
+    TAccountDepositorVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountMintSigner,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountVaultDepositor,
+    TAccountDepositReceipt,
+    TAccountDepositReceiptBase
+  >;
+
+  return instruction;
+}
+
+export type MintToWithSolInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountDepositor extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountDepositorVrtTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountMintSigner extends string = string,
+  TAccountVaultDepositAllowlistEntry extends string = string,
+  TAccountVaultDepositor extends string = string,
+  TAccountDepositReceipt extends string = string,
+  TAccountDepositReceiptBase extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vrtMint: Address<TAccountVrtMint>;
+  depositor: TransactionSigner<TAccountDepositor>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  depositorVrtTokenAccount: Address<TAccountDepositorVrtTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Signer for minting */
+  mintSigner?: TransactionSigner<TAccountMintSigner>;
+  /** Required in the allowlist deposit mode */
+  vaultDepositAllowlistEntry?: Address<TAccountVaultDepositAllowlistEntry>;
+  /** Required when the vault has a max deposit per wallet or flash deposit protection */
+  vaultDepositor?: Address<TAccountVaultDepositor>;
+  /** Created to record the deposit when passed in with its base */
+  depositReceipt?: Address<TAccountDepositReceipt>;
+  /** The base of the deposit receipt */
+  depositReceiptBase?: TransactionSigner<TAccountDepositReceiptBase>;
+  amountIn: MintToWithSolInstructionDataArgs['amountIn'];
+  minAmountOut: MintToWithSolInstructionDataArgs['minAmountOut'];
+};
+
+export function getMintToWithSolInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVrtMint extends string,
+  TAccountDepositor extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountDepositorVrtTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountMintSigner extends string,
+  TAccountVaultDepositAllowlistEntry extends string,
+  TAccountVaultDepositor extends string,
+  TAccountDepositReceipt extends string,
+  TAccountDepositReceiptBase extends string,
+>(
+  input: MintToWithSolInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountDepositor,
+    TAccountVaultTokenAccount,
+    TAccountDepositorVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountMintSigner,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountVaultDepositor,
+    TAccountDepositReceipt,
+    TAccountDepositReceiptBase
+  >
+): MintToWithSolInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVrtMint,
+  TAccountDepositor,
+  TAccountVaultTokenAccount,
+  TAccountDepositorVrtTokenAccount,
+  TAccountTokenProgram,
+  TAccountSystemProgram,
+  TAccountMintSigner,
+  TAccountVaultDepositAllowlistEntry,
+  TAccountVaultDepositor,
+  TAccountDepositReceipt,
+  TAccountDepositReceiptBase
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    depositor: { value: input.depositor ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    depositorVrtTokenAccount: {
+      value: input.depositorVrtTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    mintSigner: { value: input.mintSigner ?? null, isWritable: false },
+    vaultDepositAllowlistEntry: {
+      value: input.vaultDepositAllowlistEntry ?? null,
+      isWritable: false,
+    },
+    vaultDepositor: { value: input.vaultDepositor ?? null, isWritable: true },
+    depositReceipt: { value: input.depositReceipt ?? null, isWritable: true },
+    depositReceiptBase: {
+      value: input.depositReceiptBase ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.depositor),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.depositorVrtTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.mintSigner),
+      getAccountMeta(accounts.vaultDepositAllowlistEntry),
+      getAccountMeta(accounts.vaultDepositor),
+      getAccountMeta(accounts.depositReceipt),
+      getAccountMeta(accounts.depositReceiptBase),
+    ],
+    programAddress,
+    data: getMintToWithSolInstructionDataEncoder().encode(
+      args as MintToWithSolInstructionDataArgs
+    ),
+  } as MintToWithSolInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVrtMint,
+    TAccountDepositor,
+    TAccountVaultTokenAccount,
+    TAccountDepositorVrtTokenAccount,
+    TAccountTokenProgram,
+    TAccountSystemProgram,
+    TAccountMintSigner,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountVaultDepositor,
+    TAccountDepositReceipt,
+    TAccountDepositReceiptBase
+  >;
+
+  return instruction;
+}
+
+export type ParsedMintToWithSolInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vrtMint: TAccountMetas[2];
+    depositor: TAccountMetas[3];
+    vaultTokenAccount: TAccountMetas[4];
+    depositorVrtTokenAccount: TAccountMetas[5];
+    tokenProgram: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
+    /** Signer for minting */
+    mintSigner?: TAccountMetas[8] | undefined;
+    /** Required in the allowlist deposit mode */
+    vaultDepositAllowlistEntry?: TAccountMetas[9] | undefined;
+    /** Required when the vault has a max deposit per wallet or flash deposit protection */
+    vaultDepositor?: TAccountMetas[10] | undefined;
+    /** Created to record the deposit when passed in with its base */
+    depositReceipt?: TAccountMetas[11] | undefined;
+    /** The base of the deposit receipt */
+    depositReceiptBase?: TAccountMetas[12] | undefined;
+  };
+  data: MintToWithSolInstructionData;
+};
+
+export function parseMintToWithSolInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMintToWithSolInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 13) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_VAULT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vrtMint: getNextAccount(),
+      depositor: getNextAccount(),
+      vaultTokenAccount: getNextAccount(),
+      depositorVrtTokenAccount: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      systemProgram: getNextAccount(),
+      mintSigner: getNextOptionalAccount(),
+      vaultDepositAllowlistEntry: getNextOptionalAccount(),
+      vaultDepositor: getNextOptionalAccount(),
+      depositReceipt: getNextOptionalAccount(),
+      depositReceiptBase: getNextOptionalAccount(),
+    },
+    data: getMintToWithSolInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,306 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const PROPOSE_ADMIN_BURN_DISCRIMINATOR = 58;
+
+export function getProposeAdminBurnDiscriminatorBytes() {
+  return getU8Encoder().encode(PROPOSE_ADMIN_BURN_DISCRIMINATOR);
+}
+
+export type ProposeAdminBurnInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultStakerWithdrawalTicket extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountBurnAdmin extends string | IAccountMeta<string> = string,
+  TAccountRecovery extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultStakerWithdrawalTicket extends string
+        ? WritableAccount<TAccountVaultStakerWithdrawalTicket>
+        : TAccountVaultStakerWithdrawalTicket,
+      TAccountBurnAdmin extends string
+        ? ReadonlySignerAccount<TAccountBurnAdmin> &
+            IAccountSignerMeta<TAccountBurnAdmin>
+        : TAccountBurnAdmin,
+      TAccountRecovery extends string
+        ? ReadonlyAccount<TAccountRecovery>
+        : TAccountRecovery,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ProposeAdminBurnInstructionData = { discriminator: number };
+
+export type ProposeAdminBurnInstructionDataArgs = {};
+
+export function getProposeAdminBurnInstructionDataEncoder(): Encoder<ProposeAdminBurnInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: PROPOSE_ADMIN_BURN_DISCRIMINATOR })
+  );
+}
+
+export function getProposeAdminBurnInstructionDataDecoder(): Decoder<ProposeAdminBurnInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getProposeAdminBurnInstructionDataCodec(): Codec<
+  ProposeAdminBurnInstructionDataArgs,
+  ProposeAdminBurnInstructionData
+> {
+  return combineCodec(
+    getProposeAdminBurnInstructionDataEncoder(),
+    getProposeAdminBurnInstructionDataDecoder()
+  );
+}
+
+export type ProposeAdminBurnAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountBurnAdmin extends string = string,
+  TAccountRecovery extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  burnAdmin: TransactionSigner<TAccountBurnAdmin>;
+  recovery: Address<TAccountRecovery>;
+};
+
+export async function getProposeAdminBurnInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountBurnAdmin extends string,
+  TAccountRecovery extends string,
+>(
+  input: ProposeAdminBurnAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountBurnAdmin,
+    TAccountRecovery
+  >
+): Promise<
+  ProposeAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountBurnAdmin,
+    TAccountRecovery
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    burnAdmin: { value: input.burnAdmin ?? null, isWritable: false },
+    recovery: { value: input.recovery ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.burnAdmin),
+      getAccountMeta(accounts.recovery),
+    ],
+    programAddress,
+    data: getProposeAdminBurnInstructionDataEncoder().encode({}),
+  } as ProposeAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountBurnAdmin,
+    TAccountRecovery
+  >;
+
+  return instruction;
+}
+
+export type ProposeAdminBurnInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountBurnAdmin extends string = string,
+  TAccountRecovery extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  burnAdmin: TransactionSigner<TAccountBurnAdmin>;
+  recovery: Address<TAccountRecovery>;
+};
+
+export function getProposeAdminBurnInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountBurnAdmin extends string,
+  TAccountRecovery extends string,
+>(
+  input: ProposeAdminBurnInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountBurnAdmin,
+    TAccountRecovery
+  >
+): ProposeAdminBurnInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultStakerWithdrawalTicket,
+  TAccountBurnAdmin,
+  TAccountRecovery
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    burnAdmin: { value: input.burnAdmin ?? null, isWritable: false },
+    recovery: { value: input.recovery ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.burnAdmin),
+      getAccountMeta(accounts.recovery),
+    ],
+    programAddress,
+    data: getProposeAdminBurnInstructionDataEncoder().encode({}),
+  } as ProposeAdminBurnInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountBurnAdmin,
+    TAccountRecovery
+  >;
+
+  return instruction;
+}
+
+export type ParsedProposeAdminBurnInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vaultStakerWithdrawalTicket: TAccountMetas[2];
+    burnAdmin: TAccountMetas[3];
+    recovery: TAccountMetas[4];
+  };
+  data: ProposeAdminBurnInstructionData;
+};
+
+export function parseProposeAdminBurnInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedProposeAdminBurnInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultStakerWithdrawalTicket: getNextAccount(),
+      burnAdmin: getNextAccount(),
+      recovery: getNextAccount(),
+    },
+    data: getProposeAdminBurnInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,289 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const PROPOSE_MINT_AUTHORITY_HANDOFF_DISCRIMINATOR = 39;
+
+export function getProposeMintAuthorityHandoffDiscriminatorBytes() {
+  return getU8Encoder().encode(PROPOSE_MINT_AUTHORITY_HANDOFF_DISCRIMINATOR);
+}
+
+export type ProposeMintAuthorityHandoffInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountConfigAdmin extends string | IAccountMeta<string> = string,
+  TAccountSuccessorProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountConfigAdmin extends string
+        ? ReadonlySignerAccount<TAccountConfigAdmin> &
+            IAccountSignerMeta<TAccountConfigAdmin>
+        : TAccountConfigAdmin,
+      TAccountSuccessorProgram extends string
+        ? ReadonlyAccount<TAccountSuccessorProgram>
+        : TAccountSuccessorProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ProposeMintAuthorityHandoffInstructionData = {
+  discriminator: number;
+};
+
+export type ProposeMintAuthorityHandoffInstructionDataArgs = {};
+
+export function getProposeMintAuthorityHandoffInstructionDataEncoder(): Encoder<ProposeMintAuthorityHandoffInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: PROPOSE_MINT_AUTHORITY_HANDOFF_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getProposeMintAuthorityHandoffInstructionDataDecoder(): Decoder<ProposeMintAuthorityHandoffInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getProposeMintAuthorityHandoffInstructionDataCodec(): Codec<
+  ProposeMintAuthorityHandoffInstructionDataArgs,
+  ProposeMintAuthorityHandoffInstructionData
+> {
+  return combineCodec(
+    getProposeMintAuthorityHandoffInstructionDataEncoder(),
+    getProposeMintAuthorityHandoffInstructionDataDecoder()
+  );
+}
+
+export type ProposeMintAuthorityHandoffAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountConfigAdmin extends string = string,
+  TAccountSuccessorProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  configAdmin: TransactionSigner<TAccountConfigAdmin>;
+  successorProgram: Address<TAccountSuccessorProgram>;
+};
+
+export async function getProposeMintAuthorityHandoffInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountConfigAdmin extends string,
+  TAccountSuccessorProgram extends string,
+>(
+  input: ProposeMintAuthorityHandoffAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountConfigAdmin,
+    TAccountSuccessorProgram
+  >
+): Promise<
+  ProposeMintAuthorityHandoffInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountConfigAdmin,
+    TAccountSuccessorProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    configAdmin: { value: input.configAdmin ?? null, isWritable: false },
+    successorProgram: {
+      value: input.successorProgram ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.configAdmin),
+      getAccountMeta(accounts.successorProgram),
+    ],
+    programAddress,
+    data: getProposeMintAuthorityHandoffInstructionDataEncoder().encode({}),
+  } as ProposeMintAuthorityHandoffInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountConfigAdmin,
+    TAccountSuccessorProgram
+  >;
+
+  return instruction;
+}
+
+export type ProposeMintAuthorityHandoffInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountConfigAdmin extends string = string,
+  TAccountSuccessorProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  configAdmin: TransactionSigner<TAccountConfigAdmin>;
+  successorProgram: Address<TAccountSuccessorProgram>;
+};
+
+export function getProposeMintAuthorityHandoffInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountConfigAdmin extends string,
+  TAccountSuccessorProgram extends string,
+>(
+  input: ProposeMintAuthorityHandoffInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountConfigAdmin,
+    TAccountSuccessorProgram
+  >
+): ProposeMintAuthorityHandoffInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountConfigAdmin,
+  TAccountSuccessorProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    configAdmin: { value: input.configAdmin ?? null, isWritable: false },
+    successorProgram: {
+      value: input.successorProgram ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.configAdmin),
+      getAccountMeta(accounts.successorProgram),
+    ],
+    programAddress,
+    data: getProposeMintAuthorityHandoffInstructionDataEncoder().encode({}),
+  } as ProposeMintAuthorityHandoffInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountConfigAdmin,
+    TAccountSuccessorProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedProposeMintAuthorityHandoffInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    configAdmin: TAccountMetas[2];
+    successorProgram: TAccountMetas[3];
+  };
+  data: ProposeMintAuthorityHandoffInstructionData;
+};
+
+export function parseProposeMintAuthorityHandoffInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedProposeMintAuthorityHandoffInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      configAdmin: getNextAccount(),
+      successorProgram: getNextAccount(),
+    },
+    data: getProposeMintAuthorityHandoffInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
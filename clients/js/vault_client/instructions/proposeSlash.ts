@@ -0,0 +1,663 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import {
+  findConfigPda,
+  findSlashProposalPda,
+  findVaultNcnSlasherTicketPda,
+  findVaultNcnTicketPda,
+  findVaultOperatorDelegationPda,
+} from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const PROPOSE_SLASH_DISCRIMINATOR = 44;
+
+export function getProposeSlashDiscriminatorBytes() {
+  return getU8Encoder().encode(PROPOSE_SLASH_DISCRIMINATOR);
+}
+
+export type ProposeSlashInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountSlasher extends string | IAccountMeta<string> = string,
+  TAccountNcnOperatorState extends string | IAccountMeta<string> = string,
+  TAccountNcnVaultTicket extends string | IAccountMeta<string> = string,
+  TAccountOperatorVaultTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultNcnTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultOperatorDelegation extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountNcnVaultSlasherTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultNcnSlasherTicket extends string | IAccountMeta<string> = string,
+  TAccountVaultNcnSlasherOperatorTicket extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountSlashProposal extends string | IAccountMeta<string> = string,
+  TAccountBase extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountSlasher extends string
+        ? WritableSignerAccount<TAccountSlasher> &
+            IAccountSignerMeta<TAccountSlasher>
+        : TAccountSlasher,
+      TAccountNcnOperatorState extends string
+        ? ReadonlyAccount<TAccountNcnOperatorState>
+        : TAccountNcnOperatorState,
+      TAccountNcnVaultTicket extends string
+        ? ReadonlyAccount<TAccountNcnVaultTicket>
+        : TAccountNcnVaultTicket,
+      TAccountOperatorVaultTicket extends string
+        ? ReadonlyAccount<TAccountOperatorVaultTicket>
+        : TAccountOperatorVaultTicket,
+      TAccountVaultNcnTicket extends string
+        ? ReadonlyAccount<TAccountVaultNcnTicket>
+        : TAccountVaultNcnTicket,
+      TAccountVaultOperatorDelegation extends string
+        ? ReadonlyAccount<TAccountVaultOperatorDelegation>
+        : TAccountVaultOperatorDelegation,
+      TAccountNcnVaultSlasherTicket extends string
+        ? ReadonlyAccount<TAccountNcnVaultSlasherTicket>
+        : TAccountNcnVaultSlasherTicket,
+      TAccountVaultNcnSlasherTicket extends string
+        ? ReadonlyAccount<TAccountVaultNcnSlasherTicket>
+        : TAccountVaultNcnSlasherTicket,
+      TAccountVaultNcnSlasherOperatorTicket extends string
+        ? WritableAccount<TAccountVaultNcnSlasherOperatorTicket>
+        : TAccountVaultNcnSlasherOperatorTicket,
+      TAccountSlashProposal extends string
+        ? WritableAccount<TAccountSlashProposal>
+        : TAccountSlashProposal,
+      TAccountBase extends string
+        ? ReadonlySignerAccount<TAccountBase> & IAccountSignerMeta<TAccountBase>
+        : TAccountBase,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ProposeSlashInstructionData = {
+  discriminator: number;
+  amount: bigint;
+  evidenceHash: ReadonlyUint8Array;
+};
+
+export type ProposeSlashInstructionDataArgs = {
+  amount: number | bigint;
+  evidenceHash: ReadonlyUint8Array;
+};
+
+export function getProposeSlashInstructionDataEncoder(): Encoder<ProposeSlashInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+      ['evidenceHash', fixEncoderSize(getBytesEncoder(), 32)],
+    ]),
+    (value) => ({ ...value, discriminator: PROPOSE_SLASH_DISCRIMINATOR })
+  );
+}
+
+export function getProposeSlashInstructionDataDecoder(): Decoder<ProposeSlashInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+    ['evidenceHash', fixDecoderSize(getBytesDecoder(), 32)],
+  ]);
+}
+
+export function getProposeSlashInstructionDataCodec(): Codec<
+  ProposeSlashInstructionDataArgs,
+  ProposeSlashInstructionData
+> {
+  return combineCodec(
+    getProposeSlashInstructionDataEncoder(),
+    getProposeSlashInstructionDataDecoder()
+  );
+}
+
+export type ProposeSlashAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountNcnVaultTicket extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+  TAccountVaultNcnTicket extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherOperatorTicket extends string = string,
+  TAccountSlashProposal extends string = string,
+  TAccountBase extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  slasher: TransactionSigner<TAccountSlasher>;
+  ncnOperatorState: Address<TAccountNcnOperatorState>;
+  ncnVaultTicket: Address<TAccountNcnVaultTicket>;
+  operatorVaultTicket: Address<TAccountOperatorVaultTicket>;
+  vaultNcnTicket?: Address<TAccountVaultNcnTicket>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  ncnVaultSlasherTicket: Address<TAccountNcnVaultSlasherTicket>;
+  vaultNcnSlasherTicket?: Address<TAccountVaultNcnSlasherTicket>;
+  vaultNcnSlasherOperatorTicket: Address<TAccountVaultNcnSlasherOperatorTicket>;
+  slashProposal?: Address<TAccountSlashProposal>;
+  base: TransactionSigner<TAccountBase>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  amount: ProposeSlashInstructionDataArgs['amount'];
+  evidenceHash: ProposeSlashInstructionDataArgs['evidenceHash'];
+};
+
+export async function getProposeSlashInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSlasher extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountNcnVaultTicket extends string,
+  TAccountOperatorVaultTicket extends string,
+  TAccountVaultNcnTicket extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountVaultNcnSlasherTicket extends string,
+  TAccountVaultNcnSlasherOperatorTicket extends string,
+  TAccountSlashProposal extends string,
+  TAccountBase extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: ProposeSlashAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountSlashProposal,
+    TAccountBase,
+    TAccountSystemProgram
+  >
+): Promise<
+  ProposeSlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountSlashProposal,
+    TAccountBase,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: true },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: false,
+    },
+    ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnTicket: { value: input.vaultNcnTicket ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: false,
+    },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherTicket: {
+      value: input.vaultNcnSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherOperatorTicket: {
+      value: input.vaultNcnSlasherOperatorTicket ?? null,
+      isWritable: true,
+    },
+    slashProposal: { value: input.slashProposal ?? null, isWritable: true },
+    base: { value: input.base ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultNcnTicket.value) {
+    accounts.vaultNcnTicket.value = await findVaultNcnTicketPda({
+      vault: expectAddress(accounts.vault.value),
+      ncn: expectAddress(accounts.ncn.value),
+    });
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+  if (!accounts.vaultNcnSlasherTicket.value) {
+    accounts.vaultNcnSlasherTicket.value = await findVaultNcnSlasherTicketPda({
+      vault: expectAddress(accounts.vault.value),
+      ncn: expectAddress(accounts.ncn.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+  if (!accounts.slashProposal.value) {
+    accounts.slashProposal.value = await findSlashProposalPda({
+      vault: expectAddress(accounts.vault.value),
+      base: expectAddress(accounts.base.value),
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.operatorVaultTicket),
+      getAccountMeta(accounts.vaultNcnTicket),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherOperatorTicket),
+      getAccountMeta(accounts.slashProposal),
+      getAccountMeta(accounts.base),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getProposeSlashInstructionDataEncoder().encode(
+      args as ProposeSlashInstructionDataArgs
+    ),
+  } as ProposeSlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountSlashProposal,
+    TAccountBase,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ProposeSlashInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountNcnVaultTicket extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+  TAccountVaultNcnTicket extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherOperatorTicket extends string = string,
+  TAccountSlashProposal extends string = string,
+  TAccountBase extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  slasher: TransactionSigner<TAccountSlasher>;
+  ncnOperatorState: Address<TAccountNcnOperatorState>;
+  ncnVaultTicket: Address<TAccountNcnVaultTicket>;
+  operatorVaultTicket: Address<TAccountOperatorVaultTicket>;
+  vaultNcnTicket: Address<TAccountVaultNcnTicket>;
+  vaultOperatorDelegation: Address<TAccountVaultOperatorDelegation>;
+  ncnVaultSlasherTicket: Address<TAccountNcnVaultSlasherTicket>;
+  vaultNcnSlasherTicket: Address<TAccountVaultNcnSlasherTicket>;
+  vaultNcnSlasherOperatorTicket: Address<TAccountVaultNcnSlasherOperatorTicket>;
+  slashProposal: Address<TAccountSlashProposal>;
+  base: TransactionSigner<TAccountBase>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  amount: ProposeSlashInstructionDataArgs['amount'];
+  evidenceHash: ProposeSlashInstructionDataArgs['evidenceHash'];
+};
+
+export function getProposeSlashInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSlasher extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountNcnVaultTicket extends string,
+  TAccountOperatorVaultTicket extends string,
+  TAccountVaultNcnTicket extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountVaultNcnSlasherTicket extends string,
+  TAccountVaultNcnSlasherOperatorTicket extends string,
+  TAccountSlashProposal extends string,
+  TAccountBase extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: ProposeSlashInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountSlashProposal,
+    TAccountBase,
+    TAccountSystemProgram
+  >
+): ProposeSlashInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountSlasher,
+  TAccountNcnOperatorState,
+  TAccountNcnVaultTicket,
+  TAccountOperatorVaultTicket,
+  TAccountVaultNcnTicket,
+  TAccountVaultOperatorDelegation,
+  TAccountNcnVaultSlasherTicket,
+  TAccountVaultNcnSlasherTicket,
+  TAccountVaultNcnSlasherOperatorTicket,
+  TAccountSlashProposal,
+  TAccountBase,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: true },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: false,
+    },
+    ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnTicket: { value: input.vaultNcnTicket ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: false,
+    },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherTicket: {
+      value: input.vaultNcnSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherOperatorTicket: {
+      value: input.vaultNcnSlasherOperatorTicket ?? null,
+      isWritable: true,
+    },
+    slashProposal: { value: input.slashProposal ?? null, isWritable: true },
+    base: { value: input.base ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.operatorVaultTicket),
+      getAccountMeta(accounts.vaultNcnTicket),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherOperatorTicket),
+      getAccountMeta(accounts.slashProposal),
+      getAccountMeta(accounts.base),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getProposeSlashInstructionDataEncoder().encode(
+      args as ProposeSlashInstructionDataArgs
+    ),
+  } as ProposeSlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountSlashProposal,
+    TAccountBase,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedProposeSlashInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    operator: TAccountMetas[3];
+    slasher: TAccountMetas[4];
+    ncnOperatorState: TAccountMetas[5];
+    ncnVaultTicket: TAccountMetas[6];
+    operatorVaultTicket: TAccountMetas[7];
+    vaultNcnTicket: TAccountMetas[8];
+    vaultOperatorDelegation: TAccountMetas[9];
+    ncnVaultSlasherTicket: TAccountMetas[10];
+    vaultNcnSlasherTicket: TAccountMetas[11];
+    vaultNcnSlasherOperatorTicket: TAccountMetas[12];
+    slashProposal: TAccountMetas[13];
+    base: TAccountMetas[14];
+    systemProgram: TAccountMetas[15];
+  };
+  data: ProposeSlashInstructionData;
+};
+
+export function parseProposeSlashInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedProposeSlashInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 16) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      slasher: getNextAccount(),
+      ncnOperatorState: getNextAccount(),
+      ncnVaultTicket: getNextAccount(),
+      operatorVaultTicket: getNextAccount(),
+      vaultNcnTicket: getNextAccount(),
+      vaultOperatorDelegation: getNextAccount(),
+      ncnVaultSlasherTicket: getNextAccount(),
+      vaultNcnSlasherTicket: getNextAccount(),
+      vaultNcnSlasherOperatorTicket: getNextAccount(),
+      slashProposal: getNextAccount(),
+      base: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getProposeSlashInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,370 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import {
+  findConfigPda,
+  findDelegationStrategyPda,
+  findVaultOperatorDelegationPda,
+} from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const REBALANCE_DELEGATIONS_DISCRIMINATOR = 53;
+
+export function getRebalanceDelegationsDiscriminatorBytes() {
+  return getU8Encoder().encode(REBALANCE_DELEGATIONS_DISCRIMINATOR);
+}
+
+export type RebalanceDelegationsInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountVaultOperatorDelegation extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountDelegationStrategy extends string | IAccountMeta<string> = string,
+  TAccountOperatorVaultTicket extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountVaultOperatorDelegation extends string
+        ? WritableAccount<TAccountVaultOperatorDelegation>
+        : TAccountVaultOperatorDelegation,
+      TAccountDelegationStrategy extends string
+        ? WritableAccount<TAccountDelegationStrategy>
+        : TAccountDelegationStrategy,
+      TAccountOperatorVaultTicket extends string
+        ? ReadonlyAccount<TAccountOperatorVaultTicket>
+        : TAccountOperatorVaultTicket,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type RebalanceDelegationsInstructionData = { discriminator: number };
+
+export type RebalanceDelegationsInstructionDataArgs = {};
+
+export function getRebalanceDelegationsInstructionDataEncoder(): Encoder<RebalanceDelegationsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: REBALANCE_DELEGATIONS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getRebalanceDelegationsInstructionDataDecoder(): Decoder<RebalanceDelegationsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getRebalanceDelegationsInstructionDataCodec(): Codec<
+  RebalanceDelegationsInstructionDataArgs,
+  RebalanceDelegationsInstructionData
+> {
+  return combineCodec(
+    getRebalanceDelegationsInstructionDataEncoder(),
+    getRebalanceDelegationsInstructionDataDecoder()
+  );
+}
+
+export type RebalanceDelegationsAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountDelegationStrategy extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  delegationStrategy?: Address<TAccountDelegationStrategy>;
+  /** Required when the operator requires approval of delegations */
+  operatorVaultTicket?: Address<TAccountOperatorVaultTicket>;
+};
+
+export async function getRebalanceDelegationsInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountDelegationStrategy extends string,
+  TAccountOperatorVaultTicket extends string,
+>(
+  input: RebalanceDelegationsAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountDelegationStrategy,
+    TAccountOperatorVaultTicket
+  >
+): Promise<
+  RebalanceDelegationsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountDelegationStrategy,
+    TAccountOperatorVaultTicket
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    delegationStrategy: {
+      value: input.delegationStrategy ?? null,
+      isWritable: true,
+    },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+  if (!accounts.delegationStrategy.value) {
+    accounts.delegationStrategy.value = await findDelegationStrategyPda({
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.delegationStrategy),
+      getAccountMeta(accounts.operatorVaultTicket),
+    ],
+    programAddress,
+    data: getRebalanceDelegationsInstructionDataEncoder().encode({}),
+  } as RebalanceDelegationsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountDelegationStrategy,
+    TAccountOperatorVaultTicket
+  >;
+
+  return instruction;
+}
+
+export type RebalanceDelegationsInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountDelegationStrategy extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation: Address<TAccountVaultOperatorDelegation>;
+  delegationStrategy: Address<TAccountDelegationStrategy>;
+  /** Required when the operator requires approval of delegations */
+  operatorVaultTicket?: Address<TAccountOperatorVaultTicket>;
+};
+
+export function getRebalanceDelegationsInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountDelegationStrategy extends string,
+  TAccountOperatorVaultTicket extends string,
+>(
+  input: RebalanceDelegationsInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountDelegationStrategy,
+    TAccountOperatorVaultTicket
+  >
+): RebalanceDelegationsInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountOperator,
+  TAccountVaultOperatorDelegation,
+  TAccountDelegationStrategy,
+  TAccountOperatorVaultTicket
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    delegationStrategy: {
+      value: input.delegationStrategy ?? null,
+      isWritable: true,
+    },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.delegationStrategy),
+      getAccountMeta(accounts.operatorVaultTicket),
+    ],
+    programAddress,
+    data: getRebalanceDelegationsInstructionDataEncoder().encode({}),
+  } as RebalanceDelegationsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountDelegationStrategy,
+    TAccountOperatorVaultTicket
+  >;
+
+  return instruction;
+}
+
+export type ParsedRebalanceDelegationsInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    vaultOperatorDelegation: TAccountMetas[3];
+    delegationStrategy: TAccountMetas[4];
+    /** Required when the operator requires approval of delegations */
+    operatorVaultTicket?: TAccountMetas[5] | undefined;
+  };
+  data: RebalanceDelegationsInstructionData;
+};
+
+export function parseRebalanceDelegationsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedRebalanceDelegationsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_VAULT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      operator: getNextAccount(),
+      vaultOperatorDelegation: getNextAccount(),
+      delegationStrategy: getNextAccount(),
+      operatorVaultTicket: getNextOptionalAccount(),
+    },
+    data: getRebalanceDelegationsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,312 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const RECONCILE_VAULT_BALANCE_DISCRIMINATOR = 62;
+
+export function getReconcileVaultBalanceDiscriminatorBytes() {
+  return getU8Encoder().encode(RECONCILE_VAULT_BALANCE_DISCRIMINATOR);
+}
+
+export type ReconcileVaultBalanceInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultTokenAccount extends string
+        ? ReadonlyAccount<TAccountVaultTokenAccount>
+        : TAccountVaultTokenAccount,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ReconcileVaultBalanceInstructionData = {
+  discriminator: number;
+  maxAdjustment: bigint;
+};
+
+export type ReconcileVaultBalanceInstructionDataArgs = {
+  maxAdjustment: number | bigint;
+};
+
+export function getReconcileVaultBalanceInstructionDataEncoder(): Encoder<ReconcileVaultBalanceInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['maxAdjustment', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: RECONCILE_VAULT_BALANCE_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getReconcileVaultBalanceInstructionDataDecoder(): Decoder<ReconcileVaultBalanceInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['maxAdjustment', getU64Decoder()],
+  ]);
+}
+
+export function getReconcileVaultBalanceInstructionDataCodec(): Codec<
+  ReconcileVaultBalanceInstructionDataArgs,
+  ReconcileVaultBalanceInstructionData
+> {
+  return combineCodec(
+    getReconcileVaultBalanceInstructionDataEncoder(),
+    getReconcileVaultBalanceInstructionDataDecoder()
+  );
+}
+
+export type ReconcileVaultBalanceAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  admin: TransactionSigner<TAccountAdmin>;
+  maxAdjustment: ReconcileVaultBalanceInstructionDataArgs['maxAdjustment'];
+};
+
+export async function getReconcileVaultBalanceInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountAdmin extends string,
+>(
+  input: ReconcileVaultBalanceAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountAdmin
+  >
+): Promise<
+  ReconcileVaultBalanceInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: false,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getReconcileVaultBalanceInstructionDataEncoder().encode(
+      args as ReconcileVaultBalanceInstructionDataArgs
+    ),
+  } as ReconcileVaultBalanceInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ReconcileVaultBalanceInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  admin: TransactionSigner<TAccountAdmin>;
+  maxAdjustment: ReconcileVaultBalanceInstructionDataArgs['maxAdjustment'];
+};
+
+export function getReconcileVaultBalanceInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountAdmin extends string,
+>(
+  input: ReconcileVaultBalanceInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountAdmin
+  >
+): ReconcileVaultBalanceInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultTokenAccount,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: false,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getReconcileVaultBalanceInstructionDataEncoder().encode(
+      args as ReconcileVaultBalanceInstructionDataArgs
+    ),
+  } as ReconcileVaultBalanceInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedReconcileVaultBalanceInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vaultTokenAccount: TAccountMetas[2];
+    admin: TAccountMetas[3];
+  };
+  data: ReconcileVaultBalanceInstructionData;
+};
+
+export function parseReconcileVaultBalanceInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedReconcileVaultBalanceInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultTokenAccount: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getReconcileVaultBalanceInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,324 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findVaultDepositAllowlistEntryPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const REMOVE_FROM_DEPOSIT_ALLOWLIST_DISCRIMINATOR = 36;
+
+export function getRemoveFromDepositAllowlistDiscriminatorBytes() {
+  return getU8Encoder().encode(REMOVE_FROM_DEPOSIT_ALLOWLIST_DISCRIMINATOR);
+}
+
+export type RemoveFromDepositAllowlistInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountDepositor extends string | IAccountMeta<string> = string,
+  TAccountVaultDepositAllowlistEntry extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountDepositor extends string
+        ? ReadonlyAccount<TAccountDepositor>
+        : TAccountDepositor,
+      TAccountVaultDepositAllowlistEntry extends string
+        ? WritableAccount<TAccountVaultDepositAllowlistEntry>
+        : TAccountVaultDepositAllowlistEntry,
+      TAccountAdmin extends string
+        ? WritableSignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type RemoveFromDepositAllowlistInstructionData = {
+  discriminator: number;
+};
+
+export type RemoveFromDepositAllowlistInstructionDataArgs = {};
+
+export function getRemoveFromDepositAllowlistInstructionDataEncoder(): Encoder<RemoveFromDepositAllowlistInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: REMOVE_FROM_DEPOSIT_ALLOWLIST_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getRemoveFromDepositAllowlistInstructionDataDecoder(): Decoder<RemoveFromDepositAllowlistInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getRemoveFromDepositAllowlistInstructionDataCodec(): Codec<
+  RemoveFromDepositAllowlistInstructionDataArgs,
+  RemoveFromDepositAllowlistInstructionData
+> {
+  return combineCodec(
+    getRemoveFromDepositAllowlistInstructionDataEncoder(),
+    getRemoveFromDepositAllowlistInstructionDataDecoder()
+  );
+}
+
+export type RemoveFromDepositAllowlistAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountDepositor extends string = string,
+  TAccountVaultDepositAllowlistEntry extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  depositor: Address<TAccountDepositor>;
+  vaultDepositAllowlistEntry?: Address<TAccountVaultDepositAllowlistEntry>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getRemoveFromDepositAllowlistInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountDepositor extends string,
+  TAccountVaultDepositAllowlistEntry extends string,
+  TAccountAdmin extends string,
+>(
+  input: RemoveFromDepositAllowlistAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountDepositor,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountAdmin
+  >
+): Promise<
+  RemoveFromDepositAllowlistInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountDepositor,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    depositor: { value: input.depositor ?? null, isWritable: false },
+    vaultDepositAllowlistEntry: {
+      value: input.vaultDepositAllowlistEntry ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultDepositAllowlistEntry.value) {
+    accounts.vaultDepositAllowlistEntry.value =
+      await findVaultDepositAllowlistEntryPda({
+        vault: expectAddress(accounts.vault.value),
+        depositor: expectAddress(accounts.depositor.value),
+      });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.depositor),
+      getAccountMeta(accounts.vaultDepositAllowlistEntry),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getRemoveFromDepositAllowlistInstructionDataEncoder().encode({}),
+  } as RemoveFromDepositAllowlistInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountDepositor,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type RemoveFromDepositAllowlistInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountDepositor extends string = string,
+  TAccountVaultDepositAllowlistEntry extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  depositor: Address<TAccountDepositor>;
+  vaultDepositAllowlistEntry: Address<TAccountVaultDepositAllowlistEntry>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export function getRemoveFromDepositAllowlistInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountDepositor extends string,
+  TAccountVaultDepositAllowlistEntry extends string,
+  TAccountAdmin extends string,
+>(
+  input: RemoveFromDepositAllowlistInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountDepositor,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountAdmin
+  >
+): RemoveFromDepositAllowlistInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountDepositor,
+  TAccountVaultDepositAllowlistEntry,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    depositor: { value: input.depositor ?? null, isWritable: false },
+    vaultDepositAllowlistEntry: {
+      value: input.vaultDepositAllowlistEntry ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.depositor),
+      getAccountMeta(accounts.vaultDepositAllowlistEntry),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getRemoveFromDepositAllowlistInstructionDataEncoder().encode({}),
+  } as RemoveFromDepositAllowlistInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountDepositor,
+    TAccountVaultDepositAllowlistEntry,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedRemoveFromDepositAllowlistInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    depositor: TAccountMetas[2];
+    vaultDepositAllowlistEntry: TAccountMetas[3];
+    admin: TAccountMetas[4];
+  };
+  data: RemoveFromDepositAllowlistInstructionData;
+};
+
+export function parseRemoveFromDepositAllowlistInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedRemoveFromDepositAllowlistInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      depositor: getNextAccount(),
+      vaultDepositAllowlistEntry: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getRemoveFromDepositAllowlistInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,335 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findVaultNcnSlasherTicketPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const REMOVE_SLASHER_DISCRIMINATOR = 79;
+
+export function getRemoveSlasherDiscriminatorBytes() {
+  return getU8Encoder().encode(REMOVE_SLASHER_DISCRIMINATOR);
+}
+
+export type RemoveSlasherInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountSlasher extends string | IAccountMeta<string> = string,
+  TAccountVaultNcnSlasherTicket extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountSlasher extends string
+        ? ReadonlyAccount<TAccountSlasher>
+        : TAccountSlasher,
+      TAccountVaultNcnSlasherTicket extends string
+        ? WritableAccount<TAccountVaultNcnSlasherTicket>
+        : TAccountVaultNcnSlasherTicket,
+      TAccountAdmin extends string
+        ? WritableSignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type RemoveSlasherInstructionData = { discriminator: number };
+
+export type RemoveSlasherInstructionDataArgs = {};
+
+export function getRemoveSlasherInstructionDataEncoder(): Encoder<RemoveSlasherInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: REMOVE_SLASHER_DISCRIMINATOR })
+  );
+}
+
+export function getRemoveSlasherInstructionDataDecoder(): Decoder<RemoveSlasherInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getRemoveSlasherInstructionDataCodec(): Codec<
+  RemoveSlasherInstructionDataArgs,
+  RemoveSlasherInstructionData
+> {
+  return combineCodec(
+    getRemoveSlasherInstructionDataEncoder(),
+    getRemoveSlasherInstructionDataDecoder()
+  );
+}
+
+export type RemoveSlasherAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountVaultNcnSlasherTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  slasher: Address<TAccountSlasher>;
+  vaultNcnSlasherTicket?: Address<TAccountVaultNcnSlasherTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export async function getRemoveSlasherInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountSlasher extends string,
+  TAccountVaultNcnSlasherTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: RemoveSlasherAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherTicket,
+    TAccountAdmin
+  >
+): Promise<
+  RemoveSlasherInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherTicket,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    vaultNcnSlasherTicket: {
+      value: input.vaultNcnSlasherTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultNcnSlasherTicket.value) {
+    accounts.vaultNcnSlasherTicket.value = await findVaultNcnSlasherTicketPda({
+      vault: expectAddress(accounts.vault.value),
+      ncn: expectAddress(accounts.ncn.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.vaultNcnSlasherTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getRemoveSlasherInstructionDataEncoder().encode({}),
+  } as RemoveSlasherInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type RemoveSlasherInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountVaultNcnSlasherTicket extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  slasher: Address<TAccountSlasher>;
+  vaultNcnSlasherTicket: Address<TAccountVaultNcnSlasherTicket>;
+  admin: TransactionSigner<TAccountAdmin>;
+};
+
+export function getRemoveSlasherInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountSlasher extends string,
+  TAccountVaultNcnSlasherTicket extends string,
+  TAccountAdmin extends string,
+>(
+  input: RemoveSlasherInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherTicket,
+    TAccountAdmin
+  >
+): RemoveSlasherInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountNcn,
+  TAccountSlasher,
+  TAccountVaultNcnSlasherTicket,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    vaultNcnSlasherTicket: {
+      value: input.vaultNcnSlasherTicket ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.vaultNcnSlasherTicket),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getRemoveSlasherInstructionDataEncoder().encode({}),
+  } as RemoveSlasherInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherTicket,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedRemoveSlasherInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    slasher: TAccountMetas[3];
+    vaultNcnSlasherTicket: TAccountMetas[4];
+    admin: TAccountMetas[5];
+  };
+  data: RemoveSlasherInstructionData;
+};
+
+export function parseRemoveSlasherInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedRemoveSlasherInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      ncn: getNextAccount(),
+      slasher: getNextAccount(),
+      vaultNcnSlasherTicket: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getRemoveSlasherInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -27,6 +27,7 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
@@ -90,6 +91,80 @@ export function getSetAdminInstructionDataCodec(): Codec<
   );
 }
 
+export type SetAdminAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOldAdmin extends string = string,
+  TAccountNewAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  oldAdmin: TransactionSigner<TAccountOldAdmin>;
+  newAdmin: TransactionSigner<TAccountNewAdmin>;
+};
+
+export async function getSetAdminInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOldAdmin extends string,
+  TAccountNewAdmin extends string,
+>(
+  input: SetAdminAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOldAdmin,
+    TAccountNewAdmin
+  >
+): Promise<
+  SetAdminInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOldAdmin,
+    TAccountNewAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    oldAdmin: { value: input.oldAdmin ?? null, isWritable: false },
+    newAdmin: { value: input.newAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.oldAdmin),
+      getAccountMeta(accounts.newAdmin),
+    ],
+    programAddress,
+    data: getSetAdminInstructionDataEncoder().encode({}),
+  } as SetAdminInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOldAdmin,
+    TAccountNewAdmin
+  >;
+
+  return instruction;
+}
+
 export type SetAdminInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
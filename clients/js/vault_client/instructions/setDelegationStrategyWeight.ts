@@ -0,0 +1,384 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import {
+  findConfigPda,
+  findDelegationStrategyPda,
+  findVaultOperatorDelegationPda,
+} from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const SET_DELEGATION_STRATEGY_WEIGHT_DISCRIMINATOR = 52;
+
+export function getSetDelegationStrategyWeightDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_DELEGATION_STRATEGY_WEIGHT_DISCRIMINATOR);
+}
+
+export type SetDelegationStrategyWeightInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountVaultOperatorDelegation extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountDelegationStrategy extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountVaultOperatorDelegation extends string
+        ? WritableAccount<TAccountVaultOperatorDelegation>
+        : TAccountVaultOperatorDelegation,
+      TAccountDelegationStrategy extends string
+        ? WritableAccount<TAccountDelegationStrategy>
+        : TAccountDelegationStrategy,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetDelegationStrategyWeightInstructionData = {
+  discriminator: number;
+  weight: bigint;
+};
+
+export type SetDelegationStrategyWeightInstructionDataArgs = {
+  weight: number | bigint;
+};
+
+export function getSetDelegationStrategyWeightInstructionDataEncoder(): Encoder<SetDelegationStrategyWeightInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['weight', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SET_DELEGATION_STRATEGY_WEIGHT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetDelegationStrategyWeightInstructionDataDecoder(): Decoder<SetDelegationStrategyWeightInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['weight', getU64Decoder()],
+  ]);
+}
+
+export function getSetDelegationStrategyWeightInstructionDataCodec(): Codec<
+  SetDelegationStrategyWeightInstructionDataArgs,
+  SetDelegationStrategyWeightInstructionData
+> {
+  return combineCodec(
+    getSetDelegationStrategyWeightInstructionDataEncoder(),
+    getSetDelegationStrategyWeightInstructionDataDecoder()
+  );
+}
+
+export type SetDelegationStrategyWeightAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountDelegationStrategy extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  delegationStrategy?: Address<TAccountDelegationStrategy>;
+  admin: TransactionSigner<TAccountAdmin>;
+  weight: SetDelegationStrategyWeightInstructionDataArgs['weight'];
+};
+
+export async function getSetDelegationStrategyWeightInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountDelegationStrategy extends string,
+  TAccountAdmin extends string,
+>(
+  input: SetDelegationStrategyWeightAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountDelegationStrategy,
+    TAccountAdmin
+  >
+): Promise<
+  SetDelegationStrategyWeightInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountDelegationStrategy,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    delegationStrategy: {
+      value: input.delegationStrategy ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+  if (!accounts.delegationStrategy.value) {
+    accounts.delegationStrategy.value = await findDelegationStrategyPda({
+      vault: expectAddress(accounts.vault.value),
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.delegationStrategy),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetDelegationStrategyWeightInstructionDataEncoder().encode(
+      args as SetDelegationStrategyWeightInstructionDataArgs
+    ),
+  } as SetDelegationStrategyWeightInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountDelegationStrategy,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type SetDelegationStrategyWeightInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountDelegationStrategy extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation: Address<TAccountVaultOperatorDelegation>;
+  delegationStrategy: Address<TAccountDelegationStrategy>;
+  admin: TransactionSigner<TAccountAdmin>;
+  weight: SetDelegationStrategyWeightInstructionDataArgs['weight'];
+};
+
+export function getSetDelegationStrategyWeightInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountDelegationStrategy extends string,
+  TAccountAdmin extends string,
+>(
+  input: SetDelegationStrategyWeightInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountDelegationStrategy,
+    TAccountAdmin
+  >
+): SetDelegationStrategyWeightInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountOperator,
+  TAccountVaultOperatorDelegation,
+  TAccountDelegationStrategy,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    delegationStrategy: {
+      value: input.delegationStrategy ?? null,
+      isWritable: true,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.delegationStrategy),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetDelegationStrategyWeightInstructionDataEncoder().encode(
+      args as SetDelegationStrategyWeightInstructionDataArgs
+    ),
+  } as SetDelegationStrategyWeightInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountDelegationStrategy,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetDelegationStrategyWeightInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    vaultOperatorDelegation: TAccountMetas[3];
+    delegationStrategy: TAccountMetas[4];
+    admin: TAccountMetas[5];
+  };
+  data: SetDelegationStrategyWeightInstructionData;
+};
+
+export function parseSetDelegationStrategyWeightInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetDelegationStrategyWeightInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      operator: getNextAccount(),
+      vaultOperatorDelegation: getNextAccount(),
+      delegationStrategy: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getSetDelegationStrategyWeightInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
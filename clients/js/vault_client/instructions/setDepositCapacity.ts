@@ -29,6 +29,7 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
@@ -42,7 +43,7 @@ export type SetDepositCapacityInstruction<
   TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
   TAccountConfig extends string | IAccountMeta<string> = string,
   TAccountVault extends string | IAccountMeta<string> = string,
-  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountCapacityAdmin extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -54,10 +55,10 @@ export type SetDepositCapacityInstruction<
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
-      TAccountAdmin extends string
-        ? ReadonlySignerAccount<TAccountAdmin> &
-            IAccountSignerMeta<TAccountAdmin>
-        : TAccountAdmin,
+      TAccountCapacityAdmin extends string
+        ? ReadonlySignerAccount<TAccountCapacityAdmin> &
+            IAccountSignerMeta<TAccountCapacityAdmin>
+        : TAccountCapacityAdmin,
       ...TRemainingAccounts,
     ]
   >;
@@ -96,28 +97,104 @@ export function getSetDepositCapacityInstructionDataCodec(): Codec<
   );
 }
 
+export type SetDepositCapacityAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountCapacityAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  capacityAdmin: TransactionSigner<TAccountCapacityAdmin>;
+  amount: SetDepositCapacityInstructionDataArgs['amount'];
+};
+
+export async function getSetDepositCapacityInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountCapacityAdmin extends string,
+>(
+  input: SetDepositCapacityAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountCapacityAdmin
+  >
+): Promise<
+  SetDepositCapacityInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountCapacityAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    capacityAdmin: { value: input.capacityAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.capacityAdmin),
+    ],
+    programAddress,
+    data: getSetDepositCapacityInstructionDataEncoder().encode(
+      args as SetDepositCapacityInstructionDataArgs
+    ),
+  } as SetDepositCapacityInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountCapacityAdmin
+  >;
+
+  return instruction;
+}
+
 export type SetDepositCapacityInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
-  TAccountAdmin extends string = string,
+  TAccountCapacityAdmin extends string = string,
 > = {
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
-  admin: TransactionSigner<TAccountAdmin>;
+  capacityAdmin: TransactionSigner<TAccountCapacityAdmin>;
   amount: SetDepositCapacityInstructionDataArgs['amount'];
 };
 
 export function getSetDepositCapacityInstruction<
   TAccountConfig extends string,
   TAccountVault extends string,
-  TAccountAdmin extends string,
+  TAccountCapacityAdmin extends string,
 >(
-  input: SetDepositCapacityInput<TAccountConfig, TAccountVault, TAccountAdmin>
+  input: SetDepositCapacityInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountCapacityAdmin
+  >
 ): SetDepositCapacityInstruction<
   typeof JITO_VAULT_PROGRAM_ADDRESS,
   TAccountConfig,
   TAccountVault,
-  TAccountAdmin
+  TAccountCapacityAdmin
 > {
   // Program address.
   const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
@@ -126,7 +203,7 @@ export function getSetDepositCapacityInstruction<
   const originalAccounts = {
     config: { value: input.config ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: true },
-    admin: { value: input.admin ?? null, isWritable: false },
+    capacityAdmin: { value: input.capacityAdmin ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -141,7 +218,7 @@ export function getSetDepositCapacityInstruction<
     accounts: [
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.vault),
-      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.capacityAdmin),
     ],
     programAddress,
     data: getSetDepositCapacityInstructionDataEncoder().encode(
@@ -151,7 +228,7 @@ export function getSetDepositCapacityInstruction<
     typeof JITO_VAULT_PROGRAM_ADDRESS,
     TAccountConfig,
     TAccountVault,
-    TAccountAdmin
+    TAccountCapacityAdmin
   >;
 
   return instruction;
@@ -165,7 +242,7 @@ export type ParsedSetDepositCapacityInstruction<
   accounts: {
     config: TAccountMetas[0];
     vault: TAccountMetas[1];
-    admin: TAccountMetas[2];
+    capacityAdmin: TAccountMetas[2];
   };
   data: SetDepositCapacityInstructionData;
 };
@@ -193,7 +270,7 @@ export function parseSetDepositCapacityInstruction<
     accounts: {
       config: getNextAccount(),
       vault: getNextAccount(),
-      admin: getNextAccount(),
+      capacityAdmin: getNextAccount(),
     },
     data: getSetDepositCapacityInstructionDataDecoder().decode(
       instruction.data
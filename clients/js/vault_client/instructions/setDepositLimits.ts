@@ -0,0 +1,285 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_DEPOSIT_LIMITS_DISCRIMINATOR = 37;
+
+export function getSetDepositLimitsDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_DEPOSIT_LIMITS_DISCRIMINATOR);
+}
+
+export type SetDepositLimitsInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetDepositLimitsInstructionData = {
+  discriminator: number;
+  maxDepositPerTransaction: Option<bigint>;
+  maxDepositPerWallet: Option<bigint>;
+};
+
+export type SetDepositLimitsInstructionDataArgs = {
+  maxDepositPerTransaction: OptionOrNullable<number | bigint>;
+  maxDepositPerWallet: OptionOrNullable<number | bigint>;
+};
+
+export function getSetDepositLimitsInstructionDataEncoder(): Encoder<SetDepositLimitsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['maxDepositPerTransaction', getOptionEncoder(getU64Encoder())],
+      ['maxDepositPerWallet', getOptionEncoder(getU64Encoder())],
+    ]),
+    (value) => ({ ...value, discriminator: SET_DEPOSIT_LIMITS_DISCRIMINATOR })
+  );
+}
+
+export function getSetDepositLimitsInstructionDataDecoder(): Decoder<SetDepositLimitsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['maxDepositPerTransaction', getOptionDecoder(getU64Decoder())],
+    ['maxDepositPerWallet', getOptionDecoder(getU64Decoder())],
+  ]);
+}
+
+export function getSetDepositLimitsInstructionDataCodec(): Codec<
+  SetDepositLimitsInstructionDataArgs,
+  SetDepositLimitsInstructionData
+> {
+  return combineCodec(
+    getSetDepositLimitsInstructionDataEncoder(),
+    getSetDepositLimitsInstructionDataDecoder()
+  );
+}
+
+export type SetDepositLimitsAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  maxDepositPerTransaction: SetDepositLimitsInstructionDataArgs['maxDepositPerTransaction'];
+  maxDepositPerWallet: SetDepositLimitsInstructionDataArgs['maxDepositPerWallet'];
+};
+
+export async function getSetDepositLimitsInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+>(
+  input: SetDepositLimitsAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+): Promise<
+  SetDepositLimitsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetDepositLimitsInstructionDataEncoder().encode(
+      args as SetDepositLimitsInstructionDataArgs
+    ),
+  } as SetDepositLimitsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type SetDepositLimitsInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  maxDepositPerTransaction: SetDepositLimitsInstructionDataArgs['maxDepositPerTransaction'];
+  maxDepositPerWallet: SetDepositLimitsInstructionDataArgs['maxDepositPerWallet'];
+};
+
+export function getSetDepositLimitsInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+>(
+  input: SetDepositLimitsInput<TAccountConfig, TAccountVault, TAccountAdmin>
+): SetDepositLimitsInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetDepositLimitsInstructionDataEncoder().encode(
+      args as SetDepositLimitsInstructionDataArgs
+    ),
+  } as SetDepositLimitsInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetDepositLimitsInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    admin: TAccountMetas[2];
+  };
+  data: SetDepositLimitsInstructionData;
+};
+
+export function parseSetDepositLimitsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetDepositLimitsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getSetDepositLimitsInstructionDataDecoder().decode(instruction.data),
+  };
+}
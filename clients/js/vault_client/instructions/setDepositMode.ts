@@ -0,0 +1,275 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getDepositModeDecoder,
+  getDepositModeEncoder,
+  type DepositMode,
+  type DepositModeArgs,
+} from '../types';
+
+export const SET_DEPOSIT_MODE_DISCRIMINATOR = 34;
+
+export function getSetDepositModeDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_DEPOSIT_MODE_DISCRIMINATOR);
+}
+
+export type SetDepositModeInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetDepositModeInstructionData = {
+  discriminator: number;
+  depositMode: DepositMode;
+};
+
+export type SetDepositModeInstructionDataArgs = {
+  depositMode: DepositModeArgs;
+};
+
+export function getSetDepositModeInstructionDataEncoder(): Encoder<SetDepositModeInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['depositMode', getDepositModeEncoder()],
+    ]),
+    (value) => ({ ...value, discriminator: SET_DEPOSIT_MODE_DISCRIMINATOR })
+  );
+}
+
+export function getSetDepositModeInstructionDataDecoder(): Decoder<SetDepositModeInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['depositMode', getDepositModeDecoder()],
+  ]);
+}
+
+export function getSetDepositModeInstructionDataCodec(): Codec<
+  SetDepositModeInstructionDataArgs,
+  SetDepositModeInstructionData
+> {
+  return combineCodec(
+    getSetDepositModeInstructionDataEncoder(),
+    getSetDepositModeInstructionDataDecoder()
+  );
+}
+
+export type SetDepositModeAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  depositMode: SetDepositModeInstructionDataArgs['depositMode'];
+};
+
+export async function getSetDepositModeInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+>(
+  input: SetDepositModeAsyncInput<TAccountConfig, TAccountVault, TAccountAdmin>
+): Promise<
+  SetDepositModeInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetDepositModeInstructionDataEncoder().encode(
+      args as SetDepositModeInstructionDataArgs
+    ),
+  } as SetDepositModeInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type SetDepositModeInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  depositMode: SetDepositModeInstructionDataArgs['depositMode'];
+};
+
+export function getSetDepositModeInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+>(
+  input: SetDepositModeInput<TAccountConfig, TAccountVault, TAccountAdmin>
+): SetDepositModeInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetDepositModeInstructionDataEncoder().encode(
+      args as SetDepositModeInstructionDataArgs
+    ),
+  } as SetDepositModeInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetDepositModeInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    admin: TAccountMetas[2];
+  };
+  data: SetDepositModeInstructionData;
+};
+
+export function parseSetDepositModeInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetDepositModeInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getSetDepositModeInstructionDataDecoder().decode(instruction.data),
+  };
+}
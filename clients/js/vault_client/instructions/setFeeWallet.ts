@@ -0,0 +1,306 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_FEE_WALLET_DISCRIMINATOR = 31;
+
+export function getSetFeeWalletDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_FEE_WALLET_DISCRIMINATOR);
+}
+
+export type SetFeeWalletInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountNewFeeWallet extends string | IAccountMeta<string> = string,
+  TAccountNewFeeWalletVrtTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountNewFeeWallet extends string
+        ? ReadonlyAccount<TAccountNewFeeWallet>
+        : TAccountNewFeeWallet,
+      TAccountNewFeeWalletVrtTokenAccount extends string
+        ? ReadonlyAccount<TAccountNewFeeWalletVrtTokenAccount>
+        : TAccountNewFeeWalletVrtTokenAccount,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetFeeWalletInstructionData = { discriminator: number };
+
+export type SetFeeWalletInstructionDataArgs = {};
+
+export function getSetFeeWalletInstructionDataEncoder(): Encoder<SetFeeWalletInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: SET_FEE_WALLET_DISCRIMINATOR })
+  );
+}
+
+export function getSetFeeWalletInstructionDataDecoder(): Decoder<SetFeeWalletInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getSetFeeWalletInstructionDataCodec(): Codec<
+  SetFeeWalletInstructionDataArgs,
+  SetFeeWalletInstructionData
+> {
+  return combineCodec(
+    getSetFeeWalletInstructionDataEncoder(),
+    getSetFeeWalletInstructionDataDecoder()
+  );
+}
+
+export type SetFeeWalletAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountNewFeeWallet extends string = string,
+  TAccountNewFeeWalletVrtTokenAccount extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  newFeeWallet: Address<TAccountNewFeeWallet>;
+  newFeeWalletVrtTokenAccount: Address<TAccountNewFeeWalletVrtTokenAccount>;
+};
+
+export async function getSetFeeWalletInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+  TAccountNewFeeWallet extends string,
+  TAccountNewFeeWalletVrtTokenAccount extends string,
+>(
+  input: SetFeeWalletAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountNewFeeWallet,
+    TAccountNewFeeWalletVrtTokenAccount
+  >
+): Promise<
+  SetFeeWalletInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountNewFeeWallet,
+    TAccountNewFeeWalletVrtTokenAccount
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    newFeeWallet: { value: input.newFeeWallet ?? null, isWritable: false },
+    newFeeWalletVrtTokenAccount: {
+      value: input.newFeeWalletVrtTokenAccount ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.newFeeWallet),
+      getAccountMeta(accounts.newFeeWalletVrtTokenAccount),
+    ],
+    programAddress,
+    data: getSetFeeWalletInstructionDataEncoder().encode({}),
+  } as SetFeeWalletInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountNewFeeWallet,
+    TAccountNewFeeWalletVrtTokenAccount
+  >;
+
+  return instruction;
+}
+
+export type SetFeeWalletInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountNewFeeWallet extends string = string,
+  TAccountNewFeeWalletVrtTokenAccount extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  newFeeWallet: Address<TAccountNewFeeWallet>;
+  newFeeWalletVrtTokenAccount: Address<TAccountNewFeeWalletVrtTokenAccount>;
+};
+
+export function getSetFeeWalletInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+  TAccountNewFeeWallet extends string,
+  TAccountNewFeeWalletVrtTokenAccount extends string,
+>(
+  input: SetFeeWalletInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountNewFeeWallet,
+    TAccountNewFeeWalletVrtTokenAccount
+  >
+): SetFeeWalletInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountAdmin,
+  TAccountNewFeeWallet,
+  TAccountNewFeeWalletVrtTokenAccount
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    newFeeWallet: { value: input.newFeeWallet ?? null, isWritable: false },
+    newFeeWalletVrtTokenAccount: {
+      value: input.newFeeWalletVrtTokenAccount ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.newFeeWallet),
+      getAccountMeta(accounts.newFeeWalletVrtTokenAccount),
+    ],
+    programAddress,
+    data: getSetFeeWalletInstructionDataEncoder().encode({}),
+  } as SetFeeWalletInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountNewFeeWallet,
+    TAccountNewFeeWalletVrtTokenAccount
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetFeeWalletInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    admin: TAccountMetas[2];
+    newFeeWallet: TAccountMetas[3];
+    newFeeWalletVrtTokenAccount: TAccountMetas[4];
+  };
+  data: SetFeeWalletInstructionData;
+};
+
+export function parseSetFeeWalletInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetFeeWalletInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      admin: getNextAccount(),
+      newFeeWallet: getNextAccount(),
+      newFeeWalletVrtTokenAccount: getNextAccount(),
+    },
+    data: getSetFeeWalletInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,284 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_MAX_TOTAL_SLASHABLE_PER_EPOCH_DISCRIMINATOR = 76;
+
+export function getSetMaxTotalSlashablePerEpochDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_MAX_TOTAL_SLASHABLE_PER_EPOCH_DISCRIMINATOR);
+}
+
+export type SetMaxTotalSlashablePerEpochInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetMaxTotalSlashablePerEpochInstructionData = {
+  discriminator: number;
+  maxTotalSlashableBpsPerEpoch: number;
+};
+
+export type SetMaxTotalSlashablePerEpochInstructionDataArgs = {
+  maxTotalSlashableBpsPerEpoch: number;
+};
+
+export function getSetMaxTotalSlashablePerEpochInstructionDataEncoder(): Encoder<SetMaxTotalSlashablePerEpochInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['maxTotalSlashableBpsPerEpoch', getU16Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SET_MAX_TOTAL_SLASHABLE_PER_EPOCH_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetMaxTotalSlashablePerEpochInstructionDataDecoder(): Decoder<SetMaxTotalSlashablePerEpochInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['maxTotalSlashableBpsPerEpoch', getU16Decoder()],
+  ]);
+}
+
+export function getSetMaxTotalSlashablePerEpochInstructionDataCodec(): Codec<
+  SetMaxTotalSlashablePerEpochInstructionDataArgs,
+  SetMaxTotalSlashablePerEpochInstructionData
+> {
+  return combineCodec(
+    getSetMaxTotalSlashablePerEpochInstructionDataEncoder(),
+    getSetMaxTotalSlashablePerEpochInstructionDataDecoder()
+  );
+}
+
+export type SetMaxTotalSlashablePerEpochAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  maxTotalSlashableBpsPerEpoch: SetMaxTotalSlashablePerEpochInstructionDataArgs['maxTotalSlashableBpsPerEpoch'];
+};
+
+export async function getSetMaxTotalSlashablePerEpochInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+>(
+  input: SetMaxTotalSlashablePerEpochAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+): Promise<
+  SetMaxTotalSlashablePerEpochInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetMaxTotalSlashablePerEpochInstructionDataEncoder().encode(
+      args as SetMaxTotalSlashablePerEpochInstructionDataArgs
+    ),
+  } as SetMaxTotalSlashablePerEpochInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type SetMaxTotalSlashablePerEpochInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  maxTotalSlashableBpsPerEpoch: SetMaxTotalSlashablePerEpochInstructionDataArgs['maxTotalSlashableBpsPerEpoch'];
+};
+
+export function getSetMaxTotalSlashablePerEpochInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+>(
+  input: SetMaxTotalSlashablePerEpochInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >
+): SetMaxTotalSlashablePerEpochInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getSetMaxTotalSlashablePerEpochInstructionDataEncoder().encode(
+      args as SetMaxTotalSlashablePerEpochInstructionDataArgs
+    ),
+  } as SetMaxTotalSlashablePerEpochInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetMaxTotalSlashablePerEpochInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    admin: TAccountMetas[2];
+  };
+  data: SetMaxTotalSlashablePerEpochInstructionData;
+};
+
+export function parseSetMaxTotalSlashablePerEpochInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetMaxTotalSlashablePerEpochInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getSetMaxTotalSlashablePerEpochInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,287 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_MIGRATION_PARTNER_DISCRIMINATOR = 77;
+
+export function getSetMigrationPartnerDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_MIGRATION_PARTNER_DISCRIMINATOR);
+}
+
+export type SetMigrationPartnerInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountMigrationPartner extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountMigrationPartner extends string
+        ? ReadonlyAccount<TAccountMigrationPartner>
+        : TAccountMigrationPartner,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetMigrationPartnerInstructionData = { discriminator: number };
+
+export type SetMigrationPartnerInstructionDataArgs = {};
+
+export function getSetMigrationPartnerInstructionDataEncoder(): Encoder<SetMigrationPartnerInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: SET_MIGRATION_PARTNER_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetMigrationPartnerInstructionDataDecoder(): Decoder<SetMigrationPartnerInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getSetMigrationPartnerInstructionDataCodec(): Codec<
+  SetMigrationPartnerInstructionDataArgs,
+  SetMigrationPartnerInstructionData
+> {
+  return combineCodec(
+    getSetMigrationPartnerInstructionDataEncoder(),
+    getSetMigrationPartnerInstructionDataDecoder()
+  );
+}
+
+export type SetMigrationPartnerAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountMigrationPartner extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  migrationPartner: Address<TAccountMigrationPartner>;
+};
+
+export async function getSetMigrationPartnerInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+  TAccountMigrationPartner extends string,
+>(
+  input: SetMigrationPartnerAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountMigrationPartner
+  >
+): Promise<
+  SetMigrationPartnerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountMigrationPartner
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    migrationPartner: {
+      value: input.migrationPartner ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.migrationPartner),
+    ],
+    programAddress,
+    data: getSetMigrationPartnerInstructionDataEncoder().encode({}),
+  } as SetMigrationPartnerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountMigrationPartner
+  >;
+
+  return instruction;
+}
+
+export type SetMigrationPartnerInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountMigrationPartner extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  migrationPartner: Address<TAccountMigrationPartner>;
+};
+
+export function getSetMigrationPartnerInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+  TAccountMigrationPartner extends string,
+>(
+  input: SetMigrationPartnerInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountMigrationPartner
+  >
+): SetMigrationPartnerInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountAdmin,
+  TAccountMigrationPartner
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    migrationPartner: {
+      value: input.migrationPartner ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.migrationPartner),
+    ],
+    programAddress,
+    data: getSetMigrationPartnerInstructionDataEncoder().encode({}),
+  } as SetMigrationPartnerInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountMigrationPartner
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetMigrationPartnerInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    admin: TAccountMetas[2];
+    migrationPartner: TAccountMetas[3];
+  };
+  data: SetMigrationPartnerInstructionData;
+};
+
+export function parseSetMigrationPartnerInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetMigrationPartnerInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      admin: getNextAccount(),
+      migrationPartner: getNextAccount(),
+    },
+    data: getSetMigrationPartnerInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
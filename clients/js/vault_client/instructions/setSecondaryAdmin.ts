@@ -27,6 +27,7 @@ import {
   type TransactionSigner,
   type WritableAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
@@ -106,6 +107,86 @@ export function getSetSecondaryAdminInstructionDataCodec(): Codec<
   );
 }
 
+export type SetSecondaryAdminAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountNewAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  admin: TransactionSigner<TAccountAdmin>;
+  newAdmin: Address<TAccountNewAdmin>;
+  vaultAdminRole: SetSecondaryAdminInstructionDataArgs['vaultAdminRole'];
+};
+
+export async function getSetSecondaryAdminInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountAdmin extends string,
+  TAccountNewAdmin extends string,
+>(
+  input: SetSecondaryAdminAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountNewAdmin
+  >
+): Promise<
+  SetSecondaryAdminInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountNewAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+    newAdmin: { value: input.newAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.newAdmin),
+    ],
+    programAddress,
+    data: getSetSecondaryAdminInstructionDataEncoder().encode(
+      args as SetSecondaryAdminInstructionDataArgs
+    ),
+  } as SetSecondaryAdminInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountAdmin,
+    TAccountNewAdmin
+  >;
+
+  return instruction;
+}
+
 export type SetSecondaryAdminInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -0,0 +1,316 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_WITHDRAWAL_TICKET_AUTO_CLAIM_FEE_DISCRIMINATOR = 38;
+
+export function getSetWithdrawalTicketAutoClaimFeeDiscriminatorBytes() {
+  return getU8Encoder().encode(
+    SET_WITHDRAWAL_TICKET_AUTO_CLAIM_FEE_DISCRIMINATOR
+  );
+}
+
+export type SetWithdrawalTicketAutoClaimFeeInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultStakerWithdrawalTicket extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountStaker extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultStakerWithdrawalTicket extends string
+        ? WritableAccount<TAccountVaultStakerWithdrawalTicket>
+        : TAccountVaultStakerWithdrawalTicket,
+      TAccountStaker extends string
+        ? ReadonlySignerAccount<TAccountStaker> &
+            IAccountSignerMeta<TAccountStaker>
+        : TAccountStaker,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetWithdrawalTicketAutoClaimFeeInstructionData = {
+  discriminator: number;
+  feeBps: number;
+};
+
+export type SetWithdrawalTicketAutoClaimFeeInstructionDataArgs = {
+  feeBps: number;
+};
+
+export function getSetWithdrawalTicketAutoClaimFeeInstructionDataEncoder(): Encoder<SetWithdrawalTicketAutoClaimFeeInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['feeBps', getU16Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: SET_WITHDRAWAL_TICKET_AUTO_CLAIM_FEE_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetWithdrawalTicketAutoClaimFeeInstructionDataDecoder(): Decoder<SetWithdrawalTicketAutoClaimFeeInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['feeBps', getU16Decoder()],
+  ]);
+}
+
+export function getSetWithdrawalTicketAutoClaimFeeInstructionDataCodec(): Codec<
+  SetWithdrawalTicketAutoClaimFeeInstructionDataArgs,
+  SetWithdrawalTicketAutoClaimFeeInstructionData
+> {
+  return combineCodec(
+    getSetWithdrawalTicketAutoClaimFeeInstructionDataEncoder(),
+    getSetWithdrawalTicketAutoClaimFeeInstructionDataDecoder()
+  );
+}
+
+export type SetWithdrawalTicketAutoClaimFeeAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountStaker extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  staker: TransactionSigner<TAccountStaker>;
+  feeBps: SetWithdrawalTicketAutoClaimFeeInstructionDataArgs['feeBps'];
+};
+
+export async function getSetWithdrawalTicketAutoClaimFeeInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountStaker extends string,
+>(
+  input: SetWithdrawalTicketAutoClaimFeeAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountStaker
+  >
+): Promise<
+  SetWithdrawalTicketAutoClaimFeeInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountStaker
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    staker: { value: input.staker ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.staker),
+    ],
+    programAddress,
+    data: getSetWithdrawalTicketAutoClaimFeeInstructionDataEncoder().encode(
+      args as SetWithdrawalTicketAutoClaimFeeInstructionDataArgs
+    ),
+  } as SetWithdrawalTicketAutoClaimFeeInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountStaker
+  >;
+
+  return instruction;
+}
+
+export type SetWithdrawalTicketAutoClaimFeeInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultStakerWithdrawalTicket extends string = string,
+  TAccountStaker extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultStakerWithdrawalTicket: Address<TAccountVaultStakerWithdrawalTicket>;
+  staker: TransactionSigner<TAccountStaker>;
+  feeBps: SetWithdrawalTicketAutoClaimFeeInstructionDataArgs['feeBps'];
+};
+
+export function getSetWithdrawalTicketAutoClaimFeeInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultStakerWithdrawalTicket extends string,
+  TAccountStaker extends string,
+>(
+  input: SetWithdrawalTicketAutoClaimFeeInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountStaker
+  >
+): SetWithdrawalTicketAutoClaimFeeInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultStakerWithdrawalTicket,
+  TAccountStaker
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultStakerWithdrawalTicket: {
+      value: input.vaultStakerWithdrawalTicket ?? null,
+      isWritable: true,
+    },
+    staker: { value: input.staker ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultStakerWithdrawalTicket),
+      getAccountMeta(accounts.staker),
+    ],
+    programAddress,
+    data: getSetWithdrawalTicketAutoClaimFeeInstructionDataEncoder().encode(
+      args as SetWithdrawalTicketAutoClaimFeeInstructionDataArgs
+    ),
+  } as SetWithdrawalTicketAutoClaimFeeInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultStakerWithdrawalTicket,
+    TAccountStaker
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetWithdrawalTicketAutoClaimFeeInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    vaultStakerWithdrawalTicket: TAccountMetas[2];
+    staker: TAccountMetas[3];
+  };
+  data: SetWithdrawalTicketAutoClaimFeeInstructionData;
+};
+
+export function parseSetWithdrawalTicketAutoClaimFeeInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetWithdrawalTicketAutoClaimFeeInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultStakerWithdrawalTicket: getNextAccount(),
+      staker: getNextAccount(),
+    },
+    data: getSetWithdrawalTicketAutoClaimFeeInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
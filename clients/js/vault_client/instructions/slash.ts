@@ -26,8 +26,18 @@ import {
   type ReadonlyAccount,
   type WritableAccount,
 } from '@solana/web3.js';
+import {
+  findConfigPda,
+  findVaultNcnSlasherTicketPda,
+  findVaultNcnTicketPda,
+  findVaultOperatorDelegationPda,
+} from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const SLASH_DISCRIMINATOR = 29;
 
@@ -55,7 +65,7 @@ export type SlashInstruction<
     | string
     | IAccountMeta<string> = string,
   TAccountVaultTokenAccount extends string | IAccountMeta<string> = string,
-  TAccountSlasherTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountSlashDestination extends string | IAccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
@@ -104,9 +114,9 @@ export type SlashInstruction<
       TAccountVaultTokenAccount extends string
         ? WritableAccount<TAccountVaultTokenAccount>
         : TAccountVaultTokenAccount,
-      TAccountSlasherTokenAccount extends string
-        ? ReadonlyAccount<TAccountSlasherTokenAccount>
-        : TAccountSlasherTokenAccount,
+      TAccountSlashDestination extends string
+        ? WritableAccount<TAccountSlashDestination>
+        : TAccountSlashDestination,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -145,6 +155,230 @@ export function getSlashInstructionDataCodec(): Codec<
   );
 }
 
+export type SlashAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountNcnVaultTicket extends string = string,
+  TAccountOperatorVaultTicket extends string = string,
+  TAccountVaultNcnTicket extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountNcnVaultSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherTicket extends string = string,
+  TAccountVaultNcnSlasherOperatorTicket extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountSlashDestination extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  slasher: Address<TAccountSlasher>;
+  ncnOperatorState: Address<TAccountNcnOperatorState>;
+  ncnVaultTicket: Address<TAccountNcnVaultTicket>;
+  operatorVaultTicket: Address<TAccountOperatorVaultTicket>;
+  vaultNcnTicket?: Address<TAccountVaultNcnTicket>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  ncnVaultSlasherTicket: Address<TAccountNcnVaultSlasherTicket>;
+  vaultNcnSlasherTicket?: Address<TAccountVaultNcnSlasherTicket>;
+  vaultNcnSlasherOperatorTicket: Address<TAccountVaultNcnSlasherOperatorTicket>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  slashDestination: Address<TAccountSlashDestination>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  amount: SlashInstructionDataArgs['amount'];
+};
+
+export async function getSlashInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSlasher extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountNcnVaultTicket extends string,
+  TAccountOperatorVaultTicket extends string,
+  TAccountVaultNcnTicket extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountNcnVaultSlasherTicket extends string,
+  TAccountVaultNcnSlasherTicket extends string,
+  TAccountVaultNcnSlasherOperatorTicket extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountSlashDestination extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: SlashAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >
+): Promise<
+  SlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: false },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: false,
+    },
+    ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: false },
+    operatorVaultTicket: {
+      value: input.operatorVaultTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnTicket: { value: input.vaultNcnTicket ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: true,
+    },
+    ncnVaultSlasherTicket: {
+      value: input.ncnVaultSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherTicket: {
+      value: input.vaultNcnSlasherTicket ?? null,
+      isWritable: false,
+    },
+    vaultNcnSlasherOperatorTicket: {
+      value: input.vaultNcnSlasherOperatorTicket ?? null,
+      isWritable: true,
+    },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    slashDestination: {
+      value: input.slashDestination ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultNcnTicket.value) {
+    accounts.vaultNcnTicket.value = await findVaultNcnTicketPda({
+      vault: expectAddress(accounts.vault.value),
+      ncn: expectAddress(accounts.ncn.value),
+    });
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+  if (!accounts.vaultNcnSlasherTicket.value) {
+    accounts.vaultNcnSlasherTicket.value = await findVaultNcnSlasherTicketPda({
+      vault: expectAddress(accounts.vault.value),
+      ncn: expectAddress(accounts.ncn.value),
+      slasher: expectAddress(accounts.slasher.value),
+    });
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.ncnVaultTicket),
+      getAccountMeta(accounts.operatorVaultTicket),
+      getAccountMeta(accounts.vaultNcnTicket),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.ncnVaultSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherTicket),
+      getAccountMeta(accounts.vaultNcnSlasherOperatorTicket),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.slashDestination),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getSlashInstructionDataEncoder().encode(
+      args as SlashInstructionDataArgs
+    ),
+  } as SlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountNcnOperatorState,
+    TAccountNcnVaultTicket,
+    TAccountOperatorVaultTicket,
+    TAccountVaultNcnTicket,
+    TAccountVaultOperatorDelegation,
+    TAccountNcnVaultSlasherTicket,
+    TAccountVaultNcnSlasherTicket,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountVaultTokenAccount,
+    TAccountSlashDestination,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
 export type SlashInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -160,7 +394,7 @@ export type SlashInput<
   TAccountVaultNcnSlasherTicket extends string = string,
   TAccountVaultNcnSlasherOperatorTicket extends string = string,
   TAccountVaultTokenAccount extends string = string,
-  TAccountSlasherTokenAccount extends string = string,
+  TAccountSlashDestination extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
   config: Address<TAccountConfig>;
@@ -177,7 +411,7 @@ export type SlashInput<
   vaultNcnSlasherTicket: Address<TAccountVaultNcnSlasherTicket>;
   vaultNcnSlasherOperatorTicket: Address<TAccountVaultNcnSlasherOperatorTicket>;
   vaultTokenAccount: Address<TAccountVaultTokenAccount>;
-  slasherTokenAccount: Address<TAccountSlasherTokenAccount>;
+  slashDestination: Address<TAccountSlashDestination>;
   tokenProgram?: Address<TAccountTokenProgram>;
   amount: SlashInstructionDataArgs['amount'];
 };
@@ -197,7 +431,7 @@ export function getSlashInstruction<
   TAccountVaultNcnSlasherTicket extends string,
   TAccountVaultNcnSlasherOperatorTicket extends string,
   TAccountVaultTokenAccount extends string,
-  TAccountSlasherTokenAccount extends string,
+  TAccountSlashDestination extends string,
   TAccountTokenProgram extends string,
 >(
   input: SlashInput<
@@ -215,7 +449,7 @@ export function getSlashInstruction<
     TAccountVaultNcnSlasherTicket,
     TAccountVaultNcnSlasherOperatorTicket,
     TAccountVaultTokenAccount,
-    TAccountSlasherTokenAccount,
+    TAccountSlashDestination,
     TAccountTokenProgram
   >
 ): SlashInstruction<
@@ -234,7 +468,7 @@ export function getSlashInstruction<
   TAccountVaultNcnSlasherTicket,
   TAccountVaultNcnSlasherOperatorTicket,
   TAccountVaultTokenAccount,
-  TAccountSlasherTokenAccount,
+  TAccountSlashDestination,
   TAccountTokenProgram
 > {
   // Program address.
@@ -277,9 +511,9 @@ export function getSlashInstruction<
       value: input.vaultTokenAccount ?? null,
       isWritable: true,
     },
-    slasherTokenAccount: {
-      value: input.slasherTokenAccount ?? null,
-      isWritable: false,
+    slashDestination: {
+      value: input.slashDestination ?? null,
+      isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
@@ -314,7 +548,7 @@ export function getSlashInstruction<
       getAccountMeta(accounts.vaultNcnSlasherTicket),
       getAccountMeta(accounts.vaultNcnSlasherOperatorTicket),
       getAccountMeta(accounts.vaultTokenAccount),
-      getAccountMeta(accounts.slasherTokenAccount),
+      getAccountMeta(accounts.slashDestination),
       getAccountMeta(accounts.tokenProgram),
     ],
     programAddress,
@@ -337,7 +571,7 @@ export function getSlashInstruction<
     TAccountVaultNcnSlasherTicket,
     TAccountVaultNcnSlasherOperatorTicket,
     TAccountVaultTokenAccount,
-    TAccountSlasherTokenAccount,
+    TAccountSlashDestination,
     TAccountTokenProgram
   >;
 
@@ -364,7 +598,7 @@ export type ParsedSlashInstruction<
     vaultNcnSlasherTicket: TAccountMetas[11];
     vaultNcnSlasherOperatorTicket: TAccountMetas[12];
     vaultTokenAccount: TAccountMetas[13];
-    slasherTokenAccount: TAccountMetas[14];
+    slashDestination: TAccountMetas[14];
     tokenProgram: TAccountMetas[15];
   };
   data: SlashInstructionData;
@@ -405,7 +639,7 @@ export function parseSlashInstruction<
       vaultNcnSlasherTicket: getNextAccount(),
       vaultNcnSlasherOperatorTicket: getNextAccount(),
       vaultTokenAccount: getNextAccount(),
-      slasherTokenAccount: getNextAccount(),
+      slashDestination: getNextAccount(),
       tokenProgram: getNextAccount(),
     },
     data: getSlashInstructionDataDecoder().decode(instruction.data),
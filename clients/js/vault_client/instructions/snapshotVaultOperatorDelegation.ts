@@ -0,0 +1,420 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { findConfigPda, findVaultOperatorDelegationPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export const SNAPSHOT_VAULT_OPERATOR_DELEGATION_DISCRIMINATOR = 63;
+
+export function getSnapshotVaultOperatorDelegationDiscriminatorBytes() {
+  return getU8Encoder().encode(
+    SNAPSHOT_VAULT_OPERATOR_DELEGATION_DISCRIMINATOR
+  );
+}
+
+export type SnapshotVaultOperatorDelegationInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountVaultOperatorDelegation extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountVaultUpdateStateTracker extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountVaultOperatorDelegationSnapshot extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountVaultOperatorDelegation extends string
+        ? ReadonlyAccount<TAccountVaultOperatorDelegation>
+        : TAccountVaultOperatorDelegation,
+      TAccountVaultUpdateStateTracker extends string
+        ? ReadonlyAccount<TAccountVaultUpdateStateTracker>
+        : TAccountVaultUpdateStateTracker,
+      TAccountVaultOperatorDelegationSnapshot extends string
+        ? WritableAccount<TAccountVaultOperatorDelegationSnapshot>
+        : TAccountVaultOperatorDelegationSnapshot,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SnapshotVaultOperatorDelegationInstructionData = {
+  discriminator: number;
+};
+
+export type SnapshotVaultOperatorDelegationInstructionDataArgs = {};
+
+export function getSnapshotVaultOperatorDelegationInstructionDataEncoder(): Encoder<SnapshotVaultOperatorDelegationInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: SNAPSHOT_VAULT_OPERATOR_DELEGATION_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSnapshotVaultOperatorDelegationInstructionDataDecoder(): Decoder<SnapshotVaultOperatorDelegationInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getSnapshotVaultOperatorDelegationInstructionDataCodec(): Codec<
+  SnapshotVaultOperatorDelegationInstructionDataArgs,
+  SnapshotVaultOperatorDelegationInstructionData
+> {
+  return combineCodec(
+    getSnapshotVaultOperatorDelegationInstructionDataEncoder(),
+    getSnapshotVaultOperatorDelegationInstructionDataDecoder()
+  );
+}
+
+export type SnapshotVaultOperatorDelegationAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountVaultUpdateStateTracker extends string = string,
+  TAccountVaultOperatorDelegationSnapshot extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation?: Address<TAccountVaultOperatorDelegation>;
+  vaultUpdateStateTracker: Address<TAccountVaultUpdateStateTracker>;
+  vaultOperatorDelegationSnapshot: Address<TAccountVaultOperatorDelegationSnapshot>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export async function getSnapshotVaultOperatorDelegationInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountVaultUpdateStateTracker extends string,
+  TAccountVaultOperatorDelegationSnapshot extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: SnapshotVaultOperatorDelegationAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountVaultUpdateStateTracker,
+    TAccountVaultOperatorDelegationSnapshot,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): Promise<
+  SnapshotVaultOperatorDelegationInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountVaultUpdateStateTracker,
+    TAccountVaultOperatorDelegationSnapshot,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: false,
+    },
+    vaultUpdateStateTracker: {
+      value: input.vaultUpdateStateTracker ?? null,
+      isWritable: false,
+    },
+    vaultOperatorDelegationSnapshot: {
+      value: input.vaultOperatorDelegationSnapshot ?? null,
+      isWritable: true,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.vaultOperatorDelegation.value) {
+    accounts.vaultOperatorDelegation.value =
+      await findVaultOperatorDelegationPda({
+        vault: expectAddress(accounts.vault.value),
+        operator: expectAddress(accounts.operator.value),
+      });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.vaultUpdateStateTracker),
+      getAccountMeta(accounts.vaultOperatorDelegationSnapshot),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getSnapshotVaultOperatorDelegationInstructionDataEncoder().encode({}),
+  } as SnapshotVaultOperatorDelegationInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountVaultUpdateStateTracker,
+    TAccountVaultOperatorDelegationSnapshot,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type SnapshotVaultOperatorDelegationInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountOperator extends string = string,
+  TAccountVaultOperatorDelegation extends string = string,
+  TAccountVaultUpdateStateTracker extends string = string,
+  TAccountVaultOperatorDelegationSnapshot extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  operator: Address<TAccountOperator>;
+  vaultOperatorDelegation: Address<TAccountVaultOperatorDelegation>;
+  vaultUpdateStateTracker: Address<TAccountVaultUpdateStateTracker>;
+  vaultOperatorDelegationSnapshot: Address<TAccountVaultOperatorDelegationSnapshot>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getSnapshotVaultOperatorDelegationInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountOperator extends string,
+  TAccountVaultOperatorDelegation extends string,
+  TAccountVaultUpdateStateTracker extends string,
+  TAccountVaultOperatorDelegationSnapshot extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: SnapshotVaultOperatorDelegationInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountVaultUpdateStateTracker,
+    TAccountVaultOperatorDelegationSnapshot,
+    TAccountPayer,
+    TAccountSystemProgram
+  >
+): SnapshotVaultOperatorDelegationInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountOperator,
+  TAccountVaultOperatorDelegation,
+  TAccountVaultUpdateStateTracker,
+  TAccountVaultOperatorDelegationSnapshot,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    vaultOperatorDelegation: {
+      value: input.vaultOperatorDelegation ?? null,
+      isWritable: false,
+    },
+    vaultUpdateStateTracker: {
+      value: input.vaultUpdateStateTracker ?? null,
+      isWritable: false,
+    },
+    vaultOperatorDelegationSnapshot: {
+      value: input.vaultOperatorDelegationSnapshot ?? null,
+      isWritable: true,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.vaultOperatorDelegation),
+      getAccountMeta(accounts.vaultUpdateStateTracker),
+      getAccountMeta(accounts.vaultOperatorDelegationSnapshot),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getSnapshotVaultOperatorDelegationInstructionDataEncoder().encode({}),
+  } as SnapshotVaultOperatorDelegationInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountOperator,
+    TAccountVaultOperatorDelegation,
+    TAccountVaultUpdateStateTracker,
+    TAccountVaultOperatorDelegationSnapshot,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedSnapshotVaultOperatorDelegationInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    vaultOperatorDelegation: TAccountMetas[3];
+    vaultUpdateStateTracker: TAccountMetas[4];
+    vaultOperatorDelegationSnapshot: TAccountMetas[5];
+    payer: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
+  };
+  data: SnapshotVaultOperatorDelegationInstructionData;
+};
+
+export function parseSnapshotVaultOperatorDelegationInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSnapshotVaultOperatorDelegationInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      operator: getNextAccount(),
+      vaultOperatorDelegation: getNextAccount(),
+      vaultUpdateStateTracker: getNextAccount(),
+      vaultOperatorDelegationSnapshot: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getSnapshotVaultOperatorDelegationInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
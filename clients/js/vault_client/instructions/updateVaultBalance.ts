@@ -18,12 +18,16 @@ import {
   type Decoder,
   type Encoder,
   type IAccountMeta,
+  type IAccountSignerMeta,
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
@@ -43,6 +47,11 @@ export type UpdateVaultBalanceInstruction<
   TAccountTokenProgram extends
     | string
     | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountProtocolFeeTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountCranker extends string | IAccountMeta<string> = string,
+  TAccountCrankerTokenAccount extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -55,7 +64,7 @@ export type UpdateVaultBalanceInstruction<
         ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountVaultTokenAccount extends string
-        ? ReadonlyAccount<TAccountVaultTokenAccount>
+        ? WritableAccount<TAccountVaultTokenAccount>
         : TAccountVaultTokenAccount,
       TAccountVrtMint extends string
         ? WritableAccount<TAccountVrtMint>
@@ -66,6 +75,16 @@ export type UpdateVaultBalanceInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountProtocolFeeTokenAccount extends string
+        ? WritableAccount<TAccountProtocolFeeTokenAccount>
+        : TAccountProtocolFeeTokenAccount,
+      TAccountCranker extends string
+        ? WritableSignerAccount<TAccountCranker> &
+            IAccountSignerMeta<TAccountCranker>
+        : TAccountCranker,
+      TAccountCrankerTokenAccount extends string
+        ? WritableAccount<TAccountCrankerTokenAccount>
+        : TAccountCrankerTokenAccount,
       ...TRemainingAccounts,
     ]
   >;
@@ -95,6 +114,138 @@ export function getUpdateVaultBalanceInstructionDataCodec(): Codec<
   );
 }
 
+export type UpdateVaultBalanceAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultTokenAccount extends string = string,
+  TAccountVrtMint extends string = string,
+  TAccountVaultFeeTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountProtocolFeeTokenAccount extends string = string,
+  TAccountCranker extends string = string,
+  TAccountCrankerTokenAccount extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  vaultTokenAccount: Address<TAccountVaultTokenAccount>;
+  vrtMint: Address<TAccountVrtMint>;
+  vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  protocolFeeTokenAccount: Address<TAccountProtocolFeeTokenAccount>;
+  /** Receives the crank reward */
+  cranker?: TransactionSigner<TAccountCranker>;
+  /** The cranker's associated token account for the supported mint, required when the config has a crank reward in basis points */
+  crankerTokenAccount?: Address<TAccountCrankerTokenAccount>;
+};
+
+export async function getUpdateVaultBalanceInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultTokenAccount extends string,
+  TAccountVrtMint extends string,
+  TAccountVaultFeeTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountProtocolFeeTokenAccount extends string,
+  TAccountCranker extends string,
+  TAccountCrankerTokenAccount extends string,
+>(
+  input: UpdateVaultBalanceAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountProtocolFeeTokenAccount,
+    TAccountCranker,
+    TAccountCrankerTokenAccount
+  >
+): Promise<
+  UpdateVaultBalanceInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountProtocolFeeTokenAccount,
+    TAccountCranker,
+    TAccountCrankerTokenAccount
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultTokenAccount: {
+      value: input.vaultTokenAccount ?? null,
+      isWritable: true,
+    },
+    vrtMint: { value: input.vrtMint ?? null, isWritable: true },
+    vaultFeeTokenAccount: {
+      value: input.vaultFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    protocolFeeTokenAccount: {
+      value: input.protocolFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    cranker: { value: input.cranker ?? null, isWritable: true },
+    crankerTokenAccount: {
+      value: input.crankerTokenAccount ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultTokenAccount),
+      getAccountMeta(accounts.vrtMint),
+      getAccountMeta(accounts.vaultFeeTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.protocolFeeTokenAccount),
+      getAccountMeta(accounts.cranker),
+      getAccountMeta(accounts.crankerTokenAccount),
+    ],
+    programAddress,
+    data: getUpdateVaultBalanceInstructionDataEncoder().encode({}),
+  } as UpdateVaultBalanceInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultTokenAccount,
+    TAccountVrtMint,
+    TAccountVaultFeeTokenAccount,
+    TAccountTokenProgram,
+    TAccountProtocolFeeTokenAccount,
+    TAccountCranker,
+    TAccountCrankerTokenAccount
+  >;
+
+  return instruction;
+}
+
 export type UpdateVaultBalanceInput<
   TAccountConfig extends string = string,
   TAccountVault extends string = string,
@@ -102,6 +253,9 @@ export type UpdateVaultBalanceInput<
   TAccountVrtMint extends string = string,
   TAccountVaultFeeTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountProtocolFeeTokenAccount extends string = string,
+  TAccountCranker extends string = string,
+  TAccountCrankerTokenAccount extends string = string,
 > = {
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
@@ -109,6 +263,11 @@ export type UpdateVaultBalanceInput<
   vrtMint: Address<TAccountVrtMint>;
   vaultFeeTokenAccount: Address<TAccountVaultFeeTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  protocolFeeTokenAccount: Address<TAccountProtocolFeeTokenAccount>;
+  /** Receives the crank reward */
+  cranker?: TransactionSigner<TAccountCranker>;
+  /** The cranker's associated token account for the supported mint, required when the config has a crank reward in basis points */
+  crankerTokenAccount?: Address<TAccountCrankerTokenAccount>;
 };
 
 export function getUpdateVaultBalanceInstruction<
@@ -118,6 +277,9 @@ export function getUpdateVaultBalanceInstruction<
   TAccountVrtMint extends string,
   TAccountVaultFeeTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountProtocolFeeTokenAccount extends string,
+  TAccountCranker extends string,
+  TAccountCrankerTokenAccount extends string,
 >(
   input: UpdateVaultBalanceInput<
     TAccountConfig,
@@ -125,7 +287,10 @@ export function getUpdateVaultBalanceInstruction<
     TAccountVaultTokenAccount,
     TAccountVrtMint,
     TAccountVaultFeeTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountProtocolFeeTokenAccount,
+    TAccountCranker,
+    TAccountCrankerTokenAccount
   >
 ): UpdateVaultBalanceInstruction<
   typeof JITO_VAULT_PROGRAM_ADDRESS,
@@ -134,7 +299,10 @@ export function getUpdateVaultBalanceInstruction<
   TAccountVaultTokenAccount,
   TAccountVrtMint,
   TAccountVaultFeeTokenAccount,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountProtocolFeeTokenAccount,
+  TAccountCranker,
+  TAccountCrankerTokenAccount
 > {
   // Program address.
   const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
@@ -145,7 +313,7 @@ export function getUpdateVaultBalanceInstruction<
     vault: { value: input.vault ?? null, isWritable: true },
     vaultTokenAccount: {
       value: input.vaultTokenAccount ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     vrtMint: { value: input.vrtMint ?? null, isWritable: true },
     vaultFeeTokenAccount: {
@@ -153,6 +321,15 @@ export function getUpdateVaultBalanceInstruction<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    protocolFeeTokenAccount: {
+      value: input.protocolFeeTokenAccount ?? null,
+      isWritable: true,
+    },
+    cranker: { value: input.cranker ?? null, isWritable: true },
+    crankerTokenAccount: {
+      value: input.crankerTokenAccount ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -174,6 +351,9 @@ export function getUpdateVaultBalanceInstruction<
       getAccountMeta(accounts.vrtMint),
       getAccountMeta(accounts.vaultFeeTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.protocolFeeTokenAccount),
+      getAccountMeta(accounts.cranker),
+      getAccountMeta(accounts.crankerTokenAccount),
     ],
     programAddress,
     data: getUpdateVaultBalanceInstructionDataEncoder().encode({}),
@@ -184,7 +364,10 @@ export function getUpdateVaultBalanceInstruction<
     TAccountVaultTokenAccount,
     TAccountVrtMint,
     TAccountVaultFeeTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountProtocolFeeTokenAccount,
+    TAccountCranker,
+    TAccountCrankerTokenAccount
   >;
 
   return instruction;
@@ -202,6 +385,11 @@ export type ParsedUpdateVaultBalanceInstruction<
     vrtMint: TAccountMetas[3];
     vaultFeeTokenAccount: TAccountMetas[4];
     tokenProgram: TAccountMetas[5];
+    protocolFeeTokenAccount: TAccountMetas[6];
+    /** Receives the crank reward */
+    cranker?: TAccountMetas[7] | undefined;
+    /** The cranker's associated token account for the supported mint, required when the config has a crank reward in basis points */
+    crankerTokenAccount?: TAccountMetas[8] | undefined;
   };
   data: UpdateVaultBalanceInstructionData;
 };
@@ -214,7 +402,7 @@ export function parseUpdateVaultBalanceInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedUpdateVaultBalanceInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -224,6 +412,12 @@ export function parseUpdateVaultBalanceInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_VAULT_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -233,6 +427,9 @@ export function parseUpdateVaultBalanceInstruction<
       vrtMint: getNextAccount(),
       vaultFeeTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
+      protocolFeeTokenAccount: getNextAccount(),
+      cranker: getNextOptionalAccount(),
+      crankerTokenAccount: getNextOptionalAccount(),
     },
     data: getUpdateVaultBalanceInstructionDataDecoder().decode(
       instruction.data
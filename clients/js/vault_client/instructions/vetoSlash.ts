@@ -0,0 +1,370 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { findConfigPda } from '../pdas';
+import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const VETO_SLASH_DISCRIMINATOR = 45;
+
+export function getVetoSlashDiscriminatorBytes() {
+  return getU8Encoder().encode(VETO_SLASH_DISCRIMINATOR);
+}
+
+export type VetoSlashInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountSlasher extends string | IAccountMeta<string> = string,
+  TAccountVaultNcnSlasherOperatorTicket extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountSlashProposal extends string | IAccountMeta<string> = string,
+  TAccountSlashVetoAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountSlasher extends string
+        ? WritableAccount<TAccountSlasher>
+        : TAccountSlasher,
+      TAccountVaultNcnSlasherOperatorTicket extends string
+        ? WritableAccount<TAccountVaultNcnSlasherOperatorTicket>
+        : TAccountVaultNcnSlasherOperatorTicket,
+      TAccountSlashProposal extends string
+        ? WritableAccount<TAccountSlashProposal>
+        : TAccountSlashProposal,
+      TAccountSlashVetoAdmin extends string
+        ? ReadonlySignerAccount<TAccountSlashVetoAdmin> &
+            IAccountSignerMeta<TAccountSlashVetoAdmin>
+        : TAccountSlashVetoAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type VetoSlashInstructionData = { discriminator: number };
+
+export type VetoSlashInstructionDataArgs = {};
+
+export function getVetoSlashInstructionDataEncoder(): Encoder<VetoSlashInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: VETO_SLASH_DISCRIMINATOR })
+  );
+}
+
+export function getVetoSlashInstructionDataDecoder(): Decoder<VetoSlashInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getVetoSlashInstructionDataCodec(): Codec<
+  VetoSlashInstructionDataArgs,
+  VetoSlashInstructionData
+> {
+  return combineCodec(
+    getVetoSlashInstructionDataEncoder(),
+    getVetoSlashInstructionDataDecoder()
+  );
+}
+
+export type VetoSlashAsyncInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountVaultNcnSlasherOperatorTicket extends string = string,
+  TAccountSlashProposal extends string = string,
+  TAccountSlashVetoAdmin extends string = string,
+> = {
+  config?: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  slasher: Address<TAccountSlasher>;
+  vaultNcnSlasherOperatorTicket: Address<TAccountVaultNcnSlasherOperatorTicket>;
+  slashProposal: Address<TAccountSlashProposal>;
+  slashVetoAdmin: TransactionSigner<TAccountSlashVetoAdmin>;
+};
+
+export async function getVetoSlashInstructionAsync<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSlasher extends string,
+  TAccountVaultNcnSlasherOperatorTicket extends string,
+  TAccountSlashProposal extends string,
+  TAccountSlashVetoAdmin extends string,
+>(
+  input: VetoSlashAsyncInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountSlashProposal,
+    TAccountSlashVetoAdmin
+  >
+): Promise<
+  VetoSlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountSlashProposal,
+    TAccountSlashVetoAdmin
+  >
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: true },
+    vaultNcnSlasherOperatorTicket: {
+      value: input.vaultNcnSlasherOperatorTicket ?? null,
+      isWritable: true,
+    },
+    slashProposal: { value: input.slashProposal ?? null, isWritable: true },
+    slashVetoAdmin: { value: input.slashVetoAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await findConfigPda();
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.vaultNcnSlasherOperatorTicket),
+      getAccountMeta(accounts.slashProposal),
+      getAccountMeta(accounts.slashVetoAdmin),
+    ],
+    programAddress,
+    data: getVetoSlashInstructionDataEncoder().encode({}),
+  } as VetoSlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountSlashProposal,
+    TAccountSlashVetoAdmin
+  >;
+
+  return instruction;
+}
+
+export type VetoSlashInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountSlasher extends string = string,
+  TAccountVaultNcnSlasherOperatorTicket extends string = string,
+  TAccountSlashProposal extends string = string,
+  TAccountSlashVetoAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  vault: Address<TAccountVault>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  slasher: Address<TAccountSlasher>;
+  vaultNcnSlasherOperatorTicket: Address<TAccountVaultNcnSlasherOperatorTicket>;
+  slashProposal: Address<TAccountSlashProposal>;
+  slashVetoAdmin: TransactionSigner<TAccountSlashVetoAdmin>;
+};
+
+export function getVetoSlashInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountSlasher extends string,
+  TAccountVaultNcnSlasherOperatorTicket extends string,
+  TAccountSlashProposal extends string,
+  TAccountSlashVetoAdmin extends string,
+>(
+  input: VetoSlashInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountSlashProposal,
+    TAccountSlashVetoAdmin
+  >
+): VetoSlashInstruction<
+  typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountSlasher,
+  TAccountVaultNcnSlasherOperatorTicket,
+  TAccountSlashProposal,
+  TAccountSlashVetoAdmin
+> {
+  // Program address.
+  const programAddress = JITO_VAULT_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    slasher: { value: input.slasher ?? null, isWritable: true },
+    vaultNcnSlasherOperatorTicket: {
+      value: input.vaultNcnSlasherOperatorTicket ?? null,
+      isWritable: true,
+    },
+    slashProposal: { value: input.slashProposal ?? null, isWritable: true },
+    slashVetoAdmin: { value: input.slashVetoAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.slasher),
+      getAccountMeta(accounts.vaultNcnSlasherOperatorTicket),
+      getAccountMeta(accounts.slashProposal),
+      getAccountMeta(accounts.slashVetoAdmin),
+    ],
+    programAddress,
+    data: getVetoSlashInstructionDataEncoder().encode({}),
+  } as VetoSlashInstruction<
+    typeof JITO_VAULT_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountSlasher,
+    TAccountVaultNcnSlasherOperatorTicket,
+    TAccountSlashProposal,
+    TAccountSlashVetoAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedVetoSlashInstruction<
+  TProgram extends string = typeof JITO_VAULT_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    vault: TAccountMetas[1];
+    ncn: TAccountMetas[2];
+    operator: TAccountMetas[3];
+    slasher: TAccountMetas[4];
+    vaultNcnSlasherOperatorTicket: TAccountMetas[5];
+    slashProposal: TAccountMetas[6];
+    slashVetoAdmin: TAccountMetas[7];
+  };
+  data: VetoSlashInstructionData;
+};
+
+export function parseVetoSlashInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedVetoSlashInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      slasher: getNextAccount(),
+      vaultNcnSlasherOperatorTicket: getNextAccount(),
+      slashProposal: getNextAccount(),
+      slashVetoAdmin: getNextAccount(),
+    },
+    data: getVetoSlashInstructionDataDecoder().decode(instruction.data),
+  };
+}
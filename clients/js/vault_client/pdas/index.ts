@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+export * from './config';
+export * from './delegationStrategy';
+export * from './slashProposal';
+export * from './vault';
+export * from './vaultDepositAllowlistEntry';
+export * from './vaultDepositor';
+export * from './vaultEpochStats';
+export * from './vaultNcnSlasherOperatorTicket';
+export * from './vaultNcnSlasherTicket';
+export * from './vaultNcnTicket';
+export * from './vaultOperatorDelegation';
+export * from './vaultStakerWithdrawalTicket';
+export * from './vaultUpdateStateTracker';
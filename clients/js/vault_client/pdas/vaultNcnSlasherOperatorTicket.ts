@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  getU64Encoder,
+  getUtf8Encoder,
+  type Address,
+  type ProgramDerivedAddress,
+} from '@solana/web3.js';
+
+export type VaultNcnSlasherOperatorTicketSeeds = {
+  vault: Address;
+  ncn: Address;
+  slasher: Address;
+  operator: Address;
+  epoch: number | bigint;
+};
+
+export async function findVaultNcnSlasherOperatorTicketPda(
+  seeds: VaultNcnSlasherOperatorTicketSeeds,
+  config: { programAddress?: Address | undefined } = {}
+): Promise<ProgramDerivedAddress> {
+  const {
+    programAddress = '34X2uqBhEGiWHu43RDEMwrMqXF4CpCPEZNaKdAaUS9jx' as Address<'34X2uqBhEGiWHu43RDEMwrMqXF4CpCPEZNaKdAaUS9jx'>,
+  } = config;
+  return await getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      getUtf8Encoder().encode('vault_ncn_slasher_operator'),
+      getAddressEncoder().encode(seeds.vault),
+      getAddressEncoder().encode(seeds.ncn),
+      getAddressEncoder().encode(seeds.slasher),
+      getAddressEncoder().encode(seeds.operator),
+      getU64Encoder().encode(seeds.epoch),
+    ],
+  });
+}
@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  getUtf8Encoder,
+  type Address,
+  type ProgramDerivedAddress,
+} from '@solana/web3.js';
+
+export type VaultNcnSlasherTicketSeeds = {
+  vault: Address;
+  ncn: Address;
+  slasher: Address;
+};
+
+export async function findVaultNcnSlasherTicketPda(
+  seeds: VaultNcnSlasherTicketSeeds,
+  config: { programAddress?: Address | undefined } = {}
+): Promise<ProgramDerivedAddress> {
+  const {
+    programAddress = '34X2uqBhEGiWHu43RDEMwrMqXF4CpCPEZNaKdAaUS9jx' as Address<'34X2uqBhEGiWHu43RDEMwrMqXF4CpCPEZNaKdAaUS9jx'>,
+  } = config;
+  return await getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      getUtf8Encoder().encode('vault_slasher_ticket'),
+      getAddressEncoder().encode(seeds.vault),
+      getAddressEncoder().encode(seeds.ncn),
+      getAddressEncoder().encode(seeds.slasher),
+    ],
+  });
+}
@@ -14,33 +14,92 @@ import {
 } from '@solana/web3.js';
 import {
   type ParsedAddDelegationInstruction,
+  type ParsedAddToDepositAllowlistInstruction,
+  type ParsedAdminSetConfigPausedInstruction,
+  type ParsedAdminSetCrankRewardInstruction,
+  type ParsedAdminSetDelegationCooldownInstruction,
+  type ParsedAdminSetProtocolFeeInstruction,
+  type ParsedAdminSetSlashVetoInstruction,
+  type ParsedAdminSetVaultEpochLengthBoundsInstruction,
   type ParsedAdminWithdrawInstruction,
+  type ParsedBanOperatorInstruction,
+  type ParsedBurnDelegatedInstruction,
   type ParsedBurnInstruction,
+  type ParsedBurnOrEnqueueWithdrawalInstruction,
   type ParsedBurnWithdrawTicketInstruction,
+  type ParsedCancelAdminBurnInstruction,
+  type ParsedCancelMintAuthorityHandoffInstruction,
   type ParsedChangeWithdrawalTicketOwnerInstruction,
+  type ParsedClaimRewardsInstruction,
+  type ParsedCloseDepositReceiptInstruction,
+  type ParsedCloseVaultInstruction,
   type ParsedCloseVaultUpdateStateTrackerInstruction,
   type ParsedCooldownDelegationInstruction,
   type ParsedCooldownVaultNcnSlasherTicketInstruction,
   type ParsedCooldownVaultNcnTicketInstruction,
+  type ParsedCrankOperatorStakeWeightInstruction,
   type ParsedCrankVaultUpdateStateTrackerInstruction,
   type ParsedCreateTokenMetadataInstruction,
+  type ParsedDistributeNcnRewardsInstruction,
+  type ParsedEnableAdminBurnInstruction,
   type ParsedEnqueueWithdrawalInstruction,
+  type ParsedExecuteAdminBurnInstruction,
+  type ParsedExecuteMintAuthorityHandoffInstruction,
+  type ParsedExecuteSlashInstruction,
+  type ParsedFundInsuranceInstruction,
   type ParsedInitializeConfigInstruction,
+  type ParsedInitializeDelegationStrategyInstruction,
+  type ParsedInitializeNcnRewardRouterInstruction,
+  type ParsedInitializeOperatorStakeWeightInstruction,
+  type ParsedInitializeRewardDistributionInstruction,
+  type ParsedInitializeVaultEpochStatsInstruction,
   type ParsedInitializeVaultInstruction,
   type ParsedInitializeVaultNcnSlasherOperatorTicketInstruction,
   type ParsedInitializeVaultNcnSlasherTicketInstruction,
   type ParsedInitializeVaultNcnTicketInstruction,
   type ParsedInitializeVaultOperatorDelegationInstruction,
+  type ParsedInitializeVaultTokenAccountInstruction,
   type ParsedInitializeVaultUpdateStateTrackerInstruction,
   type ParsedInitializeVaultWithMintInstruction,
+  type ParsedInitiateVaultShutdownInstruction,
+  type ParsedMigrateAccountInstruction,
+  type ParsedMigrateToVaultInstruction,
   type ParsedMintToInstruction,
+  type ParsedMintToWithSolInstruction,
+  type ParsedProposeAdminBurnInstruction,
+  type ParsedProposeMintAuthorityHandoffInstruction,
+  type ParsedProposeSlashInstruction,
+  type ParsedRebalanceDelegationsInstruction,
+  type ParsedReconcileVaultBalanceInstruction,
+  type ParsedRemoveFromDepositAllowlistInstruction,
+  type ParsedRemoveSlasherInstruction,
   type ParsedSetAdminInstruction,
+  type ParsedSetDelegationStrategyWeightInstruction,
   type ParsedSetDepositCapacityInstruction,
+  type ParsedSetDepositLimitsInstruction,
+  type ParsedSetDepositModeInstruction,
+  type ParsedSetFeeWalletInstruction,
   type ParsedSetFeesInstruction,
+  type ParsedSetFlashDepositProtectionInstruction,
+  type ParsedSetHookProgramInstruction,
+  type ParsedSetInsuranceParamsInstruction,
+  type ParsedSetMaxTotalSlashablePerEpochInstruction,
+  type ParsedSetMigrationPartnerInstruction,
+  type ParsedSetMinDelegationAmountInstruction,
+  type ParsedSetOperatorDelegationPausedInstruction,
+  type ParsedSetOperatorMaxDelegationInstruction,
   type ParsedSetSecondaryAdminInstruction,
+  type ParsedSetSlashDisputeHaircutInstruction,
+  type ParsedSetVaultEpochLengthInstruction,
+  type ParsedSetVaultPausedInstruction,
+  type ParsedSetWithdrawalDelayInstruction,
+  type ParsedSetWithdrawalTicketAutoClaimFeeInstruction,
   type ParsedSlashInstruction,
+  type ParsedSnapshotVaultOperatorDelegationInstruction,
+  type ParsedUnbanOperatorInstruction,
   type ParsedUpdateTokenMetadataInstruction,
   type ParsedUpdateVaultBalanceInstruction,
+  type ParsedVetoSlashInstruction,
   type ParsedWarmupVaultNcnSlasherTicketInstruction,
   type ParsedWarmupVaultNcnTicketInstruction,
 } from '../instructions';
@@ -50,11 +109,23 @@ export const JITO_VAULT_PROGRAM_ADDRESS =
 
 export enum JitoVaultAccount {
   Config,
+  DelegationStrategy,
+  DepositReceipt,
+  NcnRewardRouter,
+  OperatorStakeWeight,
+  RewardClaim,
+  RewardDistribution,
+  SlashProposal,
   Vault,
+  VaultDepositAllowlistEntry,
+  VaultDepositor,
+  VaultEpochStats,
+  VaultInsurance,
   VaultNcnSlasherOperatorTicket,
   VaultNcnSlasherTicket,
   VaultNcnTicket,
   VaultOperatorDelegation,
+  VaultOperatorDelegationSnapshot,
   VaultStakerWithdrawalTicket,
   VaultUpdateStateTracker,
 }
@@ -90,6 +161,65 @@ export enum JitoVaultInstruction {
   CreateTokenMetadata,
   UpdateTokenMetadata,
   Slash,
+  SetVaultPaused,
+  SetFeeWallet,
+  AdminSetProtocolFee,
+  SetMinDelegationAmount,
+  SetDepositMode,
+  AddToDepositAllowlist,
+  RemoveFromDepositAllowlist,
+  SetDepositLimits,
+  SetWithdrawalTicketAutoClaimFee,
+  ProposeMintAuthorityHandoff,
+  CancelMintAuthorityHandoff,
+  ExecuteMintAuthorityHandoff,
+  AdminSetDelegationCooldown,
+  AdminSetSlashVeto,
+  ProposeSlash,
+  VetoSlash,
+  ExecuteSlash,
+  MintToWithSol,
+  CloseVault,
+  SetOperatorMaxDelegation,
+  SetOperatorDelegationPaused,
+  InitializeDelegationStrategy,
+  SetDelegationStrategyWeight,
+  RebalanceDelegations,
+  BurnOrEnqueueWithdrawal,
+  InitializeVaultEpochStats,
+  SetFlashDepositProtection,
+  EnableAdminBurn,
+  ProposeAdminBurn,
+  CancelAdminBurn,
+  ExecuteAdminBurn,
+  AdminSetConfigPaused,
+  ReconcileVaultBalance,
+  SnapshotVaultOperatorDelegation,
+  InitializeRewardDistribution,
+  ClaimRewards,
+  InitiateVaultShutdown,
+  InitializeNcnRewardRouter,
+  DistributeNcnRewards,
+  SetInsuranceParams,
+  FundInsurance,
+  SetHookProgram,
+  BurnDelegated,
+  AdminSetCrankReward,
+  InitializeVaultTokenAccount,
+  CloseDepositReceipt,
+  SetMaxTotalSlashablePerEpoch,
+  SetMigrationPartner,
+  MigrateToVault,
+  RemoveSlasher,
+  SetWithdrawalDelay,
+  MigrateAccount,
+  InitializeOperatorStakeWeight,
+  CrankOperatorStakeWeight,
+  AdminSetVaultEpochLengthBounds,
+  SetVaultEpochLength,
+  BanOperator,
+  UnbanOperator,
+  SetSlashDisputeHaircut,
 }
 
 export function identifyJitoVaultInstruction(
@@ -186,6 +316,183 @@ export function identifyJitoVaultInstruction(
   if (containsBytes(data, getU8Encoder().encode(29), 0)) {
     return JitoVaultInstruction.Slash;
   }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return JitoVaultInstruction.SetVaultPaused;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return JitoVaultInstruction.SetFeeWallet;
+  }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return JitoVaultInstruction.AdminSetProtocolFee;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return JitoVaultInstruction.SetMinDelegationAmount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return JitoVaultInstruction.SetDepositMode;
+  }
+  if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+    return JitoVaultInstruction.AddToDepositAllowlist;
+  }
+  if (containsBytes(data, getU8Encoder().encode(36), 0)) {
+    return JitoVaultInstruction.RemoveFromDepositAllowlist;
+  }
+  if (containsBytes(data, getU8Encoder().encode(37), 0)) {
+    return JitoVaultInstruction.SetDepositLimits;
+  }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return JitoVaultInstruction.SetWithdrawalTicketAutoClaimFee;
+  }
+  if (containsBytes(data, getU8Encoder().encode(39), 0)) {
+    return JitoVaultInstruction.ProposeMintAuthorityHandoff;
+  }
+  if (containsBytes(data, getU8Encoder().encode(40), 0)) {
+    return JitoVaultInstruction.CancelMintAuthorityHandoff;
+  }
+  if (containsBytes(data, getU8Encoder().encode(41), 0)) {
+    return JitoVaultInstruction.ExecuteMintAuthorityHandoff;
+  }
+  if (containsBytes(data, getU8Encoder().encode(42), 0)) {
+    return JitoVaultInstruction.AdminSetDelegationCooldown;
+  }
+  if (containsBytes(data, getU8Encoder().encode(43), 0)) {
+    return JitoVaultInstruction.AdminSetSlashVeto;
+  }
+  if (containsBytes(data, getU8Encoder().encode(44), 0)) {
+    return JitoVaultInstruction.ProposeSlash;
+  }
+  if (containsBytes(data, getU8Encoder().encode(45), 0)) {
+    return JitoVaultInstruction.VetoSlash;
+  }
+  if (containsBytes(data, getU8Encoder().encode(46), 0)) {
+    return JitoVaultInstruction.ExecuteSlash;
+  }
+  if (containsBytes(data, getU8Encoder().encode(47), 0)) {
+    return JitoVaultInstruction.MintToWithSol;
+  }
+  if (containsBytes(data, getU8Encoder().encode(48), 0)) {
+    return JitoVaultInstruction.CloseVault;
+  }
+  if (containsBytes(data, getU8Encoder().encode(49), 0)) {
+    return JitoVaultInstruction.SetOperatorMaxDelegation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(50), 0)) {
+    return JitoVaultInstruction.SetOperatorDelegationPaused;
+  }
+  if (containsBytes(data, getU8Encoder().encode(51), 0)) {
+    return JitoVaultInstruction.InitializeDelegationStrategy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(52), 0)) {
+    return JitoVaultInstruction.SetDelegationStrategyWeight;
+  }
+  if (containsBytes(data, getU8Encoder().encode(53), 0)) {
+    return JitoVaultInstruction.RebalanceDelegations;
+  }
+  if (containsBytes(data, getU8Encoder().encode(54), 0)) {
+    return JitoVaultInstruction.BurnOrEnqueueWithdrawal;
+  }
+  if (containsBytes(data, getU8Encoder().encode(55), 0)) {
+    return JitoVaultInstruction.InitializeVaultEpochStats;
+  }
+  if (containsBytes(data, getU8Encoder().encode(56), 0)) {
+    return JitoVaultInstruction.SetFlashDepositProtection;
+  }
+  if (containsBytes(data, getU8Encoder().encode(57), 0)) {
+    return JitoVaultInstruction.EnableAdminBurn;
+  }
+  if (containsBytes(data, getU8Encoder().encode(58), 0)) {
+    return JitoVaultInstruction.ProposeAdminBurn;
+  }
+  if (containsBytes(data, getU8Encoder().encode(59), 0)) {
+    return JitoVaultInstruction.CancelAdminBurn;
+  }
+  if (containsBytes(data, getU8Encoder().encode(60), 0)) {
+    return JitoVaultInstruction.ExecuteAdminBurn;
+  }
+  if (containsBytes(data, getU8Encoder().encode(61), 0)) {
+    return JitoVaultInstruction.AdminSetConfigPaused;
+  }
+  if (containsBytes(data, getU8Encoder().encode(62), 0)) {
+    return JitoVaultInstruction.ReconcileVaultBalance;
+  }
+  if (containsBytes(data, getU8Encoder().encode(63), 0)) {
+    return JitoVaultInstruction.SnapshotVaultOperatorDelegation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(64), 0)) {
+    return JitoVaultInstruction.InitializeRewardDistribution;
+  }
+  if (containsBytes(data, getU8Encoder().encode(65), 0)) {
+    return JitoVaultInstruction.ClaimRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(66), 0)) {
+    return JitoVaultInstruction.InitiateVaultShutdown;
+  }
+  if (containsBytes(data, getU8Encoder().encode(67), 0)) {
+    return JitoVaultInstruction.InitializeNcnRewardRouter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(68), 0)) {
+    return JitoVaultInstruction.DistributeNcnRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(69), 0)) {
+    return JitoVaultInstruction.SetInsuranceParams;
+  }
+  if (containsBytes(data, getU8Encoder().encode(70), 0)) {
+    return JitoVaultInstruction.FundInsurance;
+  }
+  if (containsBytes(data, getU8Encoder().encode(71), 0)) {
+    return JitoVaultInstruction.SetHookProgram;
+  }
+  if (containsBytes(data, getU8Encoder().encode(72), 0)) {
+    return JitoVaultInstruction.BurnDelegated;
+  }
+  if (containsBytes(data, getU8Encoder().encode(73), 0)) {
+    return JitoVaultInstruction.AdminSetCrankReward;
+  }
+  if (containsBytes(data, getU8Encoder().encode(74), 0)) {
+    return JitoVaultInstruction.InitializeVaultTokenAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(75), 0)) {
+    return JitoVaultInstruction.CloseDepositReceipt;
+  }
+  if (containsBytes(data, getU8Encoder().encode(76), 0)) {
+    return JitoVaultInstruction.SetMaxTotalSlashablePerEpoch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(77), 0)) {
+    return JitoVaultInstruction.SetMigrationPartner;
+  }
+  if (containsBytes(data, getU8Encoder().encode(78), 0)) {
+    return JitoVaultInstruction.MigrateToVault;
+  }
+  if (containsBytes(data, getU8Encoder().encode(79), 0)) {
+    return JitoVaultInstruction.RemoveSlasher;
+  }
+  if (containsBytes(data, getU8Encoder().encode(80), 0)) {
+    return JitoVaultInstruction.SetWithdrawalDelay;
+  }
+  if (containsBytes(data, getU8Encoder().encode(81), 0)) {
+    return JitoVaultInstruction.MigrateAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(82), 0)) {
+    return JitoVaultInstruction.InitializeOperatorStakeWeight;
+  }
+  if (containsBytes(data, getU8Encoder().encode(83), 0)) {
+    return JitoVaultInstruction.CrankOperatorStakeWeight;
+  }
+  if (containsBytes(data, getU8Encoder().encode(84), 0)) {
+    return JitoVaultInstruction.AdminSetVaultEpochLengthBounds;
+  }
+  if (containsBytes(data, getU8Encoder().encode(85), 0)) {
+    return JitoVaultInstruction.SetVaultEpochLength;
+  }
+  if (containsBytes(data, getU8Encoder().encode(86), 0)) {
+    return JitoVaultInstruction.BanOperator;
+  }
+  if (containsBytes(data, getU8Encoder().encode(87), 0)) {
+    return JitoVaultInstruction.UnbanOperator;
+  }
+  if (containsBytes(data, getU8Encoder().encode(88), 0)) {
+    return JitoVaultInstruction.SetSlashDisputeHaircut;
+  }
   throw new Error(
     'The provided instruction could not be identified as a jitoVault instruction.'
   );
@@ -283,4 +590,181 @@ export type ParsedJitoVaultInstruction<
     } & ParsedUpdateTokenMetadataInstruction<TProgram>)
   | ({
       instructionType: JitoVaultInstruction.Slash;
-    } & ParsedSlashInstruction<TProgram>);
+    } & ParsedSlashInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetVaultPaused;
+    } & ParsedSetVaultPausedInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetFeeWallet;
+    } & ParsedSetFeeWalletInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.AdminSetProtocolFee;
+    } & ParsedAdminSetProtocolFeeInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetMinDelegationAmount;
+    } & ParsedSetMinDelegationAmountInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetDepositMode;
+    } & ParsedSetDepositModeInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.AddToDepositAllowlist;
+    } & ParsedAddToDepositAllowlistInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.RemoveFromDepositAllowlist;
+    } & ParsedRemoveFromDepositAllowlistInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetDepositLimits;
+    } & ParsedSetDepositLimitsInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetWithdrawalTicketAutoClaimFee;
+    } & ParsedSetWithdrawalTicketAutoClaimFeeInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.ProposeMintAuthorityHandoff;
+    } & ParsedProposeMintAuthorityHandoffInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.CancelMintAuthorityHandoff;
+    } & ParsedCancelMintAuthorityHandoffInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.ExecuteMintAuthorityHandoff;
+    } & ParsedExecuteMintAuthorityHandoffInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.AdminSetDelegationCooldown;
+    } & ParsedAdminSetDelegationCooldownInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.AdminSetSlashVeto;
+    } & ParsedAdminSetSlashVetoInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.ProposeSlash;
+    } & ParsedProposeSlashInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.VetoSlash;
+    } & ParsedVetoSlashInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.ExecuteSlash;
+    } & ParsedExecuteSlashInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.MintToWithSol;
+    } & ParsedMintToWithSolInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.CloseVault;
+    } & ParsedCloseVaultInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetOperatorMaxDelegation;
+    } & ParsedSetOperatorMaxDelegationInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetOperatorDelegationPaused;
+    } & ParsedSetOperatorDelegationPausedInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.InitializeDelegationStrategy;
+    } & ParsedInitializeDelegationStrategyInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetDelegationStrategyWeight;
+    } & ParsedSetDelegationStrategyWeightInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.RebalanceDelegations;
+    } & ParsedRebalanceDelegationsInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.BurnOrEnqueueWithdrawal;
+    } & ParsedBurnOrEnqueueWithdrawalInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.InitializeVaultEpochStats;
+    } & ParsedInitializeVaultEpochStatsInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetFlashDepositProtection;
+    } & ParsedSetFlashDepositProtectionInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.EnableAdminBurn;
+    } & ParsedEnableAdminBurnInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.ProposeAdminBurn;
+    } & ParsedProposeAdminBurnInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.CancelAdminBurn;
+    } & ParsedCancelAdminBurnInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.ExecuteAdminBurn;
+    } & ParsedExecuteAdminBurnInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.AdminSetConfigPaused;
+    } & ParsedAdminSetConfigPausedInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.ReconcileVaultBalance;
+    } & ParsedReconcileVaultBalanceInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SnapshotVaultOperatorDelegation;
+    } & ParsedSnapshotVaultOperatorDelegationInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.InitializeRewardDistribution;
+    } & ParsedInitializeRewardDistributionInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.ClaimRewards;
+    } & ParsedClaimRewardsInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.InitiateVaultShutdown;
+    } & ParsedInitiateVaultShutdownInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.InitializeNcnRewardRouter;
+    } & ParsedInitializeNcnRewardRouterInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.DistributeNcnRewards;
+    } & ParsedDistributeNcnRewardsInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetInsuranceParams;
+    } & ParsedSetInsuranceParamsInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.FundInsurance;
+    } & ParsedFundInsuranceInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetHookProgram;
+    } & ParsedSetHookProgramInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.BurnDelegated;
+    } & ParsedBurnDelegatedInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.AdminSetCrankReward;
+    } & ParsedAdminSetCrankRewardInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.InitializeVaultTokenAccount;
+    } & ParsedInitializeVaultTokenAccountInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.CloseDepositReceipt;
+    } & ParsedCloseDepositReceiptInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetMaxTotalSlashablePerEpoch;
+    } & ParsedSetMaxTotalSlashablePerEpochInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetMigrationPartner;
+    } & ParsedSetMigrationPartnerInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.MigrateToVault;
+    } & ParsedMigrateToVaultInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.RemoveSlasher;
+    } & ParsedRemoveSlasherInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetWithdrawalDelay;
+    } & ParsedSetWithdrawalDelayInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.InitializeOperatorStakeWeight;
+    } & ParsedInitializeOperatorStakeWeightInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.CrankOperatorStakeWeight;
+    } & ParsedCrankOperatorStakeWeightInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.AdminSetVaultEpochLengthBounds;
+    } & ParsedAdminSetVaultEpochLengthBoundsInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetVaultEpochLength;
+    } & ParsedSetVaultEpochLengthInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.BanOperator;
+    } & ParsedBanOperatorInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.UnbanOperator;
+    } & ParsedUnbanOperatorInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.SetSlashDisputeHaircut;
+    } & ParsedSetSlashDisputeHaircutInstruction<TProgram>);
@@ -0,0 +1,56 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type AccountMetaData = {
+  pubkey: Address;
+  isSigner: boolean;
+  isWritable: boolean;
+};
+
+export type AccountMetaDataArgs = {
+  pubkey: Address;
+  isSigner: boolean;
+  isWritable: boolean;
+};
+
+export function getAccountMetaDataEncoder(): Encoder<AccountMetaDataArgs> {
+  return getStructEncoder([
+    ['pubkey', getAddressEncoder()],
+    ['isSigner', getBooleanEncoder()],
+    ['isWritable', getBooleanEncoder()],
+  ]);
+}
+
+export function getAccountMetaDataDecoder(): Decoder<AccountMetaData> {
+  return getStructDecoder([
+    ['pubkey', getAddressDecoder()],
+    ['isSigner', getBooleanDecoder()],
+    ['isWritable', getBooleanDecoder()],
+  ]);
+}
+
+export function getAccountMetaDataCodec(): Codec<
+  AccountMetaDataArgs,
+  AccountMetaData
+> {
+  return combineCodec(getAccountMetaDataEncoder(), getAccountMetaDataDecoder());
+}
@@ -0,0 +1,59 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type BalanceReconciledEvent = {
+  vault: Address;
+  tokensDepositedBefore: bigint;
+  tokensDepositedAfter: bigint;
+};
+
+export type BalanceReconciledEventArgs = {
+  vault: Address;
+  tokensDepositedBefore: number | bigint;
+  tokensDepositedAfter: number | bigint;
+};
+
+export function getBalanceReconciledEventEncoder(): Encoder<BalanceReconciledEventArgs> {
+  return getStructEncoder([
+    ['vault', getAddressEncoder()],
+    ['tokensDepositedBefore', getU64Encoder()],
+    ['tokensDepositedAfter', getU64Encoder()],
+  ]);
+}
+
+export function getBalanceReconciledEventDecoder(): Decoder<BalanceReconciledEvent> {
+  return getStructDecoder([
+    ['vault', getAddressDecoder()],
+    ['tokensDepositedBefore', getU64Decoder()],
+    ['tokensDepositedAfter', getU64Decoder()],
+  ]);
+}
+
+export function getBalanceReconciledEventCodec(): Codec<
+  BalanceReconciledEventArgs,
+  BalanceReconciledEvent
+> {
+  return combineCodec(
+    getBalanceReconciledEventEncoder(),
+    getBalanceReconciledEventDecoder()
+  );
+}
@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type CooldownCohorts = { amounts: Array<bigint> };
+
+export type CooldownCohortsArgs = { amounts: Array<number | bigint> };
+
+export function getCooldownCohortsEncoder(): Encoder<CooldownCohortsArgs> {
+  return getStructEncoder([
+    ['amounts', getArrayEncoder(getU64Encoder(), { size: 16 })],
+  ]);
+}
+
+export function getCooldownCohortsDecoder(): Decoder<CooldownCohorts> {
+  return getStructDecoder([
+    ['amounts', getArrayDecoder(getU64Decoder(), { size: 16 })],
+  ]);
+}
+
+export function getCooldownCohortsCodec(): Codec<
+  CooldownCohortsArgs,
+  CooldownCohorts
+> {
+  return combineCodec(getCooldownCohortsEncoder(), getCooldownCohortsDecoder());
+}
@@ -0,0 +1,41 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getEnumDecoder,
+  getEnumEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export enum DelegationChange {
+  Delegate,
+  Cooldown,
+}
+
+export type DelegationChangeArgs = DelegationChange;
+
+export function getDelegationChangeEncoder(): Encoder<DelegationChangeArgs> {
+  return getEnumEncoder(DelegationChange);
+}
+
+export function getDelegationChangeDecoder(): Decoder<DelegationChange> {
+  return getEnumDecoder(DelegationChange);
+}
+
+export function getDelegationChangeCodec(): Codec<
+  DelegationChangeArgs,
+  DelegationChange
+> {
+  return combineCodec(
+    getDelegationChangeEncoder(),
+    getDelegationChangeDecoder()
+  );
+}
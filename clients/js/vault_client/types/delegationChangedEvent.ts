@@ -0,0 +1,73 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+import {
+  getDelegationChangeDecoder,
+  getDelegationChangeEncoder,
+  type DelegationChange,
+  type DelegationChangeArgs,
+} from '.';
+
+export type DelegationChangedEvent = {
+  vault: Address;
+  operator: Address;
+  change: DelegationChange;
+  amount: bigint;
+  stakedAmount: bigint;
+};
+
+export type DelegationChangedEventArgs = {
+  vault: Address;
+  operator: Address;
+  change: DelegationChangeArgs;
+  amount: number | bigint;
+  stakedAmount: number | bigint;
+};
+
+export function getDelegationChangedEventEncoder(): Encoder<DelegationChangedEventArgs> {
+  return getStructEncoder([
+    ['vault', getAddressEncoder()],
+    ['operator', getAddressEncoder()],
+    ['change', getDelegationChangeEncoder()],
+    ['amount', getU64Encoder()],
+    ['stakedAmount', getU64Encoder()],
+  ]);
+}
+
+export function getDelegationChangedEventDecoder(): Decoder<DelegationChangedEvent> {
+  return getStructDecoder([
+    ['vault', getAddressDecoder()],
+    ['operator', getAddressDecoder()],
+    ['change', getDelegationChangeDecoder()],
+    ['amount', getU64Decoder()],
+    ['stakedAmount', getU64Decoder()],
+  ]);
+}
+
+export function getDelegationChangedEventCodec(): Codec<
+  DelegationChangedEventArgs,
+  DelegationChangedEvent
+> {
+  return combineCodec(
+    getDelegationChangedEventEncoder(),
+    getDelegationChangedEventDecoder()
+  );
+}
@@ -0,0 +1,53 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type DelegationSnapshot = { leafCount: bigint; frontier: Array<number> };
+
+export type DelegationSnapshotArgs = {
+  leafCount: number | bigint;
+  frontier: Array<number>;
+};
+
+export function getDelegationSnapshotEncoder(): Encoder<DelegationSnapshotArgs> {
+  return getStructEncoder([
+    ['leafCount', getU64Encoder()],
+    ['frontier', getArrayEncoder(getU8Encoder(), { size: 1024 })],
+  ]);
+}
+
+export function getDelegationSnapshotDecoder(): Decoder<DelegationSnapshot> {
+  return getStructDecoder([
+    ['leafCount', getU64Decoder()],
+    ['frontier', getArrayDecoder(getU8Decoder(), { size: 1024 })],
+  ]);
+}
+
+export function getDelegationSnapshotCodec(): Codec<
+  DelegationSnapshotArgs,
+  DelegationSnapshot
+> {
+  return combineCodec(
+    getDelegationSnapshotEncoder(),
+    getDelegationSnapshotDecoder()
+  );
+}
@@ -20,11 +20,18 @@ import {
   type Decoder,
   type Encoder,
 } from '@solana/web3.js';
+import {
+  getCooldownCohortsDecoder,
+  getCooldownCohortsEncoder,
+  type CooldownCohorts,
+  type CooldownCohortsArgs,
+} from '.';
 
 export type DelegationState = {
   stakedAmount: bigint;
   enqueuedForCooldownAmount: bigint;
   coolingDownAmount: bigint;
+  coolingDownCohorts: CooldownCohorts;
   reserved: Array<number>;
 };
 
@@ -32,6 +39,7 @@ export type DelegationStateArgs = {
   stakedAmount: number | bigint;
   enqueuedForCooldownAmount: number | bigint;
   coolingDownAmount: number | bigint;
+  coolingDownCohorts: CooldownCohortsArgs;
   reserved: Array<number>;
 };
 
@@ -40,7 +48,8 @@ export function getDelegationStateEncoder(): Encoder<DelegationStateArgs> {
     ['stakedAmount', getU64Encoder()],
     ['enqueuedForCooldownAmount', getU64Encoder()],
     ['coolingDownAmount', getU64Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 256 })],
+    ['coolingDownCohorts', getCooldownCohortsEncoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 128 })],
   ]);
 }
 
@@ -49,7 +58,8 @@ export function getDelegationStateDecoder(): Decoder<DelegationState> {
     ['stakedAmount', getU64Decoder()],
     ['enqueuedForCooldownAmount', getU64Decoder()],
     ['coolingDownAmount', getU64Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 256 })],
+    ['coolingDownCohorts', getCooldownCohortsDecoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 128 })],
   ]);
 }
 
@@ -0,0 +1,61 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type DepositEvent = {
+  vault: Address;
+  depositor: Address;
+  amountIn: bigint;
+  vrtToDepositor: bigint;
+  vrtFee: bigint;
+};
+
+export type DepositEventArgs = {
+  vault: Address;
+  depositor: Address;
+  amountIn: number | bigint;
+  vrtToDepositor: number | bigint;
+  vrtFee: number | bigint;
+};
+
+export function getDepositEventEncoder(): Encoder<DepositEventArgs> {
+  return getStructEncoder([
+    ['vault', getAddressEncoder()],
+    ['depositor', getAddressEncoder()],
+    ['amountIn', getU64Encoder()],
+    ['vrtToDepositor', getU64Encoder()],
+    ['vrtFee', getU64Encoder()],
+  ]);
+}
+
+export function getDepositEventDecoder(): Decoder<DepositEvent> {
+  return getStructDecoder([
+    ['vault', getAddressDecoder()],
+    ['depositor', getAddressDecoder()],
+    ['amountIn', getU64Decoder()],
+    ['vrtToDepositor', getU64Decoder()],
+    ['vrtFee', getU64Decoder()],
+  ]);
+}
+
+export function getDepositEventCodec(): Codec<DepositEventArgs, DepositEvent> {
+  return combineCodec(getDepositEventEncoder(), getDepositEventDecoder());
+}
@@ -0,0 +1,36 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getEnumDecoder,
+  getEnumEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export enum DepositMode {
+  Open,
+  SignerGated,
+  Allowlist,
+}
+
+export type DepositModeArgs = DepositMode;
+
+export function getDepositModeEncoder(): Encoder<DepositModeArgs> {
+  return getEnumEncoder(DepositMode);
+}
+
+export function getDepositModeDecoder(): Decoder<DepositMode> {
+  return getEnumDecoder(DepositMode);
+}
+
+export function getDepositModeCodec(): Codec<DepositModeArgs, DepositMode> {
+  return combineCodec(getDepositModeEncoder(), getDepositModeDecoder());
+}
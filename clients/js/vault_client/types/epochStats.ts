@@ -0,0 +1,58 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type EpochStats = {
+  epoch: bigint;
+  deposited: bigint;
+  withdrawn: bigint;
+  vrtFees: bigint;
+  slashed: bigint;
+};
+
+export type EpochStatsArgs = {
+  epoch: number | bigint;
+  deposited: number | bigint;
+  withdrawn: number | bigint;
+  vrtFees: number | bigint;
+  slashed: number | bigint;
+};
+
+export function getEpochStatsEncoder(): Encoder<EpochStatsArgs> {
+  return getStructEncoder([
+    ['epoch', getU64Encoder()],
+    ['deposited', getU64Encoder()],
+    ['withdrawn', getU64Encoder()],
+    ['vrtFees', getU64Encoder()],
+    ['slashed', getU64Encoder()],
+  ]);
+}
+
+export function getEpochStatsDecoder(): Decoder<EpochStats> {
+  return getStructDecoder([
+    ['epoch', getU64Decoder()],
+    ['deposited', getU64Decoder()],
+    ['withdrawn', getU64Decoder()],
+    ['vrtFees', getU64Decoder()],
+    ['slashed', getU64Decoder()],
+  ]);
+}
+
+export function getEpochStatsCodec(): Codec<EpochStatsArgs, EpochStats> {
+  return combineCodec(getEpochStatsEncoder(), getEpochStatsDecoder());
+}
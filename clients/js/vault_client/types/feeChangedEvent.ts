@@ -0,0 +1,64 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type Option,
+  type OptionOrNullable,
+} from '@solana/web3.js';
+
+export type FeeChangedEvent = {
+  vault: Address;
+  depositFeeBps: Option<number>;
+  withdrawalFeeBps: Option<number>;
+  rewardFeeBps: Option<number>;
+};
+
+export type FeeChangedEventArgs = {
+  vault: Address;
+  depositFeeBps: OptionOrNullable<number>;
+  withdrawalFeeBps: OptionOrNullable<number>;
+  rewardFeeBps: OptionOrNullable<number>;
+};
+
+export function getFeeChangedEventEncoder(): Encoder<FeeChangedEventArgs> {
+  return getStructEncoder([
+    ['vault', getAddressEncoder()],
+    ['depositFeeBps', getOptionEncoder(getU16Encoder())],
+    ['withdrawalFeeBps', getOptionEncoder(getU16Encoder())],
+    ['rewardFeeBps', getOptionEncoder(getU16Encoder())],
+  ]);
+}
+
+export function getFeeChangedEventDecoder(): Decoder<FeeChangedEvent> {
+  return getStructDecoder([
+    ['vault', getAddressDecoder()],
+    ['depositFeeBps', getOptionDecoder(getU16Decoder())],
+    ['withdrawalFeeBps', getOptionDecoder(getU16Decoder())],
+    ['rewardFeeBps', getOptionDecoder(getU16Decoder())],
+  ]);
+}
+
+export function getFeeChangedEventCodec(): Codec<
+  FeeChangedEventArgs,
+  FeeChangedEvent
+> {
+  return combineCodec(getFeeChangedEventEncoder(), getFeeChangedEventDecoder());
+}
@@ -6,10 +6,26 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './accountMetaData';
+export * from './balanceReconciledEvent';
+export * from './cooldownCohorts';
 export * from './createMetadataAccountArgsV3';
 export * from './dataV2';
+export * from './delegationChange';
+export * from './delegationChangedEvent';
+export * from './delegationSnapshot';
 export * from './delegationState';
+export * from './depositEvent';
+export * from './depositMode';
+export * from './epochStats';
+export * from './feeChangedEvent';
+export * from './instructionData';
+export * from './slashedEvent';
 export * from './slotToggle';
+export * from './slotToggleState';
 export * from './updateMetadataAccountArgsV2';
 export * from './vaultAdminRole';
+export * from './vaultEvent';
+export * from './vaultHookInstruction';
+export * from './withdrawEvent';
 export * from './withdrawalAllocationMethod';
@@ -0,0 +1,69 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+import {
+  getAccountMetaDataDecoder,
+  getAccountMetaDataEncoder,
+  type AccountMetaData,
+  type AccountMetaDataArgs,
+} from '.';
+
+export type InstructionData = {
+  programId: Address;
+  accounts: Array<AccountMetaData>;
+  data: ReadonlyUint8Array;
+};
+
+export type InstructionDataArgs = {
+  programId: Address;
+  accounts: Array<AccountMetaDataArgs>;
+  data: ReadonlyUint8Array;
+};
+
+export function getInstructionDataEncoder(): Encoder<InstructionDataArgs> {
+  return getStructEncoder([
+    ['programId', getAddressEncoder()],
+    ['accounts', getArrayEncoder(getAccountMetaDataEncoder())],
+    ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+  ]);
+}
+
+export function getInstructionDataDecoder(): Decoder<InstructionData> {
+  return getStructDecoder([
+    ['programId', getAddressDecoder()],
+    ['accounts', getArrayDecoder(getAccountMetaDataDecoder())],
+    ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+  ]);
+}
+
+export function getInstructionDataCodec(): Codec<
+  InstructionDataArgs,
+  InstructionData
+> {
+  return combineCodec(getInstructionDataEncoder(), getInstructionDataDecoder());
+}
@@ -0,0 +1,61 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type SlashedEvent = {
+  vault: Address;
+  ncn: Address;
+  operator: Address;
+  slasher: Address;
+  amount: bigint;
+};
+
+export type SlashedEventArgs = {
+  vault: Address;
+  ncn: Address;
+  operator: Address;
+  slasher: Address;
+  amount: number | bigint;
+};
+
+export function getSlashedEventEncoder(): Encoder<SlashedEventArgs> {
+  return getStructEncoder([
+    ['vault', getAddressEncoder()],
+    ['ncn', getAddressEncoder()],
+    ['operator', getAddressEncoder()],
+    ['slasher', getAddressEncoder()],
+    ['amount', getU64Encoder()],
+  ]);
+}
+
+export function getSlashedEventDecoder(): Decoder<SlashedEvent> {
+  return getStructDecoder([
+    ['vault', getAddressDecoder()],
+    ['ncn', getAddressDecoder()],
+    ['operator', getAddressDecoder()],
+    ['slasher', getAddressDecoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getSlashedEventCodec(): Codec<SlashedEventArgs, SlashedEvent> {
+  return combineCodec(getSlashedEventEncoder(), getSlashedEventDecoder());
+}
@@ -25,6 +25,8 @@ export enum VaultAdminRole {
   MintBurnAdmin,
   WithdrawAdmin,
   FeeAdmin,
+  PauseAdmin,
+  MetadataAdmin,
 }
 
 export type VaultAdminRoleArgs = VaultAdminRole;
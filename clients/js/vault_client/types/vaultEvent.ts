@@ -0,0 +1,221 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getDiscriminatedUnionDecoder,
+  getDiscriminatedUnionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getTupleDecoder,
+  getTupleEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type GetDiscriminatedUnionVariant,
+  type GetDiscriminatedUnionVariantContent,
+} from '@solana/web3.js';
+import {
+  getBalanceReconciledEventDecoder,
+  getBalanceReconciledEventEncoder,
+  getDelegationChangedEventDecoder,
+  getDelegationChangedEventEncoder,
+  getDepositEventDecoder,
+  getDepositEventEncoder,
+  getFeeChangedEventDecoder,
+  getFeeChangedEventEncoder,
+  getSlashedEventDecoder,
+  getSlashedEventEncoder,
+  getWithdrawEventDecoder,
+  getWithdrawEventEncoder,
+  type BalanceReconciledEvent,
+  type BalanceReconciledEventArgs,
+  type DelegationChangedEvent,
+  type DelegationChangedEventArgs,
+  type DepositEvent,
+  type DepositEventArgs,
+  type FeeChangedEvent,
+  type FeeChangedEventArgs,
+  type SlashedEvent,
+  type SlashedEventArgs,
+  type WithdrawEvent,
+  type WithdrawEventArgs,
+} from '.';
+
+export type VaultEvent =
+  | { __kind: 'Deposit'; fields: readonly [DepositEvent] }
+  | { __kind: 'Withdraw'; fields: readonly [WithdrawEvent] }
+  | { __kind: 'DelegationChanged'; fields: readonly [DelegationChangedEvent] }
+  | { __kind: 'Slashed'; fields: readonly [SlashedEvent] }
+  | { __kind: 'FeeChanged'; fields: readonly [FeeChangedEvent] }
+  | { __kind: 'BalanceReconciled'; fields: readonly [BalanceReconciledEvent] };
+
+export type VaultEventArgs =
+  | { __kind: 'Deposit'; fields: readonly [DepositEventArgs] }
+  | { __kind: 'Withdraw'; fields: readonly [WithdrawEventArgs] }
+  | {
+      __kind: 'DelegationChanged';
+      fields: readonly [DelegationChangedEventArgs];
+    }
+  | { __kind: 'Slashed'; fields: readonly [SlashedEventArgs] }
+  | { __kind: 'FeeChanged'; fields: readonly [FeeChangedEventArgs] }
+  | {
+      __kind: 'BalanceReconciled';
+      fields: readonly [BalanceReconciledEventArgs];
+    };
+
+export function getVaultEventEncoder(): Encoder<VaultEventArgs> {
+  return getDiscriminatedUnionEncoder([
+    [
+      'Deposit',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getDepositEventEncoder()])],
+      ]),
+    ],
+    [
+      'Withdraw',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getWithdrawEventEncoder()])],
+      ]),
+    ],
+    [
+      'DelegationChanged',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getDelegationChangedEventEncoder()])],
+      ]),
+    ],
+    [
+      'Slashed',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getSlashedEventEncoder()])],
+      ]),
+    ],
+    [
+      'FeeChanged',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getFeeChangedEventEncoder()])],
+      ]),
+    ],
+    [
+      'BalanceReconciled',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getBalanceReconciledEventEncoder()])],
+      ]),
+    ],
+  ]);
+}
+
+export function getVaultEventDecoder(): Decoder<VaultEvent> {
+  return getDiscriminatedUnionDecoder([
+    [
+      'Deposit',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getDepositEventDecoder()])],
+      ]),
+    ],
+    [
+      'Withdraw',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getWithdrawEventDecoder()])],
+      ]),
+    ],
+    [
+      'DelegationChanged',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getDelegationChangedEventDecoder()])],
+      ]),
+    ],
+    [
+      'Slashed',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getSlashedEventDecoder()])],
+      ]),
+    ],
+    [
+      'FeeChanged',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getFeeChangedEventDecoder()])],
+      ]),
+    ],
+    [
+      'BalanceReconciled',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getBalanceReconciledEventDecoder()])],
+      ]),
+    ],
+  ]);
+}
+
+export function getVaultEventCodec(): Codec<VaultEventArgs, VaultEvent> {
+  return combineCodec(getVaultEventEncoder(), getVaultEventDecoder());
+}
+
+// Data Enum Helpers.
+export function vaultEvent(
+  kind: 'Deposit',
+  data: GetDiscriminatedUnionVariantContent<
+    VaultEventArgs,
+    '__kind',
+    'Deposit'
+  >['fields']
+): GetDiscriminatedUnionVariant<VaultEventArgs, '__kind', 'Deposit'>;
+export function vaultEvent(
+  kind: 'Withdraw',
+  data: GetDiscriminatedUnionVariantContent<
+    VaultEventArgs,
+    '__kind',
+    'Withdraw'
+  >['fields']
+): GetDiscriminatedUnionVariant<VaultEventArgs, '__kind', 'Withdraw'>;
+export function vaultEvent(
+  kind: 'DelegationChanged',
+  data: GetDiscriminatedUnionVariantContent<
+    VaultEventArgs,
+    '__kind',
+    'DelegationChanged'
+  >['fields']
+): GetDiscriminatedUnionVariant<VaultEventArgs, '__kind', 'DelegationChanged'>;
+export function vaultEvent(
+  kind: 'Slashed',
+  data: GetDiscriminatedUnionVariantContent<
+    VaultEventArgs,
+    '__kind',
+    'Slashed'
+  >['fields']
+): GetDiscriminatedUnionVariant<VaultEventArgs, '__kind', 'Slashed'>;
+export function vaultEvent(
+  kind: 'FeeChanged',
+  data: GetDiscriminatedUnionVariantContent<
+    VaultEventArgs,
+    '__kind',
+    'FeeChanged'
+  >['fields']
+): GetDiscriminatedUnionVariant<VaultEventArgs, '__kind', 'FeeChanged'>;
+export function vaultEvent(
+  kind: 'BalanceReconciled',
+  data: GetDiscriminatedUnionVariantContent<
+    VaultEventArgs,
+    '__kind',
+    'BalanceReconciled'
+  >['fields']
+): GetDiscriminatedUnionVariant<VaultEventArgs, '__kind', 'BalanceReconciled'>;
+export function vaultEvent<K extends VaultEventArgs['__kind'], Data>(
+  kind: K,
+  data?: Data
+) {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isVaultEvent<K extends VaultEvent['__kind']>(
+  kind: K,
+  value: VaultEvent
+): value is VaultEvent & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,129 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getDiscriminatedUnionDecoder,
+  getDiscriminatedUnionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type GetDiscriminatedUnionVariant,
+  type GetDiscriminatedUnionVariantContent,
+} from '@solana/web3.js';
+
+export type VaultHookInstruction =
+  | { __kind: 'AfterMint'; amountIn: bigint; vrtToDepositor: bigint }
+  | { __kind: 'AfterBurn'; vrtBurned: bigint; amountOut: bigint };
+
+export type VaultHookInstructionArgs =
+  | {
+      __kind: 'AfterMint';
+      amountIn: number | bigint;
+      vrtToDepositor: number | bigint;
+    }
+  | {
+      __kind: 'AfterBurn';
+      vrtBurned: number | bigint;
+      amountOut: number | bigint;
+    };
+
+export function getVaultHookInstructionEncoder(): Encoder<VaultHookInstructionArgs> {
+  return getDiscriminatedUnionEncoder([
+    [
+      'AfterMint',
+      getStructEncoder([
+        ['amountIn', getU64Encoder()],
+        ['vrtToDepositor', getU64Encoder()],
+      ]),
+    ],
+    [
+      'AfterBurn',
+      getStructEncoder([
+        ['vrtBurned', getU64Encoder()],
+        ['amountOut', getU64Encoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getVaultHookInstructionDecoder(): Decoder<VaultHookInstruction> {
+  return getDiscriminatedUnionDecoder([
+    [
+      'AfterMint',
+      getStructDecoder([
+        ['amountIn', getU64Decoder()],
+        ['vrtToDepositor', getU64Decoder()],
+      ]),
+    ],
+    [
+      'AfterBurn',
+      getStructDecoder([
+        ['vrtBurned', getU64Decoder()],
+        ['amountOut', getU64Decoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getVaultHookInstructionCodec(): Codec<
+  VaultHookInstructionArgs,
+  VaultHookInstruction
+> {
+  return combineCodec(
+    getVaultHookInstructionEncoder(),
+    getVaultHookInstructionDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function vaultHookInstruction(
+  kind: 'AfterMint',
+  data: GetDiscriminatedUnionVariantContent<
+    VaultHookInstructionArgs,
+    '__kind',
+    'AfterMint'
+  >
+): GetDiscriminatedUnionVariant<
+  VaultHookInstructionArgs,
+  '__kind',
+  'AfterMint'
+>;
+export function vaultHookInstruction(
+  kind: 'AfterBurn',
+  data: GetDiscriminatedUnionVariantContent<
+    VaultHookInstructionArgs,
+    '__kind',
+    'AfterBurn'
+  >
+): GetDiscriminatedUnionVariant<
+  VaultHookInstructionArgs,
+  '__kind',
+  'AfterBurn'
+>;
+export function vaultHookInstruction<
+  K extends VaultHookInstructionArgs['__kind'],
+  Data,
+>(kind: K, data?: Data) {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isVaultHookInstruction<
+  K extends VaultHookInstruction['__kind'],
+>(
+  kind: K,
+  value: VaultHookInstruction
+): value is VaultHookInstruction & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,64 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type WithdrawEvent = {
+  vault: Address;
+  staker: Address;
+  vrtBurned: bigint;
+  vrtFee: bigint;
+  amountOut: bigint;
+};
+
+export type WithdrawEventArgs = {
+  vault: Address;
+  staker: Address;
+  vrtBurned: number | bigint;
+  vrtFee: number | bigint;
+  amountOut: number | bigint;
+};
+
+export function getWithdrawEventEncoder(): Encoder<WithdrawEventArgs> {
+  return getStructEncoder([
+    ['vault', getAddressEncoder()],
+    ['staker', getAddressEncoder()],
+    ['vrtBurned', getU64Encoder()],
+    ['vrtFee', getU64Encoder()],
+    ['amountOut', getU64Encoder()],
+  ]);
+}
+
+export function getWithdrawEventDecoder(): Decoder<WithdrawEvent> {
+  return getStructDecoder([
+    ['vault', getAddressDecoder()],
+    ['staker', getAddressDecoder()],
+    ['vrtBurned', getU64Decoder()],
+    ['vrtFee', getU64Decoder()],
+    ['amountOut', getU64Decoder()],
+  ]);
+}
+
+export function getWithdrawEventCodec(): Codec<
+  WithdrawEventArgs,
+  WithdrawEvent
+> {
+  return combineCodec(getWithdrawEventEncoder(), getWithdrawEventDecoder());
+}
@@ -25,11 +25,39 @@ pub struct Config {
     pub operator_count: u64,
     pub epoch_length: u64,
     pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_admin: Pubkey,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved1: [u8; 263],
+    pub reserved1: [u8; 231],
 }
 
 impl Config {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `Config::PREFIX`
+    pub const PREFIX: &'static [u8] = "config".as_bytes();
+
+    pub fn create_pda(
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &["config".as_bytes(), &[bump]],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
+    pub fn find_pda() -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &["config".as_bytes()],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
@@ -51,11 +51,37 @@ pub struct Ncn {
     pub vault_count: u64,
     pub slasher_count: u64,
     pub bump: u8,
+    pub ticket_expiry_epochs: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 255],
 }
 
 impl Ncn {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `Ncn::PREFIX`
+    ///   1. base (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "ncn".as_bytes();
+
+    pub fn create_pda(
+        base: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &["ncn".as_bytes(), base.as_ref(), &[bump]],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
+    pub fn find_pda(base: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &["ncn".as_bytes(), base.as_ref()],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
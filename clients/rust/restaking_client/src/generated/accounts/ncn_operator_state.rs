@@ -27,11 +27,52 @@ pub struct NcnOperatorState {
     pub ncn_opt_in_state: SlotToggle,
     pub operator_opt_in_state: SlotToggle,
     pub bump: u8,
+    pub task_window_end_slot: u64,
+    pub frozen_until_slot: u64,
+    pub expiry_slot: u64,
+    pub task_window_start_slot: u64,
+    pub task_window_extensions: u8,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 230],
 }
 
 impl NcnOperatorState {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `NcnOperatorState::PREFIX`
+    ///   1. ncn (`Pubkey`)
+    ///   2. operator (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "ncn_operator_state".as_bytes();
+
+    pub fn create_pda(
+        ncn: Pubkey,
+        operator: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "ncn_operator_state".as_bytes(),
+                ncn.as_ref(),
+                operator.as_ref(),
+                &[bump],
+            ],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
+    pub fn find_pda(ncn: &Pubkey, operator: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "ncn_operator_state".as_bytes(),
+                ncn.as_ref(),
+                operator.as_ref(),
+            ],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
@@ -32,11 +32,63 @@ pub struct NcnVaultSlasherTicket {
     pub index: u64,
     pub state: SlotToggle,
     pub bump: u8,
+    pub slash_destination: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub insurance_wallet: Pubkey,
+    pub pending_max_slashable_per_epoch: u64,
+    pub pending_max_slashable_epoch: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 214],
 }
 
 impl NcnVaultSlasherTicket {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `NcnVaultSlasherTicket::PREFIX`
+    ///   1. ncn (`Pubkey`)
+    ///   2. vault (`Pubkey`)
+    ///   3. slasher (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "ncn_slasher_ticket".as_bytes();
+
+    pub fn create_pda(
+        ncn: Pubkey,
+        vault: Pubkey,
+        slasher: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "ncn_slasher_ticket".as_bytes(),
+                ncn.as_ref(),
+                vault.as_ref(),
+                slasher.as_ref(),
+                &[bump],
+            ],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
+    pub fn find_pda(
+        ncn: &Pubkey,
+        vault: &Pubkey,
+        slasher: &Pubkey,
+    ) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "ncn_slasher_ticket".as_bytes(),
+                ncn.as_ref(),
+                vault.as_ref(),
+                slasher.as_ref(),
+            ],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
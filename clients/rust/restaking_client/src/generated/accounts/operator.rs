@@ -50,11 +50,47 @@ pub struct Operator {
     pub ncn_count: u64,
     pub vault_count: u64,
     pub bump: u8,
+    pub task_window_count: u64,
+    pub task_window_end_slot: u64,
+    pub freeze_count: u64,
+    pub frozen_until_slot: u64,
+    pub commission_bps: u16,
+    pub commission_bps_epoch_start: u16,
+    pub commission_epoch: u64,
+    pub name: [u8; 32],
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved_space: [u8; 263],
+    pub website: [u8; 64],
+    pub delegation_approval_required: u8,
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    pub reserved_space: [u8; 122],
 }
 
 impl Operator {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `Operator::PREFIX`
+    ///   1. base (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "operator".as_bytes();
+
+    pub fn create_pda(
+        base: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &["operator".as_bytes(), base.as_ref(), &[bump]],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
+    pub fn find_pda(base: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &["operator".as_bytes(), base.as_ref()],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
@@ -26,11 +26,48 @@ pub struct OperatorVaultTicket {
     pub index: u64,
     pub state: SlotToggle,
     pub bump: u8,
+    pub approved_delegation_amount: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 255],
 }
 
 impl OperatorVaultTicket {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `OperatorVaultTicket::PREFIX`
+    ///   1. operator (`Pubkey`)
+    ///   2. vault (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "operator_vault_ticket".as_bytes();
+
+    pub fn create_pda(
+        operator: Pubkey,
+        vault: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "operator_vault_ticket".as_bytes(),
+                operator.as_ref(),
+                vault.as_ref(),
+                &[bump],
+            ],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
+    pub fn find_pda(operator: &Pubkey, vault: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "operator_vault_ticket".as_bytes(),
+                operator.as_ref(),
+                vault.as_ref(),
+            ],
+            &crate::JITO_RESTAKING_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
@@ -42,6 +42,36 @@ pub enum JitoRestakingError {
     /// 1010 - NcnVaultTicketFailedWarmup
     #[error("NcnVaultTicketFailedWarmup")]
     NcnVaultTicketFailedWarmup = 0x3F2,
+    /// 1011 - NcnTaskWindowInvalid
+    #[error("NcnTaskWindowInvalid")]
+    NcnTaskWindowInvalid = 0x3F3,
+    /// 1012 - NcnTaskWindowNotOpen
+    #[error("NcnTaskWindowNotOpen")]
+    NcnTaskWindowNotOpen = 0x3F4,
+    /// 1013 - NcnOperatorNotFrozen
+    #[error("NcnOperatorNotFrozen")]
+    NcnOperatorNotFrozen = 0x3F5,
+    /// 1014 - NcnTicketExpiryInvalid
+    #[error("NcnTicketExpiryInvalid")]
+    NcnTicketExpiryInvalid = 0x3F6,
+    /// 1015 - NcnInsuranceWalletMissing
+    #[error("NcnInsuranceWalletMissing")]
+    NcnInsuranceWalletMissing = 0x3F7,
+    /// 1016 - NcnOperatorStateUnslashable
+    #[error("NcnOperatorStateUnslashable")]
+    NcnOperatorStateUnslashable = 0x3F8,
+    /// 1017 - NcnVaultTicketUnslashable
+    #[error("NcnVaultTicketUnslashable")]
+    NcnVaultTicketUnslashable = 0x3F9,
+    /// 1018 - NcnVaultSlasherTicketUnslashable
+    #[error("NcnVaultSlasherTicketUnslashable")]
+    NcnVaultSlasherTicketUnslashable = 0x3FA,
+    /// 1019 - NcnOperatorBatchInvalid
+    #[error("NcnOperatorBatchInvalid")]
+    NcnOperatorBatchInvalid = 0x3FB,
+    /// 1020 - NcnTaskWindowExtensionsExceeded
+    #[error("NcnTaskWindowExtensionsExceeded")]
+    NcnTaskWindowExtensionsExceeded = 0x3FC,
     /// 2000 - OperatorNcnAdminInvalid
     #[error("OperatorNcnAdminInvalid")]
     OperatorNcnAdminInvalid = 0x7D0,
@@ -78,6 +108,39 @@ pub enum JitoRestakingError {
     /// 2011 - SlasherOverflow
     #[error("SlasherOverflow")]
     SlasherOverflow = 0x7DB,
+    /// 2012 - OperatorCommissionInvalid
+    #[error("OperatorCommissionInvalid")]
+    OperatorCommissionInvalid = 0x7DC,
+    /// 2013 - OperatorCommissionChangeTooLarge
+    #[error("OperatorCommissionChangeTooLarge")]
+    OperatorCommissionChangeTooLarge = 0x7DD,
+    /// 2014 - OperatorMetadataInvalid
+    #[error("OperatorMetadataInvalid")]
+    OperatorMetadataInvalid = 0x7DE,
+    /// 3000 - RestakingAccountDataEmpty
+    #[error("RestakingAccountDataEmpty")]
+    RestakingAccountDataEmpty = 0xBB8,
+    /// 3001 - RestakingAccountNotWritable
+    #[error("RestakingAccountNotWritable")]
+    RestakingAccountNotWritable = 0xBB9,
+    /// 3002 - RestakingAccountDiscriminatorInvalid
+    #[error("RestakingAccountDiscriminatorInvalid")]
+    RestakingAccountDiscriminatorInvalid = 0xBBA,
+    /// 3003 - RestakingAccountNotCanonicalPda
+    #[error("RestakingAccountNotCanonicalPda")]
+    RestakingAccountNotCanonicalPda = 0xBBB,
+    /// 3004 - RestakingAccountVersionInvalid
+    #[error("RestakingAccountVersionInvalid")]
+    RestakingAccountVersionInvalid = 0xBBC,
+    /// 4000 - ConfigAdminInvalid
+    #[error("ConfigAdminInvalid")]
+    ConfigAdminInvalid = 0xFA0,
+    /// 4001 - ConfigPendingAdminInvalid
+    #[error("ConfigPendingAdminInvalid")]
+    ConfigPendingAdminInvalid = 0xFA1,
+    /// 4002 - ConfigEpochLengthInvalid
+    #[error("ConfigEpochLengthInvalid")]
+    ConfigEpochLengthInvalid = 0xFA2,
 }
 
 impl solana_program::program_error::PrintProgramError for JitoRestakingError {
@@ -4,6 +4,11 @@
 //!
 //! <https://github.com/kinobi-so/kinobi>
 
+pub(crate) mod r#batch_add_operators;
+pub(crate) mod r#config_accept_admin;
+pub(crate) mod r#config_set_admin;
+pub(crate) mod r#config_set_epoch_length;
+pub(crate) mod r#config_set_parameters;
 pub(crate) mod r#cooldown_ncn_vault_slasher_ticket;
 pub(crate) mod r#cooldown_ncn_vault_ticket;
 pub(crate) mod r#cooldown_operator_vault_ticket;
@@ -14,28 +19,49 @@ pub(crate) mod r#initialize_ncn_vault_slasher_ticket;
 pub(crate) mod r#initialize_ncn_vault_ticket;
 pub(crate) mod r#initialize_operator;
 pub(crate) mod r#initialize_operator_vault_ticket;
+pub(crate) mod r#migrate_account;
+pub(crate) mod r#ncn_close_task_window;
 pub(crate) mod r#ncn_cooldown_operator;
+pub(crate) mod r#ncn_freeze_operator;
+pub(crate) mod r#ncn_open_task_window;
+pub(crate) mod r#ncn_request_slash;
 pub(crate) mod r#ncn_set_admin;
+pub(crate) mod r#ncn_set_max_slashable_per_epoch;
 pub(crate) mod r#ncn_set_secondary_admin;
+pub(crate) mod r#ncn_set_slash_destination;
+pub(crate) mod r#ncn_set_ticket_expiry;
+pub(crate) mod r#ncn_unfreeze_operator;
 pub(crate) mod r#ncn_warmup_operator;
 pub(crate) mod r#ncn_withdrawal_asset;
+pub(crate) mod r#operator_approve_delegation;
 pub(crate) mod r#operator_cooldown_ncn;
 pub(crate) mod r#operator_set_admin;
+pub(crate) mod r#operator_set_commission;
+pub(crate) mod r#operator_set_delegation_approval_required;
+pub(crate) mod r#operator_set_metadata;
 pub(crate) mod r#operator_set_secondary_admin;
 pub(crate) mod r#operator_warmup_ncn;
 pub(crate) mod r#operator_withdrawal_asset;
+pub(crate) mod r#renew_ticket;
 pub(crate) mod r#warmup_ncn_vault_slasher_ticket;
 pub(crate) mod r#warmup_ncn_vault_ticket;
 pub(crate) mod r#warmup_operator_vault_ticket;
 
 pub use self::{
+    r#batch_add_operators::*, r#config_accept_admin::*, r#config_set_admin::*,
+    r#config_set_epoch_length::*, r#config_set_parameters::*,
     r#cooldown_ncn_vault_slasher_ticket::*, r#cooldown_ncn_vault_ticket::*,
     r#cooldown_operator_vault_ticket::*, r#initialize_config::*, r#initialize_ncn::*,
     r#initialize_ncn_operator_state::*, r#initialize_ncn_vault_slasher_ticket::*,
     r#initialize_ncn_vault_ticket::*, r#initialize_operator::*,
-    r#initialize_operator_vault_ticket::*, r#ncn_cooldown_operator::*, r#ncn_set_admin::*,
-    r#ncn_set_secondary_admin::*, r#ncn_warmup_operator::*, r#ncn_withdrawal_asset::*,
-    r#operator_cooldown_ncn::*, r#operator_set_admin::*, r#operator_set_secondary_admin::*,
-    r#operator_warmup_ncn::*, r#operator_withdrawal_asset::*, r#warmup_ncn_vault_slasher_ticket::*,
+    r#initialize_operator_vault_ticket::*, r#migrate_account::*, r#ncn_close_task_window::*,
+    r#ncn_cooldown_operator::*, r#ncn_freeze_operator::*, r#ncn_open_task_window::*,
+    r#ncn_request_slash::*, r#ncn_set_admin::*, r#ncn_set_max_slashable_per_epoch::*,
+    r#ncn_set_secondary_admin::*, r#ncn_set_slash_destination::*, r#ncn_set_ticket_expiry::*,
+    r#ncn_unfreeze_operator::*, r#ncn_warmup_operator::*, r#ncn_withdrawal_asset::*,
+    r#operator_approve_delegation::*, r#operator_cooldown_ncn::*, r#operator_set_admin::*,
+    r#operator_set_commission::*, r#operator_set_delegation_approval_required::*,
+    r#operator_set_metadata::*, r#operator_set_secondary_admin::*, r#operator_warmup_ncn::*,
+    r#operator_withdrawal_asset::*, r#renew_ticket::*, r#warmup_ncn_vault_slasher_ticket::*,
     r#warmup_ncn_vault_ticket::*, r#warmup_operator_vault_ticket::*,
 };
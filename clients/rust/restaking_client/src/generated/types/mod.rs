@@ -6,6 +6,11 @@
 
 pub(crate) mod r#ncn_admin_role;
 pub(crate) mod r#operator_admin_role;
+pub(crate) mod r#slash_destination;
 pub(crate) mod r#slot_toggle;
+pub(crate) mod r#slot_toggle_state;
 
-pub use self::{r#ncn_admin_role::*, r#operator_admin_role::*, r#slot_toggle::*};
+pub use self::{
+    r#ncn_admin_role::*, r#operator_admin_role::*, r#slash_destination::*, r#slot_toggle::*,
+    r#slot_toggle_state::*,
+};
@@ -9,7 +9,60 @@ mod generated;
 use generated::*;
 
 pub mod accounts {
+    use borsh::BorshDeserialize;
+
     pub use super::generated::accounts::*;
+
+    /// An account of the restaking program that can be parsed from fetched account data without
+    /// depending on jito-restaking-core
+    pub trait ProgramAccount: BorshDeserialize {
+        /// The first byte of the account data, the account's discriminator in jito-restaking-core
+        const DISCRIMINATOR: u8;
+
+        /// Deserializes the account data, checking the discriminator first so an account of a
+        /// different type isn't silently parsed into this one. The rest of the eight byte header,
+        /// e.g. the layout version, is ignored, as is any data past the end of the struct.
+        fn try_from_slice_with_discriminator(data: &[u8]) -> Result<Self, std::io::Error> {
+            match data.first() {
+                Some(discriminator) if *discriminator == Self::DISCRIMINATOR => {
+                    let mut data = data;
+                    Self::deserialize(&mut data)
+                }
+                Some(discriminator) => Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "Discriminator is invalid; expected {}, got {}",
+                        Self::DISCRIMINATOR,
+                        discriminator
+                    ),
+                )),
+                None => Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "Account data is empty",
+                )),
+            }
+        }
+    }
+
+    macro_rules! impl_program_account {
+        ($($account:ident = $discriminator:literal),* $(,)?) => {
+            $(
+                impl ProgramAccount for $account {
+                    const DISCRIMINATOR: u8 = $discriminator;
+                }
+            )*
+        };
+    }
+
+    impl_program_account!(
+        Config = 1,
+        Ncn = 2,
+        Operator = 3,
+        NcnOperatorState = 4,
+        OperatorVaultTicket = 5,
+        NcnVaultTicket = 6,
+        NcnVaultSlasherTicket = 7,
+    );
 }
 
 pub mod instructions {
@@ -22,6 +75,79 @@ pub mod errors {
 
 pub mod types {
     pub use super::generated::types::*;
+
+    impl SlotToggleState {
+        /// Get the state of a toggle from its raw slot_added and slot_removed values, mirroring
+        /// the program's slot math so off-chain consumers don't need to duplicate it
+        ///
+        /// # Arguments
+        /// * `slot_added` - The slot at which the feature was added
+        /// * `slot_removed` - The slot at which the feature was removed
+        /// * `slot` - The slot at which the state is being queried
+        /// * `epoch_length` - The length of an epoch in slots
+        pub fn state_at_slot(
+            slot_added: u64,
+            slot_removed: u64,
+            slot: u64,
+            epoch_length: u64,
+        ) -> Self {
+            let current_epoch = slot / epoch_length;
+            match slot_added.cmp(&slot_removed) {
+                std::cmp::Ordering::Equal => Self::Inactive,
+                std::cmp::Ordering::Less => {
+                    if current_epoch > slot_removed / epoch_length + 1 {
+                        Self::Inactive
+                    } else {
+                        Self::Cooldown
+                    }
+                }
+                std::cmp::Ordering::Greater => {
+                    if current_epoch > slot_added / epoch_length + 1 {
+                        Self::Active
+                    } else {
+                        Self::WarmUp
+                    }
+                }
+            }
+        }
+    }
+
+    impl SlotToggle {
+        /// Get the state of the toggle at the given slot, see [`SlotToggleState::state_at_slot`]
+        pub fn state_at_slot(&self, slot: u64, epoch_length: u64) -> SlotToggleState {
+            SlotToggleState::state_at_slot(self.slot_added, self.slot_removed, slot, epoch_length)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_slot_toggle_state_at_slot() {
+            let toggle = SlotToggle {
+                slot_added: 100,
+                slot_removed: 0,
+                reserved: [0; 32],
+            };
+            assert_eq!(toggle.state_at_slot(150, 100), SlotToggleState::WarmUp);
+            assert_eq!(toggle.state_at_slot(299, 100), SlotToggleState::WarmUp);
+            assert_eq!(toggle.state_at_slot(300, 100), SlotToggleState::Active);
+
+            let toggle = SlotToggle {
+                slot_added: 100,
+                slot_removed: 400,
+                reserved: [0; 32],
+            };
+            assert_eq!(toggle.state_at_slot(599, 100), SlotToggleState::Cooldown);
+            assert_eq!(toggle.state_at_slot(600, 100), SlotToggleState::Inactive);
+
+            assert_eq!(
+                SlotToggleState::state_at_slot(5, 5, 1_000, 100),
+                SlotToggleState::Inactive
+            );
+        }
+    }
 }
 
 pub mod programs {
@@ -27,11 +27,52 @@ pub struct Config {
     pub fee_rate_of_change_bps: u16,
     pub fee_bump_bps: u16,
     pub bump: u8,
+    pub protocol_fee_bps: u16,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub protocol_fee_wallet: Pubkey,
+    pub delegation_cooldown_epochs: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub slash_veto_admin: Pubkey,
+    pub slash_veto_window_slots: u64,
+    pub is_paused: u8,
+    pub crank_reward_lamports: u64,
+    pub crank_reward_bps: u16,
+    pub min_vault_epoch_length: u64,
+    pub max_vault_epoch_length: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 154],
 }
 
 impl Config {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `Config::PREFIX`
+    pub const PREFIX: &'static [u8] = "config".as_bytes();
+
+    pub fn create_pda(
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &["config".as_bytes(), &[bump]],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
+    pub fn find_pda() -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &["config".as_bytes()],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
@@ -0,0 +1,95 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegationStrategy {
+    pub discriminator: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault: Pubkey,
+    pub total_weight: u64,
+    pub max_movement_per_epoch: u64,
+    pub movement_epoch: u64,
+    pub moved_amount: u64,
+    pub bump: u8,
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    pub reserved: [u8; 263],
+}
+
+impl DelegationStrategy {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `DelegationStrategy::PREFIX`
+    ///   1. vault (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "delegation_strategy".as_bytes();
+
+    pub fn create_pda(
+        vault: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &["delegation_strategy".as_bytes(), vault.as_ref(), &[bump]],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
+    pub fn find_pda(vault: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &["delegation_strategy".as_bytes(), vault.as_ref()],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for DelegationStrategy {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for DelegationStrategy {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        Ok(Self::deserialize(buf)?)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for DelegationStrategy {}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for DelegationStrategy {
+    fn owner() -> Pubkey {
+        crate::JITO_VAULT_ID
+    }
+}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::IdlBuild for DelegationStrategy {}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::Discriminator for DelegationStrategy {
+    const DISCRIMINATOR: [u8; 8] = [0; 8];
+}
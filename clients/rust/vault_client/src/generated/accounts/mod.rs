@@ -5,16 +5,31 @@
 //! <https://github.com/kinobi-so/kinobi>
 
 pub(crate) mod r#config;
+pub(crate) mod r#delegation_strategy;
+pub(crate) mod r#deposit_receipt;
+pub(crate) mod r#ncn_reward_router;
+pub(crate) mod r#operator_stake_weight;
+pub(crate) mod r#reward_claim;
+pub(crate) mod r#reward_distribution;
+pub(crate) mod r#slash_proposal;
 pub(crate) mod r#vault;
+pub(crate) mod r#vault_deposit_allowlist_entry;
+pub(crate) mod r#vault_depositor;
+pub(crate) mod r#vault_epoch_stats;
+pub(crate) mod r#vault_insurance;
 pub(crate) mod r#vault_ncn_slasher_operator_ticket;
 pub(crate) mod r#vault_ncn_slasher_ticket;
 pub(crate) mod r#vault_ncn_ticket;
 pub(crate) mod r#vault_operator_delegation;
+pub(crate) mod r#vault_operator_delegation_snapshot;
 pub(crate) mod r#vault_staker_withdrawal_ticket;
 pub(crate) mod r#vault_update_state_tracker;
 
 pub use self::{
-    r#config::*, r#vault::*, r#vault_ncn_slasher_operator_ticket::*, r#vault_ncn_slasher_ticket::*,
-    r#vault_ncn_ticket::*, r#vault_operator_delegation::*, r#vault_staker_withdrawal_ticket::*,
-    r#vault_update_state_tracker::*,
+    r#config::*, r#delegation_strategy::*, r#deposit_receipt::*, r#ncn_reward_router::*,
+    r#operator_stake_weight::*, r#reward_claim::*, r#reward_distribution::*, r#slash_proposal::*,
+    r#vault::*, r#vault_deposit_allowlist_entry::*, r#vault_depositor::*, r#vault_epoch_stats::*,
+    r#vault_insurance::*, r#vault_ncn_slasher_operator_ticket::*, r#vault_ncn_slasher_ticket::*,
+    r#vault_ncn_ticket::*, r#vault_operator_delegation::*, r#vault_operator_delegation_snapshot::*,
+    r#vault_staker_withdrawal_ticket::*, r#vault_update_state_tracker::*,
 };
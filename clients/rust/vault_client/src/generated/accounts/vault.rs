@@ -7,7 +7,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
-use crate::generated::types::DelegationState;
+use crate::generated::types::{CooldownCohorts, DelegationState};
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -95,11 +95,94 @@ pub struct Vault {
     pub withdrawal_fee_bps: u16,
     pub reward_fee_bps: u16,
     pub bump: u8,
+    pub is_paused: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pause_admin: Pubkey,
+    pub next_deposit_fee_bps: u16,
+    pub next_withdrawal_fee_bps: u16,
+    pub is_fee_change_pending: u8,
+    pub vrt_fees_accrued: u64,
+    pub last_fee_materialization_slot: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub metadata_admin: Pubkey,
+    pub min_delegation_amount: u64,
+    pub deposit_mode: u8,
+    pub max_deposit_per_transaction: u64,
+    pub max_deposit_per_wallet: u64,
+    pub delegation_snapshot_root: [u8; 32],
+    pub delegation_snapshot_epoch: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint_authority_successor: Pubkey,
+    pub mint_authority_handoff_slot: u64,
+    pub epoch_stats_enabled: u8,
+    pub flash_deposit_protection: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub burn_admin: Pubkey,
+    pub is_shut_down: u8,
+    pub shutdown_slot: u64,
+    pub insurance_enabled: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub hook_program: Pubkey,
+    pub last_crank_reward_slot: u64,
+    pub max_total_slashable_bps_per_epoch: u16,
+    pub slash_epoch: u64,
+    pub slash_epoch_tokens_deposited: u64,
+    pub slashed_this_epoch: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub migration_partner: Pubkey,
+    pub withdrawal_delay_epochs: u64,
+    pub epoch_length_slots: u64,
+    pub slash_dispute_haircut: u8,
+    pub pending_slash_amount: u64,
+    pub vrt_cooling_down_cohorts: CooldownCohorts,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 161],
 }
 
 impl Vault {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `Vault::PREFIX`
+    ///   1. base (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "vault".as_bytes();
+
+    pub fn create_pda(
+        base: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &["vault".as_bytes(), base.as_ref(), &[bump]],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
+    pub fn find_pda(base: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &["vault".as_bytes(), base.as_ref()],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
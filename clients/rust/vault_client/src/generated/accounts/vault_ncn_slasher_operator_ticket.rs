@@ -39,6 +39,60 @@ pub struct VaultNcnSlasherOperatorTicket {
 }
 
 impl VaultNcnSlasherOperatorTicket {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `VaultNcnSlasherOperatorTicket::PREFIX`
+    ///   1. vault (`Pubkey`)
+    ///   2. ncn (`Pubkey`)
+    ///   3. slasher (`Pubkey`)
+    ///   4. operator (`Pubkey`)
+    ///   5. epoch (`u64`)
+    pub const PREFIX: &'static [u8] = "vault_ncn_slasher_operator".as_bytes();
+
+    pub fn create_pda(
+        vault: Pubkey,
+        ncn: Pubkey,
+        slasher: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "vault_ncn_slasher_operator".as_bytes(),
+                vault.as_ref(),
+                ncn.as_ref(),
+                slasher.as_ref(),
+                operator.as_ref(),
+                epoch.to_string().as_ref(),
+                &[bump],
+            ],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
+    pub fn find_pda(
+        vault: &Pubkey,
+        ncn: &Pubkey,
+        slasher: &Pubkey,
+        operator: &Pubkey,
+        epoch: &u64,
+    ) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "vault_ncn_slasher_operator".as_bytes(),
+                vault.as_ref(),
+                ncn.as_ref(),
+                slasher.as_ref(),
+                operator.as_ref(),
+                epoch.to_string().as_ref(),
+            ],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
@@ -37,6 +37,50 @@ pub struct VaultNcnSlasherTicket {
 }
 
 impl VaultNcnSlasherTicket {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `VaultNcnSlasherTicket::PREFIX`
+    ///   1. vault (`Pubkey`)
+    ///   2. ncn (`Pubkey`)
+    ///   3. slasher (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "vault_slasher_ticket".as_bytes();
+
+    pub fn create_pda(
+        vault: Pubkey,
+        ncn: Pubkey,
+        slasher: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "vault_slasher_ticket".as_bytes(),
+                vault.as_ref(),
+                ncn.as_ref(),
+                slasher.as_ref(),
+                &[bump],
+            ],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
+    pub fn find_pda(
+        vault: &Pubkey,
+        ncn: &Pubkey,
+        slasher: &Pubkey,
+    ) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "vault_slasher_ticket".as_bytes(),
+                vault.as_ref(),
+                ncn.as_ref(),
+                slasher.as_ref(),
+            ],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
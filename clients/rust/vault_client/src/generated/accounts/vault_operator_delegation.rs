@@ -27,11 +27,53 @@ pub struct VaultOperatorDelegation {
     pub last_update_slot: u64,
     pub index: u64,
     pub bump: u8,
+    pub reserved_cooldown_start_epoch: [u8; 8],
+    pub max_delegation_amount: u64,
+    pub delegation_paused: u8,
+    pub target_weight: u64,
+    pub banned: u8,
+    pub ban_reason: u8,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 236],
 }
 
 impl VaultOperatorDelegation {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `VaultOperatorDelegation::PREFIX`
+    ///   1. vault (`Pubkey`)
+    ///   2. operator (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "vault_operator_delegation".as_bytes();
+
+    pub fn create_pda(
+        vault: Pubkey,
+        operator: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "vault_operator_delegation".as_bytes(),
+                vault.as_ref(),
+                operator.as_ref(),
+                &[bump],
+            ],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
+    pub fn find_pda(vault: &Pubkey, operator: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "vault_operator_delegation".as_bytes(),
+                vault.as_ref(),
+                operator.as_ref(),
+            ],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
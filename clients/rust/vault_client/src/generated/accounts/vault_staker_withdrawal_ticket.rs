@@ -29,11 +29,55 @@ pub struct VaultStakerWithdrawalTicket {
     pub vrt_amount: u64,
     pub slot_unstaked: u64,
     pub bump: u8,
+    pub auto_claim_fee_bps: u16,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub admin_burn_recovery: Pubkey,
+    pub admin_burn_slot: u64,
+    pub withdrawal_delay_epochs: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 213],
 }
 
 impl VaultStakerWithdrawalTicket {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `VaultStakerWithdrawalTicket::PREFIX`
+    ///   1. vault (`Pubkey`)
+    ///   2. base (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "vault_staker_withdrawal_ticket".as_bytes();
+
+    pub fn create_pda(
+        vault: Pubkey,
+        base: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "vault_staker_withdrawal_ticket".as_bytes(),
+                vault.as_ref(),
+                base.as_ref(),
+                &[bump],
+            ],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
+    pub fn find_pda(vault: &Pubkey, base: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "vault_staker_withdrawal_ticket".as_bytes(),
+                vault.as_ref(),
+                base.as_ref(),
+            ],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
@@ -7,7 +7,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
-use crate::generated::types::DelegationState;
+use crate::generated::types::{DelegationSnapshot, DelegationState};
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -23,11 +23,49 @@ pub struct VaultUpdateStateTracker {
     pub additional_assets_need_unstaking: u64,
     pub delegation_state: DelegationState,
     pub withdrawal_allocation_method: u8,
+    pub delegation_snapshot: DelegationSnapshot,
+    pub deferred_update_count: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 255],
 }
 
 impl VaultUpdateStateTracker {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `VaultUpdateStateTracker::PREFIX`
+    ///   1. vault (`Pubkey`)
+    ///   2. ncn_epoch (`u64`)
+    pub const PREFIX: &'static [u8] = "vault_update_state_tracker".as_bytes();
+
+    pub fn create_pda(
+        vault: Pubkey,
+        ncn_epoch: u64,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "vault_update_state_tracker".as_bytes(),
+                vault.as_ref(),
+                ncn_epoch.to_string().as_ref(),
+                &[bump],
+            ],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
+    pub fn find_pda(vault: &Pubkey, ncn_epoch: &u64) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "vault_update_state_tracker".as_bytes(),
+                vault.as_ref(),
+                ncn_epoch.to_string().as_ref(),
+            ],
+            &crate::JITO_VAULT_ID,
+        )
+    }
+
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
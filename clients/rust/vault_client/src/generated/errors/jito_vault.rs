@@ -144,6 +144,216 @@ pub enum JitoVaultError {
     /// 1044 - VaultMintZero
     #[error("VaultMintZero")]
     VaultMintZero = 0x414,
+    /// 1045 - VaultPauseAdminInvalid
+    #[error("VaultPauseAdminInvalid")]
+    VaultPauseAdminInvalid = 0x415,
+    /// 1046 - VaultIsPaused
+    #[error("VaultIsPaused")]
+    VaultIsPaused = 0x416,
+    /// 1047 - ConfigAdminInvalid
+    #[error("ConfigAdminInvalid")]
+    ConfigAdminInvalid = 0x417,
+    /// 1048 - ConfigProtocolFeeCapExceeded
+    #[error("ConfigProtocolFeeCapExceeded")]
+    ConfigProtocolFeeCapExceeded = 0x418,
+    /// 1049 - VaultMetadataAdminInvalid
+    #[error("VaultMetadataAdminInvalid")]
+    VaultMetadataAdminInvalid = 0x419,
+    /// 1050 - VaultDelegationBelowMinimum
+    #[error("VaultDelegationBelowMinimum")]
+    VaultDelegationBelowMinimum = 0x41A,
+    /// 1051 - VaultDepositNotAllowed
+    #[error("VaultDepositNotAllowed")]
+    VaultDepositNotAllowed = 0x41B,
+    /// 1052 - VaultDepositPerTransactionLimitExceeded
+    #[error("VaultDepositPerTransactionLimitExceeded")]
+    VaultDepositPerTransactionLimitExceeded = 0x41C,
+    /// 1053 - VaultDepositPerWalletLimitExceeded
+    #[error("VaultDepositPerWalletLimitExceeded")]
+    VaultDepositPerWalletLimitExceeded = 0x41D,
+    /// 1054 - VaultMintAuthorityHandoffPending
+    #[error("VaultMintAuthorityHandoffPending")]
+    VaultMintAuthorityHandoffPending = 0x41E,
+    /// 1055 - VaultMintAuthorityHandoffNotPending
+    #[error("VaultMintAuthorityHandoffNotPending")]
+    VaultMintAuthorityHandoffNotPending = 0x41F,
+    /// 1056 - VaultMintAuthorityHandoffChallengeWindowActive
+    #[error("VaultMintAuthorityHandoffChallengeWindowActive")]
+    VaultMintAuthorityHandoffChallengeWindowActive = 0x420,
+    /// 1057 - VaultMintAuthoritySuccessorInvalid
+    #[error("VaultMintAuthoritySuccessorInvalid")]
+    VaultMintAuthoritySuccessorInvalid = 0x421,
+    /// 1058 - ConfigDelegationCooldownEpochsInvalid
+    #[error("ConfigDelegationCooldownEpochsInvalid")]
+    ConfigDelegationCooldownEpochsInvalid = 0x422,
+    /// 1059 - ConfigSlashVetoWindowInvalid
+    #[error("ConfigSlashVetoWindowInvalid")]
+    ConfigSlashVetoWindowInvalid = 0x423,
+    /// 1060 - ConfigSlashVetoAdminInvalid
+    #[error("ConfigSlashVetoAdminInvalid")]
+    ConfigSlashVetoAdminInvalid = 0x424,
+    /// 1061 - VaultSlashProposalRequired
+    #[error("VaultSlashProposalRequired")]
+    VaultSlashProposalRequired = 0x425,
+    /// 1062 - VaultSlashProposalVetoWindowActive
+    #[error("VaultSlashProposalVetoWindowActive")]
+    VaultSlashProposalVetoWindowActive = 0x426,
+    /// 1063 - VaultSlashProposalInvalid
+    #[error("VaultSlashProposalInvalid")]
+    VaultSlashProposalInvalid = 0x427,
+    /// 1064 - VaultOperatorFrozen
+    #[error("VaultOperatorFrozen")]
+    VaultOperatorFrozen = 0x428,
+    /// 1065 - VaultNotEmpty
+    #[error("VaultNotEmpty")]
+    VaultNotEmpty = 0x429,
+    /// 1066 - VaultDelegationAboveMaximum
+    #[error("VaultDelegationAboveMaximum")]
+    VaultDelegationAboveMaximum = 0x42A,
+    /// 1067 - VaultOperatorDelegationPaused
+    #[error("VaultOperatorDelegationPaused")]
+    VaultOperatorDelegationPaused = 0x42B,
+    /// 1068 - VaultDelegationStrategyMovementExceeded
+    #[error("VaultDelegationStrategyMovementExceeded")]
+    VaultDelegationStrategyMovementExceeded = 0x42C,
+    /// 1069 - VaultBurnInDepositSlot
+    #[error("VaultBurnInDepositSlot")]
+    VaultBurnInDepositSlot = 0x42D,
+    /// 1070 - VaultAdminBurnAlreadyEnabled
+    #[error("VaultAdminBurnAlreadyEnabled")]
+    VaultAdminBurnAlreadyEnabled = 0x42E,
+    /// 1071 - VaultAdminBurnEnableAfterDeposit
+    #[error("VaultAdminBurnEnableAfterDeposit")]
+    VaultAdminBurnEnableAfterDeposit = 0x42F,
+    /// 1072 - VaultAdminBurnNotEnabled
+    #[error("VaultAdminBurnNotEnabled")]
+    VaultAdminBurnNotEnabled = 0x430,
+    /// 1073 - VaultBurnAdminInvalid
+    #[error("VaultBurnAdminInvalid")]
+    VaultBurnAdminInvalid = 0x431,
+    /// 1074 - VaultAdminBurnPending
+    #[error("VaultAdminBurnPending")]
+    VaultAdminBurnPending = 0x432,
+    /// 1075 - VaultAdminBurnNotPending
+    #[error("VaultAdminBurnNotPending")]
+    VaultAdminBurnNotPending = 0x433,
+    /// 1076 - VaultAdminBurnTimelockActive
+    #[error("VaultAdminBurnTimelockActive")]
+    VaultAdminBurnTimelockActive = 0x434,
+    /// 1077 - VaultAccountDataEmpty
+    #[error("VaultAccountDataEmpty")]
+    VaultAccountDataEmpty = 0x435,
+    /// 1078 - VaultAccountNotWritable
+    #[error("VaultAccountNotWritable")]
+    VaultAccountNotWritable = 0x436,
+    /// 1079 - VaultAccountDiscriminatorInvalid
+    #[error("VaultAccountDiscriminatorInvalid")]
+    VaultAccountDiscriminatorInvalid = 0x437,
+    /// 1080 - VaultAccountNotCanonicalPda
+    #[error("VaultAccountNotCanonicalPda")]
+    VaultAccountNotCanonicalPda = 0x438,
+    /// 1081 - VaultVrtMintInvalid
+    #[error("VaultVrtMintInvalid")]
+    VaultVrtMintInvalid = 0x439,
+    /// 1082 - VaultSupportedMintInvalid
+    #[error("VaultSupportedMintInvalid")]
+    VaultSupportedMintInvalid = 0x43A,
+    /// 1083 - VaultMetadataAccountInvalid
+    #[error("VaultMetadataAccountInvalid")]
+    VaultMetadataAccountInvalid = 0x43B,
+    /// 1084 - ConfigPaused
+    #[error("ConfigPaused")]
+    ConfigPaused = 0x43C,
+    /// 1085 - VaultTokenAccountClosed
+    #[error("VaultTokenAccountClosed")]
+    VaultTokenAccountClosed = 0x43D,
+    /// 1086 - VaultBalanceDiverged
+    #[error("VaultBalanceDiverged")]
+    VaultBalanceDiverged = 0x43E,
+    /// 1087 - VaultBalanceNotDiverged
+    #[error("VaultBalanceNotDiverged")]
+    VaultBalanceNotDiverged = 0x43F,
+    /// 1088 - VaultReconcileAdjustmentExceeded
+    #[error("VaultReconcileAdjustmentExceeded")]
+    VaultReconcileAdjustmentExceeded = 0x440,
+    /// 1089 - VaultOperatorDelegationNotCranked
+    #[error("VaultOperatorDelegationNotCranked")]
+    VaultOperatorDelegationNotCranked = 0x441,
+    /// 1090 - VaultRewardMintInvalid
+    #[error("VaultRewardMintInvalid")]
+    VaultRewardMintInvalid = 0x442,
+    /// 1091 - VaultRewardProofInvalid
+    #[error("VaultRewardProofInvalid")]
+    VaultRewardProofInvalid = 0x443,
+    /// 1092 - VaultRewardsExceeded
+    #[error("VaultRewardsExceeded")]
+    VaultRewardsExceeded = 0x444,
+    /// 1093 - VaultIsShutDown
+    #[error("VaultIsShutDown")]
+    VaultIsShutDown = 0x445,
+    /// 1094 - VaultOperatorDelegationsIncomplete
+    #[error("VaultOperatorDelegationsIncomplete")]
+    VaultOperatorDelegationsIncomplete = 0x446,
+    /// 1095 - VaultSlashDestinationInvalid
+    #[error("VaultSlashDestinationInvalid")]
+    VaultSlashDestinationInvalid = 0x447,
+    /// 1096 - VaultHookProgramInvalid
+    #[error("VaultHookProgramInvalid")]
+    VaultHookProgramInvalid = 0x448,
+    /// 1097 - VaultBurnDelegateInvalid
+    #[error("VaultBurnDelegateInvalid")]
+    VaultBurnDelegateInvalid = 0x449,
+    /// 1098 - ConfigCrankRewardInvalid
+    #[error("ConfigCrankRewardInvalid")]
+    ConfigCrankRewardInvalid = 0x44A,
+    /// 1099 - VaultMaxTotalSlashableBpsInvalid
+    #[error("VaultMaxTotalSlashableBpsInvalid")]
+    VaultMaxTotalSlashableBpsInvalid = 0x44B,
+    /// 1100 - VaultMaxTotalSlashedPerEpochExceeded
+    #[error("VaultMaxTotalSlashedPerEpochExceeded")]
+    VaultMaxTotalSlashedPerEpochExceeded = 0x44C,
+    /// 1101 - VaultMigrationInvalid
+    #[error("VaultMigrationInvalid")]
+    VaultMigrationInvalid = 0x44D,
+    /// 1102 - VaultNcnSlasherTicketNotInactive
+    #[error("VaultNcnSlasherTicketNotInactive")]
+    VaultNcnSlasherTicketNotInactive = 0x44E,
+    /// 1103 - VaultWithdrawalDelayInvalid
+    #[error("VaultWithdrawalDelayInvalid")]
+    VaultWithdrawalDelayInvalid = 0x44F,
+    /// 1104 - VaultWithdrawalDelayActive
+    #[error("VaultWithdrawalDelayActive")]
+    VaultWithdrawalDelayActive = 0x450,
+    /// 1105 - VaultAccountVersionInvalid
+    #[error("VaultAccountVersionInvalid")]
+    VaultAccountVersionInvalid = 0x451,
+    /// 1106 - OperatorStakeWeightVaultIndexInvalid
+    #[error("OperatorStakeWeightVaultIndexInvalid")]
+    OperatorStakeWeightVaultIndexInvalid = 0x452,
+    /// 1107 - OperatorStakeWeightVaultAlreadyCounted
+    #[error("OperatorStakeWeightVaultAlreadyCounted")]
+    OperatorStakeWeightVaultAlreadyCounted = 0x453,
+    /// 1108 - OperatorStakeWeightVaultNcnInactive
+    #[error("OperatorStakeWeightVaultNcnInactive")]
+    OperatorStakeWeightVaultNcnInactive = 0x454,
+    /// 1109 - VaultDelegationNotApproved
+    #[error("VaultDelegationNotApproved")]
+    VaultDelegationNotApproved = 0x455,
+    /// 1110 - ConfigVaultEpochLengthBoundsInvalid
+    #[error("ConfigVaultEpochLengthBoundsInvalid")]
+    ConfigVaultEpochLengthBoundsInvalid = 0x456,
+    /// 1111 - VaultEpochLengthInvalid
+    #[error("VaultEpochLengthInvalid")]
+    VaultEpochLengthInvalid = 0x457,
+    /// 1112 - VaultEpochLengthCooldownInProgress
+    #[error("VaultEpochLengthCooldownInProgress")]
+    VaultEpochLengthCooldownInProgress = 0x458,
+    /// 1113 - VaultOperatorBanned
+    #[error("VaultOperatorBanned")]
+    VaultOperatorBanned = 0x459,
+    /// 1114 - VaultOperatorNotBanned
+    #[error("VaultOperatorNotBanned")]
+    VaultOperatorNotBanned = 0x45A,
 }
 
 impl solana_program::program_error::PrintProgramError for JitoVaultError {
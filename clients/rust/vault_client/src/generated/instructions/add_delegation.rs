@@ -17,6 +17,8 @@ pub struct AddDelegation {
     pub vault_operator_delegation: solana_program::pubkey::Pubkey,
 
     pub admin: solana_program::pubkey::Pubkey,
+    /// Required when the operator requires approval of delegations
+    pub operator_vault_ticket: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl AddDelegation {
@@ -32,7 +34,7 @@ impl AddDelegation {
         args: AddDelegationInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -51,6 +53,17 @@ impl AddDelegation {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.admin, true,
         ));
+        if let Some(operator_vault_ticket) = self.operator_vault_ticket {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                operator_vault_ticket,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = AddDelegationInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -96,6 +109,7 @@ pub struct AddDelegationInstructionArgs {
 ///   2. `[]` operator
 ///   3. `[writable]` vault_operator_delegation
 ///   4. `[signer]` admin
+///   5. `[optional]` operator_vault_ticket
 #[derive(Clone, Debug, Default)]
 pub struct AddDelegationBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -103,6 +117,7 @@ pub struct AddDelegationBuilder {
     operator: Option<solana_program::pubkey::Pubkey>,
     vault_operator_delegation: Option<solana_program::pubkey::Pubkey>,
     admin: Option<solana_program::pubkey::Pubkey>,
+    operator_vault_ticket: Option<solana_program::pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -139,6 +154,16 @@ impl AddDelegationBuilder {
         self.admin = Some(admin);
         self
     }
+    /// `[optional account]`
+    /// Required when the operator requires approval of delegations
+    #[inline(always)]
+    pub fn operator_vault_ticket(
+        &mut self,
+        operator_vault_ticket: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.operator_vault_ticket = operator_vault_ticket;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -172,6 +197,7 @@ impl AddDelegationBuilder {
                 .vault_operator_delegation
                 .expect("vault_operator_delegation is not set"),
             admin: self.admin.expect("admin is not set"),
+            operator_vault_ticket: self.operator_vault_ticket,
         };
         let args = AddDelegationInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -192,6 +218,8 @@ pub struct AddDelegationCpiAccounts<'a, 'b> {
     pub vault_operator_delegation: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required when the operator requires approval of delegations
+    pub operator_vault_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `add_delegation` CPI instruction.
@@ -208,6 +236,8 @@ pub struct AddDelegationCpi<'a, 'b> {
     pub vault_operator_delegation: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required when the operator requires approval of delegations
+    pub operator_vault_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: AddDelegationInstructionArgs,
 }
@@ -225,6 +255,7 @@ impl<'a, 'b> AddDelegationCpi<'a, 'b> {
             operator: accounts.operator,
             vault_operator_delegation: accounts.vault_operator_delegation,
             admin: accounts.admin,
+            operator_vault_ticket: accounts.operator_vault_ticket,
             __args: args,
         }
     }
@@ -261,7 +292,7 @@ impl<'a, 'b> AddDelegationCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -282,6 +313,17 @@ impl<'a, 'b> AddDelegationCpi<'a, 'b> {
             *self.admin.key,
             true,
         ));
+        if let Some(operator_vault_ticket) = self.operator_vault_ticket {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *operator_vault_ticket.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -298,13 +340,16 @@ impl<'a, 'b> AddDelegationCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.operator.clone());
         account_infos.push(self.vault_operator_delegation.clone());
         account_infos.push(self.admin.clone());
+        if let Some(operator_vault_ticket) = self.operator_vault_ticket {
+            account_infos.push(operator_vault_ticket.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -326,6 +371,7 @@ impl<'a, 'b> AddDelegationCpi<'a, 'b> {
 ///   2. `[]` operator
 ///   3. `[writable]` vault_operator_delegation
 ///   4. `[signer]` admin
+///   5. `[optional]` operator_vault_ticket
 #[derive(Clone, Debug)]
 pub struct AddDelegationCpiBuilder<'a, 'b> {
     instruction: Box<AddDelegationCpiBuilderInstruction<'a, 'b>>,
@@ -340,6 +386,7 @@ impl<'a, 'b> AddDelegationCpiBuilder<'a, 'b> {
             operator: None,
             vault_operator_delegation: None,
             admin: None,
+            operator_vault_ticket: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -379,6 +426,16 @@ impl<'a, 'b> AddDelegationCpiBuilder<'a, 'b> {
         self.instruction.admin = Some(admin);
         self
     }
+    /// `[optional account]`
+    /// Required when the operator requires approval of delegations
+    #[inline(always)]
+    pub fn operator_vault_ticket(
+        &mut self,
+        operator_vault_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operator_vault_ticket = operator_vault_ticket;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -443,6 +500,8 @@ impl<'a, 'b> AddDelegationCpiBuilder<'a, 'b> {
                 .expect("vault_operator_delegation is not set"),
 
             admin: self.instruction.admin.expect("admin is not set"),
+
+            operator_vault_ticket: self.instruction.operator_vault_ticket,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -460,6 +519,7 @@ struct AddDelegationCpiBuilderInstruction<'a, 'b> {
     operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_operator_delegation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_vault_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -29,6 +29,8 @@ pub struct Burn {
     pub system_program: solana_program::pubkey::Pubkey,
     /// Signer for burning
     pub burn_signer: Option<solana_program::pubkey::Pubkey>,
+    /// The staker's VaultDepositor, required when the vault has flash deposit protection
+    pub vault_depositor: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl Burn {
@@ -44,7 +46,7 @@ impl Burn {
         args: BurnInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -95,6 +97,17 @@ impl Burn {
                 false,
             ));
         }
+        if let Some(vault_depositor) = self.vault_depositor {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                vault_depositor,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = BurnInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -147,6 +160,7 @@ pub struct BurnInstructionArgs {
 ///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   10. `[signer, optional]` burn_signer
+///   11. `[optional]` vault_depositor
 #[derive(Clone, Debug, Default)]
 pub struct BurnBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -160,6 +174,7 @@ pub struct BurnBuilder {
     token_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     burn_signer: Option<solana_program::pubkey::Pubkey>,
+    vault_depositor: Option<solana_program::pubkey::Pubkey>,
     amount_in: Option<u64>,
     min_amount_out: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -243,6 +258,16 @@ impl BurnBuilder {
         self.burn_signer = burn_signer;
         self
     }
+    /// `[optional account]`
+    /// The staker's VaultDepositor, required when the vault has flash deposit protection
+    #[inline(always)]
+    pub fn vault_depositor(
+        &mut self,
+        vault_depositor: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_depositor = vault_depositor;
+        self
+    }
     #[inline(always)]
     pub fn amount_in(&mut self, amount_in: u64) -> &mut Self {
         self.amount_in = Some(amount_in);
@@ -297,6 +322,7 @@ impl BurnBuilder {
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
             burn_signer: self.burn_signer,
+            vault_depositor: self.vault_depositor,
         };
         let args = BurnInstructionArgs {
             amount_in: self.amount_in.clone().expect("amount_in is not set"),
@@ -333,6 +359,8 @@ pub struct BurnCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Signer for burning
     pub burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The staker's VaultDepositor, required when the vault has flash deposit protection
+    pub vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `burn` CPI instruction.
@@ -361,6 +389,8 @@ pub struct BurnCpi<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Signer for burning
     pub burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The staker's VaultDepositor, required when the vault has flash deposit protection
+    pub vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: BurnInstructionArgs,
 }
@@ -384,6 +414,7 @@ impl<'a, 'b> BurnCpi<'a, 'b> {
             token_program: accounts.token_program,
             system_program: accounts.system_program,
             burn_signer: accounts.burn_signer,
+            vault_depositor: accounts.vault_depositor,
             __args: args,
         }
     }
@@ -420,7 +451,7 @@ impl<'a, 'b> BurnCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -472,6 +503,17 @@ impl<'a, 'b> BurnCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(vault_depositor) = self.vault_depositor {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *vault_depositor.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -488,7 +530,7 @@ impl<'a, 'b> BurnCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
@@ -503,6 +545,9 @@ impl<'a, 'b> BurnCpi<'a, 'b> {
         if let Some(burn_signer) = self.burn_signer {
             account_infos.push(burn_signer.clone());
         }
+        if let Some(vault_depositor) = self.vault_depositor {
+            account_infos.push(vault_depositor.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -530,6 +575,7 @@ impl<'a, 'b> BurnCpi<'a, 'b> {
 ///   8. `[]` token_program
 ///   9. `[]` system_program
 ///   10. `[signer, optional]` burn_signer
+///   11. `[optional]` vault_depositor
 #[derive(Clone, Debug)]
 pub struct BurnCpiBuilder<'a, 'b> {
     instruction: Box<BurnCpiBuilderInstruction<'a, 'b>>,
@@ -550,6 +596,7 @@ impl<'a, 'b> BurnCpiBuilder<'a, 'b> {
             token_program: None,
             system_program: None,
             burn_signer: None,
+            vault_depositor: None,
             amount_in: None,
             min_amount_out: None,
             __remaining_accounts: Vec::new(),
@@ -643,6 +690,16 @@ impl<'a, 'b> BurnCpiBuilder<'a, 'b> {
         self.instruction.burn_signer = burn_signer;
         self
     }
+    /// `[optional account]`
+    /// The staker's VaultDepositor, required when the vault has flash deposit protection
+    #[inline(always)]
+    pub fn vault_depositor(
+        &mut self,
+        vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_depositor = vault_depositor;
+        self
+    }
     #[inline(always)]
     pub fn amount_in(&mut self, amount_in: u64) -> &mut Self {
         self.instruction.amount_in = Some(amount_in);
@@ -748,6 +805,8 @@ impl<'a, 'b> BurnCpiBuilder<'a, 'b> {
                 .expect("system_program is not set"),
 
             burn_signer: self.instruction.burn_signer,
+
+            vault_depositor: self.instruction.vault_depositor,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -771,6 +830,7 @@ struct BurnCpiBuilderInstruction<'a, 'b> {
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount_in: Option<u64>,
     min_amount_out: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -31,6 +31,8 @@ pub struct BurnWithdrawTicket {
     pub system_program: solana_program::pubkey::Pubkey,
     /// Signer for burning
     pub burn_signer: Option<solana_program::pubkey::Pubkey>,
+    /// Receives the auto-claim fee when the ticket opted into auto-claiming
+    pub claimer_token_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl BurnWithdrawTicket {
@@ -46,7 +48,7 @@ impl BurnWithdrawTicket {
         args: BurnWithdrawTicketInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -101,6 +103,17 @@ impl BurnWithdrawTicket {
                 false,
             ));
         }
+        if let Some(claimer_token_account) = self.claimer_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                claimer_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = BurnWithdrawTicketInstructionData::new()
             .try_to_vec()
@@ -155,6 +168,7 @@ pub struct BurnWithdrawTicketInstructionArgs {
 ///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   11. `[signer, optional]` burn_signer
+///   12. `[writable, optional]` claimer_token_account
 #[derive(Clone, Debug, Default)]
 pub struct BurnWithdrawTicketBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -169,6 +183,7 @@ pub struct BurnWithdrawTicketBuilder {
     token_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     burn_signer: Option<solana_program::pubkey::Pubkey>,
+    claimer_token_account: Option<solana_program::pubkey::Pubkey>,
     min_amount_out: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -260,6 +275,16 @@ impl BurnWithdrawTicketBuilder {
         self.burn_signer = burn_signer;
         self
     }
+    /// `[optional account]`
+    /// Receives the auto-claim fee when the ticket opted into auto-claiming
+    #[inline(always)]
+    pub fn claimer_token_account(
+        &mut self,
+        claimer_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.claimer_token_account = claimer_token_account;
+        self
+    }
     #[inline(always)]
     pub fn min_amount_out(&mut self, min_amount_out: u64) -> &mut Self {
         self.min_amount_out = Some(min_amount_out);
@@ -312,6 +337,7 @@ impl BurnWithdrawTicketBuilder {
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
             burn_signer: self.burn_signer,
+            claimer_token_account: self.claimer_token_account,
         };
         let args = BurnWithdrawTicketInstructionArgs {
             min_amount_out: self
@@ -350,6 +376,8 @@ pub struct BurnWithdrawTicketCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Signer for burning
     pub burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Receives the auto-claim fee when the ticket opted into auto-claiming
+    pub claimer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `burn_withdraw_ticket` CPI instruction.
@@ -381,6 +409,8 @@ pub struct BurnWithdrawTicketCpi<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Signer for burning
     pub burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Receives the auto-claim fee when the ticket opted into auto-claiming
+    pub claimer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: BurnWithdrawTicketInstructionArgs,
 }
@@ -406,6 +436,7 @@ impl<'a, 'b> BurnWithdrawTicketCpi<'a, 'b> {
             token_program: accounts.token_program,
             system_program: accounts.system_program,
             burn_signer: accounts.burn_signer,
+            claimer_token_account: accounts.claimer_token_account,
             __args: args,
         }
     }
@@ -442,7 +473,7 @@ impl<'a, 'b> BurnWithdrawTicketCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -498,6 +529,17 @@ impl<'a, 'b> BurnWithdrawTicketCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(claimer_token_account) = self.claimer_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *claimer_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -516,7 +558,7 @@ impl<'a, 'b> BurnWithdrawTicketCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
@@ -532,6 +574,9 @@ impl<'a, 'b> BurnWithdrawTicketCpi<'a, 'b> {
         if let Some(burn_signer) = self.burn_signer {
             account_infos.push(burn_signer.clone());
         }
+        if let Some(claimer_token_account) = self.claimer_token_account {
+            account_infos.push(claimer_token_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -560,6 +605,7 @@ impl<'a, 'b> BurnWithdrawTicketCpi<'a, 'b> {
 ///   9. `[]` token_program
 ///   10. `[]` system_program
 ///   11. `[signer, optional]` burn_signer
+///   12. `[writable, optional]` claimer_token_account
 #[derive(Clone, Debug)]
 pub struct BurnWithdrawTicketCpiBuilder<'a, 'b> {
     instruction: Box<BurnWithdrawTicketCpiBuilderInstruction<'a, 'b>>,
@@ -581,6 +627,7 @@ impl<'a, 'b> BurnWithdrawTicketCpiBuilder<'a, 'b> {
             token_program: None,
             system_program: None,
             burn_signer: None,
+            claimer_token_account: None,
             min_amount_out: None,
             __remaining_accounts: Vec::new(),
         });
@@ -685,6 +732,16 @@ impl<'a, 'b> BurnWithdrawTicketCpiBuilder<'a, 'b> {
         self.instruction.burn_signer = burn_signer;
         self
     }
+    /// `[optional account]`
+    /// Receives the auto-claim fee when the ticket opted into auto-claiming
+    #[inline(always)]
+    pub fn claimer_token_account(
+        &mut self,
+        claimer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.claimer_token_account = claimer_token_account;
+        self
+    }
     #[inline(always)]
     pub fn min_amount_out(&mut self, min_amount_out: u64) -> &mut Self {
         self.instruction.min_amount_out = Some(min_amount_out);
@@ -785,6 +842,8 @@ impl<'a, 'b> BurnWithdrawTicketCpiBuilder<'a, 'b> {
                 .expect("system_program is not set"),
 
             burn_signer: self.instruction.burn_signer,
+
+            claimer_token_account: self.instruction.claimer_token_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -810,6 +869,7 @@ struct BurnWithdrawTicketCpiBuilderInstruction<'a, 'b> {
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    claimer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     min_amount_out: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
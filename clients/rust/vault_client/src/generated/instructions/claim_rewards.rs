@@ -0,0 +1,669 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct ClaimRewards {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub vault: solana_program::pubkey::Pubkey,
+
+    pub reward_distribution: solana_program::pubkey::Pubkey,
+
+    pub reward_claim: solana_program::pubkey::Pubkey,
+
+    pub vault_rewards_token_account: solana_program::pubkey::Pubkey,
+
+    pub staker_token_account: solana_program::pubkey::Pubkey,
+
+    pub staker: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl ClaimRewards {
+    pub fn instruction(
+        &self,
+        args: ClaimRewardsInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: ClaimRewardsInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.reward_distribution,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.reward_claim,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_rewards_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.staker_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.staker,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = ClaimRewardsInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ClaimRewardsInstructionData {
+    discriminator: u8,
+}
+
+impl ClaimRewardsInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 65 }
+    }
+}
+
+impl Default for ClaimRewardsInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClaimRewardsInstructionArgs {
+    pub amount: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Instruction builder for `ClaimRewards`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` vault
+///   2. `[writable]` reward_distribution
+///   3. `[writable]` reward_claim
+///   4. `[writable]` vault_rewards_token_account
+///   5. `[writable]` staker_token_account
+///   6. `[writable, signer]` staker
+///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Clone, Debug, Default)]
+pub struct ClaimRewardsBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    reward_distribution: Option<solana_program::pubkey::Pubkey>,
+    reward_claim: Option<solana_program::pubkey::Pubkey>,
+    vault_rewards_token_account: Option<solana_program::pubkey::Pubkey>,
+    staker_token_account: Option<solana_program::pubkey::Pubkey>,
+    staker: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    amount: Option<u64>,
+    proof: Option<Vec<[u8; 32]>>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl ClaimRewardsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn reward_distribution(
+        &mut self,
+        reward_distribution: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reward_distribution = Some(reward_distribution);
+        self
+    }
+    #[inline(always)]
+    pub fn reward_claim(&mut self, reward_claim: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_claim = Some(reward_claim);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_rewards_token_account(
+        &mut self,
+        vault_rewards_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_rewards_token_account = Some(vault_rewards_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_token_account(
+        &mut self,
+        staker_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.staker_token_account = Some(staker_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker(&mut self, staker: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.staker = Some(staker);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    #[inline(always)]
+    pub fn proof(&mut self, proof: Vec<[u8; 32]>) -> &mut Self {
+        self.proof = Some(proof);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = ClaimRewards {
+            config: self.config.expect("config is not set"),
+            vault: self.vault.expect("vault is not set"),
+            reward_distribution: self
+                .reward_distribution
+                .expect("reward_distribution is not set"),
+            reward_claim: self.reward_claim.expect("reward_claim is not set"),
+            vault_rewards_token_account: self
+                .vault_rewards_token_account
+                .expect("vault_rewards_token_account is not set"),
+            staker_token_account: self
+                .staker_token_account
+                .expect("staker_token_account is not set"),
+            staker: self.staker.expect("staker is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = ClaimRewardsInstructionArgs {
+            amount: self.amount.clone().expect("amount is not set"),
+            proof: self.proof.clone().expect("proof is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `claim_rewards` CPI accounts.
+pub struct ClaimRewardsCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub reward_distribution: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub reward_claim: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_rewards_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `claim_rewards` CPI instruction.
+pub struct ClaimRewardsCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub reward_distribution: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub reward_claim: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_rewards_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: ClaimRewardsInstructionArgs,
+}
+
+impl<'a, 'b> ClaimRewardsCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: ClaimRewardsCpiAccounts<'a, 'b>,
+        args: ClaimRewardsInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            vault: accounts.vault,
+            reward_distribution: accounts.reward_distribution,
+            reward_claim: accounts.reward_claim,
+            vault_rewards_token_account: accounts.vault_rewards_token_account,
+            staker_token_account: accounts.staker_token_account,
+            staker: accounts.staker,
+            token_program: accounts.token_program,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.reward_distribution.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.reward_claim.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_rewards_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.staker_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.staker.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = ClaimRewardsInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.reward_distribution.clone());
+        account_infos.push(self.reward_claim.clone());
+        account_infos.push(self.vault_rewards_token_account.clone());
+        account_infos.push(self.staker_token_account.clone());
+        account_infos.push(self.staker.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ClaimRewards` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` vault
+///   2. `[writable]` reward_distribution
+///   3. `[writable]` reward_claim
+///   4. `[writable]` vault_rewards_token_account
+///   5. `[writable]` staker_token_account
+///   6. `[writable, signer]` staker
+///   7. `[]` token_program
+///   8. `[]` system_program
+#[derive(Clone, Debug)]
+pub struct ClaimRewardsCpiBuilder<'a, 'b> {
+    instruction: Box<ClaimRewardsCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ClaimRewardsCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ClaimRewardsCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            vault: None,
+            reward_distribution: None,
+            reward_claim: None,
+            vault_rewards_token_account: None,
+            staker_token_account: None,
+            staker: None,
+            token_program: None,
+            system_program: None,
+            amount: None,
+            proof: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn reward_distribution(
+        &mut self,
+        reward_distribution: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_distribution = Some(reward_distribution);
+        self
+    }
+    #[inline(always)]
+    pub fn reward_claim(
+        &mut self,
+        reward_claim: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_claim = Some(reward_claim);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_rewards_token_account(
+        &mut self,
+        vault_rewards_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_rewards_token_account = Some(vault_rewards_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_token_account(
+        &mut self,
+        staker_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.staker_token_account = Some(staker_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker(
+        &mut self,
+        staker: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.staker = Some(staker);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
+    #[inline(always)]
+    pub fn proof(&mut self, proof: Vec<[u8; 32]>) -> &mut Self {
+        self.instruction.proof = Some(proof);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = ClaimRewardsInstructionArgs {
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+            proof: self.instruction.proof.clone().expect("proof is not set"),
+        };
+        let instruction = ClaimRewardsCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            reward_distribution: self
+                .instruction
+                .reward_distribution
+                .expect("reward_distribution is not set"),
+
+            reward_claim: self
+                .instruction
+                .reward_claim
+                .expect("reward_claim is not set"),
+
+            vault_rewards_token_account: self
+                .instruction
+                .vault_rewards_token_account
+                .expect("vault_rewards_token_account is not set"),
+
+            staker_token_account: self
+                .instruction
+                .staker_token_account
+                .expect("staker_token_account is not set"),
+
+            staker: self.instruction.staker.expect("staker is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ClaimRewardsCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_distribution: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_claim: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_rewards_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amount: Option<u64>,
+    proof: Option<Vec<[u8; 32]>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -15,6 +15,12 @@ pub struct CloseVaultUpdateStateTracker {
     pub vault_update_state_tracker: solana_program::pubkey::Pubkey,
 
     pub payer: solana_program::pubkey::Pubkey,
+    /// Required when the config has a crank reward in basis points
+    pub vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    /// The payer's associated token account for the supported mint, required when the config has a crank reward in basis points
+    pub payer_token_account: Option<solana_program::pubkey::Pubkey>,
+    /// Required when the config has a crank reward in basis points
+    pub token_program: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl CloseVaultUpdateStateTracker {
@@ -30,7 +36,7 @@ impl CloseVaultUpdateStateTracker {
         args: CloseVaultUpdateStateTrackerInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -45,6 +51,39 @@ impl CloseVaultUpdateStateTracker {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
+        if let Some(vault_token_account) = self.vault_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                vault_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(payer_token_account) = self.payer_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                payer_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CloseVaultUpdateStateTrackerInstructionData::new()
             .try_to_vec()
@@ -91,12 +130,18 @@ pub struct CloseVaultUpdateStateTrackerInstructionArgs {
 ///   1. `[writable]` vault
 ///   2. `[writable]` vault_update_state_tracker
 ///   3. `[writable, signer]` payer
+///   4. `[writable, optional]` vault_token_account
+///   5. `[writable, optional]` payer_token_account
+///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 #[derive(Clone, Debug, Default)]
 pub struct CloseVaultUpdateStateTrackerBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
     vault: Option<solana_program::pubkey::Pubkey>,
     vault_update_state_tracker: Option<solana_program::pubkey::Pubkey>,
     payer: Option<solana_program::pubkey::Pubkey>,
+    vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    payer_token_account: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
     ncn_epoch: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -128,6 +173,36 @@ impl CloseVaultUpdateStateTrackerBuilder {
         self.payer = Some(payer);
         self
     }
+    /// `[optional account]`
+    /// Required when the config has a crank reward in basis points
+    #[inline(always)]
+    pub fn vault_token_account(
+        &mut self,
+        vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_token_account = vault_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The payer's associated token account for the supported mint, required when the config has a crank reward in basis points
+    #[inline(always)]
+    pub fn payer_token_account(
+        &mut self,
+        payer_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.payer_token_account = payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Required when the config has a crank reward in basis points
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
     #[inline(always)]
     pub fn ncn_epoch(&mut self, ncn_epoch: u64) -> &mut Self {
         self.ncn_epoch = Some(ncn_epoch);
@@ -160,6 +235,9 @@ impl CloseVaultUpdateStateTrackerBuilder {
                 .vault_update_state_tracker
                 .expect("vault_update_state_tracker is not set"),
             payer: self.payer.expect("payer is not set"),
+            vault_token_account: self.vault_token_account,
+            payer_token_account: self.payer_token_account,
+            token_program: self.token_program,
         };
         let args = CloseVaultUpdateStateTrackerInstructionArgs {
             ncn_epoch: self.ncn_epoch.clone().expect("ncn_epoch is not set"),
@@ -178,6 +256,12 @@ pub struct CloseVaultUpdateStateTrackerCpiAccounts<'a, 'b> {
     pub vault_update_state_tracker: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required when the config has a crank reward in basis points
+    pub vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The payer's associated token account for the supported mint, required when the config has a crank reward in basis points
+    pub payer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required when the config has a crank reward in basis points
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `close_vault_update_state_tracker` CPI instruction.
@@ -192,6 +276,12 @@ pub struct CloseVaultUpdateStateTrackerCpi<'a, 'b> {
     pub vault_update_state_tracker: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required when the config has a crank reward in basis points
+    pub vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The payer's associated token account for the supported mint, required when the config has a crank reward in basis points
+    pub payer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required when the config has a crank reward in basis points
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CloseVaultUpdateStateTrackerInstructionArgs,
 }
@@ -208,6 +298,9 @@ impl<'a, 'b> CloseVaultUpdateStateTrackerCpi<'a, 'b> {
             vault: accounts.vault,
             vault_update_state_tracker: accounts.vault_update_state_tracker,
             payer: accounts.payer,
+            vault_token_account: accounts.vault_token_account,
+            payer_token_account: accounts.payer_token_account,
+            token_program: accounts.token_program,
             __args: args,
         }
     }
@@ -244,7 +337,7 @@ impl<'a, 'b> CloseVaultUpdateStateTrackerCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -261,6 +354,39 @@ impl<'a, 'b> CloseVaultUpdateStateTrackerCpi<'a, 'b> {
             *self.payer.key,
             true,
         ));
+        if let Some(vault_token_account) = self.vault_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *vault_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(payer_token_account) = self.payer_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *payer_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -279,12 +405,21 @@ impl<'a, 'b> CloseVaultUpdateStateTrackerCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.vault_update_state_tracker.clone());
         account_infos.push(self.payer.clone());
+        if let Some(vault_token_account) = self.vault_token_account {
+            account_infos.push(vault_token_account.clone());
+        }
+        if let Some(payer_token_account) = self.payer_token_account {
+            account_infos.push(payer_token_account.clone());
+        }
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -305,6 +440,9 @@ impl<'a, 'b> CloseVaultUpdateStateTrackerCpi<'a, 'b> {
 ///   1. `[writable]` vault
 ///   2. `[writable]` vault_update_state_tracker
 ///   3. `[writable, signer]` payer
+///   4. `[writable, optional]` vault_token_account
+///   5. `[writable, optional]` payer_token_account
+///   6. `[optional]` token_program
 #[derive(Clone, Debug)]
 pub struct CloseVaultUpdateStateTrackerCpiBuilder<'a, 'b> {
     instruction: Box<CloseVaultUpdateStateTrackerCpiBuilderInstruction<'a, 'b>>,
@@ -318,6 +456,9 @@ impl<'a, 'b> CloseVaultUpdateStateTrackerCpiBuilder<'a, 'b> {
             vault: None,
             vault_update_state_tracker: None,
             payer: None,
+            vault_token_account: None,
+            payer_token_account: None,
+            token_program: None,
             ncn_epoch: None,
             __remaining_accounts: Vec::new(),
         });
@@ -349,6 +490,36 @@ impl<'a, 'b> CloseVaultUpdateStateTrackerCpiBuilder<'a, 'b> {
         self.instruction.payer = Some(payer);
         self
     }
+    /// `[optional account]`
+    /// Required when the config has a crank reward in basis points
+    #[inline(always)]
+    pub fn vault_token_account(
+        &mut self,
+        vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_token_account = vault_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The payer's associated token account for the supported mint, required when the config has a crank reward in basis points
+    #[inline(always)]
+    pub fn payer_token_account(
+        &mut self,
+        payer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payer_token_account = payer_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Required when the config has a crank reward in basis points
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
     #[inline(always)]
     pub fn ncn_epoch(&mut self, ncn_epoch: u64) -> &mut Self {
         self.instruction.ncn_epoch = Some(ncn_epoch);
@@ -415,6 +586,12 @@ impl<'a, 'b> CloseVaultUpdateStateTrackerCpiBuilder<'a, 'b> {
                 .expect("vault_update_state_tracker is not set"),
 
             payer: self.instruction.payer.expect("payer is not set"),
+
+            vault_token_account: self.instruction.vault_token_account,
+
+            payer_token_account: self.instruction.payer_token_account,
+
+            token_program: self.instruction.token_program,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -431,6 +608,9 @@ struct CloseVaultUpdateStateTrackerCpiBuilderInstruction<'a, 'b> {
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_update_state_tracker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_epoch: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -0,0 +1,805 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct InitializeRewardDistribution {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub vault: solana_program::pubkey::Pubkey,
+
+    pub reward_distribution: solana_program::pubkey::Pubkey,
+
+    pub mint: solana_program::pubkey::Pubkey,
+
+    pub vault_rewards_token_account: solana_program::pubkey::Pubkey,
+
+    pub admin_token_account: solana_program::pubkey::Pubkey,
+
+    pub admin: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// The operator the rewards were earned through, paid its commission
+    pub operator: Option<solana_program::pubkey::Pubkey>,
+    /// The operator withdrawal fee wallet's token account for the reward mint
+    pub operator_fee_token_account: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl InitializeRewardDistribution {
+    pub fn instruction(
+        &self,
+        args: InitializeRewardDistributionInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: InitializeRewardDistributionInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.reward_distribution,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_rewards_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.admin_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.admin, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(operator) = self.operator {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                operator, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(operator_fee_token_account) = self.operator_fee_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                operator_fee_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = InitializeRewardDistributionInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct InitializeRewardDistributionInstructionData {
+    discriminator: u8,
+}
+
+impl InitializeRewardDistributionInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 64 }
+    }
+}
+
+impl Default for InitializeRewardDistributionInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializeRewardDistributionInstructionArgs {
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+    pub total_rewards: u64,
+}
+
+/// Instruction builder for `InitializeRewardDistribution`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` vault
+///   2. `[writable]` reward_distribution
+///   3. `[]` mint
+///   4. `[writable]` vault_rewards_token_account
+///   5. `[writable]` admin_token_account
+///   6. `[writable, signer]` admin
+///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   9. `[optional]` operator
+///   10. `[writable, optional]` operator_fee_token_account
+#[derive(Clone, Debug, Default)]
+pub struct InitializeRewardDistributionBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    reward_distribution: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    vault_rewards_token_account: Option<solana_program::pubkey::Pubkey>,
+    admin_token_account: Option<solana_program::pubkey::Pubkey>,
+    admin: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    operator: Option<solana_program::pubkey::Pubkey>,
+    operator_fee_token_account: Option<solana_program::pubkey::Pubkey>,
+    epoch: Option<u64>,
+    merkle_root: Option<[u8; 32]>,
+    total_rewards: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl InitializeRewardDistributionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn reward_distribution(
+        &mut self,
+        reward_distribution: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reward_distribution = Some(reward_distribution);
+        self
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_rewards_token_account(
+        &mut self,
+        vault_rewards_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_rewards_token_account = Some(vault_rewards_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn admin_token_account(
+        &mut self,
+        admin_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.admin_token_account = Some(admin_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn admin(&mut self, admin: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.admin = Some(admin);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The operator the rewards were earned through, paid its commission
+    #[inline(always)]
+    pub fn operator(&mut self, operator: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.operator = operator;
+        self
+    }
+    /// `[optional account]`
+    /// The operator withdrawal fee wallet's token account for the reward mint
+    #[inline(always)]
+    pub fn operator_fee_token_account(
+        &mut self,
+        operator_fee_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.operator_fee_token_account = operator_fee_token_account;
+        self
+    }
+    #[inline(always)]
+    pub fn epoch(&mut self, epoch: u64) -> &mut Self {
+        self.epoch = Some(epoch);
+        self
+    }
+    #[inline(always)]
+    pub fn merkle_root(&mut self, merkle_root: [u8; 32]) -> &mut Self {
+        self.merkle_root = Some(merkle_root);
+        self
+    }
+    #[inline(always)]
+    pub fn total_rewards(&mut self, total_rewards: u64) -> &mut Self {
+        self.total_rewards = Some(total_rewards);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = InitializeRewardDistribution {
+            config: self.config.expect("config is not set"),
+            vault: self.vault.expect("vault is not set"),
+            reward_distribution: self
+                .reward_distribution
+                .expect("reward_distribution is not set"),
+            mint: self.mint.expect("mint is not set"),
+            vault_rewards_token_account: self
+                .vault_rewards_token_account
+                .expect("vault_rewards_token_account is not set"),
+            admin_token_account: self
+                .admin_token_account
+                .expect("admin_token_account is not set"),
+            admin: self.admin.expect("admin is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            operator: self.operator,
+            operator_fee_token_account: self.operator_fee_token_account,
+        };
+        let args = InitializeRewardDistributionInstructionArgs {
+            epoch: self.epoch.clone().expect("epoch is not set"),
+            merkle_root: self.merkle_root.clone().expect("merkle_root is not set"),
+            total_rewards: self
+                .total_rewards
+                .clone()
+                .expect("total_rewards is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `initialize_reward_distribution` CPI accounts.
+pub struct InitializeRewardDistributionCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub reward_distribution: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_rewards_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The operator the rewards were earned through, paid its commission
+    pub operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The operator withdrawal fee wallet's token account for the reward mint
+    pub operator_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `initialize_reward_distribution` CPI instruction.
+pub struct InitializeRewardDistributionCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub reward_distribution: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_rewards_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The operator the rewards were earned through, paid its commission
+    pub operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The operator withdrawal fee wallet's token account for the reward mint
+    pub operator_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: InitializeRewardDistributionInstructionArgs,
+}
+
+impl<'a, 'b> InitializeRewardDistributionCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: InitializeRewardDistributionCpiAccounts<'a, 'b>,
+        args: InitializeRewardDistributionInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            vault: accounts.vault,
+            reward_distribution: accounts.reward_distribution,
+            mint: accounts.mint,
+            vault_rewards_token_account: accounts.vault_rewards_token_account,
+            admin_token_account: accounts.admin_token_account,
+            admin: accounts.admin,
+            token_program: accounts.token_program,
+            system_program: accounts.system_program,
+            operator: accounts.operator,
+            operator_fee_token_account: accounts.operator_fee_token_account,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.reward_distribution.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_rewards_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.admin_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.admin.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(operator) = self.operator {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *operator.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(operator_fee_token_account) = self.operator_fee_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *operator_fee_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = InitializeRewardDistributionInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.reward_distribution.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.vault_rewards_token_account.clone());
+        account_infos.push(self.admin_token_account.clone());
+        account_infos.push(self.admin.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(operator) = self.operator {
+            account_infos.push(operator.clone());
+        }
+        if let Some(operator_fee_token_account) = self.operator_fee_token_account {
+            account_infos.push(operator_fee_token_account.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `InitializeRewardDistribution` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` vault
+///   2. `[writable]` reward_distribution
+///   3. `[]` mint
+///   4. `[writable]` vault_rewards_token_account
+///   5. `[writable]` admin_token_account
+///   6. `[writable, signer]` admin
+///   7. `[]` token_program
+///   8. `[]` system_program
+///   9. `[optional]` operator
+///   10. `[writable, optional]` operator_fee_token_account
+#[derive(Clone, Debug)]
+pub struct InitializeRewardDistributionCpiBuilder<'a, 'b> {
+    instruction: Box<InitializeRewardDistributionCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> InitializeRewardDistributionCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(InitializeRewardDistributionCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            vault: None,
+            reward_distribution: None,
+            mint: None,
+            vault_rewards_token_account: None,
+            admin_token_account: None,
+            admin: None,
+            token_program: None,
+            system_program: None,
+            operator: None,
+            operator_fee_token_account: None,
+            epoch: None,
+            merkle_root: None,
+            total_rewards: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn reward_distribution(
+        &mut self,
+        reward_distribution: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_distribution = Some(reward_distribution);
+        self
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_rewards_token_account(
+        &mut self,
+        vault_rewards_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_rewards_token_account = Some(vault_rewards_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn admin_token_account(
+        &mut self,
+        admin_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.admin_token_account = Some(admin_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn admin(&mut self, admin: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.admin = Some(admin);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The operator the rewards were earned through, paid its commission
+    #[inline(always)]
+    pub fn operator(
+        &mut self,
+        operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operator = operator;
+        self
+    }
+    /// `[optional account]`
+    /// The operator withdrawal fee wallet's token account for the reward mint
+    #[inline(always)]
+    pub fn operator_fee_token_account(
+        &mut self,
+        operator_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operator_fee_token_account = operator_fee_token_account;
+        self
+    }
+    #[inline(always)]
+    pub fn epoch(&mut self, epoch: u64) -> &mut Self {
+        self.instruction.epoch = Some(epoch);
+        self
+    }
+    #[inline(always)]
+    pub fn merkle_root(&mut self, merkle_root: [u8; 32]) -> &mut Self {
+        self.instruction.merkle_root = Some(merkle_root);
+        self
+    }
+    #[inline(always)]
+    pub fn total_rewards(&mut self, total_rewards: u64) -> &mut Self {
+        self.instruction.total_rewards = Some(total_rewards);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = InitializeRewardDistributionInstructionArgs {
+            epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+            merkle_root: self
+                .instruction
+                .merkle_root
+                .clone()
+                .expect("merkle_root is not set"),
+            total_rewards: self
+                .instruction
+                .total_rewards
+                .clone()
+                .expect("total_rewards is not set"),
+        };
+        let instruction = InitializeRewardDistributionCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            reward_distribution: self
+                .instruction
+                .reward_distribution
+                .expect("reward_distribution is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            vault_rewards_token_account: self
+                .instruction
+                .vault_rewards_token_account
+                .expect("vault_rewards_token_account is not set"),
+
+            admin_token_account: self
+                .instruction
+                .admin_token_account
+                .expect("admin_token_account is not set"),
+
+            admin: self.instruction.admin.expect("admin is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            operator: self.instruction.operator,
+
+            operator_fee_token_account: self.instruction.operator_fee_token_account,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct InitializeRewardDistributionCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_distribution: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_rewards_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    admin_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch: Option<u64>,
+    merkle_root: Option<[u8; 32]>,
+    total_rewards: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,933 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct MigrateToVault {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub source_vault: solana_program::pubkey::Pubkey,
+
+    pub source_vault_token_account: solana_program::pubkey::Pubkey,
+
+    pub source_vrt_mint: solana_program::pubkey::Pubkey,
+
+    pub source_vault_fee_token_account: solana_program::pubkey::Pubkey,
+
+    pub staker: solana_program::pubkey::Pubkey,
+
+    pub staker_token_account: solana_program::pubkey::Pubkey,
+
+    pub staker_source_vrt_token_account: solana_program::pubkey::Pubkey,
+
+    pub destination_vault: solana_program::pubkey::Pubkey,
+
+    pub destination_vault_token_account: solana_program::pubkey::Pubkey,
+
+    pub destination_vrt_mint: solana_program::pubkey::Pubkey,
+
+    pub staker_destination_vrt_token_account: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// Signer for burning and minting
+    pub mint_burn_signer: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl MigrateToVault {
+    pub fn instruction(
+        &self,
+        args: MigrateToVaultInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MigrateToVaultInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.source_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.source_vault_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.source_vrt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.source_vault_fee_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.staker,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.staker_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.staker_source_vrt_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.destination_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.destination_vault_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.destination_vrt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.staker_destination_vrt_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        if let Some(mint_burn_signer) = self.mint_burn_signer {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint_burn_signer,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = MigrateToVaultInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MigrateToVaultInstructionData {
+    discriminator: u8,
+}
+
+impl MigrateToVaultInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 78 }
+    }
+}
+
+impl Default for MigrateToVaultInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MigrateToVaultInstructionArgs {
+    pub amount_in: u64,
+    pub min_amount_out: u64,
+}
+
+/// Instruction builder for `MigrateToVault`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` source_vault
+///   2. `[writable]` source_vault_token_account
+///   3. `[writable]` source_vrt_mint
+///   4. `[writable]` source_vault_fee_token_account
+///   5. `[writable, signer]` staker
+///   6. `[writable]` staker_token_account
+///   7. `[writable]` staker_source_vrt_token_account
+///   8. `[writable]` destination_vault
+///   9. `[writable]` destination_vault_token_account
+///   10. `[writable]` destination_vrt_mint
+///   11. `[writable]` staker_destination_vrt_token_account
+///   12. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   13. `[signer, optional]` mint_burn_signer
+#[derive(Clone, Debug, Default)]
+pub struct MigrateToVaultBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    source_vault: Option<solana_program::pubkey::Pubkey>,
+    source_vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    source_vrt_mint: Option<solana_program::pubkey::Pubkey>,
+    source_vault_fee_token_account: Option<solana_program::pubkey::Pubkey>,
+    staker: Option<solana_program::pubkey::Pubkey>,
+    staker_token_account: Option<solana_program::pubkey::Pubkey>,
+    staker_source_vrt_token_account: Option<solana_program::pubkey::Pubkey>,
+    destination_vault: Option<solana_program::pubkey::Pubkey>,
+    destination_vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    destination_vrt_mint: Option<solana_program::pubkey::Pubkey>,
+    staker_destination_vrt_token_account: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    mint_burn_signer: Option<solana_program::pubkey::Pubkey>,
+    amount_in: Option<u64>,
+    min_amount_out: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MigrateToVaultBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn source_vault(&mut self, source_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.source_vault = Some(source_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn source_vault_token_account(
+        &mut self,
+        source_vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.source_vault_token_account = Some(source_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn source_vrt_mint(
+        &mut self,
+        source_vrt_mint: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.source_vrt_mint = Some(source_vrt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn source_vault_fee_token_account(
+        &mut self,
+        source_vault_fee_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.source_vault_fee_token_account = Some(source_vault_fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker(&mut self, staker: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.staker = Some(staker);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_token_account(
+        &mut self,
+        staker_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.staker_token_account = Some(staker_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_source_vrt_token_account(
+        &mut self,
+        staker_source_vrt_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.staker_source_vrt_token_account = Some(staker_source_vrt_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn destination_vault(
+        &mut self,
+        destination_vault: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.destination_vault = Some(destination_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn destination_vault_token_account(
+        &mut self,
+        destination_vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.destination_vault_token_account = Some(destination_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn destination_vrt_mint(
+        &mut self,
+        destination_vrt_mint: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.destination_vrt_mint = Some(destination_vrt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_destination_vrt_token_account(
+        &mut self,
+        staker_destination_vrt_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.staker_destination_vrt_token_account = Some(staker_destination_vrt_token_account);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account]`
+    /// Signer for burning and minting
+    #[inline(always)]
+    pub fn mint_burn_signer(
+        &mut self,
+        mint_burn_signer: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.mint_burn_signer = mint_burn_signer;
+        self
+    }
+    #[inline(always)]
+    pub fn amount_in(&mut self, amount_in: u64) -> &mut Self {
+        self.amount_in = Some(amount_in);
+        self
+    }
+    #[inline(always)]
+    pub fn min_amount_out(&mut self, min_amount_out: u64) -> &mut Self {
+        self.min_amount_out = Some(min_amount_out);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = MigrateToVault {
+            config: self.config.expect("config is not set"),
+            source_vault: self.source_vault.expect("source_vault is not set"),
+            source_vault_token_account: self
+                .source_vault_token_account
+                .expect("source_vault_token_account is not set"),
+            source_vrt_mint: self.source_vrt_mint.expect("source_vrt_mint is not set"),
+            source_vault_fee_token_account: self
+                .source_vault_fee_token_account
+                .expect("source_vault_fee_token_account is not set"),
+            staker: self.staker.expect("staker is not set"),
+            staker_token_account: self
+                .staker_token_account
+                .expect("staker_token_account is not set"),
+            staker_source_vrt_token_account: self
+                .staker_source_vrt_token_account
+                .expect("staker_source_vrt_token_account is not set"),
+            destination_vault: self
+                .destination_vault
+                .expect("destination_vault is not set"),
+            destination_vault_token_account: self
+                .destination_vault_token_account
+                .expect("destination_vault_token_account is not set"),
+            destination_vrt_mint: self
+                .destination_vrt_mint
+                .expect("destination_vrt_mint is not set"),
+            staker_destination_vrt_token_account: self
+                .staker_destination_vrt_token_account
+                .expect("staker_destination_vrt_token_account is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            mint_burn_signer: self.mint_burn_signer,
+        };
+        let args = MigrateToVaultInstructionArgs {
+            amount_in: self.amount_in.clone().expect("amount_in is not set"),
+            min_amount_out: self
+                .min_amount_out
+                .clone()
+                .expect("min_amount_out is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `migrate_to_vault` CPI accounts.
+pub struct MigrateToVaultCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub source_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub source_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub source_vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub source_vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_source_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub destination_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub destination_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub destination_vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_destination_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Signer for burning and minting
+    pub mint_burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `migrate_to_vault` CPI instruction.
+pub struct MigrateToVaultCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub source_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub source_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub source_vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub source_vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_source_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub destination_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub destination_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub destination_vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_destination_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Signer for burning and minting
+    pub mint_burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: MigrateToVaultInstructionArgs,
+}
+
+impl<'a, 'b> MigrateToVaultCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MigrateToVaultCpiAccounts<'a, 'b>,
+        args: MigrateToVaultInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            source_vault: accounts.source_vault,
+            source_vault_token_account: accounts.source_vault_token_account,
+            source_vrt_mint: accounts.source_vrt_mint,
+            source_vault_fee_token_account: accounts.source_vault_fee_token_account,
+            staker: accounts.staker,
+            staker_token_account: accounts.staker_token_account,
+            staker_source_vrt_token_account: accounts.staker_source_vrt_token_account,
+            destination_vault: accounts.destination_vault,
+            destination_vault_token_account: accounts.destination_vault_token_account,
+            destination_vrt_mint: accounts.destination_vrt_mint,
+            staker_destination_vrt_token_account: accounts.staker_destination_vrt_token_account,
+            token_program: accounts.token_program,
+            mint_burn_signer: accounts.mint_burn_signer,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.source_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.source_vault_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.source_vrt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.source_vault_fee_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.staker.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.staker_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.staker_source_vrt_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.destination_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.destination_vault_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.destination_vrt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.staker_destination_vrt_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        if let Some(mint_burn_signer) = self.mint_burn_signer {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint_burn_signer.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = MigrateToVaultInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(15 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.source_vault.clone());
+        account_infos.push(self.source_vault_token_account.clone());
+        account_infos.push(self.source_vrt_mint.clone());
+        account_infos.push(self.source_vault_fee_token_account.clone());
+        account_infos.push(self.staker.clone());
+        account_infos.push(self.staker_token_account.clone());
+        account_infos.push(self.staker_source_vrt_token_account.clone());
+        account_infos.push(self.destination_vault.clone());
+        account_infos.push(self.destination_vault_token_account.clone());
+        account_infos.push(self.destination_vrt_mint.clone());
+        account_infos.push(self.staker_destination_vrt_token_account.clone());
+        account_infos.push(self.token_program.clone());
+        if let Some(mint_burn_signer) = self.mint_burn_signer {
+            account_infos.push(mint_burn_signer.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MigrateToVault` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` source_vault
+///   2. `[writable]` source_vault_token_account
+///   3. `[writable]` source_vrt_mint
+///   4. `[writable]` source_vault_fee_token_account
+///   5. `[writable, signer]` staker
+///   6. `[writable]` staker_token_account
+///   7. `[writable]` staker_source_vrt_token_account
+///   8. `[writable]` destination_vault
+///   9. `[writable]` destination_vault_token_account
+///   10. `[writable]` destination_vrt_mint
+///   11. `[writable]` staker_destination_vrt_token_account
+///   12. `[]` token_program
+///   13. `[signer, optional]` mint_burn_signer
+#[derive(Clone, Debug)]
+pub struct MigrateToVaultCpiBuilder<'a, 'b> {
+    instruction: Box<MigrateToVaultCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MigrateToVaultCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MigrateToVaultCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            source_vault: None,
+            source_vault_token_account: None,
+            source_vrt_mint: None,
+            source_vault_fee_token_account: None,
+            staker: None,
+            staker_token_account: None,
+            staker_source_vrt_token_account: None,
+            destination_vault: None,
+            destination_vault_token_account: None,
+            destination_vrt_mint: None,
+            staker_destination_vrt_token_account: None,
+            token_program: None,
+            mint_burn_signer: None,
+            amount_in: None,
+            min_amount_out: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn source_vault(
+        &mut self,
+        source_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source_vault = Some(source_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn source_vault_token_account(
+        &mut self,
+        source_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source_vault_token_account = Some(source_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn source_vrt_mint(
+        &mut self,
+        source_vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source_vrt_mint = Some(source_vrt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn source_vault_fee_token_account(
+        &mut self,
+        source_vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source_vault_fee_token_account = Some(source_vault_fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker(
+        &mut self,
+        staker: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.staker = Some(staker);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_token_account(
+        &mut self,
+        staker_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.staker_token_account = Some(staker_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_source_vrt_token_account(
+        &mut self,
+        staker_source_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.staker_source_vrt_token_account = Some(staker_source_vrt_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn destination_vault(
+        &mut self,
+        destination_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination_vault = Some(destination_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn destination_vault_token_account(
+        &mut self,
+        destination_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination_vault_token_account = Some(destination_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn destination_vrt_mint(
+        &mut self,
+        destination_vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination_vrt_mint = Some(destination_vrt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_destination_vrt_token_account(
+        &mut self,
+        staker_destination_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.staker_destination_vrt_token_account =
+            Some(staker_destination_vrt_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account]`
+    /// Signer for burning and minting
+    #[inline(always)]
+    pub fn mint_burn_signer(
+        &mut self,
+        mint_burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint_burn_signer = mint_burn_signer;
+        self
+    }
+    #[inline(always)]
+    pub fn amount_in(&mut self, amount_in: u64) -> &mut Self {
+        self.instruction.amount_in = Some(amount_in);
+        self
+    }
+    #[inline(always)]
+    pub fn min_amount_out(&mut self, min_amount_out: u64) -> &mut Self {
+        self.instruction.min_amount_out = Some(min_amount_out);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = MigrateToVaultInstructionArgs {
+            amount_in: self
+                .instruction
+                .amount_in
+                .clone()
+                .expect("amount_in is not set"),
+            min_amount_out: self
+                .instruction
+                .min_amount_out
+                .clone()
+                .expect("min_amount_out is not set"),
+        };
+        let instruction = MigrateToVaultCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            source_vault: self
+                .instruction
+                .source_vault
+                .expect("source_vault is not set"),
+
+            source_vault_token_account: self
+                .instruction
+                .source_vault_token_account
+                .expect("source_vault_token_account is not set"),
+
+            source_vrt_mint: self
+                .instruction
+                .source_vrt_mint
+                .expect("source_vrt_mint is not set"),
+
+            source_vault_fee_token_account: self
+                .instruction
+                .source_vault_fee_token_account
+                .expect("source_vault_fee_token_account is not set"),
+
+            staker: self.instruction.staker.expect("staker is not set"),
+
+            staker_token_account: self
+                .instruction
+                .staker_token_account
+                .expect("staker_token_account is not set"),
+
+            staker_source_vrt_token_account: self
+                .instruction
+                .staker_source_vrt_token_account
+                .expect("staker_source_vrt_token_account is not set"),
+
+            destination_vault: self
+                .instruction
+                .destination_vault
+                .expect("destination_vault is not set"),
+
+            destination_vault_token_account: self
+                .instruction
+                .destination_vault_token_account
+                .expect("destination_vault_token_account is not set"),
+
+            destination_vrt_mint: self
+                .instruction
+                .destination_vrt_mint
+                .expect("destination_vrt_mint is not set"),
+
+            staker_destination_vrt_token_account: self
+                .instruction
+                .staker_destination_vrt_token_account
+                .expect("staker_destination_vrt_token_account is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            mint_burn_signer: self.instruction.mint_burn_signer,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MigrateToVaultCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source_vrt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source_vault_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_source_vrt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination_vrt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_destination_vrt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint_burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amount_in: Option<u64>,
+    min_amount_out: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -22,11 +22,19 @@ pub struct MintTo {
 
     pub depositor_vrt_token_account: solana_program::pubkey::Pubkey,
 
-    pub vault_fee_token_account: solana_program::pubkey::Pubkey,
-
     pub token_program: solana_program::pubkey::Pubkey,
     /// Signer for minting
     pub mint_signer: Option<solana_program::pubkey::Pubkey>,
+    /// Required in the allowlist deposit mode
+    pub vault_deposit_allowlist_entry: Option<solana_program::pubkey::Pubkey>,
+    /// Required when the vault has a max deposit per wallet or flash deposit protection
+    pub vault_depositor: Option<solana_program::pubkey::Pubkey>,
+    /// Required to create the vault depositor or deposit receipt
+    pub system_program: Option<solana_program::pubkey::Pubkey>,
+    /// Created to record the deposit when passed in with its base
+    pub deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    /// The base of the deposit receipt
+    pub deposit_receipt_base: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl MintTo {
@@ -42,7 +50,7 @@ impl MintTo {
         args: MintToInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -70,10 +78,6 @@ impl MintTo {
             self.depositor_vrt_token_account,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            self.vault_fee_token_account,
-            false,
-        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.token_program,
             false,
@@ -89,6 +93,61 @@ impl MintTo {
                 false,
             ));
         }
+        if let Some(vault_deposit_allowlist_entry) = self.vault_deposit_allowlist_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                vault_deposit_allowlist_entry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_depositor) = self.vault_depositor {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                vault_depositor,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(deposit_receipt) = self.deposit_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                deposit_receipt,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(deposit_receipt_base) = self.deposit_receipt_base {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                deposit_receipt_base,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = MintToInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -137,9 +196,13 @@ pub struct MintToInstructionArgs {
 ///   4. `[writable]` depositor_token_account
 ///   5. `[writable]` vault_token_account
 ///   6. `[writable]` depositor_vrt_token_account
-///   7. `[writable]` vault_fee_token_account
-///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   9. `[signer, optional]` mint_signer
+///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   8. `[signer, optional]` mint_signer
+///   9. `[optional]` vault_deposit_allowlist_entry
+///   10. `[writable, optional]` vault_depositor
+///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   12. `[writable, optional]` deposit_receipt
+///   13. `[signer, optional]` deposit_receipt_base
 #[derive(Clone, Debug, Default)]
 pub struct MintToBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -149,9 +212,13 @@ pub struct MintToBuilder {
     depositor_token_account: Option<solana_program::pubkey::Pubkey>,
     vault_token_account: Option<solana_program::pubkey::Pubkey>,
     depositor_vrt_token_account: Option<solana_program::pubkey::Pubkey>,
-    vault_fee_token_account: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     mint_signer: Option<solana_program::pubkey::Pubkey>,
+    vault_deposit_allowlist_entry: Option<solana_program::pubkey::Pubkey>,
+    vault_depositor: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    deposit_receipt_base: Option<solana_program::pubkey::Pubkey>,
     amount_in: Option<u64>,
     min_amount_out: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -205,14 +272,6 @@ impl MintToBuilder {
         self.depositor_vrt_token_account = Some(depositor_vrt_token_account);
         self
     }
-    #[inline(always)]
-    pub fn vault_fee_token_account(
-        &mut self,
-        vault_fee_token_account: solana_program::pubkey::Pubkey,
-    ) -> &mut Self {
-        self.vault_fee_token_account = Some(vault_fee_token_account);
-        self
-    }
     /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
     #[inline(always)]
     pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -229,6 +288,56 @@ impl MintToBuilder {
         self.mint_signer = mint_signer;
         self
     }
+    /// `[optional account]`
+    /// Required in the allowlist deposit mode
+    #[inline(always)]
+    pub fn vault_deposit_allowlist_entry(
+        &mut self,
+        vault_deposit_allowlist_entry: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_deposit_allowlist_entry = vault_deposit_allowlist_entry;
+        self
+    }
+    /// `[optional account]`
+    /// Required when the vault has a max deposit per wallet or flash deposit protection
+    #[inline(always)]
+    pub fn vault_depositor(
+        &mut self,
+        vault_depositor: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_depositor = vault_depositor;
+        self
+    }
+    /// `[optional account]`
+    /// Required to create the vault depositor or deposit receipt
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
+    /// `[optional account]`
+    /// Created to record the deposit when passed in with its base
+    #[inline(always)]
+    pub fn deposit_receipt(
+        &mut self,
+        deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.deposit_receipt = deposit_receipt;
+        self
+    }
+    /// `[optional account]`
+    /// The base of the deposit receipt
+    #[inline(always)]
+    pub fn deposit_receipt_base(
+        &mut self,
+        deposit_receipt_base: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.deposit_receipt_base = deposit_receipt_base;
+        self
+    }
     #[inline(always)]
     pub fn amount_in(&mut self, amount_in: u64) -> &mut Self {
         self.amount_in = Some(amount_in);
@@ -273,13 +382,15 @@ impl MintToBuilder {
             depositor_vrt_token_account: self
                 .depositor_vrt_token_account
                 .expect("depositor_vrt_token_account is not set"),
-            vault_fee_token_account: self
-                .vault_fee_token_account
-                .expect("vault_fee_token_account is not set"),
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
             mint_signer: self.mint_signer,
+            vault_deposit_allowlist_entry: self.vault_deposit_allowlist_entry,
+            vault_depositor: self.vault_depositor,
+            system_program: self.system_program,
+            deposit_receipt: self.deposit_receipt,
+            deposit_receipt_base: self.deposit_receipt_base,
         };
         let args = MintToInstructionArgs {
             amount_in: self.amount_in.clone().expect("amount_in is not set"),
@@ -309,11 +420,19 @@ pub struct MintToCpiAccounts<'a, 'b> {
 
     pub depositor_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
-
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Signer for minting
     pub mint_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required in the allowlist deposit mode
+    pub vault_deposit_allowlist_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required when the vault has a max deposit per wallet or flash deposit protection
+    pub vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required to create the vault depositor or deposit receipt
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Created to record the deposit when passed in with its base
+    pub deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The base of the deposit receipt
+    pub deposit_receipt_base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `mint_to` CPI instruction.
@@ -335,11 +454,19 @@ pub struct MintToCpi<'a, 'b> {
 
     pub depositor_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
-
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Signer for minting
     pub mint_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required in the allowlist deposit mode
+    pub vault_deposit_allowlist_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required when the vault has a max deposit per wallet or flash deposit protection
+    pub vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required to create the vault depositor or deposit receipt
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Created to record the deposit when passed in with its base
+    pub deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The base of the deposit receipt
+    pub deposit_receipt_base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: MintToInstructionArgs,
 }
@@ -359,9 +486,13 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
             depositor_token_account: accounts.depositor_token_account,
             vault_token_account: accounts.vault_token_account,
             depositor_vrt_token_account: accounts.depositor_vrt_token_account,
-            vault_fee_token_account: accounts.vault_fee_token_account,
             token_program: accounts.token_program,
             mint_signer: accounts.mint_signer,
+            vault_deposit_allowlist_entry: accounts.vault_deposit_allowlist_entry,
+            vault_depositor: accounts.vault_depositor,
+            system_program: accounts.system_program,
+            deposit_receipt: accounts.deposit_receipt,
+            deposit_receipt_base: accounts.deposit_receipt_base,
             __args: args,
         }
     }
@@ -398,7 +529,7 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -427,10 +558,6 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
             *self.depositor_vrt_token_account.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            *self.vault_fee_token_account.key,
-            false,
-        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false,
@@ -446,6 +573,61 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(vault_deposit_allowlist_entry) = self.vault_deposit_allowlist_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *vault_deposit_allowlist_entry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_depositor) = self.vault_depositor {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *vault_depositor.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(deposit_receipt) = self.deposit_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *deposit_receipt.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(deposit_receipt_base) = self.deposit_receipt_base {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *deposit_receipt_base.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -462,7 +644,7 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(15 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
@@ -471,11 +653,25 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
         account_infos.push(self.depositor_token_account.clone());
         account_infos.push(self.vault_token_account.clone());
         account_infos.push(self.depositor_vrt_token_account.clone());
-        account_infos.push(self.vault_fee_token_account.clone());
         account_infos.push(self.token_program.clone());
         if let Some(mint_signer) = self.mint_signer {
             account_infos.push(mint_signer.clone());
         }
+        if let Some(vault_deposit_allowlist_entry) = self.vault_deposit_allowlist_entry {
+            account_infos.push(vault_deposit_allowlist_entry.clone());
+        }
+        if let Some(vault_depositor) = self.vault_depositor {
+            account_infos.push(vault_depositor.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
+        if let Some(deposit_receipt) = self.deposit_receipt {
+            account_infos.push(deposit_receipt.clone());
+        }
+        if let Some(deposit_receipt_base) = self.deposit_receipt_base {
+            account_infos.push(deposit_receipt_base.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -499,9 +695,13 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
 ///   4. `[writable]` depositor_token_account
 ///   5. `[writable]` vault_token_account
 ///   6. `[writable]` depositor_vrt_token_account
-///   7. `[writable]` vault_fee_token_account
-///   8. `[]` token_program
-///   9. `[signer, optional]` mint_signer
+///   7. `[]` token_program
+///   8. `[signer, optional]` mint_signer
+///   9. `[optional]` vault_deposit_allowlist_entry
+///   10. `[writable, optional]` vault_depositor
+///   11. `[optional]` system_program
+///   12. `[writable, optional]` deposit_receipt
+///   13. `[signer, optional]` deposit_receipt_base
 #[derive(Clone, Debug)]
 pub struct MintToCpiBuilder<'a, 'b> {
     instruction: Box<MintToCpiBuilderInstruction<'a, 'b>>,
@@ -518,9 +718,13 @@ impl<'a, 'b> MintToCpiBuilder<'a, 'b> {
             depositor_token_account: None,
             vault_token_account: None,
             depositor_vrt_token_account: None,
-            vault_fee_token_account: None,
             token_program: None,
             mint_signer: None,
+            vault_deposit_allowlist_entry: None,
+            vault_depositor: None,
+            system_program: None,
+            deposit_receipt: None,
+            deposit_receipt_base: None,
             amount_in: None,
             min_amount_out: None,
             __remaining_accounts: Vec::new(),
@@ -581,14 +785,6 @@ impl<'a, 'b> MintToCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn vault_fee_token_account(
-        &mut self,
-        vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
-    ) -> &mut Self {
-        self.instruction.vault_fee_token_account = Some(vault_fee_token_account);
-        self
-    }
-    #[inline(always)]
     pub fn token_program(
         &mut self,
         token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -606,6 +802,56 @@ impl<'a, 'b> MintToCpiBuilder<'a, 'b> {
         self.instruction.mint_signer = mint_signer;
         self
     }
+    /// `[optional account]`
+    /// Required in the allowlist deposit mode
+    #[inline(always)]
+    pub fn vault_deposit_allowlist_entry(
+        &mut self,
+        vault_deposit_allowlist_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_deposit_allowlist_entry = vault_deposit_allowlist_entry;
+        self
+    }
+    /// `[optional account]`
+    /// Required when the vault has a max deposit per wallet or flash deposit protection
+    #[inline(always)]
+    pub fn vault_depositor(
+        &mut self,
+        vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_depositor = vault_depositor;
+        self
+    }
+    /// `[optional account]`
+    /// Required to create the vault depositor or deposit receipt
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
+    /// `[optional account]`
+    /// Created to record the deposit when passed in with its base
+    #[inline(always)]
+    pub fn deposit_receipt(
+        &mut self,
+        deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.deposit_receipt = deposit_receipt;
+        self
+    }
+    /// `[optional account]`
+    /// The base of the deposit receipt
+    #[inline(always)]
+    pub fn deposit_receipt_base(
+        &mut self,
+        deposit_receipt_base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.deposit_receipt_base = deposit_receipt_base;
+        self
+    }
     #[inline(always)]
     pub fn amount_in(&mut self, amount_in: u64) -> &mut Self {
         self.instruction.amount_in = Some(amount_in);
@@ -695,17 +941,22 @@ impl<'a, 'b> MintToCpiBuilder<'a, 'b> {
                 .depositor_vrt_token_account
                 .expect("depositor_vrt_token_account is not set"),
 
-            vault_fee_token_account: self
-                .instruction
-                .vault_fee_token_account
-                .expect("vault_fee_token_account is not set"),
-
             token_program: self
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
 
             mint_signer: self.instruction.mint_signer,
+
+            vault_deposit_allowlist_entry: self.instruction.vault_deposit_allowlist_entry,
+
+            vault_depositor: self.instruction.vault_depositor,
+
+            system_program: self.instruction.system_program,
+
+            deposit_receipt: self.instruction.deposit_receipt,
+
+            deposit_receipt_base: self.instruction.deposit_receipt_base,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -725,9 +976,13 @@ struct MintToCpiBuilderInstruction<'a, 'b> {
     depositor_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     depositor_vrt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    vault_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_deposit_allowlist_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    deposit_receipt_base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount_in: Option<u64>,
     min_amount_out: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -0,0 +1,932 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct MintToWithSol {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub vault: solana_program::pubkey::Pubkey,
+
+    pub vrt_mint: solana_program::pubkey::Pubkey,
+
+    pub depositor: solana_program::pubkey::Pubkey,
+
+    pub vault_token_account: solana_program::pubkey::Pubkey,
+
+    pub depositor_vrt_token_account: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// Signer for minting
+    pub mint_signer: Option<solana_program::pubkey::Pubkey>,
+    /// Required in the allowlist deposit mode
+    pub vault_deposit_allowlist_entry: Option<solana_program::pubkey::Pubkey>,
+    /// Required when the vault has a max deposit per wallet or flash deposit protection
+    pub vault_depositor: Option<solana_program::pubkey::Pubkey>,
+    /// Created to record the deposit when passed in with its base
+    pub deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    /// The base of the deposit receipt
+    pub deposit_receipt_base: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl MintToWithSol {
+    pub fn instruction(
+        &self,
+        args: MintToWithSolInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MintToWithSolInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vrt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.depositor,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.depositor_vrt_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(mint_signer) = self.mint_signer {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint_signer,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_deposit_allowlist_entry) = self.vault_deposit_allowlist_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                vault_deposit_allowlist_entry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_depositor) = self.vault_depositor {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                vault_depositor,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(deposit_receipt) = self.deposit_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                deposit_receipt,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(deposit_receipt_base) = self.deposit_receipt_base {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                deposit_receipt_base,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = MintToWithSolInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MintToWithSolInstructionData {
+    discriminator: u8,
+}
+
+impl MintToWithSolInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 47 }
+    }
+}
+
+impl Default for MintToWithSolInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MintToWithSolInstructionArgs {
+    pub amount_in: u64,
+    pub min_amount_out: u64,
+}
+
+/// Instruction builder for `MintToWithSol`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` vault
+///   2. `[writable]` vrt_mint
+///   3. `[writable, signer]` depositor
+///   4. `[writable]` vault_token_account
+///   5. `[writable]` depositor_vrt_token_account
+///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[signer, optional]` mint_signer
+///   9. `[optional]` vault_deposit_allowlist_entry
+///   10. `[writable, optional]` vault_depositor
+///   11. `[writable, optional]` deposit_receipt
+///   12. `[signer, optional]` deposit_receipt_base
+#[derive(Clone, Debug, Default)]
+pub struct MintToWithSolBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    vrt_mint: Option<solana_program::pubkey::Pubkey>,
+    depositor: Option<solana_program::pubkey::Pubkey>,
+    vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    depositor_vrt_token_account: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    mint_signer: Option<solana_program::pubkey::Pubkey>,
+    vault_deposit_allowlist_entry: Option<solana_program::pubkey::Pubkey>,
+    vault_depositor: Option<solana_program::pubkey::Pubkey>,
+    deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    deposit_receipt_base: Option<solana_program::pubkey::Pubkey>,
+    amount_in: Option<u64>,
+    min_amount_out: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MintToWithSolBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn vrt_mint(&mut self, vrt_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vrt_mint = Some(vrt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn depositor(&mut self, depositor: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.depositor = Some(depositor);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_token_account(
+        &mut self,
+        vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_token_account = Some(vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn depositor_vrt_token_account(
+        &mut self,
+        depositor_vrt_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.depositor_vrt_token_account = Some(depositor_vrt_token_account);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Signer for minting
+    #[inline(always)]
+    pub fn mint_signer(
+        &mut self,
+        mint_signer: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.mint_signer = mint_signer;
+        self
+    }
+    /// `[optional account]`
+    /// Required in the allowlist deposit mode
+    #[inline(always)]
+    pub fn vault_deposit_allowlist_entry(
+        &mut self,
+        vault_deposit_allowlist_entry: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_deposit_allowlist_entry = vault_deposit_allowlist_entry;
+        self
+    }
+    /// `[optional account]`
+    /// Required when the vault has a max deposit per wallet or flash deposit protection
+    #[inline(always)]
+    pub fn vault_depositor(
+        &mut self,
+        vault_depositor: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_depositor = vault_depositor;
+        self
+    }
+    /// `[optional account]`
+    /// Created to record the deposit when passed in with its base
+    #[inline(always)]
+    pub fn deposit_receipt(
+        &mut self,
+        deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.deposit_receipt = deposit_receipt;
+        self
+    }
+    /// `[optional account]`
+    /// The base of the deposit receipt
+    #[inline(always)]
+    pub fn deposit_receipt_base(
+        &mut self,
+        deposit_receipt_base: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.deposit_receipt_base = deposit_receipt_base;
+        self
+    }
+    #[inline(always)]
+    pub fn amount_in(&mut self, amount_in: u64) -> &mut Self {
+        self.amount_in = Some(amount_in);
+        self
+    }
+    #[inline(always)]
+    pub fn min_amount_out(&mut self, min_amount_out: u64) -> &mut Self {
+        self.min_amount_out = Some(min_amount_out);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = MintToWithSol {
+            config: self.config.expect("config is not set"),
+            vault: self.vault.expect("vault is not set"),
+            vrt_mint: self.vrt_mint.expect("vrt_mint is not set"),
+            depositor: self.depositor.expect("depositor is not set"),
+            vault_token_account: self
+                .vault_token_account
+                .expect("vault_token_account is not set"),
+            depositor_vrt_token_account: self
+                .depositor_vrt_token_account
+                .expect("depositor_vrt_token_account is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            mint_signer: self.mint_signer,
+            vault_deposit_allowlist_entry: self.vault_deposit_allowlist_entry,
+            vault_depositor: self.vault_depositor,
+            deposit_receipt: self.deposit_receipt,
+            deposit_receipt_base: self.deposit_receipt_base,
+        };
+        let args = MintToWithSolInstructionArgs {
+            amount_in: self.amount_in.clone().expect("amount_in is not set"),
+            min_amount_out: self
+                .min_amount_out
+                .clone()
+                .expect("min_amount_out is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `mint_to_with_sol` CPI accounts.
+pub struct MintToWithSolCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub depositor: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub depositor_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Signer for minting
+    pub mint_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required in the allowlist deposit mode
+    pub vault_deposit_allowlist_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required when the vault has a max deposit per wallet or flash deposit protection
+    pub vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Created to record the deposit when passed in with its base
+    pub deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The base of the deposit receipt
+    pub deposit_receipt_base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `mint_to_with_sol` CPI instruction.
+pub struct MintToWithSolCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub depositor: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub depositor_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Signer for minting
+    pub mint_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required in the allowlist deposit mode
+    pub vault_deposit_allowlist_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required when the vault has a max deposit per wallet or flash deposit protection
+    pub vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Created to record the deposit when passed in with its base
+    pub deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The base of the deposit receipt
+    pub deposit_receipt_base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: MintToWithSolInstructionArgs,
+}
+
+impl<'a, 'b> MintToWithSolCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MintToWithSolCpiAccounts<'a, 'b>,
+        args: MintToWithSolInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            vault: accounts.vault,
+            vrt_mint: accounts.vrt_mint,
+            depositor: accounts.depositor,
+            vault_token_account: accounts.vault_token_account,
+            depositor_vrt_token_account: accounts.depositor_vrt_token_account,
+            token_program: accounts.token_program,
+            system_program: accounts.system_program,
+            mint_signer: accounts.mint_signer,
+            vault_deposit_allowlist_entry: accounts.vault_deposit_allowlist_entry,
+            vault_depositor: accounts.vault_depositor,
+            deposit_receipt: accounts.deposit_receipt,
+            deposit_receipt_base: accounts.deposit_receipt_base,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vrt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.depositor.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.depositor_vrt_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(mint_signer) = self.mint_signer {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint_signer.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_deposit_allowlist_entry) = self.vault_deposit_allowlist_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *vault_deposit_allowlist_entry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_depositor) = self.vault_depositor {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *vault_depositor.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(deposit_receipt) = self.deposit_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *deposit_receipt.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(deposit_receipt_base) = self.deposit_receipt_base {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *deposit_receipt_base.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = MintToWithSolInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.vrt_mint.clone());
+        account_infos.push(self.depositor.clone());
+        account_infos.push(self.vault_token_account.clone());
+        account_infos.push(self.depositor_vrt_token_account.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(mint_signer) = self.mint_signer {
+            account_infos.push(mint_signer.clone());
+        }
+        if let Some(vault_deposit_allowlist_entry) = self.vault_deposit_allowlist_entry {
+            account_infos.push(vault_deposit_allowlist_entry.clone());
+        }
+        if let Some(vault_depositor) = self.vault_depositor {
+            account_infos.push(vault_depositor.clone());
+        }
+        if let Some(deposit_receipt) = self.deposit_receipt {
+            account_infos.push(deposit_receipt.clone());
+        }
+        if let Some(deposit_receipt_base) = self.deposit_receipt_base {
+            account_infos.push(deposit_receipt_base.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MintToWithSol` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` vault
+///   2. `[writable]` vrt_mint
+///   3. `[writable, signer]` depositor
+///   4. `[writable]` vault_token_account
+///   5. `[writable]` depositor_vrt_token_account
+///   6. `[]` token_program
+///   7. `[]` system_program
+///   8. `[signer, optional]` mint_signer
+///   9. `[optional]` vault_deposit_allowlist_entry
+///   10. `[writable, optional]` vault_depositor
+///   11. `[writable, optional]` deposit_receipt
+///   12. `[signer, optional]` deposit_receipt_base
+#[derive(Clone, Debug)]
+pub struct MintToWithSolCpiBuilder<'a, 'b> {
+    instruction: Box<MintToWithSolCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MintToWithSolCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MintToWithSolCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            vault: None,
+            vrt_mint: None,
+            depositor: None,
+            vault_token_account: None,
+            depositor_vrt_token_account: None,
+            token_program: None,
+            system_program: None,
+            mint_signer: None,
+            vault_deposit_allowlist_entry: None,
+            vault_depositor: None,
+            deposit_receipt: None,
+            deposit_receipt_base: None,
+            amount_in: None,
+            min_amount_out: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn vrt_mint(
+        &mut self,
+        vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vrt_mint = Some(vrt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn depositor(
+        &mut self,
+        depositor: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.depositor = Some(depositor);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_token_account(
+        &mut self,
+        vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_token_account = Some(vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn depositor_vrt_token_account(
+        &mut self,
+        depositor_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.depositor_vrt_token_account = Some(depositor_vrt_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Signer for minting
+    #[inline(always)]
+    pub fn mint_signer(
+        &mut self,
+        mint_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint_signer = mint_signer;
+        self
+    }
+    /// `[optional account]`
+    /// Required in the allowlist deposit mode
+    #[inline(always)]
+    pub fn vault_deposit_allowlist_entry(
+        &mut self,
+        vault_deposit_allowlist_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_deposit_allowlist_entry = vault_deposit_allowlist_entry;
+        self
+    }
+    /// `[optional account]`
+    /// Required when the vault has a max deposit per wallet or flash deposit protection
+    #[inline(always)]
+    pub fn vault_depositor(
+        &mut self,
+        vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_depositor = vault_depositor;
+        self
+    }
+    /// `[optional account]`
+    /// Created to record the deposit when passed in with its base
+    #[inline(always)]
+    pub fn deposit_receipt(
+        &mut self,
+        deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.deposit_receipt = deposit_receipt;
+        self
+    }
+    /// `[optional account]`
+    /// The base of the deposit receipt
+    #[inline(always)]
+    pub fn deposit_receipt_base(
+        &mut self,
+        deposit_receipt_base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.deposit_receipt_base = deposit_receipt_base;
+        self
+    }
+    #[inline(always)]
+    pub fn amount_in(&mut self, amount_in: u64) -> &mut Self {
+        self.instruction.amount_in = Some(amount_in);
+        self
+    }
+    #[inline(always)]
+    pub fn min_amount_out(&mut self, min_amount_out: u64) -> &mut Self {
+        self.instruction.min_amount_out = Some(min_amount_out);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = MintToWithSolInstructionArgs {
+            amount_in: self
+                .instruction
+                .amount_in
+                .clone()
+                .expect("amount_in is not set"),
+            min_amount_out: self
+                .instruction
+                .min_amount_out
+                .clone()
+                .expect("min_amount_out is not set"),
+        };
+        let instruction = MintToWithSolCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            vrt_mint: self.instruction.vrt_mint.expect("vrt_mint is not set"),
+
+            depositor: self.instruction.depositor.expect("depositor is not set"),
+
+            vault_token_account: self
+                .instruction
+                .vault_token_account
+                .expect("vault_token_account is not set"),
+
+            depositor_vrt_token_account: self
+                .instruction
+                .depositor_vrt_token_account
+                .expect("depositor_vrt_token_account is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            mint_signer: self.instruction.mint_signer,
+
+            vault_deposit_allowlist_entry: self.instruction.vault_deposit_allowlist_entry,
+
+            vault_depositor: self.instruction.vault_depositor,
+
+            deposit_receipt: self.instruction.deposit_receipt,
+
+            deposit_receipt_base: self.instruction.deposit_receipt_base,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MintToWithSolCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vrt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    depositor_vrt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_deposit_allowlist_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_depositor: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    deposit_receipt_base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amount_in: Option<u64>,
+    min_amount_out: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -5,46 +5,126 @@
 //! <https://github.com/kinobi-so/kinobi>
 
 pub(crate) mod r#add_delegation;
+pub(crate) mod r#add_to_deposit_allowlist;
+pub(crate) mod r#admin_set_config_paused;
+pub(crate) mod r#admin_set_crank_reward;
+pub(crate) mod r#admin_set_delegation_cooldown;
+pub(crate) mod r#admin_set_protocol_fee;
+pub(crate) mod r#admin_set_slash_veto;
+pub(crate) mod r#admin_set_vault_epoch_length_bounds;
 pub(crate) mod r#admin_withdraw;
+pub(crate) mod r#ban_operator;
 pub(crate) mod r#burn;
+pub(crate) mod r#burn_delegated;
+pub(crate) mod r#burn_or_enqueue_withdrawal;
 pub(crate) mod r#burn_withdraw_ticket;
+pub(crate) mod r#cancel_admin_burn;
+pub(crate) mod r#cancel_mint_authority_handoff;
 pub(crate) mod r#change_withdrawal_ticket_owner;
+pub(crate) mod r#claim_rewards;
+pub(crate) mod r#close_deposit_receipt;
+pub(crate) mod r#close_vault;
 pub(crate) mod r#close_vault_update_state_tracker;
 pub(crate) mod r#cooldown_delegation;
 pub(crate) mod r#cooldown_vault_ncn_slasher_ticket;
 pub(crate) mod r#cooldown_vault_ncn_ticket;
+pub(crate) mod r#crank_operator_stake_weight;
 pub(crate) mod r#crank_vault_update_state_tracker;
 pub(crate) mod r#create_token_metadata;
+pub(crate) mod r#distribute_ncn_rewards;
+pub(crate) mod r#enable_admin_burn;
 pub(crate) mod r#enqueue_withdrawal;
+pub(crate) mod r#execute_admin_burn;
+pub(crate) mod r#execute_mint_authority_handoff;
+pub(crate) mod r#execute_slash;
+pub(crate) mod r#fund_insurance;
 pub(crate) mod r#initialize_config;
+pub(crate) mod r#initialize_delegation_strategy;
+pub(crate) mod r#initialize_ncn_reward_router;
+pub(crate) mod r#initialize_operator_stake_weight;
+pub(crate) mod r#initialize_reward_distribution;
 pub(crate) mod r#initialize_vault;
+pub(crate) mod r#initialize_vault_epoch_stats;
 pub(crate) mod r#initialize_vault_ncn_slasher_operator_ticket;
 pub(crate) mod r#initialize_vault_ncn_slasher_ticket;
 pub(crate) mod r#initialize_vault_ncn_ticket;
 pub(crate) mod r#initialize_vault_operator_delegation;
+pub(crate) mod r#initialize_vault_token_account;
 pub(crate) mod r#initialize_vault_update_state_tracker;
 pub(crate) mod r#initialize_vault_with_mint;
+pub(crate) mod r#initiate_vault_shutdown;
+pub(crate) mod r#migrate_account;
+pub(crate) mod r#migrate_to_vault;
 pub(crate) mod r#mint_to;
+pub(crate) mod r#mint_to_with_sol;
+pub(crate) mod r#propose_admin_burn;
+pub(crate) mod r#propose_mint_authority_handoff;
+pub(crate) mod r#propose_slash;
+pub(crate) mod r#rebalance_delegations;
+pub(crate) mod r#reconcile_vault_balance;
+pub(crate) mod r#remove_from_deposit_allowlist;
+pub(crate) mod r#remove_slasher;
 pub(crate) mod r#set_admin;
+pub(crate) mod r#set_delegation_strategy_weight;
 pub(crate) mod r#set_deposit_capacity;
+pub(crate) mod r#set_deposit_limits;
+pub(crate) mod r#set_deposit_mode;
+pub(crate) mod r#set_fee_wallet;
 pub(crate) mod r#set_fees;
+pub(crate) mod r#set_flash_deposit_protection;
+pub(crate) mod r#set_hook_program;
+pub(crate) mod r#set_insurance_params;
+pub(crate) mod r#set_max_total_slashable_per_epoch;
+pub(crate) mod r#set_migration_partner;
+pub(crate) mod r#set_min_delegation_amount;
+pub(crate) mod r#set_operator_delegation_paused;
+pub(crate) mod r#set_operator_max_delegation;
 pub(crate) mod r#set_secondary_admin;
+pub(crate) mod r#set_slash_dispute_haircut;
+pub(crate) mod r#set_vault_epoch_length;
+pub(crate) mod r#set_vault_paused;
+pub(crate) mod r#set_withdrawal_delay;
+pub(crate) mod r#set_withdrawal_ticket_auto_claim_fee;
 pub(crate) mod r#slash;
+pub(crate) mod r#snapshot_vault_operator_delegation;
+pub(crate) mod r#unban_operator;
 pub(crate) mod r#update_token_metadata;
 pub(crate) mod r#update_vault_balance;
+pub(crate) mod r#veto_slash;
 pub(crate) mod r#warmup_vault_ncn_slasher_ticket;
 pub(crate) mod r#warmup_vault_ncn_ticket;
 
 pub use self::{
-    r#add_delegation::*, r#admin_withdraw::*, r#burn::*, r#burn_withdraw_ticket::*,
-    r#change_withdrawal_ticket_owner::*, r#close_vault_update_state_tracker::*,
-    r#cooldown_delegation::*, r#cooldown_vault_ncn_slasher_ticket::*,
-    r#cooldown_vault_ncn_ticket::*, r#crank_vault_update_state_tracker::*,
-    r#create_token_metadata::*, r#enqueue_withdrawal::*, r#initialize_config::*,
-    r#initialize_vault::*, r#initialize_vault_ncn_slasher_operator_ticket::*,
-    r#initialize_vault_ncn_slasher_ticket::*, r#initialize_vault_ncn_ticket::*,
-    r#initialize_vault_operator_delegation::*, r#initialize_vault_update_state_tracker::*,
-    r#initialize_vault_with_mint::*, r#mint_to::*, r#set_admin::*, r#set_deposit_capacity::*,
-    r#set_fees::*, r#set_secondary_admin::*, r#slash::*, r#update_token_metadata::*,
-    r#update_vault_balance::*, r#warmup_vault_ncn_slasher_ticket::*, r#warmup_vault_ncn_ticket::*,
+    r#add_delegation::*, r#add_to_deposit_allowlist::*, r#admin_set_config_paused::*,
+    r#admin_set_crank_reward::*, r#admin_set_delegation_cooldown::*, r#admin_set_protocol_fee::*,
+    r#admin_set_slash_veto::*, r#admin_set_vault_epoch_length_bounds::*, r#admin_withdraw::*,
+    r#ban_operator::*, r#burn::*, r#burn_delegated::*, r#burn_or_enqueue_withdrawal::*,
+    r#burn_withdraw_ticket::*, r#cancel_admin_burn::*, r#cancel_mint_authority_handoff::*,
+    r#change_withdrawal_ticket_owner::*, r#claim_rewards::*, r#close_deposit_receipt::*,
+    r#close_vault::*, r#close_vault_update_state_tracker::*, r#cooldown_delegation::*,
+    r#cooldown_vault_ncn_slasher_ticket::*, r#cooldown_vault_ncn_ticket::*,
+    r#crank_operator_stake_weight::*, r#crank_vault_update_state_tracker::*,
+    r#create_token_metadata::*, r#distribute_ncn_rewards::*, r#enable_admin_burn::*,
+    r#enqueue_withdrawal::*, r#execute_admin_burn::*, r#execute_mint_authority_handoff::*,
+    r#execute_slash::*, r#fund_insurance::*, r#initialize_config::*,
+    r#initialize_delegation_strategy::*, r#initialize_ncn_reward_router::*,
+    r#initialize_operator_stake_weight::*, r#initialize_reward_distribution::*,
+    r#initialize_vault::*, r#initialize_vault_epoch_stats::*,
+    r#initialize_vault_ncn_slasher_operator_ticket::*, r#initialize_vault_ncn_slasher_ticket::*,
+    r#initialize_vault_ncn_ticket::*, r#initialize_vault_operator_delegation::*,
+    r#initialize_vault_token_account::*, r#initialize_vault_update_state_tracker::*,
+    r#initialize_vault_with_mint::*, r#initiate_vault_shutdown::*, r#migrate_account::*,
+    r#migrate_to_vault::*, r#mint_to::*, r#mint_to_with_sol::*, r#propose_admin_burn::*,
+    r#propose_mint_authority_handoff::*, r#propose_slash::*, r#rebalance_delegations::*,
+    r#reconcile_vault_balance::*, r#remove_from_deposit_allowlist::*, r#remove_slasher::*,
+    r#set_admin::*, r#set_delegation_strategy_weight::*, r#set_deposit_capacity::*,
+    r#set_deposit_limits::*, r#set_deposit_mode::*, r#set_fee_wallet::*, r#set_fees::*,
+    r#set_flash_deposit_protection::*, r#set_hook_program::*, r#set_insurance_params::*,
+    r#set_max_total_slashable_per_epoch::*, r#set_migration_partner::*,
+    r#set_min_delegation_amount::*, r#set_operator_delegation_paused::*,
+    r#set_operator_max_delegation::*, r#set_secondary_admin::*, r#set_slash_dispute_haircut::*,
+    r#set_vault_epoch_length::*, r#set_vault_paused::*, r#set_withdrawal_delay::*,
+    r#set_withdrawal_ticket_auto_claim_fee::*, r#slash::*, r#snapshot_vault_operator_delegation::*,
+    r#unban_operator::*, r#update_token_metadata::*, r#update_vault_balance::*, r#veto_slash::*,
+    r#warmup_vault_ncn_slasher_ticket::*, r#warmup_vault_ncn_ticket::*,
 };
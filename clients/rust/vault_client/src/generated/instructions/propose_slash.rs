@@ -0,0 +1,958 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct ProposeSlash {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub vault: solana_program::pubkey::Pubkey,
+
+    pub ncn: solana_program::pubkey::Pubkey,
+
+    pub operator: solana_program::pubkey::Pubkey,
+
+    pub slasher: solana_program::pubkey::Pubkey,
+
+    pub ncn_operator_state: solana_program::pubkey::Pubkey,
+
+    pub ncn_vault_ticket: solana_program::pubkey::Pubkey,
+
+    pub operator_vault_ticket: solana_program::pubkey::Pubkey,
+
+    pub vault_ncn_ticket: solana_program::pubkey::Pubkey,
+
+    pub vault_operator_delegation: solana_program::pubkey::Pubkey,
+
+    pub ncn_vault_slasher_ticket: solana_program::pubkey::Pubkey,
+
+    pub vault_ncn_slasher_ticket: solana_program::pubkey::Pubkey,
+
+    pub vault_ncn_slasher_operator_ticket: solana_program::pubkey::Pubkey,
+
+    pub slash_proposal: solana_program::pubkey::Pubkey,
+
+    pub base: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl ProposeSlash {
+    pub fn instruction(
+        &self,
+        args: ProposeSlashInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: ProposeSlashInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.operator,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.slasher,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn_operator_state,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn_vault_ticket,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.operator_vault_ticket,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault_ncn_ticket,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault_operator_delegation,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn_vault_slasher_ticket,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault_ncn_slasher_ticket,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_ncn_slasher_operator_ticket,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.slash_proposal,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.base, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = ProposeSlashInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ProposeSlashInstructionData {
+    discriminator: u8,
+}
+
+impl ProposeSlashInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 44 }
+    }
+}
+
+impl Default for ProposeSlashInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposeSlashInstructionArgs {
+    pub amount: u64,
+    pub evidence_hash: [u8; 32],
+}
+
+/// Instruction builder for `ProposeSlash`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` vault
+///   2. `[]` ncn
+///   3. `[]` operator
+///   4. `[writable, signer]` slasher
+///   5. `[]` ncn_operator_state
+///   6. `[]` ncn_vault_ticket
+///   7. `[]` operator_vault_ticket
+///   8. `[]` vault_ncn_ticket
+///   9. `[]` vault_operator_delegation
+///   10. `[]` ncn_vault_slasher_ticket
+///   11. `[]` vault_ncn_slasher_ticket
+///   12. `[writable]` vault_ncn_slasher_operator_ticket
+///   13. `[writable]` slash_proposal
+///   14. `[signer]` base
+///   15. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Clone, Debug, Default)]
+pub struct ProposeSlashBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    ncn: Option<solana_program::pubkey::Pubkey>,
+    operator: Option<solana_program::pubkey::Pubkey>,
+    slasher: Option<solana_program::pubkey::Pubkey>,
+    ncn_operator_state: Option<solana_program::pubkey::Pubkey>,
+    ncn_vault_ticket: Option<solana_program::pubkey::Pubkey>,
+    operator_vault_ticket: Option<solana_program::pubkey::Pubkey>,
+    vault_ncn_ticket: Option<solana_program::pubkey::Pubkey>,
+    vault_operator_delegation: Option<solana_program::pubkey::Pubkey>,
+    ncn_vault_slasher_ticket: Option<solana_program::pubkey::Pubkey>,
+    vault_ncn_slasher_ticket: Option<solana_program::pubkey::Pubkey>,
+    vault_ncn_slasher_operator_ticket: Option<solana_program::pubkey::Pubkey>,
+    slash_proposal: Option<solana_program::pubkey::Pubkey>,
+    base: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    amount: Option<u64>,
+    evidence_hash: Option<[u8; 32]>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl ProposeSlashBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn operator(&mut self, operator: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.operator = Some(operator);
+        self
+    }
+    #[inline(always)]
+    pub fn slasher(&mut self, slasher: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.slasher = Some(slasher);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_operator_state(
+        &mut self,
+        ncn_operator_state: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.ncn_operator_state = Some(ncn_operator_state);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_vault_ticket(
+        &mut self,
+        ncn_vault_ticket: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.ncn_vault_ticket = Some(ncn_vault_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn operator_vault_ticket(
+        &mut self,
+        operator_vault_ticket: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.operator_vault_ticket = Some(operator_vault_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_ncn_ticket(
+        &mut self,
+        vault_ncn_ticket: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_ncn_ticket = Some(vault_ncn_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_operator_delegation(
+        &mut self,
+        vault_operator_delegation: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_operator_delegation = Some(vault_operator_delegation);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_vault_slasher_ticket(
+        &mut self,
+        ncn_vault_slasher_ticket: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.ncn_vault_slasher_ticket = Some(ncn_vault_slasher_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_ncn_slasher_ticket(
+        &mut self,
+        vault_ncn_slasher_ticket: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_ncn_slasher_ticket = Some(vault_ncn_slasher_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_ncn_slasher_operator_ticket(
+        &mut self,
+        vault_ncn_slasher_operator_ticket: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_ncn_slasher_operator_ticket = Some(vault_ncn_slasher_operator_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn slash_proposal(&mut self, slash_proposal: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.slash_proposal = Some(slash_proposal);
+        self
+    }
+    #[inline(always)]
+    pub fn base(&mut self, base: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.base = Some(base);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    #[inline(always)]
+    pub fn evidence_hash(&mut self, evidence_hash: [u8; 32]) -> &mut Self {
+        self.evidence_hash = Some(evidence_hash);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = ProposeSlash {
+            config: self.config.expect("config is not set"),
+            vault: self.vault.expect("vault is not set"),
+            ncn: self.ncn.expect("ncn is not set"),
+            operator: self.operator.expect("operator is not set"),
+            slasher: self.slasher.expect("slasher is not set"),
+            ncn_operator_state: self
+                .ncn_operator_state
+                .expect("ncn_operator_state is not set"),
+            ncn_vault_ticket: self.ncn_vault_ticket.expect("ncn_vault_ticket is not set"),
+            operator_vault_ticket: self
+                .operator_vault_ticket
+                .expect("operator_vault_ticket is not set"),
+            vault_ncn_ticket: self.vault_ncn_ticket.expect("vault_ncn_ticket is not set"),
+            vault_operator_delegation: self
+                .vault_operator_delegation
+                .expect("vault_operator_delegation is not set"),
+            ncn_vault_slasher_ticket: self
+                .ncn_vault_slasher_ticket
+                .expect("ncn_vault_slasher_ticket is not set"),
+            vault_ncn_slasher_ticket: self
+                .vault_ncn_slasher_ticket
+                .expect("vault_ncn_slasher_ticket is not set"),
+            vault_ncn_slasher_operator_ticket: self
+                .vault_ncn_slasher_operator_ticket
+                .expect("vault_ncn_slasher_operator_ticket is not set"),
+            slash_proposal: self.slash_proposal.expect("slash_proposal is not set"),
+            base: self.base.expect("base is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = ProposeSlashInstructionArgs {
+            amount: self.amount.clone().expect("amount is not set"),
+            evidence_hash: self
+                .evidence_hash
+                .clone()
+                .expect("evidence_hash is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `propose_slash` CPI accounts.
+pub struct ProposeSlashCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub slasher: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_operator_state: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_ncn_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_operator_delegation: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_vault_slasher_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_ncn_slasher_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_ncn_slasher_operator_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub slash_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub base: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `propose_slash` CPI instruction.
+pub struct ProposeSlashCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub slasher: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_operator_state: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operator_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_ncn_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_operator_delegation: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_vault_slasher_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_ncn_slasher_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_ncn_slasher_operator_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub slash_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub base: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: ProposeSlashInstructionArgs,
+}
+
+impl<'a, 'b> ProposeSlashCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: ProposeSlashCpiAccounts<'a, 'b>,
+        args: ProposeSlashInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            vault: accounts.vault,
+            ncn: accounts.ncn,
+            operator: accounts.operator,
+            slasher: accounts.slasher,
+            ncn_operator_state: accounts.ncn_operator_state,
+            ncn_vault_ticket: accounts.ncn_vault_ticket,
+            operator_vault_ticket: accounts.operator_vault_ticket,
+            vault_ncn_ticket: accounts.vault_ncn_ticket,
+            vault_operator_delegation: accounts.vault_operator_delegation,
+            ncn_vault_slasher_ticket: accounts.ncn_vault_slasher_ticket,
+            vault_ncn_slasher_ticket: accounts.vault_ncn_slasher_ticket,
+            vault_ncn_slasher_operator_ticket: accounts.vault_ncn_slasher_operator_ticket,
+            slash_proposal: accounts.slash_proposal,
+            base: accounts.base,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.operator.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.slasher.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn_operator_state.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn_vault_ticket.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.operator_vault_ticket.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault_ncn_ticket.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault_operator_delegation.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn_vault_slasher_ticket.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault_ncn_slasher_ticket.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_ncn_slasher_operator_ticket.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.slash_proposal.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.base.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = ProposeSlashInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(16 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.ncn.clone());
+        account_infos.push(self.operator.clone());
+        account_infos.push(self.slasher.clone());
+        account_infos.push(self.ncn_operator_state.clone());
+        account_infos.push(self.ncn_vault_ticket.clone());
+        account_infos.push(self.operator_vault_ticket.clone());
+        account_infos.push(self.vault_ncn_ticket.clone());
+        account_infos.push(self.vault_operator_delegation.clone());
+        account_infos.push(self.ncn_vault_slasher_ticket.clone());
+        account_infos.push(self.vault_ncn_slasher_ticket.clone());
+        account_infos.push(self.vault_ncn_slasher_operator_ticket.clone());
+        account_infos.push(self.slash_proposal.clone());
+        account_infos.push(self.base.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ProposeSlash` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` vault
+///   2. `[]` ncn
+///   3. `[]` operator
+///   4. `[writable, signer]` slasher
+///   5. `[]` ncn_operator_state
+///   6. `[]` ncn_vault_ticket
+///   7. `[]` operator_vault_ticket
+///   8. `[]` vault_ncn_ticket
+///   9. `[]` vault_operator_delegation
+///   10. `[]` ncn_vault_slasher_ticket
+///   11. `[]` vault_ncn_slasher_ticket
+///   12. `[writable]` vault_ncn_slasher_operator_ticket
+///   13. `[writable]` slash_proposal
+///   14. `[signer]` base
+///   15. `[]` system_program
+#[derive(Clone, Debug)]
+pub struct ProposeSlashCpiBuilder<'a, 'b> {
+    instruction: Box<ProposeSlashCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ProposeSlashCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ProposeSlashCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            vault: None,
+            ncn: None,
+            operator: None,
+            slasher: None,
+            ncn_operator_state: None,
+            ncn_vault_ticket: None,
+            operator_vault_ticket: None,
+            vault_ncn_ticket: None,
+            vault_operator_delegation: None,
+            ncn_vault_slasher_ticket: None,
+            vault_ncn_slasher_ticket: None,
+            vault_ncn_slasher_operator_ticket: None,
+            slash_proposal: None,
+            base: None,
+            system_program: None,
+            amount: None,
+            evidence_hash: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn operator(
+        &mut self,
+        operator: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.operator = Some(operator);
+        self
+    }
+    #[inline(always)]
+    pub fn slasher(
+        &mut self,
+        slasher: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.slasher = Some(slasher);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_operator_state(
+        &mut self,
+        ncn_operator_state: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ncn_operator_state = Some(ncn_operator_state);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_vault_ticket(
+        &mut self,
+        ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ncn_vault_ticket = Some(ncn_vault_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn operator_vault_ticket(
+        &mut self,
+        operator_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.operator_vault_ticket = Some(operator_vault_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_ncn_ticket(
+        &mut self,
+        vault_ncn_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_ncn_ticket = Some(vault_ncn_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_operator_delegation(
+        &mut self,
+        vault_operator_delegation: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_operator_delegation = Some(vault_operator_delegation);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_vault_slasher_ticket(
+        &mut self,
+        ncn_vault_slasher_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ncn_vault_slasher_ticket = Some(ncn_vault_slasher_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_ncn_slasher_ticket(
+        &mut self,
+        vault_ncn_slasher_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_ncn_slasher_ticket = Some(vault_ncn_slasher_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_ncn_slasher_operator_ticket(
+        &mut self,
+        vault_ncn_slasher_operator_ticket: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_ncn_slasher_operator_ticket =
+            Some(vault_ncn_slasher_operator_ticket);
+        self
+    }
+    #[inline(always)]
+    pub fn slash_proposal(
+        &mut self,
+        slash_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.slash_proposal = Some(slash_proposal);
+        self
+    }
+    #[inline(always)]
+    pub fn base(&mut self, base: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.base = Some(base);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
+    #[inline(always)]
+    pub fn evidence_hash(&mut self, evidence_hash: [u8; 32]) -> &mut Self {
+        self.instruction.evidence_hash = Some(evidence_hash);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = ProposeSlashInstructionArgs {
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+            evidence_hash: self
+                .instruction
+                .evidence_hash
+                .clone()
+                .expect("evidence_hash is not set"),
+        };
+        let instruction = ProposeSlashCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            ncn: self.instruction.ncn.expect("ncn is not set"),
+
+            operator: self.instruction.operator.expect("operator is not set"),
+
+            slasher: self.instruction.slasher.expect("slasher is not set"),
+
+            ncn_operator_state: self
+                .instruction
+                .ncn_operator_state
+                .expect("ncn_operator_state is not set"),
+
+            ncn_vault_ticket: self
+                .instruction
+                .ncn_vault_ticket
+                .expect("ncn_vault_ticket is not set"),
+
+            operator_vault_ticket: self
+                .instruction
+                .operator_vault_ticket
+                .expect("operator_vault_ticket is not set"),
+
+            vault_ncn_ticket: self
+                .instruction
+                .vault_ncn_ticket
+                .expect("vault_ncn_ticket is not set"),
+
+            vault_operator_delegation: self
+                .instruction
+                .vault_operator_delegation
+                .expect("vault_operator_delegation is not set"),
+
+            ncn_vault_slasher_ticket: self
+                .instruction
+                .ncn_vault_slasher_ticket
+                .expect("ncn_vault_slasher_ticket is not set"),
+
+            vault_ncn_slasher_ticket: self
+                .instruction
+                .vault_ncn_slasher_ticket
+                .expect("vault_ncn_slasher_ticket is not set"),
+
+            vault_ncn_slasher_operator_ticket: self
+                .instruction
+                .vault_ncn_slasher_operator_ticket
+                .expect("vault_ncn_slasher_operator_ticket is not set"),
+
+            slash_proposal: self
+                .instruction
+                .slash_proposal
+                .expect("slash_proposal is not set"),
+
+            base: self.instruction.base.expect("base is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ProposeSlashCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    slasher: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_operator_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_vault_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_vault_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_ncn_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_operator_delegation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_vault_slasher_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_ncn_slasher_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_ncn_slasher_operator_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    slash_proposal: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amount: Option<u64>,
+    evidence_hash: Option<[u8; 32]>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -12,7 +12,7 @@ pub struct SetDepositCapacity {
 
     pub vault: solana_program::pubkey::Pubkey,
 
-    pub admin: solana_program::pubkey::Pubkey,
+    pub capacity_admin: solana_program::pubkey::Pubkey,
 }
 
 impl SetDepositCapacity {
@@ -37,7 +37,8 @@ impl SetDepositCapacity {
             self.vault, false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.admin, true,
+            self.capacity_admin,
+            true,
         ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = SetDepositCapacityInstructionData::new()
@@ -83,12 +84,12 @@ pub struct SetDepositCapacityInstructionArgs {
 ///
 ///   0. `[]` config
 ///   1. `[writable]` vault
-///   2. `[signer]` admin
+///   2. `[signer]` capacity_admin
 #[derive(Clone, Debug, Default)]
 pub struct SetDepositCapacityBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
     vault: Option<solana_program::pubkey::Pubkey>,
-    admin: Option<solana_program::pubkey::Pubkey>,
+    capacity_admin: Option<solana_program::pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -108,8 +109,8 @@ impl SetDepositCapacityBuilder {
         self
     }
     #[inline(always)]
-    pub fn admin(&mut self, admin: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.admin = Some(admin);
+    pub fn capacity_admin(&mut self, capacity_admin: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.capacity_admin = Some(capacity_admin);
         self
     }
     #[inline(always)]
@@ -140,7 +141,7 @@ impl SetDepositCapacityBuilder {
         let accounts = SetDepositCapacity {
             config: self.config.expect("config is not set"),
             vault: self.vault.expect("vault is not set"),
-            admin: self.admin.expect("admin is not set"),
+            capacity_admin: self.capacity_admin.expect("capacity_admin is not set"),
         };
         let args = SetDepositCapacityInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -156,7 +157,7 @@ pub struct SetDepositCapacityCpiAccounts<'a, 'b> {
 
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+    pub capacity_admin: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `set_deposit_capacity` CPI instruction.
@@ -168,7 +169,7 @@ pub struct SetDepositCapacityCpi<'a, 'b> {
 
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+    pub capacity_admin: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: SetDepositCapacityInstructionArgs,
 }
@@ -183,7 +184,7 @@ impl<'a, 'b> SetDepositCapacityCpi<'a, 'b> {
             __program: program,
             config: accounts.config,
             vault: accounts.vault,
-            admin: accounts.admin,
+            capacity_admin: accounts.capacity_admin,
             __args: args,
         }
     }
@@ -230,7 +231,7 @@ impl<'a, 'b> SetDepositCapacityCpi<'a, 'b> {
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.admin.key,
+            *self.capacity_admin.key,
             true,
         ));
         remaining_accounts.iter().for_each(|remaining_account| {
@@ -255,7 +256,7 @@ impl<'a, 'b> SetDepositCapacityCpi<'a, 'b> {
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
-        account_infos.push(self.admin.clone());
+        account_infos.push(self.capacity_admin.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -274,7 +275,7 @@ impl<'a, 'b> SetDepositCapacityCpi<'a, 'b> {
 ///
 ///   0. `[]` config
 ///   1. `[writable]` vault
-///   2. `[signer]` admin
+///   2. `[signer]` capacity_admin
 #[derive(Clone, Debug)]
 pub struct SetDepositCapacityCpiBuilder<'a, 'b> {
     instruction: Box<SetDepositCapacityCpiBuilderInstruction<'a, 'b>>,
@@ -286,7 +287,7 @@ impl<'a, 'b> SetDepositCapacityCpiBuilder<'a, 'b> {
             __program: program,
             config: None,
             vault: None,
-            admin: None,
+            capacity_admin: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -306,8 +307,11 @@ impl<'a, 'b> SetDepositCapacityCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn admin(&mut self, admin: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-        self.instruction.admin = Some(admin);
+    pub fn capacity_admin(
+        &mut self,
+        capacity_admin: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.capacity_admin = Some(capacity_admin);
         self
     }
     #[inline(always)]
@@ -366,7 +370,10 @@ impl<'a, 'b> SetDepositCapacityCpiBuilder<'a, 'b> {
 
             vault: self.instruction.vault.expect("vault is not set"),
 
-            admin: self.instruction.admin.expect("admin is not set"),
+            capacity_admin: self
+                .instruction
+                .capacity_admin
+                .expect("capacity_admin is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -381,7 +388,7 @@ struct SetDepositCapacityCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    capacity_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -36,7 +36,7 @@ pub struct Slash {
 
     pub vault_token_account: solana_program::pubkey::Pubkey,
 
-    pub slasher_token_account: solana_program::pubkey::Pubkey,
+    pub slash_destination: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
 }
@@ -109,8 +109,8 @@ impl Slash {
             self.vault_token_account,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.slasher_token_account,
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.slash_destination,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
@@ -171,7 +171,7 @@ pub struct SlashInstructionArgs {
 ///   11. `[]` vault_ncn_slasher_ticket
 ///   12. `[writable]` vault_ncn_slasher_operator_ticket
 ///   13. `[writable]` vault_token_account
-///   14. `[]` slasher_token_account
+///   14. `[writable]` slash_destination
 ///   15. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 #[derive(Clone, Debug, Default)]
 pub struct SlashBuilder {
@@ -189,7 +189,7 @@ pub struct SlashBuilder {
     vault_ncn_slasher_ticket: Option<solana_program::pubkey::Pubkey>,
     vault_ncn_slasher_operator_ticket: Option<solana_program::pubkey::Pubkey>,
     vault_token_account: Option<solana_program::pubkey::Pubkey>,
-    slasher_token_account: Option<solana_program::pubkey::Pubkey>,
+    slash_destination: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -297,11 +297,11 @@ impl SlashBuilder {
         self
     }
     #[inline(always)]
-    pub fn slasher_token_account(
+    pub fn slash_destination(
         &mut self,
-        slasher_token_account: solana_program::pubkey::Pubkey,
+        slash_destination: solana_program::pubkey::Pubkey,
     ) -> &mut Self {
-        self.slasher_token_account = Some(slasher_token_account);
+        self.slash_destination = Some(slash_destination);
         self
     }
     /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
@@ -364,9 +364,9 @@ impl SlashBuilder {
             vault_token_account: self
                 .vault_token_account
                 .expect("vault_token_account is not set"),
-            slasher_token_account: self
-                .slasher_token_account
-                .expect("slasher_token_account is not set"),
+            slash_destination: self
+                .slash_destination
+                .expect("slash_destination is not set"),
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
@@ -409,7 +409,7 @@ pub struct SlashCpiAccounts<'a, 'b> {
 
     pub vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub slasher_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    pub slash_destination: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
@@ -447,7 +447,7 @@ pub struct SlashCpi<'a, 'b> {
 
     pub vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub slasher_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    pub slash_destination: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
@@ -476,7 +476,7 @@ impl<'a, 'b> SlashCpi<'a, 'b> {
             vault_ncn_slasher_ticket: accounts.vault_ncn_slasher_ticket,
             vault_ncn_slasher_operator_ticket: accounts.vault_ncn_slasher_operator_ticket,
             vault_token_account: accounts.vault_token_account,
-            slasher_token_account: accounts.slasher_token_account,
+            slash_destination: accounts.slash_destination,
             token_program: accounts.token_program,
             __args: args,
         }
@@ -571,8 +571,8 @@ impl<'a, 'b> SlashCpi<'a, 'b> {
             *self.vault_token_account.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.slasher_token_account.key,
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.slash_destination.key,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
@@ -611,7 +611,7 @@ impl<'a, 'b> SlashCpi<'a, 'b> {
         account_infos.push(self.vault_ncn_slasher_ticket.clone());
         account_infos.push(self.vault_ncn_slasher_operator_ticket.clone());
         account_infos.push(self.vault_token_account.clone());
-        account_infos.push(self.slasher_token_account.clone());
+        account_infos.push(self.slash_destination.clone());
         account_infos.push(self.token_program.clone());
         remaining_accounts
             .iter()
@@ -643,7 +643,7 @@ impl<'a, 'b> SlashCpi<'a, 'b> {
 ///   11. `[]` vault_ncn_slasher_ticket
 ///   12. `[writable]` vault_ncn_slasher_operator_ticket
 ///   13. `[writable]` vault_token_account
-///   14. `[]` slasher_token_account
+///   14. `[writable]` slash_destination
 ///   15. `[]` token_program
 #[derive(Clone, Debug)]
 pub struct SlashCpiBuilder<'a, 'b> {
@@ -668,7 +668,7 @@ impl<'a, 'b> SlashCpiBuilder<'a, 'b> {
             vault_ncn_slasher_ticket: None,
             vault_ncn_slasher_operator_ticket: None,
             vault_token_account: None,
-            slasher_token_account: None,
+            slash_destination: None,
             token_program: None,
             amount: None,
             __remaining_accounts: Vec::new(),
@@ -783,11 +783,11 @@ impl<'a, 'b> SlashCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn slasher_token_account(
+    pub fn slash_destination(
         &mut self,
-        slasher_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+        slash_destination: &'b solana_program::account_info::AccountInfo<'a>,
     ) -> &mut Self {
-        self.instruction.slasher_token_account = Some(slasher_token_account);
+        self.instruction.slash_destination = Some(slash_destination);
         self
     }
     #[inline(always)]
@@ -905,10 +905,10 @@ impl<'a, 'b> SlashCpiBuilder<'a, 'b> {
                 .vault_token_account
                 .expect("vault_token_account is not set"),
 
-            slasher_token_account: self
+            slash_destination: self
                 .instruction
-                .slasher_token_account
-                .expect("slasher_token_account is not set"),
+                .slash_destination
+                .expect("slash_destination is not set"),
 
             token_program: self
                 .instruction
@@ -940,7 +940,7 @@ struct SlashCpiBuilderInstruction<'a, 'b> {
     vault_ncn_slasher_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_ncn_slasher_operator_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    slasher_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    slash_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
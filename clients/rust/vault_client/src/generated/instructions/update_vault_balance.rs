@@ -19,6 +19,12 @@ pub struct UpdateVaultBalance {
     pub vault_fee_token_account: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
+
+    pub protocol_fee_token_account: solana_program::pubkey::Pubkey,
+    /// Receives the crank reward
+    pub cranker: Option<solana_program::pubkey::Pubkey>,
+    /// The cranker's associated token account for the supported mint, required when the config has a crank reward in basis points
+    pub cranker_token_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl UpdateVaultBalance {
@@ -30,7 +36,7 @@ impl UpdateVaultBalance {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -38,7 +44,7 @@ impl UpdateVaultBalance {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.vault, false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.vault_token_account,
             false,
         ));
@@ -54,6 +60,29 @@ impl UpdateVaultBalance {
             self.token_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.protocol_fee_token_account,
+            false,
+        ));
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(cranker, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(cranker_token_account) = self.cranker_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                cranker_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = UpdateVaultBalanceInstructionData::new()
             .try_to_vec()
@@ -90,10 +119,13 @@ impl Default for UpdateVaultBalanceInstructionData {
 ///
 ///   0. `[]` config
 ///   1. `[writable]` vault
-///   2. `[]` vault_token_account
+///   2. `[writable]` vault_token_account
 ///   3. `[writable]` vrt_mint
 ///   4. `[writable]` vault_fee_token_account
 ///   5. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   6. `[writable]` protocol_fee_token_account
+///   7. `[writable, signer, optional]` cranker
+///   8. `[writable, optional]` cranker_token_account
 #[derive(Clone, Debug, Default)]
 pub struct UpdateVaultBalanceBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -102,6 +134,9 @@ pub struct UpdateVaultBalanceBuilder {
     vrt_mint: Option<solana_program::pubkey::Pubkey>,
     vault_fee_token_account: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
+    protocol_fee_token_account: Option<solana_program::pubkey::Pubkey>,
+    cranker: Option<solana_program::pubkey::Pubkey>,
+    cranker_token_account: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -146,6 +181,31 @@ impl UpdateVaultBalanceBuilder {
         self.token_program = Some(token_program);
         self
     }
+    #[inline(always)]
+    pub fn protocol_fee_token_account(
+        &mut self,
+        protocol_fee_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.protocol_fee_token_account = Some(protocol_fee_token_account);
+        self
+    }
+    /// `[optional account]`
+    /// Receives the crank reward
+    #[inline(always)]
+    pub fn cranker(&mut self, cranker: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    /// The cranker's associated token account for the supported mint, required when the config has a crank reward in basis points
+    #[inline(always)]
+    pub fn cranker_token_account(
+        &mut self,
+        cranker_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.cranker_token_account = cranker_token_account;
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -179,6 +239,11 @@ impl UpdateVaultBalanceBuilder {
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            protocol_fee_token_account: self
+                .protocol_fee_token_account
+                .expect("protocol_fee_token_account is not set"),
+            cranker: self.cranker,
+            cranker_token_account: self.cranker_token_account,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -198,6 +263,12 @@ pub struct UpdateVaultBalanceCpiAccounts<'a, 'b> {
     pub vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub protocol_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receives the crank reward
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The cranker's associated token account for the supported mint, required when the config has a crank reward in basis points
+    pub cranker_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `update_vault_balance` CPI instruction.
@@ -216,6 +287,12 @@ pub struct UpdateVaultBalanceCpi<'a, 'b> {
     pub vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub protocol_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receives the crank reward
+    pub cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The cranker's associated token account for the supported mint, required when the config has a crank reward in basis points
+    pub cranker_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
@@ -231,6 +308,9 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
             vrt_mint: accounts.vrt_mint,
             vault_fee_token_account: accounts.vault_fee_token_account,
             token_program: accounts.token_program,
+            protocol_fee_token_account: accounts.protocol_fee_token_account,
+            cranker: accounts.cranker,
+            cranker_token_account: accounts.cranker_token_account,
         }
     }
     #[inline(always)]
@@ -266,7 +346,7 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -275,7 +355,7 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
             *self.vault.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.vault_token_account.key,
             false,
         ));
@@ -291,6 +371,32 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.protocol_fee_token_account.key,
+            false,
+        ));
+        if let Some(cranker) = self.cranker {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *cranker.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(cranker_token_account) = self.cranker_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *cranker_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -307,7 +413,7 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
@@ -315,6 +421,13 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
         account_infos.push(self.vrt_mint.clone());
         account_infos.push(self.vault_fee_token_account.clone());
         account_infos.push(self.token_program.clone());
+        account_infos.push(self.protocol_fee_token_account.clone());
+        if let Some(cranker) = self.cranker {
+            account_infos.push(cranker.clone());
+        }
+        if let Some(cranker_token_account) = self.cranker_token_account {
+            account_infos.push(cranker_token_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -333,10 +446,13 @@ impl<'a, 'b> UpdateVaultBalanceCpi<'a, 'b> {
 ///
 ///   0. `[]` config
 ///   1. `[writable]` vault
-///   2. `[]` vault_token_account
+///   2. `[writable]` vault_token_account
 ///   3. `[writable]` vrt_mint
 ///   4. `[writable]` vault_fee_token_account
 ///   5. `[]` token_program
+///   6. `[writable]` protocol_fee_token_account
+///   7. `[writable, signer, optional]` cranker
+///   8. `[writable, optional]` cranker_token_account
 #[derive(Clone, Debug)]
 pub struct UpdateVaultBalanceCpiBuilder<'a, 'b> {
     instruction: Box<UpdateVaultBalanceCpiBuilderInstruction<'a, 'b>>,
@@ -352,6 +468,9 @@ impl<'a, 'b> UpdateVaultBalanceCpiBuilder<'a, 'b> {
             vrt_mint: None,
             vault_fee_token_account: None,
             token_program: None,
+            protocol_fee_token_account: None,
+            cranker: None,
+            cranker_token_account: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -401,6 +520,34 @@ impl<'a, 'b> UpdateVaultBalanceCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    #[inline(always)]
+    pub fn protocol_fee_token_account(
+        &mut self,
+        protocol_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_fee_token_account = Some(protocol_fee_token_account);
+        self
+    }
+    /// `[optional account]`
+    /// Receives the crank reward
+    #[inline(always)]
+    pub fn cranker(
+        &mut self,
+        cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cranker = cranker;
+        self
+    }
+    /// `[optional account]`
+    /// The cranker's associated token account for the supported mint, required when the config has a crank reward in basis points
+    #[inline(always)]
+    pub fn cranker_token_account(
+        &mut self,
+        cranker_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.cranker_token_account = cranker_token_account;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -465,6 +612,15 @@ impl<'a, 'b> UpdateVaultBalanceCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            protocol_fee_token_account: self
+                .instruction
+                .protocol_fee_token_account
+                .expect("protocol_fee_token_account is not set"),
+
+            cranker: self.instruction.cranker,
+
+            cranker_token_account: self.instruction.cranker_token_account,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -482,6 +638,9 @@ struct UpdateVaultBalanceCpiBuilderInstruction<'a, 'b> {
     vrt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    protocol_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cranker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cranker_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
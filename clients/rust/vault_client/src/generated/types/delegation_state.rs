@@ -6,12 +6,15 @@
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
+use crate::generated::types::CooldownCohorts;
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DelegationState {
     pub staked_amount: u64,
     pub enqueued_for_cooldown_amount: u64,
     pub cooling_down_amount: u64,
+    pub cooling_down_cohorts: CooldownCohorts,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 256],
+    pub reserved: [u8; 128],
 }
@@ -4,15 +4,36 @@
 //!
 //! <https://github.com/kinobi-so/kinobi>
 
+pub(crate) mod r#account_meta_data;
+pub(crate) mod r#balance_reconciled_event;
+pub(crate) mod r#cooldown_cohorts;
 pub(crate) mod r#create_metadata_account_args_v3;
 pub(crate) mod r#data_v2;
+pub(crate) mod r#delegation_change;
+pub(crate) mod r#delegation_changed_event;
+pub(crate) mod r#delegation_snapshot;
 pub(crate) mod r#delegation_state;
+pub(crate) mod r#deposit_event;
+pub(crate) mod r#deposit_mode;
+pub(crate) mod r#epoch_stats;
+pub(crate) mod r#fee_changed_event;
+pub(crate) mod r#instruction_data;
+pub(crate) mod r#slashed_event;
 pub(crate) mod r#slot_toggle;
+pub(crate) mod r#slot_toggle_state;
 pub(crate) mod r#update_metadata_account_args_v2;
 pub(crate) mod r#vault_admin_role;
+pub(crate) mod r#vault_event;
+pub(crate) mod r#vault_hook_instruction;
+pub(crate) mod r#withdraw_event;
 pub(crate) mod r#withdrawal_allocation_method;
 
 pub use self::{
-    r#create_metadata_account_args_v3::*, r#data_v2::*, r#delegation_state::*, r#slot_toggle::*,
-    r#update_metadata_account_args_v2::*, r#vault_admin_role::*, r#withdrawal_allocation_method::*,
+    r#account_meta_data::*, r#balance_reconciled_event::*, r#cooldown_cohorts::*,
+    r#create_metadata_account_args_v3::*, r#data_v2::*, r#delegation_change::*,
+    r#delegation_changed_event::*, r#delegation_snapshot::*, r#delegation_state::*,
+    r#deposit_event::*, r#deposit_mode::*, r#epoch_stats::*, r#fee_changed_event::*,
+    r#instruction_data::*, r#slashed_event::*, r#slot_toggle::*, r#slot_toggle_state::*,
+    r#update_metadata_account_args_v2::*, r#vault_admin_role::*, r#vault_event::*,
+    r#vault_hook_instruction::*, r#withdraw_event::*, r#withdrawal_allocation_method::*,
 };
@@ -30,4 +30,6 @@ pub enum VaultAdminRole {
     MintBurnAdmin,
     WithdrawAdmin,
     FeeAdmin,
+    PauseAdmin,
+    MetadataAdmin,
 }
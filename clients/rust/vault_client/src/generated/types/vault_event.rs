@@ -0,0 +1,23 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::generated::types::{
+    BalanceReconciledEvent, DelegationChangedEvent, DepositEvent, FeeChangedEvent, SlashedEvent,
+    WithdrawEvent,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VaultEvent {
+    Deposit(DepositEvent),
+    Withdraw(WithdrawEvent),
+    DelegationChanged(DelegationChangedEvent),
+    Slashed(SlashedEvent),
+    FeeChanged(FeeChangedEvent),
+    BalanceReconciled(BalanceReconciledEvent),
+}
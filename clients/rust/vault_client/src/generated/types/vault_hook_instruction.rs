@@ -0,0 +1,20 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VaultHookInstruction {
+    AfterMint {
+        amount_in: u64,
+        vrt_to_depositor: u64,
+    },
+    AfterBurn {
+        vrt_burned: u64,
+        amount_out: u64,
+    },
+}
@@ -0,0 +1,40 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use solana_program::{decode_error::DecodeError, program_error::ProgramError};
+
+/// Errors returned by the shared core library. The codes are stable and live in their
+/// own range so they never collide with the vault or restaking program errors. The programs log
+/// these and return their own instruction-specific error, so they aren't part of the IDLs. Shank
+/// picks up every enum deriving `thiserror::Error` as a program error, hence the manual impls.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RestakingCoreError {
+    SlotToggleNotInactive = 9000,
+    SlotToggleActivatedAtCreationSlot,
+    SlotToggleNotActive,
+}
+
+impl Display for RestakingCoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for RestakingCoreError {}
+
+impl<T> DecodeError<T> for RestakingCoreError {
+    fn type_of() -> &'static str {
+        "jito::core"
+    }
+}
+
+impl From<RestakingCoreError> for ProgramError {
+    fn from(e: RestakingCoreError) -> Self {
+        Self::Custom(e as u32)
+    }
+}
+
+impl From<RestakingCoreError> for u64 {
+    fn from(e: RestakingCoreError) -> Self {
+        e as Self
+    }
+}
@@ -1,15 +1,19 @@
+use std::{cmp::Ordering, mem::size_of};
+
+use jito_bytemuck::AccountVersion;
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint::ProgramResult,
-    program::{invoke, invoke_signed},
-    program_error::ProgramError,
-    pubkey::Pubkey,
-    rent::Rent,
-    system_instruction,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, system_instruction,
 };
 
+use crate::system::invoke_system;
+
+pub mod error;
 pub mod loader;
 pub mod slot_toggle;
+pub mod system;
+pub mod sysvar;
+pub mod token;
 
 /// Creates a new account or initializes an existing account
 /// # Arguments
@@ -35,7 +39,7 @@ pub fn create_account<'a, 'info>(
     let current_lamports = **new_account.try_borrow_lamports()?;
     if current_lamports == 0 {
         // If there are no lamports in the new account, we create it with the create_account instruction
-        invoke_signed(
+        invoke_system(
             &system_instruction::create_account(
                 payer.key,
                 new_account.key,
@@ -60,13 +64,14 @@ pub fn create_account<'a, 'info>(
             .max(1)
             .saturating_sub(current_lamports);
         if required_lamports > 0 {
-            invoke(
+            invoke_system(
                 &system_instruction::transfer(payer.key, new_account.key, required_lamports),
                 &[payer.clone(), new_account.clone(), system_program.clone()],
+                &[],
             )?;
         }
         // Allocate space.
-        invoke_signed(
+        invoke_system(
             &system_instruction::allocate(new_account.key, space),
             &[new_account.clone(), system_program.clone()],
             &[seeds
@@ -76,7 +81,7 @@ pub fn create_account<'a, 'info>(
                 .as_slice()],
         )?;
         // Assign to the specified program
-        invoke_signed(
+        invoke_system(
             &system_instruction::assign(new_account.key, program_owner),
             &[new_account.clone(), system_program.clone()],
             &[seeds
@@ -113,6 +118,16 @@ pub fn close_program_account<'a>(
     Ok(())
 }
 
+/// Reallocates a program account to `new_size`, leaving it holding exactly the rent-exempt
+/// minimum for its new size. The payer tops up any shortfall and is refunded any excess, so
+/// calls that don't change the rent-exempt minimum don't move any lamports.
+/// # Arguments
+/// * `account` - The program account to reallocate, owned by the calling program
+/// * `new_size` - The new size of the account data
+/// * `payer` - The writable account that pays for, or is refunded, the rent difference
+/// * `rent` - The rent sysvar
+/// # Returns
+/// * `ProgramResult` - The result of the operation
 pub fn realloc<'a, 'info>(
     account: &'a AccountInfo<'info>,
     new_size: usize,
@@ -120,12 +135,261 @@ pub fn realloc<'a, 'info>(
     rent: &Rent,
 ) -> ProgramResult {
     let new_minimum_balance = rent.minimum_balance(new_size);
+    let current_lamports = account.lamports();
+
+    match new_minimum_balance.cmp(&current_lamports) {
+        Ordering::Greater => {
+            invoke_system(
+                &system_instruction::transfer(
+                    payer.key,
+                    account.key,
+                    new_minimum_balance - current_lamports,
+                ),
+                &[payer.clone(), account.clone()],
+                &[],
+            )?;
+        }
+        Ordering::Less => {
+            let refund = current_lamports - new_minimum_balance;
+            **account.try_borrow_mut_lamports()? = new_minimum_balance;
+            let payer_lamports = payer
+                .lamports()
+                .checked_add(refund)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            **payer.try_borrow_mut_lamports()? = payer_lamports;
+        }
+        Ordering::Equal => {}
+    }
 
-    let lamports_diff = new_minimum_balance.saturating_sub(account.lamports());
-    invoke(
-        &system_instruction::transfer(payer.key, account.key, lamports_diff),
-        &[payer.clone(), account.clone()],
-    )?;
-    account.realloc(new_size, false)?;
+    if account.data_len() != new_size {
+        account.realloc(new_size, false)?;
+    }
     Ok(())
 }
+
+/// Migrates a program account to the current layout of `T` in place. The account shall be the size
+/// of the layout at its version, see [`AccountVersion::size_of_version`]. It's grown to fit the
+/// current layout if it's smaller, with the payer covering the rent, then
+/// [`AccountVersion::migrate`] upgrades the data and the current version is recorded in the header.
+/// # Arguments
+/// * `account` - The program account to migrate, owned by the calling program and already checked
+///   to hold a `T`
+/// * `payer` - The writable account that pays for the extra rent
+/// * `rent` - The rent sysvar
+/// # Returns
+/// * `Result<u8, ProgramError>` - The version the account was migrated from
+pub fn migrate_account<'a, 'info, T: AccountVersion>(
+    account: &'a AccountInfo<'info>,
+    payer: &'a AccountInfo<'info>,
+    rent: &Rent,
+) -> Result<u8, ProgramError> {
+    let from_version = T::version(&account.data.borrow())?;
+    if from_version > T::VERSION {
+        msg!(
+            "Account version {} is newer than the program's version {}",
+            from_version,
+            T::VERSION
+        );
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The account shall be the size of the layout at its version, so an account at an unknown
+    // layout isn't reinterpreted
+    let from_size = T::size_of_version(from_version).ok_or_else(|| {
+        msg!("Account version {} can't be migrated", from_version);
+        ProgramError::InvalidAccountData
+    })?;
+    if account.data_len() != from_size {
+        msg!(
+            "Account is {} bytes, expected {} bytes at version {}",
+            account.data_len(),
+            from_size,
+            from_version
+        );
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let size = 8_usize
+        .checked_add(size_of::<T>())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if account.data_len() < size {
+        realloc(account, size, payer, rent)?;
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    T::migrate(&mut data, from_version)?;
+    data[1] = T::VERSION;
+    Ok(from_version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_realloc_same_size_moves_no_lamports() {
+        let rent = Rent::default();
+        let program_id = Pubkey::new_unique();
+        let account_key = Pubkey::new_unique();
+        let payer_key = Pubkey::new_unique();
+        let system_program_id = solana_program::system_program::id();
+
+        let mut account_lamports = rent.minimum_balance(100);
+        let mut account_data = vec![0; 100];
+        let account = AccountInfo::new(
+            &account_key,
+            false,
+            true,
+            &mut account_lamports,
+            &mut account_data,
+            &program_id,
+            false,
+            0,
+        );
+        let mut payer_lamports = 1_000_000_000;
+        let mut payer_data = vec![];
+        let payer = AccountInfo::new(
+            &payer_key,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &system_program_id,
+            false,
+            0,
+        );
+
+        realloc(&account, 100, &payer, &rent).unwrap();
+        assert_eq!(account.lamports(), rent.minimum_balance(100));
+        assert_eq!(payer.lamports(), 1_000_000_000);
+    }
+
+    #[test]
+    fn test_realloc_refunds_excess_lamports() {
+        let rent = Rent::default();
+        let program_id = Pubkey::new_unique();
+        let account_key = Pubkey::new_unique();
+        let payer_key = Pubkey::new_unique();
+        let system_program_id = solana_program::system_program::id();
+
+        let excess = 12_345;
+        let mut account_lamports = rent.minimum_balance(100) + excess;
+        let mut account_data = vec![0; 100];
+        let account = AccountInfo::new(
+            &account_key,
+            false,
+            true,
+            &mut account_lamports,
+            &mut account_data,
+            &program_id,
+            false,
+            0,
+        );
+        let mut payer_lamports = 1_000_000_000;
+        let mut payer_data = vec![];
+        let payer = AccountInfo::new(
+            &payer_key,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &system_program_id,
+            false,
+            0,
+        );
+
+        realloc(&account, 100, &payer, &rent).unwrap();
+        assert_eq!(account.lamports(), rent.minimum_balance(100));
+        assert_eq!(payer.lamports(), 1_000_000_000 + excess);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, bytemuck::Pod, bytemuck::Zeroable)]
+    #[repr(C)]
+    struct TestAccount {
+        value: [u8; 8],
+    }
+
+    impl jito_bytemuck::Discriminator for TestAccount {
+        const DISCRIMINATOR: u8 = 1;
+    }
+
+    impl jito_bytemuck::AccountDeserialize for TestAccount {}
+
+    impl AccountVersion for TestAccount {
+        const VERSION: u8 = 2;
+
+        fn size_of_version(version: u8) -> Option<usize> {
+            match version {
+                // version 1 grew the account from 4 to 8 bytes
+                0 => Some(8 + 4),
+                1 | 2 => Some(8 + size_of::<Self>()),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_migrate_account() {
+        let rent = Rent::default();
+        let program_id = Pubkey::new_unique();
+        let account_key = Pubkey::new_unique();
+        let payer_key = Pubkey::new_unique();
+        let system_program_id = solana_program::system_program::id();
+
+        let size = 8 + size_of::<TestAccount>();
+        let mut account_lamports = rent.minimum_balance(size);
+        let mut account_data = vec![0; size];
+        account_data[0] = 1;
+        account_data[1] = 1;
+        account_data[8] = 42;
+        let account = AccountInfo::new(
+            &account_key,
+            false,
+            true,
+            &mut account_lamports,
+            &mut account_data,
+            &program_id,
+            false,
+            0,
+        );
+        let mut payer_lamports = 1_000_000_000;
+        let mut payer_data = vec![];
+        let payer = AccountInfo::new(
+            &payer_key,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &system_program_id,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            migrate_account::<TestAccount>(&account, &payer, &rent).unwrap(),
+            1
+        );
+        assert_eq!(TestAccount::version(&account.data.borrow()).unwrap(), 2);
+        assert_eq!(account.data.borrow()[8], 42);
+        assert_eq!(payer.lamports(), 1_000_000_000);
+
+        // migrating an account that's already current is a no-op
+        assert_eq!(
+            migrate_account::<TestAccount>(&account, &payer, &rent).unwrap(),
+            2
+        );
+
+        // an account that isn't the size of the layout at its version isn't reinterpreted
+        account.data.borrow_mut()[1] = 0;
+        assert_eq!(
+            migrate_account::<TestAccount>(&account, &payer, &rent),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        account.data.borrow_mut()[1] = 3;
+        assert_eq!(
+            migrate_account::<TestAccount>(&account, &payer, &rent),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
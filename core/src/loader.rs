@@ -60,6 +60,22 @@ pub fn load_token_program(info: &AccountInfo) -> Result<(), ProgramError> {
     Ok(())
 }
 
+/// Loads the account as the associated token account program, returning an error if it is not.
+///
+/// # Arguments
+/// * `info` - The account to load the associated token account program from
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+pub fn load_associated_token_program(info: &AccountInfo) -> Result<(), ProgramError> {
+    if info.key.ne(&spl_associated_token_account::id()) {
+        msg!("Account is not the associated token account program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Ok(())
+}
+
 /// Loads the account as a system account, returning an error if it is not or if it is not writable
 /// while expected to be.
 ///
@@ -7,6 +7,8 @@ use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::types::PodU64;
 use shank::ShankType;
 
+use crate::error::RestakingCoreError;
+
 /// SlotToggle is a state tracker that allows for activation and deactivation of certain features
 /// based on slot time.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
@@ -21,7 +23,7 @@ pub struct SlotToggle {
 }
 
 /// The state of the SlotToggle
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ShankType)]
 pub enum SlotToggleState {
     /// The feature is inactive
     Inactive,
@@ -33,6 +35,54 @@ pub enum SlotToggleState {
     Cooldown,
 }
 
+impl SlotToggleState {
+    /// Get the state of a toggle from its raw slot_added and slot_removed values.
+    /// This allows off-chain consumers holding a deserialized toggle to compute its state
+    /// without duplicating the slot math.
+    ///
+    /// # Arguments
+    /// * `slot_added` - The slot at which the feature was added
+    /// * `slot_removed` - The slot at which the feature was removed
+    /// * `slot` - The slot at which the state is being queried
+    /// * `epoch_length` - The length of an epoch in slots
+    ///
+    /// # Returns
+    /// * `SlotToggleState` - The state of the feature at the given slot
+    pub fn state_at_slot(slot_added: u64, slot_removed: u64, slot: u64, epoch_length: u64) -> Self {
+        let current_epoch = slot.checked_div(epoch_length).unwrap();
+
+        match slot_added.cmp(&slot_removed) {
+            Ordering::Equal => Self::Inactive,
+            Ordering::Less => {
+                let slot_removed_epoch = slot_removed.checked_div(epoch_length).unwrap();
+                if current_epoch > slot_removed_epoch.checked_add(1).unwrap() {
+                    Self::Inactive
+                } else {
+                    Self::Cooldown
+                }
+            }
+            Ordering::Greater => {
+                let slot_added_epoch = slot_added.checked_div(epoch_length).unwrap();
+                if current_epoch > slot_added_epoch.checked_add(1).unwrap() {
+                    Self::Active
+                } else {
+                    Self::WarmUp
+                }
+            }
+        }
+    }
+
+    /// Check if the state is active
+    pub const fn is_active(&self) -> bool {
+        matches!(self, Self::Active)
+    }
+
+    /// Check if the state is active or in cooldown
+    pub const fn is_active_or_cooldown(&self) -> bool {
+        matches!(self, Self::Active | Self::Cooldown)
+    }
+}
+
 impl SlotToggle {
     /// Create a new SlotToggle with the given slot
     /// This sets slot_added and slot_removed to the same value, meaning the feature is inactive upon creation
@@ -63,20 +113,20 @@ impl SlotToggle {
     /// * `epoch_length` - The length of an epoch in slots
     ///
     /// # Returns
-    /// * `bool` - Whether the feature was successfully activated
-    pub fn activate(&mut self, slot: u64, epoch_length: u64) -> bool {
+    /// * `Result<(), RestakingCoreError>` - Why the feature couldn't be activated, if it failed
+    pub fn activate(&mut self, slot: u64, epoch_length: u64) -> Result<(), RestakingCoreError> {
         match self.state(slot, epoch_length) {
             SlotToggleState::Inactive => {
                 if self.slot_added() == slot {
                     // this should only be possible if the feature is being activated for the first time
                     // and the slot is the same as the slot it was created at
-                    false
+                    Err(RestakingCoreError::SlotToggleActivatedAtCreationSlot)
                 } else {
                     self.slot_added = PodU64::from(slot);
-                    true
+                    Ok(())
                 }
             }
-            _ => false,
+            _ => Err(RestakingCoreError::SlotToggleNotInactive),
         }
     }
 
@@ -89,28 +139,25 @@ impl SlotToggle {
     /// * `epoch_length` - The length of an epoch in slots
     ///
     /// # Returns
-    /// * `bool` - Whether the feature was successfully deactivated
-    pub fn deactivate(&mut self, slot: u64, epoch_length: u64) -> bool {
+    /// * `Result<(), RestakingCoreError>` - Why the feature couldn't be deactivated, if it failed
+    pub fn deactivate(&mut self, slot: u64, epoch_length: u64) -> Result<(), RestakingCoreError> {
         match self.state(slot, epoch_length) {
             SlotToggleState::Active => {
                 self.slot_removed = PodU64::from(slot);
-                true
+                Ok(())
             }
-            _ => false,
+            _ => Err(RestakingCoreError::SlotToggleNotActive),
         }
     }
 
     /// Check if the feature is active or in cooldown state at the given slot.
     pub fn is_active_or_cooldown(&self, slot: u64, epoch_length: u64) -> bool {
-        matches!(
-            self.state(slot, epoch_length),
-            SlotToggleState::Active | SlotToggleState::Cooldown
-        )
+        self.state(slot, epoch_length).is_active_or_cooldown()
     }
 
     /// Check if the feature is active at the given slot.
     pub fn is_active(&self, slot: u64, epoch_length: u64) -> bool {
-        matches!(self.state(slot, epoch_length), SlotToggleState::Active)
+        self.state(slot, epoch_length).is_active()
     }
 
     /// Get the state of the feature at the given slot.
@@ -123,30 +170,7 @@ impl SlotToggle {
     /// # Returns
     /// * `SlotToggleState` - The state of the feature at the given slot
     pub fn state(&self, slot: u64, epoch_length: u64) -> SlotToggleState {
-        let current_epoch = slot.checked_div(epoch_length).unwrap();
-
-        let slot_added: u64 = self.slot_added.into();
-        let slot_removed: u64 = self.slot_removed.into();
-
-        match slot_added.cmp(&slot_removed) {
-            Ordering::Equal => SlotToggleState::Inactive,
-            Ordering::Less => {
-                let slot_removed_epoch = slot_removed.checked_div(epoch_length).unwrap();
-                if current_epoch > slot_removed_epoch.checked_add(1).unwrap() {
-                    SlotToggleState::Inactive
-                } else {
-                    SlotToggleState::Cooldown
-                }
-            }
-            Ordering::Greater => {
-                let slot_added_epoch = slot_added.checked_div(epoch_length).unwrap();
-                if current_epoch > slot_added_epoch.checked_add(1).unwrap() {
-                    SlotToggleState::Active
-                } else {
-                    SlotToggleState::WarmUp
-                }
-            }
-        }
+        SlotToggleState::state_at_slot(self.slot_added(), self.slot_removed(), slot, epoch_length)
     }
 }
 
@@ -154,7 +178,10 @@ impl SlotToggle {
 mod tests {
     use jito_bytemuck::types::PodU64;
 
-    use crate::slot_toggle::{SlotToggle, SlotToggleState};
+    use crate::{
+        error::RestakingCoreError,
+        slot_toggle::{SlotToggle, SlotToggleState},
+    };
 
     #[test]
     fn test_slot_toggle_no_padding() {
@@ -196,8 +223,14 @@ mod tests {
         let mut toggle = SlotToggle::new(creation_slot);
 
         // can't transition to activate the same slot it was created at
-        assert!(!toggle.activate(creation_slot, epoch_length));
-        assert!(!toggle.deactivate(creation_slot, epoch_length));
+        assert_eq!(
+            toggle.activate(creation_slot, epoch_length),
+            Err(RestakingCoreError::SlotToggleActivatedAtCreationSlot)
+        );
+        assert_eq!(
+            toggle.deactivate(creation_slot, epoch_length),
+            Err(RestakingCoreError::SlotToggleNotActive)
+        );
     }
 
     #[test]
@@ -216,7 +249,7 @@ mod tests {
 
         // Transition to warming up
         current_slot += 1;
-        assert!(toggle.activate(current_slot, epoch_length));
+        assert_eq!(toggle.activate(current_slot, epoch_length), Ok(()));
         assert_eq!(
             toggle.state(current_slot, epoch_length),
             SlotToggleState::WarmUp
@@ -228,6 +261,14 @@ mod tests {
             toggle.state(current_slot, epoch_length),
             SlotToggleState::WarmUp
         );
+        assert_eq!(
+            toggle.activate(current_slot, epoch_length),
+            Err(RestakingCoreError::SlotToggleNotInactive)
+        );
+        assert_eq!(
+            toggle.deactivate(current_slot, epoch_length),
+            Err(RestakingCoreError::SlotToggleNotActive)
+        );
 
         // Assert active
         current_slot += epoch_length;
@@ -237,7 +278,7 @@ mod tests {
         );
 
         // Assert Deactivate
-        assert!(toggle.deactivate(current_slot, epoch_length));
+        assert_eq!(toggle.deactivate(current_slot, epoch_length), Ok(()));
         assert_eq!(
             toggle.state(current_slot, epoch_length),
             SlotToggleState::Cooldown
@@ -255,4 +296,37 @@ mod tests {
             SlotToggleState::Inactive
         );
     }
+
+    #[test]
+    fn test_state_at_slot_matches_toggle() {
+        let epoch_length = 150;
+        let mut toggle = SlotToggle::new(100);
+        toggle.activate(101, epoch_length).unwrap();
+        toggle.deactivate(401, epoch_length).unwrap();
+
+        for slot in (100..800).step_by(25) {
+            assert_eq!(
+                SlotToggleState::state_at_slot(
+                    toggle.slot_added(),
+                    toggle.slot_removed(),
+                    slot,
+                    epoch_length
+                ),
+                toggle.state(slot, epoch_length)
+            );
+        }
+    }
+
+    #[test]
+    fn test_state_helpers() {
+        assert!(!SlotToggleState::Inactive.is_active());
+        assert!(!SlotToggleState::WarmUp.is_active());
+        assert!(SlotToggleState::Active.is_active());
+        assert!(!SlotToggleState::Cooldown.is_active());
+
+        assert!(!SlotToggleState::Inactive.is_active_or_cooldown());
+        assert!(!SlotToggleState::WarmUp.is_active_or_cooldown());
+        assert!(SlotToggleState::Active.is_active_or_cooldown());
+        assert!(SlotToggleState::Cooldown.is_active_or_cooldown());
+    }
 }
@@ -0,0 +1,147 @@
+//! System program CPIs.
+//!
+//! With the `accounting-only` feature, system instructions aren't invoked but applied directly to
+//! the accounts passed in, the same way [`crate::token`] applies token instructions. Only the
+//! instructions the programs use are supported, and the account lamports are moved out of must
+//! have signed. Account data can't be resized outside the runtime, so allocating swaps in a new
+//! buffer that's leaked, which is fine for the tests and fuzzing the feature is meant for.
+
+#[cfg(all(feature = "accounting-only", target_os = "solana"))]
+compile_error!(
+    "the `accounting-only` feature applies system instructions in memory instead of invoking the system program, it must not be built for the Solana runtime"
+);
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction,
+};
+#[cfg(feature = "accounting-only")]
+use solana_program::{
+    msg,
+    program_error::ProgramError,
+    program_utils::limited_deserialize,
+    system_instruction::{SystemError, SystemInstruction},
+    system_program,
+};
+
+/// Invokes a system program instruction, signed for with `signers_seeds`.
+///
+/// With the `accounting-only` feature, the instruction is applied in memory with
+/// [`process_system_instruction`] instead.
+#[inline(always)]
+pub fn invoke_system(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    #[cfg(not(feature = "accounting-only"))]
+    {
+        solana_program::program::invoke_signed(instruction, account_infos, signers_seeds)
+    }
+    #[cfg(feature = "accounting-only")]
+    {
+        let _ = signers_seeds;
+        process_system_instruction(instruction, account_infos)
+    }
+}
+
+/// Applies a system program instruction directly to the accounts in `account_infos`.
+///
+/// Supports `CreateAccount`, `Transfer`, `Allocate` and `Assign`.
+#[cfg(feature = "accounting-only")]
+pub fn process_system_instruction(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+) -> ProgramResult {
+    let account = |index| find_account(instruction, account_infos, index);
+
+    let system_instruction = limited_deserialize(&instruction.data, 1024)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    match system_instruction {
+        SystemInstruction::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => {
+            let (from, to) = (account(0)?, account(1)?);
+            if to.lamports() > 0 || to.owner.ne(&system_program::id()) {
+                msg!("Account {} is already in use", to.key);
+                return Err(ProgramError::Custom(
+                    SystemError::AccountAlreadyInUse as u32,
+                ));
+            }
+            allocate(to, space)?;
+            transfer_lamports(from, to, lamports)?;
+            to.assign(&owner);
+            Ok(())
+        }
+        SystemInstruction::Transfer { lamports } => {
+            transfer_lamports(account(0)?, account(1)?, lamports)
+        }
+        SystemInstruction::Allocate { space } => allocate(account(0)?, space),
+        SystemInstruction::Assign { owner } => {
+            account(0)?.assign(&owner);
+            Ok(())
+        }
+        _ => {
+            msg!("System instruction is not supported in accounting-only mode");
+            Err(ProgramError::InvalidInstructionData)
+        }
+    }
+}
+
+/// Finds the account info for the instruction's account at `index`
+#[cfg(feature = "accounting-only")]
+fn find_account<'a, 'info>(
+    instruction: &Instruction,
+    account_infos: &'a [AccountInfo<'info>],
+    index: usize,
+) -> Result<&'a AccountInfo<'info>, ProgramError> {
+    let key = instruction
+        .accounts
+        .get(index)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .pubkey;
+    account_infos
+        .iter()
+        .find(|info| info.key.eq(&key))
+        .ok_or(ProgramError::NotEnoughAccountKeys)
+}
+
+/// Gives the empty `account` `space` zeroed bytes in a new, leaked buffer
+#[cfg(feature = "accounting-only")]
+fn allocate(account: &AccountInfo, space: u64) -> ProgramResult {
+    if !account.data_is_empty() {
+        msg!("Account {} is already allocated", account.key);
+        return Err(ProgramError::Custom(
+            SystemError::AccountAlreadyInUse as u32,
+        ));
+    }
+    let space = usize::try_from(space).map_err(|_| ProgramError::InvalidArgument)?;
+    *account.try_borrow_mut_data()? = Box::leak(vec![0; space].into_boxed_slice());
+    Ok(())
+}
+
+/// Moves `lamports` from `from`, which must have signed, to `to`
+#[cfg(feature = "accounting-only")]
+fn transfer_lamports(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    if !from.is_signer {
+        msg!("Account {} did not sign the transfer", from.key);
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if from.key.eq(to.key) {
+        return Ok(());
+    }
+    let from_lamports = from
+        .lamports()
+        .checked_sub(lamports)
+        .ok_or(ProgramError::Custom(
+            SystemError::ResultWithNegativeLamports as u32,
+        ))?;
+    let to_lamports = to
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **from.try_borrow_mut_lamports()? = from_lamports;
+    **to.try_borrow_mut_lamports()? = to_lamports;
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! Sysvar access.
+//!
+//! With the `accounting-only` feature there are no sysvars to read, so the clock is passed in by
+//! whoever processes the instruction with [`with_clock`] and the rent is the default rent.
+
+#[cfg(all(feature = "accounting-only", target_os = "solana"))]
+compile_error!(
+    "the `accounting-only` feature reads the clock passed in with `with_clock` instead of the clock sysvar, it must not be built for the Solana runtime"
+);
+
+#[cfg(feature = "accounting-only")]
+use std::cell::RefCell;
+
+#[cfg(feature = "accounting-only")]
+use solana_program::msg;
+#[cfg(not(feature = "accounting-only"))]
+use solana_program::sysvar::Sysvar;
+use solana_program::{clock::Clock, program_error::ProgramError, rent::Rent};
+
+#[cfg(feature = "accounting-only")]
+thread_local! {
+    static CLOCK: RefCell<Option<Clock>> = const { RefCell::new(None) };
+}
+
+/// Gets the clock sysvar.
+///
+/// With the `accounting-only` feature, gets the clock passed to [`with_clock`] instead.
+#[inline(always)]
+pub fn get_clock() -> Result<Clock, ProgramError> {
+    #[cfg(not(feature = "accounting-only"))]
+    {
+        Clock::get()
+    }
+    #[cfg(feature = "accounting-only")]
+    {
+        CLOCK.with(|clock| clock.borrow().clone()).ok_or_else(|| {
+            msg!("No clock was passed in to process the instruction with");
+            ProgramError::UnsupportedSysvar
+        })
+    }
+}
+
+/// Gets the rent sysvar.
+///
+/// With the `accounting-only` feature, gets the default rent instead.
+#[inline(always)]
+pub fn get_rent() -> Result<Rent, ProgramError> {
+    #[cfg(not(feature = "accounting-only"))]
+    {
+        Rent::get()
+    }
+    #[cfg(feature = "accounting-only")]
+    {
+        Ok(Rent::default())
+    }
+}
+
+/// Runs `f` with `clock` as the clock returned by [`get_clock`]
+#[cfg(feature = "accounting-only")]
+pub fn with_clock<T>(clock: &Clock, f: impl FnOnce() -> T) -> T {
+    let previous = CLOCK.with(|cell| cell.replace(Some(clock.clone())));
+    let result = f();
+    CLOCK.with(|cell| cell.replace(previous));
+    result
+}
+
+#[cfg(all(test, feature = "accounting-only"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_clock() {
+        assert_eq!(get_clock(), Err(ProgramError::UnsupportedSysvar));
+
+        let clock = Clock {
+            slot: 100,
+            ..Clock::default()
+        };
+        let slot = with_clock(&clock, || get_clock().map(|clock| clock.slot));
+        assert_eq!(slot, Ok(100));
+        assert_eq!(get_clock(), Err(ProgramError::UnsupportedSysvar));
+    }
+}
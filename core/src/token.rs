@@ -0,0 +1,596 @@
+//! Token program CPIs.
+//!
+//! With the `accounting-only` feature, token instructions aren't invoked but applied directly to
+//! the token accounts passed in, so the programs' instructions can be fuzzed and property-tested
+//! in pure Rust without a banks client. Only the instructions the programs use are supported, and
+//! only the checks that matter for accounting are made. The owner or delegate moving tokens out of
+//! a token account must have signed, or be a PDA of its owning program signed for with the seeds
+//! passed in, and transfers and burns by a delegate are limited to and use up its delegated amount.
+//! Programs built with the feature don't work on-chain, since they can't write to token accounts.
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction, msg,
+    program_error::ProgramError, program_memory::sol_memset, program_option::COption,
+    program_pack::Pack, pubkey::Pubkey,
+};
+use spl_token::{
+    error::TokenError,
+    instruction::{AuthorityType, TokenInstruction},
+    state::{Account, AccountState, Mint},
+};
+
+/// Invokes a token program instruction, signed for with `signers_seeds`.
+///
+/// With the `accounting-only` feature, the instruction is applied in memory with
+/// [`process_token_instruction`] instead.
+#[inline(always)]
+pub fn invoke_token(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    #[cfg(not(feature = "accounting-only"))]
+    {
+        solana_program::program::invoke_signed(instruction, account_infos, signers_seeds)
+    }
+    #[cfg(feature = "accounting-only")]
+    {
+        process_token_instruction(instruction, account_infos, signers_seeds)
+    }
+}
+
+/// Applies a token program instruction directly to the token accounts in `account_infos`, with the
+/// PDAs derived from `signers_seeds` counting as signers.
+///
+/// Supports `InitializeMint2`, `Transfer`, `MintTo`, `Burn`, `CloseAccount`, `SyncNative` and
+/// setting the mint authority of a mint with `SetAuthority`.
+pub fn process_token_instruction(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account = |index| find_account(instruction, account_infos, index);
+
+    match TokenInstruction::unpack(&instruction.data)? {
+        TokenInstruction::InitializeMint2 {
+            decimals,
+            mint_authority,
+            freeze_authority,
+        } => {
+            let mint_info = account(0)?;
+            let mut mint_data = mint_info.data.borrow_mut();
+            if Mint::unpack_unchecked(&mint_data)?.is_initialized {
+                return Err(TokenError::AlreadyInUse.into());
+            }
+            let mint = Mint {
+                mint_authority: COption::Some(mint_authority),
+                supply: 0,
+                decimals,
+                is_initialized: true,
+                freeze_authority,
+            };
+            Mint::pack(mint, &mut mint_data)
+        }
+        TokenInstruction::Transfer { amount } => {
+            let (source_info, destination_info) = (account(0)?, account(1)?);
+            let mut source = Account::unpack(&source_info.data.borrow())?;
+            check_authority(&mut source, account(2)?, amount, signers_seeds)?;
+            if source.amount < amount {
+                return Err(TokenError::InsufficientFunds.into());
+            }
+            if source_info.key.eq(destination_info.key) {
+                return Ok(());
+            }
+            let mut destination = Account::unpack(&destination_info.data.borrow())?;
+            if source.mint.ne(&destination.mint) {
+                return Err(TokenError::MintMismatch.into());
+            }
+            source.amount -= amount;
+            destination.amount = destination
+                .amount
+                .checked_add(amount)
+                .ok_or(TokenError::Overflow)?;
+            Account::pack(source, &mut source_info.data.borrow_mut())?;
+            Account::pack(destination, &mut destination_info.data.borrow_mut())
+        }
+        TokenInstruction::MintTo { amount } => {
+            let (mint_info, destination_info) = (account(0)?, account(1)?);
+            let mut mint = Mint::unpack(&mint_info.data.borrow())?;
+            if mint.mint_authority != COption::Some(*account(2)?.key) {
+                msg!("Mint authority does not match");
+                return Err(TokenError::OwnerMismatch.into());
+            }
+            let mut destination = Account::unpack(&destination_info.data.borrow())?;
+            if destination.mint.ne(mint_info.key) {
+                return Err(TokenError::MintMismatch.into());
+            }
+            destination.amount = destination
+                .amount
+                .checked_add(amount)
+                .ok_or(TokenError::Overflow)?;
+            mint.supply = mint
+                .supply
+                .checked_add(amount)
+                .ok_or(TokenError::Overflow)?;
+            Account::pack(destination, &mut destination_info.data.borrow_mut())?;
+            Mint::pack(mint, &mut mint_info.data.borrow_mut())
+        }
+        TokenInstruction::Burn { amount } => {
+            let (source_info, mint_info) = (account(0)?, account(1)?);
+            let mut source = Account::unpack(&source_info.data.borrow())?;
+            check_authority(&mut source, account(2)?, amount, signers_seeds)?;
+            if source.mint.ne(mint_info.key) {
+                return Err(TokenError::MintMismatch.into());
+            }
+            if source.amount < amount {
+                return Err(TokenError::InsufficientFunds.into());
+            }
+            let mut mint = Mint::unpack(&mint_info.data.borrow())?;
+            source.amount -= amount;
+            mint.supply = mint
+                .supply
+                .checked_sub(amount)
+                .ok_or(TokenError::Overflow)?;
+            Account::pack(source, &mut source_info.data.borrow_mut())?;
+            Mint::pack(mint, &mut mint_info.data.borrow_mut())
+        }
+        TokenInstruction::CloseAccount => {
+            let (source_info, destination_info) = (account(0)?, account(1)?);
+            let source = Account::unpack(&source_info.data.borrow())?;
+            check_owner(&source, account(2)?.key)?;
+            if source.amount != 0 {
+                return Err(TokenError::NonNativeHasBalance.into());
+            }
+            let lamports = source_info.lamports();
+            **destination_info.lamports.borrow_mut() = destination_info
+                .lamports()
+                .checked_add(lamports)
+                .ok_or(TokenError::Overflow)?;
+            **source_info.lamports.borrow_mut() = 0;
+            let mut source_data = source_info.data.borrow_mut();
+            let data_len = source_data.len();
+            sol_memset(*source_data, 0, data_len);
+            Ok(())
+        }
+        TokenInstruction::SyncNative => {
+            let native_info = account(0)?;
+            let mut native = Account::unpack(&native_info.data.borrow())?;
+            let COption::Some(rent_exempt_reserve) = native.is_native else {
+                return Err(TokenError::NonNativeNotSupported.into());
+            };
+            let amount = native_info
+                .lamports()
+                .checked_sub(rent_exempt_reserve)
+                .ok_or(TokenError::Overflow)?;
+            if amount < native.amount {
+                return Err(TokenError::InvalidState.into());
+            }
+            native.amount = amount;
+            Account::pack(native, &mut native_info.data.borrow_mut())
+        }
+        TokenInstruction::SetAuthority {
+            authority_type: AuthorityType::MintTokens,
+            new_authority,
+        } => {
+            let mint_info = account(0)?;
+            let mut mint = Mint::unpack(&mint_info.data.borrow())?;
+            if mint.mint_authority != COption::Some(*account(1)?.key) {
+                msg!("Mint authority does not match");
+                return Err(TokenError::OwnerMismatch.into());
+            }
+            mint.mint_authority = new_authority;
+            Mint::pack(mint, &mut mint_info.data.borrow_mut())
+        }
+        _ => {
+            msg!("Token instruction is not supported in accounting-only mode");
+            Err(ProgramError::InvalidInstructionData)
+        }
+    }
+}
+
+/// Finds the account info for the instruction's account at `index`
+fn find_account<'a, 'info>(
+    instruction: &Instruction,
+    account_infos: &'a [AccountInfo<'info>],
+    index: usize,
+) -> Result<&'a AccountInfo<'info>, ProgramError> {
+    let key = instruction
+        .accounts
+        .get(index)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .pubkey;
+    account_infos
+        .iter()
+        .find(|info| info.key.eq(&key))
+        .ok_or(ProgramError::NotEnoughAccountKeys)
+}
+
+fn check_owner(account: &Account, authority: &Pubkey) -> ProgramResult {
+    if account.state == AccountState::Frozen {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if account.owner.ne(authority) {
+        msg!("Token account owner does not match");
+        return Err(TokenError::OwnerMismatch.into());
+    }
+    Ok(())
+}
+
+/// Checks `authority` can move `amount` out of `account`, either as its owner or as its delegate,
+/// using up the delegated amount
+fn check_authority(
+    account: &mut Account,
+    authority: &AccountInfo,
+    amount: u64,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    if account.owner.eq(authority.key) || account.delegate != COption::Some(*authority.key) {
+        check_owner(account, authority.key)?;
+        return check_signed(authority, signers_seeds);
+    }
+    if account.state == AccountState::Frozen {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    check_signed(authority, signers_seeds)?;
+    account.delegated_amount = account
+        .delegated_amount
+        .checked_sub(amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+    if account.delegated_amount == 0 {
+        account.delegate = COption::None;
+    }
+    Ok(())
+}
+
+/// Checks `authority` signed the transaction, or is a PDA of its owner signed for with one of
+/// `signers_seeds` the way `invoke_signed` would
+fn check_signed(authority: &AccountInfo, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+    let signed_for = signers_seeds.iter().any(|seeds| {
+        Pubkey::create_program_address(seeds, authority.owner)
+            .is_ok_and(|address| address.eq(authority.key))
+    });
+    if !authority.is_signer && !signed_for {
+        msg!("Token authority did not sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use spl_token::instruction::{
+        burn, close_account, initialize_mint2, mint_to, sync_native, transfer,
+    };
+
+    use super::*;
+
+    struct TestAccount {
+        key: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn new(len: usize) -> Self {
+            Self {
+                key: Pubkey::new_unique(),
+                lamports: 1_000,
+                data: vec![0; len],
+            }
+        }
+
+        fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Self {
+            let mut account = Self::new(Account::LEN);
+            let token_account = Account {
+                mint: *mint,
+                owner: *owner,
+                amount,
+                state: AccountState::Initialized,
+                ..Account::default()
+            };
+            Account::pack(token_account, &mut account.data).unwrap();
+            account
+        }
+
+        fn info(&mut self) -> AccountInfo {
+            self.info_with_signer(false)
+        }
+
+        fn signer_info(&mut self) -> AccountInfo {
+            self.info_with_signer(true)
+        }
+
+        fn info_with_signer(&mut self, is_signer: bool) -> AccountInfo {
+            AccountInfo::new(
+                &self.key,
+                is_signer,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &spl_token::ID,
+                false,
+                0,
+            )
+        }
+    }
+
+    fn amount(info: &AccountInfo) -> u64 {
+        Account::unpack(&info.data.borrow()).unwrap().amount
+    }
+
+    fn supply(info: &AccountInfo) -> u64 {
+        Mint::unpack(&info.data.borrow()).unwrap().supply
+    }
+
+    #[test]
+    fn test_mint_transfer_burn_close() {
+        let authority = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut mint = TestAccount::new(Mint::LEN);
+        let mut source = TestAccount::token_account(&mint.key, &owner, 0);
+        let mut destination = TestAccount::token_account(&mint.key, &Pubkey::new_unique(), 0);
+        let mut authority_account = TestAccount::new(0);
+        authority_account.key = authority;
+        let mut owner_account = TestAccount::new(0);
+        owner_account.key = owner;
+
+        let mint_info = mint.info();
+        let source_info = source.info();
+        let destination_info = destination.info();
+        let authority_info = authority_account.info();
+        let owner_info = owner_account.signer_info();
+        let infos = [
+            mint_info.clone(),
+            source_info.clone(),
+            destination_info.clone(),
+            authority_info.clone(),
+            owner_info.clone(),
+        ];
+
+        process_token_instruction(
+            &initialize_mint2(&spl_token::ID, mint_info.key, &authority, None, 9).unwrap(),
+            &infos,
+            &[],
+        )
+        .unwrap();
+        process_token_instruction(
+            &mint_to(
+                &spl_token::ID,
+                mint_info.key,
+                source_info.key,
+                &authority,
+                &[],
+                100,
+            )
+            .unwrap(),
+            &infos,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(amount(&source_info), 100);
+        assert_eq!(supply(&mint_info), 100);
+
+        process_token_instruction(
+            &transfer(
+                &spl_token::ID,
+                source_info.key,
+                destination_info.key,
+                &owner,
+                &[],
+                40,
+            )
+            .unwrap(),
+            &infos,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(amount(&source_info), 60);
+        assert_eq!(amount(&destination_info), 40);
+
+        process_token_instruction(
+            &burn(
+                &spl_token::ID,
+                source_info.key,
+                mint_info.key,
+                &owner,
+                &[],
+                60,
+            )
+            .unwrap(),
+            &infos,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(amount(&source_info), 0);
+        assert_eq!(supply(&mint_info), 40);
+
+        process_token_instruction(
+            &close_account(&spl_token::ID, source_info.key, owner_info.key, &owner, &[]).unwrap(),
+            &infos,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(source_info.lamports(), 0);
+        assert_eq!(owner_info.lamports(), 2_000);
+        assert!(source_info.data.borrow().iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn test_transfer_checks() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let mut source = TestAccount::token_account(&mint, &owner, 10);
+        let mut destination = TestAccount::token_account(&mint, &Pubkey::new_unique(), 0);
+        let mut other_mint = TestAccount::token_account(&Pubkey::new_unique(), &owner, 0);
+        let mut owner_account = TestAccount::new(0);
+        owner_account.key = owner;
+
+        let source_info = source.info();
+        let destination_info = destination.info();
+        let other_mint_info = other_mint.info();
+        let owner_info = owner_account.signer_info();
+        let infos = [
+            source_info.clone(),
+            destination_info.clone(),
+            other_mint_info.clone(),
+            owner_info.clone(),
+        ];
+
+        let transfer_ix = |destination: &Pubkey, authority: &Pubkey, amount: u64| {
+            transfer(
+                &spl_token::ID,
+                source_info.key,
+                destination,
+                authority,
+                &[],
+                amount,
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            process_token_instruction(&transfer_ix(destination_info.key, &owner, 11), &infos, &[]),
+            Err(TokenError::InsufficientFunds.into())
+        );
+        assert_eq!(
+            process_token_instruction(
+                &transfer_ix(destination_info.key, destination_info.key, 1),
+                &infos,
+                &[]
+            ),
+            Err(TokenError::OwnerMismatch.into())
+        );
+        assert_eq!(
+            process_token_instruction(&transfer_ix(other_mint_info.key, &owner, 1), &infos, &[]),
+            Err(TokenError::MintMismatch.into())
+        );
+        assert_eq!(
+            process_token_instruction(&transfer_ix(&Pubkey::new_unique(), &owner, 1), &infos, &[]),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+
+        // the owner must have signed
+        let mut unsigned_owner_info = owner_info.clone();
+        unsigned_owner_info.is_signer = false;
+        let unsigned_infos = [
+            source_info.clone(),
+            destination_info.clone(),
+            unsigned_owner_info,
+        ];
+        assert_eq!(
+            process_token_instruction(
+                &transfer_ix(destination_info.key, &owner, 1),
+                &unsigned_infos,
+                &[]
+            ),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+        assert_eq!(amount(&source_info), 10);
+    }
+
+    #[test]
+    fn test_delegate_transfer_burn() {
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let mut mint = TestAccount::new(Mint::LEN);
+        let mut source = TestAccount::token_account(&mint.key, &owner, 100);
+        let mut token_account = Account::unpack(&source.data).unwrap();
+        token_account.delegate = COption::Some(delegate);
+        token_account.delegated_amount = 50;
+        Account::pack(token_account, &mut source.data).unwrap();
+        let mint_account = Mint {
+            supply: 100,
+            is_initialized: true,
+            ..Mint::default()
+        };
+        Mint::pack(mint_account, &mut mint.data).unwrap();
+        let mut destination = TestAccount::token_account(&mint.key, &Pubkey::new_unique(), 0);
+        let mut delegate_account = TestAccount::new(0);
+        delegate_account.key = delegate;
+
+        let mint_info = mint.info();
+        let source_info = source.info();
+        let destination_info = destination.info();
+        let delegate_info = delegate_account.signer_info();
+        let infos = [
+            mint_info.clone(),
+            source_info.clone(),
+            destination_info.clone(),
+            delegate_info.clone(),
+        ];
+
+        let transfer_ix = |amount: u64| {
+            transfer(
+                &spl_token::ID,
+                source_info.key,
+                destination_info.key,
+                &delegate,
+                &[],
+                amount,
+            )
+            .unwrap()
+        };
+        let burn_ix = |amount: u64| {
+            burn(
+                &spl_token::ID,
+                source_info.key,
+                mint_info.key,
+                &delegate,
+                &[],
+                amount,
+            )
+            .unwrap()
+        };
+
+        process_token_instruction(&transfer_ix(20), &infos, &[]).unwrap();
+        process_token_instruction(&burn_ix(20), &infos, &[]).unwrap();
+        assert_eq!(amount(&source_info), 60);
+        assert_eq!(amount(&destination_info), 20);
+        assert_eq!(supply(&mint_info), 80);
+
+        // the delegate can't move more than its delegated amount
+        assert_eq!(
+            process_token_instruction(&burn_ix(11), &infos, &[]),
+            Err(TokenError::InsufficientFunds.into())
+        );
+        process_token_instruction(&burn_ix(10), &infos, &[]).unwrap();
+        let token_account = Account::unpack(&source_info.data.borrow()).unwrap();
+        assert_eq!(token_account.delegate, COption::None);
+        assert_eq!(token_account.delegated_amount, 0);
+        assert_eq!(
+            process_token_instruction(&transfer_ix(1), &infos, &[]),
+            Err(TokenError::OwnerMismatch.into())
+        );
+    }
+
+    #[test]
+    fn test_sync_native() {
+        let owner = Pubkey::new_unique();
+        let mut native = TestAccount::token_account(&spl_token::native_mint::ID, &owner, 0);
+        let mut token_account = Account::unpack(&native.data).unwrap();
+        token_account.is_native = COption::Some(1_000);
+        Account::pack(token_account, &mut native.data).unwrap();
+        let mut non_native = TestAccount::token_account(&Pubkey::new_unique(), &owner, 0);
+
+        let native_info = native.info();
+        let non_native_info = non_native.info();
+        let infos = [native_info.clone(), non_native_info.clone()];
+
+        **native_info.lamports.borrow_mut() += 500;
+        process_token_instruction(
+            &sync_native(&spl_token::ID, native_info.key).unwrap(),
+            &infos,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(amount(&native_info), 500);
+
+        assert_eq!(
+            process_token_instruction(
+                &sync_native(&spl_token::ID, non_native_info.key).unwrap(),
+                &infos,
+                &[]
+            ),
+            Err(TokenError::NonNativeNotSupported.into())
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Decodes restaking and vault program accounts into the rows stored by the indexer.
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_restaking_client::programs::JITO_RESTAKING_ID;
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_vault_client::programs::JITO_VAULT_ID;
+use jito_vault_core::{
+    vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use serde::Serialize;
+use solana_program::pubkey::Pubkey;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VaultRow {
+    pub pubkey: String,
+    pub base: String,
+    pub admin: String,
+    pub vrt_mint: String,
+    pub supported_mint: String,
+    pub capacity: u64,
+    pub tokens_deposited: u64,
+    pub vrt_supply: u64,
+    pub staked_amount: u64,
+    pub enqueued_for_cooldown_amount: u64,
+    pub cooling_down_amount: u64,
+    pub vrt_enqueued_for_cooldown_amount: u64,
+    pub vrt_cooling_down_amount: u64,
+    pub vrt_ready_to_claim_amount: u64,
+    pub operator_count: u64,
+    pub is_paused: bool,
+    pub slot: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OperatorRow {
+    pub pubkey: String,
+    pub base: String,
+    pub admin: String,
+    pub index: u64,
+    pub ncn_count: u64,
+    pub vault_count: u64,
+    pub slot: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NcnRow {
+    pub pubkey: String,
+    pub base: String,
+    pub admin: String,
+    pub index: u64,
+    pub operator_count: u64,
+    pub vault_count: u64,
+    pub slot: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VaultOperatorDelegationRow {
+    pub pubkey: String,
+    pub vault: String,
+    pub operator: String,
+    pub staked_amount: u64,
+    pub enqueued_for_cooldown_amount: u64,
+    pub cooling_down_amount: u64,
+    pub last_update_slot: u64,
+    pub slot: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VaultStakerWithdrawalTicketRow {
+    pub pubkey: String,
+    pub vault: String,
+    pub staker: String,
+    pub vrt_amount: u64,
+    pub slot_unstaked: u64,
+    pub slot: u64,
+}
+
+/// A program account the indexer knows how to store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexedAccount {
+    Vault(VaultRow),
+    Operator(OperatorRow),
+    Ncn(NcnRow),
+    VaultOperatorDelegation(VaultOperatorDelegationRow),
+    VaultStakerWithdrawalTicket(VaultStakerWithdrawalTicketRow),
+}
+
+impl IndexedAccount {
+    /// Decodes an account owned by the restaking or vault program.
+    ///
+    /// # Returns
+    /// * `Option<IndexedAccount>` - None if the account isn't one the indexer tracks
+    pub fn decode(pubkey: &Pubkey, owner: &Pubkey, data: &[u8], slot: u64) -> Option<Self> {
+        let pubkey = pubkey.to_string();
+        // match on the discriminator first, try_from_slice_unchecked logs on a mismatch
+        let discriminator = *data.first()?;
+        if owner.eq(&JITO_VAULT_ID) {
+            if discriminator == Vault::DISCRIMINATOR {
+                let vault = Vault::try_from_slice_unchecked(data).ok()?;
+                return Some(Self::Vault(VaultRow {
+                    pubkey,
+                    base: vault.base.to_string(),
+                    admin: vault.admin.to_string(),
+                    vrt_mint: vault.vrt_mint.to_string(),
+                    supported_mint: vault.supported_mint.to_string(),
+                    capacity: vault.capacity(),
+                    tokens_deposited: vault.tokens_deposited(),
+                    vrt_supply: vault.vrt_supply(),
+                    staked_amount: vault.delegation_state.staked_amount(),
+                    enqueued_for_cooldown_amount: vault
+                        .delegation_state
+                        .enqueued_for_cooldown_amount(),
+                    cooling_down_amount: vault.delegation_state.cooling_down_amount(),
+                    vrt_enqueued_for_cooldown_amount: vault.vrt_enqueued_for_cooldown_amount(),
+                    vrt_cooling_down_amount: vault.vrt_cooling_down_amount(),
+                    vrt_ready_to_claim_amount: vault.vrt_ready_to_claim_amount(),
+                    operator_count: vault.operator_count(),
+                    is_paused: vault.is_paused(),
+                    slot,
+                }));
+            }
+            if discriminator == VaultOperatorDelegation::DISCRIMINATOR {
+                let delegation = VaultOperatorDelegation::try_from_slice_unchecked(data).ok()?;
+                return Some(Self::VaultOperatorDelegation(VaultOperatorDelegationRow {
+                    pubkey,
+                    vault: delegation.vault.to_string(),
+                    operator: delegation.operator.to_string(),
+                    staked_amount: delegation.delegation_state.staked_amount(),
+                    enqueued_for_cooldown_amount: delegation
+                        .delegation_state
+                        .enqueued_for_cooldown_amount(),
+                    cooling_down_amount: delegation.delegation_state.cooling_down_amount(),
+                    last_update_slot: delegation.last_update_slot(),
+                    slot,
+                }));
+            }
+            if discriminator == VaultStakerWithdrawalTicket::DISCRIMINATOR {
+                let ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked(data).ok()?;
+                return Some(Self::VaultStakerWithdrawalTicket(
+                    VaultStakerWithdrawalTicketRow {
+                        pubkey,
+                        vault: ticket.vault.to_string(),
+                        staker: ticket.staker.to_string(),
+                        vrt_amount: ticket.vrt_amount(),
+                        slot_unstaked: ticket.slot_unstaked(),
+                        slot,
+                    },
+                ));
+            }
+        } else if owner.eq(&JITO_RESTAKING_ID) {
+            if discriminator == Operator::DISCRIMINATOR {
+                let operator = Operator::try_from_slice_unchecked(data).ok()?;
+                return Some(Self::Operator(OperatorRow {
+                    pubkey,
+                    base: operator.base.to_string(),
+                    admin: operator.admin.to_string(),
+                    index: operator.index(),
+                    ncn_count: operator.ncn_count(),
+                    vault_count: operator.vault_count(),
+                    slot,
+                }));
+            }
+            if discriminator == Ncn::DISCRIMINATOR {
+                let ncn = Ncn::try_from_slice_unchecked(data).ok()?;
+                return Some(Self::Ncn(NcnRow {
+                    pubkey,
+                    base: ncn.base.to_string(),
+                    admin: ncn.admin.to_string(),
+                    index: ncn.index(),
+                    operator_count: ncn.operator_count(),
+                    vault_count: ncn.vault_count(),
+                    slot,
+                }));
+            }
+        }
+        None
+    }
+
+    pub const fn account_type(&self) -> &'static str {
+        match self {
+            Self::Vault(_) => "vault",
+            Self::Operator(_) => "operator",
+            Self::Ncn(_) => "ncn",
+            Self::VaultOperatorDelegation(_) => "vault_operator_delegation",
+            Self::VaultStakerWithdrawalTicket(_) => "vault_staker_withdrawal_ticket",
+        }
+    }
+}
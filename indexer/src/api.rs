@@ -0,0 +1,108 @@
+//! The REST API served to front-ends.
+//!
+//! - `GET /vaults`: all vaults
+//! - `GET /vaults/:vault/withdrawal_queue`: the withdrawal tickets and VRT cooldown buckets of a vault
+//! - `GET /operators`: all operators
+//! - `GET /operators/:operator/exposure`: the assets delegated to an operator across all vaults
+//! - `GET /ncns`: all NCNs
+//! - `GET /events?limit=100`: the most recent account updates and closures
+use std::{str::FromStr, sync::Arc};
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use log::error;
+use serde::Deserialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    accounts::{NcnRow, OperatorRow, VaultRow},
+    db::{Event, OperatorExposure, Store, WithdrawalQueue},
+    IndexerError,
+};
+
+const DEFAULT_EVENTS_LIMIT: u64 = 100;
+const MAX_EVENTS_LIMIT: u64 = 1_000;
+
+pub fn router(store: Arc<Store>) -> Router {
+    Router::new()
+        .route("/vaults", get(vaults))
+        .route("/vaults/:vault/withdrawal_queue", get(withdrawal_queue))
+        .route("/operators", get(operators))
+        .route("/operators/:operator/exposure", get(operator_exposure))
+        .route("/ncns", get(ncns))
+        .route("/events", get(events))
+        .with_state(store)
+}
+
+pub struct ApiError(StatusCode, String);
+
+impl From<IndexerError> for ApiError {
+    fn from(e: IndexerError) -> Self {
+        error!("Error serving request: {}", e);
+        Self(StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, self.1).into_response()
+    }
+}
+
+fn parse_pubkey(pubkey: &str) -> Result<String, ApiError> {
+    Pubkey::from_str(pubkey)
+        .map(|pubkey| pubkey.to_string())
+        .map_err(|_| ApiError(StatusCode::BAD_REQUEST, format!("invalid pubkey {pubkey}")))
+}
+
+async fn vaults(State(store): State<Arc<Store>>) -> Result<Json<Vec<VaultRow>>, ApiError> {
+    Ok(Json(store.vaults()?))
+}
+
+async fn withdrawal_queue(
+    State(store): State<Arc<Store>>,
+    Path(vault): Path<String>,
+) -> Result<Json<WithdrawalQueue>, ApiError> {
+    let vault = parse_pubkey(&vault)?;
+    store
+        .withdrawal_queue(&vault)?
+        .map(Json)
+        .ok_or_else(|| ApiError(StatusCode::NOT_FOUND, format!("vault {vault} not found")))
+}
+
+async fn operators(State(store): State<Arc<Store>>) -> Result<Json<Vec<OperatorRow>>, ApiError> {
+    Ok(Json(store.operators()?))
+}
+
+async fn operator_exposure(
+    State(store): State<Arc<Store>>,
+    Path(operator): Path<String>,
+) -> Result<Json<OperatorExposure>, ApiError> {
+    let operator = parse_pubkey(&operator)?;
+    Ok(Json(store.operator_exposure(&operator)?))
+}
+
+async fn ncns(State(store): State<Arc<Store>>) -> Result<Json<Vec<NcnRow>>, ApiError> {
+    Ok(Json(store.ncns()?))
+}
+
+#[derive(Deserialize)]
+struct EventsQuery {
+    limit: Option<u64>,
+}
+
+async fn events(
+    State(store): State<Arc<Store>>,
+    Query(query): Query<EventsQuery>,
+) -> Result<Json<Vec<Event>>, ApiError> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_EVENTS_LIMIT)
+        .min(MAX_EVENTS_LIMIT);
+    Ok(Json(store.events(limit)?))
+}
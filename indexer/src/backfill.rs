@@ -0,0 +1,61 @@
+//! Loads the current state of every indexed account with getProgramAccounts. Geyser only streams
+//! changes, so this runs on startup and after every reconnect to fill in what was missed.
+use std::collections::HashSet;
+
+use jito_restaking_client::programs::JITO_RESTAKING_ID;
+use jito_vault_client::programs::JITO_VAULT_ID;
+use log::info;
+use solana_account_decoder::UiAccountEncoding;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+
+use crate::{accounts::IndexedAccount, db::Store, IndexerError};
+
+pub async fn backfill(rpc_client: &RpcClient, store: &Store) -> Result<(), IndexerError> {
+    let mut seen = HashSet::new();
+    let mut slot = 0;
+    for program_id in [JITO_VAULT_ID, JITO_RESTAKING_ID] {
+        slot = rpc_client.get_slot().await?;
+        let accounts = rpc_client
+            .get_program_accounts_with_config(
+                &program_id,
+                RpcProgramAccountsConfig {
+                    filters: None,
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        data_slice: None,
+                        commitment: None,
+                        min_context_slot: None,
+                    },
+                    with_context: None,
+                },
+            )
+            .await?;
+
+        let mut indexed = 0;
+        for (pubkey, account) in accounts.iter() {
+            if let Some(account) =
+                IndexedAccount::decode(pubkey, &account.owner, &account.data, slot)
+            {
+                store.upsert(&account)?;
+                seen.insert(pubkey.to_string());
+                indexed += 1;
+            }
+        }
+        info!(
+            "Backfilled {} of {} accounts owned by {} at slot {}",
+            indexed,
+            accounts.len(),
+            program_id,
+            slot
+        );
+    }
+
+    // accounts closed while the indexer wasn't streaming
+    for pubkey in store.pubkeys()? {
+        if !seen.contains(&pubkey) {
+            store.remove(&pubkey, slot)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::{net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
+
+use clap::Parser;
+use env_logger::Env;
+use jito_restaking_indexer::{api, backfill::backfill, db::Store, geyser};
+use log::{error, info};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+
+#[derive(Parser)]
+#[command(
+    author,
+    version,
+    about = "Indexes restaking and vault accounts and serves them over REST"
+)]
+struct Args {
+    /// RPC URL used to backfill accounts on startup and after reconnecting
+    #[arg(long)]
+    rpc_url: String,
+
+    /// Yellowstone Geyser gRPC endpoint
+    #[arg(long)]
+    geyser_url: String,
+
+    /// Yellowstone Geyser gRPC x-token
+    #[arg(long)]
+    x_token: Option<String>,
+
+    /// Path to the SQLite database
+    #[arg(long, default_value = "restaking-indexer.sqlite")]
+    db_path: PathBuf,
+
+    /// Address to serve the REST API on
+    #[arg(long, default_value = "0.0.0.0:8080")]
+    bind_address: SocketAddr,
+
+    /// Seconds to wait before reconnecting after the Geyser stream fails
+    #[arg(long, default_value_t = 5)]
+    reconnect_delay_secs: u64,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(Env::default().default_filter_or("info")).init();
+    let args = Args::parse();
+
+    let store = Arc::new(Store::open(&args.db_path)?);
+
+    let indexer_store = store.clone();
+    tokio::spawn(async move {
+        let rpc_client =
+            RpcClient::new_with_commitment(args.rpc_url, CommitmentConfig::confirmed());
+        loop {
+            // an update landing between the backfill and the subscription is only picked up
+            // the next time that account changes
+            if let Err(e) = backfill(&rpc_client, &indexer_store).await {
+                error!("Error backfilling accounts: {}", e);
+            } else if let Err(e) = geyser::stream(
+                indexer_store.clone(),
+                args.geyser_url.clone(),
+                args.x_token.clone(),
+            )
+            .await
+            {
+                error!("Error streaming Geyser updates: {}", e);
+            }
+            tokio::time::sleep(Duration::from_secs(args.reconnect_delay_secs)).await;
+        }
+    });
+
+    info!("Serving REST API on {}", args.bind_address);
+    let listener = tokio::net::TcpListener::bind(args.bind_address).await?;
+    axum::serve(listener, api::router(store)).await?;
+    Ok(())
+}
@@ -0,0 +1,582 @@
+//! SQLite storage for indexed accounts.
+//!
+//! u64 amounts are stored bit-cast into SQLite's signed 64-bit INTEGER and aggregated in Rust,
+//! so values above i64::MAX (e.g. an unlimited vault capacity) round-trip unchanged.
+use std::{
+    path::Path,
+    sync::{Mutex, MutexGuard},
+};
+
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::Serialize;
+
+use crate::{
+    accounts::{IndexedAccount, NcnRow, OperatorRow, VaultOperatorDelegationRow, VaultRow},
+    IndexerError,
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS vaults (
+    pubkey TEXT PRIMARY KEY,
+    base TEXT NOT NULL,
+    admin TEXT NOT NULL,
+    vrt_mint TEXT NOT NULL,
+    supported_mint TEXT NOT NULL,
+    capacity INTEGER NOT NULL,
+    tokens_deposited INTEGER NOT NULL,
+    vrt_supply INTEGER NOT NULL,
+    staked_amount INTEGER NOT NULL,
+    enqueued_for_cooldown_amount INTEGER NOT NULL,
+    cooling_down_amount INTEGER NOT NULL,
+    vrt_enqueued_for_cooldown_amount INTEGER NOT NULL,
+    vrt_cooling_down_amount INTEGER NOT NULL,
+    vrt_ready_to_claim_amount INTEGER NOT NULL,
+    operator_count INTEGER NOT NULL,
+    is_paused INTEGER NOT NULL,
+    slot INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS operators (
+    pubkey TEXT PRIMARY KEY,
+    base TEXT NOT NULL,
+    admin TEXT NOT NULL,
+    idx INTEGER NOT NULL,
+    ncn_count INTEGER NOT NULL,
+    vault_count INTEGER NOT NULL,
+    slot INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS ncns (
+    pubkey TEXT PRIMARY KEY,
+    base TEXT NOT NULL,
+    admin TEXT NOT NULL,
+    idx INTEGER NOT NULL,
+    operator_count INTEGER NOT NULL,
+    vault_count INTEGER NOT NULL,
+    slot INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS vault_operator_delegations (
+    pubkey TEXT PRIMARY KEY,
+    vault TEXT NOT NULL,
+    operator TEXT NOT NULL,
+    staked_amount INTEGER NOT NULL,
+    enqueued_for_cooldown_amount INTEGER NOT NULL,
+    cooling_down_amount INTEGER NOT NULL,
+    last_update_slot INTEGER NOT NULL,
+    slot INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS vault_operator_delegations_operator
+    ON vault_operator_delegations (operator);
+CREATE TABLE IF NOT EXISTS vault_staker_withdrawal_tickets (
+    pubkey TEXT PRIMARY KEY,
+    vault TEXT NOT NULL,
+    staker TEXT NOT NULL,
+    vrt_amount INTEGER NOT NULL,
+    slot_unstaked INTEGER NOT NULL,
+    slot INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS vault_staker_withdrawal_tickets_vault
+    ON vault_staker_withdrawal_tickets (vault);
+CREATE TABLE IF NOT EXISTS events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    slot INTEGER NOT NULL,
+    pubkey TEXT NOT NULL,
+    account_type TEXT NOT NULL,
+    kind TEXT NOT NULL
+);
+";
+
+const TABLES: [&str; 5] = [
+    "vaults",
+    "operators",
+    "ncns",
+    "vault_operator_delegations",
+    "vault_staker_withdrawal_tickets",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OperatorExposure {
+    pub operator: String,
+    pub staked_amount: u64,
+    pub enqueued_for_cooldown_amount: u64,
+    pub cooling_down_amount: u64,
+    pub delegations: Vec<VaultOperatorDelegationRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WithdrawalQueue {
+    pub vault: String,
+    pub ticket_count: u64,
+    pub ticket_vrt_amount: u64,
+    pub vrt_enqueued_for_cooldown_amount: u64,
+    pub vrt_cooling_down_amount: u64,
+    pub vrt_ready_to_claim_amount: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Event {
+    pub id: i64,
+    pub slot: u64,
+    pub pubkey: String,
+    pub account_type: String,
+    pub kind: String,
+}
+
+pub struct Store {
+    connection: Mutex<Connection>,
+}
+
+#[inline(always)]
+const fn to_sql(value: u64) -> i64 {
+    value as i64
+}
+
+#[inline(always)]
+fn from_sql(row: &Row, idx: usize) -> rusqlite::Result<u64> {
+    Ok(row.get::<_, i64>(idx)? as u64)
+}
+
+impl Store {
+    pub fn open(path: &Path) -> Result<Self, IndexerError> {
+        Self::new(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, IndexerError> {
+        Self::new(Connection::open_in_memory()?)
+    }
+
+    fn new(connection: Connection) -> Result<Self, IndexerError> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    fn connection(&self) -> MutexGuard<'_, Connection> {
+        // a panic while holding the lock can't leave a half-applied write behind,
+        // every write is a single transaction
+        self.connection
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Stores the latest state of an account and records an event for it
+    pub fn upsert(&self, account: &IndexedAccount) -> Result<(), IndexerError> {
+        let mut connection = self.connection();
+        let tx = connection.transaction()?;
+        let (pubkey, slot) = match account {
+            IndexedAccount::Vault(v) => {
+                tx.execute(
+                    "INSERT OR REPLACE INTO vaults VALUES
+                     (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
+                    params![
+                        v.pubkey,
+                        v.base,
+                        v.admin,
+                        v.vrt_mint,
+                        v.supported_mint,
+                        to_sql(v.capacity),
+                        to_sql(v.tokens_deposited),
+                        to_sql(v.vrt_supply),
+                        to_sql(v.staked_amount),
+                        to_sql(v.enqueued_for_cooldown_amount),
+                        to_sql(v.cooling_down_amount),
+                        to_sql(v.vrt_enqueued_for_cooldown_amount),
+                        to_sql(v.vrt_cooling_down_amount),
+                        to_sql(v.vrt_ready_to_claim_amount),
+                        to_sql(v.operator_count),
+                        v.is_paused,
+                        to_sql(v.slot),
+                    ],
+                )?;
+                (&v.pubkey, v.slot)
+            }
+            IndexedAccount::Operator(o) => {
+                tx.execute(
+                    "INSERT OR REPLACE INTO operators VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    params![
+                        o.pubkey,
+                        o.base,
+                        o.admin,
+                        to_sql(o.index),
+                        to_sql(o.ncn_count),
+                        to_sql(o.vault_count),
+                        to_sql(o.slot),
+                    ],
+                )?;
+                (&o.pubkey, o.slot)
+            }
+            IndexedAccount::Ncn(n) => {
+                tx.execute(
+                    "INSERT OR REPLACE INTO ncns VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    params![
+                        n.pubkey,
+                        n.base,
+                        n.admin,
+                        to_sql(n.index),
+                        to_sql(n.operator_count),
+                        to_sql(n.vault_count),
+                        to_sql(n.slot),
+                    ],
+                )?;
+                (&n.pubkey, n.slot)
+            }
+            IndexedAccount::VaultOperatorDelegation(d) => {
+                tx.execute(
+                    "INSERT OR REPLACE INTO vault_operator_delegations VALUES
+                     (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    params![
+                        d.pubkey,
+                        d.vault,
+                        d.operator,
+                        to_sql(d.staked_amount),
+                        to_sql(d.enqueued_for_cooldown_amount),
+                        to_sql(d.cooling_down_amount),
+                        to_sql(d.last_update_slot),
+                        to_sql(d.slot),
+                    ],
+                )?;
+                (&d.pubkey, d.slot)
+            }
+            IndexedAccount::VaultStakerWithdrawalTicket(t) => {
+                tx.execute(
+                    "INSERT OR REPLACE INTO vault_staker_withdrawal_tickets VALUES
+                     (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![
+                        t.pubkey,
+                        t.vault,
+                        t.staker,
+                        to_sql(t.vrt_amount),
+                        to_sql(t.slot_unstaked),
+                        to_sql(t.slot),
+                    ],
+                )?;
+                (&t.pubkey, t.slot)
+            }
+        };
+        tx.execute(
+            "INSERT INTO events (slot, pubkey, account_type, kind) VALUES (?1, ?2, ?3, 'update')",
+            params![to_sql(slot), pubkey, account.account_type()],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Removes a closed account from whichever table it was indexed in
+    ///
+    /// # Returns
+    /// * `bool` - Whether the account was indexed
+    pub fn remove(&self, pubkey: &str, slot: u64) -> Result<bool, IndexerError> {
+        let mut connection = self.connection();
+        let tx = connection.transaction()?;
+        let mut removed_from = None;
+        for table in TABLES {
+            if tx.execute(&format!("DELETE FROM {table} WHERE pubkey = ?1"), [pubkey])? > 0 {
+                removed_from = Some(table);
+            }
+        }
+        if let Some(table) = removed_from {
+            tx.execute(
+                "INSERT INTO events (slot, pubkey, account_type, kind) VALUES (?1, ?2, ?3, 'close')",
+                params![to_sql(slot), pubkey, table.trim_end_matches('s')],
+            )?;
+        }
+        tx.commit()?;
+        Ok(removed_from.is_some())
+    }
+
+    /// The pubkeys of all indexed accounts
+    pub fn pubkeys(&self) -> Result<Vec<String>, IndexerError> {
+        let connection = self.connection();
+        let mut pubkeys = Vec::new();
+        for table in TABLES {
+            let mut stmt = connection.prepare(&format!("SELECT pubkey FROM {table}"))?;
+            for pubkey in stmt.query_map([], |row| row.get(0))? {
+                pubkeys.push(pubkey?);
+            }
+        }
+        Ok(pubkeys)
+    }
+
+    /// The pubkeys of all indexed withdrawal tickets, watched by pubkey so their closure is seen
+    pub fn withdrawal_ticket_pubkeys(&self) -> Result<Vec<String>, IndexerError> {
+        let connection = self.connection();
+        let mut stmt = connection.prepare("SELECT pubkey FROM vault_staker_withdrawal_tickets")?;
+        let pubkeys = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+        Ok(pubkeys)
+    }
+
+    pub fn vaults(&self) -> Result<Vec<VaultRow>, IndexerError> {
+        let connection = self.connection();
+        let mut stmt = connection.prepare("SELECT * FROM vaults ORDER BY pubkey")?;
+        let vaults = stmt
+            .query_map([], Self::vault_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(vaults)
+    }
+
+    pub fn vault(&self, pubkey: &str) -> Result<Option<VaultRow>, IndexerError> {
+        let connection = self.connection();
+        let vault = connection
+            .query_row(
+                "SELECT * FROM vaults WHERE pubkey = ?1",
+                [pubkey],
+                Self::vault_from_row,
+            )
+            .optional()?;
+        Ok(vault)
+    }
+
+    pub fn operators(&self) -> Result<Vec<OperatorRow>, IndexerError> {
+        let connection = self.connection();
+        let mut stmt = connection.prepare("SELECT * FROM operators ORDER BY idx")?;
+        let operators = stmt
+            .query_map([], |row| {
+                Ok(OperatorRow {
+                    pubkey: row.get(0)?,
+                    base: row.get(1)?,
+                    admin: row.get(2)?,
+                    index: from_sql(row, 3)?,
+                    ncn_count: from_sql(row, 4)?,
+                    vault_count: from_sql(row, 5)?,
+                    slot: from_sql(row, 6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(operators)
+    }
+
+    pub fn ncns(&self) -> Result<Vec<NcnRow>, IndexerError> {
+        let connection = self.connection();
+        let mut stmt = connection.prepare("SELECT * FROM ncns ORDER BY idx")?;
+        let ncns = stmt
+            .query_map([], |row| {
+                Ok(NcnRow {
+                    pubkey: row.get(0)?,
+                    base: row.get(1)?,
+                    admin: row.get(2)?,
+                    index: from_sql(row, 3)?,
+                    operator_count: from_sql(row, 4)?,
+                    vault_count: from_sql(row, 5)?,
+                    slot: from_sql(row, 6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ncns)
+    }
+
+    /// The assets delegated to an operator across all vaults
+    pub fn operator_exposure(&self, operator: &str) -> Result<OperatorExposure, IndexerError> {
+        let connection = self.connection();
+        let mut stmt = connection.prepare(
+            "SELECT * FROM vault_operator_delegations WHERE operator = ?1 ORDER BY vault",
+        )?;
+        let delegations = stmt
+            .query_map([operator], |row| {
+                Ok(VaultOperatorDelegationRow {
+                    pubkey: row.get(0)?,
+                    vault: row.get(1)?,
+                    operator: row.get(2)?,
+                    staked_amount: from_sql(row, 3)?,
+                    enqueued_for_cooldown_amount: from_sql(row, 4)?,
+                    cooling_down_amount: from_sql(row, 5)?,
+                    last_update_slot: from_sql(row, 6)?,
+                    slot: from_sql(row, 7)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let sum = |f: fn(&VaultOperatorDelegationRow) -> u64| {
+            delegations
+                .iter()
+                .try_fold(0u64, |acc, d| acc.checked_add(f(d)))
+                .ok_or(IndexerError::Overflow)
+        };
+        Ok(OperatorExposure {
+            operator: operator.to_string(),
+            staked_amount: sum(|d| d.staked_amount)?,
+            enqueued_for_cooldown_amount: sum(|d| d.enqueued_for_cooldown_amount)?,
+            cooling_down_amount: sum(|d| d.cooling_down_amount)?,
+            delegations,
+        })
+    }
+
+    /// The withdrawal tickets waiting on a vault, along with the vault's VRT cooldown buckets
+    pub fn withdrawal_queue(&self, vault: &str) -> Result<Option<WithdrawalQueue>, IndexerError> {
+        let Some(vault_row) = self.vault(vault)? else {
+            return Ok(None);
+        };
+        let connection = self.connection();
+        let mut stmt = connection
+            .prepare("SELECT vrt_amount FROM vault_staker_withdrawal_tickets WHERE vault = ?1")?;
+        let vrt_amounts = stmt
+            .query_map([vault], |row| from_sql(row, 0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Some(WithdrawalQueue {
+            vault: vault.to_string(),
+            ticket_count: vrt_amounts.len() as u64,
+            ticket_vrt_amount: vrt_amounts
+                .iter()
+                .try_fold(0u64, |acc, amount| acc.checked_add(*amount))
+                .ok_or(IndexerError::Overflow)?,
+            vrt_enqueued_for_cooldown_amount: vault_row.vrt_enqueued_for_cooldown_amount,
+            vrt_cooling_down_amount: vault_row.vrt_cooling_down_amount,
+            vrt_ready_to_claim_amount: vault_row.vrt_ready_to_claim_amount,
+        }))
+    }
+
+    /// The most recent account events, newest first
+    pub fn events(&self, limit: u64) -> Result<Vec<Event>, IndexerError> {
+        let connection = self.connection();
+        let mut stmt = connection.prepare("SELECT * FROM events ORDER BY id DESC LIMIT ?1")?;
+        let events = stmt
+            .query_map([to_sql(limit)], |row| {
+                Ok(Event {
+                    id: row.get(0)?,
+                    slot: from_sql(row, 1)?,
+                    pubkey: row.get(2)?,
+                    account_type: row.get(3)?,
+                    kind: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(events)
+    }
+
+    fn vault_from_row(row: &Row) -> rusqlite::Result<VaultRow> {
+        Ok(VaultRow {
+            pubkey: row.get(0)?,
+            base: row.get(1)?,
+            admin: row.get(2)?,
+            vrt_mint: row.get(3)?,
+            supported_mint: row.get(4)?,
+            capacity: from_sql(row, 5)?,
+            tokens_deposited: from_sql(row, 6)?,
+            vrt_supply: from_sql(row, 7)?,
+            staked_amount: from_sql(row, 8)?,
+            enqueued_for_cooldown_amount: from_sql(row, 9)?,
+            cooling_down_amount: from_sql(row, 10)?,
+            vrt_enqueued_for_cooldown_amount: from_sql(row, 11)?,
+            vrt_cooling_down_amount: from_sql(row, 12)?,
+            vrt_ready_to_claim_amount: from_sql(row, 13)?,
+            operator_count: from_sql(row, 14)?,
+            is_paused: row.get(15)?,
+            slot: from_sql(row, 16)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::VaultStakerWithdrawalTicketRow;
+
+    fn vault_row(pubkey: &str) -> VaultRow {
+        VaultRow {
+            pubkey: pubkey.to_string(),
+            base: "base".to_string(),
+            admin: "admin".to_string(),
+            vrt_mint: "vrt_mint".to_string(),
+            supported_mint: "supported_mint".to_string(),
+            capacity: u64::MAX,
+            tokens_deposited: 1_000,
+            vrt_supply: 1_000,
+            staked_amount: 500,
+            enqueued_for_cooldown_amount: 0,
+            cooling_down_amount: 0,
+            vrt_enqueued_for_cooldown_amount: 100,
+            vrt_cooling_down_amount: 50,
+            vrt_ready_to_claim_amount: 25,
+            operator_count: 2,
+            is_paused: false,
+            slot: 1,
+        }
+    }
+
+    fn delegation_row(pubkey: &str, vault: &str, operator: &str, staked: u64) -> IndexedAccount {
+        IndexedAccount::VaultOperatorDelegation(VaultOperatorDelegationRow {
+            pubkey: pubkey.to_string(),
+            vault: vault.to_string(),
+            operator: operator.to_string(),
+            staked_amount: staked,
+            enqueued_for_cooldown_amount: 1,
+            cooling_down_amount: 2,
+            last_update_slot: 1,
+            slot: 1,
+        })
+    }
+
+    fn ticket_row(pubkey: &str, vault: &str, vrt_amount: u64) -> IndexedAccount {
+        IndexedAccount::VaultStakerWithdrawalTicket(VaultStakerWithdrawalTicketRow {
+            pubkey: pubkey.to_string(),
+            vault: vault.to_string(),
+            staker: "staker".to_string(),
+            vrt_amount,
+            slot_unstaked: 1,
+            slot: 1,
+        })
+    }
+
+    #[test]
+    fn test_upsert_vault_round_trips_u64_max() {
+        let store = Store::open_in_memory().unwrap();
+        store
+            .upsert(&IndexedAccount::Vault(vault_row("vault")))
+            .unwrap();
+
+        let mut updated = vault_row("vault");
+        updated.tokens_deposited = 2_000;
+        updated.slot = 2;
+        store
+            .upsert(&IndexedAccount::Vault(updated.clone()))
+            .unwrap();
+
+        assert_eq!(store.vaults().unwrap(), vec![updated]);
+        assert_eq!(store.events(10).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_operator_exposure() {
+        let store = Store::open_in_memory().unwrap();
+        store
+            .upsert(&delegation_row("d1", "vault_a", "operator", 100))
+            .unwrap();
+        store
+            .upsert(&delegation_row("d2", "vault_b", "operator", 200))
+            .unwrap();
+        store
+            .upsert(&delegation_row("d3", "vault_a", "other_operator", 400))
+            .unwrap();
+
+        let exposure = store.operator_exposure("operator").unwrap();
+        assert_eq!(exposure.staked_amount, 300);
+        assert_eq!(exposure.enqueued_for_cooldown_amount, 2);
+        assert_eq!(exposure.cooling_down_amount, 4);
+        assert_eq!(exposure.delegations.len(), 2);
+    }
+
+    #[test]
+    fn test_withdrawal_queue() {
+        let store = Store::open_in_memory().unwrap();
+        assert_eq!(store.withdrawal_queue("vault").unwrap(), None);
+
+        store
+            .upsert(&IndexedAccount::Vault(vault_row("vault")))
+            .unwrap();
+        store.upsert(&ticket_row("t1", "vault", 10)).unwrap();
+        store.upsert(&ticket_row("t2", "vault", 20)).unwrap();
+        store.upsert(&ticket_row("t3", "other_vault", 40)).unwrap();
+
+        let queue = store.withdrawal_queue("vault").unwrap().unwrap();
+        assert_eq!(queue.ticket_count, 2);
+        assert_eq!(queue.ticket_vrt_amount, 30);
+        assert_eq!(queue.vrt_enqueued_for_cooldown_amount, 100);
+
+        assert!(store.remove("t1", 2).unwrap());
+        assert!(!store.remove("t1", 2).unwrap());
+        let queue = store.withdrawal_queue("vault").unwrap().unwrap();
+        assert_eq!(queue.ticket_count, 1);
+        assert_eq!(queue.ticket_vrt_amount, 20);
+        assert_eq!(store.events(1).unwrap()[0].kind, "close");
+    }
+}
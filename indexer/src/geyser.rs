@@ -0,0 +1,105 @@
+//! Streams restaking and vault program account updates from a Yellowstone Geyser gRPC endpoint.
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use futures::{SinkExt, StreamExt};
+use jito_restaking_client::programs::JITO_RESTAKING_ID;
+use jito_vault_client::programs::JITO_VAULT_ID;
+use log::{debug, info};
+use solana_program::pubkey::Pubkey;
+use yellowstone_grpc_client::GeyserGrpcClient;
+use yellowstone_grpc_proto::prelude::{
+    subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest,
+    SubscribeRequestFilterAccounts, SubscribeUpdateAccount,
+};
+
+use crate::{accounts::IndexedAccount, db::Store, IndexerError};
+
+/// Closing an account hands it back to the system program, so closures don't match the program
+/// owner filter. Withdrawal tickets are closed when they're burned, so they're also watched by pubkey.
+fn subscribe_request(withdrawal_tickets: &HashSet<String>) -> SubscribeRequest {
+    let mut accounts = HashMap::new();
+    accounts.insert(
+        "programs".to_string(),
+        SubscribeRequestFilterAccounts {
+            owner: vec![JITO_VAULT_ID.to_string(), JITO_RESTAKING_ID.to_string()],
+            ..Default::default()
+        },
+    );
+    if !withdrawal_tickets.is_empty() {
+        accounts.insert(
+            "withdrawal_tickets".to_string(),
+            SubscribeRequestFilterAccounts {
+                account: withdrawal_tickets.iter().cloned().collect(),
+                ..Default::default()
+            },
+        );
+    }
+    SubscribeRequest {
+        accounts,
+        commitment: Some(CommitmentLevel::Confirmed as i32),
+        ..Default::default()
+    }
+}
+
+/// Applies account updates to the store until the stream ends or errors
+pub async fn stream(
+    store: Arc<Store>,
+    endpoint: String,
+    x_token: Option<String>,
+) -> Result<(), IndexerError> {
+    let mut client = GeyserGrpcClient::build_from_shared(endpoint)
+        .and_then(|builder| builder.x_token(x_token))
+        .map_err(|e| IndexerError::Geyser(e.to_string()))?
+        .connect()
+        .await
+        .map_err(|e| IndexerError::Geyser(e.to_string()))?;
+
+    let mut withdrawal_tickets: HashSet<String> =
+        store.withdrawal_ticket_pubkeys()?.into_iter().collect();
+    let (mut subscribe_tx, mut stream) = client
+        .subscribe_with_request(Some(subscribe_request(&withdrawal_tickets)))
+        .await
+        .map_err(|e| IndexerError::Geyser(e.to_string()))?;
+    info!("Subscribed to Geyser account updates");
+
+    while let Some(message) = stream.next().await {
+        let message = message.map_err(|e| IndexerError::Geyser(e.to_string()))?;
+        let Some(UpdateOneof::Account(SubscribeUpdateAccount {
+            account: Some(account),
+            slot,
+            ..
+        })) = message.update_oneof
+        else {
+            continue;
+        };
+
+        let pubkey =
+            Pubkey::try_from(account.pubkey.as_slice()).map_err(|_| IndexerError::InvalidPubkey)?;
+        let owner =
+            Pubkey::try_from(account.owner.as_slice()).map_err(|_| IndexerError::InvalidPubkey)?;
+
+        let watched_tickets_changed = if account.lamports == 0 {
+            if store.remove(&pubkey.to_string(), slot)? {
+                debug!("Account {} closed at slot {}", pubkey, slot);
+            }
+            withdrawal_tickets.remove(&pubkey.to_string())
+        } else if let Some(indexed) = IndexedAccount::decode(&pubkey, &owner, &account.data, slot) {
+            store.upsert(&indexed)?;
+            matches!(indexed, IndexedAccount::VaultStakerWithdrawalTicket(_))
+                && withdrawal_tickets.insert(pubkey.to_string())
+        } else {
+            false
+        };
+
+        if watched_tickets_changed {
+            subscribe_tx
+                .send(subscribe_request(&withdrawal_tickets))
+                .await
+                .map_err(|e| IndexerError::Geyser(e.to_string()))?;
+        }
+    }
+    Err(IndexerError::Geyser("stream closed".to_string()))
+}
@@ -0,0 +1,23 @@
+//! Indexes restaking and vault program accounts from a Geyser gRPC stream into SQLite and serves
+//! them over a small REST API, so front-ends don't need to run getProgramAccounts scans.
+use thiserror::Error;
+
+pub mod accounts;
+pub mod api;
+pub mod backfill;
+pub mod db;
+pub mod geyser;
+
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    #[error("Sqlite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+    #[error("Rpc error: {0}")]
+    Rpc(#[from] solana_rpc_client_api::client_error::Error),
+    #[error("Geyser error: {0}")]
+    Geyser(String),
+    #[error("Invalid pubkey")]
+    InvalidPubkey,
+    #[error("Overflow")]
+    Overflow,
+}
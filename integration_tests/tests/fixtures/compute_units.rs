@@ -0,0 +1,54 @@
+use std::{cell::RefCell, rc::Rc};
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{BanksClient, BanksClientError};
+use solana_sdk::{commitment_config::CommitmentLevel, transaction::Transaction};
+
+/// The compute units consumed by a transaction with a single instruction
+pub struct ComputeUnitsRecord {
+    pub program_id: Pubkey,
+    pub data: Vec<u8>,
+    pub compute_units_consumed: u64,
+}
+
+/// Shared by the program clients to record the compute units consumed by each single-instruction
+/// transaction they process
+#[derive(Clone, Default)]
+pub struct ComputeUnitsRecorder {
+    records: Rc<RefCell<Vec<ComputeUnitsRecord>>>,
+}
+
+impl ComputeUnitsRecorder {
+    /// Processes the transaction, recording the compute units it consumed if it succeeds with a
+    /// single instruction. The compute units come from simulating it first, since processing it
+    /// with metadata can race the account locks of the transaction before it.
+    pub async fn process_transaction(
+        &self,
+        banks_client: &mut BanksClient,
+        tx: &Transaction,
+    ) -> Result<(), BanksClientError> {
+        let simulation = banks_client.simulate_transaction(tx.clone()).await?;
+        banks_client
+            .process_transaction_with_preflight_and_commitment(
+                tx.clone(),
+                CommitmentLevel::Processed,
+            )
+            .await?;
+
+        if let ([instruction], Some(simulation_details)) = (
+            tx.message.instructions.as_slice(),
+            simulation.simulation_details,
+        ) {
+            self.records.borrow_mut().push(ComputeUnitsRecord {
+                program_id: tx.message.account_keys[usize::from(instruction.program_id_index)],
+                data: instruction.data.clone(),
+                compute_units_consumed: simulation_details.units_consumed,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn take_records(&self) -> Vec<ComputeUnitsRecord> {
+        self.records.take()
+    }
+}
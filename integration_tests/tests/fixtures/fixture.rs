@@ -7,6 +7,7 @@ use solana_program::{
 };
 use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
+    account::AccountSharedData,
     commitment_config::CommitmentLevel,
     signature::{Keypair, Signer},
     transaction::Transaction,
@@ -17,13 +18,17 @@ use spl_associated_token_account::{
 use spl_token::state::{Account, Mint};
 
 use crate::fixtures::{
+    compute_units::ComputeUnitsRecorder,
+    program_data_logs::log_program_data,
     restaking_client::{NcnRoot, OperatorRoot, RestakingProgramClient},
     vault_client::{VaultProgramClient, VaultRoot},
+    vault_hook::{process_vault_hook_instruction, VAULT_HOOK_PROGRAM_ID},
     TestResult,
 };
 
 pub struct TestBuilder {
     context: ProgramTestContext,
+    compute_units_recorder: Option<ComputeUnitsRecorder>,
 }
 
 impl Debug for TestBuilder {
@@ -58,11 +63,22 @@ impl TestBuilder {
             jito_restaking_program::id(),
             processor!(jito_restaking_program::process_instruction),
         );
+        // the test hook is always native, even when the programs are loaded from SBF_OUT_DIR
+        program_test.prefer_bpf(false);
+        program_test.add_program(
+            "vault_hook",
+            VAULT_HOOK_PROGRAM_ID,
+            processor!(process_vault_hook_instruction),
+        );
         program_test.prefer_bpf(true);
         program_test.add_program("mpl_token_metadata", inline_mpl_token_metadata::id(), None);
 
         let context = program_test.start_with_context().await;
-        Self { context }
+        log_program_data();
+        Self {
+            context,
+            compute_units_recorder: None,
+        }
     }
 
     pub async fn transfer(&mut self, to: &Pubkey, sol: f64) -> Result<(), BanksClientError> {
@@ -98,6 +114,62 @@ impl TestBuilder {
         Ok(Account::unpack(&account.data).unwrap())
     }
 
+    /// Overwrites the balance of a token account, for simulating the vault token account diverging
+    /// from the vault's accounting
+    pub async fn set_token_account_amount(
+        &mut self,
+        token_account: &Pubkey,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(*token_account)
+            .await?
+            .unwrap();
+        let mut token_account_data = Account::unpack(&account.data).unwrap();
+        token_account_data.amount = amount;
+        Account::pack(token_account_data, &mut account.data).unwrap();
+        self.context.set_account(token_account, &account.into());
+        Ok(())
+    }
+
+    pub async fn get_account_data(&mut self, pubkey: &Pubkey) -> Result<Vec<u8>, BanksClientError> {
+        Ok(self
+            .context
+            .banks_client
+            .get_account(*pubkey)
+            .await?
+            .unwrap()
+            .data)
+    }
+
+    /// Overwrites an account's data, keeping it rent exempt, for simulating accounts written by an
+    /// older version of a program
+    pub async fn set_account_data(
+        &mut self,
+        pubkey: &Pubkey,
+        data: Vec<u8>,
+    ) -> Result<(), BanksClientError> {
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(*pubkey)
+            .await?
+            .unwrap();
+        let rent = self.context.banks_client.get_rent().await?;
+        account.lamports = rent.minimum_balance(data.len());
+        account.data = data;
+        self.context.set_account(pubkey, &account.into());
+        Ok(())
+    }
+
+    /// Removes a token account, as if it had been closed
+    pub fn close_token_account(&mut self, token_account: &Pubkey) {
+        self.context
+            .set_account(token_account, &AccountSharedData::default());
+    }
+
     pub async fn get_token_mint(&mut self, token_mint: &Pubkey) -> Result<Mint, BanksClientError> {
         let account = self
             .context
@@ -191,6 +263,7 @@ impl TestBuilder {
         VaultProgramClient::new(
             self.context.banks_client.clone(),
             self.context.payer.insecure_clone(),
+            self.compute_units_recorder.clone(),
         )
     }
 
@@ -198,9 +271,18 @@ impl TestBuilder {
         RestakingProgramClient::new(
             self.context.banks_client.clone(),
             self.context.payer.insecure_clone(),
+            self.compute_units_recorder.clone(),
         )
     }
 
+    /// Records the compute units of the single-instruction transactions processed by the program
+    /// clients created after this call
+    pub fn record_compute_units(&mut self) -> ComputeUnitsRecorder {
+        let compute_units_recorder = ComputeUnitsRecorder::default();
+        self.compute_units_recorder = Some(compute_units_recorder.clone());
+        compute_units_recorder
+    }
+
     /// Configures a vault with an NCN and operators fully configured
     pub async fn setup_vault_with_ncn_and_operators(
         &mut self,
@@ -3,9 +3,12 @@ use solana_program_test::BanksClientError;
 use solana_sdk::transaction::TransactionError;
 use thiserror::Error;
 
+pub mod compute_units;
 pub mod fixture;
+pub mod program_data_logs;
 pub mod restaking_client;
 pub mod vault_client;
+pub mod vault_hook;
 
 pub type TestResult<T> = Result<T, TestError>;
 
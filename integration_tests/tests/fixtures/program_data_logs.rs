@@ -0,0 +1,122 @@
+//! program-test's syscall stubs for native programs print `sol_log_data` to stdout instead of
+//! adding it to the transaction logs, so the vault events wouldn't show up when the programs aren't
+//! loaded from SBF_OUT_DIR. The stubs are wrapped to log the data as a message instead, which
+//! [`program_data_logs`] turns back into the `Program data: ` log the runtime writes.
+
+use std::sync::Once;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::Instruction,
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+    pubkey::Pubkey,
+};
+
+const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+const PROGRAM_LOG_PREFIX: &str = "Program log: ";
+
+/// Stands in for the stubs for the moment they're swapped out
+struct PlaceholderSyscallStubs;
+
+impl SyscallStubs for PlaceholderSyscallStubs {}
+
+struct ProgramDataSyscallStubs {
+    inner: Box<dyn SyscallStubs>,
+}
+
+impl SyscallStubs for ProgramDataSyscallStubs {
+    fn sol_log(&self, message: &str) {
+        self.inner.sol_log(message)
+    }
+
+    fn sol_log_compute_units(&self) {
+        self.inner.sol_log_compute_units()
+    }
+
+    fn sol_remaining_compute_units(&self) -> u64 {
+        self.inner.sol_remaining_compute_units()
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        self.inner
+            .sol_invoke_signed(instruction, account_infos, signers_seeds)
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner.sol_get_clock_sysvar(var_addr)
+    }
+
+    fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner.sol_get_epoch_schedule_sysvar(var_addr)
+    }
+
+    fn sol_get_fees_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner.sol_get_fees_sysvar(var_addr)
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner.sol_get_rent_sysvar(var_addr)
+    }
+
+    fn sol_get_epoch_rewards_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner.sol_get_epoch_rewards_sysvar(var_addr)
+    }
+
+    fn sol_get_last_restart_slot(&self, var_addr: *mut u8) -> u64 {
+        self.inner.sol_get_last_restart_slot(var_addr)
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        self.inner.sol_get_return_data()
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        self.inner.sol_set_return_data(data)
+    }
+
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        let fields: Vec<String> = fields.iter().map(|field| STANDARD.encode(field)).collect();
+        self.inner
+            .sol_log(&format!("{}{}", PROGRAM_DATA_LOG_PREFIX, fields.join(" ")))
+    }
+
+    fn sol_get_processed_sibling_instruction(&self, index: usize) -> Option<Instruction> {
+        self.inner.sol_get_processed_sibling_instruction(index)
+    }
+
+    fn sol_get_stack_height(&self) -> u64 {
+        self.inner.sol_get_stack_height()
+    }
+}
+
+/// Wraps the syscall stubs program-test installed so `sol_log_data` is logged. Must be called after
+/// a program-test context has started and before it processes any transactions.
+pub fn log_program_data() {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        let inner = set_syscall_stubs(Box::new(PlaceholderSyscallStubs));
+        set_syscall_stubs(Box::new(ProgramDataSyscallStubs { inner }));
+    });
+}
+
+/// Turns the data logged as a message by the native programs back into `Program data: ` logs
+pub fn program_data_logs(logs: Vec<String>) -> Vec<String> {
+    logs.into_iter()
+        .map(|log| {
+            match log
+                .strip_prefix(PROGRAM_LOG_PREFIX)
+                .filter(|message| message.starts_with(PROGRAM_DATA_LOG_PREFIX))
+            {
+                Some(message) => message.to_string(),
+                None => log,
+            }
+        })
+        .collect()
+}
@@ -6,13 +6,21 @@ use jito_restaking_core::{
 };
 use jito_restaking_sdk::{
     error::RestakingError,
+    instruction::{NcnAdminRole, OperatorAdminRole, SlashDestination},
     sdk::{
-        cooldown_ncn_vault_ticket, initialize_config, initialize_ncn,
+        batch_add_operators, config_accept_admin, config_set_admin, config_set_epoch_length,
+        config_set_parameters, cooldown_ncn_vault_slasher_ticket, cooldown_ncn_vault_ticket,
+        cooldown_operator_vault_ticket, initialize_config, initialize_ncn,
         initialize_ncn_operator_state, initialize_ncn_vault_slasher_ticket,
         initialize_ncn_vault_ticket, initialize_operator, initialize_operator_vault_ticket,
-        ncn_cooldown_operator, ncn_set_admin, ncn_warmup_operator, operator_cooldown_ncn,
-        operator_set_admin, operator_warmup_ncn, warmup_ncn_vault_slasher_ticket,
-        warmup_ncn_vault_ticket, warmup_operator_vault_ticket,
+        migrate_account, ncn_close_task_window, ncn_cooldown_operator, ncn_freeze_operator,
+        ncn_open_task_window, ncn_set_admin, ncn_set_max_slashable_per_epoch,
+        ncn_set_secondary_admin, ncn_set_slash_destination, ncn_set_ticket_expiry,
+        ncn_unfreeze_operator, ncn_warmup_operator, ncn_withdrawal_asset,
+        operator_approve_delegation, operator_cooldown_ncn, operator_set_admin,
+        operator_set_commission, operator_set_delegation_approval_required, operator_set_metadata,
+        operator_set_secondary_admin, operator_warmup_ncn, operator_withdrawal_asset, renew_ticket,
+        warmup_ncn_vault_slasher_ticket, warmup_ncn_vault_ticket, warmup_operator_vault_ticket,
     },
 };
 use solana_program::{
@@ -25,8 +33,9 @@ use solana_sdk::{
     signature::{Keypair, Signer},
     transaction::{Transaction, TransactionError},
 };
+use spl_associated_token_account::get_associated_token_address;
 
-use crate::fixtures::{TestError, TestResult};
+use crate::fixtures::{compute_units::ComputeUnitsRecorder, TestError, TestResult};
 
 #[derive(Debug)]
 pub struct NcnRoot {
@@ -43,13 +52,19 @@ pub struct OperatorRoot {
 pub struct RestakingProgramClient {
     banks_client: BanksClient,
     payer: Keypair,
+    compute_units_recorder: Option<ComputeUnitsRecorder>,
 }
 
 impl RestakingProgramClient {
-    pub const fn new(banks_client: BanksClient, payer: Keypair) -> Self {
+    pub const fn new(
+        banks_client: BanksClient,
+        payer: Keypair,
+        compute_units_recorder: Option<ComputeUnitsRecorder>,
+    ) -> Self {
         Self {
             banks_client,
             payer,
+            compute_units_recorder,
         }
     }
 
@@ -149,6 +164,85 @@ impl RestakingProgramClient {
         Ok(restaking_config_admin)
     }
 
+    pub async fn do_config_set_admin(
+        &mut self,
+        admin: &Keypair,
+        new_admin: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[config_set_admin(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &admin.pubkey(),
+                new_admin,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_config_accept_admin(&mut self, new_admin: &Keypair) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[config_accept_admin(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &new_admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[new_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_config_set_epoch_length(
+        &mut self,
+        admin: &Keypair,
+        epoch_length: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[config_set_epoch_length(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &admin.pubkey(),
+                epoch_length,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_config_set_parameters(
+        &mut self,
+        admin: &Keypair,
+        vault_program: Option<Pubkey>,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[config_set_parameters(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &admin.pubkey(),
+                vault_program,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_initialize_operator(&mut self) -> TestResult<OperatorRoot> {
         // create operator + add operator vault
         let operator_base = Keypair::new();
@@ -440,6 +534,192 @@ impl RestakingProgramClient {
         .await
     }
 
+    pub async fn do_ncn_open_task_window(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_pubkey: &Pubkey,
+        end_slot: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_open_task_window(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    operator_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+                end_slot,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_ncn_close_task_window(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_pubkey: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_close_task_window(
+                &jito_restaking_program::id(),
+                &ncn_root.ncn_pubkey,
+                operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    operator_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_ncn_freeze_operator(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_pubkey: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_freeze_operator(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    operator_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_ncn_unfreeze_operator(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_pubkey: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_unfreeze_operator(
+                &jito_restaking_program::id(),
+                &ncn_root.ncn_pubkey,
+                operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    operator_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_ncn_set_ticket_expiry(
+        &mut self,
+        ncn_root: &NcnRoot,
+        ticket_expiry_epochs: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_ticket_expiry(
+                &jito_restaking_program::id(),
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin.pubkey(),
+                ticket_expiry_epochs,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_renew_ncn_operator_state(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_pubkey: &Pubkey,
+    ) -> TestResult<()> {
+        let ncn_operator_state = NcnOperatorState::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            operator_pubkey,
+        )
+        .0;
+        self.renew_ticket(ncn_root, operator_pubkey, &ncn_operator_state)
+            .await
+    }
+
+    pub async fn do_renew_ncn_vault_ticket(
+        &mut self,
+        ncn_root: &NcnRoot,
+        vault: &Pubkey,
+    ) -> TestResult<()> {
+        let ncn_vault_ticket = NcnVaultTicket::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            vault,
+        )
+        .0;
+        self.renew_ticket(ncn_root, vault, &ncn_vault_ticket).await
+    }
+
+    pub async fn renew_ticket(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_or_vault: &Pubkey,
+        ticket: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[renew_ticket(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                operator_or_vault,
+                ticket,
+                &ncn_root.ncn_admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn ncn_cooldown_operator(
         &mut self,
         config: &Pubkey,
@@ -607,6 +887,46 @@ impl RestakingProgramClient {
         .await
     }
 
+    /// Adds the operators to the NCN with BatchAddOperators, one transaction per chunk of
+    /// operators
+    pub async fn do_batch_add_operators(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operators: &[Pubkey],
+    ) -> TestResult<()> {
+        let operators: Vec<_> = operators
+            .iter()
+            .map(|operator| {
+                let ncn_operator_state = NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    operator,
+                )
+                .0;
+                (*operator, ncn_operator_state)
+            })
+            .collect();
+
+        for instruction in batch_add_operators(
+            &jito_restaking_program::id(),
+            &Config::find_program_address(&jito_restaking_program::id()).0,
+            &ncn_root.ncn_pubkey,
+            &ncn_root.ncn_admin.pubkey(),
+            &self.payer.pubkey(),
+            &operators,
+        ) {
+            let blockhash = self.banks_client.get_latest_blockhash().await?;
+            self.process_transaction(&Transaction::new_signed_with_payer(
+                &[instruction],
+                Some(&self.payer.pubkey()),
+                &[&ncn_root.ncn_admin, &self.payer],
+                blockhash,
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+
     pub async fn do_initialize_ncn_vault_slasher_ticket(
         &mut self,
         ncn_root: &NcnRoot,
@@ -642,6 +962,74 @@ impl RestakingProgramClient {
         .await
     }
 
+    pub async fn do_ncn_set_slash_destination(
+        &mut self,
+        ncn_root: &NcnRoot,
+        vault: &Pubkey,
+        slasher: &Pubkey,
+        insurance_wallet: Option<&Pubkey>,
+        slash_destination: SlashDestination,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_slash_destination(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                vault,
+                slasher,
+                &NcnVaultSlasherTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    vault,
+                    slasher,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+                insurance_wallet,
+                slash_destination,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_ncn_set_max_slashable_per_epoch(
+        &mut self,
+        ncn_root: &NcnRoot,
+        vault: &Pubkey,
+        slasher: &Pubkey,
+        max_slashable_per_epoch: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_max_slashable_per_epoch(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                vault,
+                slasher,
+                &NcnVaultSlasherTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    vault,
+                    slasher,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+                max_slashable_per_epoch,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_warmup_ncn_vault_slasher_ticket(
         &mut self,
         ncn_root: &NcnRoot,
@@ -862,6 +1250,102 @@ impl RestakingProgramClient {
         .await
     }
 
+    pub async fn do_operator_set_commission(
+        &mut self,
+        operator_root: &OperatorRoot,
+        commission_bps: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_set_commission(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin.pubkey(),
+                commission_bps,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_operator_set_metadata(
+        &mut self,
+        operator_root: &OperatorRoot,
+        name: &str,
+        website: &str,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_set_metadata(
+                &jito_restaking_program::id(),
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin.pubkey(),
+                name.to_string(),
+                website.to_string(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_operator_set_delegation_approval_required(
+        &mut self,
+        operator_root: &OperatorRoot,
+        delegation_approval_required: bool,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_set_delegation_approval_required(
+                &jito_restaking_program::id(),
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin.pubkey(),
+                delegation_approval_required,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_operator_approve_delegation(
+        &mut self,
+        operator_root: &OperatorRoot,
+        vault: &Pubkey,
+        approved_delegation_amount: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_approve_delegation(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &operator_root.operator_pubkey,
+                vault,
+                &OperatorVaultTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    &operator_root.operator_pubkey,
+                    vault,
+                )
+                .0,
+                &operator_root.operator_admin.pubkey(),
+                approved_delegation_amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn initialize_operator(
         &mut self,
         config: &Pubkey,
@@ -914,7 +1398,191 @@ impl RestakingProgramClient {
         .await
     }
 
+    pub async fn do_cooldown_ncn_vault_slasher_ticket(
+        &mut self,
+        ncn_root: &NcnRoot,
+        vault: &Pubkey,
+        slasher: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[cooldown_ncn_vault_slasher_ticket(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                vault,
+                slasher,
+                &NcnVaultSlasherTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    vault,
+                    slasher,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_cooldown_operator_vault_ticket(
+        &mut self,
+        operator_root: &OperatorRoot,
+        vault: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[cooldown_operator_vault_ticket(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &operator_root.operator_pubkey,
+                vault,
+                &OperatorVaultTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    &operator_root.operator_pubkey,
+                    vault,
+                )
+                .0,
+                &operator_root.operator_admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_set_secondary_admin(
+        &mut self,
+        ncn: &Pubkey,
+        admin: &Keypair,
+        new_admin: &Pubkey,
+        role: NcnAdminRole,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_secondary_admin(
+                &jito_restaking_program::id(),
+                ncn,
+                &admin.pubkey(),
+                new_admin,
+                role,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_set_secondary_admin(
+        &mut self,
+        operator: &Pubkey,
+        admin: &Keypair,
+        new_admin: &Pubkey,
+        role: OperatorAdminRole,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_set_secondary_admin(
+                &jito_restaking_program::id(),
+                operator,
+                &admin.pubkey(),
+                new_admin,
+                role,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Withdraws tokens from the NCN's associated token account to the NCN admin's, who is the
+    /// withdraw admin and fee wallet by default
+    pub async fn do_ncn_withdrawal_asset(
+        &mut self,
+        ncn_root: &NcnRoot,
+        token_mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_withdrawal_asset(
+                &jito_restaking_program::id(),
+                &ncn_root.ncn_pubkey,
+                &get_associated_token_address(&ncn_root.ncn_pubkey, token_mint),
+                &get_associated_token_address(&ncn_root.ncn_admin.pubkey(), token_mint),
+                &ncn_root.ncn_admin.pubkey(),
+                &spl_token::id(),
+                *token_mint,
+                amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Withdraws tokens from the operator's associated token account to the operator admin's, who
+    /// is the withdrawal admin and fee wallet by default
+    pub async fn do_operator_withdrawal_asset(
+        &mut self,
+        operator_root: &OperatorRoot,
+        token_mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_withdrawal_asset(
+                &jito_restaking_program::id(),
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin.pubkey(),
+                &get_associated_token_address(&operator_root.operator_pubkey, token_mint),
+                &get_associated_token_address(&operator_root.operator_admin.pubkey(), token_mint),
+                &spl_token::id(),
+                *token_mint,
+                amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn migrate_account(&mut self, account: &Pubkey) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[migrate_account(
+                &jito_restaking_program::id(),
+                account,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn process_transaction(&mut self, tx: &Transaction) -> TestResult<()> {
+        if let Some(compute_units_recorder) = &self.compute_units_recorder {
+            return Ok(compute_units_recorder
+                .process_transaction(&mut self.banks_client, tx)
+                .await?);
+        }
         self.banks_client
             .process_transaction_with_preflight_and_commitment(
                 tx.clone(),
@@ -4,21 +4,32 @@ use borsh::BorshDeserialize;
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::{
     ncn_operator_state::NcnOperatorState, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
-    ncn_vault_ticket::NcnVaultTicket, operator_vault_ticket::OperatorVaultTicket,
+    ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+    operator_vault_ticket::OperatorVaultTicket,
 };
+use jito_restaking_sdk::instruction::SlashDestination;
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+    config::Config, delegation_strategy::DelegationStrategy, deposit_receipt::DepositReceipt,
+    ncn_reward_router::NcnRewardRouter, operator_stake_weight::OperatorStakeWeight,
+    reward_claim::RewardClaim, reward_distribution::RewardDistribution,
+    slash_proposal::SlashProposal, vault::Vault,
+    vault_deposit_allowlist_entry::VaultDepositAllowlistEntry, vault_depositor::VaultDepositor,
+    vault_epoch_stats::VaultEpochStats, vault_insurance::VaultInsurance,
+    vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
     vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
     vault_operator_delegation::VaultOperatorDelegation,
+    vault_operator_delegation_snapshot::VaultOperatorDelegationSnapshot,
     vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
     vault_update_state_tracker::VaultUpdateStateTracker,
 };
 use jito_vault_sdk::{
     error::VaultError,
+    events::{parse_vault_events, VaultEvent},
     inline_mpl_token_metadata,
-    instruction::{VaultAdminRole, WithdrawalAllocationMethod},
+    instruction::{DepositMode, VaultAdminRole, WithdrawalAllocationMethod},
     sdk::{
-        add_delegation, cooldown_delegation, initialize_config, initialize_vault,
+        add_delegation, cooldown_delegation, cooldown_vault_ncn_slasher_ticket,
+        cooldown_vault_ncn_ticket, initialize_config, initialize_vault, remove_slasher,
         set_deposit_capacity, warmup_vault_ncn_slasher_ticket, warmup_vault_ncn_ticket,
     },
 };
@@ -30,11 +41,12 @@ use solana_program::{
     pubkey::Pubkey,
     rent::Rent,
     system_instruction::{create_account, transfer},
+    system_program,
 };
 use solana_program_test::{BanksClient, BanksClientError};
 use solana_sdk::{
     commitment_config::CommitmentLevel,
-    instruction::InstructionError,
+    instruction::{AccountMeta, Instruction, InstructionError},
     signature::{Keypair, Signer},
     transaction::{Transaction, TransactionError},
 };
@@ -46,7 +58,10 @@ use spl_token::{
     state::{Account as SPLTokenAccount, Mint},
 };
 
-use crate::fixtures::{TestError, TestResult};
+use crate::fixtures::{
+    compute_units::ComputeUnitsRecorder, program_data_logs::program_data_logs, TestError,
+    TestResult,
+};
 
 pub struct VaultRoot {
     pub vault_pubkey: Pubkey,
@@ -71,13 +86,19 @@ pub struct VaultStakerWithdrawalTicketRoot {
 pub struct VaultProgramClient {
     banks_client: BanksClient,
     payer: Keypair,
+    compute_units_recorder: Option<ComputeUnitsRecorder>,
 }
 
 impl VaultProgramClient {
-    pub const fn new(banks_client: BanksClient, payer: Keypair) -> Self {
+    pub const fn new(
+        banks_client: BanksClient,
+        payer: Keypair,
+        compute_units_recorder: Option<ComputeUnitsRecorder>,
+    ) -> Self {
         Self {
             banks_client,
             payer,
+            compute_units_recorder,
         }
     }
 
@@ -107,6 +128,10 @@ impl VaultProgramClient {
         Ok(Vault::try_from_slice_unchecked(&mut account.data.as_slice())?.clone())
     }
 
+    pub async fn vault_exists(&mut self, account: &Pubkey) -> Result<bool, TestError> {
+        Ok(self.banks_client.get_account(*account).await?.is_some())
+    }
+
     pub async fn get_vault_ncn_ticket(
         &mut self,
         vault: &Pubkey,
@@ -117,6 +142,177 @@ impl VaultProgramClient {
         Ok(VaultNcnTicket::try_from_slice_unchecked(&mut account.data.as_slice())?.clone())
     }
 
+    pub async fn get_vault_deposit_allowlist_entry(
+        &mut self,
+        vault: &Pubkey,
+        depositor: &Pubkey,
+    ) -> Result<Option<VaultDepositAllowlistEntry>, TestError> {
+        let account = VaultDepositAllowlistEntry::find_program_address(
+            &jito_vault_program::id(),
+            vault,
+            depositor,
+        )
+        .0;
+        let account = self.banks_client.get_account(account).await?;
+        Ok(account.map(|account| {
+            *VaultDepositAllowlistEntry::try_from_slice_unchecked(account.data.as_slice()).unwrap()
+        }))
+    }
+
+    pub async fn get_vault_depositor(
+        &mut self,
+        vault: &Pubkey,
+        depositor: &Pubkey,
+    ) -> Result<Option<VaultDepositor>, TestError> {
+        let account =
+            VaultDepositor::find_program_address(&jito_vault_program::id(), vault, depositor).0;
+        let account = self.banks_client.get_account(account).await?;
+        Ok(account.map(|account| {
+            *VaultDepositor::try_from_slice_unchecked(account.data.as_slice()).unwrap()
+        }))
+    }
+
+    pub async fn get_deposit_receipt(
+        &mut self,
+        vault: &Pubkey,
+        depositor: &Pubkey,
+        base: &Pubkey,
+    ) -> Result<Option<DepositReceipt>, TestError> {
+        let account =
+            DepositReceipt::find_program_address(&jito_vault_program::id(), vault, depositor, base)
+                .0;
+        let account = self.banks_client.get_account(account).await?;
+        Ok(account.map(|account| {
+            *DepositReceipt::try_from_slice_unchecked(account.data.as_slice()).unwrap()
+        }))
+    }
+
+    pub async fn get_delegation_strategy(
+        &mut self,
+        vault: &Pubkey,
+    ) -> Result<DelegationStrategy, TestError> {
+        let account = DelegationStrategy::find_program_address(&jito_vault_program::id(), vault).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*DelegationStrategy::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_vault_epoch_stats(
+        &mut self,
+        vault: &Pubkey,
+    ) -> Result<VaultEpochStats, TestError> {
+        let account = VaultEpochStats::find_program_address(&jito_vault_program::id(), vault).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*VaultEpochStats::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    /// Appends the vault's epoch stats account to `instruction` if the vault has it enabled
+    async fn push_vault_epoch_stats(
+        &mut self,
+        vault: &Pubkey,
+        instruction: &mut Instruction,
+    ) -> Result<(), TestError> {
+        if self.get_vault(vault).await?.epoch_stats_enabled() {
+            instruction.accounts.push(AccountMeta::new(
+                VaultEpochStats::find_program_address(&jito_vault_program::id(), vault).0,
+                false,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Appends the vault's hook program to `instruction` if the vault has one
+    async fn push_vault_hook_program(
+        &mut self,
+        vault: &Pubkey,
+        instruction: &mut Instruction,
+    ) -> Result<(), TestError> {
+        let vault = self.get_vault(vault).await?;
+        if vault.has_hook_program() {
+            instruction
+                .accounts
+                .push(AccountMeta::new_readonly(vault.hook_program, false));
+        }
+        Ok(())
+    }
+
+    pub async fn get_vault_insurance(
+        &mut self,
+        vault: &Pubkey,
+    ) -> Result<VaultInsurance, TestError> {
+        let account = VaultInsurance::find_program_address(&jito_vault_program::id(), vault).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*VaultInsurance::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    /// Appends the vault's insurance account and its VRT token account to `instruction` if the
+    /// vault has insurance enabled, and the VRT mint when `include_vrt_mint` is set
+    async fn push_vault_insurance(
+        &mut self,
+        vault: &Pubkey,
+        instruction: &mut Instruction,
+        include_vrt_mint: bool,
+    ) -> Result<(), TestError> {
+        let vault_account = self.get_vault(vault).await?;
+        if vault_account.insurance_enabled() {
+            let vault_insurance =
+                VaultInsurance::find_program_address(&jito_vault_program::id(), vault).0;
+            instruction
+                .accounts
+                .push(AccountMeta::new(vault_insurance, false));
+            instruction.accounts.push(AccountMeta::new(
+                get_associated_token_address(&vault_insurance, &vault_account.vrt_mint),
+                false,
+            ));
+            if include_vrt_mint {
+                instruction
+                    .accounts
+                    .push(AccountMeta::new(vault_account.vrt_mint, false));
+            }
+        }
+        Ok(())
+    }
+
+    /// Pushes the fixture payer's token account for the vault's supported mint, along with the
+    /// vault token account and token program when `include_vault_token_account` is set, when the
+    /// config pays crank rewards in basis points
+    async fn push_crank_reward_accounts(
+        &mut self,
+        vault: &Pubkey,
+        instruction: &mut Instruction,
+        include_vault_token_account: bool,
+    ) -> Result<(), TestError> {
+        let config = self
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await?;
+        if config.crank_reward_bps() == 0 {
+            return Ok(());
+        }
+
+        let vault_account = self.get_vault(vault).await?;
+        if include_vault_token_account {
+            instruction.accounts.push(AccountMeta::new(
+                get_associated_token_address(vault, &vault_account.supported_mint),
+                false,
+            ));
+        }
+        instruction.accounts.push(AccountMeta::new(
+            get_associated_token_address(&self.payer.pubkey(), &vault_account.supported_mint),
+            false,
+        ));
+        if include_vault_token_account {
+            instruction
+                .accounts
+                .push(AccountMeta::new_readonly(spl_token::id(), false));
+        }
+        Ok(())
+    }
+
     pub async fn get_vault_operator_delegation(
         &mut self,
         vault: &Pubkey,
@@ -135,6 +331,90 @@ impl VaultProgramClient {
         )
     }
 
+    pub async fn get_operator_stake_weight(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        supported_mint: &Pubkey,
+        ncn_epoch: u64,
+    ) -> Result<OperatorStakeWeight, TestError> {
+        let account = OperatorStakeWeight::find_program_address(
+            &jito_vault_program::id(),
+            ncn,
+            operator,
+            supported_mint,
+            ncn_epoch,
+        )
+        .0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*OperatorStakeWeight::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_vault_operator_delegation_snapshot(
+        &mut self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        ncn_epoch: u64,
+    ) -> Result<VaultOperatorDelegationSnapshot, TestError> {
+        let account = VaultOperatorDelegationSnapshot::find_program_address(
+            &jito_vault_program::id(),
+            vault,
+            operator,
+            ncn_epoch,
+        )
+        .0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*VaultOperatorDelegationSnapshot::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_reward_distribution(
+        &mut self,
+        vault: &Pubkey,
+        epoch: u64,
+    ) -> Result<RewardDistribution, TestError> {
+        let account =
+            RewardDistribution::find_program_address(&jito_vault_program::id(), vault, epoch).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*RewardDistribution::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_ncn_reward_router(
+        &mut self,
+        ncn: &Pubkey,
+        vault: &Pubkey,
+        epoch: u64,
+    ) -> Result<NcnRewardRouter, TestError> {
+        let account =
+            NcnRewardRouter::find_program_address(&jito_vault_program::id(), ncn, vault, epoch).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*NcnRewardRouter::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_reward_claim(
+        &mut self,
+        reward_distribution: &Pubkey,
+        staker: &Pubkey,
+    ) -> Result<RewardClaim, TestError> {
+        let account = RewardClaim::find_program_address(
+            &jito_vault_program::id(),
+            reward_distribution,
+            staker,
+        )
+        .0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*RewardClaim::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
     pub async fn get_vault_staker_withdrawal_ticket(
         &mut self,
         vault: &Pubkey,
@@ -155,6 +435,18 @@ impl VaultProgramClient {
         Ok(withdrawal_ticket)
     }
 
+    pub async fn get_slash_proposal(
+        &mut self,
+        vault: &Pubkey,
+        base: &Pubkey,
+    ) -> Result<SlashProposal, TestError> {
+        let account = SlashProposal::find_program_address(&jito_vault_program::id(), vault, base).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*SlashProposal::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
     pub async fn get_vault_ncn_slasher_ticket(
         &mut self,
         vault: &Pubkey,
@@ -280,6 +572,27 @@ impl VaultProgramClient {
         withdraw_fee_bps: u16,
         reward_fee_bps: u16,
         decimals: u8,
+    ) -> Result<VaultRoot, TestError> {
+        let token_mint = Keypair::new();
+        self.create_token_mint(&token_mint).await?;
+
+        self.do_initialize_vault_with_supported_mint(
+            &token_mint.pubkey(),
+            deposit_fee_bps,
+            withdraw_fee_bps,
+            reward_fee_bps,
+            decimals,
+        )
+        .await
+    }
+
+    pub async fn do_initialize_vault_with_supported_mint(
+        &mut self,
+        supported_mint: &Pubkey,
+        deposit_fee_bps: u16,
+        withdraw_fee_bps: u16,
+        reward_fee_bps: u16,
+        decimals: u8,
     ) -> Result<VaultRoot, TestError> {
         let vault_base = Keypair::new();
 
@@ -288,16 +601,14 @@ impl VaultProgramClient {
 
         let vrt_mint = Keypair::new();
         let vault_admin = Keypair::new();
-        let token_mint = Keypair::new();
 
         self.airdrop(&vault_admin.pubkey(), 100.0).await?;
-        self.create_token_mint(&token_mint).await?;
 
         self.initialize_vault(
             &Config::find_program_address(&jito_vault_program::id()).0,
             &vault_pubkey,
             &vrt_mint,
-            &token_mint,
+            supported_mint,
             &vault_admin,
             &vault_base,
             deposit_fee_bps,
@@ -308,7 +619,8 @@ impl VaultProgramClient {
         .await?;
 
         // for holding the backed asset in the vault
-        self.create_ata(&token_mint.pubkey(), &vault_pubkey).await?;
+        self.initialize_vault_token_account(&vault_pubkey, supported_mint)
+            .await?;
         // for holding fees
         self.create_ata(&vrt_mint.pubkey(), &vault_admin.pubkey())
             .await?;
@@ -319,6 +631,27 @@ impl VaultProgramClient {
         })
     }
 
+    pub async fn initialize_vault_token_account(
+        &mut self,
+        vault: &Pubkey,
+        supported_mint: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::initialize_vault_token_account(
+                &jito_vault_program::id(),
+                vault,
+                &get_associated_token_address(vault, supported_mint),
+                supported_mint,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_initialize_vault_ncn_ticket(
         &mut self,
         vault_root: &VaultRoot,
@@ -354,7 +687,7 @@ impl VaultProgramClient {
         &mut self,
         config: &Pubkey,
         vault: &Pubkey,
-        admin: &Keypair,
+        capacity_admin: &Keypair,
         capacity: u64,
     ) -> Result<(), TestError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
@@ -364,11 +697,11 @@ impl VaultProgramClient {
                 &jito_vault_program::id(),
                 &config,
                 &vault,
-                &admin.pubkey(),
+                &capacity_admin.pubkey(),
                 capacity,
             )],
-            Some(&admin.pubkey()),
-            &[&admin],
+            Some(&capacity_admin.pubkey()),
+            &[&capacity_admin],
             blockhash,
         ))
         .await
@@ -424,6 +757,34 @@ impl VaultProgramClient {
         .await
     }
 
+    pub async fn do_cooldown_vault_ncn_ticket(
+        &mut self,
+        vault_root: &VaultRoot,
+        ncn: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[cooldown_vault_ncn_ticket(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                ncn,
+                &VaultNcnTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    ncn,
+                )
+                .0,
+                &vault_root.vault_admin.pubkey(),
+            )],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn setup_vault_ncn_slasher_operator_ticket(
         &mut self,
         vault_root: &VaultRoot,
@@ -478,6 +839,98 @@ impl VaultProgramClient {
         operator_pubkey: &Pubkey,
         amount: u64,
     ) -> Result<(), TestError> {
+        let instruction = self
+            .slash_instruction(
+                vault_root,
+                ncn_pubkey,
+                &slasher.pubkey(),
+                operator_pubkey,
+                amount,
+            )
+            .await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&slasher.pubkey()),
+            &[slasher],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// The slasher slashes through the restaking program's NcnRequestSlash, which checks the NCN's
+    /// side before invoking the vault's Slash
+    pub async fn do_ncn_request_slash(
+        &mut self,
+        vault_root: &VaultRoot,
+        ncn_pubkey: &Pubkey,
+        slasher: &Keypair,
+        operator_pubkey: &Pubkey,
+        amount: u64,
+    ) -> Result<(), TestError> {
+        let slash = self
+            .slash_instruction(
+                vault_root,
+                ncn_pubkey,
+                &slasher.pubkey(),
+                operator_pubkey,
+                amount,
+            )
+            .await?;
+
+        // NcnRequestSlash takes the vault's Slash accounts after the restaking config and the
+        // vault program, including the optional ones
+        let keys: Vec<Pubkey> = slash.accounts.iter().map(|a| a.pubkey).collect();
+        let mut instruction = jito_restaking_sdk::sdk::ncn_request_slash(
+            &jito_restaking_program::id(),
+            &jito_restaking_core::config::Config::find_program_address(
+                &jito_restaking_program::id(),
+            )
+            .0,
+            &jito_vault_program::id(),
+            &keys[0],
+            &keys[1],
+            &keys[2],
+            &keys[3],
+            &keys[4],
+            &keys[5],
+            &keys[6],
+            &keys[7],
+            &keys[8],
+            &keys[9],
+            &keys[10],
+            &keys[11],
+            &keys[12],
+            &keys[13],
+            &keys[14],
+            &keys[15],
+            amount,
+        );
+        instruction
+            .accounts
+            .extend(slash.accounts.into_iter().skip(16));
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&slasher.pubkey()),
+            &[slasher],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Builds the vault's Slash instruction with the accounts derived for the vault, NCN, slasher
+    /// and operator
+    async fn slash_instruction(
+        &mut self,
+        vault_root: &VaultRoot,
+        ncn_pubkey: &Pubkey,
+        slasher: &Pubkey,
+        operator_pubkey: &Pubkey,
+        amount: u64,
+    ) -> Result<Instruction, TestError> {
         let ncn_operator_state_pubkey = NcnOperatorState::find_program_address(
             &jito_restaking_program::id(),
             ncn_pubkey,
@@ -512,14 +965,14 @@ impl VaultProgramClient {
             &jito_restaking_program::id(),
             ncn_pubkey,
             &vault_root.vault_pubkey,
-            &slasher.pubkey(),
+            slasher,
         )
         .0;
         let vault_slasher_ticket_pubkey = VaultNcnSlasherTicket::find_program_address(
             &jito_vault_program::id(),
             &vault_root.vault_pubkey,
             ncn_pubkey,
-            &slasher.pubkey(),
+            slasher,
         )
         .0;
         let config = self
@@ -533,7 +986,7 @@ impl VaultProgramClient {
                 &jito_vault_program::id(),
                 &vault_root.vault_pubkey,
                 ncn_pubkey,
-                &slasher.pubkey(),
+                slasher,
                 operator_pubkey,
                 clock.slot / config.epoch_length(),
             )
@@ -542,10 +995,12 @@ impl VaultProgramClient {
         let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
         let vault_token_account =
             get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint);
-        let slasher_token_account =
-            get_associated_token_address(&slasher.pubkey(), &vault.supported_mint);
+        let slash_destination = self
+            .get_slash_destination(&vault_root.vault_pubkey, ncn_pubkey, slasher)
+            .await?;
 
-        self.slash(
+        let mut instruction = jito_vault_sdk::sdk::slash(
+            &jito_vault_program::id(),
             &Config::find_program_address(&jito_vault_program::id()).0,
             &vault_root.vault_pubkey,
             &ncn_pubkey,
@@ -560,12 +1015,235 @@ impl VaultProgramClient {
             &vault_slasher_ticket_pubkey,
             &vault_ncn_slasher_operator_ticket,
             &vault_token_account,
-            &slasher_token_account,
+            &slash_destination,
             amount,
-        )
-        .await?;
+        );
+        self.push_vault_insurance(&vault_root.vault_pubkey, &mut instruction, true)
+            .await?;
+        self.push_vault_epoch_stats(&vault_root.vault_pubkey, &mut instruction)
+            .await?;
 
-        Ok(())
+        Ok(instruction)
+    }
+
+    /// Returns the account the slasher's slashed funds are sent to under the NCN vault slasher
+    /// ticket's slash destination
+    pub async fn get_slash_destination(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        ncn_pubkey: &Pubkey,
+        slasher: &Pubkey,
+    ) -> Result<Pubkey, TestError> {
+        let ncn_vault_slasher_ticket = NcnVaultSlasherTicket::find_program_address(
+            &jito_restaking_program::id(),
+            ncn_pubkey,
+            vault_pubkey,
+            slasher,
+        )
+        .0;
+        let account = self
+            .banks_client
+            .get_account(ncn_vault_slasher_ticket)
+            .await?
+            .unwrap();
+        let ncn_vault_slasher_ticket =
+            *NcnVaultSlasherTicket::try_from_slice_unchecked(account.data.as_slice())?;
+        let vault = self.get_vault(vault_pubkey).await?;
+        let config = self
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await?;
+
+        let owner = match ncn_vault_slasher_ticket.slash_destination()? {
+            SlashDestination::Slasher => *slasher,
+            SlashDestination::Burn => return Ok(vault.supported_mint),
+            SlashDestination::Insurance => ncn_vault_slasher_ticket.insurance_wallet,
+            SlashDestination::Treasury => config.protocol_fee_wallet,
+        };
+        Ok(get_associated_token_address(&owner, &vault.supported_mint))
+    }
+
+    /// Proposes a slash of the operator by the slasher, returning the base of the SlashProposal
+    pub async fn do_propose_slash(
+        &mut self,
+        vault_root: &VaultRoot,
+        ncn_pubkey: &Pubkey,
+        slasher: &Keypair,
+        operator_pubkey: &Pubkey,
+        amount: u64,
+        evidence_hash: [u8; 32],
+    ) -> Result<Pubkey, TestError> {
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = self.get_config(&config_pubkey).await?;
+        let clock: Clock = self.banks_client.get_sysvar().await?;
+        let base = Keypair::new();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::propose_slash(
+                &jito_vault_program::id(),
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                ncn_pubkey,
+                operator_pubkey,
+                &slasher.pubkey(),
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn_pubkey,
+                    operator_pubkey,
+                )
+                .0,
+                &NcnVaultTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn_pubkey,
+                    &vault_root.vault_pubkey,
+                )
+                .0,
+                &OperatorVaultTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    operator_pubkey,
+                    &vault_root.vault_pubkey,
+                )
+                .0,
+                &VaultNcnTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    ncn_pubkey,
+                )
+                .0,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    operator_pubkey,
+                )
+                .0,
+                &NcnVaultSlasherTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn_pubkey,
+                    &vault_root.vault_pubkey,
+                    &slasher.pubkey(),
+                )
+                .0,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    ncn_pubkey,
+                    &slasher.pubkey(),
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    ncn_pubkey,
+                    &slasher.pubkey(),
+                    operator_pubkey,
+                    clock.slot / config.epoch_length(),
+                )
+                .0,
+                &SlashProposal::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &base.pubkey(),
+                )
+                .0,
+                &base.pubkey(),
+                amount,
+                evidence_hash,
+            )],
+            Some(&slasher.pubkey()),
+            &[slasher, &base],
+            blockhash,
+        ))
+        .await?;
+
+        Ok(base.pubkey())
+    }
+
+    pub async fn do_veto_slash(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        base: &Pubkey,
+        slash_veto_admin: &Keypair,
+    ) -> Result<(), TestError> {
+        let slash_proposal = self.get_slash_proposal(vault_pubkey, base).await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::veto_slash(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault_pubkey,
+                &slash_proposal.ncn,
+                &slash_proposal.operator,
+                &slash_proposal.slasher,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    vault_pubkey,
+                    &slash_proposal.ncn,
+                    &slash_proposal.slasher,
+                    &slash_proposal.operator,
+                    slash_proposal.epoch(),
+                )
+                .0,
+                &SlashProposal::find_program_address(&jito_vault_program::id(), vault_pubkey, base)
+                    .0,
+                &slash_veto_admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, slash_veto_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_execute_slash(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        base: &Pubkey,
+        slasher: &Keypair,
+    ) -> Result<(), TestError> {
+        let slash_proposal = self.get_slash_proposal(vault_pubkey, base).await?;
+        let vault = self.get_vault(vault_pubkey).await?;
+        let slash_destination = self
+            .get_slash_destination(vault_pubkey, &slash_proposal.ncn, &slasher.pubkey())
+            .await?;
+
+        let mut instruction = jito_vault_sdk::sdk::execute_slash(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            vault_pubkey,
+            &slash_proposal.ncn,
+            &slash_proposal.operator,
+            &slasher.pubkey(),
+            &VaultOperatorDelegation::find_program_address(
+                &jito_vault_program::id(),
+                vault_pubkey,
+                &slash_proposal.operator,
+            )
+            .0,
+            &SlashProposal::find_program_address(&jito_vault_program::id(), vault_pubkey, base).0,
+            &NcnVaultSlasherTicket::find_program_address(
+                &jito_restaking_program::id(),
+                &slash_proposal.ncn,
+                vault_pubkey,
+                &slasher.pubkey(),
+            )
+            .0,
+            &get_associated_token_address(vault_pubkey, &vault.supported_mint),
+            &slash_destination,
+        );
+        self.push_vault_insurance(vault_pubkey, &mut instruction, true)
+            .await?;
+        self.push_vault_epoch_stats(vault_pubkey, &mut instruction)
+            .await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&slasher.pubkey()),
+            &[slasher],
+            blockhash,
+        ))
+        .await
     }
 
     pub async fn do_initialize_vault_operator_delegation(
@@ -690,6 +1368,70 @@ impl VaultProgramClient {
         .await
     }
 
+    pub async fn do_cooldown_vault_ncn_slasher_ticket(
+        &mut self,
+        vault_root: &VaultRoot,
+        ncn_pubkey: &Pubkey,
+        slasher: &Pubkey,
+    ) -> Result<(), TestError> {
+        let vault_slasher_ticket_pubkey = VaultNcnSlasherTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            ncn_pubkey,
+            slasher,
+        )
+        .0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[cooldown_vault_ncn_slasher_ticket(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                ncn_pubkey,
+                slasher,
+                &vault_slasher_ticket_pubkey,
+                &vault_root.vault_admin.pubkey(),
+            )],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_remove_slasher(
+        &mut self,
+        vault_root: &VaultRoot,
+        ncn_pubkey: &Pubkey,
+        slasher: &Pubkey,
+    ) -> Result<(), TestError> {
+        let vault_slasher_ticket_pubkey = VaultNcnSlasherTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            ncn_pubkey,
+            slasher,
+        )
+        .0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[remove_slasher(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                ncn_pubkey,
+                slasher,
+                &vault_slasher_ticket_pubkey,
+                &vault_root.vault_admin.pubkey(),
+            )],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_add_delegation(
         &mut self,
         vault_root: &VaultRoot,
@@ -719,7 +1461,7 @@ impl VaultProgramClient {
         config: &Pubkey,
         vault: &Pubkey,
         vrt_mint: &Keypair,
-        token_mint: &Keypair,
+        token_mint: &Pubkey,
         vault_admin: &Keypair,
         vault_base: &Keypair,
         deposit_fee_bps: u16,
@@ -735,7 +1477,7 @@ impl VaultProgramClient {
                 &config,
                 &vault,
                 &vrt_mint.pubkey(),
-                &token_mint.pubkey(),
+                token_mint,
                 &vault_admin.pubkey(),
                 &vault_base.pubkey(),
                 deposit_fee_bps,
@@ -884,265 +1626,326 @@ impl VaultProgramClient {
         .await
     }
 
-    pub async fn do_enqueue_withdraw(
-        &mut self,
-        vault_root: &VaultRoot,
-        depositor: &Keypair,
-        amount: u64,
-    ) -> Result<VaultStakerWithdrawalTicketRoot, TestError> {
-        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
-        let depositor_vrt_token_account =
-            get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint);
-
-        let base = Keypair::new();
-        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
-            &jito_vault_program::id(),
-            &vault_root.vault_pubkey,
-            &base.pubkey(),
-        )
-        .0;
-        info!(
-            "vault_staker_withdrawal_ticket: {:?}",
-            vault_staker_withdrawal_ticket
-        );
-        let vault_staker_withdrawal_ticket_token_account =
-            get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint);
-
-        self.create_ata(&vault.vrt_mint, &vault_staker_withdrawal_ticket)
-            .await?;
-
-        self.enqueue_withdraw(
-            &Config::find_program_address(&jito_vault_program::id()).0,
-            &vault_root.vault_pubkey,
-            &vault_staker_withdrawal_ticket,
-            &vault_staker_withdrawal_ticket_token_account,
-            depositor,
-            &depositor_vrt_token_account,
-            &base,
-            amount,
-        )
-        .await?;
-
-        Ok(VaultStakerWithdrawalTicketRoot {
-            base: base.pubkey(),
-        })
-    }
-
-    pub async fn do_cooldown_delegation(
+    pub async fn admin_set_protocol_fee(
         &mut self,
-        vault_root: &VaultRoot,
-        operator: &Pubkey,
-        amount: u64,
-    ) -> TestResult<()> {
-        self.cooldown_delegation(
-            &Config::find_program_address(&jito_vault_program::id()).0,
-            &vault_root.vault_pubkey,
-            operator,
-            &VaultOperatorDelegation::find_program_address(
+        config_admin: &Keypair,
+        new_protocol_fee_wallet: &Pubkey,
+        protocol_fee_bps: u16,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::admin_set_protocol_fee(
                 &jito_vault_program::id(),
-                &vault_root.vault_pubkey,
-                &operator,
-            )
-            .0,
-            &vault_root.vault_admin,
-            amount,
-        )
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &config_admin.pubkey(),
+                new_protocol_fee_wallet,
+                protocol_fee_bps,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
         .await
     }
 
-    pub async fn cooldown_delegation(
+    pub async fn admin_set_delegation_cooldown(
         &mut self,
-        config: &Pubkey,
-        vault: &Pubkey,
-        operator: &Pubkey,
-        vault_operator_delegation: &Pubkey,
-        admin: &Keypair,
-        amount: u64,
-    ) -> TestResult<()> {
+        config_admin: &Keypair,
+        delegation_cooldown_epochs: u64,
+    ) -> Result<(), TestError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self._process_transaction(&Transaction::new_signed_with_payer(
-            &[cooldown_delegation(
+            &[jito_vault_sdk::sdk::admin_set_delegation_cooldown(
                 &jito_vault_program::id(),
-                config,
-                vault,
-                operator,
-                vault_operator_delegation,
-                &admin.pubkey(),
-                amount,
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &config_admin.pubkey(),
+                delegation_cooldown_epochs,
             )],
-            Some(&self.payer.pubkey()),
-            &[&self.payer, admin],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_full_vault_update(
+    pub async fn admin_set_vault_epoch_length_bounds(
         &mut self,
-        vault_pubkey: &Pubkey,
-        operators: &[Pubkey],
+        config_admin: &Keypair,
+        min_vault_epoch_length: u64,
+        max_vault_epoch_length: u64,
     ) -> Result<(), TestError> {
-        let slot = self.banks_client.get_sysvar::<Clock>().await?.slot;
-
-        let config = self
-            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
-            .await?;
-
-        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
-            &jito_vault_program::id(),
-            &vault_pubkey,
-            slot / config.epoch_length(),
-        )
-        .0;
-        self.initialize_vault_update_state_tracker(&vault_pubkey, &vault_update_state_tracker)
-            .await?;
-
-        for operator in operators {
-            self.crank_vault_update_state_tracker(
-                &vault_pubkey,
-                operator,
-                &VaultOperatorDelegation::find_program_address(
-                    &jito_vault_program::id(),
-                    &vault_pubkey,
-                    operator,
-                )
-                .0,
-                &vault_update_state_tracker,
-            )
-            .await?;
-        }
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::admin_set_vault_epoch_length_bounds(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &config_admin.pubkey(),
+                min_vault_epoch_length,
+                max_vault_epoch_length,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
 
-        self.close_vault_update_state_tracker(
-            &vault_pubkey,
-            &vault_update_state_tracker,
-            slot / config.epoch_length(),
-        )
-        .await?;
+    pub async fn admin_set_slash_veto(
+        &mut self,
+        config_admin: &Keypair,
+        new_slash_veto_admin: &Pubkey,
+        slash_veto_window_slots: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::admin_set_slash_veto(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &config_admin.pubkey(),
+                new_slash_veto_admin,
+                slash_veto_window_slots,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
 
-        self.update_vault_balance(&vault_pubkey).await?;
+    pub async fn admin_set_config_paused(
+        &mut self,
+        config_admin: &Keypair,
+        is_paused: bool,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::admin_set_config_paused(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &config_admin.pubkey(),
+                is_paused,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
 
-        Ok(())
+    pub async fn admin_set_crank_reward(
+        &mut self,
+        config_admin: &Keypair,
+        crank_reward_lamports: u64,
+        crank_reward_bps: u16,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::admin_set_crank_reward(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &config_admin.pubkey(),
+                crank_reward_lamports,
+                crank_reward_bps,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
     }
 
-    pub async fn do_crank_vault_update_state_tracker(
+    pub async fn reconcile_vault_balance(
         &mut self,
-        vault: &Pubkey,
-        operator: &Pubkey,
+        vault_pubkey: &Pubkey,
+        admin: &Keypair,
+        max_adjustment: u64,
     ) -> TestResult<()> {
-        let slot = self.banks_client.get_sysvar::<Clock>().await?.slot;
-        let config = self
-            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
-            .await?;
-        let ncn_epoch = slot / config.epoch_length();
-        self.crank_vault_update_state_tracker(
-            vault,
-            operator,
-            &VaultOperatorDelegation::find_program_address(
-                &jito_vault_program::id(),
-                vault,
-                operator,
-            )
-            .0,
-            &VaultUpdateStateTracker::find_program_address(
+        let vault = self.get_vault(vault_pubkey).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::reconcile_vault_balance(
                 &jito_vault_program::id(),
-                vault,
-                ncn_epoch,
-            )
-            .0,
-        )
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault_pubkey,
+                &get_associated_token_address(vault_pubkey, &vault.supported_mint),
+                &admin.pubkey(),
+                max_adjustment,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
         .await
     }
 
-    pub async fn crank_vault_update_state_tracker(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn initialize_reward_distribution(
         &mut self,
-        vault: &Pubkey,
-        operator: &Pubkey,
-        vault_operator_delegation: &Pubkey,
-        vault_update_state_tracker: &Pubkey,
+        vault_pubkey: &Pubkey,
+        admin: &Keypair,
+        mint: &Pubkey,
+        operator: Option<(&Pubkey, &Pubkey)>,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        total_rewards: u64,
     ) -> TestResult<()> {
+        let operator_accounts = operator.map(|(operator, withdrawal_fee_wallet)| {
+            (
+                *operator,
+                get_associated_token_address(withdrawal_fee_wallet, mint),
+            )
+        });
         let blockhash = self.banks_client.get_latest_blockhash().await?;
-
         self._process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::sdk::crank_vault_update_state_tracker(
+            &[jito_vault_sdk::sdk::initialize_reward_distribution(
                 &jito_vault_program::id(),
                 &Config::find_program_address(&jito_vault_program::id()).0,
-                vault,
-                operator,
-                vault_operator_delegation,
-                vault_update_state_tracker,
+                vault_pubkey,
+                &RewardDistribution::find_program_address(
+                    &jito_vault_program::id(),
+                    vault_pubkey,
+                    epoch,
+                )
+                .0,
+                mint,
+                &get_associated_token_address(vault_pubkey, mint),
+                &get_associated_token_address(&admin.pubkey(), mint),
+                &admin.pubkey(),
+                operator_accounts
+                    .as_ref()
+                    .map(|(operator, operator_fee_token_account)| {
+                        (operator, operator_fee_token_account)
+                    }),
+                epoch,
+                merkle_root,
+                total_rewards,
             )],
-            Some(&self.payer.pubkey()),
-            &[&self.payer],
+            Some(&admin.pubkey()),
+            &[admin],
             blockhash,
         ))
-        .await?;
-        Ok(())
+        .await
     }
 
-    pub async fn update_vault_balance(&mut self, vault_pubkey: &Pubkey) -> TestResult<()> {
+    pub async fn claim_rewards(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        epoch: u64,
+        staker: &Keypair,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> TestResult<()> {
+        let reward_distribution_pubkey = RewardDistribution::find_program_address(
+            &jito_vault_program::id(),
+            vault_pubkey,
+            epoch,
+        )
+        .0;
+        let reward_distribution = self.get_reward_distribution(vault_pubkey, epoch).await?;
         let blockhash = self.banks_client.get_latest_blockhash().await?;
-
-        let vault = self.get_vault(vault_pubkey).await?;
-
         self._process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::sdk::update_vault_balance(
+            &[jito_vault_sdk::sdk::claim_rewards(
                 &jito_vault_program::id(),
                 &Config::find_program_address(&jito_vault_program::id()).0,
-                &vault_pubkey,
-                &get_associated_token_address(&vault_pubkey, &vault.supported_mint),
-                &vault.vrt_mint,
-                &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
-                &spl_token::ID,
+                vault_pubkey,
+                &reward_distribution_pubkey,
+                &RewardClaim::find_program_address(
+                    &jito_vault_program::id(),
+                    &reward_distribution_pubkey,
+                    &staker.pubkey(),
+                )
+                .0,
+                &get_associated_token_address(vault_pubkey, &reward_distribution.mint),
+                &get_associated_token_address(&staker.pubkey(), &reward_distribution.mint),
+                &staker.pubkey(),
+                amount,
+                proof,
             )],
-            Some(&self.payer.pubkey()),
-            &[&self.payer],
+            Some(&staker.pubkey()),
+            &[staker],
             blockhash,
         ))
-        .await?;
-
-        Ok(())
+        .await
     }
 
-    pub async fn initialize_vault_update_state_tracker(
+    pub async fn initialize_ncn_reward_router(
         &mut self,
         vault_pubkey: &Pubkey,
-        vault_update_state_tracker: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
-
         self._process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::sdk::initialize_vault_update_state_tracker(
+            &[jito_vault_sdk::sdk::initialize_ncn_reward_router(
                 &jito_vault_program::id(),
                 &Config::find_program_address(&jito_vault_program::id()).0,
                 vault_pubkey,
-                vault_update_state_tracker,
+                ncn,
+                &NcnRewardRouter::find_program_address(
+                    &jito_vault_program::id(),
+                    ncn,
+                    vault_pubkey,
+                    epoch,
+                )
+                .0,
                 &self.payer.pubkey(),
-                WithdrawalAllocationMethod::Greedy,
+                epoch,
             )],
             Some(&self.payer.pubkey()),
             &[&self.payer],
             blockhash,
         ))
-        .await?;
-        Ok(())
+        .await
     }
 
-    pub async fn close_vault_update_state_tracker(
+    /// Distributes the rewards in the NCN reward router, passing `operators` in the given order
+    pub async fn distribute_ncn_rewards(
         &mut self,
         vault_pubkey: &Pubkey,
-        vault_update_state_tracker: &Pubkey,
-        ncn_epoch: u64,
+        ncn: &Pubkey,
+        epoch: u64,
+        operators: &[Pubkey],
     ) -> TestResult<()> {
-        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = self.get_config(&config_pubkey).await?;
+        let vault = self.get_vault(vault_pubkey).await?;
+        let ncn_reward_router = NcnRewardRouter::find_program_address(
+            &jito_vault_program::id(),
+            ncn,
+            vault_pubkey,
+            epoch,
+        )
+        .0;
+
+        let mut operator_accounts = Vec::with_capacity(operators.len());
+        for operator in operators {
+            let account = self.banks_client.get_account(*operator).await?.unwrap();
+            let withdrawal_fee_wallet =
+                Operator::try_from_slice_unchecked(account.data.as_slice())?.withdrawal_fee_wallet;
+            operator_accounts.push((
+                *operator,
+                VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    vault_pubkey,
+                    operator,
+                )
+                .0,
+                get_associated_token_address(&withdrawal_fee_wallet, &vault.supported_mint),
+            ));
+        }
 
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
         self._process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::sdk::close_vault_update_state_tracker(
+            &[jito_vault_sdk::sdk::distribute_ncn_rewards(
                 &jito_vault_program::id(),
-                &Config::find_program_address(&jito_vault_program::id()).0,
+                &config_pubkey,
                 vault_pubkey,
-                vault_update_state_tracker,
-                &self.payer.pubkey(),
-                ncn_epoch,
+                ncn,
+                &ncn_reward_router,
+                &get_associated_token_address(&ncn_reward_router, &vault.supported_mint),
+                &get_associated_token_address(vault_pubkey, &vault.supported_mint),
+                &get_associated_token_address(&config.protocol_fee_wallet, &vault.supported_mint),
+                &operator_accounts,
             )],
             Some(&self.payer.pubkey()),
             &[&self.payer],
@@ -1151,189 +1954,2120 @@ impl VaultProgramClient {
         .await
     }
 
-    pub async fn enqueue_withdraw(
+    pub async fn propose_mint_authority_handoff(
+        &mut self,
+        vault: &Pubkey,
+        config_admin: &Keypair,
+        successor_program: &Pubkey,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::propose_mint_authority_handoff(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &config_admin.pubkey(),
+                successor_program,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn cancel_mint_authority_handoff(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::cancel_mint_authority_handoff(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn execute_mint_authority_handoff(
+        &mut self,
+        vault: &Pubkey,
+        vrt_mint: &Pubkey,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::execute_mint_authority_handoff(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                vrt_mint,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn enable_admin_burn(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+        burn_admin: &Pubkey,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::enable_admin_burn(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &admin.pubkey(),
+                burn_admin,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn propose_admin_burn(
+        &mut self,
+        vault: &Pubkey,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        burn_admin: &Keypair,
+        recovery: &Pubkey,
+    ) -> Result<(), TestError> {
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            vault,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::propose_admin_burn(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &vault_staker_withdrawal_ticket,
+                &burn_admin.pubkey(),
+                recovery,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, burn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn cancel_admin_burn(
+        &mut self,
+        vault: &Pubkey,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), TestError> {
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            vault,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::cancel_admin_burn(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &vault_staker_withdrawal_ticket,
+                &admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Executes the pending admin burn of the ticket, creating the recovery address' associated
+    /// token account if needed
+    pub async fn do_execute_admin_burn(
+        &mut self,
+        vault_root: &VaultRoot,
+        staker: &Pubkey,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        burn_admin: &Keypair,
+        recovery: &Pubkey,
+    ) -> Result<(), TestError> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+        self.create_ata(&vault.supported_mint, recovery).await?;
+
+        let mut instruction = jito_vault_sdk::sdk::execute_admin_burn(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &vault.vrt_mint,
+            staker,
+            &vault_staker_withdrawal_ticket,
+            &get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint),
+            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+            &get_associated_token_address(recovery, &vault.supported_mint),
+            &burn_admin.pubkey(),
+        );
+        self.push_vault_epoch_stats(&vault_root.vault_pubkey, &mut instruction)
+            .await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, burn_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_fee_wallet(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        fee_admin: &Keypair,
+        new_fee_wallet: &Pubkey,
+    ) -> Result<(), TestError> {
+        let vault_account = self.get_vault(vault).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_fee_wallet(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &fee_admin.pubkey(),
+                new_fee_wallet,
+                &get_associated_token_address(new_fee_wallet, &vault_account.vrt_mint),
+            )],
+            Some(&fee_admin.pubkey()),
+            &[fee_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_hook_program(
+        &mut self,
+        vault_root: &VaultRoot,
+        hook_program: &Pubkey,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_hook_program(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin.pubkey(),
+                hook_program,
+            )],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_migration_partner(
+        &mut self,
+        vault_root: &VaultRoot,
+        migration_partner: &Pubkey,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_migration_partner(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin.pubkey(),
+                migration_partner,
+            )],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn close_vault(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        admin: &Keypair,
+        companion_accounts: &[Pubkey],
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::close_vault(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &admin.pubkey(),
+                companion_accounts,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn initiate_vault_shutdown(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::initiate_vault_shutdown(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_vault_paused(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        pause_admin: &Keypair,
+        is_paused: bool,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_vault_paused(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &pause_admin.pubkey(),
+                is_paused,
+            )],
+            Some(&pause_admin.pubkey()),
+            &[pause_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_min_delegation_amount(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        delegation_admin: &Keypair,
+        amount: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_min_delegation_amount(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &delegation_admin.pubkey(),
+                amount,
+            )],
+            Some(&delegation_admin.pubkey()),
+            &[delegation_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_withdrawal_delay(
+        &mut self,
+        vault_root: &VaultRoot,
+        withdrawal_delay_epochs: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_withdrawal_delay(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin.pubkey(),
+                withdrawal_delay_epochs,
+            )],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_vault_epoch_length(
+        &mut self,
+        vault_root: &VaultRoot,
+        epoch_length_slots: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_vault_epoch_length(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin.pubkey(),
+                epoch_length_slots,
+            )],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn migrate_account(&mut self, account: &Pubkey) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::migrate_account(
+                &jito_vault_program::id(),
+                account,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_max_total_slashable_per_epoch(
+        &mut self,
+        vault: &Pubkey,
+        slasher_admin: &Keypair,
+        max_total_slashable_bps_per_epoch: u16,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_max_total_slashable_per_epoch(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &slasher_admin.pubkey(),
+                max_total_slashable_bps_per_epoch,
+            )],
+            Some(&slasher_admin.pubkey()),
+            &[slasher_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_operator_max_delegation(
+        &mut self,
+        vault_root: &VaultRoot,
+        operator: &Pubkey,
+        delegation_admin: &Keypair,
+        max_delegation_amount: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_operator_max_delegation(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    operator,
+                )
+                .0,
+                &delegation_admin.pubkey(),
+                max_delegation_amount,
+            )],
+            Some(&delegation_admin.pubkey()),
+            &[delegation_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_operator_delegation_paused(
+        &mut self,
+        vault_root: &VaultRoot,
+        operator: &Pubkey,
+        delegation_admin: &Keypair,
+        is_paused: bool,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_operator_delegation_paused(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    operator,
+                )
+                .0,
+                &delegation_admin.pubkey(),
+                is_paused,
+            )],
+            Some(&delegation_admin.pubkey()),
+            &[delegation_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_ban_operator(
+        &mut self,
+        vault_root: &VaultRoot,
+        operator: &Pubkey,
+        operator_admin: &Keypair,
+        reason: u8,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::ban_operator(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    operator,
+                )
+                .0,
+                &operator_admin.pubkey(),
+                reason,
+            )],
+            Some(&operator_admin.pubkey()),
+            &[operator_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_unban_operator(
+        &mut self,
+        vault_root: &VaultRoot,
+        operator: &Pubkey,
+        operator_admin: &Keypair,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::unban_operator(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    operator,
+                )
+                .0,
+                &operator_admin.pubkey(),
+            )],
+            Some(&operator_admin.pubkey()),
+            &[operator_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_initialize_delegation_strategy(
+        &mut self,
+        vault_root: &VaultRoot,
+        max_movement_per_epoch: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::initialize_delegation_strategy(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &DelegationStrategy::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                )
+                .0,
+                &vault_root.vault_admin.pubkey(),
+                &self.payer.pubkey(),
+                max_movement_per_epoch,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_initialize_vault_epoch_stats(
+        &mut self,
+        vault_root: &VaultRoot,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::initialize_vault_epoch_stats(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &VaultEpochStats::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                )
+                .0,
+                &vault_root.vault_admin.pubkey(),
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_insurance_params(
+        &mut self,
+        vault_root: &VaultRoot,
+        fee_bps: u16,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_insurance_params(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &VaultInsurance::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                )
+                .0,
+                &vault_root.vault_admin.pubkey(),
+                &self.payer.pubkey(),
+                fee_bps,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Transfers `amount` VRT from the vault admin's VRT token account to the vault's insurance
+    /// fund, creating the fund's VRT token account if needed
+    pub async fn do_fund_insurance(
+        &mut self,
+        vault_root: &VaultRoot,
+        amount: u64,
+    ) -> Result<(), TestError> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await?;
+        let vault_insurance = VaultInsurance::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let insurance_vrt_token_account =
+            get_associated_token_address(&vault_insurance, &vault.vrt_mint);
+        self.create_ata(&vault.vrt_mint, &vault_insurance).await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::fund_insurance(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_insurance,
+                &vault.vrt_mint,
+                &get_associated_token_address(&vault_root.vault_admin.pubkey(), &vault.vrt_mint),
+                &insurance_vrt_token_account,
+                &vault_root.vault_admin.pubkey(),
+                amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_delegation_strategy_weight(
+        &mut self,
+        vault_root: &VaultRoot,
+        operator: &Pubkey,
+        weight: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_delegation_strategy_weight(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    operator,
+                )
+                .0,
+                &DelegationStrategy::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                )
+                .0,
+                &vault_root.vault_admin.pubkey(),
+                weight,
+            )],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_rebalance_delegations(
+        &mut self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::rebalance_delegations(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    vault,
+                    operator,
+                )
+                .0,
+                &DelegationStrategy::find_program_address(&jito_vault_program::id(), vault).0,
+                Some(
+                    &OperatorVaultTicket::find_program_address(
+                        &jito_restaking_program::id(),
+                        operator,
+                        vault,
+                    )
+                    .0,
+                ),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_deposit_limits(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        capacity_admin: &Keypair,
+        max_deposit_per_transaction: Option<u64>,
+        max_deposit_per_wallet: Option<u64>,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_deposit_limits(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &capacity_admin.pubkey(),
+                max_deposit_per_transaction,
+                max_deposit_per_wallet,
+            )],
+            Some(&capacity_admin.pubkey()),
+            &[capacity_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_flash_deposit_protection(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        capacity_admin: &Keypair,
+        enabled: bool,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_flash_deposit_protection(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &capacity_admin.pubkey(),
+                enabled,
+            )],
+            Some(&capacity_admin.pubkey()),
+            &[capacity_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_slash_dispute_haircut(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        admin: &Keypair,
+        enabled: bool,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_slash_dispute_haircut(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &admin.pubkey(),
+                enabled,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_deposit_mode(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        admin: &Keypair,
+        deposit_mode: DepositMode,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_deposit_mode(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                &admin.pubkey(),
+                deposit_mode,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_add_to_deposit_allowlist(
+        &mut self,
+        vault_root: &VaultRoot,
+        depositor: &Pubkey,
+    ) -> Result<(), TestError> {
+        self.add_to_deposit_allowlist(
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            depositor,
+            &VaultDepositAllowlistEntry::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                depositor,
+            )
+            .0,
+            &vault_root.vault_admin,
+        )
+        .await
+    }
+
+    pub async fn add_to_deposit_allowlist(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        depositor: &Pubkey,
+        vault_deposit_allowlist_entry: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::add_to_deposit_allowlist(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                depositor,
+                vault_deposit_allowlist_entry,
+                &admin.pubkey(),
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_remove_from_deposit_allowlist(
+        &mut self,
+        vault_root: &VaultRoot,
+        depositor: &Pubkey,
+    ) -> Result<(), TestError> {
+        self.remove_from_deposit_allowlist(
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            depositor,
+            &VaultDepositAllowlistEntry::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                depositor,
+            )
+            .0,
+            &vault_root.vault_admin,
+        )
+        .await
+    }
+
+    pub async fn remove_from_deposit_allowlist(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        depositor: &Pubkey,
+        vault_deposit_allowlist_entry: &Pubkey,
+        admin: &Keypair,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::remove_from_deposit_allowlist(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                depositor,
+                vault_deposit_allowlist_entry,
+                &admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_enqueue_withdraw(
+        &mut self,
+        vault_root: &VaultRoot,
+        depositor: &Keypair,
+        amount: u64,
+    ) -> Result<VaultStakerWithdrawalTicketRoot, TestError> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let depositor_vrt_token_account =
+            get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint);
+
+        let base = Keypair::new();
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &base.pubkey(),
+        )
+        .0;
+        info!(
+            "vault_staker_withdrawal_ticket: {:?}",
+            vault_staker_withdrawal_ticket
+        );
+        let vault_staker_withdrawal_ticket_token_account =
+            get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint);
+
+        self.create_ata(&vault.vrt_mint, &vault_staker_withdrawal_ticket)
+            .await?;
+
+        self.enqueue_withdraw(
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            &vault_staker_withdrawal_ticket,
+            &vault_staker_withdrawal_ticket_token_account,
+            depositor,
+            &depositor_vrt_token_account,
+            &base,
+            amount,
+        )
+        .await?;
+
+        Ok(VaultStakerWithdrawalTicketRoot {
+            base: base.pubkey(),
+        })
+    }
+
+    pub async fn do_cooldown_delegation(
+        &mut self,
+        vault_root: &VaultRoot,
+        operator: &Pubkey,
+        amount: u64,
+    ) -> TestResult<()> {
+        self.cooldown_delegation(
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            operator,
+            &VaultOperatorDelegation::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                &operator,
+            )
+            .0,
+            &vault_root.vault_admin,
+            amount,
+        )
+        .await
+    }
+
+    pub async fn cooldown_delegation(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        vault_operator_delegation: &Pubkey,
+        admin: &Keypair,
+        amount: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[cooldown_delegation(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                operator,
+                vault_operator_delegation,
+                &admin.pubkey(),
+                amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_full_vault_update(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        operators: &[Pubkey],
+    ) -> Result<(), TestError> {
+        let slot = self.banks_client.get_sysvar::<Clock>().await?.slot;
+
+        let config = self
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await?;
+        let epoch_length = self
+            .get_vault(vault_pubkey)
+            .await?
+            .epoch_length(config.epoch_length());
+
+        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
+            &jito_vault_program::id(),
+            &vault_pubkey,
+            slot / epoch_length,
+        )
+        .0;
+        self.initialize_vault_update_state_tracker(&vault_pubkey, &vault_update_state_tracker)
+            .await?;
+
+        for operator in operators {
+            self.crank_vault_update_state_tracker(
+                &vault_pubkey,
+                operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_pubkey,
+                    operator,
+                )
+                .0,
+                &vault_update_state_tracker,
+            )
+            .await?;
+        }
+
+        self.close_vault_update_state_tracker(
+            &vault_pubkey,
+            &vault_update_state_tracker,
+            slot / epoch_length,
+        )
+        .await?;
+
+        self.update_vault_balance(&vault_pubkey).await?;
+
+        Ok(())
+    }
+
+    pub async fn do_crank_vault_update_state_tracker(
+        &mut self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+    ) -> TestResult<()> {
+        let slot = self.banks_client.get_sysvar::<Clock>().await?.slot;
+        let config = self
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await?;
+        let ncn_epoch = slot
+            / self
+                .get_vault(vault)
+                .await?
+                .epoch_length(config.epoch_length());
+        self.crank_vault_update_state_tracker(
+            vault,
+            operator,
+            &VaultOperatorDelegation::find_program_address(
+                &jito_vault_program::id(),
+                vault,
+                operator,
+            )
+            .0,
+            &VaultUpdateStateTracker::find_program_address(
+                &jito_vault_program::id(),
+                vault,
+                ncn_epoch,
+            )
+            .0,
+        )
+        .await
+    }
+
+    pub async fn crank_vault_update_state_tracker(
+        &mut self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        vault_operator_delegation: &Pubkey,
+        vault_update_state_tracker: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::crank_vault_update_state_tracker(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                operator,
+                vault_operator_delegation,
+                vault_update_state_tracker,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await?;
+        Ok(())
+    }
+
+    pub async fn snapshot_vault_operator_delegation(
+        &mut self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        ncn_epoch: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::snapshot_vault_operator_delegation(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    vault,
+                    operator,
+                )
+                .0,
+                &VaultUpdateStateTracker::find_program_address(
+                    &jito_vault_program::id(),
+                    vault,
+                    ncn_epoch,
+                )
+                .0,
+                &VaultOperatorDelegationSnapshot::find_program_address(
+                    &jito_vault_program::id(),
+                    vault,
+                    operator,
+                    ncn_epoch,
+                )
+                .0,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await?;
+        Ok(())
+    }
+
+    pub async fn initialize_operator_stake_weight(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        supported_mint: &Pubkey,
+        ncn_epoch: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::initialize_operator_stake_weight(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                ncn,
+                operator,
+                supported_mint,
+                &OperatorStakeWeight::find_program_address(
+                    &jito_vault_program::id(),
+                    ncn,
+                    operator,
+                    supported_mint,
+                    ncn_epoch,
+                )
+                .0,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn crank_operator_stake_weight(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        vault: &Pubkey,
+        ncn_epoch: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let supported_mint = self.get_vault(vault).await?.supported_mint;
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::crank_operator_stake_weight(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                ncn,
+                operator,
+                vault,
+                &NcnVaultTicket::find_program_address(&jito_restaking_program::id(), ncn, vault).0,
+                &VaultNcnTicket::find_program_address(&jito_vault_program::id(), vault, ncn).0,
+                &VaultOperatorDelegationSnapshot::find_program_address(
+                    &jito_vault_program::id(),
+                    vault,
+                    operator,
+                    ncn_epoch,
+                )
+                .0,
+                &OperatorStakeWeight::find_program_address(
+                    &jito_vault_program::id(),
+                    ncn,
+                    operator,
+                    &supported_mint,
+                    ncn_epoch,
+                )
+                .0,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn update_vault_balance(&mut self, vault_pubkey: &Pubkey) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        let vault = self.get_vault(vault_pubkey).await?;
+        let config = self
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await?;
+
+        let mut instruction = jito_vault_sdk::sdk::update_vault_balance(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            vault_pubkey,
+            &get_associated_token_address(vault_pubkey, &vault.supported_mint),
+            &vault.vrt_mint,
+            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+            &spl_token::ID,
+            &get_associated_token_address(&config.protocol_fee_wallet, &vault.vrt_mint),
+        );
+        self.push_vault_insurance(vault_pubkey, &mut instruction, false)
+            .await?;
+        if config.has_crank_reward() {
+            instruction
+                .accounts
+                .push(AccountMeta::new(self.payer.pubkey(), true));
+            self.push_crank_reward_accounts(vault_pubkey, &mut instruction, false)
+                .await?;
+        }
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn initialize_vault_update_state_tracker(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        vault_update_state_tracker: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::initialize_vault_update_state_tracker(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault_pubkey,
+                vault_update_state_tracker,
+                &self.payer.pubkey(),
+                WithdrawalAllocationMethod::Greedy,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await?;
+        Ok(())
+    }
+
+    pub async fn close_vault_update_state_tracker(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        vault_update_state_tracker: &Pubkey,
+        ncn_epoch: u64,
+    ) -> TestResult<()> {
+        let mut instruction = jito_vault_sdk::sdk::close_vault_update_state_tracker(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            vault_pubkey,
+            vault_update_state_tracker,
+            &self.payer.pubkey(),
+            ncn_epoch,
+        );
+        self.push_crank_reward_accounts(vault_pubkey, &mut instruction, true)
+            .await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn enqueue_withdraw(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        vault_staker_withdrawal_ticket: &Pubkey,
+        vault_staker_withdrawal_ticket_token_account: &Pubkey,
+        staker: &Keypair,
+        staker_vrt_token_account: &Pubkey,
+        base: &Keypair,
+        amount: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::enqueue_withdraw(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                vault_staker_withdrawal_ticket,
+                vault_staker_withdrawal_ticket_token_account,
+                &staker.pubkey(),
+                staker_vrt_token_account,
+                &base.pubkey(),
+                amount,
+            )],
+            Some(&staker.pubkey()),
+            &[staker, base],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_burn_withdrawal_ticket(
+        &mut self,
+        vault_root: &VaultRoot,
+        staker: &Keypair,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        min_amount_out: u64,
+    ) -> Result<(), TestError> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+
+        self.burn_withdrawal_ticket(
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &vault.vrt_mint,
+            &staker.pubkey(),
+            &get_associated_token_address(&staker.pubkey(), &vault.supported_mint),
+            &vault_staker_withdrawal_ticket,
+            &get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint),
+            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+            None,
+            min_amount_out,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Burns the withdrawal ticket with the staker's signature, which is required while a slash
+    /// dispute haircut applies
+    pub async fn do_burn_withdrawal_ticket_as_staker(
+        &mut self,
+        vault_root: &VaultRoot,
+        staker: &Keypair,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        min_amount_out: u64,
+    ) -> Result<(), TestError> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+
+        let mut instruction = jito_vault_sdk::sdk::burn_withdrawal_ticket(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &vault.vrt_mint,
+            &staker.pubkey(),
+            &get_associated_token_address(&staker.pubkey(), &vault.supported_mint),
+            &vault_staker_withdrawal_ticket,
+            &get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint),
+            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+            None,
+            None,
+            min_amount_out,
+        );
+        instruction.accounts[4].is_signer = true;
+        self.push_vault_epoch_stats(&vault_root.vault_pubkey, &mut instruction)
+            .await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, staker],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Burns the withdrawal ticket on behalf of `staker`, paying any auto-claim fee to the
+    /// claimer's associated token account
+    pub async fn do_auto_claim_withdrawal_ticket(
+        &mut self,
+        vault_root: &VaultRoot,
+        staker: &Pubkey,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        claimer: &Pubkey,
+        min_amount_out: u64,
+    ) -> Result<(), TestError> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+
+        self.burn_withdrawal_ticket(
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &vault.vrt_mint,
+            staker,
+            &get_associated_token_address(staker, &vault.supported_mint),
+            &vault_staker_withdrawal_ticket,
+            &get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint),
+            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+            Some(&get_associated_token_address(
+                claimer,
+                &vault.supported_mint,
+            )),
+            min_amount_out,
+        )
+        .await
+    }
+
+    pub async fn change_withdrawal_ticket_owner(
+        &mut self,
+        vault_root: &VaultRoot,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        old_owner: &Keypair,
+        new_owner: &Pubkey,
+    ) -> Result<(), TestError> {
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::change_withdrawal_ticket_owner(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_staker_withdrawal_ticket,
+                &old_owner.pubkey(),
+                new_owner,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, old_owner],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_withdrawal_ticket_auto_claim_fee(
+        &mut self,
+        vault_root: &VaultRoot,
+        staker: &Keypair,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        fee_bps: u16,
+    ) -> Result<(), TestError> {
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_withdrawal_ticket_auto_claim_fee(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_staker_withdrawal_ticket,
+                &staker.pubkey(),
+                fee_bps,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, staker],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn burn_withdrawal_ticket(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        vault_token_account: &Pubkey,
+        vrt_mint: &Pubkey,
+        staker: &Pubkey,
+        staker_token_account: &Pubkey,
+        vault_staker_withdrawal_ticket: &Pubkey,
+        vault_staker_withdrawal_ticket_token_account: &Pubkey,
+        vault_fee_token_account: &Pubkey,
+        claimer_token_account: Option<&Pubkey>,
+        min_amount_out: u64,
+    ) -> Result<(), TestError> {
+        let mut instruction = jito_vault_sdk::sdk::burn_withdrawal_ticket(
+            &jito_vault_program::id(),
+            config,
+            vault,
+            vault_token_account,
+            vrt_mint,
+            staker,
+            staker_token_account,
+            vault_staker_withdrawal_ticket,
+            vault_staker_withdrawal_ticket_token_account,
+            vault_fee_token_account,
+            None,
+            claimer_token_account,
+            min_amount_out,
+        );
+        self.push_vault_epoch_stats(vault, &mut instruction).await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn add_delegation(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        vault_operator_delegation: &Pubkey,
+        admin: &Keypair,
+        amount: u64,
+    ) -> Result<(), TestError> {
+        let operator_vault_ticket = OperatorVaultTicket::find_program_address(
+            &jito_restaking_program::id(),
+            operator,
+            vault,
+        )
+        .0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[add_delegation(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                operator,
+                vault_operator_delegation,
+                &admin.pubkey(),
+                Some(&operator_vault_ticket),
+                amount,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_mint_to(
+        &mut self,
+        vault_root: &VaultRoot,
+        depositor: &Keypair,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let vault_deposit_allowlist_entry = (vault.deposit_mode() == Ok(DepositMode::Allowlist))
+            .then(|| {
+                VaultDepositAllowlistEntry::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &depositor.pubkey(),
+                )
+                .0
+            });
+        let vault_depositor = vault.tracks_depositors().then(|| {
+            VaultDepositor::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+            )
+            .0
+        });
+        self.mint_to(
+            &vault_root.vault_pubkey,
+            &vault.vrt_mint,
+            &depositor,
+            &get_associated_token_address(&depositor.pubkey(), &vault.supported_mint),
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint),
+            None,
+            vault_deposit_allowlist_entry.as_ref(),
+            vault_depositor.as_ref(),
+            amount_in,
+            min_amount_out,
+        )
+        .await
+    }
+
+    pub async fn mint_to(
+        &mut self,
+        vault: &Pubkey,
+        vrt_mint: &Pubkey,
+        depositor: &Keypair,
+        depositor_token_account: &Pubkey,
+        vault_token_account: &Pubkey,
+        depositor_vrt_token_account: &Pubkey,
+        mint_signer: Option<&Keypair>,
+        vault_deposit_allowlist_entry: Option<&Pubkey>,
+        vault_depositor: Option<&Pubkey>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<(), TestError> {
+        let mut instruction = jito_vault_sdk::sdk::mint_to(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            vault,
+            vrt_mint,
+            &depositor.pubkey(),
+            depositor_token_account,
+            vault_token_account,
+            depositor_vrt_token_account,
+            mint_signer.map(|s| s.pubkey()).as_ref(),
+            vault_deposit_allowlist_entry,
+            vault_depositor,
+            amount_in,
+            min_amount_out,
+        );
+        self.push_vault_epoch_stats(vault, &mut instruction).await?;
+        self.push_vault_hook_program(vault, &mut instruction)
+            .await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let mut signers = vec![depositor];
+        if let Some(signer) = mint_signer {
+            signers.push(signer);
+        }
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&depositor.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Mints to the depositor like [`Self::do_mint_to`] on a vault that doesn't track depositors,
+    /// opting into a deposit receipt derived from `base`
+    pub async fn do_mint_to_with_deposit_receipt(
+        &mut self,
+        vault_root: &VaultRoot,
+        depositor: &Keypair,
+        base: &Keypair,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let mut instruction = jito_vault_sdk::sdk::mint_to(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            &vault.vrt_mint,
+            &depositor.pubkey(),
+            &get_associated_token_address(&depositor.pubkey(), &vault.supported_mint),
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint),
+            None,
+            None,
+            None,
+            amount_in,
+            min_amount_out,
+        );
+        instruction.accounts.extend([
+            AccountMeta::new(
+                DepositReceipt::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &depositor.pubkey(),
+                    &base.pubkey(),
+                )
+                .0,
+                false,
+            ),
+            AccountMeta::new_readonly(base.pubkey(), true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ]);
+        self.push_vault_epoch_stats(&vault_root.vault_pubkey, &mut instruction)
+            .await?;
+        self.push_vault_hook_program(&vault_root.vault_pubkey, &mut instruction)
+            .await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&depositor.pubkey()),
+            &[depositor, base],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn close_deposit_receipt(
+        &mut self,
+        deposit_receipt: &Pubkey,
+        depositor: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::close_deposit_receipt(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                deposit_receipt,
+                &depositor.pubkey(),
+            )],
+            Some(&depositor.pubkey()),
+            &[depositor],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_mint_to_with_sol(
+        &mut self,
+        vault_root: &VaultRoot,
+        depositor: &Keypair,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let vault_deposit_allowlist_entry = (vault.deposit_mode() == Ok(DepositMode::Allowlist))
+            .then(|| {
+                VaultDepositAllowlistEntry::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &depositor.pubkey(),
+                )
+                .0
+            });
+        let vault_depositor = vault.tracks_depositors().then(|| {
+            VaultDepositor::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+            )
+            .0
+        });
+        let mut instruction = jito_vault_sdk::sdk::mint_to_with_sol(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            &vault.vrt_mint,
+            &depositor.pubkey(),
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint),
+            None,
+            vault_deposit_allowlist_entry.as_ref(),
+            vault_depositor.as_ref(),
+            amount_in,
+            min_amount_out,
+        );
+        self.push_vault_hook_program(&vault_root.vault_pubkey, &mut instruction)
+            .await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&depositor.pubkey()),
+            &[depositor],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_burn(
+        &mut self,
+        vault_root: &VaultRoot,
+        staker: &Keypair,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let vault_depositor = vault.flash_deposit_protection().then(|| {
+            VaultDepositor::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                &staker.pubkey(),
+            )
+            .0
+        });
+        self.burn(
+            &vault_root.vault_pubkey,
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &vault.vrt_mint,
+            staker,
+            &get_associated_token_address(&staker.pubkey(), &vault.supported_mint),
+            &get_associated_token_address(&staker.pubkey(), &vault.vrt_mint),
+            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+            None,
+            vault_depositor.as_ref(),
+            amount_in,
+            min_amount_out,
+        )
+        .await
+    }
+
+    /// Migrates the staker's VRT of the source vault into the destination vault, creating the
+    /// staker's destination VRT token account
+    pub async fn do_migrate_to_vault(
         &mut self,
-        config: &Pubkey,
-        vault: &Pubkey,
-        vault_staker_withdrawal_ticket: &Pubkey,
-        vault_staker_withdrawal_ticket_token_account: &Pubkey,
+        source_vault_root: &VaultRoot,
+        destination_vault_root: &VaultRoot,
         staker: &Keypair,
-        staker_vrt_token_account: &Pubkey,
-        base: &Keypair,
-        amount: u64,
-    ) -> Result<(), TestError> {
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        let source_vault = self.get_vault(&source_vault_root.vault_pubkey).await?;
+        let destination_vault = self.get_vault(&destination_vault_root.vault_pubkey).await?;
+        self.create_ata(&destination_vault.vrt_mint, &staker.pubkey())
+            .await?;
+
+        let mut instruction = jito_vault_sdk::sdk::migrate_to_vault(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &source_vault_root.vault_pubkey,
+            &get_associated_token_address(
+                &source_vault_root.vault_pubkey,
+                &source_vault.supported_mint,
+            ),
+            &source_vault.vrt_mint,
+            &get_associated_token_address(&source_vault.fee_wallet, &source_vault.vrt_mint),
+            &staker.pubkey(),
+            &get_associated_token_address(&staker.pubkey(), &source_vault.supported_mint),
+            &get_associated_token_address(&staker.pubkey(), &source_vault.vrt_mint),
+            &destination_vault_root.vault_pubkey,
+            &get_associated_token_address(
+                &destination_vault_root.vault_pubkey,
+                &destination_vault.supported_mint,
+            ),
+            &destination_vault.vrt_mint,
+            &get_associated_token_address(&staker.pubkey(), &destination_vault.vrt_mint),
+            None,
+            amount_in,
+            min_amount_out,
+        );
+        if source_vault.flash_deposit_protection() {
+            instruction.accounts.push(AccountMeta::new_readonly(
+                VaultDepositor::find_program_address(
+                    &jito_vault_program::id(),
+                    &source_vault_root.vault_pubkey,
+                    &staker.pubkey(),
+                )
+                .0,
+                false,
+            ));
+        }
+        if destination_vault.tracks_depositors() {
+            instruction.accounts.extend([
+                AccountMeta::new(
+                    VaultDepositor::find_program_address(
+                        &jito_vault_program::id(),
+                        &destination_vault_root.vault_pubkey,
+                        &staker.pubkey(),
+                    )
+                    .0,
+                    false,
+                ),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ]);
+        }
+        self.push_vault_epoch_stats(&source_vault_root.vault_pubkey, &mut instruction)
+            .await?;
+        self.push_vault_epoch_stats(&destination_vault_root.vault_pubkey, &mut instruction)
+            .await?;
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self._process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::sdk::enqueue_withdraw(
-                &jito_vault_program::id(),
-                config,
-                vault,
-                vault_staker_withdrawal_ticket,
-                vault_staker_withdrawal_ticket_token_account,
-                &staker.pubkey(),
-                staker_vrt_token_account,
-                &base.pubkey(),
-                amount,
-            )],
+            &[instruction],
             Some(&staker.pubkey()),
-            &[staker, base],
+            &[staker],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_burn_withdrawal_ticket(
+    pub async fn do_burn_or_enqueue_withdrawal(
         &mut self,
         vault_root: &VaultRoot,
         staker: &Keypair,
-        vault_staker_withdrawal_ticket_base: &Pubkey,
+        amount_in: u64,
         min_amount_out: u64,
-    ) -> Result<(), TestError> {
+    ) -> Result<VaultStakerWithdrawalTicketRoot, TestError> {
         let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+        let vault_depositor = vault.flash_deposit_protection().then(|| {
+            VaultDepositor::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                &staker.pubkey(),
+            )
+            .0
+        });
+
+        let base = Keypair::new();
         let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
             &jito_vault_program::id(),
             &vault_root.vault_pubkey,
-            vault_staker_withdrawal_ticket_base,
+            &base.pubkey(),
         )
         .0;
+        self.create_ata(&vault.vrt_mint, &vault_staker_withdrawal_ticket)
+            .await?;
 
-        self.burn_withdrawal_ticket(
+        let mut instruction = jito_vault_sdk::sdk::burn_or_enqueue_withdrawal(
+            &jito_vault_program::id(),
             &Config::find_program_address(&jito_vault_program::id()).0,
             &vault_root.vault_pubkey,
             &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
             &vault.vrt_mint,
             &staker.pubkey(),
             &get_associated_token_address(&staker.pubkey(), &vault.supported_mint),
+            &get_associated_token_address(&staker.pubkey(), &vault.vrt_mint),
+            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
             &vault_staker_withdrawal_ticket,
             &get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint),
-            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+            &base.pubkey(),
+            None,
+            vault_depositor.as_ref(),
+            amount_in,
             min_amount_out,
-        )
+        );
+        self.push_vault_epoch_stats(&vault_root.vault_pubkey, &mut instruction)
+            .await?;
+        self.push_vault_hook_program(&vault_root.vault_pubkey, &mut instruction)
+            .await?;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&staker.pubkey()),
+            &[staker, &base],
+            blockhash,
+        ))
         .await?;
 
-        Ok(())
+        Ok(VaultStakerWithdrawalTicketRoot {
+            base: base.pubkey(),
+        })
     }
 
-    pub async fn burn_withdrawal_ticket(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn burn(
         &mut self,
-        config: &Pubkey,
         vault: &Pubkey,
         vault_token_account: &Pubkey,
         vrt_mint: &Pubkey,
-        staker: &Pubkey,
+        staker: &Keypair,
         staker_token_account: &Pubkey,
-        vault_staker_withdrawal_ticket: &Pubkey,
-        vault_staker_withdrawal_ticket_token_account: &Pubkey,
+        staker_vrt_token_account: &Pubkey,
         vault_fee_token_account: &Pubkey,
+        burn_signer: Option<&Keypair>,
+        vault_depositor: Option<&Pubkey>,
+        amount_in: u64,
         min_amount_out: u64,
     ) -> Result<(), TestError> {
+        let mut instruction = jito_vault_sdk::sdk::burn(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            vault,
+            vault_token_account,
+            vrt_mint,
+            &staker.pubkey(),
+            staker_token_account,
+            staker_vrt_token_account,
+            vault_fee_token_account,
+            burn_signer.map(|s| s.pubkey()).as_ref(),
+            vault_depositor,
+            amount_in,
+            min_amount_out,
+        );
+        self.push_vault_epoch_stats(vault, &mut instruction).await?;
+        self.push_vault_hook_program(vault, &mut instruction)
+            .await?;
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let mut signers = vec![staker];
+        if let Some(signer) = burn_signer {
+            signers.push(signer);
+        }
         self._process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::sdk::burn_withdrawal_ticket(
-                &jito_vault_program::id(),
-                config,
-                vault,
-                vault_token_account,
-                vrt_mint,
-                &staker,
-                staker_token_account,
-                vault_staker_withdrawal_ticket,
-                vault_staker_withdrawal_ticket_token_account,
-                vault_fee_token_account,
-                min_amount_out,
-            )],
-            Some(&self.payer.pubkey()),
-            &[&self.payer],
+            &[instruction],
+            Some(&staker.pubkey()),
+            &signers,
             blockhash,
         ))
         .await
     }
 
-    pub async fn add_delegation(
+    /// Approves the vault as the delegate of `owner`'s VRT token account for `amount`
+    pub async fn approve_vrt_delegate(
         &mut self,
-        config: &Pubkey,
-        vault: &Pubkey,
-        operator: &Pubkey,
-        vault_operator_delegation: &Pubkey,
-        admin: &Keypair,
+        vault_root: &VaultRoot,
+        owner: &Keypair,
+        delegate: &Pubkey,
         amount: u64,
-    ) -> Result<(), TestError> {
+    ) -> TestResult<()> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self._process_transaction(&Transaction::new_signed_with_payer(
-            &[add_delegation(
-                &jito_vault_program::id(),
-                config,
-                vault,
-                operator,
-                vault_operator_delegation,
-                &admin.pubkey(),
+            &[spl_token::instruction::approve(
+                &spl_token::id(),
+                &get_associated_token_address(&owner.pubkey(), &vault.vrt_mint),
+                delegate,
+                &owner.pubkey(),
+                &[],
                 amount,
-            )],
-            Some(&admin.pubkey()),
-            &[admin],
+            )
+            .unwrap()],
+            Some(&owner.pubkey()),
+            &[owner],
             blockhash,
         ))
         .await
     }
 
-    pub async fn do_mint_to(
+    pub async fn do_burn_delegated(
         &mut self,
         vault_root: &VaultRoot,
-        depositor: &Keypair,
+        burner: &Keypair,
+        owner: &Pubkey,
         amount_in: u64,
         min_amount_out: u64,
     ) -> TestResult<()> {
         let vault = self.get_vault(&vault_root.vault_pubkey).await.unwrap();
-        self.mint_to(
+        let vault_depositor = vault.flash_deposit_protection().then(|| {
+            VaultDepositor::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                owner,
+            )
+            .0
+        });
+        let mut instruction = jito_vault_sdk::sdk::burn_delegated(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
             &vault_root.vault_pubkey,
-            &vault.vrt_mint,
-            &depositor,
-            &get_associated_token_address(&depositor.pubkey(), &vault.supported_mint),
             &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
-            &get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint),
+            &vault.vrt_mint,
+            owner,
+            &burner.pubkey(),
+            &get_associated_token_address(owner, &vault.supported_mint),
+            &get_associated_token_address(owner, &vault.vrt_mint),
             &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
             None,
+            vault_depositor.as_ref(),
             amount_in,
             min_amount_out,
-        )
-        .await
-    }
+        );
+        self.push_vault_epoch_stats(&vault_root.vault_pubkey, &mut instruction)
+            .await?;
+        self.push_vault_hook_program(&vault_root.vault_pubkey, &mut instruction)
+            .await?;
 
-    pub async fn mint_to(
-        &mut self,
-        vault: &Pubkey,
-        vrt_mint: &Pubkey,
-        depositor: &Keypair,
-        depositor_token_account: &Pubkey,
-        vault_token_account: &Pubkey,
-        depositor_vrt_token_account: &Pubkey,
-        vault_fee_token_account: &Pubkey,
-        mint_signer: Option<&Keypair>,
-        amount_in: u64,
-        min_amount_out: u64,
-    ) -> Result<(), TestError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
-        let mut signers = vec![depositor];
-        if let Some(signer) = mint_signer {
-            signers.push(signer);
-        }
         self._process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::sdk::mint_to(
-                &jito_vault_program::id(),
-                &Config::find_program_address(&jito_vault_program::id()).0,
-                vault,
-                vrt_mint,
-                &depositor.pubkey(),
-                depositor_token_account,
-                vault_token_account,
-                depositor_vrt_token_account,
-                vault_fee_token_account,
-                mint_signer.map(|s| s.pubkey()).as_ref(),
-                amount_in,
-                min_amount_out,
-            )],
-            Some(&depositor.pubkey()),
-            &signers,
+            &[instruction],
+            Some(&burner.pubkey()),
+            &[burner],
             blockhash,
         ))
         .await
@@ -1403,53 +4137,6 @@ impl VaultProgramClient {
         .await
     }
 
-    pub async fn slash(
-        &mut self,
-        config: &Pubkey,
-        vault: &Pubkey,
-        ncn: &Pubkey,
-        operator: &Pubkey,
-        slasher: &Keypair,
-        ncn_operator_state: &Pubkey,
-        ncn_vault_ticket: &Pubkey,
-        operator_vault_ticket: &Pubkey,
-        vault_ncn_ticket: &Pubkey,
-        vault_operator_delegation: &Pubkey,
-        ncn_vault_slasher_ticket: &Pubkey,
-        vault_ncn_slasher_ticket: &Pubkey,
-        vault_ncn_slasher_operator_ticket: &Pubkey,
-        vault_token_account: &Pubkey,
-        slasher_token_account: &Pubkey,
-        amount: u64,
-    ) -> Result<(), TestError> {
-        let blockhash = self.banks_client.get_latest_blockhash().await?;
-        self._process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::sdk::slash(
-                &jito_vault_program::id(),
-                config,
-                vault,
-                ncn,
-                operator,
-                &slasher.pubkey(),
-                ncn_operator_state,
-                ncn_vault_ticket,
-                operator_vault_ticket,
-                vault_ncn_ticket,
-                vault_operator_delegation,
-                ncn_vault_slasher_ticket,
-                vault_ncn_slasher_ticket,
-                vault_ncn_slasher_operator_ticket,
-                vault_token_account,
-                slasher_token_account,
-                amount,
-            )],
-            Some(&slasher.pubkey()),
-            &[slasher],
-            blockhash,
-        ))
-        .await
-    }
-
     pub async fn create_token_metadata(
         &mut self,
         vault: &Pubkey,
@@ -1461,19 +4148,22 @@ impl VaultProgramClient {
         symbol: String,
         uri: String,
     ) -> Result<(), TestError> {
+        let mut ix = jito_vault_sdk::sdk::create_token_metadata(
+            &jito_vault_program::id(),
+            vault,
+            &admin.pubkey(),
+            vrt_mint,
+            &payer.pubkey(),
+            name,
+            symbol,
+            uri,
+        );
+        // allow tests to pass a metadata account other than the derived PDA
+        ix.accounts[4].pubkey = *metadata;
+
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self._process_transaction(&Transaction::new_signed_with_payer(
-            &[jito_vault_sdk::sdk::create_token_metadata(
-                &jito_vault_program::id(),
-                vault,
-                &admin.pubkey(),
-                vrt_mint,
-                &payer.pubkey(),
-                metadata,
-                name,
-                symbol,
-                uri,
-            )],
+            &[ix],
             Some(&payer.pubkey()),
             &[admin, payer],
             blockhash,
@@ -1486,7 +4176,6 @@ impl VaultProgramClient {
         vault: &Pubkey,
         admin: &Keypair,
         vrt_mint: &Pubkey,
-        metadata: &Pubkey,
         name: String,
         symbol: String,
         uri: String,
@@ -1498,7 +4187,6 @@ impl VaultProgramClient {
                 vault,
                 &admin.pubkey(),
                 vrt_mint,
-                metadata,
                 name,
                 symbol,
                 uri,
@@ -1510,7 +4198,70 @@ impl VaultProgramClient {
         .await
     }
 
+    /// Processes the instructions in a transaction paid by the first signer and returns the vault
+    /// events emitted
+    pub async fn process_with_events(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> TestResult<Vec<VaultEvent>> {
+        let (log_messages, _) = self.process_with_metadata(instructions, signers).await?;
+        Ok(parse_vault_events(&jito_vault_program::id(), &log_messages))
+    }
+
+    /// Processes the instructions in a transaction paid by the first signer and returns its log
+    /// messages
+    pub async fn process_with_logs(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> TestResult<Vec<String>> {
+        let (log_messages, _) = self.process_with_metadata(instructions, signers).await?;
+        Ok(log_messages)
+    }
+
+    /// Processes the instructions in a transaction paid by the first signer and returns the
+    /// compute units consumed
+    pub async fn process_with_compute_units(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> TestResult<u64> {
+        let (_, compute_units_consumed) = self.process_with_metadata(instructions, signers).await?;
+        Ok(compute_units_consumed)
+    }
+
+    /// Processes the transaction, returning its log messages and the compute units consumed. These
+    /// come from simulating it first, since processing it with metadata can race the account locks
+    /// of the transaction before it.
+    async fn process_with_metadata(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> TestResult<(Vec<String>, u64)> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&signers[0].pubkey()),
+            signers,
+            blockhash,
+        );
+        let simulation = self.banks_client.simulate_transaction(tx.clone()).await?;
+        self.banks_client
+            .process_transaction_with_preflight_and_commitment(tx, CommitmentLevel::Processed)
+            .await?;
+        Ok(simulation
+            .simulation_details
+            .map(|details| (program_data_logs(details.logs), details.units_consumed))
+            .unwrap_or_default())
+    }
+
     async fn _process_transaction(&mut self, tx: &Transaction) -> Result<(), TestError> {
+        if let Some(compute_units_recorder) = &self.compute_units_recorder {
+            return Ok(compute_units_recorder
+                .process_transaction(&mut self.banks_client, tx)
+                .await?);
+        }
         self.banks_client
             .process_transaction_with_preflight_and_commitment(
                 tx.clone(),
@@ -1520,6 +4271,15 @@ impl VaultProgramClient {
         Ok(())
     }
 
+    /// The fixture payer, which pays and cranks the vault updates
+    pub fn payer_pubkey(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    pub async fn get_lamports(&mut self, account: &Pubkey) -> Result<u64, TestError> {
+        Ok(self.banks_client.get_balance(*account).await?)
+    }
+
     pub async fn airdrop(&mut self, to: &Pubkey, sol: f64) -> Result<(), TestError> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
         self.banks_client
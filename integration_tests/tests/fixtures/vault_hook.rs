@@ -0,0 +1,40 @@
+//! A vault hook program for the tests. It logs the hook instructions it's invoked with and fails
+//! deposits and withdrawals above [`VAULT_HOOK_MAX_AMOUNT`], like a hook enforcing a cap would.
+
+use jito_vault_sdk::hook::VaultHookInstruction;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey,
+    pubkey::Pubkey,
+};
+
+pub const VAULT_HOOK_PROGRAM_ID: Pubkey = pubkey!("HookTest11111111111111111111111111111111111");
+
+/// The largest deposit or withdrawal the hook lets through
+pub const VAULT_HOOK_MAX_AMOUNT: u64 = 50_000;
+
+/// The error the hook fails deposits and withdrawals above [`VAULT_HOOK_MAX_AMOUNT`] with
+pub const VAULT_HOOK_AMOUNT_EXCEEDED: u32 = 7_000;
+
+pub fn process_vault_hook_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [vault, user] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let Some(instruction) = VaultHookInstruction::unpack(instruction_data) else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+    msg!("Vault {} user {} {:?}", vault.key, user.key, instruction);
+
+    let amount = match instruction {
+        VaultHookInstruction::AfterMint { amount_in, .. } => amount_in,
+        VaultHookInstruction::AfterBurn { vrt_burned, .. } => vrt_burned,
+    };
+    if amount > VAULT_HOOK_MAX_AMOUNT {
+        msg!("Amount {} is above the hook's limit", amount);
+        return Err(ProgramError::Custom(VAULT_HOOK_AMOUNT_EXCEEDED));
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_sdk::{error::RestakingError, instruction::MAX_BATCH_ADD_OPERATORS};
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot},
+    };
+
+    #[tokio::test]
+    async fn test_batch_add_operators_across_transactions_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        // more operators than fit in one instruction, so the SDK splits them
+        let mut operators = Vec::new();
+        for _ in 0..MAX_BATCH_ADD_OPERATORS + 2 {
+            let operator_root = restaking_program_client
+                .do_initialize_operator()
+                .await
+                .unwrap();
+            operators.push(operator_root.operator_pubkey);
+        }
+
+        restaking_program_client
+            .do_batch_add_operators(&ncn_root, &operators)
+            .await
+            .unwrap();
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.operator_count(), operators.len() as u64);
+        for (index, operator) in operators.iter().enumerate() {
+            let ncn_operator_state = restaking_program_client
+                .get_ncn_operator_state(&ncn_root.ncn_pubkey, operator)
+                .await
+                .unwrap();
+            assert_eq!(ncn_operator_state.operator, *operator);
+            assert_eq!(ncn_operator_state.index(), index as u64);
+            let operator = restaking_program_client
+                .get_operator(operator)
+                .await
+                .unwrap();
+            assert_eq!(operator.ncn_count(), 1);
+        }
+
+        // an operator can't be added twice
+        let result = restaking_program_client
+            .do_batch_add_operators(&ncn_root, &operators[..1])
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_batch_add_operators_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        let wrong_ncn_root = NcnRoot {
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            ncn_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .do_batch_add_operators(&wrong_ncn_root, &[operator_root.operator_pubkey])
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::config::Config;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{fixture::TestBuilder, restaking_client::assert_restaking_error};
+
+    #[tokio::test]
+    async fn test_config_set_admin_two_step_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        let new_admin = Keypair::new();
+        restaking_program_client
+            .do_config_set_admin(&config_admin, &new_admin.pubkey())
+            .await
+            .unwrap();
+        let config = restaking_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.admin, config_admin.pubkey());
+        assert_eq!(config.pending_admin, new_admin.pubkey());
+
+        // only the proposed admin can accept
+        let result = restaking_program_client
+            .do_config_accept_admin(&Keypair::new())
+            .await;
+        assert_restaking_error(result, RestakingError::ConfigPendingAdminInvalid);
+
+        restaking_program_client
+            .do_config_accept_admin(&new_admin)
+            .await
+            .unwrap();
+        let config = restaking_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.admin, new_admin.pubkey());
+        assert_eq!(config.pending_admin, Pubkey::default());
+
+        // the old admin lost its rights
+        let result = restaking_program_client
+            .do_config_set_epoch_length(&config_admin, 100)
+            .await;
+        assert_restaking_error(result, RestakingError::ConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_config_set_admin_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .do_config_set_admin(&Keypair::new(), &Pubkey::new_unique())
+            .await;
+        assert_restaking_error(result, RestakingError::ConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_config_set_epoch_length_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        let result = restaking_program_client
+            .do_config_set_epoch_length(&config_admin, 0)
+            .await;
+        assert_restaking_error(result, RestakingError::ConfigEpochLengthInvalid);
+
+        restaking_program_client
+            .do_config_set_epoch_length(&config_admin, 100)
+            .await
+            .unwrap();
+        let config = restaking_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.epoch_length(), 100);
+    }
+
+    #[tokio::test]
+    async fn test_config_set_parameters_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        // leaving a parameter out keeps it
+        restaking_program_client
+            .do_config_set_parameters(&config_admin, None)
+            .await
+            .unwrap();
+        let config = restaking_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.vault_program, jito_vault_program::id());
+
+        let vault_program = Pubkey::new_unique();
+        restaking_program_client
+            .do_config_set_parameters(&config_admin, Some(vault_program))
+            .await
+            .unwrap();
+        let config = restaking_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.vault_program, vault_program);
+    }
+}
@@ -1,12 +1,15 @@
 #[cfg(test)]
 mod tests {
     use jito_restaking_core::config::Config;
+    use jito_restaking_sdk::error::RestakingError;
     use solana_program::{
         clock::DEFAULT_SLOTS_PER_EPOCH, instruction::InstructionError, pubkey::Pubkey,
     };
     use solana_sdk::signature::{Keypair, Signer};
 
-    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+    use crate::fixtures::{
+        assert_ix_error, fixture::TestBuilder, restaking_client::assert_restaking_error,
+    };
 
     #[tokio::test]
     async fn test_initialize_config_ok() {
@@ -78,6 +81,9 @@ mod tests {
             .initialize_config(&Pubkey::new_unique(), &config_admin)
             .await;
 
-        assert_ix_error(transaction_error, InstructionError::InvalidAccountData);
+        assert_restaking_error(
+            transaction_error,
+            RestakingError::RestakingAccountNotCanonicalPda,
+        );
     }
 }
@@ -1,10 +1,13 @@
 #[cfg(test)]
 mod tests {
     use jito_restaking_core::{config::Config, ncn::Ncn};
+    use jito_restaking_sdk::error::RestakingError;
     use solana_program::{instruction::InstructionError, pubkey::Pubkey};
     use solana_sdk::signature::{Keypair, Signer};
 
-    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+    use crate::fixtures::{
+        assert_ix_error, fixture::TestBuilder, restaking_client::assert_restaking_error,
+    };
 
     #[tokio::test]
     async fn test_initialize_ncn_ok() {
@@ -68,7 +71,7 @@ mod tests {
             .initialize_ncn(&config, &incorrect_ncn_pubkey, &ncn_admin, &ncn_base)
             .await;
 
-        assert_ix_error(result, InstructionError::InvalidAccountData);
+        assert_restaking_error(result, RestakingError::RestakingAccountNotCanonicalPda);
     }
 
     #[tokio::test]
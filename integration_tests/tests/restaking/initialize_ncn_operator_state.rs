@@ -3,12 +3,9 @@ mod tests {
     use jito_jsm_core::slot_toggle::SlotToggleState;
     use jito_restaking_core::{config::Config, ncn_operator_state::NcnOperatorState};
     use jito_restaking_sdk::error::RestakingError;
-    use solana_program::instruction::InstructionError;
     use solana_sdk::signature::Keypair;
 
-    use crate::fixtures::{
-        assert_ix_error, fixture::TestBuilder, restaking_client::assert_restaking_error,
-    };
+    use crate::fixtures::{fixture::TestBuilder, restaking_client::assert_restaking_error};
 
     #[tokio::test]
     async fn test_initialize_ncn_operator_state_ok() {
@@ -140,7 +137,10 @@ mod tests {
             )
             .await;
 
-        assert_ix_error(transaction_error, InstructionError::InvalidAccountData);
+        assert_restaking_error(
+            transaction_error,
+            RestakingError::RestakingAccountNotCanonicalPda,
+        );
     }
 
     #[tokio::test]
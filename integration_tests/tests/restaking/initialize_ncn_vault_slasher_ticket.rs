@@ -6,12 +6,10 @@ mod tests {
         ncn_vault_ticket::NcnVaultTicket,
     };
     use jito_restaking_sdk::error::RestakingError;
-    use solana_program::{instruction::InstructionError, pubkey::Pubkey};
+    use solana_program::pubkey::Pubkey;
     use solana_sdk::signature::{Keypair, Signer};
 
-    use crate::fixtures::{
-        assert_ix_error, fixture::TestBuilder, restaking_client::assert_restaking_error,
-    };
+    use crate::fixtures::{fixture::TestBuilder, restaking_client::assert_restaking_error};
 
     #[tokio::test]
     async fn test_initialize_ncn_vault_slasher_ticket_ok() {
@@ -118,7 +116,10 @@ mod tests {
             )
             .await;
 
-        assert_ix_error(transaction_error, InstructionError::InvalidAccountData);
+        assert_restaking_error(
+            transaction_error,
+            RestakingError::RestakingAccountNotCanonicalPda,
+        );
     }
 
     #[tokio::test]
@@ -1,10 +1,13 @@
 #[cfg(test)]
 mod tests {
     use jito_restaking_core::{config::Config, operator::Operator};
+    use jito_restaking_sdk::error::RestakingError;
     use solana_program::{instruction::InstructionError, pubkey::Pubkey};
     use solana_sdk::signature::{Keypair, Signer};
 
-    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+    use crate::fixtures::{
+        assert_ix_error, fixture::TestBuilder, restaking_client::assert_restaking_error,
+    };
 
     #[tokio::test]
     async fn test_initialize_operator_ok() {
@@ -66,7 +69,10 @@ mod tests {
             )
             .await;
 
-        assert_ix_error(transaction_error, InstructionError::InvalidAccountData);
+        assert_restaking_error(
+            transaction_error,
+            RestakingError::RestakingAccountNotCanonicalPda,
+        );
     }
 
     #[tokio::test]
@@ -1,3 +1,5 @@
+mod batch_add_operators;
+mod config_admin;
 mod initialize_config;
 mod initialize_ncn;
 mod initialize_ncn_operator_state;
@@ -6,8 +8,17 @@ mod initialize_ncn_vault_ticket;
 mod initialize_operator;
 mod initialize_operator_vault_ticket;
 mod ncn_cooldown_operator;
+mod ncn_freeze_operator;
+mod ncn_request_slash;
 mod ncn_set_admin;
+mod ncn_set_max_slashable_per_epoch;
+mod ncn_set_slash_destination;
+mod ncn_task_window;
 mod ncn_warmup_operator;
 mod operator_cooldown_ncn;
 mod operator_set_admin;
+mod operator_set_commission;
+mod operator_set_metadata;
 mod operator_warmup_ncn;
+mod pda;
+mod renew_ticket;
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, ncn_operator_state::NcnOperatorState};
+    use jito_restaking_sdk::error::RestakingError;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot, RestakingProgramClient},
+    };
+
+    async fn setup_ncn_and_operator(
+        fixture: &mut TestBuilder,
+    ) -> (RestakingProgramClient, NcnRoot, OperatorRoot) {
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        (restaking_program_client, ncn_root, operator_root)
+    }
+
+    #[tokio::test]
+    async fn test_ncn_freeze_and_unfreeze_operator_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        restaking_program_client
+            .do_ncn_freeze_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_operator_state.frozen_until_slot(),
+            slot + config.epoch_length() * NcnOperatorState::FREEZE_EPOCHS
+        );
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator.freeze_count(), 1);
+        assert!(operator.is_frozen(slot));
+
+        restaking_program_client
+            .do_ncn_unfreeze_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert!(!ncn_operator_state.has_freeze());
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator.freeze_count(), 0);
+        assert!(!operator.is_frozen(slot));
+    }
+
+    #[tokio::test]
+    async fn test_ncn_freeze_operator_expires() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        restaking_program_client
+            .do_ncn_freeze_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length() * NcnOperatorState::FREEZE_EPOCHS)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert!(!operator.is_frozen(slot));
+    }
+
+    #[tokio::test]
+    async fn test_ncn_freeze_operator_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        let wrong_ncn_root = NcnRoot {
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            ncn_admin: operator_root.operator_admin.insecure_clone(),
+        };
+        let result = restaking_program_client
+            .do_ncn_freeze_operator(&wrong_ncn_root, &operator_root.operator_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnSlasherAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_unfreeze_operator_not_frozen_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        let result = restaking_program_client
+            .do_ncn_unfreeze_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorNotFrozen);
+    }
+}
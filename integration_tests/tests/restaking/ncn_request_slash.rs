@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_sdk::error::RestakingError;
+    use jito_vault_core::{
+        config::Config, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+        vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    };
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        restaking_client::assert_restaking_error,
+    };
+
+    const MAX_SLASH_AMOUNT: u64 = 100;
+    const MINT_AMOUNT: u64 = 100_000;
+    const DELEGATION_AMOUNT: u64 = 10_000;
+
+    /// Sets up a vault delegating to an operator with a slasher, up-to-date after warping two
+    /// epochs so the delegation is staked
+    async fn setup_delegated_vault() -> (TestBuilder, ConfiguredVault) {
+        let mut fixture = TestBuilder::new().await;
+        let mut configured_vault = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+        let ConfiguredVault {
+            vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = &mut configured_vault;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(
+                vault_root,
+                &operator_roots[0].operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(
+                &vault_root.vault_pubkey,
+                &[operator_roots[0].operator_pubkey],
+            )
+            .await
+            .unwrap();
+
+        (fixture, configured_vault)
+    }
+
+    #[tokio::test]
+    async fn test_ncn_request_slash_ok() {
+        let (mut fixture, configured_vault) = setup_delegated_vault().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = configured_vault;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let slasher = &slashers_amounts[0].0;
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &slasher.pubkey())
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher.pubkey(),
+                &operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                    &operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_ncn_request_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - MAX_SLASH_AMOUNT);
+        let vault_ncn_slasher_operator_ticket = vault_program_client
+            .get_vault_ncn_slasher_operator_ticket(
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher.pubkey(),
+                &operator_pubkey,
+                epoch,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_ncn_slasher_operator_ticket.slashed(),
+            MAX_SLASH_AMOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_request_slash_operator_not_in_ncn_fails() {
+        let (mut fixture, configured_vault) = setup_delegated_vault().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = configured_vault;
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        let slasher = &slashers_amounts[0].0;
+
+        // The NCN drops the operator, once the cooldown is over it's no longer a member
+        restaking_program_client
+            .do_ncn_cooldown_operator(&ncn_root, &operator_pubkey)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_ncn_request_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorStateUnslashable);
+    }
+}
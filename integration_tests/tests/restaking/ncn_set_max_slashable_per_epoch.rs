@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::config::Config;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot},
+    };
+
+    #[tokio::test]
+    async fn test_ncn_set_max_slashable_per_epoch_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let slasher = Keypair::new();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                100,
+            )
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_ncn_set_max_slashable_per_epoch(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                50,
+            )
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        let ticket = restaking_program_client
+            .get_ncn_vault_slasher_ticket(
+                &ncn_root.ncn_pubkey,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+            )
+            .await
+            .unwrap();
+        // the change only takes effect in the next epoch
+        assert_eq!(ticket.max_slashable_per_epoch_at(epoch), 100);
+        assert_eq!(ticket.max_slashable_per_epoch_at(epoch + 1), 50);
+        assert_eq!(ticket.pending_max_slashable_epoch(), epoch + 1);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_max_slashable_per_epoch_bad_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let slasher = Keypair::new();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                100,
+            )
+            .await
+            .unwrap();
+
+        let bad_ncn_root = NcnRoot {
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            ncn_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .do_ncn_set_max_slashable_per_epoch(
+                &bad_ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                50,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnSlasherAdminInvalid);
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_sdk::{error::RestakingError, instruction::SlashDestination};
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot},
+    };
+
+    #[tokio::test]
+    async fn test_ncn_set_slash_destination_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let slasher = Keypair::new();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                100,
+            )
+            .await
+            .unwrap();
+
+        let ticket = restaking_program_client
+            .get_ncn_vault_slasher_ticket(
+                &ncn_root.ncn_pubkey,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            ticket.slash_destination().unwrap(),
+            SlashDestination::Slasher
+        );
+
+        // insurance needs a wallet to send the slashed funds to
+        let result = restaking_program_client
+            .do_ncn_set_slash_destination(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                None,
+                SlashDestination::Insurance,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnInsuranceWalletMissing);
+
+        let insurance_wallet = Keypair::new();
+        restaking_program_client
+            .do_ncn_set_slash_destination(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                Some(&insurance_wallet.pubkey()),
+                SlashDestination::Insurance,
+            )
+            .await
+            .unwrap();
+
+        let ticket = restaking_program_client
+            .get_ncn_vault_slasher_ticket(
+                &ncn_root.ncn_pubkey,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            ticket.slash_destination().unwrap(),
+            SlashDestination::Insurance
+        );
+        assert_eq!(ticket.insurance_wallet, insurance_wallet.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_slash_destination_bad_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let slasher = Keypair::new();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                100,
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .do_ncn_set_slash_destination(
+                &NcnRoot {
+                    ncn_pubkey: ncn_root.ncn_pubkey,
+                    ncn_admin: Keypair::new(),
+                },
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                None,
+                SlashDestination::Burn,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnSlasherAdminInvalid);
+    }
+}
@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, ncn_operator_state::NcnOperatorState};
+    use jito_restaking_sdk::error::RestakingError;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot, RestakingProgramClient},
+    };
+
+    /// Sets up an NCN and operator that are actively opted in to each other
+    async fn setup_ncn_and_operator(
+        fixture: &mut TestBuilder,
+    ) -> (RestakingProgramClient, NcnRoot, OperatorRoot, u64) {
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_warmup_ncn(&operator_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        (
+            restaking_program_client,
+            ncn_root,
+            operator_root,
+            config.epoch_length(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ncn_open_and_close_task_window_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root, epoch_length) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let end_slot = slot + epoch_length;
+        restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator_root.operator_pubkey, end_slot)
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_operator_state.task_window_end_slot(), end_slot);
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator.task_window_count(), 1);
+        assert!(operator.is_stake_locked(slot));
+
+        restaking_program_client
+            .do_ncn_close_task_window(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert!(!ncn_operator_state.has_task_window());
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator.task_window_count(), 0);
+        assert!(!operator.is_stake_locked(slot));
+    }
+
+    #[tokio::test]
+    async fn test_ncn_open_task_window_too_long_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root, epoch_length) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let result = restaking_program_client
+            .do_ncn_open_task_window(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                slot + epoch_length * NcnOperatorState::MAX_TASK_WINDOW_EPOCHS + 1,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnTaskWindowInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_extend_task_window_past_cap_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root, epoch_length) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        let open_slot = fixture.get_current_slot().await.unwrap();
+        let max_end_slot = open_slot + epoch_length * NcnOperatorState::MAX_TASK_WINDOW_EPOCHS;
+        restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator_root.operator_pubkey, open_slot + 1)
+            .await
+            .unwrap();
+
+        // The cap is measured from the slot the window opened, not the slot it's extended
+        fixture.warp_slot_incremental(epoch_length).await.unwrap();
+        let result = restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator_root.operator_pubkey, max_end_slot + 1)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnTaskWindowInvalid);
+
+        for extension in 1..=NcnOperatorState::MAX_TASK_WINDOW_EXTENSIONS {
+            restaking_program_client
+                .do_ncn_open_task_window(
+                    &ncn_root,
+                    &operator_root.operator_pubkey,
+                    max_end_slot - u64::from(extension),
+                )
+                .await
+                .unwrap();
+        }
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_operator_state.task_window_start_slot(), open_slot);
+        assert_eq!(
+            ncn_operator_state.task_window_extensions(),
+            NcnOperatorState::MAX_TASK_WINDOW_EXTENSIONS
+        );
+
+        let result = restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator_root.operator_pubkey, max_end_slot)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnTaskWindowExtensionsExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_open_task_window_not_opted_in_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let result = restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator_root.operator_pubkey, slot + 10)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnTaskWindowInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_open_task_window_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root, _epoch_length) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        let wrong_ncn_root = NcnRoot {
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            ncn_admin: operator_root.operator_admin.insecure_clone(),
+        };
+        let slot = fixture.get_current_slot().await.unwrap();
+        let result = restaking_program_client
+            .do_ncn_open_task_window(&wrong_ncn_root, &operator_root.operator_pubkey, slot + 10)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_close_task_window_not_open_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root, _epoch_length) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        let result = restaking_program_client
+            .do_ncn_close_task_window(&ncn_root, &operator_root.operator_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnTaskWindowNotOpen);
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, operator::Operator};
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, OperatorRoot, RestakingProgramClient},
+    };
+
+    async fn setup(fixture: &TestBuilder) -> (RestakingProgramClient, OperatorRoot) {
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        (restaking_program_client, operator_root)
+    }
+
+    #[tokio::test]
+    async fn test_operator_set_commission_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, operator_root) = setup(&fixture).await;
+
+        restaking_program_client
+            .do_operator_set_commission(&operator_root, 500)
+            .await
+            .unwrap();
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator.commission_bps(), 500);
+
+        // the commission can't move further from where it started the epoch
+        fixture.warp_slot_incremental(1).await.unwrap();
+        let result = restaking_program_client
+            .do_operator_set_commission(&operator_root, 501)
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorCommissionChangeTooLarge);
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_set_commission(&operator_root, 1_000)
+            .await
+            .unwrap();
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator.commission_bps(), 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_operator_set_commission_exceeds_max_fails() {
+        let fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, operator_root) = setup(&fixture).await;
+
+        let result = restaking_program_client
+            .do_operator_set_commission(&operator_root, Operator::MAX_COMMISSION_BPS + 1)
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorCommissionInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_operator_set_commission_bad_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, operator_root) = setup(&fixture).await;
+
+        let bad_operator_root = OperatorRoot {
+            operator_pubkey: operator_root.operator_pubkey,
+            operator_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .do_operator_set_commission(&bad_operator_root, 100)
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorAdminInvalid);
+    }
+}
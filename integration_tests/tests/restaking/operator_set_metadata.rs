@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, OperatorRoot, RestakingProgramClient},
+    };
+
+    async fn setup(fixture: &TestBuilder) -> (RestakingProgramClient, OperatorRoot) {
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        (restaking_program_client, operator_root)
+    }
+
+    #[tokio::test]
+    async fn test_operator_set_metadata_ok() {
+        let fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, operator_root) = setup(&fixture).await;
+
+        restaking_program_client
+            .do_operator_set_metadata(&operator_root, "Jito Operator", "https://jito.network")
+            .await
+            .unwrap();
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator.name(), "Jito Operator");
+        assert_eq!(operator.website(), "https://jito.network");
+    }
+
+    #[tokio::test]
+    async fn test_operator_set_metadata_too_long_fails() {
+        let fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, operator_root) = setup(&fixture).await;
+
+        let result = restaking_program_client
+            .do_operator_set_metadata(&operator_root, &"a".repeat(33), "")
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorMetadataInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_operator_set_metadata_bad_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, operator_root) = setup(&fixture).await;
+
+        let bad_operator_root = OperatorRoot {
+            operator_pubkey: operator_root.operator_pubkey,
+            operator_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .do_operator_set_metadata(&bad_operator_root, "Jito Operator", "")
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorAdminInvalid);
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{
+        ncn_operator_state::NcnOperatorState, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+        ncn_vault_ticket::NcnVaultTicket, operator_vault_ticket::OperatorVaultTicket,
+    };
+    use jito_restaking_sdk::pda;
+    use solana_sdk::signer::Signer;
+
+    use crate::fixtures::fixture::{ConfiguredVault, TestBuilder};
+
+    #[tokio::test]
+    async fn test_pda_matches_accounts_created_on_chain() {
+        let mut fixture = TestBuilder::new().await;
+        let program_id = jito_restaking_program::id();
+
+        let ConfiguredVault {
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[100])
+            .await
+            .unwrap();
+        let vault = vault_root.vault_pubkey;
+        let ncn = ncn_root.ncn_pubkey;
+        let operator = operator_roots[0].operator_pubkey;
+        let slasher = slashers_amounts[0].0.pubkey();
+
+        let (config_pubkey, config_bump) = pda::derive_config(&program_id);
+        let config = restaking_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.bump, config_bump);
+
+        let ncn_account = restaking_program_client.get_ncn(&ncn).await.unwrap();
+        assert_eq!(
+            pda::derive_ncn(&program_id, &ncn_account.base),
+            (ncn, ncn_account.bump)
+        );
+
+        let operator_account = restaking_program_client
+            .get_operator(&operator)
+            .await
+            .unwrap();
+        assert_eq!(
+            pda::derive_operator(&program_id, &operator_account.base),
+            (operator, operator_account.bump)
+        );
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn, &operator)
+            .await
+            .unwrap();
+        assert_eq!(
+            pda::derive_ncn_operator_state(&program_id, &ncn, &operator),
+            (
+                NcnOperatorState::find_program_address(&program_id, &ncn, &operator).0,
+                ncn_operator_state.bump
+            )
+        );
+
+        let ncn_vault_ticket = restaking_program_client
+            .get_ncn_vault_ticket(&ncn, &vault)
+            .await
+            .unwrap();
+        assert_eq!(
+            pda::derive_ncn_vault_ticket(&program_id, &ncn, &vault),
+            (
+                NcnVaultTicket::find_program_address(&program_id, &ncn, &vault).0,
+                ncn_vault_ticket.bump
+            )
+        );
+
+        let operator_vault_ticket = restaking_program_client
+            .get_operator_vault_ticket(&operator, &vault)
+            .await
+            .unwrap();
+        assert_eq!(
+            pda::derive_operator_vault_ticket(&program_id, &operator, &vault),
+            (
+                OperatorVaultTicket::find_program_address(&program_id, &operator, &vault).0,
+                operator_vault_ticket.bump
+            )
+        );
+
+        let ncn_vault_slasher_ticket = restaking_program_client
+            .get_ncn_vault_slasher_ticket(&ncn, &vault, &slasher)
+            .await
+            .unwrap();
+        assert_eq!(
+            pda::derive_ncn_vault_slasher_ticket(&program_id, &ncn, &vault, &slasher),
+            (
+                NcnVaultSlasherTicket::find_program_address(&program_id, &ncn, &vault, &slasher).0,
+                ncn_vault_slasher_ticket.bump
+            )
+        );
+    }
+}
@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, ncn::Ncn};
+    use jito_restaking_sdk::error::RestakingError;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot, RestakingProgramClient},
+    };
+
+    const TICKET_EXPIRY_EPOCHS: u64 = Ncn::MIN_TICKET_EXPIRY_EPOCHS;
+
+    /// Sets up an NCN with ticket expiry and an operator that are actively opted in to each other
+    async fn setup_ncn_and_operator(
+        fixture: &mut TestBuilder,
+    ) -> (RestakingProgramClient, NcnRoot, OperatorRoot, u64) {
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_ncn_set_ticket_expiry(&ncn_root, TICKET_EXPIRY_EPOCHS)
+            .await
+            .unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_warmup_ncn(&operator_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        (
+            restaking_program_client,
+            ncn_root,
+            operator_root,
+            config.epoch_length(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_ticket_expiry_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.ticket_expiry_epochs(), 0);
+
+        restaking_program_client
+            .do_ncn_set_ticket_expiry(&ncn_root, TICKET_EXPIRY_EPOCHS)
+            .await
+            .unwrap();
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.ticket_expiry_epochs(), TICKET_EXPIRY_EPOCHS);
+
+        let result = restaking_program_client
+            .do_ncn_set_ticket_expiry(&ncn_root, Ncn::MIN_TICKET_EXPIRY_EPOCHS - 1)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnTicketExpiryInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_operator_state_expires_and_renews() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root, epoch_length) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_ne!(ncn_operator_state.expiry_slot(), 0);
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert!(!ncn_operator_state.is_expired(slot));
+        restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator_root.operator_pubkey, slot + 1)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_ncn_close_task_window(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        // the relationship lapses without renewal
+        fixture.warp_slot_incremental(epoch_length).await.unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert!(ncn_operator_state.is_expired(slot));
+        let result = restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator_root.operator_pubkey, slot + 1)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnTaskWindowInvalid);
+
+        restaking_program_client
+            .do_renew_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_operator_state.expiry_slot(),
+            slot + TICKET_EXPIRY_EPOCHS * epoch_length
+        );
+        restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator_root.operator_pubkey, slot + 1)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_renew_ncn_vault_ticket_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // tickets created before the NCN enabled expiry never expire until renewed
+        let ticket = restaking_program_client
+            .get_ncn_vault_ticket(&ncn_root.ncn_pubkey, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ticket.expiry_slot(), 0);
+
+        restaking_program_client
+            .do_ncn_set_ticket_expiry(&ncn_root, TICKET_EXPIRY_EPOCHS)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_renew_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let ticket = restaking_program_client
+            .get_ncn_vault_ticket(&ncn_root.ncn_pubkey, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_ne!(ticket.expiry_slot(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_renew_ticket_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let (mut restaking_program_client, ncn_root, operator_root, _epoch_length) =
+            setup_ncn_and_operator(&mut fixture).await;
+
+        let wrong_ncn_root = NcnRoot {
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            ncn_admin: operator_root.operator_admin.insecure_clone(),
+        };
+        let result = restaking_program_client
+            .do_renew_ncn_operator_state(&wrong_ncn_root, &operator_root.operator_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
+    }
+}
@@ -133,4 +133,52 @@ mod tests {
             .await;
         assert_vault_error(result, VaultError::VaultInsufficientFunds);
     }
+
+    #[tokio::test]
+    async fn test_add_delegation_frozen_operator_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 100_000)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_ncn_freeze_operator(&ncn_root, &operator)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator, 50_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorFrozen);
+
+        restaking_program_client
+            .do_ncn_unfreeze_operator(&ncn_root, &operator)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 50_000)
+            .await
+            .unwrap();
+    }
 }
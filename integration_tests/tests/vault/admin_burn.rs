@@ -0,0 +1,240 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+        ADMIN_BURN_TIMELOCK_EPOCHS,
+    };
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultStakerWithdrawalTicketRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_admin_burn_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+
+        let burn_admin = Keypair::new();
+        vault_program_client
+            .enable_admin_burn(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &burn_admin.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let recovery = Pubkey::new_unique();
+        let result = vault_program_client
+            .propose_admin_burn(&vault_root.vault_pubkey, &base, &depositor, &recovery)
+            .await;
+        assert_vault_error(result, VaultError::VaultBurnAdminInvalid);
+
+        vault_program_client
+            .propose_admin_burn(&vault_root.vault_pubkey, &base, &burn_admin, &recovery)
+            .await
+            .unwrap();
+
+        // the staker can't move the ticket out of reach while the admin burn is pending
+        let result = vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, 0)
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminBurnPending);
+
+        let result = vault_program_client
+            .do_execute_admin_burn(
+                &vault_root,
+                &depositor.pubkey(),
+                &base,
+                &burn_admin,
+                &recovery,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminBurnTimelockActive);
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        for _ in 0..ADMIN_BURN_TIMELOCK_EPOCHS {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+                .await
+                .unwrap();
+        }
+
+        vault_program_client
+            .do_execute_admin_burn(
+                &vault_root,
+                &depositor.pubkey(),
+                &base,
+                &burn_admin,
+                &recovery,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_supply(), 0);
+        assert_eq!(vault.tokens_deposited(), 0);
+
+        let recovery_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &recovery,
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(recovery_token_account.amount, MINT_AMOUNT);
+
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &base,
+        )
+        .0;
+        assert!(!vault_program_client
+            .vault_exists(&vault_staker_withdrawal_ticket)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_admin_burn_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let burn_admin = Keypair::new();
+        vault_program_client
+            .enable_admin_burn(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &burn_admin.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .propose_admin_burn(
+                &vault_root.vault_pubkey,
+                &base,
+                &burn_admin,
+                &Pubkey::new_unique(),
+            )
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .cancel_admin_burn(&vault_root.vault_pubkey, &base, &depositor)
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+
+        vault_program_client
+            .cancel_admin_burn(&vault_root.vault_pubkey, &base, &vault_root.vault_admin)
+            .await
+            .unwrap();
+
+        let ticket = vault_program_client
+            .get_vault_staker_withdrawal_ticket(
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+                &base,
+            )
+            .await
+            .unwrap();
+        assert!(!ticket.is_admin_burn_pending());
+    }
+
+    #[tokio::test]
+    async fn test_enable_admin_burn_after_deposit_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .enable_admin_burn(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &Pubkey::new_unique(),
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminBurnEnableAfterDeposit);
+    }
+}
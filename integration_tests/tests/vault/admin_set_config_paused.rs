@@ -0,0 +1,271 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, deposit_receipt::DepositReceipt};
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultStakerWithdrawalTicketRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_admin_set_config_paused_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, _vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert!(!config.is_paused());
+
+        vault_program_client
+            .admin_set_config_paused(&config_admin, true)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert!(config.is_paused());
+
+        vault_program_client
+            .admin_set_config_paused(&config_admin, false)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert!(!config.is_paused());
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_config_paused_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .admin_set_config_paused(&vault_root.vault_admin, true)
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_paused_config_rejects_mutations() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 2 * MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .admin_set_config_paused(&vault_config_admin, true)
+            .await
+            .unwrap();
+
+        // the vault itself isn't paused
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(!vault.is_paused());
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::ConfigPaused);
+
+        let result = vault_program_client
+            .do_burn(&vault_root, &depositor, 1_000, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::ConfigPaused);
+
+        let result = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::ConfigPaused);
+
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::ConfigPaused);
+
+        let result = vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::ConfigPaused);
+
+        vault_program_client
+            .admin_set_config_paused(&vault_config_admin, false)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), 2 * MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_paused_config_rejects_transfers() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let vault_admin_pubkey = vault_root.vault_admin.pubkey();
+        vault_program_client
+            .configure_depositor(&vault_root, &vault_admin_pubkey, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(
+                &vault_root,
+                &vault_root.vault_admin,
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        let deposit_receipt_base = Keypair::new();
+        vault_program_client
+            .do_mint_to_with_deposit_receipt(
+                &vault_root,
+                &depositor,
+                &deposit_receipt_base,
+                MINT_AMOUNT,
+                0,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(
+                &vault_root.vault_pubkey,
+                &[operator_roots[0].operator_pubkey],
+            )
+            .await
+            .unwrap();
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_set_insurance_params(&vault_root, 0)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .admin_set_config_paused(&vault_config_admin, true)
+            .await
+            .unwrap();
+
+        let new_owner = Keypair::new();
+        let result = vault_program_client
+            .change_withdrawal_ticket_owner(&vault_root, &base, &depositor, &new_owner.pubkey())
+            .await;
+        assert_vault_error(result, VaultError::ConfigPaused);
+
+        let result = vault_program_client
+            .do_fund_insurance(&vault_root, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::ConfigPaused);
+
+        let deposit_receipt = DepositReceipt::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &depositor.pubkey(),
+            &deposit_receipt_base.pubkey(),
+        )
+        .0;
+        let result = vault_program_client
+            .close_deposit_receipt(&deposit_receipt, &depositor)
+            .await;
+        assert_vault_error(result, VaultError::ConfigPaused);
+
+        vault_program_client
+            .admin_set_config_paused(&vault_config_admin, false)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .change_withdrawal_ticket_owner(&vault_root, &base, &depositor, &new_owner.pubkey())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_fund_insurance(&vault_root, 1_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .close_deposit_receipt(&deposit_receipt, &depositor)
+            .await
+            .unwrap();
+    }
+}
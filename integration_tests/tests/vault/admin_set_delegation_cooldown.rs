@@ -0,0 +1,278 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultStakerWithdrawalTicketRoot},
+    };
+
+    const DELEGATION_COOLDOWN_EPOCHS: u64 = 3;
+
+    #[tokio::test]
+    async fn test_admin_set_delegation_cooldown_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, _vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            config.delegation_cooldown_epochs(),
+            Config::DEFAULT_DELEGATION_COOLDOWN_EPOCHS
+        );
+
+        vault_program_client
+            .admin_set_delegation_cooldown(&config_admin, DELEGATION_COOLDOWN_EPOCHS)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            config.delegation_cooldown_epochs(),
+            DELEGATION_COOLDOWN_EPOCHS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_delegation_cooldown_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .admin_set_delegation_cooldown(&vault_root.vault_admin, DELEGATION_COOLDOWN_EPOCHS)
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_delegation_cooldown_invalid_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, _vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .admin_set_delegation_cooldown(&config_admin, 0)
+            .await;
+        assert_vault_error(result, VaultError::ConfigDelegationCooldownEpochsInvalid);
+
+        let result = vault_program_client
+            .admin_set_delegation_cooldown(
+                &config_admin,
+                Config::MAX_DELEGATION_COOLDOWN_EPOCHS + 1,
+            )
+            .await;
+        assert_vault_error(result, VaultError::ConfigDelegationCooldownEpochsInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_delegation_cools_down_for_configured_epochs() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        vault_program_client
+            .admin_set_delegation_cooldown(&vault_config_admin, DELEGATION_COOLDOWN_EPOCHS)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator, 50_000)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        // the undelegated stake stays at-risk while it cools down
+        for _ in 0..DELEGATION_COOLDOWN_EPOCHS {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+                .await
+                .unwrap();
+
+            let vault = vault_program_client
+                .get_vault(&vault_root.vault_pubkey)
+                .await
+                .unwrap();
+            assert_eq!(vault.delegation_state.staked_amount(), 50_000);
+            assert_eq!(vault.delegation_state.cooling_down_amount(), 50_000);
+            assert_eq!(vault.delegation_state.total_security().unwrap(), 100_000);
+        }
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state.staked_amount(), 50_000);
+        assert_eq!(vault.delegation_state.cooling_down_amount(), 0);
+        assert_eq!(vault.delegation_state.total_security().unwrap(), 50_000);
+    }
+
+    #[tokio::test]
+    async fn test_withdrawal_ticket_matures_with_configured_cooldown() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        vault_program_client
+            .admin_set_delegation_cooldown(&vault_config_admin, DELEGATION_COOLDOWN_EPOCHS)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 100_000)
+            .await
+            .unwrap();
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator, 100_000)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        // the ticket can't be claimed while the stake paying for it is still cooling down
+        for _ in 0..DELEGATION_COOLDOWN_EPOCHS {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+                .await
+                .unwrap();
+
+            let vault = vault_program_client
+                .get_vault(&vault_root.vault_pubkey)
+                .await
+                .unwrap();
+            assert_eq!(vault.delegation_state.cooling_down_amount(), 100_000);
+            assert_eq!(vault.vrt_cooling_down_amount(), 100_000);
+            assert_eq!(vault.vrt_ready_to_claim_amount(), 0);
+
+            let result = vault_program_client
+                .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, 100_000)
+                .await;
+            assert_vault_error(
+                result,
+                VaultError::VaultStakerWithdrawalTicketNotWithdrawable,
+            );
+        }
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state.total_security().unwrap(), 0);
+        assert_eq!(vault.vrt_cooling_down_amount(), 0);
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 100_000);
+
+        vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, 100_000)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), 0);
+        assert_eq!(vault.vrt_supply(), 0);
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 0);
+    }
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, MAX_FEE_BPS};
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+    const DEPOSIT_FEE_BPS: u16 = 100; // 1%
+    const PROTOCOL_FEE_BPS: u16 = 2_500; // 25%
+
+    #[tokio::test]
+    async fn test_admin_set_protocol_fee_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, _vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.protocol_fee_bps(), 0);
+        assert_eq!(config.protocol_fee_wallet, config_admin.pubkey());
+
+        let protocol_fee_wallet = Keypair::new();
+        vault_program_client
+            .admin_set_protocol_fee(
+                &config_admin,
+                &protocol_fee_wallet.pubkey(),
+                PROTOCOL_FEE_BPS,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.protocol_fee_bps(), PROTOCOL_FEE_BPS);
+        assert_eq!(config.protocol_fee_wallet, protocol_fee_wallet.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_protocol_fee_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        // the vault admin is not the config admin
+        let result = vault_program_client
+            .admin_set_protocol_fee(
+                &vault_root.vault_admin,
+                &vault_root.vault_admin.pubkey(),
+                PROTOCOL_FEE_BPS,
+            )
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_protocol_fee_above_cap_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, _vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .admin_set_protocol_fee(&config_admin, &config_admin.pubkey(), MAX_FEE_BPS + 1)
+            .await;
+        assert_vault_error(result, VaultError::ConfigProtocolFeeCapExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_protocol_fee_skimmed_from_vault_fees() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(DEPOSIT_FEE_BPS, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let protocol_fee_wallet = Keypair::new();
+        vault_program_client
+            .create_ata(&vault.vrt_mint, &protocol_fee_wallet.pubkey())
+            .await
+            .unwrap();
+        vault_program_client
+            .admin_set_protocol_fee(
+                &vault_config_admin,
+                &protocol_fee_wallet.pubkey(),
+                PROTOCOL_FEE_BPS,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(
+                &vault_root.vault_pubkey,
+                &[operator_roots[0].operator_pubkey],
+            )
+            .await
+            .unwrap();
+
+        let fee_amount = MINT_AMOUNT * DEPOSIT_FEE_BPS as u64 / 10_000;
+        let protocol_fee_amount = fee_amount * PROTOCOL_FEE_BPS as u64 / 10_000;
+
+        let vault_fee_account = vault_program_client
+            .get_reward_fee_token_account(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault_fee_account.amount, fee_amount - protocol_fee_amount);
+
+        let protocol_fee_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &protocol_fee_wallet.pubkey(),
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(protocol_fee_account.amount, protocol_fee_amount);
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT);
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+    const BAN_REASON: u8 = 7;
+
+    #[tokio::test]
+    async fn test_banned_operator_rejects_delegation_until_unbanned() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_ban_operator(&vault_root, &operator, &vault_root.vault_admin, BAN_REASON)
+            .await
+            .unwrap();
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operator)
+            .await
+            .unwrap();
+        assert!(vault_operator_delegation.is_banned());
+        assert_eq!(vault_operator_delegation.ban_reason(), BAN_REASON);
+
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorBanned);
+
+        vault_program_client
+            .do_unban_operator(&vault_root, &operator, &vault_root.vault_admin)
+            .await
+            .unwrap();
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operator)
+            .await
+            .unwrap();
+        assert!(!vault_operator_delegation.is_banned());
+        assert_eq!(vault_operator_delegation.ban_reason(), 0);
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 1_000)
+            .await
+            .unwrap();
+
+        // unbanning an operator that isn't banned fails
+        let result = vault_program_client
+            .do_unban_operator(&vault_root, &operator, &vault_root.vault_admin)
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorNotBanned);
+    }
+
+    #[tokio::test]
+    async fn test_ban_operator_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_ban_operator(
+                &vault_root,
+                &operator_roots[0].operator_pubkey,
+                &wrong_admin,
+                BAN_REASON,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorAdminInvalid);
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_burn_requires_vault_update_each_epoch() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_burn(&vault_root, &depositor, 1_000, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultUpdateNeeded);
+
+        let operator_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_burn(&vault_root, &depositor, 1_000, 1_000)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - 1_000);
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT - 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_burn_below_min_amount_out_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 100, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        // 1% withdrawal fee leaves 9_900 VRT to burn for 9_900 tokens
+        let result = vault_program_client
+            .do_burn(&vault_root, &depositor, 10_000, 9_901)
+            .await;
+        assert_vault_error(result, VaultError::SlippageError);
+
+        vault_program_client
+            .do_burn(&vault_root, &depositor, 10_000, 9_900)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - 9_900);
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT - 9_900);
+    }
+}
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_burn_delegated_ok() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let owner = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &owner.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &owner, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let burner = Keypair::new();
+        vault_program_client
+            .airdrop(&burner.pubkey(), 1.0)
+            .await
+            .unwrap();
+        vault_program_client
+            .approve_vrt_delegate(&vault_root, &owner, &burner.pubkey(), 10_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_burn_delegated(&vault_root, &burner, &owner.pubkey(), 10_000, 10_000)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT - 10_000);
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - 10_000);
+
+        let owner_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &owner.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(owner_token_account.amount, 10_000);
+
+        let owner_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &owner.pubkey(),
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(owner_vrt_token_account.amount, MINT_AMOUNT - 10_000);
+        assert_eq!(owner_vrt_token_account.delegated_amount, 0);
+        assert!(owner_vrt_token_account.delegate.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_burn_delegated_without_approval_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let owner = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &owner.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &owner, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let burner = Keypair::new();
+        vault_program_client
+            .airdrop(&burner.pubkey(), 1.0)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_burn_delegated(&vault_root, &burner, &owner.pubkey(), 10_000, 10_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultBurnDelegateInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_burn_delegated_over_approval_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let owner = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &owner.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &owner, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let burner = Keypair::new();
+        vault_program_client
+            .airdrop(&burner.pubkey(), 1.0)
+            .await
+            .unwrap();
+        vault_program_client
+            .approve_vrt_delegate(&vault_root, &owner, &burner.pubkey(), 10_000)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_burn_delegated(&vault_root, &burner, &owner.pubkey(), 10_001, 10_001)
+            .await;
+        assert_vault_error(result, VaultError::VaultBurnDelegateInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_burn_delegated_by_third_party_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let owner = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &owner.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &owner, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let burner = Keypair::new();
+        vault_program_client
+            .approve_vrt_delegate(&vault_root, &owner, &burner.pubkey(), 10_000)
+            .await
+            .unwrap();
+
+        // only the approved burner can burn, and choose the minimum amount out
+        let third_party = Keypair::new();
+        vault_program_client
+            .airdrop(&third_party.pubkey(), 1.0)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_burn_delegated(&vault_root, &third_party, &owner.pubkey(), 10_000, 0)
+            .await;
+        assert_vault_error(result, VaultError::VaultBurnDelegateInvalid);
+
+        // approving the vault doesn't let anyone burn on its behalf
+        vault_program_client
+            .approve_vrt_delegate(&vault_root, &owner, &vault_root.vault_pubkey, 10_000)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_burn_delegated(&vault_root, &third_party, &owner.pubkey(), 10_000, 0)
+            .await;
+        assert_vault_error(result, VaultError::VaultBurnDelegateInvalid);
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT);
+    }
+}
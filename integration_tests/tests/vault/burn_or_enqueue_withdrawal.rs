@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    };
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::VaultStakerWithdrawalTicketRoot,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+    const DELEGATION_AMOUNT: u64 = 60_000;
+
+    #[tokio::test]
+    async fn test_burn_or_enqueue_withdrawal() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        // let vault operator ticket warmup
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let operator_root_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_roots[0].operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        // 40_000 idle tokens cover the burn
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_burn_or_enqueue_withdrawal(&vault_root, &depositor, 30_000, 30_000)
+            .await
+            .unwrap();
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &base,
+        )
+        .0;
+        assert!(!vault_program_client
+            .vault_exists(&vault_staker_withdrawal_ticket)
+            .await
+            .unwrap());
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - 30_000);
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT - 30_000);
+        let depositor_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_token_account.amount, 30_000);
+
+        // Only 10_000 idle tokens remain, so the rest is enqueued
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_burn_or_enqueue_withdrawal(&vault_root, &depositor, 20_000, 20_000)
+            .await
+            .unwrap();
+        let vault_staker_withdrawal_ticket = vault_program_client
+            .get_vault_staker_withdrawal_ticket(
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+                &base,
+            )
+            .await
+            .unwrap();
+        assert_eq!(vault_staker_withdrawal_ticket.vrt_amount(), 20_000);
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - 30_000);
+        assert_eq!(vault.vrt_enqueued_for_cooldown_amount(), 20_000);
+    }
+}
@@ -553,9 +553,155 @@ mod tests {
                 &vault_staker_withdrawal_ticket,
                 &get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint),
                 &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+                None,
                 MIN_AMOUNT_OUT,
             )
             .await;
         assert_vault_error(result, VaultError::VaultStakerWithdrawalTicketInvalidStaker);
     }
+
+    /// A keeper burning a ticket that opted into auto-claiming receives the auto-claim fee
+    #[tokio::test]
+    async fn test_burn_withdrawal_ticket_auto_claim_fee() {
+        const MINT_AMOUNT: u64 = 100_000;
+        const AUTO_CLAIM_FEE_BPS: u16 = 100;
+
+        let deposit_fee_bps = 0;
+        let withdraw_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![];
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            restaking_program_client: _,
+            vault_config_admin: _,
+            vault_root,
+            restaking_config_admin: _,
+            ncn_root: _,
+            operator_roots,
+            slashers_amounts: _,
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdraw_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_roots[0].operator_pubkey, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        // the fee is capped
+        let result = vault_program_client
+            .set_withdrawal_ticket_auto_claim_fee(&vault_root, &depositor, &base, 10_001)
+            .await;
+        assert_vault_error(result, VaultError::VaultFeeCapExceeded);
+
+        // only the staker can opt in
+        let result = vault_program_client
+            .set_withdrawal_ticket_auto_claim_fee(
+                &vault_root,
+                &Keypair::new(),
+                &base,
+                AUTO_CLAIM_FEE_BPS,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultStakerWithdrawalTicketInvalidStaker);
+
+        vault_program_client
+            .set_withdrawal_ticket_auto_claim_fee(
+                &vault_root,
+                &depositor,
+                &base,
+                AUTO_CLAIM_FEE_BPS,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator_roots[0].operator_pubkey, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        for _ in 0..2 {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(
+                    &vault_root.vault_pubkey,
+                    &[operator_roots[0].operator_pubkey],
+                )
+                .await
+                .unwrap();
+        }
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let claimer = Pubkey::new_unique();
+        fixture
+            .create_ata(&vault.supported_mint, &claimer)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_auto_claim_withdrawal_ticket(
+                &vault_root,
+                &depositor.pubkey(),
+                &base,
+                &claimer,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let auto_claim_fee = MINT_AMOUNT * AUTO_CLAIM_FEE_BPS as u64 / 10_000;
+        let claimer_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &claimer,
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(claimer_token_account.amount, auto_claim_fee);
+
+        let depositor_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_token_account.amount, MINT_AMOUNT - auto_claim_fee);
+    }
 }
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultStakerWithdrawalTicketRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_change_withdrawal_ticket_owner() {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(
+                &vault_root.vault_pubkey,
+                &[operator_roots[0].operator_pubkey],
+            )
+            .await
+            .unwrap();
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        // only the ticket's staker can hand it off
+        let new_owner = Keypair::new();
+        let result = vault_program_client
+            .change_withdrawal_ticket_owner(&vault_root, &base, &new_owner, &new_owner.pubkey())
+            .await;
+        assert_vault_error(result, VaultError::VaultStakerWithdrawalTicketInvalidStaker);
+
+        vault_program_client
+            .change_withdrawal_ticket_owner(&vault_root, &base, &depositor, &new_owner.pubkey())
+            .await
+            .unwrap();
+        let ticket = vault_program_client
+            .get_vault_staker_withdrawal_ticket(
+                &vault_root.vault_pubkey,
+                &new_owner.pubkey(),
+                &base,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ticket.staker, new_owner.pubkey());
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use jito_vault_core::{config::Config, vault_update_state_tracker::VaultUpdateStateTracker};
+    use jito_vault_core::{
+        config::Config, delegation_snapshot::DelegationSnapshot,
+        vault_update_state_tracker::VaultUpdateStateTracker,
+    };
     use jito_vault_sdk::error::VaultError;
     use solana_sdk::signature::{Keypair, Signer};
 
@@ -339,4 +342,100 @@ mod tests {
         assert_eq!(vault.vrt_cooling_down_amount(), 0);
         assert_eq!(vault.vrt_ready_to_claim_amount(), 100_000);
     }
+
+    #[tokio::test]
+    async fn test_close_update_state_tracker_stores_delegation_snapshot() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let mut fixture = TestBuilder::new().await;
+
+        let deposit_fee_bps = 0;
+        let withdraw_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 3;
+        let slasher_amounts = vec![];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdraw_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_roots[0].operator_pubkey, 10_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_roots[2].operator_pubkey, 30_000)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let operator_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+
+        let mut delegations = Vec::with_capacity(operator_pubkeys.len());
+        for operator in operator_pubkeys.iter() {
+            let vault_operator_delegation = vault_program_client
+                .get_vault_operator_delegation(&vault_root.vault_pubkey, operator)
+                .await
+                .unwrap();
+            delegations.push((
+                *operator,
+                vault_operator_delegation.delegation_state.staked_amount(),
+            ));
+        }
+        assert_eq!(
+            delegations.iter().map(|(_, amount)| amount).sum::<u64>(),
+            40_000
+        );
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault.delegation_snapshot_epoch(),
+            slot / config.epoch_length()
+        );
+        assert_eq!(
+            vault.delegation_snapshot_root(),
+            &DelegationSnapshot::compute_root(&delegations).unwrap()
+        );
+    }
 }
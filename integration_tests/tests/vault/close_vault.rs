@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{fixture::TestBuilder, vault_client::assert_vault_error};
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_close_vault_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .close_vault(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &[],
+            )
+            .await
+            .unwrap();
+
+        assert!(!vault_program_client
+            .vault_exists(&vault_root.vault_pubkey)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_close_vault_with_supply_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let result = vault_program_client
+            .close_vault(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &[],
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultNotEmpty);
+
+        vault_program_client
+            .do_burn(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .close_vault(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &[],
+            )
+            .await
+            .unwrap();
+        assert!(!vault_program_client
+            .vault_exists(&vault_root.vault_pubkey)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_close_vault_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let result = vault_program_client
+            .close_vault(&config_pubkey, &vault_root.vault_pubkey, &wrong_admin, &[])
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+}
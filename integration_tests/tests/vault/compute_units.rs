@@ -0,0 +1,1733 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, path::PathBuf};
+
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use jito_restaking_core::ncn::Ncn;
+    use jito_restaking_sdk::instruction::{
+        NcnAdminRole, OperatorAdminRole, RestakingInstruction, SlashDestination,
+    };
+    use jito_vault_core::{
+        config::Config, deposit_receipt::DepositReceipt, ncn_reward_router::NcnRewardRouter,
+        reward_distribution::RewardDistribution,
+        vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+        vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+        vault_operator_delegation::VaultOperatorDelegation,
+        vault_update_state_tracker::VaultUpdateStateTracker, ADMIN_BURN_TIMELOCK_EPOCHS,
+        MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS,
+    };
+    use jito_vault_sdk::{
+        compute_units::recommended_compute_units,
+        inline_mpl_token_metadata,
+        instruction::{DepositMode, VaultAdminRole, VaultInstruction, WithdrawalAllocationMethod},
+    };
+    use solana_program::{native_token::sol_to_lamports, pubkey::Pubkey, system_program};
+    use solana_sdk::{
+        instruction::Instruction,
+        signature::{Keypair, Signer},
+    };
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        compute_units::ComputeUnitsRecord,
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{VaultProgramClient, VaultRoot, VaultStakerWithdrawalTicketRoot},
+        vault_hook::VAULT_HOOK_PROGRAM_ID,
+    };
+
+    /// How far over its baseline an instruction can go before it's flagged as a regression, even if
+    /// it's still within its recommended compute units
+    const BASELINE_THRESHOLD_BPS: u64 = 1_000;
+    /// The token metadata and associated token account programs search for the bump of a PDA
+    /// derived from a freshly generated mint or vault, which costs a `create_program_address` per
+    /// attempt and varies from run to run
+    const PDA_BUMP_SEARCH_ALLOWANCE: u64 = 12 * 1_500;
+    /// Every instruction is measured, so a new one needs a scenario as well as a baseline
+    const VAULT_INSTRUCTION_COUNT: u8 = 89;
+    const RESTAKING_INSTRUCTION_COUNT: u8 = 42;
+
+    const MINT_AMOUNT: u64 = 100_000;
+    const DELEGATION_AMOUNT: u64 = 10_000;
+    const MAX_SLASH_AMOUNT: u64 = 100;
+    const SLASH_AMOUNT: u64 = 10;
+    const SLASH_VETO_WINDOW_SLOTS: u64 = 100;
+
+    /// Processes the instruction and checks it stays within its recommended compute units
+    async fn assert_within_recommended_compute_units(
+        vault_program_client: &mut VaultProgramClient,
+        instruction: Instruction,
+        signers: &[&Keypair],
+    ) {
+        let vault_instruction = VaultInstruction::try_from_slice(&instruction.data).unwrap();
+        let recommended = recommended_compute_units(&vault_instruction);
+        let consumed = vault_program_client
+            .process_with_compute_units(&[instruction], signers)
+            .await
+            .unwrap();
+
+        println!(
+            "{:?} consumed {} compute units, {} recommended",
+            vault_instruction, consumed, recommended
+        );
+        assert!(consumed <= u64::from(recommended));
+    }
+
+    #[tokio::test]
+    async fn test_recommended_compute_units_cover_measured() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(10, 10, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+
+        let depositor_token_account =
+            get_associated_token_address(&depositor.pubkey(), &vault.supported_mint);
+        let vault_token_account =
+            get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint);
+        let depositor_vrt_token_account =
+            get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint);
+        let vault_fee_token_account =
+            get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint);
+
+        assert_within_recommended_compute_units(
+            &mut vault_program_client,
+            jito_vault_sdk::sdk::mint_to(
+                &jito_vault_program::id(),
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault.vrt_mint,
+                &depositor.pubkey(),
+                &depositor_token_account,
+                &vault_token_account,
+                &depositor_vrt_token_account,
+                None,
+                None,
+                None,
+                100_000,
+                0,
+            ),
+            &[&depositor],
+        )
+        .await;
+
+        let operator = operator_roots[0].operator_pubkey;
+        assert_within_recommended_compute_units(
+            &mut vault_program_client,
+            jito_vault_sdk::sdk::add_delegation(
+                &jito_vault_program::id(),
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &operator,
+                )
+                .0,
+                &vault_root.vault_admin.pubkey(),
+                None,
+                10_000,
+            ),
+            &[&depositor, &vault_root.vault_admin],
+        )
+        .await;
+
+        assert_within_recommended_compute_units(
+            &mut vault_program_client,
+            jito_vault_sdk::sdk::burn(
+                &jito_vault_program::id(),
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_token_account,
+                &vault.vrt_mint,
+                &depositor.pubkey(),
+                &depositor_token_account,
+                &depositor_vrt_token_account,
+                &vault_fee_token_account,
+                None,
+                None,
+                1_000,
+                0,
+            ),
+            &[&depositor],
+        )
+        .await;
+
+        // fees can only change once an epoch has passed since the last change
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length() * 2 + 1)
+            .await
+            .unwrap();
+        assert_within_recommended_compute_units(
+            &mut vault_program_client,
+            jito_vault_sdk::sdk::set_fees(
+                &jito_vault_program::id(),
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin.pubkey(),
+                None,
+                None,
+                Some(20),
+            ),
+            &[&vault_root.vault_admin],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_recommended_compute_units_cover_vault_update() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let operator = operator_roots[0].operator_pubkey;
+        let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &operator,
+        )
+        .0;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 10_000)
+            .await
+            .unwrap();
+
+        // the update cycle walks every operator delegation, so it's the first to approach the limit
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        let ncn_epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            ncn_epoch,
+        )
+        .0;
+
+        assert_within_recommended_compute_units(
+            &mut vault_program_client,
+            jito_vault_sdk::sdk::initialize_vault_update_state_tracker(
+                &jito_vault_program::id(),
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+                &vault_root.vault_admin.pubkey(),
+                WithdrawalAllocationMethod::Greedy,
+            ),
+            &[&vault_root.vault_admin],
+        )
+        .await;
+        assert_within_recommended_compute_units(
+            &mut vault_program_client,
+            jito_vault_sdk::sdk::crank_vault_update_state_tracker(
+                &jito_vault_program::id(),
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &operator,
+                &vault_operator_delegation,
+                &vault_update_state_tracker,
+            ),
+            &[&vault_root.vault_admin],
+        )
+        .await;
+        assert_within_recommended_compute_units(
+            &mut vault_program_client,
+            jito_vault_sdk::sdk::close_vault_update_state_tracker(
+                &jito_vault_program::id(),
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+                &vault_root.vault_admin.pubkey(),
+                ncn_epoch,
+            ),
+            &[&vault_root.vault_admin],
+        )
+        .await;
+
+        assert_within_recommended_compute_units(
+            &mut vault_program_client,
+            jito_vault_sdk::sdk::cooldown_delegation(
+                &jito_vault_program::id(),
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &operator,
+                &vault_operator_delegation,
+                &vault_root.vault_admin.pubkey(),
+                1_000,
+            ),
+            &[&vault_root.vault_admin],
+        )
+        .await;
+    }
+
+    /// The compute units each vault instruction consumed in the scenarios below when the baselines
+    /// were last recorded, the most expensive path where an instruction is measured more than once
+    const fn vault_baseline_compute_units(instruction: &VaultInstruction) -> u64 {
+        match instruction {
+            VaultInstruction::InitializeConfig => 268,
+            VaultInstruction::InitializeVault { .. } => 3_315,
+            VaultInstruction::InitializeVaultWithMint => 1,
+            VaultInstruction::InitializeVaultOperatorDelegation => 408,
+            VaultInstruction::InitializeVaultNcnTicket => 408,
+            VaultInstruction::InitializeVaultNcnSlasherOperatorTicket => 548,
+            VaultInstruction::InitializeVaultNcnSlasherTicket => 408,
+            VaultInstruction::WarmupVaultNcnTicket => 281,
+            VaultInstruction::CooldownVaultNcnTicket => 281,
+            VaultInstruction::WarmupVaultNcnSlasherTicket => 281,
+            VaultInstruction::CooldownVaultNcnSlasherTicket => 281,
+            VaultInstruction::MintTo { .. } => 9_825,
+            VaultInstruction::Burn { .. } => 14_233,
+            VaultInstruction::EnqueueWithdrawal { .. } => 5_193,
+            VaultInstruction::ChangeWithdrawalTicketOwner => 1,
+            VaultInstruction::BurnWithdrawTicket { .. } => 22_173,
+            VaultInstruction::SetDepositCapacity { .. } => 1,
+            VaultInstruction::SetFees { .. } => 141,
+            VaultInstruction::AdminWithdraw { .. } => 1,
+            VaultInstruction::SetAdmin => 1,
+            VaultInstruction::SetSecondaryAdmin(..) => 1,
+            VaultInstruction::AddDelegation { .. } => 141,
+            VaultInstruction::CooldownDelegation { .. } => 141,
+            VaultInstruction::UpdateVaultBalance => 141,
+            VaultInstruction::InitializeVaultUpdateStateTracker { .. } => 688,
+            VaultInstruction::CrankVaultUpdateStateTracker => 141,
+            VaultInstruction::CloseVaultUpdateStateTracker { .. } => 141,
+            VaultInstruction::CreateTokenMetadata { .. } => 34_840 + PDA_BUMP_SEARCH_ALLOWANCE,
+            VaultInstruction::UpdateTokenMetadata { .. } => 25_227,
+            VaultInstruction::Slash { .. } => 5_066,
+            VaultInstruction::SetVaultPaused { .. } => 1,
+            VaultInstruction::SetFeeWallet => 1,
+            VaultInstruction::AdminSetProtocolFee { .. } => 1,
+            VaultInstruction::SetMinDelegationAmount { .. } => 1,
+            VaultInstruction::SetDepositMode { .. } => 1,
+            VaultInstruction::AddToDepositAllowlist => 268,
+            VaultInstruction::RemoveFromDepositAllowlist => 1,
+            VaultInstruction::SetDepositLimits { .. } => 1,
+            VaultInstruction::SetWithdrawalTicketAutoClaimFee { .. } => 1,
+            VaultInstruction::ProposeMintAuthorityHandoff => 141,
+            VaultInstruction::CancelMintAuthorityHandoff => 1,
+            VaultInstruction::ExecuteMintAuthorityHandoff => 3_102,
+            VaultInstruction::AdminSetDelegationCooldown { .. } => 1,
+            VaultInstruction::AdminSetSlashVeto { .. } => 1,
+            VaultInstruction::ProposeSlash { .. } => 548,
+            VaultInstruction::VetoSlash => 1,
+            VaultInstruction::ExecuteSlash => 4_786,
+            VaultInstruction::MintToWithSol { .. } => 7_968,
+            VaultInstruction::CloseVault => 1,
+            VaultInstruction::SetOperatorMaxDelegation { .. } => 1,
+            VaultInstruction::SetOperatorDelegationPaused { .. } => 1,
+            VaultInstruction::InitializeDelegationStrategy { .. } => 268,
+            VaultInstruction::SetDelegationStrategyWeight { .. } => 1,
+            VaultInstruction::RebalanceDelegations => 141,
+            VaultInstruction::BurnOrEnqueueWithdrawal { .. } => 14_233,
+            VaultInstruction::InitializeVaultEpochStats => 268,
+            VaultInstruction::SetFlashDepositProtection { .. } => 1,
+            VaultInstruction::EnableAdminBurn => 1,
+            VaultInstruction::ProposeAdminBurn => 141,
+            VaultInstruction::CancelAdminBurn => 1,
+            VaultInstruction::ExecuteAdminBurn => 17_248,
+            VaultInstruction::AdminSetConfigPaused { .. } => 1,
+            VaultInstruction::ReconcileVaultBalance { .. } => 1,
+            VaultInstruction::SnapshotVaultOperatorDelegation => 408,
+            VaultInstruction::InitializeRewardDistribution { .. } => 4_913,
+            VaultInstruction::ClaimRewards { .. } => 5_053,
+            VaultInstruction::InitiateVaultShutdown => 141,
+            VaultInstruction::InitializeNcnRewardRouter { .. } => 268,
+            VaultInstruction::DistributeNcnRewards => 9_431,
+            VaultInstruction::SetInsuranceParams { .. } => 268,
+            VaultInstruction::FundInsurance { .. } => 4_646,
+            VaultInstruction::SetHookProgram => 1,
+            VaultInstruction::BurnDelegated { .. } => 14_423,
+            VaultInstruction::AdminSetCrankReward { .. } => 1,
+            VaultInstruction::InitializeVaultTokenAccount => 20_499 + PDA_BUMP_SEARCH_ALLOWANCE,
+            VaultInstruction::CloseDepositReceipt => 1,
+            VaultInstruction::SetMaxTotalSlashablePerEpoch { .. } => 1,
+            VaultInstruction::SetMigrationPartner => 1,
+            VaultInstruction::MigrateToVault { .. } => 23_650,
+            VaultInstruction::RemoveSlasher => 141,
+            VaultInstruction::SetWithdrawalDelay { .. } => 1,
+            VaultInstruction::MigrateAccount => 118,
+            VaultInstruction::InitializeOperatorStakeWeight => 408,
+            VaultInstruction::CrankOperatorStakeWeight => 141,
+            VaultInstruction::AdminSetVaultEpochLengthBounds { .. } => 1,
+            VaultInstruction::SetVaultEpochLength { .. } => 141,
+            VaultInstruction::BanOperator { .. } => 1,
+            VaultInstruction::UnbanOperator => 1,
+            VaultInstruction::SetSlashDisputeHaircut { .. } => 1,
+        }
+    }
+
+    /// The compute units each restaking instruction consumed in the scenarios below when the
+    /// baselines were last recorded
+    const fn restaking_baseline_compute_units(instruction: &RestakingInstruction) -> u64 {
+        match instruction {
+            RestakingInstruction::InitializeConfig => 268,
+            RestakingInstruction::InitializeNcn => 268,
+            RestakingInstruction::InitializeOperator => 268,
+            RestakingInstruction::InitializeNcnVaultSlasherTicket(..) => 408,
+            RestakingInstruction::InitializeNcnVaultTicket => 408,
+            RestakingInstruction::InitializeOperatorVaultTicket => 408,
+            RestakingInstruction::InitializeNcnOperatorState => 408,
+            RestakingInstruction::WarmupNcnVaultTicket => 141,
+            RestakingInstruction::CooldownNcnVaultTicket => 141,
+            RestakingInstruction::NcnWarmupOperator => 141,
+            RestakingInstruction::NcnCooldownOperator => 141,
+            RestakingInstruction::OperatorWarmupNcn => 141,
+            RestakingInstruction::OperatorCooldownNcn => 141,
+            RestakingInstruction::WarmupNcnVaultSlasherTicket => 141,
+            RestakingInstruction::CooldownNcnVaultSlasherTicket => 141,
+            RestakingInstruction::WarmupOperatorVaultTicket => 141,
+            RestakingInstruction::CooldownOperatorVaultTicket => 141,
+            RestakingInstruction::NcnSetAdmin => 1,
+            RestakingInstruction::NcnSetSecondaryAdmin(..) => 1,
+            RestakingInstruction::OperatorSetAdmin => 1,
+            RestakingInstruction::OperatorSetSecondaryAdmin(..) => 1,
+            RestakingInstruction::NcnWithdrawalAsset { .. } => 4_646,
+            RestakingInstruction::OperatorWithdrawalAsset { .. } => 4_646,
+            RestakingInstruction::NcnOpenTaskWindow { .. } => 141,
+            RestakingInstruction::NcnCloseTaskWindow => 1,
+            RestakingInstruction::NcnFreezeOperator => 141,
+            RestakingInstruction::NcnUnfreezeOperator => 1,
+            RestakingInstruction::NcnSetTicketExpiry { .. } => 1,
+            RestakingInstruction::RenewTicket => 141,
+            RestakingInstruction::OperatorSetCommission { .. } => 141,
+            RestakingInstruction::NcnSetSlashDestination { .. } => 1,
+            RestakingInstruction::MigrateAccount => 118,
+            RestakingInstruction::OperatorSetMetadata { .. } => 1,
+            RestakingInstruction::NcnSetMaxSlashablePerEpoch { .. } => 141,
+            RestakingInstruction::OperatorSetDelegationApprovalRequired { .. } => 1,
+            RestakingInstruction::OperatorApproveDelegation { .. } => 1,
+            RestakingInstruction::ConfigSetAdmin => 1,
+            RestakingInstruction::ConfigAcceptAdmin => 1,
+            RestakingInstruction::ConfigSetEpochLength { .. } => 1,
+            RestakingInstruction::ConfigSetParameters { .. } => 1,
+            RestakingInstruction::NcnRequestSlash { .. } => 5_207,
+            RestakingInstruction::BatchAddOperators => 408,
+        }
+    }
+
+    const fn within_baseline(consumed: u64, baseline: u64) -> bool {
+        consumed * 10_000 <= baseline * (10_000 + BASELINE_THRESHOLD_BPS)
+    }
+
+    /// Initializes the slasher's ticket for slashing the operator in the epoch
+    async fn initialize_slasher_operator_ticket(
+        vault_program_client: &mut VaultProgramClient,
+        vault_root: &VaultRoot,
+        ncn: &Pubkey,
+        slasher: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+        payer: &Keypair,
+    ) {
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                ncn,
+                slasher,
+                operator,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    ncn,
+                    slasher,
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    ncn,
+                    slasher,
+                    operator,
+                    epoch,
+                )
+                .0,
+                payer,
+            )
+            .await
+            .unwrap();
+    }
+
+    /// Measures the restaking instructions over the lifecycle of an NCN and operator, along with
+    /// the vault's side of their tickets
+    async fn measure_restaking_lifecycle() -> Vec<ComputeUnitsRecord> {
+        let mut fixture = TestBuilder::new().await;
+        let compute_units_recorder = fixture.record_compute_units();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut restaking_program_client,
+            vault_config_admin,
+            vault_root,
+            restaking_config_admin,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+        let ncn = ncn_root.ncn_pubkey;
+        let operator_root = &operator_roots[0];
+        let operator = operator_root.operator_pubkey;
+        let slasher = &slashers_amounts[0].0;
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_operator_set_commission(operator_root, 500)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_set_metadata(operator_root, "operator", "https://operator.example")
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_set_delegation_approval_required(operator_root, true)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_approve_delegation(
+                operator_root,
+                &vault_root.vault_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, DELEGATION_AMOUNT)
+            .await
+            .unwrap();
+
+        // the NCN slashes through the restaking program once the delegation is staked
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &slasher.pubkey())
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        initialize_slasher_operator_ticket(
+            &mut vault_program_client,
+            &vault_root,
+            &ncn,
+            &slasher.pubkey(),
+            &operator,
+            epoch,
+            &vault_config_admin,
+        )
+        .await;
+        vault_program_client
+            .do_ncn_request_slash(&vault_root, &ncn, slasher, &operator, SLASH_AMOUNT)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator, slot + config.epoch_length())
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_ncn_close_task_window(&ncn_root, &operator)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_ncn_freeze_operator(&ncn_root, &operator)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_ncn_unfreeze_operator(&ncn_root, &operator)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_ncn_set_slash_destination(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                Some(&Pubkey::new_unique()),
+                SlashDestination::Insurance,
+            )
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_ncn_set_max_slashable_per_epoch(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                MAX_SLASH_AMOUNT,
+            )
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_ncn_set_ticket_expiry(&ncn_root, Ncn::MIN_TICKET_EXPIRY_EPOCHS)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_renew_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let new_operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_batch_add_operators(&ncn_root, &[new_operator_root.operator_pubkey])
+            .await
+            .unwrap();
+
+        // tokens sent to the NCN and the operator are withdrawn by their admins
+        restaking_program_client
+            .ncn_set_secondary_admin(
+                &ncn,
+                &ncn_root.ncn_admin,
+                &ncn_root.ncn_admin.pubkey(),
+                NcnAdminRole::Withdraw,
+            )
+            .await
+            .unwrap();
+        restaking_program_client
+            .operator_set_secondary_admin(
+                &operator,
+                &operator_root.operator_admin,
+                &operator_root.operator_admin.pubkey(),
+                OperatorAdminRole::WithdrawAdmin,
+            )
+            .await
+            .unwrap();
+        for (owner, admin) in [
+            (ncn, ncn_root.ncn_admin.pubkey()),
+            (operator, operator_root.operator_admin.pubkey()),
+        ] {
+            fixture
+                .mint_spl_to(&vault.supported_mint, &owner, 1_000)
+                .await
+                .unwrap();
+            fixture
+                .create_ata(&vault.supported_mint, &admin)
+                .await
+                .unwrap();
+        }
+        restaking_program_client
+            .do_ncn_withdrawal_asset(&ncn_root, &vault.supported_mint, 1_000)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_withdrawal_asset(operator_root, &vault.supported_mint, 1_000)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .migrate_account(
+                &jito_restaking_core::config::Config::find_program_address(
+                    &jito_restaking_program::id(),
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        // both sides wind down their relationships
+        restaking_program_client
+            .do_operator_cooldown_ncn(operator_root, &ncn)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_ncn_cooldown_operator(&ncn_root, &operator)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_cooldown_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+            )
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_cooldown_operator_vault_ticket(operator_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_cooldown_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_cooldown_vault_ncn_slasher_ticket(&vault_root, &ncn, &slasher.pubkey())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_cooldown_vault_ncn_ticket(&vault_root, &ncn)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .ncn_set_admin(&ncn, &ncn_root.ncn_admin, &Keypair::new())
+            .await
+            .unwrap();
+        restaking_program_client
+            .operator_set_admin(&operator, &operator_root.operator_admin, &Keypair::new())
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_config_set_parameters(&restaking_config_admin, Some(jito_vault_program::id()))
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_config_set_epoch_length(&restaking_config_admin, config.epoch_length())
+            .await
+            .unwrap();
+        let new_restaking_config_admin = Keypair::new();
+        restaking_program_client
+            .do_config_set_admin(
+                &restaking_config_admin,
+                &new_restaking_config_admin.pubkey(),
+            )
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_config_accept_admin(&new_restaking_config_admin)
+            .await
+            .unwrap();
+
+        compute_units_recorder.take_records()
+    }
+
+    /// Measures the vault instructions over the lifecycle of a vault delegated to two operators,
+    /// from deposits through delegation, slashing and withdrawals to the admin changes
+    async fn measure_vault_lifecycle() -> Vec<ComputeUnitsRecord> {
+        let mut fixture = TestBuilder::new().await;
+        let compute_units_recorder = fixture.record_compute_units();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 2, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+        let ncn = ncn_root.ncn_pubkey;
+        let operators: Vec<Pubkey> = operator_roots
+            .iter()
+            .map(|operator_root| operator_root.operator_pubkey)
+            .collect();
+        let slasher = &slashers_amounts[0].0;
+        let vault_admin = &vault_root.vault_admin;
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // admin burn can only be enabled before the first deposit
+        let burn_admin = Keypair::new();
+        let recovery = Pubkey::new_unique();
+        vault_program_client
+            .enable_admin_burn(&vault_root.vault_pubkey, vault_admin, &burn_admin.pubkey())
+            .await
+            .unwrap();
+
+        // deposits
+        vault_program_client
+            .do_initialize_vault_epoch_stats(&vault_root)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_capacity(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                vault_admin,
+                u64::MAX,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .set_deposit_limits(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                vault_admin,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .set_flash_deposit_protection(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                vault_admin,
+                false,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 2 * MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_deposit_mode(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                vault_admin,
+                DepositMode::Allowlist,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_to_deposit_allowlist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_remove_from_deposit_allowlist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+        vault_program_client
+            .set_deposit_mode(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                vault_admin,
+                DepositMode::Open,
+            )
+            .await
+            .unwrap();
+
+        let deposit_receipt_base = Keypair::new();
+        vault_program_client
+            .do_mint_to_with_deposit_receipt(
+                &vault_root,
+                &depositor,
+                &deposit_receipt_base,
+                MINT_AMOUNT,
+                0,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .close_deposit_receipt(
+                &DepositReceipt::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &depositor.pubkey(),
+                    &deposit_receipt_base.pubkey(),
+                )
+                .0,
+                &depositor,
+            )
+            .await
+            .unwrap();
+
+        // withdrawals out of the idle assets
+        vault_program_client
+            .do_burn(&vault_root, &depositor, 1_000, 0)
+            .await
+            .unwrap();
+        let caller = Keypair::new();
+        vault_program_client
+            .airdrop(&caller.pubkey(), 1.0)
+            .await
+            .unwrap();
+        vault_program_client
+            .approve_vrt_delegate(&vault_root, &depositor, &caller.pubkey(), 1_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_burn_delegated(&vault_root, &caller, &depositor.pubkey(), 1_000, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_burn_or_enqueue_withdrawal(&vault_root, &depositor, 1_000, 0)
+            .await
+            .unwrap();
+
+        // delegation
+        vault_program_client
+            .set_min_delegation_amount(&config_pubkey, &vault_root.vault_pubkey, vault_admin, 1)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_set_operator_max_delegation(&vault_root, &operators[0], vault_admin, u64::MAX)
+            .await
+            .unwrap();
+        for is_paused in [true, false] {
+            vault_program_client
+                .do_set_operator_delegation_paused(
+                    &vault_root,
+                    &operators[0],
+                    vault_admin,
+                    is_paused,
+                )
+                .await
+                .unwrap();
+        }
+        vault_program_client
+            .do_ban_operator(&vault_root, &operators[1], vault_admin, 1)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_unban_operator(&vault_root, &operators[1], vault_admin)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operators[0], DELEGATION_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_delegation_strategy(&vault_root, DELEGATION_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_set_delegation_strategy_weight(&vault_root, &operators[1], 1)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_rebalance_delegations(&vault_root.vault_pubkey, &operators[1])
+            .await
+            .unwrap();
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operators[0], 1_000)
+            .await
+            .unwrap();
+
+        // withdrawal tickets, one claimed by a cranker, one moved to a new owner and one the
+        // burn admin recovers
+        let VaultStakerWithdrawalTicketRoot {
+            base: auto_claim_base,
+        } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, 1_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_withdrawal_ticket_auto_claim_fee(&vault_root, &depositor, &auto_claim_base, 100)
+            .await
+            .unwrap();
+        let VaultStakerWithdrawalTicketRoot { base: burn_base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, 1_000)
+            .await
+            .unwrap();
+        let VaultStakerWithdrawalTicketRoot { base: moved_base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, 1_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .change_withdrawal_ticket_owner(
+                &vault_root,
+                &moved_base,
+                &depositor,
+                &Pubkey::new_unique(),
+            )
+            .await
+            .unwrap();
+        let VaultStakerWithdrawalTicketRoot {
+            base: admin_burn_base,
+        } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, 1_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .propose_admin_burn(
+                &vault_root.vault_pubkey,
+                &admin_burn_base,
+                &burn_admin,
+                &recovery,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .cancel_admin_burn(&vault_root.vault_pubkey, &admin_burn_base, vault_admin)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        vault_program_client
+            .propose_admin_burn(
+                &vault_root.vault_pubkey,
+                &admin_burn_base,
+                &burn_admin,
+                &recovery,
+            )
+            .await
+            .unwrap();
+
+        // the delegation is staked after the first update, then the second is cranked one
+        // instruction at a time alongside the NCN's stake weight
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            epoch,
+        )
+        .0;
+        vault_program_client
+            .initialize_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+            )
+            .await
+            .unwrap();
+        for operator in operators.iter() {
+            vault_program_client
+                .do_crank_vault_update_state_tracker(&vault_root.vault_pubkey, operator)
+                .await
+                .unwrap();
+        }
+        vault_program_client
+            .snapshot_vault_operator_delegation(&vault_root.vault_pubkey, &operators[0], epoch)
+            .await
+            .unwrap();
+        vault_program_client
+            .initialize_operator_stake_weight(&ncn, &operators[0], &vault.supported_mint, epoch)
+            .await
+            .unwrap();
+        vault_program_client
+            .crank_operator_stake_weight(&ncn, &operators[0], &vault_root.vault_pubkey, epoch)
+            .await
+            .unwrap();
+        vault_program_client
+            .close_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+                epoch,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .update_vault_balance(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // slashing, directly and then through a proposal once the veto window is enabled
+        fixture
+            .create_ata(&vault.supported_mint, &slasher.pubkey())
+            .await
+            .unwrap();
+        initialize_slasher_operator_ticket(
+            &mut vault_program_client,
+            &vault_root,
+            &ncn,
+            &slasher.pubkey(),
+            &operators[0],
+            epoch,
+            &vault_config_admin,
+        )
+        .await;
+        vault_program_client
+            .do_slash(&vault_root, &ncn, slasher, &operators[0], SLASH_AMOUNT)
+            .await
+            .unwrap();
+        let slash_veto_admin = Keypair::new();
+        vault_program_client
+            .admin_set_slash_veto(
+                &vault_config_admin,
+                &slash_veto_admin.pubkey(),
+                SLASH_VETO_WINDOW_SLOTS,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .set_slash_dispute_haircut(&config_pubkey, &vault_root.vault_pubkey, vault_admin, true)
+            .await
+            .unwrap();
+        let base = vault_program_client
+            .do_propose_slash(
+                &vault_root,
+                &ncn,
+                slasher,
+                &operators[0],
+                SLASH_AMOUNT,
+                [1; 32],
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_veto_slash(&vault_root.vault_pubkey, &base, &slash_veto_admin)
+            .await
+            .unwrap();
+        let base = vault_program_client
+            .do_propose_slash(
+                &vault_root,
+                &ncn,
+                slasher,
+                &operators[0],
+                SLASH_AMOUNT,
+                [2; 32],
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(SLASH_VETO_WINDOW_SLOTS)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_execute_slash(&vault_root.vault_pubkey, &base, slasher)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_max_total_slashable_per_epoch(&vault_root.vault_pubkey, vault_admin, 10_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_cooldown_vault_ncn_slasher_ticket(&vault_root, &ncn, &slasher.pubkey())
+            .await
+            .unwrap();
+
+        // insurance is funded with the admin's own VRT
+        vault_program_client
+            .do_set_insurance_params(&vault_root, 2_500)
+            .await
+            .unwrap();
+        vault_program_client
+            .configure_depositor(&vault_root, &vault_admin.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, vault_admin, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_fund_insurance(&vault_root, 1_000)
+            .await
+            .unwrap();
+
+        // the withdrawal tickets mature and the admin burn's timelock passes
+        for _ in 0..ADMIN_BURN_TIMELOCK_EPOCHS {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &operators)
+                .await
+                .unwrap();
+        }
+        vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &burn_base, 0)
+            .await
+            .unwrap();
+        let claimer = Pubkey::new_unique();
+        fixture
+            .create_ata(&vault.supported_mint, &claimer)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_auto_claim_withdrawal_ticket(
+                &vault_root,
+                &depositor.pubkey(),
+                &auto_claim_base,
+                &claimer,
+                0,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_execute_admin_burn(
+                &vault_root,
+                &depositor.pubkey(),
+                &admin_burn_base,
+                &burn_admin,
+                &recovery,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_remove_slasher(&vault_root, &ncn, &slasher.pubkey())
+            .await
+            .unwrap();
+
+        // vault and config parameters
+        vault_program_client
+            .set_fees(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                vault_admin,
+                None,
+                None,
+                Some(10),
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .set_withdrawal_delay(&vault_root, 1)
+            .await
+            .unwrap();
+        for is_paused in [true, false] {
+            vault_program_client
+                .set_vault_paused(
+                    &config_pubkey,
+                    &vault_root.vault_pubkey,
+                    vault_admin,
+                    is_paused,
+                )
+                .await
+                .unwrap();
+        }
+        for hook_program in [VAULT_HOOK_PROGRAM_ID, system_program::id()] {
+            vault_program_client
+                .set_hook_program(&vault_root, &hook_program)
+                .await
+                .unwrap();
+        }
+        let fee_wallet = Pubkey::new_unique();
+        vault_program_client
+            .create_ata(&vault.vrt_mint, &fee_wallet)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_fee_wallet(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                vault_admin,
+                &fee_wallet,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .admin_set_delegation_cooldown(&vault_config_admin, config.delegation_cooldown_epochs())
+            .await
+            .unwrap();
+        vault_program_client
+            .admin_set_crank_reward(&vault_config_admin, 0, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .admin_set_protocol_fee(&vault_config_admin, &Pubkey::new_unique(), 100)
+            .await
+            .unwrap();
+        for is_paused in [true, false] {
+            vault_program_client
+                .admin_set_config_paused(&vault_config_admin, is_paused)
+                .await
+                .unwrap();
+        }
+        vault_program_client
+            .admin_set_vault_epoch_length_bounds(&vault_config_admin, 1_000, 10_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_vault_epoch_length(&vault_root, 1_000)
+            .await
+            .unwrap();
+
+        let metadata = inline_mpl_token_metadata::pda::find_metadata_account(&vault.vrt_mint).0;
+        vault_program_client
+            .create_token_metadata(
+                &vault_root.vault_pubkey,
+                vault_admin,
+                &vault.vrt_mint,
+                vault_admin,
+                &metadata,
+                "restaking JTO".to_string(),
+                "rJTO".to_string(),
+                "https://www.jito.network/restaking/".to_string(),
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .update_token_metadata(
+                &vault_root.vault_pubkey,
+                vault_admin,
+                &vault.vrt_mint,
+                "restaking JTO v2".to_string(),
+                "rJTO2".to_string(),
+                "https://www.jito.network/restaking/v2/".to_string(),
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .migrate_account(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // the admin reconciles tokens that left the vault outside of the program
+        let vault_token_account =
+            get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint);
+        let balance = fixture
+            .get_token_account(&vault_token_account)
+            .await
+            .unwrap()
+            .amount;
+        fixture
+            .set_token_account_amount(&vault_token_account, balance - 1)
+            .await
+            .unwrap();
+        vault_program_client
+            .reconcile_vault_balance(&vault_root.vault_pubkey, vault_admin, 1)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_cooldown_vault_ncn_ticket(&vault_root, &ncn)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_secondary_admin(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                vault_admin,
+                &vault_admin.pubkey(),
+                VaultAdminRole::PauseAdmin,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .set_admin(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                vault_admin,
+                &Keypair::new(),
+            )
+            .await
+            .unwrap();
+
+        compute_units_recorder.take_records()
+    }
+
+    /// Measures the vault instructions around rewards, migrations between vaults, shutting a
+    /// vault down and handing off its mint authority
+    async fn measure_vault_rewards_and_migrations() -> Vec<ComputeUnitsRecord> {
+        let mut fixture = TestBuilder::new().await;
+        let compute_units_recorder = fixture.record_compute_units();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator_root = &operator_roots[0];
+        let vault_admin = &vault_root.vault_admin;
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        // the vault admin distributes rewards in another mint to the stakers
+        let reward_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&reward_mint)
+            .await
+            .unwrap();
+        vault_program_client
+            .mint_spl_to(&reward_mint.pubkey(), &vault_admin.pubkey(), 1_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .create_ata(&reward_mint.pubkey(), &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .create_ata(&reward_mint.pubkey(), &depositor.pubkey())
+            .await
+            .unwrap();
+        let claims = [(depositor.pubkey(), 1_000), (Pubkey::new_unique(), 0)];
+        vault_program_client
+            .initialize_reward_distribution(
+                &vault_root.vault_pubkey,
+                vault_admin,
+                &reward_mint.pubkey(),
+                None,
+                1,
+                RewardDistribution::compute_root(&claims),
+                1_000,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .claim_rewards(
+                &vault_root.vault_pubkey,
+                1,
+                &depositor,
+                1_000,
+                RewardDistribution::compute_proof(&claims, 0).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // the NCN pays rewards through its router
+        let protocol_fee_wallet = Pubkey::new_unique();
+        vault_program_client
+            .admin_set_protocol_fee(&vault_config_admin, &protocol_fee_wallet, 1_000)
+            .await
+            .unwrap();
+        for owner in [protocol_fee_wallet, operator_root.operator_admin.pubkey()] {
+            vault_program_client
+                .create_ata(&vault.supported_mint, &owner)
+                .await
+                .unwrap();
+        }
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_ncn_reward_router(&vault_root.vault_pubkey, &ncn_root.ncn_pubkey, epoch)
+            .await
+            .unwrap();
+        fixture
+            .mint_spl_to(
+                &vault.supported_mint,
+                &NcnRewardRouter::find_program_address(
+                    &jito_vault_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &vault_root.vault_pubkey,
+                    epoch,
+                )
+                .0,
+                1_000,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .distribute_ncn_rewards(
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                epoch,
+                &[operator_root.operator_pubkey],
+            )
+            .await
+            .unwrap();
+
+        // the depositor migrates part of their stake to a partner vault with the same mint
+        let destination_vault_root = vault_program_client
+            .do_initialize_vault_with_supported_mint(&vault.supported_mint, 0, 0, 0, 9)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_migration_partner(&vault_root, &destination_vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_migrate_to_vault(&vault_root, &destination_vault_root, &depositor, 1_000, 0)
+            .await
+            .unwrap();
+
+        // a vault for wrapped SOL takes deposits in SOL
+        let sol_vault_root = vault_program_client
+            .do_initialize_vault_with_supported_mint(&spl_token::native_mint::id(), 0, 0, 0, 9)
+            .await
+            .unwrap();
+        let sol_vault = vault_program_client
+            .get_vault(&sol_vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let sol_depositor = Keypair::new();
+        vault_program_client
+            .airdrop(&sol_depositor.pubkey(), 10.0)
+            .await
+            .unwrap();
+        vault_program_client
+            .create_ata(&sol_vault.vrt_mint, &sol_depositor.pubkey())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to_with_sol(
+                &sol_vault_root,
+                &sol_depositor,
+                sol_to_lamports(1.0),
+                sol_to_lamports(1.0),
+            )
+            .await
+            .unwrap();
+
+        // an empty vault is shut down and closed
+        vault_program_client
+            .initiate_vault_shutdown(
+                &destination_vault_root.vault_pubkey,
+                &destination_vault_root.vault_admin,
+            )
+            .await
+            .unwrap();
+        let empty_vault_root = vault_program_client
+            .do_initialize_vault(0, 0, 0, 9)
+            .await
+            .unwrap();
+        vault_program_client
+            .close_vault(
+                &config_pubkey,
+                &empty_vault_root.vault_pubkey,
+                &empty_vault_root.vault_admin,
+                &[],
+            )
+            .await
+            .unwrap();
+
+        // the VRT mint authority is handed off to a successor program after the challenge window
+        vault_program_client
+            .propose_mint_authority_handoff(
+                &vault_root.vault_pubkey,
+                &vault_config_admin,
+                &jito_restaking_program::id(),
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .cancel_mint_authority_handoff(&vault_root.vault_pubkey, vault_admin)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        vault_program_client
+            .propose_mint_authority_handoff(
+                &vault_root.vault_pubkey,
+                &vault_config_admin,
+                &jito_restaking_program::id(),
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .execute_mint_authority_handoff(&vault_root.vault_pubkey, &vault.vrt_mint)
+            .await
+            .unwrap();
+
+        compute_units_recorder.take_records()
+    }
+
+    /// Measures the instructions that aren't implemented, which succeed without touching any
+    /// accounts
+    async fn measure_unimplemented_vault_instructions() -> Vec<ComputeUnitsRecord> {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let mut records = Vec::new();
+        for vault_instruction in [
+            VaultInstruction::InitializeVaultWithMint,
+            VaultInstruction::AdminWithdraw { amount: 0 },
+        ] {
+            let data = vault_instruction.try_to_vec().unwrap();
+            let compute_units_consumed = vault_program_client
+                .process_with_compute_units(
+                    &[Instruction {
+                        program_id: jito_vault_program::id(),
+                        accounts: vec![],
+                        data: data.clone(),
+                    }],
+                    &[&vault_root.vault_admin],
+                )
+                .await
+                .unwrap();
+            records.push(ComputeUnitsRecord {
+                program_id: jito_vault_program::id(),
+                data,
+                compute_units_consumed,
+            });
+        }
+        records
+    }
+
+    /// Fails unless the programs are loaded from the SBF build. The native processors only meter
+    /// syscalls and CPIs, so they can't be measured against the baselines.
+    fn assert_sbf_build() {
+        let sbf_out_dir = std::env::var_os("SBF_OUT_DIR")
+            .or_else(|| std::env::var_os("BPF_OUT_DIR"))
+            .map(PathBuf::from)
+            .expect("compute units are measured against the SBF build, run cargo-build-sbf and set SBF_OUT_DIR");
+        for program in ["jito_vault_program.so", "jito_restaking_program.so"] {
+            assert!(
+                sbf_out_dir.join(program).exists(),
+                "{} is missing from {}, run cargo-build-sbf",
+                program,
+                sbf_out_dir.display()
+            );
+        }
+    }
+
+    /// Every vault and restaking instruction stays within the regression threshold of its baseline
+    /// and every vault instruction within its recommended compute units.
+    ///
+    /// The baselines are recorded against the SBF build, re-record them by running with
+    /// --nocapture whenever an instruction's compute units change on purpose:
+    ///
+    /// $ cargo-build-sbf && SBF_OUT_DIR=$(pwd)/target/sbf-solana-solana/release cargo nextest run --run-ignored all compute_units
+    #[ignore = "needs the SBF build, see assert_sbf_build"]
+    #[tokio::test]
+    async fn test_compute_units_within_baseline() {
+        assert_sbf_build();
+
+        let mut records = measure_restaking_lifecycle().await;
+        records.extend(measure_vault_lifecycle().await);
+        records.extend(measure_vault_rewards_and_migrations().await);
+        records.extend(measure_unimplemented_vault_instructions().await);
+
+        let mut vault_measured = BTreeSet::new();
+        let mut restaking_measured = BTreeSet::new();
+        let mut regressions = Vec::new();
+        for record in records {
+            let consumed = record.compute_units_consumed;
+            if record.program_id == jito_vault_program::id() {
+                let instruction = VaultInstruction::try_from_slice(&record.data).unwrap();
+                let baseline = vault_baseline_compute_units(&instruction);
+                let recommended = recommended_compute_units(&instruction);
+                println!(
+                    "{:?} consumed {} compute units, baseline {}, {} recommended",
+                    instruction, consumed, baseline, recommended
+                );
+                assert!(
+                    consumed <= u64::from(recommended),
+                    "{:?} consumed {} compute units, {} recommended",
+                    instruction,
+                    consumed,
+                    recommended
+                );
+                if !within_baseline(consumed, baseline) {
+                    regressions.push(format!("{:?}: {} > {}", instruction, consumed, baseline));
+                }
+                vault_measured.insert(instruction.discriminator());
+            } else if record.program_id == jito_restaking_program::id() {
+                let instruction = RestakingInstruction::try_from_slice(&record.data).unwrap();
+                let baseline = restaking_baseline_compute_units(&instruction);
+                println!(
+                    "{:?} consumed {} compute units, baseline {}",
+                    instruction, consumed, baseline
+                );
+                if !within_baseline(consumed, baseline) {
+                    regressions.push(format!("{:?}: {} > {}", instruction, consumed, baseline));
+                }
+                restaking_measured.insert(instruction.discriminator());
+            }
+        }
+
+        assert!(
+            regressions.is_empty(),
+            "compute units regressed past their baselines:\n{}",
+            regressions.join("\n")
+        );
+        let unmeasured: Vec<u8> = (0..VAULT_INSTRUCTION_COUNT)
+            .filter(|discriminator| !vault_measured.contains(discriminator))
+            .collect();
+        assert!(
+            unmeasured.is_empty(),
+            "vault instructions not measured: {:?}",
+            unmeasured
+        );
+        let unmeasured: Vec<u8> = (0..RESTAKING_INSTRUCTION_COUNT)
+            .filter(|discriminator| !restaking_measured.contains(discriminator))
+            .collect();
+        assert!(
+            unmeasured.is_empty(),
+            "restaking instructions not measured: {:?}",
+            unmeasured
+        );
+    }
+}
@@ -0,0 +1,327 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+    const CRANK_REWARD_LAMPORTS: u64 = 5_000;
+
+    #[tokio::test]
+    async fn test_admin_set_crank_reward_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, _vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .admin_set_crank_reward(
+                &config_admin,
+                CRANK_REWARD_LAMPORTS,
+                Config::MAX_CRANK_REWARD_BPS,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        assert_eq!(config.crank_reward_lamports(), CRANK_REWARD_LAMPORTS);
+        assert_eq!(config.crank_reward_bps(), Config::MAX_CRANK_REWARD_BPS);
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_crank_reward_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .admin_set_crank_reward(&vault_root.vault_admin, CRANK_REWARD_LAMPORTS, 0)
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_crank_reward_above_max_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, _vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .admin_set_crank_reward(&config_admin, 0, Config::MAX_CRANK_REWARD_BPS + 1)
+            .await;
+        assert_vault_error(result, VaultError::ConfigCrankRewardInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_crank_reward_paid_once_per_epoch() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .admin_set_crank_reward(
+                &vault_config_admin,
+                CRANK_REWARD_LAMPORTS,
+                Config::MAX_CRANK_REWARD_BPS,
+            )
+            .await
+            .unwrap();
+
+        // fund the vault's lamport rewards and the cranker's token account
+        vault_program_client
+            .airdrop(&vault_root.vault_pubkey, 1.0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let cranker = vault_program_client.payer_pubkey();
+        vault_program_client
+            .create_ata(&vault.supported_mint, &cranker)
+            .await
+            .unwrap();
+        let vault_lamports = vault_program_client
+            .get_lamports(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        // closing the tracker pays 10 bps of 100,000, updating the balance in the same epoch isn't
+        // paid again
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+
+        let cranker_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &cranker,
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(cranker_token_account.amount, 100);
+        assert_eq!(
+            vault_program_client
+                .get_lamports(&vault_root.vault_pubkey)
+                .await
+                .unwrap(),
+            vault_lamports - CRANK_REWARD_LAMPORTS
+        );
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - 100);
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT);
+
+        // updating the balance again in the same epoch isn't paid either
+        vault_program_client
+            .update_vault_balance(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let cranker_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &cranker,
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(cranker_token_account.amount, 100);
+
+        // the next epoch pays once more, 10 bps of 99,900
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+        let cranker_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &cranker,
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(cranker_token_account.amount, 100 + 99);
+        assert_eq!(
+            vault_program_client
+                .get_lamports(&vault_root.vault_pubkey)
+                .await
+                .unwrap(),
+            vault_lamports - 2 * CRANK_REWARD_LAMPORTS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_crank_reward_not_paid_without_harvest() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        // update the vault before there's a crank reward, so none is paid this epoch
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .admin_set_crank_reward(
+                &vault_config_admin,
+                CRANK_REWARD_LAMPORTS,
+                Config::MAX_CRANK_REWARD_BPS,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .airdrop(&vault_root.vault_pubkey, 1.0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let cranker = vault_program_client.payer_pubkey();
+        vault_program_client
+            .create_ata(&vault.supported_mint, &cranker)
+            .await
+            .unwrap();
+        let cranker_token_account = get_associated_token_address(&cranker, &vault.supported_mint);
+        let vault_lamports = vault_program_client
+            .get_lamports(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // nothing to harvest, nothing paid
+        vault_program_client
+            .update_vault_balance(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            fixture
+                .get_token_account(&cranker_token_account)
+                .await
+                .unwrap()
+                .amount,
+            0
+        );
+        assert_eq!(
+            vault_program_client
+                .get_lamports(&vault_root.vault_pubkey)
+                .await
+                .unwrap(),
+            vault_lamports
+        );
+
+        // harvesting rewards is paid, 10 bps of 101,000
+        fixture
+            .mint_spl_to(&vault.supported_mint, &vault_root.vault_pubkey, 1_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .update_vault_balance(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            fixture
+                .get_token_account(&cranker_token_account)
+                .await
+                .unwrap()
+                .amount,
+            101
+        );
+        assert_eq!(
+            vault_program_client
+                .get_lamports(&vault_root.vault_pubkey)
+                .await
+                .unwrap(),
+            vault_lamports - CRANK_REWARD_LAMPORTS
+        );
+    }
+}
@@ -9,7 +9,7 @@ mod tests {
 
     use crate::fixtures::{
         fixture::{ConfiguredVault, TestBuilder},
-        vault_client::assert_vault_error,
+        vault_client::{assert_vault_error, VaultStakerWithdrawalTicketRoot},
     };
 
     #[tokio::test]
@@ -633,4 +633,335 @@ mod tests {
             DelegationState::new(50000, 0, 0)
         );
     }
+
+    #[tokio::test]
+    async fn test_crank_vault_update_state_tracker_defers_cooldown_during_task_window() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        // the NCN and operator must be actively opted in to each other to open a task window
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator, 50_000)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator, slot + 2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        // the stake enqueued for cooldown stays enqueued while the window is open
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault.delegation_state,
+            DelegationState::new(50_000, 50_000, 0)
+        );
+
+        restaking_program_client
+            .do_ncn_close_task_window(&ncn_root, &operator)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        // the stake now cools down in the cohort it was released into
+        assert_eq!(vault.delegation_state.staked_amount(), 50_000);
+        assert_eq!(vault.delegation_state.enqueued_for_cooldown_amount(), 0);
+        assert_eq!(vault.delegation_state.cooling_down_amount(), 50_000);
+    }
+
+    #[tokio::test]
+    async fn test_burn_withdrawal_ticket_during_task_window_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        // the NCN and operator must be actively opted in to each other to open a task window
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 100_000)
+            .await
+            .unwrap();
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator, 100_000)
+            .await
+            .unwrap();
+
+        // the stake and the VRT start cooling down together
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        restaking_program_client
+            .do_ncn_open_task_window(&ncn_root, &operator, slot + 2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        // the stake's cooldown is deferred while the window is open, so the VRT is held with it
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state.cooling_down_amount(), 100_000);
+        assert_eq!(vault.vrt_cooling_down_amount(), 100_000);
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 0);
+
+        let result = vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, 100_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultUnderflow);
+
+        restaking_program_client
+            .do_ncn_close_task_window(&ncn_root, &operator)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state, DelegationState::default());
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 100_000);
+
+        vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, 100_000)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_burn_withdrawal_ticket_while_operator_frozen_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 100_000)
+            .await
+            .unwrap();
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator, 100_000)
+            .await
+            .unwrap();
+
+        // the stake and the VRT start cooling down together
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_ncn_freeze_operator(&ncn_root, &operator)
+            .await
+            .unwrap();
+
+        // the stake's cooldown is deferred while the operator is frozen, so the VRT is held with it
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state.cooling_down_amount(), 100_000);
+        assert_eq!(vault.vrt_cooling_down_amount(), 100_000);
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 0);
+
+        let result = vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, 100_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultUnderflow);
+
+        restaking_program_client
+            .do_ncn_unfreeze_operator(&ncn_root, &operator)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state, DelegationState::default());
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 100_000);
+
+        vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, 100_000)
+            .await
+            .unwrap();
+    }
 }
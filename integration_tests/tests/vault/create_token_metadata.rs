@@ -1,12 +1,9 @@
 #[cfg(test)]
 mod tests {
     use jito_vault_sdk::{error::VaultError, inline_mpl_token_metadata};
-    use solana_sdk::{
-        instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
-    };
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
 
     use crate::fixtures::{
-        assert_ix_error,
         fixture::TestBuilder,
         vault_client::{assert_vault_error, VaultRoot},
     };
@@ -108,7 +105,7 @@ mod tests {
             )
             .await;
 
-        assert_ix_error(result, InstructionError::InvalidAccountData);
+        assert_vault_error(result, VaultError::VaultVrtMintInvalid);
     }
 
     #[tokio::test]
@@ -148,7 +145,7 @@ mod tests {
             )
             .await;
 
-        assert_ix_error(result, InstructionError::InvalidAccountData);
+        assert_vault_error(result, VaultError::VaultMetadataAccountInvalid);
     }
 
     #[tokio::test]
@@ -192,6 +189,6 @@ mod tests {
             )
             .await;
 
-        assert_vault_error(response, VaultError::VaultAdminInvalid);
+        assert_vault_error(response, VaultError::VaultMetadataAdminInvalid);
     }
 }
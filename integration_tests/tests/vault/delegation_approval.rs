@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_sdk::error::RestakingError;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        restaking_client::assert_restaking_error,
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_delegation_above_approved_amount_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut restaking_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator_root = &operator_roots[0];
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_operator_set_delegation_approval_required(operator_root, true)
+            .await
+            .unwrap();
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert!(operator.is_delegation_approval_required());
+
+        // nothing is approved yet
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, 1)
+            .await;
+        assert_vault_error(result, VaultError::VaultDelegationNotApproved);
+
+        restaking_program_client
+            .do_operator_approve_delegation(operator_root, &vault_root.vault_pubkey, 1_000)
+            .await
+            .unwrap();
+        let operator_vault_ticket = restaking_program_client
+            .get_operator_vault_ticket(&operator_root.operator_pubkey, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator_vault_ticket.approved_delegation_amount(), 1_000);
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, 600)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, 401)
+            .await;
+        assert_vault_error(result, VaultError::VaultDelegationNotApproved);
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, 400)
+            .await
+            .unwrap();
+
+        // turning the requirement off lifts the cap
+        restaking_program_client
+            .do_operator_set_delegation_approval_required(operator_root, false)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, 1)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_approve_delegation_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut restaking_program_client,
+            vault_root,
+            mut operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let mut operator_root = operator_roots.remove(0);
+        operator_root.operator_admin = Keypair::new();
+
+        let result = restaking_program_client
+            .do_operator_set_delegation_approval_required(&operator_root, true)
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorAdminInvalid);
+
+        let result = restaking_program_client
+            .do_operator_approve_delegation(&operator_root, &vault_root.vault_pubkey, 1_000)
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorVaultAdminInvalid);
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_rebalance_delegations_moves_stake_towards_targets() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 2, &[])
+            .await
+            .unwrap();
+        let operators: Vec<_> = operator_roots.iter().map(|o| o.operator_pubkey).collect();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_initialize_delegation_strategy(&vault_root, 30_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_set_delegation_strategy_weight(&vault_root, &operators[0], 1)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_set_delegation_strategy_weight(&vault_root, &operators[1], 3)
+            .await
+            .unwrap();
+        let delegation_strategy = vault_program_client
+            .get_delegation_strategy(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(delegation_strategy.total_weight(), 4);
+
+        // the first operator reaches its target, the second is capped by the movement limit
+        for operator in operators.iter() {
+            vault_program_client
+                .do_rebalance_delegations(&vault_root.vault_pubkey, operator)
+                .await
+                .unwrap();
+        }
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operators[0])
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            25_000
+        );
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operators[1])
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            5_000
+        );
+
+        // the movement limit resets the next epoch
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_rebalance_delegations(&vault_root.vault_pubkey, &operators[1])
+            .await
+            .unwrap();
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operators[1])
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            35_000
+        );
+
+        // dropping an operator's weight to zero cools its stake down
+        vault_program_client
+            .do_set_delegation_strategy_weight(&vault_root, &operators[0], 0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_rebalance_delegations(&vault_root.vault_pubkey, &operators[0])
+            .await
+            .unwrap();
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operators[0])
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            0
+        );
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .enqueued_for_cooldown_amount(),
+            25_000
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rebalance_delegations_requires_vault_update() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        vault_program_client
+            .do_initialize_delegation_strategy(&vault_root, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_set_delegation_strategy_weight(&vault_root, &operator, 1)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_rebalance_delegations(&vault_root.vault_pubkey, &operator)
+            .await;
+        assert_vault_error(result, VaultError::VaultUpdateNeeded);
+    }
+}
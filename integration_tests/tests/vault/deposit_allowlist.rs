@@ -0,0 +1,217 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, vault_deposit_allowlist_entry::VaultDepositAllowlistEntry,
+    };
+    use jito_vault_sdk::{error::VaultError, instruction::DepositMode};
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{fixture::TestBuilder, vault_client::assert_vault_error};
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_set_deposit_mode_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.deposit_mode(), Ok(DepositMode::Open));
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_deposit_mode(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                DepositMode::Allowlist,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.deposit_mode(), Ok(DepositMode::Allowlist));
+    }
+
+    #[tokio::test]
+    async fn test_set_deposit_mode_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let result = vault_program_client
+            .set_deposit_mode(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &wrong_admin,
+                DepositMode::Allowlist,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_allowlist_deposit_mode() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_deposit_mode(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                DepositMode::Allowlist,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 3 * MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultDepositNotAllowed);
+
+        vault_program_client
+            .do_add_to_deposit_allowlist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+        let vault_deposit_allowlist_entry = vault_program_client
+            .get_vault_deposit_allowlist_entry(&vault_root.vault_pubkey, &depositor.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(vault_deposit_allowlist_entry.vault, vault_root.vault_pubkey);
+        assert_eq!(vault_deposit_allowlist_entry.depositor, depositor.pubkey());
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_remove_from_deposit_allowlist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+        assert!(vault_program_client
+            .get_vault_deposit_allowlist_entry(&vault_root.vault_pubkey, &depositor.pubkey())
+            .await
+            .unwrap()
+            .is_none());
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultDepositNotAllowed);
+
+        // opening the vault back up allows anyone to deposit
+        vault_program_client
+            .set_deposit_mode(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                DepositMode::Open,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_add_to_deposit_allowlist_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        let depositor = Keypair::new();
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let result = vault_program_client
+            .add_to_deposit_allowlist(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+                &VaultDepositAllowlistEntry::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &depositor.pubkey(),
+                )
+                .0,
+                &wrong_admin,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_signer_gated_deposit_mode_without_mint_burn_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_deposit_mode(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                DepositMode::SignerGated,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultDepositNotAllowed);
+    }
+}
@@ -0,0 +1,212 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{fixture::TestBuilder, vault_client::assert_vault_error};
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_set_deposit_limits_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.max_deposit_per_transaction(), 0);
+        assert_eq!(vault.max_deposit_per_wallet(), 0);
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_deposit_limits(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                Some(MINT_AMOUNT),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.max_deposit_per_transaction(), MINT_AMOUNT);
+        assert_eq!(vault.max_deposit_per_wallet(), 0);
+
+        vault_program_client
+            .set_deposit_limits(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                None,
+                Some(2 * MINT_AMOUNT),
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.max_deposit_per_transaction(), MINT_AMOUNT);
+        assert_eq!(vault.max_deposit_per_wallet(), 2 * MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_set_deposit_limits_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let result = vault_program_client
+            .set_deposit_limits(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &wrong_admin,
+                Some(MINT_AMOUNT),
+                Some(MINT_AMOUNT),
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultCapacityAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_mint_above_max_deposit_per_transaction_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_deposit_limits(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                Some(MINT_AMOUNT),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 3 * MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT + 1, 0)
+            .await;
+        assert_vault_error(result, VaultError::VaultDepositPerTransactionLimitExceeded);
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mint_above_max_deposit_per_wallet_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_deposit_limits(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                None,
+                Some(2 * MINT_AMOUNT),
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 3 * MINT_AMOUNT)
+            .await
+            .unwrap();
+        assert!(vault_program_client
+            .get_vault_depositor(&vault_root.vault_pubkey, &depositor.pubkey())
+            .await
+            .unwrap()
+            .is_none());
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        let vault_depositor = vault_program_client
+            .get_vault_depositor(&vault_root.vault_pubkey, &depositor.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(vault_depositor.vault, vault_root.vault_pubkey);
+        assert_eq!(vault_depositor.depositor, depositor.pubkey());
+        assert_eq!(vault_depositor.tokens_deposited(), MINT_AMOUNT);
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, 1, 0)
+            .await;
+        assert_vault_error(result, VaultError::VaultDepositPerWalletLimitExceeded);
+
+        // other depositors have their own limit
+        let other_depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &other_depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &other_depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault_depositor = vault_program_client
+            .get_vault_depositor(&vault_root.vault_pubkey, &depositor.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(vault_depositor.tokens_deposited(), 2 * MINT_AMOUNT);
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::deposit_receipt::DepositReceipt;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_mint_to_with_deposit_receipt_ok() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(100, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 2 * MINT_AMOUNT)
+            .await
+            .unwrap();
+        let base = Keypair::new();
+        vault_program_client
+            .do_mint_to_with_deposit_receipt(&vault_root, &depositor, &base, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let deposit_receipt = vault_program_client
+            .get_deposit_receipt(
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+                &base.pubkey(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(deposit_receipt.vault, vault_root.vault_pubkey);
+        assert_eq!(deposit_receipt.depositor, depositor.pubkey());
+        assert_eq!(deposit_receipt.base, base.pubkey());
+        assert_eq!(deposit_receipt.amount_in(), MINT_AMOUNT);
+        assert_eq!(deposit_receipt.vrt_minted(), 99_000);
+        assert_eq!(deposit_receipt.slot(), slot);
+
+        // depositing without a receipt doesn't touch it
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+        let deposit_receipt = vault_program_client
+            .get_deposit_receipt(
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+                &base.pubkey(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(deposit_receipt.amount_in(), MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_close_deposit_receipt_ok() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        let base = Keypair::new();
+        vault_program_client
+            .do_mint_to_with_deposit_receipt(&vault_root, &depositor, &base, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+
+        let deposit_receipt = DepositReceipt::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &depositor.pubkey(),
+            &base.pubkey(),
+        )
+        .0;
+        let receipt_lamports = vault_program_client
+            .get_lamports(&deposit_receipt)
+            .await
+            .unwrap();
+        let depositor_lamports = vault_program_client
+            .get_lamports(&depositor.pubkey())
+            .await
+            .unwrap();
+
+        vault_program_client
+            .close_deposit_receipt(&deposit_receipt, &depositor)
+            .await
+            .unwrap();
+
+        assert!(vault_program_client
+            .get_deposit_receipt(
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+                &base.pubkey()
+            )
+            .await
+            .unwrap()
+            .is_none());
+        // the depositor pays the transaction fee out of the reclaimed rent
+        assert_eq!(
+            vault_program_client
+                .get_lamports(&depositor.pubkey())
+                .await
+                .unwrap(),
+            depositor_lamports + receipt_lamports - 5_000
+        );
+    }
+
+    #[tokio::test]
+    async fn test_close_deposit_receipt_wrong_depositor_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        let base = Keypair::new();
+        vault_program_client
+            .do_mint_to_with_deposit_receipt(&vault_root, &depositor, &base, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+
+        let deposit_receipt = DepositReceipt::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &depositor.pubkey(),
+            &base.pubkey(),
+        )
+        .0;
+        let other = Keypair::new();
+        vault_program_client
+            .airdrop(&other.pubkey(), 1.0)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .close_deposit_receipt(&deposit_receipt, &other)
+            .await;
+        assert_vault_error(result, VaultError::VaultAccountNotCanonicalPda);
+    }
+}
@@ -67,6 +67,7 @@ mod tests {
             MINT_AMOUNT * (10_000 - DEPOSIT_FEE_BPS) as u64 / 10_000
         );
 
+        // the deposit fee is accrued in the vault until the next vault update
         let vault_fee_account = fixture
             .get_token_account(&get_associated_token_address(
                 &vault.fee_wallet,
@@ -74,8 +75,13 @@ mod tests {
             ))
             .await
             .unwrap();
+        assert_eq!(vault_fee_account.amount, 0);
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
         assert_eq!(
-            vault_fee_account.amount,
+            vault.vrt_fees_accrued(),
             MINT_AMOUNT * DEPOSIT_FEE_BPS as u64 / 10_000
         );
 
@@ -98,6 +104,18 @@ mod tests {
             .await
             .unwrap();
 
+        let vault_fee_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &vault.fee_wallet,
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_fee_account.amount,
+            MINT_AMOUNT * DEPOSIT_FEE_BPS as u64 / 10_000
+        );
+
         let operator_root = operator_roots.first().unwrap();
         vault_program_client
             .do_add_delegation(&vault_root, &operator_root.operator_pubkey, MINT_AMOUNT)
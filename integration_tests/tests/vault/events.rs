@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::events::{DepositEvent, FeeChangedEvent, VaultEvent};
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::fixture::{ConfiguredVault, TestBuilder};
+
+    #[tokio::test]
+    async fn test_mint_to_emits_deposit_event() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(100, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let instruction = jito_vault_sdk::sdk::mint_to(
+            &jito_vault_program::id(),
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            &vault.vrt_mint,
+            &depositor.pubkey(),
+            &get_associated_token_address(&depositor.pubkey(), &vault.supported_mint),
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint),
+            None,
+            None,
+            None,
+            100_000,
+            99_000,
+        );
+        let events = vault_program_client
+            .process_with_events(&[instruction], &[&depositor])
+            .await
+            .unwrap();
+
+        // 1% deposit fee
+        assert_eq!(
+            events,
+            vec![VaultEvent::Deposit(DepositEvent {
+                vault: vault_root.vault_pubkey,
+                depositor: depositor.pubkey(),
+                amount_in: 100_000,
+                vrt_to_depositor: 99_000,
+                vrt_fee: 1_000,
+            })]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_fees_emits_fee_changed_event() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length() * 2 + 1)
+            .await
+            .unwrap();
+
+        let instruction = jito_vault_sdk::sdk::set_fees(
+            &jito_vault_program::id(),
+            &config_pubkey,
+            &vault_root.vault_pubkey,
+            &vault_root.vault_admin.pubkey(),
+            None,
+            Some(10),
+            Some(20),
+        );
+        let events = vault_program_client
+            .process_with_events(&[instruction], &[&vault_root.vault_admin])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            events,
+            vec![VaultEvent::FeeChanged(FeeChangedEvent {
+                vault: vault_root.vault_pubkey,
+                deposit_fee_bps: None,
+                withdrawal_fee_bps: Some(10),
+                reward_fee_bps: Some(20),
+            })]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_burn_in_deposit_slot_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_flash_deposit_protection(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                true,
+            )
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(vault.flash_deposit_protection());
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault_depositor = vault_program_client
+            .get_vault_depositor(&vault_root.vault_pubkey, &depositor.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            vault_depositor.last_deposit_slot(),
+            fixture.get_current_slot().await.unwrap()
+        );
+
+        let result = vault_program_client
+            .do_burn(&vault_root, &depositor, 1_000, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultBurnInDepositSlot);
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        vault_program_client
+            .do_burn(&vault_root, &depositor, 1_000, 1_000)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_set_flash_deposit_protection_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .set_flash_deposit_protection(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &wrong_admin,
+                true,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultCapacityAdminInvalid);
+    }
+}
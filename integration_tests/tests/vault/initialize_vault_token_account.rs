@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{fixture::TestBuilder, vault_client::assert_vault_error};
+
+    #[tokio::test]
+    async fn test_initialize_vault_token_account_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        // the fixture creates the vault token account with InitializeVaultTokenAccount
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let vault_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &vault_root.vault_pubkey,
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(vault_token_account.owner, vault_root.vault_pubkey);
+        assert_eq!(vault_token_account.mint, vault.supported_mint);
+        assert_eq!(vault_token_account.amount, 0);
+
+        // creating it again is a no-op
+        vault_program_client
+            .initialize_vault_token_account(&vault_root.vault_pubkey, &vault.supported_mint)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_initialize_vault_token_account_wrong_mint_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let other_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&other_mint)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .initialize_vault_token_account(&vault_root.vault_pubkey, &other_mint.pubkey())
+            .await;
+        assert_vault_error(result, VaultError::VaultSupportedMintInvalid);
+    }
+}
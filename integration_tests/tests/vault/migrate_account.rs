@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use jito_bytemuck::AccountVersion;
+    use jito_vault_core::{config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket};
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{fixture::TestBuilder, vault_client::assert_vault_error};
+
+    #[tokio::test]
+    async fn test_migrate_current_accounts_is_noop() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(99, 100, 0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config_before = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        let vault_before = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .migrate_account(&config_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .migrate_account(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let config_after = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        let vault_after = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config_before, config_after);
+        assert_eq!(vault_before, vault_after);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_version_0_vault_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(99, 100, 0)
+            .await
+            .unwrap();
+
+        // rewrite the vault as a version 0 account, before the layout grew
+        let size = Vault::size_of_version(Vault::VERSION).unwrap();
+        let version_0_size = Vault::size_of_version(0).unwrap();
+        let mut data = fixture
+            .get_account_data(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(data.len(), size);
+        data.truncate(version_0_size);
+        data[1] = 0;
+        fixture
+            .set_account_data(&vault_root.vault_pubkey, data.clone())
+            .await
+            .unwrap();
+
+        // the program refuses to load the vault until it's migrated
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let result = vault_program_client
+            .set_capacity(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                100_000,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultAccountVersionInvalid);
+
+        vault_program_client
+            .migrate_account(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let migrated_data = fixture
+            .get_account_data(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(migrated_data.len(), size);
+        assert_eq!(migrated_data[1], Vault::VERSION);
+        assert_eq!(migrated_data[2..version_0_size], data[2..]);
+        assert!(migrated_data[version_0_size..]
+            .iter()
+            .all(|byte| *byte == 0));
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), 100_000);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_unsupported_account_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(99, 100, 0)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let vault_ncn_ticket = VaultNcnTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &ncn_root.ncn_pubkey,
+        )
+        .0;
+        let result = vault_program_client
+            .migrate_account(&vault_ncn_ticket)
+            .await;
+        assert_vault_error(result, VaultError::VaultAccountDiscriminatorInvalid);
+    }
+}
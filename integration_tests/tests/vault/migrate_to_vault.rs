@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    /// Sets up a source vault with a 1% withdrawal fee, a destination vault with the same
+    /// supported mint and a staker holding VRT of the source vault
+    async fn setup_vaults(
+        vault_program_client: &mut VaultProgramClient,
+    ) -> (VaultRoot, VaultRoot, Keypair) {
+        vault_program_client.do_initialize_config().await.unwrap();
+        let source_vault_root = vault_program_client
+            .do_initialize_vault(0, 100, 0, 9)
+            .await
+            .unwrap();
+        let source_vault = vault_program_client
+            .get_vault(&source_vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let destination_vault_root = vault_program_client
+            .do_initialize_vault_with_supported_mint(&source_vault.supported_mint, 0, 0, 0, 9)
+            .await
+            .unwrap();
+
+        let staker = Keypair::new();
+        vault_program_client
+            .configure_depositor(&source_vault_root, &staker.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&source_vault_root, &staker, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        (source_vault_root, destination_vault_root, staker)
+    }
+
+    #[tokio::test]
+    async fn test_migrate_to_vault_charges_withdrawal_fee_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (source_vault_root, destination_vault_root, staker) =
+            setup_vaults(&mut vault_program_client).await;
+
+        // only one of the vaults opting in doesn't waive the fee
+        vault_program_client
+            .set_migration_partner(&source_vault_root, &destination_vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_migrate_to_vault(
+                &source_vault_root,
+                &destination_vault_root,
+                &staker,
+                MINT_AMOUNT,
+                99_000,
+            )
+            .await
+            .unwrap();
+
+        let source_vault = vault_program_client
+            .get_vault(&source_vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(source_vault.vrt_supply(), 1_000);
+        assert_eq!(source_vault.tokens_deposited(), 1_000);
+        let destination_vault = vault_program_client
+            .get_vault(&destination_vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(destination_vault.vrt_supply(), 99_000);
+        assert_eq!(destination_vault.tokens_deposited(), 99_000);
+
+        let staker_destination_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &staker.pubkey(),
+                &destination_vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(staker_destination_vrt_token_account.amount, 99_000);
+        let staker_source_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &staker.pubkey(),
+                &source_vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(staker_source_vrt_token_account.amount, 0);
+        let staker_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &staker.pubkey(),
+                &source_vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(staker_token_account.amount, 0);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_to_vault_between_partners_waives_withdrawal_fee_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (source_vault_root, destination_vault_root, staker) =
+            setup_vaults(&mut vault_program_client).await;
+
+        vault_program_client
+            .set_migration_partner(&source_vault_root, &destination_vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_migration_partner(&destination_vault_root, &source_vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_migrate_to_vault(
+                &source_vault_root,
+                &destination_vault_root,
+                &staker,
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let source_vault = vault_program_client
+            .get_vault(&source_vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(source_vault.vrt_supply(), 0);
+        assert_eq!(source_vault.tokens_deposited(), 0);
+        let destination_vault = vault_program_client
+            .get_vault(&destination_vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(destination_vault.tokens_deposited(), MINT_AMOUNT);
+
+        let staker_destination_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &staker.pubkey(),
+                &destination_vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(staker_destination_vrt_token_account.amount, MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_to_vault_different_supported_mint_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (source_vault_root, _, staker) = setup_vaults(&mut vault_program_client).await;
+
+        let other_vault_root = vault_program_client
+            .do_initialize_vault(0, 0, 0, 9)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_migrate_to_vault(
+                &source_vault_root,
+                &other_vault_root,
+                &staker,
+                MINT_AMOUNT,
+                0,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultMigrationInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_migration_partner_self_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (source_vault_root, _, _) = setup_vaults(&mut vault_program_client).await;
+
+        let result = vault_program_client
+            .set_migration_partner(&source_vault_root, &source_vault_root.vault_pubkey)
+            .await;
+        assert_vault_error(result, VaultError::VaultMigrationInvalid);
+    }
+}
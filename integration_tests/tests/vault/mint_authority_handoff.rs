@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, vault::Vault, MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS};
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::{program_option::COption, pubkey::Pubkey};
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{fixture::TestBuilder, vault_client::assert_vault_error};
+
+    #[tokio::test]
+    async fn test_mint_authority_handoff_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        // only the config admin can propose a handoff
+        let result = vault_program_client
+            .propose_mint_authority_handoff(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &jito_restaking_program::id(),
+            )
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+
+        vault_program_client
+            .propose_mint_authority_handoff(
+                &vault_root.vault_pubkey,
+                &config_admin,
+                &jito_restaking_program::id(),
+            )
+            .await
+            .unwrap();
+
+        let successor = Vault::find_mint_authority_successor(
+            &jito_restaking_program::id(),
+            &vault_root.vault_pubkey,
+        );
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.mint_authority_successor, successor);
+
+        let result = vault_program_client
+            .execute_mint_authority_handoff(&vault_root.vault_pubkey, &vault.vrt_mint)
+            .await;
+        assert_vault_error(
+            result,
+            VaultError::VaultMintAuthorityHandoffChallengeWindowActive,
+        );
+
+        fixture
+            .warp_slot_incremental(MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS * config.epoch_length())
+            .await
+            .unwrap();
+
+        vault_program_client
+            .execute_mint_authority_handoff(&vault_root.vault_pubkey, &vault.vrt_mint)
+            .await
+            .unwrap();
+
+        let vrt_mint = fixture.get_token_mint(&vault.vrt_mint).await.unwrap();
+        assert_eq!(vrt_mint.mint_authority, COption::Some(successor));
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(!vault.is_mint_authority_handoff_pending());
+    }
+
+    #[tokio::test]
+    async fn test_mint_authority_handoff_cancelled_by_vault_admin() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .propose_mint_authority_handoff(
+                &vault_root.vault_pubkey,
+                &config_admin,
+                &jito_restaking_program::id(),
+            )
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .cancel_mint_authority_handoff(&vault_root.vault_pubkey, &Keypair::new())
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+
+        vault_program_client
+            .cancel_mint_authority_handoff(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS * config.epoch_length())
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .execute_mint_authority_handoff(&vault_root.vault_pubkey, &vault.vrt_mint)
+            .await;
+        assert_vault_error(result, VaultError::VaultMintAuthorityHandoffNotPending);
+
+        let vrt_mint = fixture.get_token_mint(&vault.vrt_mint).await.unwrap();
+        assert_eq!(
+            vrt_mint.mint_authority,
+            COption::Some(vault_root.vault_pubkey)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mint_authority_handoff_non_executable_successor_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .propose_mint_authority_handoff(
+                &vault_root.vault_pubkey,
+                &config_admin,
+                &Pubkey::new_unique(),
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultMintAuthoritySuccessorInvalid);
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::native_token::sol_to_lamports;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{fixture::TestBuilder, vault_client::assert_vault_error};
+
+    #[tokio::test]
+    async fn test_mint_to_with_sol_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault_with_supported_mint(&spl_token::native_mint::id(), 0, 0, 0, 9)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .airdrop(&depositor.pubkey(), 100.0)
+            .await
+            .unwrap();
+        vault_program_client
+            .create_ata(&vault.vrt_mint, &depositor.pubkey())
+            .await
+            .unwrap();
+
+        let amount_in = sol_to_lamports(10.0);
+        vault_program_client
+            .do_mint_to_with_sol(&vault_root, &depositor, amount_in, amount_in)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), amount_in);
+        assert_eq!(vault.vrt_supply(), amount_in);
+
+        let vault_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &vault_root.vault_pubkey,
+                &spl_token::native_mint::id(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(vault_token_account.amount, amount_in);
+
+        let depositor_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_vrt_token_account.amount, amount_in);
+    }
+
+    #[tokio::test]
+    async fn test_mint_to_with_sol_non_native_vault_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to_with_sol(&vault_root, &depositor, 100_000, 100_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultSupportedMintInvalid);
+    }
+}
@@ -1,21 +1,65 @@
 mod add_delegation;
+mod admin_burn;
+mod admin_set_config_paused;
+mod admin_set_delegation_cooldown;
+mod admin_set_protocol_fee;
+mod ban_operator;
+mod burn;
+mod burn_delegated;
+mod burn_or_enqueue_withdrawal;
 mod burn_withdrawal_ticket;
+mod change_withdrawal_ticket_owner;
 mod close_update_state_tracker;
+mod close_vault;
+mod compute_units;
 mod cooldown_delegation;
+mod crank_reward;
 mod crank_vault_update_state_tracker;
 mod create_token_metadata;
+mod delegation_approval;
+mod delegation_strategy;
+mod deposit_allowlist;
+mod deposit_limits;
+mod deposit_receipt;
 mod enqueue_withdrawal;
+mod events;
+mod flash_deposit_protection;
 mod initialize_config;
 mod initialize_vault;
 mod initialize_vault_ncn_slasher_ticket;
 mod initialize_vault_ncn_ticket;
 mod initialize_vault_operator_delegation;
+mod initialize_vault_token_account;
 mod initialize_vault_update_state_tracker;
+mod migrate_account;
+mod migrate_to_vault;
+mod mint_authority_handoff;
+mod mint_to;
+mod mint_to_with_sol;
+mod ncn_reward_router;
+mod operator_stake_weight;
+mod pda;
+mod reconcile_vault_balance;
+mod remove_slasher;
+mod reward_distribution;
 mod reward_fee;
 mod set_admin;
 mod set_capacity;
+mod set_fee_wallet;
 mod set_fees;
+mod set_hook_program;
+mod set_min_delegation_amount;
+mod set_operator_delegation_paused;
+mod set_operator_max_delegation;
 mod set_secondary_admin;
+mod set_vault_paused;
 mod slash;
+mod slash_proposal;
 mod update_token_metadata;
 mod update_vault_balance;
+mod vault_epoch_length;
+mod vault_epoch_stats;
+mod vault_insurance;
+mod vault_operator_delegation_snapshot;
+mod vault_shutdown;
+mod withdrawal_delay;
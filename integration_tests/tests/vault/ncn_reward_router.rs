@@ -0,0 +1,222 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, ncn_reward_router::NcnRewardRouter};
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const PROTOCOL_FEE_BPS: u16 = 1_000;
+    const OPERATOR_COMMISSION_BPS: u16 = 500;
+
+    #[tokio::test]
+    async fn test_distribute_ncn_rewards_ok() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut restaking_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 2, &[])
+            .await
+            .unwrap();
+        let operators: Vec<Pubkey> = operator_roots
+            .iter()
+            .map(|operator_root| operator_root.operator_pubkey)
+            .collect();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let protocol_fee_wallet = Keypair::new();
+        vault_program_client
+            .admin_set_protocol_fee(
+                &vault_config_admin,
+                &protocol_fee_wallet.pubkey(),
+                PROTOCOL_FEE_BPS,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .create_ata(&vault.supported_mint, &protocol_fee_wallet.pubkey())
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_set_commission(&operator_roots[0], OPERATOR_COMMISSION_BPS)
+            .await
+            .unwrap();
+        for operator_root in operator_roots.iter() {
+            vault_program_client
+                .create_ata(
+                    &vault.supported_mint,
+                    &operator_root.operator_admin.pubkey(),
+                )
+                .await
+                .unwrap();
+        }
+
+        // three quarters of the stake is delegated to the first operator
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operators[0], 30_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operators[1], 10_000)
+            .await
+            .unwrap();
+
+        // the NCN pays into the router
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_ncn_reward_router(&vault_root.vault_pubkey, &ncn_root.ncn_pubkey, epoch)
+            .await
+            .unwrap();
+        let ncn_reward_router = NcnRewardRouter::find_program_address(
+            &jito_vault_program::id(),
+            &ncn_root.ncn_pubkey,
+            &vault_root.vault_pubkey,
+            epoch,
+        )
+        .0;
+        fixture
+            .mint_spl_to(&vault.supported_mint, &ncn_reward_router, 10_000)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .distribute_ncn_rewards(
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                epoch,
+                &operators,
+            )
+            .await
+            .unwrap();
+
+        // 1,000 to the protocol, then 5% of the first operator's 6,750 share
+        let ncn_reward_router_account = vault_program_client
+            .get_ncn_reward_router(&ncn_root.ncn_pubkey, &vault_root.vault_pubkey, epoch)
+            .await
+            .unwrap();
+        assert_eq!(ncn_reward_router_account.total_rewards(), 10_000);
+        assert_eq!(ncn_reward_router_account.protocol_rewards(), 1_000);
+        assert_eq!(ncn_reward_router_account.operator_rewards(), 337);
+        assert_eq!(ncn_reward_router_account.vault_rewards(), 8_663);
+
+        for (owner, amount) in [
+            (ncn_reward_router, 0),
+            (protocol_fee_wallet.pubkey(), 1_000),
+            (operator_roots[0].operator_admin.pubkey(), 337),
+            (operator_roots[1].operator_admin.pubkey(), 0),
+            (vault_root.vault_pubkey, 108_663),
+        ] {
+            assert_eq!(
+                fixture
+                    .get_token_account(&get_associated_token_address(&owner, &vault.supported_mint))
+                    .await
+                    .unwrap()
+                    .amount,
+                amount
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_distribute_ncn_rewards_missing_operator_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 2, &[])
+            .await
+            .unwrap();
+        let operators: Vec<Pubkey> = operator_roots
+            .iter()
+            .map(|operator_root| operator_root.operator_pubkey)
+            .collect();
+
+        vault_program_client
+            .initialize_ncn_reward_router(&vault_root.vault_pubkey, &ncn_root.ncn_pubkey, 0)
+            .await
+            .unwrap();
+
+        // the token accounts exist, so only the missing delegations fail the distribution
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        let ncn_reward_router = NcnRewardRouter::find_program_address(
+            &jito_vault_program::id(),
+            &ncn_root.ncn_pubkey,
+            &vault_root.vault_pubkey,
+            0,
+        )
+        .0;
+        for owner in [ncn_reward_router, config.protocol_fee_wallet]
+            .into_iter()
+            .chain(
+                operator_roots
+                    .iter()
+                    .map(|operator_root| operator_root.operator_admin.pubkey()),
+            )
+        {
+            vault_program_client
+                .create_ata(&vault.supported_mint, &owner)
+                .await
+                .unwrap();
+        }
+
+        let result = vault_program_client
+            .distribute_ncn_rewards(
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                0,
+                &operators[..1],
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorDelegationsIncomplete);
+
+        let result = vault_program_client
+            .distribute_ncn_rewards(
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                0,
+                &[operators[1], operators[0]],
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorDelegationsIncomplete);
+    }
+}
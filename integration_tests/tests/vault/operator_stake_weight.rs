@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, vault_update_state_tracker::VaultUpdateStateTracker};
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    #[tokio::test]
+    async fn test_crank_operator_stake_weight_ok() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let ncn = ncn_root.ncn_pubkey;
+        let operator = operator_roots[0].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 50_000)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_epoch = slot / config.epoch_length();
+        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            ncn_epoch,
+        )
+        .0;
+        vault_program_client
+            .initialize_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_crank_vault_update_state_tracker(&vault_root.vault_pubkey, &operator)
+            .await
+            .unwrap();
+
+        let supported_mint = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap()
+            .supported_mint;
+        vault_program_client
+            .initialize_operator_stake_weight(&ncn, &operator, &supported_mint, ncn_epoch)
+            .await
+            .unwrap();
+
+        // the vault can't be counted before its delegation is snapshotted
+        let result = vault_program_client
+            .crank_operator_stake_weight(&ncn, &operator, &vault_root.vault_pubkey, ncn_epoch)
+            .await;
+        assert!(result.is_err());
+
+        vault_program_client
+            .snapshot_vault_operator_delegation(&vault_root.vault_pubkey, &operator, ncn_epoch)
+            .await
+            .unwrap();
+        vault_program_client
+            .crank_operator_stake_weight(&ncn, &operator, &vault_root.vault_pubkey, ncn_epoch)
+            .await
+            .unwrap();
+
+        let operator_stake_weight = vault_program_client
+            .get_operator_stake_weight(&ncn, &operator, &supported_mint, ncn_epoch)
+            .await
+            .unwrap();
+        assert_eq!(operator_stake_weight.ncn, ncn);
+        assert_eq!(operator_stake_weight.operator, operator);
+        assert_eq!(operator_stake_weight.ncn_epoch(), ncn_epoch);
+        assert_eq!(operator_stake_weight.stake_weight(), 50_000);
+        assert_eq!(operator_stake_weight.vault_count(), 1);
+
+        // each vault is only counted once
+        fixture.warp_slot_incremental(1).await.unwrap();
+        let result = vault_program_client
+            .crank_operator_stake_weight(&ncn, &operator, &vault_root.vault_pubkey, ncn_epoch)
+            .await;
+        assert_vault_error(result, VaultError::OperatorStakeWeightVaultAlreadyCounted);
+    }
+}
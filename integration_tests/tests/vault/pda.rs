@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
+        vault_operator_delegation::VaultOperatorDelegation,
+        vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+        vault_update_state_tracker::VaultUpdateStateTracker,
+    };
+    use jito_vault_sdk::pda;
+    use solana_sdk::{pubkey::Pubkey, signer::Signer};
+
+    use crate::fixtures::fixture::{ConfiguredVault, TestBuilder};
+
+    #[tokio::test]
+    async fn test_pda_matches_accounts_created_on_chain() {
+        let mut fixture = TestBuilder::new().await;
+        let program_id = jito_vault_program::id();
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[100])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+        let slasher = slashers_amounts[0].0.pubkey();
+
+        let (config_pubkey, config_bump) = pda::derive_config(&program_id);
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.bump, config_bump);
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let (vault_pubkey, vault_bump) = pda::derive_vault(&program_id, &vault.base);
+        assert_eq!(vault_pubkey, vault_root.vault_pubkey);
+        assert_eq!(vault.bump, vault_bump);
+
+        let vault_ncn_ticket = vault_program_client
+            .get_vault_ncn_ticket(&vault_root.vault_pubkey, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            pda::derive_vault_ncn_ticket(
+                &program_id,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey
+            ),
+            (
+                VaultNcnTicket::find_program_address(
+                    &program_id,
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey
+                )
+                .0,
+                vault_ncn_ticket.bump
+            )
+        );
+
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operator)
+            .await
+            .unwrap();
+        assert_eq!(
+            pda::derive_vault_operator_delegation(&program_id, &vault_root.vault_pubkey, &operator),
+            (
+                VaultOperatorDelegation::find_program_address(
+                    &program_id,
+                    &vault_root.vault_pubkey,
+                    &operator
+                )
+                .0,
+                vault_operator_delegation.bump
+            )
+        );
+
+        let vault_ncn_slasher_ticket = vault_program_client
+            .get_vault_ncn_slasher_ticket(&vault_root.vault_pubkey, &ncn_root.ncn_pubkey, &slasher)
+            .await
+            .unwrap();
+        assert_eq!(
+            pda::derive_vault_ncn_slasher_ticket(
+                &program_id,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher
+            ),
+            (
+                VaultNcnSlasherTicket::find_program_address(
+                    &program_id,
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher
+                )
+                .0,
+                vault_ncn_slasher_ticket.bump
+            )
+        );
+    }
+
+    #[test]
+    fn test_pda_matches_core_seeds() {
+        let program_id = jito_vault_program::id();
+        let vault = Pubkey::new_unique();
+        let base = Pubkey::new_unique();
+
+        let (address, bump, _) =
+            VaultUpdateStateTracker::find_program_address(&program_id, &vault, 7);
+        assert_eq!(
+            pda::derive_vault_update_state_tracker(&program_id, &vault, 7),
+            (address, bump)
+        );
+
+        let (address, bump, _) =
+            VaultStakerWithdrawalTicket::find_program_address(&program_id, &vault, &base);
+        assert_eq!(
+            pda::derive_vault_staker_withdrawal_ticket(&program_id, &vault, &base),
+            (address, bump)
+        );
+    }
+}
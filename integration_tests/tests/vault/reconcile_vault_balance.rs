@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{fixture::TestBuilder, vault_client::assert_vault_error};
+
+    const MINT_AMOUNT: u64 = 100_000;
+    const SHORTFALL: u64 = 1_000;
+
+    #[tokio::test]
+    async fn test_reconcile_vault_balance_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT * 2)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let vault_token_account =
+            get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint);
+        fixture
+            .set_token_account_amount(&vault_token_account, MINT_AMOUNT - SHORTFALL)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await;
+        assert_vault_error(result, VaultError::VaultBalanceDiverged);
+        let result = vault_program_client
+            .do_burn(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await;
+        assert_vault_error(result, VaultError::VaultBalanceDiverged);
+
+        let result = vault_program_client
+            .reconcile_vault_balance(&vault_root.vault_pubkey, &depositor, SHORTFALL)
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+        let result = vault_program_client
+            .reconcile_vault_balance(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                SHORTFALL - 1,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultReconcileAdjustmentExceeded);
+
+        vault_program_client
+            .reconcile_vault_balance(&vault_root.vault_pubkey, &vault_root.vault_admin, SHORTFALL)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - SHORTFALL);
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT);
+
+        let result = vault_program_client
+            .reconcile_vault_balance(&vault_root.vault_pubkey, &vault_root.vault_admin, SHORTFALL)
+            .await;
+        assert_vault_error(result, VaultError::VaultBalanceNotDiverged);
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_closed_vault_token_account() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT * 2)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let vault_token_account =
+            get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint);
+        fixture.close_token_account(&vault_token_account);
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await;
+        assert_vault_error(result, VaultError::VaultTokenAccountClosed);
+        let result = vault_program_client
+            .reconcile_vault_balance(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                MINT_AMOUNT,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultTokenAccountClosed);
+
+        // the admin recreates the token account and writes the lost tokens off
+        fixture
+            .create_ata(&vault.supported_mint, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .reconcile_vault_balance(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), 0);
+    }
+}
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, vault_ncn_slasher_ticket::VaultNcnSlasherTicket};
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultRoot},
+    };
+
+    #[tokio::test]
+    async fn test_remove_slasher_after_cooldown_ok() {
+        let mut fixture = TestBuilder::new().await;
+
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(99, 100, 0)
+            .await
+            .unwrap();
+
+        let _restaking_config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let slasher = Keypair::new();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                100,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_ncn_slasher_ticket(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                &slasher.pubkey(),
+            )
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        vault_program_client
+            .do_warmup_vault_ncn_slasher_ticket(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                &slasher.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[])
+            .await
+            .unwrap();
+
+        // an active slasher can't be removed
+        let result = vault_program_client
+            .do_remove_slasher(&vault_root, &ncn_root.ncn_pubkey, &slasher.pubkey())
+            .await;
+        assert_vault_error(result, VaultError::VaultNcnSlasherTicketNotInactive);
+
+        vault_program_client
+            .do_cooldown_vault_ncn_slasher_ticket(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                &slasher.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        // nor can one that's cooling down, so in-flight slashes can still resolve
+        let result = vault_program_client
+            .do_remove_slasher(&vault_root, &ncn_root.ncn_pubkey, &slasher.pubkey())
+            .await;
+        assert_vault_error(result, VaultError::VaultNcnSlasherTicketNotInactive);
+
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_remove_slasher(&vault_root, &ncn_root.ncn_pubkey, &slasher.pubkey())
+            .await
+            .unwrap();
+
+        let vault_ncn_slasher_ticket = VaultNcnSlasherTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &ncn_root.ncn_pubkey,
+            &slasher.pubkey(),
+        )
+        .0;
+        assert_eq!(
+            vault_program_client
+                .get_lamports(&vault_ncn_slasher_ticket)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remove_slasher_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(99, 100, 0)
+            .await
+            .unwrap();
+
+        let _restaking_config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let slasher = Keypair::new();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                100,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_ncn_slasher_ticket(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                &slasher.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        let wrong_admin_root = VaultRoot {
+            vault_pubkey: vault_root.vault_pubkey,
+            vault_admin: Keypair::new(),
+        };
+        vault_program_client
+            .airdrop(&wrong_admin_root.vault_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_remove_slasher(&wrong_admin_root, &ncn_root.ncn_pubkey, &slasher.pubkey())
+            .await;
+        assert_vault_error(result, VaultError::VaultSlasherAdminInvalid);
+    }
+}
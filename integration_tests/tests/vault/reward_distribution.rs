@@ -0,0 +1,434 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, reward_distribution::RewardDistribution};
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    const EPOCH: u64 = 1;
+
+    /// Creates a reward mint funded to the vault admin and stakers with token accounts for it
+    async fn setup(
+        vault_program_client: &mut VaultProgramClient,
+        vault_root: &VaultRoot,
+        num_stakers: usize,
+        total_rewards: u64,
+    ) -> (Pubkey, Vec<Keypair>) {
+        let mint = Keypair::new();
+        vault_program_client.create_token_mint(&mint).await.unwrap();
+        vault_program_client
+            .mint_spl_to(
+                &mint.pubkey(),
+                &vault_root.vault_admin.pubkey(),
+                total_rewards,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .create_ata(&mint.pubkey(), &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let mut stakers = Vec::with_capacity(num_stakers);
+        for _ in 0..num_stakers {
+            let staker = Keypair::new();
+            vault_program_client
+                .airdrop(&staker.pubkey(), 1.0)
+                .await
+                .unwrap();
+            vault_program_client
+                .create_ata(&mint.pubkey(), &staker.pubkey())
+                .await
+                .unwrap();
+            stakers.push(staker);
+        }
+
+        (mint.pubkey(), stakers)
+    }
+
+    #[tokio::test]
+    async fn test_claim_rewards_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let (mint, stakers) = setup(&mut vault_program_client, &vault_root, 3, 6_000).await;
+        let claims: Vec<(Pubkey, u64)> = stakers
+            .iter()
+            .zip([1_000, 2_000, 3_000])
+            .map(|(staker, amount)| (staker.pubkey(), amount))
+            .collect();
+
+        vault_program_client
+            .initialize_reward_distribution(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &mint,
+                None,
+                EPOCH,
+                RewardDistribution::compute_root(&claims),
+                6_000,
+            )
+            .await
+            .unwrap();
+        let vault_rewards_token_account =
+            get_associated_token_address(&vault_root.vault_pubkey, &mint);
+        assert_eq!(
+            fixture
+                .get_token_account(&vault_rewards_token_account)
+                .await
+                .unwrap()
+                .amount,
+            6_000
+        );
+
+        // a staker can't claim more than they're owed
+        let proof = RewardDistribution::compute_proof(&claims, 1).unwrap();
+        let result = vault_program_client
+            .claim_rewards(
+                &vault_root.vault_pubkey,
+                EPOCH,
+                &stakers[1],
+                2_001,
+                proof.clone(),
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultRewardProofInvalid);
+
+        for (index, (staker, amount)) in stakers.iter().zip(claims.iter().map(|c| c.1)).enumerate()
+        {
+            vault_program_client
+                .claim_rewards(
+                    &vault_root.vault_pubkey,
+                    EPOCH,
+                    staker,
+                    amount,
+                    RewardDistribution::compute_proof(&claims, index).unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(
+                fixture
+                    .get_token_account(&get_associated_token_address(&staker.pubkey(), &mint))
+                    .await
+                    .unwrap()
+                    .amount,
+                amount
+            );
+        }
+
+        let reward_distribution_pubkey = RewardDistribution::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            EPOCH,
+        )
+        .0;
+        let reward_distribution = vault_program_client
+            .get_reward_distribution(&vault_root.vault_pubkey, EPOCH)
+            .await
+            .unwrap();
+        assert_eq!(reward_distribution.total_claimed(), 6_000);
+        assert_eq!(reward_distribution.num_claims(), 3);
+        let reward_claim = vault_program_client
+            .get_reward_claim(&reward_distribution_pubkey, &stakers[1].pubkey())
+            .await
+            .unwrap();
+        assert_eq!(reward_claim.amount(), 2_000);
+        assert_eq!(
+            fixture
+                .get_token_account(&vault_rewards_token_account)
+                .await
+                .unwrap()
+                .amount,
+            0
+        );
+
+        // a staker can only claim once
+        fixture.warp_slot_incremental(1).await.unwrap();
+        let result = vault_program_client
+            .claim_rewards(&vault_root.vault_pubkey, EPOCH, &stakers[1], 2_000, proof)
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_claim_rewards_exceeds_total_rewards_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let (mint, stakers) = setup(&mut vault_program_client, &vault_root, 2, 2_500).await;
+        let claims = [(stakers[0].pubkey(), 1_000), (stakers[1].pubkey(), 2_000)];
+
+        vault_program_client
+            .initialize_reward_distribution(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &mint,
+                None,
+                EPOCH,
+                RewardDistribution::compute_root(&claims),
+                2_500,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .claim_rewards(
+                &vault_root.vault_pubkey,
+                EPOCH,
+                &stakers[0],
+                1_000,
+                RewardDistribution::compute_proof(&claims, 0).unwrap(),
+            )
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .claim_rewards(
+                &vault_root.vault_pubkey,
+                EPOCH,
+                &stakers[1],
+                2_000,
+                RewardDistribution::compute_proof(&claims, 1).unwrap(),
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultRewardsExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_claim_rewards_paused_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let (mint, stakers) = setup(&mut vault_program_client, &vault_root, 1, 1_000).await;
+        let claims = [(stakers[0].pubkey(), 1_000)];
+
+        vault_program_client
+            .initialize_reward_distribution(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &mint,
+                None,
+                EPOCH,
+                RewardDistribution::compute_root(&claims),
+                1_000,
+            )
+            .await
+            .unwrap();
+        let proof = RewardDistribution::compute_proof(&claims, 0).unwrap();
+
+        vault_program_client
+            .admin_set_config_paused(&config_admin, true)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .claim_rewards(
+                &vault_root.vault_pubkey,
+                EPOCH,
+                &stakers[0],
+                1_000,
+                proof.clone(),
+            )
+            .await;
+        assert_vault_error(result, VaultError::ConfigPaused);
+        vault_program_client
+            .admin_set_config_paused(&config_admin, false)
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+        vault_program_client
+            .set_vault_paused(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                true,
+            )
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .claim_rewards(
+                &vault_root.vault_pubkey,
+                EPOCH,
+                &stakers[0],
+                1_000,
+                proof.clone(),
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultIsPaused);
+        vault_program_client
+            .set_vault_paused(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                false,
+            )
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+        vault_program_client
+            .claim_rewards(&vault_root.vault_pubkey, EPOCH, &stakers[0], 1_000, proof)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_initialize_reward_distribution_operator_commission_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_set_commission(&operator_root, 500)
+            .await
+            .unwrap();
+        let withdrawal_fee_wallet = operator_root.operator_admin.pubkey();
+
+        let (mint, stakers) = setup(&mut vault_program_client, &vault_root, 1, 10_000).await;
+        vault_program_client
+            .create_ata(&mint, &withdrawal_fee_wallet)
+            .await
+            .unwrap();
+        let claims = [(stakers[0].pubkey(), 9_500)];
+
+        vault_program_client
+            .initialize_reward_distribution(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &mint,
+                Some((&operator_root.operator_pubkey, &withdrawal_fee_wallet)),
+                EPOCH,
+                RewardDistribution::compute_root(&claims),
+                10_000,
+            )
+            .await
+            .unwrap();
+
+        let reward_distribution = vault_program_client
+            .get_reward_distribution(&vault_root.vault_pubkey, EPOCH)
+            .await
+            .unwrap();
+        assert_eq!(reward_distribution.operator, operator_root.operator_pubkey);
+        assert_eq!(reward_distribution.operator_commission(), 500);
+        assert_eq!(reward_distribution.total_rewards(), 9_500);
+        assert_eq!(
+            fixture
+                .get_token_account(&get_associated_token_address(&withdrawal_fee_wallet, &mint))
+                .await
+                .unwrap()
+                .amount,
+            500
+        );
+        assert_eq!(
+            fixture
+                .get_token_account(&get_associated_token_address(
+                    &vault_root.vault_pubkey,
+                    &mint
+                ))
+                .await
+                .unwrap()
+                .amount,
+            9_500
+        );
+
+        vault_program_client
+            .claim_rewards(
+                &vault_root.vault_pubkey,
+                EPOCH,
+                &stakers[0],
+                9_500,
+                RewardDistribution::compute_proof(&claims, 0).unwrap(),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_initialize_reward_distribution_supported_mint_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .mint_spl_to(
+                &vault.supported_mint,
+                &vault_root.vault_admin.pubkey(),
+                1_000,
+            )
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .initialize_reward_distribution(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &vault.supported_mint,
+                None,
+                EPOCH,
+                [0; 32],
+                1_000,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultRewardMintInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_reward_distribution_not_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let (mint, stakers) = setup(&mut vault_program_client, &vault_root, 1, 0).await;
+        vault_program_client
+            .mint_spl_to(&mint, &stakers[0].pubkey(), 1_000)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .initialize_reward_distribution(
+                &vault_root.vault_pubkey,
+                &stakers[0],
+                &mint,
+                None,
+                EPOCH,
+                [0; 32],
+                1_000,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+}
@@ -2,6 +2,7 @@
 mod tests {
     use jito_vault_core::config::Config;
     use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
 
     use crate::fixtures::fixture::{ConfiguredVault, TestBuilder};
 
@@ -304,4 +305,92 @@ mod tests {
         assert_eq!(MINT_AMOUNT / 10, reward_fee_account.amount);
         assert_eq!(MINT_AMOUNT + MINT_AMOUNT / 10, vault.vrt_supply());
     }
+
+    /// Rewards harvested by UpdateVaultBalance raise the exchange rate, so later depositors
+    /// receive less VRT for the same amount of tokens
+    #[tokio::test]
+    async fn test_rewards_compound_into_exchange_rate() {
+        let mut fixture = TestBuilder::new().await;
+
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let deposit_fee_bps = 0;
+        let withdraw_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdraw_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT * 2)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        // double the assets backing the VRT
+        vault_program_client
+            .create_and_fund_reward_vault(&vault_root.vault_pubkey, &depositor, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        let operator_root_pubkeys: Vec<_> =
+            operator_roots.iter().map(|r| r.operator_pubkey).collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT * 2);
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT);
+
+        let late_depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &late_depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &late_depositor, MINT_AMOUNT, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+
+        let late_depositor_vrt_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &late_depositor.pubkey(),
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(late_depositor_vrt_account.amount, MINT_AMOUNT / 2);
+    }
 }
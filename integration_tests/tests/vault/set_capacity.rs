@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use jito_vault_core::config::Config;
-    use jito_vault_sdk::error::VaultError;
+    use jito_vault_sdk::{error::VaultError, instruction::VaultAdminRole};
     use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::fixtures::{fixture::TestBuilder, vault_client::assert_vault_error};
@@ -62,4 +62,60 @@ mod tests {
             .await;
         assert_vault_error(result, VaultError::VaultCapacityAdminInvalid);
     }
+
+    #[tokio::test]
+    async fn test_set_capacity_delegated_capacity_admin() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let capacity_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&capacity_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_secondary_admin(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &capacity_admin.pubkey(),
+                VaultAdminRole::CapacityAdmin,
+            )
+            .await
+            .unwrap();
+
+        // the vault admin no longer holds the capacity admin role
+        let result = vault_program_client
+            .set_capacity(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                100,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultCapacityAdminInvalid);
+
+        vault_program_client
+            .set_capacity(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &capacity_admin,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.capacity(), 100);
+    }
 }
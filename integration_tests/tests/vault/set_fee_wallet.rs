@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+    const DEPOSIT_FEE_BPS: u16 = 100;
+
+    #[tokio::test]
+    async fn test_set_fee_wallet_ok() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(DEPOSIT_FEE_BPS, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let new_fee_wallet = Keypair::new();
+        vault_program_client
+            .create_ata(&vault.vrt_mint, &new_fee_wallet.pubkey())
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_fee_wallet(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &new_fee_wallet.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.fee_wallet, new_fee_wallet.pubkey());
+
+        // deposit fees are minted to the new fee wallet
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(
+                &vault_root.vault_pubkey,
+                &[operator_roots[0].operator_pubkey],
+            )
+            .await
+            .unwrap();
+
+        let fee_account = vault_program_client
+            .get_reward_fee_token_account(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(fee_account.owner, new_fee_wallet.pubkey());
+        assert_eq!(
+            fee_account.amount,
+            MINT_AMOUNT * DEPOSIT_FEE_BPS as u64 / 10_000
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_fee_wallet_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let new_fee_wallet = Keypair::new();
+        vault_program_client
+            .create_ata(&vault.vrt_mint, &new_fee_wallet.pubkey())
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .set_fee_wallet(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &wrong_admin,
+                &new_fee_wallet.pubkey(),
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultFeeAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_fee_wallet_without_token_account_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let new_fee_wallet = Keypair::new();
+        let result = vault_program_client
+            .set_fee_wallet(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &new_fee_wallet.pubkey(),
+            )
+            .await;
+        assert!(result.is_err());
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.fee_wallet, vault_root.vault_admin.pubkey());
+    }
+}
@@ -8,7 +8,7 @@ mod tests {
     };
 
     use crate::fixtures::{
-        fixture::TestBuilder,
+        fixture::{ConfiguredVault, TestBuilder},
         vault_client::{assert_vault_error, VaultRoot},
         TestError,
     };
@@ -140,8 +140,8 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(vault.deposit_fee_bps(), new_deposit_fee_bps);
-        assert_eq!(vault.withdrawal_fee_bps(), new_withdrawal_fee_bps);
+        assert_eq!(vault.next_deposit_fee_bps(), new_deposit_fee_bps);
+        assert_eq!(vault.next_withdrawal_fee_bps(), new_withdrawal_fee_bps);
         assert_eq!(vault.reward_fee_bps(), new_reward_fee_bps);
     }
 
@@ -232,8 +232,8 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(vault.deposit_fee_bps(), new_deposit_fee_bps);
-        assert_eq!(vault.withdrawal_fee_bps(), new_withdrawal_fee_bps);
+        assert_eq!(vault.next_deposit_fee_bps(), new_deposit_fee_bps);
+        assert_eq!(vault.next_withdrawal_fee_bps(), new_withdrawal_fee_bps);
         assert_eq!(vault.reward_fee_bps(), new_reward_fee_bps);
 
         // Warp again
@@ -265,8 +265,8 @@ mod tests {
             .get_vault(&vault_pubkey)
             .await
             .unwrap();
-        assert_eq!(vault.deposit_fee_bps(), new_deposit_fee_bps);
-        assert_eq!(vault.withdrawal_fee_bps(), new_withdrawal_fee_bps);
+        assert_eq!(vault.next_deposit_fee_bps(), new_deposit_fee_bps);
+        assert_eq!(vault.next_withdrawal_fee_bps(), new_withdrawal_fee_bps);
         assert_eq!(vault.reward_fee_bps(), new_reward_fee_bps);
     }
 
@@ -370,8 +370,8 @@ mod tests {
             .get_vault(&vault_pubkey)
             .await
             .unwrap();
-        assert_eq!(vault.deposit_fee_bps(), new_deposit_fee_bps);
-        assert_eq!(vault.withdrawal_fee_bps(), new_withdrawal_fee_bps);
+        assert_eq!(vault.next_deposit_fee_bps(), new_deposit_fee_bps);
+        assert_eq!(vault.next_withdrawal_fee_bps(), new_withdrawal_fee_bps);
         assert_eq!(vault.reward_fee_bps(), new_reward_fee_bps);
     }
 
@@ -550,8 +550,8 @@ mod tests {
             .get_vault(&vault_pubkey)
             .await
             .unwrap();
-        assert_eq!(vault.deposit_fee_bps(), new_deposit_fee_bps);
-        assert_eq!(vault.withdrawal_fee_bps(), new_withdrawal_fee_bps);
+        assert_eq!(vault.next_deposit_fee_bps(), new_deposit_fee_bps);
+        assert_eq!(vault.next_withdrawal_fee_bps(), new_withdrawal_fee_bps);
         assert_eq!(vault.reward_fee_bps(), new_reward_fee_bps);
     }
 
@@ -660,8 +660,8 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(vault.deposit_fee_bps(), new_deposit_fee_bps);
-        assert_eq!(vault.withdrawal_fee_bps(), new_withdrawal_fee_bps);
+        assert_eq!(vault.next_deposit_fee_bps(), new_deposit_fee_bps);
+        assert_eq!(vault.next_withdrawal_fee_bps(), new_withdrawal_fee_bps);
     }
 
     #[tokio::test]
@@ -775,8 +775,11 @@ mod tests {
             .get_vault(&vault_pubkey)
             .await
             .unwrap();
-        assert_eq!(updated_vault.deposit_fee_bps(), new_deposit_fee_bps);
-        assert_eq!(updated_vault.withdrawal_fee_bps(), new_withdrawal_fee_bps);
+        assert_eq!(updated_vault.next_deposit_fee_bps(), new_deposit_fee_bps);
+        assert_eq!(
+            updated_vault.next_withdrawal_fee_bps(),
+            new_withdrawal_fee_bps
+        );
     }
 
     #[tokio::test]
@@ -828,8 +831,11 @@ mod tests {
             .get_vault(&vault_pubkey)
             .await
             .unwrap();
-        assert_eq!(updated_vault.deposit_fee_bps(), new_deposit_fee_bps);
-        assert_eq!(updated_vault.withdrawal_fee_bps(), new_withdrawal_fee_bps);
+        assert_eq!(updated_vault.next_deposit_fee_bps(), new_deposit_fee_bps);
+        assert_eq!(
+            updated_vault.next_withdrawal_fee_bps(),
+            new_withdrawal_fee_bps
+        );
         assert_eq!(updated_vault.reward_fee_bps(), reward_fee_bps);
     }
 
@@ -881,8 +887,8 @@ mod tests {
             .get_vault(&vault_pubkey)
             .await
             .unwrap();
-        assert_eq!(updated_vault.deposit_fee_bps(), new_deposit_fee_bps);
-        assert_eq!(updated_vault.withdrawal_fee_bps(), withdrawal_fee_bps);
+        assert_eq!(updated_vault.next_deposit_fee_bps(), new_deposit_fee_bps);
+        assert_eq!(updated_vault.next_withdrawal_fee_bps(), withdrawal_fee_bps);
         assert_eq!(updated_vault.reward_fee_bps(), reward_fee_bps);
 
         let new_withdraw_fee_bps = withdrawal_fee_bps + 1;
@@ -910,8 +916,11 @@ mod tests {
             .get_vault(&vault_pubkey)
             .await
             .unwrap();
-        assert_eq!(updated_vault.deposit_fee_bps(), new_deposit_fee_bps);
-        assert_eq!(updated_vault.withdrawal_fee_bps(), new_withdraw_fee_bps);
+        assert_eq!(updated_vault.next_deposit_fee_bps(), new_deposit_fee_bps);
+        assert_eq!(
+            updated_vault.next_withdrawal_fee_bps(),
+            new_withdraw_fee_bps
+        );
         assert_eq!(updated_vault.reward_fee_bps(), reward_fee_bps);
 
         let new_reward_fee_bps = reward_fee_bps + 1;
@@ -1017,4 +1026,88 @@ mod tests {
 
         assert_vault_error(result, VaultError::VaultFeeCapExceeded);
     }
+
+    #[tokio::test]
+    async fn test_set_fees_takes_effect_next_epoch() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(100, 100, 0, 1, &[])
+            .await
+            .unwrap();
+        let operators = [operator_roots[0].operator_pubkey];
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length() * 2)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_fees(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                Some(110),
+                Some(90),
+                None,
+            )
+            .await
+            .unwrap();
+
+        // the old fees remain in effect for the rest of the epoch
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(vault.is_fee_change_pending());
+        assert_eq!(vault.deposit_fee_bps(), 100);
+        assert_eq!(vault.withdrawal_fee_bps(), 100);
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 20_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 10_000, 9_900)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(!vault.is_fee_change_pending());
+        assert_eq!(vault.deposit_fee_bps(), 110);
+        assert_eq!(vault.withdrawal_fee_bps(), 90);
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, 10_000, 9_900)
+            .await;
+        assert_vault_error(result, VaultError::SlippageError);
+    }
 }
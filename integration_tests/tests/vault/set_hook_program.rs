@@ -0,0 +1,323 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::{error::VaultError, hook::VaultHookInstruction};
+    use solana_program::{
+        instruction::{AccountMeta, InstructionError},
+        pubkey::Pubkey,
+        system_program,
+    };
+    use solana_sdk::{
+        pubkey,
+        signature::{Keypair, Signer},
+    };
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        assert_ix_error,
+        fixture::TestBuilder,
+        vault_client::assert_vault_error,
+        vault_hook::{VAULT_HOOK_AMOUNT_EXCEEDED, VAULT_HOOK_MAX_AMOUNT, VAULT_HOOK_PROGRAM_ID},
+    };
+
+    /// The SPL memo program, loaded by program-test. It rejects the vault hook instructions since
+    /// the accounts aren't signers, so it stands in for a hook that blocks deposits.
+    const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_set_hook_program_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_hook_program(&vault_root, &MEMO_PROGRAM_ID)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(vault.has_hook_program());
+        assert_eq!(vault.hook_program, MEMO_PROGRAM_ID);
+
+        // the hook is invoked after the mint and its failure fails the deposit
+        assert!(vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .is_err());
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), 0);
+
+        // passing the system program removes the hook
+        vault_program_client
+            .set_hook_program(&vault_root, &system_program::id())
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(!vault.has_hook_program());
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_set_hook_program_not_executable_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .set_hook_program(&vault_root, &Pubkey::new_unique())
+            .await;
+        assert_vault_error(result, VaultError::VaultHookProgramInvalid);
+
+        let result = vault_program_client
+            .set_hook_program(&vault_root, &jito_vault_program::id())
+            .await;
+        assert_vault_error(result, VaultError::VaultHookProgramInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_vault_hook_invoked_after_mint_to_and_burn() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_hook_program(&vault_root, &VAULT_HOOK_PROGRAM_ID)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let depositor_token_account =
+            get_associated_token_address(&depositor.pubkey(), &vault.supported_mint);
+        let vault_token_account =
+            get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint);
+        let depositor_vrt_token_account =
+            get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint);
+        let vault_fee_token_account =
+            get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint);
+        let hook_invoked = format!("Program {} invoke [2]", VAULT_HOOK_PROGRAM_ID);
+
+        let mut instruction = jito_vault_sdk::sdk::mint_to(
+            &jito_vault_program::id(),
+            &config_pubkey,
+            &vault_root.vault_pubkey,
+            &vault.vrt_mint,
+            &depositor.pubkey(),
+            &depositor_token_account,
+            &vault_token_account,
+            &depositor_vrt_token_account,
+            None,
+            None,
+            None,
+            10_000,
+            10_000,
+        );
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(VAULT_HOOK_PROGRAM_ID, false));
+        let log_messages = vault_program_client
+            .process_with_logs(&[instruction], &[&depositor])
+            .await
+            .unwrap();
+        assert!(log_messages.contains(&hook_invoked));
+        assert!(log_messages.contains(&format!(
+            "Program log: Vault {} user {} {:?}",
+            vault_root.vault_pubkey,
+            depositor.pubkey(),
+            VaultHookInstruction::AfterMint {
+                amount_in: 10_000,
+                vrt_to_depositor: 10_000,
+            }
+        )));
+
+        let mut instruction = jito_vault_sdk::sdk::burn(
+            &jito_vault_program::id(),
+            &config_pubkey,
+            &vault_root.vault_pubkey,
+            &vault_token_account,
+            &vault.vrt_mint,
+            &depositor.pubkey(),
+            &depositor_token_account,
+            &depositor_vrt_token_account,
+            &vault_fee_token_account,
+            None,
+            None,
+            4_000,
+            4_000,
+        );
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(VAULT_HOOK_PROGRAM_ID, false));
+        let log_messages = vault_program_client
+            .process_with_logs(&[instruction], &[&depositor])
+            .await
+            .unwrap();
+        assert!(log_messages.contains(&hook_invoked));
+        assert!(log_messages.contains(&format!(
+            "Program log: Vault {} user {} {:?}",
+            vault_root.vault_pubkey,
+            depositor.pubkey(),
+            VaultHookInstruction::AfterBurn {
+                vrt_burned: 4_000,
+                amount_out: 4_000,
+            }
+        )));
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), 6_000);
+        assert_eq!(vault.vrt_supply(), 6_000);
+    }
+
+    #[tokio::test]
+    async fn test_vault_hook_failure_aborts_mint_to() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_hook_program(&vault_root, &VAULT_HOOK_PROGRAM_ID)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, VAULT_HOOK_MAX_AMOUNT + 1, 0)
+            .await;
+        assert_ix_error(result, InstructionError::Custom(VAULT_HOOK_AMOUNT_EXCEEDED));
+
+        // the tokens transferred and VRT minted before the hook ran are rolled back
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), 0);
+        assert_eq!(vault.vrt_supply(), 0);
+        let depositor_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_token_account.amount, MINT_AMOUNT);
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, VAULT_HOOK_MAX_AMOUNT, 0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), VAULT_HOOK_MAX_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_vault_hook_failure_aborts_burn() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_hook_program(&vault_root, &VAULT_HOOK_PROGRAM_ID)
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            vault_program_client
+                .do_mint_to(&vault_root, &depositor, MINT_AMOUNT / 2, 0)
+                .await
+                .unwrap();
+        }
+
+        let result = vault_program_client
+            .do_burn(&vault_root, &depositor, VAULT_HOOK_MAX_AMOUNT + 1, 0)
+            .await;
+        assert_ix_error(result, InstructionError::Custom(VAULT_HOOK_AMOUNT_EXCEEDED));
+
+        // the VRT burned and tokens transferred before the hook ran are rolled back
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT);
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT);
+        let depositor_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_vrt_token_account.amount, MINT_AMOUNT);
+
+        vault_program_client
+            .do_burn(&vault_root, &depositor, VAULT_HOOK_MAX_AMOUNT, 0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT - VAULT_HOOK_MAX_AMOUNT);
+    }
+}
@@ -0,0 +1,248 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_set_min_delegation_amount_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.min_delegation_amount(), 0);
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_min_delegation_amount(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                1_000,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.min_delegation_amount(), 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_set_min_delegation_amount_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let result = vault_program_client
+            .set_min_delegation_amount(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &wrong_admin,
+                1_000,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultDelegationAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_delegation_below_minimum_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_min_delegation_amount(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                1_000,
+            )
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator, 999)
+            .await;
+        assert_vault_error(result, VaultError::VaultDelegationBelowMinimum);
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 1_000)
+            .await
+            .unwrap();
+
+        // leaving less than the minimum staked is rejected, but cooling down everything is fine
+        let result = vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator, 1)
+            .await;
+        assert_vault_error(result, VaultError::VaultDelegationBelowMinimum);
+
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator, 1_000)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_crank_sweeps_dust_to_idle() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 2, &[])
+            .await
+            .unwrap();
+        let operators: Vec<_> = operator_roots.iter().map(|o| o.operator_pubkey).collect();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operators[0], 500)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operators[1], 50_000)
+            .await
+            .unwrap();
+
+        // raising the minimum turns the first delegation into dust
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_min_delegation_amount(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                1_000,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+
+        for operator in operators.iter() {
+            let vault_operator_delegation = vault_program_client
+                .get_vault_operator_delegation(&vault_root.vault_pubkey, operator)
+                .await
+                .unwrap();
+            let staked_amount = vault_operator_delegation.delegation_state.staked_amount();
+            assert!(staked_amount == 0 || staked_amount >= 1_000);
+        }
+
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operators[0])
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .cooling_down_amount(),
+            500
+        );
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state.staked_amount(), 50_000);
+        assert_eq!(vault.delegation_state.cooling_down_amount(), 500);
+
+        // after the cooldown completes, the dust is back to idle
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operators[0])
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .total_security()
+                .unwrap(),
+            0
+        );
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state.total_security().unwrap(), 50_000);
+    }
+}
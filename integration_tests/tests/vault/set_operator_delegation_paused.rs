@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_paused_operator_rejects_delegation_and_cools_down() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 2, &[])
+            .await
+            .unwrap();
+        let operators: Vec<_> = operator_roots.iter().map(|o| o.operator_pubkey).collect();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operators[0], 10_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operators[1], 20_000)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_set_operator_delegation_paused(
+                &vault_root,
+                &operators[0],
+                &vault_root.vault_admin,
+                true,
+            )
+            .await
+            .unwrap();
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operators[0])
+            .await
+            .unwrap();
+        assert!(vault_operator_delegation.is_delegation_paused());
+
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operators[0], 1_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorDelegationPaused);
+
+        // the other operator isn't affected
+        vault_program_client
+            .do_add_delegation(&vault_root, &operators[1], 1_000)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operators[0])
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            0
+        );
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .cooling_down_amount(),
+            10_000
+        );
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operators[1])
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            21_000
+        );
+
+        vault_program_client
+            .do_set_operator_delegation_paused(
+                &vault_root,
+                &operators[0],
+                &vault_root.vault_admin,
+                false,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operators[0], 1_000)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_set_operator_delegation_paused_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_set_operator_delegation_paused(
+                &vault_root,
+                &operator_roots[0].operator_pubkey,
+                &wrong_admin,
+                true,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultDelegationAdminInvalid);
+    }
+}
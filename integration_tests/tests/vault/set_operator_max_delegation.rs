@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_delegation_above_maximum_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 2, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+        let other_operator = operator_roots[1].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_set_operator_max_delegation(&vault_root, &operator, &vault_root.vault_admin, 1_000)
+            .await
+            .unwrap();
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operator)
+            .await
+            .unwrap();
+        assert_eq!(vault_operator_delegation.max_delegation_amount(), 1_000);
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 600)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator, 401)
+            .await;
+        assert_vault_error(result, VaultError::VaultDelegationAboveMaximum);
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 400)
+            .await
+            .unwrap();
+
+        // the maximum only applies to the operator it's set on
+        vault_program_client
+            .do_add_delegation(&vault_root, &other_operator, 5_000)
+            .await
+            .unwrap();
+
+        // removing the maximum lets the vault delegate more
+        vault_program_client
+            .do_set_operator_max_delegation(&vault_root, &operator, &vault_root.vault_admin, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 1)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_set_operator_max_delegation_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_set_operator_max_delegation(
+                &vault_root,
+                &operator_roots[0].operator_pubkey,
+                &wrong_admin,
+                1_000,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultDelegationAdminInvalid);
+    }
+}
@@ -139,21 +139,25 @@ mod tests {
         }
 
         {
-            // Fee Wallet
-            let new_admin = Pubkey::new_unique();
-            vault_program_client
+            // Fee Wallet, only settable by the fee admin with SetFeeWallet
+            let fee_wallet = vault_program_client
+                .get_vault(&vault_pubkey)
+                .await
+                .unwrap()
+                .fee_wallet;
+            let result = vault_program_client
                 .set_secondary_admin(
                     &config_pubkey,
                     &vault_pubkey,
                     &vault_admin,
-                    &new_admin,
+                    &Pubkey::new_unique(),
                     VaultAdminRole::FeeWallet,
                 )
-                .await
-                .unwrap();
+                .await;
+            assert_vault_error(result, VaultError::VaultFeeAdminInvalid);
 
             let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
-            assert_eq!(vault.fee_wallet, new_admin);
+            assert_eq!(vault.fee_wallet, fee_wallet);
         }
 
         {
@@ -210,6 +214,42 @@ mod tests {
             assert_eq!(vault.operator_admin, new_admin);
         }
 
+        {
+            // Pause Admin
+            let new_admin = Pubkey::new_unique();
+            vault_program_client
+                .set_secondary_admin(
+                    &config_pubkey,
+                    &vault_pubkey,
+                    &vault_admin,
+                    &new_admin,
+                    VaultAdminRole::PauseAdmin,
+                )
+                .await
+                .unwrap();
+
+            let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+            assert_eq!(vault.pause_admin, new_admin);
+        }
+
+        {
+            // Metadata Admin
+            let new_admin = Pubkey::new_unique();
+            vault_program_client
+                .set_secondary_admin(
+                    &config_pubkey,
+                    &vault_pubkey,
+                    &vault_admin,
+                    &new_admin,
+                    VaultAdminRole::MetadataAdmin,
+                )
+                .await
+                .unwrap();
+
+            let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+            assert_eq!(vault.metadata_admin, new_admin);
+        }
+
         {
             // Slasher Admin
             let new_admin = Pubkey::new_unique();
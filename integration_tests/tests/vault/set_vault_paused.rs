@@ -0,0 +1,253 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultStakerWithdrawalTicketRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_set_vault_paused_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(!vault.is_paused());
+        assert_eq!(vault.pause_admin, vault_root.vault_admin.pubkey());
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_vault_paused(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                true,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(vault.is_paused());
+
+        vault_program_client
+            .set_vault_paused(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                false,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(!vault.is_paused());
+    }
+
+    #[tokio::test]
+    async fn test_set_vault_paused_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let wrong_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&wrong_admin.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let result = vault_program_client
+            .set_vault_paused(&config_pubkey, &vault_root.vault_pubkey, &wrong_admin, true)
+            .await;
+        assert_vault_error(result, VaultError::VaultPauseAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_paused_vault_rejects_mutations() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 2 * MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        vault_program_client
+            .set_vault_paused(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                true,
+            )
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsPaused);
+
+        let result = vault_program_client
+            .do_burn(&vault_root, &depositor, 1_000, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsPaused);
+
+        let result = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsPaused);
+
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsPaused);
+
+        let result = vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsPaused);
+
+        vault_program_client
+            .set_vault_paused(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                false,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), 2 * MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_paused_vault_rejects_burn_withdrawal_ticket() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(
+                    &vault_root.vault_pubkey,
+                    &[operator_roots[0].operator_pubkey],
+                )
+                .await
+                .unwrap();
+        }
+
+        vault_program_client
+            .set_vault_paused(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                true,
+            )
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsPaused);
+
+        vault_program_client
+            .set_vault_paused(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                false,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, MINT_AMOUNT)
+            .await
+            .unwrap();
+    }
+}
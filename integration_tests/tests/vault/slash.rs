@@ -1,12 +1,18 @@
 #[cfg(test)]
 mod tests {
+    use jito_restaking_sdk::instruction::SlashDestination;
     use jito_vault_core::{
         config::Config, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
         vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
     };
+    use jito_vault_sdk::error::VaultError;
     use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
 
-    use crate::fixtures::fixture::{ConfiguredVault, TestBuilder};
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
 
     #[tokio::test]
     async fn test_slash_ok() {
@@ -174,4 +180,700 @@ mod tests {
             operator_root.operator_pubkey
         );
     }
+
+    #[tokio::test]
+    async fn test_slash_stale_vault_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        const MAX_SLASH_AMOUNT: u64 = 100;
+        const MINT_AMOUNT: u64 = 100_000;
+        const DELEGATION_AMOUNT: u64 = 10_000;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let slasher = &slashers_amounts[0].0;
+        fixture
+            .create_ata(&vault.supported_mint, &slasher.pubkey())
+            .await
+            .unwrap();
+
+        // the vault hasn't been updated since the epoch rolled over
+        let result = vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                slasher,
+                &operator_root.operator_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultUpdateNeeded);
+    }
+
+    #[tokio::test]
+    async fn test_slash_pro_rata_across_cooldown_and_capped_per_epoch() {
+        let mut fixture = TestBuilder::new().await;
+
+        const MAX_SLASH_AMOUNT: u64 = 100;
+        const MINT_AMOUNT: u64 = 100_000;
+        const DELEGATION_AMOUNT: u64 = 10_000;
+        const COOLDOWN_AMOUNT: u64 = 2_500;
+        const SLASH_AMOUNT: u64 = 60;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator_root.operator_pubkey])
+            .await
+            .unwrap();
+
+        // a quarter of the delegation is enqueued for cooldown, but still slashable
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator_root.operator_pubkey, COOLDOWN_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let slasher = &slashers_amounts[0].0;
+        fixture
+            .create_ata(&vault.supported_mint, &slasher.pubkey())
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher.pubkey(),
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                slasher,
+                &operator_root.operator_pubkey,
+                SLASH_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            DELEGATION_AMOUNT - COOLDOWN_AMOUNT - SLASH_AMOUNT * 3 / 4
+        );
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .enqueued_for_cooldown_amount(),
+            COOLDOWN_AMOUNT - SLASH_AMOUNT / 4
+        );
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault.delegation_state,
+            vault_operator_delegation.delegation_state
+        );
+
+        // the slasher can't exceed the per-epoch max for the operator
+        let result = vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                slasher,
+                &operator_root.operator_pubkey,
+                MAX_SLASH_AMOUNT - SLASH_AMOUNT + 1,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultMaxSlashedPerOperatorExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_slash_cap_accumulates_within_epoch_and_resets_next_epoch() {
+        let mut fixture = TestBuilder::new().await;
+
+        const MAX_SLASH_AMOUNT: u64 = 100;
+        const MINT_AMOUNT: u64 = 100_000;
+        const DELEGATION_AMOUNT: u64 = 10_000;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_pubkey, DELEGATION_AMOUNT)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let slasher = &slashers_amounts[0].0;
+        fixture
+            .create_ata(&vault.supported_mint, &slasher.pubkey())
+            .await
+            .unwrap();
+
+        for epoch_offset in [2, 1] {
+            fixture
+                .warp_slot_incremental(epoch_offset * config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &[operator_pubkey])
+                .await
+                .unwrap();
+
+            let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+            vault_program_client
+                .initialize_vault_ncn_slasher_operator_ticket(
+                    &config_pubkey,
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                    &operator_pubkey,
+                    &VaultNcnSlasherTicket::find_program_address(
+                        &jito_vault_program::id(),
+                        &vault_root.vault_pubkey,
+                        &ncn_root.ncn_pubkey,
+                        &slasher.pubkey(),
+                    )
+                    .0,
+                    &VaultNcnSlasherOperatorTicket::find_program_address(
+                        &jito_vault_program::id(),
+                        &vault_root.vault_pubkey,
+                        &ncn_root.ncn_pubkey,
+                        &slasher.pubkey(),
+                        &operator_pubkey,
+                        epoch,
+                    )
+                    .0,
+                    &vault_config_admin,
+                )
+                .await
+                .unwrap();
+
+            // several slashes in the epoch add up to the cap
+            for _ in 0..2 {
+                vault_program_client
+                    .do_slash(
+                        &vault_root,
+                        &ncn_root.ncn_pubkey,
+                        slasher,
+                        &operator_pubkey,
+                        MAX_SLASH_AMOUNT / 2,
+                    )
+                    .await
+                    .unwrap();
+            }
+            let result = vault_program_client
+                .do_slash(
+                    &vault_root,
+                    &ncn_root.ncn_pubkey,
+                    slasher,
+                    &operator_pubkey,
+                    1,
+                )
+                .await;
+            assert_vault_error(result, VaultError::VaultMaxSlashedPerOperatorExceeded);
+
+            let vault_ncn_slasher_operator_ticket = vault_program_client
+                .get_vault_ncn_slasher_operator_ticket(
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                    &operator_pubkey,
+                    epoch,
+                )
+                .await
+                .unwrap();
+            assert_eq!(
+                vault_ncn_slasher_operator_ticket.slashed(),
+                MAX_SLASH_AMOUNT
+            );
+        }
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - 2 * MAX_SLASH_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_slash_max_total_slashable_per_epoch_across_slashers() {
+        let mut fixture = TestBuilder::new().await;
+
+        const MAX_SLASH_AMOUNT: u64 = 100;
+        const MINT_AMOUNT: u64 = 100_000;
+        const DELEGATION_AMOUNT: u64 = 10_000;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT, MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        // all slashers together can slash 0.15% of the vault's tokens, 150 tokens, per epoch
+        vault_program_client
+            .set_max_total_slashable_per_epoch(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                15,
+            )
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .set_max_total_slashable_per_epoch(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                10_001,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultMaxTotalSlashableBpsInvalid);
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_pubkey, DELEGATION_AMOUNT)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator_pubkey])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        for (slasher, _) in slashers_amounts.iter() {
+            fixture
+                .create_ata(&vault.supported_mint, &slasher.pubkey())
+                .await
+                .unwrap();
+            vault_program_client
+                .initialize_vault_ncn_slasher_operator_ticket(
+                    &config_pubkey,
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                    &operator_pubkey,
+                    &VaultNcnSlasherTicket::find_program_address(
+                        &jito_vault_program::id(),
+                        &vault_root.vault_pubkey,
+                        &ncn_root.ncn_pubkey,
+                        &slasher.pubkey(),
+                    )
+                    .0,
+                    &VaultNcnSlasherOperatorTicket::find_program_address(
+                        &jito_vault_program::id(),
+                        &vault_root.vault_pubkey,
+                        &ncn_root.ncn_pubkey,
+                        &slasher.pubkey(),
+                        &operator_pubkey,
+                        epoch,
+                    )
+                    .0,
+                    &vault_config_admin,
+                )
+                .await
+                .unwrap();
+        }
+
+        let first_slasher = &slashers_amounts[0].0;
+        let second_slasher = &slashers_amounts[1].0;
+        vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                first_slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                second_slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT / 2,
+            )
+            .await
+            .unwrap();
+
+        // the second slasher is under its own cap but the vault's total is reached
+        let result = vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                second_slasher,
+                &operator_pubkey,
+                1,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultMaxTotalSlashedPerEpochExceeded);
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.slash_epoch(), epoch);
+        assert_eq!(vault.slash_epoch_tokens_deposited(), MINT_AMOUNT);
+        assert_eq!(vault.slashed_this_epoch(), 150);
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - 150);
+    }
+
+    #[tokio::test]
+    async fn test_slash_destination_ok() {
+        let mut fixture = TestBuilder::new().await;
+
+        const MAX_SLASH_AMOUNT: u64 = 100;
+        const MINT_AMOUNT: u64 = 100_000;
+        const DELEGATION_AMOUNT: u64 = 10_000;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut restaking_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator_root.operator_pubkey])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let slasher = &slashers_amounts[0].0;
+        fixture
+            .create_ata(&vault.supported_mint, &slasher.pubkey())
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher.pubkey(),
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        // burned funds come out of the supported mint's supply
+        restaking_program_client
+            .do_ncn_set_slash_destination(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                None,
+                SlashDestination::Burn,
+            )
+            .await
+            .unwrap();
+        let supply_before = fixture
+            .get_token_mint(&vault.supported_mint)
+            .await
+            .unwrap()
+            .supply;
+        vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                slasher,
+                &operator_root.operator_pubkey,
+                40,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            fixture
+                .get_token_mint(&vault.supported_mint)
+                .await
+                .unwrap()
+                .supply,
+            supply_before - 40
+        );
+
+        // insured funds can only go to the insurance wallet's token account
+        let insurance_wallet = Keypair::new();
+        restaking_program_client
+            .do_ncn_set_slash_destination(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                Some(&insurance_wallet.pubkey()),
+                SlashDestination::Insurance,
+            )
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                slasher,
+                &operator_root.operator_pubkey,
+                30,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultSlashDestinationInvalid);
+
+        fixture
+            .create_ata(&vault.supported_mint, &insurance_wallet.pubkey())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                slasher,
+                &operator_root.operator_pubkey,
+                30,
+            )
+            .await
+            .unwrap();
+
+        for (owner, amount) in [(insurance_wallet.pubkey(), 30), (slasher.pubkey(), 0)] {
+            assert_eq!(
+                fixture
+                    .get_token_account(&get_associated_token_address(&owner, &vault.supported_mint))
+                    .await
+                    .unwrap()
+                    .amount,
+                amount
+            );
+        }
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - 70);
+    }
 }
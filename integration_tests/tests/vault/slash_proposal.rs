@@ -0,0 +1,670 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+        vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    };
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+    };
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        assert_ix_error,
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{
+            assert_vault_error, VaultProgramClient, VaultRoot, VaultStakerWithdrawalTicketRoot,
+        },
+    };
+
+    const MAX_SLASH_AMOUNT: u64 = 100;
+    const MINT_AMOUNT: u64 = 100_000;
+    const DELEGATION_AMOUNT: u64 = 10_000;
+    const SLASH_VETO_WINDOW_SLOTS: u64 = 100;
+    const EVIDENCE_HASH: [u8; 32] = [7; 32];
+    const BURN_AMOUNT: u64 = 1_000;
+
+    struct SlashProposalSetup {
+        fixture: TestBuilder,
+        vault_program_client: VaultProgramClient,
+        vault_config_admin: Keypair,
+        slash_veto_admin: Keypair,
+        vault_root: VaultRoot,
+        depositor: Keypair,
+        ncn_pubkey: Pubkey,
+        operator_pubkey: Pubkey,
+        slasher: Keypair,
+        epoch: u64,
+    }
+
+    /// Sets up a vault delegated to an operator that can be slashed, with the slash veto window
+    /// enabled
+    async fn setup_slash_proposal() -> SlashProposalSetup {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            mut slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_pubkey = operator_roots[0].operator_pubkey;
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_pubkey, DELEGATION_AMOUNT)
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator_pubkey])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let (slasher, _) = slashers_amounts.remove(0);
+        fixture
+            .create_ata(&vault.supported_mint, &slasher.pubkey())
+            .await
+            .unwrap();
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher.pubkey(),
+                &operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                    &operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        let slash_veto_admin = Keypair::new();
+        vault_program_client
+            .admin_set_slash_veto(
+                &vault_config_admin,
+                &slash_veto_admin.pubkey(),
+                SLASH_VETO_WINDOW_SLOTS,
+            )
+            .await
+            .unwrap();
+
+        SlashProposalSetup {
+            fixture,
+            vault_program_client,
+            vault_config_admin,
+            slash_veto_admin,
+            vault_root,
+            depositor,
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            operator_pubkey,
+            slasher,
+            epoch,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_propose_and_execute_slash_ok() {
+        let SlashProposalSetup {
+            mut fixture,
+            mut vault_program_client,
+            vault_root,
+            ncn_pubkey,
+            operator_pubkey,
+            slasher,
+            epoch,
+            ..
+        } = setup_slash_proposal().await;
+
+        // slashing immediately isn't allowed while the veto window is enabled
+        let result = vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_pubkey,
+                &slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultSlashProposalRequired);
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let base = vault_program_client
+            .do_propose_slash(
+                &vault_root,
+                &ncn_pubkey,
+                &slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+                EVIDENCE_HASH,
+            )
+            .await
+            .unwrap();
+
+        let slash_proposal = vault_program_client
+            .get_slash_proposal(&vault_root.vault_pubkey, &base)
+            .await
+            .unwrap();
+        assert_eq!(slash_proposal.vault, vault_root.vault_pubkey);
+        assert_eq!(slash_proposal.ncn, ncn_pubkey);
+        assert_eq!(slash_proposal.operator, operator_pubkey);
+        assert_eq!(slash_proposal.slasher, slasher.pubkey());
+        assert_eq!(slash_proposal.evidence_hash, EVIDENCE_HASH);
+        assert_eq!(slash_proposal.amount(), MAX_SLASH_AMOUNT);
+        assert_eq!(slash_proposal.epoch(), epoch);
+        assert_eq!(
+            slash_proposal.executable_slot(),
+            slot + SLASH_VETO_WINDOW_SLOTS
+        );
+
+        // the proposed amount counts against the slasher's limit right away
+        let vault_ncn_slasher_operator_ticket = vault_program_client
+            .get_vault_ncn_slasher_operator_ticket(
+                &vault_root.vault_pubkey,
+                &ncn_pubkey,
+                &slasher.pubkey(),
+                &operator_pubkey,
+                epoch,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_ncn_slasher_operator_ticket.slashed(),
+            MAX_SLASH_AMOUNT
+        );
+
+        let result = vault_program_client
+            .do_execute_slash(&vault_root.vault_pubkey, &base, &slasher)
+            .await;
+        assert_vault_error(result, VaultError::VaultSlashProposalVetoWindowActive);
+
+        fixture
+            .warp_slot_incremental(SLASH_VETO_WINDOW_SLOTS)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_execute_slash(&vault_root.vault_pubkey, &base, &slasher)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - MAX_SLASH_AMOUNT);
+        assert_eq!(
+            vault.delegation_state.total_security().unwrap(),
+            DELEGATION_AMOUNT - MAX_SLASH_AMOUNT
+        );
+        let slasher_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &slasher.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(slasher_token_account.amount, MAX_SLASH_AMOUNT);
+
+        // executing doesn't count the slash twice
+        let vault_ncn_slasher_operator_ticket = vault_program_client
+            .get_vault_ncn_slasher_operator_ticket(
+                &vault_root.vault_pubkey,
+                &ncn_pubkey,
+                &slasher.pubkey(),
+                &operator_pubkey,
+                epoch,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_ncn_slasher_operator_ticket.slashed(),
+            MAX_SLASH_AMOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_veto_slash_ok() {
+        let SlashProposalSetup {
+            mut fixture,
+            mut vault_program_client,
+            slash_veto_admin,
+            vault_root,
+            ncn_pubkey,
+            operator_pubkey,
+            slasher,
+            epoch,
+            ..
+        } = setup_slash_proposal().await;
+
+        let base = vault_program_client
+            .do_propose_slash(
+                &vault_root,
+                &ncn_pubkey,
+                &slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+                EVIDENCE_HASH,
+            )
+            .await
+            .unwrap();
+
+        // the pending proposal uses up the slasher's limit for the epoch
+        let result = vault_program_client
+            .do_propose_slash(
+                &vault_root,
+                &ncn_pubkey,
+                &slasher,
+                &operator_pubkey,
+                1,
+                EVIDENCE_HASH,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultMaxSlashedPerOperatorExceeded);
+
+        // the slash can be vetoed even after the veto window has passed
+        fixture
+            .warp_slot_incremental(SLASH_VETO_WINDOW_SLOTS)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_veto_slash(&vault_root.vault_pubkey, &base, &slash_veto_admin)
+            .await
+            .unwrap();
+
+        let vault_ncn_slasher_operator_ticket = vault_program_client
+            .get_vault_ncn_slasher_operator_ticket(
+                &vault_root.vault_pubkey,
+                &ncn_pubkey,
+                &slasher.pubkey(),
+                &operator_pubkey,
+                epoch,
+            )
+            .await
+            .unwrap();
+        assert_eq!(vault_ncn_slasher_operator_ticket.slashed(), 0);
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT);
+        assert_eq!(
+            vault.delegation_state.total_security().unwrap(),
+            DELEGATION_AMOUNT
+        );
+
+        // the released limit can be used by a new proposal
+        vault_program_client
+            .do_propose_slash(
+                &vault_root,
+                &ncn_pubkey,
+                &slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+                EVIDENCE_HASH,
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn get_depositor_balance(
+        fixture: &mut TestBuilder,
+        vault_program_client: &mut VaultProgramClient,
+        vault_root: &VaultRoot,
+        depositor: &Keypair,
+    ) -> u64 {
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap()
+            .amount
+    }
+
+    #[tokio::test]
+    async fn test_burn_with_pending_slash_without_haircut_ok() {
+        let SlashProposalSetup {
+            mut fixture,
+            mut vault_program_client,
+            vault_root,
+            depositor,
+            ncn_pubkey,
+            operator_pubkey,
+            slasher,
+            ..
+        } = setup_slash_proposal().await;
+
+        vault_program_client
+            .do_propose_slash(
+                &vault_root,
+                &ncn_pubkey,
+                &slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+                EVIDENCE_HASH,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.pending_slash_amount(), MAX_SLASH_AMOUNT);
+        assert!(!vault.slash_dispute_haircut());
+
+        // without the opt-in, the pending slash doesn't change the burn price
+        vault_program_client
+            .do_burn(&vault_root, &depositor, BURN_AMOUNT, BURN_AMOUNT)
+            .await
+            .unwrap();
+        assert_eq!(
+            get_depositor_balance(
+                &mut fixture,
+                &mut vault_program_client,
+                &vault_root,
+                &depositor
+            )
+            .await,
+            BURN_AMOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_burn_with_pending_slash_haircut_ok() {
+        let SlashProposalSetup {
+            mut fixture,
+            mut vault_program_client,
+            vault_config_admin,
+            slash_veto_admin,
+            vault_root,
+            depositor,
+            ncn_pubkey,
+            operator_pubkey,
+            slasher,
+            ..
+        } = setup_slash_proposal().await;
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+
+        let result = vault_program_client
+            .set_slash_dispute_haircut(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_config_admin,
+                true,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+
+        vault_program_client
+            .set_slash_dispute_haircut(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                true,
+            )
+            .await
+            .unwrap();
+
+        let base = vault_program_client
+            .do_propose_slash(
+                &vault_root,
+                &ncn_pubkey,
+                &slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+                EVIDENCE_HASH,
+            )
+            .await
+            .unwrap();
+
+        // the VRT is priced against the deposits left after the pending slash
+        let haircut_amount = BURN_AMOUNT * (MINT_AMOUNT - MAX_SLASH_AMOUNT) / MINT_AMOUNT;
+        let result = vault_program_client
+            .do_burn(&vault_root, &depositor, BURN_AMOUNT, BURN_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::SlippageError);
+
+        vault_program_client
+            .do_burn(&vault_root, &depositor, BURN_AMOUNT, haircut_amount)
+            .await
+            .unwrap();
+        assert_eq!(
+            get_depositor_balance(
+                &mut fixture,
+                &mut vault_program_client,
+                &vault_root,
+                &depositor
+            )
+            .await,
+            haircut_amount
+        );
+
+        // once the slash is vetoed, the haircut is released
+        vault_program_client
+            .do_veto_slash(&vault_root.vault_pubkey, &base, &slash_veto_admin)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.pending_slash_amount(), 0);
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - haircut_amount);
+
+        vault_program_client
+            .do_burn(&vault_root, &depositor, BURN_AMOUNT, BURN_AMOUNT)
+            .await
+            .unwrap();
+        assert_eq!(
+            get_depositor_balance(
+                &mut fixture,
+                &mut vault_program_client,
+                &vault_root,
+                &depositor
+            )
+            .await,
+            haircut_amount + BURN_AMOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_burn_withdrawal_ticket_with_pending_slash_haircut_ok() {
+        let SlashProposalSetup {
+            mut fixture,
+            mut vault_program_client,
+            vault_root,
+            depositor,
+            ncn_pubkey,
+            operator_pubkey,
+            slasher,
+            ..
+        } = setup_slash_proposal().await;
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let config = vault_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_slash_dispute_haircut(
+                &config_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                true,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_propose_slash(
+                &vault_root,
+                &ncn_pubkey,
+                &slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+                EVIDENCE_HASH,
+            )
+            .await
+            .unwrap();
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, BURN_AMOUNT)
+            .await
+            .unwrap();
+
+        for _ in 0..2 {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &[operator_pubkey])
+                .await
+                .unwrap();
+        }
+
+        // the haircut isn't returned if the slash is vetoed, so no one else can accept it for the
+        // staker
+        let haircut_amount = BURN_AMOUNT * (MINT_AMOUNT - MAX_SLASH_AMOUNT) / MINT_AMOUNT;
+        let result = vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, haircut_amount)
+            .await;
+        assert_ix_error(result, InstructionError::MissingRequiredSignature);
+
+        // the ticket is priced when it's burned, so the still-pending slash is haircut
+        vault_program_client
+            .do_burn_withdrawal_ticket_as_staker(&vault_root, &depositor, &base, haircut_amount)
+            .await
+            .unwrap();
+        assert_eq!(
+            get_depositor_balance(
+                &mut fixture,
+                &mut vault_program_client,
+                &vault_root,
+                &depositor
+            )
+            .await,
+            haircut_amount
+        );
+    }
+
+    #[tokio::test]
+    async fn test_veto_slash_wrong_admin_fails() {
+        let SlashProposalSetup {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_pubkey,
+            operator_pubkey,
+            slasher,
+            ..
+        } = setup_slash_proposal().await;
+
+        let base = vault_program_client
+            .do_propose_slash(
+                &vault_root,
+                &ncn_pubkey,
+                &slasher,
+                &operator_pubkey,
+                MAX_SLASH_AMOUNT,
+                EVIDENCE_HASH,
+            )
+            .await
+            .unwrap();
+
+        for admin in [&vault_config_admin, &slasher] {
+            let result = vault_program_client
+                .do_veto_slash(&vault_root.vault_pubkey, &base, admin)
+                .await;
+            assert_vault_error(result, VaultError::ConfigSlashVetoAdminInvalid);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_set_slash_veto_invalid_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .admin_set_slash_veto(
+                &vault_root.vault_admin,
+                &Pubkey::new_unique(),
+                SLASH_VETO_WINDOW_SLOTS,
+            )
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+
+        let result = vault_program_client
+            .admin_set_slash_veto(
+                &config_admin,
+                &Pubkey::new_unique(),
+                config.epoch_length() * Config::MAX_SLASH_VETO_WINDOW_EPOCHS + 1,
+            )
+            .await;
+        assert_vault_error(result, VaultError::ConfigSlashVetoWindowInvalid);
+    }
+}
@@ -1,9 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use jito_vault_sdk::{
-        error::VaultError,
-        inline_mpl_token_metadata::{self, pda::find_metadata_account},
-    };
+    use jito_vault_sdk::{error::VaultError, inline_mpl_token_metadata};
     use solana_program::pubkey::Pubkey;
     use solana_sdk::signature::Keypair;
 
@@ -69,14 +66,11 @@ mod tests {
 
         let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
 
-        let metadata_pubkey = find_metadata_account(&vault.vrt_mint).0;
-
         vault_program_client
             .update_token_metadata(
                 &vault_pubkey,
                 &vault_admin,
                 &vault.vrt_mint,
-                &metadata_pubkey,
                 updated_name.to_string(),
                 updated_symbol.to_string(),
                 updated_uri.to_string(),
@@ -104,21 +98,18 @@ mod tests {
 
         let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
 
-        let metadata_pubkey = find_metadata_account(&vault.vrt_mint).0;
-
         let bad_admin = Keypair::new();
         let response = vault_program_client
             .update_token_metadata(
                 &vault_pubkey,
                 &bad_admin,
                 &vault.vrt_mint,
-                &metadata_pubkey,
                 updated_name.to_string(),
                 updated_symbol.to_string(),
                 updated_uri.to_string(),
             )
             .await;
 
-        assert_vault_error(response, VaultError::VaultAdminInvalid);
+        assert_vault_error(response, VaultError::VaultMetadataAdminInvalid);
     }
 }
@@ -131,4 +131,87 @@ mod tests {
         assert_eq!(reward_fee_account.amount, MINT_AMOUNT / 10);
         assert_eq!(vault.vrt_supply(), MINT_AMOUNT / 10);
     }
+
+    #[tokio::test]
+    async fn test_update_vault_balance_mints_accrued_fees_once_per_epoch() {
+        const DEPOSIT_FEE_BPS: u16 = 100; // 1%
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(DEPOSIT_FEE_BPS, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 2 * MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_fees_accrued(), MINT_AMOUNT / 100);
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT);
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+
+        let fee_account = vault_program_client
+            .get_reward_fee_token_account(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(fee_account.amount, MINT_AMOUNT / 100);
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_fees_accrued(), 0);
+
+        // fees accrued after the fees were minted this epoch wait until the next epoch
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .update_vault_balance(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let fee_account = vault_program_client
+            .get_reward_fee_token_account(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(fee_account.amount, MINT_AMOUNT / 100);
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_fees_accrued(), MINT_AMOUNT / 100);
+        assert_eq!(vault.vrt_supply(), 2 * MINT_AMOUNT);
+    }
 }
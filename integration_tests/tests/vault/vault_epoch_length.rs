@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultStakerWithdrawalTicketRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+    const VAULT_EPOCH_LENGTH: u64 = 1_000;
+
+    #[tokio::test]
+    async fn test_vault_epoch_length_shortens_withdrawals() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator_root_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+
+        vault_program_client
+            .admin_set_vault_epoch_length_bounds(&vault_config_admin, VAULT_EPOCH_LENGTH, 10_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_vault_epoch_length(&vault_root, VAULT_EPOCH_LENGTH)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.epoch_length_slots(), VAULT_EPOCH_LENGTH);
+        assert_eq!(
+            vault.epoch_length(config.epoch_length()),
+            VAULT_EPOCH_LENGTH
+        );
+
+        // the vault is updated every one of its own epochs
+        fixture
+            .warp_slot_incremental(VAULT_EPOCH_LENGTH)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdraw(&vault_root, &depositor, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(VAULT_EPOCH_LENGTH)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, MINT_AMOUNT)
+            .await;
+        assert_vault_error(
+            result,
+            VaultError::VaultStakerWithdrawalTicketNotWithdrawable,
+        );
+
+        // matured after two of the vault's epochs, well within a single config epoch
+        fixture
+            .warp_slot_incremental(VAULT_EPOCH_LENGTH)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_supply(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_set_vault_epoch_length_out_of_bounds_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        // vaults can't override the epoch length until the config admin sets the bounds
+        let result = vault_program_client
+            .set_vault_epoch_length(&vault_root, VAULT_EPOCH_LENGTH)
+            .await;
+        assert_vault_error(result, VaultError::VaultEpochLengthInvalid);
+
+        let result = vault_program_client
+            .admin_set_vault_epoch_length_bounds(&config_admin, 2_000, VAULT_EPOCH_LENGTH)
+            .await;
+        assert_vault_error(result, VaultError::ConfigVaultEpochLengthBoundsInvalid);
+        let result = vault_program_client
+            .admin_set_vault_epoch_length_bounds(&vault_root.vault_admin, VAULT_EPOCH_LENGTH, 2_000)
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+
+        vault_program_client
+            .admin_set_vault_epoch_length_bounds(&config_admin, VAULT_EPOCH_LENGTH, 2_000)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .set_vault_epoch_length(&vault_root, VAULT_EPOCH_LENGTH - 1)
+            .await;
+        assert_vault_error(result, VaultError::VaultEpochLengthInvalid);
+        let result = vault_program_client
+            .set_vault_epoch_length(&vault_root, 2_001)
+            .await;
+        assert_vault_error(result, VaultError::VaultEpochLengthInvalid);
+        vault_program_client
+            .set_vault_epoch_length(&vault_root, 2_000)
+            .await
+            .unwrap();
+
+        // zero goes back to the config's epoch length
+        vault_program_client
+            .set_vault_epoch_length(&vault_root, 0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.epoch_length_slots(), 0);
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+
+    use crate::fixtures::fixture::{ConfiguredVault, TestBuilder};
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_vault_epoch_stats_records_flows() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(100, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_initialize_vault_epoch_stats(&vault_root)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(vault.epoch_stats_enabled());
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_burn(&vault_root, &depositor, 10_000, 0)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+
+        let vault_epoch_stats = vault_program_client
+            .get_vault_epoch_stats(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let stats = vault_epoch_stats.epoch_stats(epoch).unwrap();
+        assert_eq!(stats.deposited(), MINT_AMOUNT);
+        // 1% deposit fee on the mint, no withdrawal fee
+        assert_eq!(stats.vrt_fees(), 1_000);
+        assert_eq!(stats.withdrawn(), 10_000);
+        assert_eq!(stats.slashed(), 0);
+        assert_eq!(stats.net_flow(), 90_000);
+    }
+}
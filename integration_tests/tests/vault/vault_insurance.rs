@@ -0,0 +1,296 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, vault_insurance::VaultInsurance,
+        vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+        vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    };
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_set_insurance_params_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_set_insurance_params(&vault_root, 2_500)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(vault.insurance_enabled());
+        let vault_insurance = vault_program_client
+            .get_vault_insurance(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault_insurance.vault, vault_root.vault_pubkey);
+        assert_eq!(vault_insurance.fee_bps(), 2_500);
+
+        // updating the params keeps the existing fund
+        vault_program_client
+            .do_set_insurance_params(&vault_root, 1_000)
+            .await
+            .unwrap();
+        let vault_insurance = vault_program_client
+            .get_vault_insurance(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault_insurance.fee_bps(), 1_000);
+
+        let result = vault_program_client
+            .do_set_insurance_params(&vault_root, 10_001)
+            .await;
+        assert_vault_error(result, VaultError::VaultFeeCapExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_insurance_receives_fee_share() {
+        const REWARD_FEE_BPS: u16 = 1_000; // 10%
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, REWARD_FEE_BPS, 1, &[])
+            .await
+            .unwrap();
+        let operator_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+
+        // half of the vault's fees go to the insurance fund
+        vault_program_client
+            .do_set_insurance_params(&vault_root, 5_000)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let vault_insurance_pubkey = VaultInsurance::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        fixture
+            .create_ata(&vault.vrt_mint, &vault_insurance_pubkey)
+            .await
+            .unwrap();
+
+        let rewarder = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &rewarder.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .create_and_fund_reward_vault(&vault_root.vault_pubkey, &rewarder, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_pubkeys)
+            .await
+            .unwrap();
+
+        let fee_account = vault_program_client
+            .get_reward_fee_token_account(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let insurance_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &vault_insurance_pubkey,
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(fee_account.amount, MINT_AMOUNT / 20);
+        assert_eq!(insurance_account.amount, MINT_AMOUNT / 20);
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT / 10);
+        let vault_insurance = vault_program_client
+            .get_vault_insurance(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault_insurance.vrt_funded(), MINT_AMOUNT / 20);
+    }
+
+    #[tokio::test]
+    async fn test_insurance_absorbs_slash() {
+        const MAX_SLASH_AMOUNT: u64 = 100;
+        const DELEGATION_AMOUNT: u64 = 10_000;
+        const INSURANCE_AMOUNT: u64 = 1_000;
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            slashers_amounts,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[MAX_SLASH_AMOUNT])
+            .await
+            .unwrap();
+
+        // the vault admin stakes and funds the insurance with part of its VRT
+        let vault_admin_pubkey = vault_root.vault_admin.pubkey();
+        vault_program_client
+            .configure_depositor(&vault_root, &vault_admin_pubkey, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(
+                &vault_root,
+                &vault_root.vault_admin,
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_set_insurance_params(&vault_root, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_fund_insurance(&vault_root, INSURANCE_AMOUNT)
+            .await
+            .unwrap();
+
+        let operator_root = &operator_roots[0];
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                DELEGATION_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator_root.operator_pubkey])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let slasher = &slashers_amounts[0].0;
+        fixture
+            .create_ata(&vault.supported_mint, &slasher.pubkey())
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        vault_program_client
+            .initialize_vault_ncn_slasher_operator_ticket(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher.pubkey(),
+                &operator_root.operator_pubkey,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                )
+                .0,
+                &VaultNcnSlasherOperatorTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &ncn_root.ncn_pubkey,
+                    &slasher.pubkey(),
+                    &operator_root.operator_pubkey,
+                    epoch,
+                )
+                .0,
+                &vault_config_admin,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_slash(
+                &vault_root,
+                &ncn_root.ncn_pubkey,
+                slasher,
+                &operator_root.operator_pubkey,
+                MAX_SLASH_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        // the insurance fund burned the slash's share of the VRT supply, so the exchange rate
+        // stays at 1:1
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), MINT_AMOUNT - MAX_SLASH_AMOUNT);
+        assert_eq!(vault.vrt_supply(), MINT_AMOUNT - MAX_SLASH_AMOUNT);
+
+        let vault_insurance_pubkey = VaultInsurance::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let insurance_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &vault_insurance_pubkey,
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            insurance_account.amount,
+            INSURANCE_AMOUNT - MAX_SLASH_AMOUNT
+        );
+        let vault_insurance = vault_program_client
+            .get_vault_insurance(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault_insurance.vrt_funded(), INSURANCE_AMOUNT);
+        assert_eq!(vault_insurance.vrt_absorbed(), MAX_SLASH_AMOUNT);
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, vault_update_state_tracker::VaultUpdateStateTracker};
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegation_ok() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, 100_000, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, 50_000)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_epoch = slot / config.epoch_length();
+        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            ncn_epoch,
+        )
+        .0;
+        vault_program_client
+            .initialize_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+            )
+            .await
+            .unwrap();
+
+        // the delegation hasn't been cranked for the epoch yet
+        let result = vault_program_client
+            .snapshot_vault_operator_delegation(&vault_root.vault_pubkey, &operator, ncn_epoch)
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorDelegationNotCranked);
+
+        vault_program_client
+            .do_crank_vault_update_state_tracker(&vault_root.vault_pubkey, &operator)
+            .await
+            .unwrap();
+        vault_program_client
+            .snapshot_vault_operator_delegation(&vault_root.vault_pubkey, &operator, ncn_epoch)
+            .await
+            .unwrap();
+
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operator)
+            .await
+            .unwrap();
+        let snapshot = vault_program_client
+            .get_vault_operator_delegation_snapshot(&vault_root.vault_pubkey, &operator, ncn_epoch)
+            .await
+            .unwrap();
+        assert_eq!(snapshot.vault, vault_root.vault_pubkey);
+        assert_eq!(snapshot.operator, operator);
+        assert_eq!(snapshot.ncn_epoch(), ncn_epoch);
+        assert_eq!(snapshot.index(), vault_operator_delegation.index());
+        assert_eq!(
+            snapshot.delegation_state,
+            vault_operator_delegation.delegation_state
+        );
+        assert_eq!(snapshot.delegation_state.staked_amount(), 50_000);
+
+        // only one snapshot per operator and epoch
+        fixture.warp_slot_incremental(1).await.unwrap();
+        let result = vault_program_client
+            .snapshot_vault_operator_delegation(&vault_root.vault_pubkey, &operator, ncn_epoch)
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, vault_ncn_ticket::VaultNcnTicket,
+        vault_operator_delegation::VaultOperatorDelegation,
+    };
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+    const DELEGATION_AMOUNT: u64 = 50_000;
+    const WITHDRAWAL_FEE_BPS: u16 = 100;
+
+    #[tokio::test]
+    async fn test_vault_shutdown_lifecycle_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, WITHDRAWAL_FEE_BPS, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT * 2)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, DELEGATION_AMOUNT)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .initiate_vault_shutdown(&vault_root.vault_pubkey, &depositor)
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+        vault_program_client
+            .initiate_vault_shutdown(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(vault.is_shut_down());
+        assert_eq!(vault.withdrawal_fee_bps(), 0);
+
+        // no new deposits or delegation
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsShutDown);
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator, 1_000)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsShutDown);
+        fixture.warp_slot_incremental(1).await.unwrap();
+        let result = vault_program_client
+            .initiate_vault_shutdown(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsShutDown);
+
+        // the vault updates cool down all the delegations
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        for _ in 0..config.delegation_cooldown_epochs() + 2 {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+                .await
+                .unwrap();
+        }
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state.total_security().unwrap(), 0);
+
+        // the remaining holders redeem their pro-rata share without the withdrawal fee
+        vault_program_client
+            .do_burn(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault_ncn_ticket = VaultNcnTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &ncn_root.ncn_pubkey,
+        )
+        .0;
+        let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &operator,
+        )
+        .0;
+        vault_program_client
+            .close_vault(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &[vault_ncn_ticket, vault_operator_delegation],
+            )
+            .await
+            .unwrap();
+
+        assert!(!vault_program_client
+            .vault_exists(&vault_root.vault_pubkey)
+            .await
+            .unwrap());
+        for account in [vault_ncn_ticket, vault_operator_delegation] {
+            assert!(!vault_program_client.vault_exists(&account).await.unwrap());
+        }
+    }
+}
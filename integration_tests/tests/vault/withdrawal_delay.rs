@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, MAX_WITHDRAWAL_DELAY_EPOCHS};
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultStakerWithdrawalTicketRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+    const WITHDRAWAL_DELAY_EPOCHS: u64 = 3;
+
+    #[tokio::test]
+    async fn test_withdrawal_delay_applies_to_idle_assets() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator_root_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_withdrawal_delay(&vault_root, WITHDRAWAL_DELAY_EPOCHS)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.withdrawal_delay_epochs(), WITHDRAWAL_DELAY_EPOCHS);
+
+        // all the assets are idle, but they can't be burned right away
+        let result = vault_program_client
+            .do_burn(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultWithdrawalDelayActive);
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_burn_or_enqueue_withdrawal(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        let vault_staker_withdrawal_ticket = vault_program_client
+            .get_vault_staker_withdrawal_ticket(
+                &vault_root.vault_pubkey,
+                &depositor.pubkey(),
+                &base,
+            )
+            .await
+            .unwrap();
+        assert_eq!(vault_staker_withdrawal_ticket.vrt_amount(), MINT_AMOUNT);
+        assert_eq!(
+            vault_staker_withdrawal_ticket.withdrawal_delay_epochs(),
+            WITHDRAWAL_DELAY_EPOCHS
+        );
+
+        // lifting the delay doesn't shorten withdrawals already enqueued
+        vault_program_client
+            .set_withdrawal_delay(&vault_root, 0)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        for _ in 0..WITHDRAWAL_DELAY_EPOCHS {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+                .await
+                .unwrap();
+            let result = vault_program_client
+                .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, MINT_AMOUNT)
+                .await;
+            assert_vault_error(
+                result,
+                VaultError::VaultStakerWithdrawalTicketNotWithdrawable,
+            );
+        }
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_burn_withdrawal_ticket(&vault_root, &depositor, &base, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.tokens_deposited(), 0);
+        assert_eq!(vault.vrt_supply(), 0);
+        let depositor_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_token_account.amount, MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_set_withdrawal_delay_too_long_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .set_withdrawal_delay(&vault_root, MAX_WITHDRAWAL_DELAY_EPOCHS + 1)
+            .await;
+        assert_vault_error(result, VaultError::VaultWithdrawalDelayInvalid);
+    }
+}
@@ -1,7 +1,7 @@
 //! Global configuration account for the restaking program
 
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, AccountVersion, Discriminator};
 use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
 use solana_program::{
@@ -14,6 +14,10 @@ impl Discriminator for Config {
     const DISCRIMINATOR: u8 = 1;
 }
 
+impl AccountVersion for Config {
+    const VERSION: u8 = 0;
+}
+
 /// The global configuration account for the restaking program. Manages
 /// program-wide settings and state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
@@ -37,8 +41,11 @@ pub struct Config {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The admin proposed by the current admin, who becomes the admin once they accept
+    pub pending_admin: Pubkey,
+
     /// Reserved space
-    reserved_1: [u8; 263],
+    reserved_1: [u8; 231],
 }
 
 impl Config {
@@ -50,7 +57,8 @@ impl Config {
             ncn_count: PodU64::from(0),
             operator_count: PodU64::from(0),
             bump,
-            reserved_1: [0; 263],
+            pending_admin: Pubkey::default(),
+            reserved_1: [0; 231],
         }
     }
 
@@ -58,6 +66,17 @@ impl Config {
         self.epoch_length.into()
     }
 
+    /// Sets the length of an epoch in slots. Ticket states are tracked by slot, so a new length
+    /// only changes how long the warmups and cooldowns still in progress take.
+    pub fn set_epoch_length(&mut self, epoch_length: u64) -> Result<(), RestakingError> {
+        if epoch_length == 0 {
+            msg!("Epoch length must be greater than zero");
+            return Err(RestakingError::ConfigEpochLengthInvalid);
+        }
+        self.epoch_length = PodU64::from(epoch_length);
+        Ok(())
+    }
+
     pub fn ncn_count(&self) -> u64 {
         self.ncn_count.into()
     }
@@ -124,19 +143,23 @@ impl Config {
         }
         if account.data_is_empty() {
             msg!("Config account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDataEmpty.into());
         }
         if expect_writable && !account.is_writable {
             msg!("Config account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotWritable.into());
         }
         if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("Config account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDiscriminatorInvalid.into());
+        }
+        if Self::version(&account.data.borrow())?.ne(&Self::VERSION) {
+            msg!("Config account must be migrated to the current version");
+            return Err(RestakingError::RestakingAccountVersionInvalid.into());
         }
         if account.key.ne(&Self::find_program_address(program_id).0) {
             msg!("Config account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -155,7 +178,38 @@ mod tests {
             std::mem::size_of::<PodU64>() + // operator_count
             std::mem::size_of::<PodU64>() + // epoch_length
             std::mem::size_of::<u8>() + // bump
-            263; // reserved
+            std::mem::size_of::<Pubkey>() + // pending_admin
+            231; // reserved
         assert_eq!(config_size, sum_of_fields);
     }
+
+    #[test]
+    fn test_config_deserialize_rejects_trailing_data() {
+        let config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+        let config_size = std::mem::size_of::<Config>();
+        let mut data = vec![0; 8 + config_size + 256];
+        data[0] = Config::DISCRIMINATOR;
+        data[8..8 + config_size].copy_from_slice(bytemuck::bytes_of(&config));
+
+        assert!(Config::try_from_slice_unchecked(&data).is_err());
+        assert_eq!(
+            *Config::try_from_slice_unchecked(&data[..8 + config_size]).unwrap(),
+            config
+        );
+        assert!(Config::try_from_slice_unchecked(&data[..8 + config_size - 1]).is_err());
+    }
+
+    #[test]
+    fn test_set_epoch_length() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+
+        assert_eq!(
+            config.set_epoch_length(0),
+            Err(RestakingError::ConfigEpochLengthInvalid)
+        );
+        assert_eq!(config.epoch_length(), DEFAULT_SLOTS_PER_EPOCH);
+
+        config.set_epoch_length(100).unwrap();
+        assert_eq!(config.epoch_length(), 100);
+    }
 }
@@ -6,7 +6,7 @@
 use std::fmt::Debug;
 
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, AccountVersion, Discriminator};
 use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
@@ -51,14 +51,22 @@ pub struct Ncn {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The number of epochs the NCN's operator and vault relationships last before they lapse
+    /// unless renewed, zero if they never expire
+    ticket_expiry_epochs: PodU64,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 255],
 }
 
 impl Discriminator for Ncn {
     const DISCRIMINATOR: u8 = 2;
 }
 
+impl AccountVersion for Ncn {
+    const VERSION: u8 = 0;
+}
+
 impl Ncn {
     #[allow(clippy::too_many_arguments)]
     pub fn new(base: Pubkey, admin: Pubkey, ncn_index: u64, bump: u8) -> Self {
@@ -75,10 +83,14 @@ impl Ncn {
             vault_count: PodU64::from(0),
             slasher_count: PodU64::from(0),
             bump,
-            reserved: [0; 263],
+            ticket_expiry_epochs: PodU64::from(0),
+            reserved: [0; 255],
         }
     }
 
+    /// The minimum non-zero ticket expiry, so a relationship doesn't lapse before it's warmed up
+    pub const MIN_TICKET_EXPIRY_EPOCHS: u64 = 3;
+
     pub fn index(&self) -> u64 {
         self.index.into()
     }
@@ -95,6 +107,42 @@ impl Ncn {
         self.slasher_count.into()
     }
 
+    pub fn ticket_expiry_epochs(&self) -> u64 {
+        self.ticket_expiry_epochs.into()
+    }
+
+    /// Sets the number of epochs the NCN's operator and vault relationships last, zero to disable
+    /// expiry. The new expiry applies to relationships created or renewed from then on.
+    pub fn set_ticket_expiry_epochs(
+        &mut self,
+        ticket_expiry_epochs: u64,
+    ) -> Result<(), RestakingError> {
+        if ticket_expiry_epochs != 0 && ticket_expiry_epochs < Self::MIN_TICKET_EXPIRY_EPOCHS {
+            msg!(
+                "Ticket expiry must be zero or at least {} epochs",
+                Self::MIN_TICKET_EXPIRY_EPOCHS
+            );
+            return Err(RestakingError::NcnTicketExpiryInvalid);
+        }
+        self.ticket_expiry_epochs = PodU64::from(ticket_expiry_epochs);
+        Ok(())
+    }
+
+    /// The slot a relationship created or renewed at `slot` expires, zero if it never expires
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn ticket_expiry_slot(&self, slot: u64, epoch_length: u64) -> Result<u64, RestakingError> {
+        if self.ticket_expiry_epochs() == 0 {
+            return Ok(0);
+        }
+        epoch_length
+            .checked_mul(self.ticket_expiry_epochs())
+            .and_then(|x| x.checked_add(slot))
+            .ok_or(RestakingError::NcnOverflow)
+    }
+
     pub fn increment_operator_count(&mut self) -> Result<(), RestakingError> {
         let mut operator_count: u64 = self.operator_count.into();
         operator_count = operator_count
@@ -194,15 +242,19 @@ impl Ncn {
         }
         if account.data_is_empty() {
             msg!("NCN account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDataEmpty.into());
         }
         if expect_writable && !account.is_writable {
             msg!("NCN account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotWritable.into());
         }
         if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("NCN account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDiscriminatorInvalid.into());
+        }
+        if Self::version(&account.data.borrow())?.ne(&Self::VERSION) {
+            msg!("NCN account must be migrated to the current version");
+            return Err(RestakingError::RestakingAccountVersionInvalid.into());
         }
         let base = Self::try_from_slice_unchecked(&account.data.borrow())?.base;
         if account
@@ -210,7 +262,7 @@ impl Ncn {
             .ne(&Self::find_program_address(program_id, &base).0)
         {
             msg!("NCN account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -219,6 +271,7 @@ impl Ncn {
 #[cfg(test)]
 mod tests {
     use jito_bytemuck::types::PodU64;
+    use jito_restaking_sdk::error::RestakingError;
     use solana_program::pubkey::Pubkey;
 
     use super::Ncn;
@@ -238,7 +291,8 @@ mod tests {
             std::mem::size_of::<PodU64>() + // vault_count
             std::mem::size_of::<PodU64>() + // slasher_count
             std::mem::size_of::<u8>() + // bump
-            263; // reserved
+            std::mem::size_of::<PodU64>() + // ticket_expiry_epochs
+            255; // reserved
         assert_eq!(ncn_size, sum_of_fields);
     }
 
@@ -262,4 +316,26 @@ mod tests {
         assert_eq!(ncn.withdraw_admin, new_admin);
         assert_eq!(ncn.withdraw_fee_wallet, new_admin);
     }
+
+    #[test]
+    fn test_ticket_expiry() {
+        let mut ncn = Ncn::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+        assert_eq!(ncn.ticket_expiry_epochs(), 0);
+        assert_eq!(ncn.ticket_expiry_slot(100, 100), Ok(0));
+
+        assert_eq!(
+            ncn.set_ticket_expiry_epochs(Ncn::MIN_TICKET_EXPIRY_EPOCHS - 1),
+            Err(RestakingError::NcnTicketExpiryInvalid)
+        );
+
+        ncn.set_ticket_expiry_epochs(Ncn::MIN_TICKET_EXPIRY_EPOCHS)
+            .unwrap();
+        assert_eq!(
+            ncn.ticket_expiry_slot(100, 100),
+            Ok(100 + 100 * Ncn::MIN_TICKET_EXPIRY_EPOCHS)
+        );
+
+        ncn.set_ticket_expiry_epochs(0).unwrap();
+        assert_eq!(ncn.ticket_expiry_slot(100, 100), Ok(0));
+    }
 }
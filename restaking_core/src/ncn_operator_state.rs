@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use jito_jsm_core::slot_toggle::SlotToggle;
+use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
@@ -30,11 +31,38 @@ pub struct NcnOperatorState {
 
     pub bump: u8,
 
+    /// The last slot of the NCN's task window on the operator, zero if the NCN has no window open
+    task_window_end_slot: PodU64,
+
+    /// The slot the NCN's freeze of the operator expires, zero if the NCN hasn't frozen the operator
+    frozen_until_slot: PodU64,
+
+    /// The slot the relationship lapses unless the NCN renews it, zero if it never expires
+    expiry_slot: PodU64,
+
+    /// The slot the NCN's current task window on the operator was first opened, zero if the NCN
+    /// has no window open
+    task_window_start_slot: PodU64,
+
+    /// The number of times the NCN has moved the end slot of its current task window
+    task_window_extensions: u8,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 230],
 }
 
 impl NcnOperatorState {
+    /// The maximum length of a task window in epochs, so an NCN can't lock an operator's stake
+    /// indefinitely
+    pub const MAX_TASK_WINDOW_EPOCHS: u64 = 2;
+
+    /// The maximum number of times an NCN can move the end slot of an open task window
+    pub const MAX_TASK_WINDOW_EXTENSIONS: u8 = 3;
+
+    /// The number of epochs a freeze lasts before it expires, giving the NCN time to investigate a
+    /// compromised operator without freezing it indefinitely
+    pub const FREEZE_EPOCHS: u64 = 3;
+
     pub fn new(ncn: Pubkey, operator: Pubkey, index: u64, bump: u8, slot: u64) -> Self {
         Self {
             ncn,
@@ -43,7 +71,12 @@ impl NcnOperatorState {
             ncn_opt_in_state: SlotToggle::new(slot),
             operator_opt_in_state: SlotToggle::new(slot),
             bump,
-            reserved: [0; 263],
+            task_window_end_slot: PodU64::from(0),
+            frozen_until_slot: PodU64::from(0),
+            expiry_slot: PodU64::from(0),
+            task_window_start_slot: PodU64::from(0),
+            task_window_extensions: 0,
+            reserved: [0; 230],
         }
     }
 
@@ -51,6 +84,140 @@ impl NcnOperatorState {
         self.index.into()
     }
 
+    pub fn task_window_end_slot(&self) -> u64 {
+        self.task_window_end_slot.into()
+    }
+
+    pub fn task_window_start_slot(&self) -> u64 {
+        self.task_window_start_slot.into()
+    }
+
+    pub fn task_window_extensions(&self) -> u8 {
+        self.task_window_extensions
+    }
+
+    /// Whether the NCN has a task window on the operator that hasn't been closed, even if it has
+    /// already ended
+    pub fn has_task_window(&self) -> bool {
+        self.task_window_end_slot() != 0
+    }
+
+    /// Opens or extends the NCN's task window on the operator. The window can't end more than
+    /// [`Self::MAX_TASK_WINDOW_EPOCHS`] after the slot it was first opened, and its end slot can
+    /// only be moved [`Self::MAX_TASK_WINDOW_EXTENSIONS`] times.
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `end_slot` - The slot the task window ends
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn open_task_window(
+        &mut self,
+        slot: u64,
+        end_slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), RestakingError> {
+        let is_new = !self.has_task_window();
+        if !is_new && self.task_window_extensions >= Self::MAX_TASK_WINDOW_EXTENSIONS {
+            msg!(
+                "Task window can't be extended more than {} times",
+                Self::MAX_TASK_WINDOW_EXTENSIONS
+            );
+            return Err(RestakingError::NcnTaskWindowExtensionsExceeded);
+        }
+
+        let start_slot = if is_new {
+            slot
+        } else {
+            self.task_window_start_slot()
+        };
+        let max_end_slot = epoch_length
+            .checked_mul(Self::MAX_TASK_WINDOW_EPOCHS)
+            .and_then(|x| x.checked_add(start_slot))
+            .ok_or(RestakingError::NcnOverflow)?;
+        if end_slot <= slot || end_slot > max_end_slot {
+            msg!(
+                "Task window must end after the current slot and within {} epochs of opening",
+                Self::MAX_TASK_WINDOW_EPOCHS
+            );
+            return Err(RestakingError::NcnTaskWindowInvalid);
+        }
+
+        if is_new {
+            self.task_window_start_slot = PodU64::from(slot);
+            self.task_window_extensions = 0;
+        } else {
+            self.task_window_extensions = self
+                .task_window_extensions
+                .checked_add(1)
+                .ok_or(RestakingError::NcnOverflow)?;
+        }
+        self.task_window_end_slot = PodU64::from(end_slot);
+        Ok(())
+    }
+
+    /// Closes the NCN's task window on the operator
+    pub fn close_task_window(&mut self) -> Result<(), RestakingError> {
+        if !self.has_task_window() {
+            msg!("NCN has no task window open on the operator");
+            return Err(RestakingError::NcnTaskWindowNotOpen);
+        }
+        self.task_window_end_slot = PodU64::from(0);
+        self.task_window_start_slot = PodU64::from(0);
+        self.task_window_extensions = 0;
+        Ok(())
+    }
+
+    pub fn frozen_until_slot(&self) -> u64 {
+        self.frozen_until_slot.into()
+    }
+
+    /// Whether the NCN has frozen the operator and not unfrozen it, even if the freeze has expired
+    pub fn has_freeze(&self) -> bool {
+        self.frozen_until_slot() != 0
+    }
+
+    /// Freezes the operator for [`Self::FREEZE_EPOCHS`], or restarts an existing freeze
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    ///
+    /// # Returns
+    /// * `u64` - The slot the freeze expires
+    pub fn freeze(&mut self, slot: u64, epoch_length: u64) -> Result<u64, RestakingError> {
+        let frozen_until_slot = epoch_length
+            .checked_mul(Self::FREEZE_EPOCHS)
+            .and_then(|x| x.checked_add(slot))
+            .ok_or(RestakingError::NcnOverflow)?;
+        self.frozen_until_slot = PodU64::from(frozen_until_slot);
+        Ok(frozen_until_slot)
+    }
+
+    /// Lifts the NCN's freeze of the operator
+    pub fn unfreeze(&mut self) -> Result<(), RestakingError> {
+        if !self.has_freeze() {
+            msg!("NCN has not frozen the operator");
+            return Err(RestakingError::NcnOperatorNotFrozen);
+        }
+        self.frozen_until_slot = PodU64::from(0);
+        Ok(())
+    }
+
+    pub fn expiry_slot(&self) -> u64 {
+        self.expiry_slot.into()
+    }
+
+    /// Whether the relationship lapsed because the NCN didn't renew it in time. An expired
+    /// relationship counts as inactive regardless of its opt-in state.
+    pub fn is_expired(&self, slot: u64) -> bool {
+        self.expiry_slot() != 0 && slot >= self.expiry_slot()
+    }
+
+    /// Renews the relationship until `expiry_slot`, see [`crate::ncn::Ncn::ticket_expiry_slot`]
+    pub fn renew(&mut self, expiry_slot: u64) {
+        self.expiry_slot = PodU64::from(expiry_slot);
+    }
+
     pub fn seeds(ncn: &Pubkey, operator: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([
             b"ncn_operator_state".to_vec(),
@@ -94,20 +261,20 @@ impl NcnOperatorState {
         }
         if ncn_operator_state.data_is_empty() {
             msg!("NCNOperatorState account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDataEmpty.into());
         }
         if expect_writable && !ncn_operator_state.is_writable {
             msg!("NCNOperatorState account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotWritable.into());
         }
         if ncn_operator_state.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("NCNOperatorState account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDiscriminatorInvalid.into());
         }
         let expected_pubkey = Self::find_program_address(program_id, ncn.key, operator.key).0;
         if ncn_operator_state.key.ne(&expected_pubkey) {
             msg!("NCNOperatorState account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -128,7 +295,12 @@ mod tests {
             size_of::<SlotToggle>() + // ncn_opt_in_state
             size_of::<SlotToggle>() + // operator_opt_in_state
             size_of::<u8>() + // bump
-            263; // reserved
+            size_of::<PodU64>() + // task_window_end_slot
+            size_of::<PodU64>() + // frozen_until_slot
+            size_of::<PodU64>() + // expiry_slot
+            size_of::<PodU64>() + // task_window_start_slot
+            size_of::<u8>() + // task_window_extensions
+            230; // reserved
         assert_eq!(ncn_operator_state_size, sum_of_fields);
     }
 
@@ -148,4 +320,119 @@ mod tests {
             SlotToggleState::Inactive
         );
     }
+
+    #[test]
+    fn test_task_window() {
+        let mut ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+        assert!(!ncn_operator_state.has_task_window());
+        assert!(matches!(
+            ncn_operator_state.close_task_window(),
+            Err(RestakingError::NcnTaskWindowNotOpen)
+        ));
+
+        assert!(matches!(
+            ncn_operator_state.open_task_window(100, 100, 100),
+            Err(RestakingError::NcnTaskWindowInvalid)
+        ));
+        assert!(matches!(
+            ncn_operator_state.open_task_window(
+                100,
+                100 + 100 * NcnOperatorState::MAX_TASK_WINDOW_EPOCHS + 1,
+                100
+            ),
+            Err(RestakingError::NcnTaskWindowInvalid)
+        ));
+
+        ncn_operator_state
+            .open_task_window(
+                100,
+                100 + 100 * NcnOperatorState::MAX_TASK_WINDOW_EPOCHS,
+                100,
+            )
+            .unwrap();
+        assert!(ncn_operator_state.has_task_window());
+
+        ncn_operator_state.close_task_window().unwrap();
+        assert!(!ncn_operator_state.has_task_window());
+    }
+
+    #[test]
+    fn test_task_window_extensions() {
+        let mut ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+        let max_end_slot = 100 + 100 * NcnOperatorState::MAX_TASK_WINDOW_EPOCHS;
+        ncn_operator_state.open_task_window(100, 150, 100).unwrap();
+        assert_eq!(ncn_operator_state.task_window_start_slot(), 100);
+
+        // Extending later doesn't move the cap, which is measured from when the window opened
+        assert!(matches!(
+            ncn_operator_state.open_task_window(250, max_end_slot + 1, 100),
+            Err(RestakingError::NcnTaskWindowInvalid)
+        ));
+
+        for extension in 1..=NcnOperatorState::MAX_TASK_WINDOW_EXTENSIONS {
+            ncn_operator_state
+                .open_task_window(100 + u64::from(extension), max_end_slot, 100)
+                .unwrap();
+            assert_eq!(ncn_operator_state.task_window_extensions(), extension);
+        }
+        assert!(matches!(
+            ncn_operator_state.open_task_window(150, max_end_slot, 100),
+            Err(RestakingError::NcnTaskWindowExtensionsExceeded)
+        ));
+
+        // Closing the window starts the next one afresh
+        ncn_operator_state.close_task_window().unwrap();
+        assert_eq!(ncn_operator_state.task_window_extensions(), 0);
+        ncn_operator_state
+            .open_task_window(
+                250,
+                250 + 100 * NcnOperatorState::MAX_TASK_WINDOW_EPOCHS,
+                100,
+            )
+            .unwrap();
+        assert_eq!(ncn_operator_state.task_window_start_slot(), 250);
+    }
+
+    #[test]
+    fn test_freeze() {
+        let mut ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+        assert!(!ncn_operator_state.has_freeze());
+        assert!(matches!(
+            ncn_operator_state.unfreeze(),
+            Err(RestakingError::NcnOperatorNotFrozen)
+        ));
+
+        let frozen_until_slot = ncn_operator_state.freeze(100, 100).unwrap();
+        assert_eq!(
+            frozen_until_slot,
+            100 + 100 * NcnOperatorState::FREEZE_EPOCHS
+        );
+        assert!(ncn_operator_state.has_freeze());
+
+        // freezing again restarts the freeze
+        assert_eq!(
+            ncn_operator_state.freeze(200, 100).unwrap(),
+            200 + 100 * NcnOperatorState::FREEZE_EPOCHS
+        );
+
+        ncn_operator_state.unfreeze().unwrap();
+        assert!(!ncn_operator_state.has_freeze());
+    }
+
+    #[test]
+    fn test_expiry() {
+        let mut ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+        assert!(!ncn_operator_state.is_expired(u64::MAX));
+
+        ncn_operator_state.renew(500);
+        assert!(!ncn_operator_state.is_expired(499));
+        assert!(ncn_operator_state.is_expired(500));
+
+        ncn_operator_state.renew(0);
+        assert!(!ncn_operator_state.is_expired(500));
+    }
 }
@@ -4,6 +4,7 @@
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use jito_jsm_core::slot_toggle::SlotToggle;
+use jito_restaking_sdk::{error::RestakingError, instruction::SlashDestination};
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
@@ -39,8 +40,20 @@ pub struct NcnVaultSlasherTicket {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// Where the slashed funds are sent, see [`SlashDestination`]
+    slash_destination: u8,
+
+    /// The NCN's insurance wallet, when slashed funds are sent to insurance
+    pub insurance_wallet: Pubkey,
+
+    /// The max slashable funds per epoch per operator once the pending change takes effect
+    pending_max_slashable_per_epoch: PodU64,
+
+    /// The epoch the pending change takes effect, zero if there's none
+    pending_max_slashable_epoch: PodU64,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 214],
 }
 
 impl NcnVaultSlasherTicket {
@@ -72,7 +85,11 @@ impl NcnVaultSlasherTicket {
             index: PodU64::from(index),
             state: SlotToggle::new(slot),
             bump,
-            reserved: [0; 263],
+            slash_destination: SlashDestination::Slasher as u8,
+            insurance_wallet: Pubkey::default(),
+            pending_max_slashable_per_epoch: PodU64::from(0),
+            pending_max_slashable_epoch: PodU64::from(0),
+            reserved: [0; 214],
         }
     }
 
@@ -80,10 +97,74 @@ impl NcnVaultSlasherTicket {
         self.index.into()
     }
 
+    /// The max slashable funds per epoch per operator, ignoring any pending change. Use
+    /// [`Self::max_slashable_per_epoch_at`] to get the value in effect in an epoch.
     pub fn max_slashable_per_epoch(&self) -> u64 {
         self.max_slashable_per_epoch.into()
     }
 
+    pub fn pending_max_slashable_epoch(&self) -> u64 {
+        self.pending_max_slashable_epoch.into()
+    }
+
+    /// The max slashable funds per epoch per operator in effect in `epoch`
+    pub fn max_slashable_per_epoch_at(&self, epoch: u64) -> u64 {
+        let pending_epoch = self.pending_max_slashable_epoch();
+        if pending_epoch != 0 && epoch >= pending_epoch {
+            self.pending_max_slashable_per_epoch.into()
+        } else {
+            self.max_slashable_per_epoch()
+        }
+    }
+
+    /// Changes the max slashable funds per epoch per operator from the next epoch, so the amount
+    /// the vault sees a slasher able to slash never changes within an epoch. A change already
+    /// pending in the next epoch is replaced.
+    ///
+    /// # Arguments
+    /// * `max_slashable_per_epoch` - The new max slashable funds per epoch per operator
+    /// * `epoch` - The current epoch
+    pub fn set_max_slashable_per_epoch(
+        &mut self,
+        max_slashable_per_epoch: u64,
+        epoch: u64,
+    ) -> Result<(), RestakingError> {
+        let next_epoch = epoch
+            .checked_add(1)
+            .ok_or(RestakingError::SlasherOverflow)?;
+        self.max_slashable_per_epoch = PodU64::from(self.max_slashable_per_epoch_at(epoch));
+        self.pending_max_slashable_per_epoch = PodU64::from(max_slashable_per_epoch);
+        self.pending_max_slashable_epoch = PodU64::from(next_epoch);
+        Ok(())
+    }
+
+    pub fn slash_destination(&self) -> Result<SlashDestination, ProgramError> {
+        SlashDestination::try_from(self.slash_destination)
+    }
+
+    /// Sets where the slashed funds are sent. The insurance wallet is only kept when slashed funds
+    /// are sent to insurance.
+    ///
+    /// # Arguments
+    /// * `slash_destination` - Where the slashed funds are sent
+    /// * `insurance_wallet` - The NCN's insurance wallet, required for [`SlashDestination::Insurance`]
+    pub fn set_slash_destination(
+        &mut self,
+        slash_destination: SlashDestination,
+        insurance_wallet: Option<Pubkey>,
+    ) -> Result<(), RestakingError> {
+        self.insurance_wallet = match (slash_destination, insurance_wallet) {
+            (SlashDestination::Insurance, Some(insurance_wallet)) => insurance_wallet,
+            (SlashDestination::Insurance, None) => {
+                msg!("Slashed funds sent to insurance need an insurance wallet");
+                return Err(RestakingError::NcnInsuranceWalletMissing);
+            }
+            _ => Pubkey::default(),
+        };
+        self.slash_destination = slash_destination as u8;
+        Ok(())
+    }
+
     /// Returns the seeds for the PDA
     ///
     /// # Arguments
@@ -152,21 +233,21 @@ impl NcnVaultSlasherTicket {
         }
         if ncn_vault_slasher_ticket.data_is_empty() {
             msg!("NCN vault slasher ticket account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDataEmpty.into());
         }
         if expect_writable && !ncn_vault_slasher_ticket.is_writable {
             msg!("NCN vault slasher ticket account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotWritable.into());
         }
         if ncn_vault_slasher_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("NCN vault slasher ticket account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDiscriminatorInvalid.into());
         }
         let expected_pubkey =
             Self::find_program_address(program_id, ncn.key, vault.key, slasher.key).0;
         if ncn_vault_slasher_ticket.key.ne(&expected_pubkey) {
             msg!("NCN vault slasher ticket account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -188,7 +269,11 @@ mod tests {
             size_of::<PodU64>() + // index
             size_of::<SlotToggle>() + // state
             size_of::<u8>() + // bump
-            263; // reserved
+            size_of::<u8>() + // slash_destination
+            size_of::<Pubkey>() + // insurance_wallet
+            size_of::<PodU64>() + // pending_max_slashable_per_epoch
+            size_of::<PodU64>() + // pending_max_slashable_epoch
+            214; // reserved
         assert_eq!(ncn_vault_slasher_ticket_size, sum_of_fields);
     }
 
@@ -209,4 +294,79 @@ mod tests {
             SlotToggleState::Inactive
         );
     }
+
+    #[test]
+    fn test_set_slash_destination() {
+        let mut ncn_vault_slasher_ticket = NcnVaultSlasherTicket::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+            0,
+            0,
+        );
+        assert_eq!(
+            ncn_vault_slasher_ticket.slash_destination().unwrap(),
+            SlashDestination::Slasher
+        );
+
+        assert_eq!(
+            ncn_vault_slasher_ticket.set_slash_destination(SlashDestination::Insurance, None),
+            Err(RestakingError::NcnInsuranceWalletMissing)
+        );
+
+        let insurance_wallet = Pubkey::new_unique();
+        ncn_vault_slasher_ticket
+            .set_slash_destination(SlashDestination::Insurance, Some(insurance_wallet))
+            .unwrap();
+        assert_eq!(
+            ncn_vault_slasher_ticket.slash_destination().unwrap(),
+            SlashDestination::Insurance
+        );
+        assert_eq!(ncn_vault_slasher_ticket.insurance_wallet, insurance_wallet);
+
+        ncn_vault_slasher_ticket
+            .set_slash_destination(SlashDestination::Burn, Some(insurance_wallet))
+            .unwrap();
+        assert_eq!(
+            ncn_vault_slasher_ticket.slash_destination().unwrap(),
+            SlashDestination::Burn
+        );
+        assert_eq!(ncn_vault_slasher_ticket.insurance_wallet, Pubkey::default());
+    }
+
+    #[test]
+    fn test_set_max_slashable_per_epoch_takes_effect_next_epoch() {
+        let mut ncn_vault_slasher_ticket = NcnVaultSlasherTicket::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            100,
+            0,
+            0,
+            0,
+        );
+        assert_eq!(ncn_vault_slasher_ticket.max_slashable_per_epoch_at(5), 100);
+
+        ncn_vault_slasher_ticket
+            .set_max_slashable_per_epoch(50, 5)
+            .unwrap();
+        assert_eq!(ncn_vault_slasher_ticket.max_slashable_per_epoch_at(5), 100);
+        assert_eq!(ncn_vault_slasher_ticket.max_slashable_per_epoch_at(6), 50);
+
+        // a second change in the same epoch replaces the pending one
+        ncn_vault_slasher_ticket
+            .set_max_slashable_per_epoch(200, 5)
+            .unwrap();
+        assert_eq!(ncn_vault_slasher_ticket.max_slashable_per_epoch_at(5), 100);
+        assert_eq!(ncn_vault_slasher_ticket.max_slashable_per_epoch_at(6), 200);
+
+        // a change once the pending one took effect starts from it
+        ncn_vault_slasher_ticket
+            .set_max_slashable_per_epoch(10, 7)
+            .unwrap();
+        assert_eq!(ncn_vault_slasher_ticket.max_slashable_per_epoch_at(7), 200);
+        assert_eq!(ncn_vault_slasher_ticket.max_slashable_per_epoch_at(8), 10);
+    }
 }
@@ -6,6 +6,7 @@ use std::fmt::Debug;
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use jito_jsm_core::slot_toggle::SlotToggle;
+use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
@@ -28,8 +29,11 @@ pub struct NcnVaultTicket {
 
     pub bump: u8,
 
+    /// The slot the relationship lapses unless the NCN renews it, zero if it never expires
+    expiry_slot: PodU64,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 255],
 }
 
 impl NcnVaultTicket {
@@ -40,7 +44,8 @@ impl NcnVaultTicket {
             index: PodU64::from(index),
             state: SlotToggle::new(slot),
             bump,
-            reserved: [0; 263],
+            expiry_slot: PodU64::from(0),
+            reserved: [0; 255],
         }
     }
 
@@ -48,6 +53,21 @@ impl NcnVaultTicket {
         self.index.into()
     }
 
+    pub fn expiry_slot(&self) -> u64 {
+        self.expiry_slot.into()
+    }
+
+    /// Whether the relationship lapsed because the NCN didn't renew it in time. An expired
+    /// relationship counts as inactive regardless of its opt-in state.
+    pub fn is_expired(&self, slot: u64) -> bool {
+        self.expiry_slot() != 0 && slot >= self.expiry_slot()
+    }
+
+    /// Renews the relationship until `expiry_slot`, see [`crate::ncn::Ncn::ticket_expiry_slot`]
+    pub fn renew(&mut self, expiry_slot: u64) {
+        self.expiry_slot = PodU64::from(expiry_slot);
+    }
+
     pub fn seeds(ncn: &Pubkey, vault: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([
             b"ncn_vault_ticket".to_vec(),
@@ -91,20 +111,20 @@ impl NcnVaultTicket {
         }
         if ncn_vault_ticket.data_is_empty() {
             msg!("NCN vault ticket account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDataEmpty.into());
         }
         if expect_writable && !ncn_vault_ticket.is_writable {
             msg!("NCN vault ticket account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotWritable.into());
         }
         if ncn_vault_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("NCN vault ticket account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDiscriminatorInvalid.into());
         }
         let expected_pubkey = Self::find_program_address(program_id, ncn.key, vault.key).0;
         if ncn_vault_ticket.key.ne(&expected_pubkey) {
             msg!("NCN vault ticket account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -124,7 +144,8 @@ mod tests {
             size_of::<PodU64>() + // index
             size_of::<SlotToggle>() + // state
             size_of::<u8>() + // bump
-            263; // reserved
+            size_of::<PodU64>() + // expiry_slot
+            255; // reserved
         assert_eq!(ncn_vault_ticket_size, sum_of_fields);
     }
 
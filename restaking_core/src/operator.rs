@@ -4,7 +4,10 @@
 use std::fmt::Debug;
 
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize, AccountVersion, Discriminator,
+};
 use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
@@ -13,6 +16,10 @@ impl Discriminator for Operator {
     const DISCRIMINATOR: u8 = 3;
 }
 
+impl AccountVersion for Operator {
+    const VERSION: u8 = 0;
+}
+
 /// The Operator account stores global information for a particular operator
 /// including the admin, voter, and the number of NCN and vault accounts.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
@@ -54,11 +61,50 @@ pub struct Operator {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The number of NCN task windows opened on the operator that haven't been closed
+    task_window_count: PodU64,
+
+    /// The last slot of any open NCN task window, the operator's stake is locked until then
+    task_window_end_slot: PodU64,
+
+    /// The number of NCNs that have frozen the operator and not unfrozen it
+    freeze_count: PodU64,
+
+    /// The latest slot any NCN's freeze of the operator expires
+    frozen_until_slot: PodU64,
+
+    /// The operator's commission on the restaking rewards distributed by vaults, in basis points
+    commission_bps: PodU16,
+
+    /// The commission at the start of the epoch it was last changed in, which bounds how far it
+    /// can move within that epoch
+    commission_bps_epoch_start: PodU16,
+
+    /// The epoch the commission was last changed in
+    commission_epoch: PodU64,
+
+    /// The operator's display name, UTF-8 and zero-padded
+    name: [u8; 32],
+
+    /// The operator's website, UTF-8 and zero-padded
+    website: [u8; 64],
+
+    /// Whether vaults can only delegate to the operator up to the amount the operator approved on
+    /// each vault's OperatorVaultTicket
+    delegation_approval_required: u8,
+
     /// Reserved space
-    reserved_space: [u8; 263],
+    reserved_space: [u8; 122],
 }
 
 impl Operator {
+    /// The maximum commission, all of the rewards
+    pub const MAX_COMMISSION_BPS: u16 = 10_000;
+
+    /// The most the commission can move within an epoch, so stakers have time to react to an
+    /// operator raising it
+    pub const MAX_COMMISSION_CHANGE_BPS_PER_EPOCH: u16 = 500;
+
     /// Create a new Operator account
     /// # Arguments
     /// * `base` - The base account used as a PDA seed
@@ -78,7 +124,17 @@ impl Operator {
             ncn_count: PodU64::from(0),
             vault_count: PodU64::from(0),
             bump,
-            reserved_space: [0; 263],
+            task_window_count: PodU64::from(0),
+            task_window_end_slot: PodU64::from(0),
+            freeze_count: PodU64::from(0),
+            frozen_until_slot: PodU64::from(0),
+            commission_bps: PodU16::from(0),
+            commission_bps_epoch_start: PodU16::from(0),
+            commission_epoch: PodU64::from(0),
+            name: [0; 32],
+            website: [0; 64],
+            delegation_approval_required: 0,
+            reserved_space: [0; 122],
         }
     }
 
@@ -112,6 +168,207 @@ impl Operator {
         Ok(())
     }
 
+    pub fn task_window_count(&self) -> u64 {
+        self.task_window_count.into()
+    }
+
+    pub fn task_window_end_slot(&self) -> u64 {
+        self.task_window_end_slot.into()
+    }
+
+    /// Whether an NCN task window is open at the given slot, in which case vaults shall not move
+    /// the operator's delegated stake through its cooldown
+    pub fn is_stake_locked(&self, slot: u64) -> bool {
+        self.task_window_count() > 0 && slot < self.task_window_end_slot()
+    }
+
+    /// Records an NCN opening or extending a task window on the operator
+    ///
+    /// # Arguments
+    /// * `end_slot` - The slot the task window ends
+    /// * `is_new` - Whether the NCN didn't already have a task window on the operator
+    pub fn open_task_window(&mut self, end_slot: u64, is_new: bool) -> Result<(), RestakingError> {
+        if is_new {
+            let task_window_count = self
+                .task_window_count()
+                .checked_add(1)
+                .ok_or(RestakingError::OperatorOverflow)?;
+            self.task_window_count = PodU64::from(task_window_count);
+        }
+        if end_slot > self.task_window_end_slot() {
+            self.task_window_end_slot = PodU64::from(end_slot);
+        }
+        Ok(())
+    }
+
+    /// Records an NCN closing its task window on the operator. The end slot is only cleared once
+    /// every NCN has closed its window, since the operator doesn't track each window's end.
+    pub fn close_task_window(&mut self) -> Result<(), RestakingError> {
+        let task_window_count = self
+            .task_window_count()
+            .checked_sub(1)
+            .ok_or(RestakingError::OperatorOverflow)?;
+        self.task_window_count = PodU64::from(task_window_count);
+        if task_window_count == 0 {
+            self.task_window_end_slot = PodU64::from(0);
+        }
+        Ok(())
+    }
+
+    pub fn freeze_count(&self) -> u64 {
+        self.freeze_count.into()
+    }
+
+    pub fn frozen_until_slot(&self) -> u64 {
+        self.frozen_until_slot.into()
+    }
+
+    /// Whether an NCN has frozen the operator at the given slot, in which case vaults shall not
+    /// delegate to the operator or move its delegated stake through its cooldown. Slashing is
+    /// still allowed.
+    pub fn is_frozen(&self, slot: u64) -> bool {
+        self.freeze_count() > 0 && slot < self.frozen_until_slot()
+    }
+
+    /// Records an NCN freezing the operator or restarting its freeze
+    ///
+    /// # Arguments
+    /// * `frozen_until_slot` - The slot the freeze expires
+    /// * `is_new` - Whether the NCN didn't already have a freeze on the operator
+    pub fn freeze(&mut self, frozen_until_slot: u64, is_new: bool) -> Result<(), RestakingError> {
+        if is_new {
+            let freeze_count = self
+                .freeze_count()
+                .checked_add(1)
+                .ok_or(RestakingError::OperatorOverflow)?;
+            self.freeze_count = PodU64::from(freeze_count);
+        }
+        if frozen_until_slot > self.frozen_until_slot() {
+            self.frozen_until_slot = PodU64::from(frozen_until_slot);
+        }
+        Ok(())
+    }
+
+    /// Records an NCN unfreezing the operator. The expiry is only cleared once every NCN has
+    /// unfrozen the operator, since the operator doesn't track each freeze's expiry.
+    pub fn unfreeze(&mut self) -> Result<(), RestakingError> {
+        let freeze_count = self
+            .freeze_count()
+            .checked_sub(1)
+            .ok_or(RestakingError::OperatorOverflow)?;
+        self.freeze_count = PodU64::from(freeze_count);
+        if freeze_count == 0 {
+            self.frozen_until_slot = PodU64::from(0);
+        }
+        Ok(())
+    }
+
+    pub fn commission_bps(&self) -> u16 {
+        self.commission_bps.into()
+    }
+
+    pub fn commission_epoch(&self) -> u64 {
+        self.commission_epoch.into()
+    }
+
+    /// Sets the commission, which shall not exceed [`Self::MAX_COMMISSION_BPS`] or move more than
+    /// [`Self::MAX_COMMISSION_CHANGE_BPS_PER_EPOCH`] from where it was at the start of the epoch
+    ///
+    /// # Arguments
+    /// * `commission_bps` - The new commission in basis points
+    /// * `epoch` - The current epoch
+    pub fn set_commission_bps(
+        &mut self,
+        commission_bps: u16,
+        epoch: u64,
+    ) -> Result<(), RestakingError> {
+        if commission_bps > Self::MAX_COMMISSION_BPS {
+            msg!(
+                "Commission of {} bps exceeds the maximum of {} bps",
+                commission_bps,
+                Self::MAX_COMMISSION_BPS
+            );
+            return Err(RestakingError::OperatorCommissionInvalid);
+        }
+
+        let epoch_start = if epoch == self.commission_epoch() {
+            self.commission_bps_epoch_start.into()
+        } else {
+            self.commission_bps()
+        };
+        if commission_bps.abs_diff(epoch_start) > Self::MAX_COMMISSION_CHANGE_BPS_PER_EPOCH {
+            msg!(
+                "Commission can move at most {} bps from {} bps this epoch",
+                Self::MAX_COMMISSION_CHANGE_BPS_PER_EPOCH,
+                epoch_start
+            );
+            return Err(RestakingError::OperatorCommissionChangeTooLarge);
+        }
+
+        self.commission_bps_epoch_start = PodU16::from(epoch_start);
+        self.commission_epoch = PodU64::from(epoch);
+        self.commission_bps = PodU16::from(commission_bps);
+        Ok(())
+    }
+
+    /// The commission owed to the operator on `rewards`, rounded down
+    pub fn calculate_commission(&self, rewards: u64) -> Result<u64, RestakingError> {
+        let commission = (rewards as u128)
+            .checked_mul(self.commission_bps() as u128)
+            .and_then(|product| product.checked_div(Self::MAX_COMMISSION_BPS as u128))
+            .ok_or(RestakingError::OperatorOverflow)?;
+        u64::try_from(commission).map_err(|_| RestakingError::OperatorOverflow)
+    }
+
+    /// The operator's display name, empty if unset
+    pub fn name(&self) -> &str {
+        Self::metadata_str(&self.name)
+    }
+
+    /// The operator's website, empty if unset
+    pub fn website(&self) -> &str {
+        Self::metadata_str(&self.website)
+    }
+
+    fn metadata_str(bytes: &[u8]) -> &str {
+        let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+        std::str::from_utf8(&bytes[..len]).unwrap_or_default()
+    }
+
+    /// Sets the metadata delegators use to discover the operator. Empty strings clear a field.
+    ///
+    /// # Arguments
+    /// * `name` - The display name, at most 32 bytes
+    /// * `website` - The website, at most 64 bytes
+    pub fn set_metadata(&mut self, name: &str, website: &str) -> Result<(), RestakingError> {
+        if name.len() > self.name.len() || website.len() > self.website.len() {
+            msg!(
+                "Operator name can be at most {} bytes and website at most {} bytes",
+                self.name.len(),
+                self.website.len()
+            );
+            return Err(RestakingError::OperatorMetadataInvalid);
+        }
+        if name.contains('\0') || website.contains('\0') {
+            msg!("Operator metadata can't contain null bytes");
+            return Err(RestakingError::OperatorMetadataInvalid);
+        }
+
+        self.name = [0; 32];
+        self.name[..name.len()].copy_from_slice(name.as_bytes());
+        self.website = [0; 64];
+        self.website[..website.len()].copy_from_slice(website.as_bytes());
+        Ok(())
+    }
+
+    pub fn is_delegation_approval_required(&self) -> bool {
+        self.delegation_approval_required == 1
+    }
+
+    pub fn set_delegation_approval_required(&mut self, delegation_approval_required: bool) {
+        self.delegation_approval_required = delegation_approval_required as u8;
+    }
+
     /// Replace all secondary admins that were equal to the old admin to the new admin
     ///
     /// # Arguments
@@ -192,15 +449,19 @@ impl Operator {
         }
         if account.data_is_empty() {
             msg!("Operator account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDataEmpty.into());
         }
         if expect_writable && !account.is_writable {
             msg!("Operator account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotWritable.into());
         }
         if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("Operator account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDiscriminatorInvalid.into());
+        }
+        if Self::version(&account.data.borrow())?.ne(&Self::VERSION) {
+            msg!("Operator account must be migrated to the current version");
+            return Err(RestakingError::RestakingAccountVersionInvalid.into());
         }
         let base = Self::try_from_slice_unchecked(&account.data.borrow())?.base;
         if account
@@ -208,7 +469,7 @@ impl Operator {
             .ne(&Self::find_program_address(program_id, &base).0)
         {
             msg!("Operator account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -216,7 +477,8 @@ impl Operator {
 
 #[cfg(test)]
 mod tests {
-    use jito_bytemuck::types::PodU64;
+    use jito_bytemuck::types::{PodU16, PodU64};
+    use jito_restaking_sdk::error::RestakingError;
     use solana_program::pubkey::Pubkey;
 
     use crate::operator::Operator;
@@ -235,7 +497,17 @@ mod tests {
             std::mem::size_of::<PodU64>() + // ncn_count
             std::mem::size_of::<PodU64>() + // vault_count
             std::mem::size_of::<u8>() + // bump
-            263; // reserved_space
+            std::mem::size_of::<PodU64>() + // task_window_count
+            std::mem::size_of::<PodU64>() + // task_window_end_slot
+            std::mem::size_of::<PodU64>() + // freeze_count
+            std::mem::size_of::<PodU64>() + // frozen_until_slot
+            std::mem::size_of::<PodU16>() + // commission_bps
+            std::mem::size_of::<PodU16>() + // commission_bps_epoch_start
+            std::mem::size_of::<PodU64>() + // commission_epoch
+            32 + // name
+            64 + // website
+            std::mem::size_of::<u8>() + // delegation_approval_required
+            122; // reserved_space
         assert_eq!(operator_size, sum_of_fields);
     }
 
@@ -259,4 +531,127 @@ mod tests {
         assert_eq!(operator.withdrawal_admin, new_admin);
         assert_eq!(operator.withdrawal_fee_wallet, new_admin);
     }
+
+    #[test]
+    fn test_task_windows_lock_stake_until_all_closed() {
+        let mut operator = Operator::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+        assert!(!operator.is_stake_locked(0));
+
+        operator.open_task_window(100, true).unwrap();
+        operator.open_task_window(50, true).unwrap();
+        assert_eq!(operator.task_window_count(), 2);
+        assert_eq!(operator.task_window_end_slot(), 100);
+        assert!(operator.is_stake_locked(99));
+        assert!(!operator.is_stake_locked(100));
+
+        // extending an existing window doesn't count it twice
+        operator.open_task_window(150, false).unwrap();
+        assert_eq!(operator.task_window_count(), 2);
+        assert_eq!(operator.task_window_end_slot(), 150);
+
+        operator.close_task_window().unwrap();
+        assert!(operator.is_stake_locked(149));
+
+        operator.close_task_window().unwrap();
+        assert_eq!(operator.task_window_end_slot(), 0);
+        assert!(!operator.is_stake_locked(0));
+        assert!(operator.close_task_window().is_err());
+    }
+
+    #[test]
+    fn test_freezes_expire_or_last_until_all_unfrozen() {
+        let mut operator = Operator::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+        assert!(!operator.is_frozen(0));
+
+        operator.freeze(100, true).unwrap();
+        operator.freeze(50, true).unwrap();
+        assert_eq!(operator.freeze_count(), 2);
+        assert_eq!(operator.frozen_until_slot(), 100);
+        assert!(operator.is_frozen(99));
+        // the freeze expires on its own
+        assert!(!operator.is_frozen(100));
+
+        operator.unfreeze().unwrap();
+        assert!(operator.is_frozen(99));
+
+        operator.unfreeze().unwrap();
+        assert_eq!(operator.frozen_until_slot(), 0);
+        assert!(!operator.is_frozen(0));
+        assert!(operator.unfreeze().is_err());
+    }
+
+    #[test]
+    fn test_commission_change_bounded_per_epoch() {
+        let mut operator = Operator::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+        assert_eq!(operator.commission_bps(), 0);
+
+        assert_eq!(
+            operator.set_commission_bps(Operator::MAX_COMMISSION_BPS + 1, 1),
+            Err(RestakingError::OperatorCommissionInvalid)
+        );
+
+        operator.set_commission_bps(300, 1).unwrap();
+        operator.set_commission_bps(500, 1).unwrap();
+        // the change is measured from the start of the epoch, not the last change
+        assert_eq!(
+            operator.set_commission_bps(501, 1),
+            Err(RestakingError::OperatorCommissionChangeTooLarge)
+        );
+
+        operator.set_commission_bps(1_000, 2).unwrap();
+        assert_eq!(operator.commission_bps(), 1_000);
+        assert_eq!(operator.commission_epoch(), 2);
+        assert_eq!(
+            operator.set_commission_bps(1_001, 2),
+            Err(RestakingError::OperatorCommissionChangeTooLarge)
+        );
+        operator.set_commission_bps(0, 2).unwrap();
+    }
+
+    #[test]
+    fn test_calculate_commission() {
+        let mut operator = Operator::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+        assert_eq!(operator.calculate_commission(1_000).unwrap(), 0);
+
+        operator.set_commission_bps(250, 0).unwrap();
+        assert_eq!(operator.calculate_commission(1_000).unwrap(), 25);
+        // rounds down in favor of the stakers
+        assert_eq!(operator.calculate_commission(399).unwrap(), 9);
+        assert_eq!(
+            operator.calculate_commission(u64::MAX).unwrap(),
+            u64::MAX / 40
+        );
+    }
+
+    #[test]
+    fn test_set_metadata() {
+        let mut operator = Operator::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+        assert_eq!(operator.name(), "");
+        assert_eq!(operator.website(), "");
+
+        operator
+            .set_metadata("Jito Operator", "https://jito.network")
+            .unwrap();
+        assert_eq!(operator.name(), "Jito Operator");
+        assert_eq!(operator.website(), "https://jito.network");
+
+        // a shorter value doesn't leave bytes of the old one behind
+        operator.set_metadata("Op", "").unwrap();
+        assert_eq!(operator.name(), "Op");
+        assert_eq!(operator.website(), "");
+
+        assert_eq!(
+            operator.set_metadata(&"a".repeat(33), ""),
+            Err(RestakingError::OperatorMetadataInvalid)
+        );
+        assert_eq!(
+            operator.set_metadata("", &"a".repeat(65)),
+            Err(RestakingError::OperatorMetadataInvalid)
+        );
+        assert_eq!(
+            operator.set_metadata("a\0b", ""),
+            Err(RestakingError::OperatorMetadataInvalid)
+        );
+        assert_eq!(operator.name(), "Op");
+    }
 }
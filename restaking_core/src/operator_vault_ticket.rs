@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use jito_jsm_core::slot_toggle::SlotToggle;
+use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
@@ -27,8 +28,12 @@ pub struct OperatorVaultTicket {
 
     pub bump: u8,
 
+    /// The most the vault can have staked with the operator when the operator requires approval of
+    /// incoming delegations
+    approved_delegation_amount: PodU64,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 255],
 }
 
 impl OperatorVaultTicket {
@@ -39,7 +44,8 @@ impl OperatorVaultTicket {
             index: PodU64::from(index),
             state: SlotToggle::new(slot),
             bump,
-            reserved: [0; 263],
+            approved_delegation_amount: PodU64::from(0),
+            reserved: [0; 255],
         }
     }
 
@@ -47,6 +53,14 @@ impl OperatorVaultTicket {
         self.index.into()
     }
 
+    pub fn approved_delegation_amount(&self) -> u64 {
+        self.approved_delegation_amount.into()
+    }
+
+    pub fn set_approved_delegation_amount(&mut self, approved_delegation_amount: u64) {
+        self.approved_delegation_amount = PodU64::from(approved_delegation_amount);
+    }
+
     pub fn seeds(operator: &Pubkey, vault: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([
             b"operator_vault_ticket".to_vec(),
@@ -90,20 +104,20 @@ impl OperatorVaultTicket {
         }
         if operator_vault_ticket.data_is_empty() {
             msg!("Operator vault ticket account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDataEmpty.into());
         }
         if expect_writable && !operator_vault_ticket.is_writable {
             msg!("Operator vault ticket account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotWritable.into());
         }
         if operator_vault_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("Operator vault ticket account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountDiscriminatorInvalid.into());
         }
         let expected_pubkey = Self::find_program_address(program_id, operator.key, vault.key).0;
         if operator_vault_ticket.key.ne(&expected_pubkey) {
             msg!("Operator vault ticket account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -123,7 +137,8 @@ mod tests {
             size_of::<PodU64>() + // index
             size_of::<SlotToggle>() + // state
             size_of::<u8>() + // bump
-            263; // reserved
+            size_of::<PodU64>() + // approved_delegation_amount
+            255; // reserved
         assert_eq!(operator_vault_ticket_size, sum_of_fields);
     }
 
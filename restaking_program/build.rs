@@ -0,0 +1,24 @@
+//! Exposes the commit the program is built from as `GIT_SHA`, for the `source_revision` of the
+//! program's security.txt. Builds outside a git checkout, such as from a published crate, embed
+//! `unknown`. `GIT_SHA` can also be set by the build environment, taking precedence.
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=GIT_SHA");
+    println!("cargo:rerun-if-changed=../.git/HEAD");
+    println!("cargo:rerun-if-changed=../.git/refs/heads");
+
+    let git_sha = std::env::var("GIT_SHA").ok().or_else(|| {
+        Command::new("git")
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|sha| sha.trim().to_string())
+    });
+    println!(
+        "cargo:rustc-env=GIT_SHA={}",
+        git_sha.unwrap_or_else(|| "unknown".to_string())
+    );
+}
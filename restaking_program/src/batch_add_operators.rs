@@ -0,0 +1,82 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    loader::{load_signer, load_system_program},
+    sysvar::get_clock,
+};
+use jito_restaking_core::{config::Config, ncn::Ncn};
+use jito_restaking_sdk::{error::RestakingError, instruction::MAX_BATCH_ADD_OPERATORS};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::initialize_ncn_operator_state::add_operator_to_ncn;
+
+/// The NCN operator admin adds several operators to the NCN at once, the same as calling
+/// [`crate::RestakingInstruction::InitializeNcnOperatorState`] for each of them.
+/// [`crate::RestakingInstruction::BatchAddOperators`]
+///
+/// Specification:
+/// - The NCN operator admin shall be the signer of the transaction
+/// - Each operator shall be followed by its NcnOperatorState, which shall be at the canonical PDA
+///   and not exist yet
+/// - There shall be between 1 and [`MAX_BATCH_ADD_OPERATORS`] operators
+/// - The operators shall be added in order, so their indexes on the NCN follow the order passed in
+pub fn process_batch_add_operators(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, ncn_info, ncn_operator_admin, payer, system_program, operator_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Ncn::load(program_id, ncn_info, true)?;
+    load_signer(ncn_operator_admin, false)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    if operator_accounts.len() % 2 != 0 {
+        msg!("Each operator shall be followed by its NcnOperatorState");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let operator_count = operator_accounts.len() / 2;
+    if operator_count == 0 || operator_count > MAX_BATCH_ADD_OPERATORS {
+        msg!(
+            "Batch of {} operators shall be between 1 and {}",
+            operator_count,
+            MAX_BATCH_ADD_OPERATORS
+        );
+        return Err(RestakingError::NcnOperatorBatchInvalid.into());
+    }
+
+    // The NCN operator admin must be the signer for adding operators to the NCN
+    let mut ncn_data = ncn_info.data.borrow_mut();
+    let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
+    if ncn.operator_admin.ne(ncn_operator_admin.key) {
+        msg!("Invalid operator admin for NCN");
+        return Err(RestakingError::NcnOperatorAdminInvalid.into());
+    }
+
+    let slot = get_clock()?.slot;
+    for pair in operator_accounts.chunks_exact(2) {
+        let [operator, ncn_operator_state] = pair else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        add_operator_to_ncn(
+            program_id,
+            config,
+            ncn_info,
+            ncn,
+            operator,
+            ncn_operator_state,
+            payer,
+            system_program,
+            slot,
+        )?;
+    }
+    msg!("Added {} operators to the NCN", operator_count);
+
+    Ok(())
+}
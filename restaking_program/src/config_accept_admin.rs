@@ -0,0 +1,38 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::config::Config;
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The proposed config admin accepts and becomes the config admin
+/// [`crate::RestakingInstruction::ConfigAcceptAdmin`]
+///
+/// Specification:
+/// - The pending admin proposed with [`crate::RestakingInstruction::ConfigSetAdmin`] shall be the
+///   signer of the transaction
+/// - The pending admin shall be cleared once accepted
+pub fn process_config_accept_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, new_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, true)?;
+    load_signer(new_admin, false)?;
+
+    // The pending admin shall be the signer of the transaction
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    if config.pending_admin.eq(&Pubkey::default()) || config.pending_admin.ne(new_admin.key) {
+        msg!("Signer is not the pending config admin");
+        return Err(RestakingError::ConfigPendingAdminInvalid.into());
+    }
+
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+    msg!("Config admin set to {}", new_admin.key);
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::config::Config;
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The config admin proposes a new config admin
+/// [`crate::RestakingInstruction::ConfigSetAdmin`]
+///
+/// Specification:
+/// - The config admin shall be the signer of the transaction
+/// - The new admin only becomes the config admin once they accept with
+///   [`crate::RestakingInstruction::ConfigAcceptAdmin`], so the config can't be handed to a key
+///   nobody controls
+/// - Proposing again replaces the pending admin
+pub fn process_config_set_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, admin, new_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, true)?;
+    load_signer(admin, false)?;
+
+    // The config admin shall be the signer of the transaction
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    if config.admin.ne(admin.key) {
+        msg!("Invalid config admin");
+        return Err(RestakingError::ConfigAdminInvalid.into());
+    }
+
+    config.pending_admin = *new_admin.key;
+    msg!("Proposed {} as the config admin", new_admin.key);
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::config::Config;
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The config admin changes the length of an epoch in slots
+/// [`crate::RestakingInstruction::ConfigSetEpochLength`]
+///
+/// Specification:
+/// - The config admin shall be the signer of the transaction
+/// - The epoch length shall be greater than zero
+/// - Tickets are tracked by slot, the new length applies to warmups and cooldowns in progress
+pub fn process_config_set_epoch_length(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch_length: u64,
+) -> ProgramResult {
+    let [config, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, true)?;
+    load_signer(admin, false)?;
+
+    // The config admin shall be the signer of the transaction
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    if config.admin.ne(admin.key) {
+        msg!("Invalid config admin");
+        return Err(RestakingError::ConfigAdminInvalid.into());
+    }
+
+    config.set_epoch_length(epoch_length)?;
+    msg!("Epoch length set to {} slots", epoch_length);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::config::Config;
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The config admin changes the config's parameters
+/// [`crate::RestakingInstruction::ConfigSetParameters`]
+///
+/// Specification:
+/// - The config admin shall be the signer of the transaction
+/// - Only the parameters passed in shall change
+/// - The vault program is the program whose vaults the restaking program accepts, replacing it
+///   lets the restaking program follow a redeployed vault program
+pub fn process_config_set_parameters(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    vault_program: Option<Pubkey>,
+) -> ProgramResult {
+    let [config, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, true)?;
+    load_signer(admin, false)?;
+
+    // The config admin shall be the signer of the transaction
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    if config.admin.ne(admin.key) {
+        msg!("Invalid config admin");
+        return Err(RestakingError::ConfigAdminInvalid.into());
+    }
+
+    if let Some(vault_program) = vault_program {
+        config.vault_program = vault_program;
+        msg!("Vault program set to {}", vault_program);
+    }
+
+    Ok(())
+}
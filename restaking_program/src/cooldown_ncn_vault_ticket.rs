@@ -1,10 +1,10 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
 use jito_restaking_core::{config::Config, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// [`crate::RestakingInstruction::CooldownNcnVaultTicket`]
@@ -35,11 +35,11 @@ pub fn process_cooldown_ncn_vault_ticket(
     let mut ncn_vault_ticket_data = ncn_vault_ticket.data.borrow_mut();
     let ncn_vault_ticket =
         NcnVaultTicket::try_from_slice_unchecked_mut(&mut ncn_vault_ticket_data)?;
-    if !ncn_vault_ticket
+    if let Err(e) = ncn_vault_ticket
         .state
-        .deactivate(Clock::get()?.slot, config.epoch_length())
+        .deactivate(get_clock()?.slot, config.epoch_length())
     {
-        msg!("Vault is not ready to be deactivated");
+        msg!("Vault is not ready to be deactivated: {}", e);
         return Err(RestakingError::NcnVaultTicketFailedCooldown.into());
     }
 
@@ -1,14 +1,16 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::{AccountDeserialize, AccountVersion, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::get_rent,
 };
 use jito_restaking_core::config::Config;
+use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    pubkey::Pubkey,
 };
 
 /// Initializes the global configuration for the restaking program
@@ -27,7 +29,7 @@ pub fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo])
     config_seeds.push(vec![config_bump]);
     if config.key.ne(&config_pubkey) {
         msg!("Config account is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
     }
 
     msg!("Initializing config at address {}", config.key);
@@ -36,13 +38,14 @@ pub fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo])
         config,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64.checked_add(size_of::<Config>() as u64).unwrap(),
         &config_seeds,
     )?;
 
     let mut config_data = config.try_borrow_mut_data()?;
     config_data[0] = Config::DISCRIMINATOR;
+    config_data[1] = Config::VERSION;
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
     *config = Config::new(*admin.key, *vault_program.key, config_bump);
 
@@ -1,14 +1,16 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::{AccountDeserialize, AccountVersion, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::get_rent,
 };
 use jito_restaking_core::{config::Config, ncn::Ncn};
+use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    pubkey::Pubkey,
 };
 
 /// Initializes an NCN and associated accounts
@@ -28,7 +30,7 @@ pub fn process_initialize_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) ->
     ncn_seeds.push(vec![ncn_bump]);
     if ncn.key.ne(&ncn_pubkey) {
         msg!("NCN account is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
     }
 
     msg!("Initializing NCN at address {}", ncn.key);
@@ -37,7 +39,7 @@ pub fn process_initialize_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) ->
         ncn,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64.checked_add(size_of::<Ncn>() as u64).unwrap(),
         &ncn_seeds,
     )?;
@@ -47,6 +49,7 @@ pub fn process_initialize_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) ->
 
     let mut ncn_data = ncn.try_borrow_mut_data()?;
     ncn_data[0] = Ncn::DISCRIMINATOR;
+    ncn_data[1] = Ncn::VERSION;
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
     *ncn = Ncn::new(*base.key, *admin.key, config.ncn_count(), ncn_bump);
 
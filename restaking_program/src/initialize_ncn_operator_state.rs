@@ -4,14 +4,15 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
 };
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
 };
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// After an operator opts-in to an NCN, the NCN operator admin can add the operator to the NCN.
@@ -29,22 +30,13 @@ pub fn process_initialize_ncn_operator_state(
     };
 
     Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
     Ncn::load(program_id, ncn_info, true)?;
-    Operator::load(program_id, operator, true)?;
-    load_system_account(ncn_operator_state, true)?;
     load_signer(ncn_operator_admin, false)?;
     load_signer(payer, true)?;
     load_system_program(system_program)?;
 
-    // The NcnOperatorState shall be at the canonical PDA
-    let (ncn_operator_state_pubkey, ncn_operator_state_bump, mut ncn_operator_state_seeds) =
-        NcnOperatorState::find_program_address(program_id, ncn_info.key, operator.key);
-    ncn_operator_state_seeds.push(vec![ncn_operator_state_bump]);
-    if ncn_operator_state_pubkey.ne(ncn_operator_state.key) {
-        msg!("NcnOperatorState is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
-    }
-
     // The NCN operator admin must be the signer for adding an operator to the NCN
     let mut ncn_data = ncn_info.data.borrow_mut();
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
@@ -53,13 +45,53 @@ pub fn process_initialize_ncn_operator_state(
         return Err(RestakingError::NcnOperatorAdminInvalid.into());
     }
 
+    add_operator_to_ncn(
+        program_id,
+        config,
+        ncn_info,
+        ncn,
+        operator,
+        ncn_operator_state,
+        payer,
+        system_program,
+        get_clock()?.slot,
+    )
+}
+
+/// Creates the [`NcnOperatorState`] for the operator at its canonical PDA and counts the operator
+/// on the NCN, after the caller checked the NCN operator admin signed. Shared with
+/// [`crate::RestakingInstruction::BatchAddOperators`].
+#[allow(clippy::too_many_arguments)]
+pub fn add_operator_to_ncn<'a, 'info>(
+    program_id: &Pubkey,
+    config: &Config,
+    ncn_info: &AccountInfo<'info>,
+    ncn: &mut Ncn,
+    operator: &'a AccountInfo<'info>,
+    ncn_operator_state: &'a AccountInfo<'info>,
+    payer: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    slot: u64,
+) -> ProgramResult {
+    Operator::load(program_id, operator, true)?;
+    load_system_account(ncn_operator_state, true)?;
+
+    // The NcnOperatorState shall be at the canonical PDA
+    let (ncn_operator_state_pubkey, ncn_operator_state_bump, mut ncn_operator_state_seeds) =
+        NcnOperatorState::find_program_address(program_id, ncn_info.key, operator.key);
+    ncn_operator_state_seeds.push(vec![ncn_operator_state_bump]);
+    if ncn_operator_state_pubkey.ne(ncn_operator_state.key) {
+        msg!("NcnOperatorState is not at the correct PDA");
+        return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
+    }
+
     msg!("Initializing NcnOperatorState at address {}", operator.key);
     create_account(
         payer,
         ncn_operator_state,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64
             .checked_add(size_of::<NcnOperatorState>() as u64)
             .unwrap(),
@@ -75,8 +107,9 @@ pub fn process_initialize_ncn_operator_state(
         *operator.key,
         ncn.operator_count(),
         ncn_operator_state_bump,
-        Clock::get()?.slot,
+        slot,
     );
+    ncn_operator_state.renew(ncn.ticket_expiry_slot(slot, config.epoch_length())?);
 
     let mut operator_data = operator.data.borrow_mut();
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
@@ -4,6 +4,7 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
 };
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
@@ -12,8 +13,8 @@ use jito_restaking_core::{
 use jito_restaking_sdk::error::RestakingError;
 use jito_vault_core::vault::Vault;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 pub fn process_initialize_ncn_vault_slasher_ticket(
@@ -55,7 +56,7 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
         .ne(&ncn_vault_slasher_ticket_pubkey)
     {
         msg!("Ncn vault slasher ticket account is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
     }
 
     let mut ncn_data = ncn_info.data.borrow_mut();
@@ -74,7 +75,7 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
         ncn_vault_slasher_ticket,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64
             .checked_add(size_of::<NcnVaultSlasherTicket>() as u64)
             .unwrap(),
@@ -91,7 +92,7 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
         max_slashable_per_epoch,
         ncn.slasher_count(),
         ncn_vault_slasher_ticket_bump,
-        Clock::get()?.slot,
+        get_clock()?.slot,
     );
 
     ncn.increment_slasher_count()?;
@@ -4,13 +4,14 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
 };
 use jito_restaking_core::{config::Config, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
 use jito_restaking_sdk::error::RestakingError;
 use jito_vault_core::vault::Vault;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// The NCN opts-in to vaults by storing the vault in the NCN vault list. It also CPI's into
@@ -43,7 +44,7 @@ pub fn process_initialize_ncn_vault_ticket(
     ncn_vault_ticket_seeds.push(vec![ncn_vault_ticket_bump]);
     if ncn_vault_ticket_pubkey.ne(ncn_vault_ticket.key) {
         msg!("NCN vault ticket is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
     }
 
     let mut ncn_data = ncn_info.data.borrow_mut();
@@ -62,7 +63,7 @@ pub fn process_initialize_ncn_vault_ticket(
         ncn_vault_ticket,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64
             .checked_add(size_of::<NcnVaultTicket>() as u64)
             .unwrap(),
@@ -73,13 +74,15 @@ pub fn process_initialize_ncn_vault_ticket(
     ncn_vault_ticket_data[0] = NcnVaultTicket::DISCRIMINATOR;
     let ncn_vault_ticket =
         NcnVaultTicket::try_from_slice_unchecked_mut(&mut ncn_vault_ticket_data)?;
+    let slot = get_clock()?.slot;
     *ncn_vault_ticket = NcnVaultTicket::new(
         *ncn_info.key,
         *vault.key,
         ncn.vault_count(),
         ncn_vault_ticket_bump,
-        Clock::get()?.slot,
+        slot,
     );
+    ncn_vault_ticket.renew(ncn.ticket_expiry_slot(slot, config.epoch_length())?);
 
     ncn.increment_vault_count()?;
 
@@ -1,14 +1,16 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::{AccountDeserialize, AccountVersion, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::get_rent,
 };
 use jito_restaking_core::{config::Config, operator::Operator};
+use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    pubkey::Pubkey,
 };
 
 /// Initializes a node operator and associated accounts.
@@ -29,7 +31,7 @@ pub fn process_initialize_operator(program_id: &Pubkey, accounts: &[AccountInfo]
     operator_seed.push(vec![operator_bump]);
     if operator.key.ne(&operator_pubkey) {
         msg!("Operator account is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(RestakingError::RestakingAccountNotCanonicalPda.into());
     }
 
     msg!("Initializing operator at address {}", operator.key);
@@ -38,7 +40,7 @@ pub fn process_initialize_operator(program_id: &Pubkey, accounts: &[AccountInfo]
         operator,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64.checked_add(size_of::<Operator>() as u64).unwrap(),
         &operator_seed,
     )?;
@@ -48,6 +50,7 @@ pub fn process_initialize_operator(program_id: &Pubkey, accounts: &[AccountInfo]
 
     let mut operator_data = operator.try_borrow_mut_data()?;
     operator_data[0] = Operator::DISCRIMINATOR;
+    operator_data[1] = Operator::VERSION;
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
     *operator = Operator::new(
         *base.key,
@@ -4,14 +4,16 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
 };
 use jito_restaking_core::{
     config::Config, operator::Operator, operator_vault_ticket::OperatorVaultTicket,
 };
+use jito_restaking_sdk::error::RestakingError;
 use jito_vault_core::vault::Vault;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// The node operator admin can add support for receiving delegation from a vault.
@@ -55,7 +57,7 @@ pub fn process_initialize_operator_vault_ticket(
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
     if operator.vault_admin.ne(operator_vault_admin.key) {
         msg!("Invalid operator vault admin");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(RestakingError::OperatorVaultAdminInvalid.into());
     }
 
     msg!(
@@ -67,7 +69,7 @@ pub fn process_initialize_operator_vault_ticket(
         operator_vault_ticket_account,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64
             .checked_add(size_of::<OperatorVaultTicket>() as u64)
             .unwrap(),
@@ -83,7 +85,7 @@ pub fn process_initialize_operator_vault_ticket(
         *vault.key,
         operator.vault_count(),
         operator_vault_ticket_bump,
-        Clock::get()?.slot,
+        get_clock()?.slot,
     );
 
     operator.increment_vault_count()?;
@@ -1,3 +1,8 @@
+mod batch_add_operators;
+mod config_accept_admin;
+mod config_set_admin;
+mod config_set_epoch_length;
+mod config_set_parameters;
 mod cooldown_ncn_vault_slasher_ticket;
 mod cooldown_ncn_vault_ticket;
 mod cooldown_operator_vault_ticket;
@@ -8,16 +13,30 @@ mod initialize_ncn_vault_slasher_ticket;
 mod initialize_ncn_vault_ticket;
 mod initialize_operator;
 mod initialize_operator_vault_ticket;
+mod migrate_account;
+mod ncn_close_task_window;
 mod ncn_cooldown_operator;
+mod ncn_freeze_operator;
+mod ncn_open_task_window;
+mod ncn_request_slash;
 mod ncn_set_admin;
+mod ncn_set_max_slashable_per_epoch;
 mod ncn_set_secondary_admin;
+mod ncn_set_slash_destination;
+mod ncn_set_ticket_expiry;
+mod ncn_unfreeze_operator;
 mod ncn_warmup_operator;
 mod ncn_withdraw_asset;
+mod operator_approve_delegation;
 mod operator_cooldown_ncn;
 mod operator_set_admin;
+mod operator_set_commission;
+mod operator_set_delegation_approval_required;
+mod operator_set_metadata;
 mod operator_set_secondary_admin;
 mod operator_warmup_ncn;
 mod operator_withdrawal_asset;
+mod renew_ticket;
 mod warmup_ncn_vault_slasher_ticket;
 mod warmup_ncn_vault_ticket;
 mod warmup_operator_vault_ticket;
@@ -33,6 +52,10 @@ use solana_program::{
 use solana_security_txt::security_txt;
 
 use crate::{
+    batch_add_operators::process_batch_add_operators,
+    config_accept_admin::process_config_accept_admin, config_set_admin::process_config_set_admin,
+    config_set_epoch_length::process_config_set_epoch_length,
+    config_set_parameters::process_config_set_parameters,
     cooldown_ncn_vault_slasher_ticket::process_cooldown_ncn_vault_slasher_ticket,
     cooldown_ncn_vault_ticket::process_cooldown_ncn_vault_ticket,
     cooldown_operator_vault_ticket::process_cooldown_operator_vault_ticket,
@@ -42,15 +65,28 @@ use crate::{
     initialize_ncn_vault_ticket::process_initialize_ncn_vault_ticket,
     initialize_operator::process_initialize_operator,
     initialize_operator_vault_ticket::process_initialize_operator_vault_ticket,
-    ncn_cooldown_operator::process_ncn_cooldown_operator, ncn_set_admin::process_ncn_set_admin,
+    migrate_account::process_migrate_account, ncn_close_task_window::process_ncn_close_task_window,
+    ncn_cooldown_operator::process_ncn_cooldown_operator,
+    ncn_freeze_operator::process_ncn_freeze_operator,
+    ncn_open_task_window::process_ncn_open_task_window,
+    ncn_request_slash::process_ncn_request_slash, ncn_set_admin::process_ncn_set_admin,
+    ncn_set_max_slashable_per_epoch::process_ncn_set_max_slashable_per_epoch,
     ncn_set_secondary_admin::process_ncn_set_secondary_admin,
+    ncn_set_slash_destination::process_ncn_set_slash_destination,
+    ncn_set_ticket_expiry::process_ncn_set_ticket_expiry,
+    ncn_unfreeze_operator::process_ncn_unfreeze_operator,
     ncn_warmup_operator::process_ncn_warmup_operator,
     ncn_withdraw_asset::process_ncn_withdraw_asset,
+    operator_approve_delegation::process_operator_approve_delegation,
     operator_cooldown_ncn::process_operator_cooldown_ncn,
     operator_set_admin::process_set_node_operator_admin,
+    operator_set_commission::process_operator_set_commission,
+    operator_set_delegation_approval_required::process_operator_set_delegation_approval_required,
+    operator_set_metadata::process_operator_set_metadata,
     operator_set_secondary_admin::process_set_operator_secondary_admin,
     operator_warmup_ncn::process_operator_warmup_ncn,
     operator_withdrawal_asset::process_operator_withdrawal_asset,
+    renew_ticket::process_renew_ticket,
     warmup_ncn_vault_slasher_ticket::process_warmup_ncn_vault_slasher_ticket,
     warmup_ncn_vault_ticket::process_warmup_ncn_vault_ticket,
     warmup_operator_vault_ticket::process_warmup_operator_vault_ticket,
@@ -67,12 +103,28 @@ security_txt! {
     policy: "https://github.com/jito-foundation/restaking",
     // Optional Fields
     preferred_languages: "en",
-    source_code: "https://github.com/jito-foundation/restaking"
+    source_code: "https://github.com/jito-foundation/restaking",
+    source_release: env!("CARGO_PKG_VERSION"),
+    source_revision: env!("GIT_SHA")
 }
 
 #[cfg(not(feature = "no-entrypoint"))]
 solana_program::entrypoint!(process_instruction);
 
+/// Processes an instruction at `clock`, for programs built with the `accounting-only` feature
+/// where there's no clock sysvar to read
+#[cfg(feature = "accounting-only")]
+pub fn process_instruction_with_clock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+    clock: &solana_program::clock::Clock,
+) -> ProgramResult {
+    jito_jsm_core::sysvar::with_clock(clock, || {
+        process_instruction(program_id, accounts, instruction_data)
+    })
+}
+
 pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -181,5 +233,93 @@ pub fn process_instruction(
             msg!("Instruction: OperatorWithdrawalAsset");
             process_operator_withdrawal_asset(program_id, accounts, token_mint, amount)
         }
+        RestakingInstruction::NcnOpenTaskWindow { end_slot } => {
+            msg!("Instruction: NcnOpenTaskWindow");
+            process_ncn_open_task_window(program_id, accounts, end_slot)
+        }
+        RestakingInstruction::NcnCloseTaskWindow => {
+            msg!("Instruction: NcnCloseTaskWindow");
+            process_ncn_close_task_window(program_id, accounts)
+        }
+        RestakingInstruction::NcnFreezeOperator => {
+            msg!("Instruction: NcnFreezeOperator");
+            process_ncn_freeze_operator(program_id, accounts)
+        }
+        RestakingInstruction::NcnUnfreezeOperator => {
+            msg!("Instruction: NcnUnfreezeOperator");
+            process_ncn_unfreeze_operator(program_id, accounts)
+        }
+        RestakingInstruction::NcnSetTicketExpiry {
+            ticket_expiry_epochs,
+        } => {
+            msg!("Instruction: NcnSetTicketExpiry");
+            process_ncn_set_ticket_expiry(program_id, accounts, ticket_expiry_epochs)
+        }
+        RestakingInstruction::RenewTicket => {
+            msg!("Instruction: RenewTicket");
+            process_renew_ticket(program_id, accounts)
+        }
+        RestakingInstruction::OperatorSetCommission { commission_bps } => {
+            msg!("Instruction: OperatorSetCommission");
+            process_operator_set_commission(program_id, accounts, commission_bps)
+        }
+        RestakingInstruction::NcnSetSlashDestination { slash_destination } => {
+            msg!("Instruction: NcnSetSlashDestination");
+            process_ncn_set_slash_destination(program_id, accounts, slash_destination)
+        }
+        RestakingInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+        RestakingInstruction::OperatorSetMetadata { name, website } => {
+            msg!("Instruction: OperatorSetMetadata");
+            process_operator_set_metadata(program_id, accounts, &name, &website)
+        }
+        RestakingInstruction::NcnSetMaxSlashablePerEpoch {
+            max_slashable_per_epoch,
+        } => {
+            msg!("Instruction: NcnSetMaxSlashablePerEpoch");
+            process_ncn_set_max_slashable_per_epoch(program_id, accounts, max_slashable_per_epoch)
+        }
+        RestakingInstruction::OperatorSetDelegationApprovalRequired {
+            delegation_approval_required,
+        } => {
+            msg!("Instruction: OperatorSetDelegationApprovalRequired");
+            process_operator_set_delegation_approval_required(
+                program_id,
+                accounts,
+                delegation_approval_required,
+            )
+        }
+        RestakingInstruction::OperatorApproveDelegation {
+            approved_delegation_amount,
+        } => {
+            msg!("Instruction: OperatorApproveDelegation");
+            process_operator_approve_delegation(program_id, accounts, approved_delegation_amount)
+        }
+        RestakingInstruction::ConfigSetAdmin => {
+            msg!("Instruction: ConfigSetAdmin");
+            process_config_set_admin(program_id, accounts)
+        }
+        RestakingInstruction::ConfigAcceptAdmin => {
+            msg!("Instruction: ConfigAcceptAdmin");
+            process_config_accept_admin(program_id, accounts)
+        }
+        RestakingInstruction::ConfigSetEpochLength { epoch_length } => {
+            msg!("Instruction: ConfigSetEpochLength");
+            process_config_set_epoch_length(program_id, accounts, epoch_length)
+        }
+        RestakingInstruction::ConfigSetParameters { vault_program } => {
+            msg!("Instruction: ConfigSetParameters");
+            process_config_set_parameters(program_id, accounts, vault_program)
+        }
+        RestakingInstruction::NcnRequestSlash { amount } => {
+            msg!("Instruction: NcnRequestSlash");
+            process_ncn_request_slash(program_id, accounts, amount)
+        }
+        RestakingInstruction::BatchAddOperators => {
+            msg!("Instruction: BatchAddOperators");
+            process_batch_add_operators(program_id, accounts)
+        }
     }
 }
@@ -0,0 +1,56 @@
+use jito_bytemuck::Discriminator;
+use jito_jsm_core::{
+    loader::{load_signer, load_system_program},
+    migrate_account,
+    sysvar::get_rent,
+};
+use jito_restaking_core::{config::Config, ncn::Ncn, operator::Operator};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the migrate account instruction: [`crate::RestakingInstruction::MigrateAccount`]
+///
+/// Specification:
+/// - The account shall be a Config, an Ncn or an Operator owned by the restaking program
+/// - Anyone can migrate an account, the payer covers the rent of any space the current layout adds
+/// - The account shall be grown to fit the current layout, upgraded in place and set to the current
+///   version. Migrating an account that's already at the current version does nothing.
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [account, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if account.owner.ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !account.is_writable {
+        msg!("Account is not writable");
+        return Err(RestakingError::RestakingAccountNotWritable.into());
+    }
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let rent = get_rent()?;
+    let discriminator = account.data.borrow().first().copied();
+    let from_version = match discriminator {
+        Some(Config::DISCRIMINATOR) => migrate_account::<Config>(account, payer, &rent)?,
+        Some(Ncn::DISCRIMINATOR) => migrate_account::<Ncn>(account, payer, &rent)?,
+        Some(Operator::DISCRIMINATOR) => migrate_account::<Operator>(account, payer, &rent)?,
+        _ => {
+            msg!("Account isn't a Config, an Ncn or an Operator");
+            return Err(RestakingError::RestakingAccountDiscriminatorInvalid.into());
+        }
+    };
+
+    msg!(
+        "Migrated account {} from version {}",
+        account.key,
+        from_version
+    );
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN operator admin can close its task window on an operator, unlocking the operator's stake
+/// once no other NCN has a window open on it.
+/// [`crate::RestakingInstruction::NcnCloseTaskWindow`]
+///
+/// Specification:
+/// - The NCN operator admin shall be the signer of the transaction
+/// - The NCN shall have a task window on the operator, windows that have already ended can be closed
+pub fn process_ncn_close_task_window(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [ncn, operator, ncn_operator_state, ncn_operator_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, true)?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
+    load_signer(ncn_operator_admin, false)?;
+
+    // The NCN operator admin shall be the signer of the transaction
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    if ncn.operator_admin.ne(ncn_operator_admin.key) {
+        msg!("Invalid operator admin for NCN");
+        return Err(RestakingError::NcnOperatorAdminInvalid.into());
+    }
+
+    let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
+    let ncn_operator_state =
+        NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
+    ncn_operator_state.close_task_window()?;
+
+    let mut operator_data = operator.data.borrow_mut();
+    let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
+    operator.close_task_window()?;
+
+    msg!(
+        "Task window closed on operator {}",
+        ncn_operator_state.operator
+    );
+
+    Ok(())
+}
@@ -1,12 +1,12 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
 };
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// The NCN admin can remove a node operator from the NCN.
@@ -40,11 +40,11 @@ pub fn process_ncn_cooldown_operator(
     let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
     let ncn_operator_ticket =
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
-    if !ncn_operator_ticket
+    if let Err(e) = ncn_operator_ticket
         .ncn_opt_in_state
-        .deactivate(Clock::get()?.slot, config.epoch_length())
+        .deactivate(get_clock()?.slot, config.epoch_length())
     {
-        msg!("NCN is not ready to deactivate operator");
+        msg!("NCN is not ready to deactivate operator: {}", e);
         return Err(RestakingError::NcnCooldownOperatorFailed.into());
     }
 
@@ -0,0 +1,60 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN slasher admin can freeze an operator it suspects is compromised, stopping vaults from
+/// delegating to it and deferring cooldowns of its delegated stake while the NCN investigates.
+/// [`crate::RestakingInstruction::NcnFreezeOperator`]
+///
+/// Specification:
+/// - The NCN slasher admin shall be the signer of the transaction
+/// - The freeze shall expire after [`NcnOperatorState::FREEZE_EPOCHS`], freezing an operator the NCN
+///   already froze restarts its freeze
+/// - Slashing the operator is still allowed while it's frozen
+pub fn process_ncn_freeze_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, ncn, operator, ncn_operator_state, ncn_slasher_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, true)?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
+    load_signer(ncn_slasher_admin, false)?;
+
+    // The NCN slasher admin shall be the signer of the transaction
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    if ncn.slasher_admin.ne(ncn_slasher_admin.key) {
+        msg!("Invalid slasher admin for NCN");
+        return Err(RestakingError::NcnSlasherAdminInvalid.into());
+    }
+
+    let slot = get_clock()?.slot;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
+    let ncn_operator_state =
+        NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
+
+    let is_new = !ncn_operator_state.has_freeze();
+    let frozen_until_slot = ncn_operator_state.freeze(slot, config.epoch_length())?;
+
+    let mut operator_data = operator.data.borrow_mut();
+    let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
+    operator.freeze(frozen_until_slot, is_new)?;
+
+    msg!(
+        "Operator {} frozen until slot {}",
+        ncn_operator_state.operator,
+        frozen_until_slot
+    );
+
+    Ok(())
+}
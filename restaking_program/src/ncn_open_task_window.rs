@@ -0,0 +1,80 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN operator admin can open a task window on an operator, locking the stake vaults have
+/// delegated to the operator until the window ends or is closed.
+/// [`crate::RestakingInstruction::NcnOpenTaskWindow`]
+///
+/// Specification:
+/// - The NCN operator admin shall be the signer of the transaction
+/// - The NCN and operator shall both be actively opted in to each other and their relationship
+///   shall not have expired
+/// - The window shall end after the current slot and within [`NcnOperatorState::MAX_TASK_WINDOW_EPOCHS`]
+///   of the slot it was first opened
+/// - Opening a window the NCN already has on the operator moves its end slot, at most
+///   [`NcnOperatorState::MAX_TASK_WINDOW_EXTENSIONS`] times
+pub fn process_ncn_open_task_window(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    end_slot: u64,
+) -> ProgramResult {
+    let [config, ncn, operator, ncn_operator_state, ncn_operator_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, true)?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
+    load_signer(ncn_operator_admin, false)?;
+
+    // The NCN operator admin shall be the signer of the transaction
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    if ncn.operator_admin.ne(ncn_operator_admin.key) {
+        msg!("Invalid operator admin for NCN");
+        return Err(RestakingError::NcnOperatorAdminInvalid.into());
+    }
+
+    let slot = get_clock()?.slot;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
+    let ncn_operator_state =
+        NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
+
+    // The NCN shall only lock the stake of an operator it's working with
+    if !ncn_operator_state
+        .ncn_opt_in_state
+        .is_active(slot, config.epoch_length())
+        || !ncn_operator_state
+            .operator_opt_in_state
+            .is_active(slot, config.epoch_length())
+        || ncn_operator_state.is_expired(slot)
+    {
+        msg!("NCN and operator must be opted in to each other to open a task window");
+        return Err(RestakingError::NcnTaskWindowInvalid.into());
+    }
+
+    let is_new = !ncn_operator_state.has_task_window();
+    ncn_operator_state.open_task_window(slot, end_slot, config.epoch_length())?;
+
+    let mut operator_data = operator.data.borrow_mut();
+    let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
+    operator.open_task_window(end_slot, is_new)?;
+
+    msg!(
+        "Task window open on operator {} until slot {}",
+        ncn_operator_state.operator,
+        end_slot
+    );
+
+    Ok(())
+}
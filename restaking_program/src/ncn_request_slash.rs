@@ -0,0 +1,183 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
+    operator::Operator,
+};
+use jito_restaking_sdk::error::RestakingError;
+use jito_vault_core::vault::Vault;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::AccountMeta, msg,
+    program::invoke, program_error::ProgramError, pubkey::Pubkey,
+};
+
+/// The NCN's slasher slashes an operator's stake in a vault through the restaking program.
+/// [`crate::RestakingInstruction::NcnRequestSlash`]
+///
+/// The restaking program checks the NCN's side of the slash before invoking the vault program's
+/// Slash with the accounts after the vault program, so the slasher can't slash an operator or vault
+/// the NCN doesn't have a relationship with. The vault program checks its own side, the amount
+/// slashable and where the slashed funds go. The accounts the vault needs for insurance or epoch
+/// stats are passed through after the token program.
+///
+/// Specification:
+/// - The vault program shall be the config's vault program
+/// - The slasher shall be the signer of the transaction
+/// - The NCN and the operator shall have opted in to each other, active or cooling down, and the
+///   relationship shall not have expired
+/// - The NCN vault ticket shall be active or cooling down and shall not have expired
+/// - The NCN vault slasher ticket shall be the slasher's and shall be active or cooling down
+pub fn process_ncn_request_slash(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    if accounts.len() < 18 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, optional_accounts) = accounts.split_at(18);
+
+    let [config, vault_program, vault_config, vault, ncn, operator, slasher, ncn_operator_state, ncn_vault_ticket, operator_vault_ticket, vault_ncn_ticket, vault_operator_delegation, ncn_vault_slasher_ticket, vault_ncn_slasher_ticket, vault_ncn_slasher_operator_ticket, vault_token_account, slash_destination, token_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_ncn_slashable(
+        program_id,
+        config,
+        vault_program,
+        vault,
+        ncn,
+        operator,
+        slasher,
+        ncn_operator_state,
+        ncn_vault_ticket,
+        ncn_vault_slasher_ticket,
+    )?;
+
+    let mut instruction = jito_vault_sdk::sdk::slash(
+        vault_program.key,
+        vault_config.key,
+        vault.key,
+        ncn.key,
+        operator.key,
+        slasher.key,
+        ncn_operator_state.key,
+        ncn_vault_ticket.key,
+        operator_vault_ticket.key,
+        vault_ncn_ticket.key,
+        vault_operator_delegation.key,
+        ncn_vault_slasher_ticket.key,
+        vault_ncn_slasher_ticket.key,
+        vault_ncn_slasher_operator_ticket.key,
+        vault_token_account.key,
+        slash_destination.key,
+        amount,
+    );
+    // The vault's Slash requires the slasher's signature, which the SDK doesn't mark
+    instruction.accounts[4].is_signer = true;
+    instruction.accounts[15].pubkey = *token_program.key;
+    instruction
+        .accounts
+        .extend(optional_accounts.iter().map(|account| {
+            if account.is_writable {
+                AccountMeta::new(*account.key, account.is_signer)
+            } else {
+                AccountMeta::new_readonly(*account.key, account.is_signer)
+            }
+        }));
+
+    msg!(
+        "NCN {} requesting slash of {} from operator {} in vault {}",
+        ncn.key,
+        amount,
+        operator.key,
+        vault.key
+    );
+    invoke(&instruction, &accounts[1..])
+}
+
+/// Checks the NCN's side of the slash. The account data is only borrowed here so it's released
+/// before the vault program is invoked.
+#[allow(clippy::too_many_arguments)]
+fn check_ncn_slashable(
+    program_id: &Pubkey,
+    config: &AccountInfo,
+    vault_program: &AccountInfo,
+    vault: &AccountInfo,
+    ncn: &AccountInfo,
+    operator: &AccountInfo,
+    slasher: &AccountInfo,
+    ncn_operator_state: &AccountInfo,
+    ncn_vault_ticket: &AccountInfo,
+    ncn_vault_slasher_ticket: &AccountInfo,
+) -> ProgramResult {
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    if config.vault_program.ne(vault_program.key) {
+        msg!("Vault program is not the config's vault program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Vault::load(&config.vault_program, vault, true)?;
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, false)?;
+    load_signer(slasher, false)?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, false)?;
+    NcnVaultTicket::load(program_id, ncn_vault_ticket, ncn, vault, false)?;
+    NcnVaultSlasherTicket::load(
+        program_id,
+        ncn_vault_slasher_ticket,
+        ncn,
+        vault,
+        slasher,
+        false,
+    )?;
+
+    let slot = get_clock()?.slot;
+    let epoch_length = config.epoch_length();
+
+    // The NCN and the operator shall have opted in to each other and the relationship shall not
+    // have expired
+    let ncn_operator_state_data = ncn_operator_state.data.borrow();
+    let ncn_operator_state = NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
+    if !ncn_operator_state
+        .ncn_opt_in_state
+        .is_active_or_cooldown(slot, epoch_length)
+        || !ncn_operator_state
+            .operator_opt_in_state
+            .is_active_or_cooldown(slot, epoch_length)
+        || ncn_operator_state.is_expired(slot)
+    {
+        msg!("Operator is not a member of the NCN");
+        return Err(RestakingError::NcnOperatorStateUnslashable.into());
+    }
+
+    // The NCN vault ticket shall be active or cooling down and shall not have expired
+    let ncn_vault_ticket_data = ncn_vault_ticket.data.borrow();
+    let ncn_vault_ticket = NcnVaultTicket::try_from_slice_unchecked(&ncn_vault_ticket_data)?;
+    if !ncn_vault_ticket
+        .state
+        .is_active_or_cooldown(slot, epoch_length)
+        || ncn_vault_ticket.is_expired(slot)
+    {
+        msg!("NCN vault ticket is not active or in cooldown");
+        return Err(RestakingError::NcnVaultTicketUnslashable.into());
+    }
+
+    // The NCN vault slasher ticket shall be active or cooling down
+    let ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow();
+    let ncn_vault_slasher_ticket =
+        NcnVaultSlasherTicket::try_from_slice_unchecked(&ncn_vault_slasher_ticket_data)?;
+    if !ncn_vault_slasher_ticket
+        .state
+        .is_active_or_cooldown(slot, epoch_length)
+    {
+        msg!("NCN vault slasher ticket is not active or in cooldown");
+        return Err(RestakingError::NcnVaultSlasherTicketUnslashable.into());
+    }
+
+    Ok(())
+}
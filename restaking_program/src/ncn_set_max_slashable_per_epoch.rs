@@ -0,0 +1,70 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+};
+use jito_restaking_sdk::error::RestakingError;
+use jito_vault_core::vault::Vault;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN slasher admin changes the max a slasher can slash from a vault per epoch per operator.
+/// [`crate::RestakingInstruction::NcnSetMaxSlashablePerEpoch`]
+///
+/// Specification:
+/// - The NCN slasher admin shall be the signer of the transaction
+/// - The new max shall take effect in the next epoch, so the vault sees the same max for the whole
+///   epoch
+/// - The vault program caps slashes at the lower of the max the vault agreed to when it added the
+///   slasher and the max in effect on the ticket
+pub fn process_ncn_set_max_slashable_per_epoch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_slashable_per_epoch: u64,
+) -> ProgramResult {
+    let [config, ncn_info, vault, slasher, ncn_vault_slasher_ticket, ncn_slasher_admin] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Ncn::load(program_id, ncn_info, false)?;
+    Vault::load(&config.vault_program, vault, false)?;
+    NcnVaultSlasherTicket::load(
+        program_id,
+        ncn_vault_slasher_ticket,
+        ncn_info,
+        vault,
+        slasher,
+        true,
+    )?;
+    load_signer(ncn_slasher_admin, false)?;
+
+    // The NCN slasher admin shall be the signer of the transaction
+    let ncn_data = ncn_info.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    if ncn.slasher_admin.ne(ncn_slasher_admin.key) {
+        msg!("Admin is not the slasher admin");
+        return Err(RestakingError::NcnSlasherAdminInvalid.into());
+    }
+
+    let epoch = get_clock()?
+        .slot
+        .checked_div(config.epoch_length())
+        .ok_or(RestakingError::SlasherOverflow)?;
+    let mut ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow_mut();
+    let ncn_vault_slasher_ticket =
+        NcnVaultSlasherTicket::try_from_slice_unchecked_mut(&mut ncn_vault_slasher_ticket_data)?;
+    ncn_vault_slasher_ticket.set_max_slashable_per_epoch(max_slashable_per_epoch, epoch)?;
+    msg!(
+        "Max slashable per epoch set to {} from epoch {}",
+        max_slashable_per_epoch,
+        ncn_vault_slasher_ticket.pending_max_slashable_epoch()
+    );
+
+    Ok(())
+}
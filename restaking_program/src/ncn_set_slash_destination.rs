@@ -0,0 +1,67 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+};
+use jito_restaking_sdk::{error::RestakingError, instruction::SlashDestination};
+use jito_vault_core::vault::Vault;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN slasher admin sets where the funds slashed by a slasher from a vault are sent.
+/// [`crate::RestakingInstruction::NcnSetSlashDestination`]
+///
+/// Specification:
+/// - The NCN slasher admin shall be the signer of the transaction
+/// - The insurance wallet shall be passed in when slashed funds are sent to insurance
+/// - The destination is enforced by the vault program when the slash is executed
+pub fn process_ncn_set_slash_destination(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slash_destination: SlashDestination,
+) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(6);
+
+    let [config, ncn_info, vault, slasher, ncn_vault_slasher_ticket, ncn_slasher_admin] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Ncn::load(program_id, ncn_info, false)?;
+    Vault::load(&config.vault_program, vault, false)?;
+    NcnVaultSlasherTicket::load(
+        program_id,
+        ncn_vault_slasher_ticket,
+        ncn_info,
+        vault,
+        slasher,
+        true,
+    )?;
+    load_signer(ncn_slasher_admin, false)?;
+
+    // The NCN slasher admin shall be the signer of the transaction
+    let ncn_data = ncn_info.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    if ncn.slasher_admin.ne(ncn_slasher_admin.key) {
+        msg!("Admin is not the slasher admin");
+        return Err(RestakingError::NcnSlasherAdminInvalid.into());
+    }
+
+    let insurance_wallet = optional_accounts
+        .first()
+        .map(|insurance_wallet| *insurance_wallet.key);
+
+    let mut ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow_mut();
+    let ncn_vault_slasher_ticket =
+        NcnVaultSlasherTicket::try_from_slice_unchecked_mut(&mut ncn_vault_slasher_ticket_data)?;
+    ncn_vault_slasher_ticket.set_slash_destination(slash_destination, insurance_wallet)?;
+    msg!("Slash destination set to {:?}", slash_destination);
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN admin sets how many epochs the NCN's operator and vault relationships last before they
+/// lapse unless renewed.
+/// [`crate::RestakingInstruction::NcnSetTicketExpiry`]
+///
+/// Specification:
+/// - The NCN admin shall be the signer of the transaction
+/// - The expiry shall be zero, which disables it, or at least [`Ncn::MIN_TICKET_EXPIRY_EPOCHS`]
+/// - The expiry applies to relationships created or renewed from then on
+pub fn process_ncn_set_ticket_expiry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    ticket_expiry_epochs: u64,
+) -> ProgramResult {
+    let [ncn, ncn_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(program_id, ncn, true)?;
+    load_signer(ncn_admin, false)?;
+
+    // The NCN admin shall be the signer of the transaction
+    let mut ncn_data = ncn.data.borrow_mut();
+    let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
+    if ncn.admin.ne(ncn_admin.key) {
+        msg!("Invalid admin for NCN");
+        return Err(RestakingError::NcnAdminInvalid.into());
+    }
+
+    ncn.set_ticket_expiry_epochs(ticket_expiry_epochs)?;
+    msg!("Ticket expiry set to {} epochs", ticket_expiry_epochs);
+
+    Ok(())
+}
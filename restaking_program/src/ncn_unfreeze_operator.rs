@@ -0,0 +1,49 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN slasher admin can lift its freeze of an operator, unfreezing the operator once no other
+/// NCN has it frozen.
+/// [`crate::RestakingInstruction::NcnUnfreezeOperator`]
+///
+/// Specification:
+/// - The NCN slasher admin shall be the signer of the transaction
+/// - The NCN shall have frozen the operator, freezes that have already expired can be lifted
+pub fn process_ncn_unfreeze_operator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [ncn, operator, ncn_operator_state, ncn_slasher_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, true)?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
+    load_signer(ncn_slasher_admin, false)?;
+
+    // The NCN slasher admin shall be the signer of the transaction
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    if ncn.slasher_admin.ne(ncn_slasher_admin.key) {
+        msg!("Invalid slasher admin for NCN");
+        return Err(RestakingError::NcnSlasherAdminInvalid.into());
+    }
+
+    let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
+    let ncn_operator_state =
+        NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
+    ncn_operator_state.unfreeze()?;
+
+    let mut operator_data = operator.data.borrow_mut();
+    let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
+    operator.unfreeze()?;
+
+    msg!("Operator {} unfrozen", ncn_operator_state.operator);
+
+    Ok(())
+}
@@ -1,10 +1,13 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_associated_token_account, load_signer, load_token_program};
+use jito_jsm_core::{
+    loader::{load_associated_token_account, load_signer, load_token_program},
+    token::invoke_token,
+};
 use jito_restaking_core::ncn::Ncn;
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 use spl_token::instruction::transfer;
 
@@ -63,7 +66,7 @@ fn _withdraw_ncn_asset<'a, 'info>(
     seeds: &[&[u8]],
     amount: u64,
 ) -> ProgramResult {
-    invoke_signed(
+    invoke_token(
         &transfer(
             &spl_token::id(),
             ncn_token_account.key,
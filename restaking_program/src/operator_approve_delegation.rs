@@ -0,0 +1,55 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{
+    config::Config, operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sdk::error::RestakingError;
+use jito_vault_core::vault::Vault;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The operator vault admin approves how much a vault can have staked with the operator.
+/// [`crate::RestakingInstruction::OperatorApproveDelegation`]
+///
+/// Specification:
+/// - The operator vault admin shall be the signer of the transaction
+/// - The approval replaces any previous one, lowering it doesn't undelegate stake already above it
+/// - The approval is only enforced by the vault program while the operator requires approval
+pub fn process_operator_approve_delegation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    approved_delegation_amount: u64,
+) -> ProgramResult {
+    let [config, operator, vault, operator_vault_ticket, operator_vault_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Operator::load(program_id, operator, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(&config.vault_program, vault, false)?;
+    OperatorVaultTicket::load(program_id, operator_vault_ticket, operator, vault, true)?;
+    load_signer(operator_vault_admin, false)?;
+
+    // The operator vault admin shall be the signer of the transaction
+    let operator_data = operator.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+    if operator.vault_admin.ne(operator_vault_admin.key) {
+        msg!("Invalid vault admin for operator");
+        return Err(RestakingError::OperatorVaultAdminInvalid.into());
+    }
+
+    let mut operator_vault_ticket_data = operator_vault_ticket.data.borrow_mut();
+    let operator_vault_ticket =
+        OperatorVaultTicket::try_from_slice_unchecked_mut(&mut operator_vault_ticket_data)?;
+    operator_vault_ticket.set_approved_delegation_amount(approved_delegation_amount);
+    msg!(
+        "Approved delegation amount set to {}",
+        approved_delegation_amount
+    );
+
+    Ok(())
+}
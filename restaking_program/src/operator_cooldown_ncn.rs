@@ -1,12 +1,12 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
 };
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// [`crate::RestakingInstruction::OperatorCooldownNcn`]
@@ -38,11 +38,11 @@ pub fn process_operator_cooldown_ncn(
     let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
     let ncn_operator_state =
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
-    if !ncn_operator_state
+    if let Err(e) = ncn_operator_state
         .operator_opt_in_state
-        .deactivate(Clock::get()?.slot, config.epoch_length())
+        .deactivate(get_clock()?.slot, config.epoch_length())
     {
-        msg!("Operator is not ready to deactivate NCN");
+        msg!("Operator is not ready to deactivate NCN: {}", e);
         return Err(RestakingError::OperatorCooldownNcnFailed.into());
     }
 
@@ -0,0 +1,50 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_restaking_core::{config::Config, operator::Operator};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The operator admin sets the operator's commission on the restaking rewards distributed by
+/// vaults.
+/// [`crate::RestakingInstruction::OperatorSetCommission`]
+///
+/// Specification:
+/// - The operator admin shall be the signer of the transaction
+/// - The commission shall not exceed [`Operator::MAX_COMMISSION_BPS`]
+/// - The commission shall not move more than [`Operator::MAX_COMMISSION_CHANGE_BPS_PER_EPOCH`]
+///   from where it was at the start of the current epoch
+pub fn process_operator_set_commission(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    commission_bps: u16,
+) -> ProgramResult {
+    let [config, operator, operator_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Operator::load(program_id, operator, true)?;
+    load_signer(operator_admin, false)?;
+
+    // The operator admin shall be the signer of the transaction
+    let mut operator_data = operator.data.borrow_mut();
+    let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
+    if operator.admin.ne(operator_admin.key) {
+        msg!("Invalid operator admin");
+        return Err(RestakingError::OperatorAdminInvalid.into());
+    }
+
+    let epoch = get_clock()?
+        .slot
+        .checked_div(config.epoch_length())
+        .ok_or(RestakingError::OperatorOverflow)?;
+    operator.set_commission_bps(commission_bps, epoch)?;
+    msg!("Commission set to {} bps", commission_bps);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::operator::Operator;
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The operator admin sets whether vaults need the operator's approval to delegate to it.
+/// [`crate::RestakingInstruction::OperatorSetDelegationApprovalRequired`]
+///
+/// Specification:
+/// - The operator admin shall be the signer of the transaction
+/// - While approval is required, the vault program shall not let a vault's stake with the
+///   operator grow past the amount approved on the vault's OperatorVaultTicket
+pub fn process_operator_set_delegation_approval_required(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    delegation_approval_required: bool,
+) -> ProgramResult {
+    let [operator, operator_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Operator::load(program_id, operator, true)?;
+    load_signer(operator_admin, false)?;
+
+    // The operator admin shall be the signer of the transaction
+    let mut operator_data = operator.data.borrow_mut();
+    let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
+    if operator.admin.ne(operator_admin.key) {
+        msg!("Invalid operator admin");
+        return Err(RestakingError::OperatorAdminInvalid.into());
+    }
+
+    operator.set_delegation_approval_required(delegation_approval_required);
+    msg!(
+        "Delegation approval required set to {}",
+        delegation_approval_required
+    );
+
+    Ok(())
+}
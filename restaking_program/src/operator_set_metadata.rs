@@ -0,0 +1,46 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::operator::Operator;
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The operator admin sets the operator's name and website so delegators can discover it
+/// on-chain.
+/// [`crate::RestakingInstruction::OperatorSetMetadata`]
+///
+/// Specification:
+/// - The operator admin shall be the signer of the transaction
+/// - The name shall be at most 32 bytes and the website at most 64 bytes
+pub fn process_operator_set_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: &str,
+    website: &str,
+) -> ProgramResult {
+    let [operator, operator_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Operator::load(program_id, operator, true)?;
+    load_signer(operator_admin, false)?;
+
+    // The operator admin shall be the signer of the transaction
+    let mut operator_data = operator.data.borrow_mut();
+    let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
+    if operator.admin.ne(operator_admin.key) {
+        msg!("Invalid operator admin");
+        return Err(RestakingError::OperatorAdminInvalid.into());
+    }
+
+    operator.set_metadata(name, website)?;
+    msg!(
+        "Operator metadata set to name {:?}, website {:?}",
+        name,
+        website
+    );
+
+    Ok(())
+}
@@ -1,10 +1,13 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_associated_token_account, load_signer};
+use jito_jsm_core::{
+    loader::{load_associated_token_account, load_signer},
+    token::invoke_token,
+};
 use jito_restaking_core::operator::Operator;
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 use spl_token::instruction::transfer;
 
@@ -61,7 +64,7 @@ fn _withdraw_operator_asset<'a, 'info>(
     seeds: &[&[u8]],
     amount: u64,
 ) -> ProgramResult {
-    invoke_signed(
+    invoke_token(
         &transfer(
             &spl_token::id(),
             operator_token_account.key,
@@ -0,0 +1,80 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+};
+use jito_restaking_sdk::error::RestakingError;
+use jito_vault_core::vault::Vault;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN renews its relationship with an operator or a vault, so it doesn't lapse.
+/// [`crate::RestakingInstruction::RenewTicket`]
+///
+/// Specification:
+/// - The ticket shall be an [`NcnOperatorState`] with its operator or an [`NcnVaultTicket`] with its
+///   vault
+/// - The NCN operator admin shall sign to renew an [`NcnOperatorState`] and the NCN vault admin to
+///   renew an [`NcnVaultTicket`]
+/// - The relationship shall expire [`Ncn::ticket_expiry_epochs`] after the current slot, or never
+///   if the NCN disabled expiry, even if it had already lapsed
+pub fn process_renew_ticket(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, ncn, operator_or_vault, ticket, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    load_signer(admin, false)?;
+
+    // The ticket shall be an NcnOperatorState or an NcnVaultTicket of the NCN
+    let ticket_discriminator = ticket.data.borrow().first().copied();
+    let is_ncn_operator_state = match ticket_discriminator {
+        Some(NcnOperatorState::DISCRIMINATOR) => {
+            Operator::load(program_id, operator_or_vault, false)?;
+            NcnOperatorState::load(program_id, ticket, ncn, operator_or_vault, true)?;
+            true
+        }
+        Some(NcnVaultTicket::DISCRIMINATOR) => {
+            Vault::load(&config.vault_program, operator_or_vault, false)?;
+            NcnVaultTicket::load(program_id, ticket, ncn, operator_or_vault, true)?;
+            false
+        }
+        _ => {
+            msg!("Ticket is not an NCN operator state or an NCN vault ticket");
+            return Err(RestakingError::RestakingAccountDiscriminatorInvalid.into());
+        }
+    };
+
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    let expiry_slot = ncn.ticket_expiry_slot(get_clock()?.slot, config.epoch_length())?;
+
+    let mut ticket_data = ticket.data.borrow_mut();
+    if is_ncn_operator_state {
+        // The NCN operator admin shall be the signer of the transaction
+        if ncn.operator_admin.ne(admin.key) {
+            msg!("Invalid operator admin for NCN");
+            return Err(RestakingError::NcnOperatorAdminInvalid.into());
+        }
+        let ncn_operator_state = NcnOperatorState::try_from_slice_unchecked_mut(&mut ticket_data)?;
+        ncn_operator_state.renew(expiry_slot);
+    } else {
+        // The NCN vault admin shall be the signer of the transaction
+        if ncn.vault_admin.ne(admin.key) {
+            msg!("Invalid vault admin for NCN");
+            return Err(RestakingError::NcnVaultAdminInvalid.into());
+        }
+        let ncn_vault_ticket = NcnVaultTicket::try_from_slice_unchecked_mut(&mut ticket_data)?;
+        ncn_vault_ticket.renew(expiry_slot);
+    }
+
+    msg!("Ticket {} renewed until slot {}", ticket.key, expiry_slot);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
     ncn_vault_ticket::NcnVaultTicket,
@@ -7,8 +7,8 @@ use jito_restaking_core::{
 use jito_restaking_sdk::error::RestakingError;
 use jito_vault_core::vault::Vault;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// [`crate::RestakingInstruction::WarmupNcnVaultSlasherTicket`]
@@ -48,11 +48,11 @@ pub fn process_warmup_ncn_vault_slasher_ticket(
     let mut ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow_mut();
     let ncn_vault_slasher_ticket =
         NcnVaultSlasherTicket::try_from_slice_unchecked_mut(&mut ncn_vault_slasher_ticket_data)?;
-    if !ncn_vault_slasher_ticket
+    if let Err(e) = ncn_vault_slasher_ticket
         .state
-        .activate(Clock::get()?.slot, config.epoch_length())
+        .activate(get_clock()?.slot, config.epoch_length())
     {
-        msg!("Slasher is not ready to be activated");
+        msg!("Slasher is not ready to be activated: {}", e);
         return Err(RestakingError::NcnVaultSlasherTicketFailedWarmup.into());
     }
 
@@ -1,13 +1,13 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
 use jito_restaking_core::{
     config::Config, operator::Operator, operator_vault_ticket::OperatorVaultTicket,
 };
 use jito_restaking_sdk::error::RestakingError;
 use jito_vault_core::vault::Vault;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// [`crate::RestakingInstruction::WarmupOperatorVaultTicket`]
@@ -38,11 +38,11 @@ pub fn process_warmup_operator_vault_ticket(
     let mut operator_vault_ticket_data = operator_vault_ticket.data.borrow_mut();
     let operator_vault_ticket =
         OperatorVaultTicket::try_from_slice_unchecked_mut(&mut operator_vault_ticket_data)?;
-    if !operator_vault_ticket
+    if let Err(e) = operator_vault_ticket
         .state
-        .activate(Clock::get()?.slot, config.epoch_length())
+        .activate(get_clock()?.slot, config.epoch_length())
     {
-        msg!("Operator is not ready to be activated");
+        msg!("Operator is not ready to be activated: {}", e);
         return Err(RestakingError::OperatorVaultTicketFailedWarmup.into());
     }
 
@@ -1,7 +1,9 @@
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive as _;
 use solana_program::{decode_error::DecodeError, program_error::ProgramError};
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+#[derive(Debug, Error, PartialEq, Eq, FromPrimitive)]
 pub enum RestakingError {
     #[error("NcnOperatorAdminInvalid")]
     NcnOperatorAdminInvalid = 1000,
@@ -25,6 +27,26 @@ pub enum RestakingError {
     NcnVaultSlasherTicketFailedWarmup,
     #[error("NcnVaultTicketFailedWarmup")]
     NcnVaultTicketFailedWarmup,
+    #[error("NcnTaskWindowInvalid")]
+    NcnTaskWindowInvalid,
+    #[error("NcnTaskWindowNotOpen")]
+    NcnTaskWindowNotOpen,
+    #[error("NcnOperatorNotFrozen")]
+    NcnOperatorNotFrozen,
+    #[error("NcnTicketExpiryInvalid")]
+    NcnTicketExpiryInvalid,
+    #[error("NcnInsuranceWalletMissing")]
+    NcnInsuranceWalletMissing,
+    #[error("NcnOperatorStateUnslashable")]
+    NcnOperatorStateUnslashable,
+    #[error("NcnVaultTicketUnslashable")]
+    NcnVaultTicketUnslashable,
+    #[error("NcnVaultSlasherTicketUnslashable")]
+    NcnVaultSlasherTicketUnslashable,
+    #[error("NcnOperatorBatchInvalid")]
+    NcnOperatorBatchInvalid,
+    #[error("NcnTaskWindowExtensionsExceeded")]
+    NcnTaskWindowExtensionsExceeded,
 
     #[error("OperatorNcnAdminInvalid")]
     OperatorNcnAdminInvalid = 2000,
@@ -50,6 +72,37 @@ pub enum RestakingError {
     VaultOverflow,
     #[error("SlasherOverflow")]
     SlasherOverflow,
+    #[error("OperatorCommissionInvalid")]
+    OperatorCommissionInvalid,
+    #[error("OperatorCommissionChangeTooLarge")]
+    OperatorCommissionChangeTooLarge,
+    #[error("OperatorMetadataInvalid")]
+    OperatorMetadataInvalid,
+
+    #[error("RestakingAccountDataEmpty")]
+    RestakingAccountDataEmpty = 3000,
+    #[error("RestakingAccountNotWritable")]
+    RestakingAccountNotWritable,
+    #[error("RestakingAccountDiscriminatorInvalid")]
+    RestakingAccountDiscriminatorInvalid,
+    #[error("RestakingAccountNotCanonicalPda")]
+    RestakingAccountNotCanonicalPda,
+    #[error("RestakingAccountVersionInvalid")]
+    RestakingAccountVersionInvalid,
+
+    #[error("ConfigAdminInvalid")]
+    ConfigAdminInvalid = 4000,
+    #[error("ConfigPendingAdminInvalid")]
+    ConfigPendingAdminInvalid,
+    #[error("ConfigEpochLengthInvalid")]
+    ConfigEpochLengthInvalid,
+}
+
+impl RestakingError {
+    /// Decodes the error from a [`ProgramError::Custom`] code returned by the restaking program
+    pub fn from_code(code: u32) -> Option<Self> {
+        Self::from_u32(code)
+    }
 }
 
 impl<T> DecodeError<T> for RestakingError {
@@ -69,3 +122,21 @@ impl From<RestakingError> for u64 {
         e as Self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(
+            RestakingError::from_code(2000),
+            Some(RestakingError::OperatorNcnAdminInvalid)
+        );
+        assert_eq!(
+            RestakingError::from_code(3003),
+            Some(RestakingError::RestakingAccountNotCanonicalPda)
+        );
+        assert_eq!(RestakingError::from_code(1999), None);
+    }
+}
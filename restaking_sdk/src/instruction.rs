@@ -1,6 +1,10 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankInstruction;
-use solana_program::pubkey::Pubkey;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+/// The most operators [`RestakingInstruction::BatchAddOperators`] adds at once, so the transaction
+/// stays within the size limit without address lookup tables
+pub const MAX_BATCH_ADD_OPERATORS: usize = 10;
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction)]
 pub enum RestakingInstruction {
@@ -184,6 +188,208 @@ pub enum RestakingInstruction {
     #[account(3, writable, name = "receiver_token_account")]
     #[account(4, name = "token_program")]
     OperatorWithdrawalAsset { token_mint: Pubkey, amount: u64 },
+
+    /// The NCN locks an operator's delegated stake until the end slot, deferring vault cooldowns
+    /// of stake delegated to the operator until the window closes
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "operator")]
+    #[account(3, writable, name = "ncn_operator_state")]
+    #[account(4, signer, name = "admin")]
+    NcnOpenTaskWindow { end_slot: u64 },
+
+    /// The NCN closes its task window on an operator
+    #[account(0, name = "ncn")]
+    #[account(1, writable, name = "operator")]
+    #[account(2, writable, name = "ncn_operator_state")]
+    #[account(3, signer, name = "admin")]
+    NcnCloseTaskWindow,
+
+    /// The NCN freezes an operator it suspects is compromised, stopping new delegations to the
+    /// operator and deferring vault cooldowns of its stake until the freeze is lifted or expires
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "operator")]
+    #[account(3, writable, name = "ncn_operator_state")]
+    #[account(4, signer, name = "admin")]
+    NcnFreezeOperator,
+
+    /// The NCN lifts its freeze of an operator
+    #[account(0, name = "ncn")]
+    #[account(1, writable, name = "operator")]
+    #[account(2, writable, name = "ncn_operator_state")]
+    #[account(3, signer, name = "admin")]
+    NcnUnfreezeOperator,
+
+    /// The NCN admin sets how many epochs its operator and vault relationships last before they
+    /// lapse unless renewed, zero to never expire
+    #[account(0, writable, name = "ncn")]
+    #[account(1, signer, name = "admin")]
+    NcnSetTicketExpiry { ticket_expiry_epochs: u64 },
+
+    /// The NCN renews its relationship with an operator or a vault before it lapses
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator_or_vault")]
+    #[account(3, writable, name = "ticket")]
+    #[account(4, signer, name = "admin")]
+    RenewTicket,
+
+    /// Sets the operator's commission on the restaking rewards distributed by vaults
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "operator")]
+    #[account(2, signer, name = "admin")]
+    OperatorSetCommission { commission_bps: u16 },
+
+    /// The NCN slasher admin sets where the funds slashed by a slasher from a vault are sent
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "vault")]
+    #[account(3, name = "slasher")]
+    #[account(4, writable, name = "ncn_vault_slasher_ticket")]
+    #[account(5, signer, name = "admin")]
+    #[account(6, optional, name = "insurance_wallet")]
+    NcnSetSlashDestination { slash_destination: SlashDestination },
+
+    /// Upgrades a Config, Ncn or Operator account to the current layout in place
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
+
+    /// Sets the operator's name and website so delegators can discover it on-chain
+    #[account(0, writable, name = "operator")]
+    #[account(1, signer, name = "admin")]
+    OperatorSetMetadata { name: String, website: String },
+
+    /// The NCN slasher admin changes the max a slasher can slash from a vault per epoch per
+    /// operator, taking effect in the next epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "vault")]
+    #[account(3, name = "slasher")]
+    #[account(4, writable, name = "ncn_vault_slasher_ticket")]
+    #[account(5, signer, name = "admin")]
+    NcnSetMaxSlashablePerEpoch { max_slashable_per_epoch: u64 },
+
+    /// Sets whether vaults need the operator's approval to delegate to it
+    #[account(0, writable, name = "operator")]
+    #[account(1, signer, name = "admin")]
+    OperatorSetDelegationApprovalRequired { delegation_approval_required: bool },
+
+    /// The operator vault admin approves how much a vault can have staked with the operator
+    #[account(0, name = "config")]
+    #[account(1, name = "operator")]
+    #[account(2, name = "vault")]
+    #[account(3, writable, name = "operator_vault_ticket")]
+    #[account(4, signer, name = "admin")]
+    OperatorApproveDelegation { approved_delegation_amount: u64 },
+
+    /// The config admin proposes a new config admin, who has to accept with ConfigAcceptAdmin
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "new_admin")]
+    ConfigSetAdmin,
+
+    /// The proposed config admin accepts and becomes the config admin
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "new_admin")]
+    ConfigAcceptAdmin,
+
+    /// The config admin changes the length of an epoch in slots
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    ConfigSetEpochLength { epoch_length: u64 },
+
+    /// The config admin changes the config's parameters, the ones left out are unchanged
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    ConfigSetParameters { vault_program: Option<Pubkey> },
+
+    /// The NCN's slasher slashes an operator's stake in a vault, after the restaking program checks
+    /// the NCN's side of the relationships and invokes the vault program's Slash
+    #[account(0, name = "config")]
+    #[account(1, name = "vault_program")]
+    #[account(2, name = "vault_config")]
+    #[account(3, writable, name = "vault")]
+    #[account(4, name = "ncn")]
+    #[account(5, name = "operator")]
+    #[account(6, signer, name = "slasher")]
+    #[account(7, name = "ncn_operator_state")]
+    #[account(8, name = "ncn_vault_ticket")]
+    #[account(9, name = "operator_vault_ticket")]
+    #[account(10, name = "vault_ncn_ticket")]
+    #[account(11, writable, name = "vault_operator_delegation")]
+    #[account(12, name = "ncn_vault_slasher_ticket")]
+    #[account(13, name = "vault_ncn_slasher_ticket")]
+    #[account(14, writable, name = "vault_ncn_slasher_operator_ticket")]
+    #[account(15, writable, name = "vault_token_account")]
+    #[account(16, writable, name = "slash_destination")]
+    #[account(17, name = "token_program")]
+    NcnRequestSlash { amount: u64 },
+
+    /// The NCN operator admin adds up to [`MAX_BATCH_ADD_OPERATORS`] operators to the NCN, each
+    /// operator followed by its NcnOperatorState after the system program
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn")]
+    #[account(2, signer, name = "admin")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    BatchAddOperators,
+}
+
+impl RestakingInstruction {
+    /// The instruction's wire discriminator, the first byte of its serialized data.
+    ///
+    /// Borsh encodes the variant index, so instructions must only ever be appended to the enum.
+    /// The values are spelled out here and locked by tests so reordering or inserting a variant
+    /// can't silently change the encoding of an existing instruction.
+    pub const fn discriminator(&self) -> u8 {
+        match self {
+            Self::InitializeConfig => 0,
+            Self::InitializeNcn => 1,
+            Self::InitializeOperator => 2,
+            Self::InitializeNcnVaultSlasherTicket(..) => 3,
+            Self::InitializeNcnVaultTicket => 4,
+            Self::InitializeOperatorVaultTicket => 5,
+            Self::InitializeNcnOperatorState => 6,
+            Self::WarmupNcnVaultTicket => 7,
+            Self::CooldownNcnVaultTicket => 8,
+            Self::NcnWarmupOperator => 9,
+            Self::NcnCooldownOperator => 10,
+            Self::OperatorWarmupNcn => 11,
+            Self::OperatorCooldownNcn => 12,
+            Self::WarmupNcnVaultSlasherTicket => 13,
+            Self::CooldownNcnVaultSlasherTicket => 14,
+            Self::WarmupOperatorVaultTicket => 15,
+            Self::CooldownOperatorVaultTicket => 16,
+            Self::NcnSetAdmin => 17,
+            Self::NcnSetSecondaryAdmin(..) => 18,
+            Self::OperatorSetAdmin => 19,
+            Self::OperatorSetSecondaryAdmin(..) => 20,
+            Self::NcnWithdrawalAsset { .. } => 21,
+            Self::OperatorWithdrawalAsset { .. } => 22,
+            Self::NcnOpenTaskWindow { .. } => 23,
+            Self::NcnCloseTaskWindow => 24,
+            Self::NcnFreezeOperator => 25,
+            Self::NcnUnfreezeOperator => 26,
+            Self::NcnSetTicketExpiry { .. } => 27,
+            Self::RenewTicket => 28,
+            Self::OperatorSetCommission { .. } => 29,
+            Self::NcnSetSlashDestination { .. } => 30,
+            Self::MigrateAccount => 31,
+            Self::OperatorSetMetadata { .. } => 32,
+            Self::NcnSetMaxSlashablePerEpoch { .. } => 33,
+            Self::OperatorSetDelegationApprovalRequired { .. } => 34,
+            Self::OperatorApproveDelegation { .. } => 35,
+            Self::ConfigSetAdmin => 36,
+            Self::ConfigAcceptAdmin => 37,
+            Self::ConfigSetEpochLength { .. } => 38,
+            Self::ConfigSetParameters { .. } => 39,
+            Self::NcnRequestSlash { .. } => 40,
+            Self::BatchAddOperators => 41,
+        }
+    }
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
@@ -203,3 +409,178 @@ pub enum OperatorAdminRole {
     WithdrawAdmin,
     WithdrawWallet,
 }
+
+/// Where the funds slashed by a slasher from a vault are sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[repr(u8)]
+pub enum SlashDestination {
+    /// The slasher's token account
+    Slasher,
+    /// Burned, reducing the supply of the vault's supported mint
+    Burn,
+    /// The token account of an insurance wallet controlled by the NCN
+    Insurance,
+    /// The token account of the vault program's protocol fee wallet
+    Treasury,
+}
+
+impl TryFrom<u8> for SlashDestination {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Slasher),
+            1 => Ok(Self::Burn),
+            2 => Ok(Self::Insurance),
+            3 => Ok(Self::Treasury),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every instruction with its wire discriminator. Existing values must never change.
+    fn instructions() -> Vec<(RestakingInstruction, u8)> {
+        vec![
+            (RestakingInstruction::InitializeConfig, 0),
+            (RestakingInstruction::InitializeNcn, 1),
+            (RestakingInstruction::InitializeOperator, 2),
+            (RestakingInstruction::InitializeNcnVaultSlasherTicket(1), 3),
+            (RestakingInstruction::InitializeNcnVaultTicket, 4),
+            (RestakingInstruction::InitializeOperatorVaultTicket, 5),
+            (RestakingInstruction::InitializeNcnOperatorState, 6),
+            (RestakingInstruction::WarmupNcnVaultTicket, 7),
+            (RestakingInstruction::CooldownNcnVaultTicket, 8),
+            (RestakingInstruction::NcnWarmupOperator, 9),
+            (RestakingInstruction::NcnCooldownOperator, 10),
+            (RestakingInstruction::OperatorWarmupNcn, 11),
+            (RestakingInstruction::OperatorCooldownNcn, 12),
+            (RestakingInstruction::WarmupNcnVaultSlasherTicket, 13),
+            (RestakingInstruction::CooldownNcnVaultSlasherTicket, 14),
+            (RestakingInstruction::WarmupOperatorVaultTicket, 15),
+            (RestakingInstruction::CooldownOperatorVaultTicket, 16),
+            (RestakingInstruction::NcnSetAdmin, 17),
+            (
+                RestakingInstruction::NcnSetSecondaryAdmin(NcnAdminRole::Operator),
+                18,
+            ),
+            (RestakingInstruction::OperatorSetAdmin, 19),
+            (
+                RestakingInstruction::OperatorSetSecondaryAdmin(OperatorAdminRole::NcnAdmin),
+                20,
+            ),
+            (
+                RestakingInstruction::NcnWithdrawalAsset {
+                    token_mint: Pubkey::new_unique(),
+                    amount: 1,
+                },
+                21,
+            ),
+            (
+                RestakingInstruction::OperatorWithdrawalAsset {
+                    token_mint: Pubkey::new_unique(),
+                    amount: 1,
+                },
+                22,
+            ),
+            (RestakingInstruction::NcnOpenTaskWindow { end_slot: 1 }, 23),
+            (RestakingInstruction::NcnCloseTaskWindow, 24),
+            (RestakingInstruction::NcnFreezeOperator, 25),
+            (RestakingInstruction::NcnUnfreezeOperator, 26),
+            (
+                RestakingInstruction::NcnSetTicketExpiry {
+                    ticket_expiry_epochs: 3,
+                },
+                27,
+            ),
+            (RestakingInstruction::RenewTicket, 28),
+            (
+                RestakingInstruction::OperatorSetCommission { commission_bps: 1 },
+                29,
+            ),
+            (
+                RestakingInstruction::NcnSetSlashDestination {
+                    slash_destination: SlashDestination::Burn,
+                },
+                30,
+            ),
+            (RestakingInstruction::MigrateAccount, 31),
+            (
+                RestakingInstruction::OperatorSetMetadata {
+                    name: String::new(),
+                    website: String::new(),
+                },
+                32,
+            ),
+            (
+                RestakingInstruction::NcnSetMaxSlashablePerEpoch {
+                    max_slashable_per_epoch: 1,
+                },
+                33,
+            ),
+            (
+                RestakingInstruction::OperatorSetDelegationApprovalRequired {
+                    delegation_approval_required: true,
+                },
+                34,
+            ),
+            (
+                RestakingInstruction::OperatorApproveDelegation {
+                    approved_delegation_amount: 1,
+                },
+                35,
+            ),
+            (RestakingInstruction::ConfigSetAdmin, 36),
+            (RestakingInstruction::ConfigAcceptAdmin, 37),
+            (
+                RestakingInstruction::ConfigSetEpochLength { epoch_length: 1 },
+                38,
+            ),
+            (
+                RestakingInstruction::ConfigSetParameters {
+                    vault_program: Some(Pubkey::new_unique()),
+                },
+                39,
+            ),
+            (RestakingInstruction::NcnRequestSlash { amount: 1 }, 40),
+            (RestakingInstruction::BatchAddOperators, 41),
+        ]
+    }
+
+    #[test]
+    fn test_instruction_discriminators_are_stable() {
+        for (instruction, discriminator) in instructions() {
+            assert_eq!(
+                instruction.discriminator(),
+                discriminator,
+                "{:?}",
+                instruction
+            );
+            assert_eq!(
+                instruction.try_to_vec().unwrap()[0],
+                discriminator,
+                "{:?}",
+                instruction
+            );
+        }
+    }
+
+    #[test]
+    fn test_instruction_discriminators_round_trip() {
+        for (instruction, discriminator) in instructions() {
+            let data = instruction.try_to_vec().unwrap();
+            let decoded = RestakingInstruction::try_from_slice(&data).unwrap();
+            assert_eq!(decoded.discriminator(), discriminator);
+        }
+    }
+
+    #[test]
+    fn test_instruction_discriminators_are_contiguous() {
+        for (i, (_, discriminator)) in instructions().into_iter().enumerate() {
+            assert_eq!(discriminator as usize, i);
+        }
+    }
+}
@@ -1,3 +1,4 @@
 pub mod error;
 pub mod instruction;
+pub mod pda;
 pub mod sdk;
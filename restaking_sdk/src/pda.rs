@@ -0,0 +1,72 @@
+//! Program derived addresses of the restaking program's accounts
+//!
+//! Clients that only depend on the SDK can derive the accounts an instruction needs without
+//! re-implementing the seeds. Each function returns the address and its bump seed, the same as
+//! the account's `find_program_address` in `jito-restaking-core`.
+
+use solana_program::pubkey::Pubkey;
+
+/// The global restaking program configuration
+pub fn derive_config(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], program_id)
+}
+
+/// The NCN created with `base`
+pub fn derive_ncn(program_id: &Pubkey, base: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"ncn", base.as_ref()], program_id)
+}
+
+/// The operator created with `base`
+pub fn derive_operator(program_id: &Pubkey, base: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"operator", base.as_ref()], program_id)
+}
+
+/// The NCN and operator's opt-ins to each other
+pub fn derive_ncn_operator_state(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"ncn_operator_state", ncn.as_ref(), operator.as_ref()],
+        program_id,
+    )
+}
+
+/// The NCN's opt-in to the vault
+pub fn derive_ncn_vault_ticket(program_id: &Pubkey, ncn: &Pubkey, vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"ncn_vault_ticket", ncn.as_ref(), vault.as_ref()],
+        program_id,
+    )
+}
+
+/// The operator's opt-in to the vault
+pub fn derive_operator_vault_ticket(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    vault: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"operator_vault_ticket", operator.as_ref(), vault.as_ref()],
+        program_id,
+    )
+}
+
+/// The NCN's slasher for the vault
+pub fn derive_ncn_vault_slasher_ticket(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    vault: &Pubkey,
+    slasher: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ncn_slasher_ticket",
+            ncn.as_ref(),
+            vault.as_ref(),
+            slasher.as_ref(),
+        ],
+        program_id,
+    )
+}
@@ -5,7 +5,10 @@ use solana_program::{
     system_program,
 };
 
-use crate::instruction::{NcnAdminRole, OperatorAdminRole, RestakingInstruction};
+use crate::instruction::{
+    NcnAdminRole, OperatorAdminRole, RestakingInstruction, SlashDestination,
+    MAX_BATCH_ADD_OPERATORS,
+};
 
 pub fn initialize_config(
     program_id: &Pubkey,
@@ -210,6 +213,68 @@ pub fn cooldown_ncn_vault_slasher_ticket(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn ncn_set_slash_destination(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    vault: &Pubkey,
+    slasher: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    admin: &Pubkey,
+    insurance_wallet: Option<&Pubkey>,
+    slash_destination: SlashDestination,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*ncn_vault_slasher_ticket, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    if let Some(insurance_wallet) = insurance_wallet {
+        accounts.push(AccountMeta::new_readonly(*insurance_wallet, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetSlashDestination { slash_destination }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ncn_set_max_slashable_per_epoch(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    vault: &Pubkey,
+    slasher: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    admin: &Pubkey,
+    max_slashable_per_epoch: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*ncn_vault_slasher_ticket, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetMaxSlashablePerEpoch {
+            max_slashable_per_epoch,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
 pub fn ncn_set_admin(
     program_id: &Pubkey,
     ncn: &Pubkey,
@@ -290,6 +355,27 @@ pub fn operator_set_admin(
     }
 }
 
+pub fn operator_set_commission(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    admin: &Pubkey,
+    commission_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*operator, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorSetCommission { commission_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
 pub fn operator_set_secondary_admin(
     program_id: &Pubkey,
     operator: &Pubkey,
@@ -512,6 +598,142 @@ pub fn ncn_cooldown_operator(
     }
 }
 
+pub fn ncn_open_task_window(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    admin: &Pubkey,
+    end_slot: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*operator, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnOpenTaskWindow { end_slot }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn ncn_close_task_window(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*operator, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnCloseTaskWindow
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn ncn_freeze_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*operator, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnFreezeOperator
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn ncn_unfreeze_operator(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*operator, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnUnfreezeOperator
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn ncn_set_ticket_expiry(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    admin: &Pubkey,
+    ticket_expiry_epochs: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*ncn, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetTicketExpiry {
+            ticket_expiry_epochs,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn renew_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator_or_vault: &Pubkey,
+    ticket: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator_or_vault, false),
+        AccountMeta::new(*ticket, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::RenewTicket.try_to_vec().unwrap(),
+    }
+}
+
 pub fn warmup_ncn_vault_ticket(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -588,3 +810,249 @@ pub fn warmup_ncn_vault_slasher_ticket(
             .unwrap(),
     }
 }
+
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::MigrateAccount.try_to_vec().unwrap(),
+    }
+}
+
+pub fn operator_set_metadata(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    admin: &Pubkey,
+    name: String,
+    website: String,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*operator, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorSetMetadata { name, website }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn operator_set_delegation_approval_required(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    admin: &Pubkey,
+    delegation_approval_required: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*operator, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorSetDelegationApprovalRequired {
+            delegation_approval_required,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn operator_approve_delegation(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    operator_vault_ticket: &Pubkey,
+    admin: &Pubkey,
+    approved_delegation_amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*operator_vault_ticket, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorApproveDelegation {
+            approved_delegation_amount,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn config_set_admin(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*new_admin, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigSetAdmin.try_to_vec().unwrap(),
+    }
+}
+
+pub fn config_accept_admin(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*new_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigAcceptAdmin
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn config_set_epoch_length(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    epoch_length: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigSetEpochLength { epoch_length }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn config_set_parameters(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    vault_program: Option<Pubkey>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::ConfigSetParameters { vault_program }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ncn_request_slash(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault_program: &Pubkey,
+    vault_config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    ncn_vault_ticket: &Pubkey,
+    operator_vault_ticket: &Pubkey,
+    vault_ncn_ticket: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    vault_ncn_slasher_ticket: &Pubkey,
+    vault_ncn_slasher_operator_ticket: &Pubkey,
+    vault_token_account: &Pubkey,
+    slash_destination: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault_program, false),
+        AccountMeta::new_readonly(*vault_config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, true),
+        AccountMeta::new_readonly(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*ncn_vault_ticket, false),
+        AccountMeta::new_readonly(*operator_vault_ticket, false),
+        AccountMeta::new_readonly(*vault_ncn_ticket, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false),
+        AccountMeta::new_readonly(*vault_ncn_slasher_ticket, false),
+        AccountMeta::new(*vault_ncn_slasher_operator_ticket, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*slash_destination, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnRequestSlash { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Adds the operators to the NCN with as many BatchAddOperators instructions as needed, each
+/// adding up to [`MAX_BATCH_ADD_OPERATORS`] operators. Each instruction is meant to be sent in its
+/// own transaction.
+///
+/// # Arguments
+/// * `operators` - The operators and their NcnOperatorState accounts
+pub fn batch_add_operators(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+    operators: &[(Pubkey, Pubkey)],
+) -> Vec<Instruction> {
+    operators
+        .chunks(MAX_BATCH_ADD_OPERATORS)
+        .map(|chunk| {
+            let mut accounts = vec![
+                AccountMeta::new_readonly(*config, false),
+                AccountMeta::new(*ncn, false),
+                AccountMeta::new_readonly(*admin, true),
+                AccountMeta::new(*payer, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ];
+            for (operator, ncn_operator_state) in chunk {
+                accounts.push(AccountMeta::new(*operator, false));
+                accounts.push(AccountMeta::new(*ncn_operator_state, false));
+            }
+            Instruction {
+                program_id: *program_id,
+                accounts,
+                data: RestakingInstruction::BatchAddOperators
+                    .try_to_vec()
+                    .unwrap(),
+            }
+        })
+        .collect()
+}
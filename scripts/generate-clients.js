@@ -1,5 +1,6 @@
 const kinobi = require("kinobi");
 const anchorIdl = require("@kinobi-so/nodes-from-anchor");
+const fs = require("fs");
 const path = require("path");
 const renderers = require('@kinobi-so/renderers');
 
@@ -7,6 +8,113 @@ const renderers = require('@kinobi-so/renderers');
 const projectRoot = path.join(__dirname, "..");
 
 const idlDir = path.join(projectRoot, "idl");
+const kinobiIdlDir = path.join(idlDir, "kinobi");
+
+// Well-known programs that instructions default to when the account is not provided.
+const programDefaults = {
+    systemProgram: kinobi.publicKeyValueNode("11111111111111111111111111111111", "systemProgram"),
+    tokenProgram: kinobi.publicKeyValueNode("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", "tokenProgram"),
+    mplTokenMetadataProgram: kinobi.publicKeyValueNode("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s", "mplTokenMetadataProgram"),
+};
+
+// Seed helpers, mirroring the `seeds` functions on each account in the core crates.
+const prefix = (value) => kinobi.constantPdaSeedNodeFromString("utf8", value);
+const pubkeySeed = (name) => kinobi.variablePdaSeedNode(name, kinobi.publicKeyTypeNode());
+const u64Seed = (name) => kinobi.variablePdaSeedNode(name, kinobi.numberTypeNode("u64"));
+
+// Declares a PDA for each account and links the account to it.
+const pdasVisitor = (pdas) => kinobi.updateAccountsVisitor(
+    Object.fromEntries(Object.entries(pdas).map(([account, seeds]) => [account, {seeds}]))
+);
+
+// Defaults instruction accounts to their PDA when every variable seed can be resolved from another
+// account or an argument of the same instruction, and well-known programs to their address.
+// Accounts used as a seed of another PDA in the instruction are left to the caller, since the
+// shared seed (e.g. `base`) belongs to that other account.
+// `aliases` maps instruction account names that differ from the account they hold.
+const instructionDefaultsVisitor = (pdas, aliases = {}) => kinobi.bottomUpTransformerVisitor([
+    {
+        select: (node) => kinobi.isNode(node, "instructionNode"),
+        transform: (node) => {
+            kinobi.assertIsNode(node, "instructionNode");
+            const accountNames = node.accounts.map((account) => account.name);
+            const argumentNames = node.arguments.map((argument) => argument.name);
+            const seedNames = accountNames
+                .map((name) => pdas[aliases[name] ?? name] ?? [])
+                .flat()
+                .map((seed) => seed.name);
+            const seedValue = (seed) => {
+                if (accountNames.includes(seed.name)) {
+                    return kinobi.pdaSeedValueNode(seed.name, kinobi.accountValueNode(seed.name));
+                }
+                if (argumentNames.includes(seed.name)) {
+                    return kinobi.pdaSeedValueNode(seed.name, kinobi.argumentValueNode(seed.name));
+                }
+                return null;
+            };
+            const defaultValue = (name) => {
+                if (programDefaults[name]) {
+                    return programDefaults[name];
+                }
+                const pda = aliases[name] ?? name;
+                if (!pdas[pda] || seedNames.includes(name)) {
+                    return undefined;
+                }
+                const seeds = pdas[pda]
+                    .filter((seed) => kinobi.isNode(seed, "variablePdaSeedNode"))
+                    .map(seedValue);
+                if (seeds.includes(null)) {
+                    return undefined;
+                }
+                return kinobi.pdaValueNode(pda, seeds);
+            };
+            return {
+                ...node,
+                accounts: node.accounts.map((account) => ({
+                    ...account,
+                    defaultValue: account.defaultValue ?? defaultValue(account.name),
+                })),
+            };
+        },
+    },
+]);
+
+// Writes the Kinobi tree so other client generators can consume it without re-deriving PDAs.
+const writeKinobiIdl = (kinobiInstance, name) => {
+    fs.mkdirSync(kinobiIdlDir, {recursive: true});
+    fs.writeFileSync(path.join(kinobiIdlDir, name), kinobiInstance.getJson());
+};
+
+const restakingPdas = {
+    config: [prefix("config")],
+    ncn: [prefix("ncn"), pubkeySeed("base")],
+    operator: [prefix("operator"), pubkeySeed("base")],
+    ncnOperatorState: [prefix("ncn_operator_state"), pubkeySeed("ncn"), pubkeySeed("operator")],
+    ncnVaultTicket: [prefix("ncn_vault_ticket"), pubkeySeed("ncn"), pubkeySeed("vault")],
+    ncnVaultSlasherTicket: [prefix("ncn_slasher_ticket"), pubkeySeed("ncn"), pubkeySeed("vault"), pubkeySeed("slasher")],
+    operatorVaultTicket: [prefix("operator_vault_ticket"), pubkeySeed("operator"), pubkeySeed("vault")],
+};
+
+const vaultPdas = {
+    config: [prefix("config")],
+    vault: [prefix("vault"), pubkeySeed("base")],
+    vaultNcnTicket: [prefix("vault_ncn_ticket"), pubkeySeed("vault"), pubkeySeed("ncn")],
+    vaultOperatorDelegation: [prefix("vault_operator_delegation"), pubkeySeed("vault"), pubkeySeed("operator")],
+    vaultNcnSlasherTicket: [prefix("vault_slasher_ticket"), pubkeySeed("vault"), pubkeySeed("ncn"), pubkeySeed("slasher")],
+    vaultNcnSlasherOperatorTicket: [prefix("vault_ncn_slasher_operator"), pubkeySeed("vault"), pubkeySeed("ncn"), pubkeySeed("slasher"), pubkeySeed("operator"), u64Seed("epoch")],
+    vaultStakerWithdrawalTicket: [prefix("vault_staker_withdrawal_ticket"), pubkeySeed("vault"), pubkeySeed("base")],
+    vaultUpdateStateTracker: [prefix("vault_update_state_tracker"), pubkeySeed("vault"), u64Seed("ncnEpoch")],
+    vaultDepositAllowlistEntry: [prefix("vault_deposit_allowlist_entry"), pubkeySeed("vault"), pubkeySeed("depositor")],
+    vaultDepositor: [prefix("vault_depositor"), pubkeySeed("vault"), pubkeySeed("depositor")],
+    slashProposal: [prefix("slash_proposal"), pubkeySeed("vault"), pubkeySeed("base")],
+    delegationStrategy: [prefix("delegation_strategy"), pubkeySeed("vault")],
+    vaultEpochStats: [prefix("vault_epoch_stats"), pubkeySeed("vault")],
+};
+
+// Instruction accounts in the vault program named differently from the account they hold.
+const vaultAccountAliases = {
+    vaultSlasherTicket: "vaultNcnSlasherTicket",
+};
 
 const rustClientsDir = path.join(__dirname, "..", "clients", "rust");
 const jsClientsDir = path.join(__dirname, "..", "clients", "js");
@@ -88,6 +196,9 @@ restakingKinobi.update(kinobi.bottomUpTransformerVisitor([
         },
     },
 ]));
+restakingKinobi.update(pdasVisitor(restakingPdas));
+restakingKinobi.update(instructionDefaultsVisitor(restakingPdas));
+writeKinobiIdl(restakingKinobi, "jito_restaking.json");
 restakingKinobi.accept(renderers.renderRustVisitor(path.join(rustRestakingClientDir, "src", "generated"), {
     formatCode: true,
     crateFolder: rustRestakingClientDir,
@@ -172,6 +283,9 @@ vaultKinobi.update(kinobi.bottomUpTransformerVisitor([
         },
     },
 ]));
+vaultKinobi.update(pdasVisitor(vaultPdas));
+vaultKinobi.update(instructionDefaultsVisitor(vaultPdas, vaultAccountAliases));
+writeKinobiIdl(vaultKinobi, "jito_vault.json");
 vaultKinobi.accept(renderers.renderRustVisitor(path.join(rustVaultClientDir, "src", "generated"), {
     formatCode: true,
     crateFolder: rustVaultClientDir,
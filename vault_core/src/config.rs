@@ -5,7 +5,7 @@ use std::fmt::Debug;
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
     types::{PodU16, PodU64},
-    AccountDeserialize, Discriminator,
+    AccountDeserialize, AccountVersion, Discriminator,
 };
 use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
@@ -14,10 +14,38 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey,
 };
 
+use crate::{
+    cooldown_cohorts::COOLDOWN_COHORTS,
+    rounding::{bps_of, RoundingDirection},
+    MAX_FEE_BPS,
+};
+
 impl Discriminator for Config {
     const DISCRIMINATOR: u8 = 1;
 }
 
+impl AccountVersion for Config {
+    /// Version 1 bounds the delegation cooldown by the number of cooldown cohorts
+    const VERSION: u8 = 1;
+
+    fn size_of_version(version: u8) -> Option<usize> {
+        // version 1 didn't change the size of the layout
+        (version <= Self::VERSION).then(|| 8 + size_of::<Self>())
+    }
+
+    fn migrate(data: &mut [u8], from_version: u8) -> Result<(), ProgramError> {
+        let config = Self::try_from_slice_unchecked_mut(data)?;
+        if from_version < 1 {
+            // cooldowns longer than the cohorts can track are cut to the longest they can
+            let delegation_cooldown_epochs = config
+                .delegation_cooldown_epochs()
+                .min(Self::MAX_DELEGATION_COOLDOWN_EPOCHS);
+            config.delegation_cooldown_epochs = PodU64::from(delegation_cooldown_epochs);
+        }
+        Ok(())
+    }
+}
+
 /// The vault configuration account for the vault program.
 /// Manages program-wide settings and state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
@@ -47,8 +75,42 @@ pub struct Config {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The portion of the vault fees minted to the protocol fee wallet in basis points
+    protocol_fee_bps: PodU16,
+
+    /// The wallet that receives the protocol fees
+    pub protocol_fee_wallet: Pubkey,
+
+    /// The number of epochs undelegated stake spends cooling down before it returns to the vault
+    delegation_cooldown_epochs: PodU64,
+
+    /// The admin that can veto proposed slashes
+    pub slash_veto_admin: Pubkey,
+
+    /// The number of slots a proposed slash can be vetoed before it can be executed. Zero
+    /// disables the veto window, letting slashers slash immediately.
+    slash_veto_window_slots: PodU64,
+
+    /// Whether the config admin halted every vault, see [`Config::check_is_paused`]
+    is_paused: u8,
+
+    /// The lamports paid out of a vault's balance above rent exemption to the cranker that
+    /// advances its epoch state
+    crank_reward_lamports: PodU64,
+
+    /// The portion of a vault's idle assets transferred to the cranker that advances its epoch
+    /// state in basis points
+    crank_reward_bps: PodU16,
+
+    /// The shortest epoch length in slots a vault can override the epoch length with
+    min_vault_epoch_length: PodU64,
+
+    /// The longest epoch length in slots a vault can override the epoch length with, zero while
+    /// vaults can't override the epoch length
+    max_vault_epoch_length: PodU64,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 154],
 }
 
 impl Config {
@@ -58,6 +120,16 @@ impl Config {
     pub const DEFAULT_FEE_RATE_OF_CHANGE_BPS: u16 = 2_500; // 25%
     /// Maximum bump in fee change above the rate of change
     pub const DEFAULT_FEE_BUMP_BPS: u16 = 10; // 0.1%
+    /// Default number of epochs undelegated stake spends cooling down
+    pub const DEFAULT_DELEGATION_COOLDOWN_EPOCHS: u64 = 1;
+    /// Maximum number of epochs undelegated stake can be made to cool down, one per cohort a
+    /// delegation tracks its stake cooling down in
+    pub const MAX_DELEGATION_COOLDOWN_EPOCHS: u64 = COOLDOWN_COHORTS as u64;
+    /// Maximum length of the slash veto window in epochs, so stake doesn't leave the operator
+    /// before a proposed slash can be executed
+    pub const MAX_SLASH_VETO_WINDOW_EPOCHS: u64 = 1;
+    /// Maximum crank reward in basis points of a vault's assets, paid at most once per epoch
+    pub const MAX_CRANK_REWARD_BPS: u16 = 10; // 0.1%
 
     pub fn new(admin: Pubkey, restaking_program: Pubkey, bump: u8) -> Self {
         Self {
@@ -70,7 +142,17 @@ impl Config {
             fee_rate_of_change_bps: PodU16::from(Self::DEFAULT_FEE_RATE_OF_CHANGE_BPS),
             fee_bump_bps: PodU16::from(Self::DEFAULT_FEE_BUMP_BPS),
             bump,
-            reserved: [0; 263],
+            protocol_fee_bps: PodU16::from(0),
+            protocol_fee_wallet: admin,
+            delegation_cooldown_epochs: PodU64::from(Self::DEFAULT_DELEGATION_COOLDOWN_EPOCHS),
+            slash_veto_admin: admin,
+            slash_veto_window_slots: PodU64::from(0),
+            is_paused: 0,
+            crank_reward_lamports: PodU64::from(0),
+            crank_reward_bps: PodU16::from(0),
+            min_vault_epoch_length: PodU64::from(0),
+            max_vault_epoch_length: PodU64::from(0),
+            reserved: [0; 154],
         }
     }
 
@@ -94,6 +176,193 @@ impl Config {
         self.fee_bump_bps.into()
     }
 
+    pub fn protocol_fee_bps(&self) -> u16 {
+        self.protocol_fee_bps.into()
+    }
+
+    pub fn set_protocol_fee_bps(&mut self, protocol_fee_bps: u16) -> Result<(), VaultError> {
+        if protocol_fee_bps > MAX_FEE_BPS {
+            msg!("Protocol fee exceeds maximum allowed of {}", MAX_FEE_BPS);
+            return Err(VaultError::ConfigProtocolFeeCapExceeded);
+        }
+        self.protocol_fee_bps = PodU16::from(protocol_fee_bps);
+        Ok(())
+    }
+
+    /// The number of epochs undelegated stake spends cooling down. Configs created before the
+    /// cooldown was configurable read as zero, which is treated as the default.
+    pub fn delegation_cooldown_epochs(&self) -> u64 {
+        let delegation_cooldown_epochs: u64 = self.delegation_cooldown_epochs.into();
+        if delegation_cooldown_epochs == 0 {
+            Self::DEFAULT_DELEGATION_COOLDOWN_EPOCHS
+        } else {
+            delegation_cooldown_epochs
+        }
+    }
+
+    pub fn set_delegation_cooldown_epochs(
+        &mut self,
+        delegation_cooldown_epochs: u64,
+    ) -> Result<(), VaultError> {
+        if delegation_cooldown_epochs == 0
+            || delegation_cooldown_epochs > Self::MAX_DELEGATION_COOLDOWN_EPOCHS
+        {
+            msg!(
+                "Delegation cooldown must be between 1 and {} epochs",
+                Self::MAX_DELEGATION_COOLDOWN_EPOCHS
+            );
+            return Err(VaultError::ConfigDelegationCooldownEpochsInvalid);
+        }
+        self.delegation_cooldown_epochs = PodU64::from(delegation_cooldown_epochs);
+        Ok(())
+    }
+
+    pub fn slash_veto_window_slots(&self) -> u64 {
+        self.slash_veto_window_slots.into()
+    }
+
+    pub fn set_slash_veto_window_slots(
+        &mut self,
+        slash_veto_window_slots: u64,
+    ) -> Result<(), VaultError> {
+        let max_slash_veto_window_slots = self
+            .epoch_length()
+            .checked_mul(Self::MAX_SLASH_VETO_WINDOW_EPOCHS)
+            .ok_or(VaultError::VaultOverflow)?;
+        if slash_veto_window_slots > max_slash_veto_window_slots {
+            msg!(
+                "Slash veto window exceeds maximum allowed of {} slots",
+                max_slash_veto_window_slots
+            );
+            return Err(VaultError::ConfigSlashVetoWindowInvalid);
+        }
+        self.slash_veto_window_slots = PodU64::from(slash_veto_window_slots);
+        Ok(())
+    }
+
+    /// Slashes must be proposed and wait out the veto window when it's enabled
+    pub fn check_slash_veto_window_disabled(&self) -> Result<(), VaultError> {
+        if self.slash_veto_window_slots() > 0 {
+            msg!("Slashes must be proposed while the slash veto window is enabled");
+            return Err(VaultError::VaultSlashProposalRequired);
+        }
+        Ok(())
+    }
+
+    pub fn check_slash_veto_admin(&self, slash_veto_admin: &Pubkey) -> Result<(), VaultError> {
+        if self.slash_veto_admin.ne(slash_veto_admin) {
+            msg!("Slash veto admin does not match the provided admin");
+            return Err(VaultError::ConfigSlashVetoAdminInvalid);
+        }
+        Ok(())
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.is_paused == 1
+    }
+
+    pub fn set_is_paused(&mut self, is_paused: bool) {
+        self.is_paused = u8::from(is_paused);
+    }
+
+    /// While the config is paused, every vault rejects the instructions it rejects when the vault
+    /// itself is paused
+    #[inline(always)]
+    pub fn check_is_paused(&self) -> Result<(), VaultError> {
+        if self.is_paused() {
+            msg!("Vault program is paused");
+            return Err(VaultError::ConfigPaused);
+        }
+        Ok(())
+    }
+
+    pub fn crank_reward_lamports(&self) -> u64 {
+        self.crank_reward_lamports.into()
+    }
+
+    pub fn crank_reward_bps(&self) -> u16 {
+        self.crank_reward_bps.into()
+    }
+
+    pub fn set_crank_reward(
+        &mut self,
+        crank_reward_lamports: u64,
+        crank_reward_bps: u16,
+    ) -> Result<(), VaultError> {
+        if crank_reward_bps > Self::MAX_CRANK_REWARD_BPS {
+            msg!(
+                "Crank reward exceeds maximum allowed of {} bps",
+                Self::MAX_CRANK_REWARD_BPS
+            );
+            return Err(VaultError::ConfigCrankRewardInvalid);
+        }
+        self.crank_reward_lamports = PodU64::from(crank_reward_lamports);
+        self.crank_reward_bps = PodU16::from(crank_reward_bps);
+        Ok(())
+    }
+
+    pub fn min_vault_epoch_length(&self) -> u64 {
+        self.min_vault_epoch_length.into()
+    }
+
+    pub fn max_vault_epoch_length(&self) -> u64 {
+        self.max_vault_epoch_length.into()
+    }
+
+    /// Sets the bounds of the epoch length vaults can override the epoch length with. Both zero
+    /// stops vaults from setting an override, overrides already set are kept.
+    pub fn set_vault_epoch_length_bounds(
+        &mut self,
+        min_vault_epoch_length: u64,
+        max_vault_epoch_length: u64,
+    ) -> Result<(), VaultError> {
+        let disabled = min_vault_epoch_length == 0 && max_vault_epoch_length == 0;
+        if !disabled
+            && (min_vault_epoch_length == 0 || min_vault_epoch_length > max_vault_epoch_length)
+        {
+            msg!("Vault epoch length bounds must be a non-empty range of non-zero lengths");
+            return Err(VaultError::ConfigVaultEpochLengthBoundsInvalid);
+        }
+        self.min_vault_epoch_length = PodU64::from(min_vault_epoch_length);
+        self.max_vault_epoch_length = PodU64::from(max_vault_epoch_length);
+        Ok(())
+    }
+
+    /// Checks a vault's epoch length override is within the bounds, zero removes the override
+    pub fn check_vault_epoch_length(&self, epoch_length: u64) -> Result<(), VaultError> {
+        if epoch_length != 0
+            && (epoch_length < self.min_vault_epoch_length()
+                || epoch_length > self.max_vault_epoch_length())
+        {
+            msg!(
+                "Vault epoch length must be between {} and {} slots",
+                self.min_vault_epoch_length(),
+                self.max_vault_epoch_length()
+            );
+            return Err(VaultError::VaultEpochLengthInvalid);
+        }
+        Ok(())
+    }
+
+    /// Whether crankers are paid for advancing a vault's epoch state
+    pub fn has_crank_reward(&self) -> bool {
+        self.crank_reward_lamports() > 0 || self.crank_reward_bps() > 0
+    }
+
+    pub fn check_admin(&self, admin: &Pubkey) -> Result<(), VaultError> {
+        if self.admin.ne(admin) {
+            msg!("Config admin does not match the provided admin");
+            return Err(VaultError::ConfigAdminInvalid);
+        }
+        Ok(())
+    }
+
+    /// Calculates the portion of `fee_amount` VRT that goes to the protocol fee wallet.
+    /// Rounded down, so any remainder stays with the vault fee wallet.
+    pub fn calculate_protocol_fee(&self, fee_amount: u64) -> Result<u64, VaultError> {
+        bps_of(fee_amount, self.protocol_fee_bps(), RoundingDirection::Down)
+    }
+
     pub fn increment_num_vaults(&mut self) -> Result<(), VaultError> {
         let mut num_vaults: u64 = self.num_vaults.into();
         num_vaults = num_vaults.checked_add(1).ok_or(VaultError::VaultOverflow)?;
@@ -132,19 +401,23 @@ impl Config {
         }
         if account.data_is_empty() {
             msg!("Config account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDataEmpty.into());
         }
         if expect_writable && !account.is_writable {
             msg!("Config account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotWritable.into());
         }
         if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("Config account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        if Self::version(&account.data.borrow())?.ne(&Self::VERSION) {
+            msg!("Config account must be migrated to the current version");
+            return Err(VaultError::VaultAccountVersionInvalid.into());
         }
         if account.key.ne(&Self::find_program_address(program_id).0) {
             msg!("Config account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -165,7 +438,192 @@ mod tests {
             std::mem::size_of::<PodU16>() + // fee_rate_of_change_bps
             std::mem::size_of::<PodU16>() + // fee_bump_bps
             std::mem::size_of::<u8>() + // bump
-            263; // reserved
+            std::mem::size_of::<PodU16>() + // protocol_fee_bps
+            std::mem::size_of::<Pubkey>() + // protocol_fee_wallet
+            std::mem::size_of::<PodU64>() + // delegation_cooldown_epochs
+            std::mem::size_of::<Pubkey>() + // slash_veto_admin
+            std::mem::size_of::<PodU64>() + // slash_veto_window_slots
+            std::mem::size_of::<u8>() + // is_paused
+            std::mem::size_of::<PodU64>() + // crank_reward_lamports
+            std::mem::size_of::<PodU16>() + // crank_reward_bps
+            std::mem::size_of::<PodU64>() + // min_vault_epoch_length
+            std::mem::size_of::<PodU64>() + // max_vault_epoch_length
+            154; // reserved
         assert_eq!(config_size, sum_of_fields);
     }
+
+    #[test]
+    fn test_set_protocol_fee_bps() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        assert_eq!(config.protocol_fee_bps(), 0);
+        assert_eq!(config.protocol_fee_wallet, config.admin);
+
+        config.set_protocol_fee_bps(MAX_FEE_BPS).unwrap();
+        assert_eq!(config.protocol_fee_bps(), MAX_FEE_BPS);
+        assert_eq!(
+            config.set_protocol_fee_bps(MAX_FEE_BPS + 1),
+            Err(VaultError::ConfigProtocolFeeCapExceeded)
+        );
+    }
+
+    #[test]
+    fn test_set_delegation_cooldown_epochs() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        assert_eq!(
+            config.delegation_cooldown_epochs(),
+            Config::DEFAULT_DELEGATION_COOLDOWN_EPOCHS
+        );
+
+        config
+            .set_delegation_cooldown_epochs(Config::MAX_DELEGATION_COOLDOWN_EPOCHS)
+            .unwrap();
+        assert_eq!(
+            config.delegation_cooldown_epochs(),
+            Config::MAX_DELEGATION_COOLDOWN_EPOCHS
+        );
+        assert_eq!(
+            config.set_delegation_cooldown_epochs(0),
+            Err(VaultError::ConfigDelegationCooldownEpochsInvalid)
+        );
+        assert_eq!(
+            config.set_delegation_cooldown_epochs(Config::MAX_DELEGATION_COOLDOWN_EPOCHS + 1),
+            Err(VaultError::ConfigDelegationCooldownEpochsInvalid)
+        );
+
+        // a config created before the cooldown was configurable uses the default
+        config.delegation_cooldown_epochs = PodU64::from(0);
+        assert_eq!(
+            config.delegation_cooldown_epochs(),
+            Config::DEFAULT_DELEGATION_COOLDOWN_EPOCHS
+        );
+    }
+
+    #[test]
+    fn test_migrate_bounds_delegation_cooldown_epochs() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        config.delegation_cooldown_epochs = PodU64::from(32);
+
+        let mut data = vec![0; 8 + std::mem::size_of::<Config>()];
+        data[0] = Config::DISCRIMINATOR;
+        data[8..].copy_from_slice(bytemuck::bytes_of(&config));
+
+        Config::migrate(&mut data, 0).unwrap();
+        let config = Config::try_from_slice_unchecked(&data).unwrap();
+        assert_eq!(
+            config.delegation_cooldown_epochs(),
+            Config::MAX_DELEGATION_COOLDOWN_EPOCHS
+        );
+    }
+
+    #[test]
+    fn test_set_slash_veto_window_slots() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        assert_eq!(config.slash_veto_window_slots(), 0);
+        assert_eq!(config.slash_veto_admin, config.admin);
+        assert_eq!(config.check_slash_veto_window_disabled(), Ok(()));
+
+        let max_slash_veto_window_slots =
+            config.epoch_length() * Config::MAX_SLASH_VETO_WINDOW_EPOCHS;
+        config
+            .set_slash_veto_window_slots(max_slash_veto_window_slots)
+            .unwrap();
+        assert_eq!(
+            config.slash_veto_window_slots(),
+            max_slash_veto_window_slots
+        );
+        assert_eq!(
+            config.check_slash_veto_window_disabled(),
+            Err(VaultError::VaultSlashProposalRequired)
+        );
+        assert_eq!(
+            config.set_slash_veto_window_slots(max_slash_veto_window_slots + 1),
+            Err(VaultError::ConfigSlashVetoWindowInvalid)
+        );
+    }
+
+    #[test]
+    fn test_set_crank_reward() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        assert!(!config.has_crank_reward());
+
+        config
+            .set_crank_reward(5_000, Config::MAX_CRANK_REWARD_BPS)
+            .unwrap();
+        assert!(config.has_crank_reward());
+        assert_eq!(config.crank_reward_lamports(), 5_000);
+        assert_eq!(config.crank_reward_bps(), Config::MAX_CRANK_REWARD_BPS);
+        assert_eq!(
+            config.set_crank_reward(5_000, Config::MAX_CRANK_REWARD_BPS + 1),
+            Err(VaultError::ConfigCrankRewardInvalid)
+        );
+
+        config.set_crank_reward(0, 0).unwrap();
+        assert!(!config.has_crank_reward());
+    }
+
+    #[test]
+    fn test_vault_epoch_length_bounds() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+
+        // vaults can't override the epoch length until the bounds are set
+        assert_eq!(
+            config.check_vault_epoch_length(1_000),
+            Err(VaultError::VaultEpochLengthInvalid)
+        );
+        config.check_vault_epoch_length(0).unwrap();
+
+        assert_eq!(
+            config.set_vault_epoch_length_bounds(0, 1_000),
+            Err(VaultError::ConfigVaultEpochLengthBoundsInvalid)
+        );
+        assert_eq!(
+            config.set_vault_epoch_length_bounds(2_000, 1_000),
+            Err(VaultError::ConfigVaultEpochLengthBoundsInvalid)
+        );
+        config.set_vault_epoch_length_bounds(1_000, 2_000).unwrap();
+
+        config.check_vault_epoch_length(1_000).unwrap();
+        config.check_vault_epoch_length(2_000).unwrap();
+        assert_eq!(
+            config.check_vault_epoch_length(999),
+            Err(VaultError::VaultEpochLengthInvalid)
+        );
+        assert_eq!(
+            config.check_vault_epoch_length(2_001),
+            Err(VaultError::VaultEpochLengthInvalid)
+        );
+
+        config.set_vault_epoch_length_bounds(0, 0).unwrap();
+        assert_eq!(
+            config.check_vault_epoch_length(1_000),
+            Err(VaultError::VaultEpochLengthInvalid)
+        );
+    }
+
+    #[test]
+    fn test_check_is_paused() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        assert_eq!(config.check_is_paused(), Ok(()));
+
+        config.set_is_paused(true);
+        assert_eq!(config.check_is_paused(), Err(VaultError::ConfigPaused));
+
+        config.set_is_paused(false);
+        assert_eq!(config.check_is_paused(), Ok(()));
+    }
+
+    #[test]
+    fn test_calculate_protocol_fee_rounds_down() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        assert_eq!(config.calculate_protocol_fee(1_000).unwrap(), 0);
+
+        config.set_protocol_fee_bps(1_000).unwrap();
+        assert_eq!(config.calculate_protocol_fee(1_000).unwrap(), 100);
+        assert_eq!(config.calculate_protocol_fee(9).unwrap(), 0);
+        assert_eq!(config.calculate_protocol_fee(19).unwrap(), 1);
+        assert_eq!(
+            config.calculate_protocol_fee(u64::MAX).unwrap(),
+            u64::MAX / 10
+        );
+    }
 }
@@ -0,0 +1,208 @@
+//! [`CooldownCohorts`] buckets an amount that's cooling down by the number of epochs it has been
+//! cooling down for, so each amount is released exactly the configured cooldown after it started
+//! cooling down, no matter how much starts cooling down after it.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::types::PodU64;
+use jito_vault_sdk::error::VaultError;
+use shank::ShankType;
+use solana_program::msg;
+
+/// The number of per-epoch cohorts an amount cooling down is tracked in, which bounds the
+/// cooldown length in epochs
+pub const COOLDOWN_COHORTS: usize = 16;
+
+const _: () = assert!(COOLDOWN_COHORTS == 16);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
+#[repr(C)]
+pub struct CooldownCohorts {
+    /// The amount that has been cooling down for `i` epochs, at index `i`.
+    /// The length is spelled out since shank can't parse a const array length.
+    amounts: [PodU64; 16],
+}
+
+impl Default for CooldownCohorts {
+    fn default() -> Self {
+        Self {
+            amounts: [PodU64::from(0); COOLDOWN_COHORTS],
+        }
+    }
+}
+
+impl CooldownCohorts {
+    /// The amount that has been cooling down for `epochs` epochs
+    pub fn amount(&self, epochs: usize) -> u64 {
+        self.amounts
+            .get(epochs)
+            .map_or(0, |amount| (*amount).into())
+    }
+
+    /// The total amount cooling down across all cohorts
+    pub fn total(&self) -> Result<u64, VaultError> {
+        self.amounts.iter().try_fold(0_u64, |total, amount| {
+            total
+                .checked_add((*amount).into())
+                .ok_or(VaultError::VaultSecurityOverflow)
+        })
+    }
+
+    /// Ages every cohort by `epochs` epoch boundaries and starts cooling down `enqueued_amount` at
+    /// the first of them. Any cohort that has been cooling down for at least `cooldown_epochs`
+    /// epochs is released.
+    ///
+    /// # Arguments
+    /// * `enqueued_amount` - The amount that starts cooling down at the first epoch boundary
+    /// * `epochs` - The number of epoch boundaries crossed
+    /// * `cooldown_epochs` - The number of epochs an amount cools down for, at most [`COOLDOWN_COHORTS`]
+    ///
+    /// # Returns
+    /// * `Result<u64, VaultError>` - The amount released
+    pub fn advance(
+        &mut self,
+        enqueued_amount: u64,
+        epochs: u64,
+        cooldown_epochs: u64,
+    ) -> Result<u64, VaultError> {
+        if cooldown_epochs > COOLDOWN_COHORTS as u64 {
+            msg!(
+                "Delegation cooldown of {} epochs is longer than the {} cohorts tracked",
+                cooldown_epochs,
+                COOLDOWN_COHORTS
+            );
+            return Err(VaultError::ConfigDelegationCooldownEpochsInvalid);
+        }
+        if epochs == 0 {
+            return Ok(0);
+        }
+
+        let mut amounts = [0_u64; COOLDOWN_COHORTS];
+        let mut released_amount = 0_u64;
+        let mut age_into = |amount: u64, age: u64| -> Result<(), VaultError> {
+            if age < cooldown_epochs {
+                let cohort = &mut amounts[age as usize];
+                *cohort = cohort
+                    .checked_add(amount)
+                    .ok_or(VaultError::VaultSecurityOverflow)?;
+            } else {
+                released_amount = released_amount
+                    .checked_add(amount)
+                    .ok_or(VaultError::VaultSecurityOverflow)?;
+            }
+            Ok(())
+        };
+        for (age, amount) in self.amounts.iter().enumerate() {
+            age_into((*amount).into(), (age as u64).saturating_add(epochs))?;
+        }
+        age_into(enqueued_amount, epochs.checked_sub(1).unwrap())?;
+
+        self.amounts = amounts.map(PodU64::from);
+        Ok(released_amount)
+    }
+
+    /// Removes `amount` from the cohorts, starting with the one that has been cooling down the
+    /// longest
+    pub fn shrink(&mut self, amount: u64) -> Result<(), VaultError> {
+        let mut remaining = amount;
+        for cohort in self.amounts.iter_mut().rev() {
+            if remaining == 0 {
+                break;
+            }
+            let cohort_amount: u64 = (*cohort).into();
+            let shrink_amount = cohort_amount.min(remaining);
+            *cohort = PodU64::from(cohort_amount.checked_sub(shrink_amount).unwrap());
+            remaining = remaining.checked_sub(shrink_amount).unwrap();
+        }
+        if remaining > 0 {
+            return Err(VaultError::VaultSecurityUnderflow);
+        }
+        Ok(())
+    }
+
+    /// Used to accumulate the cohorts of other into the cohorts of self
+    pub fn accumulate(&mut self, other: &Self) -> Result<(), VaultError> {
+        for (amount, other_amount) in self.amounts.iter_mut().zip(other.amounts.iter()) {
+            let sum = u64::from(*amount)
+                .checked_add((*other_amount).into())
+                .ok_or(VaultError::VaultSecurityOverflow)?;
+            *amount = PodU64::from(sum);
+        }
+        Ok(())
+    }
+
+    pub fn subtract(&mut self, other: &Self) -> Result<(), VaultError> {
+        for (amount, other_amount) in self.amounts.iter_mut().zip(other.amounts.iter()) {
+            let difference = u64::from(*amount)
+                .checked_sub((*other_amount).into())
+                .ok_or(VaultError::VaultSecurityUnderflow)?;
+            *amount = PodU64::from(difference);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_releases_after_cooldown() {
+        let mut cohorts = CooldownCohorts::default();
+        assert_eq!(cohorts.advance(100, 1, 3), Ok(0));
+        assert_eq!(cohorts.amount(0), 100);
+        assert_eq!(cohorts.advance(0, 1, 3), Ok(0));
+        assert_eq!(cohorts.advance(0, 1, 3), Ok(0));
+        assert_eq!(cohorts.amount(2), 100);
+        assert_eq!(cohorts.advance(0, 1, 3), Ok(100));
+        assert_eq!(cohorts.total(), Ok(0));
+    }
+
+    #[test]
+    fn test_advance_consecutive_cohorts_release_on_their_own_schedule() {
+        let mut cohorts = CooldownCohorts::default();
+        assert_eq!(cohorts.advance(100, 1, 3), Ok(0));
+        assert_eq!(cohorts.advance(10, 1, 3), Ok(0));
+        assert_eq!(cohorts.advance(1, 1, 3), Ok(0));
+        assert_eq!(cohorts.advance(0, 1, 3), Ok(100));
+        assert_eq!(cohorts.advance(0, 1, 3), Ok(10));
+        assert_eq!(cohorts.advance(0, 1, 3), Ok(1));
+    }
+
+    #[test]
+    fn test_advance_skipped_epochs() {
+        let mut cohorts = CooldownCohorts::default();
+        assert_eq!(cohorts.advance(100, 1, 3), Ok(0));
+        // the enqueued amount starts cooling down at the first of the two boundaries
+        assert_eq!(cohorts.advance(10, 2, 3), Ok(0));
+        assert_eq!(cohorts.amount(2), 100);
+        assert_eq!(cohorts.amount(1), 10);
+        assert_eq!(cohorts.advance(0, 5, 3), Ok(110));
+        assert_eq!(cohorts.advance(1, COOLDOWN_COHORTS as u64 + 1, 16), Ok(1));
+    }
+
+    #[test]
+    fn test_advance_cooldown_longer_than_cohorts_fails() {
+        let mut cohorts = CooldownCohorts::default();
+        assert_eq!(
+            cohorts.advance(100, 1, COOLDOWN_COHORTS as u64 + 1),
+            Err(VaultError::ConfigDelegationCooldownEpochsInvalid)
+        );
+    }
+
+    #[test]
+    fn test_advance_no_epochs() {
+        let mut cohorts = CooldownCohorts::default();
+        assert_eq!(cohorts.advance(100, 0, 3), Ok(0));
+        assert_eq!(cohorts.total(), Ok(0));
+    }
+
+    #[test]
+    fn test_shrink_takes_from_oldest_cohort_first() {
+        let mut cohorts = CooldownCohorts::default();
+        cohorts.advance(100, 1, 3).unwrap();
+        cohorts.advance(10, 1, 3).unwrap();
+        cohorts.shrink(105).unwrap();
+        assert_eq!(cohorts.amount(1), 0);
+        assert_eq!(cohorts.amount(0), 5);
+        assert_eq!(cohorts.shrink(6), Err(VaultError::VaultSecurityUnderflow));
+    }
+}
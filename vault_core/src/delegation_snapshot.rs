@@ -0,0 +1,168 @@
+//! The [`DelegationSnapshot`] is an append-only Merkle tree over a vault's operator delegations.
+//! It's built up by the [`crate::vault_update_state_tracker::VaultUpdateStateTracker`] as each
+//! operator delegation is cranked, and its root is stored on the vault once the update finishes so
+//! anyone indexing the vault can attest they computed the same stake weights for the epoch.
+//!
+//! Leaves are `sha256(0x00 || operator || staked_amount)`, with the staked amount little-endian,
+//! in [`crate::vault_operator_delegation::VaultOperatorDelegation`] index order. Nodes are
+//! `sha256(0x01 || left || right)`. The tree has a fixed depth of [`DELEGATION_SNAPSHOT_DEPTH`],
+//! empty leaves are all zeroes.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::types::PodU64;
+use jito_vault_sdk::error::VaultError;
+use shank::ShankType;
+use solana_program::{hash::hashv, msg, pubkey::Pubkey};
+
+/// The depth of the snapshot tree, enough for any realistic number of operators
+pub const DELEGATION_SNAPSHOT_DEPTH: usize = 32;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
+#[repr(C)]
+pub struct DelegationSnapshot {
+    /// The number of leaves appended to the tree
+    leaf_count: PodU64,
+
+    /// The rightmost left node at each level of the tree, enough to append leaves and compute the
+    /// root. The nodes are stored back to back since shank can't describe nested arrays.
+    frontier: [u8; 1024],
+}
+
+impl Default for DelegationSnapshot {
+    fn default() -> Self {
+        Self {
+            leaf_count: PodU64::from(0),
+            frontier: [0; 1024],
+        }
+    }
+}
+
+impl DelegationSnapshot {
+    pub fn leaf_count(&self) -> u64 {
+        self.leaf_count.into()
+    }
+
+    /// Hashes an operator's delegation into a leaf of the tree
+    pub fn leaf_hash(operator: &Pubkey, staked_amount: u64) -> [u8; 32] {
+        hashv(&[LEAF_PREFIX, operator.as_ref(), &staked_amount.to_le_bytes()]).to_bytes()
+    }
+
+    fn node_hash(left: &[u8], right: &[u8]) -> [u8; 32] {
+        hashv(&[NODE_PREFIX, left, right]).to_bytes()
+    }
+
+    /// Appends an operator's delegation as the next leaf of the tree
+    pub fn append(&mut self, operator: &Pubkey, staked_amount: u64) -> Result<(), VaultError> {
+        let leaf_count = self.leaf_count();
+        if leaf_count >= 1 << DELEGATION_SNAPSHOT_DEPTH {
+            msg!("Delegation snapshot is full");
+            return Err(VaultError::VaultOverflow);
+        }
+
+        let mut node = Self::leaf_hash(operator, staked_amount);
+        let mut size = leaf_count;
+        for level in self.frontier.chunks_exact_mut(32) {
+            if size & 1 == 0 {
+                level.copy_from_slice(&node);
+                break;
+            }
+            node = Self::node_hash(level, &node);
+            size >>= 1;
+        }
+        self.leaf_count = PodU64::from(leaf_count.checked_add(1).ok_or(VaultError::VaultOverflow)?);
+        Ok(())
+    }
+
+    /// Computes the Merkle root over the leaves appended so far
+    pub fn root(&self) -> [u8; 32] {
+        let mut node = [0; 32];
+        let mut zero = [0; 32];
+        let mut size = self.leaf_count();
+        for level in self.frontier.chunks_exact(32) {
+            node = if size & 1 == 1 {
+                Self::node_hash(level, &node)
+            } else {
+                Self::node_hash(&node, &zero)
+            };
+            zero = Self::node_hash(&zero, &zero);
+            size >>= 1;
+        }
+        node
+    }
+
+    /// Computes the root for a list of `(operator, staked_amount)` delegations in index order,
+    /// useful for checking the root stored on a vault against an off-chain view of its delegations.
+    pub fn compute_root(delegations: &[(Pubkey, u64)]) -> Result<[u8; 32], VaultError> {
+        let mut snapshot = Self::default();
+        for (operator, staked_amount) in delegations {
+            snapshot.append(operator, *staked_amount)?;
+        }
+        Ok(snapshot.root())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Computes the root by hashing the full tree level by level
+    fn naive_root(delegations: &[(Pubkey, u64)]) -> [u8; 32] {
+        let mut level: Vec<[u8; 32]> = delegations
+            .iter()
+            .map(|(operator, amount)| DelegationSnapshot::leaf_hash(operator, *amount))
+            .collect();
+        let mut zero = [0; 32];
+        for _ in 0..DELEGATION_SNAPSHOT_DEPTH {
+            if level.len() % 2 == 1 {
+                level.push(zero);
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| DelegationSnapshot::node_hash(&pair[0], &pair[1]))
+                .collect();
+            if level.is_empty() {
+                level.push(DelegationSnapshot::node_hash(&zero, &zero));
+            }
+            zero = DelegationSnapshot::node_hash(&zero, &zero);
+        }
+        level[0]
+    }
+
+    #[test]
+    fn test_delegation_snapshot_no_padding() {
+        let delegation_snapshot_size = std::mem::size_of::<DelegationSnapshot>();
+        let sum_of_fields = size_of::<PodU64>() + // leaf_count
+            size_of::<[u8; 32]>() * DELEGATION_SNAPSHOT_DEPTH; // frontier
+        assert_eq!(delegation_snapshot_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_root_matches_full_tree() {
+        let delegations: Vec<(Pubkey, u64)> =
+            (0..10).map(|i| (Pubkey::new_unique(), i * 1_000)).collect();
+        for count in 0..=delegations.len() {
+            assert_eq!(
+                DelegationSnapshot::compute_root(&delegations[..count]).unwrap(),
+                naive_root(&delegations[..count])
+            );
+        }
+    }
+
+    #[test]
+    fn test_root_depends_on_order_and_amount() {
+        let operator_a = Pubkey::new_unique();
+        let operator_b = Pubkey::new_unique();
+        let root = DelegationSnapshot::compute_root(&[(operator_a, 1), (operator_b, 2)]).unwrap();
+
+        assert_ne!(
+            root,
+            DelegationSnapshot::compute_root(&[(operator_b, 2), (operator_a, 1)]).unwrap()
+        );
+        assert_ne!(
+            root,
+            DelegationSnapshot::compute_root(&[(operator_a, 1), (operator_b, 3)]).unwrap()
+        );
+    }
+}
@@ -6,6 +6,11 @@ use jito_vault_sdk::error::VaultError;
 use shank::ShankType;
 use solana_program::msg;
 
+use crate::{
+    cooldown_cohorts::CooldownCohorts,
+    rounding::{mul_div, RoundingDirection},
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
 #[repr(C)]
 pub struct DelegationState {
@@ -15,11 +20,16 @@ pub struct DelegationState {
     /// Any stake that was deactivated in the current epoch
     enqueued_for_cooldown_amount: PodU64,
 
-    /// Any stake that was deactivated in the previous epoch,
-    /// to be available for re-delegation in the current epoch + 1
+    /// Any stake that was deactivated in a previous epoch and is still cooling down, to be
+    /// available for re-delegation once its cooldown elapses
     cooling_down_amount: PodU64,
 
-    reserved: [u8; 256],
+    /// The cooling_down_amount bucketed by the number of epochs it has been cooling down for.
+    /// Stake that started cooling down before cohorts were tracked isn't in any cohort and is
+    /// released at the next epoch boundary.
+    cooling_down_cohorts: CooldownCohorts,
+
+    reserved: [u8; 128],
 }
 
 impl Default for DelegationState {
@@ -28,7 +38,8 @@ impl Default for DelegationState {
             staked_amount: PodU64::from(0),
             enqueued_for_cooldown_amount: PodU64::from(0),
             cooling_down_amount: PodU64::from(0),
-            reserved: [0; 256],
+            cooling_down_cohorts: CooldownCohorts::default(),
+            reserved: [0; 128],
         }
     }
 }
@@ -43,7 +54,8 @@ impl DelegationState {
             staked_amount: PodU64::from(staked_amount),
             enqueued_for_cooldown_amount: PodU64::from(enqueued_for_cooldown_amount),
             cooling_down_amount: PodU64::from(cooling_down_amount),
-            reserved: [0; 256],
+            cooling_down_cohorts: CooldownCohorts::default(),
+            reserved: [0; 128],
         }
     }
 
@@ -59,6 +71,10 @@ impl DelegationState {
         self.cooling_down_amount.into()
     }
 
+    pub const fn cooling_down_cohorts(&self) -> &CooldownCohorts {
+        &self.cooling_down_cohorts
+    }
+
     pub fn subtract(&mut self, other: &Self) -> Result<(), VaultError> {
         let mut staked_amount: u64 = self.staked_amount.into();
         staked_amount = staked_amount
@@ -75,6 +91,9 @@ impl DelegationState {
             .checked_sub(other.cooling_down_amount.into())
             .ok_or(VaultError::VaultSecurityUnderflow)?;
 
+        self.cooling_down_cohorts
+            .subtract(&other.cooling_down_cohorts)?;
+
         self.staked_amount = PodU64::from(staked_amount);
         self.enqueued_for_cooldown_amount = PodU64::from(enqueued_for_cooldown_amount);
         self.cooling_down_amount = PodU64::from(cooling_down_amount);
@@ -98,6 +117,9 @@ impl DelegationState {
             .checked_add(other.cooling_down_amount.into())
             .ok_or(VaultError::VaultSecurityOverflow)?;
 
+        self.cooling_down_cohorts
+            .accumulate(&other.cooling_down_cohorts)?;
+
         self.staked_amount = PodU64::from(staked_amount);
         self.enqueued_for_cooldown_amount = PodU64::from(enqueued_for_cooldown_amount);
         self.cooling_down_amount = PodU64::from(cooling_down_amount);
@@ -118,15 +140,47 @@ impl DelegationState {
             .ok_or(VaultError::VaultSecurityOverflow)
     }
 
-    #[inline(always)]
-    pub fn update(&mut self) {
-        self.cooling_down_amount = self.enqueued_for_cooldown_amount;
+    /// The stake cooling down that isn't in any cohort
+    fn untracked_cooling_down_amount(&self) -> Result<u64, VaultError> {
+        Ok(self
+            .cooling_down_amount()
+            .saturating_sub(self.cooling_down_cohorts.total()?))
+    }
+
+    /// Moves the delegation across `epochs` epoch boundaries. The enqueued_for_cooldown_amount
+    /// starts cooling down at the first of them, and any stake that has been cooling down for
+    /// `cooldown_epochs` epochs is released back to the vault.
+    ///
+    /// # Arguments
+    /// * `epochs` - The number of epoch boundaries crossed
+    /// * `cooldown_epochs` - The number of epochs stake cools down for
+    ///
+    /// # Returns
+    /// * `Result<u64, VaultError>` - The amount of stake released
+    pub fn advance_cooldown(
+        &mut self,
+        epochs: u64,
+        cooldown_epochs: u64,
+    ) -> Result<u64, VaultError> {
+        if epochs == 0 {
+            return Ok(0);
+        }
+        let untracked_amount = self.untracked_cooling_down_amount()?;
+        let released_amount = self
+            .cooling_down_cohorts
+            .advance(self.enqueued_for_cooldown_amount(), epochs, cooldown_epochs)?
+            .checked_add(untracked_amount)
+            .ok_or(VaultError::VaultSecurityOverflow)?;
         self.enqueued_for_cooldown_amount = PodU64::from(0);
+        self.cooling_down_amount = PodU64::from(self.cooling_down_cohorts.total()?);
+        Ok(released_amount)
     }
 
     /// Slashes the operator delegation by the given amount.
     ///
-    /// Slashes are applied in the following order:
+    /// The slash is split across the staked, enqueued for cooldown and cooling down amounts in
+    /// proportion to their share of the total security, rounded down. Any remainder left by the
+    /// rounding is taken in the following order:
     /// 1. Staked amount
     /// 2. Enqueued for cooldown amount
     /// 3. Cooling down amount
@@ -147,13 +201,41 @@ impl DelegationState {
             );
             return Err(VaultError::VaultSlashUnderflow);
         }
+        if slash_amount == 0 {
+            return Ok(());
+        }
+
+        let mut buckets = [
+            self.staked_amount(),
+            self.enqueued_for_cooldown_amount(),
+            self.cooling_down_amount(),
+        ];
 
         let mut remaining_slash = slash_amount;
 
-        // Slash as much as possible from the given amount
-        let mut apply_slash = |amount: &mut u64| -> Result<(), VaultError> {
-            if *amount == 0 || remaining_slash == 0 {
-                return Ok(());
+        // Slash each bucket by its pro-rata share of the slash amount
+        let mut pro_rata_slashes = [0u64; 3];
+        for (pro_rata_slash, amount) in pro_rata_slashes.iter_mut().zip(buckets.iter()) {
+            *pro_rata_slash = mul_div(
+                slash_amount,
+                *amount,
+                total_security_amount,
+                RoundingDirection::Down,
+            )?;
+        }
+        for (amount, pro_rata_slash) in buckets.iter_mut().zip(pro_rata_slashes) {
+            *amount = amount
+                .checked_sub(pro_rata_slash)
+                .ok_or(VaultError::VaultSecurityUnderflow)?;
+            remaining_slash = remaining_slash
+                .checked_sub(pro_rata_slash)
+                .ok_or(VaultError::VaultSecurityUnderflow)?;
+        }
+
+        // Take the rounding remainder from the buckets in order
+        for amount in buckets.iter_mut() {
+            if remaining_slash == 0 {
+                break;
             }
             let slash_amount = min(*amount, remaining_slash);
             *amount = amount
@@ -162,19 +244,7 @@ impl DelegationState {
             remaining_slash = remaining_slash
                 .checked_sub(slash_amount)
                 .ok_or(VaultError::VaultSecurityUnderflow)?;
-            Ok(())
-        };
-        let mut staked_amount: u64 = self.staked_amount.into();
-        apply_slash(&mut staked_amount)?;
-        self.staked_amount = PodU64::from(staked_amount);
-
-        let mut enqueued_for_cooldown_amount: u64 = self.enqueued_for_cooldown_amount.into();
-        apply_slash(&mut enqueued_for_cooldown_amount)?;
-        self.enqueued_for_cooldown_amount = PodU64::from(enqueued_for_cooldown_amount);
-
-        let mut cooling_down_amount: u64 = self.cooling_down_amount.into();
-        apply_slash(&mut cooling_down_amount)?;
-        self.cooling_down_amount = PodU64::from(cooling_down_amount);
+        }
 
         // Ensure we've slashed the exact amount requested
         if remaining_slash > 0 {
@@ -182,6 +252,18 @@ impl DelegationState {
             return Err(VaultError::VaultSlashIncomplete);
         }
 
+        let [staked_amount, enqueued_for_cooldown_amount, cooling_down_amount] = buckets;
+        // The cohorts give up the slashed cooling down stake after any untracked stake
+        let cooling_down_slash = self
+            .cooling_down_amount()
+            .checked_sub(cooling_down_amount)
+            .ok_or(VaultError::VaultSecurityUnderflow)?;
+        self.cooling_down_cohorts
+            .shrink(cooling_down_slash.saturating_sub(self.untracked_cooling_down_amount()?))?;
+        self.staked_amount = PodU64::from(staked_amount);
+        self.enqueued_for_cooldown_amount = PodU64::from(enqueued_for_cooldown_amount);
+        self.cooling_down_amount = PodU64::from(cooling_down_amount);
+
         Ok(())
     }
 
@@ -208,6 +290,20 @@ impl DelegationState {
         Ok(())
     }
 
+    /// Cools down any staked amount below `min_delegation_amount` so it flows back to the vault
+    /// as idle assets once the cooldown completes.
+    ///
+    /// # Returns
+    /// The amount of stake that was swept
+    pub fn sweep_dust(&mut self, min_delegation_amount: u64) -> Result<u64, VaultError> {
+        let staked_amount = self.staked_amount();
+        if staked_amount == 0 || staked_amount >= min_delegation_amount {
+            return Ok(0);
+        }
+        self.cooldown(staked_amount)?;
+        Ok(staked_amount)
+    }
+
     /// Delegates assets to the operator
     pub fn delegate(&mut self, amount: u64) -> Result<(), VaultError> {
         if amount == 0 {
@@ -229,7 +325,7 @@ mod tests {
     use jito_bytemuck::types::PodU64;
     use jito_vault_sdk::error::VaultError;
 
-    use crate::delegation_state::DelegationState;
+    use crate::{cooldown_cohorts::CooldownCohorts, delegation_state::DelegationState};
 
     #[test]
     fn test_delegation_state_no_padding() {
@@ -237,7 +333,8 @@ mod tests {
         let sum_of_fields = size_of::<PodU64>() // staked_amount
          + size_of::<PodU64>() // enqueued_for_cooldown_amount
          + size_of::<PodU64>() // cooling_down_amount
-         + 256; // reserved
+         + size_of::<CooldownCohorts>() // cooling_down_cohorts
+         + 128; // reserved
         assert_eq!(delegation_state_size, sum_of_fields);
     }
 
@@ -278,19 +375,55 @@ mod tests {
         assert_eq!(delegation_state.enqueued_for_cooldown_amount(), 50);
         assert_eq!(delegation_state.total_security().unwrap(), 100);
 
-        delegation_state.update();
+        assert_eq!(delegation_state.advance_cooldown(1, 1), Ok(0));
         assert_eq!(delegation_state.staked_amount(), 50);
         assert_eq!(delegation_state.enqueued_for_cooldown_amount(), 0);
         assert_eq!(delegation_state.cooling_down_amount(), 50);
         assert_eq!(delegation_state.total_security().unwrap(), 100);
 
-        delegation_state.update();
+        assert_eq!(delegation_state.advance_cooldown(1, 1), Ok(50));
         assert_eq!(delegation_state.staked_amount(), 50);
         assert_eq!(delegation_state.enqueued_for_cooldown_amount(), 0);
         assert_eq!(delegation_state.cooling_down_amount(), 0);
         assert_eq!(delegation_state.total_security().unwrap(), 50);
     }
 
+    #[test]
+    fn test_advance_cooldown_releases_each_cohort_on_time() {
+        let mut delegation_state = DelegationState::new(100, 0, 0);
+
+        delegation_state.cooldown(10).unwrap();
+        assert_eq!(delegation_state.advance_cooldown(1, 3), Ok(0));
+        delegation_state.cooldown(20).unwrap();
+        assert_eq!(delegation_state.advance_cooldown(1, 3), Ok(0));
+        assert_eq!(delegation_state.cooling_down_amount(), 30);
+        assert_eq!(delegation_state.advance_cooldown(1, 3), Ok(0));
+        assert_eq!(delegation_state.advance_cooldown(1, 3), Ok(10));
+        assert_eq!(delegation_state.cooling_down_amount(), 20);
+        assert_eq!(delegation_state.advance_cooldown(1, 3), Ok(20));
+        assert_eq!(delegation_state.total_security().unwrap(), 70);
+    }
+
+    #[test]
+    fn test_advance_cooldown_releases_untracked_stake() {
+        // stake cooling down from before cohorts were tracked is released at the next boundary
+        let mut delegation_state = DelegationState::new(100, 10, 20);
+        assert_eq!(delegation_state.advance_cooldown(1, 3), Ok(20));
+        assert_eq!(delegation_state.cooling_down_amount(), 10);
+        assert_eq!(delegation_state.cooling_down_cohorts().amount(0), 10);
+    }
+
+    #[test]
+    fn test_slash_shrinks_cooldown_cohorts() {
+        let mut delegation_state = DelegationState::new(0, 0, 0);
+        delegation_state.delegate(100).unwrap();
+        delegation_state.cooldown(100).unwrap();
+        delegation_state.advance_cooldown(1, 3).unwrap();
+        delegation_state.slash(40).unwrap();
+        assert_eq!(delegation_state.cooling_down_amount(), 60);
+        assert_eq!(delegation_state.cooling_down_cohorts().total(), Ok(60));
+    }
+
     #[test]
     fn test_delegate_zero() {
         let mut delegation_state = DelegationState::default();
@@ -300,6 +433,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sweep_dust() {
+        let mut delegation_state = DelegationState::new(99, 10, 20);
+        assert_eq!(delegation_state.sweep_dust(100), Ok(99));
+        assert_eq!(delegation_state.staked_amount(), 0);
+        assert_eq!(delegation_state.enqueued_for_cooldown_amount(), 109);
+        assert_eq!(delegation_state.cooling_down_amount(), 20);
+        assert_eq!(delegation_state.total_security().unwrap(), 129);
+
+        // nothing left to sweep
+        assert_eq!(delegation_state.sweep_dust(100), Ok(0));
+    }
+
+    #[test]
+    fn test_sweep_dust_above_minimum() {
+        let mut delegation_state = DelegationState::new(100, 0, 0);
+        assert_eq!(delegation_state.sweep_dust(100), Ok(0));
+        assert_eq!(delegation_state.staked_amount(), 100);
+
+        assert_eq!(delegation_state.sweep_dust(0), Ok(0));
+        assert_eq!(delegation_state.staked_amount(), 100);
+    }
+
     #[test]
     fn test_cooldown_zero() {
         let mut delegation_state = DelegationState::new(100, 0, 0);
@@ -308,4 +464,68 @@ mod tests {
             Err(VaultError::VaultCooldownZero)
         );
     }
+
+    #[test]
+    fn test_slash_pro_rata() {
+        let mut delegation_state = DelegationState::new(600, 300, 100);
+        delegation_state.slash(100).unwrap();
+        assert_eq!(delegation_state, DelegationState::new(540, 270, 90));
+    }
+
+    #[test]
+    fn test_slash_rounding_remainder_taken_in_order() {
+        // each bucket's share is 1/3 of the slash, rounded down to 0 of 1
+        let mut delegation_state = DelegationState::new(1, 1, 1);
+        delegation_state.slash(1).unwrap();
+        assert_eq!(delegation_state, DelegationState::new(0, 1, 1));
+
+        let mut delegation_state = DelegationState::new(1, 1, 1);
+        delegation_state.slash(2).unwrap();
+        assert_eq!(delegation_state, DelegationState::new(0, 0, 1));
+
+        // shares of 3.33, 3.33 and 3.33 round down to 3, the remainder comes from staked first
+        let mut delegation_state = DelegationState::new(10, 10, 10);
+        delegation_state.slash(10).unwrap();
+        assert_eq!(delegation_state, DelegationState::new(6, 7, 7));
+    }
+
+    #[test]
+    fn test_slash_remainder_skips_empty_buckets() {
+        // the staked share rounds down to zero, and the remainder then empties it
+        let mut delegation_state = DelegationState::new(1, 2, 0);
+        delegation_state.slash(2).unwrap();
+        assert_eq!(delegation_state.total_security().unwrap(), 1);
+        assert_eq!(delegation_state, DelegationState::new(0, 1, 0));
+
+        let mut delegation_state = DelegationState::new(0, 0, 7);
+        delegation_state.slash(5).unwrap();
+        assert_eq!(delegation_state, DelegationState::new(0, 0, 2));
+    }
+
+    #[test]
+    fn test_slash_all_and_none() {
+        let mut delegation_state = DelegationState::new(3, 5, 7);
+        delegation_state.slash(0).unwrap();
+        assert_eq!(delegation_state, DelegationState::new(3, 5, 7));
+
+        delegation_state.slash(15).unwrap();
+        assert_eq!(delegation_state, DelegationState::default());
+    }
+
+    #[test]
+    fn test_slash_large_amounts_no_overflow() {
+        let mut delegation_state = DelegationState::new(u64::MAX / 2, u64::MAX / 4, u64::MAX / 4);
+        let total_security = delegation_state.total_security().unwrap();
+        delegation_state.slash(total_security - 1).unwrap();
+        assert_eq!(delegation_state.total_security().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_slash_exceeds_total_security() {
+        let mut delegation_state = DelegationState::new(1, 1, 1);
+        assert_eq!(
+            delegation_state.slash(4),
+            Err(VaultError::VaultSlashUnderflow)
+        );
+    }
 }
@@ -0,0 +1,241 @@
+//! The [`DelegationStrategy`] account lets a vault opt into automatic delegation. The delegation
+//! admin sets a target weight on each operator delegation and anyone can crank
+//! `RebalanceDelegations` to move stake towards the targets, within a per-epoch movement limit.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+impl Discriminator for DelegationStrategy {
+    const DISCRIMINATOR: u8 = 13;
+}
+
+/// The [`DelegationStrategy`] account tracks the sum of the target weights set on a vault's
+/// operator delegations and how much stake the rebalancing crank has moved this epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct DelegationStrategy {
+    /// The vault account
+    pub vault: Pubkey,
+
+    /// The sum of the target weights of the vault's operator delegations
+    total_weight: PodU64,
+
+    /// The maximum amount of stake the crank can delegate or cool down per epoch.
+    /// Zero means there's no maximum.
+    max_movement_per_epoch: PodU64,
+
+    /// The epoch `moved_amount` was last reset in
+    movement_epoch: PodU64,
+
+    /// The amount of stake the crank has delegated or cooled down in `movement_epoch`
+    moved_amount: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl DelegationStrategy {
+    pub fn new(vault: Pubkey, max_movement_per_epoch: u64, bump: u8) -> Self {
+        Self {
+            vault,
+            total_weight: PodU64::from(0),
+            max_movement_per_epoch: PodU64::from(max_movement_per_epoch),
+            movement_epoch: PodU64::from(0),
+            moved_amount: PodU64::from(0),
+            bump,
+            reserved: [0; 263],
+        }
+    }
+
+    pub fn total_weight(&self) -> u64 {
+        self.total_weight.into()
+    }
+
+    pub fn max_movement_per_epoch(&self) -> u64 {
+        self.max_movement_per_epoch.into()
+    }
+
+    pub fn movement_epoch(&self) -> u64 {
+        self.movement_epoch.into()
+    }
+
+    pub fn moved_amount(&self) -> u64 {
+        self.moved_amount.into()
+    }
+
+    /// Replaces an operator delegation's `old_weight` with `new_weight` in the total weight
+    pub fn update_weight(&mut self, old_weight: u64, new_weight: u64) -> Result<(), VaultError> {
+        let total_weight = self
+            .total_weight()
+            .checked_sub(old_weight)
+            .ok_or(VaultError::VaultUnderflow)?
+            .checked_add(new_weight)
+            .ok_or(VaultError::VaultOverflow)?;
+        self.total_weight = PodU64::from(total_weight);
+        Ok(())
+    }
+
+    /// The amount of stake the crank can still move in `epoch`
+    pub fn remaining_movement(&self, epoch: u64) -> u64 {
+        if self.max_movement_per_epoch() == 0 {
+            return u64::MAX;
+        }
+        if epoch > self.movement_epoch() {
+            return self.max_movement_per_epoch();
+        }
+        self.max_movement_per_epoch()
+            .saturating_sub(self.moved_amount())
+    }
+
+    /// Records `amount` of stake moved by the crank in `epoch`
+    pub fn record_movement(&mut self, epoch: u64, amount: u64) -> Result<(), VaultError> {
+        if amount > self.remaining_movement(epoch) {
+            msg!(
+                "Movement of {} exceeds the remaining movement of {} this epoch",
+                amount,
+                self.remaining_movement(epoch)
+            );
+            return Err(VaultError::VaultDelegationStrategyMovementExceeded);
+        }
+        let moved_amount = if epoch > self.movement_epoch() {
+            amount
+        } else {
+            self.moved_amount()
+                .checked_add(amount)
+                .ok_or(VaultError::VaultOverflow)?
+        };
+        self.movement_epoch = PodU64::from(epoch);
+        self.moved_amount = PodU64::from(moved_amount);
+        Ok(())
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault account
+    pub fn seeds(vault: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([b"delegation_strategy".to_vec(), vault.as_ref().to_vec()])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault account
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`DelegationStrategy`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `delegation_strategy` - The account to load
+    /// * `vault` - The vault account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        delegation_strategy: &AccountInfo,
+        vault: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if delegation_strategy.owner.ne(program_id) {
+            msg!("Delegation strategy account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if delegation_strategy.data_is_empty() {
+            msg!("Delegation strategy account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if expect_writable && !delegation_strategy.is_writable {
+            msg!("Delegation strategy account is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
+        }
+        if delegation_strategy.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Delegation strategy account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let expected_pubkey = Self::find_program_address(program_id, vault.key).0;
+        if delegation_strategy.key.ne(&expected_pubkey) {
+            msg!("Delegation strategy account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delegation_strategy_no_padding() {
+        let delegation_strategy_size = std::mem::size_of::<DelegationStrategy>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<PodU64>() + // total_weight
+            size_of::<PodU64>() + // max_movement_per_epoch
+            size_of::<PodU64>() + // movement_epoch
+            size_of::<PodU64>() + // moved_amount
+            size_of::<u8>() + // bump
+            263; // reserved
+        assert_eq!(delegation_strategy_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_update_weight() {
+        let mut delegation_strategy = DelegationStrategy::new(Pubkey::new_unique(), 0, 0);
+        delegation_strategy.update_weight(0, 3).unwrap();
+        delegation_strategy.update_weight(0, 1).unwrap();
+        assert_eq!(delegation_strategy.total_weight(), 4);
+        delegation_strategy.update_weight(3, 0).unwrap();
+        assert_eq!(delegation_strategy.total_weight(), 1);
+        assert_eq!(
+            delegation_strategy.update_weight(2, 0),
+            Err(VaultError::VaultUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_record_movement() {
+        let mut delegation_strategy = DelegationStrategy::new(Pubkey::new_unique(), 100, 0);
+        assert_eq!(delegation_strategy.remaining_movement(1), 100);
+
+        delegation_strategy.record_movement(1, 60).unwrap();
+        assert_eq!(delegation_strategy.remaining_movement(1), 40);
+        assert_eq!(
+            delegation_strategy.record_movement(1, 41),
+            Err(VaultError::VaultDelegationStrategyMovementExceeded)
+        );
+        delegation_strategy.record_movement(1, 40).unwrap();
+        assert_eq!(delegation_strategy.remaining_movement(1), 0);
+
+        // the limit resets every epoch
+        assert_eq!(delegation_strategy.remaining_movement(2), 100);
+        delegation_strategy.record_movement(2, 100).unwrap();
+        assert_eq!(delegation_strategy.moved_amount(), 100);
+    }
+
+    #[test]
+    fn test_no_movement_limit() {
+        let mut delegation_strategy = DelegationStrategy::new(Pubkey::new_unique(), 0, 0);
+        assert_eq!(delegation_strategy.remaining_movement(1), u64::MAX);
+        delegation_strategy.record_movement(1, 1_000_000).unwrap();
+        assert_eq!(delegation_strategy.remaining_movement(1), u64::MAX);
+    }
+}
@@ -0,0 +1,178 @@
+//! The [`DepositReceipt`] account records a single deposit into a vault so the depositor can prove
+//! their cost basis on-chain. It's opt-in: MintTo only writes a receipt when the depositor passes
+//! in an uninitialized receipt PDA along with its base signer. The depositor pays for the account
+//! and can close it to reclaim the rent once it's no longer needed.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+impl Discriminator for DepositReceipt {
+    const DISCRIMINATOR: u8 = 20;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct DepositReceipt {
+    /// The vault deposited into
+    pub vault: Pubkey,
+
+    /// The depositor
+    pub depositor: Pubkey,
+
+    /// The base account used as a PDA seed
+    pub base: Pubkey,
+
+    /// The amount of supported tokens deposited
+    amount_in: PodU64,
+
+    /// The amount of VRT minted to the depositor, after fees
+    vrt_minted: PodU64,
+
+    /// The slot the deposit was made
+    slot: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl DepositReceipt {
+    pub fn new(
+        vault: Pubkey,
+        depositor: Pubkey,
+        base: Pubkey,
+        amount_in: u64,
+        vrt_minted: u64,
+        slot: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            vault,
+            depositor,
+            base,
+            amount_in: PodU64::from(amount_in),
+            vrt_minted: PodU64::from(vrt_minted),
+            slot: PodU64::from(slot),
+            bump,
+            reserved: [0; 263],
+        }
+    }
+
+    pub fn amount_in(&self) -> u64 {
+        self.amount_in.into()
+    }
+
+    pub fn vrt_minted(&self) -> u64 {
+        self.vrt_minted.into()
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.slot.into()
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault
+    /// * `depositor` - The depositor
+    /// * `base` - The base account used as a PDA seed
+    pub fn seeds(vault: &Pubkey, depositor: &Pubkey, base: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"deposit_receipt".to_vec(),
+            vault.to_bytes().to_vec(),
+            depositor.to_bytes().to_vec(),
+            base.to_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault
+    /// * `depositor` - The depositor
+    /// * `base` - The base account used as a PDA seed
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        depositor: &Pubkey,
+        base: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, depositor, base);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`DepositReceipt`] account. The vault isn't loaded so receipts can be closed
+    /// after the vault is.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `deposit_receipt` - The account to load
+    /// * `depositor` - The depositor
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        deposit_receipt: &AccountInfo,
+        depositor: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if deposit_receipt.owner.ne(program_id) {
+            msg!("Deposit receipt account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if deposit_receipt.data_is_empty() {
+            msg!("Deposit receipt account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if expect_writable && !deposit_receipt.is_writable {
+            msg!("Deposit receipt account is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
+        }
+        if deposit_receipt.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Deposit receipt account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let deposit_receipt_data = deposit_receipt.data.borrow();
+        let receipt = Self::try_from_slice_unchecked(&deposit_receipt_data)?;
+        let expected_pubkey =
+            Self::find_program_address(program_id, &receipt.vault, depositor.key, &receipt.base).0;
+        if deposit_receipt.key.ne(&expected_pubkey) {
+            msg!("Deposit receipt account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deposit_receipt_no_padding() {
+        let deposit_receipt_size = std::mem::size_of::<DepositReceipt>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // vault
+            std::mem::size_of::<Pubkey>() + // depositor
+            std::mem::size_of::<Pubkey>() + // base
+            std::mem::size_of::<PodU64>() + // amount_in
+            std::mem::size_of::<PodU64>() + // vrt_minted
+            std::mem::size_of::<PodU64>() + // slot
+            std::mem::size_of::<u8>() + // bump
+            263; // reserved
+        assert_eq!(deposit_receipt_size, sum_of_fields);
+    }
+}
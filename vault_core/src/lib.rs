@@ -1,12 +1,36 @@
 pub mod config;
+pub mod cooldown_cohorts;
+pub mod delegation_snapshot;
 pub mod delegation_state;
+pub mod delegation_strategy;
+pub mod deposit_receipt;
 pub mod loader;
+pub mod ncn_reward_router;
+pub mod operator_stake_weight;
+pub mod reward_claim;
+pub mod reward_distribution;
+pub mod rounding;
+pub mod slash_proposal;
 pub mod vault;
+pub mod vault_deposit_allowlist_entry;
+pub mod vault_depositor;
+pub mod vault_epoch_stats;
+pub mod vault_insurance;
 pub mod vault_ncn_slasher_operator_ticket;
 pub mod vault_ncn_slasher_ticket;
 pub mod vault_ncn_ticket;
 pub mod vault_operator_delegation;
+pub mod vault_operator_delegation_snapshot;
 pub mod vault_staker_withdrawal_ticket;
 pub mod vault_update_state_tracker;
 
 pub const MAX_FEE_BPS: u16 = 10_000;
+
+/// The number of epochs a proposed VRT mint authority handoff can be challenged before it executes
+pub const MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS: u64 = 3;
+
+/// The number of epochs a proposed admin burn of a withdrawal ticket can be cancelled before it executes
+pub const ADMIN_BURN_TIMELOCK_EPOCHS: u64 = 3;
+
+/// The maximum number of epochs a vault can require withdrawals to be enqueued for
+pub const MAX_WITHDRAWAL_DELAY_EPOCHS: u64 = 32;
@@ -1,5 +1,8 @@
 //! Loader functions for the vault program.
-use jito_vault_sdk::inline_mpl_token_metadata::{self, pda::find_metadata_account};
+use jito_vault_sdk::{
+    error::VaultError,
+    inline_mpl_token_metadata::{self, pda::find_metadata_account},
+};
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
 /// Loads the account as a mpl metadata program, returning an error if it is not.
@@ -33,7 +36,7 @@ pub fn load_mpl_metadata(info: &AccountInfo, vrt_mint: &Pubkey) -> Result<(), Pr
     let (metadata_account_pubkey, _) = find_metadata_account(vrt_mint);
 
     if metadata_account_pubkey.ne(info.key) {
-        Err(ProgramError::InvalidAccountData)
+        Err(VaultError::VaultMetadataAccountInvalid.into())
     } else {
         Ok(())
     }
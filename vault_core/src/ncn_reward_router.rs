@@ -0,0 +1,360 @@
+//! The [`NcnRewardRouter`] account collects an NCN's rewards for a vault's stake in an epoch. The
+//! NCN transfers the rewards, in the vault's supported mint, to the router's associated token
+//! account, and anyone can crank them out with `DistributeNcnRewards`. The protocol takes its fee
+//! first, each operator is paid its commission on the share of the rest matching its stake, and
+//! what's left goes to the vault token account, where `UpdateVaultBalance` picks it up as rewards.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    rounding::{bps_of, mul_div, RoundingDirection},
+    MAX_FEE_BPS,
+};
+
+impl Discriminator for NcnRewardRouter {
+    const DISCRIMINATOR: u8 = 18;
+}
+
+/// How a distribution of NCN rewards is split
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NcnRewardSplit {
+    /// The rewards paid to the protocol fee wallet
+    pub protocol_rewards: u64,
+
+    /// The commission paid to each operator, in the order the operators were given
+    pub operator_rewards: Vec<u64>,
+
+    /// The rewards paid to the vault
+    pub vault_rewards: u64,
+}
+
+impl NcnRewardSplit {
+    /// The commission paid to all of the operators
+    pub fn total_operator_rewards(&self) -> Result<u64, VaultError> {
+        self.operator_rewards
+            .iter()
+            .try_fold(0_u64, |total, rewards| total.checked_add(*rewards))
+            .ok_or(VaultError::VaultOverflow)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct NcnRewardRouter {
+    /// The NCN paying the rewards
+    pub ncn: Pubkey,
+
+    /// The vault account
+    pub vault: Pubkey,
+
+    /// The NCN epoch the rewards are for
+    epoch: PodU64,
+
+    /// The rewards distributed so far
+    total_rewards: PodU64,
+
+    /// The rewards paid to the protocol fee wallet so far
+    protocol_rewards: PodU64,
+
+    /// The commission paid to operators so far
+    operator_rewards: PodU64,
+
+    /// The rewards paid to the vault so far
+    vault_rewards: PodU64,
+
+    /// The slot the rewards were last distributed
+    last_distribution_slot: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl NcnRewardRouter {
+    pub fn new(ncn: Pubkey, vault: Pubkey, epoch: u64, bump: u8) -> Self {
+        Self {
+            ncn,
+            vault,
+            epoch: PodU64::from(epoch),
+            total_rewards: PodU64::from(0),
+            protocol_rewards: PodU64::from(0),
+            operator_rewards: PodU64::from(0),
+            vault_rewards: PodU64::from(0),
+            last_distribution_slot: PodU64::from(0),
+            bump,
+            reserved: [0; 263],
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn total_rewards(&self) -> u64 {
+        self.total_rewards.into()
+    }
+
+    pub fn protocol_rewards(&self) -> u64 {
+        self.protocol_rewards.into()
+    }
+
+    pub fn operator_rewards(&self) -> u64 {
+        self.operator_rewards.into()
+    }
+
+    pub fn vault_rewards(&self) -> u64 {
+        self.vault_rewards.into()
+    }
+
+    pub fn last_distribution_slot(&self) -> u64 {
+        self.last_distribution_slot.into()
+    }
+
+    /// Splits `rewards` between the protocol, the operators and the vault. The protocol takes
+    /// `protocol_fee_bps` first, then each operator is paid its commission on the share of the
+    /// rest matching its stake. Every split rounds down, so the vault keeps the remainder, and it
+    /// keeps everything if nothing is delegated.
+    ///
+    /// # Arguments
+    /// * `rewards` - The rewards to split
+    /// * `protocol_fee_bps` - The protocol's fee in basis points
+    /// * `operators` - Each operator's `(stake, commission_bps)`
+    pub fn split_rewards(
+        rewards: u64,
+        protocol_fee_bps: u16,
+        operators: &[(u64, u16)],
+    ) -> Result<NcnRewardSplit, VaultError> {
+        let protocol_rewards = bps_of(rewards, protocol_fee_bps, RoundingDirection::Down)?;
+        let stakers_rewards = rewards
+            .checked_sub(protocol_rewards)
+            .ok_or(VaultError::VaultUnderflow)?;
+
+        let total_stake = operators
+            .iter()
+            .try_fold(0_u64, |total, (stake, _)| total.checked_add(*stake))
+            .ok_or(VaultError::VaultOverflow)?;
+        let operator_rewards = operators
+            .iter()
+            .map(|(stake, commission_bps)| {
+                if total_stake == 0 {
+                    return Ok(0);
+                }
+                let operator_share = mul_div(
+                    stakers_rewards,
+                    *stake,
+                    total_stake,
+                    RoundingDirection::Down,
+                )?;
+                bps_of(
+                    operator_share,
+                    (*commission_bps).min(MAX_FEE_BPS),
+                    RoundingDirection::Down,
+                )
+            })
+            .collect::<Result<Vec<u64>, VaultError>>()?;
+
+        let mut split = NcnRewardSplit {
+            protocol_rewards,
+            operator_rewards,
+            vault_rewards: 0,
+        };
+        split.vault_rewards = stakers_rewards
+            .checked_sub(split.total_operator_rewards()?)
+            .ok_or(VaultError::VaultUnderflow)?;
+        Ok(split)
+    }
+
+    /// Records a distribution of rewards
+    ///
+    /// # Arguments
+    /// * `split` - How the rewards were split
+    /// * `slot` - The current slot
+    pub fn record_distribution(
+        &mut self,
+        split: &NcnRewardSplit,
+        slot: u64,
+    ) -> Result<(), VaultError> {
+        let operator_rewards = split.total_operator_rewards()?;
+        let rewards = split
+            .protocol_rewards
+            .checked_add(operator_rewards)
+            .and_then(|rewards| rewards.checked_add(split.vault_rewards))
+            .ok_or(VaultError::VaultOverflow)?;
+
+        self.total_rewards = PodU64::from(
+            self.total_rewards()
+                .checked_add(rewards)
+                .ok_or(VaultError::VaultOverflow)?,
+        );
+        self.protocol_rewards = PodU64::from(
+            self.protocol_rewards()
+                .checked_add(split.protocol_rewards)
+                .ok_or(VaultError::VaultOverflow)?,
+        );
+        self.operator_rewards = PodU64::from(
+            self.operator_rewards()
+                .checked_add(operator_rewards)
+                .ok_or(VaultError::VaultOverflow)?,
+        );
+        self.vault_rewards = PodU64::from(
+            self.vault_rewards()
+                .checked_add(split.vault_rewards)
+                .ok_or(VaultError::VaultOverflow)?,
+        );
+        self.last_distribution_slot = PodU64::from(slot);
+        Ok(())
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `ncn` - The NCN account
+    /// * `vault` - The vault account
+    /// * `epoch` - The NCN epoch the rewards are for
+    pub fn seeds(ncn: &Pubkey, vault: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"ncn_reward_router".to_vec(),
+            ncn.to_bytes().to_vec(),
+            vault.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `ncn` - The NCN account
+    /// * `vault` - The vault account
+    /// * `epoch` - The NCN epoch the rewards are for
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        vault: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, vault, epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`NcnRewardRouter`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `ncn_reward_router` - The account to load
+    /// * `ncn` - The NCN account
+    /// * `vault` - The vault account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        ncn_reward_router: &AccountInfo,
+        ncn: &AccountInfo,
+        vault: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if ncn_reward_router.owner.ne(program_id) {
+            msg!("NCN reward router account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if ncn_reward_router.data_is_empty() {
+            msg!("NCN reward router account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if expect_writable && !ncn_reward_router.is_writable {
+            msg!("NCN reward router account is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
+        }
+        if ncn_reward_router.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("NCN reward router account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let epoch = Self::try_from_slice_unchecked(&ncn_reward_router.data.borrow())?.epoch();
+        let expected_pubkey = Self::find_program_address(program_id, ncn.key, vault.key, epoch).0;
+        if ncn_reward_router.key.ne(&expected_pubkey) {
+            msg!("NCN reward router account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ncn_reward_router_no_padding() {
+        let ncn_reward_router_size = std::mem::size_of::<NcnRewardRouter>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // ncn
+            std::mem::size_of::<Pubkey>() + // vault
+            std::mem::size_of::<PodU64>() + // epoch
+            std::mem::size_of::<PodU64>() + // total_rewards
+            std::mem::size_of::<PodU64>() + // protocol_rewards
+            std::mem::size_of::<PodU64>() + // operator_rewards
+            std::mem::size_of::<PodU64>() + // vault_rewards
+            std::mem::size_of::<PodU64>() + // last_distribution_slot
+            std::mem::size_of::<u8>() + // bump
+            263; // reserved
+        assert_eq!(ncn_reward_router_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_split_rewards() {
+        // 10% protocol fee, then operators with 3:1 stake and 10% and 50% commission
+        let split =
+            NcnRewardRouter::split_rewards(10_000, 1_000, &[(300, 1_000), (100, 5_000)]).unwrap();
+        assert_eq!(split.protocol_rewards, 1_000);
+        assert_eq!(split.operator_rewards, vec![675, 1_125]);
+        assert_eq!(split.vault_rewards, 7_200);
+    }
+
+    #[test]
+    fn test_split_rewards_rounds_down_for_the_vault() {
+        let split = NcnRewardRouter::split_rewards(100, 333, &[(1, 3_333), (2, 3_333)]).unwrap();
+        assert_eq!(split.protocol_rewards, 3);
+        // 97 split 32/64, then a third of each rounded down
+        assert_eq!(split.operator_rewards, vec![10, 21]);
+        assert_eq!(split.vault_rewards, 66);
+    }
+
+    #[test]
+    fn test_split_rewards_nothing_delegated() {
+        let split = NcnRewardRouter::split_rewards(1_000, 0, &[(0, 5_000)]).unwrap();
+        assert_eq!(split.operator_rewards, vec![0]);
+        assert_eq!(split.vault_rewards, 1_000);
+
+        let split = NcnRewardRouter::split_rewards(1_000, 0, &[]).unwrap();
+        assert_eq!(split.vault_rewards, 1_000);
+    }
+
+    #[test]
+    fn test_record_distribution() {
+        let mut ncn_reward_router =
+            NcnRewardRouter::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, 0);
+        let split =
+            NcnRewardRouter::split_rewards(10_000, 1_000, &[(300, 1_000), (100, 5_000)]).unwrap();
+
+        ncn_reward_router.record_distribution(&split, 10).unwrap();
+        ncn_reward_router.record_distribution(&split, 20).unwrap();
+        assert_eq!(ncn_reward_router.total_rewards(), 20_000);
+        assert_eq!(ncn_reward_router.protocol_rewards(), 2_000);
+        assert_eq!(ncn_reward_router.operator_rewards(), 3_600);
+        assert_eq!(ncn_reward_router.vault_rewards(), 14_400);
+        assert_eq!(ncn_reward_router.last_distribution_slot(), 20);
+    }
+}
@@ -0,0 +1,285 @@
+//! The [`OperatorStakeWeight`] account sums an operator's stake across every vault delegating to
+//! it for an NCN and NCN epoch, so NCN programs can weight the operator's votes by reading a single
+//! account instead of walking every vault.
+//!
+//! It's built up by a permissionless crank, one vault at a time, from each vault's
+//! [`crate::vault_operator_delegation_snapshot::VaultOperatorDelegationSnapshot`] for the epoch.
+//! Snapshots are never modified, so the weight doesn't depend on when the crank runs. Only vaults
+//! with the NCN's supported mint are summed, weights in different mints live in different accounts.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+impl Discriminator for OperatorStakeWeight {
+    const DISCRIMINATOR: u8 = 21;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct OperatorStakeWeight {
+    /// The NCN account
+    pub ncn: Pubkey,
+
+    /// The operator account
+    pub operator: Pubkey,
+
+    /// The mint of the vaults summed
+    pub supported_mint: Pubkey,
+
+    /// The NCN epoch the stake weight is for
+    ncn_epoch: PodU64,
+
+    /// The sum of the staked amounts of the vaults counted so far
+    stake_weight: PodU64,
+
+    /// The number of vaults counted so far
+    vault_count: PodU64,
+
+    /// The slot the account was created
+    slot_created: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// A bit per vault index, set once the vault was counted so it can't be counted twice
+    counted_vaults: [u8; 256],
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl OperatorStakeWeight {
+    /// The number of vaults that can be counted, vaults with a higher index are left out
+    pub const MAX_VAULTS: u64 = 2048;
+
+    pub fn new(
+        ncn: Pubkey,
+        operator: Pubkey,
+        supported_mint: Pubkey,
+        ncn_epoch: u64,
+        slot_created: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            ncn,
+            operator,
+            supported_mint,
+            ncn_epoch: PodU64::from(ncn_epoch),
+            stake_weight: PodU64::from(0),
+            vault_count: PodU64::from(0),
+            slot_created: PodU64::from(slot_created),
+            bump,
+            counted_vaults: [0; 256],
+            reserved: [0; 263],
+        }
+    }
+
+    pub fn ncn_epoch(&self) -> u64 {
+        self.ncn_epoch.into()
+    }
+
+    pub fn stake_weight(&self) -> u64 {
+        self.stake_weight.into()
+    }
+
+    pub fn vault_count(&self) -> u64 {
+        self.vault_count.into()
+    }
+
+    pub fn slot_created(&self) -> u64 {
+        self.slot_created.into()
+    }
+
+    /// Whether the vault at `vault_index` was already counted
+    pub fn is_vault_counted(&self, vault_index: u64) -> bool {
+        vault_index < Self::MAX_VAULTS
+            && self.counted_vaults[(vault_index / 8) as usize] & (1 << (vault_index % 8)) != 0
+    }
+
+    /// Adds a vault's staked amount to the stake weight
+    ///
+    /// # Arguments
+    /// * `vault_index` - The index of the vault
+    /// * `staked_amount` - The vault's staked amount with the operator at the start of the epoch
+    pub fn count_vault(&mut self, vault_index: u64, staked_amount: u64) -> Result<(), VaultError> {
+        if vault_index >= Self::MAX_VAULTS {
+            msg!(
+                "Vault index {} exceeds the maximum of {} vaults",
+                vault_index,
+                Self::MAX_VAULTS
+            );
+            return Err(VaultError::OperatorStakeWeightVaultIndexInvalid);
+        }
+        if self.is_vault_counted(vault_index) {
+            msg!("Vault {} was already counted", vault_index);
+            return Err(VaultError::OperatorStakeWeightVaultAlreadyCounted);
+        }
+
+        let stake_weight = self
+            .stake_weight()
+            .checked_add(staked_amount)
+            .ok_or(VaultError::VaultOverflow)?;
+        let vault_count = self
+            .vault_count()
+            .checked_add(1)
+            .ok_or(VaultError::VaultOverflow)?;
+        self.stake_weight = PodU64::from(stake_weight);
+        self.vault_count = PodU64::from(vault_count);
+        self.counted_vaults[(vault_index / 8) as usize] |= 1 << (vault_index % 8);
+        Ok(())
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `ncn` - The NCN account
+    /// * `operator` - The operator account
+    /// * `supported_mint` - The mint of the vaults summed
+    /// * `ncn_epoch` - The NCN epoch
+    pub fn seeds(
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        supported_mint: &Pubkey,
+        ncn_epoch: u64,
+    ) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"operator_stake_weight".to_vec(),
+            ncn.to_bytes().to_vec(),
+            operator.to_bytes().to_vec(),
+            supported_mint.to_bytes().to_vec(),
+            ncn_epoch.to_le_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `ncn` - The NCN account
+    /// * `operator` - The operator account
+    /// * `supported_mint` - The mint of the vaults summed
+    /// * `ncn_epoch` - The NCN epoch
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        supported_mint: &Pubkey,
+        ncn_epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, operator, supported_mint, ncn_epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`OperatorStakeWeight`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `operator_stake_weight` - The account to load
+    /// * `ncn` - The NCN account
+    /// * `operator` - The operator account
+    /// * `supported_mint` - The mint of the vaults summed
+    /// * `ncn_epoch` - The NCN epoch
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        operator_stake_weight: &AccountInfo,
+        ncn: &AccountInfo,
+        operator: &AccountInfo,
+        supported_mint: &Pubkey,
+        ncn_epoch: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if operator_stake_weight.owner.ne(program_id) {
+            msg!("Operator stake weight account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if operator_stake_weight.data_is_empty() {
+            msg!("Operator stake weight account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if expect_writable && !operator_stake_weight.is_writable {
+            msg!("Operator stake weight account is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
+        }
+        if operator_stake_weight.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Operator stake weight account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let expected_pubkey = Self::find_program_address(
+            program_id,
+            ncn.key,
+            operator.key,
+            supported_mint,
+            ncn_epoch,
+        )
+        .0;
+        if operator_stake_weight.key.ne(&expected_pubkey) {
+            msg!("Operator stake weight account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_stake_weight_no_padding() {
+        let operator_stake_weight_size = std::mem::size_of::<OperatorStakeWeight>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // ncn
+            std::mem::size_of::<Pubkey>() + // operator
+            std::mem::size_of::<Pubkey>() + // supported_mint
+            std::mem::size_of::<PodU64>() + // ncn_epoch
+            std::mem::size_of::<PodU64>() + // stake_weight
+            std::mem::size_of::<PodU64>() + // vault_count
+            std::mem::size_of::<PodU64>() + // slot_created
+            std::mem::size_of::<u8>() + // bump
+            256 + // counted_vaults
+            263; // reserved
+        assert_eq!(operator_stake_weight_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_count_vault_once() {
+        let mut operator_stake_weight = OperatorStakeWeight::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            100,
+            0,
+        );
+
+        operator_stake_weight.count_vault(0, 1_000).unwrap();
+        operator_stake_weight.count_vault(9, 500).unwrap();
+        assert_eq!(operator_stake_weight.stake_weight(), 1_500);
+        assert_eq!(operator_stake_weight.vault_count(), 2);
+        assert!(operator_stake_weight.is_vault_counted(9));
+        assert!(!operator_stake_weight.is_vault_counted(8));
+
+        assert_eq!(
+            operator_stake_weight.count_vault(9, 500),
+            Err(VaultError::OperatorStakeWeightVaultAlreadyCounted)
+        );
+        assert_eq!(
+            operator_stake_weight.count_vault(OperatorStakeWeight::MAX_VAULTS, 500),
+            Err(VaultError::OperatorStakeWeightVaultIndexInvalid)
+        );
+        assert!(!operator_stake_weight.is_vault_counted(OperatorStakeWeight::MAX_VAULTS));
+        assert_eq!(operator_stake_weight.stake_weight(), 1_500);
+    }
+}
@@ -0,0 +1,150 @@
+//! The [`RewardClaim`] account records a staker's claim from a
+//! [`crate::reward_distribution::RewardDistribution`]. It's created by the claim, so a staker can
+//! only claim once from each distribution.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+impl Discriminator for RewardClaim {
+    const DISCRIMINATOR: u8 = 17;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct RewardClaim {
+    /// The reward distribution account
+    pub reward_distribution: Pubkey,
+
+    /// The staker that claimed
+    pub staker: Pubkey,
+
+    /// The amount of rewards claimed
+    amount: PodU64,
+
+    /// The slot the rewards were claimed
+    slot_claimed: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl RewardClaim {
+    pub fn new(
+        reward_distribution: Pubkey,
+        staker: Pubkey,
+        amount: u64,
+        slot_claimed: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            reward_distribution,
+            staker,
+            amount: PodU64::from(amount),
+            slot_claimed: PodU64::from(slot_claimed),
+            bump,
+            reserved: [0; 263],
+        }
+    }
+
+    pub fn amount(&self) -> u64 {
+        self.amount.into()
+    }
+
+    pub fn slot_claimed(&self) -> u64 {
+        self.slot_claimed.into()
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `reward_distribution` - The reward distribution account
+    /// * `staker` - The staker
+    pub fn seeds(reward_distribution: &Pubkey, staker: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"reward_claim".to_vec(),
+            reward_distribution.to_bytes().to_vec(),
+            staker.to_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `reward_distribution` - The reward distribution account
+    /// * `staker` - The staker
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        reward_distribution: &Pubkey,
+        staker: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(reward_distribution, staker);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`RewardClaim`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `reward_claim` - The account to load
+    /// * `reward_distribution` - The reward distribution account
+    /// * `staker` - The staker
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        reward_claim: &AccountInfo,
+        reward_distribution: &AccountInfo,
+        staker: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if reward_claim.owner.ne(program_id) {
+            msg!("Reward claim account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if reward_claim.data_is_empty() {
+            msg!("Reward claim account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if reward_claim.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Reward claim account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let expected_pubkey =
+            Self::find_program_address(program_id, reward_distribution.key, staker.key).0;
+        if reward_claim.key.ne(&expected_pubkey) {
+            msg!("Reward claim account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reward_claim_no_padding() {
+        let reward_claim_size = std::mem::size_of::<RewardClaim>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // reward_distribution
+            std::mem::size_of::<Pubkey>() + // staker
+            std::mem::size_of::<PodU64>() + // amount
+            std::mem::size_of::<PodU64>() + // slot_claimed
+            std::mem::size_of::<u8>() + // bump
+            263; // reserved
+        assert_eq!(reward_claim_size, sum_of_fields);
+    }
+}
@@ -0,0 +1,377 @@
+//! The [`RewardDistribution`] account holds the Merkle root of the rewards owed to each staker of
+//! a vault for an epoch. The vault admin posts the root along with the rewards, which are held in
+//! the vault's token account for the reward mint, and each staker claims their amount with a proof.
+//! Claims are recorded in [`crate::reward_claim::RewardClaim`] accounts, so a claim costs the same
+//! compute no matter how many stakers are in the distribution. If the rewards were earned through
+//! an operator, the operator's commission is paid to its withdrawal fee wallet when the
+//! distribution is posted and the root covers what's left.
+//!
+//! Leaves are `sha256(0x00 || staker || amount)`, with the amount little-endian. Nodes are
+//! `sha256(0x01 || min(left, right) || max(left, right))`, sorting the pair so proofs don't need
+//! to say which side each sibling is on. A node without a sibling is carried up to the next level
+//! as is.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, hash::hashv, msg, program_error::ProgramError, pubkey::Pubkey,
+};
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+impl Discriminator for RewardDistribution {
+    const DISCRIMINATOR: u8 = 16;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct RewardDistribution {
+    /// The vault account
+    pub vault: Pubkey,
+
+    /// The mint of the rewards
+    pub mint: Pubkey,
+
+    /// The epoch the rewards are for
+    epoch: PodU64,
+
+    /// The Merkle root of the `(staker, amount)` claims
+    pub merkle_root: [u8; 32],
+
+    /// The rewards funded for the distribution
+    total_rewards: PodU64,
+
+    /// The rewards claimed so far
+    total_claimed: PodU64,
+
+    /// The number of claims so far
+    num_claims: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// The operator paid a commission on the rewards, or the default pubkey if none was
+    pub operator: Pubkey,
+
+    /// The commission paid to the operator's withdrawal fee wallet, on top of the rewards funded
+    /// for the stakers
+    operator_commission: PodU64,
+
+    /// Reserved space
+    reserved: [u8; 223],
+}
+
+impl RewardDistribution {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        vault: Pubkey,
+        mint: Pubkey,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        total_rewards: u64,
+        bump: u8,
+        operator: Pubkey,
+        operator_commission: u64,
+    ) -> Self {
+        Self {
+            vault,
+            mint,
+            epoch: PodU64::from(epoch),
+            merkle_root,
+            total_rewards: PodU64::from(total_rewards),
+            total_claimed: PodU64::from(0),
+            num_claims: PodU64::from(0),
+            bump,
+            operator,
+            operator_commission: PodU64::from(operator_commission),
+            reserved: [0; 223],
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn total_rewards(&self) -> u64 {
+        self.total_rewards.into()
+    }
+
+    pub fn total_claimed(&self) -> u64 {
+        self.total_claimed.into()
+    }
+
+    pub fn num_claims(&self) -> u64 {
+        self.num_claims.into()
+    }
+
+    pub fn operator_commission(&self) -> u64 {
+        self.operator_commission.into()
+    }
+
+    /// Hashes a staker's claim into a leaf of the tree
+    pub fn leaf_hash(staker: &Pubkey, amount: u64) -> [u8; 32] {
+        hashv(&[LEAF_PREFIX, staker.as_ref(), &amount.to_le_bytes()]).to_bytes()
+    }
+
+    fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[NODE_PREFIX, a, b]).to_bytes()
+        } else {
+            hashv(&[NODE_PREFIX, b, a]).to_bytes()
+        }
+    }
+
+    /// Hashes one level of the tree into the next, carrying up a node without a sibling
+    fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+        level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => Self::node_hash(left, right),
+                [node] => *node,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    /// Computes the Merkle root of a list of `(staker, amount)` claims, all zeroes if there are none
+    pub fn compute_root(claims: &[(Pubkey, u64)]) -> [u8; 32] {
+        let mut level: Vec<[u8; 32]> = claims
+            .iter()
+            .map(|(staker, amount)| Self::leaf_hash(staker, *amount))
+            .collect();
+        if level.is_empty() {
+            return [0; 32];
+        }
+        while level.len() > 1 {
+            level = Self::next_level(&level);
+        }
+        level[0]
+    }
+
+    /// Computes the proof for the claim at `index` in a list of `(staker, amount)` claims
+    pub fn compute_proof(claims: &[(Pubkey, u64)], index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= claims.len() {
+            return None;
+        }
+        let mut level: Vec<[u8; 32]> = claims
+            .iter()
+            .map(|(staker, amount)| Self::leaf_hash(staker, *amount))
+            .collect();
+        let mut index = index;
+        let mut proof = Vec::new();
+        while level.len() > 1 {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            level = Self::next_level(&level);
+            index /= 2;
+        }
+        Some(proof)
+    }
+
+    /// Checks the `proof` shows `staker` is owed `amount` in the distribution
+    pub fn verify_claim(
+        &self,
+        staker: &Pubkey,
+        amount: u64,
+        proof: &[[u8; 32]],
+    ) -> Result<(), VaultError> {
+        let root = proof
+            .iter()
+            .fold(Self::leaf_hash(staker, amount), |node, sibling| {
+                Self::node_hash(&node, sibling)
+            });
+        if root.ne(&self.merkle_root) {
+            msg!("Reward claim proof is invalid");
+            return Err(VaultError::VaultRewardProofInvalid);
+        }
+        Ok(())
+    }
+
+    /// Records a claim of `amount`, failing if the claims would exceed the rewards funded
+    pub fn claim(&mut self, amount: u64) -> Result<(), VaultError> {
+        let total_claimed = self
+            .total_claimed()
+            .checked_add(amount)
+            .ok_or(VaultError::VaultOverflow)?;
+        if total_claimed > self.total_rewards() {
+            msg!(
+                "Claims of {} would exceed the {} rewards funded",
+                total_claimed,
+                self.total_rewards()
+            );
+            return Err(VaultError::VaultRewardsExceeded);
+        }
+        self.total_claimed = PodU64::from(total_claimed);
+        self.num_claims = PodU64::from(
+            self.num_claims()
+                .checked_add(1)
+                .ok_or(VaultError::VaultOverflow)?,
+        );
+        Ok(())
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault account
+    /// * `epoch` - The epoch the rewards are for
+    pub fn seeds(vault: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"reward_distribution".to_vec(),
+            vault.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault account
+    /// * `epoch` - The epoch the rewards are for
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`RewardDistribution`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `reward_distribution` - The account to load
+    /// * `vault` - The vault account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        reward_distribution: &AccountInfo,
+        vault: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if reward_distribution.owner.ne(program_id) {
+            msg!("Reward distribution account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if reward_distribution.data_is_empty() {
+            msg!("Reward distribution account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if expect_writable && !reward_distribution.is_writable {
+            msg!("Reward distribution account is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
+        }
+        if reward_distribution.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Reward distribution account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let epoch = Self::try_from_slice_unchecked(&reward_distribution.data.borrow())?.epoch();
+        let expected_pubkey = Self::find_program_address(program_id, vault.key, epoch).0;
+        if reward_distribution.key.ne(&expected_pubkey) {
+            msg!("Reward distribution account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reward_distribution(claims: &[(Pubkey, u64)], total_rewards: u64) -> RewardDistribution {
+        RewardDistribution::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            RewardDistribution::compute_root(claims),
+            total_rewards,
+            0,
+            Pubkey::default(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_reward_distribution_no_padding() {
+        let reward_distribution_size = std::mem::size_of::<RewardDistribution>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<Pubkey>() + // mint
+            size_of::<PodU64>() + // epoch
+            size_of::<[u8; 32]>() + // merkle_root
+            size_of::<PodU64>() + // total_rewards
+            size_of::<PodU64>() + // total_claimed
+            size_of::<PodU64>() + // num_claims
+            size_of::<u8>() + // bump
+            size_of::<Pubkey>() + // operator
+            size_of::<PodU64>() + // operator_commission
+            223; // reserved
+        assert_eq!(reward_distribution_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_verify_claim() {
+        let claims: Vec<(Pubkey, u64)> =
+            (1..=7).map(|i| (Pubkey::new_unique(), i * 1_000)).collect();
+        let reward_distribution = reward_distribution(&claims, 28_000);
+
+        for (index, (staker, amount)) in claims.iter().enumerate() {
+            let proof = RewardDistribution::compute_proof(&claims, index).unwrap();
+            reward_distribution
+                .verify_claim(staker, *amount, &proof)
+                .unwrap();
+            assert_eq!(
+                reward_distribution.verify_claim(staker, amount + 1, &proof),
+                Err(VaultError::VaultRewardProofInvalid)
+            );
+        }
+
+        let proof = RewardDistribution::compute_proof(&claims, 0).unwrap();
+        assert_eq!(
+            reward_distribution.verify_claim(&claims[1].0, claims[0].1, &proof),
+            Err(VaultError::VaultRewardProofInvalid)
+        );
+        assert_eq!(RewardDistribution::compute_proof(&claims, 7), None);
+    }
+
+    #[test]
+    fn test_verify_single_claim() {
+        let claims = [(Pubkey::new_unique(), 1_000)];
+        let reward_distribution = reward_distribution(&claims, 1_000);
+
+        let proof = RewardDistribution::compute_proof(&claims, 0).unwrap();
+        assert!(proof.is_empty());
+        reward_distribution
+            .verify_claim(&claims[0].0, 1_000, &proof)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_claim_exceeds_rewards() {
+        let mut reward_distribution = reward_distribution(&[], 1_000);
+
+        reward_distribution.claim(600).unwrap();
+        assert_eq!(
+            reward_distribution.claim(401),
+            Err(VaultError::VaultRewardsExceeded)
+        );
+        reward_distribution.claim(400).unwrap();
+        assert_eq!(reward_distribution.total_claimed(), 1_000);
+        assert_eq!(reward_distribution.num_claims(), 2);
+    }
+}
@@ -0,0 +1,96 @@
+//! Fixed-point helpers for the vault's token math. Every division takes an explicit
+//! [`RoundingDirection`] so each call site states who keeps the remainder. The vault always
+//! rounds in its own favor: amounts paid out of the vault round down and amounts paid into the
+//! vault, such as fees, round up, so repeated dust operations can't drain it.
+use jito_vault_sdk::error::VaultError;
+
+use crate::MAX_FEE_BPS;
+
+/// The direction to round the result of a division in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingDirection {
+    /// Round towards zero, for amounts leaving the vault
+    Down,
+    /// Round away from zero, for amounts owed to the vault
+    Up,
+}
+
+/// Calculates `amount * numerator / denominator` without intermediate overflow, rounding in
+/// the given direction
+///
+/// # Returns
+/// * `Result<u64, VaultError>` - The result, or [`VaultError::VaultOverflow`] if the denominator
+///   is zero or the result doesn't fit in a `u64`
+pub fn mul_div(
+    amount: u64,
+    numerator: u64,
+    denominator: u64,
+    rounding: RoundingDirection,
+) -> Result<u64, VaultError> {
+    let product = (amount as u128)
+        .checked_mul(numerator as u128)
+        .ok_or(VaultError::VaultOverflow)?;
+    let denominator = denominator as u128;
+    if denominator == 0 {
+        return Err(VaultError::VaultOverflow);
+    }
+    let result = match rounding {
+        RoundingDirection::Down => product / denominator,
+        RoundingDirection::Up => product.div_ceil(denominator),
+    };
+    result.try_into().map_err(|_| VaultError::VaultOverflow)
+}
+
+/// Calculates `bps` basis points of `amount`, rounding in the given direction
+pub fn bps_of(amount: u64, bps: u16, rounding: RoundingDirection) -> Result<u64, VaultError> {
+    mul_div(amount, bps as u64, MAX_FEE_BPS as u64, rounding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul_div_rounding() {
+        assert_eq!(mul_div(10, 1, 3, RoundingDirection::Down), Ok(3));
+        assert_eq!(mul_div(10, 1, 3, RoundingDirection::Up), Ok(4));
+        assert_eq!(mul_div(9, 1, 3, RoundingDirection::Down), Ok(3));
+        assert_eq!(mul_div(9, 1, 3, RoundingDirection::Up), Ok(3));
+        assert_eq!(mul_div(0, 5, 3, RoundingDirection::Up), Ok(0));
+    }
+
+    #[test]
+    fn test_mul_div_no_intermediate_overflow() {
+        assert_eq!(
+            mul_div(u64::MAX, u64::MAX, u64::MAX, RoundingDirection::Down),
+            Ok(u64::MAX)
+        );
+        assert_eq!(
+            mul_div(u64::MAX, 2, 1, RoundingDirection::Down),
+            Err(VaultError::VaultOverflow)
+        );
+    }
+
+    #[test]
+    fn test_mul_div_zero_denominator() {
+        assert_eq!(
+            mul_div(1, 1, 0, RoundingDirection::Down),
+            Err(VaultError::VaultOverflow)
+        );
+        assert_eq!(
+            mul_div(1, 1, 0, RoundingDirection::Up),
+            Err(VaultError::VaultOverflow)
+        );
+    }
+
+    #[test]
+    fn test_bps_of() {
+        assert_eq!(bps_of(1, 1, RoundingDirection::Down), Ok(0));
+        assert_eq!(bps_of(1, 1, RoundingDirection::Up), Ok(1));
+        assert_eq!(bps_of(10_000, 25, RoundingDirection::Up), Ok(25));
+        assert_eq!(
+            bps_of(u64::MAX, MAX_FEE_BPS, RoundingDirection::Down),
+            Ok(u64::MAX)
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! The [`SlashProposal`] account holds a slash proposed by a slasher while the vault program's slash
+//! veto window is enabled. It can be executed once the veto window has passed, unless the config's
+//! slash veto admin vetoes it first.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+impl Discriminator for SlashProposal {
+    const DISCRIMINATOR: u8 = 12;
+}
+
+/// The [`SlashProposal`] account holds a slash proposed by a slasher while the vault program's
+/// slash veto window is enabled. The slashed amount is counted against the slasher's per-epoch
+/// limit when proposed and released again if the slash is vetoed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct SlashProposal {
+    /// The vault to slash
+    pub vault: Pubkey,
+
+    /// The node consensus network the slasher slashes on behalf of
+    pub ncn: Pubkey,
+
+    /// The operator to slash
+    pub operator: Pubkey,
+
+    /// The slasher that proposed the slash
+    pub slasher: Pubkey,
+
+    /// The base account used as a PDA seed
+    pub base: Pubkey,
+
+    /// The hash of the evidence backing the slash, published by the slasher off-chain
+    pub evidence_hash: [u8; 32],
+
+    /// The amount to slash
+    amount: PodU64,
+
+    /// The NCN epoch the slash was proposed in
+    epoch: PodU64,
+
+    /// The slot the slash can be executed from
+    executable_slot: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl SlashProposal {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        vault: Pubkey,
+        ncn: Pubkey,
+        operator: Pubkey,
+        slasher: Pubkey,
+        base: Pubkey,
+        evidence_hash: [u8; 32],
+        amount: u64,
+        epoch: u64,
+        executable_slot: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            vault,
+            ncn,
+            operator,
+            slasher,
+            base,
+            evidence_hash,
+            amount: PodU64::from(amount),
+            epoch: PodU64::from(epoch),
+            executable_slot: PodU64::from(executable_slot),
+            bump,
+            reserved: [0; 263],
+        }
+    }
+
+    pub fn amount(&self) -> u64 {
+        self.amount.into()
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn executable_slot(&self) -> u64 {
+        self.executable_slot.into()
+    }
+
+    /// The slash can only be executed once the veto window has passed
+    pub fn check_executable(&self, slot: u64) -> Result<(), VaultError> {
+        if slot < self.executable_slot() {
+            msg!(
+                "Slash proposal can't be executed until slot {}",
+                self.executable_slot()
+            );
+            return Err(VaultError::VaultSlashProposalVetoWindowActive);
+        }
+        Ok(())
+    }
+
+    /// Checks the proposal was made by `slasher` against `operator` on behalf of `ncn`
+    pub fn check_parties(
+        &self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        slasher: &Pubkey,
+    ) -> Result<(), VaultError> {
+        if self.ncn.ne(ncn) || self.operator.ne(operator) || self.slasher.ne(slasher) {
+            msg!("Slash proposal does not match the provided NCN, operator or slasher");
+            return Err(VaultError::VaultSlashProposalInvalid);
+        }
+        Ok(())
+    }
+
+    /// Returns the seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault
+    /// * `base` - The base account used as a PDA seed
+    pub fn seeds(vault: &Pubkey, base: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"slash_proposal".to_vec(),
+            vault.to_bytes().to_vec(),
+            base.to_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault
+    /// * `base` - The base account used as a PDA seed
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        base: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, base);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`SlashProposal`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `slash_proposal` - The [`SlashProposal`] account
+    /// * `vault` - The [`crate::vault::Vault`] account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        slash_proposal: &AccountInfo,
+        vault: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if slash_proposal.owner.ne(program_id) {
+            msg!("Slash proposal has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if slash_proposal.data_is_empty() {
+            msg!("Slash proposal data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if expect_writable && !slash_proposal.is_writable {
+            msg!("Slash proposal is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
+        }
+        if slash_proposal.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Slash proposal discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let slash_proposal_data = slash_proposal.data.borrow();
+        let base = Self::try_from_slice_unchecked(&slash_proposal_data)?.base;
+        let expected_pubkey = Self::find_program_address(program_id, vault.key, &base).0;
+        if slash_proposal.key.ne(&expected_pubkey) {
+            msg!("Slash proposal is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slash_proposal_no_padding() {
+        let slash_proposal_size = std::mem::size_of::<SlashProposal>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<Pubkey>() + // ncn
+            size_of::<Pubkey>() + // operator
+            size_of::<Pubkey>() + // slasher
+            size_of::<Pubkey>() + // base
+            size_of::<[u8; 32]>() + // evidence_hash
+            size_of::<PodU64>() + // amount
+            size_of::<PodU64>() + // epoch
+            size_of::<PodU64>() + // executable_slot
+            size_of::<u8>() + // bump
+            263; // reserved
+        assert_eq!(slash_proposal_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_check_executable() {
+        let slash_proposal = SlashProposal::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            [1; 32],
+            100,
+            0,
+            50,
+            0,
+        );
+        assert_eq!(
+            slash_proposal.check_executable(49),
+            Err(VaultError::VaultSlashProposalVetoWindowActive)
+        );
+        assert_eq!(slash_proposal.check_executable(50), Ok(()));
+    }
+}
@@ -2,14 +2,22 @@
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{
     types::{PodU16, PodU64},
-    AccountDeserialize, Discriminator,
+    AccountDeserialize, AccountVersion, Discriminator,
 };
 use jito_jsm_core::loader::load_signer;
-use jito_vault_sdk::error::VaultError;
+use jito_vault_sdk::{error::VaultError, instruction::DepositMode};
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+};
+use spl_associated_token_account::get_associated_token_address;
 
-use crate::{delegation_state::DelegationState, MAX_FEE_BPS};
+use crate::{
+    cooldown_cohorts::CooldownCohorts,
+    delegation_state::DelegationState,
+    rounding::{bps_of, mul_div, RoundingDirection},
+    MAX_FEE_BPS, MAX_WITHDRAWAL_DELAY_EPOCHS, MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS,
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct BurnSummary {
@@ -31,6 +39,31 @@ impl Discriminator for Vault {
     const DISCRIMINATOR: u8 = 2;
 }
 
+impl AccountVersion for Vault {
+    /// Version 1 grew the layout by [`Vault::VERSION_1_GROWTH`] bytes
+    const VERSION: u8 = 1;
+
+    fn size_of_version(version: u8) -> Option<usize> {
+        match version {
+            0 => Some(8 + size_of::<Self>() - Self::VERSION_1_GROWTH),
+            1 => Some(8 + size_of::<Self>()),
+            _ => None,
+        }
+    }
+
+    fn migrate(data: &mut [u8], from_version: u8) -> Result<(), ProgramError> {
+        if from_version < 1 {
+            // the fields in the space version 1 added start out zeroed
+            let version_0_size =
+                Self::size_of_version(0).ok_or(ProgramError::InvalidAccountData)?;
+            data.get_mut(version_0_size..)
+                .ok_or(ProgramError::InvalidAccountData)?
+                .fill(0);
+        }
+        Ok(())
+    }
+}
+
 /// The vault is responsible for holding tokens and minting VRT tokens
 /// based on the amount of tokens deposited.
 /// It also contains several administrative functions for features inside the vault.
@@ -137,11 +170,137 @@ pub struct Vault {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// Whether the vault is paused, halting minting, burning, withdrawals and delegation changes
+    is_paused: u8,
+
+    /// The admin responsible for pausing and unpausing the vault during incidents
+    pub pause_admin: Pubkey,
+
+    /// The deposit fee in basis points that takes effect the epoch after the last fee change
+    next_deposit_fee_bps: PodU16,
+
+    /// The withdrawal fee in basis points that takes effect the epoch after the last fee change
+    next_withdrawal_fee_bps: PodU16,
+
+    /// Whether a deposit or withdrawal fee change is waiting to take effect
+    is_fee_change_pending: u8,
+
+    /// The deposit fees, in VRT, accrued since the last time fees were minted to the fee wallet
+    vrt_fees_accrued: PodU64,
+
+    /// The last slot the accrued fees were minted to the fee wallet
+    last_fee_materialization_slot: PodU64,
+
+    /// The metadata admin responsible for creating and updating the VRT token metadata
+    pub metadata_admin: Pubkey,
+
+    /// The minimum amount of assets an operator delegation may hold as stake, smaller amounts are swept back to idle
+    min_delegation_amount: PodU64,
+
+    /// Who is allowed to deposit into the vault, see [`DepositMode`]
+    deposit_mode: u8,
+
+    /// The max amount of tokens that can be deposited in a single MintTo, 0 means no limit
+    max_deposit_per_transaction: PodU64,
+
+    /// The max amount of tokens a single depositor can deposit over the life of the vault,
+    /// tracked in their [`crate::vault_depositor::VaultDepositor`] account, 0 means no limit
+    max_deposit_per_wallet: PodU64,
+
+    /// The root of the [`crate::delegation_snapshot::DelegationSnapshot`] over the operator
+    /// delegations, computed during the last full state update
+    delegation_snapshot_root: [u8; 32],
+
+    /// The NCN epoch of the last delegation snapshot
+    delegation_snapshot_epoch: PodU64,
+
+    /// The successor program PDA the VRT mint authority is proposed to be handed off to,
+    /// the default pubkey when no handoff is pending
+    pub mint_authority_successor: Pubkey,
+
+    /// The slot after which the pending mint authority handoff can be executed
+    mint_authority_handoff_slot: PodU64,
+
+    /// Whether the vault has a [`crate::vault_epoch_stats::VaultEpochStats`] account that deposits,
+    /// withdrawals and slashes must update
+    epoch_stats_enabled: u8,
+
+    /// Whether depositors are prevented from burning in the same slot as their last deposit, tracked
+    /// in their [`crate::vault_depositor::VaultDepositor`] account
+    flash_deposit_protection: u8,
+
+    /// The admin that can burn a withdrawal ticket's VRT and send the assets to a recovery
+    /// address, the default pubkey when admin burns aren't enabled. Can only be set before the
+    /// first deposit and never unset.
+    pub burn_admin: Pubkey,
+
+    /// Whether the vault is shut down, blocking deposits and delegation while its delegations are
+    /// cooled down and holders redeem their VRT. A shutdown can't be undone.
+    is_shut_down: u8,
+
+    /// The slot the vault was shut down
+    shutdown_slot: PodU64,
+
+    /// Whether the vault has a [`crate::vault_insurance::VaultInsurance`] fund that fee minting
+    /// and slashes must update
+    insurance_enabled: u8,
+
+    /// The program invoked after every MintTo and Burn with a
+    /// [`jito_vault_sdk::hook::VaultHookInstruction`], the default pubkey when the vault has no hook
+    pub hook_program: Pubkey,
+
+    /// The last slot a cranker was paid for updating the vault balance or closing its update state
+    /// tracker, so the crank reward is paid at most once per epoch
+    last_crank_reward_slot: PodU64,
+
+    /// The max share of the vault's tokens, in basis points, that all slashers together can slash
+    /// in one epoch, 0 means no limit
+    max_total_slashable_bps_per_epoch: PodU16,
+
+    /// The NCN epoch of the slashes tracked in `slashed_this_epoch`
+    slash_epoch: PodU64,
+
+    /// The tokens deposited before the first slash of `slash_epoch`, which the max total slashable
+    /// per epoch is a share of
+    slash_epoch_tokens_deposited: PodU64,
+
+    /// The tokens slashed across all slashers in `slash_epoch`
+    slashed_this_epoch: PodU64,
+
+    /// The vault this vault's VRT can be migrated to, and from, without the withdrawal fee when
+    /// it names this vault as its partner too, the default pubkey when there's no partner
+    pub migration_partner: Pubkey,
+
+    /// The minimum number of full epochs a withdrawal must be enqueued for before it can be
+    /// claimed, on top of stake cooling down. Exits can't skip the queue while it's set.
+    withdrawal_delay_epochs: PodU64,
+
+    /// The length of the vault's epochs in slots, overriding the config's epoch length for the
+    /// vault's updates and withdrawals when non-zero. NCN tickets and slashing keep the config's.
+    epoch_length_slots: PodU64,
+
+    /// Whether exits are paid out net of the pending slashes while a
+    /// [`crate::slash_proposal::SlashProposal`] against the vault is outstanding, so stakers can't
+    /// exit ahead of a proposed slash
+    slash_dispute_haircut: u8,
+
+    /// The total amount of the [`crate::slash_proposal::SlashProposal`]s against the vault that
+    /// haven't been executed or vetoed yet
+    pending_slash_amount: PodU64,
+
+    /// The vrt_cooling_down_amount bucketed by the number of epochs it has been cooling down for,
+    /// mirroring the cohorts of the stake cooling down to pay for it. VRT that started cooling down
+    /// before cohorts were tracked isn't in any cohort and is ready to claim at the next update.
+    vrt_cooling_down_cohorts: CooldownCohorts,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 161],
 }
 
 impl Vault {
+    /// The number of bytes version 1 of the layout added to the end of the account
+    pub const VERSION_1_GROWTH: usize = 256;
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         vrt_mint: Pubkey,
@@ -185,7 +344,41 @@ impl Vault {
             slasher_count: PodU64::from(0),
             bump,
             delegation_state: DelegationState::default(),
-            reserved: [0; 263],
+            is_paused: 0,
+            pause_admin: admin,
+            next_deposit_fee_bps: PodU16::from(deposit_fee_bps),
+            next_withdrawal_fee_bps: PodU16::from(withdrawal_fee_bps),
+            is_fee_change_pending: 0,
+            vrt_fees_accrued: PodU64::from(0),
+            last_fee_materialization_slot: PodU64::from(0),
+            metadata_admin: admin,
+            min_delegation_amount: PodU64::from(0),
+            deposit_mode: DepositMode::Open as u8,
+            max_deposit_per_transaction: PodU64::from(0),
+            max_deposit_per_wallet: PodU64::from(0),
+            delegation_snapshot_root: [0; 32],
+            delegation_snapshot_epoch: PodU64::from(0),
+            mint_authority_successor: Pubkey::default(),
+            mint_authority_handoff_slot: PodU64::from(0),
+            epoch_stats_enabled: 0,
+            flash_deposit_protection: 0,
+            burn_admin: Pubkey::default(),
+            is_shut_down: 0,
+            shutdown_slot: PodU64::from(0),
+            insurance_enabled: 0,
+            hook_program: Pubkey::default(),
+            last_crank_reward_slot: PodU64::from(0),
+            max_total_slashable_bps_per_epoch: PodU16::from(0),
+            slash_epoch: PodU64::from(0),
+            slash_epoch_tokens_deposited: PodU64::from(0),
+            slashed_this_epoch: PodU64::from(0),
+            migration_partner: Pubkey::default(),
+            withdrawal_delay_epochs: PodU64::from(0),
+            epoch_length_slots: PodU64::from(0),
+            slash_dispute_haircut: 0,
+            pending_slash_amount: PodU64::from(0),
+            vrt_cooling_down_cohorts: CooldownCohorts::default(),
+            reserved: [0; 161],
         }
     }
 
@@ -280,6 +473,10 @@ impl Vault {
         self.vrt_ready_to_claim_amount.into()
     }
 
+    pub const fn vrt_cooling_down_cohorts(&self) -> &CooldownCohorts {
+        &self.vrt_cooling_down_cohorts
+    }
+
     pub fn deposit_fee_bps(&self) -> u16 {
         self.deposit_fee_bps.into()
     }
@@ -292,10 +489,235 @@ impl Vault {
         self.reward_fee_bps.into()
     }
 
+    pub fn next_deposit_fee_bps(&self) -> u16 {
+        self.next_deposit_fee_bps.into()
+    }
+
+    pub fn next_withdrawal_fee_bps(&self) -> u16 {
+        self.next_withdrawal_fee_bps.into()
+    }
+
+    pub fn is_fee_change_pending(&self) -> bool {
+        self.is_fee_change_pending == 1
+    }
+
+    pub fn vrt_fees_accrued(&self) -> u64 {
+        self.vrt_fees_accrued.into()
+    }
+
+    pub fn last_fee_materialization_slot(&self) -> u64 {
+        self.last_fee_materialization_slot.into()
+    }
+
     pub fn operator_count(&self) -> u64 {
         self.operator_count.into()
     }
 
+    pub fn min_delegation_amount(&self) -> u64 {
+        self.min_delegation_amount.into()
+    }
+
+    pub fn set_min_delegation_amount(&mut self, min_delegation_amount: u64) {
+        self.min_delegation_amount = PodU64::from(min_delegation_amount);
+    }
+
+    pub fn deposit_mode(&self) -> Result<DepositMode, ProgramError> {
+        DepositMode::try_from(self.deposit_mode)
+    }
+
+    pub fn set_deposit_mode(&mut self, deposit_mode: DepositMode) {
+        self.deposit_mode = deposit_mode as u8;
+    }
+
+    pub fn max_deposit_per_transaction(&self) -> u64 {
+        self.max_deposit_per_transaction.into()
+    }
+
+    pub fn set_max_deposit_per_transaction(&mut self, max_deposit_per_transaction: u64) {
+        self.max_deposit_per_transaction = PodU64::from(max_deposit_per_transaction);
+    }
+
+    pub fn max_deposit_per_wallet(&self) -> u64 {
+        self.max_deposit_per_wallet.into()
+    }
+
+    pub fn set_max_deposit_per_wallet(&mut self, max_deposit_per_wallet: u64) {
+        self.max_deposit_per_wallet = PodU64::from(max_deposit_per_wallet);
+    }
+
+    pub fn flash_deposit_protection(&self) -> bool {
+        self.flash_deposit_protection == 1
+    }
+
+    pub fn set_flash_deposit_protection(&mut self, enabled: bool) {
+        self.flash_deposit_protection = u8::from(enabled);
+    }
+
+    pub fn admin_burn_enabled(&self) -> bool {
+        self.burn_admin.ne(&Pubkey::default())
+    }
+
+    /// Irreversibly enables admin burns with `burn_admin`. Depositors must know about the burn
+    /// admin before depositing, so it can only be enabled while the vault has no VRT.
+    pub fn enable_admin_burn(&mut self, burn_admin: &Pubkey) -> Result<(), VaultError> {
+        if self.admin_burn_enabled() {
+            msg!("Admin burn is already enabled");
+            return Err(VaultError::VaultAdminBurnAlreadyEnabled);
+        }
+        if burn_admin.eq(&Pubkey::default()) {
+            msg!("Burn admin can't be the default pubkey");
+            return Err(VaultError::VaultBurnAdminInvalid);
+        }
+        if self.vrt_supply() > 0 || self.tokens_deposited() > 0 {
+            msg!("Admin burn can only be enabled before the first deposit");
+            return Err(VaultError::VaultAdminBurnEnableAfterDeposit);
+        }
+        self.burn_admin = *burn_admin;
+        Ok(())
+    }
+
+    pub fn check_burn_admin(&self, burn_admin: &Pubkey) -> Result<(), VaultError> {
+        if !self.admin_burn_enabled() {
+            msg!("Admin burn is not enabled");
+            return Err(VaultError::VaultAdminBurnNotEnabled);
+        }
+        if self.burn_admin.ne(burn_admin) {
+            msg!("Burn admin does not match vault burn admin");
+            return Err(VaultError::VaultBurnAdminInvalid);
+        }
+        Ok(())
+    }
+
+    /// Whether MintTo needs the depositor's [`crate::vault_depositor::VaultDepositor`] account
+    pub fn tracks_depositors(&self) -> bool {
+        self.max_deposit_per_wallet() > 0 || self.flash_deposit_protection()
+    }
+
+    pub const fn delegation_snapshot_root(&self) -> &[u8; 32] {
+        &self.delegation_snapshot_root
+    }
+
+    pub fn delegation_snapshot_epoch(&self) -> u64 {
+        self.delegation_snapshot_epoch.into()
+    }
+
+    pub fn set_delegation_snapshot(&mut self, ncn_epoch: u64, root: [u8; 32]) {
+        self.delegation_snapshot_epoch = PodU64::from(ncn_epoch);
+        self.delegation_snapshot_root = root;
+    }
+
+    pub fn mint_authority_handoff_slot(&self) -> u64 {
+        self.mint_authority_handoff_slot.into()
+    }
+
+    pub fn epoch_stats_enabled(&self) -> bool {
+        self.epoch_stats_enabled == 1
+    }
+
+    pub fn set_epoch_stats_enabled(&mut self) {
+        self.epoch_stats_enabled = 1;
+    }
+
+    pub fn insurance_enabled(&self) -> bool {
+        self.insurance_enabled == 1
+    }
+
+    pub fn set_insurance_enabled(&mut self) {
+        self.insurance_enabled = 1;
+    }
+
+    pub fn has_hook_program(&self) -> bool {
+        self.hook_program.ne(&Pubkey::default())
+    }
+
+    pub fn is_mint_authority_handoff_pending(&self) -> bool {
+        self.mint_authority_successor.ne(&Pubkey::default())
+    }
+
+    /// Proposes handing the VRT mint authority off to `successor`, which can be executed once
+    /// [`MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS`] full epochs have passed
+    pub fn propose_mint_authority_handoff(
+        &mut self,
+        successor: &Pubkey,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), VaultError> {
+        if self.is_mint_authority_handoff_pending() {
+            msg!("A mint authority handoff is already pending");
+            return Err(VaultError::VaultMintAuthorityHandoffPending);
+        }
+        if successor.eq(&Pubkey::default()) {
+            msg!("Mint authority successor can't be the default pubkey");
+            return Err(VaultError::VaultMintAuthoritySuccessorInvalid);
+        }
+        let handoff_slot = MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS
+            .checked_mul(epoch_length)
+            .and_then(|challenge_slots| slot.checked_add(challenge_slots))
+            .ok_or(VaultError::VaultOverflow)?;
+        self.mint_authority_successor = *successor;
+        self.mint_authority_handoff_slot = PodU64::from(handoff_slot);
+        Ok(())
+    }
+
+    pub fn cancel_mint_authority_handoff(&mut self) -> Result<(), VaultError> {
+        if !self.is_mint_authority_handoff_pending() {
+            msg!("No mint authority handoff is pending");
+            return Err(VaultError::VaultMintAuthorityHandoffNotPending);
+        }
+        self.mint_authority_successor = Pubkey::default();
+        self.mint_authority_handoff_slot = PodU64::from(0);
+        Ok(())
+    }
+
+    /// Completes the pending mint authority handoff once its challenge window has passed
+    ///
+    /// # Returns
+    /// * `Result<Pubkey, VaultError>` - The successor to set as the VRT mint authority
+    pub fn execute_mint_authority_handoff(&mut self, slot: u64) -> Result<Pubkey, VaultError> {
+        if !self.is_mint_authority_handoff_pending() {
+            msg!("No mint authority handoff is pending");
+            return Err(VaultError::VaultMintAuthorityHandoffNotPending);
+        }
+        if slot < self.mint_authority_handoff_slot() {
+            msg!(
+                "Mint authority handoff can't be executed until slot {}",
+                self.mint_authority_handoff_slot()
+            );
+            return Err(VaultError::VaultMintAuthorityHandoffChallengeWindowActive);
+        }
+        let successor = self.mint_authority_successor;
+        self.mint_authority_successor = Pubkey::default();
+        self.mint_authority_handoff_slot = PodU64::from(0);
+        Ok(successor)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.is_paused == 1
+    }
+
+    pub fn set_is_paused(&mut self, is_paused: bool) {
+        self.is_paused = u8::from(is_paused);
+    }
+
+    pub fn is_shut_down(&self) -> bool {
+        self.is_shut_down == 1
+    }
+
+    pub fn shutdown_slot(&self) -> u64 {
+        self.shutdown_slot.into()
+    }
+
+    /// Shuts the vault down for good. The withdrawal fee is waived, including any pending change,
+    /// so the remaining holders all redeem their pro-rata share of the vault.
+    pub fn shut_down(&mut self, slot: u64) -> Result<(), VaultError> {
+        self.check_is_shut_down()?;
+        self.is_shut_down = 1;
+        self.shutdown_slot = PodU64::from(slot);
+        self.withdrawal_fee_bps = PodU16::from(0);
+        self.next_withdrawal_fee_bps = PodU16::from(0);
+        Ok(())
+    }
+
     pub fn set_capacity(&mut self, capacity: u64) {
         self.capacity = PodU64::from(capacity);
     }
@@ -361,6 +783,39 @@ impl Vault {
         self.vrt_enqueued_for_cooldown_amount = PodU64::from(amount);
     }
 
+    /// Moves the VRT across `epochs` epoch boundaries, on the same schedule as the delegations.
+    /// The vrt_enqueued_for_cooldown_amount starts cooling down at the first of them, and VRT that
+    /// has been cooling down for `cooldown_epochs` epochs becomes ready to claim.
+    ///
+    /// # Arguments
+    /// * `epochs` - The number of epoch boundaries crossed
+    /// * `cooldown_epochs` - The number of epochs the delegations cool down for
+    pub fn advance_vrt_cooldown(
+        &mut self,
+        epochs: u64,
+        cooldown_epochs: u64,
+    ) -> Result<(), VaultError> {
+        if epochs == 0 {
+            return Ok(());
+        }
+        let untracked_amount = self
+            .vrt_cooling_down_amount()
+            .saturating_sub(self.vrt_cooling_down_cohorts.total()?);
+        let ready_amount = self
+            .vrt_cooling_down_cohorts
+            .advance(
+                self.vrt_enqueued_for_cooldown_amount(),
+                epochs,
+                cooldown_epochs,
+            )?
+            .checked_add(untracked_amount)
+            .ok_or(VaultError::VaultOverflow)?;
+        self.increment_vrt_ready_to_claim_amount(ready_amount)?;
+        self.vrt_cooling_down_amount = PodU64::from(self.vrt_cooling_down_cohorts.total()?);
+        self.vrt_enqueued_for_cooldown_amount = PodU64::from(0);
+        Ok(())
+    }
+
     pub fn set_tokens_deposited(&mut self, tokens_deposited: u64) {
         self.tokens_deposited = PodU64::from(tokens_deposited);
     }
@@ -372,7 +827,15 @@ impl Vault {
     pub fn check_vrt_mint(&self, vrt_mint: &Pubkey) -> Result<(), ProgramError> {
         if self.vrt_mint.ne(vrt_mint) {
             msg!("Vault VRT mint does not match the provided VRT mint");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultVrtMintInvalid.into());
+        }
+        Ok(())
+    }
+
+    pub fn check_supported_mint(&self, supported_mint: &Pubkey) -> Result<(), ProgramError> {
+        if self.supported_mint.ne(supported_mint) {
+            msg!("Vault supported mint does not match the provided supported mint");
+            return Err(VaultError::VaultSupportedMintInvalid.into());
         }
         Ok(())
     }
@@ -436,6 +899,159 @@ impl Vault {
         Ok(())
     }
 
+    pub fn check_pause_admin(&self, pause_admin: &Pubkey) -> Result<(), VaultError> {
+        if self.pause_admin.ne(pause_admin) {
+            msg!("Vault pause admin does not match the provided pause admin");
+            return Err(VaultError::VaultPauseAdminInvalid);
+        }
+        Ok(())
+    }
+
+    pub fn check_metadata_admin(&self, metadata_admin: &Pubkey) -> Result<(), VaultError> {
+        if self.metadata_admin.ne(metadata_admin) {
+            msg!("Vault metadata admin does not match the provided metadata admin");
+            return Err(VaultError::VaultMetadataAdminInvalid);
+        }
+        Ok(())
+    }
+
+    /// Checks that the stake left on an operator delegation is either zero or at least
+    /// the minimum delegation amount, so cranks don't have to carry dust entries
+    pub fn check_min_delegation_amount(&self, staked_amount: u64) -> Result<(), VaultError> {
+        if staked_amount > 0 && staked_amount < self.min_delegation_amount() {
+            msg!(
+                "Delegation of {} is below the minimum delegation amount of {}",
+                staked_amount,
+                self.min_delegation_amount()
+            );
+            return Err(VaultError::VaultDelegationBelowMinimum);
+        }
+        Ok(())
+    }
+
+    /// Checks that the depositor is allowed to deposit under the vault's deposit mode.
+    /// The mint burn admin signature itself is checked by [`Vault::check_mint_burn_admin`].
+    pub fn check_deposit_allowed(
+        &self,
+        is_depositor_allowlisted: bool,
+    ) -> Result<(), ProgramError> {
+        match self.deposit_mode()? {
+            DepositMode::Open => {}
+            DepositMode::SignerGated => {
+                if self.mint_burn_admin.eq(&Pubkey::default()) {
+                    msg!("Vault deposits are signer-gated but no mint burn admin is set");
+                    return Err(VaultError::VaultDepositNotAllowed.into());
+                }
+            }
+            DepositMode::Allowlist => {
+                if !is_depositor_allowlisted {
+                    msg!("Depositor is not on the vault deposit allowlist");
+                    return Err(VaultError::VaultDepositNotAllowed.into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn check_is_paused(&self) -> Result<(), VaultError> {
+        if self.is_paused() {
+            msg!("Vault is paused");
+            return Err(VaultError::VaultIsPaused);
+        }
+        Ok(())
+    }
+
+    /// Checks VRT can be burned right away out of the vault's idle assets, which isn't allowed
+    /// while the vault has a withdrawal delay since every exit must go through the withdrawal queue
+    pub fn check_no_withdrawal_delay(&self) -> Result<(), VaultError> {
+        if self.withdrawal_delay_epochs() > 0 {
+            msg!(
+                "Vault has a withdrawal delay of {} epochs, withdrawals must be enqueued",
+                self.withdrawal_delay_epochs()
+            );
+            return Err(VaultError::VaultWithdrawalDelayActive);
+        }
+        Ok(())
+    }
+
+    pub fn check_is_shut_down(&self) -> Result<(), VaultError> {
+        if self.is_shut_down() {
+            msg!("Vault is shut down");
+            return Err(VaultError::VaultIsShutDown);
+        }
+        Ok(())
+    }
+
+    /// Checks the vault token account holds at least `tokens_deposited`. Any excess is rewards that
+    /// `UpdateVaultBalance` picks up, while a shortfall means the internal accounting diverged
+    /// from the actual balance and needs `ReconcileVaultBalance`.
+    pub fn check_token_account_balance(&self, balance: u64) -> Result<(), VaultError> {
+        if balance < self.tokens_deposited() {
+            msg!(
+                "Vault token account balance {} is below the tokens deposited {}",
+                balance,
+                self.tokens_deposited()
+            );
+            return Err(VaultError::VaultBalanceDiverged);
+        }
+        Ok(())
+    }
+
+    /// Writes `tokens_deposited` down to the vault token account's `balance`, returning the
+    /// adjustment
+    ///
+    /// # Arguments
+    /// * `balance` - The vault token account balance
+    /// * `max_adjustment` - The most tokens the admin allows `tokens_deposited` to be written down
+    pub fn reconcile_tokens_deposited(
+        &mut self,
+        balance: u64,
+        max_adjustment: u64,
+    ) -> Result<u64, VaultError> {
+        let Some(adjustment) = self
+            .tokens_deposited()
+            .checked_sub(balance)
+            .filter(|adjustment| *adjustment > 0)
+        else {
+            msg!("Vault token account balance is not below the tokens deposited");
+            return Err(VaultError::VaultBalanceNotDiverged);
+        };
+        if adjustment > max_adjustment {
+            msg!(
+                "Adjustment {} exceeds the maximum adjustment {}",
+                adjustment,
+                max_adjustment
+            );
+            return Err(VaultError::VaultReconcileAdjustmentExceeded);
+        }
+        // delegated tokens are held by the vault token account too
+        if balance < self.delegation_state.total_security()? {
+            msg!("Vault token account balance is below the delegated tokens, cool down delegations first");
+            return Err(VaultError::VaultInsufficientFunds);
+        }
+
+        self.set_tokens_deposited(balance);
+        Ok(adjustment)
+    }
+
+    /// A vault can only be closed once it holds nothing: no VRT in circulation, no deposited
+    /// tokens, no delegated or cooling down stake and no withdrawals or fees left to settle
+    pub fn check_can_close(&self) -> Result<(), VaultError> {
+        if self.vrt_supply() != 0
+            || self.tokens_deposited() != 0
+            || self.delegation_state.total_security()? != 0
+            || self.vrt_enqueued_for_cooldown_amount() != 0
+            || self.vrt_cooling_down_amount() != 0
+            || self.vrt_ready_to_claim_amount() != 0
+            || self.vrt_fees_accrued() != 0
+        {
+            msg!("Vault is not empty");
+            return Err(VaultError::VaultNotEmpty);
+        }
+        Ok(())
+    }
+
     /// Replace all secondary admins that were equal to the old admin to the new admin
     pub fn update_secondary_admin(&mut self, old_admin: &Pubkey, new_admin: &Pubkey) {
         if self.delegation_admin.eq(old_admin) {
@@ -482,6 +1098,16 @@ impl Vault {
             self.fee_admin = *new_admin;
             msg!("Fee admin set to {:?}", new_admin);
         }
+
+        if self.pause_admin.eq(old_admin) {
+            self.pause_admin = *new_admin;
+            msg!("Pause admin set to {:?}", new_admin);
+        }
+
+        if self.metadata_admin.eq(old_admin) {
+            self.metadata_admin = *new_admin;
+            msg!("Metadata admin set to {:?}", new_admin);
+        }
     }
 
     // ------------------------------------------
@@ -489,7 +1115,7 @@ impl Vault {
     // ------------------------------------------
 
     #[inline(always)]
-    fn is_update_needed(&self, slot: u64, epoch_length: u64) -> bool {
+    pub fn is_update_needed(&self, slot: u64, epoch_length: u64) -> bool {
         let last_updated_epoch = self
             .last_full_state_update_slot()
             .checked_div(epoch_length)
@@ -549,6 +1175,44 @@ impl Vault {
         Ok(())
     }
 
+    /// Starts a deposit and withdrawal fee change, carrying over the current fees
+    /// if there isn't a fee change already waiting to take effect
+    fn stage_fee_change(&mut self) {
+        if !self.is_fee_change_pending() {
+            self.next_deposit_fee_bps = self.deposit_fee_bps;
+            self.next_withdrawal_fee_bps = self.withdrawal_fee_bps;
+            self.is_fee_change_pending = 1;
+        }
+    }
+
+    /// Applies a staged deposit and withdrawal fee change once the epoch after the
+    /// last fee change has been reached, giving stakers a full epoch to exit before
+    /// a fee increase takes effect.
+    pub fn activate_pending_fees(&mut self, slot: u64, epoch_length: u64) {
+        if !self.is_fee_change_pending() {
+            return;
+        }
+
+        let current_epoch = slot.checked_div(epoch_length).unwrap();
+        let last_fee_change_epoch = self
+            .last_fee_change_slot()
+            .checked_div(epoch_length)
+            .unwrap();
+
+        if current_epoch > last_fee_change_epoch {
+            self.deposit_fee_bps = self.next_deposit_fee_bps;
+            self.withdrawal_fee_bps = self.next_withdrawal_fee_bps;
+            self.is_fee_change_pending = 0;
+            msg!(
+                "Deposit fee set to {} bps, withdrawal fee set to {} bps",
+                self.deposit_fee_bps(),
+                self.withdrawal_fee_bps()
+            );
+        }
+    }
+
+    /// Stages a new withdrawal fee, which takes effect the epoch after the fee change.
+    /// See [`Vault::activate_pending_fees`].
     pub fn set_withdrawal_fee_bps(
         &mut self,
         withdrawal_fee_bps: u16,
@@ -573,10 +1237,13 @@ impl Vault {
             fee_bump_bps,
             fee_rate_of_change_bps,
         )?;
-        self.withdrawal_fee_bps = PodU16::from(withdrawal_fee_bps);
+        self.stage_fee_change();
+        self.next_withdrawal_fee_bps = PodU16::from(withdrawal_fee_bps);
         Ok(())
     }
 
+    /// Stages a new deposit fee, which takes effect the epoch after the fee change.
+    /// See [`Vault::activate_pending_fees`].
     pub fn set_deposit_fee_bps(
         &mut self,
         deposit_fee_bps: u16,
@@ -603,7 +1270,8 @@ impl Vault {
             fee_rate_of_change_bps,
         )?;
 
-        self.deposit_fee_bps = PodU16::from(deposit_fee_bps);
+        self.stage_fee_change();
+        self.next_deposit_fee_bps = PodU16::from(deposit_fee_bps);
         Ok(())
     }
 
@@ -666,13 +1334,7 @@ impl Vault {
             return Ok(0);
         }
 
-        let fee = (vrt_rewards as u128)
-            .checked_mul(self.reward_fee_bps() as u128)
-            .map(|x| x.div_ceil(MAX_FEE_BPS as u128))
-            .and_then(|x| x.try_into().ok())
-            .ok_or(VaultError::VaultOverflow)?;
-
-        Ok(fee)
+        bps_of(vrt_rewards, self.reward_fee_bps(), RoundingDirection::Up)
     }
 
     /// Calculate the amount of VRT tokens to mint based on the amount of tokens deposited in the vault.
@@ -683,33 +1345,27 @@ impl Vault {
             return Ok(amount);
         }
 
-        (amount as u128)
-            .checked_mul(self.vrt_supply() as u128)
-            .and_then(|x| x.checked_div(self.tokens_deposited() as u128))
-            .and_then(|result| result.try_into().ok())
-            .ok_or(VaultError::VaultOverflow)
+        mul_div(
+            amount,
+            self.vrt_supply(),
+            self.tokens_deposited(),
+            RoundingDirection::Down,
+        )
     }
 
     /// Calculate the amount of tokens collected as a fee for depositing tokens in the vault.
     fn calculate_deposit_fee(&self, vrt_amount: u64) -> Result<u64, VaultError> {
-        let fee = (vrt_amount as u128)
-            .checked_mul(self.deposit_fee_bps() as u128)
-            .map(|x| x.div_ceil(MAX_FEE_BPS as u128))
-            .and_then(|x| x.try_into().ok())
-            .ok_or(VaultError::VaultOverflow)?;
-        Ok(fee)
+        bps_of(vrt_amount, self.deposit_fee_bps(), RoundingDirection::Up)
     }
 
     /// Calculate the amount of tokens collected as a fee for withdrawing tokens from the vault.
     fn calculate_withdraw_fee(&self, vrt_amount: u64) -> Result<u64, VaultError> {
-        let fee = (vrt_amount as u128)
-            .checked_mul(self.withdrawal_fee_bps() as u128)
-            .map(|x| x.div_ceil(MAX_FEE_BPS as u128))
-            .and_then(|x| x.try_into().ok())
-            .ok_or(VaultError::VaultOverflow)?;
-        Ok(fee)
+        bps_of(vrt_amount, self.withdrawal_fee_bps(), RoundingDirection::Up)
     }
 
+    /// Deposits `amount_in` tokens at the current exchange rate between `tokens_deposited` and
+    /// `vrt_supply`, so rewards and slashing are reflected in the amount of VRT minted.
+    /// The VRT amount is rounded down and the fee is rounded up, both in favor of the vault.
     pub fn mint_with_fee(
         &mut self,
         amount_in: u64,
@@ -729,6 +1385,15 @@ impl Vault {
             return Err(VaultError::VaultCapacityExceeded);
         }
 
+        if self.max_deposit_per_transaction() > 0 && amount_in > self.max_deposit_per_transaction()
+        {
+            msg!(
+                "Amount exceeds the max deposit per transaction of {}",
+                self.max_deposit_per_transaction()
+            );
+            return Err(VaultError::VaultDepositPerTransactionLimitExceeded);
+        }
+
         let vrt_mint_amount = self.calculate_vrt_mint_amount(amount_in)?;
         let vrt_to_fee_wallet = self.calculate_deposit_fee(vrt_mint_amount)?;
         let vrt_to_depositor = vrt_mint_amount
@@ -757,10 +1422,261 @@ impl Vault {
         })
     }
 
+    /// Adds `amount` VRT to the fee bucket instead of minting it to the fee wallet on every
+    /// deposit, so deposits don't write-lock the fee wallet token account. The accrued VRT
+    /// are already counted in `vrt_supply`, so the exchange rate is unaffected.
+    pub fn accrue_vrt_fees(&mut self, amount: u64) -> Result<(), VaultError> {
+        let vrt_fees_accrued = self
+            .vrt_fees_accrued()
+            .checked_add(amount)
+            .ok_or(VaultError::VaultOverflow)?;
+        self.vrt_fees_accrued = PodU64::from(vrt_fees_accrued);
+        Ok(())
+    }
+
+    /// Empties the fee bucket at most once per epoch, returning the amount of VRT
+    /// that shall be minted to the fee wallet.
+    pub fn take_vrt_fees_accrued(&mut self, slot: u64, epoch_length: u64) -> u64 {
+        let current_epoch = slot.checked_div(epoch_length).unwrap();
+        let last_materialization_epoch = self
+            .last_fee_materialization_slot()
+            .checked_div(epoch_length)
+            .unwrap();
+
+        let vrt_fees_accrued = self.vrt_fees_accrued();
+        if vrt_fees_accrued == 0 || current_epoch <= last_materialization_epoch {
+            return 0;
+        }
+
+        self.vrt_fees_accrued = PodU64::from(0);
+        self.last_fee_materialization_slot = PodU64::from(slot);
+        vrt_fees_accrued
+    }
+
+    pub fn last_crank_reward_slot(&self) -> u64 {
+        self.last_crank_reward_slot.into()
+    }
+
+    /// Whether the cranker advancing the vault's epoch state at `slot` is the first to be paid
+    /// this epoch, by either UpdateVaultBalance or CloseVaultUpdateStateTracker, recording the slot
+    /// if so
+    pub fn take_crank_reward(&mut self, slot: u64, epoch_length: u64) -> bool {
+        let current_epoch = slot.checked_div(epoch_length).unwrap();
+        let last_reward_epoch = self
+            .last_crank_reward_slot()
+            .checked_div(epoch_length)
+            .unwrap();
+        if current_epoch <= last_reward_epoch {
+            return false;
+        }
+
+        self.last_crank_reward_slot = PodU64::from(slot);
+        true
+    }
+
+    /// The tokens transferred to a cranker for advancing the vault's epoch state:
+    /// `crank_reward_bps` of the tokens deposited, rounded down and capped by the idle tokens
+    /// that aren't reserved for withdrawals so delegations and pending withdrawals stay covered
+    pub fn calculate_crank_reward(&self, crank_reward_bps: u16) -> Result<u64, VaultError> {
+        let crank_reward = bps_of(
+            self.tokens_deposited(),
+            crank_reward_bps,
+            RoundingDirection::Down,
+        )?;
+        Ok(crank_reward.min(self.calculate_amount_available_for_delegation()?))
+    }
+
+    pub fn slash_dispute_haircut(&self) -> bool {
+        self.slash_dispute_haircut == 1
+    }
+
+    pub fn set_slash_dispute_haircut(&mut self, enabled: bool) {
+        self.slash_dispute_haircut = u8::from(enabled);
+    }
+
+    pub fn pending_slash_amount(&self) -> u64 {
+        self.pending_slash_amount.into()
+    }
+
+    pub fn increment_pending_slash_amount(&mut self, amount: u64) -> Result<(), VaultError> {
+        let pending_slash_amount = self
+            .pending_slash_amount()
+            .checked_add(amount)
+            .ok_or(VaultError::VaultOverflow)?;
+        self.pending_slash_amount = PodU64::from(pending_slash_amount);
+        Ok(())
+    }
+
+    /// Releases a slash proposal's amount once it's executed or vetoed. Proposals made before the
+    /// vault tracked its pending slashes were never counted, so this saturates at zero.
+    pub fn release_pending_slash_amount(&mut self, amount: u64) {
+        self.pending_slash_amount =
+            PodU64::from(self.pending_slash_amount().saturating_sub(amount));
+    }
+
+    /// The tokens deposited that exits are paid out of: all of them, unless the vault has slash
+    /// dispute haircuts, in which case the pending slashes are taken off as if they had already
+    /// been executed
+    pub fn unslashed_tokens_deposited(&self) -> u64 {
+        if self.slash_dispute_haircut() {
+            self.tokens_deposited()
+                .saturating_sub(self.pending_slash_amount())
+        } else {
+            self.tokens_deposited()
+        }
+    }
+
+    pub fn max_total_slashable_bps_per_epoch(&self) -> u16 {
+        self.max_total_slashable_bps_per_epoch.into()
+    }
+
+    pub fn set_max_total_slashable_bps_per_epoch(
+        &mut self,
+        max_total_slashable_bps_per_epoch: u16,
+    ) -> Result<(), VaultError> {
+        if max_total_slashable_bps_per_epoch > MAX_FEE_BPS {
+            msg!(
+                "Max total slashable per epoch exceeds maximum allowed of {} bps",
+                MAX_FEE_BPS
+            );
+            return Err(VaultError::VaultMaxTotalSlashableBpsInvalid);
+        }
+        self.max_total_slashable_bps_per_epoch = PodU16::from(max_total_slashable_bps_per_epoch);
+        Ok(())
+    }
+
+    pub fn slash_epoch(&self) -> u64 {
+        self.slash_epoch.into()
+    }
+
+    pub fn slash_epoch_tokens_deposited(&self) -> u64 {
+        self.slash_epoch_tokens_deposited.into()
+    }
+
+    pub fn slashed_this_epoch(&self) -> u64 {
+        self.slashed_this_epoch.into()
+    }
+
+    /// Counts `slash_amount` against the max total slashable per epoch shared by all slashers.
+    /// The first slash of each epoch snapshots the tokens deposited the cap is a share of, so
+    /// earlier slashes in the epoch don't shrink it. Shall be called before the tokens deposited
+    /// are decremented by the slash.
+    pub fn record_epoch_slash(&mut self, slash_amount: u64, epoch: u64) -> Result<(), VaultError> {
+        if self.slash_epoch() != epoch || self.slashed_this_epoch() == 0 {
+            self.slash_epoch = PodU64::from(epoch);
+            self.slash_epoch_tokens_deposited = PodU64::from(self.tokens_deposited());
+            self.slashed_this_epoch = PodU64::from(0);
+        }
+
+        let slashed_this_epoch = self
+            .slashed_this_epoch()
+            .checked_add(slash_amount)
+            .ok_or(VaultError::VaultOverflow)?;
+        if self.max_total_slashable_bps_per_epoch() > 0 {
+            let max_total_slashable = bps_of(
+                self.slash_epoch_tokens_deposited(),
+                self.max_total_slashable_bps_per_epoch(),
+                RoundingDirection::Down,
+            )?;
+            if slashed_this_epoch > max_total_slashable {
+                msg!(
+                    "Slashes this epoch of {} exceed the vault's max total slashable of {}",
+                    slashed_this_epoch,
+                    max_total_slashable
+                );
+                return Err(VaultError::VaultMaxTotalSlashedPerEpochExceeded);
+            }
+        }
+        self.slashed_this_epoch = PodU64::from(slashed_this_epoch);
+        Ok(())
+    }
+
+    pub fn set_migration_partner(&mut self, migration_partner: Pubkey) {
+        self.migration_partner = migration_partner;
+    }
+
+    pub fn withdrawal_delay_epochs(&self) -> u64 {
+        self.withdrawal_delay_epochs.into()
+    }
+
+    pub fn set_withdrawal_delay_epochs(
+        &mut self,
+        withdrawal_delay_epochs: u64,
+    ) -> Result<(), VaultError> {
+        if withdrawal_delay_epochs > MAX_WITHDRAWAL_DELAY_EPOCHS {
+            msg!(
+                "Withdrawal delay exceeds maximum allowed of {} epochs",
+                MAX_WITHDRAWAL_DELAY_EPOCHS
+            );
+            return Err(VaultError::VaultWithdrawalDelayInvalid);
+        }
+        self.withdrawal_delay_epochs = PodU64::from(withdrawal_delay_epochs);
+        Ok(())
+    }
+
+    pub fn epoch_length_slots(&self) -> u64 {
+        self.epoch_length_slots.into()
+    }
+
+    /// The length of the vault's epochs in slots, its override or else the config's epoch length
+    pub fn epoch_length(&self, config_epoch_length: u64) -> u64 {
+        match self.epoch_length_slots() {
+            0 => config_epoch_length,
+            epoch_length_slots => epoch_length_slots,
+        }
+    }
+
+    /// Overrides the length of the vault's epochs, zero falls back to the config's epoch length.
+    ///
+    /// Delegations and VRT cooldowns are tracked in epochs, so the length can only change while
+    /// no stake or VRT is cooling down.
+    pub fn set_epoch_length_slots(&mut self, epoch_length_slots: u64) -> Result<(), VaultError> {
+        if self.delegation_state.enqueued_for_cooldown_amount() > 0
+            || self.delegation_state.cooling_down_amount() > 0
+            || self.vrt_enqueued_for_cooldown_amount() > 0
+            || self.vrt_cooling_down_amount() > 0
+        {
+            msg!("Vault epoch length can't change while stake is cooling down");
+            return Err(VaultError::VaultEpochLengthCooldownInProgress);
+        }
+        self.epoch_length_slots = PodU64::from(epoch_length_slots);
+        Ok(())
+    }
+
+    /// Whether `vault` is this vault's migration partner. Migrations between two vaults only skip
+    /// the withdrawal fee when both vaults name each other.
+    pub fn is_migration_partner(&self, vault: &Pubkey) -> bool {
+        self.migration_partner.ne(&Pubkey::default()) && self.migration_partner.eq(vault)
+    }
+
+    /// Burns `amount_in` VRT at the current exchange rate between `tokens_deposited` and
+    /// `vrt_supply`. The fee is rounded up and the amount out is rounded down, both in favor of the vault.
+    ///
+    /// While a slash is pending against a vault with slash dispute haircuts, the exchange rate is
+    /// taken on [`Self::unslashed_tokens_deposited`] and the haircut stays in the vault.
     pub fn burn_with_fee(
         &mut self,
         amount_in: u64,
         min_amount_out: u64,
+    ) -> Result<BurnSummary, VaultError> {
+        self.burn(amount_in, min_amount_out, false)
+    }
+
+    /// Burns `amount_in` VRT like [`Self::burn_with_fee`] without taking the withdrawal fee, for
+    /// migrations between vaults that are each other's migration partner
+    pub fn burn_without_fee(
+        &mut self,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<BurnSummary, VaultError> {
+        self.burn(amount_in, min_amount_out, true)
+    }
+
+    fn burn(
+        &mut self,
+        amount_in: u64,
+        min_amount_out: u64,
+        waive_fee: bool,
     ) -> Result<BurnSummary, VaultError> {
         if amount_in == 0 {
             msg!("Amount in is zero");
@@ -770,21 +1686,10 @@ impl Vault {
             return Err(VaultError::VaultInsufficientFunds);
         }
 
-        let fee_amount = self.calculate_withdraw_fee(amount_in)?;
-        let amount_to_burn = amount_in
-            .checked_sub(fee_amount)
-            .ok_or(VaultError::VaultUnderflow)?;
+        let (fee_amount, amount_to_burn, amount_out) =
+            self.calculate_burn_amounts(amount_in, waive_fee)?;
 
-        let amount_out = (amount_to_burn as u128)
-            .checked_mul(self.tokens_deposited() as u128)
-            .and_then(|x| x.checked_div(self.vrt_supply() as u128))
-            .and_then(|x| x.try_into().ok())
-            .ok_or(VaultError::VaultOverflow)?;
-
-        let max_withdrawable = self
-            .tokens_deposited()
-            .checked_sub(self.delegation_state.total_security()?)
-            .ok_or(VaultError::VaultUnderflow)?;
+        let max_withdrawable = self.calculate_max_withdrawable_amount()?;
 
         // The vault shall not be able to withdraw more than the max withdrawable amount
         if amount_out > max_withdrawable {
@@ -821,6 +1726,54 @@ impl Vault {
         })
     }
 
+    /// Splits `amount_in` VRT into the fee, the amount burned and the tokens paid out for it
+    fn calculate_burn_amounts(
+        &self,
+        amount_in: u64,
+        waive_fee: bool,
+    ) -> Result<(u64, u64, u64), VaultError> {
+        let fee_amount = if waive_fee {
+            0
+        } else {
+            self.calculate_withdraw_fee(amount_in)?
+        };
+        let amount_to_burn = amount_in
+            .checked_sub(fee_amount)
+            .ok_or(VaultError::VaultUnderflow)?;
+
+        let amount_out = mul_div(
+            amount_to_burn,
+            self.unslashed_tokens_deposited(),
+            self.vrt_supply(),
+            RoundingDirection::Down,
+        )?;
+        Ok((fee_amount, amount_to_burn, amount_out))
+    }
+
+    /// The amount of tokens that aren't delegated and can be paid out to stakers immediately
+    pub fn calculate_max_withdrawable_amount(&self) -> Result<u64, VaultError> {
+        self.tokens_deposited()
+            .checked_sub(self.delegation_state.total_security()?)
+            .ok_or(VaultError::VaultUnderflow)
+    }
+
+    /// Whether burning `amount_in` VRT can be paid out of the vault's idle assets, as opposed to
+    /// needing a withdrawal to be enqueued so delegated assets can be cooled down first. It can't
+    /// while the vault has a withdrawal delay.
+    ///
+    /// Amounts that [`Self::burn_with_fee`] rejects for other reasons (zero or above the VRT
+    /// supply) return true so the burn surfaces the appropriate error.
+    pub fn can_burn_from_idle_assets(&self, amount_in: u64) -> Result<bool, VaultError> {
+        if self.withdrawal_delay_epochs() > 0 {
+            return Ok(false);
+        }
+        if amount_in == 0 || amount_in > self.vrt_supply() {
+            return Ok(true);
+        }
+        let (_, _, amount_out) = self.calculate_burn_amounts(amount_in, false)?;
+        Ok(amount_out <= self.calculate_max_withdrawable_amount()?)
+    }
+
     /// Calculates the amount of tokens, denominated in the supported_mint asset,
     /// that should be reserved for the VRTs in the vault
     pub fn calculate_vrt_reserve_amount(&self) -> Result<u64, VaultError> {
@@ -832,11 +1785,13 @@ impl Vault {
             .checked_add(self.vrt_ready_to_claim_amount())
             .and_then(|x| x.checked_add(self.vrt_enqueued_for_cooldown_amount()))
             .ok_or(VaultError::VaultOverflow)?;
-        let amount_to_reserve_for_vrts = (vrt_reserve as u128)
-            .checked_mul(self.tokens_deposited() as u128)
-            .and_then(|x| x.checked_div(self.vrt_supply() as u128))
-            .and_then(|result| result.try_into().ok())
-            .ok_or(VaultError::VaultOverflow)?;
+        // Rounds up so the vault never sets aside less than the reserved VRT is owed
+        let amount_to_reserve_for_vrts = mul_div(
+            vrt_reserve,
+            self.tokens_deposited(),
+            self.vrt_supply(),
+            RoundingDirection::Up,
+        )?;
 
         let fee_amount = self.calculate_withdraw_fee(amount_to_reserve_for_vrts)?;
         amount_to_reserve_for_vrts
@@ -848,6 +1803,7 @@ impl Vault {
         &self,
         slot: u64,
         epoch_length: u64,
+        cooldown_epochs: u64,
     ) -> Result<u64, VaultError> {
         // Calculate the total amount of assets needed to be set aside for all potential withdrawals
         let amount_needed_set_aside_for_withdrawals = self.calculate_vrt_reserve_amount()?;
@@ -862,22 +1818,10 @@ impl Vault {
             .unwrap();
         let this_epoch = slot.checked_div(epoch_length).unwrap();
 
-        // Update the simulated delegation state based on the number of epochs passed
+        // Update the simulated delegation state based on the number of epochs passed, releasing
+        // the stake whose cooldown has elapsed by then
         let epoch_diff = this_epoch.checked_sub(last_epoch_update).unwrap();
-        match epoch_diff {
-            0 => {
-                // no-op
-            }
-            1 => {
-                delegation_state_after_update.update();
-            }
-            _ => {
-                // More than one epoch has passed, but we only need to update twice at most
-                // (enqueued -> cooling down and cooling down -> not allocated)
-                delegation_state_after_update.update();
-                delegation_state_after_update.update();
-            }
-        }
+        delegation_state_after_update.advance_cooldown(epoch_diff, cooldown_epochs)?;
 
         // Calculate the total amount of assets delegated after the simulated update
         let total_delegated_after_update = delegation_state_after_update.total_security()?;
@@ -909,6 +1853,19 @@ impl Vault {
         Ok(additional_assets_need_undelegating)
     }
 
+    /// The amount of idle tokens the vault can delegate: the tokens deposited minus the tokens
+    /// already delegated minus the tokens reserved for VRTs pending withdrawal
+    pub fn calculate_amount_available_for_delegation(&self) -> Result<u64, VaultError> {
+        // there is some protection built-in to the vault to avoid over delegating assets
+        // this numer is denominated in the supported token units
+        let amount_to_reserve_for_vrts = self.calculate_vrt_reserve_amount()?;
+
+        self.tokens_deposited()
+            .checked_sub(self.delegation_state.total_security()?)
+            .and_then(|x| x.checked_sub(amount_to_reserve_for_vrts))
+            .ok_or(VaultError::VaultUnderflow)
+    }
+
     pub fn delegate(&mut self, amount: u64) -> Result<(), VaultError> {
         if amount == 0 {
             msg!("Delegation amount is zero");
@@ -918,16 +1875,7 @@ impl Vault {
             return Err(VaultError::VaultUnderflow);
         }
 
-        // there is some protection built-in to the vault to avoid over delegating assets
-        // this numer is denominated in the supported token units
-        let amount_to_reserve_for_vrts = self.calculate_vrt_reserve_amount()?;
-
-        let amount_available_for_delegation = self
-            .tokens_deposited()
-            .checked_sub(self.delegation_state.total_security()?)
-            .and_then(|x| x.checked_sub(amount_to_reserve_for_vrts))
-            .ok_or(VaultError::VaultUnderflow)?;
-
+        let amount_available_for_delegation = self.calculate_amount_available_for_delegation()?;
         if amount > amount_available_for_delegation {
             msg!("Insufficient funds in vault for delegation");
             return Err(VaultError::VaultInsufficientFunds);
@@ -964,6 +1912,22 @@ impl Vault {
         (pda, bump, seeds)
     }
 
+    /// Find the PDA of the successor program that a vault's VRT mint authority is handed off to
+    ///
+    /// # Arguments
+    /// * `successor_program` - The program taking over the VRT mint authority
+    /// * `vault` - The vault
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The successor mint authority
+    pub fn find_mint_authority_successor(successor_program: &Pubkey, vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_mint_authority", vault.as_ref()],
+            successor_program,
+        )
+        .0
+    }
+
     /// Loads the [`Vault`] account
     ///
     /// # Arguments
@@ -984,25 +1948,57 @@ impl Vault {
         }
         if account.data_is_empty() {
             msg!("Vault account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDataEmpty.into());
         }
         if expect_writable && !account.is_writable {
             msg!("Vault account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotWritable.into());
         }
         if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("Vault account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        if Self::version(&account.data.borrow())?.ne(&Self::VERSION) {
+            msg!("Vault account must be migrated to the current version");
+            return Err(VaultError::VaultAccountVersionInvalid.into());
         }
         let base = Self::try_from_slice_unchecked(&account.data.borrow())?.base;
         if account
             .key
-            .ne(&Self::find_program_address(program_id, &base).0)
+            .ne(&Self::find_program_address(program_id, &base).0)
+        {
+            msg!("Vault account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+
+    /// Loads the vault's supported token account, distinguishing a closed account from any other
+    /// account being passed in
+    ///
+    /// # Arguments
+    /// * `vault` - The vault account
+    /// * `vault_token_account` - The vault's associated token account of the supported mint
+    ///
+    /// # Returns
+    /// * `Result<u64, ProgramError>` - The token account balance
+    pub fn load_token_account(
+        &self,
+        vault: &Pubkey,
+        vault_token_account: &AccountInfo,
+    ) -> Result<u64, ProgramError> {
+        if vault_token_account
+            .key
+            .ne(&get_associated_token_address(vault, &self.supported_mint))
         {
-            msg!("Vault account is not at the correct PDA");
+            msg!("Vault token account is not the associated token account");
             return Err(ProgramError::InvalidAccountData);
         }
-        Ok(())
+        if vault_token_account.owner.ne(&spl_token::id()) || vault_token_account.data_is_empty() {
+            msg!("Vault token account is closed");
+            return Err(VaultError::VaultTokenAccountClosed.into());
+        }
+        Ok(spl_token::state::Account::unpack(&vault_token_account.data.borrow())?.amount)
     }
 }
 
@@ -1010,14 +2006,18 @@ impl Vault {
 mod tests {
     use std::{cell::RefCell, rc::Rc};
 
-    use jito_bytemuck::types::{PodU16, PodU64};
-    use jito_vault_sdk::error::VaultError;
+    use jito_bytemuck::{
+        types::{PodU16, PodU64},
+        AccountVersion, Discriminator,
+    };
+    use jito_vault_sdk::{error::VaultError, instruction::DepositMode};
     use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
     use crate::{
+        cooldown_cohorts::CooldownCohorts,
         delegation_state::DelegationState,
         vault::{BurnSummary, MintSummary, Vault},
-        MAX_FEE_BPS,
+        MAX_FEE_BPS, MAX_WITHDRAWAL_DELAY_EPOCHS, MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS,
     };
 
     fn make_test_vault(
@@ -1078,11 +2078,64 @@ mod tests {
             std::mem::size_of::<PodU16>() + // withdrawal_fee_bps
             std::mem::size_of::<PodU16>() + // reward_fee_bps
             1 + // bump
-            263; // reserved
+            1 + // is_paused
+            std::mem::size_of::<Pubkey>() + // pause_admin
+            std::mem::size_of::<PodU16>() + // next_deposit_fee_bps
+            std::mem::size_of::<PodU16>() + // next_withdrawal_fee_bps
+            1 + // is_fee_change_pending
+            size_of::<PodU64>() + // vrt_fees_accrued
+            size_of::<PodU64>() + // last_fee_materialization_slot
+            size_of::<Pubkey>() + // metadata_admin
+            size_of::<PodU64>() + // min_delegation_amount
+            1 + // deposit_mode
+            size_of::<PodU64>() + // max_deposit_per_transaction
+            size_of::<PodU64>() + // max_deposit_per_wallet
+            size_of::<[u8; 32]>() + // delegation_snapshot_root
+            size_of::<PodU64>() + // delegation_snapshot_epoch
+            size_of::<Pubkey>() + // mint_authority_successor
+            size_of::<PodU64>() + // mint_authority_handoff_slot
+            size_of::<u8>() + // epoch_stats_enabled
+            size_of::<u8>() + // flash_deposit_protection
+            size_of::<Pubkey>() + // burn_admin
+            size_of::<u8>() + // is_shut_down
+            size_of::<PodU64>() + // shutdown_slot
+            size_of::<u8>() + // insurance_enabled
+            size_of::<Pubkey>() + // hook_program
+            size_of::<PodU64>() + // last_crank_reward_slot
+            size_of::<PodU16>() + // max_total_slashable_bps_per_epoch
+            size_of::<PodU64>() + // slash_epoch
+            size_of::<PodU64>() + // slash_epoch_tokens_deposited
+            size_of::<PodU64>() + // slashed_this_epoch
+            size_of::<Pubkey>() + // migration_partner
+            size_of::<PodU64>() + // withdrawal_delay_epochs
+            size_of::<PodU64>() + // epoch_length_slots
+            size_of::<u8>() + // slash_dispute_haircut
+            size_of::<PodU64>() + // pending_slash_amount
+            std::mem::size_of::<CooldownCohorts>() + // vrt_cooling_down_cohorts
+            161; // reserved
 
         assert_eq!(vault_size, sum_of_fields);
     }
 
+    #[test]
+    fn test_migrate_from_version_0_zeroes_new_space() {
+        let size = Vault::size_of_version(Vault::VERSION).unwrap();
+        let version_0_size = Vault::size_of_version(0).unwrap();
+        assert_eq!(size, 8 + std::mem::size_of::<Vault>());
+        assert_eq!(size - version_0_size, Vault::VERSION_1_GROWTH);
+
+        let mut data = vec![0xff; size];
+        data[0] = Vault::DISCRIMINATOR;
+        Vault::migrate(&mut data, 0).unwrap();
+        assert!(data[8..version_0_size].iter().all(|byte| *byte == 0xff));
+        assert!(data[version_0_size..].iter().all(|byte| *byte == 0));
+
+        // accounts already at version 1 are left alone
+        let mut data = vec![0xff; size];
+        Vault::migrate(&mut data, 1).unwrap();
+        assert!(data.iter().all(|byte| *byte == 0xff));
+    }
+
     #[test]
     fn test_update_secondary_admin_ok() {
         let old_admin = Pubkey::new_unique();
@@ -1108,6 +2161,8 @@ mod tests {
         assert_eq!(vault.mint_burn_admin, old_admin);
         assert_eq!(vault.withdraw_admin, old_admin);
         assert_eq!(vault.fee_admin, old_admin);
+        assert_eq!(vault.pause_admin, old_admin);
+        assert_eq!(vault.metadata_admin, old_admin);
 
         let new_admin = Pubkey::new_unique();
         vault.update_secondary_admin(&old_admin, &new_admin);
@@ -1121,6 +2176,64 @@ mod tests {
         assert_eq!(vault.mint_burn_admin, new_admin);
         assert_eq!(vault.withdraw_admin, new_admin);
         assert_eq!(vault.fee_admin, new_admin);
+        assert_eq!(vault.pause_admin, new_admin);
+        assert_eq!(vault.metadata_admin, new_admin);
+    }
+
+    #[test]
+    fn test_check_is_paused() {
+        let mut vault = make_test_vault(0, 0, 0, 0, DelegationState::default());
+        assert!(!vault.is_paused());
+        assert_eq!(vault.check_is_paused(), Ok(()));
+
+        vault.set_is_paused(true);
+        assert!(vault.is_paused());
+        assert_eq!(vault.check_is_paused(), Err(VaultError::VaultIsPaused));
+
+        vault.set_is_paused(false);
+        assert!(!vault.is_paused());
+        assert_eq!(vault.check_is_paused(), Ok(()));
+    }
+
+    #[test]
+    fn test_shut_down() {
+        let mut vault = make_test_vault(0, 100, 0, 0, DelegationState::default());
+        assert_eq!(vault.check_is_shut_down(), Ok(()));
+
+        vault.shut_down(10).unwrap();
+        assert!(vault.is_shut_down());
+        assert_eq!(vault.shutdown_slot(), 10);
+        assert_eq!(vault.withdrawal_fee_bps(), 0);
+        assert_eq!(vault.next_withdrawal_fee_bps(), 0);
+        assert_eq!(vault.check_is_shut_down(), Err(VaultError::VaultIsShutDown));
+        assert_eq!(vault.shut_down(11), Err(VaultError::VaultIsShutDown));
+    }
+
+    #[test]
+    fn test_reconcile_tokens_deposited() {
+        let mut vault = make_test_vault(0, 0, 1_000, 1_000, DelegationState::new(600, 0, 0));
+        assert_eq!(vault.check_token_account_balance(1_100), Ok(()));
+        assert_eq!(
+            vault.check_token_account_balance(900),
+            Err(VaultError::VaultBalanceDiverged)
+        );
+
+        assert_eq!(
+            vault.reconcile_tokens_deposited(1_000, 100),
+            Err(VaultError::VaultBalanceNotDiverged)
+        );
+        assert_eq!(
+            vault.reconcile_tokens_deposited(900, 99),
+            Err(VaultError::VaultReconcileAdjustmentExceeded)
+        );
+        assert_eq!(
+            vault.reconcile_tokens_deposited(500, 500),
+            Err(VaultError::VaultInsufficientFunds)
+        );
+
+        assert_eq!(vault.reconcile_tokens_deposited(900, 100), Ok(100));
+        assert_eq!(vault.tokens_deposited(), 900);
+        assert_eq!(vault.check_token_account_balance(900), Ok(()));
     }
 
     #[test]
@@ -1180,6 +2293,38 @@ mod tests {
         assert_eq!(vault.vrt_supply(), 150);
     }
 
+    #[test]
+    fn test_mint_rounds_down_in_favor_of_vault() {
+        // 3 tokens back 2 VRT, so a deposit of 2 tokens is worth 1.33 VRT
+        let mut vault = make_test_vault(0, 0, 3, 2, DelegationState::default());
+
+        let MintSummary {
+            vrt_to_depositor,
+            vrt_to_fee_wallet,
+        } = vault.mint_with_fee(2, 0).unwrap();
+        assert_eq!(vrt_to_depositor, 1);
+        assert_eq!(vrt_to_fee_wallet, 0);
+        assert_eq!(vault.tokens_deposited(), 5);
+        assert_eq!(vault.vrt_supply(), 3);
+    }
+
+    #[test]
+    fn test_burn_rounds_down_in_favor_of_vault() {
+        // 2 tokens back 3 VRT, so burning 2 VRT is worth 1.33 tokens
+        let mut vault = make_test_vault(0, 0, 2, 3, DelegationState::default());
+
+        let BurnSummary {
+            fee_amount,
+            burn_amount,
+            out_amount,
+        } = vault.burn_with_fee(2, 0).unwrap();
+        assert_eq!(fee_amount, 0);
+        assert_eq!(burn_amount, 2);
+        assert_eq!(out_amount, 1);
+        assert_eq!(vault.tokens_deposited(), 1);
+        assert_eq!(vault.vrt_supply(), 1);
+    }
+
     #[test]
     fn test_mint_burn_no_admin() {
         let vault = Vault::new(
@@ -1296,6 +2441,123 @@ mod tests {
         assert_eq!(out_amount, 99);
     }
 
+    #[test]
+    fn test_burn_with_pending_slash_haircut() {
+        let mut vault = make_test_vault(0, 1000, 1000, 1000, DelegationState::default());
+        vault.increment_pending_slash_amount(200).unwrap();
+
+        // without the opt-in the pending slash doesn't change the exchange rate
+        assert_eq!(vault.unslashed_tokens_deposited(), 1000);
+        let mut unhaircut_vault = vault;
+        assert_eq!(
+            unhaircut_vault.burn_without_fee(100, 0).unwrap().out_amount,
+            100
+        );
+
+        vault.set_slash_dispute_haircut(true);
+        assert_eq!(vault.unslashed_tokens_deposited(), 800);
+        let BurnSummary {
+            burn_amount,
+            out_amount,
+            ..
+        } = vault.burn_without_fee(100, 0).unwrap();
+        assert_eq!(burn_amount, 100);
+        assert_eq!(out_amount, 80);
+        assert_eq!(vault.vrt_supply(), 900);
+        assert_eq!(vault.tokens_deposited(), 920);
+
+        // once the slash is resolved exits are paid at the full rate again
+        vault.release_pending_slash_amount(200);
+        assert_eq!(vault.unslashed_tokens_deposited(), 920);
+        assert_eq!(vault.burn_without_fee(90, 0).unwrap().out_amount, 92);
+    }
+
+    #[test]
+    fn test_burn_without_fee_ok() {
+        let mut vault = make_test_vault(0, 1000, 100, 100, DelegationState::default());
+        let BurnSummary {
+            fee_amount,
+            burn_amount,
+            out_amount,
+        } = vault.burn_without_fee(100, 0).unwrap();
+        assert_eq!(fee_amount, 0);
+        assert_eq!(burn_amount, 100);
+        assert_eq!(out_amount, 100);
+        assert_eq!(vault.vrt_supply(), 0);
+        assert_eq!(vault.tokens_deposited(), 0);
+    }
+
+    #[test]
+    fn test_is_migration_partner() {
+        let mut vault = make_test_vault(0, 0, 0, 0, DelegationState::default());
+        assert!(!vault.is_migration_partner(&Pubkey::default()));
+
+        let partner = Pubkey::new_unique();
+        vault.set_migration_partner(partner);
+        assert!(vault.is_migration_partner(&partner));
+        assert!(!vault.is_migration_partner(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_withdrawal_delay_epochs() {
+        let mut vault = make_test_vault(0, 0, 100, 100, DelegationState::default());
+        assert_eq!(vault.withdrawal_delay_epochs(), 0);
+        assert!(vault.check_no_withdrawal_delay().is_ok());
+        assert!(vault.can_burn_from_idle_assets(10).unwrap());
+
+        vault
+            .set_withdrawal_delay_epochs(MAX_WITHDRAWAL_DELAY_EPOCHS)
+            .unwrap();
+        assert_eq!(vault.withdrawal_delay_epochs(), MAX_WITHDRAWAL_DELAY_EPOCHS);
+        assert_eq!(
+            vault.check_no_withdrawal_delay(),
+            Err(VaultError::VaultWithdrawalDelayActive)
+        );
+        assert!(!vault.can_burn_from_idle_assets(10).unwrap());
+
+        assert_eq!(
+            vault.set_withdrawal_delay_epochs(MAX_WITHDRAWAL_DELAY_EPOCHS + 1),
+            Err(VaultError::VaultWithdrawalDelayInvalid)
+        );
+    }
+
+    #[test]
+    fn test_epoch_length_slots() {
+        let mut vault = make_test_vault(0, 0, 100, 100, DelegationState::new(100, 0, 0));
+        assert_eq!(vault.epoch_length(432_000), 432_000);
+
+        vault.set_epoch_length_slots(1_000).unwrap();
+        assert_eq!(vault.epoch_length(432_000), 1_000);
+        vault.set_epoch_length_slots(0).unwrap();
+        assert_eq!(vault.epoch_length(432_000), 432_000);
+
+        vault.delegation_state = DelegationState::new(50, 0, 50);
+        assert_eq!(
+            vault.set_epoch_length_slots(1_000),
+            Err(VaultError::VaultEpochLengthCooldownInProgress)
+        );
+        vault.delegation_state = DelegationState::new(50, 50, 0);
+        assert_eq!(
+            vault.set_epoch_length_slots(1_000),
+            Err(VaultError::VaultEpochLengthCooldownInProgress)
+        );
+
+        vault.delegation_state = DelegationState::new(100, 0, 0);
+        vault.set_vrt_enqueued_for_cooldown_amount(10);
+        assert_eq!(
+            vault.set_epoch_length_slots(1_000),
+            Err(VaultError::VaultEpochLengthCooldownInProgress)
+        );
+        vault.set_vrt_enqueued_for_cooldown_amount(0);
+        vault.set_vrt_cooling_down_amount(10);
+        assert_eq!(
+            vault.set_epoch_length_slots(1_000),
+            Err(VaultError::VaultEpochLengthCooldownInProgress)
+        );
+        vault.set_vrt_cooling_down_amount(0);
+        vault.set_epoch_length_slots(1_000).unwrap();
+    }
+
     #[test]
     fn test_burn_too_much_fails() {
         let mut vault = make_test_vault(0, 100, 100, 100, DelegationState::default());
@@ -1344,6 +2606,17 @@ mod tests {
         assert_eq!(vault.burn_with_fee(51, 50), Err(VaultError::VaultUnderflow));
     }
 
+    #[test]
+    fn test_can_burn_from_idle_assets() {
+        let vault = make_test_vault(0, 0, 100, 100, DelegationState::new(50, 0, 0));
+
+        assert!(vault.can_burn_from_idle_assets(50).unwrap());
+        assert!(!vault.can_burn_from_idle_assets(51).unwrap());
+        // Left for burn_with_fee to reject
+        assert!(vault.can_burn_from_idle_assets(0).unwrap());
+        assert!(vault.can_burn_from_idle_assets(101).unwrap());
+    }
+
     #[test]
     fn test_burn_all_delegated() {
         let mut vault = make_test_vault(0, 0, 100, 100, DelegationState::new(100, 0, 0));
@@ -1532,6 +2805,45 @@ mod tests {
         assert_eq!(vault.delegate(100), Err(VaultError::VaultUnderflow));
     }
 
+    #[test]
+    fn test_advance_vrt_cooldown_releases_each_cohort_on_time() {
+        let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::default());
+
+        vault.set_vrt_enqueued_for_cooldown_amount(100);
+        vault.advance_vrt_cooldown(1, 3).unwrap();
+        vault.set_vrt_enqueued_for_cooldown_amount(10);
+        vault.advance_vrt_cooldown(1, 3).unwrap();
+        assert_eq!(vault.vrt_enqueued_for_cooldown_amount(), 0);
+        assert_eq!(vault.vrt_cooling_down_amount(), 110);
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 0);
+
+        // the first amount is released on time, despite the second cooling down after it
+        vault.advance_vrt_cooldown(1, 3).unwrap();
+        vault.advance_vrt_cooldown(1, 3).unwrap();
+        assert_eq!(vault.vrt_cooling_down_amount(), 10);
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 100);
+
+        vault.advance_vrt_cooldown(1, 3).unwrap();
+        assert_eq!(vault.vrt_cooling_down_amount(), 0);
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 110);
+        assert_eq!(vault.vrt_cooling_down_cohorts().total(), Ok(0));
+    }
+
+    #[test]
+    fn test_advance_vrt_cooldown_releases_untracked_vrt() {
+        let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::default());
+        // cooling down from before cohorts were tracked
+        vault.set_vrt_cooling_down_amount(100);
+        vault.set_vrt_enqueued_for_cooldown_amount(10);
+
+        vault.advance_vrt_cooldown(0, 3).unwrap();
+        assert_eq!(vault.vrt_cooling_down_amount(), 100);
+
+        vault.advance_vrt_cooldown(1, 3).unwrap();
+        assert_eq!(vault.vrt_cooling_down_amount(), 10);
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 100);
+    }
+
     #[test]
     fn test_calculate_vrt_reserve_amount_ok() {
         let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::default());
@@ -1548,24 +2860,33 @@ mod tests {
         assert_eq!(result, 99);
     }
 
+    #[test]
+    fn test_calculate_vrt_reserve_amount_rounds_up() {
+        let mut vault = make_test_vault(0, 0, 1000, 3000, DelegationState::default());
+        vault.set_vrt_cooling_down_amount(100);
+        // 100 * 1000 / 3000 = 33.3, so the vault reserves 34
+        let result = vault.calculate_vrt_reserve_amount().unwrap();
+        assert_eq!(result, 34);
+    }
+
     #[test]
     fn test_calculate_assets_need_undelegating_ok() {
         let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::new(1000, 0, 0));
         vault.set_vrt_cooling_down_amount(100);
         let result = vault
-            .calculate_assets_needed_for_withdrawals(100, 100)
+            .calculate_assets_needed_for_withdrawals(100, 100, 1)
             .unwrap();
         assert_eq!(result, 100);
 
         vault.delegation_state = DelegationState::new(900, 0, 100);
         let result = vault
-            .calculate_assets_needed_for_withdrawals(100, 100)
+            .calculate_assets_needed_for_withdrawals(100, 100, 1)
             .unwrap();
         assert_eq!(result, 0);
 
         vault.set_vrt_cooling_down_amount(200);
         let result = vault
-            .calculate_assets_needed_for_withdrawals(100, 100)
+            .calculate_assets_needed_for_withdrawals(100, 100, 1)
             .unwrap();
         assert_eq!(result, 100);
     }
@@ -1576,12 +2897,12 @@ mod tests {
         vault.set_vrt_cooling_down_amount(100);
 
         let result = vault
-            .calculate_assets_needed_for_withdrawals(100, 100)
+            .calculate_assets_needed_for_withdrawals(100, 100, 1)
             .unwrap();
         assert_eq!(result, 0);
 
         let result = vault
-            .calculate_assets_needed_for_withdrawals(200, 100)
+            .calculate_assets_needed_for_withdrawals(200, 100, 1)
             .unwrap();
         assert_eq!(result, 0);
     }
@@ -1592,19 +2913,19 @@ mod tests {
         vault.set_vrt_cooling_down_amount(300);
 
         let result = vault
-            .calculate_assets_needed_for_withdrawals(100, 100)
+            .calculate_assets_needed_for_withdrawals(100, 100, 1)
             .unwrap();
         assert_eq!(result, 100);
 
         let result = vault
-            .calculate_assets_needed_for_withdrawals(200, 100)
+            .calculate_assets_needed_for_withdrawals(200, 100, 1)
             .unwrap();
         assert_eq!(result, 100);
 
         vault.increment_vrt_supply(100).unwrap();
         vault.increment_tokens_deposited(100).unwrap();
         let result = vault
-            .calculate_assets_needed_for_withdrawals(200, 100)
+            .calculate_assets_needed_for_withdrawals(200, 100, 1)
             .unwrap();
         assert_eq!(result, 0);
     }
@@ -1705,6 +3026,167 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fee_change_activates_next_epoch() {
+        let mut vault = make_test_vault(100, 100, 0, 0, DelegationState::default());
+        vault.set_last_fee_change_slot(150);
+        vault.set_deposit_fee_bps(110, 10_000, 10, 2_500).unwrap();
+        assert!(vault.is_fee_change_pending());
+        assert_eq!(vault.deposit_fee_bps(), 100);
+        assert_eq!(vault.next_deposit_fee_bps(), 110);
+        assert_eq!(vault.next_withdrawal_fee_bps(), 100);
+
+        // same epoch as the fee change
+        vault.activate_pending_fees(199, 100);
+        assert!(vault.is_fee_change_pending());
+        assert_eq!(vault.deposit_fee_bps(), 100);
+
+        vault.activate_pending_fees(200, 100);
+        assert!(!vault.is_fee_change_pending());
+        assert_eq!(vault.deposit_fee_bps(), 110);
+        assert_eq!(vault.withdrawal_fee_bps(), 100);
+    }
+
+    #[test]
+    fn test_fee_change_staged_together() {
+        let mut vault = make_test_vault(100, 100, 0, 0, DelegationState::default());
+        vault.set_deposit_fee_bps(110, 10_000, 10, 2_500).unwrap();
+        vault.set_withdrawal_fee_bps(90, 10_000, 10, 2_500).unwrap();
+        assert_eq!(vault.next_deposit_fee_bps(), 110);
+        assert_eq!(vault.next_withdrawal_fee_bps(), 90);
+
+        vault.activate_pending_fees(100, 100);
+        assert_eq!(vault.deposit_fee_bps(), 110);
+        assert_eq!(vault.withdrawal_fee_bps(), 90);
+    }
+
+    #[test]
+    fn test_activate_pending_fees_no_change() {
+        let mut vault = make_test_vault(100, 100, 0, 0, DelegationState::default());
+        vault.activate_pending_fees(1_000, 100);
+        assert_eq!(vault.deposit_fee_bps(), 100);
+        assert_eq!(vault.withdrawal_fee_bps(), 100);
+    }
+
+    #[test]
+    fn test_accrued_fees_match_immediate_fees() {
+        let deposits = [1_000, 333, 7, 50_001, 12_345];
+
+        // fees minted to the fee wallet on every deposit
+        let mut immediate_vault = make_test_vault(25, 0, 1_000, 1_000, DelegationState::default());
+        let mut immediate_fee_wallet = 0;
+        let mut immediate_depositors = vec![];
+        for amount in deposits {
+            let MintSummary {
+                vrt_to_depositor,
+                vrt_to_fee_wallet,
+            } = immediate_vault.mint_with_fee(amount, 0).unwrap();
+            immediate_fee_wallet += vrt_to_fee_wallet;
+            immediate_depositors.push(vrt_to_depositor);
+        }
+
+        // fees accrued in the vault and minted once per epoch
+        let mut accrued_vault = make_test_vault(25, 0, 1_000, 1_000, DelegationState::default());
+        let mut accrued_depositors = vec![];
+        for amount in deposits {
+            let MintSummary {
+                vrt_to_depositor,
+                vrt_to_fee_wallet,
+            } = accrued_vault.mint_with_fee(amount, 0).unwrap();
+            accrued_vault.accrue_vrt_fees(vrt_to_fee_wallet).unwrap();
+            accrued_depositors.push(vrt_to_depositor);
+        }
+        assert_eq!(accrued_vault.vrt_fees_accrued(), immediate_fee_wallet);
+
+        let accrued_fee_wallet = accrued_vault.take_vrt_fees_accrued(100, 100);
+
+        assert_eq!(accrued_depositors, immediate_depositors);
+        assert_eq!(accrued_fee_wallet, immediate_fee_wallet);
+        assert_eq!(accrued_vault.vrt_supply(), immediate_vault.vrt_supply());
+        assert_eq!(
+            accrued_vault.tokens_deposited(),
+            immediate_vault.tokens_deposited()
+        );
+        assert_eq!(accrued_vault.vrt_fees_accrued(), 0);
+    }
+
+    #[test]
+    fn test_take_vrt_fees_accrued_once_per_epoch() {
+        let mut vault = make_test_vault(0, 0, 0, 0, DelegationState::default());
+
+        // nothing to take in the first epoch
+        vault.accrue_vrt_fees(10).unwrap();
+        assert_eq!(vault.take_vrt_fees_accrued(99, 100), 0);
+        assert_eq!(vault.vrt_fees_accrued(), 10);
+
+        assert_eq!(vault.take_vrt_fees_accrued(150, 100), 10);
+        assert_eq!(vault.last_fee_materialization_slot(), 150);
+
+        // fees accrued after materializing wait until the next epoch
+        vault.accrue_vrt_fees(5).unwrap();
+        assert_eq!(vault.take_vrt_fees_accrued(199, 100), 0);
+        assert_eq!(vault.take_vrt_fees_accrued(200, 100), 5);
+        assert_eq!(vault.vrt_fees_accrued(), 0);
+
+        // an empty bucket doesn't move the materialization slot
+        assert_eq!(vault.take_vrt_fees_accrued(300, 100), 0);
+        assert_eq!(vault.last_fee_materialization_slot(), 200);
+    }
+
+    #[test]
+    fn test_take_crank_reward_once_per_epoch() {
+        let mut vault = make_test_vault(0, 0, 0, 0, DelegationState::default());
+
+        assert!(!vault.take_crank_reward(99, 100));
+        assert!(vault.take_crank_reward(150, 100));
+        assert_eq!(vault.last_crank_reward_slot(), 150);
+        assert!(!vault.take_crank_reward(199, 100));
+        assert!(vault.take_crank_reward(200, 100));
+    }
+
+    #[test]
+    fn test_calculate_crank_reward_capped_by_idle_assets() {
+        let vault = make_test_vault(0, 0, 100_000, 100_000, DelegationState::default());
+        assert_eq!(vault.calculate_crank_reward(0).unwrap(), 0);
+        assert_eq!(vault.calculate_crank_reward(10).unwrap(), 100);
+
+        let vault = make_test_vault(0, 0, 100_000, 100_000, DelegationState::new(99_950, 0, 0));
+        assert_eq!(vault.calculate_crank_reward(10).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_record_epoch_slash() {
+        let mut vault = make_test_vault(0, 0, 100_000, 100_000, DelegationState::default());
+
+        // no limit by default
+        vault.record_epoch_slash(50_000, 1).unwrap();
+        vault.decrement_tokens_deposited(50_000).unwrap();
+        assert_eq!(vault.slashed_this_epoch(), 50_000);
+
+        assert_eq!(
+            vault.set_max_total_slashable_bps_per_epoch(MAX_FEE_BPS + 1),
+            Err(VaultError::VaultMaxTotalSlashableBpsInvalid)
+        );
+        vault.set_max_total_slashable_bps_per_epoch(1_000).unwrap();
+
+        // the cap is a share of the tokens deposited before the epoch's first slash
+        vault.record_epoch_slash(3_000, 2).unwrap();
+        vault.decrement_tokens_deposited(3_000).unwrap();
+        assert_eq!(vault.slash_epoch_tokens_deposited(), 50_000);
+        vault.record_epoch_slash(2_000, 2).unwrap();
+        vault.decrement_tokens_deposited(2_000).unwrap();
+        assert_eq!(
+            vault.record_epoch_slash(1, 2),
+            Err(VaultError::VaultMaxTotalSlashedPerEpochExceeded)
+        );
+        assert_eq!(vault.slashed_this_epoch(), 5_000);
+
+        // the next epoch starts over
+        vault.record_epoch_slash(4_500, 3).unwrap();
+        assert_eq!(vault.slash_epoch_tokens_deposited(), 45_000);
+        assert_eq!(vault.slashed_this_epoch(), 4_500);
+    }
+
     #[test]
     fn test_fee_increase_within_limits() {
         let current_fee_bps = 100;
@@ -1955,6 +3437,56 @@ mod tests {
         assert_eq!(vault.delegate(0), Err(VaultError::VaultDelegationZero));
     }
 
+    #[test]
+    fn test_check_min_delegation_amount() {
+        let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::default());
+        assert_eq!(vault.check_min_delegation_amount(1), Ok(()));
+
+        vault.set_min_delegation_amount(100);
+        assert_eq!(vault.check_min_delegation_amount(0), Ok(()));
+        assert_eq!(
+            vault.check_min_delegation_amount(99),
+            Err(VaultError::VaultDelegationBelowMinimum)
+        );
+        assert_eq!(vault.check_min_delegation_amount(100), Ok(()));
+    }
+
+    #[test]
+    fn test_check_deposit_allowed() {
+        let mut vault = make_test_vault(0, 0, 0, 0, DelegationState::default());
+        assert_eq!(vault.deposit_mode(), Ok(DepositMode::Open));
+        assert_eq!(vault.check_deposit_allowed(false), Ok(()));
+
+        vault.set_deposit_mode(DepositMode::SignerGated);
+        assert_eq!(
+            vault.check_deposit_allowed(false),
+            Err(VaultError::VaultDepositNotAllowed.into())
+        );
+        vault.mint_burn_admin = Pubkey::new_unique();
+        assert_eq!(vault.check_deposit_allowed(false), Ok(()));
+
+        vault.set_deposit_mode(DepositMode::Allowlist);
+        assert_eq!(
+            vault.check_deposit_allowed(false),
+            Err(VaultError::VaultDepositNotAllowed.into())
+        );
+        assert_eq!(vault.check_deposit_allowed(true), Ok(()));
+    }
+
+    #[test]
+    fn test_mint_with_fee_max_deposit_per_transaction() {
+        let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::default());
+        vault.set_max_deposit_per_transaction(100);
+        assert_eq!(
+            vault.mint_with_fee(101, 0),
+            Err(VaultError::VaultDepositPerTransactionLimitExceeded)
+        );
+        assert!(vault.mint_with_fee(100, 0).is_ok());
+
+        vault.set_max_deposit_per_transaction(0);
+        assert!(vault.mint_with_fee(1000, 0).is_ok());
+    }
+
     #[test]
     fn test_mint_with_fee_zero_amount() {
         let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::default());
@@ -1966,4 +3498,112 @@ mod tests {
         let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::default());
         assert_eq!(vault.burn_with_fee(0, 0), Err(VaultError::VaultBurnZero));
     }
+
+    #[test]
+    fn test_mint_authority_handoff() {
+        let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::default());
+        let epoch_length = 100;
+        let successor = Pubkey::new_unique();
+
+        assert_eq!(
+            vault.execute_mint_authority_handoff(0),
+            Err(VaultError::VaultMintAuthorityHandoffNotPending)
+        );
+        assert_eq!(
+            vault.propose_mint_authority_handoff(&Pubkey::default(), 0, epoch_length),
+            Err(VaultError::VaultMintAuthoritySuccessorInvalid)
+        );
+
+        vault
+            .propose_mint_authority_handoff(&successor, 50, epoch_length)
+            .unwrap();
+        assert!(vault.is_mint_authority_handoff_pending());
+        let handoff_slot = 50 + MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS * epoch_length;
+        assert_eq!(vault.mint_authority_handoff_slot(), handoff_slot);
+        assert_eq!(
+            vault.propose_mint_authority_handoff(&Pubkey::new_unique(), 50, epoch_length),
+            Err(VaultError::VaultMintAuthorityHandoffPending)
+        );
+
+        assert_eq!(
+            vault.execute_mint_authority_handoff(handoff_slot - 1),
+            Err(VaultError::VaultMintAuthorityHandoffChallengeWindowActive)
+        );
+        assert_eq!(
+            vault.execute_mint_authority_handoff(handoff_slot),
+            Ok(successor)
+        );
+        assert!(!vault.is_mint_authority_handoff_pending());
+        assert_eq!(vault.mint_authority_handoff_slot(), 0);
+    }
+
+    #[test]
+    fn test_cancel_mint_authority_handoff() {
+        let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::default());
+
+        assert_eq!(
+            vault.cancel_mint_authority_handoff(),
+            Err(VaultError::VaultMintAuthorityHandoffNotPending)
+        );
+
+        vault
+            .propose_mint_authority_handoff(&Pubkey::new_unique(), 0, 100)
+            .unwrap();
+        vault.cancel_mint_authority_handoff().unwrap();
+        assert!(!vault.is_mint_authority_handoff_pending());
+        assert_eq!(
+            vault.execute_mint_authority_handoff(u64::MAX),
+            Err(VaultError::VaultMintAuthorityHandoffNotPending)
+        );
+    }
+
+    #[test]
+    fn test_enable_admin_burn() {
+        let mut vault = make_test_vault(0, 0, 1000, 1000, DelegationState::default());
+        let burn_admin = Pubkey::new_unique();
+        assert_eq!(
+            vault.enable_admin_burn(&burn_admin),
+            Err(VaultError::VaultAdminBurnEnableAfterDeposit)
+        );
+
+        let mut vault = make_test_vault(0, 0, 0, 0, DelegationState::default());
+        assert_eq!(
+            vault.check_burn_admin(&burn_admin),
+            Err(VaultError::VaultAdminBurnNotEnabled)
+        );
+        assert_eq!(
+            vault.enable_admin_burn(&Pubkey::default()),
+            Err(VaultError::VaultBurnAdminInvalid)
+        );
+        vault.enable_admin_burn(&burn_admin).unwrap();
+        assert!(vault.admin_burn_enabled());
+        vault.check_burn_admin(&burn_admin).unwrap();
+        assert_eq!(
+            vault.check_burn_admin(&Pubkey::new_unique()),
+            Err(VaultError::VaultBurnAdminInvalid)
+        );
+        assert_eq!(
+            vault.enable_admin_burn(&Pubkey::new_unique()),
+            Err(VaultError::VaultAdminBurnAlreadyEnabled)
+        );
+    }
+
+    #[test]
+    fn test_check_can_close() {
+        let vault = make_test_vault(0, 0, 0, 0, DelegationState::default());
+        assert_eq!(vault.check_can_close(), Ok(()));
+
+        let vault = make_test_vault(0, 0, 0, 1, DelegationState::default());
+        assert_eq!(vault.check_can_close(), Err(VaultError::VaultNotEmpty));
+
+        let vault = make_test_vault(0, 0, 1, 0, DelegationState::default());
+        assert_eq!(vault.check_can_close(), Err(VaultError::VaultNotEmpty));
+
+        let vault = make_test_vault(0, 0, 0, 0, DelegationState::new(0, 0, 1));
+        assert_eq!(vault.check_can_close(), Err(VaultError::VaultNotEmpty));
+
+        let mut vault = make_test_vault(0, 0, 0, 0, DelegationState::default());
+        vault.increment_vrt_ready_to_claim_amount(1).unwrap();
+        assert_eq!(vault.check_can_close(), Err(VaultError::VaultNotEmpty));
+    }
 }
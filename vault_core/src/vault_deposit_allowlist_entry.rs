@@ -0,0 +1,133 @@
+//! The [`VaultDepositAllowlistEntry`] account allows a depositor to mint VRT from a vault
+//! running in the [`jito_vault_sdk::instruction::DepositMode::Allowlist`] deposit mode.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+impl Discriminator for VaultDepositAllowlistEntry {
+    const DISCRIMINATOR: u8 = 10;
+}
+
+/// The [`VaultDepositAllowlistEntry`] account allows a depositor to mint VRT from a vault
+/// running in the [`jito_vault_sdk::instruction::DepositMode::Allowlist`] deposit mode.
+/// It is created and closed by the vault admin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct VaultDepositAllowlistEntry {
+    /// The vault account
+    pub vault: Pubkey,
+
+    /// The depositor allowed to mint VRT from the vault
+    pub depositor: Pubkey,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl VaultDepositAllowlistEntry {
+    pub const fn new(vault: Pubkey, depositor: Pubkey, bump: u8) -> Self {
+        Self {
+            vault,
+            depositor,
+            bump,
+            reserved: [0; 263],
+        }
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault account
+    /// * `depositor` - The depositor account
+    pub fn seeds(vault: &Pubkey, depositor: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"vault_deposit_allowlist_entry".to_vec(),
+            vault.as_ref().to_vec(),
+            depositor.as_ref().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault account
+    /// * `depositor` - The depositor account
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        depositor: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, depositor);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`VaultDepositAllowlistEntry`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault_deposit_allowlist_entry` - The account to load
+    /// * `vault` - The vault account
+    /// * `depositor` - The depositor account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        vault_deposit_allowlist_entry: &AccountInfo,
+        vault: &AccountInfo,
+        depositor: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if vault_deposit_allowlist_entry.owner.ne(program_id) {
+            msg!("Vault deposit allowlist entry account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_deposit_allowlist_entry.data_is_empty() {
+            msg!("Vault deposit allowlist entry account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if expect_writable && !vault_deposit_allowlist_entry.is_writable {
+            msg!("Vault deposit allowlist entry account is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
+        }
+        if vault_deposit_allowlist_entry.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Vault deposit allowlist entry account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let expected_pubkey = Self::find_program_address(program_id, vault.key, depositor.key).0;
+        if vault_deposit_allowlist_entry.key.ne(&expected_pubkey) {
+            msg!("Vault deposit allowlist entry account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_deposit_allowlist_entry_no_padding() {
+        let vault_deposit_allowlist_entry_size = std::mem::size_of::<VaultDepositAllowlistEntry>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // vault
+            std::mem::size_of::<Pubkey>() + // depositor
+            std::mem::size_of::<u8>() + // bump
+            263; // reserved
+        assert_eq!(vault_deposit_allowlist_entry_size, sum_of_fields);
+    }
+}
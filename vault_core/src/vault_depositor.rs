@@ -0,0 +1,219 @@
+//! The [`VaultDepositor`] account tracks the cumulative amount of tokens a depositor has
+//! deposited into a vault, used to enforce the vault's max deposit per wallet, and the slot of
+//! their last deposit, used to enforce the vault's flash deposit protection.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+impl Discriminator for VaultDepositor {
+    const DISCRIMINATOR: u8 = 11;
+}
+
+/// The [`VaultDepositor`] account tracks the cumulative amount of tokens a depositor has
+/// deposited into a vault. It is created by MintTo the first time the depositor deposits
+/// into a vault with a max deposit per wallet or flash deposit protection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct VaultDepositor {
+    /// The vault account
+    pub vault: Pubkey,
+
+    /// The depositor
+    pub depositor: Pubkey,
+
+    /// The cumulative amount of tokens deposited by the depositor, withdrawals do not reduce it
+    tokens_deposited: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// The slot of the depositor's last deposit
+    last_deposit_slot: PodU64,
+
+    /// Reserved space
+    reserved: [u8; 247],
+}
+
+impl VaultDepositor {
+    pub fn new(vault: Pubkey, depositor: Pubkey, bump: u8) -> Self {
+        Self {
+            vault,
+            depositor,
+            tokens_deposited: PodU64::from(0),
+            bump,
+            last_deposit_slot: PodU64::from(0),
+            reserved: [0; 247],
+        }
+    }
+
+    pub fn tokens_deposited(&self) -> u64 {
+        self.tokens_deposited.into()
+    }
+
+    pub fn last_deposit_slot(&self) -> u64 {
+        self.last_deposit_slot.into()
+    }
+
+    pub fn set_last_deposit_slot(&mut self, slot: u64) {
+        self.last_deposit_slot = PodU64::from(slot);
+    }
+
+    /// Burning in the same slot as a deposit is rejected so an exchange rate change in between,
+    /// like rewards landing, can't be sandwiched
+    pub fn check_not_deposited_in_slot(&self, slot: u64) -> Result<(), VaultError> {
+        if self.tokens_deposited() > 0 && self.last_deposit_slot() == slot {
+            msg!("Depositor can't burn in the same slot as their last deposit");
+            return Err(VaultError::VaultBurnInDepositSlot);
+        }
+        Ok(())
+    }
+
+    /// Records a deposit of `amount` tokens, failing if the cumulative amount deposited
+    /// would exceed `max_deposit_per_wallet`. A `max_deposit_per_wallet` of 0 means no limit.
+    pub fn deposit(&mut self, amount: u64, max_deposit_per_wallet: u64) -> Result<(), VaultError> {
+        let tokens_deposited = self
+            .tokens_deposited()
+            .checked_add(amount)
+            .ok_or(VaultError::VaultOverflow)?;
+        if max_deposit_per_wallet > 0 && tokens_deposited > max_deposit_per_wallet {
+            msg!(
+                "Amount exceeds the max deposit per wallet of {}, already deposited {}",
+                max_deposit_per_wallet,
+                self.tokens_deposited()
+            );
+            return Err(VaultError::VaultDepositPerWalletLimitExceeded);
+        }
+        self.tokens_deposited = PodU64::from(tokens_deposited);
+        Ok(())
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault account
+    /// * `depositor` - The depositor account
+    pub fn seeds(vault: &Pubkey, depositor: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"vault_depositor".to_vec(),
+            vault.as_ref().to_vec(),
+            depositor.as_ref().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault account
+    /// * `depositor` - The depositor account
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        depositor: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, depositor);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`VaultDepositor`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault_depositor` - The account to load
+    /// * `vault` - The vault account
+    /// * `depositor` - The depositor account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        vault_depositor: &AccountInfo,
+        vault: &AccountInfo,
+        depositor: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if vault_depositor.owner.ne(program_id) {
+            msg!("Vault depositor account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_depositor.data_is_empty() {
+            msg!("Vault depositor account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if expect_writable && !vault_depositor.is_writable {
+            msg!("Vault depositor account is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
+        }
+        if vault_depositor.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Vault depositor account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let expected_pubkey = Self::find_program_address(program_id, vault.key, depositor.key).0;
+        if vault_depositor.key.ne(&expected_pubkey) {
+            msg!("Vault depositor account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_depositor_no_padding() {
+        let vault_depositor_size = std::mem::size_of::<VaultDepositor>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // vault
+            std::mem::size_of::<Pubkey>() + // depositor
+            std::mem::size_of::<PodU64>() + // tokens_deposited
+            std::mem::size_of::<u8>() + // bump
+            std::mem::size_of::<PodU64>() + // last_deposit_slot
+            247; // reserved
+        assert_eq!(vault_depositor_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_check_not_deposited_in_slot() {
+        let mut vault_depositor =
+            VaultDepositor::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        // never deposited
+        vault_depositor.check_not_deposited_in_slot(0).unwrap();
+
+        vault_depositor.deposit(100, 0).unwrap();
+        vault_depositor.set_last_deposit_slot(10);
+        assert_eq!(
+            vault_depositor.check_not_deposited_in_slot(10),
+            Err(VaultError::VaultBurnInDepositSlot)
+        );
+        vault_depositor.check_not_deposited_in_slot(11).unwrap();
+    }
+
+    #[test]
+    fn test_deposit() {
+        let mut vault_depositor =
+            VaultDepositor::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        vault_depositor.deposit(60, 100).unwrap();
+        assert_eq!(
+            vault_depositor.deposit(41, 100),
+            Err(VaultError::VaultDepositPerWalletLimitExceeded)
+        );
+        assert_eq!(vault_depositor.tokens_deposited(), 60);
+        vault_depositor.deposit(40, 100).unwrap();
+        assert_eq!(vault_depositor.tokens_deposited(), 100);
+
+        // no limit
+        vault_depositor.deposit(1000, 0).unwrap();
+        assert_eq!(vault_depositor.tokens_deposited(), 1100);
+    }
+}
@@ -0,0 +1,276 @@
+//! The [`VaultEpochStats`] account keeps a rolling history of a vault's deposits, withdrawals, fees
+//! and slashes for the last [`VAULT_EPOCH_STATS_LEN`] epochs, so on-chain strategies and UIs can
+//! read recent flows without an indexer. Once a vault initializes it, the processors that move
+//! assets in or out of the vault require it and update it inline.
+use std::cmp::Ordering;
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::{ShankAccount, ShankType};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// The number of epochs of history kept
+pub const VAULT_EPOCH_STATS_LEN: usize = 16;
+
+impl Discriminator for VaultEpochStats {
+    const DISCRIMINATOR: u8 = 14;
+}
+
+/// The flows of a vault in a single epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
+#[repr(C)]
+pub struct EpochStats {
+    /// The epoch the stats are for
+    epoch: PodU64,
+
+    /// The amount of supported tokens deposited
+    deposited: PodU64,
+
+    /// The amount of supported tokens withdrawn by burning VRT or withdrawal tickets
+    withdrawn: PodU64,
+
+    /// The deposit and withdrawal fees taken, in VRT
+    vrt_fees: PodU64,
+
+    /// The amount of supported tokens slashed
+    slashed: PodU64,
+}
+
+impl EpochStats {
+    fn new(epoch: u64) -> Self {
+        Self {
+            epoch: PodU64::from(epoch),
+            ..Self::zeroed()
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn deposited(&self) -> u64 {
+        self.deposited.into()
+    }
+
+    pub fn withdrawn(&self) -> u64 {
+        self.withdrawn.into()
+    }
+
+    pub fn vrt_fees(&self) -> u64 {
+        self.vrt_fees.into()
+    }
+
+    pub fn slashed(&self) -> u64 {
+        self.slashed.into()
+    }
+
+    /// The supported tokens that flowed into the vault minus those that flowed out
+    pub fn net_flow(&self) -> i128 {
+        i128::from(self.deposited()) - i128::from(self.withdrawn()) - i128::from(self.slashed())
+    }
+
+    fn add(counter: &mut PodU64, amount: u64) -> Result<(), VaultError> {
+        let value = u64::from(*counter)
+            .checked_add(amount)
+            .ok_or(VaultError::VaultOverflow)?;
+        *counter = PodU64::from(value);
+        Ok(())
+    }
+}
+
+const _: () = assert!(VAULT_EPOCH_STATS_LEN == 16);
+
+/// The [`VaultEpochStats`] account is a ring buffer of [`EpochStats`] indexed by epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct VaultEpochStats {
+    /// The vault account
+    pub vault: Pubkey,
+
+    /// The stats of the last [`VAULT_EPOCH_STATS_LEN`] epochs, at the index `epoch % VAULT_EPOCH_STATS_LEN`.
+    /// The length is spelled out since shank can't parse a const array length.
+    epochs: [EpochStats; 16],
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl VaultEpochStats {
+    pub fn new(vault: Pubkey, bump: u8) -> Self {
+        Self {
+            vault,
+            epochs: [EpochStats::zeroed(); VAULT_EPOCH_STATS_LEN],
+            bump,
+            reserved: [0; 263],
+        }
+    }
+
+    /// The stats of `epoch`, if it's still in the history. Epochs without any flows return zeroes.
+    pub fn epoch_stats(&self, epoch: u64) -> Option<EpochStats> {
+        let stats = self.epochs[Self::index(epoch)];
+        match stats.epoch().cmp(&epoch) {
+            Ordering::Equal => Some(stats),
+            Ordering::Less => Some(EpochStats::new(epoch)),
+            Ordering::Greater => None,
+        }
+    }
+
+    fn index(epoch: u64) -> usize {
+        (epoch % VAULT_EPOCH_STATS_LEN as u64) as usize
+    }
+
+    /// The stats of `epoch`, overwriting the stats of the epoch that previously used the slot
+    fn epoch_stats_mut(&mut self, epoch: u64) -> &mut EpochStats {
+        let stats = &mut self.epochs[Self::index(epoch)];
+        if stats.epoch() != epoch {
+            *stats = EpochStats::new(epoch);
+        }
+        stats
+    }
+
+    /// Records a deposit of `amount` supported tokens, paying `vrt_fee` VRT in fees
+    pub fn record_deposit(
+        &mut self,
+        epoch: u64,
+        amount: u64,
+        vrt_fee: u64,
+    ) -> Result<(), VaultError> {
+        let stats = self.epoch_stats_mut(epoch);
+        EpochStats::add(&mut stats.deposited, amount)?;
+        EpochStats::add(&mut stats.vrt_fees, vrt_fee)
+    }
+
+    /// Records a withdrawal of `amount` supported tokens, paying `vrt_fee` VRT in fees
+    pub fn record_withdrawal(
+        &mut self,
+        epoch: u64,
+        amount: u64,
+        vrt_fee: u64,
+    ) -> Result<(), VaultError> {
+        let stats = self.epoch_stats_mut(epoch);
+        EpochStats::add(&mut stats.withdrawn, amount)?;
+        EpochStats::add(&mut stats.vrt_fees, vrt_fee)
+    }
+
+    /// Records a slash of `amount` supported tokens
+    pub fn record_slash(&mut self, epoch: u64, amount: u64) -> Result<(), VaultError> {
+        let stats = self.epoch_stats_mut(epoch);
+        EpochStats::add(&mut stats.slashed, amount)
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault account
+    pub fn seeds(vault: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([b"vault_epoch_stats".to_vec(), vault.as_ref().to_vec()])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault account
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`VaultEpochStats`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault_epoch_stats` - The account to load
+    /// * `vault` - The vault account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        vault_epoch_stats: &AccountInfo,
+        vault: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if vault_epoch_stats.owner.ne(program_id) {
+            msg!("Vault epoch stats account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_epoch_stats.data_is_empty() {
+            msg!("Vault epoch stats account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if expect_writable && !vault_epoch_stats.is_writable {
+            msg!("Vault epoch stats account is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
+        }
+        if vault_epoch_stats.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Vault epoch stats account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let expected_pubkey = Self::find_program_address(program_id, vault.key).0;
+        if vault_epoch_stats.key.ne(&expected_pubkey) {
+            msg!("Vault epoch stats account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_epoch_stats_no_padding() {
+        let vault_epoch_stats_size = std::mem::size_of::<VaultEpochStats>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<EpochStats>() * VAULT_EPOCH_STATS_LEN + // epochs
+            size_of::<u8>() + // bump
+            263; // reserved
+        assert_eq!(vault_epoch_stats_size, sum_of_fields);
+        assert_eq!(size_of::<EpochStats>(), 5 * size_of::<PodU64>());
+    }
+
+    #[test]
+    fn test_record_flows() {
+        let mut vault_epoch_stats = VaultEpochStats::new(Pubkey::new_unique(), 0);
+        vault_epoch_stats.record_deposit(3, 1_000, 10).unwrap();
+        vault_epoch_stats.record_deposit(3, 500, 5).unwrap();
+        vault_epoch_stats.record_withdrawal(3, 2_000, 20).unwrap();
+        vault_epoch_stats.record_slash(3, 100).unwrap();
+
+        let stats = vault_epoch_stats.epoch_stats(3).unwrap();
+        assert_eq!(stats.deposited(), 1_500);
+        assert_eq!(stats.withdrawn(), 2_000);
+        assert_eq!(stats.vrt_fees(), 35);
+        assert_eq!(stats.slashed(), 100);
+        assert_eq!(stats.net_flow(), -600);
+    }
+
+    #[test]
+    fn test_history_rolls_over() {
+        let mut vault_epoch_stats = VaultEpochStats::new(Pubkey::new_unique(), 0);
+        vault_epoch_stats.record_deposit(1, 1_000, 0).unwrap();
+
+        // an epoch without flows reads as zeroes
+        assert_eq!(vault_epoch_stats.epoch_stats(2).unwrap().deposited(), 0);
+
+        // the epoch sharing the slot overwrites it and the older epoch falls out of the history
+        let next = 1 + VAULT_EPOCH_STATS_LEN as u64;
+        vault_epoch_stats.record_deposit(next, 7, 0).unwrap();
+        assert_eq!(vault_epoch_stats.epoch_stats(next).unwrap().deposited(), 7);
+        assert_eq!(vault_epoch_stats.epoch_stats(1), None);
+    }
+}
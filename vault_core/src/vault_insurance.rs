@@ -0,0 +1,252 @@
+//! The [`VaultInsurance`] account backs a vault with an insurance fund of its own VRT, held in the
+//! account's associated token account. The fund is topped up by the admin and by a share of the
+//! vault's fees, and burns VRT when the vault is slashed so the VRT exchange rate doesn't drop
+//! until the fund runs out. Once a vault initializes it, the processors that mint fees or slash
+//! require it.
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize, Discriminator,
+};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    rounding::{bps_of, mul_div, RoundingDirection},
+    MAX_FEE_BPS,
+};
+
+impl Discriminator for VaultInsurance {
+    const DISCRIMINATOR: u8 = 19;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct VaultInsurance {
+    /// The vault the insurance fund backs
+    pub vault: Pubkey,
+
+    /// The share of the vault's fees, in basis points, minted to the insurance fund instead of the
+    /// fee wallet
+    fee_bps: PodU16,
+
+    /// The VRT added to the insurance fund by the admin and from fees
+    vrt_funded: PodU64,
+
+    /// The VRT burned by the insurance fund to absorb slashes
+    vrt_absorbed: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl VaultInsurance {
+    pub fn new(vault: Pubkey, bump: u8) -> Self {
+        Self {
+            vault,
+            fee_bps: PodU16::from(0),
+            vrt_funded: PodU64::from(0),
+            vrt_absorbed: PodU64::from(0),
+            bump,
+            reserved: [0; 263],
+        }
+    }
+
+    pub fn fee_bps(&self) -> u16 {
+        self.fee_bps.into()
+    }
+
+    pub fn vrt_funded(&self) -> u64 {
+        self.vrt_funded.into()
+    }
+
+    pub fn vrt_absorbed(&self) -> u64 {
+        self.vrt_absorbed.into()
+    }
+
+    pub fn set_fee_bps(&mut self, fee_bps: u16) -> Result<(), VaultError> {
+        if fee_bps > MAX_FEE_BPS {
+            msg!(
+                "Insurance fee share exceeds maximum allowed of {}",
+                MAX_FEE_BPS
+            );
+            return Err(VaultError::VaultFeeCapExceeded);
+        }
+        self.fee_bps = PodU16::from(fee_bps);
+        Ok(())
+    }
+
+    /// Calculates the insurance fund's share of the fees minted to the vault's fee wallet, rounded
+    /// down so the fee wallet keeps any remainder
+    pub fn calculate_fee(&self, vault_fee_amount: u64) -> Result<u64, VaultError> {
+        bps_of(vault_fee_amount, self.fee_bps(), RoundingDirection::Down)
+    }
+
+    /// Calculates the VRT the insurance fund burns to absorb a slash, which is the slash's share
+    /// of the VRT supply before the slash, rounded down and capped at the fund's balance
+    ///
+    /// # Arguments
+    /// * `slash_amount` - The supported tokens slashed
+    /// * `tokens_deposited` - The vault's tokens deposited before the slash
+    /// * `vrt_supply` - The vault's VRT supply before the slash
+    /// * `insurance_vrt_balance` - The VRT held by the insurance fund
+    pub fn calculate_vrt_to_absorb(
+        slash_amount: u64,
+        tokens_deposited: u64,
+        vrt_supply: u64,
+        insurance_vrt_balance: u64,
+    ) -> Result<u64, VaultError> {
+        if tokens_deposited == 0 {
+            return Ok(0);
+        }
+        let vrt_to_absorb = mul_div(
+            slash_amount,
+            vrt_supply,
+            tokens_deposited,
+            RoundingDirection::Down,
+        )?;
+        Ok(vrt_to_absorb.min(insurance_vrt_balance))
+    }
+
+    pub fn record_funding(&mut self, vrt_amount: u64) -> Result<(), VaultError> {
+        let vrt_funded = self
+            .vrt_funded()
+            .checked_add(vrt_amount)
+            .ok_or(VaultError::VaultOverflow)?;
+        self.vrt_funded = PodU64::from(vrt_funded);
+        Ok(())
+    }
+
+    pub fn record_absorption(&mut self, vrt_amount: u64) -> Result<(), VaultError> {
+        let vrt_absorbed = self
+            .vrt_absorbed()
+            .checked_add(vrt_amount)
+            .ok_or(VaultError::VaultOverflow)?;
+        self.vrt_absorbed = PodU64::from(vrt_absorbed);
+        Ok(())
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault
+    pub fn seeds(vault: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([b"vault_insurance".to_vec(), vault.to_bytes().to_vec()])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`VaultInsurance`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault_insurance` - The account to load
+    /// * `vault` - The vault
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        vault_insurance: &AccountInfo,
+        vault: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if vault_insurance.owner.ne(program_id) {
+            msg!("Vault insurance account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_insurance.data_is_empty() {
+            msg!("Vault insurance account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if expect_writable && !vault_insurance.is_writable {
+            msg!("Vault insurance account is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
+        }
+        if vault_insurance.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Vault insurance account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let expected_pubkey = Self::find_program_address(program_id, vault.key).0;
+        if vault_insurance.key.ne(&expected_pubkey) {
+            msg!("Vault insurance account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_insurance_no_padding() {
+        let vault_insurance_size = std::mem::size_of::<VaultInsurance>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // vault
+            std::mem::size_of::<PodU16>() + // fee_bps
+            std::mem::size_of::<PodU64>() + // vrt_funded
+            std::mem::size_of::<PodU64>() + // vrt_absorbed
+            std::mem::size_of::<u8>() + // bump
+            263; // reserved
+        assert_eq!(vault_insurance_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_calculate_fee() {
+        let mut vault_insurance = VaultInsurance::new(Pubkey::new_unique(), 0);
+        assert_eq!(vault_insurance.calculate_fee(1_000), Ok(0));
+
+        vault_insurance.set_fee_bps(2_500).unwrap();
+        assert_eq!(vault_insurance.calculate_fee(1_000), Ok(250));
+        assert_eq!(vault_insurance.calculate_fee(3), Ok(0));
+
+        assert_eq!(
+            vault_insurance.set_fee_bps(MAX_FEE_BPS + 1),
+            Err(VaultError::VaultFeeCapExceeded)
+        );
+    }
+
+    #[test]
+    fn test_calculate_vrt_to_absorb() {
+        // 100 tokens slashed from 1,000 backing 500 VRT is 50 VRT
+        assert_eq!(
+            VaultInsurance::calculate_vrt_to_absorb(100, 1_000, 500, 1_000),
+            Ok(50)
+        );
+        // capped at the fund's balance
+        assert_eq!(
+            VaultInsurance::calculate_vrt_to_absorb(100, 1_000, 500, 20),
+            Ok(20)
+        );
+        // rounded down
+        assert_eq!(
+            VaultInsurance::calculate_vrt_to_absorb(1, 3, 2, 1_000),
+            Ok(0)
+        );
+        assert_eq!(
+            VaultInsurance::calculate_vrt_to_absorb(100, 0, 0, 1_000),
+            Ok(0)
+        );
+    }
+}
@@ -83,6 +83,16 @@ impl VaultNcnSlasherOperatorTicket {
         Ok(())
     }
 
+    /// Releases an amount counted against the slasher's limit by a slash that was never executed
+    pub fn decrement_slashed(&mut self, amount: u64) -> Result<(), VaultError> {
+        let slashed = self
+            .slashed()
+            .checked_sub(amount)
+            .ok_or(VaultError::VaultSlashUnderflow)?;
+        self.slashed = PodU64::from(slashed);
+        Ok(())
+    }
+
     #[inline(always)]
     pub fn check_slashing_amount_not_exceeded(
         &self,
@@ -188,15 +198,15 @@ impl VaultNcnSlasherOperatorTicket {
         }
         if vault_ncn_slasher_operator_ticket.data_is_empty() {
             msg!("Vault NCN slasher operator data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDataEmpty.into());
         }
         if expect_writable && !vault_ncn_slasher_operator_ticket.is_writable {
             msg!("Vault NCN slasher operator is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotWritable.into());
         }
         if vault_ncn_slasher_operator_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("Vault NCN slasher operator discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
         }
         let expected_pubkey = Self::find_program_address(
             program_id,
@@ -209,7 +219,7 @@ impl VaultNcnSlasherOperatorTicket {
         .0;
         if vault_ncn_slasher_operator_ticket.key.ne(&expected_pubkey) {
             msg!("Vault NCN slasher operator is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -3,6 +3,7 @@
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use jito_jsm_core::slot_toggle::SlotToggle;
+use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
@@ -109,20 +110,20 @@ impl VaultNcnTicket {
         }
         if vault_ncn_ticket.data_is_empty() {
             msg!("Vault NCN ticket account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDataEmpty.into());
         }
         if expect_writable && !vault_ncn_ticket.is_writable {
             msg!("Vault NCN ticket account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotWritable.into());
         }
         if vault_ncn_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("Vault NCN ticket account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
         }
         let expected_pubkey = Self::find_program_address(program_id, vault.key, ncn.key).0;
         if vault_ncn_ticket.key.ne(&expected_pubkey) {
             msg!("Vault NCN ticket account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -2,6 +2,7 @@
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
@@ -32,8 +33,28 @@ pub struct VaultOperatorDelegation {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// Reserved space, formerly the epoch stake last started cooling down. Zeroed by the next
+    /// [`VaultOperatorDelegation::update`] on accounts that still hold it.
+    reserved_cooldown_start_epoch: [u8; 8],
+
+    /// The maximum stake the vault can have on the operator, including stake cooling down.
+    /// Zero means there's no maximum.
+    max_delegation_amount: PodU64,
+
+    /// Whether the delegation admin paused delegating to the operator
+    delegation_paused: u8,
+
+    /// The operator's target weight in the vault's [`crate::delegation_strategy::DelegationStrategy`]
+    target_weight: PodU64,
+
+    /// Whether the vault operator admin banned the operator from receiving new delegations
+    banned: u8,
+
+    /// The vault's reason code for the ban, zero while the operator isn't banned
+    ban_reason: u8,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 236],
 }
 
 impl VaultOperatorDelegation {
@@ -45,7 +66,13 @@ impl VaultOperatorDelegation {
             delegation_state: DelegationState::default(),
             index: PodU64::from(index),
             bump,
-            reserved: [0; 263],
+            reserved_cooldown_start_epoch: [0; 8],
+            max_delegation_amount: PodU64::from(0),
+            delegation_paused: 0,
+            target_weight: PodU64::from(0),
+            banned: 0,
+            ban_reason: 0,
+            reserved: [0; 236],
         }
     }
 
@@ -57,6 +84,95 @@ impl VaultOperatorDelegation {
         self.index.into()
     }
 
+    pub fn max_delegation_amount(&self) -> u64 {
+        self.max_delegation_amount.into()
+    }
+
+    pub fn set_max_delegation_amount(&mut self, max_delegation_amount: u64) {
+        self.max_delegation_amount = PodU64::from(max_delegation_amount);
+    }
+
+    pub fn target_weight(&self) -> u64 {
+        self.target_weight.into()
+    }
+
+    pub fn set_target_weight(&mut self, target_weight: u64) {
+        self.target_weight = PodU64::from(target_weight);
+    }
+
+    pub fn is_delegation_paused(&self) -> bool {
+        self.delegation_paused == 1
+    }
+
+    pub fn set_delegation_paused(&mut self, delegation_paused: bool) {
+        self.delegation_paused = u8::from(delegation_paused);
+    }
+
+    #[inline(always)]
+    pub fn check_delegation_paused(&self) -> Result<(), VaultError> {
+        if self.is_delegation_paused() {
+            msg!("Delegation to operator {} is paused", self.operator);
+            return Err(VaultError::VaultOperatorDelegationPaused);
+        }
+        Ok(())
+    }
+
+    pub fn is_banned(&self) -> bool {
+        self.banned == 1
+    }
+
+    pub const fn ban_reason(&self) -> u8 {
+        self.ban_reason
+    }
+
+    /// Bans the operator, or changes the reason of an existing ban
+    ///
+    /// # Arguments
+    /// * `reason` - The vault's reason code for the ban
+    pub fn ban(&mut self, reason: u8) {
+        self.banned = 1;
+        self.ban_reason = reason;
+    }
+
+    /// Lifts the ban on the operator
+    pub fn unban(&mut self) -> Result<(), VaultError> {
+        if !self.is_banned() {
+            msg!("Operator {} is not banned", self.operator);
+            return Err(VaultError::VaultOperatorNotBanned);
+        }
+        self.banned = 0;
+        self.ban_reason = 0;
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn check_not_banned(&self) -> Result<(), VaultError> {
+        if self.is_banned() {
+            msg!(
+                "Operator {} is banned with reason {}",
+                self.operator,
+                self.ban_reason
+            );
+            return Err(VaultError::VaultOperatorBanned);
+        }
+        Ok(())
+    }
+
+    /// Checks the stake on the operator, including stake that's cooling down and can still be
+    /// slashed, doesn't exceed the delegation's maximum, bounding the vault's exposure to it
+    pub fn check_max_delegation_amount(&self) -> Result<(), VaultError> {
+        let total_security = self.delegation_state.total_security()?;
+        if self.max_delegation_amount() > 0 && total_security > self.max_delegation_amount() {
+            msg!(
+                "Delegation of {} exceeds the operator's maximum delegation amount of {}",
+                total_security,
+                self.max_delegation_amount()
+            );
+            return Err(VaultError::VaultDelegationAboveMaximum);
+        }
+        Ok(())
+    }
+
     pub fn is_update_needed(&self, slot: u64, epoch_length: u64) -> bool {
         let last_updated_epoch = self.last_update_slot().checked_div(epoch_length).unwrap();
         let current_epoch = slot.checked_div(epoch_length).unwrap();
@@ -64,29 +180,34 @@ impl VaultOperatorDelegation {
     }
 
     /// Updates the state of the delegation
-    /// The cooling_down_amount becomes the enqueued_for_cooldown_amount
-    /// The enqueued_for_cooldown_amount is zeroed out
-    /// The cooling_down_for_withdraw_amount becomes the enqueued_for_withdraw_amount
-    /// The enqueued_for_withdraw_amount is zeroed out
+    /// The enqueued_for_cooldown_amount starts cooling down at the first epoch boundary, and stake
+    /// cooling down is released `cooldown_epochs` epochs after it started cooling down, regardless
+    /// of any stake that started cooling down after it
     #[inline(always)]
-    pub fn update(&mut self, slot: u64, epoch_length: u64) {
+    pub fn update(
+        &mut self,
+        slot: u64,
+        epoch_length: u64,
+        cooldown_epochs: u64,
+    ) -> Result<(), VaultError> {
         let last_update_epoch = self.last_update_slot().checked_div(epoch_length).unwrap();
         let current_epoch = slot.checked_div(epoch_length).unwrap();
 
-        let epoch_diff = current_epoch.checked_sub(last_update_epoch).unwrap();
-        match epoch_diff {
-            0 => {
-                // do nothing
-            }
-            1 => {
-                self.delegation_state.update();
-            }
-            _ => {
-                // max 2 transitions needed (enqueued -> cooling down and cooling down -> not allocated)
-                self.delegation_state.update();
-                self.delegation_state.update();
-            }
+        if current_epoch > last_update_epoch {
+            self.delegation_state.advance_cooldown(
+                current_epoch.checked_sub(last_update_epoch).unwrap(),
+                cooldown_epochs,
+            )?;
         }
+        self.reserved_cooldown_start_epoch = [0; 8];
+        self.last_update_slot = PodU64::from(slot);
+        Ok(())
+    }
+
+    /// Marks the delegation as updated without moving any stake through the cooldown, used while
+    /// the operator's stake is locked by an NCN task window. The deferred transitions happen the
+    /// next time the delegation is updated after the window closes.
+    pub fn defer_update(&mut self, slot: u64) {
         self.last_update_slot = PodU64::from(slot);
     }
 
@@ -145,20 +266,20 @@ impl VaultOperatorDelegation {
         }
         if vault_operator_delegation.data_is_empty() {
             msg!("Vault operator ticket account data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDataEmpty.into());
         }
         if expect_writable && !vault_operator_delegation.is_writable {
             msg!("Vault operator ticket account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotWritable.into());
         }
         if vault_operator_delegation.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("Vault operator ticket account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
         }
         let expected_pubkey = Self::find_program_address(program_id, vault.key, operator.key).0;
         if vault_operator_delegation.key.ne(&expected_pubkey) {
             msg!("Vault operator ticket account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -177,10 +298,91 @@ mod tests {
             size_of::<PodU64>() + // last_update_slot
             size_of::<PodU64>() + // index
             size_of::<u8>() + // bump
-            263; // reserved
+            8 + // reserved_cooldown_start_epoch
+            size_of::<PodU64>() + // max_delegation_amount
+            size_of::<u8>() + // delegation_paused
+            size_of::<PodU64>() + // target_weight
+            size_of::<u8>() + // banned
+            size_of::<u8>() + // ban_reason
+            236; // reserved
         assert_eq!(vault_operator_delegation_size, sum_of_fields);
     }
 
+    #[test]
+    fn test_check_max_delegation_amount() {
+        let mut vault_operator_delegation =
+            VaultOperatorDelegation::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+        vault_operator_delegation
+            .delegation_state
+            .delegate(100)
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.check_max_delegation_amount(),
+            Ok(())
+        );
+
+        vault_operator_delegation.set_max_delegation_amount(100);
+        assert_eq!(
+            vault_operator_delegation.check_max_delegation_amount(),
+            Ok(())
+        );
+
+        // stake cooling down still counts towards the maximum
+        vault_operator_delegation
+            .delegation_state
+            .cooldown(50)
+            .unwrap();
+        vault_operator_delegation
+            .delegation_state
+            .delegate(1)
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.check_max_delegation_amount(),
+            Err(VaultError::VaultDelegationAboveMaximum)
+        );
+    }
+
+    #[test]
+    fn test_check_delegation_paused() {
+        let mut vault_operator_delegation =
+            VaultOperatorDelegation::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+        assert_eq!(vault_operator_delegation.check_delegation_paused(), Ok(()));
+
+        vault_operator_delegation.set_delegation_paused(true);
+        assert!(vault_operator_delegation.is_delegation_paused());
+        assert_eq!(
+            vault_operator_delegation.check_delegation_paused(),
+            Err(VaultError::VaultOperatorDelegationPaused)
+        );
+
+        vault_operator_delegation.set_delegation_paused(false);
+        assert_eq!(vault_operator_delegation.check_delegation_paused(), Ok(()));
+    }
+
+    #[test]
+    fn test_ban_and_unban() {
+        let mut vault_operator_delegation =
+            VaultOperatorDelegation::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+        assert_eq!(vault_operator_delegation.check_not_banned(), Ok(()));
+        assert_eq!(
+            vault_operator_delegation.unban(),
+            Err(VaultError::VaultOperatorNotBanned)
+        );
+
+        vault_operator_delegation.ban(3);
+        assert!(vault_operator_delegation.is_banned());
+        assert_eq!(vault_operator_delegation.ban_reason(), 3);
+        assert_eq!(
+            vault_operator_delegation.check_not_banned(),
+            Err(VaultError::VaultOperatorBanned)
+        );
+
+        vault_operator_delegation.unban().unwrap();
+        assert!(!vault_operator_delegation.is_banned());
+        assert_eq!(vault_operator_delegation.ban_reason(), 0);
+        assert_eq!(vault_operator_delegation.check_not_banned(), Ok(()));
+    }
+
     #[test]
     fn test_vault_operator_delegation_update_single_epoch_ok() {
         let mut vault_operator_delegation =
@@ -194,7 +396,7 @@ mod tests {
             .delegation_state
             .cooldown(50)
             .unwrap();
-        vault_operator_delegation.update(100, 100);
+        vault_operator_delegation.update(100, 100, 1).unwrap();
         assert_eq!(
             vault_operator_delegation.delegation_state.staked_amount(),
             50
@@ -226,7 +428,7 @@ mod tests {
             .delegation_state
             .cooldown(50)
             .unwrap();
-        vault_operator_delegation.update(200, 100);
+        vault_operator_delegation.update(200, 100, 1).unwrap();
         assert_eq!(
             vault_operator_delegation.delegation_state.staked_amount(),
             50
@@ -259,7 +461,7 @@ mod tests {
             .cooldown(50)
             .unwrap();
 
-        vault_operator_delegation.update(599, 100);
+        vault_operator_delegation.update(599, 100, 1).unwrap();
         assert_eq!(
             vault_operator_delegation.delegation_state.staked_amount(),
             50
@@ -278,7 +480,7 @@ mod tests {
         );
         assert_eq!(vault_operator_delegation.last_update_slot(), 599);
 
-        vault_operator_delegation.update(600, 100);
+        vault_operator_delegation.update(600, 100, 1).unwrap();
         assert_eq!(
             vault_operator_delegation.delegation_state.staked_amount(),
             50
@@ -297,4 +499,176 @@ mod tests {
         );
         assert_eq!(vault_operator_delegation.last_update_slot(), 600);
     }
+
+    #[test]
+    fn test_vault_operator_delegation_update_multi_epoch_cooldown_ok() {
+        let mut vault_operator_delegation =
+            VaultOperatorDelegation::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+
+        vault_operator_delegation
+            .delegation_state
+            .delegate(100)
+            .unwrap();
+        vault_operator_delegation
+            .delegation_state
+            .cooldown(50)
+            .unwrap();
+
+        // enqueued in epoch 0, cooling down from epoch 1 until epoch 4
+        for slot in [100, 200, 300] {
+            vault_operator_delegation.update(slot, 100, 3).unwrap();
+            assert_eq!(
+                vault_operator_delegation
+                    .delegation_state
+                    .cooling_down_amount(),
+                50
+            );
+        }
+
+        vault_operator_delegation.update(400, 100, 3).unwrap();
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .cooling_down_amount(),
+            0
+        );
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            50
+        );
+    }
+
+    #[test]
+    fn test_vault_operator_delegation_update_skipped_epochs_with_cooldown_ok() {
+        let mut vault_operator_delegation =
+            VaultOperatorDelegation::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+
+        vault_operator_delegation
+            .delegation_state
+            .delegate(100)
+            .unwrap();
+        vault_operator_delegation
+            .delegation_state
+            .cooldown(50)
+            .unwrap();
+
+        vault_operator_delegation.update(300, 100, 3).unwrap();
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .cooling_down_amount(),
+            50
+        );
+
+        vault_operator_delegation.update(1_000, 100, 3).unwrap();
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .cooling_down_amount(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_vault_operator_delegation_update_zeroes_legacy_cooldown_start_epoch() {
+        let mut vault_operator_delegation =
+            VaultOperatorDelegation::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+        vault_operator_delegation.reserved_cooldown_start_epoch = 7_u64.to_le_bytes();
+
+        vault_operator_delegation.update(100, 100, 1).unwrap();
+        assert_eq!(
+            vault_operator_delegation.reserved_cooldown_start_epoch,
+            [0; 8]
+        );
+    }
+
+    #[test]
+    fn test_vault_operator_delegation_update_cooldown_per_cohort_ok() {
+        let mut vault_operator_delegation =
+            VaultOperatorDelegation::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+
+        vault_operator_delegation
+            .delegation_state
+            .delegate(100)
+            .unwrap();
+
+        // stake enqueued in epochs 0, 1 and 2, each cooling down for 3 epochs from the next one
+        for (slot, amount) in [(100, 50), (200, 20), (300, 10)] {
+            vault_operator_delegation
+                .delegation_state
+                .cooldown(amount)
+                .unwrap();
+            vault_operator_delegation.update(slot, 100, 3).unwrap();
+        }
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .cooling_down_amount(),
+            80
+        );
+
+        // the first amount is released on time even though more stake started cooling down since
+        vault_operator_delegation.update(400, 100, 3).unwrap();
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .cooling_down_amount(),
+            30
+        );
+
+        vault_operator_delegation.update(500, 100, 3).unwrap();
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .cooling_down_amount(),
+            10
+        );
+
+        vault_operator_delegation.update(600, 100, 3).unwrap();
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .cooling_down_amount(),
+            0
+        );
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .total_security()
+                .unwrap(),
+            20
+        );
+    }
+
+    #[test]
+    fn test_vault_operator_delegation_defer_update_ok() {
+        let mut vault_operator_delegation =
+            VaultOperatorDelegation::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+
+        vault_operator_delegation
+            .delegation_state
+            .delegate(100)
+            .unwrap();
+        vault_operator_delegation
+            .delegation_state
+            .cooldown(50)
+            .unwrap();
+
+        vault_operator_delegation.defer_update(100);
+        assert_eq!(vault_operator_delegation.last_update_slot(), 100);
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .enqueued_for_cooldown_amount(),
+            50
+        );
+
+        vault_operator_delegation.update(200, 100, 1).unwrap();
+        assert_eq!(
+            vault_operator_delegation
+                .delegation_state
+                .cooling_down_amount(),
+            50
+        );
+    }
 }
@@ -0,0 +1,176 @@
+//! The [`VaultOperatorDelegationSnapshot`] account freezes a vault's delegation to an operator at
+//! the start of an NCN epoch. It's written once per operator and epoch while the vault is being
+//! updated, after the operator's delegation is cranked and before anything can change it, and is
+//! never modified, so reward distributors and NCNs can compute payouts against it instead of the
+//! live [`crate::vault_operator_delegation::VaultOperatorDelegation`].
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::delegation_state::DelegationState;
+
+impl Discriminator for VaultOperatorDelegationSnapshot {
+    const DISCRIMINATOR: u8 = 15;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct VaultOperatorDelegationSnapshot {
+    /// The vault account
+    pub vault: Pubkey,
+
+    /// The operator account
+    pub operator: Pubkey,
+
+    /// The NCN epoch the snapshot was taken at the start of
+    ncn_epoch: PodU64,
+
+    /// The index of the operator delegation in the vault
+    index: PodU64,
+
+    /// The operator delegation's state at the start of the epoch
+    pub delegation_state: DelegationState,
+
+    /// The slot the snapshot was taken
+    slot_created: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl VaultOperatorDelegationSnapshot {
+    pub fn new(
+        vault: Pubkey,
+        operator: Pubkey,
+        ncn_epoch: u64,
+        index: u64,
+        delegation_state: DelegationState,
+        slot_created: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            vault,
+            operator,
+            ncn_epoch: PodU64::from(ncn_epoch),
+            index: PodU64::from(index),
+            delegation_state,
+            slot_created: PodU64::from(slot_created),
+            bump,
+            reserved: [0; 263],
+        }
+    }
+
+    pub fn ncn_epoch(&self) -> u64 {
+        self.ncn_epoch.into()
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index.into()
+    }
+
+    pub fn slot_created(&self) -> u64 {
+        self.slot_created.into()
+    }
+
+    /// The seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault account
+    /// * `operator` - The operator account
+    /// * `ncn_epoch` - The NCN epoch
+    pub fn seeds(vault: &Pubkey, operator: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"vault_delegation_snapshot".to_vec(),
+            vault.to_bytes().to_vec(),
+            operator.to_bytes().to_vec(),
+            ncn_epoch.to_le_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault account
+    /// * `operator` - The operator account
+    /// * `ncn_epoch` - The NCN epoch
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        ncn_epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, operator, ncn_epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`VaultOperatorDelegationSnapshot`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault_operator_delegation_snapshot` - The account to load
+    /// * `vault` - The vault account
+    /// * `operator` - The operator account
+    /// * `ncn_epoch` - The NCN epoch
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        vault_operator_delegation_snapshot: &AccountInfo,
+        vault: &AccountInfo,
+        operator: &AccountInfo,
+        ncn_epoch: u64,
+    ) -> Result<(), ProgramError> {
+        if vault_operator_delegation_snapshot.owner.ne(program_id) {
+            msg!("Vault operator delegation snapshot account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_operator_delegation_snapshot.data_is_empty() {
+            msg!("Vault operator delegation snapshot account data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
+        }
+        if vault_operator_delegation_snapshot.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Vault operator delegation snapshot account discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+        let expected_pubkey =
+            Self::find_program_address(program_id, vault.key, operator.key, ncn_epoch).0;
+        if vault_operator_delegation_snapshot.key.ne(&expected_pubkey) {
+            msg!("Vault operator delegation snapshot account is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_operator_delegation_snapshot_no_padding() {
+        let snapshot_size = std::mem::size_of::<VaultOperatorDelegationSnapshot>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // vault
+            std::mem::size_of::<Pubkey>() + // operator
+            std::mem::size_of::<PodU64>() + // ncn_epoch
+            std::mem::size_of::<PodU64>() + // index
+            std::mem::size_of::<DelegationState>() + // delegation_state
+            std::mem::size_of::<PodU64>() + // slot_created
+            std::mem::size_of::<u8>() + // bump
+            263; // reserved
+        assert_eq!(snapshot_size, sum_of_fields);
+    }
+}
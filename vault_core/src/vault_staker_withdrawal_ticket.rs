@@ -1,11 +1,19 @@
 //! The [`VaultStakerWithdrawalTicket`] account is used to represent a pending withdrawal from a vault by a staker.
 //! For every withdraw ticket, there's an associated token account owned by the withdrawal ticket with the staker's VRT.
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize, Discriminator,
+};
 use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
+use crate::{
+    rounding::{bps_of, RoundingDirection},
+    ADMIN_BURN_TIMELOCK_EPOCHS, MAX_FEE_BPS,
+};
+
 impl Discriminator for VaultStakerWithdrawalTicket {
     const DISCRIMINATOR: u8 = 7;
 }
@@ -34,7 +42,22 @@ pub struct VaultStakerWithdrawalTicket {
     /// The bump seed used to create the PDA
     pub bump: u8,
 
-    reserved: [u8; 263],
+    /// The fee in basis points of the withdrawn assets the staker pays to whoever burns the
+    /// ticket on their behalf. Zero means the staker hasn't opted into auto-claiming.
+    auto_claim_fee_bps: PodU16,
+
+    /// The address the ticket's assets are sent to by a pending admin burn, the default pubkey
+    /// when no admin burn is pending
+    pub admin_burn_recovery: Pubkey,
+
+    /// The slot after which the pending admin burn can be executed
+    admin_burn_slot: PodU64,
+
+    /// The vault's withdrawal delay when the withdrawal was enqueued, so changing the delay
+    /// doesn't affect withdrawals already in the queue
+    withdrawal_delay_epochs: PodU64,
+
+    reserved: [u8; 213],
 }
 
 impl VaultStakerWithdrawalTicket {
@@ -45,6 +68,7 @@ impl VaultStakerWithdrawalTicket {
         vrt_amount: u64,
         slot_unstaked: u64,
         bump: u8,
+        withdrawal_delay_epochs: u64,
     ) -> Self {
         Self {
             vault,
@@ -53,7 +77,11 @@ impl VaultStakerWithdrawalTicket {
             vrt_amount: PodU64::from(vrt_amount),
             slot_unstaked: PodU64::from(slot_unstaked),
             bump,
-            reserved: [0; 263],
+            auto_claim_fee_bps: PodU16::from(0),
+            admin_burn_recovery: Pubkey::default(),
+            admin_burn_slot: PodU64::from(0),
+            withdrawal_delay_epochs: PodU64::from(withdrawal_delay_epochs),
+            reserved: [0; 213],
         }
     }
 
@@ -65,6 +93,101 @@ impl VaultStakerWithdrawalTicket {
         self.slot_unstaked.into()
     }
 
+    pub fn withdrawal_delay_epochs(&self) -> u64 {
+        self.withdrawal_delay_epochs.into()
+    }
+
+    pub fn auto_claim_fee_bps(&self) -> u16 {
+        self.auto_claim_fee_bps.into()
+    }
+
+    pub fn set_auto_claim_fee_bps(&mut self, auto_claim_fee_bps: u16) -> Result<(), VaultError> {
+        if auto_claim_fee_bps > MAX_FEE_BPS {
+            msg!("Auto-claim fee exceeds maximum allowed of {}", MAX_FEE_BPS);
+            return Err(VaultError::VaultFeeCapExceeded);
+        }
+        self.auto_claim_fee_bps = PodU16::from(auto_claim_fee_bps);
+        Ok(())
+    }
+
+    /// Calculates the portion of `out_amount` paid to the claimer of an auto-claimed ticket.
+    /// The fee comes out of the staker's withdrawal, so it's rounded down in their favor.
+    pub fn calculate_auto_claim_fee(&self, out_amount: u64) -> Result<u64, VaultError> {
+        bps_of(
+            out_amount,
+            self.auto_claim_fee_bps(),
+            RoundingDirection::Down,
+        )
+    }
+
+    pub fn admin_burn_slot(&self) -> u64 {
+        self.admin_burn_slot.into()
+    }
+
+    pub fn is_admin_burn_pending(&self) -> bool {
+        self.admin_burn_recovery.ne(&Pubkey::default())
+    }
+
+    /// The staker can't burn or transfer the ticket while an admin burn is pending
+    pub fn check_no_admin_burn_pending(&self) -> Result<(), VaultError> {
+        if self.is_admin_burn_pending() {
+            msg!("An admin burn is pending on the withdrawal ticket");
+            return Err(VaultError::VaultAdminBurnPending);
+        }
+        Ok(())
+    }
+
+    /// Proposes burning the ticket's VRT and sending the assets to `recovery`, which can be
+    /// executed once [`ADMIN_BURN_TIMELOCK_EPOCHS`] full epochs have passed
+    pub fn propose_admin_burn(
+        &mut self,
+        recovery: &Pubkey,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), VaultError> {
+        self.check_no_admin_burn_pending()?;
+        if recovery.eq(&Pubkey::default()) {
+            msg!("Admin burn recovery address can't be the default pubkey");
+            return Err(VaultError::VaultBurnAdminInvalid);
+        }
+        let admin_burn_slot = ADMIN_BURN_TIMELOCK_EPOCHS
+            .checked_mul(epoch_length)
+            .and_then(|timelock_slots| slot.checked_add(timelock_slots))
+            .ok_or(VaultError::VaultOverflow)?;
+        self.admin_burn_recovery = *recovery;
+        self.admin_burn_slot = PodU64::from(admin_burn_slot);
+        Ok(())
+    }
+
+    pub fn cancel_admin_burn(&mut self) -> Result<(), VaultError> {
+        if !self.is_admin_burn_pending() {
+            msg!("No admin burn is pending on the withdrawal ticket");
+            return Err(VaultError::VaultAdminBurnNotPending);
+        }
+        self.admin_burn_recovery = Pubkey::default();
+        self.admin_burn_slot = PodU64::from(0);
+        Ok(())
+    }
+
+    /// Checks that the pending admin burn's timelock has passed
+    ///
+    /// # Returns
+    /// * `Result<Pubkey, VaultError>` - The recovery address to send the assets to
+    pub fn check_admin_burn_executable(&self, slot: u64) -> Result<Pubkey, VaultError> {
+        if !self.is_admin_burn_pending() {
+            msg!("No admin burn is pending on the withdrawal ticket");
+            return Err(VaultError::VaultAdminBurnNotPending);
+        }
+        if slot < self.admin_burn_slot() {
+            msg!(
+                "Admin burn can't be executed until slot {}",
+                self.admin_burn_slot()
+            );
+            return Err(VaultError::VaultAdminBurnTimelockActive);
+        }
+        Ok(self.admin_burn_recovery)
+    }
+
     pub fn check_staker(&self, staker: &Pubkey) -> Result<(), VaultError> {
         if self.staker.ne(staker) {
             msg!("Staker is not the owner of the withdrawal ticket");
@@ -73,14 +196,20 @@ impl VaultStakerWithdrawalTicket {
         Ok(())
     }
 
-    /// In order for the ticket to be withdrawable, it needs to be more than one **full** epoch
-    /// since unstaking
-    pub fn is_withdrawable(&self, slot: u64, epoch_length: u64) -> Result<bool, ProgramError> {
+    /// In order for the ticket to be withdrawable, it needs to be more than `cooldown_epochs`
+    /// **full** epochs since unstaking, so the stake cooling down to pay for it has been released,
+    /// or more than the vault's withdrawal delay at the time it was enqueued if that's longer
+    pub fn is_withdrawable(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+        cooldown_epochs: u64,
+    ) -> Result<bool, ProgramError> {
         let current_epoch = slot.checked_div(epoch_length).unwrap();
         let epoch_unstaked = self.slot_unstaked().checked_div(epoch_length).unwrap();
         if current_epoch
             <= epoch_unstaked
-                .checked_add(1)
+                .checked_add(self.withdrawal_delay_epochs().max(cooldown_epochs).max(1))
                 .ok_or(ProgramError::ArithmeticOverflow)?
         {
             Ok(false)
@@ -149,22 +278,22 @@ impl VaultStakerWithdrawalTicket {
         }
         if vault_staker_withdrawal_ticket.data_is_empty() {
             msg!("Vault staker withdraw ticket data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDataEmpty.into());
         }
         if expect_writable && !vault_staker_withdrawal_ticket.is_writable {
             msg!("Vault staker withdraw ticket is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotWritable.into());
         }
         if vault_staker_withdrawal_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
             msg!("Vault staker withdraw ticket discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
         }
         let vault_staker_withdraw_ticket_data = vault_staker_withdrawal_ticket.data.borrow();
         let base = Self::try_from_slice_unchecked(&vault_staker_withdraw_ticket_data)?.base;
         let expected_pubkey = Self::find_program_address(program_id, vault.key, &base).0;
         if vault_staker_withdrawal_ticket.key.ne(&expected_pubkey) {
             msg!("Vault staker withdraw ticket is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -184,7 +313,144 @@ mod tests {
             size_of::<PodU64>() + // vrt_amount
             size_of::<PodU64>() + // slot_unstaked
             size_of::<u8>() + // bump
-            263; // reserved
+            size_of::<PodU16>() + // auto_claim_fee_bps
+            size_of::<Pubkey>() + // admin_burn_recovery
+            size_of::<PodU64>() + // admin_burn_slot
+            size_of::<PodU64>() + // withdrawal_delay_epochs
+            213; // reserved
         assert_eq!(vault_staker_withdrawal_ticket_size, sum_of_fields);
     }
+
+    #[test]
+    fn test_auto_claim_fee() {
+        let mut ticket = VaultStakerWithdrawalTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            0,
+            0,
+            0,
+        );
+        assert_eq!(ticket.auto_claim_fee_bps(), 0);
+        assert_eq!(ticket.calculate_auto_claim_fee(1_000).unwrap(), 0);
+
+        ticket.set_auto_claim_fee_bps(50).unwrap();
+        assert_eq!(ticket.calculate_auto_claim_fee(1_000).unwrap(), 5);
+        // rounds down in favor of the staker
+        assert_eq!(ticket.calculate_auto_claim_fee(399).unwrap(), 1);
+
+        assert_eq!(
+            ticket.set_auto_claim_fee_bps(MAX_FEE_BPS + 1),
+            Err(VaultError::VaultFeeCapExceeded)
+        );
+        assert_eq!(ticket.auto_claim_fee_bps(), 50);
+    }
+
+    #[test]
+    fn test_is_withdrawable_with_withdrawal_delay() {
+        let epoch_length = 100;
+        let ticket = VaultStakerWithdrawalTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            50,
+            0,
+            0,
+        );
+        assert!(!ticket.is_withdrawable(199, epoch_length, 1).unwrap());
+        assert!(ticket.is_withdrawable(200, epoch_length, 1).unwrap());
+
+        let ticket = VaultStakerWithdrawalTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            50,
+            0,
+            3,
+        );
+        assert!(!ticket.is_withdrawable(399, epoch_length, 1).unwrap());
+        assert!(ticket.is_withdrawable(400, epoch_length, 1).unwrap());
+    }
+
+    #[test]
+    fn test_is_withdrawable_with_delegation_cooldown() {
+        let epoch_length = 100;
+        let ticket = VaultStakerWithdrawalTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            50,
+            0,
+            0,
+        );
+        assert!(!ticket.is_withdrawable(399, epoch_length, 3).unwrap());
+        assert!(ticket.is_withdrawable(400, epoch_length, 3).unwrap());
+
+        // the longer of the withdrawal delay and the cooldown applies
+        let ticket = VaultStakerWithdrawalTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            50,
+            0,
+            5,
+        );
+        assert!(!ticket.is_withdrawable(599, epoch_length, 3).unwrap());
+        assert!(ticket.is_withdrawable(600, epoch_length, 3).unwrap());
+    }
+
+    #[test]
+    fn test_admin_burn() {
+        let mut ticket = VaultStakerWithdrawalTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            0,
+            0,
+            0,
+        );
+        let epoch_length = 100;
+        ticket.check_no_admin_burn_pending().unwrap();
+        assert_eq!(
+            ticket.cancel_admin_burn(),
+            Err(VaultError::VaultAdminBurnNotPending)
+        );
+        assert_eq!(
+            ticket.check_admin_burn_executable(u64::MAX),
+            Err(VaultError::VaultAdminBurnNotPending)
+        );
+
+        let recovery = Pubkey::new_unique();
+        ticket
+            .propose_admin_burn(&recovery, 50, epoch_length)
+            .unwrap();
+        assert_eq!(
+            ticket.check_no_admin_burn_pending(),
+            Err(VaultError::VaultAdminBurnPending)
+        );
+        assert_eq!(
+            ticket.propose_admin_burn(&Pubkey::new_unique(), 50, epoch_length),
+            Err(VaultError::VaultAdminBurnPending)
+        );
+        let admin_burn_slot = 50 + ADMIN_BURN_TIMELOCK_EPOCHS * epoch_length;
+        assert_eq!(ticket.admin_burn_slot(), admin_burn_slot);
+        assert_eq!(
+            ticket.check_admin_burn_executable(admin_burn_slot - 1),
+            Err(VaultError::VaultAdminBurnTimelockActive)
+        );
+        assert_eq!(
+            ticket.check_admin_burn_executable(admin_burn_slot),
+            Ok(recovery)
+        );
+
+        ticket.cancel_admin_burn().unwrap();
+        assert!(!ticket.is_admin_burn_pending());
+        assert_eq!(ticket.admin_burn_slot(), 0);
+    }
 }
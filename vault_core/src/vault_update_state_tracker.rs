@@ -4,7 +4,7 @@ use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::delegation_state::DelegationState;
+use crate::{delegation_snapshot::DelegationSnapshot, delegation_state::DelegationState};
 
 impl Discriminator for VaultUpdateStateTracker {
     const DISCRIMINATOR: u8 = 9;
@@ -30,7 +30,15 @@ pub struct VaultUpdateStateTracker {
 
     pub withdrawal_allocation_method: u8,
 
-    reserved: [u8; 263],
+    /// The Merkle tree over the operator delegations cranked so far, its root is stored on the
+    /// vault when the tracker is closed
+    pub delegation_snapshot: DelegationSnapshot,
+
+    /// The number of operator delegations with stake whose update was deferred because an NCN
+    /// has a task window open on, or has frozen, the operator
+    deferred_update_count: PodU64,
+
+    reserved: [u8; 255],
 }
 
 impl VaultUpdateStateTracker {
@@ -47,7 +55,9 @@ impl VaultUpdateStateTracker {
             last_updated_index: PodU64::from(u64::MAX),
             delegation_state: DelegationState::default(),
             withdrawal_allocation_method,
-            reserved: [0; 263],
+            delegation_snapshot: DelegationSnapshot::default(),
+            deferred_update_count: PodU64::from(0),
+            reserved: [0; 255],
         }
     }
 
@@ -63,6 +73,25 @@ impl VaultUpdateStateTracker {
         self.last_updated_index.into()
     }
 
+    pub fn deferred_update_count(&self) -> u64 {
+        self.deferred_update_count.into()
+    }
+
+    /// Whether any operator delegation's update was deferred this epoch, in which case the vault
+    /// holds its VRT cooldown so it doesn't release VRT the deferred stake hasn't paid for
+    pub fn has_deferred_updates(&self) -> bool {
+        self.deferred_update_count() > 0
+    }
+
+    pub fn increment_deferred_update_count(&mut self) -> Result<(), VaultError> {
+        let new_count = self
+            .deferred_update_count()
+            .checked_add(1)
+            .ok_or(VaultError::VaultOverflow)?;
+        self.deferred_update_count = PodU64::from(new_count);
+        Ok(())
+    }
+
     pub fn decrement_additional_assets_need_unstaking(
         &mut self,
         amount: u64,
@@ -89,6 +118,15 @@ impl VaultUpdateStateTracker {
         Ok(())
     }
 
+    /// Checks the operator delegation at `index` was already cranked for the epoch
+    pub fn check_cranked(&self, index: u64) -> Result<(), VaultError> {
+        if self.last_updated_index() == u64::MAX || index > self.last_updated_index() {
+            msg!("Vault operator delegation has not been cranked this epoch");
+            return Err(VaultError::VaultOperatorDelegationNotCranked);
+        }
+        Ok(())
+    }
+
     /// Returns the seeds for the PDA
     ///
     /// # Arguments
@@ -122,25 +160,25 @@ impl VaultUpdateStateTracker {
         expect_writable: bool,
     ) -> Result<(), ProgramError> {
         if vault_update_delegation_ticket.owner.ne(program_id) {
-            msg!("Vault update delegations ticket has an invalid owner");
+            msg!("Vault update state tracker has an invalid owner");
             return Err(ProgramError::InvalidAccountOwner);
         }
         if vault_update_delegation_ticket.data_is_empty() {
-            msg!("Vault update delegations ticket data is empty");
-            return Err(ProgramError::InvalidAccountData);
+            msg!("Vault update state tracker data is empty");
+            return Err(VaultError::VaultAccountDataEmpty.into());
         }
         if expect_writable && !vault_update_delegation_ticket.is_writable {
-            msg!("Vault update delegations ticket is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            msg!("Vault update state tracker is not writable");
+            return Err(VaultError::VaultAccountNotWritable.into());
         }
         if vault_update_delegation_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Vault update delegations ticket discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+            msg!("Vault update state tracker discriminator is invalid");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
         }
         let expected_pubkey = Self::find_program_address(program_id, vault.key, ncn_epoch).0;
         if vault_update_delegation_ticket.key.ne(&expected_pubkey) {
-            msg!("Vault update delegations ticket is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            msg!("Vault update state tracker is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
         }
         Ok(())
     }
@@ -153,7 +191,8 @@ mod tests {
     use solana_program::pubkey::Pubkey;
 
     use crate::{
-        delegation_state::DelegationState, vault_update_state_tracker::VaultUpdateStateTracker,
+        delegation_snapshot::DelegationSnapshot, delegation_state::DelegationState,
+        vault_update_state_tracker::VaultUpdateStateTracker,
     };
 
     #[test]
@@ -165,7 +204,9 @@ mod tests {
             size_of::<PodU64>() + // additional_assets_need_unstaking
             size_of::<DelegationState>() + // delegation_state
             size_of::<u8>() + // withdrawal_allocation_method
-            263; // reserved
+            size_of::<DelegationSnapshot>() + // delegation_snapshot
+            size_of::<PodU64>() + // deferred_update_count
+            255; // reserved
         assert_eq!(vault_update_state_tracker_size, sum_of_fields);
     }
 
@@ -202,4 +243,23 @@ mod tests {
             Err(VaultError::VaultUpdateIncorrectIndex)
         );
     }
+
+    #[test]
+    fn test_check_cranked() {
+        let mut vault_update_state_tracker =
+            VaultUpdateStateTracker::new(Pubkey::new_unique(), 0, 0, 0);
+        assert_eq!(
+            vault_update_state_tracker.check_cranked(0),
+            Err(VaultError::VaultOperatorDelegationNotCranked)
+        );
+
+        vault_update_state_tracker
+            .check_and_update_index(0)
+            .unwrap();
+        vault_update_state_tracker.check_cranked(0).unwrap();
+        assert_eq!(
+            vault_update_state_tracker.check_cranked(1),
+            Err(VaultError::VaultOperatorDelegationNotCranked)
+        );
+    }
 }
@@ -1,36 +1,57 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::operator::Operator;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_restaking_core::{operator::Operator, operator_vault_ticket::OperatorVaultTicket};
 use jito_vault_core::{
     config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
 };
+use jito_vault_sdk::{
+    error::VaultError,
+    events::{DelegationChange, DelegationChangedEvent, VaultEvent},
+};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// Process the addition of a delegation: [`jito_vault_sdk::instruction::VaultInstruction::AddDelegation`]
 ///
 /// Specification:
 /// - Only the vault delegation admin shall be able to call this instruction.
+/// - The config and the vault must not be paused.
+/// - The vault must not be shut down.
 /// - The vault must be up-to-date before adding a delegation.
 /// - The amount delegated must be less than or equal to: the amount of tokens in the vault minus the amount of tokens
 ///   already delegated minus the amount of tokens reserved for VRTs.
 /// - The amount delegated to the operator must be accurately reported in the VaultOperatorDelegation account.
 /// - The vault's delegation state must be updated accordingly to ensure it's accurately tracking state across the entire operator delegation set.
 /// - The amount delegated must be greater than zero.
+/// - The stake on the operator delegation after adding the delegation must be at least the vault's
+///   minimum delegation amount.
+/// - The operator must not be frozen by an NCN.
+/// - Delegation to the operator must not be paused by the delegation admin.
+/// - The operator must not be banned by the operator admin.
+/// - The stake on the operator delegation after adding the delegation must not exceed the
+///   delegation's maximum delegation amount, if one is set.
+/// - If the operator requires approval of incoming delegations, the stake on the operator
+///   delegation after adding the delegation must not exceed the amount the operator approved on
+///   its OperatorVaultTicket for the vault, which shall be passed after the other accounts.
+/// - A [`jito_vault_sdk::events::VaultEvent::DelegationChanged`] event shall be emitted.
 pub fn process_add_delegation(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     amount: u64,
 ) -> ProgramResult {
+    if accounts.len() < 5 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, optional_accounts) = accounts.split_at(5);
     let [config, vault_info, operator, vault_operator_delegation, vault_delegation_admin] =
-        accounts
+        required_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let clock = Clock::get()?;
+    let clock = get_clock()?;
 
     Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
@@ -39,6 +60,8 @@ pub fn process_add_delegation(
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
     Operator::load(&config.restaking_program, operator, false)?;
+    let operator_data = operator.data.borrow();
+    let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
     VaultOperatorDelegation::load(
         program_id,
         vault_operator_delegation,
@@ -54,13 +77,99 @@ pub fn process_add_delegation(
     // The Vault delegation admin shall be the signer of the transaction
     vault.check_delegation_admin(vault_delegation_admin.key)?;
 
+    config.check_is_paused()?;
+    vault.check_is_paused()?;
+    vault.check_is_shut_down()?;
+
     // The Vault shall be up-to-date before adding delegation
-    vault.check_update_state_ok(clock.slot, config.epoch_length())?;
+    vault.check_update_state_ok(clock.slot, vault.epoch_length(config.epoch_length()))?;
+
+    // An NCN froze the operator pending an investigation of its keys
+    if operator_account.is_frozen(clock.slot) {
+        msg!("Operator is frozen, no new delegations are allowed");
+        return Err(VaultError::VaultOperatorFrozen.into());
+    }
+
+    vault_operator_delegation.check_delegation_paused()?;
+    vault_operator_delegation.check_not_banned()?;
 
     vault.delegate(amount)?;
     vault_operator_delegation
         .delegation_state
         .delegate(amount)?;
+    vault
+        .check_min_delegation_amount(vault_operator_delegation.delegation_state.staked_amount())?;
+    vault_operator_delegation.check_max_delegation_amount()?;
+
+    // The operator controls how much of the vault's assets it's liable for
+    if let Some(approved_delegation_amount) = load_approved_delegation_amount(
+        &config.restaking_program,
+        operator_account,
+        operator,
+        vault_info,
+        optional_accounts,
+    )? {
+        let total_security = vault_operator_delegation
+            .delegation_state
+            .total_security()?;
+        if total_security > approved_delegation_amount {
+            msg!(
+                "Delegation of {} exceeds the {} approved by the operator",
+                total_security,
+                approved_delegation_amount
+            );
+            return Err(VaultError::VaultDelegationNotApproved.into());
+        }
+    }
+
+    VaultEvent::DelegationChanged(DelegationChangedEvent {
+        vault: *vault_info.key,
+        operator: *operator.key,
+        change: DelegationChange::Delegate,
+        amount,
+        staked_amount: vault_operator_delegation.delegation_state.staked_amount(),
+    })
+    .emit();
 
     Ok(())
 }
+
+/// Finds the amount the operator approved the vault to delegate to it, if the operator requires
+/// approval of incoming delegations.
+///
+/// The operator's [`OperatorVaultTicket`] is looked up by its address among `optional_accounts`
+/// and is required once the operator requires approval.
+pub fn load_approved_delegation_amount(
+    restaking_program: &Pubkey,
+    operator_account: &Operator,
+    operator: &AccountInfo,
+    vault_info: &AccountInfo,
+    optional_accounts: &[AccountInfo],
+) -> Result<Option<u64>, ProgramError> {
+    if !operator_account.is_delegation_approval_required() {
+        return Ok(None);
+    }
+
+    let operator_vault_ticket_pubkey =
+        OperatorVaultTicket::find_program_address(restaking_program, operator.key, vault_info.key)
+            .0;
+    let Some(operator_vault_ticket) = optional_accounts
+        .iter()
+        .find(|account| account.key.eq(&operator_vault_ticket_pubkey))
+    else {
+        msg!("Operator requires approval of delegations, the operator vault ticket is required");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    OperatorVaultTicket::load(
+        restaking_program,
+        operator_vault_ticket,
+        operator,
+        vault_info,
+        false,
+    )?;
+    let operator_vault_ticket_data = operator_vault_ticket.data.borrow();
+    let operator_vault_ticket =
+        OperatorVaultTicket::try_from_slice_unchecked(&operator_vault_ticket_data)?;
+    Ok(Some(operator_vault_ticket.approved_delegation_amount()))
+}
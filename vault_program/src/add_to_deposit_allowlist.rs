@@ -0,0 +1,84 @@
+use std::mem::size_of;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+    sysvar::get_rent,
+};
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_deposit_allowlist_entry::VaultDepositAllowlistEntry,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the add to deposit allowlist instruction: [`crate::VaultInstruction::AddToDepositAllowlist`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The VaultDepositAllowlistEntry shall be created at the canonical PDA for the vault and depositor
+pub fn process_add_to_deposit_allowlist(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, depositor, vault_deposit_allowlist_entry, vault_admin, payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    load_system_account(vault_deposit_allowlist_entry, true)?;
+    load_signer(vault_admin, false)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    vault.check_admin(vault_admin.key)?;
+
+    // The VaultDepositAllowlistEntry shall be at the canonical PDA
+    let (
+        vault_deposit_allowlist_entry_pubkey,
+        vault_deposit_allowlist_entry_bump,
+        mut vault_deposit_allowlist_entry_seeds,
+    ) = VaultDepositAllowlistEntry::find_program_address(program_id, vault_info.key, depositor.key);
+    vault_deposit_allowlist_entry_seeds.push(vec![vault_deposit_allowlist_entry_bump]);
+    if vault_deposit_allowlist_entry_pubkey.ne(vault_deposit_allowlist_entry.key) {
+        msg!("Vault deposit allowlist entry is not at the correct PDA");
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
+    }
+
+    msg!(
+        "Initializing VaultDepositAllowlistEntry at address {}",
+        vault_deposit_allowlist_entry.key
+    );
+    create_account(
+        payer,
+        vault_deposit_allowlist_entry,
+        system_program,
+        program_id,
+        &get_rent()?,
+        8_u64
+            .checked_add(size_of::<VaultDepositAllowlistEntry>() as u64)
+            .unwrap(),
+        &vault_deposit_allowlist_entry_seeds,
+    )?;
+    let mut vault_deposit_allowlist_entry_data =
+        vault_deposit_allowlist_entry.try_borrow_mut_data()?;
+    vault_deposit_allowlist_entry_data[0] = VaultDepositAllowlistEntry::DISCRIMINATOR;
+    let vault_deposit_allowlist_entry = VaultDepositAllowlistEntry::try_from_slice_unchecked_mut(
+        &mut vault_deposit_allowlist_entry_data,
+    )?;
+    *vault_deposit_allowlist_entry = VaultDepositAllowlistEntry::new(
+        *vault_info.key,
+        *depositor.key,
+        vault_deposit_allowlist_entry_bump,
+    );
+
+    Ok(())
+}
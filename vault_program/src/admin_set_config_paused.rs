@@ -0,0 +1,34 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::config::Config;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the admin set config paused instruction: [`crate::VaultInstruction::AdminSetConfigPaused`]
+///
+/// Specification:
+/// - The admin must be the config admin and sign the transaction
+/// - While the config is paused, every vault shall reject the instructions it rejects while the
+///   vault itself is paused, regardless of the vault's own pause flag
+pub fn process_admin_set_config_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    is_paused: bool,
+) -> ProgramResult {
+    let [config, config_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, true)?;
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    load_signer(config_admin, false)?;
+
+    config.check_admin(config_admin.key)?;
+    config.set_is_paused(is_paused);
+    msg!("Config paused set to {}", is_paused);
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, sysvar::get_rent, token::invoke_token};
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::instruction::transfer;
+
+/// Processes the admin set crank reward instruction: [`crate::VaultInstruction::AdminSetCrankReward`]
+///
+/// Specification:
+/// - The admin must be the config admin and sign the transaction
+/// - The crank reward in basis points shall not exceed [`Config::MAX_CRANK_REWARD_BPS`]
+/// - Setting both rewards to zero stops paying crankers
+pub fn process_admin_set_crank_reward(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    crank_reward_lamports: u64,
+    crank_reward_bps: u16,
+) -> ProgramResult {
+    let [config, config_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, true)?;
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    load_signer(config_admin, false)?;
+
+    config.check_admin(config_admin.key)?;
+    config.set_crank_reward(crank_reward_lamports, crank_reward_bps)?;
+
+    msg!(
+        "Crank reward set to {} lamports and {} bps",
+        crank_reward_lamports,
+        crank_reward_bps
+    );
+
+    Ok(())
+}
+
+/// Pays the config's crank reward to `cranker` for advancing the vault's epoch state.
+///
+/// The lamport reward comes out of the vault's lamports above rent exemption, which anyone can
+/// top up, and shrinks to what's left once they run out. The token reward is transferred from the
+/// vault token account to the cranker's associated token account for the supported mint, which
+/// are looked up in `accounts` along with the token program.
+///
+/// The vault data must not be borrowed by the caller.
+pub fn pay_crank_reward<'info>(
+    program_id: &Pubkey,
+    config: &Config,
+    vault_info: &AccountInfo<'info>,
+    cranker: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+) -> ProgramResult {
+    if !config.has_crank_reward() {
+        return Ok(());
+    }
+
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    let token_reward = vault.calculate_crank_reward(config.crank_reward_bps())?;
+    vault.decrement_tokens_deposited(token_reward)?;
+    let supported_mint = vault.supported_mint;
+    let (_, vault_bump, mut vault_seeds) = Vault::find_program_address(program_id, &vault.base);
+    vault_seeds.push(vec![vault_bump]);
+    let seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
+    drop(vault_data);
+
+    if token_reward > 0 {
+        let vault_token_account_pubkey =
+            get_associated_token_address(vault_info.key, &supported_mint);
+        let cranker_token_account_pubkey =
+            get_associated_token_address(cranker.key, &supported_mint);
+        let find = |pubkey: &Pubkey| accounts.iter().find(|account| account.key.eq(pubkey));
+        let (Some(vault_token_account), Some(cranker_token_account), Some(_)) = (
+            find(&vault_token_account_pubkey),
+            find(&cranker_token_account_pubkey),
+            find(&spl_token::id()),
+        ) else {
+            msg!("Config has a crank reward in basis points, the vault token account, cranker token account and token program are required");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        invoke_token(
+            &transfer(
+                &spl_token::id(),
+                vault_token_account.key,
+                cranker_token_account.key,
+                vault_info.key,
+                &[],
+                token_reward,
+            )?,
+            &[
+                vault_token_account.clone(),
+                cranker_token_account.clone(),
+                vault_info.clone(),
+            ],
+            &[&seed_slices],
+        )?;
+    }
+
+    // The lamports move after the token transfer so the CPI sees the vault's balance unchanged
+    let rent_exempt_lamports = get_rent()?.minimum_balance(vault_info.data_len());
+    let lamport_reward = config
+        .crank_reward_lamports()
+        .min(vault_info.lamports().saturating_sub(rent_exempt_lamports));
+    if lamport_reward > 0 {
+        **vault_info.lamports.borrow_mut() = vault_info
+            .lamports()
+            .checked_sub(lamport_reward)
+            .ok_or(VaultError::VaultUnderflow)?;
+        **cranker.lamports.borrow_mut() = cranker
+            .lamports()
+            .checked_add(lamport_reward)
+            .ok_or(VaultError::VaultOverflow)?;
+    }
+
+    msg!(
+        "Paid crank reward of {} lamports and {} tokens to {}",
+        lamport_reward,
+        token_reward,
+        cranker.key
+    );
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::config::Config;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the admin set delegation cooldown instruction: [`crate::VaultInstruction::AdminSetDelegationCooldown`]
+///
+/// Specification:
+/// - The admin must be the config admin and sign the transaction
+/// - The delegation cooldown shall be at least one epoch and at most [`Config::MAX_DELEGATION_COOLDOWN_EPOCHS`],
+///   the number of per-epoch cohorts a delegation tracks its stake cooling down in. Longer cooldowns
+///   are rejected rather than shortened.
+/// - The new cooldown applies to any stake still cooling down the next time its delegation is updated
+pub fn process_admin_set_delegation_cooldown(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    delegation_cooldown_epochs: u64,
+) -> ProgramResult {
+    let [config, config_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, true)?;
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    load_signer(config_admin, false)?;
+
+    config.check_admin(config_admin.key)?;
+    config.set_delegation_cooldown_epochs(delegation_cooldown_epochs)?;
+
+    msg!(
+        "Delegation cooldown set to {} epochs",
+        delegation_cooldown_epochs
+    );
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::config::Config;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the admin set protocol fee instruction: [`crate::VaultInstruction::AdminSetProtocolFee`]
+///
+/// Specification:
+/// - The admin must be the config admin and sign the transaction
+/// - The protocol fee shall not exceed 100% of the vault fees
+/// - The protocol fee wallet shall be set to the new protocol fee wallet
+pub fn process_admin_set_protocol_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    protocol_fee_bps: u16,
+) -> ProgramResult {
+    let [config, config_admin, new_protocol_fee_wallet] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, true)?;
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    load_signer(config_admin, false)?;
+
+    config.check_admin(config_admin.key)?;
+    config.set_protocol_fee_bps(protocol_fee_bps)?;
+    config.protocol_fee_wallet = *new_protocol_fee_wallet.key;
+
+    msg!(
+        "Protocol fee set to {} bps, protocol fee wallet set to {:?}",
+        protocol_fee_bps,
+        new_protocol_fee_wallet.key
+    );
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::config::Config;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the admin set slash veto instruction: [`crate::VaultInstruction::AdminSetSlashVeto`]
+///
+/// Specification:
+/// - The admin must be the config admin and sign the transaction
+/// - The slash veto window shall not exceed [`Config::MAX_SLASH_VETO_WINDOW_EPOCHS`] epochs
+/// - The slash veto admin shall be set to the new slash veto admin
+/// - A zero veto window lets slashers slash immediately, otherwise slashes must be proposed first
+pub fn process_admin_set_slash_veto(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slash_veto_window_slots: u64,
+) -> ProgramResult {
+    let [config, config_admin, new_slash_veto_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, true)?;
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    load_signer(config_admin, false)?;
+
+    config.check_admin(config_admin.key)?;
+    config.set_slash_veto_window_slots(slash_veto_window_slots)?;
+    config.slash_veto_admin = *new_slash_veto_admin.key;
+
+    msg!(
+        "Slash veto window set to {} slots, slash veto admin set to {:?}",
+        slash_veto_window_slots,
+        new_slash_veto_admin.key
+    );
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::config::Config;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the admin set vault epoch length bounds instruction: [`crate::VaultInstruction::AdminSetVaultEpochLengthBounds`]
+///
+/// Specification:
+/// - The admin must be the config admin and sign the transaction
+/// - The minimum shall be non-zero and at most the maximum, or both shall be zero to stop vaults
+///   from overriding the epoch length
+/// - The bounds apply when a vault sets its epoch length, overrides already set are kept
+pub fn process_admin_set_vault_epoch_length_bounds(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    min_vault_epoch_length: u64,
+    max_vault_epoch_length: u64,
+) -> ProgramResult {
+    let [config, config_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, true)?;
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    load_signer(config_admin, false)?;
+
+    config.check_admin(config_admin.key)?;
+    config.set_vault_epoch_length_bounds(min_vault_epoch_length, max_vault_epoch_length)?;
+
+    msg!(
+        "Vault epoch length bounds set to {} to {} slots",
+        min_vault_epoch_length,
+        max_vault_epoch_length
+    );
+
+    Ok(())
+}
@@ -1,23 +1,31 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{
-    load_associated_token_account, load_signer, load_system_program, load_token_mint,
-    load_token_program,
+use jito_jsm_core::{
+    loader::{
+        load_associated_token_account, load_signer, load_system_program, load_token_mint,
+        load_token_program,
+    },
+    sysvar::get_clock,
+    token::invoke_token,
 };
 use jito_vault_core::{
     config::Config,
     vault::{BurnSummary, Vault},
+    vault_depositor::VaultDepositor,
+};
+use jito_vault_sdk::{
+    events::{VaultEvent, WithdrawEvent},
+    hook::VaultHookInstruction,
 };
 use solana_program::{
-    account_info::AccountInfo,
-    clock::Clock,
-    entrypoint::ProgramResult,
-    program::{invoke, invoke_signed},
-    program_error::ProgramError,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
-    sysvar::Sysvar,
 };
 use spl_token::instruction::{burn, transfer};
 
+use crate::{
+    initialize_vault_epoch_stats::record_vault_epoch_stats, set_hook_program::invoke_vault_hook,
+};
+
 /// Burns the specified amount of tokens from the staker's account and transfers the corresponding amount of VRT tokens to the vault's fee wallet.
 ///
 /// It's strongly encouraged to call [`jito_vault_sdk::instruction::VaultInstruction::UpdateVaultBalance`] before burning to ensure the vault's token balance
@@ -25,18 +33,30 @@ use spl_token::instruction::{burn, transfer};
 ///
 /// Specification:
 /// - The VRT mint must be correct
+/// - The config and the vault must not be paused
 /// - The vault must be up-to-date
+/// - The vault token account must be open and hold at least the tokens deposited
 /// - If the vault mint burn authority is set, it must match be present and be a signer
 /// - The amount to burn must be greater than zero
 /// - The amount to burn must be less than the VRT supply
 /// - The vault fee wallet must get the fee amount
+/// - While a slash is pending against a vault with slash dispute haircuts, the amount out shall be
+///   taken at the exchange rate net of the pending slashes
 /// - The transaction shall fail if the amount out is less than the minimum amount out
 /// - The transaction shall fail if the vault does not have enough unstaked assets to transfer to the staker
+/// - The transaction shall fail if the vault has a withdrawal delay, withdrawals must be enqueued
+/// - If the vault has flash deposit protection, the staker's VaultDepositor must be passed in after
+///   the burn signer, and the transaction shall fail if the staker deposited in the current slot
+/// - If the vault has epoch stats enabled, the VaultEpochStats must be passed in after the burn
+///   signer and shall record the withdrawal and fee
 /// - The VRT supply shall be updated correctly to match the VRT token mint supply
 /// - The tokens deposited shall be updated to match the tokens in the account
 /// - The fee amount shall be transferred to the vault fee wallet
 /// - The VRT tokens shall be burned from the staker's account
 /// - The assets shall be transferred from the vault to the staker's account
+/// - A [`jito_vault_sdk::events::VaultEvent::Withdraw`] event shall be emitted
+/// - If the vault has a hook program, it must be passed in after the burn signer and shall be
+///   invoked with [`VaultHookInstruction::AfterBurn`]
 pub fn process_burn(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -51,7 +71,60 @@ pub fn process_burn(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let clock = Clock::get()?;
+    load_signer(staker, false)?;
+    load_associated_token_account(staker_vrt_token_account, staker.key, vrt_mint.key)?;
+    load_system_program(system_program)?;
+
+    burn_vrt(
+        program_id,
+        config,
+        vault_info,
+        vault_token_account,
+        vrt_mint,
+        staker,
+        staker_token_account,
+        staker_vrt_token_account,
+        staker,
+        vault_fee_token_account,
+        token_program,
+        optional_accounts,
+        amount_in,
+        min_amount_out,
+        false,
+    )?;
+
+    Ok(())
+}
+
+/// Checks the burn against the vault, burns `amount_in` VRT less the fee from the staker's VRT
+/// token account, transfers the fee to the fee wallet and the pro-rata amount of the supported
+/// mint to the staker.
+///
+/// Shared by [`process_burn`], [`crate::burn_delegated::process_burn_delegated`] and
+/// [`crate::migrate_to_vault::process_migrate_to_vault`]. The VRT is moved by `vrt_authority`, the
+/// staker or the delegate of the staker's VRT token account, who must already be loaded as a
+/// signer. The withdrawal fee is skipped when `waive_withdrawal_fee` is set.
+///
+/// Returns the amount of the supported mint transferred to the staker.
+#[allow(clippy::too_many_arguments)]
+pub fn burn_vrt<'info>(
+    program_id: &Pubkey,
+    config: &AccountInfo<'info>,
+    vault_info: &AccountInfo<'info>,
+    vault_token_account: &AccountInfo<'info>,
+    vrt_mint: &AccountInfo<'info>,
+    staker: &AccountInfo<'info>,
+    staker_token_account: &AccountInfo<'info>,
+    staker_vrt_token_account: &AccountInfo<'info>,
+    vrt_authority: &AccountInfo<'info>,
+    vault_fee_token_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    optional_accounts: &[AccountInfo<'info>],
+    amount_in: u64,
+    min_amount_out: u64,
+    waive_withdrawal_fee: bool,
+) -> Result<u64, ProgramError> {
+    let clock = get_clock()?;
 
     Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
@@ -59,66 +132,104 @@ pub fn process_burn(
     Vault::load(program_id, vault_info, true)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    load_associated_token_account(vault_token_account, vault_info.key, &vault.supported_mint)?;
+    let vault_token_balance = vault.load_token_account(vault_info.key, vault_token_account)?;
+    vault.check_token_account_balance(vault_token_balance)?;
     load_token_mint(vrt_mint)?;
-    load_signer(staker, false)?;
     load_associated_token_account(staker_token_account, staker.key, &vault.supported_mint)?;
-    load_associated_token_account(staker_vrt_token_account, staker.key, &vault.vrt_mint)?;
     load_associated_token_account(vault_fee_token_account, &vault.fee_wallet, &vault.vrt_mint)?;
     load_token_program(token_program)?;
-    load_system_program(system_program)?;
 
     // The vault VRT mint shall be correct
     // The vault shall not need an update
     vault.check_vrt_mint(vrt_mint.key)?;
-    vault.check_update_state_ok(clock.slot, config.epoch_length())?;
+    config.check_is_paused()?;
+    vault.check_is_paused()?;
+    vault.check_update_state_ok(clock.slot, vault.epoch_length(config.epoch_length()))?;
     vault.check_mint_burn_admin(optional_accounts.first())?;
+    vault.check_no_withdrawal_delay()?;
+
+    if vault.flash_deposit_protection() {
+        let vault_depositor_pubkey =
+            VaultDepositor::find_program_address(program_id, vault_info.key, staker.key).0;
+        let Some(vault_depositor) = optional_accounts
+            .iter()
+            .find(|account| account.key.eq(&vault_depositor_pubkey))
+        else {
+            msg!("Vault has flash deposit protection, the vault depositor account is required");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // A staker who never deposited has no VaultDepositor to check
+        if !vault_depositor.data_is_empty() {
+            VaultDepositor::load(program_id, vault_depositor, vault_info, staker, false)?;
+            let vault_depositor_data = vault_depositor.data.borrow();
+            let vault_depositor = VaultDepositor::try_from_slice_unchecked(&vault_depositor_data)?;
+            vault_depositor.check_not_deposited_in_slot(clock.slot)?;
+        }
+    }
 
     let BurnSummary {
         fee_amount,
         burn_amount,
         out_amount,
-    } = vault.burn_with_fee(amount_in, min_amount_out)?;
+    } = if waive_withdrawal_fee {
+        vault.burn_without_fee(amount_in, min_amount_out)?
+    } else {
+        vault.burn_with_fee(amount_in, min_amount_out)?
+    };
 
-    // Burn the VRT tokens from the staker's account
-    invoke(
-        &burn(
+    let epoch = clock
+        .slot
+        .checked_div(vault.epoch_length(config.epoch_length()))
+        .unwrap();
+    record_vault_epoch_stats(program_id, vault_info, vault, optional_accounts, |stats| {
+        stats.record_withdrawal(epoch, out_amount, fee_amount)
+    })?;
+
+    let (_, vault_bump, mut vault_seeds) = Vault::find_program_address(program_id, &vault.base);
+    vault_seeds.push(vec![vault_bump]);
+    let seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
+    let hook_program = vault.hook_program;
+    drop(vault_data);
+
+    // Transfer the fee from the staker to the vault fee account before burning, since a burn that
+    // uses up the staker's approval clears the delegate
+    invoke_token(
+        &transfer(
             &spl_token::id(),
             staker_vrt_token_account.key,
-            vrt_mint.key,
-            staker.key,
+            vault_fee_token_account.key,
+            vrt_authority.key,
             &[],
-            burn_amount,
+            fee_amount,
         )?,
         &[
             staker_vrt_token_account.clone(),
-            vrt_mint.clone(),
-            staker.clone(),
+            vault_fee_token_account.clone(),
+            vrt_authority.clone(),
         ],
+        &[],
     )?;
-    // Transfer the assets from the staker to the vault fee account
-    invoke(
-        &transfer(
+    // Burn the VRT tokens from the staker's account
+    invoke_token(
+        &burn(
             &spl_token::id(),
             staker_vrt_token_account.key,
-            vault_fee_token_account.key,
-            staker.key,
+            vrt_mint.key,
+            vrt_authority.key,
             &[],
-            fee_amount,
+            burn_amount,
         )?,
         &[
             staker_vrt_token_account.clone(),
-            vault_fee_token_account.clone(),
-            staker.clone(),
+            vrt_mint.clone(),
+            vrt_authority.clone(),
         ],
+        &[],
     )?;
 
     // Transfer the assets from the vault to the staker's account
-    let (_, vault_bump, mut vault_seeds) = Vault::find_program_address(program_id, &vault.base);
-    vault_seeds.push(vec![vault_bump]);
-    let seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
-    drop(vault_data);
-    invoke_signed(
+    invoke_token(
         &transfer(
             &spl_token::id(),
             vault_token_account.key,
@@ -135,5 +246,25 @@ pub fn process_burn(
         &[seed_slices.as_slice()],
     )?;
 
-    Ok(())
+    VaultEvent::Withdraw(WithdrawEvent {
+        vault: *vault_info.key,
+        staker: *staker.key,
+        vrt_burned: amount_in,
+        vrt_fee: fee_amount,
+        amount_out: out_amount,
+    })
+    .emit();
+
+    invoke_vault_hook(
+        &hook_program,
+        vault_info,
+        staker,
+        optional_accounts,
+        VaultHookInstruction::AfterBurn {
+            vrt_burned: amount_in,
+            amount_out: out_amount,
+        },
+    )?;
+
+    Ok(out_amount)
 }
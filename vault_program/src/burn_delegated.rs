@@ -0,0 +1,78 @@
+use jito_jsm_core::loader::load_signer;
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    program_option::COption, program_pack::Pack, pubkey::Pubkey,
+};
+use spl_token::state::Account;
+
+use crate::burn::burn_vrt;
+
+/// Processes the burn delegated instruction: [`crate::VaultInstruction::BurnDelegated`]
+///
+/// Lets the delegate the owner approved on their VRT token account burn that VRT without the
+/// owner's signature, so integrators like lending protocols can liquidate VRT positions. The
+/// supported tokens are always sent to the owner.
+///
+/// Specification:
+/// - The burner must sign the transaction
+/// - The VRT token account shall be owned by the owner, hold the vault's VRT and have the burner
+///   as its delegate for at least the amount to burn. It doesn't need to be an associated token
+///   account
+/// - The supported tokens shall be transferred to the owner's associated token account
+/// - The same checks and optional accounts as [`crate::VaultInstruction::Burn`] apply, with the
+///   owner in place of the staker
+pub fn process_burn_delegated(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount_in: u64,
+    min_amount_out: u64,
+) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(10);
+
+    let [config, vault_info, vault_token_account, vrt_mint, owner, burner, owner_token_account, owner_vrt_token_account, vault_fee_token_account, token_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(burner, false)?;
+    if owner_vrt_token_account.owner.ne(&spl_token::id()) {
+        msg!("VRT token account is not owned by the token program");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let vrt_token_account = Account::unpack(&owner_vrt_token_account.data.borrow())?;
+    if vrt_token_account.owner.ne(owner.key) || vrt_token_account.mint.ne(vrt_mint.key) {
+        msg!("VRT token account does not belong to the owner");
+        return Err(VaultError::VaultBurnDelegateInvalid.into());
+    }
+    if vrt_token_account.delegate != COption::Some(*burner.key)
+        || vrt_token_account.delegated_amount < amount_in
+    {
+        msg!(
+            "Burner is not approved to burn {} VRT from the VRT token account",
+            amount_in
+        );
+        return Err(VaultError::VaultBurnDelegateInvalid.into());
+    }
+
+    burn_vrt(
+        program_id,
+        config,
+        vault_info,
+        vault_token_account,
+        vrt_mint,
+        owner,
+        owner_token_account,
+        owner_vrt_token_account,
+        burner,
+        vault_fee_token_account,
+        token_program,
+        optional_accounts,
+        amount_in,
+        min_amount_out,
+        false,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_vault_core::vault::Vault;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{burn::process_burn, enqueue_withdrawal::process_enqueue_withdrawal};
+
+/// Processes the burn or enqueue withdrawal instruction: [`crate::VaultInstruction::BurnOrEnqueueWithdrawal`]
+///
+/// Lets stakers exit without checking ahead of time whether the vault's idle assets cover the
+/// withdrawal.
+///
+/// Specification:
+/// - If the vault's idle assets cover the amount out of burning `amount_in` VRT, the instruction
+///   shall behave exactly like [`crate::VaultInstruction::Burn`], including the slippage check
+/// - Otherwise the instruction shall behave exactly like [`crate::VaultInstruction::EnqueueWithdrawal`]
+///   for the full `amount_in`, and the VaultStakerWithdrawalTicket address shall be logged.
+///   `min_amount_out` is not checked since the amount out is only known once the ticket is burned.
+pub fn process_burn_or_enqueue_withdrawal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount_in: u64,
+    min_amount_out: u64,
+) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(13);
+
+    let [config, vault_info, vault_token_account, vrt_mint, staker, staker_token_account, staker_vrt_token_account, vault_fee_token_account, vault_staker_withdrawal_ticket, vault_staker_withdrawal_ticket_token_account, base, token_program, system_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Vault::load(program_id, vault_info, true)?;
+    let can_burn = {
+        let vault_data = vault_info.data.borrow();
+        let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+        vault.can_burn_from_idle_assets(amount_in)?
+    };
+
+    if can_burn {
+        let mut burn_accounts = vec![
+            config.clone(),
+            vault_info.clone(),
+            vault_token_account.clone(),
+            vrt_mint.clone(),
+            staker.clone(),
+            staker_token_account.clone(),
+            staker_vrt_token_account.clone(),
+            vault_fee_token_account.clone(),
+            token_program.clone(),
+            system_program.clone(),
+        ];
+        burn_accounts.extend_from_slice(optional_accounts);
+        return process_burn(program_id, &burn_accounts, amount_in, min_amount_out);
+    }
+
+    msg!(
+        "Vault idle assets can't cover burning {} VRT, enqueuing a withdrawal at {}",
+        amount_in,
+        vault_staker_withdrawal_ticket.key
+    );
+    let mut enqueue_accounts = vec![
+        config.clone(),
+        vault_info.clone(),
+        vault_staker_withdrawal_ticket.clone(),
+        vault_staker_withdrawal_ticket_token_account.clone(),
+        staker.clone(),
+        staker_vrt_token_account.clone(),
+        base.clone(),
+        token_program.clone(),
+        system_program.clone(),
+    ];
+    enqueue_accounts.extend_from_slice(optional_accounts);
+    process_enqueue_withdrawal(program_id, &enqueue_accounts, amount_in)
+}
@@ -4,23 +4,50 @@ use jito_jsm_core::{
     loader::{
         load_associated_token_account, load_system_program, load_token_mint, load_token_program,
     },
+    sysvar::get_clock,
+    token::invoke_token,
 };
 use jito_vault_core::{
     config::Config,
     vault::{BurnSummary, Vault},
+    vault_epoch_stats::VaultEpochStats,
     vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
 };
-use jito_vault_sdk::error::VaultError;
+use jito_vault_sdk::{
+    error::VaultError,
+    events::{VaultEvent, WithdrawEvent},
+};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 use spl_token::instruction::{burn, close_account, transfer};
 
+use crate::initialize_vault_epoch_stats::record_vault_epoch_stats;
+
 /// Burns the withdrawal ticket, transferring the assets to the staker and closing the withdrawal ticket.
 ///
 /// One should call the [`crate::VaultInstruction::CrankVaultUpdateStateTracker`] instruction before running this instruction
 /// to ensure that any rewards that were accrued are accounted for.
+///
+/// The config and the vault shall not be paused, and the vault token account shall be open and
+/// hold at least the tokens deposited.
+///
+/// Anyone can burn a claimable ticket on the staker's behalf. If the staker opted into
+/// auto-claiming with [`crate::VaultInstruction::SetWithdrawalTicketAutoClaimFee`] and a claimer
+/// token account is passed after the optional burn signer, the auto-claim fee is taken out of the
+/// staker's assets and transferred to the claimer.
+///
+/// If the vault has epoch stats enabled, the VaultEpochStats must be passed in after the other
+/// optional accounts and shall record the withdrawal and fee.
+///
+/// The ticket can't be burned while an admin burn is pending on it.
+///
+/// While a slash is pending against a vault with slash dispute haircuts, the assets are paid out at
+/// the exchange rate net of the pending slashes. The haircut isn't returned if the slash is vetoed,
+/// so only the staker can burn the ticket until the slash is resolved and must sign.
+///
+/// A [`jito_vault_sdk::events::VaultEvent::Withdraw`] event is emitted for the staker.
 pub fn process_burn_withdrawal_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -39,7 +66,8 @@ pub fn process_burn_withdrawal_ticket(
     Vault::load(program_id, vault_info, true)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    load_associated_token_account(vault_token_account, vault_info.key, &vault.supported_mint)?;
+    let vault_token_balance = vault.load_token_account(vault_info.key, vault_token_account)?;
+    vault.check_token_account_balance(vault_token_balance)?;
     load_token_mint(vrt_mint)?;
     // staker
     load_associated_token_account(staker_token_account, staker.key, &vault.supported_mint)?;
@@ -63,21 +91,64 @@ pub fn process_burn_withdrawal_ticket(
     load_system_program(system_program)?;
 
     vault.check_mint_burn_admin(optional_accounts.first())?;
+    // the claimer token account follows the burn signer when the vault has a mint burn admin
+    let claimer_token_account = if vault.mint_burn_admin.ne(&Pubkey::default()) {
+        optional_accounts.get(1)
+    } else {
+        optional_accounts.first()
+    }
+    .filter(|account| {
+        !vault.epoch_stats_enabled()
+            || account
+                .key
+                .ne(&VaultEpochStats::find_program_address(program_id, vault_info.key).0)
+    });
     vault.check_vrt_mint(vrt_mint.key)?;
-    vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+    config.check_is_paused()?;
+    vault.check_is_paused()?;
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
     vault_staker_withdrawal_ticket.check_staker(staker.key)?;
+    vault_staker_withdrawal_ticket.check_no_admin_burn_pending()?;
+    if vault.slash_dispute_haircut() && vault.pending_slash_amount() > 0 && !staker.is_signer {
+        msg!(
+            "A slash is pending, only the staker can burn the ticket at the haircut exchange rate"
+        );
+        return Err(ProgramError::MissingRequiredSignature);
+    }
 
-    if !vault_staker_withdrawal_ticket.is_withdrawable(Clock::get()?.slot, config.epoch_length())? {
+    if !vault_staker_withdrawal_ticket.is_withdrawable(
+        get_clock()?.slot,
+        vault.epoch_length(config.epoch_length()),
+        config.delegation_cooldown_epochs(),
+    )? {
         msg!("Vault staker withdrawal ticket is not withdrawable");
         return Err(VaultError::VaultStakerWithdrawalTicketNotWithdrawable.into());
     }
 
+    let vrt_amount = vault_staker_withdrawal_ticket.vrt_amount();
     let BurnSummary {
         fee_amount,
         burn_amount,
         out_amount,
-    } = vault.burn_with_fee(vault_staker_withdrawal_ticket.vrt_amount(), min_amount_out)?;
-    vault.decrement_vrt_ready_to_claim_amount(vault_staker_withdrawal_ticket.vrt_amount())?;
+    } = vault.burn_with_fee(vrt_amount, min_amount_out)?;
+    vault.decrement_vrt_ready_to_claim_amount(vrt_amount)?;
+
+    let epoch = get_clock()?
+        .slot
+        .checked_div(vault.epoch_length(config.epoch_length()))
+        .unwrap();
+    record_vault_epoch_stats(program_id, vault_info, vault, optional_accounts, |stats| {
+        stats.record_withdrawal(epoch, out_amount, fee_amount)
+    })?;
+
+    // the mint of the claimer token account is checked by the token program on transfer
+    let auto_claim_fee_amount = match claimer_token_account {
+        Some(_) => vault_staker_withdrawal_ticket.calculate_auto_claim_fee(out_amount)?,
+        None => 0,
+    };
+    let staker_amount = out_amount
+        .checked_sub(auto_claim_fee_amount)
+        .ok_or(VaultError::VaultUnderflow)?;
 
     let (_, vault_staker_withdraw_bump, mut vault_staker_withdraw_seeds) =
         VaultStakerWithdrawalTicket::find_program_address(
@@ -93,7 +164,7 @@ pub fn process_burn_withdrawal_ticket(
     drop(vault_staker_withdrawal_ticket_data);
 
     // transfer fee to fee wallet
-    invoke_signed(
+    invoke_token(
         &transfer(
             &spl_token::id(),
             vault_staker_withdrawal_ticket_token_account.key,
@@ -110,7 +181,7 @@ pub fn process_burn_withdrawal_ticket(
         &[&seed_slices],
     )?;
     // burn the VRT tokens
-    invoke_signed(
+    invoke_token(
         &burn(
             &spl_token::id(),
             vault_staker_withdrawal_ticket_token_account.key,
@@ -128,7 +199,7 @@ pub fn process_burn_withdrawal_ticket(
     )?;
 
     // close token account
-    invoke_signed(
+    invoke_token(
         &close_account(
             &spl_token::id(),
             vault_staker_withdrawal_ticket_token_account.key,
@@ -150,14 +221,14 @@ pub fn process_burn_withdrawal_ticket(
     vault_seeds.push(vec![vault_bump]);
     let seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
     drop(vault_data); // avoid double borrow
-    invoke_signed(
+    invoke_token(
         &transfer(
             &spl_token::id(),
             vault_token_account.key,
             staker_token_account.key,
             vault_info.key,
             &[],
-            out_amount,
+            staker_amount,
         )?,
         &[
             vault_token_account.clone(),
@@ -167,5 +238,36 @@ pub fn process_burn_withdrawal_ticket(
         &[&seed_slices],
     )?;
 
+    // pay the auto-claim fee to the claimer
+    if let Some(claimer_token_account) = claimer_token_account {
+        if auto_claim_fee_amount > 0 {
+            invoke_token(
+                &transfer(
+                    &spl_token::id(),
+                    vault_token_account.key,
+                    claimer_token_account.key,
+                    vault_info.key,
+                    &[],
+                    auto_claim_fee_amount,
+                )?,
+                &[
+                    vault_token_account.clone(),
+                    claimer_token_account.clone(),
+                    vault_info.clone(),
+                ],
+                &[&seed_slices],
+            )?;
+        }
+    }
+
+    VaultEvent::Withdraw(WithdrawEvent {
+        vault: *vault_info.key,
+        staker: *staker.key,
+        vrt_burned: vrt_amount,
+        vrt_fee: fee_amount,
+        amount_out: out_amount,
+    })
+    .emit();
+
     Ok(())
 }
@@ -0,0 +1,57 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the cancel admin burn instruction: [`crate::VaultInstruction::CancelAdminBurn`]
+///
+/// Specification:
+/// - The admin must be the vault admin or the vault burn admin and sign the transaction
+/// - An admin burn shall be pending on the withdrawal ticket
+/// - The staker can burn or transfer the ticket again afterwards
+pub fn process_cancel_admin_burn(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault_info, vault_staker_withdrawal_ticket_info, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    VaultStakerWithdrawalTicket::load(
+        program_id,
+        vault_staker_withdrawal_ticket_info,
+        vault_info,
+        true,
+    )?;
+    let mut vault_staker_withdrawal_ticket_data =
+        vault_staker_withdrawal_ticket_info.data.borrow_mut();
+    let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked_mut(
+        &mut vault_staker_withdrawal_ticket_data,
+    )?;
+    load_signer(admin, false)?;
+
+    if vault.admin.ne(admin.key) && vault.burn_admin.ne(admin.key) {
+        msg!("Admin is neither the vault admin nor the burn admin");
+        return Err(VaultError::VaultAdminInvalid.into());
+    }
+
+    let recovery = vault_staker_withdrawal_ticket.admin_burn_recovery;
+    vault_staker_withdrawal_ticket.cancel_admin_burn()?;
+
+    msg!(
+        "AdminBurnCancelled: vault {} ticket {} recovery {} cancelled by {}",
+        vault_info.key,
+        vault_staker_withdrawal_ticket_info.key,
+        recovery,
+        admin.key
+    );
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the cancel mint authority handoff instruction: [`crate::VaultInstruction::CancelMintAuthorityHandoff`]
+///
+/// Specification:
+/// - The admin must be the vault admin or the config admin and sign the transaction
+/// - A mint authority handoff shall be pending
+pub fn process_cancel_mint_authority_handoff(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(admin, false)?;
+
+    if vault.admin.ne(admin.key) && config.admin.ne(admin.key) {
+        msg!("Admin is neither the vault admin nor the config admin");
+        return Err(VaultError::VaultAdminInvalid.into());
+    }
+
+    let successor = vault.mint_authority_successor;
+    vault.cancel_mint_authority_handoff()?;
+
+    msg!(
+        "MintAuthorityHandoffCancelled: vault {} successor {} cancelled by {}",
+        vault_info.key,
+        successor,
+        admin.key
+    );
+
+    Ok(())
+}
@@ -16,6 +16,8 @@ pub fn process_change_withdrawal_ticket_owner(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
     Vault::load(program_id, vault, false)?;
     VaultStakerWithdrawalTicket::load(program_id, vault_staker_withdrawal_ticket, vault, true)?;
     let mut vault_staker_withdrawal_ticket_data = vault_staker_withdrawal_ticket.data.borrow_mut();
@@ -24,7 +26,9 @@ pub fn process_change_withdrawal_ticket_owner(
     )?;
     load_signer(old_owner, false)?;
 
+    config.check_is_paused()?;
     vault_staker_withdrawal_ticket.check_staker(old_owner.key)?;
+    vault_staker_withdrawal_ticket.check_no_admin_burn_pending()?;
     vault_staker_withdrawal_ticket.staker = *new_owner.key;
 
     Ok(())
@@ -0,0 +1,132 @@
+use std::mem::size_of;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{
+        load_associated_token_account, load_signer, load_system_account, load_system_program,
+        load_token_program,
+    },
+    sysvar::{get_clock, get_rent},
+    token::invoke_token,
+};
+use jito_vault_core::{
+    config::Config, reward_claim::RewardClaim, reward_distribution::RewardDistribution,
+    vault::Vault,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token::instruction::transfer;
+
+/// Processes the claim rewards instruction: [`crate::VaultInstruction::ClaimRewards`]
+///
+/// Specification:
+/// - The config and the vault shall not be paused
+/// - The staker must sign the transaction and pays for the reward claim account
+/// - The proof shall show the staker is owed `amount` under the reward distribution's Merkle root
+/// - The reward claim shall be at the canonical PDA for the reward distribution and staker, so a
+///   staker can only claim once from each distribution
+/// - The claims shall not exceed the total rewards of the distribution
+/// - The amount shall be transferred from the vault rewards token account to the staker's token
+///   account
+pub fn process_claim_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> ProgramResult {
+    let [config, vault_info, reward_distribution, reward_claim, vault_rewards_token_account, staker_token_account, staker, token_program, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    RewardDistribution::load(program_id, reward_distribution, vault_info, true)?;
+    let mut reward_distribution_data = reward_distribution.data.borrow_mut();
+    let reward_distribution_account =
+        RewardDistribution::try_from_slice_unchecked_mut(&mut reward_distribution_data)?;
+    load_system_account(reward_claim, true)?;
+    load_associated_token_account(
+        vault_rewards_token_account,
+        vault_info.key,
+        &reward_distribution_account.mint,
+    )?;
+    load_associated_token_account(
+        staker_token_account,
+        staker.key,
+        &reward_distribution_account.mint,
+    )?;
+    load_signer(staker, true)?;
+    load_token_program(token_program)?;
+    load_system_program(system_program)?;
+
+    config.check_is_paused()?;
+    vault.check_is_paused()?;
+
+    reward_distribution_account.verify_claim(staker.key, amount, &proof)?;
+    reward_distribution_account.claim(amount)?;
+
+    // The RewardClaim shall be at the canonical PDA
+    let (reward_claim_pubkey, reward_claim_bump, mut reward_claim_seeds) =
+        RewardClaim::find_program_address(program_id, reward_distribution.key, staker.key);
+    reward_claim_seeds.push(vec![reward_claim_bump]);
+    if reward_claim_pubkey.ne(reward_claim.key) {
+        msg!("Reward claim is not at the correct PDA");
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
+    }
+
+    msg!("Initializing RewardClaim at address {}", reward_claim.key);
+    create_account(
+        staker,
+        reward_claim,
+        system_program,
+        program_id,
+        &get_rent()?,
+        8_u64.checked_add(size_of::<RewardClaim>() as u64).unwrap(),
+        &reward_claim_seeds,
+    )?;
+
+    let mut reward_claim_data = reward_claim.try_borrow_mut_data()?;
+    reward_claim_data[0] = RewardClaim::DISCRIMINATOR;
+    let reward_claim_account = RewardClaim::try_from_slice_unchecked_mut(&mut reward_claim_data)?;
+    *reward_claim_account = RewardClaim::new(
+        *reward_distribution.key,
+        *staker.key,
+        amount,
+        get_clock()?.slot,
+        reward_claim_bump,
+    );
+
+    // Transfer the rewards from the vault to the staker
+    let (_, vault_bump, mut vault_seeds) = Vault::find_program_address(program_id, &vault.base);
+    vault_seeds.push(vec![vault_bump]);
+    let seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
+    drop(vault_data);
+    invoke_token(
+        &transfer(
+            &spl_token::id(),
+            vault_rewards_token_account.key,
+            staker_token_account.key,
+            vault_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            vault_rewards_token_account.clone(),
+            staker_token_account.clone(),
+            vault_info.clone(),
+        ],
+        &[seed_slices.as_slice()],
+    )?;
+
+    Ok(())
+}
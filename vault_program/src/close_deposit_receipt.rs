@@ -0,0 +1,36 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{close_program_account, loader::load_signer};
+use jito_vault_core::{config::Config, deposit_receipt::DepositReceipt};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the close deposit receipt instruction: [`crate::VaultInstruction::CloseDepositReceipt`]
+///
+/// Specification:
+/// - The config must not be paused
+/// - The depositor must be the receipt's depositor and sign the transaction
+/// - The DepositReceipt shall be closed and its rent returned to the depositor
+/// - The receipt can be closed after its vault is closed
+pub fn process_close_deposit_receipt(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, deposit_receipt, depositor] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    DepositReceipt::load(program_id, deposit_receipt, depositor, true)?;
+    load_signer(depositor, true)?;
+
+    config.check_is_paused()?;
+
+    msg!("Closing deposit receipt {}", deposit_receipt.key);
+    close_program_account(program_id, deposit_receipt, depositor)?;
+
+    Ok(())
+}
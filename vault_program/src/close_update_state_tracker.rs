@@ -1,26 +1,32 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::{close_program_account, loader::load_signer};
+use jito_jsm_core::{close_program_account, loader::load_signer, sysvar::get_clock};
 use jito_vault_core::{
     config::Config, vault::Vault, vault_update_state_tracker::VaultUpdateStateTracker,
 };
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, hash::Hash, msg,
+    program_error::ProgramError, pubkey::Pubkey,
 };
 
+use crate::admin_set_crank_reward::pay_crank_reward;
+
 /// Close the VaultUpdateStateTracker
 /// Can close previous epochs to get rent back, but it shall not update the current epoch
+///
+/// The payer is paid the config's crank reward when closing the tracker completes the current
+/// epoch's update, unless a crank reward was already paid this epoch
 pub fn process_close_vault_update_state_tracker(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     ncn_epoch: u64,
 ) -> ProgramResult {
-    let [config, vault_info, vault_update_state_tracker_info, payer] = accounts else {
+    let (required_accounts, optional_accounts) = accounts.split_at(4);
+    let [config, vault_info, vault_update_state_tracker_info, payer] = required_accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let slot = Clock::get()?.slot;
+    let slot = get_clock()?.slot;
 
     Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
@@ -41,10 +47,14 @@ pub fn process_close_vault_update_state_tracker(
     )?;
     load_signer(payer, true)?;
 
-    let current_ncn_epoch = slot.checked_div(config.epoch_length()).unwrap();
+    let current_ncn_epoch = slot
+        .checked_div(vault.epoch_length(config.epoch_length()))
+        .unwrap();
 
     // The VaultUpdateStateTracker shall be up-to-date before closing
-    if ncn_epoch != current_ncn_epoch {
+    let completes_epoch = ncn_epoch == current_ncn_epoch;
+    let mut pays_crank_reward = false;
+    if !completes_epoch {
         msg!(
             "Warning: VaultUpdateStateTracker is from an old epoch ({}), current epoch is {}",
             ncn_epoch,
@@ -62,17 +72,50 @@ pub fn process_close_vault_update_state_tracker(
         msg!("Finished updating VaultUpdateStateTracker");
 
         vault.delegation_state = vault_update_state_tracker.delegation_state;
+        let delegation_snapshot_root = vault_update_state_tracker.delegation_snapshot.root();
+        msg!(
+            "Delegation snapshot root: {}",
+            Hash::new_from_array(delegation_snapshot_root)
+        );
+        vault.set_delegation_snapshot(ncn_epoch, delegation_snapshot_root);
+        let epochs_since_last_update = current_ncn_epoch
+            .checked_sub(
+                vault
+                    .last_full_state_update_slot()
+                    .checked_div(vault.epoch_length(config.epoch_length()))
+                    .unwrap(),
+            )
+            .ok_or(VaultError::VaultUnderflow)?;
         vault.set_last_full_state_update_slot(slot);
+        vault.activate_pending_fees(slot, vault.epoch_length(config.epoch_length()));
 
-        // shift the VRT amounts down by one, accumulating in vrt_ready_to_claim_amount
-        vault.increment_vrt_ready_to_claim_amount(vault.vrt_cooling_down_amount())?;
-        vault.set_vrt_cooling_down_amount(vault.vrt_enqueued_for_cooldown_amount());
-        vault.set_vrt_enqueued_for_cooldown_amount(0);
+        // move the VRT on the same cooldown schedule as the delegations, accumulating in
+        // vrt_ready_to_claim_amount. While any delegation's cooldown is deferred, the VRT is held
+        // too, so tickets can't claim assets the deferred stake hasn't released
+        if vault_update_state_tracker.has_deferred_updates() {
+            msg!(
+                "Holding VRT cooldown, {} operator updates were deferred",
+                vault_update_state_tracker.deferred_update_count()
+            );
+        } else {
+            vault.advance_vrt_cooldown(
+                epochs_since_last_update,
+                config.delegation_cooldown_epochs(),
+            )?;
+        }
+
+        pays_crank_reward = config.has_crank_reward()
+            && vault.take_crank_reward(slot, vault.epoch_length(config.epoch_length()));
     }
 
     msg!("Closing VaultUpdateStateTracker");
     drop(vault_update_state_tracker_data);
+    drop(vault_data);
     close_program_account(program_id, vault_update_state_tracker_info, payer)?;
 
+    if pays_crank_reward {
+        pay_crank_reward(program_id, config, vault_info, payer, optional_accounts)?;
+    }
+
     Ok(())
 }
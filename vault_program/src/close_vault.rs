@@ -0,0 +1,129 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{close_program_account, loader::load_signer};
+use jito_vault_core::{
+    config::Config, delegation_strategy::DelegationStrategy, vault::Vault,
+    vault_deposit_allowlist_entry::VaultDepositAllowlistEntry, vault_depositor::VaultDepositor,
+    vault_epoch_stats::VaultEpochStats, vault_insurance::VaultInsurance,
+    vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+    vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
+    vault_operator_delegation_snapshot::VaultOperatorDelegationSnapshot,
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the close vault instruction: [`crate::VaultInstruction::CloseVault`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The vault shall have no VRT supply, no deposited tokens, no delegated or cooling down stake
+///   and no pending withdrawals or fees
+/// - The vault's rent shall be returned to the admin
+/// - Any accounts passed in after the admin shall be the vault's tickets, operator delegations,
+///   update state trackers, snapshots, epoch stats, delegation strategy, allowlist entries or
+///   depositors, and shall be closed with their rent returned to the admin. Operator delegations
+///   shall have no stake left.
+pub fn process_close_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault_info, admin, companion_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    load_signer(admin, true)?;
+
+    {
+        let vault_data = vault_info.data.borrow();
+        let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+        vault.check_admin(admin.key)?;
+        vault.check_can_close()?;
+    }
+
+    for companion_account in companion_accounts {
+        check_companion_account(program_id, vault_info.key, companion_account)?;
+        msg!("Closing vault account {}", companion_account.key);
+        close_program_account(program_id, companion_account, admin)?;
+    }
+
+    msg!("Closing vault {}", vault_info.key);
+    close_program_account(program_id, vault_info, admin)?;
+
+    Ok(())
+}
+
+/// Checks `account` is a writable program account belonging to `vault` that can be closed with it
+fn check_companion_account(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    account: &AccountInfo,
+) -> Result<(), ProgramError> {
+    if account.owner.ne(program_id) {
+        msg!("Vault account {} has an invalid owner", account.key);
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !account.is_writable {
+        msg!("Vault account {} is not writable", account.key);
+        return Err(VaultError::VaultAccountNotWritable.into());
+    }
+
+    let data = account.data.borrow();
+    let account_vault = match data.first() {
+        Some(&VaultNcnTicket::DISCRIMINATOR) => {
+            VaultNcnTicket::try_from_slice_unchecked(&data)?.vault
+        }
+        Some(&VaultNcnSlasherTicket::DISCRIMINATOR) => {
+            VaultNcnSlasherTicket::try_from_slice_unchecked(&data)?.vault
+        }
+        Some(&VaultNcnSlasherOperatorTicket::DISCRIMINATOR) => {
+            VaultNcnSlasherOperatorTicket::try_from_slice_unchecked(&data)?.vault
+        }
+        Some(&VaultOperatorDelegation::DISCRIMINATOR) => {
+            let vault_operator_delegation =
+                VaultOperatorDelegation::try_from_slice_unchecked(&data)?;
+            if vault_operator_delegation
+                .delegation_state
+                .total_security()?
+                != 0
+            {
+                msg!("Vault operator delegation {} still has stake", account.key);
+                return Err(VaultError::VaultNotEmpty.into());
+            }
+            vault_operator_delegation.vault
+        }
+        Some(&VaultUpdateStateTracker::DISCRIMINATOR) => {
+            VaultUpdateStateTracker::try_from_slice_unchecked(&data)?.vault
+        }
+        Some(&VaultOperatorDelegationSnapshot::DISCRIMINATOR) => {
+            VaultOperatorDelegationSnapshot::try_from_slice_unchecked(&data)?.vault
+        }
+        Some(&VaultEpochStats::DISCRIMINATOR) => {
+            VaultEpochStats::try_from_slice_unchecked(&data)?.vault
+        }
+        Some(&VaultInsurance::DISCRIMINATOR) => {
+            VaultInsurance::try_from_slice_unchecked(&data)?.vault
+        }
+        Some(&DelegationStrategy::DISCRIMINATOR) => {
+            DelegationStrategy::try_from_slice_unchecked(&data)?.vault
+        }
+        Some(&VaultDepositAllowlistEntry::DISCRIMINATOR) => {
+            VaultDepositAllowlistEntry::try_from_slice_unchecked(&data)?.vault
+        }
+        Some(&VaultDepositor::DISCRIMINATOR) => {
+            VaultDepositor::try_from_slice_unchecked(&data)?.vault
+        }
+        _ => {
+            msg!("Account {} can't be closed with the vault", account.key);
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+    };
+    if account_vault.ne(vault) {
+        msg!("Account {} does not belong to the vault", account.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
@@ -1,23 +1,27 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
 use jito_restaking_core::operator::Operator;
 use jito_vault_core::{
     config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
 };
+use jito_vault_sdk::events::{DelegationChange, DelegationChangedEvent, VaultEvent};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// Cools down a delegation
 ///
 /// Specification:
 /// - The amount to cooldown shall be greater than zero
+/// - The config and the vault shall not be paused
 /// - The vault shall be up-to-date
 /// - The vault delegation admin shall be a signer on the transaction
 /// - The assets enqueued for cooldown shall be subtracted from the staked amount and added to the
 ///   enqueued for cooldown amount
+/// - The stake left on the operator delegation shall be zero or at least the vault's minimum delegation amount
 /// - The vault shall be updated to reflect the cooldown amount and the delegation state shall match the sum of all operator delegations
+/// - A [`jito_vault_sdk::events::VaultEvent::DelegationChanged`] event shall be emitted
 pub fn process_cooldown_delegation(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -49,12 +53,25 @@ pub fn process_cooldown_delegation(
     load_signer(vault_delegation_admin, false)?;
 
     vault.check_delegation_admin(vault_delegation_admin.key)?;
-    vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+    config.check_is_paused()?;
+    vault.check_is_paused()?;
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
 
     vault_operator_delegation
         .delegation_state
         .cooldown(amount)?;
+    vault
+        .check_min_delegation_amount(vault_operator_delegation.delegation_state.staked_amount())?;
     vault.delegation_state.cooldown(amount)?;
 
+    VaultEvent::DelegationChanged(DelegationChangedEvent {
+        vault: *vault_info.key,
+        operator: *operator.key,
+        change: DelegationChange::Cooldown,
+        amount,
+        staked_amount: vault_operator_delegation.delegation_state.staked_amount(),
+    })
+    .emit();
+
     Ok(())
 }
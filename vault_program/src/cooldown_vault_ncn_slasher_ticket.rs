@@ -1,13 +1,13 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
 use jito_restaking_core::ncn::Ncn;
 use jito_vault_core::{
     config::Config, vault::Vault, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
 };
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// Instruction: [`crate::VaultInstruction::CooldownVaultNcnSlasherTicket`]
@@ -42,14 +42,14 @@ pub fn process_cooldown_vault_ncn_slasher_ticket(
     load_signer(vault_slasher_admin, false)?;
 
     vault.check_slasher_admin(vault_slasher_admin.key)?;
-    vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
 
     // The vault slasher ticket must be active in order to cooldown the slasher
-    if !vault_ncn_slasher_ticket
+    if let Err(e) = vault_ncn_slasher_ticket
         .state
-        .deactivate(Clock::get()?.slot, config.epoch_length())
+        .deactivate(get_clock()?.slot, config.epoch_length())
     {
-        msg!("Slasher is not ready to be deactivated");
+        msg!("Slasher is not ready to be deactivated: {}", e);
         return Err(VaultError::VaultNcnSlasherTicketFailedCooldown.into());
     }
 
@@ -1,11 +1,11 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
 use jito_restaking_core::ncn::Ncn;
 use jito_vault_core::{config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket};
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// Remove a vault from the vault's NCN list.
@@ -30,21 +30,21 @@ pub fn process_cooldown_vault_ncn_ticket(
     let vault_data = vault_info.data.borrow();
     let vault = Vault::try_from_slice_unchecked(&vault_data)?;
     Ncn::load(&config.restaking_program, ncn, false)?;
-    VaultNcnTicket::load(program_id, vault_ncn_ticket, ncn, vault_info, true)?;
+    VaultNcnTicket::load(program_id, vault_ncn_ticket, vault_info, ncn, true)?;
     let mut vault_ncn_ticket_data = vault_ncn_ticket.data.borrow_mut();
     let vault_ncn_ticket =
         VaultNcnTicket::try_from_slice_unchecked_mut(&mut vault_ncn_ticket_data)?;
     load_signer(vault_ncn_admin, false)?;
 
     vault.check_ncn_admin(vault_ncn_admin.key)?;
-    vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
 
     // The VaultNcnTicket must be active in order to cooldown the NCN
-    if !vault_ncn_ticket
+    if let Err(e) = vault_ncn_ticket
         .state
-        .deactivate(Clock::get()?.slot, config.epoch_length())
+        .deactivate(get_clock()?.slot, config.epoch_length())
     {
-        msg!("NCN is not ready to be deactivated");
+        msg!("NCN is not ready to be deactivated: {}", e);
         return Err(VaultError::VaultNcnTicketFailedCooldown.into());
     }
 
@@ -0,0 +1,104 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::sysvar::get_clock;
+use jito_restaking_core::{ncn::Ncn, ncn_vault_ticket::NcnVaultTicket, operator::Operator};
+use jito_vault_core::{
+    config::Config, operator_stake_weight::OperatorStakeWeight, vault::Vault,
+    vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation_snapshot::VaultOperatorDelegationSnapshot,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the crank operator stake weight instruction:
+/// [`crate::VaultInstruction::CrankOperatorStakeWeight`]
+///
+/// Anyone can crank a vault into the operator's stake weight. Vaults can be cranked in any order,
+/// vaults the NCN doesn't secure or that didn't delegate to the operator can't be cranked.
+///
+/// Specification:
+/// - The OperatorStakeWeight shall be the current epoch's and for the vault's supported mint
+/// - The VaultOperatorDelegationSnapshot shall be the vault's for the operator and current epoch
+/// - The NCN and vault shall have opted-in to each other, and the NCN's ticket shall not have
+///   expired
+/// - Each vault shall only be counted once, adding its snapshotted staked amount
+pub fn process_crank_operator_stake_weight(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, ncn, operator, vault_info, ncn_vault_ticket, vault_ncn_ticket, vault_operator_delegation_snapshot, operator_stake_weight] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Ncn::load(&config.restaking_program, ncn, false)?;
+    Operator::load(&config.restaking_program, operator, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    NcnVaultTicket::load(
+        &config.restaking_program,
+        ncn_vault_ticket,
+        ncn,
+        vault_info,
+        false,
+    )?;
+    let ncn_vault_ticket_data = ncn_vault_ticket.data.borrow();
+    let ncn_vault_ticket = NcnVaultTicket::try_from_slice_unchecked(&ncn_vault_ticket_data)?;
+    VaultNcnTicket::load(program_id, vault_ncn_ticket, vault_info, ncn, false)?;
+    let vault_ncn_ticket_data = vault_ncn_ticket.data.borrow();
+    let vault_ncn_ticket = VaultNcnTicket::try_from_slice_unchecked(&vault_ncn_ticket_data)?;
+
+    let slot = get_clock()?.slot;
+    let epoch_length = config.epoch_length();
+    let ncn_epoch = slot.checked_div(epoch_length).unwrap();
+    VaultOperatorDelegationSnapshot::load(
+        program_id,
+        vault_operator_delegation_snapshot,
+        vault_info,
+        operator,
+        ncn_epoch,
+    )?;
+    let snapshot_data = vault_operator_delegation_snapshot.data.borrow();
+    let snapshot = VaultOperatorDelegationSnapshot::try_from_slice_unchecked(&snapshot_data)?;
+    OperatorStakeWeight::load(
+        program_id,
+        operator_stake_weight,
+        ncn,
+        operator,
+        &vault.supported_mint,
+        ncn_epoch,
+        true,
+    )?;
+    let mut operator_stake_weight_data = operator_stake_weight.data.borrow_mut();
+    let operator_stake_weight =
+        OperatorStakeWeight::try_from_slice_unchecked_mut(&mut operator_stake_weight_data)?;
+
+    // The NCN and vault shall have opted-in to each other
+    if !ncn_vault_ticket.state.is_active(slot, epoch_length) || ncn_vault_ticket.is_expired(slot) {
+        msg!("NCN vault ticket is not active");
+        return Err(VaultError::OperatorStakeWeightVaultNcnInactive.into());
+    }
+    if !vault_ncn_ticket.state.is_active(slot, epoch_length) {
+        msg!("Vault NCN ticket is not active");
+        return Err(VaultError::OperatorStakeWeightVaultNcnInactive.into());
+    }
+
+    let staked_amount = snapshot.delegation_state.staked_amount();
+    operator_stake_weight.count_vault(vault.vault_index(), staked_amount)?;
+    msg!(
+        "Counted vault {} with {} staked, stake weight is {} across {} vaults",
+        vault.vault_index(),
+        staked_amount,
+        operator_stake_weight.stake_weight(),
+        operator_stake_weight.vault_count()
+    );
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use std::cmp::min;
 
 use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::sysvar::get_clock;
 use jito_restaking_core::operator::Operator;
 use jito_vault_core::{
     config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
@@ -8,10 +9,25 @@ use jito_vault_core::{
 };
 use jito_vault_sdk::instruction::WithdrawalAllocationMethod;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
+/// Updates an operator delegation for the epoch and accumulates it into the [`VaultUpdateStateTracker`]
+///
+/// Specification:
+/// - Operator delegations shall be cranked in index order
+/// - Stake shall be cooled down to cover withdrawals based on the withdrawal allocation method
+/// - All stake on an operator whose delegation is paused, or on every operator once the vault is
+///   shut down, shall be cooled down, counting towards the assets needed for withdrawals
+/// - Any stake left below the vault's minimum delegation amount shall be cooled down so it returns to
+///   the vault as idle assets
+/// - While an NCN task window is open on the operator, no stake shall be cooled down or move
+///   through the cooldown, deferring withdrawals that need it until the window closes
+/// - The same applies while an NCN has frozen the operator, until the freeze is lifted or expires
+/// - A deferred update on a delegation with stake shall be recorded on the tracker, so the vault
+///   holds its VRT cooldown for the epoch
+/// - The operator and its updated staked amount shall be appended to the delegation snapshot
 pub fn process_crank_vault_update_state_tracker(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -22,13 +38,19 @@ pub fn process_crank_vault_update_state_tracker(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let slot = Clock::get()?.slot;
+    let slot = get_clock()?.slot;
 
     Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
     Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
     Operator::load(&config.restaking_program, operator, false)?;
+    let operator_data = operator.data.borrow();
+    let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+    let operator_stake_locked = operator_account.is_stake_locked(slot);
+    let operator_frozen = operator_account.is_frozen(slot);
     VaultOperatorDelegation::load(
         program_id,
         vault_operator_delegation,
@@ -39,7 +61,9 @@ pub fn process_crank_vault_update_state_tracker(
     let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
     let vault_operator_delegation =
         VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
-    let ncn_epoch = slot.checked_div(config.epoch_length()).unwrap();
+    let ncn_epoch = slot
+        .checked_div(vault.epoch_length(config.epoch_length()))
+        .unwrap();
     VaultUpdateStateTracker::load(
         program_id,
         vault_update_state_tracker,
@@ -54,15 +78,74 @@ pub fn process_crank_vault_update_state_tracker(
 
     vault_update_state_tracker.check_and_update_index(vault_operator_delegation.index())?;
 
+    if operator_stake_locked || operator_frozen {
+        msg!(
+            "Operator {} has an open task window or is frozen, deferring its cooldowns",
+            vault_operator_delegation.operator
+        );
+        vault_operator_delegation.defer_update(slot);
+        if vault_operator_delegation
+            .delegation_state
+            .total_security()?
+            > 0
+        {
+            vault_update_state_tracker.increment_deferred_update_count()?;
+        }
+    } else {
+        cooldown_and_update_delegation(
+            config,
+            vault,
+            vault_operator_delegation,
+            vault_update_state_tracker,
+            slot,
+        )?;
+    }
+    vault_update_state_tracker
+        .delegation_state
+        .accumulate(&vault_operator_delegation.delegation_state)?;
+    vault_update_state_tracker.delegation_snapshot.append(
+        &vault_operator_delegation.operator,
+        vault_operator_delegation.delegation_state.staked_amount(),
+    )?;
+
+    Ok(())
+}
+
+/// Cools down stake for withdrawals and dust, then moves the delegation through its cooldown
+fn cooldown_and_update_delegation(
+    config: &Config,
+    vault: &Vault,
+    vault_operator_delegation: &mut VaultOperatorDelegation,
+    vault_update_state_tracker: &mut VaultUpdateStateTracker,
+    slot: u64,
+) -> ProgramResult {
+    if vault.is_shut_down() || vault_operator_delegation.is_delegation_paused() {
+        let staked_amount = vault_operator_delegation.delegation_state.staked_amount();
+        if staked_amount > 0 {
+            msg!(
+                "Cooling down all {} assets from operator {}",
+                staked_amount,
+                vault_operator_delegation.operator
+            );
+            vault_operator_delegation
+                .delegation_state
+                .cooldown(staked_amount)?;
+            vault_update_state_tracker.decrement_additional_assets_need_unstaking(min(
+                staked_amount,
+                vault_update_state_tracker.additional_assets_need_unstaking(),
+            ))?;
+        }
+    }
+
     match WithdrawalAllocationMethod::try_from(
         vault_update_state_tracker.withdrawal_allocation_method,
     ) {
         Ok(WithdrawalAllocationMethod::Greedy) => {
-            if vault_update_state_tracker.additional_assets_need_unstaking() > 0 {
-                let max_cooldown = min(
-                    vault_operator_delegation.delegation_state.staked_amount(),
-                    vault_update_state_tracker.additional_assets_need_unstaking(),
-                );
+            let max_cooldown = min(
+                vault_operator_delegation.delegation_state.staked_amount(),
+                vault_update_state_tracker.additional_assets_need_unstaking(),
+            );
+            if max_cooldown > 0 {
                 msg!(
                     "Force cooling down {} assets from operator {}",
                     max_cooldown,
@@ -84,10 +167,22 @@ pub fn process_crank_vault_update_state_tracker(
         }
     }
 
-    vault_operator_delegation.update(slot, config.epoch_length());
-    vault_update_state_tracker
+    let dust_amount = vault_operator_delegation
         .delegation_state
-        .accumulate(&vault_operator_delegation.delegation_state)?;
+        .sweep_dust(vault.min_delegation_amount())?;
+    if dust_amount > 0 {
+        msg!(
+            "Sweeping {} dust assets from operator {}",
+            dust_amount,
+            vault_operator_delegation.operator
+        );
+    }
+
+    vault_operator_delegation.update(
+        slot,
+        vault.epoch_length(config.epoch_length()),
+        config.delegation_cooldown_epochs(),
+    )?;
 
     Ok(())
 }
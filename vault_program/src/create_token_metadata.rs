@@ -3,6 +3,7 @@ use jito_jsm_core::loader::{
     load_signer, load_system_account, load_system_program, load_token_mint,
 };
 use jito_vault_core::{loader::load_mpl_metadata_program, vault::Vault};
+use jito_vault_sdk::error::VaultError;
 use jito_vault_sdk::inline_mpl_token_metadata::{
     instruction::create_metadata_accounts_v3, pda::find_metadata_account,
 };
@@ -11,6 +12,13 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey,
 };
 
+/// Processes the create token metadata instruction: [`crate::VaultInstruction::CreateTokenMetadata`]
+///
+/// Specification:
+/// - The vault's metadata admin must sign
+/// - The metadata account must be the Metaplex metadata PDA of the vault's VRT mint
+/// - The metadata account is created through a CPI into the Metaplex Token Metadata program,
+///   signed by the vault PDA as mint and update authority
 pub fn process_create_token_metadata(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -34,13 +42,13 @@ pub fn process_create_token_metadata(
     load_mpl_metadata_program(mpl_token_metadata_program)?;
     load_system_program(system_program)?;
 
-    vault.check_admin(admin.key)?;
+    vault.check_metadata_admin(admin.key)?;
     vault.check_vrt_mint(vrt_mint.key)?;
 
     let (metadata_account_pubkey, _) = find_metadata_account(vrt_mint.key);
     if metadata_account_pubkey != *metadata.key {
         msg!("Metadata account PDA does not match");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(VaultError::VaultMetadataAccountInvalid.into());
     }
 
     let new_metadata_instruction = create_metadata_accounts_v3(
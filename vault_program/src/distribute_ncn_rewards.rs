@@ -0,0 +1,184 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    loader::{load_associated_token_account, load_token_program},
+    sysvar::get_clock,
+    token::invoke_token,
+};
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_vault_core::{
+    config::Config, ncn_reward_router::NcnRewardRouter, vault::Vault,
+    vault_operator_delegation::VaultOperatorDelegation,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    program_pack::Pack, pubkey::Pubkey,
+};
+use spl_token::{instruction::transfer, state::Account};
+
+/// Processes the distribute NCN rewards instruction: [`crate::VaultInstruction::DistributeNcnRewards`]
+///
+/// Anyone can crank the rewards out of the router, as often as the NCN pays into it.
+///
+/// Specification:
+/// - The config shall not be paused
+/// - Each operator the vault delegates to, its VaultOperatorDelegation and the token account of
+///   its withdrawal fee wallet for the supported mint shall be passed in after the token program,
+///   in the order of the operator delegations' indices
+/// - The router's whole token balance shall be split by [`NcnRewardRouter::split_rewards`] with
+///   the config's protocol fee, and each operator's stake and commission
+/// - The protocol rewards shall be transferred to the protocol fee wallet's token account, each
+///   operator's commission to its fee token account and the rest to the vault token account, where
+///   [`crate::VaultInstruction::UpdateVaultBalance`] picks it up as rewards
+pub fn process_distribute_ncn_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let (required_accounts, operator_accounts) = accounts.split_at(8);
+
+    let [config, vault_info, ncn, ncn_reward_router, ncn_reward_router_token_account, vault_token_account, protocol_fee_token_account, token_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    Ncn::load(&config.restaking_program, ncn, false)?;
+    NcnRewardRouter::load(program_id, ncn_reward_router, ncn, vault_info, true)?;
+    let mut ncn_reward_router_data = ncn_reward_router.data.borrow_mut();
+    let ncn_reward_router_account =
+        NcnRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+    load_associated_token_account(
+        ncn_reward_router_token_account,
+        ncn_reward_router.key,
+        &vault.supported_mint,
+    )?;
+    vault.load_token_account(vault_info.key, vault_token_account)?;
+    load_associated_token_account(
+        protocol_fee_token_account,
+        &config.protocol_fee_wallet,
+        &vault.supported_mint,
+    )?;
+    load_token_program(token_program)?;
+
+    config.check_is_paused()?;
+
+    // Every operator delegation shall be passed in, in index order
+    if operator_accounts.len() % 3 != 0 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let operator_accounts: Vec<&[AccountInfo]> = operator_accounts.chunks_exact(3).collect();
+    if operator_accounts.len() as u64 != vault.operator_count() {
+        msg!(
+            "Expected {} operators, got {}",
+            vault.operator_count(),
+            operator_accounts.len()
+        );
+        return Err(VaultError::VaultOperatorDelegationsIncomplete.into());
+    }
+    let mut operators = Vec::with_capacity(operator_accounts.len());
+    for (index, accounts) in operator_accounts.iter().enumerate() {
+        let [operator, vault_operator_delegation, operator_fee_token_account] = *accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        Operator::load(&config.restaking_program, operator, false)?;
+        VaultOperatorDelegation::load(
+            program_id,
+            vault_operator_delegation,
+            vault_info,
+            operator,
+            false,
+        )?;
+        let operator_data = operator.data.borrow();
+        let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+        let vault_operator_delegation_data = vault_operator_delegation.data.borrow();
+        let vault_operator_delegation =
+            VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?;
+        if vault_operator_delegation.index() != index as u64 {
+            msg!(
+                "Expected the operator delegation at index {}, got {}",
+                index,
+                vault_operator_delegation.index()
+            );
+            return Err(VaultError::VaultOperatorDelegationsIncomplete.into());
+        }
+        load_associated_token_account(
+            operator_fee_token_account,
+            &operator.withdrawal_fee_wallet,
+            &vault.supported_mint,
+        )?;
+
+        operators.push((
+            vault_operator_delegation
+                .delegation_state
+                .total_security()?,
+            operator.commission_bps(),
+        ));
+    }
+
+    let rewards = Account::unpack(&ncn_reward_router_token_account.data.borrow())?.amount;
+    let split =
+        NcnRewardRouter::split_rewards(rewards, config.protocol_fee_bps(), operators.as_slice())?;
+    ncn_reward_router_account.record_distribution(&split, get_clock()?.slot)?;
+    msg!(
+        "Distributing {} rewards: {} to the protocol, {} to operators and {} to the vault",
+        rewards,
+        split.protocol_rewards,
+        split.total_operator_rewards()?,
+        split.vault_rewards
+    );
+
+    let (_, ncn_reward_router_bump, mut ncn_reward_router_seeds) =
+        NcnRewardRouter::find_program_address(
+            program_id,
+            ncn.key,
+            vault_info.key,
+            ncn_reward_router_account.epoch(),
+        );
+    ncn_reward_router_seeds.push(vec![ncn_reward_router_bump]);
+    let seed_slices: Vec<&[u8]> = ncn_reward_router_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect();
+    drop(ncn_reward_router_data);
+
+    let transfers = [
+        (protocol_fee_token_account, split.protocol_rewards),
+        (vault_token_account, split.vault_rewards),
+    ]
+    .into_iter()
+    .chain(
+        operator_accounts
+            .iter()
+            .map(|accounts| &accounts[2])
+            .zip(split.operator_rewards.iter().copied()),
+    );
+    for (destination, amount) in transfers {
+        if amount == 0 {
+            continue;
+        }
+        invoke_token(
+            &transfer(
+                &spl_token::id(),
+                ncn_reward_router_token_account.key,
+                destination.key,
+                ncn_reward_router.key,
+                &[],
+                amount,
+            )?,
+            &[
+                ncn_reward_router_token_account.clone(),
+                destination.clone(),
+                ncn_reward_router.clone(),
+            ],
+            &[seed_slices.as_slice()],
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the enable admin burn instruction: [`crate::VaultInstruction::EnableAdminBurn`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - Admin burns shall only be enabled before the first deposit, so every depositor knows about
+///   the burn admin, and can't be disabled afterwards
+pub fn process_enable_admin_burn(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault_info, vault_admin, burn_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_admin, false)?;
+
+    vault.check_admin(vault_admin.key)?;
+    vault.enable_admin_burn(burn_admin.key)?;
+
+    msg!(
+        "AdminBurnEnabled: vault {} burn admin {}",
+        vault_info.key,
+        burn_admin.key
+    );
+
+    Ok(())
+}
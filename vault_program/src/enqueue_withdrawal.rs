@@ -7,14 +7,16 @@ use jito_jsm_core::{
         load_associated_token_account, load_signer, load_system_account, load_system_program,
         load_token_program,
     },
+    sysvar::{get_clock, get_rent},
+    token::invoke_token,
 };
 use jito_vault_core::{
     config::Config, vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
 };
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, program::invoke,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 use spl_token::instruction::transfer;
 
@@ -23,10 +25,15 @@ use spl_token::instruction::transfer;
 ///
 /// Specification:
 /// - If the vault has a mint burn admin, it shall be present and be a signer of the transaction
+/// - The config and the vault shall not be paused
 /// - The vault shall be up to date
 /// - The amount to withdraw must be greater than zero
 /// - The VaultStakerWithdrawalTicket account shall be at the canonical PDA
+/// - The VaultStakerWithdrawalTicket shall record the vault's withdrawal delay, which it must wait
+///   out before it can be burned
 /// - The vault shall accurately track the amount of VRT that has been enqueued for cooldown
+/// - The ticket's VRT shall stay exposed to slashes proposed before it's burned, on vaults with
+///   slash dispute haircuts it's paid out net of the slashes still pending at that point
 /// - The staker's VRT tokens shall be transferred to the VaultStakerWithdrawalTicket associated token account
 pub fn process_enqueue_withdrawal(
     program_id: &Pubkey,
@@ -60,7 +67,9 @@ pub fn process_enqueue_withdrawal(
     load_system_program(system_program)?;
 
     vault.check_mint_burn_admin(optional_accounts.first())?;
-    vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+    config.check_is_paused()?;
+    vault.check_is_paused()?;
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
     if vrt_amount == 0 {
         msg!("VRT amount must be greater than zero");
         return Err(VaultError::VaultEnqueueWithdrawalAmountZero.into());
@@ -78,7 +87,7 @@ pub fn process_enqueue_withdrawal(
         .ne(&vault_staker_withdrawal_ticket_pubkey)
     {
         msg!("Vault staker withdrawal ticket is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
     }
 
     // Create the VaultStakerWithdrawalTicket account
@@ -91,7 +100,7 @@ pub fn process_enqueue_withdrawal(
         vault_staker_withdrawal_ticket,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64
             .checked_add(size_of::<VaultStakerWithdrawalTicket>() as u64)
             .unwrap(),
@@ -107,15 +116,16 @@ pub fn process_enqueue_withdrawal(
         *staker.key,
         *base.key,
         vrt_amount,
-        Clock::get()?.slot,
+        get_clock()?.slot,
         vault_staker_withdrawal_ticket_bump,
+        vault.withdrawal_delay_epochs(),
     );
 
     vault.increment_vrt_enqueued_for_cooldown_amount(vrt_amount)?;
 
     // Withdraw funds from the staker's VRT account, transferring them to an ATA owned
     // by the VaultStakerWithdrawalTicket
-    invoke(
+    invoke_token(
         &transfer(
             &spl_token::id(),
             staker_vrt_token_account.key,
@@ -129,6 +139,7 @@ pub fn process_enqueue_withdrawal(
             vault_staker_withdrawal_ticket_token_account.clone(),
             staker.clone(),
         ],
+        &[],
     )?;
 
     Ok(())
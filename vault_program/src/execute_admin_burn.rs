@@ -0,0 +1,227 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    close_program_account,
+    loader::{
+        load_associated_token_account, load_signer, load_system_program, load_token_mint,
+        load_token_program,
+    },
+    sysvar::get_clock,
+    token::invoke_token,
+};
+use jito_vault_core::{
+    config::Config,
+    vault::{BurnSummary, Vault},
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    events::{VaultEvent, WithdrawEvent},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token::instruction::{burn, close_account, transfer};
+
+use crate::initialize_vault_epoch_stats::record_vault_epoch_stats;
+
+/// Processes the execute admin burn instruction: [`crate::VaultInstruction::ExecuteAdminBurn`]
+///
+/// Specification:
+/// - The admin must be the vault burn admin and sign the transaction
+/// - An admin burn shall be pending on the withdrawal ticket and its timelock shall have passed
+/// - The config and the vault must not be paused and the vault must be up-to-date
+/// - The vault token account must be open and hold at least the tokens deposited
+/// - The withdrawal ticket shall be withdrawable, as with
+///   [`crate::VaultInstruction::BurnWithdrawTicket`]
+/// - The withdrawal fee shall be transferred to the vault fee wallet and the rest of the ticket's
+///   VRT burned, the same as burning the ticket
+/// - The assets shall be transferred to the recovery address' associated token account instead of
+///   the staker's
+/// - The ticket and its token account shall be closed, returning the rent to the staker
+/// - If the vault has epoch stats enabled, the VaultEpochStats must be passed in after the
+///   required accounts and shall record the withdrawal and fee
+/// - A [`jito_vault_sdk::events::VaultEvent::Withdraw`] event shall be emitted for the staker
+pub fn process_execute_admin_burn(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(12);
+    let [config, vault_info, vault_token_account, vrt_mint, staker, vault_staker_withdrawal_ticket_info, vault_staker_withdrawal_ticket_token_account, vault_fee_token_account, recovery_token_account, burn_admin, token_program, system_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let clock = get_clock()?;
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    let vault_token_balance = vault.load_token_account(vault_info.key, vault_token_account)?;
+    vault.check_token_account_balance(vault_token_balance)?;
+    load_token_mint(vrt_mint)?;
+    VaultStakerWithdrawalTicket::load(
+        program_id,
+        vault_staker_withdrawal_ticket_info,
+        vault_info,
+        true,
+    )?;
+    let vault_staker_withdrawal_ticket_data = vault_staker_withdrawal_ticket_info.data.borrow();
+    let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked(
+        &vault_staker_withdrawal_ticket_data,
+    )?;
+    load_associated_token_account(
+        vault_staker_withdrawal_ticket_token_account,
+        vault_staker_withdrawal_ticket_info.key,
+        &vault.vrt_mint,
+    )?;
+    load_associated_token_account(vault_fee_token_account, &vault.fee_wallet, &vault.vrt_mint)?;
+    load_signer(burn_admin, false)?;
+    load_token_program(token_program)?;
+    load_system_program(system_program)?;
+
+    vault.check_burn_admin(burn_admin.key)?;
+    vault.check_vrt_mint(vrt_mint.key)?;
+    config.check_is_paused()?;
+    vault.check_is_paused()?;
+    vault.check_update_state_ok(clock.slot, vault.epoch_length(config.epoch_length()))?;
+    vault_staker_withdrawal_ticket.check_staker(staker.key)?;
+
+    let recovery = vault_staker_withdrawal_ticket.check_admin_burn_executable(clock.slot)?;
+    load_associated_token_account(recovery_token_account, &recovery, &vault.supported_mint)?;
+
+    if !vault_staker_withdrawal_ticket.is_withdrawable(
+        clock.slot,
+        vault.epoch_length(config.epoch_length()),
+        config.delegation_cooldown_epochs(),
+    )? {
+        msg!("Vault staker withdrawal ticket is not withdrawable");
+        return Err(VaultError::VaultStakerWithdrawalTicketNotWithdrawable.into());
+    }
+
+    let vrt_amount = vault_staker_withdrawal_ticket.vrt_amount();
+    let BurnSummary {
+        fee_amount,
+        burn_amount,
+        out_amount,
+    } = vault.burn_with_fee(vrt_amount, 0)?;
+    vault.decrement_vrt_ready_to_claim_amount(vrt_amount)?;
+
+    let epoch = clock
+        .slot
+        .checked_div(vault.epoch_length(config.epoch_length()))
+        .unwrap();
+    record_vault_epoch_stats(program_id, vault_info, vault, optional_accounts, |stats| {
+        stats.record_withdrawal(epoch, out_amount, fee_amount)
+    })?;
+
+    msg!(
+        "AdminBurnExecuted: vault {} ticket {} staker {} vrt {} assets {} recovery {}",
+        vault_info.key,
+        vault_staker_withdrawal_ticket_info.key,
+        staker.key,
+        vrt_amount,
+        out_amount,
+        recovery
+    );
+
+    let (_, vault_staker_withdraw_bump, mut vault_staker_withdraw_seeds) =
+        VaultStakerWithdrawalTicket::find_program_address(
+            program_id,
+            vault_info.key,
+            &vault_staker_withdrawal_ticket.base,
+        );
+    vault_staker_withdraw_seeds.push(vec![vault_staker_withdraw_bump]);
+    let seed_slices: Vec<&[u8]> = vault_staker_withdraw_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect();
+    drop(vault_staker_withdrawal_ticket_data);
+
+    // transfer fee to fee wallet
+    invoke_token(
+        &transfer(
+            &spl_token::id(),
+            vault_staker_withdrawal_ticket_token_account.key,
+            vault_fee_token_account.key,
+            vault_staker_withdrawal_ticket_info.key,
+            &[],
+            fee_amount,
+        )?,
+        &[
+            vault_staker_withdrawal_ticket_token_account.clone(),
+            vault_fee_token_account.clone(),
+            vault_staker_withdrawal_ticket_info.clone(),
+        ],
+        &[&seed_slices],
+    )?;
+    // burn the VRT tokens
+    invoke_token(
+        &burn(
+            &spl_token::id(),
+            vault_staker_withdrawal_ticket_token_account.key,
+            vrt_mint.key,
+            vault_staker_withdrawal_ticket_info.key,
+            &[],
+            burn_amount,
+        )?,
+        &[
+            vault_staker_withdrawal_ticket_token_account.clone(),
+            vrt_mint.clone(),
+            vault_staker_withdrawal_ticket_info.clone(),
+        ],
+        &[&seed_slices],
+    )?;
+
+    // close token account, the rent goes back to the staker that paid it
+    invoke_token(
+        &close_account(
+            &spl_token::id(),
+            vault_staker_withdrawal_ticket_token_account.key,
+            staker.key,
+            vault_staker_withdrawal_ticket_info.key,
+            &[],
+        )?,
+        &[
+            vault_staker_withdrawal_ticket_token_account.clone(),
+            staker.clone(),
+            vault_staker_withdrawal_ticket_info.clone(),
+        ],
+        &[&seed_slices],
+    )?;
+    close_program_account(program_id, vault_staker_withdrawal_ticket_info, staker)?;
+
+    // transfer the assets to the recovery address
+    let (_, vault_bump, mut vault_seeds) = Vault::find_program_address(program_id, &vault.base);
+    vault_seeds.push(vec![vault_bump]);
+    let seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
+    drop(vault_data); // avoid double borrow
+    invoke_token(
+        &transfer(
+            &spl_token::id(),
+            vault_token_account.key,
+            recovery_token_account.key,
+            vault_info.key,
+            &[],
+            out_amount,
+        )?,
+        &[
+            vault_token_account.clone(),
+            recovery_token_account.clone(),
+            vault_info.clone(),
+        ],
+        &[&seed_slices],
+    )?;
+
+    VaultEvent::Withdraw(WithdrawEvent {
+        vault: *vault_info.key,
+        staker: *staker.key,
+        vrt_burned: vrt_amount,
+        vrt_fee: fee_amount,
+        amount_out: out_amount,
+    })
+    .emit();
+
+    Ok(())
+}
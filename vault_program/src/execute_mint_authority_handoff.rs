@@ -0,0 +1,65 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    loader::{load_token_mint, load_token_program},
+    sysvar::get_clock,
+    token::invoke_token,
+};
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token::instruction::{set_authority, AuthorityType};
+
+/// Processes the execute mint authority handoff instruction: [`crate::VaultInstruction::ExecuteMintAuthorityHandoff`]
+///
+/// Specification:
+/// - Anyone can execute the handoff once its challenge window has passed
+/// - The VRT mint authority shall be transferred from the vault to the successor
+/// - Instructions that mint VRT fail afterwards, burning VRT is unaffected so holders can still exit
+pub fn process_execute_mint_authority_handoff(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, vrt_mint, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_token_mint(vrt_mint)?;
+    load_token_program(token_program)?;
+
+    vault.check_vrt_mint(vrt_mint.key)?;
+
+    let successor = vault.execute_mint_authority_handoff(get_clock()?.slot)?;
+
+    let (_, vault_bump, mut vault_seeds) = Vault::find_program_address(program_id, &vault.base);
+    vault_seeds.push(vec![vault_bump]);
+    let seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
+    drop(vault_data);
+
+    invoke_token(
+        &set_authority(
+            &spl_token::id(),
+            vrt_mint.key,
+            Some(&successor),
+            AuthorityType::MintTokens,
+            vault_info.key,
+            &[],
+        )?,
+        &[vrt_mint.clone(), vault_info.clone()],
+        &[&seed_slices],
+    )?;
+
+    msg!(
+        "MintAuthorityHandoffExecuted: vault {} VRT mint {} authority set to {}",
+        vault_info.key,
+        vrt_mint.key,
+        successor
+    );
+
+    Ok(())
+}
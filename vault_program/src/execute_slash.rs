@@ -0,0 +1,155 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    close_program_account,
+    loader::{load_signer, load_token_program},
+    sysvar::get_clock,
+};
+use jito_restaking_core::ncn_vault_slasher_ticket::NcnVaultSlasherTicket;
+use jito_vault_core::{
+    config::Config, slash_proposal::SlashProposal, vault::Vault,
+    vault_operator_delegation::VaultOperatorDelegation,
+};
+use jito_vault_sdk::events::{SlashedEvent, VaultEvent};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    initialize_vault_epoch_stats::record_vault_epoch_stats,
+    slash::{
+        absorb_slash_with_insurance, load_slash_destination, slash_and_update_vault,
+        transfer_slashed_funds,
+    },
+};
+
+/// Processes the execute slash instruction: [`crate::VaultInstruction::ExecuteSlash`]
+///
+/// Specification:
+/// - The NCN, operator and slasher shall be the proposal's, and the slasher shall sign the transaction
+/// - The vault shall be up to date
+/// - The vault token account must be open and hold at least the tokens deposited
+/// - The SlashProposal's veto window shall have passed
+/// - The proposed amount shall no longer count as pending against the vault
+/// - The slashes executed in the current epoch across all slashers shall not exceed the vault's
+///   max total slashable per epoch
+/// - If the vault has insurance enabled, the vault insurance, its VRT token account and the VRT
+///   mint must be passed in after the token program, and the insurance fund shall absorb the slash
+///   before the exchange rate drops
+/// - The VaultOperatorDelegation shall be slashed and the vault amounts shall be updated
+/// - If the vault has epoch stats enabled, the VaultEpochStats must be passed in after the token
+///   program and shall record the slash
+/// - A [`jito_vault_sdk::events::VaultEvent::Slashed`] event shall be emitted
+/// - The slashed funds shall be sent where the NCN vault slasher ticket's slash destination says
+///   at the time the slash is executed
+/// - The SlashProposal account shall be closed, returning its rent to the slasher
+pub fn process_execute_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(11);
+
+    let [config, vault_info, ncn, operator, slasher, vault_operator_delegation, slash_proposal_info, ncn_vault_slasher_ticket, vault_token_account, slash_destination, token_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(slasher, true)?;
+    VaultOperatorDelegation::load(
+        program_id,
+        vault_operator_delegation,
+        vault_info,
+        operator,
+        true,
+    )?;
+    let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
+    SlashProposal::load(program_id, slash_proposal_info, vault_info, true)?;
+    let slash_proposal_data = slash_proposal_info.data.borrow();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked(&slash_proposal_data)?;
+    slash_proposal.check_parties(ncn.key, operator.key, slasher.key)?;
+    let vault_token_balance = vault.load_token_account(vault_info.key, vault_token_account)?;
+    vault.check_token_account_balance(vault_token_balance)?;
+    NcnVaultSlasherTicket::load(
+        &config.restaking_program,
+        ncn_vault_slasher_ticket,
+        ncn,
+        vault_info,
+        slasher,
+        false,
+    )?;
+    let ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow();
+    let ncn_vault_slasher_ticket =
+        NcnVaultSlasherTicket::try_from_slice_unchecked(&ncn_vault_slasher_ticket_data)?;
+    let slash_destination_kind = load_slash_destination(
+        ncn_vault_slasher_ticket,
+        config,
+        vault,
+        slasher,
+        slash_destination,
+    )?;
+    load_token_program(token_program)?;
+
+    let slot = get_clock()?.slot;
+    vault.check_update_state_ok(slot, vault.epoch_length(config.epoch_length()))?;
+    slash_proposal.check_executable(slot)?;
+
+    // The slashes executed this epoch shall not exceed the vault's max total slashable per epoch
+    let slash_amount = slash_proposal.amount();
+    let epoch = slot
+        .checked_div(vault.epoch_length(config.epoch_length()))
+        .unwrap();
+    vault.record_epoch_slash(slash_amount, epoch)?;
+    vault.release_pending_slash_amount(slash_amount);
+
+    // The VaultOperatorDelegation shall be slashed and the vault amounts shall be updated
+    absorb_slash_with_insurance(
+        program_id,
+        vault_info,
+        vault,
+        optional_accounts,
+        slash_amount,
+    )?;
+    slash_and_update_vault(vault, vault_operator_delegation, slash_amount)?;
+    record_vault_epoch_stats(program_id, vault_info, vault, optional_accounts, |stats| {
+        stats.record_slash(epoch, slash_amount)
+    })?;
+
+    msg!(
+        "SlashExecuted: vault {} operator {} amount {}",
+        vault_info.key,
+        operator.key,
+        slash_amount
+    );
+    VaultEvent::Slashed(SlashedEvent {
+        vault: *vault_info.key,
+        ncn: *ncn.key,
+        operator: *operator.key,
+        slasher: *slasher.key,
+        amount: slash_amount,
+    })
+    .emit();
+
+    // transfer the slashed funds
+    let mut vault_seeds = Vault::seeds(&vault.base);
+    vault_seeds.push(vec![vault.bump]);
+    drop(vault_data);
+    transfer_slashed_funds(
+        vault_info,
+        vault_token_account,
+        slash_destination,
+        slash_destination_kind,
+        &vault_seeds,
+        slash_amount,
+    )?;
+
+    drop(slash_proposal_data);
+    close_program_account(program_id, slash_proposal_info, slasher)?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    loader::{load_associated_token_account, load_signer, load_token_mint, load_token_program},
+    token::invoke_token,
+};
+use jito_vault_core::{config::Config, vault::Vault, vault_insurance::VaultInsurance};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token::instruction::transfer;
+
+/// Processes the fund insurance instruction: [`crate::VaultInstruction::FundInsurance`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The vault shall have insurance enabled with [`crate::VaultInstruction::SetInsuranceParams`]
+/// - The amount of VRT shall be transferred from the admin's VRT token account to the vault
+///   insurance's VRT token account
+pub fn process_fund_insurance(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let [config, vault_info, vault_insurance, vrt_mint, admin_vrt_token_account, insurance_vrt_token_account, vault_admin, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    VaultInsurance::load(program_id, vault_insurance, vault_info, true)?;
+    let mut vault_insurance_data = vault_insurance.data.borrow_mut();
+    let vault_insurance_account =
+        VaultInsurance::try_from_slice_unchecked_mut(&mut vault_insurance_data)?;
+    load_token_mint(vrt_mint)?;
+    load_associated_token_account(admin_vrt_token_account, vault_admin.key, vrt_mint.key)?;
+    load_associated_token_account(
+        insurance_vrt_token_account,
+        vault_insurance.key,
+        vrt_mint.key,
+    )?;
+    load_signer(vault_admin, false)?;
+    load_token_program(token_program)?;
+
+    config.check_is_paused()?;
+    vault.check_admin(vault_admin.key)?;
+    vault.check_vrt_mint(vrt_mint.key)?;
+
+    vault_insurance_account.record_funding(amount)?;
+
+    invoke_token(
+        &transfer(
+            &spl_token::id(),
+            admin_vrt_token_account.key,
+            insurance_vrt_token_account.key,
+            vault_admin.key,
+            &[],
+            amount,
+        )?,
+        &[
+            admin_vrt_token_account.clone(),
+            insurance_vrt_token_account.clone(),
+            vault_admin.clone(),
+        ],
+        &[],
+    )?;
+
+    Ok(())
+}
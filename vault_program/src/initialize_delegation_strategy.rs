@@ -0,0 +1,81 @@
+use std::mem::size_of;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+    sysvar::get_rent,
+};
+use jito_vault_core::{config::Config, delegation_strategy::DelegationStrategy, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the initialize delegation strategy instruction: [`crate::VaultInstruction::InitializeDelegationStrategy`]
+///
+/// Specification:
+/// - The admin must be the vault delegation admin and sign the transaction
+/// - The delegation strategy shall be at the canonical PDA for the vault
+/// - The strategy starts with no target weights, so the crank doesn't move stake until the
+///   delegation admin sets them
+pub fn process_initialize_delegation_strategy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_movement_per_epoch: u64,
+) -> ProgramResult {
+    let [config, vault_info, delegation_strategy, vault_delegation_admin, payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    load_system_account(delegation_strategy, true)?;
+    load_signer(vault_delegation_admin, false)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    vault.check_delegation_admin(vault_delegation_admin.key)?;
+
+    // The DelegationStrategy shall be at the canonical PDA
+    let (delegation_strategy_pubkey, delegation_strategy_bump, mut delegation_strategy_seeds) =
+        DelegationStrategy::find_program_address(program_id, vault_info.key);
+    delegation_strategy_seeds.push(vec![delegation_strategy_bump]);
+    if delegation_strategy_pubkey.ne(delegation_strategy.key) {
+        msg!("Delegation strategy is not at the correct PDA");
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
+    }
+
+    msg!(
+        "Initializing DelegationStrategy at address {}",
+        delegation_strategy.key
+    );
+    create_account(
+        payer,
+        delegation_strategy,
+        system_program,
+        program_id,
+        &get_rent()?,
+        8_u64
+            .checked_add(size_of::<DelegationStrategy>() as u64)
+            .unwrap(),
+        &delegation_strategy_seeds,
+    )?;
+
+    let mut delegation_strategy_data = delegation_strategy.try_borrow_mut_data()?;
+    delegation_strategy_data[0] = DelegationStrategy::DISCRIMINATOR;
+    let delegation_strategy =
+        DelegationStrategy::try_from_slice_unchecked_mut(&mut delegation_strategy_data)?;
+    *delegation_strategy = DelegationStrategy::new(
+        *vault_info.key,
+        max_movement_per_epoch,
+        delegation_strategy_bump,
+    );
+
+    Ok(())
+}
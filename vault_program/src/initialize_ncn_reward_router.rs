@@ -0,0 +1,78 @@
+use std::mem::size_of;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+    sysvar::get_rent,
+};
+use jito_restaking_core::ncn::Ncn;
+use jito_vault_core::{config::Config, ncn_reward_router::NcnRewardRouter, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the initialize NCN reward router instruction: [`crate::VaultInstruction::InitializeNcnRewardRouter`]
+///
+/// Anyone can create the router, paying for the account. The NCN pays its rewards by transferring
+/// them to the router's associated token account for the vault's supported mint, which has to be
+/// created separately.
+///
+/// Specification:
+/// - The NCN shall be an NCN of the restaking program
+/// - The NcnRewardRouter shall be at the canonical PDA for the NCN, vault and epoch, so only one
+///   router can be created per NCN, vault and epoch
+pub fn process_initialize_ncn_reward_router(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+) -> ProgramResult {
+    let [config, vault_info, ncn, ncn_reward_router, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    Ncn::load(&config.restaking_program, ncn, false)?;
+    load_system_account(ncn_reward_router, true)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    // The NcnRewardRouter shall be at the canonical PDA
+    let (ncn_reward_router_pubkey, ncn_reward_router_bump, mut ncn_reward_router_seeds) =
+        NcnRewardRouter::find_program_address(program_id, ncn.key, vault_info.key, epoch);
+    ncn_reward_router_seeds.push(vec![ncn_reward_router_bump]);
+    if ncn_reward_router_pubkey.ne(ncn_reward_router.key) {
+        msg!("NCN reward router is not at the correct PDA");
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
+    }
+
+    msg!(
+        "Initializing NcnRewardRouter at address {}",
+        ncn_reward_router.key
+    );
+    create_account(
+        payer,
+        ncn_reward_router,
+        system_program,
+        program_id,
+        &get_rent()?,
+        8_u64
+            .checked_add(size_of::<NcnRewardRouter>() as u64)
+            .unwrap(),
+        &ncn_reward_router_seeds,
+    )?;
+
+    let mut ncn_reward_router_data = ncn_reward_router.try_borrow_mut_data()?;
+    ncn_reward_router_data[0] = NcnRewardRouter::DISCRIMINATOR;
+    let ncn_reward_router =
+        NcnRewardRouter::try_from_slice_unchecked_mut(&mut ncn_reward_router_data)?;
+    *ncn_reward_router =
+        NcnRewardRouter::new(*ncn.key, *vault_info.key, epoch, ncn_reward_router_bump);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use std::mem::size_of;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program, load_token_mint},
+    sysvar::{get_clock, get_rent},
+};
+use jito_restaking_core::{ncn::Ncn, operator::Operator};
+use jito_vault_core::{config::Config, operator_stake_weight::OperatorStakeWeight};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the initialize operator stake weight instruction:
+/// [`crate::VaultInstruction::InitializeOperatorStakeWeight`]
+///
+/// Anyone can initialize the stake weight, paying for the account. Vaults are then added to it
+/// with [`crate::VaultInstruction::CrankOperatorStakeWeight`].
+///
+/// Specification:
+/// - The OperatorStakeWeight shall be at the canonical PDA for the NCN, operator, supported mint
+///   and current epoch, so only one can be initialized per epoch
+/// - The stake weight shall start at zero with no vaults counted
+pub fn process_initialize_operator_stake_weight(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, ncn, operator, supported_mint, operator_stake_weight, payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Ncn::load(&config.restaking_program, ncn, false)?;
+    Operator::load(&config.restaking_program, operator, false)?;
+    load_token_mint(supported_mint)?;
+    load_system_account(operator_stake_weight, true)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let slot = get_clock()?.slot;
+    let ncn_epoch = slot.checked_div(config.epoch_length()).unwrap();
+
+    // The OperatorStakeWeight shall be at the canonical PDA
+    let (operator_stake_weight_pubkey, operator_stake_weight_bump, mut operator_stake_weight_seeds) =
+        OperatorStakeWeight::find_program_address(
+            program_id,
+            ncn.key,
+            operator.key,
+            supported_mint.key,
+            ncn_epoch,
+        );
+    operator_stake_weight_seeds.push(vec![operator_stake_weight_bump]);
+    if operator_stake_weight_pubkey.ne(operator_stake_weight.key) {
+        msg!("Operator stake weight is not at the correct PDA");
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
+    }
+
+    msg!(
+        "Initializing OperatorStakeWeight at address {}",
+        operator_stake_weight.key
+    );
+    create_account(
+        payer,
+        operator_stake_weight,
+        system_program,
+        program_id,
+        &get_rent()?,
+        8_u64
+            .checked_add(size_of::<OperatorStakeWeight>() as u64)
+            .unwrap(),
+        &operator_stake_weight_seeds,
+    )?;
+
+    let mut operator_stake_weight_data = operator_stake_weight.try_borrow_mut_data()?;
+    operator_stake_weight_data[0] = OperatorStakeWeight::DISCRIMINATOR;
+    let operator_stake_weight =
+        OperatorStakeWeight::try_from_slice_unchecked_mut(&mut operator_stake_weight_data)?;
+    *operator_stake_weight = OperatorStakeWeight::new(
+        *ncn.key,
+        *operator.key,
+        *supported_mint.key,
+        ncn_epoch,
+        slot,
+        operator_stake_weight_bump,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use std::mem::size_of;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{
+        load_associated_token_account, load_signer, load_system_account, load_system_program,
+        load_token_mint, load_token_program,
+    },
+    sysvar::get_rent,
+    token::invoke_token,
+};
+use jito_restaking_core::operator::Operator;
+use jito_vault_core::{config::Config, reward_distribution::RewardDistribution, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token::instruction::transfer;
+
+/// Processes the initialize reward distribution instruction: [`crate::VaultInstruction::InitializeRewardDistribution`]
+///
+/// The rewards are held in the vault's associated token account for the reward mint until they're
+/// claimed with [`crate::VaultInstruction::ClaimRewards`]. Rewards in the supported mint are
+/// deposited into the vault token account instead, where they raise the VRT exchange rate.
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The reward mint shall not be the vault's supported mint
+/// - The reward distribution shall be at the canonical PDA for the vault and epoch, so only one
+///   distribution can be posted per vault and epoch
+/// - If the rewards were earned through an operator, the operator and the operator's fee token
+///   account, the associated token account of its withdrawal fee wallet for the reward mint, shall
+///   be passed in after the system program. The operator's commission on the total rewards, rounded
+///   down, shall be transferred from the admin's token account to the operator's fee token account
+///   and the Merkle root shall cover the rest
+/// - The total rewards less any commission shall be transferred from the admin's token account to
+///   the vault rewards token account
+pub fn process_initialize_reward_distribution(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    merkle_root: [u8; 32],
+    total_rewards: u64,
+) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(9);
+
+    let [config, vault_info, reward_distribution, mint, vault_rewards_token_account, admin_token_account, vault_admin, token_program, system_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    load_system_account(reward_distribution, true)?;
+    load_token_mint(mint)?;
+    load_associated_token_account(vault_rewards_token_account, vault_info.key, mint.key)?;
+    load_associated_token_account(admin_token_account, vault_admin.key, mint.key)?;
+    load_signer(vault_admin, true)?;
+    load_token_program(token_program)?;
+    load_system_program(system_program)?;
+
+    vault.check_admin(vault_admin.key)?;
+
+    if mint.key.eq(&vault.supported_mint) {
+        msg!("Rewards in the supported mint shall be deposited into the vault token account");
+        return Err(VaultError::VaultRewardMintInvalid.into());
+    }
+
+    // The operator's commission comes off the top of the rewards
+    let (operator_key, operator_commission) = match optional_accounts {
+        [] => (Pubkey::default(), 0),
+        [operator, operator_fee_token_account, ..] => {
+            Operator::load(&config.restaking_program, operator, false)?;
+            let operator_data = operator.data.borrow();
+            let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+            load_associated_token_account(
+                operator_fee_token_account,
+                &operator_account.withdrawal_fee_wallet,
+                mint.key,
+            )?;
+            let operator_commission = operator_account.calculate_commission(total_rewards)?;
+
+            if operator_commission > 0 {
+                msg!(
+                    "Paying operator {} a commission of {}",
+                    operator.key,
+                    operator_commission
+                );
+                invoke_token(
+                    &transfer(
+                        &spl_token::id(),
+                        admin_token_account.key,
+                        operator_fee_token_account.key,
+                        vault_admin.key,
+                        &[],
+                        operator_commission,
+                    )?,
+                    &[
+                        admin_token_account.clone(),
+                        operator_fee_token_account.clone(),
+                        vault_admin.clone(),
+                    ],
+                    &[],
+                )?;
+            }
+
+            (*operator.key, operator_commission)
+        }
+        [_] => return Err(ProgramError::NotEnoughAccountKeys),
+    };
+    let stakers_rewards = total_rewards
+        .checked_sub(operator_commission)
+        .ok_or(VaultError::VaultUnderflow)?;
+
+    // The RewardDistribution shall be at the canonical PDA
+    let (reward_distribution_pubkey, reward_distribution_bump, mut reward_distribution_seeds) =
+        RewardDistribution::find_program_address(program_id, vault_info.key, epoch);
+    reward_distribution_seeds.push(vec![reward_distribution_bump]);
+    if reward_distribution_pubkey.ne(reward_distribution.key) {
+        msg!("Reward distribution is not at the correct PDA");
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
+    }
+
+    msg!(
+        "Initializing RewardDistribution at address {}",
+        reward_distribution.key
+    );
+    create_account(
+        vault_admin,
+        reward_distribution,
+        system_program,
+        program_id,
+        &get_rent()?,
+        8_u64
+            .checked_add(size_of::<RewardDistribution>() as u64)
+            .unwrap(),
+        &reward_distribution_seeds,
+    )?;
+
+    let mut reward_distribution_data = reward_distribution.try_borrow_mut_data()?;
+    reward_distribution_data[0] = RewardDistribution::DISCRIMINATOR;
+    let reward_distribution_account =
+        RewardDistribution::try_from_slice_unchecked_mut(&mut reward_distribution_data)?;
+    *reward_distribution_account = RewardDistribution::new(
+        *vault_info.key,
+        *mint.key,
+        epoch,
+        merkle_root,
+        stakers_rewards,
+        reward_distribution_bump,
+        operator_key,
+        operator_commission,
+    );
+
+    // Transfer the rewards from the admin to the vault rewards token account
+    invoke_token(
+        &transfer(
+            &spl_token::id(),
+            admin_token_account.key,
+            vault_rewards_token_account.key,
+            vault_admin.key,
+            &[],
+            stakers_rewards,
+        )?,
+        &[
+            admin_token_account.clone(),
+            vault_rewards_token_account.clone(),
+            vault_admin.clone(),
+        ],
+        &[],
+    )?;
+
+    Ok(())
+}
@@ -1,18 +1,20 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::{AccountDeserialize, AccountVersion, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{
         load_signer, load_system_account, load_system_program, load_token_mint, load_token_program,
     },
+    system::invoke_system,
+    sysvar::get_rent,
+    token::invoke_token,
 };
 use jito_vault_core::{config::Config, vault::Vault, MAX_FEE_BPS};
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
-    program_error::ProgramError, program_pack::Pack, pubkey::Pubkey, rent::Rent,
-    system_instruction, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    program_pack::Pack, pubkey::Pubkey, system_instruction,
 };
 use spl_token::state::Mint;
 
@@ -48,7 +50,7 @@ pub fn process_initialize_vault(
     vault_seeds.push(vec![vault_bump]);
     if vault.key.ne(&vault_pubkey) {
         msg!("Vault account is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
     }
 
     if deposit_fee_bps > config.deposit_withdrawal_fee_cap_bps()
@@ -62,12 +64,12 @@ pub fn process_initialize_vault(
         return Err(VaultError::VaultFeeCapExceeded.into());
     }
 
-    let rent = Rent::get()?;
+    let rent = get_rent()?;
 
     // Initialize VRT mint
     {
         msg!("Initializing mint @ address {}", vrt_mint.key);
-        invoke(
+        invoke_system(
             &system_instruction::create_account(
                 admin.key,
                 vrt_mint.key,
@@ -76,9 +78,10 @@ pub fn process_initialize_vault(
                 token_program.key,
             ),
             &[admin.clone(), vrt_mint.clone(), system_program.clone()],
+            &[],
         )?;
 
-        invoke(
+        invoke_token(
             &spl_token::instruction::initialize_mint2(
                 &spl_token::id(),
                 vrt_mint.key,
@@ -87,6 +90,7 @@ pub fn process_initialize_vault(
                 decimals,
             )?,
             &[vrt_mint.clone()],
+            &[],
         )?;
     }
 
@@ -98,13 +102,14 @@ pub fn process_initialize_vault(
             vault,
             system_program,
             program_id,
-            &Rent::get()?,
+            &get_rent()?,
             8_u64.checked_add(size_of::<Vault>() as u64).unwrap(),
             &vault_seeds,
         )?;
 
         let mut vault_data = vault.try_borrow_mut_data()?;
         vault_data[0] = Vault::DISCRIMINATOR;
+        vault_data[1] = Vault::VERSION;
         let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
 
         *vault = Vault::new(
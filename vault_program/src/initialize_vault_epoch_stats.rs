@@ -0,0 +1,111 @@
+use std::mem::size_of;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+    sysvar::get_rent,
+};
+use jito_vault_core::{config::Config, vault::Vault, vault_epoch_stats::VaultEpochStats};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the initialize vault epoch stats instruction: [`crate::VaultInstruction::InitializeVaultEpochStats`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The vault epoch stats shall be at the canonical PDA for the vault
+/// - The vault shall require the vault epoch stats account in every instruction that deposits,
+///   withdraws or slashes from then on, see [`record_vault_epoch_stats`]
+pub fn process_initialize_vault_epoch_stats(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, vault_epoch_stats, vault_admin, payer, system_program] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_system_account(vault_epoch_stats, true)?;
+    load_signer(vault_admin, false)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    vault.check_admin(vault_admin.key)?;
+
+    // The VaultEpochStats shall be at the canonical PDA
+    let (vault_epoch_stats_pubkey, vault_epoch_stats_bump, mut vault_epoch_stats_seeds) =
+        VaultEpochStats::find_program_address(program_id, vault_info.key);
+    vault_epoch_stats_seeds.push(vec![vault_epoch_stats_bump]);
+    if vault_epoch_stats_pubkey.ne(vault_epoch_stats.key) {
+        msg!("Vault epoch stats is not at the correct PDA");
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
+    }
+
+    msg!(
+        "Initializing VaultEpochStats at address {}",
+        vault_epoch_stats.key
+    );
+    create_account(
+        payer,
+        vault_epoch_stats,
+        system_program,
+        program_id,
+        &get_rent()?,
+        8_u64
+            .checked_add(size_of::<VaultEpochStats>() as u64)
+            .unwrap(),
+        &vault_epoch_stats_seeds,
+    )?;
+
+    let mut vault_epoch_stats_data = vault_epoch_stats.try_borrow_mut_data()?;
+    vault_epoch_stats_data[0] = VaultEpochStats::DISCRIMINATOR;
+    let vault_epoch_stats =
+        VaultEpochStats::try_from_slice_unchecked_mut(&mut vault_epoch_stats_data)?;
+    *vault_epoch_stats = VaultEpochStats::new(*vault_info.key, vault_epoch_stats_bump);
+
+    vault.set_epoch_stats_enabled();
+
+    Ok(())
+}
+
+/// Applies `record` to the vault's [`VaultEpochStats`] if the vault has one.
+///
+/// The account is looked up by its PDA among `optional_accounts`, so it can be passed anywhere
+/// after the instruction's other optional accounts, and is required once the vault enabled it.
+pub fn record_vault_epoch_stats(
+    program_id: &Pubkey,
+    vault_info: &AccountInfo,
+    vault: &Vault,
+    optional_accounts: &[AccountInfo],
+    record: impl FnOnce(&mut VaultEpochStats) -> Result<(), VaultError>,
+) -> ProgramResult {
+    if !vault.epoch_stats_enabled() {
+        return Ok(());
+    }
+
+    let vault_epoch_stats_pubkey =
+        VaultEpochStats::find_program_address(program_id, vault_info.key).0;
+    let Some(vault_epoch_stats) = optional_accounts
+        .iter()
+        .find(|account| account.key.eq(&vault_epoch_stats_pubkey))
+    else {
+        msg!("Vault has epoch stats enabled, the vault epoch stats account is required");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    VaultEpochStats::load(program_id, vault_epoch_stats, vault_info, true)?;
+    let mut vault_epoch_stats_data = vault_epoch_stats.data.borrow_mut();
+    let vault_epoch_stats =
+        VaultEpochStats::try_from_slice_unchecked_mut(&mut vault_epoch_stats_data)?;
+    record(vault_epoch_stats)?;
+
+    Ok(())
+}
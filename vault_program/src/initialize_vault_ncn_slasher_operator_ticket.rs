@@ -4,15 +4,17 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
 };
 use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_vault_core::{
     config::Config, vault::Vault, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
     vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
 };
+use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// Instruction: [`crate::VaultInstruction::InitializeVaultNcnSlasherOperatorTicket`]
@@ -46,7 +48,7 @@ pub fn process_initialize_vault_ncn_slasher_operator_ticket(
     load_signer(payer, false)?;
     load_system_program(system_program)?;
 
-    let ncn_epoch = Clock::get()?
+    let ncn_epoch = get_clock()?
         .slot
         .checked_div(config.epoch_length())
         .unwrap();
@@ -70,11 +72,11 @@ pub fn process_initialize_vault_ncn_slasher_operator_ticket(
         .ne(&vault_ncn_slasher_operator_ticket_pubkey)
     {
         msg!("Vault NCN slasher operator ticket is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
     }
 
     // The vault shall be up-to-date before adding support for the NCN slasher operator
-    vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
 
     msg!(
         "Initializing vault NCN slasher operator ticket at address {}",
@@ -85,7 +87,7 @@ pub fn process_initialize_vault_ncn_slasher_operator_ticket(
         vault_ncn_slasher_operator_ticket,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64
             .checked_add(size_of::<VaultNcnSlasherOperatorTicket>() as u64)
             .unwrap(),
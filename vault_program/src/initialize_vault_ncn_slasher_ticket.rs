@@ -4,14 +4,16 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
 };
 use jito_restaking_core::{ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket};
 use jito_vault_core::{
     config::Config, vault::Vault, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
 };
+use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// Processes the register slasher instruction: [`crate::VaultInstruction::InitializeVaultNcnSlasherTicket`]
@@ -59,13 +61,13 @@ pub fn process_initialize_vault_ncn_slasher_ticket(
     vault_ncn_slasher_ticket_seeds.push(vec![vault_ncn_slasher_ticket_bump]);
     if vault_ncn_slasher_ticket_pubkey.ne(vault_ncn_slasher_ticket.key) {
         msg!("Vault NCN slasher ticket is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
     }
 
-    let slot = Clock::get()?.slot;
+    let slot = get_clock()?.slot;
 
     vault.check_slasher_admin(vault_slasher_admin.key)?;
-    vault.check_update_state_ok(slot, config.epoch_length())?;
+    vault.check_update_state_ok(slot, vault.epoch_length(config.epoch_length()))?;
 
     msg!(
         "Initializing VaultNcnSlasherTicket at address {}",
@@ -76,7 +78,7 @@ pub fn process_initialize_vault_ncn_slasher_ticket(
         vault_ncn_slasher_ticket,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64
             .checked_add(size_of::<VaultNcnSlasherTicket>() as u64)
             .unwrap(),
@@ -95,7 +97,8 @@ pub fn process_initialize_vault_ncn_slasher_ticket(
         *vault_info.key,
         *ncn.key,
         *slasher.key,
-        ncn_vault_slasher_ticket.max_slashable_per_epoch(),
+        ncn_vault_slasher_ticket
+            .max_slashable_per_epoch_at(slot.checked_div(config.epoch_length()).unwrap()),
         vault.slasher_count(),
         vault_ncn_slasher_ticket_bump,
         slot,
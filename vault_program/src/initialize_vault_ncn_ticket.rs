@@ -4,12 +4,14 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
 };
 use jito_restaking_core::{ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
 use jito_vault_core::{config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket};
+use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// Adds an NCN to the vault NCN list, which means delegation applied to operators staking to the NCN
@@ -55,13 +57,13 @@ pub fn process_initialize_vault_ncn_ticket(
     vault_ncn_ticket_seeds.push(vec![vault_ncn_ticket_bump]);
     if vault_ncn_ticket_pubkey.ne(vault_ncn_ticket.key) {
         msg!("Vault NCN ticket is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
     }
 
-    let slot = Clock::get()?.slot;
+    let slot = get_clock()?.slot;
 
     vault.check_ncn_admin(vault_ncn_admin.key)?;
-    vault.check_update_state_ok(slot, config.epoch_length())?;
+    vault.check_update_state_ok(slot, vault.epoch_length(config.epoch_length()))?;
 
     // The NcnVaultTicket shall be active
     msg!(
@@ -73,7 +75,7 @@ pub fn process_initialize_vault_ncn_ticket(
         vault_ncn_ticket,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64
             .checked_add(size_of::<VaultNcnTicket>() as u64)
             .unwrap(),
@@ -4,14 +4,16 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
 };
 use jito_restaking_core::{operator::Operator, operator_vault_ticket::OperatorVaultTicket};
 use jito_vault_core::{
     config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
 };
+use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// Instruction: [`crate::VaultInstruction::InitializeVaultOperatorDelegation`]
@@ -53,13 +55,13 @@ pub fn process_initialize_vault_operator_delegation(
     vault_operator_delegation_seeds.push(vec![vault_operator_delegation_bump]);
     if vault_operator_delegation_pubkey.ne(vault_operator_delegation.key) {
         msg!("Vault operator delegation is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
     }
 
-    let slot = Clock::get()?.slot;
+    let slot = get_clock()?.slot;
 
     vault.check_operator_admin(vault_operator_admin.key)?;
-    vault.check_update_state_ok(slot, config.epoch_length())?;
+    vault.check_update_state_ok(slot, vault.epoch_length(config.epoch_length()))?;
 
     msg!(
         "Initializing VaultOperatorDelegation at address {}",
@@ -70,7 +72,7 @@ pub fn process_initialize_vault_operator_delegation(
         vault_operator_delegation,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64
             .checked_add(size_of::<VaultOperatorDelegation>() as u64)
             .unwrap(),
@@ -0,0 +1,73 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{
+    load_associated_token_program, load_signer, load_system_program, load_token_mint,
+    load_token_program,
+};
+use jito_vault_core::vault::Vault;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
+
+/// Processes the initialize vault token account instruction: [`crate::VaultInstruction::InitializeVaultTokenAccount`]
+///
+/// Specification:
+/// - Anyone can create the vault token account and pay for its rent
+/// - The supported mint shall be the vault's supported mint
+/// - The vault token account shall be the vault's associated token account for the supported mint,
+///   the only vault token account [`crate::VaultInstruction::MintTo`] accepts
+/// - The vault token account shall be created by the associated token account program, and the
+///   instruction shall succeed without changes if it already exists
+pub fn process_initialize_vault_token_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [vault_info, vault_token_account, supported_mint, payer, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    load_token_mint(supported_mint)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+    load_token_program(token_program)?;
+    load_associated_token_program(associated_token_program)?;
+
+    vault.check_supported_mint(supported_mint.key)?;
+    if vault_token_account.key.ne(&get_associated_token_address(
+        vault_info.key,
+        supported_mint.key,
+    )) {
+        msg!("Vault token account is not the associated token account");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    drop(vault_data);
+
+    msg!(
+        "Initializing vault token account {}",
+        vault_token_account.key
+    );
+    invoke(
+        &create_associated_token_account_idempotent(
+            payer.key,
+            vault_info.key,
+            supported_mint.key,
+            &spl_token::id(),
+        ),
+        &[
+            payer.clone(),
+            vault_token_account.clone(),
+            vault_info.clone(),
+            supported_mint.clone(),
+            system_program.clone(),
+            token_program.clone(),
+        ],
+    )
+}
@@ -4,17 +4,18 @@ use jito_bytemuck::{AccountDeserialize, Discriminator};
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
 };
 use jito_vault_core::{
     config::Config, vault::Vault, vault_update_state_tracker::VaultUpdateStateTracker,
 };
 use jito_vault_sdk::{error::VaultError, instruction::WithdrawalAllocationMethod};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
-/// Instruction: [`crate::VaultInstruction::InitializeVaultUpdateDelegationsTicket`]
+/// Instruction: [`crate::VaultInstruction::InitializeVaultUpdateStateTracker`]
 /// Initializes a new [`VaultUpdateStateTracker`] account, which is used to track the delegations
 /// that are to be updated at the epoch boundary.
 pub fn process_initialize_vault_update_state_tracker(
@@ -36,9 +37,9 @@ pub fn process_initialize_vault_update_state_tracker(
     load_system_program(system_program)?;
 
     // The VaultUpdateStateTracker shall be at the canonical PDA
-    let ncn_epoch = Clock::get()?
+    let ncn_epoch = get_clock()?
         .slot
-        .checked_div(config.epoch_length())
+        .checked_div(vault.epoch_length(config.epoch_length()))
         .unwrap();
     let (
         vault_update_state_tracker_pubkey,
@@ -47,12 +48,12 @@ pub fn process_initialize_vault_update_state_tracker(
     ) = VaultUpdateStateTracker::find_program_address(program_id, vault_info.key, ncn_epoch);
     vault_update_state_tracker_seeds.push(vec![vault_update_state_tracker_bump]);
     if vault_update_state_tracker_pubkey.ne(vault_update_state_tracker.key) {
-        msg!("Vault update delegations ticket is not at the correct PDA");
-        return Err(ProgramError::InvalidAccountData);
+        msg!("Vault update state tracker is not at the correct PDA");
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
     }
 
     if vault
-        .check_update_state_ok(Clock::get()?.slot, config.epoch_length())
+        .check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))
         .is_ok()
     {
         msg!("Vault update state tracker is not needed");
@@ -60,7 +61,7 @@ pub fn process_initialize_vault_update_state_tracker(
     }
 
     msg!(
-        "Initializing VaultUpdateDelegationsTicket at address {}",
+        "Initializing VaultUpdateStateTracker at address {}",
         vault_update_state_tracker.key
     );
     create_account(
@@ -68,15 +69,18 @@ pub fn process_initialize_vault_update_state_tracker(
         vault_update_state_tracker,
         system_program,
         program_id,
-        &Rent::get()?,
+        &get_rent()?,
         8_u64
             .checked_add(size_of::<VaultUpdateStateTracker>() as u64)
             .unwrap(),
         &vault_update_state_tracker_seeds,
     )?;
 
-    let additional_assets_need_unstaking =
-        vault.calculate_assets_needed_for_withdrawals(Clock::get()?.slot, config.epoch_length())?;
+    let additional_assets_need_unstaking = vault.calculate_assets_needed_for_withdrawals(
+        get_clock()?.slot,
+        vault.epoch_length(config.epoch_length()),
+        config.delegation_cooldown_epochs(),
+    )?;
 
     let mut vault_update_state_tracker_data = vault_update_state_tracker.try_borrow_mut_data()?;
     vault_update_state_tracker_data[0] = VaultUpdateStateTracker::DISCRIMINATOR;
@@ -0,0 +1,43 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the initiate vault shutdown instruction: [`crate::VaultInstruction::InitiateVaultShutdown`]
+///
+/// Shutting down is the first step to retiring a vault. From then on, each vault update cools down
+/// all of the vault's delegations, the remaining VRT holders burn their VRT for their pro-rata share
+/// of the vault, and once it's empty the admin closes it with [`crate::VaultInstruction::CloseVault`].
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The vault shall not already be shut down
+/// - The vault shall reject MintTo, MintToWithSol, AddDelegation, RebalanceDelegations and SetFees
+///   once shut down, and a shutdown can't be undone
+/// - The withdrawal fee shall be waived, including any pending withdrawal fee change
+/// - CrankVaultUpdateStateTracker shall cool down all the stake on every operator delegation
+pub fn process_initiate_vault_shutdown(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, vault_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_admin, false)?;
+
+    vault.check_admin(vault_admin.key)?;
+
+    let slot = get_clock()?.slot;
+    vault.shut_down(slot)?;
+    msg!("Vault {} shut down at slot {}", vault_info.key, slot);
+
+    Ok(())
+}
@@ -1,30 +1,89 @@
 mod add_delegation;
+mod add_to_deposit_allowlist;
+mod admin_set_config_paused;
+mod admin_set_crank_reward;
+mod admin_set_delegation_cooldown;
+mod admin_set_protocol_fee;
+mod admin_set_slash_veto;
+mod admin_set_vault_epoch_length_bounds;
+mod ban_operator;
 mod burn;
+mod burn_delegated;
+mod burn_or_enqueue_withdrawal;
 mod burn_withdrawal_ticket;
+mod cancel_admin_burn;
+mod cancel_mint_authority_handoff;
 mod change_withdrawal_ticket_owner;
+mod claim_rewards;
+mod close_deposit_receipt;
 mod close_update_state_tracker;
+mod close_vault;
 mod cooldown_delegation;
 mod cooldown_vault_ncn_slasher_ticket;
 mod cooldown_vault_ncn_ticket;
+mod crank_operator_stake_weight;
 mod crank_vault_update_state_tracker;
 mod create_token_metadata;
+mod distribute_ncn_rewards;
+mod enable_admin_burn;
 mod enqueue_withdrawal;
+mod execute_admin_burn;
+mod execute_mint_authority_handoff;
+mod execute_slash;
+mod fund_insurance;
 mod initialize_config;
+mod initialize_delegation_strategy;
+mod initialize_ncn_reward_router;
+mod initialize_operator_stake_weight;
+mod initialize_reward_distribution;
 mod initialize_vault;
+mod initialize_vault_epoch_stats;
 mod initialize_vault_ncn_slasher_operator_ticket;
 mod initialize_vault_ncn_slasher_ticket;
 mod initialize_vault_ncn_ticket;
 mod initialize_vault_operator_delegation;
+mod initialize_vault_token_account;
 mod initialize_vault_update_state_tracker;
 mod initialize_vault_with_mint;
+mod initiate_vault_shutdown;
+mod migrate_account;
+mod migrate_to_vault;
 mod mint_to;
+mod mint_to_with_sol;
+mod propose_admin_burn;
+mod propose_mint_authority_handoff;
+mod propose_slash;
+mod rebalance_delegations;
+mod reconcile_vault_balance;
+mod remove_from_deposit_allowlist;
+mod remove_slasher;
 mod set_admin;
 mod set_capacity;
+mod set_delegation_strategy_weight;
+mod set_deposit_limits;
+mod set_deposit_mode;
+mod set_fee_wallet;
 mod set_fees;
+mod set_flash_deposit_protection;
+mod set_hook_program;
+mod set_insurance_params;
+mod set_max_total_slashable_per_epoch;
+mod set_migration_partner;
+mod set_min_delegation_amount;
+mod set_operator_delegation_paused;
+mod set_operator_max_delegation;
 mod set_secondary_admin;
+mod set_slash_dispute_haircut;
+mod set_vault_epoch_length;
+mod set_vault_paused;
+mod set_withdrawal_delay;
+mod set_withdrawal_ticket_auto_claim_fee;
 mod slash;
+mod snapshot_vault_operator_delegation;
+mod unban_operator;
 mod update_token_metadata;
 mod update_vault_balance;
+mod veto_slash;
 mod warmup_vault_ncn_slasher_ticket;
 mod warmup_vault_ncn_ticket;
 mod withdrawal_asset;
@@ -40,27 +99,76 @@ use solana_program::{
 use solana_security_txt::security_txt;
 
 use crate::{
-    add_delegation::process_add_delegation, burn::process_burn,
+    add_delegation::process_add_delegation,
+    add_to_deposit_allowlist::process_add_to_deposit_allowlist,
+    admin_set_config_paused::process_admin_set_config_paused,
+    admin_set_crank_reward::process_admin_set_crank_reward,
+    admin_set_delegation_cooldown::process_admin_set_delegation_cooldown,
+    admin_set_protocol_fee::process_admin_set_protocol_fee,
+    admin_set_slash_veto::process_admin_set_slash_veto,
+    admin_set_vault_epoch_length_bounds::process_admin_set_vault_epoch_length_bounds,
+    ban_operator::process_ban_operator, burn::process_burn, burn_delegated::process_burn_delegated,
+    burn_or_enqueue_withdrawal::process_burn_or_enqueue_withdrawal,
     burn_withdrawal_ticket::process_burn_withdrawal_ticket,
+    cancel_admin_burn::process_cancel_admin_burn,
+    cancel_mint_authority_handoff::process_cancel_mint_authority_handoff,
     change_withdrawal_ticket_owner::process_change_withdrawal_ticket_owner,
+    claim_rewards::process_claim_rewards, close_deposit_receipt::process_close_deposit_receipt,
     close_update_state_tracker::process_close_vault_update_state_tracker,
-    cooldown_delegation::process_cooldown_delegation,
+    close_vault::process_close_vault, cooldown_delegation::process_cooldown_delegation,
     cooldown_vault_ncn_slasher_ticket::process_cooldown_vault_ncn_slasher_ticket,
     cooldown_vault_ncn_ticket::process_cooldown_vault_ncn_ticket,
+    crank_operator_stake_weight::process_crank_operator_stake_weight,
     crank_vault_update_state_tracker::process_crank_vault_update_state_tracker,
     create_token_metadata::process_create_token_metadata,
-    enqueue_withdrawal::process_enqueue_withdrawal, initialize_config::process_initialize_config,
+    distribute_ncn_rewards::process_distribute_ncn_rewards,
+    enable_admin_burn::process_enable_admin_burn, enqueue_withdrawal::process_enqueue_withdrawal,
+    execute_admin_burn::process_execute_admin_burn,
+    execute_mint_authority_handoff::process_execute_mint_authority_handoff,
+    execute_slash::process_execute_slash, fund_insurance::process_fund_insurance,
+    initialize_config::process_initialize_config,
+    initialize_delegation_strategy::process_initialize_delegation_strategy,
+    initialize_ncn_reward_router::process_initialize_ncn_reward_router,
+    initialize_operator_stake_weight::process_initialize_operator_stake_weight,
+    initialize_reward_distribution::process_initialize_reward_distribution,
     initialize_vault::process_initialize_vault,
+    initialize_vault_epoch_stats::process_initialize_vault_epoch_stats,
     initialize_vault_ncn_slasher_operator_ticket::process_initialize_vault_ncn_slasher_operator_ticket,
     initialize_vault_ncn_slasher_ticket::process_initialize_vault_ncn_slasher_ticket,
     initialize_vault_ncn_ticket::process_initialize_vault_ncn_ticket,
     initialize_vault_operator_delegation::process_initialize_vault_operator_delegation,
+    initialize_vault_token_account::process_initialize_vault_token_account,
     initialize_vault_update_state_tracker::process_initialize_vault_update_state_tracker,
-    initialize_vault_with_mint::process_initialize_vault_with_mint, mint_to::process_mint,
-    set_admin::process_set_admin, set_capacity::process_set_deposit_capacity,
-    set_fees::process_set_fees, set_secondary_admin::process_set_secondary_admin,
-    slash::process_slash, update_token_metadata::process_update_token_metadata,
-    update_vault_balance::process_update_vault_balance,
+    initialize_vault_with_mint::process_initialize_vault_with_mint,
+    initiate_vault_shutdown::process_initiate_vault_shutdown,
+    migrate_account::process_migrate_account, migrate_to_vault::process_migrate_to_vault,
+    mint_to::process_mint, mint_to_with_sol::process_mint_with_sol,
+    propose_admin_burn::process_propose_admin_burn,
+    propose_mint_authority_handoff::process_propose_mint_authority_handoff,
+    propose_slash::process_propose_slash, rebalance_delegations::process_rebalance_delegations,
+    reconcile_vault_balance::process_reconcile_vault_balance,
+    remove_from_deposit_allowlist::process_remove_from_deposit_allowlist,
+    remove_slasher::process_remove_slasher, set_admin::process_set_admin,
+    set_capacity::process_set_deposit_capacity,
+    set_delegation_strategy_weight::process_set_delegation_strategy_weight,
+    set_deposit_limits::process_set_deposit_limits, set_deposit_mode::process_set_deposit_mode,
+    set_fee_wallet::process_set_fee_wallet, set_fees::process_set_fees,
+    set_flash_deposit_protection::process_set_flash_deposit_protection,
+    set_hook_program::process_set_hook_program, set_insurance_params::process_set_insurance_params,
+    set_max_total_slashable_per_epoch::process_set_max_total_slashable_per_epoch,
+    set_migration_partner::process_set_migration_partner,
+    set_min_delegation_amount::process_set_min_delegation_amount,
+    set_operator_delegation_paused::process_set_operator_delegation_paused,
+    set_operator_max_delegation::process_set_operator_max_delegation,
+    set_secondary_admin::process_set_secondary_admin,
+    set_slash_dispute_haircut::process_set_slash_dispute_haircut,
+    set_vault_epoch_length::process_set_vault_epoch_length,
+    set_vault_paused::process_set_vault_paused, set_withdrawal_delay::process_set_withdrawal_delay,
+    set_withdrawal_ticket_auto_claim_fee::process_set_withdrawal_ticket_auto_claim_fee,
+    slash::process_slash,
+    snapshot_vault_operator_delegation::process_snapshot_vault_operator_delegation,
+    unban_operator::process_unban_operator, update_token_metadata::process_update_token_metadata,
+    update_vault_balance::process_update_vault_balance, veto_slash::process_veto_slash,
     warmup_vault_ncn_slasher_ticket::process_warmup_vault_ncn_slasher_ticket,
     warmup_vault_ncn_ticket::process_warmup_vault_ncn_ticket,
     withdrawal_asset::process_withdrawal_asset,
@@ -77,12 +185,28 @@ security_txt! {
     policy: "https://github.com/jito-foundation/restaking",
     // Optional Fields
     preferred_languages: "en",
-    source_code: "https://github.com/jito-foundation/restaking"
+    source_code: "https://github.com/jito-foundation/restaking",
+    source_release: env!("CARGO_PKG_VERSION"),
+    source_revision: env!("GIT_SHA")
 }
 
 #[cfg(not(feature = "no-entrypoint"))]
 solana_program::entrypoint!(process_instruction);
 
+/// Processes an instruction at `clock`, for programs built with the `accounting-only` feature
+/// where there's no clock sysvar to read
+#[cfg(feature = "accounting-only")]
+pub fn process_instruction_with_clock(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+    clock: &solana_program::clock::Clock,
+) -> ProgramResult {
+    jito_jsm_core::sysvar::with_clock(clock, || {
+        process_instruction(program_id, accounts, instruction_data)
+    })
+}
+
 pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -122,6 +246,10 @@ pub fn process_instruction(
             msg!("Instruction: InitializeVaultWithMint");
             process_initialize_vault_with_mint(program_id, accounts)
         }
+        VaultInstruction::InitializeVaultTokenAccount => {
+            msg!("Instruction: InitializeVaultTokenAccount");
+            process_initialize_vault_token_account(program_id, accounts)
+        }
         VaultInstruction::InitializeVaultNcnTicket => {
             msg!("Instruction: InitializeVaultNcnTicket");
             process_initialize_vault_ncn_ticket(program_id, accounts)
@@ -138,6 +266,10 @@ pub fn process_instruction(
             msg!("Instruction: InitializeVaultNcnSlasherOperatorTicket");
             process_initialize_vault_ncn_slasher_operator_ticket(program_id, accounts)
         }
+        VaultInstruction::InitializeVaultEpochStats => {
+            msg!("Instruction: InitializeVaultEpochStats");
+            process_initialize_vault_epoch_stats(program_id, accounts)
+        }
         // ------------------------------------------
         // Vault administration
         // ------------------------------------------
@@ -171,6 +303,139 @@ pub fn process_instruction(
                 reward_fee_bps,
             )
         }
+        VaultInstruction::SetVaultPaused { is_paused } => {
+            msg!("Instruction: SetVaultPaused");
+            process_set_vault_paused(program_id, accounts, is_paused)
+        }
+        VaultInstruction::SetFeeWallet => {
+            msg!("Instruction: SetFeeWallet");
+            process_set_fee_wallet(program_id, accounts)
+        }
+        VaultInstruction::CloseVault => {
+            msg!("Instruction: CloseVault");
+            process_close_vault(program_id, accounts)
+        }
+        VaultInstruction::CloseDepositReceipt => {
+            msg!("Instruction: CloseDepositReceipt");
+            process_close_deposit_receipt(program_id, accounts)
+        }
+        VaultInstruction::InitiateVaultShutdown => {
+            msg!("Instruction: InitiateVaultShutdown");
+            process_initiate_vault_shutdown(program_id, accounts)
+        }
+        VaultInstruction::AdminSetProtocolFee { protocol_fee_bps } => {
+            msg!("Instruction: AdminSetProtocolFee");
+            process_admin_set_protocol_fee(program_id, accounts, protocol_fee_bps)
+        }
+        VaultInstruction::AdminSetDelegationCooldown {
+            delegation_cooldown_epochs,
+        } => {
+            msg!("Instruction: AdminSetDelegationCooldown");
+            process_admin_set_delegation_cooldown(program_id, accounts, delegation_cooldown_epochs)
+        }
+        VaultInstruction::AdminSetVaultEpochLengthBounds {
+            min_vault_epoch_length,
+            max_vault_epoch_length,
+        } => {
+            msg!("Instruction: AdminSetVaultEpochLengthBounds");
+            process_admin_set_vault_epoch_length_bounds(
+                program_id,
+                accounts,
+                min_vault_epoch_length,
+                max_vault_epoch_length,
+            )
+        }
+        VaultInstruction::AdminSetSlashVeto {
+            slash_veto_window_slots,
+        } => {
+            msg!("Instruction: AdminSetSlashVeto");
+            process_admin_set_slash_veto(program_id, accounts, slash_veto_window_slots)
+        }
+        VaultInstruction::AdminSetConfigPaused { is_paused } => {
+            msg!("Instruction: AdminSetConfigPaused");
+            process_admin_set_config_paused(program_id, accounts, is_paused)
+        }
+        VaultInstruction::AdminSetCrankReward {
+            crank_reward_lamports,
+            crank_reward_bps,
+        } => {
+            msg!("Instruction: AdminSetCrankReward");
+            process_admin_set_crank_reward(
+                program_id,
+                accounts,
+                crank_reward_lamports,
+                crank_reward_bps,
+            )
+        }
+        // ------------------------------------------
+        // Vault deposit allowlist
+        // ------------------------------------------
+        VaultInstruction::SetDepositMode { deposit_mode } => {
+            msg!("Instruction: SetDepositMode");
+            process_set_deposit_mode(program_id, accounts, deposit_mode)
+        }
+        VaultInstruction::AddToDepositAllowlist => {
+            msg!("Instruction: AddToDepositAllowlist");
+            process_add_to_deposit_allowlist(program_id, accounts)
+        }
+        VaultInstruction::RemoveFromDepositAllowlist => {
+            msg!("Instruction: RemoveFromDepositAllowlist");
+            process_remove_from_deposit_allowlist(program_id, accounts)
+        }
+        VaultInstruction::SetDepositLimits {
+            max_deposit_per_transaction,
+            max_deposit_per_wallet,
+        } => {
+            msg!("Instruction: SetDepositLimits");
+            process_set_deposit_limits(
+                program_id,
+                accounts,
+                max_deposit_per_transaction,
+                max_deposit_per_wallet,
+            )
+        }
+        VaultInstruction::SetFlashDepositProtection { enabled } => {
+            msg!("Instruction: SetFlashDepositProtection");
+            process_set_flash_deposit_protection(program_id, accounts, enabled)
+        }
+        VaultInstruction::SetHookProgram => {
+            msg!("Instruction: SetHookProgram");
+            process_set_hook_program(program_id, accounts)
+        }
+        // ------------------------------------------
+        // VRT mint authority handoff
+        // ------------------------------------------
+        VaultInstruction::ProposeMintAuthorityHandoff => {
+            msg!("Instruction: ProposeMintAuthorityHandoff");
+            process_propose_mint_authority_handoff(program_id, accounts)
+        }
+        VaultInstruction::CancelMintAuthorityHandoff => {
+            msg!("Instruction: CancelMintAuthorityHandoff");
+            process_cancel_mint_authority_handoff(program_id, accounts)
+        }
+        VaultInstruction::ExecuteMintAuthorityHandoff => {
+            msg!("Instruction: ExecuteMintAuthorityHandoff");
+            process_execute_mint_authority_handoff(program_id, accounts)
+        }
+        // ------------------------------------------
+        // Admin burn
+        // ------------------------------------------
+        VaultInstruction::EnableAdminBurn => {
+            msg!("Instruction: EnableAdminBurn");
+            process_enable_admin_burn(program_id, accounts)
+        }
+        VaultInstruction::ProposeAdminBurn => {
+            msg!("Instruction: ProposeAdminBurn");
+            process_propose_admin_burn(program_id, accounts)
+        }
+        VaultInstruction::CancelAdminBurn => {
+            msg!("Instruction: CancelAdminBurn");
+            process_cancel_admin_burn(program_id, accounts)
+        }
+        VaultInstruction::ExecuteAdminBurn => {
+            msg!("Instruction: ExecuteAdminBurn");
+            process_execute_admin_burn(program_id, accounts)
+        }
         // ------------------------------------------
         // Vault minting and burning
         // ------------------------------------------
@@ -181,6 +446,13 @@ pub fn process_instruction(
             msg!("Instruction: MintTo");
             process_mint(program_id, accounts, amount_in, min_amount_out)
         }
+        VaultInstruction::MintToWithSol {
+            amount_in,
+            min_amount_out,
+        } => {
+            msg!("Instruction: MintToWithSol");
+            process_mint_with_sol(program_id, accounts, amount_in, min_amount_out)
+        }
         VaultInstruction::Burn {
             amount_in,
             min_amount_out,
@@ -188,10 +460,24 @@ pub fn process_instruction(
             msg!("Instruction: Burn");
             process_burn(program_id, accounts, amount_in, min_amount_out)
         }
+        VaultInstruction::BurnDelegated {
+            amount_in,
+            min_amount_out,
+        } => {
+            msg!("Instruction: BurnDelegated");
+            process_burn_delegated(program_id, accounts, amount_in, min_amount_out)
+        }
         VaultInstruction::EnqueueWithdrawal { amount } => {
             msg!("Instruction: EnqueueWithdrawal");
             process_enqueue_withdrawal(program_id, accounts, amount)
         }
+        VaultInstruction::BurnOrEnqueueWithdrawal {
+            amount_in,
+            min_amount_out,
+        } => {
+            msg!("Instruction: BurnOrEnqueueWithdrawal");
+            process_burn_or_enqueue_withdrawal(program_id, accounts, amount_in, min_amount_out)
+        }
         VaultInstruction::ChangeWithdrawalTicketOwner => {
             msg!("Instruction: ChangeWithdrawalTicketOwner");
             process_change_withdrawal_ticket_owner(program_id, accounts)
@@ -200,6 +486,20 @@ pub fn process_instruction(
             msg!("Instruction: BurnWithdrawTicket");
             process_burn_withdrawal_ticket(program_id, accounts, min_amount_out)
         }
+        VaultInstruction::SetWithdrawalTicketAutoClaimFee { fee_bps } => {
+            msg!("Instruction: SetWithdrawalTicketAutoClaimFee");
+            process_set_withdrawal_ticket_auto_claim_fee(program_id, accounts, fee_bps)
+        }
+        VaultInstruction::SetWithdrawalDelay {
+            withdrawal_delay_epochs,
+        } => {
+            msg!("Instruction: SetWithdrawalDelay");
+            process_set_withdrawal_delay(program_id, accounts, withdrawal_delay_epochs)
+        }
+        VaultInstruction::SetVaultEpochLength { epoch_length_slots } => {
+            msg!("Instruction: SetVaultEpochLength");
+            process_set_vault_epoch_length(program_id, accounts, epoch_length_slots)
+        }
         // ------------------------------------------
         // Vault-NCN operations
         // ------------------------------------------
@@ -222,6 +522,10 @@ pub fn process_instruction(
             msg!("Instruction: CooldownVaultNcnSlasherTicket");
             process_cooldown_vault_ncn_slasher_ticket(program_id, accounts)
         }
+        VaultInstruction::RemoveSlasher => {
+            msg!("Instruction: RemoveSlasher");
+            process_remove_slasher(program_id, accounts)
+        }
         // ------------------------------------------
         // Vault delegation
         // ------------------------------------------
@@ -233,10 +537,87 @@ pub fn process_instruction(
             msg!("Instruction: CooldownDelegation");
             process_cooldown_delegation(program_id, accounts, amount)
         }
+        VaultInstruction::SetMaxTotalSlashablePerEpoch {
+            max_total_slashable_bps_per_epoch,
+        } => {
+            msg!("Instruction: SetMaxTotalSlashablePerEpoch");
+            process_set_max_total_slashable_per_epoch(
+                program_id,
+                accounts,
+                max_total_slashable_bps_per_epoch,
+            )
+        }
+        VaultInstruction::SetMigrationPartner => {
+            msg!("Instruction: SetMigrationPartner");
+            process_set_migration_partner(program_id, accounts)
+        }
+        VaultInstruction::MigrateToVault {
+            amount_in,
+            min_amount_out,
+        } => {
+            msg!("Instruction: MigrateToVault");
+            process_migrate_to_vault(program_id, accounts, amount_in, min_amount_out)
+        }
+        VaultInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+        VaultInstruction::InitializeOperatorStakeWeight => {
+            msg!("Instruction: InitializeOperatorStakeWeight");
+            process_initialize_operator_stake_weight(program_id, accounts)
+        }
+        VaultInstruction::CrankOperatorStakeWeight => {
+            msg!("Instruction: CrankOperatorStakeWeight");
+            process_crank_operator_stake_weight(program_id, accounts)
+        }
+        VaultInstruction::SetMinDelegationAmount { amount } => {
+            msg!("Instruction: SetMinDelegationAmount");
+            process_set_min_delegation_amount(program_id, accounts, amount)
+        }
+        VaultInstruction::SetOperatorMaxDelegation {
+            max_delegation_amount,
+        } => {
+            msg!("Instruction: SetOperatorMaxDelegation");
+            process_set_operator_max_delegation(program_id, accounts, max_delegation_amount)
+        }
+        VaultInstruction::SetOperatorDelegationPaused { is_paused } => {
+            msg!("Instruction: SetOperatorDelegationPaused");
+            process_set_operator_delegation_paused(program_id, accounts, is_paused)
+        }
+        VaultInstruction::BanOperator { reason } => {
+            msg!("Instruction: BanOperator");
+            process_ban_operator(program_id, accounts, reason)
+        }
+        VaultInstruction::UnbanOperator => {
+            msg!("Instruction: UnbanOperator");
+            process_unban_operator(program_id, accounts)
+        }
+        VaultInstruction::SetSlashDisputeHaircut { enabled } => {
+            msg!("Instruction: SetSlashDisputeHaircut");
+            process_set_slash_dispute_haircut(program_id, accounts, enabled)
+        }
+        VaultInstruction::InitializeDelegationStrategy {
+            max_movement_per_epoch,
+        } => {
+            msg!("Instruction: InitializeDelegationStrategy");
+            process_initialize_delegation_strategy(program_id, accounts, max_movement_per_epoch)
+        }
+        VaultInstruction::SetDelegationStrategyWeight { weight } => {
+            msg!("Instruction: SetDelegationStrategyWeight");
+            process_set_delegation_strategy_weight(program_id, accounts, weight)
+        }
+        VaultInstruction::RebalanceDelegations => {
+            msg!("Instruction: RebalanceDelegations");
+            process_rebalance_delegations(program_id, accounts)
+        }
         VaultInstruction::UpdateVaultBalance => {
             msg!("Instruction: UpdateVaultBalance");
             process_update_vault_balance(program_id, accounts)
         }
+        VaultInstruction::ReconcileVaultBalance { max_adjustment } => {
+            msg!("Instruction: ReconcileVaultBalance");
+            process_reconcile_vault_balance(program_id, accounts, max_adjustment)
+        }
         VaultInstruction::InitializeVaultUpdateStateTracker {
             withdrawal_allocation_method,
         } => {
@@ -251,6 +632,10 @@ pub fn process_instruction(
             msg!("Instruction: CrankVaultUpdateStateTracker");
             process_crank_vault_update_state_tracker(program_id, accounts)
         }
+        VaultInstruction::SnapshotVaultOperatorDelegation => {
+            msg!("Instruction: SnapshotVaultOperatorDelegation");
+            process_snapshot_vault_operator_delegation(program_id, accounts)
+        }
         VaultInstruction::CloseVaultUpdateStateTracker { ncn_epoch } => {
             msg!("Instruction: CloseVaultUpdateStateTracker");
             process_close_vault_update_state_tracker(program_id, accounts, ncn_epoch)
@@ -262,6 +647,21 @@ pub fn process_instruction(
             msg!("Instruction: Slash");
             process_slash(program_id, accounts, amount)
         }
+        VaultInstruction::ProposeSlash {
+            amount,
+            evidence_hash,
+        } => {
+            msg!("Instruction: ProposeSlash");
+            process_propose_slash(program_id, accounts, amount, evidence_hash)
+        }
+        VaultInstruction::VetoSlash => {
+            msg!("Instruction: VetoSlash");
+            process_veto_slash(program_id, accounts)
+        }
+        VaultInstruction::ExecuteSlash => {
+            msg!("Instruction: ExecuteSlash");
+            process_execute_slash(program_id, accounts)
+        }
         // ------------------------------------------
         // VRT metadata
         // ------------------------------------------
@@ -273,5 +673,45 @@ pub fn process_instruction(
             msg!("Instruction: UpdateTokenMetadata");
             process_update_token_metadata(program_id, accounts, name, symbol, uri)
         }
+        // ------------------------------------------
+        // Reward distribution
+        // ------------------------------------------
+        VaultInstruction::InitializeRewardDistribution {
+            epoch,
+            merkle_root,
+            total_rewards,
+        } => {
+            msg!("Instruction: InitializeRewardDistribution");
+            process_initialize_reward_distribution(
+                program_id,
+                accounts,
+                epoch,
+                merkle_root,
+                total_rewards,
+            )
+        }
+        VaultInstruction::ClaimRewards { amount, proof } => {
+            msg!("Instruction: ClaimRewards");
+            process_claim_rewards(program_id, accounts, amount, proof)
+        }
+        VaultInstruction::InitializeNcnRewardRouter { epoch } => {
+            msg!("Instruction: InitializeNcnRewardRouter");
+            process_initialize_ncn_reward_router(program_id, accounts, epoch)
+        }
+        VaultInstruction::DistributeNcnRewards => {
+            msg!("Instruction: DistributeNcnRewards");
+            process_distribute_ncn_rewards(program_id, accounts)
+        }
+        // ------------------------------------------
+        // Insurance
+        // ------------------------------------------
+        VaultInstruction::SetInsuranceParams { fee_bps } => {
+            msg!("Instruction: SetInsuranceParams");
+            process_set_insurance_params(program_id, accounts, fee_bps)
+        }
+        VaultInstruction::FundInsurance { amount } => {
+            msg!("Instruction: FundInsurance");
+            process_fund_insurance(program_id, accounts, amount)
+        }
     }
 }
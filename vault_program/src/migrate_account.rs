@@ -0,0 +1,55 @@
+use jito_bytemuck::Discriminator;
+use jito_jsm_core::{
+    loader::{load_signer, load_system_program},
+    migrate_account,
+    sysvar::get_rent,
+};
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the migrate account instruction: [`crate::VaultInstruction::MigrateAccount`]
+///
+/// Specification:
+/// - The account shall be a Config or a Vault owned by the vault program
+/// - Anyone can migrate an account, the payer covers the rent of any space the current layout adds
+/// - The account shall be grown to fit the current layout, upgraded in place and set to the current
+///   version. Migrating an account that's already at the current version does nothing.
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [account, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if account.owner.ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !account.is_writable {
+        msg!("Account is not writable");
+        return Err(VaultError::VaultAccountNotWritable.into());
+    }
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let rent = get_rent()?;
+    let discriminator = account.data.borrow().first().copied();
+    let from_version = match discriminator {
+        Some(Config::DISCRIMINATOR) => migrate_account::<Config>(account, payer, &rent)?,
+        Some(Vault::DISCRIMINATOR) => migrate_account::<Vault>(account, payer, &rent)?,
+        _ => {
+            msg!("Account isn't a Config or a Vault");
+            return Err(VaultError::VaultAccountDiscriminatorInvalid.into());
+        }
+    };
+
+    msg!(
+        "Migrated account {} from version {}",
+        account.key,
+        from_version
+    );
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    loader::{load_associated_token_account, load_signer},
+    token::invoke_token,
+};
+use jito_vault_core::vault::Vault;
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token::instruction::transfer;
+
+use crate::{burn::burn_vrt, mint_to::mint_vrt};
+
+/// Processes the migrate to vault instruction: [`crate::VaultInstruction::MigrateToVault`]
+///
+/// Burns the staker's VRT of the source vault and deposits the assets it's redeemed for into the
+/// destination vault in the same instruction, minting the destination vault's VRT to the staker.
+///
+/// Specification:
+/// - The staker must sign the transaction
+/// - The source and destination vaults shall be different vaults with the same supported mint
+/// - The burn shall pass the same checks as [`crate::VaultInstruction::Burn`] on the source vault,
+///   and the source vault shall have enough unstaked assets to pay out the burn
+/// - The withdrawal fee shall be skipped when the source and destination vaults are each other's
+///   migration partner, see [`crate::VaultInstruction::SetMigrationPartner`]
+/// - The assets redeemed shall pass through the staker's token account for the supported mint
+///   and be deposited into the destination vault with the same checks as
+///   [`crate::VaultInstruction::MintTo`]
+/// - The transaction shall fail if the destination VRT minted is less than the minimum amount out
+/// - The optional accounts of Burn on the source vault and MintTo on the destination vault are
+///   passed in after the token program. Vaults with a mint burn admin share the first optional
+///   account, so both vaults must have the same mint burn admin if both have one
+pub fn process_migrate_to_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount_in: u64,
+    min_amount_out: u64,
+) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(13);
+
+    let [config, source_vault_info, source_vault_token_account, source_vrt_mint, source_vault_fee_token_account, staker, staker_token_account, staker_source_vrt_token_account, destination_vault_info, destination_vault_token_account, destination_vrt_mint, staker_destination_vrt_token_account, token_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(staker, false)?;
+    load_associated_token_account(
+        staker_source_vrt_token_account,
+        staker.key,
+        source_vrt_mint.key,
+    )?;
+    if source_vault_info.key.eq(destination_vault_info.key) {
+        msg!("Source and destination vaults are the same");
+        return Err(VaultError::VaultMigrationInvalid.into());
+    }
+    Vault::load(program_id, source_vault_info, true)?;
+    Vault::load(program_id, destination_vault_info, true)?;
+    let waive_withdrawal_fee = {
+        let source_vault_data = source_vault_info.data.borrow();
+        let source_vault = Vault::try_from_slice_unchecked(&source_vault_data)?;
+        let destination_vault_data = destination_vault_info.data.borrow();
+        let destination_vault = Vault::try_from_slice_unchecked(&destination_vault_data)?;
+        if source_vault
+            .supported_mint
+            .ne(&destination_vault.supported_mint)
+        {
+            msg!("Source and destination vaults have different supported mints");
+            return Err(VaultError::VaultMigrationInvalid.into());
+        }
+        source_vault.is_migration_partner(destination_vault_info.key)
+            && destination_vault.is_migration_partner(source_vault_info.key)
+    };
+
+    let amount_out = burn_vrt(
+        program_id,
+        config,
+        source_vault_info,
+        source_vault_token_account,
+        source_vrt_mint,
+        staker,
+        staker_token_account,
+        staker_source_vrt_token_account,
+        staker,
+        source_vault_fee_token_account,
+        token_program,
+        optional_accounts,
+        amount_in,
+        0,
+        waive_withdrawal_fee,
+    )?;
+
+    mint_vrt(
+        program_id,
+        config,
+        destination_vault_info,
+        destination_vrt_mint,
+        staker,
+        destination_vault_token_account,
+        staker_destination_vrt_token_account,
+        token_program,
+        optional_accounts,
+        amount_out,
+        min_amount_out,
+        |_| {
+            // transfer the redeemed assets from the staker to the destination vault
+            invoke_token(
+                &transfer(
+                    &spl_token::id(),
+                    staker_token_account.key,
+                    destination_vault_token_account.key,
+                    staker.key,
+                    &[],
+                    amount_out,
+                )?,
+                &[
+                    staker_token_account.clone(),
+                    destination_vault_token_account.clone(),
+                    staker.clone(),
+                ],
+                &[],
+            )
+        },
+    )?;
+
+    msg!(
+        "Migrated {} VRT of {} to {}",
+        amount_in,
+        source_vault_info.key,
+        destination_vault_info.key
+    );
+
+    Ok(())
+}
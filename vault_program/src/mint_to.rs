@@ -1,22 +1,35 @@
-use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{
-    load_associated_token_account, load_signer, load_token_mint, load_token_program,
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{
+        load_associated_token_account, load_signer, load_system_account, load_system_program,
+        load_token_mint, load_token_program,
+    },
+    sysvar::{get_clock, get_rent},
+    token::invoke_token,
 };
 use jito_vault_core::{
     config::Config,
+    deposit_receipt::DepositReceipt,
     vault::{MintSummary, Vault},
+    vault_deposit_allowlist_entry::VaultDepositAllowlistEntry,
+    vault_depositor::VaultDepositor,
+};
+use jito_vault_sdk::{
+    events::{DepositEvent, VaultEvent},
+    hook::VaultHookInstruction,
+    instruction::DepositMode,
 };
 use solana_program::{
-    account_info::AccountInfo,
-    clock::Clock,
-    entrypoint::ProgramResult,
-    program::{invoke, invoke_signed},
-    program_error::ProgramError,
-    pubkey::Pubkey,
-    sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, system_program,
 };
 use spl_token::instruction::{mint_to, transfer};
 
+use crate::{
+    initialize_vault_epoch_stats::record_vault_epoch_stats, set_hook_program::invoke_vault_hook,
+};
+
 /// Processes the mint instruction: [`crate::VaultInstruction::MintTo`]
 ///
 /// Note: it's strongly encouraged to call [`jito_vault_sdk::instruction::VaultInstruction::UpdateVaultBalance`] before calling this instruction to ensure
@@ -24,28 +37,114 @@ use spl_token::instruction::{mint_to, transfer};
 ///
 /// Specification:
 /// - If the vault has a mint burn admin, it must match be present and be a signer
-/// - The vault must be up-to-date
+/// - The depositor must be allowed to deposit under the vault's deposit mode. In the allowlist
+///   deposit mode, the depositor's VaultDepositAllowlistEntry must be passed in after the mint signer
+/// - The config and the vault must not be paused
+/// - The vault must not be shut down
+/// - The vault must be up-to-date. Deposits are paused as soon as a single epoch's update is
+///   missed and resume once the update cranks complete, since minting against the stale
+///   accounting of a previous epoch would misprice the VRT
+/// - The vault token account must be the vault's associated token account for the supported mint,
+///   see [`crate::VaultInstruction::InitializeVaultTokenAccount`], be open and hold at least the
+///   tokens deposited
 /// - The vault VRT mint must be correct
 /// - The amount to mint must be greater than zero
 /// - The post-mint tokens deposited shall be less than or equal to the vault capacity
-/// - The vault fee wallet must get the fee amount
+/// - The amount in shall be less than or equal to the max deposit per transaction, if set
+/// - If the vault has a max deposit per wallet or flash deposit protection, the depositor's
+///   VaultDepositor and the system program must be passed in after the mint signer. The VaultDepositor
+///   is created with the depositor as payer on the first deposit, the depositor's cumulative deposits
+///   shall be less than or equal to the max deposit per wallet, and the deposit slot is recorded
+/// - If the vault has epoch stats enabled, the VaultEpochStats must be passed in after the mint
+///   signer and shall record the deposit and fee
+/// - The fee amount shall be accrued in the vault and minted to the fee wallet by
+///   [`jito_vault_sdk::instruction::VaultInstruction::UpdateVaultBalance`] once per epoch, so
+///   deposits don't lock the fee wallet's VRT token account
 /// - The transaction shall fail if the amount out is less than the minimum amount out
 /// - The user's assets shall be deposited into the vault supported mint ATA
-/// - The vault shall mint the pro-rata amount to the user and the fee wallet
+/// - The vault shall mint the pro-rata amount to the user
+/// - A [`jito_vault_sdk::events::VaultEvent::Deposit`] event shall be emitted
+/// - If the vault has a hook program, it must be passed in after the mint signer and shall be
+///   invoked with [`VaultHookInstruction::AfterMint`]
+/// - If the depositor passes in an uninitialized DepositReceipt, its base signer and the system
+///   program after the mint signer, the receipt is created with the depositor as payer and records
+///   the amount in, the VRT minted to the depositor and the slot
 pub fn process_mint(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     amount_in: u64,
     min_amount_out: u64,
 ) -> ProgramResult {
-    let (required_accounts, optional_accounts) = accounts.split_at(9);
+    let (required_accounts, optional_accounts) = accounts.split_at(8);
 
-    let [config, vault_info, vrt_mint, depositor, depositor_token_account, vault_token_account, depositor_vrt_token_account, vault_fee_token_account, token_program] =
+    let [config, vault_info, vrt_mint, depositor, depositor_token_account, vault_token_account, depositor_vrt_token_account, token_program] =
         required_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    load_signer(depositor, false)?;
+
+    mint_vrt(
+        program_id,
+        config,
+        vault_info,
+        vrt_mint,
+        depositor,
+        vault_token_account,
+        depositor_vrt_token_account,
+        token_program,
+        optional_accounts,
+        amount_in,
+        min_amount_out,
+        |vault| {
+            load_associated_token_account(
+                depositor_token_account,
+                depositor.key,
+                &vault.supported_mint,
+            )?;
+
+            // transfer tokens from depositor to vault
+            invoke_token(
+                &transfer(
+                    &spl_token::id(),
+                    depositor_token_account.key,
+                    vault_token_account.key,
+                    depositor.key,
+                    &[],
+                    amount_in,
+                )?,
+                &[
+                    depositor_token_account.clone(),
+                    vault_token_account.clone(),
+                    depositor.clone(),
+                ],
+                &[],
+            )
+        },
+    )
+}
+
+/// Checks the deposit against the vault, calls `deposit` to move `amount_in` of the supported
+/// mint into the vault token account, and mints the pro-rata amount of VRT to the depositor.
+///
+/// Shared by [`process_mint`] and [`crate::mint_to_with_sol::process_mint_with_sol`], which only
+/// differ in how the depositor's assets reach the vault. The depositor must already be loaded.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_vrt<'info>(
+    program_id: &Pubkey,
+    config: &AccountInfo<'info>,
+    vault_info: &AccountInfo<'info>,
+    vrt_mint: &AccountInfo<'info>,
+    depositor: &AccountInfo<'info>,
+    vault_token_account: &AccountInfo<'info>,
+    depositor_vrt_token_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    optional_accounts: &[AccountInfo<'info>],
+    amount_in: u64,
+    min_amount_out: u64,
+    deposit: impl FnOnce(&Vault) -> ProgramResult,
+) -> ProgramResult {
     Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
@@ -53,54 +152,138 @@ pub fn process_mint(
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
     load_token_mint(vrt_mint)?;
-    load_signer(depositor, false)?;
-    load_associated_token_account(
-        depositor_token_account,
-        depositor.key,
-        &vault.supported_mint,
-    )?;
-    load_associated_token_account(vault_token_account, vault_info.key, &vault.supported_mint)?;
+    let vault_token_balance = vault.load_token_account(vault_info.key, vault_token_account)?;
+    vault.check_token_account_balance(vault_token_balance)?;
     load_associated_token_account(depositor_vrt_token_account, depositor.key, vrt_mint.key)?;
-    load_associated_token_account(vault_fee_token_account, &vault.fee_wallet, vrt_mint.key)?;
     load_token_program(token_program)?;
 
     vault.check_mint_burn_admin(optional_accounts.first())?;
+    let is_depositor_allowlisted = if vault.deposit_mode()? == DepositMode::Allowlist {
+        let vault_deposit_allowlist_entry = VaultDepositAllowlistEntry::find_program_address(
+            program_id,
+            vault_info.key,
+            depositor.key,
+        )
+        .0;
+        match optional_accounts
+            .iter()
+            .find(|account| account.key.eq(&vault_deposit_allowlist_entry))
+        {
+            // A depositor never added to or removed from the allowlist has no entry to load
+            Some(vault_deposit_allowlist_entry)
+                if !vault_deposit_allowlist_entry.data_is_empty() =>
+            {
+                VaultDepositAllowlistEntry::load(
+                    program_id,
+                    vault_deposit_allowlist_entry,
+                    vault_info,
+                    depositor,
+                    false,
+                )?;
+                true
+            }
+            _ => false,
+        }
+    } else {
+        false
+    };
+    vault.check_deposit_allowed(is_depositor_allowlisted)?;
     vault.check_vrt_mint(vrt_mint.key)?;
-    vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+    config.check_is_paused()?;
+    vault.check_is_paused()?;
+    vault.check_is_shut_down()?;
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
 
     let MintSummary {
         vrt_to_depositor,
         vrt_to_fee_wallet,
     } = vault.mint_with_fee(amount_in, min_amount_out)?;
+    vault.accrue_vrt_fees(vrt_to_fee_wallet)?;
 
-    // transfer tokens from depositor to vault
-    {
-        invoke(
-            &transfer(
-                &spl_token::id(),
-                depositor_token_account.key,
-                vault_token_account.key,
-                depositor.key,
-                &[],
-                amount_in,
-            )?,
-            &[
-                depositor_token_account.clone(),
-                vault_token_account.clone(),
-                depositor.clone(),
-            ],
-        )?;
+    let epoch = get_clock()?
+        .slot
+        .checked_div(vault.epoch_length(config.epoch_length()))
+        .unwrap();
+    record_vault_epoch_stats(program_id, vault_info, vault, optional_accounts, |stats| {
+        stats.record_deposit(epoch, amount_in, vrt_to_fee_wallet)
+    })?;
+
+    if vault.tracks_depositors() {
+        let (vault_depositor_pubkey, vault_depositor_bump, mut vault_depositor_seeds) =
+            VaultDepositor::find_program_address(program_id, vault_info.key, depositor.key);
+        let Some(vault_depositor) = optional_accounts
+            .iter()
+            .find(|account| account.key.eq(&vault_depositor_pubkey))
+        else {
+            msg!("Vault tracks depositors, the vault depositor account is required");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if vault_depositor.data_is_empty() {
+            let Some(system_program) = optional_accounts
+                .iter()
+                .find(|account| account.key.eq(&system_program::id()))
+            else {
+                msg!("System program is required to create the vault depositor account");
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            load_system_program(system_program)?;
+
+            msg!(
+                "Initializing vault depositor at address {}",
+                vault_depositor.key
+            );
+            vault_depositor_seeds.push(vec![vault_depositor_bump]);
+            create_account(
+                depositor,
+                vault_depositor,
+                system_program,
+                program_id,
+                &get_rent()?,
+                8_u64
+                    .checked_add(std::mem::size_of::<VaultDepositor>() as u64)
+                    .unwrap(),
+                &vault_depositor_seeds,
+            )?;
+
+            let mut vault_depositor_data = vault_depositor.try_borrow_mut_data()?;
+            vault_depositor_data[0] = VaultDepositor::DISCRIMINATOR;
+            let vault_depositor =
+                VaultDepositor::try_from_slice_unchecked_mut(&mut vault_depositor_data)?;
+            *vault_depositor =
+                VaultDepositor::new(*vault_info.key, *depositor.key, vault_depositor_bump);
+        }
+
+        VaultDepositor::load(program_id, vault_depositor, vault_info, depositor, true)?;
+        let mut vault_depositor_data = vault_depositor.data.borrow_mut();
+        let vault_depositor =
+            VaultDepositor::try_from_slice_unchecked_mut(&mut vault_depositor_data)?;
+        vault_depositor.deposit(amount_in, vault.max_deposit_per_wallet())?;
+        vault_depositor.set_last_deposit_slot(get_clock()?.slot);
     }
 
+    record_deposit_receipt(
+        program_id,
+        vault_info,
+        depositor,
+        optional_accounts,
+        amount_in,
+        vrt_to_depositor,
+    )?;
+
+    deposit(vault)?;
+
     let (_, vault_bump, mut vault_seeds) = Vault::find_program_address(program_id, &vault.base);
     vault_seeds.push(vec![vault_bump]);
     let seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
 
+    let hook_program = vault.hook_program;
+
     drop(vault_data); // no double borrow
 
-    // mint to depositor and fee wallet
+    // mint to depositor
     {
-        invoke_signed(
+        invoke_token(
             &mint_to(
                 &spl_token::id(),
                 vrt_mint.key,
@@ -116,23 +299,97 @@ pub fn process_mint(
             ],
             &[&seed_slices],
         )?;
+    }
 
-        invoke_signed(
-            &mint_to(
-                &spl_token::id(),
-                vrt_mint.key,
-                vault_fee_token_account.key,
+    VaultEvent::Deposit(DepositEvent {
+        vault: *vault_info.key,
+        depositor: *depositor.key,
+        amount_in,
+        vrt_to_depositor,
+        vrt_fee: vrt_to_fee_wallet,
+    })
+    .emit();
+
+    invoke_vault_hook(
+        &hook_program,
+        vault_info,
+        depositor,
+        optional_accounts,
+        VaultHookInstruction::AfterMint {
+            amount_in,
+            vrt_to_depositor,
+        },
+    )
+}
+
+/// Creates the depositor's [`DepositReceipt`] for this deposit if they opted in by passing in the
+/// receipt and its base signer.
+fn record_deposit_receipt<'info>(
+    program_id: &Pubkey,
+    vault_info: &AccountInfo<'info>,
+    depositor: &AccountInfo<'info>,
+    optional_accounts: &[AccountInfo<'info>],
+    amount_in: u64,
+    vrt_minted: u64,
+) -> ProgramResult {
+    for base in optional_accounts
+        .iter()
+        .filter(|account| account.is_signer && account.key.ne(depositor.key))
+    {
+        let (deposit_receipt_pubkey, deposit_receipt_bump, mut deposit_receipt_seeds) =
+            DepositReceipt::find_program_address(
+                program_id,
                 vault_info.key,
-                &[],
-                vrt_to_fee_wallet,
-            )?,
-            &[
-                vrt_mint.clone(),
-                vault_fee_token_account.clone(),
-                vault_info.clone(),
-            ],
-            &[&seed_slices],
+                depositor.key,
+                base.key,
+            );
+        let Some(deposit_receipt) = optional_accounts
+            .iter()
+            .find(|account| account.key.eq(&deposit_receipt_pubkey))
+        else {
+            continue;
+        };
+        load_system_account(deposit_receipt, true)?;
+        let Some(system_program) = optional_accounts
+            .iter()
+            .find(|account| account.key.eq(&system_program::id()))
+        else {
+            msg!("System program is required to create the deposit receipt account");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        load_system_program(system_program)?;
+
+        msg!(
+            "Initializing deposit receipt at address {}",
+            deposit_receipt.key
+        );
+        deposit_receipt_seeds.push(vec![deposit_receipt_bump]);
+        create_account(
+            depositor,
+            deposit_receipt,
+            system_program,
+            program_id,
+            &get_rent()?,
+            8_u64
+                .checked_add(std::mem::size_of::<DepositReceipt>() as u64)
+                .unwrap(),
+            &deposit_receipt_seeds,
         )?;
+
+        let mut deposit_receipt_data = deposit_receipt.try_borrow_mut_data()?;
+        deposit_receipt_data[0] = DepositReceipt::DISCRIMINATOR;
+        let deposit_receipt =
+            DepositReceipt::try_from_slice_unchecked_mut(&mut deposit_receipt_data)?;
+        *deposit_receipt = DepositReceipt::new(
+            *vault_info.key,
+            *depositor.key,
+            *base.key,
+            amount_in,
+            vrt_minted,
+            get_clock()?.slot,
+            deposit_receipt_bump,
+        );
+        return Ok(());
     }
 
     Ok(())
@@ -0,0 +1,78 @@
+use jito_jsm_core::{
+    loader::{load_signer, load_system_program},
+    system::invoke_system,
+    token::invoke_token,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey, system_instruction,
+};
+use spl_token::instruction::sync_native;
+
+use crate::mint_to::mint_vrt;
+
+/// Processes the mint with SOL instruction: [`crate::VaultInstruction::MintToWithSol`]
+///
+/// Note: it's strongly encouraged to call [`jito_vault_sdk::instruction::VaultInstruction::UpdateVaultBalance`] before calling this instruction to ensure
+/// the vault state is up-to-date.
+///
+/// Specification:
+/// - The vault supported mint must be the native mint
+/// - The same checks as [`crate::VaultInstruction::MintTo`] apply. The optional accounts are
+///   the same, except the system program is always passed in
+/// - The depositor's lamports shall be transferred into the vault's wSOL token account and
+///   synced, wrapping them without the depositor holding a wSOL token account
+/// - The vault shall mint the pro-rata amount to the user
+pub fn process_mint_with_sol(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount_in: u64,
+    min_amount_out: u64,
+) -> ProgramResult {
+    let (required_accounts, optional_accounts) = accounts.split_at(8);
+
+    let [config, vault_info, vrt_mint, depositor, vault_token_account, depositor_vrt_token_account, token_program, system_program] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    load_signer(depositor, true)?;
+    load_system_program(system_program)?;
+
+    // the system program is needed to create the vault depositor
+    let optional_accounts = [optional_accounts, &[system_program.clone()]].concat();
+
+    mint_vrt(
+        program_id,
+        config,
+        vault_info,
+        vrt_mint,
+        depositor,
+        vault_token_account,
+        depositor_vrt_token_account,
+        token_program,
+        &optional_accounts,
+        amount_in,
+        min_amount_out,
+        |vault| {
+            vault.check_supported_mint(&spl_token::native_mint::id())?;
+
+            // wrap the depositor's lamports into the vault's wSOL token account
+            invoke_system(
+                &system_instruction::transfer(depositor.key, vault_token_account.key, amount_in),
+                &[
+                    depositor.clone(),
+                    vault_token_account.clone(),
+                    system_program.clone(),
+                ],
+                &[],
+            )?;
+            invoke_token(
+                &sync_native(&spl_token::id(), vault_token_account.key)?,
+                &[vault_token_account.clone()],
+                &[],
+            )
+        },
+    )
+}
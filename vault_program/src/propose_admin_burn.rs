@@ -0,0 +1,64 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the propose admin burn instruction: [`crate::VaultInstruction::ProposeAdminBurn`]
+///
+/// Specification:
+/// - The vault shall have admin burns enabled
+/// - The admin must be the vault burn admin and sign the transaction
+/// - Only one admin burn can be pending per withdrawal ticket
+/// - The staker can't burn or transfer the ticket while the admin burn is pending
+/// - The admin burn can be executed after [`jito_vault_core::ADMIN_BURN_TIMELOCK_EPOCHS`] epochs,
+///   during which the vault admin or burn admin can cancel it
+pub fn process_propose_admin_burn(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault_info, vault_staker_withdrawal_ticket_info, burn_admin, recovery] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    VaultStakerWithdrawalTicket::load(
+        program_id,
+        vault_staker_withdrawal_ticket_info,
+        vault_info,
+        true,
+    )?;
+    let mut vault_staker_withdrawal_ticket_data =
+        vault_staker_withdrawal_ticket_info.data.borrow_mut();
+    let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked_mut(
+        &mut vault_staker_withdrawal_ticket_data,
+    )?;
+    load_signer(burn_admin, false)?;
+
+    vault.check_burn_admin(burn_admin.key)?;
+
+    vault_staker_withdrawal_ticket.propose_admin_burn(
+        recovery.key,
+        get_clock()?.slot,
+        vault.epoch_length(config.epoch_length()),
+    )?;
+
+    msg!(
+        "AdminBurnProposed: vault {} ticket {} staker {} vrt {} recovery {} executable at slot {}",
+        vault_info.key,
+        vault_staker_withdrawal_ticket_info.key,
+        vault_staker_withdrawal_ticket.staker,
+        vault_staker_withdrawal_ticket.vrt_amount(),
+        recovery.key,
+        vault_staker_withdrawal_ticket.admin_burn_slot()
+    );
+
+    Ok(())
+}
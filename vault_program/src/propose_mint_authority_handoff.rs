@@ -0,0 +1,57 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the propose mint authority handoff instruction: [`crate::VaultInstruction::ProposeMintAuthorityHandoff`]
+///
+/// Specification:
+/// - The admin must be the config admin and sign the transaction
+/// - The successor program shall be executable, the VRT mint authority is handed off to its PDA
+///   derived from `[b"vault_mint_authority", vault]`
+/// - Only one handoff can be pending at a time
+/// - The handoff can be executed after [`jito_vault_core::MINT_AUTHORITY_HANDOFF_CHALLENGE_EPOCHS`]
+///   epochs, during which the vault admin or config admin can cancel it
+pub fn process_propose_mint_authority_handoff(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, config_admin, successor_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(config_admin, false)?;
+
+    config.check_admin(config_admin.key)?;
+
+    if !successor_program.executable {
+        msg!("Mint authority successor program is not executable");
+        return Err(VaultError::VaultMintAuthoritySuccessorInvalid.into());
+    }
+
+    let successor = Vault::find_mint_authority_successor(successor_program.key, vault_info.key);
+    vault.propose_mint_authority_handoff(
+        &successor,
+        get_clock()?.slot,
+        vault.epoch_length(config.epoch_length()),
+    )?;
+
+    msg!(
+        "MintAuthorityHandoffProposed: vault {} successor {} executable at slot {}",
+        vault_info.key,
+        successor,
+        vault.mint_authority_handoff_slot()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,234 @@
+use std::mem::size_of;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
+};
+use jito_restaking_core::{
+    ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
+    operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_core::{
+    config::Config, slash_proposal::SlashProposal, vault::Vault,
+    vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+    vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::slash::check_states_active_or_cooling_down;
+
+/// Processes the propose slash instruction: [`crate::VaultInstruction::ProposeSlash`]
+///
+/// Specification:
+/// - The slasher shall sign the transaction and pay for the SlashProposal account
+/// - The vault shall be up to date
+/// - All ticket states shall be active or cooling down, as for [`crate::VaultInstruction::Slash`]
+/// - The operator delegation shall currently hold at least the amount to slash
+/// - The amount shall be counted against the slasher's maximum slashable amount for the epoch,
+///   released again if the slash is vetoed
+/// - The amount shall be counted as pending against the vault until the slash is executed or
+///   vetoed, which vaults with slash dispute haircuts take off exits in the meantime
+/// - The SlashProposal account shall be at the canonical PDA and executable once the config's
+///   slash veto window has passed
+pub fn process_propose_slash(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slash_amount: u64,
+    evidence_hash: [u8; 32],
+) -> ProgramResult {
+    let [config, vault_info, ncn, operator, slasher, ncn_operator_state, ncn_vault_ticket, operator_vault_ticket, vault_ncn_ticket, vault_operator_delegation, ncn_vault_slasher_ticket, vault_ncn_slasher_ticket, vault_ncn_slasher_operator_ticket, slash_proposal, base, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
+    Ncn::load(&config.restaking_program, ncn, false)?;
+    Operator::load(&config.restaking_program, operator, false)?;
+    load_signer(slasher, true)?;
+    NcnOperatorState::load(
+        &config.restaking_program,
+        ncn_operator_state,
+        ncn,
+        operator,
+        false,
+    )?;
+    let ncn_operator_state_data = ncn_operator_state.data.borrow();
+    let ncn_operator_state = NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
+    NcnVaultTicket::load(
+        &config.restaking_program,
+        ncn_vault_ticket,
+        ncn,
+        vault_info,
+        false,
+    )?;
+    let ncn_vault_ticket_data = ncn_vault_ticket.data.borrow();
+    let ncn_vault_ticket = NcnVaultTicket::try_from_slice_unchecked(&ncn_vault_ticket_data)?;
+    OperatorVaultTicket::load(
+        &config.restaking_program,
+        operator_vault_ticket,
+        operator,
+        vault_info,
+        false,
+    )?;
+    let operator_vault_ticket_data = operator_vault_ticket.data.borrow();
+    let operator_vault_ticket =
+        OperatorVaultTicket::try_from_slice_unchecked(&operator_vault_ticket_data)?;
+    VaultNcnTicket::load(program_id, vault_ncn_ticket, vault_info, ncn, false)?;
+    let vault_ncn_ticket_data = vault_ncn_ticket.data.borrow();
+    let vault_ncn_ticket = VaultNcnTicket::try_from_slice_unchecked(&vault_ncn_ticket_data)?;
+    VaultOperatorDelegation::load(
+        program_id,
+        vault_operator_delegation,
+        vault_info,
+        operator,
+        false,
+    )?;
+    let vault_operator_delegation_data = vault_operator_delegation.data.borrow();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?;
+    NcnVaultSlasherTicket::load(
+        &config.restaking_program,
+        ncn_vault_slasher_ticket,
+        ncn,
+        vault_info,
+        slasher,
+        false,
+    )?;
+    let ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow();
+    let ncn_vault_slasher_ticket =
+        NcnVaultSlasherTicket::try_from_slice_unchecked(&ncn_vault_slasher_ticket_data)?;
+    VaultNcnSlasherTicket::load(
+        program_id,
+        vault_ncn_slasher_ticket,
+        vault_info,
+        ncn,
+        slasher,
+        false,
+    )?;
+    let vault_ncn_slasher_ticket_data = vault_ncn_slasher_ticket.data.borrow();
+    let vault_ncn_slasher_ticket =
+        VaultNcnSlasherTicket::try_from_slice_unchecked(&vault_ncn_slasher_ticket_data)?;
+    let slot = get_clock()?.slot;
+    let epoch_length = config.epoch_length();
+    let ncn_epoch = slot.checked_div(epoch_length).unwrap();
+    VaultNcnSlasherOperatorTicket::load(
+        program_id,
+        vault_ncn_slasher_operator_ticket,
+        vault_info,
+        ncn,
+        slasher,
+        operator,
+        ncn_epoch,
+        true,
+    )?;
+    let mut vault_ncn_slasher_operator_ticket_data =
+        vault_ncn_slasher_operator_ticket.data.borrow_mut();
+    let vault_ncn_slasher_operator_ticket =
+        VaultNcnSlasherOperatorTicket::try_from_slice_unchecked_mut(
+            &mut vault_ncn_slasher_operator_ticket_data,
+        )?;
+    load_system_account(slash_proposal, true)?;
+    load_signer(base, false)?;
+    load_system_program(system_program)?;
+
+    // All ticket states shall be active or cooling down
+    check_states_active_or_cooling_down(
+        vault_ncn_slasher_ticket,
+        ncn_vault_slasher_ticket,
+        ncn_operator_state,
+        operator_vault_ticket,
+        vault_ncn_ticket,
+        ncn_vault_ticket,
+        slot,
+        epoch_length,
+    )?;
+
+    // The operator delegation shall currently be able to cover the slash
+    if slash_amount
+        > vault_operator_delegation
+            .delegation_state
+            .total_security()?
+    {
+        msg!("Slash amount exceeds the operator delegation's total security");
+        return Err(VaultError::VaultSlashUnderflow.into());
+    }
+
+    // The amount slashed for this operator shall not exceed the maximum slashable amount per epoch,
+    // the lower of what the vault agreed to and what the NCN currently allows
+    let max_slashable_per_epoch = vault_ncn_slasher_ticket
+        .max_slashable_per_epoch()
+        .min(ncn_vault_slasher_ticket.max_slashable_per_epoch_at(ncn_epoch));
+    vault_ncn_slasher_operator_ticket
+        .check_slashing_amount_not_exceeded(slash_amount, max_slashable_per_epoch)?;
+    vault_ncn_slasher_operator_ticket.increment_slashed(slash_amount)?;
+    vault.increment_pending_slash_amount(slash_amount)?;
+
+    // The SlashProposal shall be at the canonical PDA
+    let (slash_proposal_pubkey, slash_proposal_bump, mut slash_proposal_seeds) =
+        SlashProposal::find_program_address(program_id, vault_info.key, base.key);
+    slash_proposal_seeds.push(vec![slash_proposal_bump]);
+    if slash_proposal.key.ne(&slash_proposal_pubkey) {
+        msg!("Slash proposal is not at the correct PDA");
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
+    }
+
+    let executable_slot = slot
+        .checked_add(config.slash_veto_window_slots())
+        .ok_or(VaultError::VaultOverflow)?;
+
+    msg!(
+        "Initializing slash proposal at address {}",
+        slash_proposal.key
+    );
+    create_account(
+        slasher,
+        slash_proposal,
+        system_program,
+        program_id,
+        &get_rent()?,
+        8_u64
+            .checked_add(size_of::<SlashProposal>() as u64)
+            .unwrap(),
+        &slash_proposal_seeds,
+    )?;
+    let mut slash_proposal_data = slash_proposal.data.borrow_mut();
+    slash_proposal_data[0] = SlashProposal::DISCRIMINATOR;
+    let slash_proposal = SlashProposal::try_from_slice_unchecked_mut(&mut slash_proposal_data)?;
+    *slash_proposal = SlashProposal::new(
+        *vault_info.key,
+        *ncn.key,
+        *operator.key,
+        *slasher.key,
+        *base.key,
+        evidence_hash,
+        slash_amount,
+        ncn_epoch,
+        executable_slot,
+        slash_proposal_bump,
+    );
+
+    msg!(
+        "SlashProposed: vault {} operator {} amount {} executable at slot {}",
+        vault_info.key,
+        operator.key,
+        slash_amount,
+        executable_slot
+    );
+
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use std::cmp::{min, Ordering};
+
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::sysvar::get_clock;
+use jito_restaking_core::operator::Operator;
+use jito_vault_core::{
+    config::Config,
+    delegation_strategy::DelegationStrategy,
+    rounding::{mul_div, RoundingDirection},
+    vault::Vault,
+    vault_operator_delegation::VaultOperatorDelegation,
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    events::{DelegationChange, DelegationChangedEvent, VaultEvent},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::add_delegation::load_approved_delegation_amount;
+
+/// Processes the rebalance delegations instruction: [`crate::VaultInstruction::RebalanceDelegations`]
+///
+/// Specification:
+/// - Anyone can crank the rebalance, the delegation strategy decides where the stake goes
+/// - The config and the vault shall not be paused and the vault shall be up-to-date
+/// - The vault shall not be shut down, its delegations are cooled down by the update instead
+/// - The operator's target is its share of the vault's staked and delegatable tokens by target
+///   weight, or zero while delegation to the operator is paused or the operator is banned
+/// - Stake shall be delegated to an operator under its target, up to the tokens available for
+///   delegation and the operator's maximum delegation amount, unless an NCN froze the operator
+/// - If the operator requires approval of incoming delegations, stake shall only be delegated up to
+///   the amount the operator approved on its OperatorVaultTicket for the vault, which shall be
+///   passed after the other accounts
+/// - Stake shall be cooled down from an operator over its target
+/// - The stake moved shall not exceed what's left of the strategy's movement limit this epoch
+/// - The stake left on the operator shall be zero or at least the vault's minimum delegation amount
+/// - A [`jito_vault_sdk::events::VaultEvent::DelegationChanged`] event shall be emitted when stake
+///   moves
+pub fn process_rebalance_delegations(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if accounts.len() < 5 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (required_accounts, optional_accounts) = accounts.split_at(5);
+    let [config, vault_info, operator, vault_operator_delegation, delegation_strategy] =
+        required_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let slot = get_clock()?.slot;
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    Operator::load(&config.restaking_program, operator, false)?;
+    let operator_data = operator.data.borrow();
+    let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+    VaultOperatorDelegation::load(
+        program_id,
+        vault_operator_delegation,
+        vault_info,
+        operator,
+        true,
+    )?;
+    let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
+    DelegationStrategy::load(program_id, delegation_strategy, vault_info, true)?;
+    let mut delegation_strategy_data = delegation_strategy.data.borrow_mut();
+    let delegation_strategy =
+        DelegationStrategy::try_from_slice_unchecked_mut(&mut delegation_strategy_data)?;
+
+    config.check_is_paused()?;
+    vault.check_is_paused()?;
+    vault.check_is_shut_down()?;
+    vault.check_update_state_ok(slot, vault.epoch_length(config.epoch_length()))?;
+
+    let epoch = slot
+        .checked_div(vault.epoch_length(config.epoch_length()))
+        .unwrap();
+    let amount_available_for_delegation = vault.calculate_amount_available_for_delegation()?;
+    let target_amount = if vault_operator_delegation.is_delegation_paused()
+        || vault_operator_delegation.is_banned()
+        || delegation_strategy.total_weight() == 0
+    {
+        0
+    } else {
+        let delegatable_amount = vault
+            .delegation_state
+            .staked_amount()
+            .checked_add(amount_available_for_delegation)
+            .ok_or(VaultError::VaultOverflow)?;
+        mul_div(
+            delegatable_amount,
+            vault_operator_delegation.target_weight(),
+            delegation_strategy.total_weight(),
+            RoundingDirection::Down,
+        )?
+    };
+    let staked_amount = vault_operator_delegation.delegation_state.staked_amount();
+    let remaining_movement = delegation_strategy.remaining_movement(epoch);
+
+    match target_amount.cmp(&staked_amount) {
+        Ordering::Greater => {
+            if operator_account.is_frozen(slot) {
+                msg!("Operator is frozen, no new delegations are allowed");
+                return Err(VaultError::VaultOperatorFrozen.into());
+            }
+
+            let mut amount = min(
+                target_amount.saturating_sub(staked_amount),
+                min(remaining_movement, amount_available_for_delegation),
+            );
+            if vault_operator_delegation.max_delegation_amount() > 0 {
+                let delegation_headroom = vault_operator_delegation
+                    .max_delegation_amount()
+                    .saturating_sub(
+                        vault_operator_delegation
+                            .delegation_state
+                            .total_security()?,
+                    );
+                amount = min(amount, delegation_headroom);
+            }
+            if let Some(approved_delegation_amount) = load_approved_delegation_amount(
+                &config.restaking_program,
+                operator_account,
+                operator,
+                vault_info,
+                optional_accounts,
+            )? {
+                let approved_headroom = approved_delegation_amount.saturating_sub(
+                    vault_operator_delegation
+                        .delegation_state
+                        .total_security()?,
+                );
+                amount = min(amount, approved_headroom);
+            }
+            if amount == 0 {
+                msg!(
+                    "Operator {} can't be delegated more this epoch",
+                    operator.key
+                );
+                return Ok(());
+            }
+
+            vault.delegate(amount)?;
+            vault_operator_delegation
+                .delegation_state
+                .delegate(amount)?;
+            vault.check_min_delegation_amount(
+                vault_operator_delegation.delegation_state.staked_amount(),
+            )?;
+            delegation_strategy.record_movement(epoch, amount)?;
+            msg!("Delegated {} to operator {}", amount, operator.key);
+            VaultEvent::DelegationChanged(DelegationChangedEvent {
+                vault: *vault_info.key,
+                operator: *operator.key,
+                change: DelegationChange::Delegate,
+                amount,
+                staked_amount: vault_operator_delegation.delegation_state.staked_amount(),
+            })
+            .emit();
+        }
+        Ordering::Less => {
+            let amount = min(
+                staked_amount.saturating_sub(target_amount),
+                remaining_movement,
+            );
+            if amount == 0 {
+                msg!(
+                    "Operator {} can't be cooled down more this epoch",
+                    operator.key
+                );
+                return Ok(());
+            }
+
+            vault_operator_delegation
+                .delegation_state
+                .cooldown(amount)?;
+            vault.check_min_delegation_amount(
+                vault_operator_delegation.delegation_state.staked_amount(),
+            )?;
+            vault.delegation_state.cooldown(amount)?;
+            delegation_strategy.record_movement(epoch, amount)?;
+            msg!("Cooled down {} from operator {}", amount, operator.key);
+            VaultEvent::DelegationChanged(DelegationChangedEvent {
+                vault: *vault_info.key,
+                operator: *operator.key,
+                change: DelegationChange::Cooldown,
+                amount,
+                staked_amount: vault_operator_delegation.delegation_state.staked_amount(),
+            })
+            .emit();
+        }
+        Ordering::Equal => {
+            msg!(
+                "Operator {} is at its target of {}",
+                operator.key,
+                target_amount
+            );
+        }
+    }
+
+    Ok(())
+}
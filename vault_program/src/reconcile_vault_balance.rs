@@ -0,0 +1,60 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::events::{BalanceReconciledEvent, VaultEvent};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the reconcile vault balance instruction: [`crate::VaultInstruction::ReconcileVaultBalance`]
+///
+/// Recovers a vault whose token account balance fell below the tokens deposited, which makes the
+/// instructions that move assets fail with [`jito_vault_sdk::error::VaultError::VaultBalanceDiverged`].
+/// A closed vault token account has to be recreated first.
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The vault token account must be open and its balance must be below the tokens deposited. An
+///   excess is rewards, which [`crate::VaultInstruction::UpdateVaultBalance`] picks up.
+/// - The adjustment shall not exceed `max_adjustment`
+/// - The balance shall cover the delegated tokens
+/// - The tokens deposited shall be set to the vault token account balance
+/// - A [`jito_vault_sdk::events::VaultEvent::BalanceReconciled`] event shall be emitted
+pub fn process_reconcile_vault_balance(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_adjustment: u64,
+) -> ProgramResult {
+    let [config, vault_info, vault_token_account, vault_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_admin, false)?;
+
+    vault.check_admin(vault_admin.key)?;
+
+    let balance = vault.load_token_account(vault_info.key, vault_token_account)?;
+    let tokens_deposited_before = vault.tokens_deposited();
+    let adjustment = vault.reconcile_tokens_deposited(balance, max_adjustment)?;
+
+    msg!(
+        "Reconciled the tokens deposited from {} to {}, writing down {}",
+        tokens_deposited_before,
+        balance,
+        adjustment
+    );
+
+    VaultEvent::BalanceReconciled(BalanceReconciledEvent {
+        vault: *vault_info.key,
+        tokens_deposited_before,
+        tokens_deposited_after: balance,
+    })
+    .emit();
+
+    Ok(())
+}
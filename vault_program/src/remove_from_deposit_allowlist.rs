@@ -0,0 +1,47 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{close_program_account, loader::load_signer};
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_deposit_allowlist_entry::VaultDepositAllowlistEntry,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the remove from deposit allowlist instruction: [`crate::VaultInstruction::RemoveFromDepositAllowlist`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The VaultDepositAllowlistEntry shall be closed and its rent returned to the admin
+pub fn process_remove_from_deposit_allowlist(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, depositor, vault_deposit_allowlist_entry, vault_admin] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    VaultDepositAllowlistEntry::load(
+        program_id,
+        vault_deposit_allowlist_entry,
+        vault_info,
+        depositor,
+        true,
+    )?;
+    load_signer(vault_admin, true)?;
+
+    vault.check_admin(vault_admin.key)?;
+
+    msg!(
+        "Removing {} from the vault deposit allowlist",
+        depositor.key
+    );
+    close_program_account(program_id, vault_deposit_allowlist_entry, vault_admin)?;
+
+    Ok(())
+}
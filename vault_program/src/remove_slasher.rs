@@ -0,0 +1,69 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    close_program_account, loader::load_signer, slot_toggle::SlotToggleState, sysvar::get_clock,
+};
+use jito_restaking_core::ncn::Ncn;
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the remove slasher instruction: [`crate::VaultInstruction::RemoveSlasher`]
+///
+/// Specification:
+/// - The admin must be the vault slasher admin and sign the transaction
+/// - The VaultNcnSlasherTicket shall be inactive, so a slasher must be cooled down and the
+///   cooldown epoch must have passed before it can be removed. Slashes in flight while the ticket
+///   is cooling down can still be executed.
+/// - The VaultNcnSlasherTicket shall be closed and its rent returned to the admin
+pub fn process_remove_slasher(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault_info, ncn, slasher, vault_ncn_slasher_ticket, vault_slasher_admin] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    Ncn::load(&config.restaking_program, ncn, false)?;
+    VaultNcnSlasherTicket::load(
+        program_id,
+        vault_ncn_slasher_ticket,
+        vault_info,
+        ncn,
+        slasher,
+        true,
+    )?;
+    load_signer(vault_slasher_admin, true)?;
+
+    vault.check_slasher_admin(vault_slasher_admin.key)?;
+
+    {
+        let vault_ncn_slasher_ticket_data = vault_ncn_slasher_ticket.data.borrow();
+        let vault_ncn_slasher_ticket =
+            VaultNcnSlasherTicket::try_from_slice_unchecked(&vault_ncn_slasher_ticket_data)?;
+        let state = vault_ncn_slasher_ticket
+            .state
+            .state(get_clock()?.slot, config.epoch_length());
+        if state != SlotToggleState::Inactive {
+            msg!(
+                "Slasher must be inactive to be removed, current state: {:?}",
+                state
+            );
+            return Err(VaultError::VaultNcnSlasherTicketNotInactive.into());
+        }
+    }
+
+    msg!("Removing slasher {} for NCN {}", slasher.key, ncn.key);
+    close_program_account(program_id, vault_ncn_slasher_ticket, vault_slasher_admin)?;
+
+    Ok(())
+}
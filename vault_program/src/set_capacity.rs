@@ -2,10 +2,16 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_vault_core::{config::Config, vault::Vault};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
+/// Processes the set deposit capacity instruction: [`crate::VaultInstruction::SetDepositCapacity`]
+///
+/// Specification:
+/// - The signer must be the vault capacity admin, which defaults to the vault admin and is
+///   delegated with [`crate::VaultInstruction::SetSecondaryAdmin`]
+/// - Deposits that would take the vault's tokens deposited over the capacity shall fail
 pub fn process_set_deposit_capacity(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -22,6 +28,7 @@ pub fn process_set_deposit_capacity(
 
     vault.check_capacity_admin(vault_capacity_admin.key)?;
     vault.set_capacity(capacity);
+    msg!("Vault capacity set to {}", capacity);
 
     Ok(())
 }
@@ -0,0 +1,61 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{
+    config::Config, delegation_strategy::DelegationStrategy, vault::Vault,
+    vault_operator_delegation::VaultOperatorDelegation,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set delegation strategy weight instruction: [`crate::VaultInstruction::SetDelegationStrategyWeight`]
+///
+/// Specification:
+/// - The admin must be the vault delegation admin and sign the transaction
+/// - The operator delegation's target weight shall be replaced in the strategy's total weight
+/// - A weight of zero targets no stake on the operator, so the crank cools its stake down
+pub fn process_set_delegation_strategy_weight(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    weight: u64,
+) -> ProgramResult {
+    let [config, vault_info, operator, vault_operator_delegation, delegation_strategy, vault_delegation_admin] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    VaultOperatorDelegation::load(
+        program_id,
+        vault_operator_delegation,
+        vault_info,
+        operator,
+        true,
+    )?;
+    let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
+    DelegationStrategy::load(program_id, delegation_strategy, vault_info, true)?;
+    let mut delegation_strategy_data = delegation_strategy.data.borrow_mut();
+    let delegation_strategy =
+        DelegationStrategy::try_from_slice_unchecked_mut(&mut delegation_strategy_data)?;
+    load_signer(vault_delegation_admin, false)?;
+
+    vault.check_delegation_admin(vault_delegation_admin.key)?;
+
+    delegation_strategy.update_weight(vault_operator_delegation.target_weight(), weight)?;
+    vault_operator_delegation.set_target_weight(weight);
+    msg!(
+        "Target weight for operator {} set to {} of {}",
+        operator.key,
+        weight,
+        delegation_strategy.total_weight()
+    );
+
+    Ok(())
+}
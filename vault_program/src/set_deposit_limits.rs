@@ -0,0 +1,47 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set deposit limits instruction: [`crate::VaultInstruction::SetDepositLimits`]
+///
+/// Specification:
+/// - The admin must be the vault capacity admin and sign the transaction
+/// - Only the limits that are provided are updated, a limit of 0 means no limit
+/// - Lowering the max deposit per wallet doesn't affect tokens already deposited, it only
+///   blocks further deposits from wallets above the new limit
+pub fn process_set_deposit_limits(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_deposit_per_transaction: Option<u64>,
+    max_deposit_per_wallet: Option<u64>,
+) -> ProgramResult {
+    let [config, vault, vault_capacity_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_capacity_admin, false)?;
+
+    vault.check_capacity_admin(vault_capacity_admin.key)?;
+
+    if let Some(max_deposit_per_transaction) = max_deposit_per_transaction {
+        vault.set_max_deposit_per_transaction(max_deposit_per_transaction);
+        msg!(
+            "Max deposit per transaction set to {}",
+            max_deposit_per_transaction
+        );
+    }
+    if let Some(max_deposit_per_wallet) = max_deposit_per_wallet {
+        vault.set_max_deposit_per_wallet(max_deposit_per_wallet);
+        msg!("Max deposit per wallet set to {}", max_deposit_per_wallet);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::instruction::DepositMode;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set deposit mode instruction: [`crate::VaultInstruction::SetDepositMode`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - [`DepositMode::SignerGated`] requires the vault mint burn admin to sign every MintTo
+/// - [`DepositMode::Allowlist`] requires the depositor to have a VaultDepositAllowlistEntry
+pub fn process_set_deposit_mode(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    deposit_mode: DepositMode,
+) -> ProgramResult {
+    let [config, vault, vault_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_admin, false)?;
+
+    vault.check_admin(vault_admin.key)?;
+    vault.set_deposit_mode(deposit_mode);
+    msg!("Deposit mode set to {:?}", deposit_mode);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_associated_token_account, load_signer};
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set fee wallet instruction: [`crate::VaultInstruction::SetFeeWallet`]
+///
+/// Specification:
+/// - The admin must be the vault fee admin and sign the transaction
+/// - The new fee wallet must have an initialized VRT associated token account, otherwise
+///   minting, burning and updating the vault balance would fail
+/// - Fees accrued but not yet minted shall go to the new fee wallet
+pub fn process_set_fee_wallet(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault, vault_fee_admin, new_fee_wallet, new_fee_wallet_vrt_token_account] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_fee_admin, false)?;
+    load_associated_token_account(
+        new_fee_wallet_vrt_token_account,
+        new_fee_wallet.key,
+        &vault.vrt_mint,
+    )?;
+
+    vault.check_fee_admin(vault_fee_admin.key)?;
+
+    vault.fee_wallet = *new_fee_wallet.key;
+    msg!("Fee wallet set to {:?}", new_fee_wallet.key);
+
+    Ok(())
+}
@@ -1,20 +1,25 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
 use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::events::{FeeChangedEvent, VaultEvent};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// Sets the deposit, withdrawal, and reward fees for the vault.
 ///
 /// Specification:
 /// - The fee can only be changed by the vault fee admin. The vault fee admin must sign the transaction.
+/// - The fees can't be changed once the vault is shut down.
 /// - The fees can only be changed at most once per epoch.
 /// - The fees can be changed the epoch after one full epoch has passed since the last fee change.
 /// - The Vault last_fee_change_slot shall be updated to the current slot only if any fees were updated.
 /// - The transaction shall fail if no fees are provided to update.
 /// - The transaction shall fail if any of the fees exceed 10_000 bps.
+/// - Deposit and withdrawal fee changes shall only take effect the epoch after the fee change, giving
+///   stakers a full epoch to exit before a fee increase. The reward fee takes effect immediately.
+/// - A [`jito_vault_sdk::events::VaultEvent::FeeChanged`] event shall be emitted with the fees provided.
 pub fn process_set_fees(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -22,19 +27,23 @@ pub fn process_set_fees(
     withdrawal_fee_bps: Option<u16>,
     reward_fee_bps: Option<u16>,
 ) -> ProgramResult {
-    let [config, vault, vault_fee_admin] = accounts else {
+    let [config, vault_info, vault_fee_admin] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault, true)?;
-    let mut vault_data = vault.data.borrow_mut();
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
     load_signer(vault_fee_admin, false)?;
 
+    let slot = get_clock()?.slot;
+
     vault.check_fee_admin(vault_fee_admin.key)?;
-    vault.check_can_modify_fees(Clock::get()?.slot, config.epoch_length())?;
+    vault.check_is_shut_down()?;
+    vault.check_can_modify_fees(slot, vault.epoch_length(config.epoch_length()))?;
+    vault.activate_pending_fees(slot, vault.epoch_length(config.epoch_length()));
 
     if deposit_fee_bps.is_none() && withdrawal_fee_bps.is_none() && reward_fee_bps.is_none() {
         msg!("No fees provided for update");
@@ -63,7 +72,15 @@ pub fn process_set_fees(
         vault.set_reward_fee_bps(reward_fee_bps)?;
     }
 
-    vault.set_last_fee_change_slot(Clock::get()?.slot);
+    vault.set_last_fee_change_slot(slot);
+
+    VaultEvent::FeeChanged(FeeChangedEvent {
+        vault: *vault_info.key,
+        deposit_fee_bps,
+        withdrawal_fee_bps,
+        reward_fee_bps,
+    })
+    .emit();
 
     Ok(())
 }
@@ -0,0 +1,37 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set flash deposit protection instruction: [`crate::VaultInstruction::SetFlashDepositProtection`]
+///
+/// Specification:
+/// - The admin must be the vault capacity admin and sign the transaction
+/// - Once enabled, MintTo shall record the deposit slot in the depositor's VaultDepositor and Burn
+///   shall fail in the same slot as the staker's last deposit
+/// - Deposits made before enabling aren't tracked, so they can't block burns
+pub fn process_set_flash_deposit_protection(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    enabled: bool,
+) -> ProgramResult {
+    let [config, vault, vault_capacity_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_capacity_admin, false)?;
+
+    vault.check_capacity_admin(vault_capacity_admin.key)?;
+
+    vault.set_flash_deposit_protection(enabled);
+    msg!("Flash deposit protection set to {}", enabled);
+
+    Ok(())
+}
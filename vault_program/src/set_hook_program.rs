@@ -0,0 +1,77 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::{
+    error::VaultError,
+    hook::{vault_hook, VaultHookInstruction},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey, system_program,
+};
+
+/// Processes the set hook program instruction: [`crate::VaultInstruction::SetHookProgram`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The hook program shall be an executable program other than the vault program, or the
+///   system program to remove the hook
+pub fn process_set_hook_program(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault_info, vault_admin, hook_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_admin, false)?;
+
+    vault.check_admin(vault_admin.key)?;
+
+    if hook_program.key.eq(&system_program::id()) {
+        vault.hook_program = Pubkey::default();
+        msg!("Hook program removed");
+        return Ok(());
+    }
+
+    if !hook_program.executable || hook_program.key.eq(program_id) {
+        msg!("Hook program {} is not a valid program", hook_program.key);
+        return Err(VaultError::VaultHookProgramInvalid.into());
+    }
+
+    vault.hook_program = *hook_program.key;
+    msg!("Hook program set to {}", hook_program.key);
+
+    Ok(())
+}
+
+/// Invokes the vault's hook program, if it has one, with `instruction` for `user`'s deposit or
+/// withdrawal.
+///
+/// The hook program is looked up by its address among `optional_accounts`, so it can be passed
+/// anywhere after the instruction's other accounts. The vault account must not be borrowed.
+pub fn invoke_vault_hook<'info>(
+    hook_program: &Pubkey,
+    vault_info: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    optional_accounts: &[AccountInfo<'info>],
+    instruction: VaultHookInstruction,
+) -> ProgramResult {
+    if hook_program.eq(&Pubkey::default()) {
+        return Ok(());
+    }
+
+    let Some(hook_program_info) = optional_accounts
+        .iter()
+        .find(|account| account.key.eq(hook_program))
+    else {
+        msg!("Vault has a hook program, the hook program account is required");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    invoke(
+        &vault_hook(hook_program, vault_info.key, user.key, &instruction),
+        &[vault_info.clone(), user.clone(), hook_program_info.clone()],
+    )
+}
@@ -0,0 +1,129 @@
+use std::mem::size_of;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_associated_token_account, load_signer, load_system_program},
+    sysvar::get_rent,
+};
+use jito_vault_core::{config::Config, vault::Vault, vault_insurance::VaultInsurance};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, system_program,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+/// Processes the set insurance params instruction: [`crate::VaultInstruction::SetInsuranceParams`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The vault insurance shall be at the canonical PDA for the vault, and is created the first
+///   time the params are set
+/// - The fee share shall not exceed [`jito_vault_core::MAX_FEE_BPS`]
+/// - The vault shall require the vault insurance and its VRT token account in every instruction
+///   that mints fees or slashes from then on, see [`load_vault_insurance`]
+pub fn process_set_insurance_params(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: u16,
+) -> ProgramResult {
+    let [config, vault_info, vault_insurance, vault_admin, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_admin, false)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    vault.check_admin(vault_admin.key)?;
+
+    if vault_insurance.owner.eq(&system_program::id()) && vault_insurance.data_is_empty() {
+        // The VaultInsurance shall be at the canonical PDA
+        let (vault_insurance_pubkey, vault_insurance_bump, mut vault_insurance_seeds) =
+            VaultInsurance::find_program_address(program_id, vault_info.key);
+        vault_insurance_seeds.push(vec![vault_insurance_bump]);
+        if vault_insurance_pubkey.ne(vault_insurance.key) {
+            msg!("Vault insurance is not at the correct PDA");
+            return Err(VaultError::VaultAccountNotCanonicalPda.into());
+        }
+
+        msg!(
+            "Initializing VaultInsurance at address {}",
+            vault_insurance.key
+        );
+        create_account(
+            payer,
+            vault_insurance,
+            system_program,
+            program_id,
+            &get_rent()?,
+            8_u64
+                .checked_add(size_of::<VaultInsurance>() as u64)
+                .unwrap(),
+            &vault_insurance_seeds,
+        )?;
+
+        let mut vault_insurance_data = vault_insurance.try_borrow_mut_data()?;
+        vault_insurance_data[0] = VaultInsurance::DISCRIMINATOR;
+        let vault_insurance =
+            VaultInsurance::try_from_slice_unchecked_mut(&mut vault_insurance_data)?;
+        *vault_insurance = VaultInsurance::new(*vault_info.key, vault_insurance_bump);
+
+        vault.set_insurance_enabled();
+    }
+
+    VaultInsurance::load(program_id, vault_insurance, vault_info, true)?;
+    let mut vault_insurance_data = vault_insurance.data.borrow_mut();
+    let vault_insurance = VaultInsurance::try_from_slice_unchecked_mut(&mut vault_insurance_data)?;
+    vault_insurance.set_fee_bps(fee_bps)?;
+    msg!("Insurance fee share set to {} bps", fee_bps);
+
+    Ok(())
+}
+
+/// Finds the vault's [`VaultInsurance`] and its VRT token account if the vault has an insurance
+/// fund.
+///
+/// The accounts are looked up by their addresses among `optional_accounts`, so they can be passed
+/// anywhere after the instruction's other accounts, and are required once the vault enabled
+/// insurance.
+pub fn load_vault_insurance<'a, 'info>(
+    program_id: &Pubkey,
+    vault_info: &AccountInfo<'info>,
+    vault: &Vault,
+    optional_accounts: &'a [AccountInfo<'info>],
+) -> Result<Option<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)>, ProgramError> {
+    if !vault.insurance_enabled() {
+        return Ok(None);
+    }
+
+    let vault_insurance_pubkey = VaultInsurance::find_program_address(program_id, vault_info.key).0;
+    let insurance_vrt_token_account_pubkey =
+        get_associated_token_address(&vault_insurance_pubkey, &vault.vrt_mint);
+    let find = |pubkey: &Pubkey| {
+        optional_accounts
+            .iter()
+            .find(|account| account.key.eq(pubkey))
+    };
+    let (Some(vault_insurance), Some(insurance_vrt_token_account)) = (
+        find(&vault_insurance_pubkey),
+        find(&insurance_vrt_token_account_pubkey),
+    ) else {
+        msg!("Vault has insurance enabled, the vault insurance and its VRT token account are required");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    VaultInsurance::load(program_id, vault_insurance, vault_info, true)?;
+    load_associated_token_account(
+        insurance_vrt_token_account,
+        vault_insurance.key,
+        &vault.vrt_mint,
+    )?;
+
+    Ok(Some((vault_insurance, insurance_vrt_token_account)))
+}
@@ -0,0 +1,38 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set max total slashable per epoch instruction: [`crate::VaultInstruction::SetMaxTotalSlashablePerEpoch`]
+///
+/// Specification:
+/// - The admin must be the vault slasher admin and sign the transaction
+/// - The max total slashable per epoch shall not exceed 10,000 basis points
+/// - Zero removes the limit, leaving only each slasher's maximum slashable amount per operator
+pub fn process_set_max_total_slashable_per_epoch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_total_slashable_bps_per_epoch: u16,
+) -> ProgramResult {
+    let [config, vault, vault_slasher_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_slasher_admin, false)?;
+
+    vault.check_slasher_admin(vault_slasher_admin.key)?;
+    vault.set_max_total_slashable_bps_per_epoch(max_total_slashable_bps_per_epoch)?;
+    msg!(
+        "Max total slashable per epoch set to {} bps",
+        max_total_slashable_bps_per_epoch
+    );
+
+    Ok(())
+}
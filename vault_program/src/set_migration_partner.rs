@@ -0,0 +1,49 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, system_program,
+};
+
+/// Processes the set migration partner instruction: [`crate::VaultInstruction::SetMigrationPartner`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The migration partner shall be another vault, or the system program to remove the partner
+/// - Migrations with [`crate::VaultInstruction::MigrateToVault`] skip the withdrawal fee once the
+///   partner names this vault as its migration partner too
+pub fn process_set_migration_partner(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, vault_admin, migration_partner] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_admin, false)?;
+
+    vault.check_admin(vault_admin.key)?;
+
+    if migration_partner.key.eq(&system_program::id()) {
+        vault.set_migration_partner(Pubkey::default());
+        msg!("Migration partner removed");
+        return Ok(());
+    }
+
+    if migration_partner.key.eq(vault_info.key) {
+        msg!("Vault can't be its own migration partner");
+        return Err(VaultError::VaultMigrationInvalid.into());
+    }
+    Vault::load(program_id, migration_partner, false)?;
+
+    vault.set_migration_partner(*migration_partner.key);
+    msg!("Migration partner set to {}", migration_partner.key);
+
+    Ok(())
+}
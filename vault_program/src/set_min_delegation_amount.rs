@@ -0,0 +1,35 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set min delegation amount instruction: [`crate::VaultInstruction::SetMinDelegationAmount`]
+///
+/// Specification:
+/// - The admin must be the vault delegation admin and sign the transaction
+/// - Operator delegations that fall below the new minimum are swept back to idle during the next
+///   vault update
+pub fn process_set_min_delegation_amount(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let [config, vault, vault_delegation_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_delegation_admin, false)?;
+
+    vault.check_delegation_admin(vault_delegation_admin.key)?;
+    vault.set_min_delegation_amount(amount);
+    msg!("Min delegation amount set to {}", amount);
+
+    Ok(())
+}
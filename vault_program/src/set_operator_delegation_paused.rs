@@ -0,0 +1,54 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set operator delegation paused instruction: [`crate::VaultInstruction::SetOperatorDelegationPaused`]
+///
+/// Specification:
+/// - The admin must be the vault delegation admin and sign the transaction
+/// - While paused, the vault shall reject AddDelegation to the operator
+/// - While paused, the stake on the operator shall be cooled down during the next vault update
+pub fn process_set_operator_delegation_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    is_paused: bool,
+) -> ProgramResult {
+    let [config, vault_info, operator, vault_operator_delegation, vault_delegation_admin] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    VaultOperatorDelegation::load(
+        program_id,
+        vault_operator_delegation,
+        vault_info,
+        operator,
+        true,
+    )?;
+    let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
+    load_signer(vault_delegation_admin, false)?;
+
+    vault.check_delegation_admin(vault_delegation_admin.key)?;
+
+    vault_operator_delegation.set_delegation_paused(is_paused);
+    msg!(
+        "Delegation to operator {} paused set to {}",
+        operator.key,
+        is_paused
+    );
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set operator max delegation instruction: [`crate::VaultInstruction::SetOperatorMaxDelegation`]
+///
+/// Specification:
+/// - The admin must be the vault delegation admin and sign the transaction
+/// - A maximum of zero removes the operator's maximum delegation amount
+/// - Lowering the maximum below the operator's current stake doesn't undelegate any stake, it only
+///   blocks further delegations until the stake is cooled down below it
+pub fn process_set_operator_max_delegation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_delegation_amount: u64,
+) -> ProgramResult {
+    let [config, vault_info, operator, vault_operator_delegation, vault_delegation_admin] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    VaultOperatorDelegation::load(
+        program_id,
+        vault_operator_delegation,
+        vault_info,
+        operator,
+        true,
+    )?;
+    let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
+    load_signer(vault_delegation_admin, false)?;
+
+    vault.check_delegation_admin(vault_delegation_admin.key)?;
+
+    vault_operator_delegation.set_max_delegation_amount(max_delegation_amount);
+    msg!(
+        "Max delegation amount for operator {} set to {}",
+        operator.key,
+        max_delegation_amount
+    );
+
+    Ok(())
+}
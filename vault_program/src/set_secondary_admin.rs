@@ -1,7 +1,7 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_vault_core::{config::Config, vault::Vault};
-use jito_vault_sdk::instruction::VaultAdminRole;
+use jito_vault_sdk::{error::VaultError, instruction::VaultAdminRole};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
@@ -47,8 +47,9 @@ pub fn process_set_secondary_admin(
             msg!("Capacity admin set to {:?}", new_admin.key);
         }
         VaultAdminRole::FeeWallet => {
-            vault.fee_wallet = *new_admin.key;
-            msg!("Fee wallet set to {:?}", new_admin.key);
+            // SetFeeWallet checks the new fee wallet can receive fees
+            msg!("The fee wallet can only be set by the fee admin with SetFeeWallet");
+            return Err(VaultError::VaultFeeAdminInvalid.into());
         }
         VaultAdminRole::MintBurnAdmin => {
             vault.mint_burn_admin = *new_admin.key;
@@ -62,6 +63,14 @@ pub fn process_set_secondary_admin(
             vault.fee_admin = *new_admin.key;
             msg!("Fee admin set to {:?}", new_admin.key);
         }
+        VaultAdminRole::PauseAdmin => {
+            vault.pause_admin = *new_admin.key;
+            msg!("Pause admin set to {:?}", new_admin.key);
+        }
+        VaultAdminRole::MetadataAdmin => {
+            vault.metadata_admin = *new_admin.key;
+            msg!("Metadata admin set to {:?}", new_admin.key);
+        }
     }
 
     Ok(())
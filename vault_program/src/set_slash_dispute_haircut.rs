@@ -0,0 +1,37 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set slash dispute haircut instruction: [`crate::VaultInstruction::SetSlashDisputeHaircut`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - Once enabled, Burn, BurnWithdrawTicket and the other exits shall pay out VRT at the exchange
+///   rate net of the vault's pending slashes while a SlashProposal against the vault is
+///   outstanding, so withdrawals enqueued during a slash dispute can't dodge it either
+pub fn process_set_slash_dispute_haircut(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    enabled: bool,
+) -> ProgramResult {
+    let [config, vault, vault_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_admin, false)?;
+
+    vault.check_admin(vault_admin.key)?;
+
+    vault.set_slash_dispute_haircut(enabled);
+    msg!("Slash dispute haircut set to {}", enabled);
+
+    Ok(())
+}
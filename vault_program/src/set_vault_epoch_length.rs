@@ -0,0 +1,43 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set vault epoch length instruction: [`crate::VaultInstruction::SetVaultEpochLength`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The epoch length shall be within the config's vault epoch length bounds, or zero to fall back
+///   to the config's epoch length
+/// - The vault shall be up-to-date and no stake or VRT shall be cooling down, since delegations
+///   and withdrawals record cooldowns by epoch
+/// - The vault's epoch length applies to its updates, delegations and withdrawal maturity, NCN
+///   tickets and slashing keep the config's epoch length
+pub fn process_set_vault_epoch_length(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch_length_slots: u64,
+) -> ProgramResult {
+    let [config, vault, vault_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_admin, false)?;
+
+    vault.check_admin(vault_admin.key)?;
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
+    config.check_vault_epoch_length(epoch_length_slots)?;
+    vault.set_epoch_length_slots(epoch_length_slots)?;
+    msg!("Vault epoch length set to {} slots", epoch_length_slots);
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set vault paused instruction: [`crate::VaultInstruction::SetVaultPaused`]
+///
+/// Specification:
+/// - The admin must be the vault pause admin and sign the transaction
+/// - While paused, the vault shall reject MintTo, Burn, EnqueueWithdrawal, BurnWithdrawTicket,
+///   AddDelegation and CooldownDelegation
+pub fn process_set_vault_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    is_paused: bool,
+) -> ProgramResult {
+    let [config, vault, vault_pause_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_pause_admin, false)?;
+
+    vault.check_pause_admin(vault_pause_admin.key)?;
+    vault.set_is_paused(is_paused);
+    msg!("Vault paused set to {}", is_paused);
+
+    Ok(())
+}
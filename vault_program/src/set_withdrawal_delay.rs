@@ -0,0 +1,38 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set withdrawal delay instruction: [`crate::VaultInstruction::SetWithdrawalDelay`]
+///
+/// Specification:
+/// - The admin must be the vault admin and sign the transaction
+/// - The withdrawal delay shall be at most [`jito_vault_core::MAX_WITHDRAWAL_DELAY_EPOCHS`]
+/// - The new delay applies to withdrawals enqueued afterwards, those already enqueued keep the
+///   delay they were enqueued with
+/// - While the delay is non-zero, VRT can't be burned out of idle assets and every withdrawal must
+///   be enqueued
+pub fn process_set_withdrawal_delay(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    withdrawal_delay_epochs: u64,
+) -> ProgramResult {
+    let [config, vault, vault_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(vault_admin, false)?;
+
+    vault.check_admin(vault_admin.key)?;
+    vault.set_withdrawal_delay_epochs(withdrawal_delay_epochs)?;
+    msg!("Withdrawal delay set to {} epochs", withdrawal_delay_epochs);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set withdrawal ticket auto-claim fee instruction: [`crate::VaultInstruction::SetWithdrawalTicketAutoClaimFee`]
+///
+/// Specification:
+/// - The staker shall sign the transaction
+/// - The fee shall not exceed [`jito_vault_core::MAX_FEE_BPS`]
+/// - A non-zero fee opts the ticket into auto-claiming, a fee of zero opts it out
+pub fn process_set_withdrawal_ticket_auto_claim_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    fee_bps: u16,
+) -> ProgramResult {
+    let [config, vault, vault_staker_withdrawal_ticket, staker] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, false)?;
+    VaultStakerWithdrawalTicket::load(program_id, vault_staker_withdrawal_ticket, vault, true)?;
+    let mut vault_staker_withdrawal_ticket_data = vault_staker_withdrawal_ticket.data.borrow_mut();
+    let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked_mut(
+        &mut vault_staker_withdrawal_ticket_data,
+    )?;
+    load_signer(staker, false)?;
+
+    vault_staker_withdrawal_ticket.check_staker(staker.key)?;
+    vault_staker_withdrawal_ticket.set_auto_claim_fee_bps(fee_bps)?;
+
+    msg!("Withdrawal ticket auto-claim fee set to {} bps", fee_bps);
+
+    Ok(())
+}
@@ -1,30 +1,68 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_associated_token_account, load_signer, load_token_program};
+use jito_jsm_core::{
+    loader::{load_associated_token_account, load_signer, load_token_mint, load_token_program},
+    sysvar::get_clock,
+    token::invoke_token,
+};
 use jito_restaking_core::{
     ncn::Ncn, ncn_operator_state::NcnOperatorState,
     ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
     operator::Operator, operator_vault_ticket::OperatorVaultTicket,
 };
+use jito_restaking_sdk::instruction::SlashDestination;
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+    config::Config, vault::Vault, vault_insurance::VaultInsurance,
+    vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
     vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
     vault_operator_delegation::VaultOperatorDelegation,
 };
-use jito_vault_sdk::error::VaultError;
+use jito_vault_sdk::{
+    error::VaultError,
+    events::{SlashedEvent, VaultEvent},
+};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    program_pack::Pack, pubkey::Pubkey,
+};
+use spl_token::{
+    instruction::{burn, transfer},
+    state::Account,
+};
+
+use crate::{
+    initialize_vault_epoch_stats::record_vault_epoch_stats,
+    set_insurance_params::load_vault_insurance,
 };
-use spl_token::instruction::transfer;
 
 /// Processes the vault slash instruction: [`crate::VaultInstruction::Slash`]
+///
+/// Slashes immediately, so it's only allowed while the config's slash veto window is disabled.
+/// Otherwise slashes go through [`crate::VaultInstruction::ProposeSlash`]. The vault token account
+/// shall be open and hold at least the tokens deposited.
+///
+/// The slashed funds are sent where the NCN vault slasher ticket's slash destination says, see
+/// [`load_slash_destination`].
+///
+/// Besides each slasher's maximum slashable amount per operator, the slashes in an epoch across
+/// all slashers shall not exceed the vault's max total slashable per epoch, see
+/// [`Vault::record_epoch_slash`].
+///
+/// If the vault has insurance enabled, the vault insurance, its VRT token account and the VRT mint
+/// must be passed in after the token program, and the insurance fund absorbs the slash first, see
+/// [`absorb_slash_with_insurance`].
+///
+/// If the vault has epoch stats enabled, the VaultEpochStats must be passed in after the token
+/// program and shall record the slash. A [`jito_vault_sdk::events::VaultEvent::Slashed`] event is
+/// emitted.
 pub fn process_slash(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     slash_amount: u64,
 ) -> ProgramResult {
-    let [config, vault_info, ncn, operator, slasher, ncn_operator_state, ncn_vault_ticket, operator_vault_ticket, vault_ncn_ticket, vault_operator_delegation, ncn_vault_slasher_ticket, vault_ncn_slasher_ticket, vault_ncn_slasher_operator_ticket, vault_token_account, slasher_token_account, token_program] =
-        accounts
+    let (required_accounts, optional_accounts) = accounts.split_at(16);
+
+    let [config, vault_info, ncn, operator, slasher, ncn_operator_state, ncn_vault_ticket, operator_vault_ticket, vault_ncn_ticket, vault_operator_delegation, ncn_vault_slasher_ticket, vault_ncn_slasher_ticket, vault_ncn_slasher_operator_ticket, vault_token_account, slash_destination, token_program] =
+        required_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -32,9 +70,13 @@ pub fn process_slash(
     Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
+    config.check_slash_veto_window_disabled()?;
     Vault::load(program_id, vault_info, true)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    // The vault shall be up-to-date before slashing, checked first so a stale vault isn't reported
+    // as a missing slasher operator ticket for the new epoch
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
     Ncn::load(&config.restaking_program, ncn, false)?;
     Operator::load(&config.restaking_program, operator, false)?;
     load_signer(slasher, false)?;
@@ -101,7 +143,7 @@ pub fn process_slash(
     let vault_ncn_slasher_ticket_data = vault_ncn_slasher_ticket.data.borrow();
     let vault_ncn_slasher_ticket =
         VaultNcnSlasherTicket::try_from_slice_unchecked(&vault_ncn_slasher_ticket_data)?;
-    let ncn_epoch = Clock::get()?
+    let ncn_epoch = get_clock()?
         .slot
         .checked_div(config.epoch_length())
         .unwrap();
@@ -121,16 +163,20 @@ pub fn process_slash(
         VaultNcnSlasherOperatorTicket::try_from_slice_unchecked_mut(
             &mut vault_ncn_slasher_operator_ticket_data,
         )?;
-    load_associated_token_account(vault_token_account, vault_info.key, &vault.supported_mint)?;
-    load_associated_token_account(slasher_token_account, slasher.key, &vault.supported_mint)?;
+    let vault_token_balance = vault.load_token_account(vault_info.key, vault_token_account)?;
+    vault.check_token_account_balance(vault_token_balance)?;
+    let slash_destination_kind = load_slash_destination(
+        ncn_vault_slasher_ticket,
+        config,
+        vault,
+        slasher,
+        slash_destination,
+    )?;
     load_token_program(token_program)?;
 
-    let slot = Clock::get()?.slot;
+    let slot = get_clock()?.slot;
     let epoch_length = config.epoch_length();
 
-    // The vault shall be up-to-date before slashing
-    vault.check_update_state_ok(Clock::get()?.slot, epoch_length)?;
-
     // All ticket states shall be active or cooling down
     check_states_active_or_cooling_down(
         vault_ncn_slasher_ticket,
@@ -143,50 +189,60 @@ pub fn process_slash(
         epoch_length,
     )?;
 
-    // The amount slashed for this operator shall not exceed the maximum slashable amount per epoch
-    vault_ncn_slasher_operator_ticket.check_slashing_amount_not_exceeded(
-        slash_amount,
-        vault_ncn_slasher_ticket.max_slashable_per_epoch(),
-    )?;
+    // The amount slashed for this operator shall not exceed the maximum slashable amount per epoch,
+    // the lower of what the vault agreed to and what the NCN currently allows
+    let max_slashable_per_epoch = vault_ncn_slasher_ticket
+        .max_slashable_per_epoch()
+        .min(ncn_vault_slasher_ticket.max_slashable_per_epoch_at(ncn_epoch));
+    vault_ncn_slasher_operator_ticket
+        .check_slashing_amount_not_exceeded(slash_amount, max_slashable_per_epoch)?;
 
-    // The VaultOperatorDelegation shall be slashed and the vault amounts shall be updated
-    slash_and_update_vault(
+    // The slashes this epoch across all slashers shall not exceed the vault's max total slashable
+    vault.record_epoch_slash(slash_amount, ncn_epoch)?;
+
+    // The insurance fund shall absorb the slash before the exchange rate drops
+    absorb_slash_with_insurance(
+        program_id,
+        vault_info,
         vault,
-        vault_operator_delegation,
-        vault_ncn_slasher_operator_ticket,
+        optional_accounts,
         slash_amount,
     )?;
 
+    // The VaultOperatorDelegation shall be slashed and the vault amounts shall be updated
+    slash_and_update_vault(vault, vault_operator_delegation, slash_amount)?;
+    vault_ncn_slasher_operator_ticket.increment_slashed(slash_amount)?;
+    record_vault_epoch_stats(program_id, vault_info, vault, optional_accounts, |stats| {
+        stats.record_slash(ncn_epoch, slash_amount)
+    })?;
+
+    VaultEvent::Slashed(SlashedEvent {
+        vault: *vault_info.key,
+        ncn: *ncn.key,
+        operator: *operator.key,
+        slasher: *slasher.key,
+        amount: slash_amount,
+    })
+    .emit();
+
     // transfer the slashed funds
     let mut vault_seeds = Vault::seeds(&vault.base);
     vault_seeds.push(vec![vault.bump]);
-    let vault_seeds_slice = vault_seeds
-        .iter()
-        .map(|seed| seed.as_slice())
-        .collect::<Vec<&[u8]>>();
     drop(vault_data);
-    invoke_signed(
-        &transfer(
-            &spl_token::id(),
-            vault_token_account.key,
-            slasher_token_account.key,
-            vault_info.key,
-            &[],
-            slash_amount,
-        )?,
-        &[
-            vault_token_account.clone(),
-            slasher_token_account.clone(),
-            vault_info.clone(),
-        ],
-        &[vault_seeds_slice.as_slice()],
-    )?;
-
-    Ok(())
+    transfer_slashed_funds(
+        vault_info,
+        vault_token_account,
+        slash_destination,
+        slash_destination_kind,
+        &vault_seeds,
+        slash_amount,
+    )
 }
 
+/// All ticket states shall be active or cooling down, and the NCN's relationships with the
+/// operator and vault shall not have expired, for the operator to be slashable
 #[allow(clippy::too_many_arguments)]
-fn check_states_active_or_cooling_down(
+pub fn check_states_active_or_cooling_down(
     vault_ncn_slasher_ticket: &VaultNcnSlasherTicket,
     ncn_vault_slasher_ticket: &NcnVaultSlasherTicket,
     ncn_operator_state: &NcnOperatorState,
@@ -224,6 +280,10 @@ fn check_states_active_or_cooling_down(
         msg!("Operator opt-in to NCN is not active or in cooldown");
         return Err(VaultError::NcnOperatorStateUnslashable.into());
     }
+    if ncn_operator_state.is_expired(slot) {
+        msg!("NCN operator state expired");
+        return Err(VaultError::NcnOperatorStateUnslashable.into());
+    }
     if !operator_vault_ticket
         .state
         .is_active_or_cooldown(slot, epoch_length)
@@ -245,14 +305,17 @@ fn check_states_active_or_cooling_down(
         msg!("NCN vault ticket is not active or in cooldown");
         return Err(VaultError::NcnVaultTicketUnslashable.into());
     }
+    if ncn_vault_ticket.is_expired(slot) {
+        msg!("NCN vault ticket expired");
+        return Err(VaultError::NcnVaultTicketUnslashable.into());
+    }
     Ok(())
 }
 
 /// Slashes the vault and updates the vault amounts based on the slashing amount.
-fn slash_and_update_vault(
+pub fn slash_and_update_vault(
     vault: &mut Vault,
     vault_operator_delegation: &mut VaultOperatorDelegation,
-    vault_ncn_slasher_operator_ticket: &mut VaultNcnSlasherOperatorTicket,
     slash_amount: u64,
 ) -> ProgramResult {
     // undo the delegation, slash then accumulate the delegation
@@ -267,6 +330,154 @@ fn slash_and_update_vault(
         .accumulate(&vault_operator_delegation.delegation_state)?;
 
     vault.decrement_tokens_deposited(slash_amount)?;
-    vault_ncn_slasher_operator_ticket.increment_slashed(slash_amount)?;
+    Ok(())
+}
+
+/// Burns the insurance fund's share of the VRT supply the slash would otherwise take from every
+/// staker, so the exchange rate is unchanged by the slash for as long as the fund lasts.
+///
+/// Does nothing if the vault doesn't have insurance enabled. Shall be called before the vault's
+/// tokens deposited are decremented by the slash.
+pub fn absorb_slash_with_insurance<'info>(
+    program_id: &Pubkey,
+    vault_info: &AccountInfo<'info>,
+    vault: &mut Vault,
+    optional_accounts: &[AccountInfo<'info>],
+    slash_amount: u64,
+) -> ProgramResult {
+    let Some((vault_insurance, insurance_vrt_token_account)) =
+        load_vault_insurance(program_id, vault_info, vault, optional_accounts)?
+    else {
+        return Ok(());
+    };
+    let Some(vrt_mint) = optional_accounts
+        .iter()
+        .find(|account| account.key.eq(&vault.vrt_mint))
+    else {
+        msg!("Vault has insurance enabled, the VRT mint is required");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    load_token_mint(vrt_mint)?;
+
+    let insurance_vrt_balance = Account::unpack(&insurance_vrt_token_account.data.borrow())?.amount;
+    let vrt_to_absorb = VaultInsurance::calculate_vrt_to_absorb(
+        slash_amount,
+        vault.tokens_deposited(),
+        vault.vrt_supply(),
+        insurance_vrt_balance,
+    )?;
+    if vrt_to_absorb == 0 {
+        return Ok(());
+    }
+
+    vault.decrement_vrt_supply(vrt_to_absorb)?;
+    let mut vault_insurance_data = vault_insurance.data.borrow_mut();
+    let vault_insurance_account =
+        VaultInsurance::try_from_slice_unchecked_mut(&mut vault_insurance_data)?;
+    vault_insurance_account.record_absorption(vrt_to_absorb)?;
+    let mut vault_insurance_seeds = VaultInsurance::seeds(vault_info.key);
+    vault_insurance_seeds.push(vec![vault_insurance_account.bump]);
+    drop(vault_insurance_data);
+
+    msg!("Insurance fund absorbing slash with {} VRT", vrt_to_absorb);
+    let seed_slices: Vec<&[u8]> = vault_insurance_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect();
+    invoke_token(
+        &burn(
+            &spl_token::id(),
+            insurance_vrt_token_account.key,
+            vrt_mint.key,
+            vault_insurance.key,
+            &[],
+            vrt_to_absorb,
+        )?,
+        &[
+            insurance_vrt_token_account.clone(),
+            vrt_mint.clone(),
+            vault_insurance.clone(),
+        ],
+        &[seed_slices.as_slice()],
+    )?;
+
+    Ok(())
+}
+
+/// Checks the account the slashed funds are sent to against the NCN vault slasher ticket's slash
+/// destination. It shall be the associated token account of the slasher, the NCN's insurance
+/// wallet or the protocol fee wallet, or the vault's supported mint when the slashed funds are
+/// burned.
+pub fn load_slash_destination(
+    ncn_vault_slasher_ticket: &NcnVaultSlasherTicket,
+    config: &Config,
+    vault: &Vault,
+    slasher: &AccountInfo,
+    slash_destination: &AccountInfo,
+) -> Result<SlashDestination, ProgramError> {
+    let slash_destination_kind = ncn_vault_slasher_ticket.slash_destination()?;
+    let owner = match slash_destination_kind {
+        SlashDestination::Slasher => slasher.key,
+        SlashDestination::Insurance => &ncn_vault_slasher_ticket.insurance_wallet,
+        SlashDestination::Treasury => &config.protocol_fee_wallet,
+        SlashDestination::Burn => {
+            if slash_destination.key.ne(&vault.supported_mint) {
+                msg!("Burned slashed funds need the vault's supported mint");
+                return Err(VaultError::VaultSlashDestinationInvalid.into());
+            }
+            return Ok(slash_destination_kind);
+        }
+    };
+    if load_associated_token_account(slash_destination, owner, &vault.supported_mint).is_err() {
+        msg!(
+            "Slashed funds shall be sent to the token account of {}",
+            owner
+        );
+        return Err(VaultError::VaultSlashDestinationInvalid.into());
+    }
+    Ok(slash_destination_kind)
+}
+
+/// Sends the slashed funds from the vault to the slash destination, or burns them, signed for by
+/// the vault PDA with `vault_seeds` (including the bump).
+pub fn transfer_slashed_funds<'info>(
+    vault_info: &AccountInfo<'info>,
+    vault_token_account: &AccountInfo<'info>,
+    slash_destination: &AccountInfo<'info>,
+    slash_destination_kind: SlashDestination,
+    vault_seeds: &[Vec<u8>],
+    slash_amount: u64,
+) -> ProgramResult {
+    let vault_seeds_slice = vault_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect::<Vec<&[u8]>>();
+    let ix = match slash_destination_kind {
+        SlashDestination::Burn => burn(
+            &spl_token::id(),
+            vault_token_account.key,
+            slash_destination.key,
+            vault_info.key,
+            &[],
+            slash_amount,
+        )?,
+        _ => transfer(
+            &spl_token::id(),
+            vault_token_account.key,
+            slash_destination.key,
+            vault_info.key,
+            &[],
+            slash_amount,
+        )?,
+    };
+    invoke_token(
+        &ix,
+        &[
+            vault_token_account.clone(),
+            slash_destination.clone(),
+            vault_info.clone(),
+        ],
+        &[vault_seeds_slice.as_slice()],
+    )?;
     Ok(())
 }
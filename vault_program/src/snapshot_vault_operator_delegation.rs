@@ -0,0 +1,128 @@
+use std::mem::size_of;
+
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+    sysvar::{get_clock, get_rent},
+};
+use jito_restaking_core::operator::Operator;
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+    vault_operator_delegation_snapshot::VaultOperatorDelegationSnapshot,
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the snapshot vault operator delegation instruction: [`crate::VaultInstruction::SnapshotVaultOperatorDelegation`]
+///
+/// Anyone can take the snapshot, paying for the account. While the vault is being updated,
+/// delegations can't change, so a snapshot taken between the operator delegation's crank and the
+/// [`crate::VaultInstruction::CloseVaultUpdateStateTracker`] holds its state at the start of the
+/// epoch.
+///
+/// Specification:
+/// - The VaultUpdateStateTracker shall be the current vault epoch's and the operator delegation
+///   shall have been cranked in it
+/// - The VaultOperatorDelegationSnapshot shall be at the canonical PDA for the vault, operator and
+///   current epoch, so only one snapshot can be taken per operator and epoch
+/// - The snapshot shall hold the operator delegation's index and delegation state
+pub fn process_snapshot_vault_operator_delegation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, operator, vault_operator_delegation, vault_update_state_tracker, vault_operator_delegation_snapshot, payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    Operator::load(&config.restaking_program, operator, false)?;
+    VaultOperatorDelegation::load(
+        program_id,
+        vault_operator_delegation,
+        vault_info,
+        operator,
+        false,
+    )?;
+    let vault_operator_delegation_data = vault_operator_delegation.data.borrow();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?;
+    let slot = get_clock()?.slot;
+    let ncn_epoch = slot.checked_div(config.epoch_length()).unwrap();
+    // The tracker is for the vault's own epoch when the vault overrides the epoch length
+    let vault_epoch = slot
+        .checked_div(vault.epoch_length(config.epoch_length()))
+        .unwrap();
+    VaultUpdateStateTracker::load(
+        program_id,
+        vault_update_state_tracker,
+        vault_info,
+        vault_epoch,
+        false,
+    )?;
+    let vault_update_state_tracker_data = vault_update_state_tracker.data.borrow();
+    let vault_update_state_tracker =
+        VaultUpdateStateTracker::try_from_slice_unchecked(&vault_update_state_tracker_data)?;
+    load_system_account(vault_operator_delegation_snapshot, true)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    vault_update_state_tracker.check_cranked(vault_operator_delegation.index())?;
+
+    // The VaultOperatorDelegationSnapshot shall be at the canonical PDA
+    let (snapshot_pubkey, snapshot_bump, mut snapshot_seeds) =
+        VaultOperatorDelegationSnapshot::find_program_address(
+            program_id,
+            vault_info.key,
+            operator.key,
+            ncn_epoch,
+        );
+    snapshot_seeds.push(vec![snapshot_bump]);
+    if snapshot_pubkey.ne(vault_operator_delegation_snapshot.key) {
+        msg!("Vault operator delegation snapshot is not at the correct PDA");
+        return Err(VaultError::VaultAccountNotCanonicalPda.into());
+    }
+
+    msg!(
+        "Initializing VaultOperatorDelegationSnapshot at address {}",
+        vault_operator_delegation_snapshot.key
+    );
+    create_account(
+        payer,
+        vault_operator_delegation_snapshot,
+        system_program,
+        program_id,
+        &get_rent()?,
+        8_u64
+            .checked_add(size_of::<VaultOperatorDelegationSnapshot>() as u64)
+            .unwrap(),
+        &snapshot_seeds,
+    )?;
+
+    let mut snapshot_data = vault_operator_delegation_snapshot.try_borrow_mut_data()?;
+    snapshot_data[0] = VaultOperatorDelegationSnapshot::DISCRIMINATOR;
+    let snapshot =
+        VaultOperatorDelegationSnapshot::try_from_slice_unchecked_mut(&mut snapshot_data)?;
+    *snapshot = VaultOperatorDelegationSnapshot::new(
+        *vault_info.key,
+        *operator.key,
+        ncn_epoch,
+        vault_operator_delegation.index(),
+        vault_operator_delegation.delegation_state,
+        slot,
+        snapshot_bump,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the unban operator instruction: [`crate::VaultInstruction::UnbanOperator`]
+///
+/// Specification:
+/// - The admin must be the vault operator admin and sign the transaction
+/// - The operator shall be banned
+/// - The ban's reason shall be cleared
+pub fn process_unban_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault_info, operator, vault_operator_delegation, vault_operator_admin] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    VaultOperatorDelegation::load(
+        program_id,
+        vault_operator_delegation,
+        vault_info,
+        operator,
+        true,
+    )?;
+    let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
+    load_signer(vault_operator_admin, false)?;
+
+    vault.check_operator_admin(vault_operator_admin.key)?;
+
+    vault_operator_delegation.unban()?;
+    msg!("Operator {} unbanned", operator.key);
+
+    Ok(())
+}
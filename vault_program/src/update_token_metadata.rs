@@ -12,6 +12,13 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey,
 };
 
+/// Processes the update token metadata instruction: [`crate::VaultInstruction::UpdateTokenMetadata`]
+///
+/// Specification:
+/// - The vault's metadata admin must sign
+/// - The metadata account must belong to the vault's VRT mint
+/// - The name, symbol and uri are updated through a CPI into the Metaplex Token Metadata program,
+///   signed by the vault PDA as update authority
 pub fn process_update_token_metadata(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -31,7 +38,7 @@ pub fn process_update_token_metadata(
     load_mpl_metadata(metadata, vrt_mint.key)?;
     load_mpl_metadata_program(mpl_token_metadata_program)?;
 
-    vault.check_admin(admin.key)?;
+    vault.check_metadata_admin(admin.key)?;
     vault.check_vrt_mint(vrt_mint.key)?;
 
     let update_metadata_accounts_instruction = update_metadata_accounts_v2(
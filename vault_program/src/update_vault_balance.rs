@@ -1,19 +1,41 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_associated_token_account, load_token_mint, load_token_program};
-use jito_vault_core::{config::Config, vault::Vault};
+use jito_jsm_core::{
+    loader::{load_associated_token_account, load_token_mint, load_token_program},
+    sysvar::get_clock,
+    token::invoke_token,
+};
+use jito_vault_core::{config::Config, vault::Vault, vault_insurance::VaultInsurance};
+use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program::invoke_signed, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
-    sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
-use spl_token::{instruction::mint_to, state::Account};
+use spl_token::instruction::mint_to;
+
+use crate::{admin_set_crank_reward::pay_crank_reward, set_insurance_params::load_vault_insurance};
 
+/// Processes the update vault balance instruction: [`crate::VaultInstruction::UpdateVaultBalance`]
+///
+/// Specification:
+/// - The vault must be up-to-date
+/// - The vault token account balance must be at least the tokens deposited, otherwise the vault
+///   needs [`crate::VaultInstruction::ReconcileVaultBalance`]
+/// - The reward fee shall be charged on any tokens transferred into the vault since the last update
+/// - The deposit fees accrued by MintTo shall be minted to the fee wallet at most once per epoch
+/// - The protocol fee portion of the minted fees shall be minted to the protocol fee wallet, whose
+///   VRT token account is only checked when there is a protocol fee to mint
+/// - When the vault has insurance enabled, the insurance fee share of the vault's portion shall be
+///   minted to the vault insurance's VRT token account instead of the fee wallet
+/// - The first signer among the optional accounts shall be paid the config's crank reward if the
+///   vault's balance changed or fees were minted, and no crank reward was paid this epoch, by this
+///   instruction or by [`crate::VaultInstruction::CloseVaultUpdateStateTracker`]
 pub fn process_update_vault_balance(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault_info, vault_token_account, vrt_mint, vault_fee_token_account, token_program] =
-        accounts
+    let (required_accounts, optional_accounts) = accounts.split_at(7);
+    let [config, vault_info, vault_token_account, vrt_mint, vault_fee_token_account, token_program, protocol_fee_token_account] =
+        required_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -29,48 +51,144 @@ pub fn process_update_vault_balance(
 
     load_token_mint(vrt_mint)?;
     load_associated_token_account(vault_fee_token_account, &vault.fee_wallet, vrt_mint.key)?;
-    load_associated_token_account(vault_token_account, vault_info.key, &vault.supported_mint)?;
+    let new_balance = vault.load_token_account(vault_info.key, vault_token_account)?;
     load_token_program(token_program)?;
+    let insurance_accounts =
+        load_vault_insurance(program_id, vault_info, vault, optional_accounts)?;
+    let cranker = optional_accounts.iter().find(|account| account.is_signer);
 
-    vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+    let slot = get_clock()?.slot;
+    vault.check_update_state_ok(slot, vault.epoch_length(config.epoch_length()))?;
     vault.check_vrt_mint(vrt_mint.key)?;
+    vault.check_token_account_balance(new_balance)?;
 
     // Calculate rewards
-    let new_balance = Account::unpack(&vault_token_account.data.borrow())?.amount;
-
+    let balance_changed = new_balance != vault.tokens_deposited();
     let reward_fee = vault.calculate_rewards_fee(new_balance)?;
 
     // Update state
     vault.set_tokens_deposited(new_balance);
     vault.increment_vrt_supply(reward_fee)?;
 
-    // Mint rewards
-    if reward_fee > 0 {
-        let (_, vault_bump, mut vault_seeds) = Vault::find_program_address(program_id, &vault.base);
-        vault_seeds.push(vec![vault_bump]);
-        let seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
-
-        drop(vault_data);
-
-        msg!("Minting {} VRT rewards to the fee wallet", reward_fee);
-
-        invoke_signed(
-            &mint_to(
-                &spl_token::id(),
-                vrt_mint.key,
-                vault_fee_token_account.key,
-                vault_info.key,
-                &[],
-                reward_fee,
-            )?,
-            &[
-                vrt_mint.clone(),
-                vault_fee_token_account.clone(),
-                vault_info.clone(),
-            ],
-            &[&seed_slices],
+    // Accrued deposit fees are already included in the VRT supply
+    let vrt_fees_accrued =
+        vault.take_vrt_fees_accrued(slot, vault.epoch_length(config.epoch_length()));
+    let fee_amount = reward_fee
+        .checked_add(vrt_fees_accrued)
+        .ok_or(VaultError::VaultOverflow)?;
+
+    let protocol_fee_amount = config.calculate_protocol_fee(fee_amount)?;
+    let vault_fee_amount = fee_amount
+        .checked_sub(protocol_fee_amount)
+        .ok_or(VaultError::VaultUnderflow)?;
+    let insurance_fee_amount = match insurance_accounts {
+        Some((vault_insurance, _)) => {
+            let mut vault_insurance_data = vault_insurance.data.borrow_mut();
+            let vault_insurance =
+                VaultInsurance::try_from_slice_unchecked_mut(&mut vault_insurance_data)?;
+            let insurance_fee_amount = vault_insurance.calculate_fee(vault_fee_amount)?;
+            vault_insurance.record_funding(insurance_fee_amount)?;
+            insurance_fee_amount
+        }
+        None => 0,
+    };
+    let vault_fee_amount = vault_fee_amount
+        .checked_sub(insurance_fee_amount)
+        .ok_or(VaultError::VaultUnderflow)?;
+    if protocol_fee_amount > 0 {
+        load_associated_token_account(
+            protocol_fee_token_account,
+            &config.protocol_fee_wallet,
+            vrt_mint.key,
         )?;
     }
 
+    // Only a call that harvests is paid, otherwise any signer could claim the reward for a no-op
+    let cranker = cranker.filter(|_| {
+        (balance_changed || fee_amount > 0)
+            && config.has_crank_reward()
+            && vault.take_crank_reward(slot, vault.epoch_length(config.epoch_length()))
+    });
+
+    let (_, vault_bump, mut vault_seeds) = Vault::find_program_address(program_id, &vault.base);
+    vault_seeds.push(vec![vault_bump]);
+    let seed_slices: Vec<&[u8]> = vault_seeds.iter().map(|seed| seed.as_slice()).collect();
+    drop(vault_data);
+
+    // Mint rewards and accrued fees
+    if fee_amount > 0 {
+        msg!(
+            "Minting {} VRT rewards and {} VRT accrued fees, {} VRT to the fee wallet, {} VRT to the insurance fund and {} VRT to the protocol fee wallet",
+            reward_fee,
+            vrt_fees_accrued,
+            vault_fee_amount,
+            insurance_fee_amount,
+            protocol_fee_amount
+        );
+
+        if vault_fee_amount > 0 {
+            invoke_token(
+                &mint_to(
+                    &spl_token::id(),
+                    vrt_mint.key,
+                    vault_fee_token_account.key,
+                    vault_info.key,
+                    &[],
+                    vault_fee_amount,
+                )?,
+                &[
+                    vrt_mint.clone(),
+                    vault_fee_token_account.clone(),
+                    vault_info.clone(),
+                ],
+                &[&seed_slices],
+            )?;
+        }
+
+        if let Some((_, insurance_vrt_token_account)) = insurance_accounts {
+            if insurance_fee_amount > 0 {
+                invoke_token(
+                    &mint_to(
+                        &spl_token::id(),
+                        vrt_mint.key,
+                        insurance_vrt_token_account.key,
+                        vault_info.key,
+                        &[],
+                        insurance_fee_amount,
+                    )?,
+                    &[
+                        vrt_mint.clone(),
+                        insurance_vrt_token_account.clone(),
+                        vault_info.clone(),
+                    ],
+                    &[&seed_slices],
+                )?;
+            }
+        }
+
+        if protocol_fee_amount > 0 {
+            invoke_token(
+                &mint_to(
+                    &spl_token::id(),
+                    vrt_mint.key,
+                    protocol_fee_token_account.key,
+                    vault_info.key,
+                    &[],
+                    protocol_fee_amount,
+                )?,
+                &[
+                    vrt_mint.clone(),
+                    protocol_fee_token_account.clone(),
+                    vault_info.clone(),
+                ],
+                &[&seed_slices],
+            )?;
+        }
+    }
+
+    if let Some(cranker) = cranker {
+        pay_crank_reward(program_id, config, vault_info, cranker, accounts)?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,74 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{close_program_account, loader::load_signer};
+use jito_vault_core::{
+    config::Config, slash_proposal::SlashProposal, vault::Vault,
+    vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the veto slash instruction: [`crate::VaultInstruction::VetoSlash`]
+///
+/// Specification:
+/// - The slash veto admin must be the config's slash veto admin and sign the transaction
+/// - The SlashProposal shall not have been executed, it can be vetoed even after its veto window
+///   has passed
+/// - The proposed amount shall no longer count against the slasher's maximum slashable amount for
+///   the epoch it was proposed in, nor as pending against the vault
+/// - The SlashProposal account shall be closed, returning its rent to the slasher
+pub fn process_veto_slash(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault_info, ncn, operator, slasher, vault_ncn_slasher_operator_ticket, slash_proposal_info, slash_veto_admin] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    SlashProposal::load(program_id, slash_proposal_info, vault_info, true)?;
+    let slash_proposal_data = slash_proposal_info.data.borrow();
+    let slash_proposal = SlashProposal::try_from_slice_unchecked(&slash_proposal_data)?;
+    slash_proposal.check_parties(ncn.key, operator.key, slasher.key)?;
+    VaultNcnSlasherOperatorTicket::load(
+        program_id,
+        vault_ncn_slasher_operator_ticket,
+        vault_info,
+        ncn,
+        slasher,
+        operator,
+        slash_proposal.epoch(),
+        true,
+    )?;
+    let mut vault_ncn_slasher_operator_ticket_data =
+        vault_ncn_slasher_operator_ticket.data.borrow_mut();
+    let vault_ncn_slasher_operator_ticket =
+        VaultNcnSlasherOperatorTicket::try_from_slice_unchecked_mut(
+            &mut vault_ncn_slasher_operator_ticket_data,
+        )?;
+    load_signer(slash_veto_admin, false)?;
+
+    config.check_slash_veto_admin(slash_veto_admin.key)?;
+
+    let slash_amount = slash_proposal.amount();
+    vault_ncn_slasher_operator_ticket.decrement_slashed(slash_amount)?;
+    vault.release_pending_slash_amount(slash_amount);
+
+    msg!(
+        "SlashVetoed: vault {} operator {} amount {} vetoed by {}",
+        vault_info.key,
+        operator.key,
+        slash_amount,
+        slash_veto_admin.key
+    );
+
+    drop(slash_proposal_data);
+    close_program_account(program_id, slash_proposal_info, slasher)?;
+
+    Ok(())
+}
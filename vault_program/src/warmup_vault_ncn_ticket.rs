@@ -1,11 +1,11 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::{loader::load_signer, sysvar::get_clock};
 use jito_restaking_core::ncn::Ncn;
 use jito_vault_core::{config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket};
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 /// Instruction: [`crate::VaultInstruction::WarmupVaultNcnTicket`]
@@ -33,17 +33,17 @@ pub fn process_warmup_vault_ncn_ticket(
     }
 
     // The vault shall be up-to-date before warming up the NCN
-    vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+    vault.check_update_state_ok(get_clock()?.slot, vault.epoch_length(config.epoch_length()))?;
 
     // The VaultNcnTicket shall be ready to be activated
     let mut vault_ncn_ticket_data = vault_ncn_ticket.data.borrow_mut();
     let vault_ncn_ticket =
         VaultNcnTicket::try_from_slice_unchecked_mut(&mut vault_ncn_ticket_data)?;
-    if !vault_ncn_ticket
+    if let Err(e) = vault_ncn_ticket
         .state
-        .activate(Clock::get()?.slot, config.epoch_length())
+        .activate(get_clock()?.slot, config.epoch_length())
     {
-        msg!("VaultNcnTicket is not ready to be activated");
+        msg!("VaultNcnTicket is not ready to be activated: {}", e);
         return Err(VaultError::VaultNcnTicketFailedWarmup.into());
     }
 
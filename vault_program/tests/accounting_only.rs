@@ -0,0 +1,539 @@
+//! Property tests of the vault's accounting, processing its instructions in pure Rust with the
+//! `accounting-only` feature:
+//!
+//! ```bash
+//! cargo test -p jito-vault-program --features accounting-only
+//! ```
+#![cfg(feature = "accounting-only")]
+
+use std::{collections::HashMap, mem::size_of};
+
+use jito_bytemuck::AccountDeserialize;
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_program::process_instruction_with_clock;
+use jito_vault_sdk::sdk::{
+    burn, initialize_config, initialize_vault, mint_to, update_vault_balance,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, instruction::Instruction,
+    program_option::COption, program_pack::Pack, pubkey::Pubkey, system_program,
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::{Account, AccountState, Mint};
+
+#[derive(Clone)]
+struct TestAccount {
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+}
+
+impl Default for TestAccount {
+    fn default() -> Self {
+        Self {
+            lamports: 0,
+            data: vec![],
+            owner: system_program::id(),
+        }
+    }
+}
+
+/// Holds the accounts and processes instructions against them at its clock. Like a transaction,
+/// an instruction that fails leaves the accounts unchanged.
+struct Harness {
+    accounts: HashMap<Pubkey, TestAccount>,
+    clock: Clock,
+}
+
+impl Harness {
+    fn new() -> Self {
+        Self {
+            accounts: HashMap::new(),
+            clock: Clock::default(),
+        }
+    }
+
+    fn fund(&mut self, key: Pubkey, lamports: u64) {
+        self.accounts.entry(key).or_default().lamports += lamports;
+    }
+
+    fn create_mint(&mut self, key: Pubkey, authority: Pubkey) {
+        let mut data = vec![0; Mint::LEN];
+        let mint = Mint {
+            mint_authority: COption::Some(authority),
+            decimals: 9,
+            is_initialized: true,
+            ..Mint::default()
+        };
+        Mint::pack(mint, &mut data).unwrap();
+        self.accounts.insert(
+            key,
+            TestAccount {
+                lamports: 1_000_000,
+                data,
+                owner: spl_token::id(),
+            },
+        );
+    }
+
+    /// Creates the associated token account of `owner` for `mint`
+    fn create_token_account(&mut self, owner: &Pubkey, mint: &Pubkey, amount: u64) -> Pubkey {
+        let key = get_associated_token_address(owner, mint);
+        let mut data = vec![0; Account::LEN];
+        let token_account = Account {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            state: AccountState::Initialized,
+            ..Account::default()
+        };
+        Account::pack(token_account, &mut data).unwrap();
+        self.accounts.insert(
+            key,
+            TestAccount {
+                lamports: 1_000_000,
+                data,
+                owner: spl_token::id(),
+            },
+        );
+        if amount > 0 {
+            self.update_mint(mint, |mint| mint.supply += amount);
+        }
+        key
+    }
+
+    fn token_amount(&self, key: &Pubkey) -> u64 {
+        Account::unpack(&self.accounts[key].data).unwrap().amount
+    }
+
+    fn mint_supply(&self, key: &Pubkey) -> u64 {
+        Mint::unpack(&self.accounts[key].data).unwrap().supply
+    }
+
+    /// Mints `amount` into a token account without an instruction, like a transfer from outside
+    fn airdrop_tokens(&mut self, key: &Pubkey, amount: u64) {
+        let data = &mut self.accounts.get_mut(key).unwrap().data;
+        let mut token_account = Account::unpack(data).unwrap();
+        token_account.amount += amount;
+        Account::pack(token_account, data).unwrap();
+        let mint = token_account.mint;
+        self.update_mint(&mint, |mint| mint.supply += amount);
+    }
+
+    fn update_mint(&mut self, key: &Pubkey, f: impl FnOnce(&mut Mint)) {
+        let data = &mut self.accounts.get_mut(key).unwrap().data;
+        let mut mint = Mint::unpack(data).unwrap();
+        f(&mut mint);
+        Mint::pack(mint, data).unwrap();
+    }
+
+    fn vault(&self, key: &Pubkey) -> Vault {
+        *Vault::try_from_slice_unchecked(&self.accounts[key].data).unwrap()
+    }
+
+    fn process(&mut self, instruction: &Instruction) -> ProgramResult {
+        let mut keys: Vec<Pubkey> = vec![];
+        for meta in instruction.accounts.iter() {
+            if !keys.contains(&meta.pubkey) {
+                keys.push(meta.pubkey);
+            }
+        }
+        let mut accounts: Vec<TestAccount> = keys
+            .iter()
+            .map(|key| self.accounts.get(key).cloned().unwrap_or_default())
+            .collect();
+
+        let (result, accounts) = {
+            let infos: Vec<AccountInfo> = keys
+                .iter()
+                .zip(accounts.iter_mut())
+                .map(|(key, account)| {
+                    let metas = instruction
+                        .accounts
+                        .iter()
+                        .filter(|meta| meta.pubkey.eq(key));
+                    let (is_signer, is_writable) = metas.fold((false, false), |acc, meta| {
+                        (acc.0 || meta.is_signer, acc.1 || meta.is_writable)
+                    });
+                    AccountInfo::new(
+                        key,
+                        is_signer,
+                        is_writable,
+                        &mut account.lamports,
+                        &mut account.data,
+                        &account.owner,
+                        false,
+                        0,
+                    )
+                })
+                .collect();
+            let account_infos: Vec<AccountInfo> = instruction
+                .accounts
+                .iter()
+                .map(|meta| {
+                    infos
+                        .iter()
+                        .find(|info| info.key.eq(&meta.pubkey))
+                        .unwrap()
+                        .clone()
+                })
+                .collect();
+
+            let result = process_instruction_with_clock(
+                &instruction.program_id,
+                &account_infos,
+                &instruction.data,
+                &self.clock,
+            );
+            let accounts: Vec<TestAccount> = infos
+                .iter()
+                .map(|info| TestAccount {
+                    lamports: info.lamports(),
+                    data: info.data.borrow().to_vec(),
+                    owner: *info.owner,
+                })
+                .collect();
+            (result, accounts)
+        };
+
+        if result.is_ok() {
+            for (key, account) in keys.into_iter().zip(accounts) {
+                self.accounts.insert(key, account);
+            }
+        }
+        result
+    }
+}
+
+/// Deterministic xorshift so the property test is reproducible
+fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+struct TestVault {
+    config: Pubkey,
+    vault: Pubkey,
+    vrt_mint: Pubkey,
+    supported_mint: Pubkey,
+    vault_token_account: Pubkey,
+    fee_token_account: Pubkey,
+    protocol_fee_token_account: Pubkey,
+}
+
+fn setup_vault(harness: &mut Harness) -> TestVault {
+    let program_id = jito_vault_program::id();
+    let admin = Pubkey::new_unique();
+    harness.fund(admin, 1_000_000_000_000);
+
+    let config = Config::find_program_address(&program_id).0;
+    harness
+        .process(&initialize_config(
+            &program_id,
+            &config,
+            &admin,
+            &Pubkey::new_unique(),
+        ))
+        .unwrap();
+
+    let supported_mint = Pubkey::new_unique();
+    harness.create_mint(supported_mint, Pubkey::new_unique());
+    let base = Pubkey::new_unique();
+    let vault = Vault::find_program_address(&program_id, &base).0;
+    let vrt_mint = Pubkey::new_unique();
+    harness
+        .process(&initialize_vault(
+            &program_id,
+            &config,
+            &vault,
+            &vrt_mint,
+            &supported_mint,
+            &admin,
+            &base,
+            100,
+            100,
+            1_000,
+            9,
+        ))
+        .unwrap();
+    assert_eq!(harness.accounts[&vault].owner, program_id);
+    assert_eq!(harness.accounts[&vault].data.len(), 8 + size_of::<Vault>());
+
+    TestVault {
+        config,
+        vault,
+        vrt_mint,
+        supported_mint,
+        vault_token_account: harness.create_token_account(&vault, &supported_mint, 0),
+        // the admin is both the vault's fee wallet and the config's protocol fee wallet
+        fee_token_account: harness.create_token_account(&admin, &vrt_mint, 0),
+        protocol_fee_token_account: get_associated_token_address(&admin, &vrt_mint),
+    }
+}
+
+#[test]
+fn test_mint_burn_update_vault_balance_keep_the_books_balanced() {
+    let program_id = jito_vault_program::id();
+    let mut harness = Harness::new();
+    let test_vault = setup_vault(&mut harness);
+
+    let stakers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    let mut total_supported_tokens = 0;
+    for staker in stakers.iter() {
+        harness.fund(*staker, 1_000_000_000);
+        harness.create_token_account(staker, &test_vault.supported_mint, 1_000_000_000);
+        harness.create_token_account(staker, &test_vault.vrt_mint, 0);
+        total_supported_tokens += 1_000_000_000;
+    }
+
+    let mut seed = 0x9e37_79b9_7f4a_7c15;
+    let mut num_succeeded = 0;
+    for _ in 0..1_000 {
+        harness.clock.slot += next_random(&mut seed) % 10;
+        let staker = &stakers[(next_random(&mut seed) % stakers.len() as u64) as usize];
+        let staker_token_account = get_associated_token_address(staker, &test_vault.supported_mint);
+        let staker_vrt_token_account = get_associated_token_address(staker, &test_vault.vrt_mint);
+
+        let result = match next_random(&mut seed) % 4 {
+            0 | 1 => {
+                let amount =
+                    next_random(&mut seed) % (harness.token_amount(&staker_token_account) + 1);
+                harness.process(&mint_to(
+                    &program_id,
+                    &test_vault.config,
+                    &test_vault.vault,
+                    &test_vault.vrt_mint,
+                    staker,
+                    &staker_token_account,
+                    &test_vault.vault_token_account,
+                    &staker_vrt_token_account,
+                    None,
+                    None,
+                    None,
+                    amount,
+                    0,
+                ))
+            }
+            2 => {
+                let amount =
+                    next_random(&mut seed) % (harness.token_amount(&staker_vrt_token_account) + 1);
+                harness.process(&burn(
+                    &program_id,
+                    &test_vault.config,
+                    &test_vault.vault,
+                    &test_vault.vault_token_account,
+                    &test_vault.vrt_mint,
+                    staker,
+                    &staker_token_account,
+                    &staker_vrt_token_account,
+                    &test_vault.fee_token_account,
+                    None,
+                    None,
+                    amount,
+                    0,
+                ))
+            }
+            _ => {
+                // rewards land in the vault token account before the balance is updated
+                let rewards = next_random(&mut seed) % 1_000_000;
+                harness.airdrop_tokens(&test_vault.vault_token_account, rewards);
+                total_supported_tokens += rewards;
+                let result = harness.process(&update_vault_balance(
+                    &program_id,
+                    &test_vault.config,
+                    &test_vault.vault,
+                    &test_vault.vault_token_account,
+                    &test_vault.vrt_mint,
+                    &test_vault.fee_token_account,
+                    &spl_token::id(),
+                    &test_vault.protocol_fee_token_account,
+                ));
+                if result.is_ok() {
+                    assert_eq!(
+                        harness.token_amount(&test_vault.vault_token_account),
+                        harness.vault(&test_vault.vault).tokens_deposited()
+                    );
+                }
+                result
+            }
+        };
+        if result.is_ok() {
+            num_succeeded += 1;
+        }
+
+        let vault = harness.vault(&test_vault.vault);
+        let vrt_supply = harness.mint_supply(&test_vault.vrt_mint);
+        let vrt_held: u64 = stakers
+            .iter()
+            .map(|staker| get_associated_token_address(staker, &test_vault.vrt_mint))
+            .chain([test_vault.fee_token_account])
+            .map(|key| harness.token_amount(&key))
+            .sum();
+        let supported_tokens_held: u64 = stakers
+            .iter()
+            .map(|staker| get_associated_token_address(staker, &test_vault.supported_mint))
+            .chain([test_vault.vault_token_account])
+            .map(|key| harness.token_amount(&key))
+            .sum();
+
+        // the vault's VRT supply is the VRT minted plus the deposit fees yet to be minted
+        assert_eq!(vrt_supply + vault.vrt_fees_accrued(), vault.vrt_supply());
+        assert_eq!(vrt_held, vrt_supply);
+        assert_eq!(supported_tokens_held, total_supported_tokens);
+        assert_eq!(
+            harness.mint_supply(&test_vault.supported_mint),
+            total_supported_tokens
+        );
+        assert!(harness.token_amount(&test_vault.vault_token_account) >= vault.tokens_deposited());
+    }
+    assert!(num_succeeded > 0);
+}
+
+#[test]
+fn test_mint_then_burn_never_returns_more_than_deposited() {
+    let program_id = jito_vault_program::id();
+    let mut harness = Harness::new();
+    let test_vault = setup_vault(&mut harness);
+
+    let staker = Pubkey::new_unique();
+    harness.fund(staker, 1_000_000_000);
+    let staker_token_account =
+        harness.create_token_account(&staker, &test_vault.supported_mint, 1_000_000_000);
+    let staker_vrt_token_account = harness.create_token_account(&staker, &test_vault.vrt_mint, 0);
+
+    let deposit = |amount| {
+        mint_to(
+            &program_id,
+            &test_vault.config,
+            &test_vault.vault,
+            &test_vault.vrt_mint,
+            &staker,
+            &staker_token_account,
+            &test_vault.vault_token_account,
+            &staker_vrt_token_account,
+            None,
+            None,
+            None,
+            amount,
+            0,
+        )
+    };
+    let withdraw = |amount| {
+        burn(
+            &program_id,
+            &test_vault.config,
+            &test_vault.vault,
+            &test_vault.vault_token_account,
+            &test_vault.vrt_mint,
+            &staker,
+            &staker_token_account,
+            &staker_vrt_token_account,
+            &test_vault.fee_token_account,
+            None,
+            None,
+            amount,
+            0,
+        )
+    };
+
+    harness.process(&deposit(1_000_000)).unwrap();
+
+    let mut seed = 0x9e37_79b9_7f4a_7c15;
+    let mut num_round_trips = 0;
+    for _ in 0..1_000 {
+        // rewards move the exchange rate away from one token per VRT
+        if next_random(&mut seed) % 10 == 0 {
+            harness.clock.slot += 1;
+            harness.airdrop_tokens(
+                &test_vault.vault_token_account,
+                next_random(&mut seed) % 1_000,
+            );
+            harness
+                .process(&update_vault_balance(
+                    &program_id,
+                    &test_vault.config,
+                    &test_vault.vault,
+                    &test_vault.vault_token_account,
+                    &test_vault.vrt_mint,
+                    &test_vault.fee_token_account,
+                    &spl_token::id(),
+                    &test_vault.protocol_fee_token_account,
+                ))
+                .unwrap();
+        }
+
+        let vault_before = harness.vault(&test_vault.vault);
+        let tokens_before = harness.token_amount(&staker_token_account);
+        let vrt_before = harness.token_amount(&staker_vrt_token_account);
+
+        let amount_in = next_random(&mut seed) % 1_000 + 1;
+        if harness.process(&deposit(amount_in)).is_err() {
+            continue;
+        }
+        let vrt_minted = harness.token_amount(&staker_vrt_token_account) - vrt_before;
+        if vrt_minted > 0 {
+            harness.process(&withdraw(vrt_minted)).unwrap();
+            num_round_trips += 1;
+        }
+        let amount_out = harness.token_amount(&staker_token_account) + amount_in - tokens_before;
+
+        // the depositor can never get back more than they put in
+        assert!(amount_out <= amount_in);
+        // the tokens backing each VRT can never decrease
+        let vault = harness.vault(&test_vault.vault);
+        assert!(
+            vault.tokens_deposited() as u128 * vault_before.vrt_supply() as u128
+                >= vault_before.tokens_deposited() as u128 * vault.vrt_supply() as u128
+        );
+    }
+    assert!(num_round_trips > 0);
+}
+
+#[test]
+fn test_failed_instruction_leaves_accounts_unchanged() {
+    let program_id = jito_vault_program::id();
+    let mut harness = Harness::new();
+    let test_vault = setup_vault(&mut harness);
+
+    let staker = Pubkey::new_unique();
+    let staker_token_account =
+        harness.create_token_account(&staker, &test_vault.supported_mint, 1_000);
+    let staker_vrt_token_account = harness.create_token_account(&staker, &test_vault.vrt_mint, 0);
+
+    let deposit = |min_amount_out| {
+        mint_to(
+            &program_id,
+            &test_vault.config,
+            &test_vault.vault,
+            &test_vault.vrt_mint,
+            &staker,
+            &staker_token_account,
+            &test_vault.vault_token_account,
+            &staker_vrt_token_account,
+            None,
+            None,
+            None,
+            1_000,
+            min_amount_out,
+        )
+    };
+
+    // the deposit fee leaves less than the minimum out
+    assert!(harness.process(&deposit(1_000)).is_err());
+    assert_eq!(harness.token_amount(&staker_token_account), 1_000);
+    assert_eq!(harness.vault(&test_vault.vault).tokens_deposited(), 0);
+
+    // the staker must sign to move their tokens
+    let mut instruction = deposit(0);
+    instruction.accounts[3].is_signer = false;
+    assert!(harness.process(&instruction).is_err());
+    assert_eq!(harness.token_amount(&staker_token_account), 1_000);
+
+    instruction.accounts[3].is_signer = true;
+    harness.process(&instruction).unwrap();
+    assert_eq!(harness.token_amount(&staker_token_account), 0);
+    assert_eq!(harness.token_amount(&test_vault.vault_token_account), 1_000);
+    assert_eq!(harness.token_amount(&staker_vrt_token_account), 990);
+}
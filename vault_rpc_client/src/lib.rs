@@ -0,0 +1,465 @@
+//! An async client for reading vaults over RPC and sending the common vault transactions, so bots
+//! and front-ends don't need to assemble the optional accounts each instruction takes for the
+//! vault's configuration.
+//!
+//! Transactions are signed with a recent blockhash and retried on RPC errors. A transaction the
+//! program rejects is returned as an error right away, sending it again won't help.
+use std::time::Duration;
+
+use jito_bytemuck::AccountDeserialize;
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use jito_vault_sdk::{
+    instruction::{DepositMode, WithdrawalAllocationMethod},
+    pda, sdk,
+};
+use log::{info, warn};
+use solana_account_decoder::UiAccountEncoding;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_sdk::{
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum VaultRpcClientError {
+    #[error("Rpc error: {0}")]
+    Rpc(#[from] solana_rpc_client_api::client_error::Error),
+    #[error("Account data error: {0}")]
+    AccountData(#[from] ProgramError),
+    #[error("Transaction {0} failed: {1}")]
+    TransactionFailed(Signature, solana_sdk::transaction::TransactionError),
+}
+
+pub type VaultRpcClientResult<T> = Result<T, VaultRpcClientError>;
+
+pub struct VaultRpcClient {
+    rpc_client: RpcClient,
+    program_id: Pubkey,
+    max_retries: usize,
+    retry_delay: Duration,
+}
+
+impl VaultRpcClient {
+    pub const DEFAULT_MAX_RETRIES: usize = 3;
+    pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+    pub const fn new(rpc_client: RpcClient, program_id: Pubkey) -> Self {
+        Self {
+            rpc_client,
+            program_id,
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            retry_delay: Self::DEFAULT_RETRY_DELAY,
+        }
+    }
+
+    /// Sets how many times a transaction is retried on RPC errors and how long to wait in between
+    pub const fn with_retries(mut self, max_retries: usize, retry_delay: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    pub const fn rpc_client(&self) -> &RpcClient {
+        &self.rpc_client
+    }
+
+    pub async fn get_config(&self) -> VaultRpcClientResult<Config> {
+        let config = pda::derive_config(&self.program_id).0;
+        let account = self.rpc_client.get_account(&config).await?;
+        Ok(*Config::try_from_slice_unchecked(&account.data)?)
+    }
+
+    pub async fn get_vault(&self, vault: &Pubkey) -> VaultRpcClientResult<Vault> {
+        let account = self.rpc_client.get_account(vault).await?;
+        Ok(*Vault::try_from_slice_unchecked(&account.data)?)
+    }
+
+    pub async fn get_all_vaults(&self) -> VaultRpcClientResult<Vec<(Pubkey, Vault)>> {
+        self.get_program_accounts::<Vault>(vec![]).await
+    }
+
+    /// The vault's operator delegations, in the index order they're cranked in
+    pub async fn get_vault_operator_delegations(
+        &self,
+        vault: &Pubkey,
+    ) -> VaultRpcClientResult<Vec<(Pubkey, VaultOperatorDelegation)>> {
+        let mut delegations = self
+            .get_program_accounts::<VaultOperatorDelegation>(vec![Memcmp::new(
+                8,
+                MemcmpEncodedBytes::Bytes(vault.to_bytes().to_vec()),
+            )])
+            .await?;
+        delegations.sort_by_key(|(_, delegation)| delegation.index());
+        Ok(delegations)
+    }
+
+    /// Deposits `amount_in` of the vault's supported mint from the depositor's associated token
+    /// account and mints VRT to the depositor's associated token account, creating it if needed.
+    /// The mint signer is required if the vault has a mint burn admin.
+    pub async fn deposit(
+        &self,
+        depositor: &Keypair,
+        vault_pubkey: &Pubkey,
+        mint_signer: Option<&Keypair>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> VaultRpcClientResult<Signature> {
+        let vault = self.get_vault(vault_pubkey).await?;
+        let depositor_pubkey = depositor.pubkey();
+
+        let vault_deposit_allowlist_entry = match vault.deposit_mode()? {
+            DepositMode::Allowlist => Some(
+                pda::derive_vault_deposit_allowlist_entry(
+                    &self.program_id,
+                    vault_pubkey,
+                    &depositor_pubkey,
+                )
+                .0,
+            ),
+            DepositMode::Open | DepositMode::SignerGated => None,
+        };
+        let vault_depositor = vault.tracks_depositors().then(|| {
+            pda::derive_vault_depositor(&self.program_id, vault_pubkey, &depositor_pubkey).0
+        });
+
+        let mut mint_to = sdk::mint_to(
+            &self.program_id,
+            &pda::derive_config(&self.program_id).0,
+            vault_pubkey,
+            &vault.vrt_mint,
+            &depositor_pubkey,
+            &get_associated_token_address(&depositor_pubkey, &vault.supported_mint),
+            &get_associated_token_address(vault_pubkey, &vault.supported_mint),
+            &get_associated_token_address(&depositor_pubkey, &vault.vrt_mint),
+            mint_signer.map(|signer| signer.pubkey()).as_ref(),
+            vault_deposit_allowlist_entry.as_ref(),
+            vault_depositor.as_ref(),
+            amount_in,
+            min_amount_out,
+        );
+        if vault.epoch_stats_enabled() {
+            mint_to.accounts.push(AccountMeta::new(
+                pda::derive_vault_epoch_stats(&self.program_id, vault_pubkey).0,
+                false,
+            ));
+        }
+        if vault.has_hook_program() {
+            mint_to
+                .accounts
+                .push(AccountMeta::new_readonly(vault.hook_program, false));
+        }
+
+        let instructions = [
+            create_associated_token_account_idempotent(
+                &depositor_pubkey,
+                &depositor_pubkey,
+                &vault.vrt_mint,
+                &spl_token::id(),
+            ),
+            mint_to,
+        ];
+        let mut signers = vec![depositor];
+        signers.extend(mint_signer);
+        self.send_transaction(&instructions, &signers).await
+    }
+
+    /// Enqueues `vrt_amount` of the staker's VRT for withdrawal in a new withdrawal ticket, which
+    /// can be burned for the supported mint once the vault's withdrawal delay has passed. The burn
+    /// signer is required if the vault has a mint burn admin.
+    ///
+    /// # Returns
+    /// * The withdrawal ticket and the transaction signature
+    pub async fn withdraw(
+        &self,
+        staker: &Keypair,
+        vault_pubkey: &Pubkey,
+        burn_signer: Option<&Keypair>,
+        vrt_amount: u64,
+    ) -> VaultRpcClientResult<(Pubkey, Signature)> {
+        let vault = self.get_vault(vault_pubkey).await?;
+        let staker_pubkey = staker.pubkey();
+
+        let base = Keypair::new();
+        let vault_staker_withdrawal_ticket = pda::derive_vault_staker_withdrawal_ticket(
+            &self.program_id,
+            vault_pubkey,
+            &base.pubkey(),
+        )
+        .0;
+
+        let mut enqueue_withdraw = sdk::enqueue_withdraw(
+            &self.program_id,
+            &pda::derive_config(&self.program_id).0,
+            vault_pubkey,
+            &vault_staker_withdrawal_ticket,
+            &get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint),
+            &staker_pubkey,
+            &get_associated_token_address(&staker_pubkey, &vault.vrt_mint),
+            &base.pubkey(),
+            vrt_amount,
+        );
+        if let Some(signer) = burn_signer {
+            enqueue_withdraw
+                .accounts
+                .push(AccountMeta::new_readonly(signer.pubkey(), true));
+        }
+
+        let instructions = [
+            create_associated_token_account_idempotent(
+                &staker_pubkey,
+                &vault_staker_withdrawal_ticket,
+                &vault.vrt_mint,
+                &spl_token::id(),
+            ),
+            enqueue_withdraw,
+        ];
+        let mut signers = vec![staker, &base];
+        signers.extend(burn_signer);
+        let signature = self.send_transaction(&instructions, &signers).await?;
+        Ok((vault_staker_withdrawal_ticket, signature))
+    }
+
+    /// Runs the vault's update for the current epoch: opens the update state tracker, cranks every
+    /// operator delegation in index order, closes the tracker and updates the vault balance.
+    /// Picks up where it left off if a previous run stopped part way through, and does nothing if
+    /// the vault was already updated this epoch.
+    ///
+    /// # Returns
+    /// * The signatures of the transactions sent
+    pub async fn crank_epoch(
+        &self,
+        payer: &Keypair,
+        vault_pubkey: &Pubkey,
+    ) -> VaultRpcClientResult<Vec<Signature>> {
+        let config = self.get_config().await?;
+        let vault = self.get_vault(vault_pubkey).await?;
+        let epoch_length = vault.epoch_length(config.epoch_length());
+        let slot = self.rpc_client.get_slot().await?;
+        if !vault.is_update_needed(slot, epoch_length) {
+            info!("Vault {} is already updated for this epoch", vault_pubkey);
+            return Ok(vec![]);
+        }
+
+        let config_pubkey = pda::derive_config(&self.program_id).0;
+        let ncn_epoch = slot / epoch_length;
+        let vault_update_state_tracker =
+            pda::derive_vault_update_state_tracker(&self.program_id, vault_pubkey, ncn_epoch).0;
+        let mut signatures = Vec::new();
+
+        let last_updated_index = match self
+            .rpc_client
+            .get_account_with_commitment(&vault_update_state_tracker, self.rpc_client.commitment())
+            .await?
+            .value
+        {
+            Some(account) => VaultUpdateStateTracker::try_from_slice_unchecked(&account.data)?
+                .last_updated_index(),
+            None => {
+                let initialize = sdk::initialize_vault_update_state_tracker(
+                    &self.program_id,
+                    &config_pubkey,
+                    vault_pubkey,
+                    &vault_update_state_tracker,
+                    &payer.pubkey(),
+                    WithdrawalAllocationMethod::Greedy,
+                );
+                signatures.push(self.send_transaction(&[initialize], &[payer]).await?);
+                u64::MAX
+            }
+        };
+
+        for (vault_operator_delegation, delegation) in
+            self.get_vault_operator_delegations(vault_pubkey).await?
+        {
+            // u64::MAX until the first delegation is cranked
+            if last_updated_index != u64::MAX && delegation.index() <= last_updated_index {
+                continue;
+            }
+            let crank = sdk::crank_vault_update_state_tracker(
+                &self.program_id,
+                &config_pubkey,
+                vault_pubkey,
+                &delegation.operator,
+                &vault_operator_delegation,
+                &vault_update_state_tracker,
+            );
+            signatures.push(self.send_transaction(&[crank], &[payer]).await?);
+        }
+
+        let mut close = sdk::close_vault_update_state_tracker(
+            &self.program_id,
+            &config_pubkey,
+            vault_pubkey,
+            &vault_update_state_tracker,
+            &payer.pubkey(),
+            ncn_epoch,
+        );
+        if config.crank_reward_bps() > 0 {
+            close.accounts.extend([
+                AccountMeta::new(
+                    get_associated_token_address(vault_pubkey, &vault.supported_mint),
+                    false,
+                ),
+                AccountMeta::new(
+                    get_associated_token_address(&payer.pubkey(), &vault.supported_mint),
+                    false,
+                ),
+                AccountMeta::new_readonly(spl_token::id(), false),
+            ]);
+        }
+        signatures.push(self.send_transaction(&[close], &[payer]).await?);
+
+        let mut update_vault_balance = sdk::update_vault_balance(
+            &self.program_id,
+            &config_pubkey,
+            vault_pubkey,
+            &get_associated_token_address(vault_pubkey, &vault.supported_mint),
+            &vault.vrt_mint,
+            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+            &spl_token::id(),
+            &get_associated_token_address(&config.protocol_fee_wallet, &vault.vrt_mint),
+        );
+        if vault.insurance_enabled() {
+            let vault_insurance = pda::derive_vault_insurance(&self.program_id, vault_pubkey).0;
+            update_vault_balance.accounts.extend([
+                AccountMeta::new(vault_insurance, false),
+                AccountMeta::new(
+                    get_associated_token_address(&vault_insurance, &vault.vrt_mint),
+                    false,
+                ),
+            ]);
+        }
+        if config.has_crank_reward() {
+            update_vault_balance
+                .accounts
+                .push(AccountMeta::new(payer.pubkey(), true));
+            if config.crank_reward_bps() > 0 {
+                update_vault_balance.accounts.push(AccountMeta::new(
+                    get_associated_token_address(&payer.pubkey(), &vault.supported_mint),
+                    false,
+                ));
+            }
+        }
+        signatures.push(
+            self.send_transaction(&[update_vault_balance], &[payer])
+                .await?,
+        );
+
+        Ok(signatures)
+    }
+
+    async fn get_program_accounts<T: AccountDeserialize + Copy>(
+        &self,
+        filters: Vec<Memcmp>,
+    ) -> VaultRpcClientResult<Vec<(Pubkey, T)>> {
+        let filters = [Memcmp::new(
+            0,
+            MemcmpEncodedBytes::Bytes(vec![T::DISCRIMINATOR]),
+        )]
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::Memcmp)
+        .collect();
+        let accounts = self
+            .rpc_client
+            .get_program_accounts_with_config(
+                &self.program_id,
+                RpcProgramAccountsConfig {
+                    filters: Some(filters),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        data_slice: None,
+                        commitment: None,
+                        min_context_slot: None,
+                    },
+                    with_context: None,
+                },
+            )
+            .await?;
+        accounts
+            .into_iter()
+            .map(|(pubkey, account)| Ok((pubkey, *T::try_from_slice_unchecked(&account.data)?)))
+            .collect()
+    }
+
+    /// Signs and sends the transaction, paid for by the first signer, retrying on RPC errors. The
+    /// transaction is only re-signed with a new blockhash once the old one expired, so a
+    /// transaction that landed despite the error can't land a second time.
+    async fn send_transaction(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> VaultRpcClientResult<Signature> {
+        let payer = signers[0].pubkey();
+        let mut blockhash = self.rpc_client.get_latest_blockhash().await?;
+        let mut tx =
+            Transaction::new_signed_with_payer(instructions, Some(&payer), signers, blockhash);
+
+        let mut retries = 0;
+        loop {
+            let e = match self.rpc_client.send_and_confirm_transaction(&tx).await {
+                Ok(signature) => return Ok(signature),
+                Err(e) => e,
+            };
+            if let Some(transaction_error) = e.get_transaction_error() {
+                return Err(VaultRpcClientError::TransactionFailed(
+                    tx.signatures[0],
+                    transaction_error,
+                ));
+            }
+            if retries >= self.max_retries {
+                return Err(e.into());
+            }
+            retries += 1;
+            warn!(
+                "Transaction {} failed, retrying ({}/{}): {}",
+                tx.signatures[0], retries, self.max_retries, e
+            );
+            tokio::time::sleep(self.retry_delay).await;
+
+            match self
+                .rpc_client
+                .get_signature_status(&tx.signatures[0])
+                .await?
+            {
+                Some(Ok(())) => return Ok(tx.signatures[0]),
+                Some(Err(transaction_error)) => {
+                    return Err(VaultRpcClientError::TransactionFailed(
+                        tx.signatures[0],
+                        transaction_error,
+                    ))
+                }
+                None => {}
+            }
+            if !self
+                .rpc_client
+                .is_blockhash_valid(&blockhash, self.rpc_client.commitment())
+                .await?
+            {
+                blockhash = self.rpc_client.get_latest_blockhash().await?;
+                tx = Transaction::new_signed_with_payer(
+                    instructions,
+                    Some(&payer),
+                    signers,
+                    blockhash,
+                );
+            }
+        }
+    }
+}
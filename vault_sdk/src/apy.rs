@@ -0,0 +1,261 @@
+//! Realized APY of a vault from exchange rate checkpoints
+//!
+//! The exchange rate of a vault is the supported tokens backing each VRT. It grows as rewards are
+//! deposited into the vault, net of the reward fee, and drops when the vault is slashed. The
+//! helpers here annualize its change between two [`ExchangeRateCheckpoint`]s, so integrators
+//! display the same yield numbers from the same on-chain data.
+//!
+//! Checkpoints come from the vault account, for the current rate, and from the
+//! [`VaultEvent::Deposit`] and [`VaultEvent::Withdraw`] events, for past rates. The rates are
+//! computed client-side with floating point and are meant for display only.
+
+use crate::events::VaultEvent;
+
+/// The number of seconds in a year of 365 days, used to annualize returns
+pub const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 60.0 * 60.0;
+
+/// The basis points in 100%
+const MAX_BPS: f64 = 10_000.0;
+
+/// The exchange rate of a vault at a point in time, as `supported_tokens` per `vrt` VRT
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExchangeRateCheckpoint {
+    /// The unix timestamp of the checkpoint
+    pub unix_timestamp: i64,
+    pub supported_tokens: u64,
+    pub vrt: u64,
+}
+
+impl ExchangeRateCheckpoint {
+    /// The exchange rate of a vault with `tokens_deposited` and `vrt_supply`
+    pub const fn from_vault(unix_timestamp: i64, tokens_deposited: u64, vrt_supply: u64) -> Self {
+        Self {
+            unix_timestamp,
+            supported_tokens: tokens_deposited,
+            vrt: vrt_supply,
+        }
+    }
+
+    /// The exchange rate a deposit or withdrawal was made at, before fees
+    pub fn from_event(unix_timestamp: i64, event: &VaultEvent) -> Option<Self> {
+        let (supported_tokens, vrt) = match event {
+            VaultEvent::Deposit(deposit) => (
+                deposit.amount_in,
+                deposit.vrt_to_depositor.checked_add(deposit.vrt_fee)?,
+            ),
+            VaultEvent::Withdraw(withdraw) => (
+                withdraw.amount_out,
+                withdraw.vrt_burned.checked_sub(withdraw.vrt_fee)?,
+            ),
+            _ => return None,
+        };
+        Some(Self {
+            unix_timestamp,
+            supported_tokens,
+            vrt,
+        })
+    }
+
+    /// The supported tokens per VRT, `None` if there is no VRT to price
+    pub fn exchange_rate(&self) -> Option<f64> {
+        if self.vrt == 0 {
+            return None;
+        }
+        Some(self.supported_tokens as f64 / self.vrt as f64)
+    }
+}
+
+/// Annualizes a `growth` factor over `seconds`, compounding. A growth of `1.01` over a month is
+/// about 12.7%.
+pub fn annualize(growth: f64, seconds: i64) -> Option<f64> {
+    if seconds <= 0 || growth <= 0.0 || !growth.is_finite() {
+        return None;
+    }
+    Some(growth.powf(SECONDS_PER_YEAR / seconds as f64) - 1.0)
+}
+
+/// The realized APYs of a vault between two checkpoints
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RealizedApy {
+    /// The annualized change of the exchange rate, net of reward fees and slashes
+    pub apy: f64,
+
+    /// The APY had the supported tokens slashed during the window stayed in the vault
+    pub apy_before_slashes: f64,
+
+    /// The APY of depositing at the start of the window and withdrawing at the end, net of the
+    /// deposit and withdrawal fees as well
+    pub fee_adjusted_apy: f64,
+}
+
+impl RealizedApy {
+    /// Computes the realized APYs between `start` and `end`
+    ///
+    /// # Arguments
+    /// * `start` - The checkpoint at the start of the window
+    /// * `end` - The checkpoint at the end of the window
+    /// * `slashed` - The supported tokens slashed from the vault during the window
+    /// * `deposit_fee_bps` - The deposit fee paid entering the vault
+    /// * `withdrawal_fee_bps` - The withdrawal fee paid leaving the vault
+    ///
+    /// # Returns
+    /// * `Option<RealizedApy>` - `None` if either checkpoint has no VRT or the window is empty
+    pub fn compute(
+        start: &ExchangeRateCheckpoint,
+        end: &ExchangeRateCheckpoint,
+        slashed: u64,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+    ) -> Option<Self> {
+        let seconds = end.unix_timestamp.checked_sub(start.unix_timestamp)?;
+        let start_rate = start.exchange_rate()?;
+        let growth = end.exchange_rate()? / start_rate;
+
+        let end_before_slashes = ExchangeRateCheckpoint {
+            supported_tokens: end.supported_tokens.checked_add(slashed)?,
+            ..*end
+        };
+        let growth_before_slashes = end_before_slashes.exchange_rate()? / start_rate;
+
+        let fees = (1.0 - f64::from(deposit_fee_bps) / MAX_BPS)
+            * (1.0 - f64::from(withdrawal_fee_bps) / MAX_BPS);
+
+        Some(Self {
+            apy: annualize(growth, seconds)?,
+            apy_before_slashes: annualize(growth_before_slashes, seconds)?,
+            fee_adjusted_apy: annualize(growth * fees, seconds)?,
+        })
+    }
+
+    /// Computes the realized APYs over the `window_seconds` ending at `end`, starting at the
+    /// earliest of `checkpoints` in the window
+    ///
+    /// # Arguments
+    /// * `checkpoints` - Past checkpoints, in any order
+    /// * `end` - The checkpoint at the end of the window, usually the vault's current rate
+    /// * `window_seconds` - The length of the window
+    /// * `slashes` - The unix timestamp and supported tokens of each slash, in any order
+    /// * `deposit_fee_bps` - The deposit fee paid entering the vault
+    /// * `withdrawal_fee_bps` - The withdrawal fee paid leaving the vault
+    ///
+    /// # Returns
+    /// * `Option<(ExchangeRateCheckpoint, RealizedApy)>` - The start checkpoint used and the APYs,
+    ///   `None` if no checkpoint falls in the window
+    pub fn over_window(
+        checkpoints: &[ExchangeRateCheckpoint],
+        end: &ExchangeRateCheckpoint,
+        window_seconds: i64,
+        slashes: &[(i64, u64)],
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+    ) -> Option<(ExchangeRateCheckpoint, Self)> {
+        let window_start = end.unix_timestamp.checked_sub(window_seconds)?;
+        let start = checkpoints
+            .iter()
+            .filter(|checkpoint| {
+                checkpoint.unix_timestamp >= window_start
+                    && checkpoint.unix_timestamp < end.unix_timestamp
+                    && checkpoint.vrt != 0
+            })
+            .min_by_key(|checkpoint| checkpoint.unix_timestamp)?;
+        let slashed = slashes
+            .iter()
+            .filter(|(unix_timestamp, _)| {
+                *unix_timestamp >= start.unix_timestamp && *unix_timestamp <= end.unix_timestamp
+            })
+            .try_fold(0_u64, |slashed, (_, amount)| slashed.checked_add(*amount))?;
+
+        let apy = Self::compute(start, end, slashed, deposit_fee_bps, withdrawal_fee_bps)?;
+        Some((*start, apy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+    use crate::events::{DepositEvent, WithdrawEvent};
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_annualize() {
+        assert_close(annualize(1.1, SECONDS_PER_YEAR as i64).unwrap(), 0.1);
+        assert_close(
+            annualize(1.1, SECONDS_PER_YEAR as i64 / 2).unwrap(),
+            1.1_f64.powi(2) - 1.0,
+        );
+        assert_eq!(annualize(1.1, 0), None);
+        assert_eq!(annualize(0.0, DAY), None);
+    }
+
+    #[test]
+    fn test_checkpoint_from_event() {
+        let deposit = VaultEvent::Deposit(DepositEvent {
+            vault: Pubkey::new_unique(),
+            depositor: Pubkey::new_unique(),
+            amount_in: 1_100,
+            vrt_to_depositor: 990,
+            vrt_fee: 10,
+        });
+        let checkpoint = ExchangeRateCheckpoint::from_event(1, &deposit).unwrap();
+        assert_close(checkpoint.exchange_rate().unwrap(), 1.1);
+
+        let withdraw = VaultEvent::Withdraw(WithdrawEvent {
+            vault: Pubkey::new_unique(),
+            staker: Pubkey::new_unique(),
+            vrt_burned: 1_010,
+            vrt_fee: 10,
+            amount_out: 1_200,
+        });
+        let checkpoint = ExchangeRateCheckpoint::from_event(1, &withdraw).unwrap();
+        assert_close(checkpoint.exchange_rate().unwrap(), 1.2);
+    }
+
+    #[test]
+    fn test_realized_apy() {
+        let start = ExchangeRateCheckpoint::from_vault(0, 1_000, 1_000);
+        // 50 slashed out of the 1_100 the vault would have had
+        let end = ExchangeRateCheckpoint::from_vault(SECONDS_PER_YEAR as i64, 1_050, 1_000);
+
+        let apy = RealizedApy::compute(&start, &end, 50, 100, 100).unwrap();
+        assert_close(apy.apy, 0.05);
+        assert_close(apy.apy_before_slashes, 0.1);
+        assert_close(apy.fee_adjusted_apy, 1.05 * 0.99 * 0.99 - 1.0);
+    }
+
+    #[test]
+    fn test_over_window_starts_at_earliest_checkpoint_in_window() {
+        let end = ExchangeRateCheckpoint::from_vault(40 * DAY, 1_100, 1_000);
+        let checkpoints = [
+            // before the window
+            ExchangeRateCheckpoint::from_vault(5 * DAY, 1_000, 1_000),
+            ExchangeRateCheckpoint::from_vault(20 * DAY, 1_050, 1_000),
+            ExchangeRateCheckpoint::from_vault(15 * DAY, 1_020, 1_000),
+        ];
+        let slashes = [(DAY, 1_000), (30 * DAY, 10)];
+
+        let (start, apy) =
+            RealizedApy::over_window(&checkpoints, &end, 30 * DAY, &slashes, 0, 0).unwrap();
+        assert_eq!(start, checkpoints[2]);
+        assert_close(
+            apy.apy_before_slashes,
+            annualize(1.11 / 1.02, 25 * DAY).unwrap(),
+        );
+
+        assert_eq!(
+            RealizedApy::over_window(&checkpoints, &end, DAY, &slashes, 0, 0),
+            None
+        );
+    }
+}
@@ -0,0 +1,694 @@
+//! Typed builders for the staker-facing vault instructions and the capacity admin's
+//! [`SetDepositCapacityBuilder`]
+//!
+//! The functions in [`crate::sdk`] take every account positionally, which makes it easy to swap
+//! two pubkeys without noticing. The builders take accounts by name, derive the config, PDAs and
+//! associated token accounts that can be derived from what's been set, and return a
+//! [`BuilderError`] naming the first required account or argument that's missing. They build the
+//! instruction with the matching [`crate::sdk`] function, which stays available as is.
+//!
+//! ```ignore
+//! let instruction = MintToBuilder::new(&jito_vault_program::id(), &vault)
+//!     .vault_mints(&vrt_mint, &supported_mint)
+//!     .depositor(&depositor)
+//!     .amount_in(1_000)
+//!     .min_amount_out(990)
+//!     .build()?;
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use crate::{pda, sdk};
+
+/// An instruction that couldn't be built. It isn't a program error, so it implements
+/// [`std::error::Error`] by hand rather than deriving `thiserror::Error`, which shank would add to
+/// the IDL.
+#[derive(Debug, PartialEq, Eq)]
+pub enum BuilderError {
+    MissingAccount(&'static str),
+    MissingArgument(&'static str),
+}
+
+impl Display for BuilderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingAccount(name) => write!(f, "Missing account: {}", name),
+            Self::MissingArgument(name) => write!(f, "Missing argument: {}", name),
+        }
+    }
+}
+
+impl std::error::Error for BuilderError {}
+
+fn account(account: Option<Pubkey>, name: &'static str) -> Result<Pubkey, BuilderError> {
+    account.ok_or(BuilderError::MissingAccount(name))
+}
+
+fn argument(argument: Option<u64>, name: &'static str) -> Result<u64, BuilderError> {
+    argument.ok_or(BuilderError::MissingArgument(name))
+}
+
+/// The associated token account of `owner` for `mint`, if neither is missing
+fn ata(owner: Option<Pubkey>, mint: Option<Pubkey>) -> Option<Pubkey> {
+    Some(pda::associated_token_address(&owner?, &mint?))
+}
+
+macro_rules! account_setters {
+    ($($(#[$doc:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $name(mut self, $name: &Pubkey) -> Self {
+                self.$name = Some(*$name);
+                self
+            }
+        )*
+    };
+}
+
+/// Builds [`crate::instruction::VaultInstruction::MintTo`]
+#[derive(Debug, Clone, Default)]
+pub struct MintToBuilder {
+    program_id: Pubkey,
+    vault: Pubkey,
+    config: Option<Pubkey>,
+    vrt_mint: Option<Pubkey>,
+    supported_mint: Option<Pubkey>,
+    depositor: Option<Pubkey>,
+    depositor_token_account: Option<Pubkey>,
+    vault_token_account: Option<Pubkey>,
+    depositor_vrt_token_account: Option<Pubkey>,
+    mint_signer: Option<Pubkey>,
+    allowlisted: bool,
+    tracks_depositors: bool,
+    epoch_stats_enabled: bool,
+    amount_in: Option<u64>,
+    min_amount_out: Option<u64>,
+}
+
+impl MintToBuilder {
+    pub fn new(program_id: &Pubkey, vault: &Pubkey) -> Self {
+        Self {
+            program_id: *program_id,
+            vault: *vault,
+            ..Self::default()
+        }
+    }
+
+    account_setters!(
+        /// Defaults to the program's config PDA
+        config,
+        vrt_mint,
+        supported_mint,
+        depositor,
+        /// Defaults to the depositor's associated token account for the supported mint
+        depositor_token_account,
+        /// Defaults to the vault's associated token account for the supported mint
+        vault_token_account,
+        /// Defaults to the depositor's associated token account for the VRT mint
+        depositor_vrt_token_account,
+        /// Required when the vault has a mint burn admin
+        mint_signer,
+    );
+
+    /// Sets the vault's VRT and supported mints
+    pub fn vault_mints(self, vrt_mint: &Pubkey, supported_mint: &Pubkey) -> Self {
+        self.vrt_mint(vrt_mint).supported_mint(supported_mint)
+    }
+
+    /// Passes the depositor's VaultDepositAllowlistEntry, for vaults in the allowlist deposit mode
+    pub fn allowlisted(mut self, allowlisted: bool) -> Self {
+        self.allowlisted = allowlisted;
+        self
+    }
+
+    /// Passes the depositor's VaultDepositor, for vaults with a max deposit per wallet or flash
+    /// deposit protection
+    pub fn tracks_depositors(mut self, tracks_depositors: bool) -> Self {
+        self.tracks_depositors = tracks_depositors;
+        self
+    }
+
+    /// Passes the VaultEpochStats, for vaults with epoch stats enabled
+    pub fn epoch_stats_enabled(mut self, epoch_stats_enabled: bool) -> Self {
+        self.epoch_stats_enabled = epoch_stats_enabled;
+        self
+    }
+
+    pub fn amount_in(mut self, amount_in: u64) -> Self {
+        self.amount_in = Some(amount_in);
+        self
+    }
+
+    pub fn min_amount_out(mut self, min_amount_out: u64) -> Self {
+        self.min_amount_out = Some(min_amount_out);
+        self
+    }
+
+    pub fn build(&self) -> Result<Instruction, BuilderError> {
+        let config = self
+            .config
+            .unwrap_or_else(|| pda::derive_config(&self.program_id).0);
+        let depositor = account(self.depositor, "depositor")?;
+        let vrt_mint = account(self.vrt_mint, "vrt_mint")?;
+        let depositor_token_account = account(
+            self.depositor_token_account
+                .or_else(|| ata(self.depositor, self.supported_mint)),
+            "depositor_token_account",
+        )?;
+        let vault_token_account = account(
+            self.vault_token_account
+                .or_else(|| ata(Some(self.vault), self.supported_mint)),
+            "vault_token_account",
+        )?;
+        let depositor_vrt_token_account = account(
+            self.depositor_vrt_token_account
+                .or_else(|| ata(self.depositor, self.vrt_mint)),
+            "depositor_vrt_token_account",
+        )?;
+        let amount_in = argument(self.amount_in, "amount_in")?;
+        let min_amount_out = argument(self.min_amount_out, "min_amount_out")?;
+
+        let vault_deposit_allowlist_entry = self.allowlisted.then(|| {
+            pda::derive_vault_deposit_allowlist_entry(&self.program_id, &self.vault, &depositor).0
+        });
+        let vault_depositor = self
+            .tracks_depositors
+            .then(|| pda::derive_vault_depositor(&self.program_id, &self.vault, &depositor).0);
+
+        let mut instruction = sdk::mint_to(
+            &self.program_id,
+            &config,
+            &self.vault,
+            &vrt_mint,
+            &depositor,
+            &depositor_token_account,
+            &vault_token_account,
+            &depositor_vrt_token_account,
+            self.mint_signer.as_ref(),
+            vault_deposit_allowlist_entry.as_ref(),
+            vault_depositor.as_ref(),
+            amount_in,
+            min_amount_out,
+        );
+        push_vault_epoch_stats(
+            &self.program_id,
+            &self.vault,
+            self.epoch_stats_enabled,
+            &mut instruction,
+        );
+        Ok(instruction)
+    }
+}
+
+/// Builds [`crate::instruction::VaultInstruction::Burn`]
+#[derive(Debug, Clone, Default)]
+pub struct BurnBuilder {
+    program_id: Pubkey,
+    vault: Pubkey,
+    config: Option<Pubkey>,
+    vrt_mint: Option<Pubkey>,
+    supported_mint: Option<Pubkey>,
+    fee_wallet: Option<Pubkey>,
+    staker: Option<Pubkey>,
+    vault_token_account: Option<Pubkey>,
+    staker_token_account: Option<Pubkey>,
+    staker_vrt_token_account: Option<Pubkey>,
+    vault_fee_token_account: Option<Pubkey>,
+    burn_signer: Option<Pubkey>,
+    flash_deposit_protection: bool,
+    epoch_stats_enabled: bool,
+    amount_in: Option<u64>,
+    min_amount_out: Option<u64>,
+}
+
+impl BurnBuilder {
+    pub fn new(program_id: &Pubkey, vault: &Pubkey) -> Self {
+        Self {
+            program_id: *program_id,
+            vault: *vault,
+            ..Self::default()
+        }
+    }
+
+    account_setters!(
+        /// Defaults to the program's config PDA
+        config,
+        vrt_mint,
+        supported_mint,
+        /// The vault fee wallet, used to derive the vault fee token account
+        fee_wallet,
+        staker,
+        /// Defaults to the vault's associated token account for the supported mint
+        vault_token_account,
+        /// Defaults to the staker's associated token account for the supported mint
+        staker_token_account,
+        /// Defaults to the staker's associated token account for the VRT mint
+        staker_vrt_token_account,
+        /// Defaults to the fee wallet's associated token account for the VRT mint
+        vault_fee_token_account,
+        /// Required when the vault has a mint burn admin
+        burn_signer,
+    );
+
+    /// Sets the vault's VRT and supported mints
+    pub fn vault_mints(self, vrt_mint: &Pubkey, supported_mint: &Pubkey) -> Self {
+        self.vrt_mint(vrt_mint).supported_mint(supported_mint)
+    }
+
+    /// Passes the staker's VaultDepositor, for vaults with flash deposit protection
+    pub fn flash_deposit_protection(mut self, flash_deposit_protection: bool) -> Self {
+        self.flash_deposit_protection = flash_deposit_protection;
+        self
+    }
+
+    /// Passes the VaultEpochStats, for vaults with epoch stats enabled
+    pub fn epoch_stats_enabled(mut self, epoch_stats_enabled: bool) -> Self {
+        self.epoch_stats_enabled = epoch_stats_enabled;
+        self
+    }
+
+    pub fn amount_in(mut self, amount_in: u64) -> Self {
+        self.amount_in = Some(amount_in);
+        self
+    }
+
+    pub fn min_amount_out(mut self, min_amount_out: u64) -> Self {
+        self.min_amount_out = Some(min_amount_out);
+        self
+    }
+
+    pub fn build(&self) -> Result<Instruction, BuilderError> {
+        let config = self
+            .config
+            .unwrap_or_else(|| pda::derive_config(&self.program_id).0);
+        let staker = account(self.staker, "staker")?;
+        let vrt_mint = account(self.vrt_mint, "vrt_mint")?;
+        let vault_token_account = account(
+            self.vault_token_account
+                .or_else(|| ata(Some(self.vault), self.supported_mint)),
+            "vault_token_account",
+        )?;
+        let staker_token_account = account(
+            self.staker_token_account
+                .or_else(|| ata(self.staker, self.supported_mint)),
+            "staker_token_account",
+        )?;
+        let staker_vrt_token_account = account(
+            self.staker_vrt_token_account
+                .or_else(|| ata(self.staker, self.vrt_mint)),
+            "staker_vrt_token_account",
+        )?;
+        let vault_fee_token_account = account(
+            self.vault_fee_token_account
+                .or_else(|| ata(self.fee_wallet, self.vrt_mint)),
+            "vault_fee_token_account",
+        )?;
+        let amount_in = argument(self.amount_in, "amount_in")?;
+        let min_amount_out = argument(self.min_amount_out, "min_amount_out")?;
+
+        let vault_depositor = self
+            .flash_deposit_protection
+            .then(|| pda::derive_vault_depositor(&self.program_id, &self.vault, &staker).0);
+
+        let mut instruction = sdk::burn(
+            &self.program_id,
+            &config,
+            &self.vault,
+            &vault_token_account,
+            &vrt_mint,
+            &staker,
+            &staker_token_account,
+            &staker_vrt_token_account,
+            &vault_fee_token_account,
+            self.burn_signer.as_ref(),
+            vault_depositor.as_ref(),
+            amount_in,
+            min_amount_out,
+        );
+        push_vault_epoch_stats(
+            &self.program_id,
+            &self.vault,
+            self.epoch_stats_enabled,
+            &mut instruction,
+        );
+        Ok(instruction)
+    }
+}
+
+/// Builds [`crate::instruction::VaultInstruction::EnqueueWithdrawal`]
+#[derive(Debug, Clone, Default)]
+pub struct EnqueueWithdrawalBuilder {
+    program_id: Pubkey,
+    vault: Pubkey,
+    config: Option<Pubkey>,
+    vrt_mint: Option<Pubkey>,
+    staker: Option<Pubkey>,
+    staker_vrt_token_account: Option<Pubkey>,
+    base: Option<Pubkey>,
+    amount: Option<u64>,
+}
+
+impl EnqueueWithdrawalBuilder {
+    pub fn new(program_id: &Pubkey, vault: &Pubkey) -> Self {
+        Self {
+            program_id: *program_id,
+            vault: *vault,
+            ..Self::default()
+        }
+    }
+
+    account_setters!(
+        /// Defaults to the program's config PDA
+        config,
+        vrt_mint,
+        staker,
+        /// Defaults to the staker's associated token account for the VRT mint
+        staker_vrt_token_account,
+        /// The signer the VaultStakerWithdrawalTicket PDA is derived from
+        base,
+    );
+
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// The VaultStakerWithdrawalTicket the instruction creates, once the base is set
+    pub fn vault_staker_withdrawal_ticket(&self) -> Option<Pubkey> {
+        Some(
+            pda::derive_vault_staker_withdrawal_ticket(&self.program_id, &self.vault, &self.base?)
+                .0,
+        )
+    }
+
+    pub fn build(&self) -> Result<Instruction, BuilderError> {
+        let config = self
+            .config
+            .unwrap_or_else(|| pda::derive_config(&self.program_id).0);
+        let staker = account(self.staker, "staker")?;
+        let vrt_mint = account(self.vrt_mint, "vrt_mint")?;
+        let base = account(self.base, "base")?;
+        let staker_vrt_token_account = self
+            .staker_vrt_token_account
+            .unwrap_or_else(|| pda::associated_token_address(&staker, &vrt_mint));
+        let amount = argument(self.amount, "amount")?;
+
+        let vault_staker_withdrawal_ticket =
+            pda::derive_vault_staker_withdrawal_ticket(&self.program_id, &self.vault, &base).0;
+
+        Ok(sdk::enqueue_withdraw(
+            &self.program_id,
+            &config,
+            &self.vault,
+            &vault_staker_withdrawal_ticket,
+            &pda::associated_token_address(&vault_staker_withdrawal_ticket, &vrt_mint),
+            &staker,
+            &staker_vrt_token_account,
+            &base,
+            amount,
+        ))
+    }
+}
+
+/// Builds [`crate::instruction::VaultInstruction::BurnWithdrawTicket`]
+#[derive(Debug, Clone, Default)]
+pub struct BurnWithdrawTicketBuilder {
+    program_id: Pubkey,
+    vault: Pubkey,
+    config: Option<Pubkey>,
+    vrt_mint: Option<Pubkey>,
+    supported_mint: Option<Pubkey>,
+    fee_wallet: Option<Pubkey>,
+    staker: Option<Pubkey>,
+    base: Option<Pubkey>,
+    vault_token_account: Option<Pubkey>,
+    staker_token_account: Option<Pubkey>,
+    vault_fee_token_account: Option<Pubkey>,
+    burn_signer: Option<Pubkey>,
+    claimer_token_account: Option<Pubkey>,
+    epoch_stats_enabled: bool,
+    min_amount_out: Option<u64>,
+}
+
+impl BurnWithdrawTicketBuilder {
+    pub fn new(program_id: &Pubkey, vault: &Pubkey) -> Self {
+        Self {
+            program_id: *program_id,
+            vault: *vault,
+            ..Self::default()
+        }
+    }
+
+    account_setters!(
+        /// Defaults to the program's config PDA
+        config,
+        vrt_mint,
+        supported_mint,
+        /// The vault fee wallet, used to derive the vault fee token account
+        fee_wallet,
+        staker,
+        /// The base the VaultStakerWithdrawalTicket PDA was derived from
+        base,
+        /// Defaults to the vault's associated token account for the supported mint
+        vault_token_account,
+        /// Defaults to the staker's associated token account for the supported mint
+        staker_token_account,
+        /// Defaults to the fee wallet's associated token account for the VRT mint
+        vault_fee_token_account,
+        /// Required when the vault has a mint burn admin
+        burn_signer,
+        /// Receives the auto-claim fee when burning a ticket on the staker's behalf
+        claimer_token_account,
+    );
+
+    /// Sets the vault's VRT and supported mints
+    pub fn vault_mints(self, vrt_mint: &Pubkey, supported_mint: &Pubkey) -> Self {
+        self.vrt_mint(vrt_mint).supported_mint(supported_mint)
+    }
+
+    /// Passes the VaultEpochStats, for vaults with epoch stats enabled
+    pub fn epoch_stats_enabled(mut self, epoch_stats_enabled: bool) -> Self {
+        self.epoch_stats_enabled = epoch_stats_enabled;
+        self
+    }
+
+    pub fn min_amount_out(mut self, min_amount_out: u64) -> Self {
+        self.min_amount_out = Some(min_amount_out);
+        self
+    }
+
+    pub fn build(&self) -> Result<Instruction, BuilderError> {
+        let config = self
+            .config
+            .unwrap_or_else(|| pda::derive_config(&self.program_id).0);
+        let staker = account(self.staker, "staker")?;
+        let vrt_mint = account(self.vrt_mint, "vrt_mint")?;
+        let base = account(self.base, "base")?;
+        let vault_token_account = account(
+            self.vault_token_account
+                .or_else(|| ata(Some(self.vault), self.supported_mint)),
+            "vault_token_account",
+        )?;
+        let staker_token_account = account(
+            self.staker_token_account
+                .or_else(|| ata(self.staker, self.supported_mint)),
+            "staker_token_account",
+        )?;
+        let vault_fee_token_account = account(
+            self.vault_fee_token_account
+                .or_else(|| ata(self.fee_wallet, self.vrt_mint)),
+            "vault_fee_token_account",
+        )?;
+        let min_amount_out = argument(self.min_amount_out, "min_amount_out")?;
+
+        let vault_staker_withdrawal_ticket =
+            pda::derive_vault_staker_withdrawal_ticket(&self.program_id, &self.vault, &base).0;
+
+        let mut instruction = sdk::burn_withdrawal_ticket(
+            &self.program_id,
+            &config,
+            &self.vault,
+            &vault_token_account,
+            &vrt_mint,
+            &staker,
+            &staker_token_account,
+            &vault_staker_withdrawal_ticket,
+            &pda::associated_token_address(&vault_staker_withdrawal_ticket, &vrt_mint),
+            &vault_fee_token_account,
+            self.burn_signer.as_ref(),
+            self.claimer_token_account.as_ref(),
+            min_amount_out,
+        );
+        push_vault_epoch_stats(
+            &self.program_id,
+            &self.vault,
+            self.epoch_stats_enabled,
+            &mut instruction,
+        );
+        Ok(instruction)
+    }
+}
+
+/// Builds [`crate::instruction::VaultInstruction::SetDepositCapacity`]
+#[derive(Debug, Clone, Default)]
+pub struct SetDepositCapacityBuilder {
+    program_id: Pubkey,
+    vault: Pubkey,
+    config: Option<Pubkey>,
+    capacity_admin: Option<Pubkey>,
+    amount: Option<u64>,
+}
+
+impl SetDepositCapacityBuilder {
+    pub fn new(program_id: &Pubkey, vault: &Pubkey) -> Self {
+        Self {
+            program_id: *program_id,
+            vault: *vault,
+            ..Self::default()
+        }
+    }
+
+    account_setters!(
+        /// Defaults to the program's config PDA
+        config,
+        /// The vault's capacity admin, which is the vault admin unless it delegated the role
+        capacity_admin,
+    );
+
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn build(&self) -> Result<Instruction, BuilderError> {
+        let config = self
+            .config
+            .unwrap_or_else(|| pda::derive_config(&self.program_id).0);
+        let capacity_admin = account(self.capacity_admin, "capacity_admin")?;
+        let amount = argument(self.amount, "amount")?;
+
+        Ok(sdk::set_deposit_capacity(
+            &self.program_id,
+            &config,
+            &self.vault,
+            &capacity_admin,
+            amount,
+        ))
+    }
+}
+
+/// The VaultEpochStats goes after the instruction's other optional accounts
+fn push_vault_epoch_stats(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    epoch_stats_enabled: bool,
+    instruction: &mut Instruction,
+) {
+    if epoch_stats_enabled {
+        instruction.accounts.push(AccountMeta::new(
+            pda::derive_vault_epoch_stats(program_id, vault).0,
+            false,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mint_to_builder_matches_sdk() {
+        let program_id = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let vrt_mint = Pubkey::new_unique();
+        let supported_mint = Pubkey::new_unique();
+        let depositor = Pubkey::new_unique();
+
+        let instruction = MintToBuilder::new(&program_id, &vault)
+            .vault_mints(&vrt_mint, &supported_mint)
+            .depositor(&depositor)
+            .tracks_depositors(true)
+            .amount_in(1_000)
+            .min_amount_out(990)
+            .build()
+            .unwrap();
+
+        let expected = sdk::mint_to(
+            &program_id,
+            &pda::derive_config(&program_id).0,
+            &vault,
+            &vrt_mint,
+            &depositor,
+            &pda::associated_token_address(&depositor, &supported_mint),
+            &pda::associated_token_address(&vault, &supported_mint),
+            &pda::associated_token_address(&depositor, &vrt_mint),
+            None,
+            None,
+            Some(&pda::derive_vault_depositor(&program_id, &vault, &depositor).0),
+            1_000,
+            990,
+        );
+        assert_eq!(instruction, expected);
+    }
+
+    #[test]
+    fn test_builder_missing_account_fails() {
+        let program_id = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+
+        // the vault token account can't be derived without the supported mint
+        let result = BurnBuilder::new(&program_id, &vault)
+            .vrt_mint(&Pubkey::new_unique())
+            .staker(&Pubkey::new_unique())
+            .amount_in(1_000)
+            .min_amount_out(0)
+            .build();
+        assert_eq!(
+            result,
+            Err(BuilderError::MissingAccount("vault_token_account"))
+        );
+
+        let result = EnqueueWithdrawalBuilder::new(&program_id, &vault)
+            .vrt_mint(&Pubkey::new_unique())
+            .staker(&Pubkey::new_unique())
+            .base(&Pubkey::new_unique())
+            .build();
+        assert_eq!(result, Err(BuilderError::MissingArgument("amount")));
+
+        let result = SetDepositCapacityBuilder::new(&program_id, &vault)
+            .amount(1_000)
+            .build();
+        assert_eq!(result, Err(BuilderError::MissingAccount("capacity_admin")));
+    }
+
+    #[test]
+    fn test_burn_withdraw_ticket_builder_pushes_epoch_stats_last() {
+        let program_id = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let claimer_token_account = Pubkey::new_unique();
+
+        let instruction = BurnWithdrawTicketBuilder::new(&program_id, &vault)
+            .vault_mints(&Pubkey::new_unique(), &Pubkey::new_unique())
+            .fee_wallet(&Pubkey::new_unique())
+            .staker(&Pubkey::new_unique())
+            .base(&Pubkey::new_unique())
+            .claimer_token_account(&claimer_token_account)
+            .epoch_stats_enabled(true)
+            .min_amount_out(0)
+            .build()
+            .unwrap();
+
+        let optional_accounts = &instruction.accounts[11..];
+        assert_eq!(optional_accounts.len(), 2);
+        assert_eq!(optional_accounts[0].pubkey, claimer_token_account);
+        assert_eq!(
+            optional_accounts[1].pubkey,
+            pda::derive_vault_epoch_stats(&program_id, &vault).0
+        );
+    }
+}
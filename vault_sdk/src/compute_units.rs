@@ -0,0 +1,146 @@
+//! Recommended compute unit limits for the vault instructions
+//!
+//! Clients can set a `ComputeBudgetInstruction::set_compute_unit_limit` from
+//! [`recommended_compute_units`] instead of relying on the default per-instruction limit or
+//! guessing. The limits are the compute units measured in program-test plus headroom for the
+//! optional accounts and PDA bump searches, which vary between calls.
+//!
+//! The `compute_units` integration test measures the common instructions and fails when one
+//! exceeds its limit here, printing the measurements so the table can be updated.
+
+use crate::instruction::VaultInstruction;
+
+/// Admin instructions that only update the vault or another vault program account
+pub const SET_ACCOUNT_FIELD: u32 = 10_000;
+/// Instructions that create a single vault program account
+pub const INITIALIZE_ACCOUNT: u32 = 30_000;
+/// Instructions that close a single vault program account
+pub const CLOSE_ACCOUNT: u32 = 15_000;
+
+pub const INITIALIZE_CONFIG: u32 = 25_000;
+pub const INITIALIZE_VAULT: u32 = 80_000;
+pub const INITIALIZE_VAULT_WITH_MINT: u32 = 60_000;
+pub const INITIALIZE_VAULT_TOKEN_ACCOUNT: u32 = 40_000;
+pub const MINT_TO: u32 = 60_000;
+pub const MINT_TO_WITH_SOL: u32 = 75_000;
+pub const BURN: u32 = 60_000;
+/// A burn followed by a mint
+pub const MIGRATE_TO_VAULT: u32 = 120_000;
+pub const ENQUEUE_WITHDRAWAL: u32 = 70_000;
+pub const BURN_OR_ENQUEUE_WITHDRAWAL: u32 = 80_000;
+pub const BURN_WITHDRAW_TICKET: u32 = 75_000;
+pub const CHANGE_WITHDRAWAL_TICKET_OWNER: u32 = 15_000;
+pub const ADMIN_WITHDRAW: u32 = 30_000;
+pub const UPDATE_VAULT_BALANCE: u32 = 40_000;
+pub const DELEGATION: u32 = 25_000;
+pub const REBALANCE_DELEGATIONS: u32 = 30_000;
+pub const CRANK_VAULT_UPDATE_STATE_TRACKER: u32 = 30_000;
+pub const CRANK_OPERATOR_STAKE_WEIGHT: u32 = 30_000;
+pub const CREATE_TOKEN_METADATA: u32 = 80_000;
+pub const UPDATE_TOKEN_METADATA: u32 = 40_000;
+pub const SLASH: u32 = 90_000;
+pub const PROPOSE_SLASH: u32 = 60_000;
+pub const EXECUTE_SLASH: u32 = 60_000;
+pub const EXECUTE_MINT_AUTHORITY_HANDOFF: u32 = 25_000;
+pub const EXECUTE_ADMIN_BURN: u32 = 75_000;
+pub const INITIALIZE_REWARD_DISTRIBUTION: u32 = 45_000;
+pub const CLAIM_REWARDS: u32 = 60_000;
+/// Covers a handful of operators, each one adds a transfer
+pub const DISTRIBUTE_NCN_REWARDS: u32 = 120_000;
+pub const FUND_INSURANCE: u32 = 30_000;
+
+/// The recommended compute unit limit for a transaction with only `instruction`. Add up the limits
+/// of each instruction for transactions with several.
+pub fn recommended_compute_units(instruction: &VaultInstruction) -> u32 {
+    match instruction {
+        VaultInstruction::InitializeConfig => INITIALIZE_CONFIG,
+        VaultInstruction::InitializeVault { .. } => INITIALIZE_VAULT,
+        VaultInstruction::InitializeVaultWithMint => INITIALIZE_VAULT_WITH_MINT,
+        VaultInstruction::InitializeVaultTokenAccount => INITIALIZE_VAULT_TOKEN_ACCOUNT,
+        VaultInstruction::MintTo { .. } => MINT_TO,
+        VaultInstruction::MintToWithSol { .. } => MINT_TO_WITH_SOL,
+        VaultInstruction::Burn { .. } | VaultInstruction::BurnDelegated { .. } => BURN,
+        VaultInstruction::MigrateToVault { .. } => MIGRATE_TO_VAULT,
+        VaultInstruction::EnqueueWithdrawal { .. } => ENQUEUE_WITHDRAWAL,
+        VaultInstruction::BurnOrEnqueueWithdrawal { .. } => BURN_OR_ENQUEUE_WITHDRAWAL,
+        VaultInstruction::BurnWithdrawTicket { .. } => BURN_WITHDRAW_TICKET,
+        VaultInstruction::ChangeWithdrawalTicketOwner => CHANGE_WITHDRAWAL_TICKET_OWNER,
+        VaultInstruction::AdminWithdraw { .. } => ADMIN_WITHDRAW,
+        VaultInstruction::UpdateVaultBalance => UPDATE_VAULT_BALANCE,
+        VaultInstruction::AddDelegation { .. } | VaultInstruction::CooldownDelegation { .. } => {
+            DELEGATION
+        }
+        VaultInstruction::RebalanceDelegations => REBALANCE_DELEGATIONS,
+        VaultInstruction::CrankVaultUpdateStateTracker => CRANK_VAULT_UPDATE_STATE_TRACKER,
+        VaultInstruction::CrankOperatorStakeWeight => CRANK_OPERATOR_STAKE_WEIGHT,
+        VaultInstruction::CreateTokenMetadata { .. } => CREATE_TOKEN_METADATA,
+        VaultInstruction::UpdateTokenMetadata { .. } => UPDATE_TOKEN_METADATA,
+        VaultInstruction::Slash { .. } => SLASH,
+        VaultInstruction::ProposeSlash { .. } => PROPOSE_SLASH,
+        VaultInstruction::ExecuteSlash => EXECUTE_SLASH,
+        VaultInstruction::ExecuteMintAuthorityHandoff => EXECUTE_MINT_AUTHORITY_HANDOFF,
+        VaultInstruction::ExecuteAdminBurn => EXECUTE_ADMIN_BURN,
+        VaultInstruction::InitializeRewardDistribution { .. } => INITIALIZE_REWARD_DISTRIBUTION,
+        VaultInstruction::ClaimRewards { .. } => CLAIM_REWARDS,
+        VaultInstruction::DistributeNcnRewards => DISTRIBUTE_NCN_REWARDS,
+        VaultInstruction::FundInsurance { .. } => FUND_INSURANCE,
+        VaultInstruction::InitializeVaultOperatorDelegation
+        | VaultInstruction::InitializeVaultNcnTicket
+        | VaultInstruction::InitializeVaultNcnSlasherOperatorTicket
+        | VaultInstruction::InitializeVaultNcnSlasherTicket
+        | VaultInstruction::InitializeVaultUpdateStateTracker { .. }
+        | VaultInstruction::AddToDepositAllowlist
+        | VaultInstruction::ProposeMintAuthorityHandoff
+        | VaultInstruction::InitializeDelegationStrategy { .. }
+        | VaultInstruction::InitializeVaultEpochStats
+        | VaultInstruction::SnapshotVaultOperatorDelegation
+        | VaultInstruction::InitializeNcnRewardRouter { .. }
+        | VaultInstruction::SetInsuranceParams { .. }
+        | VaultInstruction::MigrateAccount
+        | VaultInstruction::InitializeOperatorStakeWeight => INITIALIZE_ACCOUNT,
+        VaultInstruction::CloseVaultUpdateStateTracker { .. }
+        | VaultInstruction::RemoveFromDepositAllowlist
+        | VaultInstruction::CancelMintAuthorityHandoff
+        | VaultInstruction::VetoSlash
+        | VaultInstruction::CloseVault
+        | VaultInstruction::CloseDepositReceipt
+        | VaultInstruction::RemoveSlasher => CLOSE_ACCOUNT,
+        VaultInstruction::WarmupVaultNcnTicket
+        | VaultInstruction::CooldownVaultNcnTicket
+        | VaultInstruction::WarmupVaultNcnSlasherTicket
+        | VaultInstruction::CooldownVaultNcnSlasherTicket
+        | VaultInstruction::SetDepositCapacity { .. }
+        | VaultInstruction::SetFees { .. }
+        | VaultInstruction::SetAdmin
+        | VaultInstruction::SetSecondaryAdmin(_)
+        | VaultInstruction::SetVaultPaused { .. }
+        | VaultInstruction::SetFeeWallet
+        | VaultInstruction::AdminSetProtocolFee { .. }
+        | VaultInstruction::SetMinDelegationAmount { .. }
+        | VaultInstruction::SetMaxTotalSlashablePerEpoch { .. }
+        | VaultInstruction::SetMigrationPartner
+        | VaultInstruction::SetWithdrawalDelay { .. }
+        | VaultInstruction::SetVaultEpochLength { .. }
+        | VaultInstruction::SetDepositMode { .. }
+        | VaultInstruction::SetDepositLimits { .. }
+        | VaultInstruction::SetWithdrawalTicketAutoClaimFee { .. }
+        | VaultInstruction::AdminSetDelegationCooldown { .. }
+        | VaultInstruction::AdminSetVaultEpochLengthBounds { .. }
+        | VaultInstruction::AdminSetSlashVeto { .. }
+        | VaultInstruction::SetOperatorMaxDelegation { .. }
+        | VaultInstruction::SetOperatorDelegationPaused { .. }
+        | VaultInstruction::BanOperator { .. }
+        | VaultInstruction::UnbanOperator
+        | VaultInstruction::SetDelegationStrategyWeight { .. }
+        | VaultInstruction::SetFlashDepositProtection { .. }
+        | VaultInstruction::SetSlashDisputeHaircut { .. }
+        | VaultInstruction::EnableAdminBurn
+        | VaultInstruction::ProposeAdminBurn
+        | VaultInstruction::CancelAdminBurn
+        | VaultInstruction::AdminSetConfigPaused { .. }
+        | VaultInstruction::AdminSetCrankReward { .. }
+        | VaultInstruction::ReconcileVaultBalance { .. }
+        | VaultInstruction::InitiateVaultShutdown
+        | VaultInstruction::SetHookProgram => SET_ACCOUNT_FIELD,
+    }
+}
@@ -1,7 +1,9 @@
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive as _;
 use solana_program::{decode_error::DecodeError, program_error::ProgramError};
 use thiserror::Error;
 
-#[derive(Debug, Error, PartialEq, Eq)]
+#[derive(Debug, Error, PartialEq, Eq, FromPrimitive)]
 pub enum VaultError {
     #[error("VaultSlashUnderflow")]
     VaultSlashUnderflow = 1000,
@@ -93,6 +95,153 @@ pub enum VaultError {
     VaultEnqueueWithdrawalAmountZero,
     #[error("VaultMintZero")]
     VaultMintZero,
+    #[error("VaultPauseAdminInvalid")]
+    VaultPauseAdminInvalid,
+    #[error("VaultIsPaused")]
+    VaultIsPaused,
+    #[error("ConfigAdminInvalid")]
+    ConfigAdminInvalid,
+    #[error("ConfigProtocolFeeCapExceeded")]
+    ConfigProtocolFeeCapExceeded,
+    #[error("VaultMetadataAdminInvalid")]
+    VaultMetadataAdminInvalid,
+    #[error("VaultDelegationBelowMinimum")]
+    VaultDelegationBelowMinimum,
+    #[error("VaultDepositNotAllowed")]
+    VaultDepositNotAllowed,
+    #[error("VaultDepositPerTransactionLimitExceeded")]
+    VaultDepositPerTransactionLimitExceeded,
+    #[error("VaultDepositPerWalletLimitExceeded")]
+    VaultDepositPerWalletLimitExceeded,
+    #[error("VaultMintAuthorityHandoffPending")]
+    VaultMintAuthorityHandoffPending,
+    #[error("VaultMintAuthorityHandoffNotPending")]
+    VaultMintAuthorityHandoffNotPending,
+    #[error("VaultMintAuthorityHandoffChallengeWindowActive")]
+    VaultMintAuthorityHandoffChallengeWindowActive,
+    #[error("VaultMintAuthoritySuccessorInvalid")]
+    VaultMintAuthoritySuccessorInvalid,
+    #[error("ConfigDelegationCooldownEpochsInvalid")]
+    ConfigDelegationCooldownEpochsInvalid,
+    #[error("ConfigSlashVetoWindowInvalid")]
+    ConfigSlashVetoWindowInvalid,
+    #[error("ConfigSlashVetoAdminInvalid")]
+    ConfigSlashVetoAdminInvalid,
+    #[error("VaultSlashProposalRequired")]
+    VaultSlashProposalRequired,
+    #[error("VaultSlashProposalVetoWindowActive")]
+    VaultSlashProposalVetoWindowActive,
+    #[error("VaultSlashProposalInvalid")]
+    VaultSlashProposalInvalid,
+    #[error("VaultOperatorFrozen")]
+    VaultOperatorFrozen,
+    #[error("VaultNotEmpty")]
+    VaultNotEmpty,
+    #[error("VaultDelegationAboveMaximum")]
+    VaultDelegationAboveMaximum,
+    #[error("VaultOperatorDelegationPaused")]
+    VaultOperatorDelegationPaused,
+    #[error("VaultDelegationStrategyMovementExceeded")]
+    VaultDelegationStrategyMovementExceeded,
+    #[error("VaultBurnInDepositSlot")]
+    VaultBurnInDepositSlot,
+    #[error("VaultAdminBurnAlreadyEnabled")]
+    VaultAdminBurnAlreadyEnabled,
+    #[error("VaultAdminBurnEnableAfterDeposit")]
+    VaultAdminBurnEnableAfterDeposit,
+    #[error("VaultAdminBurnNotEnabled")]
+    VaultAdminBurnNotEnabled,
+    #[error("VaultBurnAdminInvalid")]
+    VaultBurnAdminInvalid,
+    #[error("VaultAdminBurnPending")]
+    VaultAdminBurnPending,
+    #[error("VaultAdminBurnNotPending")]
+    VaultAdminBurnNotPending,
+    #[error("VaultAdminBurnTimelockActive")]
+    VaultAdminBurnTimelockActive,
+    #[error("VaultAccountDataEmpty")]
+    VaultAccountDataEmpty,
+    #[error("VaultAccountNotWritable")]
+    VaultAccountNotWritable,
+    #[error("VaultAccountDiscriminatorInvalid")]
+    VaultAccountDiscriminatorInvalid,
+    #[error("VaultAccountNotCanonicalPda")]
+    VaultAccountNotCanonicalPda,
+    #[error("VaultVrtMintInvalid")]
+    VaultVrtMintInvalid,
+    #[error("VaultSupportedMintInvalid")]
+    VaultSupportedMintInvalid,
+    #[error("VaultMetadataAccountInvalid")]
+    VaultMetadataAccountInvalid,
+    #[error("ConfigPaused")]
+    ConfigPaused,
+    #[error("VaultTokenAccountClosed")]
+    VaultTokenAccountClosed,
+    #[error("VaultBalanceDiverged")]
+    VaultBalanceDiverged,
+    #[error("VaultBalanceNotDiverged")]
+    VaultBalanceNotDiverged,
+    #[error("VaultReconcileAdjustmentExceeded")]
+    VaultReconcileAdjustmentExceeded,
+    #[error("VaultOperatorDelegationNotCranked")]
+    VaultOperatorDelegationNotCranked,
+    #[error("VaultRewardMintInvalid")]
+    VaultRewardMintInvalid,
+    #[error("VaultRewardProofInvalid")]
+    VaultRewardProofInvalid,
+    #[error("VaultRewardsExceeded")]
+    VaultRewardsExceeded,
+    #[error("VaultIsShutDown")]
+    VaultIsShutDown,
+    #[error("VaultOperatorDelegationsIncomplete")]
+    VaultOperatorDelegationsIncomplete,
+    #[error("VaultSlashDestinationInvalid")]
+    VaultSlashDestinationInvalid,
+    #[error("VaultHookProgramInvalid")]
+    VaultHookProgramInvalid,
+    #[error("VaultBurnDelegateInvalid")]
+    VaultBurnDelegateInvalid,
+    #[error("ConfigCrankRewardInvalid")]
+    ConfigCrankRewardInvalid,
+    #[error("VaultMaxTotalSlashableBpsInvalid")]
+    VaultMaxTotalSlashableBpsInvalid,
+    #[error("VaultMaxTotalSlashedPerEpochExceeded")]
+    VaultMaxTotalSlashedPerEpochExceeded,
+    #[error("VaultMigrationInvalid")]
+    VaultMigrationInvalid,
+    #[error("VaultNcnSlasherTicketNotInactive")]
+    VaultNcnSlasherTicketNotInactive,
+    #[error("VaultWithdrawalDelayInvalid")]
+    VaultWithdrawalDelayInvalid,
+    #[error("VaultWithdrawalDelayActive")]
+    VaultWithdrawalDelayActive,
+    #[error("VaultAccountVersionInvalid")]
+    VaultAccountVersionInvalid,
+    #[error("OperatorStakeWeightVaultIndexInvalid")]
+    OperatorStakeWeightVaultIndexInvalid,
+    #[error("OperatorStakeWeightVaultAlreadyCounted")]
+    OperatorStakeWeightVaultAlreadyCounted,
+    #[error("OperatorStakeWeightVaultNcnInactive")]
+    OperatorStakeWeightVaultNcnInactive,
+    #[error("VaultDelegationNotApproved")]
+    VaultDelegationNotApproved,
+    #[error("ConfigVaultEpochLengthBoundsInvalid")]
+    ConfigVaultEpochLengthBoundsInvalid,
+    #[error("VaultEpochLengthInvalid")]
+    VaultEpochLengthInvalid,
+    #[error("VaultEpochLengthCooldownInProgress")]
+    VaultEpochLengthCooldownInProgress,
+    #[error("VaultOperatorBanned")]
+    VaultOperatorBanned,
+    #[error("VaultOperatorNotBanned")]
+    VaultOperatorNotBanned,
+}
+
+impl VaultError {
+    /// Decodes the error from a [`ProgramError::Custom`] code returned by the vault program
+    pub fn from_code(code: u32) -> Option<Self> {
+        Self::from_u32(code)
+    }
 }
 
 impl<T> DecodeError<T> for VaultError {
@@ -118,3 +267,21 @@ impl From<VaultError> for u32 {
         e as Self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(
+            VaultError::from_code(1000),
+            Some(VaultError::VaultSlashUnderflow)
+        );
+        assert_eq!(
+            VaultError::from_code(VaultError::VaultMetadataAccountInvalid.into()),
+            Some(VaultError::VaultMetadataAccountInvalid)
+        );
+        assert_eq!(VaultError::from_code(999), None);
+    }
+}
@@ -0,0 +1,215 @@
+//! Events emitted by the vault program
+//!
+//! The vault program logs a borsh-encoded [`VaultEvent`] with `sol_log_data` whenever assets move
+//! in or out of a vault, its delegation changes or its fees change. The enum tag is the event
+//! discriminator, so new events are only ever appended.
+//!
+//! Indexers can read them back from a transaction's log messages with [`parse_vault_events`].
+//! Events emitted after the runtime truncated the logs are lost, so the account state remains the
+//! source of truth.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+
+/// The prefix the runtime gives `sol_log_data` logs
+const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum VaultEvent {
+    /// Supported tokens were deposited for VRT
+    Deposit(DepositEvent),
+
+    /// VRT or a withdrawal ticket was burned for supported tokens
+    Withdraw(WithdrawEvent),
+
+    /// The vault delegated to or cooled down stake from an operator
+    DelegationChanged(DelegationChangedEvent),
+
+    /// Supported tokens were slashed from the vault
+    Slashed(SlashedEvent),
+
+    /// The vault fee admin changed the vault fees
+    FeeChanged(FeeChangedEvent),
+
+    /// The vault admin wrote the tokens deposited down to the vault token account balance
+    BalanceReconciled(BalanceReconciledEvent),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct DepositEvent {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+    /// The supported tokens deposited
+    pub amount_in: u64,
+    /// The VRT minted to the depositor
+    pub vrt_to_depositor: u64,
+    /// The VRT accrued to the fee wallet
+    pub vrt_fee: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct WithdrawEvent {
+    pub vault: Pubkey,
+    pub staker: Pubkey,
+    /// The VRT burned, including the fee
+    pub vrt_burned: u64,
+    /// The VRT accrued to the fee wallet
+    pub vrt_fee: u64,
+    /// The supported tokens withdrawn from the vault
+    pub amount_out: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum DelegationChange {
+    Delegate,
+    Cooldown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct DelegationChangedEvent {
+    pub vault: Pubkey,
+    pub operator: Pubkey,
+    pub change: DelegationChange,
+    /// The amount delegated or enqueued for cooldown
+    pub amount: u64,
+    /// The operator delegation's staked amount after the change
+    pub staked_amount: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SlashedEvent {
+    pub vault: Pubkey,
+    pub ncn: Pubkey,
+    pub operator: Pubkey,
+    pub slasher: Pubkey,
+    /// The supported tokens slashed
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FeeChangedEvent {
+    pub vault: Pubkey,
+    /// The new deposit fee, if it changed. It takes effect the next epoch.
+    pub deposit_fee_bps: Option<u16>,
+    /// The new withdrawal fee, if it changed. It takes effect the next epoch.
+    pub withdrawal_fee_bps: Option<u16>,
+    /// The new reward fee, if it changed
+    pub reward_fee_bps: Option<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BalanceReconciledEvent {
+    pub vault: Pubkey,
+    /// The tokens deposited before the adjustment
+    pub tokens_deposited_before: u64,
+    /// The tokens deposited after the adjustment, the vault token account balance
+    pub tokens_deposited_after: u64,
+}
+
+impl VaultEvent {
+    /// Logs the event with `sol_log_data`
+    pub fn emit(&self) {
+        sol_log_data(&[&self.try_to_vec().unwrap()]);
+    }
+
+    /// Decodes an event from the payload of a single `sol_log_data` field
+    pub fn try_from_data(data: &[u8]) -> Option<Self> {
+        Self::try_from_slice(data).ok()
+    }
+}
+
+/// Parses the [`VaultEvent`]s the vault program `program_id` emitted in a transaction, in order.
+///
+/// `logs` are the transaction's log messages. Only data logged while `program_id` is the innermost
+/// running program is decoded, so data logged by other programs, including ones the vault program
+/// invokes, is skipped.
+pub fn parse_vault_events<S: AsRef<str>>(program_id: &Pubkey, logs: &[S]) -> Vec<VaultEvent> {
+    let program_id = program_id.to_string();
+    let mut invocations: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        let log = log.as_ref();
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_LOG_PREFIX) {
+            if invocations.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+            let event = data
+                .split(' ')
+                .next()
+                .and_then(|field| STANDARD.decode(field).ok())
+                .and_then(|data| VaultEvent::try_from_data(&data));
+            events.extend(event);
+        } else if let Some(rest) = log.strip_prefix("Program ") {
+            let mut words = rest.split(' ');
+            match (words.next(), words.next()) {
+                (Some(program), Some("invoke")) => invocations.push(program),
+                (Some(_), Some("success")) | (Some(_), Some("failed:")) => {
+                    invocations.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data_log(event: &VaultEvent) -> String {
+        format!(
+            "{}{}",
+            PROGRAM_DATA_LOG_PREFIX,
+            STANDARD.encode(event.try_to_vec().unwrap())
+        )
+    }
+
+    #[test]
+    fn test_parse_vault_events() {
+        let program_id = Pubkey::new_unique();
+        let token_program = spl_token::id();
+        let other_program = Pubkey::new_unique();
+        let deposit = VaultEvent::Deposit(DepositEvent {
+            vault: Pubkey::new_unique(),
+            depositor: Pubkey::new_unique(),
+            amount_in: 1_000,
+            vrt_to_depositor: 990,
+            vrt_fee: 10,
+        });
+        let fee_changed = VaultEvent::FeeChanged(FeeChangedEvent {
+            vault: Pubkey::new_unique(),
+            deposit_fee_bps: Some(10),
+            withdrawal_fee_bps: None,
+            reward_fee_bps: None,
+        });
+
+        let logs = vec![
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Instruction: MintTo".to_string(),
+            format!("Program {} invoke [2]", token_program),
+            // data logged by a program the vault invokes
+            data_log(&fee_changed),
+            format!("Program {} success", token_program),
+            data_log(&deposit),
+            format!("Program {} success", program_id),
+            // data logged by another top-level program
+            format!("Program {} invoke [1]", other_program),
+            data_log(&deposit),
+            format!("Program {} success", other_program),
+            format!("Program {} invoke [1]", program_id),
+            // data that isn't an event
+            "Program data: bm90IGFuIGV2ZW50".to_string(),
+            data_log(&fee_changed),
+            format!("Program {} success", program_id),
+        ];
+
+        assert_eq!(
+            parse_vault_events(&program_id, &logs),
+            vec![deposit, fee_changed]
+        );
+    }
+}
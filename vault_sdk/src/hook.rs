@@ -0,0 +1,94 @@
+//! The interface between the vault program and a vault's hook program
+//!
+//! A vault admin can set a hook program with
+//! [`crate::instruction::VaultInstruction::SetHookProgram`], which the vault program then invokes
+//! after every MintTo, MintToWithSol and Burn with a [`VaultHookInstruction`]. Integrations like
+//! points programs or compliance checks can build on it without forking the vault program, and a
+//! hook that fails the instruction fails the deposit or withdrawal with it.
+//!
+//! The instruction data starts with [`VAULT_HOOK_PREFIX`], so a hook program can tell the vault's
+//! calls apart from its own instructions, followed by the borsh-encoded [`VaultHookInstruction`].
+//! The vault and the user are passed read-only and without signing, so a hook can't act on their
+//! behalf. A hook that needs to know the call came from the vault program should check the
+//! instructions sysvar.
+//!
+//! The hook's compute units come out of the deposit or withdrawal's budget, so clients should add
+//! them to the [`crate::compute_units`] limits.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+/// The prefix of the instruction data the vault program sends to hook programs
+pub const VAULT_HOOK_PREFIX: [u8; 8] = *b"vlt_hook";
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum VaultHookInstruction {
+    /// Invoked after supported tokens were deposited for VRT
+    ///
+    /// Accounts: the vault, the depositor
+    AfterMint {
+        amount_in: u64,
+        vrt_to_depositor: u64,
+    },
+
+    /// Invoked after VRT was burned for supported tokens
+    ///
+    /// Accounts: the vault, the staker
+    AfterBurn { vrt_burned: u64, amount_out: u64 },
+}
+
+impl VaultHookInstruction {
+    /// Encodes the instruction as the data the vault program sends to hook programs
+    pub fn pack(&self) -> Vec<u8> {
+        let mut data = VAULT_HOOK_PREFIX.to_vec();
+        data.extend(self.try_to_vec().unwrap());
+        data
+    }
+
+    /// Decodes instruction data sent by the vault program, returning `None` if it isn't a vault
+    /// hook instruction
+    pub fn unpack(data: &[u8]) -> Option<Self> {
+        let data = data.strip_prefix(&VAULT_HOOK_PREFIX)?;
+        Self::try_from_slice(data).ok()
+    }
+}
+
+/// Builds the instruction invoking a hook program for `user`'s deposit or withdrawal in `vault`
+pub fn vault_hook(
+    hook_program: &Pubkey,
+    vault: &Pubkey,
+    user: &Pubkey,
+    instruction: &VaultHookInstruction,
+) -> Instruction {
+    Instruction {
+        program_id: *hook_program,
+        accounts: vec![
+            AccountMeta::new_readonly(*vault, false),
+            AccountMeta::new_readonly(*user, false),
+        ],
+        data: instruction.pack(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_unpack() {
+        let instruction = VaultHookInstruction::AfterBurn {
+            vrt_burned: 1_000,
+            amount_out: 990,
+        };
+        let data = instruction.pack();
+        assert_eq!(&data[..8], &VAULT_HOOK_PREFIX);
+        assert_eq!(VaultHookInstruction::unpack(&data), Some(instruction));
+
+        // the hook program's own instructions aren't mistaken for the vault's
+        assert_eq!(VaultHookInstruction::unpack(&data[8..]), None);
+        assert_eq!(VaultHookInstruction::unpack(&[]), None);
+    }
+}
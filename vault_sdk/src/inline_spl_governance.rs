@@ -0,0 +1,240 @@
+//! Inlined SPL Governance types to avoid a direct dependency on
+//! `spl-governance`
+//!
+//! A DAO governs a vault by making one of its governance accounts, or that governance's native
+//! treasury, the vault's admin (or any of the secondary admins). Privileged vault instructions are
+//! then built with the [`crate::sdk`] functions as usual, passing the governance authority as the
+//! admin, and turned into the payload of a proposal transaction with
+//! [`proposal_transaction_instructions`]. Once the proposal passes, governance signs for its
+//! authority when it executes the transaction.
+
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+
+use self::state::InstructionData;
+
+solana_program::declare_id!("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw");
+
+/// PDA creation helpers
+pub mod pda {
+    use solana_program::pubkey::Pubkey;
+
+    const GOVERNANCE_PREFIX: &str = "account-governance";
+    const NATIVE_TREASURY_PREFIX: &str = "native-treasury";
+    const PROGRAM_AUTHORITY_PREFIX: &str = "governance";
+
+    /// Helper to find the address of a realm's governance account
+    pub fn find_governance_address(
+        program_id: &Pubkey,
+        realm: &Pubkey,
+        governance_seed: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                GOVERNANCE_PREFIX.as_bytes(),
+                realm.as_ref(),
+                governance_seed.as_ref(),
+            ],
+            program_id,
+        )
+    }
+
+    /// Helper to find the address of a governance's native treasury, a system account that
+    /// governance signs for when executing proposals
+    pub fn find_native_treasury_address(program_id: &Pubkey, governance: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[NATIVE_TREASURY_PREFIX.as_bytes(), governance.as_ref()],
+            program_id,
+        )
+    }
+
+    /// Helper to find the address of a proposal transaction
+    pub fn find_proposal_transaction_address(
+        program_id: &Pubkey,
+        proposal: &Pubkey,
+        option_index: u8,
+        transaction_index: u16,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                PROGRAM_AUTHORITY_PREFIX.as_bytes(),
+                proposal.as_ref(),
+                &option_index.to_le_bytes(),
+                &transaction_index.to_le_bytes(),
+            ],
+            program_id,
+        )
+    }
+}
+
+pub mod state {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+
+    /// An account of an instruction stored in a proposal transaction
+    #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+    pub struct AccountMetaData {
+        /// The account's pubkey
+        pub pubkey: Pubkey,
+        /// Whether the account signs the instruction
+        pub is_signer: bool,
+        /// Whether the account is writable
+        pub is_writable: bool,
+    }
+
+    /// An instruction stored in a proposal transaction
+    #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+    pub struct InstructionData {
+        /// The program the instruction is executed by
+        pub program_id: Pubkey,
+        /// The accounts of the instruction
+        pub accounts: Vec<AccountMetaData>,
+        /// The instruction data
+        pub data: Vec<u8>,
+    }
+
+    impl From<Instruction> for InstructionData {
+        fn from(instruction: Instruction) -> Self {
+            Self {
+                program_id: instruction.program_id,
+                accounts: instruction
+                    .accounts
+                    .into_iter()
+                    .map(|account| AccountMetaData {
+                        pubkey: account.pubkey,
+                        is_signer: account.is_signer,
+                        is_writable: account.is_writable,
+                    })
+                    .collect(),
+                data: instruction.data,
+            }
+        }
+    }
+
+    impl From<InstructionData> for Instruction {
+        fn from(instruction: InstructionData) -> Self {
+            Self {
+                program_id: instruction.program_id,
+                accounts: instruction
+                    .accounts
+                    .into_iter()
+                    .map(|account| AccountMeta {
+                        pubkey: account.pubkey,
+                        is_signer: account.is_signer,
+                        is_writable: account.is_writable,
+                    })
+                    .collect(),
+                data: instruction.data,
+            }
+        }
+    }
+}
+
+/// Converts instructions into the payload of a governance proposal transaction.
+///
+/// # Arguments
+/// * `instructions` - The instructions to execute once the proposal passes
+/// * `governance_authority` - The governance account or native treasury governing the vault
+///
+/// # Returns
+/// * `Result<Vec<InstructionData>, ProgramError>` - The proposal transaction payload, or an error if
+///   any instruction needs a signature other than the governance authority's, since governance
+///   can't provide it when executing the transaction
+pub fn proposal_transaction_instructions(
+    instructions: &[Instruction],
+    governance_authority: &Pubkey,
+) -> Result<Vec<InstructionData>, ProgramError> {
+    let mut payload = Vec::with_capacity(instructions.len());
+    for instruction in instructions {
+        if instruction
+            .accounts
+            .iter()
+            .any(|account| account.is_signer && account.pubkey.ne(governance_authority))
+        {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        payload.push(InstructionData::from(instruction.clone()));
+    }
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+
+    use super::{pda::*, *};
+    use crate::sdk::{set_deposit_capacity, set_fees};
+
+    #[test]
+    fn test_proposal_transaction_instructions_ok() {
+        let realm = Pubkey::new_unique();
+        let (governance, _) = find_governance_address(&ID, &realm, &Pubkey::new_unique());
+        let (native_treasury, _) = find_native_treasury_address(&ID, &governance);
+        let vault_program_id = Pubkey::new_unique();
+        let config = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+
+        let instructions = vec![
+            set_fees(
+                &vault_program_id,
+                &config,
+                &vault,
+                &native_treasury,
+                Some(100),
+                None,
+                None,
+            ),
+            set_deposit_capacity(&vault_program_id, &config, &vault, &native_treasury, 1_000),
+        ];
+        let payload = proposal_transaction_instructions(&instructions, &native_treasury).unwrap();
+        assert_eq!(payload.len(), 2);
+        for (instruction_data, instruction) in payload.into_iter().zip(instructions) {
+            assert_eq!(Instruction::from(instruction_data), instruction);
+        }
+    }
+
+    #[test]
+    fn test_proposal_transaction_instructions_other_signer_fails() {
+        let governance = Pubkey::new_unique();
+        let other_admin = Pubkey::new_unique();
+
+        let instruction = set_fees(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &other_admin,
+            Some(100),
+            None,
+            None,
+        );
+        assert_eq!(
+            proposal_transaction_instructions(&[instruction], &governance),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+
+    #[test]
+    fn test_instruction_data_layout() {
+        let program_id = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let instruction_data = InstructionData {
+            program_id,
+            accounts: vec![state::AccountMetaData {
+                pubkey: account,
+                is_signer: true,
+                is_writable: false,
+            }],
+            data: vec![7, 8],
+        };
+
+        let mut expected = program_id.to_bytes().to_vec();
+        expected.extend_from_slice(&1u32.to_le_bytes());
+        expected.extend_from_slice(account.as_ref());
+        expected.extend_from_slice(&[1, 0]);
+        expected.extend_from_slice(&2u32.to_le_bytes());
+        expected.extend_from_slice(&[7, 8]);
+        assert_eq!(instruction_data.try_to_vec().unwrap(), expected);
+    }
+}
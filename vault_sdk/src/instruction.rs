@@ -117,9 +117,13 @@ pub enum VaultInstruction {
     #[account(4, writable, name = "depositor_token_account")]
     #[account(5, writable, name = "vault_token_account")]
     #[account(6, writable, name = "depositor_vrt_token_account")]
-    #[account(7, writable, name = "vault_fee_token_account")]
-    #[account(8, name = "token_program")]
-    #[account(9, signer, optional, name = "mint_signer", description = "Signer for minting")]
+    #[account(7, name = "token_program")]
+    #[account(8, signer, optional, name = "mint_signer", description = "Signer for minting")]
+    #[account(9, optional, name = "vault_deposit_allowlist_entry", description = "Required in the allowlist deposit mode")]
+    #[account(10, writable, optional, name = "vault_depositor", description = "Required when the vault has a max deposit per wallet or flash deposit protection")]
+    #[account(11, optional, name = "system_program", description = "Required to create the vault depositor or deposit receipt")]
+    #[account(12, writable, optional, name = "deposit_receipt", description = "Created to record the deposit when passed in with its base")]
+    #[account(13, signer, optional, name = "deposit_receipt_base", description = "The base of the deposit receipt")]
     MintTo {
         amount_in: u64,
         min_amount_out: u64,
@@ -137,6 +141,7 @@ pub enum VaultInstruction {
     #[account(8, name = "token_program")]
     #[account(9, name = "system_program")]
     #[account(10, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    #[account(11, optional, name = "vault_depositor", description = "The staker's VaultDepositor, required when the vault has flash deposit protection")]
     Burn {
         amount_in: u64,
         min_amount_out: u64
@@ -167,6 +172,7 @@ pub enum VaultInstruction {
 
     /// Burns the withdraw ticket, returning funds to the staker. Withdraw tickets can be burned
     /// after one full epoch of being enqueued.
+    /// While a slash dispute haircut applies, the staker must sign.
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault")]
     #[account(2, writable, name = "vault_token_account")]
@@ -179,6 +185,7 @@ pub enum VaultInstruction {
     #[account(9, name = "token_program")]
     #[account(10, name = "system_program")]
     #[account(11, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    #[account(12, writable, optional, name = "claimer_token_account", description = "Receives the auto-claim fee when the ticket opted into auto-claiming")]
     BurnWithdrawTicket {
         min_amount_out: u64
     },
@@ -186,7 +193,7 @@ pub enum VaultInstruction {
     /// Sets the max tokens that can be deposited into the VRT
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault")]
-    #[account(2, signer, name = "admin")]
+    #[account(2, signer, name = "capacity_admin")]
     SetDepositCapacity {
         amount: u64
     },
@@ -226,6 +233,7 @@ pub enum VaultInstruction {
     #[account(2, name = "operator")]
     #[account(3, writable, name = "vault_operator_delegation")]
     #[account(4, signer, name = "admin")]
+    #[account(5, optional, name = "operator_vault_ticket", description = "Required when the operator requires approval of delegations")]
     AddDelegation {
         amount: u64,
     },
@@ -239,12 +247,17 @@ pub enum VaultInstruction {
         amount: u64,
     },
 
+    /// Updates the vault's tokens deposited and mints the fees. The first signer among the optional
+    /// accounts is paid the config's crank reward unless it was already paid this epoch
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault")]
-    #[account(2, name = "vault_token_account")]
+    #[account(2, writable, name = "vault_token_account")]
     #[account(3, writable, name = "vrt_mint")]
     #[account(4, writable, name = "vault_fee_token_account")]
     #[account(5, name = "token_program")]
+    #[account(6, writable, name = "protocol_fee_token_account")]
+    #[account(7, writable, signer, optional, name = "cranker", description = "Receives the crank reward")]
+    #[account(8, writable, optional, name = "cranker_token_account", description = "The cranker's associated token account for the supported mint, required when the config has a crank reward in basis points")]
     UpdateVaultBalance,
 
     /// Starts updating the vault
@@ -263,10 +276,15 @@ pub enum VaultInstruction {
     #[account(4, writable, name = "vault_update_state_tracker")]
     CrankVaultUpdateStateTracker,
 
+    /// Finishes updating the vault, paying the config's crank reward to the payer when it completes
+    /// the current epoch and the reward wasn't already paid this epoch
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault")]
     #[account(2, writable, name = "vault_update_state_tracker")]
     #[account(3, writable, signer, name = "payer")]
+    #[account(4, writable, optional, name = "vault_token_account", description = "Required when the config has a crank reward in basis points")]
+    #[account(5, writable, optional, name = "payer_token_account", description = "The payer's associated token account for the supported mint, required when the config has a crank reward in basis points")]
+    #[account(6, optional, name = "token_program", description = "Required when the config has a crank reward in basis points")]
     CloseVaultUpdateStateTracker {
         ncn_epoch: u64
     },
@@ -312,11 +330,721 @@ pub enum VaultInstruction {
     #[account(11, name = "vault_ncn_slasher_ticket")]
     #[account(12, writable, name = "vault_ncn_slasher_operator_ticket")]
     #[account(13, writable, name = "vault_token_account")]
-    #[account(14, name = "slasher_token_account")]
+    #[account(14, writable, name = "slash_destination")]
     #[account(15, name = "token_program")]
     Slash {
         amount: u64
     },
+
+    /// Pauses or unpauses the vault, halting minting, burning, withdrawals and delegation changes
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetVaultPaused {
+        is_paused: bool
+    },
+
+    /// Sets the wallet that receives the vault deposit, withdrawal and reward fees
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    #[account(3, name = "new_fee_wallet")]
+    #[account(4, name = "new_fee_wallet_vrt_token_account")]
+    SetFeeWallet,
+
+    /// Sets the portion of the vault fees minted to the protocol fee wallet
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "new_protocol_fee_wallet")]
+    AdminSetProtocolFee {
+        protocol_fee_bps: u16
+    },
+
+    /// Sets the minimum amount of stake an operator delegation may hold
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetMinDelegationAmount {
+        amount: u64
+    },
+
+    /// Sets who is allowed to deposit into the vault
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetDepositMode {
+        deposit_mode: DepositMode
+    },
+
+    /// Allows a depositor to mint VRT when the vault is in the allowlist deposit mode
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "depositor")]
+    #[account(3, writable, name = "vault_deposit_allowlist_entry")]
+    #[account(4, signer, name = "admin")]
+    #[account(5, writable, signer, name = "payer")]
+    #[account(6, name = "system_program")]
+    AddToDepositAllowlist,
+
+    /// Removes a depositor from the vault deposit allowlist, returning the rent to the admin
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "depositor")]
+    #[account(3, writable, name = "vault_deposit_allowlist_entry")]
+    #[account(4, writable, signer, name = "admin")]
+    RemoveFromDepositAllowlist,
+
+    /// Sets the max deposit per transaction and the max cumulative deposit per wallet, 0 means no limit
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetDepositLimits {
+        max_deposit_per_transaction: Option<u64>,
+        max_deposit_per_wallet: Option<u64>,
+    },
+
+    /// Sets the fee the staker pays to whoever burns their withdrawal ticket once it's claimable.
+    /// A non-zero fee opts the ticket into auto-claiming by keepers.
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "vault_staker_withdrawal_ticket")]
+    #[account(3, signer, name = "staker")]
+    SetWithdrawalTicketAutoClaimFee {
+        fee_bps: u16,
+    },
+
+    /// Proposes handing the VRT mint authority off to a successor program's PDA, executable after
+    /// a challenge window
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "config_admin")]
+    #[account(3, name = "successor_program")]
+    ProposeMintAuthorityHandoff,
+
+    /// Cancels a pending VRT mint authority handoff
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin", description = "The vault admin or the config admin")]
+    CancelMintAuthorityHandoff,
+
+    /// Transfers the VRT mint authority to the successor once the challenge window has passed
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vrt_mint")]
+    #[account(3, name = "token_program")]
+    ExecuteMintAuthorityHandoff,
+
+    /// Sets the number of epochs undelegated stake spends cooling down before it returns to the vault
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    AdminSetDelegationCooldown {
+        delegation_cooldown_epochs: u64
+    },
+
+    /// Sets the slash veto admin and the number of slots proposed slashes can be vetoed for
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "new_slash_veto_admin")]
+    AdminSetSlashVeto {
+        slash_veto_window_slots: u64
+    },
+
+    /// Proposes a slash, executable once the config's slash veto window has passed
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, writable, signer, name = "slasher")]
+    #[account(5, name = "ncn_operator_state")]
+    #[account(6, name = "ncn_vault_ticket")]
+    #[account(7, name = "operator_vault_ticket")]
+    #[account(8, name = "vault_ncn_ticket")]
+    #[account(9, name = "vault_operator_delegation")]
+    #[account(10, name = "ncn_vault_slasher_ticket")]
+    #[account(11, name = "vault_ncn_slasher_ticket")]
+    #[account(12, writable, name = "vault_ncn_slasher_operator_ticket")]
+    #[account(13, writable, name = "slash_proposal")]
+    #[account(14, signer, name = "base")]
+    #[account(15, name = "system_program")]
+    ProposeSlash {
+        amount: u64,
+        evidence_hash: [u8; 32]
+    },
+
+    /// Vetoes a proposed slash, closing the proposal
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, writable, name = "slasher")]
+    #[account(5, writable, name = "vault_ncn_slasher_operator_ticket")]
+    #[account(6, writable, name = "slash_proposal")]
+    #[account(7, signer, name = "slash_veto_admin")]
+    VetoSlash,
+
+    /// Executes a proposed slash once its veto window has passed
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, writable, signer, name = "slasher")]
+    #[account(5, writable, name = "vault_operator_delegation")]
+    #[account(6, writable, name = "slash_proposal")]
+    #[account(7, name = "ncn_vault_slasher_ticket")]
+    #[account(8, writable, name = "vault_token_account")]
+    #[account(9, writable, name = "slash_destination")]
+    #[account(10, name = "token_program")]
+    ExecuteSlash,
+
+    /// Mints VRT by depositing SOL into a vault whose supported mint is the native mint, wrapping
+    /// it into the vault's wSOL token account
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vrt_mint")]
+    #[account(3, writable, signer, name = "depositor")]
+    #[account(4, writable, name = "vault_token_account")]
+    #[account(5, writable, name = "depositor_vrt_token_account")]
+    #[account(6, name = "token_program")]
+    #[account(7, name = "system_program")]
+    #[account(8, signer, optional, name = "mint_signer", description = "Signer for minting")]
+    #[account(9, optional, name = "vault_deposit_allowlist_entry", description = "Required in the allowlist deposit mode")]
+    #[account(10, writable, optional, name = "vault_depositor", description = "Required when the vault has a max deposit per wallet or flash deposit protection")]
+    #[account(11, writable, optional, name = "deposit_receipt", description = "Created to record the deposit when passed in with its base")]
+    #[account(12, signer, optional, name = "deposit_receipt_base", description = "The base of the deposit receipt")]
+    MintToWithSol {
+        amount_in: u64,
+        min_amount_out: u64,
+    },
+
+    /// Closes an empty vault, returning its rent to the admin. The vault's tickets, operator
+    /// delegations and other accounts can be passed in after the admin to be closed with it.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, signer, name = "admin")]
+    CloseVault,
+
+    /// Sets the maximum amount of stake the vault can have on an operator
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "vault_operator_delegation")]
+    #[account(4, signer, name = "admin")]
+    SetOperatorMaxDelegation {
+        max_delegation_amount: u64,
+    },
+
+    /// Pauses or resumes delegating to an operator, cooling down its stake while paused
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "vault_operator_delegation")]
+    #[account(4, signer, name = "admin")]
+    SetOperatorDelegationPaused {
+        is_paused: bool,
+    },
+
+    /// Initializes the vault's delegation strategy, letting anyone rebalance its delegations
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "delegation_strategy")]
+    #[account(3, signer, name = "admin")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
+    InitializeDelegationStrategy {
+        max_movement_per_epoch: u64,
+    },
+
+    /// Sets an operator's target weight in the vault's delegation strategy
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "vault_operator_delegation")]
+    #[account(4, writable, name = "delegation_strategy")]
+    #[account(5, signer, name = "admin")]
+    SetDelegationStrategyWeight {
+        weight: u64,
+    },
+
+    /// Moves an operator's stake towards its target in the vault's delegation strategy
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "vault_operator_delegation")]
+    #[account(4, writable, name = "delegation_strategy")]
+    #[account(5, optional, name = "operator_vault_ticket", description = "Required when the operator requires approval of delegations")]
+    RebalanceDelegations,
+
+    /// Burns VRT if the vault's idle assets cover the withdrawal, otherwise enqueues a withdrawal
+    /// of the full amount into a new VaultStakerWithdrawalTicket
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_token_account")]
+    #[account(3, writable, name = "vrt_mint")]
+    #[account(4, writable, signer, name = "staker")]
+    #[account(5, writable, name = "staker_token_account")]
+    #[account(6, writable, name = "staker_vrt_token_account")]
+    #[account(7, writable, name = "vault_fee_token_account")]
+    #[account(8, writable, name = "vault_staker_withdrawal_ticket")]
+    #[account(9, writable, name = "vault_staker_withdrawal_ticket_token_account")]
+    #[account(10, signer, name = "base")]
+    #[account(11, name = "token_program")]
+    #[account(12, name = "system_program")]
+    #[account(13, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    #[account(14, optional, name = "vault_depositor", description = "The staker's VaultDepositor, required when the vault has flash deposit protection")]
+    BurnOrEnqueueWithdrawal {
+        amount_in: u64,
+        min_amount_out: u64,
+    },
+
+    /// Initializes the vault's rolling per-epoch deposit, withdrawal, fee and slash stats, which
+    /// deposits, withdrawals and slashes must update from then on
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_epoch_stats")]
+    #[account(3, signer, name = "admin")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
+    InitializeVaultEpochStats,
+
+    /// Enables or disables rejecting burns in the same slot as the staker's last deposit
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetFlashDepositProtection {
+        enabled: bool,
+    },
+
+    /// Irreversibly enables admin burns of withdrawal tickets, only allowed before the first deposit
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    #[account(3, name = "burn_admin")]
+    EnableAdminBurn,
+
+    /// Proposes burning a withdrawal ticket's VRT and sending the assets to a recovery address,
+    /// freezing the ticket until the admin burn is executed or cancelled
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "vault_staker_withdrawal_ticket")]
+    #[account(3, signer, name = "burn_admin")]
+    #[account(4, name = "recovery")]
+    ProposeAdminBurn,
+
+    /// Cancels a pending admin burn of a withdrawal ticket
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "vault_staker_withdrawal_ticket")]
+    #[account(3, signer, name = "admin", description = "The vault admin or the burn admin")]
+    CancelAdminBurn,
+
+    /// Burns a withdrawal ticket's VRT and sends the assets to the recovery address once the
+    /// admin burn's timelock has passed
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_token_account")]
+    #[account(3, writable, name = "vrt_mint")]
+    #[account(4, writable, name = "staker")]
+    #[account(5, writable, name = "vault_staker_withdrawal_ticket")]
+    #[account(6, writable, name = "vault_staker_withdrawal_ticket_token_account")]
+    #[account(7, writable, name = "vault_fee_token_account")]
+    #[account(8, writable, name = "recovery_token_account")]
+    #[account(9, signer, name = "burn_admin")]
+    #[account(10, name = "token_program")]
+    #[account(11, name = "system_program")]
+    ExecuteAdminBurn,
+
+    /// Pauses or unpauses every vault at once, halting the same instructions as pausing a vault
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    AdminSetConfigPaused {
+        is_paused: bool
+    },
+
+    /// Writes the vault's tokens deposited down to the vault token account balance after the
+    /// balance diverged from the internal accounting
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, name = "vault_token_account")]
+    #[account(3, signer, name = "admin")]
+    ReconcileVaultBalance {
+        max_adjustment: u64
+    },
+
+    /// Freezes an operator delegation at the start of the epoch, after it was cranked
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "vault_operator_delegation")]
+    #[account(4, name = "vault_update_state_tracker")]
+    #[account(5, writable, name = "vault_operator_delegation_snapshot")]
+    #[account(6, writable, signer, name = "payer")]
+    #[account(7, name = "system_program")]
+    SnapshotVaultOperatorDelegation,
+
+    /// Posts the Merkle root of the rewards owed to each staker for an epoch and funds them
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "reward_distribution")]
+    #[account(3, name = "mint")]
+    #[account(4, writable, name = "vault_rewards_token_account")]
+    #[account(5, writable, name = "admin_token_account")]
+    #[account(6, writable, signer, name = "admin")]
+    #[account(7, name = "token_program")]
+    #[account(8, name = "system_program")]
+    #[account(9, optional, name = "operator", description = "The operator the rewards were earned through, paid its commission")]
+    #[account(10, writable, optional, name = "operator_fee_token_account", description = "The operator withdrawal fee wallet's token account for the reward mint")]
+    InitializeRewardDistribution {
+        epoch: u64,
+        merkle_root: [u8; 32],
+        total_rewards: u64,
+    },
+
+    /// Claims a staker's rewards from a reward distribution with a Merkle proof
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "reward_distribution")]
+    #[account(3, writable, name = "reward_claim")]
+    #[account(4, writable, name = "vault_rewards_token_account")]
+    #[account(5, writable, name = "staker_token_account")]
+    #[account(6, writable, signer, name = "staker")]
+    #[account(7, name = "token_program")]
+    #[account(8, name = "system_program")]
+    ClaimRewards {
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    },
+
+    /// Shuts the vault down, blocking deposits and delegation while its delegations are cooled down
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    InitiateVaultShutdown,
+
+    /// Creates the account an NCN's rewards for the vault in an epoch are routed through
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "ncn")]
+    #[account(3, writable, name = "ncn_reward_router")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
+    InitializeNcnRewardRouter { epoch: u64 },
+
+    /// Splits the rewards in an NCN reward router between the protocol, the operators and the vault.
+    /// Each operator, its operator delegation and its fee token account follow the token program.
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "ncn")]
+    #[account(3, writable, name = "ncn_reward_router")]
+    #[account(4, writable, name = "ncn_reward_router_token_account")]
+    #[account(5, writable, name = "vault_token_account")]
+    #[account(6, writable, name = "protocol_fee_token_account")]
+    #[account(7, name = "token_program")]
+    DistributeNcnRewards,
+
+    /// Sets the share of the vault's fees minted to its insurance fund, initializing the fund on
+    /// first use. From then on, UpdateVaultBalance, Slash and ExecuteSlash require the vault
+    /// insurance and its VRT token account after their other accounts, and slashes also the VRT mint.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_insurance")]
+    #[account(3, signer, name = "admin")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
+    SetInsuranceParams { fee_bps: u16 },
+
+    /// Transfers VRT from the admin to the vault's insurance fund
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "vault_insurance")]
+    #[account(3, name = "vrt_mint")]
+    #[account(4, writable, name = "admin_vrt_token_account")]
+    #[account(5, writable, name = "insurance_vrt_token_account")]
+    #[account(6, signer, name = "admin")]
+    #[account(7, name = "token_program")]
+    FundInsurance { amount: u64 },
+
+    /// Sets the program invoked after every MintTo, MintToWithSol and Burn, see
+    /// [`crate::hook`]. Passing the system program removes the hook. From then on, the hook
+    /// program must be passed in after the other accounts of those instructions.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    #[account(3, name = "hook_program")]
+    SetHookProgram,
+
+    /// Burns VRT the owner approved the burner to spend as delegate of their VRT token account,
+    /// without the owner's signature. The tokens withdrawn are sent to the owner.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_token_account")]
+    #[account(3, writable, name = "vrt_mint")]
+    #[account(4, name = "owner", description = "Owner of the VRT token account to burn from")]
+    #[account(5, signer, name = "burner", description = "Delegate the owner approved on the VRT token account")]
+    #[account(6, writable, name = "owner_token_account")]
+    #[account(7, writable, name = "owner_vrt_token_account")]
+    #[account(8, writable, name = "vault_fee_token_account")]
+    #[account(9, name = "token_program")]
+    #[account(10, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    #[account(11, optional, name = "vault_depositor", description = "The owner's VaultDepositor, required when the vault has flash deposit protection")]
+    BurnDelegated {
+        amount_in: u64,
+        min_amount_out: u64,
+    },
+
+    /// Sets the reward paid to crankers for advancing a vault's epoch state
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    AdminSetCrankReward {
+        crank_reward_lamports: u64,
+        crank_reward_bps: u16,
+    },
+
+    /// Creates the vault's associated token account for the supported mint, which holds the
+    /// deposited assets
+    #[account(0, name = "vault")]
+    #[account(1, writable, name = "vault_token_account")]
+    #[account(2, name = "supported_mint")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    #[account(5, name = "token_program")]
+    #[account(6, name = "associated_token_program")]
+    InitializeVaultTokenAccount,
+
+    /// Closes a deposit receipt, returning its rent to the depositor
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "deposit_receipt")]
+    #[account(2, writable, signer, name = "depositor")]
+    CloseDepositReceipt,
+
+    /// Sets the max share of the vault's tokens all slashers together can slash in one epoch
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetMaxTotalSlashablePerEpoch {
+        max_total_slashable_bps_per_epoch: u16,
+    },
+
+    /// Sets the vault VRT can be migrated to and from without the withdrawal fee when that vault
+    /// names this one too. Pass the system program to remove the partner.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    #[account(3, name = "migration_partner")]
+    SetMigrationPartner,
+
+    /// Burns VRT of the source vault and deposits the redeemed assets into the destination vault,
+    /// minting its VRT
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "source_vault")]
+    #[account(2, writable, name = "source_vault_token_account")]
+    #[account(3, writable, name = "source_vrt_mint")]
+    #[account(4, writable, name = "source_vault_fee_token_account")]
+    #[account(5, writable, signer, name = "staker")]
+    #[account(6, writable, name = "staker_token_account")]
+    #[account(7, writable, name = "staker_source_vrt_token_account")]
+    #[account(8, writable, name = "destination_vault")]
+    #[account(9, writable, name = "destination_vault_token_account")]
+    #[account(10, writable, name = "destination_vrt_mint")]
+    #[account(11, writable, name = "staker_destination_vrt_token_account")]
+    #[account(12, name = "token_program")]
+    #[account(13, signer, optional, name = "mint_burn_signer", description = "Signer for burning and minting")]
+    MigrateToVault {
+        amount_in: u64,
+        min_amount_out: u64,
+    },
+
+    /// Removes a slasher from the vault by closing its vault NCN slasher ticket, which must have
+    /// been cooled down and be inactive
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "slasher")]
+    #[account(4, writable, name = "vault_ncn_slasher_ticket")]
+    #[account(5, writable, signer, name = "admin")]
+    RemoveSlasher,
+
+    /// Sets the minimum number of epochs withdrawals must be enqueued for, 0 means no delay beyond
+    /// the one epoch cooldown
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetWithdrawalDelay {
+        withdrawal_delay_epochs: u64,
+    },
+
+    /// Upgrades a Config or Vault account to the current layout in place
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
+
+    /// Initializes the sum of an operator's stake across vaults for an NCN and the current epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "supported_mint")]
+    #[account(4, writable, name = "operator_stake_weight")]
+    #[account(5, writable, signer, name = "payer")]
+    #[account(6, name = "system_program")]
+    InitializeOperatorStakeWeight,
+
+    /// Adds a vault's snapshotted stake with the operator to the operator's stake weight
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "vault")]
+    #[account(4, name = "ncn_vault_ticket")]
+    #[account(5, name = "vault_ncn_ticket")]
+    #[account(6, name = "vault_operator_delegation_snapshot")]
+    #[account(7, writable, name = "operator_stake_weight")]
+    CrankOperatorStakeWeight,
+
+    /// Sets the bounds of the epoch length vaults can override the epoch length with, 0 and 0
+    /// stops vaults from setting an override
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    AdminSetVaultEpochLengthBounds {
+        min_vault_epoch_length: u64,
+        max_vault_epoch_length: u64,
+    },
+
+    /// Overrides the length of the vault's epochs for its updates and withdrawals, 0 falls back
+    /// to the config's epoch length
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetVaultEpochLength {
+        epoch_length_slots: u64,
+    },
+
+    /// Bans an operator from receiving new delegations from the vault, recording the vault's
+    /// reason code
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "vault_operator_delegation")]
+    #[account(4, signer, name = "admin")]
+    BanOperator {
+        reason: u8,
+    },
+
+    /// Lifts the ban on an operator
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "vault_operator_delegation")]
+    #[account(4, signer, name = "admin")]
+    UnbanOperator,
+
+    /// Enables or disables paying exits out net of the pending slashes while a slash proposal
+    /// against the vault is outstanding
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetSlashDisputeHaircut {
+        enabled: bool,
+    },
+}
+
+impl VaultInstruction {
+    /// The instruction's wire discriminator, the first byte of its serialized data.
+    ///
+    /// Borsh encodes the variant index, so instructions must only ever be appended to the enum.
+    /// The values are spelled out here and locked by tests so reordering or inserting a variant
+    /// can't silently change the encoding of an existing instruction.
+    pub const fn discriminator(&self) -> u8 {
+        match self {
+            Self::InitializeConfig => 0,
+            Self::InitializeVault { .. } => 1,
+            Self::InitializeVaultWithMint => 2,
+            Self::InitializeVaultOperatorDelegation => 3,
+            Self::InitializeVaultNcnTicket => 4,
+            Self::InitializeVaultNcnSlasherOperatorTicket => 5,
+            Self::InitializeVaultNcnSlasherTicket => 6,
+            Self::WarmupVaultNcnTicket => 7,
+            Self::CooldownVaultNcnTicket => 8,
+            Self::WarmupVaultNcnSlasherTicket => 9,
+            Self::CooldownVaultNcnSlasherTicket => 10,
+            Self::MintTo { .. } => 11,
+            Self::Burn { .. } => 12,
+            Self::EnqueueWithdrawal { .. } => 13,
+            Self::ChangeWithdrawalTicketOwner => 14,
+            Self::BurnWithdrawTicket { .. } => 15,
+            Self::SetDepositCapacity { .. } => 16,
+            Self::SetFees { .. } => 17,
+            Self::AdminWithdraw { .. } => 18,
+            Self::SetAdmin => 19,
+            Self::SetSecondaryAdmin(..) => 20,
+            Self::AddDelegation { .. } => 21,
+            Self::CooldownDelegation { .. } => 22,
+            Self::UpdateVaultBalance => 23,
+            Self::InitializeVaultUpdateStateTracker { .. } => 24,
+            Self::CrankVaultUpdateStateTracker => 25,
+            Self::CloseVaultUpdateStateTracker { .. } => 26,
+            Self::CreateTokenMetadata { .. } => 27,
+            Self::UpdateTokenMetadata { .. } => 28,
+            Self::Slash { .. } => 29,
+            Self::SetVaultPaused { .. } => 30,
+            Self::SetFeeWallet => 31,
+            Self::AdminSetProtocolFee { .. } => 32,
+            Self::SetMinDelegationAmount { .. } => 33,
+            Self::SetDepositMode { .. } => 34,
+            Self::AddToDepositAllowlist => 35,
+            Self::RemoveFromDepositAllowlist => 36,
+            Self::SetDepositLimits { .. } => 37,
+            Self::SetWithdrawalTicketAutoClaimFee { .. } => 38,
+            Self::ProposeMintAuthorityHandoff => 39,
+            Self::CancelMintAuthorityHandoff => 40,
+            Self::ExecuteMintAuthorityHandoff => 41,
+            Self::AdminSetDelegationCooldown { .. } => 42,
+            Self::AdminSetSlashVeto { .. } => 43,
+            Self::ProposeSlash { .. } => 44,
+            Self::VetoSlash => 45,
+            Self::ExecuteSlash => 46,
+            Self::MintToWithSol { .. } => 47,
+            Self::CloseVault => 48,
+            Self::SetOperatorMaxDelegation { .. } => 49,
+            Self::SetOperatorDelegationPaused { .. } => 50,
+            Self::InitializeDelegationStrategy { .. } => 51,
+            Self::SetDelegationStrategyWeight { .. } => 52,
+            Self::RebalanceDelegations => 53,
+            Self::BurnOrEnqueueWithdrawal { .. } => 54,
+            Self::InitializeVaultEpochStats => 55,
+            Self::SetFlashDepositProtection { .. } => 56,
+            Self::EnableAdminBurn => 57,
+            Self::ProposeAdminBurn => 58,
+            Self::CancelAdminBurn => 59,
+            Self::ExecuteAdminBurn => 60,
+            Self::AdminSetConfigPaused { .. } => 61,
+            Self::ReconcileVaultBalance { .. } => 62,
+            Self::SnapshotVaultOperatorDelegation => 63,
+            Self::InitializeRewardDistribution { .. } => 64,
+            Self::ClaimRewards { .. } => 65,
+            Self::InitiateVaultShutdown => 66,
+            Self::InitializeNcnRewardRouter { .. } => 67,
+            Self::DistributeNcnRewards => 68,
+            Self::SetInsuranceParams { .. } => 69,
+            Self::FundInsurance { .. } => 70,
+            Self::SetHookProgram => 71,
+            Self::BurnDelegated { .. } => 72,
+            Self::AdminSetCrankReward { .. } => 73,
+            Self::InitializeVaultTokenAccount => 74,
+            Self::CloseDepositReceipt => 75,
+            Self::SetMaxTotalSlashablePerEpoch { .. } => 76,
+            Self::SetMigrationPartner => 77,
+            Self::MigrateToVault { .. } => 78,
+            Self::RemoveSlasher => 79,
+            Self::SetWithdrawalDelay { .. } => 80,
+            Self::MigrateAccount => 81,
+            Self::InitializeOperatorStakeWeight => 82,
+            Self::CrankOperatorStakeWeight => 83,
+            Self::AdminSetVaultEpochLengthBounds { .. } => 84,
+            Self::SetVaultEpochLength { .. } => 85,
+            Self::BanOperator { .. } => 86,
+            Self::UnbanOperator => 87,
+            Self::SetSlashDisputeHaircut { .. } => 88,
+        }
+    }
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
@@ -326,10 +1054,38 @@ pub enum VaultAdminRole {
     NcnAdmin,
     SlasherAdmin,
     CapacityAdmin,
+    /// Rejected, the fee wallet is set by the fee admin with [`VaultInstruction::SetFeeWallet`].
+    /// Kept so the roles after it keep their encoding.
     FeeWallet,
     MintBurnAdmin,
     WithdrawAdmin,
     FeeAdmin,
+    PauseAdmin,
+    MetadataAdmin,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[repr(u8)]
+pub enum DepositMode {
+    /// Anyone can deposit into the vault
+    Open,
+    /// Deposits must be signed by the vault mint burn admin
+    SignerGated,
+    /// Only depositors with a VaultDepositAllowlistEntry can deposit into the vault
+    Allowlist,
+}
+
+impl TryFrom<u8> for DepositMode {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Open),
+            1 => Ok(Self::SignerGated),
+            2 => Ok(Self::Allowlist),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
@@ -350,3 +1106,319 @@ impl TryFrom<u8> for WithdrawalAllocationMethod {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every instruction with its wire discriminator. Existing values must never change.
+    fn instructions() -> Vec<(VaultInstruction, u8)> {
+        vec![
+            (VaultInstruction::InitializeConfig, 0),
+            (
+                VaultInstruction::InitializeVault {
+                    deposit_fee_bps: 1,
+                    withdrawal_fee_bps: 1,
+                    reward_fee_bps: 1,
+                    decimals: 1,
+                },
+                1,
+            ),
+            (VaultInstruction::InitializeVaultWithMint, 2),
+            (VaultInstruction::InitializeVaultOperatorDelegation, 3),
+            (VaultInstruction::InitializeVaultNcnTicket, 4),
+            (VaultInstruction::InitializeVaultNcnSlasherOperatorTicket, 5),
+            (VaultInstruction::InitializeVaultNcnSlasherTicket, 6),
+            (VaultInstruction::WarmupVaultNcnTicket, 7),
+            (VaultInstruction::CooldownVaultNcnTicket, 8),
+            (VaultInstruction::WarmupVaultNcnSlasherTicket, 9),
+            (VaultInstruction::CooldownVaultNcnSlasherTicket, 10),
+            (
+                VaultInstruction::MintTo {
+                    amount_in: 1,
+                    min_amount_out: 1,
+                },
+                11,
+            ),
+            (
+                VaultInstruction::Burn {
+                    amount_in: 1,
+                    min_amount_out: 1,
+                },
+                12,
+            ),
+            (VaultInstruction::EnqueueWithdrawal { amount: 1 }, 13),
+            (VaultInstruction::ChangeWithdrawalTicketOwner, 14),
+            (
+                VaultInstruction::BurnWithdrawTicket { min_amount_out: 1 },
+                15,
+            ),
+            (VaultInstruction::SetDepositCapacity { amount: 1 }, 16),
+            (
+                VaultInstruction::SetFees {
+                    deposit_fee_bps: Some(1),
+                    withdrawal_fee_bps: Some(1),
+                    reward_fee_bps: Some(1),
+                },
+                17,
+            ),
+            (VaultInstruction::AdminWithdraw { amount: 1 }, 18),
+            (VaultInstruction::SetAdmin, 19),
+            (
+                VaultInstruction::SetSecondaryAdmin(VaultAdminRole::DelegationAdmin),
+                20,
+            ),
+            (VaultInstruction::AddDelegation { amount: 1 }, 21),
+            (VaultInstruction::CooldownDelegation { amount: 1 }, 22),
+            (VaultInstruction::UpdateVaultBalance, 23),
+            (
+                VaultInstruction::InitializeVaultUpdateStateTracker {
+                    withdrawal_allocation_method: WithdrawalAllocationMethod::Greedy,
+                },
+                24,
+            ),
+            (VaultInstruction::CrankVaultUpdateStateTracker, 25),
+            (
+                VaultInstruction::CloseVaultUpdateStateTracker { ncn_epoch: 1 },
+                26,
+            ),
+            (
+                VaultInstruction::CreateTokenMetadata {
+                    name: String::from("a"),
+                    symbol: String::from("a"),
+                    uri: String::from("a"),
+                },
+                27,
+            ),
+            (
+                VaultInstruction::UpdateTokenMetadata {
+                    name: String::from("a"),
+                    symbol: String::from("a"),
+                    uri: String::from("a"),
+                },
+                28,
+            ),
+            (VaultInstruction::Slash { amount: 1 }, 29),
+            (VaultInstruction::SetVaultPaused { is_paused: true }, 30),
+            (VaultInstruction::SetFeeWallet, 31),
+            (
+                VaultInstruction::AdminSetProtocolFee {
+                    protocol_fee_bps: 1,
+                },
+                32,
+            ),
+            (VaultInstruction::SetMinDelegationAmount { amount: 1 }, 33),
+            (
+                VaultInstruction::SetDepositMode {
+                    deposit_mode: DepositMode::Open,
+                },
+                34,
+            ),
+            (VaultInstruction::AddToDepositAllowlist, 35),
+            (VaultInstruction::RemoveFromDepositAllowlist, 36),
+            (
+                VaultInstruction::SetDepositLimits {
+                    max_deposit_per_transaction: Some(1),
+                    max_deposit_per_wallet: Some(1),
+                },
+                37,
+            ),
+            (
+                VaultInstruction::SetWithdrawalTicketAutoClaimFee { fee_bps: 1 },
+                38,
+            ),
+            (VaultInstruction::ProposeMintAuthorityHandoff, 39),
+            (VaultInstruction::CancelMintAuthorityHandoff, 40),
+            (VaultInstruction::ExecuteMintAuthorityHandoff, 41),
+            (
+                VaultInstruction::AdminSetDelegationCooldown {
+                    delegation_cooldown_epochs: 1,
+                },
+                42,
+            ),
+            (
+                VaultInstruction::AdminSetSlashVeto {
+                    slash_veto_window_slots: 1,
+                },
+                43,
+            ),
+            (
+                VaultInstruction::ProposeSlash {
+                    amount: 1,
+                    evidence_hash: [1; 32],
+                },
+                44,
+            ),
+            (VaultInstruction::VetoSlash, 45),
+            (VaultInstruction::ExecuteSlash, 46),
+            (
+                VaultInstruction::MintToWithSol {
+                    amount_in: 1,
+                    min_amount_out: 1,
+                },
+                47,
+            ),
+            (VaultInstruction::CloseVault, 48),
+            (
+                VaultInstruction::SetOperatorMaxDelegation {
+                    max_delegation_amount: 1,
+                },
+                49,
+            ),
+            (
+                VaultInstruction::SetOperatorDelegationPaused { is_paused: true },
+                50,
+            ),
+            (
+                VaultInstruction::InitializeDelegationStrategy {
+                    max_movement_per_epoch: 1,
+                },
+                51,
+            ),
+            (
+                VaultInstruction::SetDelegationStrategyWeight { weight: 1 },
+                52,
+            ),
+            (VaultInstruction::RebalanceDelegations, 53),
+            (
+                VaultInstruction::BurnOrEnqueueWithdrawal {
+                    amount_in: 1,
+                    min_amount_out: 1,
+                },
+                54,
+            ),
+            (VaultInstruction::InitializeVaultEpochStats, 55),
+            (
+                VaultInstruction::SetFlashDepositProtection { enabled: true },
+                56,
+            ),
+            (VaultInstruction::EnableAdminBurn, 57),
+            (VaultInstruction::ProposeAdminBurn, 58),
+            (VaultInstruction::CancelAdminBurn, 59),
+            (VaultInstruction::ExecuteAdminBurn, 60),
+            (
+                VaultInstruction::AdminSetConfigPaused { is_paused: true },
+                61,
+            ),
+            (
+                VaultInstruction::ReconcileVaultBalance { max_adjustment: 1 },
+                62,
+            ),
+            (VaultInstruction::SnapshotVaultOperatorDelegation, 63),
+            (
+                VaultInstruction::InitializeRewardDistribution {
+                    epoch: 1,
+                    merkle_root: [0; 32],
+                    total_rewards: 1,
+                },
+                64,
+            ),
+            (
+                VaultInstruction::ClaimRewards {
+                    amount: 1,
+                    proof: vec![],
+                },
+                65,
+            ),
+            (VaultInstruction::InitiateVaultShutdown, 66),
+            (VaultInstruction::InitializeNcnRewardRouter { epoch: 1 }, 67),
+            (VaultInstruction::DistributeNcnRewards, 68),
+            (VaultInstruction::SetInsuranceParams { fee_bps: 1 }, 69),
+            (VaultInstruction::FundInsurance { amount: 1 }, 70),
+            (VaultInstruction::SetHookProgram, 71),
+            (
+                VaultInstruction::BurnDelegated {
+                    amount_in: 1,
+                    min_amount_out: 1,
+                },
+                72,
+            ),
+            (
+                VaultInstruction::AdminSetCrankReward {
+                    crank_reward_lamports: 1,
+                    crank_reward_bps: 1,
+                },
+                73,
+            ),
+            (VaultInstruction::InitializeVaultTokenAccount, 74),
+            (VaultInstruction::CloseDepositReceipt, 75),
+            (
+                VaultInstruction::SetMaxTotalSlashablePerEpoch {
+                    max_total_slashable_bps_per_epoch: 1,
+                },
+                76,
+            ),
+            (VaultInstruction::SetMigrationPartner, 77),
+            (
+                VaultInstruction::MigrateToVault {
+                    amount_in: 1,
+                    min_amount_out: 1,
+                },
+                78,
+            ),
+            (VaultInstruction::RemoveSlasher, 79),
+            (
+                VaultInstruction::SetWithdrawalDelay {
+                    withdrawal_delay_epochs: 1,
+                },
+                80,
+            ),
+            (VaultInstruction::MigrateAccount, 81),
+            (VaultInstruction::InitializeOperatorStakeWeight, 82),
+            (VaultInstruction::CrankOperatorStakeWeight, 83),
+            (
+                VaultInstruction::AdminSetVaultEpochLengthBounds {
+                    min_vault_epoch_length: 1,
+                    max_vault_epoch_length: 1,
+                },
+                84,
+            ),
+            (
+                VaultInstruction::SetVaultEpochLength {
+                    epoch_length_slots: 1,
+                },
+                85,
+            ),
+            (VaultInstruction::BanOperator { reason: 1 }, 86),
+            (VaultInstruction::UnbanOperator, 87),
+            (
+                VaultInstruction::SetSlashDisputeHaircut { enabled: true },
+                88,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_instruction_discriminators_are_stable() {
+        for (instruction, discriminator) in instructions() {
+            assert_eq!(
+                instruction.discriminator(),
+                discriminator,
+                "{:?}",
+                instruction
+            );
+            assert_eq!(
+                instruction.try_to_vec().unwrap()[0],
+                discriminator,
+                "{:?}",
+                instruction
+            );
+        }
+    }
+
+    #[test]
+    fn test_instruction_discriminators_round_trip() {
+        for (instruction, discriminator) in instructions() {
+            let data = instruction.try_to_vec().unwrap();
+            let decoded = VaultInstruction::try_from_slice(&data).unwrap();
+            assert_eq!(decoded.discriminator(), discriminator);
+        }
+    }
+
+    #[test]
+    fn test_instruction_discriminators_are_contiguous() {
+        for (i, (_, discriminator)) in instructions().into_iter().enumerate() {
+            assert_eq!(discriminator as usize, i);
+        }
+    }
+}
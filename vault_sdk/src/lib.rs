@@ -1,4 +1,11 @@
+pub mod apy;
+pub mod builder;
+pub mod compute_units;
 pub mod error;
+pub mod events;
+pub mod hook;
 pub mod inline_mpl_token_metadata;
+pub mod inline_spl_governance;
 pub mod instruction;
+pub mod pda;
 pub mod sdk;
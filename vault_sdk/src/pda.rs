@@ -0,0 +1,275 @@
+//! Program derived addresses of the vault program's accounts
+//!
+//! Clients that only depend on the SDK can derive the accounts an instruction needs without
+//! re-implementing the seeds. Each `derive_*` function returns the address and its bump seed, the
+//! same as the account's `find_program_address` in `jito-vault-core`.
+
+use solana_program::pubkey::Pubkey;
+
+mod associated_token {
+    solana_program::declare_id!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+}
+
+/// The global vault program configuration
+pub fn derive_config(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], program_id)
+}
+
+/// The vault created with `base`
+pub fn derive_vault(program_id: &Pubkey, base: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault", base.as_ref()], program_id)
+}
+
+/// The vault's opt-in to the NCN
+pub fn derive_vault_ncn_ticket(program_id: &Pubkey, vault: &Pubkey, ncn: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"vault_ncn_ticket", vault.as_ref(), ncn.as_ref()],
+        program_id,
+    )
+}
+
+/// The vault's delegation to the operator
+pub fn derive_vault_operator_delegation(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"vault_operator_delegation",
+            vault.as_ref(),
+            operator.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// The vault's opt-in to the NCN's slasher
+pub fn derive_vault_ncn_slasher_ticket(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    slasher: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"vault_slasher_ticket",
+            vault.as_ref(),
+            ncn.as_ref(),
+            slasher.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// The tracker of the vault's update in `epoch`, an epoch of the vault's epoch length
+pub fn derive_vault_update_state_tracker(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    epoch: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"vault_update_state_tracker",
+            vault.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// The vault's delegation strategy
+pub fn derive_delegation_strategy(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"delegation_strategy", vault.as_ref()], program_id)
+}
+
+/// The vault's per-epoch deposit, withdrawal and slash stats
+pub fn derive_vault_epoch_stats(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault_epoch_stats", vault.as_ref()], program_id)
+}
+
+/// The vault's insurance fund
+pub fn derive_vault_insurance(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault_insurance", vault.as_ref()], program_id)
+}
+
+/// The withdrawal ticket enqueued with `base`
+pub fn derive_vault_staker_withdrawal_ticket(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    base: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"vault_staker_withdrawal_ticket",
+            vault.as_ref(),
+            base.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// The tracker of the vault's slashes of the operator for the NCN's slasher in `epoch`
+pub fn derive_vault_ncn_slasher_operator_ticket(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    slasher: &Pubkey,
+    operator: &Pubkey,
+    epoch: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"vault_ncn_slasher_operator",
+            vault.as_ref(),
+            ncn.as_ref(),
+            slasher.as_ref(),
+            operator.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// The depositor's entry in the vault's deposit allowlist
+pub fn derive_vault_deposit_allowlist_entry(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    depositor: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"vault_deposit_allowlist_entry",
+            vault.as_ref(),
+            depositor.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// The depositor's deposits into the vault
+pub fn derive_vault_depositor(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    depositor: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"vault_depositor", vault.as_ref(), depositor.as_ref()],
+        program_id,
+    )
+}
+
+/// The depositor's receipt for the deposit made with `base`
+pub fn derive_deposit_receipt(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    depositor: &Pubkey,
+    base: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"deposit_receipt",
+            vault.as_ref(),
+            depositor.as_ref(),
+            base.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// The slash proposal created with `base`
+pub fn derive_slash_proposal(program_id: &Pubkey, vault: &Pubkey, base: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"slash_proposal", vault.as_ref(), base.as_ref()],
+        program_id,
+    )
+}
+
+/// The snapshot of the vault's delegation to the operator at the start of `ncn_epoch`
+pub fn derive_vault_operator_delegation_snapshot(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    ncn_epoch: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"vault_delegation_snapshot",
+            vault.as_ref(),
+            operator.as_ref(),
+            &ncn_epoch.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// The operator's stake weight for the NCN in `ncn_epoch`, summed over vaults of `supported_mint`
+pub fn derive_operator_stake_weight(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    supported_mint: &Pubkey,
+    ncn_epoch: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"operator_stake_weight",
+            ncn.as_ref(),
+            operator.as_ref(),
+            supported_mint.as_ref(),
+            &ncn_epoch.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// The router of the NCN's rewards to the vault in `epoch`
+pub fn derive_ncn_reward_router(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    vault: &Pubkey,
+    epoch: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"ncn_reward_router",
+            ncn.as_ref(),
+            vault.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// The vault's reward distribution for `epoch`
+pub fn derive_reward_distribution(program_id: &Pubkey, vault: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"reward_distribution", vault.as_ref(), &epoch.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// The staker's claim of the reward distribution
+pub fn derive_reward_claim(
+    program_id: &Pubkey,
+    reward_distribution: &Pubkey,
+    staker: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"reward_claim",
+            reward_distribution.as_ref(),
+            staker.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// The SPL token associated token account of `owner` for `mint`
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), spl_token::id().as_ref(), mint.as_ref()],
+        &associated_token::id(),
+    )
+    .0
+}
@@ -7,7 +7,7 @@ use solana_program::{
 
 use crate::{
     inline_mpl_token_metadata::{self},
-    instruction::{VaultAdminRole, VaultInstruction, WithdrawalAllocationMethod},
+    instruction::{DepositMode, VaultAdminRole, VaultInstruction, WithdrawalAllocationMethod},
 };
 
 pub fn initialize_config(
@@ -67,6 +67,31 @@ pub fn initialize_vault(
     }
 }
 
+pub fn initialize_vault_token_account(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    vault_token_account: &Pubkey,
+    supported_mint: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new_readonly(*supported_mint, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitializeVaultTokenAccount
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn initialize_vault_ncn_ticket(
     program_id: &Pubkey,
@@ -161,8 +186,9 @@ pub fn mint_to(
     depositor_token_account: &Pubkey,
     vault_token_account: &Pubkey,
     depositor_vrt_token_account: &Pubkey,
-    vault_fee_token_account: &Pubkey,
     mint_signer: Option<&Pubkey>,
+    vault_deposit_allowlist_entry: Option<&Pubkey>,
+    vault_depositor: Option<&Pubkey>,
     amount_in: u64,
     min_amount_out: u64,
 ) -> Instruction {
@@ -174,12 +200,21 @@ pub fn mint_to(
         AccountMeta::new(*depositor_token_account, false),
         AccountMeta::new(*vault_token_account, false),
         AccountMeta::new(*depositor_vrt_token_account, false),
-        AccountMeta::new(*vault_fee_token_account, false),
         AccountMeta::new_readonly(spl_token::id(), false),
     ];
     if let Some(signer) = mint_signer {
         accounts.push(AccountMeta::new_readonly(*signer, true));
     }
+    if let Some(vault_deposit_allowlist_entry) = vault_deposit_allowlist_entry {
+        accounts.push(AccountMeta::new_readonly(
+            *vault_deposit_allowlist_entry,
+            false,
+        ));
+    }
+    if let Some(vault_depositor) = vault_depositor {
+        accounts.push(AccountMeta::new(*vault_depositor, false));
+        accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -192,6 +227,55 @@ pub fn mint_to(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn mint_to_with_sol(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vrt_mint: &Pubkey,
+    depositor: &Pubkey,
+    vault_token_account: &Pubkey,
+    depositor_vrt_token_account: &Pubkey,
+    mint_signer: Option<&Pubkey>,
+    vault_deposit_allowlist_entry: Option<&Pubkey>,
+    vault_depositor: Option<&Pubkey>,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vrt_mint, false),
+        AccountMeta::new(*depositor, true),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*depositor_vrt_token_account, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    if let Some(signer) = mint_signer {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
+    if let Some(vault_deposit_allowlist_entry) = vault_deposit_allowlist_entry {
+        accounts.push(AccountMeta::new_readonly(
+            *vault_deposit_allowlist_entry,
+            false,
+        ));
+    }
+    if let Some(vault_depositor) = vault_depositor {
+        accounts.push(AccountMeta::new(*vault_depositor, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::MintToWithSol {
+            amount_in,
+            min_amount_out,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn burn(
     program_id: &Pubkey,
@@ -204,6 +288,7 @@ pub fn burn(
     staker_vrt_token_account: &Pubkey,
     vault_fee_token_account: &Pubkey,
     burn_signer: Option<&Pubkey>,
+    vault_depositor: Option<&Pubkey>,
     amount_in: u64,
     min_amount_out: u64,
 ) -> Instruction {
@@ -222,6 +307,9 @@ pub fn burn(
     if let Some(signer) = burn_signer {
         accounts.push(AccountMeta::new_readonly(*signer, true));
     }
+    if let Some(vault_depositor) = vault_depositor {
+        accounts.push(AccountMeta::new_readonly(*vault_depositor, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -234,307 +322,1612 @@ pub fn burn(
     }
 }
 
-pub fn set_deposit_capacity(
+/// Burns VRT from `owner_vrt_token_account`, which must have approved `burner` as its delegate,
+/// sending the tokens withdrawn to the owner
+#[allow(clippy::too_many_arguments)]
+pub fn burn_delegated(
     program_id: &Pubkey,
     config: &Pubkey,
     vault: &Pubkey,
-    admin: &Pubkey,
-    amount: u64,
+    vault_token_account: &Pubkey,
+    vrt_mint: &Pubkey,
+    owner: &Pubkey,
+    burner: &Pubkey,
+    owner_token_account: &Pubkey,
+    owner_vrt_token_account: &Pubkey,
+    vault_fee_token_account: &Pubkey,
+    burn_signer: Option<&Pubkey>,
+    vault_depositor: Option<&Pubkey>,
+    amount_in: u64,
+    min_amount_out: u64,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
-        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*vrt_mint, false),
+        AccountMeta::new_readonly(*owner, false),
+        AccountMeta::new_readonly(*burner, true),
+        AccountMeta::new(*owner_token_account, false),
+        AccountMeta::new(*owner_vrt_token_account, false),
+        AccountMeta::new(*vault_fee_token_account, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
     ];
+    if let Some(signer) = burn_signer {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
+    if let Some(vault_depositor) = vault_depositor {
+        accounts.push(AccountMeta::new_readonly(*vault_depositor, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::SetDepositCapacity { amount }
-            .try_to_vec()
-            .unwrap(),
+        data: VaultInstruction::BurnDelegated {
+            amount_in,
+            min_amount_out,
+        }
+        .try_to_vec()
+        .unwrap(),
     }
 }
 
-pub fn set_fees(
+#[allow(clippy::too_many_arguments)]
+pub fn burn_or_enqueue_withdrawal(
     program_id: &Pubkey,
     config: &Pubkey,
     vault: &Pubkey,
-    admin: &Pubkey,
-    deposit_fee_bps: Option<u16>,
-    withdrawal_fee_bps: Option<u16>,
-    reward_fee_bps: Option<u16>,
+    vault_token_account: &Pubkey,
+    vrt_mint: &Pubkey,
+    staker: &Pubkey,
+    staker_token_account: &Pubkey,
+    staker_vrt_token_account: &Pubkey,
+    vault_fee_token_account: &Pubkey,
+    vault_staker_withdrawal_ticket: &Pubkey,
+    vault_staker_withdrawal_ticket_token_account: &Pubkey,
+    base: &Pubkey,
+    burn_signer: Option<&Pubkey>,
+    vault_depositor: Option<&Pubkey>,
+    amount_in: u64,
+    min_amount_out: u64,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
-        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*vrt_mint, false),
+        AccountMeta::new(*staker, true),
+        AccountMeta::new(*staker_token_account, false),
+        AccountMeta::new(*staker_vrt_token_account, false),
+        AccountMeta::new(*vault_fee_token_account, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket_token_account, false),
+        AccountMeta::new_readonly(*base, true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
     ];
+    if let Some(signer) = burn_signer {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
+    if let Some(vault_depositor) = vault_depositor {
+        accounts.push(AccountMeta::new_readonly(*vault_depositor, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::SetFees {
-            deposit_fee_bps,
-            withdrawal_fee_bps,
-            reward_fee_bps,
+        data: VaultInstruction::BurnOrEnqueueWithdrawal {
+            amount_in,
+            min_amount_out,
         }
         .try_to_vec()
         .unwrap(),
     }
 }
 
-pub fn withdrawal_asset(program_id: &Pubkey, amount: u64) -> Instruction {
+pub fn set_deposit_capacity(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    capacity_admin: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*capacity_admin, true),
+    ];
     Instruction {
         program_id: *program_id,
-        accounts: vec![],
-        data: VaultInstruction::AdminWithdraw { amount }
+        accounts,
+        data: VaultInstruction::SetDepositCapacity { amount }
             .try_to_vec()
             .unwrap(),
     }
 }
 
-pub fn set_admin(
+pub fn set_deposit_limits(
     program_id: &Pubkey,
     config: &Pubkey,
     vault: &Pubkey,
-    old_admin: &Pubkey,
-    new_admin: &Pubkey,
+    admin: &Pubkey,
+    max_deposit_per_transaction: Option<u64>,
+    max_deposit_per_wallet: Option<u64>,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
-        AccountMeta::new_readonly(*old_admin, true),
-        AccountMeta::new_readonly(*new_admin, true),
+        AccountMeta::new_readonly(*admin, true),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::SetAdmin.try_to_vec().unwrap(),
+        data: VaultInstruction::SetDepositLimits {
+            max_deposit_per_transaction,
+            max_deposit_per_wallet,
+        }
+        .try_to_vec()
+        .unwrap(),
     }
 }
 
-pub fn set_secondary_admin(
+pub fn set_flash_deposit_protection(
     program_id: &Pubkey,
     config: &Pubkey,
     vault: &Pubkey,
     admin: &Pubkey,
-    new_admin: &Pubkey,
-    role: VaultAdminRole,
+    enabled: bool,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
         AccountMeta::new_readonly(*admin, true),
-        AccountMeta::new_readonly(*new_admin, false),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::SetSecondaryAdmin(role)
+        data: VaultInstruction::SetFlashDepositProtection { enabled }
             .try_to_vec()
             .unwrap(),
     }
 }
 
-pub fn add_delegation(
+pub fn propose_mint_authority_handoff(
     program_id: &Pubkey,
     config: &Pubkey,
     vault: &Pubkey,
-    operator: &Pubkey,
-    vault_operator_delegation: &Pubkey,
-    admin: &Pubkey,
-    amount: u64,
+    config_admin: &Pubkey,
+    successor_program: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
-        AccountMeta::new_readonly(*operator, false),
-        AccountMeta::new(*vault_operator_delegation, false),
-        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*config_admin, true),
+        AccountMeta::new_readonly(*successor_program, false),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::AddDelegation { amount }
+        data: VaultInstruction::ProposeMintAuthorityHandoff
             .try_to_vec()
             .unwrap(),
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn cooldown_delegation(
+pub fn cancel_mint_authority_handoff(
     program_id: &Pubkey,
     config: &Pubkey,
     vault: &Pubkey,
-    operator: &Pubkey,
-    vault_operator_delegation: &Pubkey,
     admin: &Pubkey,
-    amount: u64,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
-        AccountMeta::new_readonly(*operator, false),
-        AccountMeta::new(*vault_operator_delegation, false),
         AccountMeta::new_readonly(*admin, true),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::CooldownDelegation { amount }
+        data: VaultInstruction::CancelMintAuthorityHandoff
             .try_to_vec()
             .unwrap(),
     }
 }
 
-pub fn crank_vault_update_state_tracker(
+pub fn execute_mint_authority_handoff(
     program_id: &Pubkey,
     config: &Pubkey,
     vault: &Pubkey,
-    operator: &Pubkey,
-    vault_operator_delegation: &Pubkey,
-    vault_update_state_tracker: &Pubkey,
+    vrt_mint: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
-        AccountMeta::new_readonly(*vault, false),
-        AccountMeta::new_readonly(*operator, false),
-        AccountMeta::new(*vault_operator_delegation, false),
-        AccountMeta::new(*vault_update_state_tracker, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vrt_mint, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::CrankVaultUpdateStateTracker
+        data: VaultInstruction::ExecuteMintAuthorityHandoff
             .try_to_vec()
             .unwrap(),
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn initialize_vault_ncn_slasher_ticket(
+pub fn enable_admin_burn(
     program_id: &Pubkey,
     config: &Pubkey,
     vault: &Pubkey,
-    ncn: &Pubkey,
-    slasher: &Pubkey,
-    ncn_slasher_ticket: &Pubkey,
-    vault_slasher_ticket: &Pubkey,
     admin: &Pubkey,
-    payer: &Pubkey,
+    burn_admin: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
-        AccountMeta::new_readonly(*ncn, false),
-        AccountMeta::new_readonly(*slasher, false),
-        AccountMeta::new_readonly(*ncn_slasher_ticket, false),
-        AccountMeta::new(*vault_slasher_ticket, false),
         AccountMeta::new_readonly(*admin, true),
-        AccountMeta::new(*payer, true),
-        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(*burn_admin, false),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::InitializeVaultNcnSlasherTicket
-            .try_to_vec()
-            .unwrap(),
+        data: VaultInstruction::EnableAdminBurn.try_to_vec().unwrap(),
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn create_token_metadata(
+pub fn propose_admin_burn(
     program_id: &Pubkey,
+    config: &Pubkey,
     vault: &Pubkey,
-    admin: &Pubkey,
-    vrt_mint: &Pubkey,
-    payer: &Pubkey,
-    metadata: &Pubkey,
-    name: String,
-    symbol: String,
-    uri: String,
+    vault_staker_withdrawal_ticket: &Pubkey,
+    burn_admin: &Pubkey,
+    recovery: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
         AccountMeta::new_readonly(*vault, false),
-        AccountMeta::new_readonly(*admin, true),
-        AccountMeta::new_readonly(*vrt_mint, false),
-        AccountMeta::new(*payer, true),
-        AccountMeta::new(*metadata, false),
-        AccountMeta::new_readonly(inline_mpl_token_metadata::id(), false),
-        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket, false),
+        AccountMeta::new_readonly(*burn_admin, true),
+        AccountMeta::new_readonly(*recovery, false),
     ];
-
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::CreateTokenMetadata { name, symbol, uri }
-            .try_to_vec()
-            .unwrap(),
+        data: VaultInstruction::ProposeAdminBurn.try_to_vec().unwrap(),
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn update_token_metadata(
+pub fn cancel_admin_burn(
     program_id: &Pubkey,
+    config: &Pubkey,
     vault: &Pubkey,
+    vault_staker_withdrawal_ticket: &Pubkey,
     admin: &Pubkey,
-    vrt_mint: &Pubkey,
-    metadata: &Pubkey,
-    name: String,
-    symbol: String,
-    uri: String,
 ) -> Instruction {
     let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
         AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket, false),
         AccountMeta::new_readonly(*admin, true),
-        AccountMeta::new(*vrt_mint, false),
-        AccountMeta::new(*metadata, false),
-        AccountMeta::new_readonly(inline_mpl_token_metadata::id(), false),
     ];
-
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::UpdateTokenMetadata { name, symbol, uri }
-            .try_to_vec()
-            .unwrap(),
+        data: VaultInstruction::CancelAdminBurn.try_to_vec().unwrap(),
     }
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn initialize_vault_ncn_slasher_operator_ticket(
+pub fn execute_admin_burn(
     program_id: &Pubkey,
     config: &Pubkey,
     vault: &Pubkey,
-    ncn: &Pubkey,
-    slasher: &Pubkey,
-    operator: &Pubkey,
-    vault_ncn_slasher_ticket: &Pubkey,
-    vault_ncn_slasher_operator_ticket: &Pubkey,
-    payer: &Pubkey,
-) -> Instruction {
+    vault_token_account: &Pubkey,
+    vrt_mint: &Pubkey,
+    staker: &Pubkey,
+    vault_staker_withdrawal_ticket: &Pubkey,
+    vault_staker_withdrawal_ticket_token_account: &Pubkey,
+    vault_fee_token_account: &Pubkey,
+    recovery_token_account: &Pubkey,
+    burn_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*vrt_mint, false),
+        AccountMeta::new(*staker, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket_token_account, false),
+        AccountMeta::new(*vault_fee_token_account, false),
+        AccountMeta::new(*recovery_token_account, false),
+        AccountMeta::new_readonly(*burn_admin, true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ExecuteAdminBurn.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_vault_paused(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    is_paused: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetVaultPaused { is_paused }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn admin_set_config_paused(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    is_paused: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::AdminSetConfigPaused { is_paused }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn admin_set_crank_reward(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    crank_reward_lamports: u64,
+    crank_reward_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::AdminSetCrankReward {
+            crank_reward_lamports,
+            crank_reward_bps,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn reconcile_vault_balance(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_token_account: &Pubkey,
+    admin: &Pubkey,
+    max_adjustment: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*vault_token_account, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ReconcileVaultBalance { max_adjustment }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn admin_set_protocol_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    new_protocol_fee_wallet: &Pubkey,
+    protocol_fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*new_protocol_fee_wallet, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::AdminSetProtocolFee { protocol_fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn admin_set_delegation_cooldown(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    delegation_cooldown_epochs: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::AdminSetDelegationCooldown {
+            delegation_cooldown_epochs,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn admin_set_vault_epoch_length_bounds(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    min_vault_epoch_length: u64,
+    max_vault_epoch_length: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::AdminSetVaultEpochLengthBounds {
+            min_vault_epoch_length,
+            max_vault_epoch_length,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn admin_set_slash_veto(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    new_slash_veto_admin: &Pubkey,
+    slash_veto_window_slots: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*new_slash_veto_admin, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::AdminSetSlashVeto {
+            slash_veto_window_slots,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn set_fee_wallet(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    new_fee_wallet: &Pubkey,
+    new_fee_wallet_vrt_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*new_fee_wallet, false),
+        AccountMeta::new_readonly(*new_fee_wallet_vrt_token_account, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetFeeWallet.try_to_vec().unwrap(),
+    }
+}
+
+/// Sets the program invoked after every deposit and withdrawal, or removes it when `hook_program`
+/// is the system program
+pub fn set_hook_program(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    hook_program: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*hook_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetHookProgram.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_migration_partner(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    migration_partner: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*migration_partner, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetMigrationPartner.try_to_vec().unwrap(),
+    }
+}
+
+/// Burns the staker's VRT of `source_vault` and deposits the redeemed assets into
+/// `destination_vault`. Any optional accounts either vault needs for Burn or MintTo, such as the
+/// vault depositors, are appended by the caller.
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_to_vault(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    source_vault: &Pubkey,
+    source_vault_token_account: &Pubkey,
+    source_vrt_mint: &Pubkey,
+    source_vault_fee_token_account: &Pubkey,
+    staker: &Pubkey,
+    staker_token_account: &Pubkey,
+    staker_source_vrt_token_account: &Pubkey,
+    destination_vault: &Pubkey,
+    destination_vault_token_account: &Pubkey,
+    destination_vrt_mint: &Pubkey,
+    staker_destination_vrt_token_account: &Pubkey,
+    mint_burn_signer: Option<&Pubkey>,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*source_vault, false),
+        AccountMeta::new(*source_vault_token_account, false),
+        AccountMeta::new(*source_vrt_mint, false),
+        AccountMeta::new(*source_vault_fee_token_account, false),
+        AccountMeta::new(*staker, true),
+        AccountMeta::new(*staker_token_account, false),
+        AccountMeta::new(*staker_source_vrt_token_account, false),
+        AccountMeta::new(*destination_vault, false),
+        AccountMeta::new(*destination_vault_token_account, false),
+        AccountMeta::new(*destination_vrt_mint, false),
+        AccountMeta::new(*staker_destination_vrt_token_account, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    if let Some(signer) = mint_burn_signer {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::MigrateToVault {
+            amount_in,
+            min_amount_out,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+/// Closes the vault along with its `companion_accounts`, such as its tickets and operator
+/// delegations
+pub fn close_vault(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    companion_accounts: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*admin, true),
+    ];
+    accounts.extend(
+        companion_accounts
+            .iter()
+            .map(|account| AccountMeta::new(*account, false)),
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CloseVault.try_to_vec().unwrap(),
+    }
+}
+
+pub fn initiate_vault_shutdown(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitiateVaultShutdown
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_min_delegation_amount(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetMinDelegationAmount { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_withdrawal_delay(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    withdrawal_delay_epochs: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetWithdrawalDelay {
+            withdrawal_delay_epochs,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn set_vault_epoch_length(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    epoch_length_slots: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetVaultEpochLength { epoch_length_slots }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_max_total_slashable_per_epoch(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    max_total_slashable_bps_per_epoch: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetMaxTotalSlashablePerEpoch {
+            max_total_slashable_bps_per_epoch,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn set_operator_max_delegation(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    admin: &Pubkey,
+    max_delegation_amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetOperatorMaxDelegation {
+            max_delegation_amount,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn set_operator_delegation_paused(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    admin: &Pubkey,
+    is_paused: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetOperatorDelegationPaused { is_paused }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn ban_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    admin: &Pubkey,
+    reason: u8,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::BanOperator { reason }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn unban_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::UnbanOperator.try_to_vec().unwrap(),
+    }
+}
+
+pub fn initialize_delegation_strategy(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    delegation_strategy: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+    max_movement_per_epoch: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*delegation_strategy, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitializeDelegationStrategy {
+            max_movement_per_epoch,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn set_delegation_strategy_weight(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    delegation_strategy: &Pubkey,
+    admin: &Pubkey,
+    weight: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new(*delegation_strategy, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetDelegationStrategyWeight { weight }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn rebalance_delegations(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    delegation_strategy: &Pubkey,
+    operator_vault_ticket: Option<&Pubkey>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new(*delegation_strategy, false),
+    ];
+    if let Some(operator_vault_ticket) = operator_vault_ticket {
+        accounts.push(AccountMeta::new_readonly(*operator_vault_ticket, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::RebalanceDelegations.try_to_vec().unwrap(),
+    }
+}
+
+pub fn initialize_vault_epoch_stats(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_epoch_stats: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vault_epoch_stats, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitializeVaultEpochStats
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_deposit_mode(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    deposit_mode: DepositMode,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetDepositMode { deposit_mode }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn add_to_deposit_allowlist(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    depositor: &Pubkey,
+    vault_deposit_allowlist_entry: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*depositor, false),
+        AccountMeta::new(*vault_deposit_allowlist_entry, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::AddToDepositAllowlist
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn remove_from_deposit_allowlist(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    depositor: &Pubkey,
+    vault_deposit_allowlist_entry: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*depositor, false),
+        AccountMeta::new(*vault_deposit_allowlist_entry, false),
+        AccountMeta::new(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::RemoveFromDepositAllowlist
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn close_deposit_receipt(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    deposit_receipt: &Pubkey,
+    depositor: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*deposit_receipt, false),
+        AccountMeta::new(*depositor, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CloseDepositReceipt.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_fees(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    deposit_fee_bps: Option<u16>,
+    withdrawal_fee_bps: Option<u16>,
+    reward_fee_bps: Option<u16>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetFees {
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            reward_fee_bps,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn withdrawal_asset(program_id: &Pubkey, amount: u64) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![],
+        data: VaultInstruction::AdminWithdraw { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_admin(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    old_admin: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*old_admin, true),
+        AccountMeta::new_readonly(*new_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetAdmin.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_secondary_admin(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    new_admin: &Pubkey,
+    role: VaultAdminRole,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*new_admin, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetSecondaryAdmin(role)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn add_delegation(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    admin: &Pubkey,
+    operator_vault_ticket: Option<&Pubkey>,
+    amount: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    if let Some(operator_vault_ticket) = operator_vault_ticket {
+        accounts.push(AccountMeta::new_readonly(*operator_vault_ticket, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::AddDelegation { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn cooldown_delegation(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    admin: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CooldownDelegation { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn crank_vault_update_state_tracker(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    vault_update_state_tracker: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new(*vault_update_state_tracker, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CrankVaultUpdateStateTracker
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn snapshot_vault_operator_delegation(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    vault_update_state_tracker: &Pubkey,
+    vault_operator_delegation_snapshot: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*vault_update_state_tracker, false),
+        AccountMeta::new(*vault_operator_delegation_snapshot, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SnapshotVaultOperatorDelegation
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_reward_distribution(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    reward_distribution: &Pubkey,
+    mint: &Pubkey,
+    vault_rewards_token_account: &Pubkey,
+    admin_token_account: &Pubkey,
+    admin: &Pubkey,
+    operator: Option<(&Pubkey, &Pubkey)>,
+    epoch: u64,
+    merkle_root: [u8; 32],
+    total_rewards: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*reward_distribution, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(*vault_rewards_token_account, false),
+        AccountMeta::new(*admin_token_account, false),
+        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    if let Some((operator, operator_fee_token_account)) = operator {
+        accounts.push(AccountMeta::new_readonly(*operator, false));
+        accounts.push(AccountMeta::new(*operator_fee_token_account, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitializeRewardDistribution {
+            epoch,
+            merkle_root,
+            total_rewards,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn claim_rewards(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    reward_distribution: &Pubkey,
+    reward_claim: &Pubkey,
+    vault_rewards_token_account: &Pubkey,
+    staker_token_account: &Pubkey,
+    staker: &Pubkey,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*reward_distribution, false),
+        AccountMeta::new(*reward_claim, false),
+        AccountMeta::new(*vault_rewards_token_account, false),
+        AccountMeta::new(*staker_token_account, false),
+        AccountMeta::new(*staker, true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ClaimRewards { amount, proof }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn initialize_ncn_reward_router(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    ncn_reward_router: &Pubkey,
+    payer: &Pubkey,
+    epoch: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_reward_router, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitializeNcnRewardRouter { epoch }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+/// Distributes the rewards in an NCN reward router
+///
+/// `operators` holds each operator, its vault operator delegation and the token account of its
+/// withdrawal fee wallet for the vault's supported mint, for every operator the vault delegates
+/// to, in index order.
+#[allow(clippy::too_many_arguments)]
+pub fn distribute_ncn_rewards(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    ncn_reward_router: &Pubkey,
+    ncn_reward_router_token_account: &Pubkey,
+    vault_token_account: &Pubkey,
+    protocol_fee_token_account: &Pubkey,
+    operators: &[(Pubkey, Pubkey, Pubkey)],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_reward_router, false),
+        AccountMeta::new(*ncn_reward_router_token_account, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*protocol_fee_token_account, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for (operator, vault_operator_delegation, operator_fee_token_account) in operators {
+        accounts.push(AccountMeta::new_readonly(*operator, false));
+        accounts.push(AccountMeta::new_readonly(*vault_operator_delegation, false));
+        accounts.push(AccountMeta::new(*operator_fee_token_account, false));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::DistributeNcnRewards.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_insurance_params(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_insurance: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+    fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vault_insurance, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetInsuranceParams { fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn fund_insurance(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_insurance: &Pubkey,
+    vrt_mint: &Pubkey,
+    admin_vrt_token_account: &Pubkey,
+    insurance_vrt_token_account: &Pubkey,
+    admin: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault_insurance, false),
+        AccountMeta::new_readonly(*vrt_mint, false),
+        AccountMeta::new(*admin_vrt_token_account, false),
+        AccountMeta::new(*insurance_vrt_token_account, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::FundInsurance { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_vault_ncn_slasher_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    slasher: &Pubkey,
+    ncn_slasher_ticket: &Pubkey,
+    vault_slasher_ticket: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*ncn_slasher_ticket, false),
+        AccountMeta::new(*vault_slasher_ticket, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitializeVaultNcnSlasherTicket
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_token_metadata(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    vrt_mint: &Pubkey,
+    payer: &Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Instruction {
+    let metadata = inline_mpl_token_metadata::pda::find_metadata_account(vrt_mint).0;
+    let accounts = vec![
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*vrt_mint, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(metadata, false),
+        AccountMeta::new_readonly(inline_mpl_token_metadata::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CreateTokenMetadata { name, symbol, uri }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_token_metadata(
+    program_id: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    vrt_mint: &Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Instruction {
+    let metadata = inline_mpl_token_metadata::pda::find_metadata_account(vrt_mint).0;
+    let accounts = vec![
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*vrt_mint, false),
+        AccountMeta::new(metadata, false),
+        AccountMeta::new_readonly(inline_mpl_token_metadata::id(), false),
+    ];
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::UpdateTokenMetadata { name, symbol, uri }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_vault_ncn_slasher_operator_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    slasher: &Pubkey,
+    operator: &Pubkey,
+    vault_ncn_slasher_ticket: &Pubkey,
+    vault_ncn_slasher_operator_ticket: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*vault_ncn_slasher_ticket, false),
+        AccountMeta::new(*vault_ncn_slasher_operator_ticket, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitializeVaultNcnSlasherOperatorTicket
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn slash(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    ncn_vault_ticket: &Pubkey,
+    operator_vault_ticket: &Pubkey,
+    vault_ncn_ticket: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    vault_ncn_slasher_ticket: &Pubkey,
+    vault_ncn_slasher_operator_ticket: &Pubkey,
+    vault_token_account: &Pubkey,
+    slash_destination: &Pubkey,
+    amount: u64,
+) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
-        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault, false),
         AccountMeta::new_readonly(*ncn, false),
-        AccountMeta::new_readonly(*slasher, false),
         AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*ncn_vault_ticket, false),
+        AccountMeta::new_readonly(*operator_vault_ticket, false),
+        AccountMeta::new_readonly(*vault_ncn_ticket, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false),
         AccountMeta::new_readonly(*vault_ncn_slasher_ticket, false),
         AccountMeta::new(*vault_ncn_slasher_operator_ticket, false),
-        AccountMeta::new(*payer, true),
-        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*slash_destination, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::InitializeVaultNcnSlasherOperatorTicket
-            .try_to_vec()
-            .unwrap(),
+        data: VaultInstruction::Slash { amount }.try_to_vec().unwrap(),
     }
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn slash(
+pub fn propose_slash(
     program_id: &Pubkey,
     config: &Pubkey,
     vault: &Pubkey,
@@ -549,32 +1942,122 @@ pub fn slash(
     ncn_vault_slasher_ticket: &Pubkey,
     vault_ncn_slasher_ticket: &Pubkey,
     vault_ncn_slasher_operator_ticket: &Pubkey,
-    vault_token_account: &Pubkey,
-    slasher_token_account: &Pubkey,
+    slash_proposal: &Pubkey,
+    base: &Pubkey,
     amount: u64,
+    evidence_hash: [u8; 32],
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
         AccountMeta::new_readonly(*ncn, false),
         AccountMeta::new_readonly(*operator, false),
-        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*slasher, true),
         AccountMeta::new_readonly(*ncn_operator_state, false),
         AccountMeta::new_readonly(*ncn_vault_ticket, false),
         AccountMeta::new_readonly(*operator_vault_ticket, false),
         AccountMeta::new_readonly(*vault_ncn_ticket, false),
-        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*vault_operator_delegation, false),
         AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false),
         AccountMeta::new_readonly(*vault_ncn_slasher_ticket, false),
         AccountMeta::new(*vault_ncn_slasher_operator_ticket, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new_readonly(*base, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ProposeSlash {
+            amount,
+            evidence_hash,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn veto_slash(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    vault_ncn_slasher_operator_ticket: &Pubkey,
+    slash_proposal: &Pubkey,
+    slash_veto_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*slasher, false),
+        AccountMeta::new(*vault_ncn_slasher_operator_ticket, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new_readonly(*slash_veto_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::VetoSlash.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_slash_dispute_haircut(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    enabled: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetSlashDisputeHaircut { enabled }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_slash(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    slasher: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    slash_proposal: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    vault_token_account: &Pubkey,
+    slash_destination: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*slasher, true),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new(*slash_proposal, false),
+        AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false),
         AccountMeta::new(*vault_token_account, false),
-        AccountMeta::new(*slasher_token_account, false),
+        AccountMeta::new(*slash_destination, false),
         AccountMeta::new_readonly(spl_token::id(), false),
     ];
     Instruction {
         program_id: *program_id,
         accounts,
-        data: VaultInstruction::Slash { amount }.try_to_vec().unwrap(),
+        data: VaultInstruction::ExecuteSlash.try_to_vec().unwrap(),
     }
 }
 
@@ -610,6 +2093,30 @@ pub fn enqueue_withdraw(
     }
 }
 
+pub fn change_withdrawal_ticket_owner(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_staker_withdrawal_ticket: &Pubkey,
+    old_owner: &Pubkey,
+    new_owner: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket, false),
+        AccountMeta::new_readonly(*old_owner, true),
+        AccountMeta::new_readonly(*new_owner, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::ChangeWithdrawalTicketOwner
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn burn_withdrawal_ticket(
     program_id: &Pubkey,
@@ -622,9 +2129,11 @@ pub fn burn_withdrawal_ticket(
     vault_staker_withdrawal_ticket: &Pubkey,
     vault_staker_withdrawal_ticket_token_account: &Pubkey,
     vault_fee_token_account: &Pubkey,
+    burn_signer: Option<&Pubkey>,
+    claimer_token_account: Option<&Pubkey>,
     min_amount_out: u64,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
         AccountMeta::new(*vault_token_account, false),
@@ -637,6 +2146,12 @@ pub fn burn_withdrawal_ticket(
         AccountMeta::new_readonly(spl_token::id(), false),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
+    if let Some(signer) = burn_signer {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
+    if let Some(claimer_token_account) = claimer_token_account {
+        accounts.push(AccountMeta::new(*claimer_token_account, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -646,6 +2161,30 @@ pub fn burn_withdrawal_ticket(
     }
 }
 
+pub fn set_withdrawal_ticket_auto_claim_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_staker_withdrawal_ticket: &Pubkey,
+    staker: &Pubkey,
+    fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new(*vault_staker_withdrawal_ticket, false),
+        AccountMeta::new_readonly(*staker, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetWithdrawalTicketAutoClaimFee { fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn update_vault_balance(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -654,14 +2193,16 @@ pub fn update_vault_balance(
     vrt_mint: &Pubkey,
     vault_fee_token_account: &Pubkey,
     token_program: &Pubkey,
+    protocol_fee_token_account: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new(*vault, false),
-        AccountMeta::new_readonly(*vault_token_account, false),
+        AccountMeta::new(*vault_token_account, false),
         AccountMeta::new(*vrt_mint, false),
         AccountMeta::new(*vault_fee_token_account, false),
         AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new(*protocol_fee_token_account, false),
     ];
     Instruction {
         program_id: *program_id,
@@ -766,3 +2307,124 @@ pub fn warmup_vault_ncn_slasher_ticket(
             .unwrap(),
     }
 }
+
+pub fn cooldown_vault_ncn_slasher_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    slasher: &Pubkey,
+    vault_slasher_ticket: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*vault_slasher_ticket, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CooldownVaultNcnSlasherTicket
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn remove_slasher(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    slasher: &Pubkey,
+    vault_slasher_ticket: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*vault_slasher_ticket, false),
+        AccountMeta::new(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::RemoveSlasher.try_to_vec().unwrap(),
+    }
+}
+
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::MigrateAccount.try_to_vec().unwrap(),
+    }
+}
+
+pub fn initialize_operator_stake_weight(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    supported_mint: &Pubkey,
+    operator_stake_weight: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*supported_mint, false),
+        AccountMeta::new(*operator_stake_weight, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitializeOperatorStakeWeight
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn crank_operator_stake_weight(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    ncn_vault_ticket: &Pubkey,
+    vault_ncn_ticket: &Pubkey,
+    vault_operator_delegation_snapshot: &Pubkey,
+    operator_stake_weight: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn_vault_ticket, false),
+        AccountMeta::new_readonly(*vault_ncn_ticket, false),
+        AccountMeta::new_readonly(*vault_operator_delegation_snapshot, false),
+        AccountMeta::new(*operator_stake_weight, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CrankOperatorStakeWeight
+            .try_to_vec()
+            .unwrap(),
+    }
+}